@@ -30,6 +30,16 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_color: bool,
 
+    /// Log line format on stderr
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value = "text",
+        env = "RUSTCONN_LOG_FORMAT"
+    )]
+    pub log_format: LogFormatArg,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -52,9 +62,51 @@ pub enum Commands {
         #[arg(short, long)]
         group: Option<String>,
 
-        /// Filter connections by tag
-        #[arg(short, long)]
-        tag: Option<String>,
+        /// Filter connections by tag (repeatable or comma-separated; all must match)
+        #[arg(short, long, value_delimiter = ',')]
+        tag: Vec<String>,
+    },
+
+    /// Search connections and manage saved searches
+    #[command(about = "Search connections, optionally saving or re-running named searches")]
+    Search {
+        /// Query text; supports protocol:, tag:, group:, host:, user: and
+        /// prop: operators
+        #[arg(conflicts_with_all = ["run", "list_searches", "delete", "interactive"])]
+        query: Vec<String>,
+
+        /// Save the query under this name, then run it
+        #[arg(long, value_name = "NAME", requires = "query")]
+        save: Option<String>,
+
+        /// Run the saved search with this name
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["list_searches", "delete"])]
+        run: Option<String>,
+
+        /// List saved searches
+        #[arg(long, conflicts_with = "delete")]
+        list_searches: bool,
+
+        /// Delete the saved search with this name
+        #[arg(long, value_name = "NAME", conflicts_with = "interactive")]
+        delete: Option<String>,
+
+        /// Read queries from stdin, one per line, and print results once
+        /// the input pauses
+        #[arg(short, long, conflicts_with_all = ["save", "run", "list_searches"])]
+        interactive: bool,
+
+        /// Maximum number of results per interactive update
+        #[arg(short, long, default_value = "10", requires = "interactive")]
+        limit: usize,
+
+        /// How query text is matched (saved searches keep their own mode)
+        #[arg(short, long, default_value = "fuzzy", value_enum)]
+        mode: SearchModeArg,
+
+        /// Output format
+        #[arg(short, long, default_value = "table", value_enum)]
+        format: OutputFormat,
     },
 
     /// Connect to a server by name or ID
@@ -255,6 +307,10 @@ pub enum Commands {
         #[arg(long)]
         skip_port_check: bool,
 
+        /// Resolve the host through Tailscale MagicDNS for port checks
+        #[arg(long)]
+        tailscale_dns: bool,
+
         /// Enable X11 forwarding (-X flag) for SSH/SFTP connections
         #[arg(long)]
         x11_forwarding: bool,
@@ -272,7 +328,8 @@ pub enum Commands {
         startup_command: Option<String>,
 
         /// SSH ProxyCommand for connections that require a proxy
-        /// (e.g., "ncat --proxy 127.0.0.1:9050 --proxy-type socks5 %h %p")
+        /// (e.g., "ncat --proxy 127.0.0.1:9050 --proxy-type socks5 %h %p");
+        /// {{host}} and {{port}} work like %h and %p
         #[arg(long, value_name = "TEXT")]
         proxy_command: Option<String>,
 
@@ -288,8 +345,8 @@ pub enum Commands {
         #[arg(long, value_name = "R:H:P")]
         remote_forward: Vec<String>,
 
-        /// Dynamic (SOCKS) port forwarding (repeatable, format: PORT)
-        #[arg(long, value_name = "PORT")]
+        /// Dynamic (SOCKS) port forwarding (repeatable, format: [BIND:]PORT)
+        #[arg(long, value_name = "[BIND:]PORT")]
         dynamic_forward: Vec<String>,
 
         /// RDP gateway hostname (enables gateway tunneling)
@@ -328,6 +385,10 @@ pub enum Commands {
         #[arg(long)]
         disable_nla: bool,
 
+        /// RDP security protocol: auto (negotiate, default), rdp, tls, or nla
+        #[arg(long, value_name = "PROTOCOL", value_parser = ["auto", "rdp", "tls", "nla"])]
+        rdp_security: Option<String>,
+
         /// RDP keyboard layout override (Windows KLID, e.g. 0x00000409 for US)
         #[arg(long, value_name = "KLID")]
         keyboard_layout: Option<u32>,
@@ -381,6 +442,14 @@ pub enum Commands {
         #[arg(long, value_name = "ARG")]
         vnc_custom_arg: Vec<String>,
 
+        /// VNC repeater to connect through, as HOST[:PORT] (default port: 5901)
+        #[arg(long, value_name = "HOST[:PORT]", requires = "vnc_repeater_id")]
+        vnc_repeater: Option<String>,
+
+        /// ID the VNC server registered with the repeater
+        #[arg(long, value_name = "ID", requires = "vnc_repeater")]
+        vnc_repeater_id: Option<String>,
+
         // --- SPICE-specific flags ---
         /// Enable SPICE TLS encryption
         #[arg(long)]
@@ -410,8 +479,8 @@ pub enum Commands {
         #[arg(long, value_name = "URL")]
         spice_proxy: Option<String>,
 
-        /// SPICE shared folder (repeatable, format: NAME:PATH)
-        #[arg(long, value_name = "NAME:PATH")]
+        /// SPICE shared folder (repeatable, format: NAME:PATH, append :ro for read-only)
+        #[arg(long, value_name = "NAME:PATH[:ro]")]
         spice_shared_folder: Vec<String>,
 
         // --- MOSH-specific flags ---
@@ -532,6 +601,14 @@ pub enum Commands {
         #[arg(short, long, default_value = "10")]
         timeout: u64,
 
+        /// Also send an ICMP echo to tell a down host from a closed port
+        #[arg(long)]
+        ping: bool,
+
+        /// Also probe the UDP port of protocols with a UDP transport (RDP)
+        #[arg(long)]
+        udp: bool,
+
         /// Output format (table, json, csv)
         #[arg(short, long, default_value = "table", value_enum)]
         format: OutputFormat,
@@ -749,6 +826,10 @@ pub enum Commands {
         #[arg(long, num_args = 0..=1, default_missing_value = "true")]
         skip_port_check: Option<bool>,
 
+        /// Set Tailscale MagicDNS resolution (use --tailscale-dns=false to clear)
+        #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+        tailscale_dns: Option<bool>,
+
         /// Enable X11 forwarding (-X flag) for SSH/SFTP connections
         #[arg(long)]
         x11_forwarding: bool,
@@ -766,7 +847,8 @@ pub enum Commands {
         startup_command: Option<String>,
 
         /// SSH ProxyCommand for connections that require a proxy
-        /// (e.g., "ncat --proxy 127.0.0.1:9050 --proxy-type socks5 %h %p")
+        /// (e.g., "ncat --proxy 127.0.0.1:9050 --proxy-type socks5 %h %p");
+        /// {{host}} and {{port}} work like %h and %p
         #[arg(long, value_name = "TEXT")]
         proxy_command: Option<String>,
 
@@ -782,8 +864,8 @@ pub enum Commands {
         #[arg(long, value_name = "R:H:P")]
         remote_forward: Vec<String>,
 
-        /// Dynamic (SOCKS) port forwarding (repeatable, format: PORT)
-        #[arg(long, value_name = "PORT")]
+        /// Dynamic (SOCKS) port forwarding (repeatable, format: [BIND:]PORT)
+        #[arg(long, value_name = "[BIND:]PORT")]
         dynamic_forward: Vec<String>,
 
         /// RDP gateway hostname (enables gateway tunneling)
@@ -822,6 +904,10 @@ pub enum Commands {
         #[arg(long)]
         disable_nla: bool,
 
+        /// RDP security protocol: auto (negotiate, default), rdp, tls, or nla
+        #[arg(long, value_name = "PROTOCOL", value_parser = ["auto", "rdp", "tls", "nla"])]
+        rdp_security: Option<String>,
+
         /// RDP keyboard layout override (Windows KLID, e.g. 0x00000409 for US)
         #[arg(long, value_name = "KLID")]
         keyboard_layout: Option<u32>,
@@ -875,6 +961,14 @@ pub enum Commands {
         #[arg(long, value_name = "ARG")]
         vnc_custom_arg: Vec<String>,
 
+        /// VNC repeater to connect through, as HOST[:PORT] (default port: 5901)
+        #[arg(long, value_name = "HOST[:PORT]")]
+        vnc_repeater: Option<String>,
+
+        /// ID the VNC server registered with the repeater
+        #[arg(long, value_name = "ID")]
+        vnc_repeater_id: Option<String>,
+
         // --- SPICE-specific flags ---
         /// Enable SPICE TLS encryption
         #[arg(long)]
@@ -904,8 +998,8 @@ pub enum Commands {
         #[arg(long, value_name = "URL")]
         spice_proxy: Option<String>,
 
-        /// SPICE shared folder (repeatable, format: NAME:PATH)
-        #[arg(long, value_name = "NAME:PATH")]
+        /// SPICE shared folder (repeatable, format: NAME:PATH, append :ro for read-only)
+        #[arg(long, value_name = "NAME:PATH[:ro]")]
         spice_shared_folder: Vec<String>,
 
         // --- MOSH-specific flags ---
@@ -983,7 +1077,7 @@ pub enum Commands {
         /// (format: AA:BB:CC:DD:EE:FF or AA-BB-CC-DD-EE-FF)
         target: String,
 
-        /// Broadcast address (default: 255.255.255.255)
+        /// Broadcast address, or IPv6 multicast such as ff02::1%eth0 (default: 255.255.255.255)
         #[arg(short, long, default_value = "255.255.255.255")]
         broadcast: String,
 
@@ -1008,6 +1102,10 @@ pub enum Commands {
     #[command(subcommand, about = "Manage connection clusters")]
     Cluster(ClusterCommands),
 
+    /// Manage reusable port-forward profiles
+    #[command(subcommand, about = "Manage reusable port-forward profiles")]
+    ForwardProfile(ForwardProfileCommands),
+
     /// Manage global variables
     #[command(subcommand, about = "Manage global variables")]
     Var(VariableCommands),
@@ -1059,6 +1157,18 @@ pub enum Commands {
         /// Open SFTP via Midnight Commander (mc) in terminal
         #[arg(long)]
         mc: bool,
+
+        /// Continue interrupted transfers (sftp -a, with --cli)
+        #[arg(long)]
+        resume: bool,
+
+        /// Remove the SSH key from the agent after this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        key_lifetime: Option<u64>,
+
+        /// Ask for confirmation each time the agent uses the SSH key
+        #[arg(long)]
+        confirm_key: bool,
     },
 
     /// Show connection statistics
@@ -1089,6 +1199,10 @@ pub enum Commands {
     #[command(subcommand, about = "View and manage connection history")]
     History(HistoryCommands),
 
+    /// Inspect RustConn internals
+    #[command(subcommand, about = "Show diagnostic information")]
+    Diagnostics(DiagnosticsCommands),
+
     /// Pin a connection to favorites
     #[command(about = "Pin a connection to favorites")]
     Pin {
@@ -1107,6 +1221,10 @@ pub enum Commands {
     #[command(subcommand, about = "Manage connection tags")]
     Tag(TagCommands),
 
+    /// Manage terminal color themes
+    #[command(subcommand, about = "Manage terminal color themes")]
+    Theme(ThemeCommands),
+
     /// Move a connection to a different group
     #[command(about = "Move a connection to a different group")]
     Move {
@@ -1121,6 +1239,46 @@ pub enum Commands {
     /// Manage per-connection monitoring
     #[command(subcommand, about = "Manage per-connection monitoring")]
     Monitor(MonitorCommands),
+
+    /// Generate a password or diceware passphrase
+    #[command(about = "Generate a random password or diceware passphrase")]
+    Password {
+        /// Generate a diceware passphrase from the EFF large wordlist
+        #[arg(long)]
+        passphrase: bool,
+
+        /// Generate a pronounceable password of consonant-vowel syllables
+        #[arg(long, conflicts_with = "passphrase")]
+        pronounceable: bool,
+
+        /// Password length in characters
+        #[arg(short, long, default_value = "16", conflicts_with = "passphrase")]
+        length: usize,
+
+        /// Number of passphrase words
+        #[arg(short, long, default_value = "6", requires = "passphrase")]
+        words: usize,
+
+        /// Separator between passphrase words
+        #[arg(short, long, default_value = "-", requires = "passphrase")]
+        separator: String,
+
+        /// Capitalize the first letter of each passphrase word
+        #[arg(long, requires = "passphrase")]
+        capitalize: bool,
+
+        /// Append a random digit to one passphrase word
+        #[arg(long, requires = "passphrase")]
+        number: bool,
+
+        /// Leave special characters out of a character password
+        #[arg(long, conflicts_with = "passphrase")]
+        no_special: bool,
+
+        /// Leave digits out of a character or pronounceable password
+        #[arg(long, conflicts_with = "passphrase")]
+        no_digits: bool,
+    },
 }
 
 /// Output format for the list command
@@ -1149,6 +1307,26 @@ impl OutputFormat {
     }
 }
 
+/// Log line format
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum LogFormatArg {
+    /// Human-readable text
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+/// Text matching mode for the search command
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum SearchModeArg {
+    /// Fuzzy matching
+    Fuzzy,
+    /// Literal substring matching
+    Substring,
+    /// Regular expression matching
+    Regex,
+}
+
 /// Export format options
 #[derive(Clone, Copy, Debug, ValueEnum)]
 pub enum ExportFormatArg {
@@ -1171,6 +1349,10 @@ pub enum ExportFormatArg {
     /// SecureCRT session format (.ini directory)
     #[value(name = "secure-crt", alias = "securecrt")]
     SecureCrt,
+    /// Windows Terminal profile fragment (.json)
+    WindowsTerminal,
+    /// Apache Guacamole user-mapping.xml (passwords as placeholders)
+    Guacamole,
 }
 
 /// Import format options
@@ -1207,6 +1389,15 @@ pub enum ImportFormatArg {
     /// SecureCRT session format (.ini directory)
     #[value(name = "secure-crt", alias = "securecrt")]
     SecureCrt,
+    /// PuTTY sessions (registry .reg export or ~/.putty/sessions)
+    Putty,
+    /// mRemoteNG connection file (confCons.xml)
+    #[value(name = "mremoteng", alias = "mremote-ng")]
+    MremoteNg,
+    /// Termius JSON export
+    Termius,
+    /// Detect the format from the file contents
+    Detect,
 }
 
 /// Snippet subcommands
@@ -1257,6 +1448,14 @@ pub enum SnippetCommands {
         /// Tags (comma-separated)
         #[arg(short, long)]
         tags: Option<String>,
+
+        /// Make a variable a choice list (format: var=opt1,opt2; can be repeated)
+        #[arg(long = "choice", value_parser = parse_key_val)]
+        choices: Vec<(String, String)>,
+
+        /// Make a variable secret, prompted without echo (can be repeated)
+        #[arg(long = "secret")]
+        secrets: Vec<String>,
     },
 
     /// Edit an existing snippet
@@ -1294,6 +1493,9 @@ pub enum SnippetCommands {
     },
 
     /// Execute a snippet with variable substitution
+    ///
+    /// Variables not given with --var are prompted for when stdin is a
+    /// terminal.
     #[command(about = "Show snippet command with variable substitution")]
     Run {
         /// Snippet name or ID
@@ -1489,6 +1691,10 @@ pub enum TemplateCommands {
         /// Description
         #[arg(short, long)]
         description: Option<String>,
+
+        /// Parent template (name or ID) to inherit unset fields from
+        #[arg(long, value_name = "TEMPLATE")]
+        parent: Option<String>,
     },
 
     /// Edit an existing template
@@ -1516,6 +1722,10 @@ pub enum TemplateCommands {
         /// New description
         #[arg(short, long)]
         description: Option<String>,
+
+        /// New parent template (name or ID); empty string removes the parent
+        #[arg(long, value_name = "TEMPLATE")]
+        parent: Option<String>,
     },
 
     /// Delete a template
@@ -1581,10 +1791,18 @@ pub enum ClusterCommands {
         /// Enable broadcast mode by default
         #[arg(short, long)]
         broadcast: bool,
+
+        /// Delay between starting member connections, in milliseconds
+        #[arg(long, default_value_t = 0)]
+        connect_delay_ms: u64,
+
+        /// Maximum number of members connecting at once (0 = unlimited)
+        #[arg(long, default_value_t = 0)]
+        max_in_flight: usize,
     },
 
-    /// Edit a cluster (rename or toggle broadcast)
-    #[command(about = "Edit a cluster's name or broadcast setting")]
+    /// Edit a cluster (rename, toggle broadcast or adjust staggered start)
+    #[command(about = "Edit a cluster's name, broadcast or staggered start settings")]
     Edit {
         /// Cluster name or ID
         name: String,
@@ -1596,6 +1814,14 @@ pub enum ClusterCommands {
         /// Enable or disable broadcast mode (true/false)
         #[arg(short, long)]
         broadcast: Option<bool>,
+
+        /// Delay between starting member connections, in milliseconds
+        #[arg(long)]
+        connect_delay_ms: Option<u64>,
+
+        /// Maximum number of members connecting at once (0 = unlimited)
+        #[arg(long)]
+        max_in_flight: Option<usize>,
     },
 
     /// Delete a cluster
@@ -1628,6 +1854,23 @@ pub enum ClusterCommands {
         #[arg(short, long)]
         connection: String,
     },
+
+    /// Set or clear a member's broadcast command template
+    #[command(about = "Set or clear a member's broadcast command template")]
+    SetTemplate {
+        /// Cluster name or ID
+        #[arg(short = 'C', long)]
+        cluster: String,
+
+        /// Connection name or ID
+        #[arg(short, long)]
+        connection: String,
+
+        /// Template applied to broadcast input; `${input}` is the broadcast
+        /// text, other `${var}` references resolve per member (omit to clear)
+        #[arg(short, long)]
+        template: Option<String>,
+    },
 }
 
 /// Variable subcommands
@@ -1689,7 +1932,7 @@ pub enum SecretCommands {
         connection: String,
 
         /// Secret backend to use
-        /// (keyring, keepass, bitwarden, 1password, passbolt)
+        /// (keyring, keepass, bitwarden, 1password, passbolt, pass, vault)
         #[arg(short, long)]
         backend: Option<String>,
     },
@@ -1716,7 +1959,7 @@ pub enum SecretCommands {
         password_stdin: bool,
 
         /// Secret backend to use
-        /// (keyring, keepass, bitwarden, 1password, passbolt)
+        /// (keyring, keepass, bitwarden, 1password, passbolt, pass, vault)
         #[arg(short, long)]
         backend: Option<String>,
     },
@@ -1728,7 +1971,7 @@ pub enum SecretCommands {
         connection: String,
 
         /// Secret backend to use
-        /// (keyring, keepass, bitwarden, 1password, passbolt)
+        /// (keyring, keepass, bitwarden, 1password, passbolt, pass, vault)
         #[arg(short, long)]
         backend: Option<String>,
     },
@@ -1910,6 +2153,21 @@ pub enum RecordingCommands {
         /// Path to the timing file
         timing_file: PathBuf,
     },
+
+    /// Export a recording's terminal output as a transcript
+    #[command(about = "Export a recording's terminal output as a transcript")]
+    Export {
+        /// Recording display name or connection name
+        name: String,
+
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Strip ANSI escape sequences and collapse carriage-return overwrites
+        #[arg(long)]
+        clean: bool,
+    },
 }
 
 /// Sync subcommands (Cloud Sync + inventory sync)
@@ -1965,6 +2223,86 @@ pub enum SyncCommands {
     },
 }
 
+/// Port-forward profile subcommands
+#[derive(Subcommand)]
+pub enum ForwardProfileCommands {
+    /// List all port-forward profiles
+    #[command(about = "List port-forward profiles and their rules")]
+    List {
+        /// Output format
+        #[arg(short, long, default_value = "table", value_enum)]
+        format: OutputFormat,
+    },
+
+    /// Create a port-forward profile
+    #[command(about = "Create or replace a port-forward profile")]
+    Create {
+        /// Profile name
+        #[arg(short, long)]
+        name: String,
+
+        /// Profile description
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Local port forwarding (repeatable, format: LOCAL_PORT:REMOTE_HOST:REMOTE_PORT)
+        #[arg(long, value_name = "L:H:P")]
+        local_forward: Vec<String>,
+
+        /// Remote port forwarding (repeatable, format: REMOTE_PORT:LOCAL_HOST:LOCAL_PORT)
+        #[arg(long, value_name = "R:H:P")]
+        remote_forward: Vec<String>,
+
+        /// Dynamic (SOCKS) port forwarding (repeatable, format: [BIND:]PORT)
+        #[arg(long, value_name = "[BIND:]PORT")]
+        dynamic_forward: Vec<String>,
+
+        /// Replace the rules of an existing profile with this name; connections
+        /// using it pick up the new rules
+        #[arg(long)]
+        replace: bool,
+    },
+
+    /// Delete a port-forward profile
+    #[command(about = "Delete a port-forward profile and detach it from all connections")]
+    Delete {
+        /// Profile name or ID
+        name: String,
+    },
+
+    /// Attach a profile to an SSH connection
+    #[command(about = "Add a profile's port forwards to an SSH connection")]
+    Attach {
+        /// Profile name or ID
+        profile: String,
+
+        /// Connection name or ID
+        connection: String,
+    },
+
+    /// Detach a profile from an SSH connection
+    #[command(about = "Remove a profile from an SSH connection")]
+    Detach {
+        /// Profile name or ID
+        profile: String,
+
+        /// Connection name or ID
+        connection: String,
+    },
+}
+
+/// Diagnostics subcommands
+#[derive(Subcommand)]
+pub enum DiagnosticsCommands {
+    /// Show the memory breakdown of the loaded configuration
+    #[command(about = "Show configuration memory usage and string interning statistics")]
+    Memory {
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 /// History subcommands
 #[derive(Subcommand)]
 pub enum HistoryCommands {
@@ -2036,6 +2374,29 @@ pub enum TagCommands {
     },
 }
 
+/// Theme subcommands
+#[derive(Subcommand)]
+pub enum ThemeCommands {
+    /// List built-in and custom terminal themes
+    #[command(about = "List built-in and custom terminal themes")]
+    List {
+        /// Output format
+        #[arg(short, long, default_value = "table", value_enum)]
+        format: OutputFormat,
+    },
+
+    /// Import an iTerm2 or Base16 color scheme as a custom theme
+    #[command(about = "Import an iTerm2 .itermcolors or Base16 .yaml color scheme")]
+    Import {
+        /// Path to the .itermcolors or Base16 .yaml file
+        file: PathBuf,
+
+        /// Theme name (defaults to the scheme or file name)
+        #[arg(short, long)]
+        name: Option<String>,
+    },
+}
+
 /// Monitor subcommands
 #[derive(Subcommand)]
 pub enum MonitorCommands {
@@ -5,10 +5,10 @@ use std::path::Path;
 use rustconn_core::config::ConfigManager;
 use rustconn_core::models::{
     AwsSsmConfig, AzureBastionConfig, AzureSshConfig, BoundaryConfig, CloudflareAccessConfig,
-    Connection, GcpIapConfig, GenericZeroTrustConfig, HoopDevConfig, OciBastionConfig, PortForward,
-    PortForwardDirection, ProtocolConfig, ProtocolType, RdpGateway, Resolution, SharedFolder,
-    SshAuthMethod, TailscaleSshConfig, TeleportConfig, ZeroTrustConfig, ZeroTrustProvider,
-    ZeroTrustProviderConfig,
+    Connection, GcpIapConfig, GenericZeroTrustConfig, HoopDevConfig, OciBastionConfig,
+    PasswordSource, PortForward, PortForwardDirection, ProtocolConfig, ProtocolType, RdpGateway,
+    RdpSecurityLayer, Resolution, SharedFolder, SshAuthMethod, TailscaleSshConfig, TeleportConfig,
+    VncRepeater, ZeroTrustConfig, ZeroTrustProvider, ZeroTrustProviderConfig,
 };
 
 use crate::error::CliError;
@@ -64,6 +64,7 @@ pub(super) struct AddParams<'a> {
     pub domain: Option<&'a str>,
     pub window_mode: Option<&'a str>,
     pub skip_port_check: bool,
+    pub tailscale_dns: bool,
     pub x11_forwarding: bool,
     pub agent_forwarding: bool,
     pub compression: bool,
@@ -82,6 +83,7 @@ pub(super) struct AddParams<'a> {
     pub resolution: Option<&'a str>,
     pub color_depth: Option<u8>,
     pub disable_nla: bool,
+    pub rdp_security: Option<&'a str>,
     pub keyboard_layout: Option<u32>,
     pub audio_redirect: bool,
     pub printer: bool,
@@ -96,6 +98,8 @@ pub(super) struct AddParams<'a> {
     pub vnc_no_scaling: bool,
     pub vnc_no_clipboard: bool,
     pub vnc_custom_arg: &'a [String],
+    pub vnc_repeater: Option<&'a str>,
+    pub vnc_repeater_id: Option<&'a str>,
     // SPICE
     pub spice_tls: bool,
     pub spice_ca_cert: Option<&'a str>,
@@ -347,6 +351,7 @@ pub(super) fn cmd_add(config_path: Option<&Path>, params: AddParams<'_>) -> Resu
         || params.resolution.is_some()
         || params.color_depth.is_some()
         || params.disable_nla
+        || params.rdp_security.is_some()
         || params.keyboard_layout.is_some()
         || params.audio_redirect
         || params.printer
@@ -374,6 +379,8 @@ pub(super) fn cmd_add(config_path: Option<&Path>, params: AddParams<'_>) -> Resu
         || params.vnc_no_scaling
         || params.vnc_no_clipboard
         || !params.vnc_custom_arg.is_empty()
+        || params.vnc_repeater.is_some()
+        || params.vnc_repeater_id.is_some()
     {
         if let rustconn_core::models::ProtocolConfig::Vnc(ref mut cfg) = connection.protocol_config
         {
@@ -519,6 +526,10 @@ pub(super) fn cmd_add(config_path: Option<&Path>, params: AddParams<'_>) -> Resu
         connection.skip_port_check = true;
     }
 
+    if params.tailscale_dns {
+        connection.resolve_via_tailscale = true;
+    }
+
     let config_manager = create_config_manager(config_path)?;
 
     // Resolve --group: find or create the group, then assign group_id
@@ -988,6 +999,11 @@ pub(super) fn apply_ssh_wave2_fields(
         cfg.startup_command = Some(cmd.to_string());
     }
     if let Some(proxy) = proxy_command {
+        if proxy.trim().is_empty() {
+            return Err(CliError::Config(
+                "--proxy-command cannot be empty".to_string(),
+            ));
+        }
         cfg.proxy_command = Some(proxy.to_string());
     }
     for (key, value) in ssh_option {
@@ -1040,27 +1056,66 @@ pub(super) fn parse_port_forward(
     })?;
     Ok(PortForward {
         direction,
+        bind_address: None,
         local_port,
         remote_host,
         remote_port,
     })
 }
 
-/// Parse a dynamic (SOCKS) port forward spec: just a port number.
+/// Parse a dynamic (SOCKS) port forward spec: `[BIND_ADDRESS:]PORT`.
+///
+/// IPv6 bind addresses are written in brackets, as for `ssh -D`.
 pub(super) fn parse_dynamic_forward(spec: &str) -> Result<PortForward, CliError> {
-    let port: u16 = spec.parse().map_err(|_| {
+    let (bind, port) = match spec.rsplit_once(':') {
+        Some((bind, port)) => (
+            Some(bind.trim_start_matches('[').trim_end_matches(']')),
+            port,
+        ),
+        None => (None, spec),
+    };
+    let port: u16 = port.parse().map_err(|_| {
         CliError::Config(format!(
-            "Invalid --dynamic-forward port '{spec}'. Expected a port number (e.g. 1080)"
+            "Invalid --dynamic-forward '{spec}'. Expected [BIND:]PORT (e.g. 1080 or 127.0.0.1:1080)"
         ))
     })?;
     Ok(PortForward {
         direction: PortForwardDirection::Dynamic,
+        bind_address: bind.filter(|b| !b.is_empty()).map(str::to_string),
         local_port: port,
         remote_host: String::new(),
         remote_port: 0,
     })
 }
 
+/// Parses `--vnc-repeater HOST[:PORT]` and `--vnc-repeater-id ID`
+///
+/// # Errors
+///
+/// Returns [`CliError::Config`] if the repeater is invalid (see
+/// [`VncRepeater::validate`]).
+pub(super) fn parse_vnc_repeater(address: &str, id: &str) -> Result<VncRepeater, CliError> {
+    let (host, port) = rustconn_core::import::parse_host_port(address.trim());
+    let mut repeater = VncRepeater::new(host, id.trim().trim_start_matches("ID:"));
+    if let Some(port) = port {
+        repeater.port = port;
+    }
+    repeater
+        .validate()
+        .map_err(|e| CliError::Config(format!("Invalid --vnc-repeater: {e}")))?;
+    Ok(repeater)
+}
+
+/// Parses an `--rdp-security` value; clap restricts it to the known names
+pub(super) fn parse_rdp_security(value: &str) -> RdpSecurityLayer {
+    match value {
+        "rdp" => RdpSecurityLayer::Rdp,
+        "tls" => RdpSecurityLayer::Tls,
+        "nla" => RdpSecurityLayer::Nla,
+        _ => RdpSecurityLayer::Negotiate,
+    }
+}
+
 /// Apply RDP-specific fields (gateway, RemoteApp, resolution, etc.) to an `RdpConfig`.
 pub(super) fn apply_rdp_fields(
     cfg: &mut rustconn_core::models::RdpConfig,
@@ -1075,6 +1130,8 @@ pub(super) fn apply_rdp_fields(
             username: params
                 .gateway_username
                 .map(std::string::ToString::to_string),
+            domain: None,
+            password_source: PasswordSource::None,
         });
     } else if params.gateway_port.is_some() || params.gateway_username.is_some() {
         tracing::warn!("--gateway-port/--gateway-username require --gateway to be set");
@@ -1111,6 +1168,11 @@ pub(super) fn apply_rdp_fields(
         cfg.disable_nla = true;
     }
 
+    // Security protocol
+    if let Some(security) = params.rdp_security {
+        cfg.security_layer = parse_rdp_security(security);
+    }
+
     // Keyboard layout
     if let Some(klid) = params.keyboard_layout {
         cfg.keyboard_layout = Some(klid);
@@ -1172,9 +1234,22 @@ pub(super) fn parse_shared_folder(spec: &str) -> Result<SharedFolder, CliError>
     Ok(SharedFolder {
         share_name: name.to_string(),
         local_path: std::path::PathBuf::from(path),
+        read_only: false,
     })
 }
 
+/// Parse a SPICE shared folder spec: "NAME:PATH", with an optional ":ro"
+/// suffix to share the folder read-only.
+pub(super) fn parse_spice_shared_folder(spec: &str) -> Result<SharedFolder, CliError> {
+    match spec.strip_suffix(":ro") {
+        Some(folder) => Ok(SharedFolder {
+            read_only: true,
+            ..parse_shared_folder(folder)?
+        }),
+        None => parse_shared_folder(spec),
+    }
+}
+
 /// Apply VNC-specific fields to a `VncConfig`.
 pub(super) fn apply_vnc_fields(
     cfg: &mut rustconn_core::models::VncConfig,
@@ -1214,6 +1289,10 @@ pub(super) fn apply_vnc_fields(
     for arg in params.vnc_custom_arg {
         cfg.custom_args.push(arg.clone());
     }
+    // clap requires both flags together
+    if let (Some(address), Some(id)) = (params.vnc_repeater, params.vnc_repeater_id) {
+        cfg.repeater = Some(parse_vnc_repeater(address, id)?);
+    }
     Ok(())
 }
 
@@ -1244,7 +1323,7 @@ pub(super) fn apply_spice_fields(
         cfg.proxy = Some(proxy.to_string());
     }
     for spec in params.spice_shared_folder {
-        cfg.shared_folders.push(parse_shared_folder(spec)?);
+        cfg.shared_folders.push(parse_spice_shared_folder(spec)?);
     }
     Ok(())
 }
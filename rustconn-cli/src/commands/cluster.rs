@@ -28,12 +28,30 @@ pub(super) fn cmd_cluster(
             name,
             connections,
             broadcast,
-        } => cmd_cluster_create(config_path, &name, connections.as_deref(), broadcast),
+            connect_delay_ms,
+            max_in_flight,
+        } => cmd_cluster_create(
+            config_path,
+            &name,
+            connections.as_deref(),
+            broadcast,
+            connect_delay_ms,
+            max_in_flight,
+        ),
         ClusterCommands::Edit {
             name,
             new_name,
             broadcast,
-        } => cmd_cluster_edit(config_path, &name, new_name.as_deref(), broadcast),
+            connect_delay_ms,
+            max_in_flight,
+        } => cmd_cluster_edit(
+            config_path,
+            &name,
+            new_name.as_deref(),
+            broadcast,
+            connect_delay_ms,
+            max_in_flight,
+        ),
         ClusterCommands::Delete { name } => cmd_cluster_delete(config_path, &name),
         ClusterCommands::AddConnection {
             cluster,
@@ -43,6 +61,11 @@ pub(super) fn cmd_cluster(
             cluster,
             connection,
         } => cmd_cluster_remove_connection(config_path, &cluster, &connection),
+        ClusterCommands::SetTemplate {
+            cluster,
+            connection,
+            template,
+        } => cmd_cluster_set_template(config_path, &cluster, &connection, template.as_deref()),
     }
 }
 
@@ -118,6 +141,17 @@ fn cmd_cluster_show(config_path: Option<&Path>, name: &str) -> Result<(), CliErr
     println!("Cluster Details:");
     println!("  ID:        {}", cluster.id);
     println!("  Name:      {}", cluster.name);
+    if cluster.is_staggered() {
+        let cap = if cluster.max_in_flight == 0 {
+            "unlimited".to_string()
+        } else {
+            cluster.max_in_flight.to_string()
+        };
+        println!(
+            "  Stagger:   {} ms delay, {cap} in flight",
+            cluster.connect_delay_ms
+        );
+    }
 
     println!("\nConnections ({}):", cluster.connection_count());
     for conn_id in &cluster.connection_ids {
@@ -129,6 +163,9 @@ fn cmd_cluster_show(config_path: Option<&Path>, name: &str) -> Result<(), CliErr
         } else {
             println!("  - {conn_id} (not found)");
         }
+        if let Some(template) = cluster.command_template(*conn_id) {
+            println!("      template: {template}");
+        }
     }
 
     Ok(())
@@ -139,6 +176,8 @@ fn cmd_cluster_create(
     name: &str,
     connections: Option<&str>,
     broadcast: bool,
+    connect_delay_ms: u64,
+    max_in_flight: usize,
 ) -> Result<(), CliError> {
     let config_manager = create_config_manager(config_path)?;
 
@@ -157,7 +196,8 @@ fn cmd_cluster_create(
         );
     }
 
-    let mut cluster = Cluster::new(name.to_string());
+    let mut cluster =
+        Cluster::new(name.to_string()).with_staggered_start(connect_delay_ms, max_in_flight);
     // broadcast_enabled is intentionally not set — see warning above.
 
     if let Some(conn_list) = connections {
@@ -184,6 +224,8 @@ fn cmd_cluster_edit(
     name: &str,
     new_name: Option<&str>,
     broadcast: Option<bool>,
+    connect_delay_ms: Option<u64>,
+    max_in_flight: Option<usize>,
 ) -> Result<(), CliError> {
     let config_manager = create_config_manager(config_path)?;
 
@@ -201,6 +243,12 @@ fn cmd_cluster_edit(
     if let Some(n) = new_name {
         cluster.name = n.to_string();
     }
+    if let Some(delay) = connect_delay_ms {
+        cluster.connect_delay_ms = delay;
+    }
+    if let Some(cap) = max_in_flight {
+        cluster.max_in_flight = cap;
+    }
     if broadcast.is_some() {
         eprintln!(
             "Warning: --broadcast is no-op since 0.14.8. Cluster broadcast was \
@@ -329,6 +377,54 @@ fn cmd_cluster_remove_connection(
 }
 
 /// Find a cluster by name or ID
+fn cmd_cluster_set_template(
+    config_path: Option<&Path>,
+    cluster_name: &str,
+    connection_name: &str,
+    template: Option<&str>,
+) -> Result<(), CliError> {
+    let config_manager = create_config_manager(config_path)?;
+
+    let mut clusters = config_manager
+        .load_clusters()
+        .map_err(|e| CliError::Cluster(format!("Failed to load clusters: {e}")))?;
+
+    let connections = config_manager
+        .load_connections()
+        .map_err(|e| CliError::Config(format!("Failed to load connections: {e}")))?;
+
+    let connection = find_connection(&connections, connection_name)?;
+    let conn_id = connection.id;
+    let conn_name = connection.name.clone();
+
+    let cluster = clusters
+        .iter_mut()
+        .find(|c| c.name.eq_ignore_ascii_case(cluster_name) || c.id.to_string() == cluster_name)
+        .ok_or_else(|| CliError::Cluster(format!("Cluster not found: {cluster_name}")))?;
+
+    if !cluster.contains_connection(conn_id) {
+        return Err(CliError::Cluster(format!(
+            "Connection '{conn_name}' is not in cluster '{}'",
+            cluster.name
+        )));
+    }
+
+    let clust_name = cluster.name.clone();
+    cluster.set_command_template(conn_id, template.unwrap_or_default());
+
+    config_manager
+        .save_clusters(&clusters)
+        .map_err(|e| CliError::Cluster(format!("Failed to save clusters: {e}")))?;
+
+    if template.is_some_and(|t| !t.is_empty()) {
+        println!("Set broadcast template for '{conn_name}' in cluster '{clust_name}'");
+    } else {
+        println!("Cleared broadcast template for '{conn_name}' in cluster '{clust_name}'");
+    }
+
+    Ok(())
+}
+
 fn find_cluster<'a>(clusters: &'a [Cluster], name_or_id: &str) -> Result<&'a Cluster, CliError> {
     if let Ok(uuid) = uuid::Uuid::parse_str(name_or_id)
         && let Some(cluster) = clusters.iter().find(|c| c.id == uuid)
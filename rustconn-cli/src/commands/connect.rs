@@ -1,8 +1,10 @@
 //! Connect command — initiate a connection to a remote server.
 
+use std::io::IsTerminal;
 use std::path::Path;
 
-use rustconn_core::models::{Connection, ProtocolType};
+use rustconn_core::config::ConfigManager;
+use rustconn_core::models::{ConnectGuardDecision, Connection, ProtocolConfig, ProtocolType};
 use rustconn_core::protocol::ProtocolRegistry;
 
 use crate::error::CliError;
@@ -16,7 +18,8 @@ use crate::util::{create_config_manager, find_connection};
 /// - [`CliError::Config`] when the configuration cannot be read or no connections are configured
 /// - [`CliError::ConnectionNotFound`] when no connection matches `name`
 /// - [`CliError::Connection`] when the protocol-specific client (ssh, xfreerdp,
-///   vncviewer, …) cannot be launched or exits with a non-zero status
+///   vncviewer, …) cannot be launched or exits with a non-zero status, or the
+///   connection's connect guard blocks it or is not confirmed
 pub fn cmd_connect(config_path: Option<&Path>, name: &str, dry_run: bool) -> Result<(), CliError> {
     let config_manager = create_config_manager(config_path)?;
 
@@ -31,6 +34,7 @@ pub fn cmd_connect(config_path: Option<&Path>, name: &str, dry_run: bool) -> Res
     }
 
     let connection = find_connection(&connections, name)?;
+    let connection = &with_port_forward_profiles(&config_manager, connection)?;
     let command = build_connection_command(connection);
 
     if dry_run {
@@ -38,6 +42,8 @@ pub fn cmd_connect(config_path: Option<&Path>, name: &str, dry_run: bool) -> Res
         return Ok(());
     }
 
+    enforce_connect_guard(connection)?;
+
     println!(
         "Connecting to '{}' ({} {}:{})...",
         connection.name, connection.protocol, connection.host, connection.port
@@ -46,6 +52,72 @@ pub fn cmd_connect(config_path: Option<&Path>, name: &str, dry_run: bool) -> Res
     execute_connection_command(&command)
 }
 
+/// Returns a copy of `connection` with its port-forward profiles expanded
+/// into its own port forwards
+fn with_port_forward_profiles(
+    config_manager: &ConfigManager,
+    connection: &Connection,
+) -> Result<Connection, CliError> {
+    let mut connection = connection.clone();
+    if let ProtocolConfig::Ssh(ssh) | ProtocolConfig::Sftp(ssh) = &mut connection.protocol_config
+        && !ssh.port_forward_profiles.is_empty()
+    {
+        let profiles = config_manager
+            .load_port_forward_profiles()
+            .map_err(|e| CliError::Config(format!("Failed to load port forward profiles: {e}")))?;
+        ssh.expand_port_forward_profiles(&profiles);
+    }
+    Ok(connection)
+}
+
+/// Applies the connection's connect guard at the current local time
+///
+/// Confirmation is read from an interactive terminal; without one the
+/// connection is refused, since nobody can confirm it.
+fn enforce_connect_guard(connection: &Connection) -> Result<(), CliError> {
+    match connection.check_connect_guard(chrono::Local::now().naive_local()) {
+        ConnectGuardDecision::Proceed => Ok(()),
+        ConnectGuardDecision::Warn { reason } => {
+            eprintln!("Warning: {reason}");
+            Ok(())
+        }
+        ConnectGuardDecision::Block { reason } => Err(CliError::Connection(reason)),
+        ConnectGuardDecision::Confirm {
+            message,
+            warning,
+            typed_hostname,
+        } => {
+            if let Some(warning) = warning {
+                eprintln!("Warning: {warning}");
+            }
+            if !std::io::stdin().is_terminal() {
+                return Err(CliError::Connection(format!(
+                    "'{}' requires confirmation; run from an interactive terminal",
+                    connection.name
+                )));
+            }
+            eprintln!("{message}");
+            match &typed_hostname {
+                Some(host) => eprint!("Type '{host}' to connect: "),
+                None => eprint!("Connect? [y/N] "),
+            }
+            let mut input = String::new();
+            std::io::stdin()
+                .read_line(&mut input)
+                .map_err(|e| CliError::Connection(format!("Failed to read confirmation: {e}")))?;
+            let answer = input.trim();
+            let confirmed = typed_hostname
+                .as_deref()
+                .map_or_else(|| answer.eq_ignore_ascii_case("y"), |host| answer == host);
+            if confirmed {
+                Ok(())
+            } else {
+                Err(CliError::Connection("Connection cancelled".to_string()))
+            }
+        }
+    }
+}
+
 /// Command to execute for a connection
 struct ConnectionCommand {
     /// The program to execute
@@ -0,0 +1,187 @@
+//! Diagnostics commands.
+
+use std::path::Path;
+
+use rustconn_core::config::ConfigManager;
+use serde::Serialize;
+
+use crate::cli::DiagnosticsCommands;
+use crate::error::CliError;
+use crate::util::create_config_manager;
+
+/// Diagnostics command dispatcher
+///
+/// # Errors
+///
+/// Returns [`CliError::Config`] when the configuration cannot be loaded.
+pub(super) fn cmd_diagnostics(
+    config_path: Option<&Path>,
+    subcmd: DiagnosticsCommands,
+) -> Result<(), CliError> {
+    match subcmd {
+        DiagnosticsCommands::Memory { json } => cmd_diagnostics_memory(config_path, json),
+    }
+}
+
+/// Item count and serialized size of one kind of configuration data
+#[derive(Debug, Default, Serialize)]
+struct MemoryCategory {
+    count: usize,
+    bytes: usize,
+}
+
+impl MemoryCategory {
+    fn of<T: Serialize>(items: &[T]) -> Self {
+        Self {
+            count: items.len(),
+            bytes: items
+                .iter()
+                .map(|item| serde_json::to_vec(item).map_or(0, |v| v.len()))
+                .sum(),
+        }
+    }
+}
+
+/// Loaded configuration data by kind
+///
+/// Sizes are those of the JSON encoding, an approximation of the heap
+/// data each kind holds.
+#[derive(Debug, Serialize)]
+struct MemoryBreakdown {
+    connections: MemoryCategory,
+    groups: MemoryCategory,
+    templates: MemoryCategory,
+    snippets: MemoryCategory,
+    sessions: MemoryCategory,
+    total: MemoryCategory,
+}
+
+/// String interner statistics
+#[derive(Debug, Serialize)]
+struct InterningReport {
+    unique_strings: usize,
+    intern_requests: usize,
+    hits: usize,
+    hit_rate: f64,
+    bytes_saved: usize,
+}
+
+/// Output of `diagnostics memory`
+#[derive(Debug, Serialize)]
+struct MemoryReport {
+    breakdown: MemoryBreakdown,
+    interning: InterningReport,
+}
+
+/// Loads the configuration and measures each kind of data
+///
+/// Sessions are the entries of the connection history.
+fn memory_report(config_manager: &ConfigManager) -> Result<MemoryReport, CliError> {
+    let connections = config_manager
+        .load_connections()
+        .map_err(|e| CliError::Config(format!("Failed to load connections: {e}")))?;
+    let groups = config_manager
+        .load_groups()
+        .map_err(|e| CliError::Config(format!("Failed to load groups: {e}")))?;
+    let templates = config_manager
+        .load_templates()
+        .map_err(|e| CliError::Config(format!("Failed to load templates: {e}")))?;
+    let snippets = config_manager.load_snippets().unwrap_or_default();
+    let history = config_manager.load_history().unwrap_or_default();
+
+    // Intern the strings the connection manager interns on load
+    for conn in &connections {
+        let _ = rustconn_core::intern_connection_strings(
+            conn.protocol.as_str(),
+            &conn.host,
+            conn.username.as_deref(),
+        );
+    }
+
+    let categories = [
+        MemoryCategory::of(&connections),
+        MemoryCategory::of(&groups),
+        MemoryCategory::of(&templates),
+        MemoryCategory::of(&snippets),
+        MemoryCategory::of(&history),
+    ];
+    let total = MemoryCategory {
+        count: categories.iter().map(|c| c.count).sum(),
+        bytes: categories.iter().map(|c| c.bytes).sum(),
+    };
+    let [connections, groups, templates, snippets, sessions] = categories;
+
+    let (intern_requests, hits, hit_rate, bytes_saved) = rustconn_core::get_interning_stats();
+    Ok(MemoryReport {
+        breakdown: MemoryBreakdown {
+            connections,
+            groups,
+            templates,
+            snippets,
+            sessions,
+            total,
+        },
+        interning: InterningReport {
+            unique_strings: rustconn_core::interner().len(),
+            intern_requests,
+            hits,
+            hit_rate,
+            bytes_saved,
+        },
+    })
+}
+
+/// Print the memory breakdown of the loaded configuration
+fn cmd_diagnostics_memory(config_path: Option<&Path>, json: bool) -> Result<(), CliError> {
+    let config_manager = create_config_manager(config_path)?;
+    let report = memory_report(&config_manager)?;
+
+    if json {
+        let json = serde_json::to_string_pretty(&report)
+            .map_err(|e| CliError::Config(format!("JSON serialization failed: {e}")))?;
+        println!("{json}");
+        return Ok(());
+    }
+
+    let breakdown = &report.breakdown;
+    println!("Memory Breakdown");
+    println!("================\n");
+    println!("{:<12} {:>8} {:>12}", "KIND", "COUNT", "SIZE");
+    for (name, category) in [
+        ("Connections", &breakdown.connections),
+        ("Groups", &breakdown.groups),
+        ("Templates", &breakdown.templates),
+        ("Snippets", &breakdown.snippets),
+        ("Sessions", &breakdown.sessions),
+        ("Total", &breakdown.total),
+    ] {
+        println!(
+            "{name:<12} {:>8} {:>12}",
+            category.count,
+            format_size(category.bytes)
+        );
+    }
+
+    let interning = &report.interning;
+    println!("\nString Interning:");
+    println!("  Unique strings:  {}", interning.unique_strings);
+    println!("  Intern requests: {}", interning.intern_requests);
+    println!(
+        "  Hits:            {} ({:.1}%)",
+        interning.hits,
+        interning.hit_rate * 100.0
+    );
+    println!("  Bytes saved:     {}", format_size(interning.bytes_saved));
+
+    Ok(())
+}
+
+fn format_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{bytes} B")
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::path::Path;
 
+use rustconn_core::import::{ImportPreview, MergeStrategy};
 use rustconn_core::models::{Connection, ConnectionGroup};
 
 use crate::cli::{ExportFormatArg, ImportFormatArg};
@@ -56,6 +57,8 @@ pub(super) fn cmd_export(
         ExportFormatArg::MobaXterm => rustconn_core::export::ExportFormat::MobaXterm,
         ExportFormatArg::Csv => rustconn_core::export::ExportFormat::Csv,
         ExportFormatArg::SecureCrt => rustconn_core::export::ExportFormat::SecureCrt,
+        ExportFormatArg::WindowsTerminal => rustconn_core::export::ExportFormat::WindowsTerminal,
+        ExportFormatArg::Guacamole => rustconn_core::export::ExportFormat::Guacamole,
     };
 
     let mut options =
@@ -105,8 +108,9 @@ fn export_connections(
     options: &rustconn_core::export::ExportOptions,
 ) -> Result<rustconn_core::export::ExportResult, CliError> {
     use rustconn_core::export::{
-        AnsibleExporter, AsbruExporter, CsvExporter, ExportFormat, ExportTarget, MobaXtermExporter,
-        NativeExport, RemminaExporter, RoyalTsExporter, SecureCrtExporter, SshConfigExporter,
+        AnsibleExporter, AsbruExporter, CsvExporter, ExportFormat, ExportTarget, GuacamoleExporter,
+        MobaXtermExporter, NativeExport, RemminaExporter, RoyalTsExporter, SecureCrtExporter,
+        SshConfigExporter, WindowsTerminalExporter,
     };
 
     let result = match options.format {
@@ -179,6 +183,18 @@ fn export_connections(
                 .export(connections, groups, options)
                 .map_err(|e| CliError::Export(e.to_string()))?
         }
+        ExportFormat::WindowsTerminal => {
+            let exporter = WindowsTerminalExporter::new();
+            exporter
+                .export(connections, groups, options)
+                .map_err(|e| CliError::Export(e.to_string()))?
+        }
+        ExportFormat::Guacamole => {
+            let exporter = GuacamoleExporter::new();
+            exporter
+                .export(connections, groups, options)
+                .map_err(|e| CliError::Export(e.to_string()))?
+        }
     };
 
     Ok(result)
@@ -263,50 +279,27 @@ pub(super) fn cmd_import(
         }
     }
 
+    let preview = ImportPreview::from_result(
+        &import_result,
+        &existing_connections,
+        &existing_groups,
+        MergeStrategy::SkipExisting,
+        "cli",
+        file.display().to_string(),
+    );
+
     if dry_run {
         println!("\n[dry-run] No changes saved.");
-        if !import_result.connections.is_empty() {
-            println!("\nConnections that would be imported:");
-            for conn in &import_result.connections {
-                let is_duplicate = existing_connections
-                    .iter()
-                    .any(|c| c.name == conn.name && c.host == conn.host);
-                let status = if is_duplicate {
-                    " (duplicate, skip)"
-                } else {
-                    ""
-                };
-                println!(
-                    "  - {} ({}://{}:{}){}",
-                    conn.name,
-                    conn.protocol.as_str(),
-                    conn.host,
-                    conn.port,
-                    status
-                );
-            }
-        }
+        print_preview(&preview, "  ");
         return Ok(());
     }
 
     let initial_count = existing_connections.len();
     let initial_group_count = existing_groups.len();
 
-    for group in import_result.groups {
-        if !existing_groups.iter().any(|g| g.name == group.name) {
-            existing_groups.push(group);
-        }
-    }
-
-    for conn in import_result.connections {
-        let is_duplicate = existing_connections
-            .iter()
-            .any(|c| c.name == conn.name && c.host == conn.host);
-
-        if !is_duplicate {
-            existing_connections.push(conn);
-        }
-    }
+    let (to_create, _, groups_to_create) = preview.apply();
+    existing_groups.extend(groups_to_create);
+    existing_connections.extend(to_create);
 
     let new_connections = existing_connections.len() - initial_count;
     let new_groups = existing_groups.len() - initial_group_count;
@@ -350,7 +343,41 @@ pub(super) fn cmd_import(
     Ok(())
 }
 
+/// Prints what an import would do: counts, each connection with its
+/// duplicate status, and the entries the importer could not parse
+fn print_preview(preview: &ImportPreview, indent: &str) {
+    println!("{indent}{}", preview.summary());
+    for entry in &preview.connections {
+        let conn = &entry.connection;
+        let status = if entry.is_duplicate {
+            " (duplicate, skip)"
+        } else {
+            ""
+        };
+        println!(
+            "{indent}- {} ({}://{}:{}){status}",
+            conn.name,
+            conn.protocol.as_str(),
+            conn.host,
+            conn.port
+        );
+    }
+    for skipped in &preview.skipped {
+        match skipped.location {
+            Some(ref location) => println!(
+                "{indent}! {} ({location}): {}",
+                skipped.identifier, skipped.reason
+            ),
+            None => println!("{indent}! {}: {}", skipped.identifier, skipped.reason),
+        }
+    }
+}
+
 /// Imports connections using the appropriate importer based on format
+#[expect(
+    clippy::too_many_lines,
+    reason = "one match arm per supported import format; each arm is a short importer call"
+)]
 fn import_connections(
     format: ImportFormatArg,
     file: &Path,
@@ -449,11 +476,51 @@ fn import_connections(
                 .import_from_path(file)
                 .map_err(|e| CliError::Import(e.to_string()))?
         }
+        ImportFormatArg::Putty => {
+            let importer = rustconn_core::import::PuttyImporter::new();
+            importer
+                .import_from_path(file)
+                .map_err(|e| CliError::Import(e.to_string()))?
+        }
+        ImportFormatArg::MremoteNg => {
+            let importer = rustconn_core::import::MremoteNgImporter::new();
+            importer
+                .import_from_path(file)
+                .map_err(|e| CliError::Import(e.to_string()))?
+        }
+        ImportFormatArg::Termius => {
+            let importer = rustconn_core::import::TermiusImporter::new();
+            importer
+                .import_from_path(file)
+                .map_err(|e| CliError::Import(e.to_string()))?
+        }
+        ImportFormatArg::Detect => detect_importer(file)?
+            .import_from_path(file)
+            .map_err(|e| CliError::Import(e.to_string()))?,
     };
 
     Ok(result)
 }
 
+/// Picks the importer for `file` by sniffing its contents
+fn detect_importer(file: &Path) -> Result<Box<dyn rustconn_core::import::ImportSource>, CliError> {
+    let ranked = rustconn_core::import::rank_formats_for_path(file)
+        .map_err(|e| CliError::Import(e.to_string()))?;
+    rustconn_core::import::unambiguous(&ranked)
+        .and_then(|m| rustconn_core::import::importer_for_source(m.source_id))
+        .ok_or_else(|| {
+            let candidates: Vec<&str> = ranked.iter().map(|m| m.source_id).collect();
+            CliError::Import(if candidates.is_empty() {
+                "Could not detect the import format; use --format".to_string()
+            } else {
+                format!(
+                    "Ambiguous import format (candidates: {}); use --format",
+                    candidates.join(", ")
+                )
+            })
+        })
+}
+
 /// Auto-detect available import sources and import from all found
 #[expect(
     clippy::too_many_lines,
@@ -513,41 +580,22 @@ fn cmd_import_auto(config_path: Option<&Path>, dry_run: bool) -> Result<(), CliE
                     result.groups.len()
                 );
 
+                // Later sources are matched against what earlier ones would
+                // add, so the dry run reports exactly what a real run saves
+                let preview = ImportPreview::from_result(
+                    &result,
+                    &existing_connections,
+                    &existing_groups,
+                    MergeStrategy::SkipExisting,
+                    source.source_id(),
+                    source.display_name(),
+                );
                 if dry_run {
-                    for conn in &result.connections {
-                        let is_duplicate = existing_connections
-                            .iter()
-                            .any(|c| c.name == conn.name && c.host == conn.host);
-                        let status = if is_duplicate {
-                            " (duplicate, skip)"
-                        } else {
-                            ""
-                        };
-                        println!(
-                            "    - {} ({}://{}:{}){}",
-                            conn.name,
-                            conn.protocol.as_str(),
-                            conn.host,
-                            conn.port,
-                            status
-                        );
-                    }
-                } else {
-                    for group in result.groups {
-                        if !existing_groups.iter().any(|g| g.name == group.name) {
-                            existing_groups.push(group);
-                        }
-                    }
-
-                    for conn in result.connections {
-                        let is_duplicate = existing_connections
-                            .iter()
-                            .any(|c| c.name == conn.name && c.host == conn.host);
-                        if !is_duplicate {
-                            existing_connections.push(conn);
-                        }
-                    }
+                    print_preview(&preview, "    ");
                 }
+                let (to_create, _, groups_to_create) = preview.apply();
+                existing_groups.extend(groups_to_create);
+                existing_connections.extend(to_create);
 
                 if !result.errors.is_empty() {
                     for error in &result.errors {
@@ -0,0 +1,288 @@
+//! Port-forward profile commands.
+
+use std::path::Path;
+
+use rustconn_core::config::ConfigManager;
+use rustconn_core::models::{
+    Connection, PortForward, PortForwardDirection, PortForwardProfile, ProtocolConfig, SshConfig,
+};
+
+use super::add::{parse_dynamic_forward, parse_port_forward};
+use crate::cli::{ForwardProfileCommands, OutputFormat};
+use crate::error::CliError;
+use crate::format::escape_csv_field;
+use crate::util::{create_config_manager, find_connection};
+
+/// Port-forward profile command handler
+///
+/// # Errors
+///
+/// Returns [`CliError::Config`] when configuration cannot be read or
+/// written, a forward spec is malformed, a profile name is taken or not
+/// found, or the connection is not an SSH connection; and
+/// [`CliError::ConnectionNotFound`] when a connection does not exist.
+pub(super) fn cmd_forward_profile(
+    config_path: Option<&Path>,
+    subcmd: ForwardProfileCommands,
+) -> Result<(), CliError> {
+    match subcmd {
+        ForwardProfileCommands::List { format } => cmd_list(config_path, format.effective()),
+        ForwardProfileCommands::Create {
+            name,
+            description,
+            local_forward,
+            remote_forward,
+            dynamic_forward,
+            replace,
+        } => {
+            let mut forwards = Vec::new();
+            for spec in &local_forward {
+                forwards.push(parse_port_forward(
+                    spec,
+                    PortForwardDirection::Local,
+                    "--local-forward",
+                )?);
+            }
+            for spec in &remote_forward {
+                forwards.push(parse_port_forward(
+                    spec,
+                    PortForwardDirection::Remote,
+                    "--remote-forward",
+                )?);
+            }
+            for spec in &dynamic_forward {
+                forwards.push(parse_dynamic_forward(spec)?);
+            }
+            cmd_create(config_path, &name, description, forwards, replace)
+        }
+        ForwardProfileCommands::Delete { name } => cmd_delete(config_path, &name),
+        ForwardProfileCommands::Attach {
+            profile,
+            connection,
+        } => cmd_attach(config_path, &profile, &connection, true),
+        ForwardProfileCommands::Detach {
+            profile,
+            connection,
+        } => cmd_attach(config_path, &profile, &connection, false),
+    }
+}
+
+fn load_profiles(config_manager: &ConfigManager) -> Result<Vec<PortForwardProfile>, CliError> {
+    config_manager
+        .load_port_forward_profiles()
+        .map_err(|e| CliError::Config(format!("Failed to load port forward profiles: {e}")))
+}
+
+fn save_profiles(
+    config_manager: &ConfigManager,
+    profiles: &[PortForwardProfile],
+) -> Result<(), CliError> {
+    config_manager
+        .save_port_forward_profiles(profiles)
+        .map_err(|e| CliError::Config(format!("Failed to save port forward profiles: {e}")))
+}
+
+fn load_connections(config_manager: &ConfigManager) -> Result<Vec<Connection>, CliError> {
+    config_manager
+        .load_connections()
+        .map_err(|e| CliError::Config(format!("Failed to load connections: {e}")))
+}
+
+fn save_connections(
+    config_manager: &ConfigManager,
+    connections: &[Connection],
+) -> Result<(), CliError> {
+    config_manager
+        .save_connections(connections)
+        .map_err(|e| CliError::Config(format!("Failed to save connections: {e}")))
+}
+
+/// Returns the rules of a profile as `ssh` arguments, e.g. `-L 8080:web:80`
+fn forward_specs(forwards: &[PortForward]) -> Vec<String> {
+    forwards.iter().map(|f| f.to_ssh_arg().join(" ")).collect()
+}
+
+fn cmd_list(config_path: Option<&Path>, format: OutputFormat) -> Result<(), CliError> {
+    let config_manager = create_config_manager(config_path)?;
+    let profiles = load_profiles(&config_manager)?;
+
+    match format {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&profiles)
+                .map_err(|e| CliError::Config(format!("JSON serialization failed: {e}")))?;
+            println!("{json}");
+        }
+        OutputFormat::Csv => {
+            println!("name,forwards");
+            for profile in &profiles {
+                println!(
+                    "{},{}",
+                    escape_csv_field(&profile.name),
+                    escape_csv_field(&forward_specs(&profile.forwards).join("; "))
+                );
+            }
+        }
+        OutputFormat::Table => {
+            if profiles.is_empty() {
+                println!("No port forward profiles.");
+                return Ok(());
+            }
+            let name_width = profiles
+                .iter()
+                .map(|p| p.name.len())
+                .max()
+                .unwrap_or(4)
+                .max(4);
+            println!("{:<name_width$}  FORWARDS", "NAME");
+            println!("{:-<name_width$}  {:-<8}", "", "");
+            for profile in &profiles {
+                println!(
+                    "{:<name_width$}  {}",
+                    profile.name,
+                    forward_specs(&profile.forwards).join(", ")
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn cmd_create(
+    config_path: Option<&Path>,
+    name: &str,
+    description: Option<String>,
+    forwards: Vec<PortForward>,
+    replace: bool,
+) -> Result<(), CliError> {
+    let config_manager = create_config_manager(config_path)?;
+    let mut profiles = load_profiles(&config_manager)?;
+
+    let mut profile = PortForwardProfile::new(name.trim());
+    profile.description = description;
+    profile.forwards = forwards;
+    profile
+        .validate()
+        .map_err(|e| CliError::Config(e.to_string()))?;
+
+    if let Some(existing) = profiles.iter_mut().find(|p| p.is_named(name)) {
+        if !replace {
+            return Err(CliError::Config(format!(
+                "Port forward profile '{}' already exists; use --replace to update it",
+                existing.name
+            )));
+        }
+        // Keep the ID so connections referencing the profile follow it
+        profile.id = existing.id;
+        *existing = profile;
+        save_profiles(&config_manager, &profiles)?;
+        println!("Updated port forward profile '{}'", name.trim());
+        return Ok(());
+    }
+
+    let id = profile.id;
+    profiles.push(profile);
+    save_profiles(&config_manager, &profiles)?;
+    println!(
+        "Created port forward profile '{}' with ID {id}",
+        name.trim()
+    );
+    Ok(())
+}
+
+fn cmd_delete(config_path: Option<&Path>, name: &str) -> Result<(), CliError> {
+    let config_manager = create_config_manager(config_path)?;
+    let mut profiles = load_profiles(&config_manager)?;
+    let id = find_profile(&profiles, name)?.id;
+
+    let mut connections = load_connections(&config_manager)?;
+    let mut detached = 0;
+    for conn in &mut connections {
+        if let Some(ssh) = ssh_config_mut(conn)
+            && ssh.port_forward_profiles.contains(&id)
+        {
+            ssh.port_forward_profiles.retain(|p| *p != id);
+            detached += 1;
+        }
+    }
+    if detached > 0 {
+        save_connections(&config_manager, &connections)?;
+    }
+
+    profiles.retain(|p| p.id != id);
+    save_profiles(&config_manager, &profiles)?;
+    println!("Deleted port forward profile '{name}' (detached from {detached} connection(s))");
+    Ok(())
+}
+
+fn cmd_attach(
+    config_path: Option<&Path>,
+    profile_name: &str,
+    connection_name: &str,
+    attach: bool,
+) -> Result<(), CliError> {
+    let config_manager = create_config_manager(config_path)?;
+    let profiles = load_profiles(&config_manager)?;
+    let profile = find_profile(&profiles, profile_name)?;
+
+    let mut connections = load_connections(&config_manager)?;
+    let conn_id = find_connection(&connections, connection_name)?.id;
+    let conn = connections
+        .iter_mut()
+        .find(|c| c.id == conn_id)
+        .ok_or_else(|| CliError::ConnectionNotFound(connection_name.to_string()))?;
+    let conn_name = conn.name.clone();
+    let ssh = ssh_config_mut(conn).ok_or_else(|| {
+        CliError::Config(format!("Connection '{conn_name}' is not an SSH connection"))
+    })?;
+
+    let attached = ssh.port_forward_profiles.contains(&profile.id);
+    if attach {
+        if attached {
+            return Err(CliError::Config(format!(
+                "Profile '{}' is already attached to '{conn_name}'",
+                profile.name
+            )));
+        }
+        ssh.port_forward_profiles.push(profile.id);
+    } else {
+        if !attached {
+            return Err(CliError::Config(format!(
+                "Profile '{}' is not attached to '{conn_name}'",
+                profile.name
+            )));
+        }
+        ssh.port_forward_profiles.retain(|p| *p != profile.id);
+    }
+    save_connections(&config_manager, &connections)?;
+
+    if attach {
+        println!("Attached profile '{}' to '{conn_name}'", profile.name);
+    } else {
+        println!("Detached profile '{}' from '{conn_name}'", profile.name);
+    }
+    Ok(())
+}
+
+/// Returns the SSH settings of SSH and SFTP connections
+fn ssh_config_mut(conn: &mut Connection) -> Option<&mut SshConfig> {
+    match &mut conn.protocol_config {
+        ProtocolConfig::Ssh(ssh) | ProtocolConfig::Sftp(ssh) => Some(ssh),
+        _ => None,
+    }
+}
+
+fn find_profile<'a>(
+    profiles: &'a [PortForwardProfile],
+    name_or_id: &str,
+) -> Result<&'a PortForwardProfile, CliError> {
+    if let Ok(uuid) = uuid::Uuid::parse_str(name_or_id)
+        && let Some(profile) = profiles.iter().find(|p| p.id == uuid)
+    {
+        return Ok(profile);
+    }
+    profiles
+        .iter()
+        .find(|p| p.is_named(name_or_id))
+        .ok_or_else(|| CliError::Config(format!("Port forward profile not found: {name_or_id}")))
+}
@@ -21,7 +21,7 @@ pub(super) fn cmd_list(
     format: OutputFormat,
     protocol: Option<&str>,
     group: Option<&str>,
-    tag: Option<&str>,
+    tags: &[String],
 ) -> Result<(), CliError> {
     let config_manager = create_config_manager(config_path)?;
 
@@ -63,15 +63,8 @@ pub(super) fn cmd_list(
                 return false;
             }
 
-            // Filter by tag
-            if let Some(tag_filter) = tag {
-                let tag_lower = tag_filter.to_lowercase();
-                if !c.tags.iter().any(|t| t.to_lowercase() == tag_lower) {
-                    return false;
-                }
-            }
-
-            true
+            // Filter by tags (all must match)
+            tags.iter().all(|tag| c.has_tag(tag))
         })
         .collect();
 
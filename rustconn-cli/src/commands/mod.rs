@@ -7,17 +7,21 @@ mod completions;
 #[cfg(feature = "client-launch")]
 mod connect;
 mod delete;
+mod diagnostics;
 mod duplicate;
 mod dynamic_folder;
 mod export_import;
+mod forward_profile;
 mod group;
 mod history;
 mod list;
 mod manpage;
 mod monitor;
 mod move_cmd;
+mod password;
 mod pin;
 mod recording;
+mod search;
 #[cfg(feature = "secret-management")]
 mod secret;
 mod sftp;
@@ -29,6 +33,7 @@ mod sync;
 mod tag;
 mod template;
 mod test;
+mod theme;
 mod update;
 mod variable;
 mod wol;
@@ -56,7 +61,31 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
             format.effective(),
             protocol.as_deref(),
             group.as_deref(),
-            tag.as_deref(),
+            &tag,
+        ),
+        Commands::Search {
+            query,
+            save,
+            run,
+            list_searches,
+            delete,
+            interactive,
+            limit,
+            mode,
+            format,
+        } => search::cmd_search(
+            config_path,
+            &search::SearchParams {
+                query: &query.join(" "),
+                save: save.as_deref(),
+                run: run.as_deref(),
+                list_searches,
+                delete: delete.as_deref(),
+                interactive,
+                limit,
+                mode,
+                format: format.effective(),
+            },
         ),
         #[cfg(feature = "client-launch")]
         Commands::Connect { name, dry_run } => connect::cmd_connect(config_path, &name, dry_run),
@@ -102,6 +131,7 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
             domain,
             window_mode,
             skip_port_check,
+            tailscale_dns,
             x11_forwarding,
             agent_forwarding,
             compression,
@@ -120,6 +150,7 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
             resolution,
             color_depth,
             disable_nla,
+            rdp_security,
             keyboard_layout,
             audio_redirect,
             printer,
@@ -133,6 +164,8 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
             vnc_no_scaling,
             vnc_no_clipboard,
             vnc_custom_arg,
+            vnc_repeater,
+            vnc_repeater_id,
             spice_tls,
             spice_ca_cert,
             spice_skip_cert_verify,
@@ -201,6 +234,7 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
                 domain: domain.as_deref(),
                 window_mode: window_mode.as_deref(),
                 skip_port_check,
+                tailscale_dns,
                 x11_forwarding,
                 agent_forwarding,
                 compression,
@@ -219,6 +253,7 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
                 resolution: resolution.as_deref(),
                 color_depth,
                 disable_nla,
+                rdp_security: rdp_security.as_deref(),
                 keyboard_layout,
                 audio_redirect,
                 printer,
@@ -232,6 +267,8 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
                 vnc_no_scaling,
                 vnc_no_clipboard,
                 vnc_custom_arg: &vnc_custom_arg,
+                vnc_repeater: vnc_repeater.as_deref(),
+                vnc_repeater_id: vnc_repeater_id.as_deref(),
                 spice_tls,
                 spice_ca_cert: spice_ca_cert.as_deref(),
                 spice_skip_cert_verify,
@@ -279,8 +316,10 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
         Commands::Test {
             name,
             timeout,
+            ping,
+            udp,
             format,
-        } => test::cmd_test(config_path, &name, timeout, format.effective()),
+        } => test::cmd_test(config_path, &name, timeout, ping, udp, format.effective()),
         Commands::Delete { name, force } => delete::cmd_delete(config_path, &name, force),
         Commands::Show { name, format } => show::cmd_show(config_path, &name, format.effective()),
         Commands::Update {
@@ -327,6 +366,7 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
             domain,
             window_mode,
             skip_port_check,
+            tailscale_dns,
             x11_forwarding,
             agent_forwarding,
             compression,
@@ -345,6 +385,7 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
             resolution,
             color_depth,
             disable_nla,
+            rdp_security,
             keyboard_layout,
             audio_redirect,
             printer,
@@ -358,6 +399,8 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
             vnc_no_scaling,
             vnc_no_clipboard,
             vnc_custom_arg,
+            vnc_repeater,
+            vnc_repeater_id,
             spice_tls,
             spice_ca_cert,
             spice_skip_cert_verify,
@@ -428,6 +471,7 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
                 domain: domain.as_deref(),
                 window_mode: window_mode.as_deref(),
                 skip_port_check,
+                tailscale_dns,
                 x11_forwarding,
                 agent_forwarding,
                 compression,
@@ -446,6 +490,7 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
                 resolution: resolution.as_deref(),
                 color_depth,
                 disable_nla,
+                rdp_security: rdp_security.as_deref(),
                 keyboard_layout,
                 audio_redirect,
                 printer,
@@ -459,6 +504,8 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
                 vnc_no_scaling,
                 vnc_no_clipboard,
                 vnc_custom_arg: &vnc_custom_arg,
+                vnc_repeater: vnc_repeater.as_deref(),
+                vnc_repeater_id: vnc_repeater_id.as_deref(),
                 spice_tls,
                 spice_ca_cert: spice_ca_cert.as_deref(),
                 spice_skip_cert_verify,
@@ -494,6 +541,9 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
         Commands::Group(subcmd) => group::cmd_group(config_path, subcmd),
         Commands::Template(subcmd) => template::cmd_template(config_path, subcmd),
         Commands::Cluster(subcmd) => cluster::cmd_cluster(config_path, subcmd),
+        Commands::ForwardProfile(subcmd) => {
+            forward_profile::cmd_forward_profile(config_path, subcmd)
+        }
         Commands::Var(subcmd) => variable::cmd_var(config_path, subcmd),
         #[cfg(feature = "secret-management")]
         Commands::Secret(subcmd) => secret::cmd_secret(config_path, subcmd),
@@ -503,16 +553,51 @@ pub fn dispatch(config_path: Option<&Path>, command: Commands) -> Result<(), Cli
         Commands::Duplicate { name, new_name } => {
             duplicate::cmd_duplicate(config_path, &name, new_name.as_deref())
         }
-        Commands::Sftp { name, cli, mc } => sftp::cmd_sftp(config_path, &name, cli, mc),
+        Commands::Sftp {
+            name,
+            cli,
+            mc,
+            resume,
+            key_lifetime,
+            confirm_key,
+        } => sftp::cmd_sftp(
+            config_path,
+            &name,
+            sftp::SftpMode { cli, mc, resume },
+            sftp::key_options(key_lifetime, confirm_key),
+        ),
         Commands::Stats { format } => stats::cmd_stats(config_path, format.effective()),
         Commands::Completions { shell } => completions::cmd_completions(shell),
         Commands::ManPage => manpage::cmd_manpage(),
         Commands::Sync(subcmd) => cloud_sync::cmd_cloud_sync(config_path, subcmd),
         Commands::History(subcmd) => history::cmd_history(config_path, subcmd),
+        Commands::Diagnostics(subcmd) => diagnostics::cmd_diagnostics(config_path, subcmd),
         Commands::Pin { name } => pin::cmd_pin(config_path, &name),
         Commands::Unpin { name } => pin::cmd_unpin(config_path, &name),
         Commands::Tag(subcmd) => tag::cmd_tag(config_path, subcmd),
+        Commands::Theme(subcmd) => theme::cmd_theme(subcmd),
         Commands::Move { name, group } => move_cmd::cmd_move(config_path, &name, &group),
         Commands::Monitor(subcmd) => monitor::cmd_monitor(config_path, subcmd),
+        Commands::Password {
+            passphrase,
+            pronounceable,
+            length,
+            words,
+            separator,
+            capitalize,
+            number,
+            no_special,
+            no_digits,
+        } => password::cmd_password(&password::PasswordParams {
+            passphrase,
+            pronounceable,
+            length,
+            words,
+            separator: &separator,
+            capitalize,
+            number,
+            no_special,
+            no_digits,
+        }),
     }
 }
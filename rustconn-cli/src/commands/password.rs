@@ -0,0 +1,73 @@
+//! Password generation command.
+
+use rustconn_core::password_generator::{
+    PasswordGenerator, PasswordGeneratorConfig, PasswordStrength, estimate_crack_time,
+};
+
+use crate::error::CliError;
+
+/// Guesses per second assumed for the crack-time estimate (offline GPU attack)
+const ATTEMPTS_PER_SECOND: f64 = 10_000_000_000.0;
+
+/// Parameters for the `password` command
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "mirrors Clap-derived flags 1:1, like AddParams"
+)]
+pub(super) struct PasswordParams<'a> {
+    pub passphrase: bool,
+    pub pronounceable: bool,
+    pub length: usize,
+    pub words: usize,
+    pub separator: &'a str,
+    pub capitalize: bool,
+    pub number: bool,
+    pub no_special: bool,
+    pub no_digits: bool,
+}
+
+/// Generate a password, pronounceable password or diceware passphrase
+///
+/// The secret is written to stdout on its own so it can be piped; the
+/// entropy estimate goes to stderr.
+///
+/// # Errors
+///
+/// Returns [`CliError::Password`] when the requested length or word count
+/// is too small, or the system RNG fails.
+pub(super) fn cmd_password(params: &PasswordParams<'_>) -> Result<(), CliError> {
+    let config = if params.passphrase {
+        PasswordGeneratorConfig::new()
+            .with_passphrase(params.words)
+            .with_word_separator(params.separator)
+            .with_capitalize_words(params.capitalize)
+            .with_include_number(params.number)
+    } else if params.pronounceable {
+        PasswordGeneratorConfig::new()
+            .with_pronounceable(params.length)
+            .with_digits(!params.no_digits)
+            .with_special(!params.no_special)
+    } else {
+        PasswordGeneratorConfig::new()
+            .with_length(params.length)
+            .with_digits(!params.no_digits)
+            .with_special(!params.no_special)
+    };
+
+    let generator = PasswordGenerator::new(config);
+    let password = generator
+        .generate()
+        .map_err(|e| CliError::Password(e.to_string()))?;
+
+    let entropy = generator.calculate_entropy(&password);
+    let strength = PasswordStrength::from_entropy(entropy);
+
+    println!("{password}");
+    eprintln!(
+        "Entropy: {entropy:.1} bits ({}), crack time: {}",
+        strength.description(),
+        estimate_crack_time(entropy, ATTEMPTS_PER_SECOND)
+    );
+
+    Ok(())
+}
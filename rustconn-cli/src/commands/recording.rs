@@ -4,6 +4,7 @@ use std::io::{self, BufRead, Write};
 use std::path::Path;
 
 use rustconn_core::session::recording::{RecordingEntry, RecordingManager, default_recordings_dir};
+use rustconn_core::session::strip_ansi;
 
 use crate::cli::{OutputFormat, RecordingCommands};
 use crate::error::CliError;
@@ -24,6 +25,11 @@ pub(super) fn cmd_recording(subcmd: RecordingCommands) -> Result<(), CliError> {
             data_file,
             timing_file,
         } => cmd_recording_import(&data_file, &timing_file),
+        RecordingCommands::Export {
+            name,
+            output,
+            clean,
+        } => cmd_recording_export(&name, output.as_deref(), clean),
     }
 }
 
@@ -220,3 +226,30 @@ fn cmd_recording_import(data_file: &Path, timing_file: &Path) -> Result<(), CliE
     );
     Ok(())
 }
+
+// ── Export ────────────────────────────────────────────────────────────
+
+fn cmd_recording_export(name: &str, output: Option<&Path>, clean: bool) -> Result<(), CliError> {
+    let manager = recordings_manager()?;
+    let entries = manager
+        .list()
+        .map_err(|e| CliError::Recording(format!("Failed to list recordings: {e}")))?;
+    let entry = find_recording(&entries, name)?;
+
+    let data = std::fs::read(&entry.data_path)
+        .map_err(|e| CliError::Recording(format!("Failed to read recording: {e}")))?;
+    let transcript = if clean {
+        strip_ansi(&String::from_utf8_lossy(&data)).into_bytes()
+    } else {
+        data
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, &transcript)?;
+            eprintln!("Exported '{}' to {}", display_name(entry), path.display());
+        }
+        None => io::stdout().lock().write_all(&transcript)?,
+    }
+    Ok(())
+}
@@ -0,0 +1,329 @@
+//! Search command with saved searches and interactive search.
+
+use std::io::{BufRead, IsTerminal};
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+
+use rustconn_core::config::{AppSettings, ConfigManager};
+use rustconn_core::models::{Connection, ConnectionGroup};
+use rustconn_core::{
+    ConnectionSearchResult, IncrementalSearch, MatchHighlight, SavedSearch, SearchEngine,
+    SearchMode, SearchQuery, SearchResult,
+};
+
+use super::list::{format_csv, format_json, format_table};
+use crate::cli::{OutputFormat, SearchModeArg};
+use crate::color;
+use crate::error::CliError;
+use crate::format::escape_csv_field;
+use crate::util::create_config_manager;
+
+/// Parameters for the `search` command
+pub(super) struct SearchParams<'a> {
+    pub query: &'a str,
+    pub save: Option<&'a str>,
+    pub run: Option<&'a str>,
+    pub list_searches: bool,
+    pub delete: Option<&'a str>,
+    pub interactive: bool,
+    pub limit: usize,
+    pub mode: SearchModeArg,
+    pub format: OutputFormat,
+}
+
+/// Search command handler
+///
+/// # Errors
+///
+/// Returns:
+/// - [`CliError::Config`] when connections, groups, or settings cannot be
+///   loaded or saved
+/// - [`CliError::Search`] when the query is invalid, no query or saved
+///   search is given, or the named saved search does not exist
+pub(super) fn cmd_search(
+    config_path: Option<&Path>,
+    params: &SearchParams<'_>,
+) -> Result<(), CliError> {
+    let config_manager = create_config_manager(config_path)?;
+
+    if params.list_searches {
+        return list_searches(&config_manager, params.format);
+    }
+    if let Some(name) = params.delete {
+        return delete_search(&config_manager, name);
+    }
+    if params.interactive {
+        return run_interactive(&config_manager, search_mode(params.mode), params.limit);
+    }
+
+    let query = if let Some(name) = params.run {
+        let settings = load_settings(&config_manager)?;
+        SearchEngine::load_saved(&settings.saved_searches, name)
+            .map_err(|e| CliError::Search(e.to_string()))?
+    } else if params.query.trim().is_empty() {
+        return Err(CliError::Search(
+            "provide a query, --run NAME or --list-searches".to_string(),
+        ));
+    } else {
+        let name = params.save.map(str::trim);
+        let saved = SavedSearch::new(name.unwrap_or_default(), params.query.trim())
+            .with_mode(search_mode(params.mode));
+        let query = saved
+            .to_query()
+            .map_err(|e| CliError::Search(e.to_string()))?;
+        if let Some(name) = name {
+            save_search(&config_manager, saved, name)?;
+        }
+        query
+    };
+
+    run_query(&config_manager, &query, params.format)
+}
+
+/// Maps the CLI mode argument to the core search mode
+const fn search_mode(mode: SearchModeArg) -> SearchMode {
+    match mode {
+        SearchModeArg::Fuzzy => SearchMode::Fuzzy,
+        SearchModeArg::Substring => SearchMode::Substring,
+        SearchModeArg::Regex => SearchMode::Regex,
+    }
+}
+
+fn load_settings(config_manager: &ConfigManager) -> Result<AppSettings, CliError> {
+    config_manager
+        .load_settings()
+        .map_err(|e| CliError::Config(format!("Failed to load settings: {e}")))
+}
+
+/// Stores a saved search, replacing one with the same name
+fn save_search(
+    config_manager: &ConfigManager,
+    search: SavedSearch,
+    name: &str,
+) -> Result<(), CliError> {
+    if name.is_empty() {
+        return Err(CliError::Search(
+            "saved search name cannot be empty".to_string(),
+        ));
+    }
+
+    let mut settings = load_settings(config_manager)?;
+    settings.save_search(search);
+    config_manager
+        .save_settings(&settings)
+        .map_err(|e| CliError::Config(format!("Failed to save settings: {e}")))?;
+
+    eprintln!("Saved search '{name}'.");
+    Ok(())
+}
+
+/// Removes a saved search
+fn delete_search(config_manager: &ConfigManager, name: &str) -> Result<(), CliError> {
+    let mut settings = load_settings(config_manager)?;
+    if !settings.remove_saved_search(name) {
+        return Err(CliError::Search(format!("No saved search named '{name}'")));
+    }
+    config_manager
+        .save_settings(&settings)
+        .map_err(|e| CliError::Config(format!("Failed to save settings: {e}")))?;
+
+    println!("Deleted saved search '{name}'.");
+    Ok(())
+}
+
+/// Prints saved searches
+fn list_searches(config_manager: &ConfigManager, format: OutputFormat) -> Result<(), CliError> {
+    let settings = load_settings(config_manager)?;
+    let searches = settings.list_searches();
+
+    match format {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&searches)
+                .map_err(|e| CliError::Config(format!("JSON serialization failed: {e}")))?;
+            println!("{json}");
+        }
+        OutputFormat::Csv => {
+            println!("name,mode,query");
+            for search in &searches {
+                println!(
+                    "{},{},{}",
+                    escape_csv_field(&search.name),
+                    search.mode.as_str(),
+                    escape_csv_field(&search.query)
+                );
+            }
+        }
+        OutputFormat::Table => {
+            if searches.is_empty() {
+                println!("No saved searches.");
+                return Ok(());
+            }
+            println!(
+                "{}{:<20}  {:<9}  {}{}",
+                color::bold(),
+                "NAME",
+                "MODE",
+                "QUERY",
+                color::reset(),
+            );
+            for search in &searches {
+                println!(
+                    "{:<20}  {:<9}  {}",
+                    search.name,
+                    search.mode.as_str(),
+                    search.query
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a query and prints the matching connections, best match first
+fn run_query(
+    config_manager: &ConfigManager,
+    query: &SearchQuery,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let connections = config_manager
+        .load_connections()
+        .map_err(|e| CliError::Config(format!("Failed to load connections: {e}")))?;
+    let groups = config_manager
+        .load_groups()
+        .map_err(|e| CliError::Config(format!("Failed to load groups: {e}")))?;
+
+    let refs: Vec<&Connection> = connections.iter().collect();
+    let results = SearchEngine::new()
+        .search(query, &refs, &groups)
+        .map_err(|e| CliError::Search(e.to_string()))?;
+
+    let matched: Vec<&Connection> = results
+        .iter()
+        .filter_map(|r| connections.iter().find(|c| c.id == r.connection_id))
+        .collect();
+
+    match format {
+        OutputFormat::Table => println!("{}", format_table(&matched)),
+        OutputFormat::Json => println!("{}", format_json(&matched)?),
+        OutputFormat::Csv => println!("{}", format_csv(&matched)),
+    }
+
+    Ok(())
+}
+
+/// Reads queries from stdin and prints the best matches once input pauses
+///
+/// Each line replaces the query. Lines arriving within the search delay of
+/// each other are searched once, for the last line; end of input searches
+/// any line still pending.
+fn run_interactive(
+    config_manager: &ConfigManager,
+    mode: SearchMode,
+    limit: usize,
+) -> Result<(), CliError> {
+    let connections = config_manager
+        .load_connections()
+        .map_err(|e| CliError::Config(format!("Failed to load connections: {e}")))?;
+    let groups = config_manager
+        .load_groups()
+        .map_err(|e| CliError::Config(format!("Failed to load groups: {e}")))?;
+    let refs: Vec<&Connection> = connections.iter().collect();
+
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut search = IncrementalSearch::new().with_mode(mode);
+    if std::io::stdin().is_terminal() {
+        eprintln!("Type a query and press Enter; Ctrl+D quits.");
+    }
+
+    loop {
+        let text = search.pending().map(str::to_string);
+        let update = match rx.recv_timeout(search.delay()) {
+            Ok(line) => {
+                search.input(&line);
+                continue;
+            }
+            Err(RecvTimeoutError::Timeout) => search.poll(&refs, &groups),
+            Err(RecvTimeoutError::Disconnected) => {
+                let update = search.flush(&refs, &groups);
+                print_update(text.as_deref(), update, &connections, &groups, limit);
+                return Ok(());
+            }
+        };
+        print_update(text.as_deref(), update, &connections, &groups, limit);
+    }
+}
+
+/// Prints the top `limit` results of one interactive search
+///
+/// Errors are reported on stderr and do not end the session.
+fn print_update(
+    text: Option<&str>,
+    update: SearchResult<Option<Vec<ConnectionSearchResult>>>,
+    connections: &[Connection],
+    groups: &[ConnectionGroup],
+    limit: usize,
+) {
+    let results = match update {
+        Ok(Some(results)) => results,
+        Ok(None) => return,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return;
+        }
+    };
+
+    println!(
+        "{}Results for \"{}\" ({} matches){}",
+        color::bold(),
+        text.unwrap_or_default(),
+        results.len(),
+        color::reset(),
+    );
+    for result in results.iter().take(limit) {
+        let Some(conn) = connections.iter().find(|c| c.id == result.connection_id) else {
+            continue;
+        };
+        let group = conn
+            .group_id
+            .and_then(|id| groups.iter().find(|g| g.id == id))
+            .map(|g| mark_highlights(&g.name, "group", &result.highlights));
+        let mut line = format!(
+            "  {:.2}  {}  {}",
+            result.score,
+            mark_highlights(&conn.name, "name", &result.highlights),
+            mark_highlights(&conn.host, "host", &result.highlights),
+        );
+        if let Some(group) = group {
+            line.push_str(&format!("  ({group})"));
+        }
+        println!("{line}");
+    }
+    if results.len() > limit {
+        println!("  … {} more", results.len() - limit);
+    }
+}
+
+/// Wraps the first highlighted span of `field` in `value` in brackets
+fn mark_highlights(value: &str, field: &str, highlights: &[MatchHighlight]) -> String {
+    highlights
+        .iter()
+        .find(|h| h.field == field)
+        .and_then(|h| {
+            Some(format!(
+                "{}[{}]{}",
+                value.get(..h.start)?,
+                value.get(h.start..h.end)?,
+                value.get(h.end..)?
+            ))
+        })
+        .unwrap_or_else(|| value.to_string())
+}
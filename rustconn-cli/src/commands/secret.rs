@@ -13,6 +13,37 @@ fn create_pass_backend(
     rustconn_core::secret::PassBackend::from_app_settings(settings)
 }
 
+/// Creates a `VaultBackend`, decrypting a token saved in the settings.
+fn create_vault_backend(
+    settings: &rustconn_core::config::SecretSettings,
+) -> rustconn_core::secret::VaultBackend {
+    let mut secrets = settings.clone();
+    secrets.decrypt_vault_token();
+    rustconn_core::secret::VaultBackend::from_secret_settings(&secrets)
+}
+
+/// Creates a `BitwardenBackend`, first pointing `bw` at the configured server.
+fn create_bitwarden_backend(
+    rt: &tokio::runtime::Runtime,
+    settings: &rustconn_core::config::SecretSettings,
+) -> Result<rustconn_core::secret::BitwardenBackend, CliError> {
+    let backend = rustconn_core::secret::BitwardenBackend::new();
+    let Some(url) = settings
+        .bitwarden_server_url
+        .as_deref()
+        .filter(|url| !url.trim().is_empty())
+    else {
+        return Ok(backend);
+    };
+    if rt
+        .block_on(rustconn_core::secret::ensure_server(url))
+        .map_err(|e| CliError::Secret(format!("Bitwarden error: {e}")))?
+    {
+        eprintln!("Bitwarden CLI reconfigured for {url}; log in again with 'bw login'");
+    }
+    Ok(backend.with_server_url(url))
+}
+
 /// Secret command handler
 ///
 /// # Errors
@@ -161,6 +192,13 @@ fn cmd_secret_status(config_path: Option<&Path>) -> Result<(), CliError> {
             "  Preferred backend: {:?}",
             settings.secrets.preferred_backend
         );
+        if let Some(ref url) = settings.secrets.bitwarden_server_url {
+            println!("  Bitwarden server: {url}");
+        }
+        if settings.secrets.preferred_backend == rustconn_core::config::SecretBackendType::Vault {
+            let backend = create_vault_backend(&settings.secrets);
+            println!("  Vault server: {}", backend.address());
+        }
         if settings.secrets.kdbx_enabled {
             if let Some(ref path) = settings.secrets.kdbx_path {
                 println!("  KDBX database: {}", path.display());
@@ -184,9 +222,10 @@ fn parse_backend(b: &str) -> Result<rustconn_core::config::SecretBackendType, Cl
         "1password" | "onepassword" | "op" => Ok(SecretBackendType::OnePassword),
         "passbolt" => Ok(SecretBackendType::Passbolt),
         "pass" => Ok(SecretBackendType::Pass),
+        "vault" | "hashicorp" => Ok(SecretBackendType::Vault),
         _ => Err(CliError::Secret(format!(
             "Unknown backend: {b}. Use: keyring, keepass, bitwarden, \
-             1password, passbolt, or pass"
+             1password, passbolt, pass, or vault"
         ))),
     }
 }
@@ -311,12 +350,10 @@ fn cmd_secret_get(
             }
         }
         SecretBackendType::Bitwarden => {
-            use rustconn_core::secret::BitwardenBackend;
-
             let rt = tokio::runtime::Runtime::new()
                 .map_err(|e| CliError::Secret(format!("Runtime error: {e}")))?;
 
-            let backend = BitwardenBackend::new();
+            let backend = create_bitwarden_backend(&rt, &settings.secrets)?;
             let result: Result<Option<Credentials>, _> = rt.block_on(backend.retrieve(&lookup_key));
 
             match result {
@@ -478,6 +515,38 @@ fn cmd_secret_get(
                 Err(e) => Err(CliError::Secret(format!("Encrypted file error: {e}"))),
             }
         }
+        SecretBackendType::Vault => {
+            let rt = tokio::runtime::Runtime::new()
+                .map_err(|e| CliError::Secret(format!("Runtime error: {e}")))?;
+
+            let backend = create_vault_backend(&settings.secrets);
+            let vault_key =
+                rustconn_core::secret::CredentialResolver::generate_lookup_key(connection);
+            let result: Result<Option<Credentials>, _> = rt.block_on(backend.retrieve(&vault_key));
+
+            match result {
+                Ok(Some(creds)) => {
+                    println!("Connection: {}", connection.name);
+                    if let Some(ref user) = creds.username {
+                        println!("Username:   {user}");
+                    }
+                    if creds.expose_password().is_some() {
+                        println!(
+                            "Password:   ******** \
+                             (stored in Vault)"
+                        );
+                    } else {
+                        println!("Password:   (not set)");
+                    }
+                    Ok(())
+                }
+                Ok(None) => Err(CliError::Secret(format!(
+                    "No credentials found in Vault for '{}'",
+                    connection.name
+                ))),
+                Err(e) => Err(CliError::Secret(format!("Vault error: {e}"))),
+            }
+        }
     }
 }
 
@@ -639,12 +708,12 @@ fn cmd_secret_set(
         }
         SecretBackendType::Bitwarden => {
             use rustconn_core::models::Credentials;
-            use rustconn_core::secret::{BitwardenBackend, SecretBackend};
+            use rustconn_core::secret::SecretBackend;
 
             let rt = tokio::runtime::Runtime::new()
                 .map_err(|e| CliError::Secret(format!("Runtime error: {e}")))?;
 
-            let backend = BitwardenBackend::new();
+            let backend = create_bitwarden_backend(&rt, &settings.secrets)?;
             let creds = Credentials {
                 username: Some(username_value.clone()),
                 password: Some(password_value),
@@ -773,6 +842,33 @@ fn cmd_secret_set(
             );
             Ok(())
         }
+        SecretBackendType::Vault => {
+            use rustconn_core::models::Credentials;
+            use rustconn_core::secret::SecretBackend;
+
+            let rt = tokio::runtime::Runtime::new()
+                .map_err(|e| CliError::Secret(format!("Runtime error: {e}")))?;
+
+            let backend = create_vault_backend(&settings.secrets);
+            let creds = Credentials {
+                username: Some(username_value.clone()),
+                password: Some(password_value),
+                key_passphrase: None,
+                domain: connection.domain.clone(),
+            };
+
+            let vault_key =
+                rustconn_core::secret::CredentialResolver::generate_lookup_key(connection);
+            rt.block_on(backend.store(&vault_key, &creds))
+                .map_err(|e| CliError::Secret(format!("Vault error: {e}")))?;
+
+            println!(
+                "Stored credentials for '{}' in Vault \
+                 (user: {})",
+                connection.name, username_value
+            );
+            Ok(())
+        }
     }
 }
 
@@ -862,12 +958,10 @@ fn cmd_secret_delete(
             Ok(())
         }
         SecretBackendType::Bitwarden => {
-            use rustconn_core::secret::BitwardenBackend;
-
             let rt = tokio::runtime::Runtime::new()
                 .map_err(|e| CliError::Secret(format!("Runtime error: {e}")))?;
 
-            let backend = BitwardenBackend::new();
+            let backend = create_bitwarden_backend(&rt, &settings.secrets)?;
             rt.block_on(backend.delete(&lookup_key))
                 .map_err(|e| CliError::Secret(format!("Bitwarden error: {e}")))?;
 
@@ -947,6 +1041,19 @@ fn cmd_secret_delete(
             );
             Ok(())
         }
+        SecretBackendType::Vault => {
+            let rt = tokio::runtime::Runtime::new()
+                .map_err(|e| CliError::Secret(format!("Runtime error: {e}")))?;
+
+            let backend = create_vault_backend(&settings.secrets);
+            let vault_key =
+                rustconn_core::secret::CredentialResolver::generate_lookup_key(connection);
+            rt.block_on(backend.delete(&vault_key))
+                .map_err(|e| CliError::Secret(format!("Vault error: {e}")))?;
+
+            println!("Deleted credentials for '{}' from Vault", connection.name);
+            Ok(())
+        }
     }
 }
 
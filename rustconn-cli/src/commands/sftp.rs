@@ -1,12 +1,35 @@
 //! SFTP session command.
 
 use std::path::Path;
+use std::time::Duration;
 
 use rustconn_core::models::ProtocolType;
+use rustconn_core::sftp::SftpTransferOptions;
+use rustconn_core::ssh_agent::AddKeyOptions;
 
 use crate::error::CliError;
 use crate::util::{create_config_manager, find_connection};
 
+/// How `sftp` opens the session
+#[derive(Debug, Clone, Copy)]
+pub(super) struct SftpMode {
+    /// Interactive `sftp` CLI instead of a file manager
+    pub cli: bool,
+    /// Midnight Commander instead of a file manager
+    pub mc: bool,
+    /// Continue interrupted transfers (`sftp -a`)
+    pub resume: bool,
+}
+
+/// Builds the agent constraints for the connection's SSH key from the
+/// `--key-lifetime` and `--confirm-key` flags
+pub(super) fn key_options(lifetime_secs: Option<u64>, confirm: bool) -> AddKeyOptions {
+    let options = AddKeyOptions::default().with_confirm(confirm);
+    lifetime_secs.map_or(options, |secs| {
+        options.with_lifetime(Duration::from_secs(secs))
+    })
+}
+
 /// Open SFTP session for an SSH connection
 ///
 /// # Errors
@@ -20,8 +43,8 @@ use crate::util::{create_config_manager, find_connection};
 pub(super) fn cmd_sftp(
     config_path: Option<&Path>,
     name: &str,
-    use_cli: bool,
-    use_mc: bool,
+    mode: SftpMode,
+    key_options: AddKeyOptions,
 ) -> Result<(), CliError> {
     let config_manager = create_config_manager(config_path)?;
 
@@ -49,11 +72,11 @@ pub(super) fn cmd_sftp(
         tracing::warn!("ssh-agent is not running. SFTP may require manual setup.");
     }
 
-    if !rustconn_core::sftp::ensure_key_in_agent(connection, &groups) {
+    if !rustconn_core::sftp::ensure_key_in_agent(connection, &groups, &key_options) {
         tracing::warn!("Could not add SSH key to agent. You may need to run ssh-add manually.");
     }
 
-    if use_mc {
+    if mode.mc {
         let cmd = rustconn_core::sftp::build_mc_sftp_command(connection, &groups)
             .ok_or_else(|| CliError::Protocol("Failed to build mc command".to_string()))?;
 
@@ -74,8 +97,9 @@ pub(super) fn cmd_sftp(
                 "mc session ended with error".to_string(),
             ));
         }
-    } else if use_cli {
-        let cmd = rustconn_core::sftp::build_sftp_command(connection, &groups)
+    } else if mode.cli {
+        let options = SftpTransferOptions::default().with_resume(mode.resume);
+        let cmd = rustconn_core::sftp::build_sftp_command(connection, &groups, &options)
             .ok_or_else(|| CliError::Protocol("Failed to build SFTP command".to_string()))?;
 
         println!("Connecting via sftp CLI to '{}'...", connection.name);
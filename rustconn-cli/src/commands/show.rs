@@ -81,6 +81,7 @@ fn print_json(
         "domain": connection.domain,
         "window_mode": format!("{:?}", connection.window_mode),
         "skip_port_check": connection.skip_port_check,
+        "resolve_via_tailscale": connection.resolve_via_tailscale,
         "session_recording_enabled": connection.session_recording_enabled,
         "is_dynamic": connection.is_dynamic,
     });
@@ -109,11 +110,8 @@ fn print_json(
                     serde_json::Value::String(key.display().to_string()),
                 );
             }
-            if let Some(ref jump) = config.proxy_jump {
-                map.insert(
-                    "proxy_jump".to_string(),
-                    serde_json::Value::String(jump.clone()),
-                );
+            if let Some(jump) = config.proxy_jump_spec() {
+                map.insert("proxy_jump".to_string(), serde_json::Value::String(jump));
             }
             if let Some(jump_id) = config.jump_host_id {
                 map.insert(
@@ -179,6 +177,17 @@ fn print_json(
             if config.mptcp {
                 map.insert("mptcp".to_string(), serde_json::Value::Bool(true));
             }
+            if let Some(ref repeater) = config.repeater {
+                map.insert(
+                    "repeater".to_string(),
+                    serde_json::Value::String(format!(
+                        "{}:{} {}",
+                        repeater.host,
+                        repeater.port,
+                        repeater.target()
+                    )),
+                );
+            }
         }
         ProtocolConfig::Spice(config) => {
             if let Some(jump_id) = config.jump_host_id {
@@ -378,7 +387,7 @@ fn print_table(connection: &Connection, connections: &[Connection]) -> Result<()
             if let Some(ref key) = config.key_path {
                 println!("  Key Path: {}", key.display());
             }
-            if let Some(ref jump) = config.proxy_jump {
+            if let Some(jump) = config.proxy_jump_spec() {
                 println!("  Proxy Jump: {jump}");
             }
             if let Some(jump_id) = config.jump_host_id {
@@ -485,6 +494,14 @@ fn print_table(connection: &Connection, connections: &[Connection]) -> Result<()
             if config.mptcp {
                 println!("  MPTCP:    enabled");
             }
+            if let Some(ref repeater) = config.repeater {
+                println!(
+                    "  Repeater: {}:{} ({})",
+                    repeater.host,
+                    repeater.port,
+                    repeater.target()
+                );
+            }
         }
         ProtocolConfig::Spice(ref config) => {
             if let Some(jump_id) = config.jump_host_id {
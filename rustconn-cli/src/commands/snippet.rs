@@ -1,10 +1,11 @@
 //! Snippet management commands.
 
 use std::collections::HashMap;
+use std::io::IsTerminal;
 use std::path::Path;
 
-use rustconn_core::models::Snippet;
-use rustconn_core::snippet::SnippetManager;
+use rustconn_core::models::{Snippet, SnippetVariable, SnippetVariableKind};
+use rustconn_core::snippet::{SnippetError, SnippetManager};
 
 use crate::cli::{OutputFormat, SnippetCommands};
 use crate::error::CliError;
@@ -41,14 +42,20 @@ pub(super) fn cmd_snippet(
             description,
             category,
             tags,
-        } => cmd_snippet_add(
-            config_path,
-            &name,
-            &command,
-            description.as_deref(),
-            category,
-            tags,
-        ),
+            choices,
+            secrets,
+        } => {
+            let variables = snippet_variables(&command, &choices, &secrets)?;
+            cmd_snippet_add(
+                config_path,
+                &name,
+                &command,
+                description.as_deref(),
+                category,
+                tags,
+                variables,
+            )
+        }
         SnippetCommands::Edit {
             name,
             new_name,
@@ -215,6 +222,7 @@ fn cmd_snippet_add(
     description: Option<&str>,
     category: Option<String>,
     tags: Option<String>,
+    variables: Vec<SnippetVariable>,
 ) -> Result<(), CliError> {
     let config_manager = create_config_manager(config_path)?;
 
@@ -234,7 +242,6 @@ fn cmd_snippet_add(
         snippet = snippet.with_tags(tag_vec);
     }
 
-    let variables = SnippetManager::extract_variable_objects(command);
     snippet = snippet.with_variables(variables);
 
     let id = snippet_manager
@@ -275,8 +282,18 @@ fn cmd_snippet_edit(
     }
     if let Some(cmd) = command {
         updated.command = cmd.to_string();
-        let variables = SnippetManager::extract_variable_objects(cmd);
-        updated.variables = variables;
+        // Keep descriptions, defaults and kinds of variables still in use
+        updated.variables = SnippetManager::extract_variable_objects(cmd)
+            .into_iter()
+            .map(|var| {
+                snippet
+                    .variables
+                    .iter()
+                    .find(|old| old.name == var.name)
+                    .cloned()
+                    .unwrap_or(var)
+            })
+            .collect();
     }
     if let Some(desc) = description {
         updated.description = Some(desc.to_string());
@@ -331,18 +348,19 @@ fn cmd_snippet_run(
 
     let snippet = find_snippet(&snippet_manager, name)?;
 
-    let values: HashMap<String, String> = vars.iter().cloned().collect();
+    let mut values: HashMap<String, String> = vars.iter().cloned().collect();
+    if std::io::stdin().is_terminal() {
+        prompt_for_variables(snippet, &mut values)?;
+    }
 
-    let missing = SnippetManager::get_missing_variables(snippet, &values);
-    if !missing.is_empty() {
-        return Err(CliError::Snippet(format!(
+    let command = SnippetManager::expand(snippet, &values).map_err(|e| match e {
+        SnippetError::MissingVariables(missing) => CliError::Snippet(format!(
             "Missing required variables: {}. \
              Use --var name=value to provide them.",
             missing.join(", ")
-        )));
-    }
-
-    let command = SnippetManager::substitute_with_defaults(snippet, &values);
+        )),
+        other => CliError::Snippet(other.to_string()),
+    })?;
 
     if execute {
         // Warn about potentially dangerous shell metacharacters in variable values
@@ -355,7 +373,10 @@ fn cmd_snippet_run(
             );
         }
 
-        println!("Executing: {command}");
+        println!(
+            "Executing: {}",
+            SnippetManager::expand_masked(snippet, &values)
+        );
         let status = std::process::Command::new("sh")
             .arg("-c")
             .arg(&command)
@@ -375,6 +396,100 @@ fn cmd_snippet_run(
     Ok(())
 }
 
+/// Builds the variable definitions for a new snippet command
+fn snippet_variables(
+    command: &str,
+    choices: &[(String, String)],
+    secrets: &[String],
+) -> Result<Vec<SnippetVariable>, CliError> {
+    let mut variables = SnippetManager::extract_variable_objects(command);
+
+    for (name, options) in choices {
+        let var = variables
+            .iter_mut()
+            .find(|v| &v.name == name)
+            .ok_or_else(|| CliError::Snippet(format!("--choice: no ${{{name}}} in command")))?;
+        let options: Vec<String> = options
+            .split(',')
+            .map(str::trim)
+            .filter(|o| !o.is_empty())
+            .map(String::from)
+            .collect();
+        if options.is_empty() {
+            return Err(CliError::Snippet(format!(
+                "--choice: no options given for '{name}'"
+            )));
+        }
+        var.kind = SnippetVariableKind::Choice { options };
+    }
+    for name in secrets {
+        let var = variables
+            .iter_mut()
+            .find(|v| &v.name == name)
+            .ok_or_else(|| CliError::Snippet(format!("--secret: no ${{{name}}} in command")))?;
+        var.kind = SnippetVariableKind::Secret;
+    }
+
+    Ok(variables)
+}
+
+/// Prompts on the terminal for each variable without a `--var` value
+///
+/// An empty answer keeps the variable's default, if any.
+fn prompt_for_variables(
+    snippet: &Snippet,
+    values: &mut HashMap<String, String>,
+) -> Result<(), CliError> {
+    let read_error = |e: std::io::Error| CliError::Snippet(format!("Failed to read input: {e}"));
+
+    for var in SnippetManager::unset_variables(snippet, values) {
+        let label = var.description.as_deref().unwrap_or(&var.name);
+        let default = var
+            .default_value
+            .as_deref()
+            .map(|d| format!(" [{d}]"))
+            .unwrap_or_default();
+
+        let answer = match &var.kind {
+            SnippetVariableKind::Secret => {
+                eprint!("{label}: ");
+                rpassword::read_password().map_err(read_error)?
+            }
+            SnippetVariableKind::Choice { options } => {
+                for (i, option) in options.iter().enumerate() {
+                    eprintln!("  {}) {option}", i + 1);
+                }
+                eprint!("{label}{default}: ");
+                let answer = read_answer().map_err(read_error)?;
+                // Accept the option number as well as the option itself
+                answer
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|n| n.checked_sub(1))
+                    .and_then(|i| options.get(i).cloned())
+                    .unwrap_or(answer)
+            }
+            SnippetVariableKind::Text => {
+                eprint!("{label}{default}: ");
+                read_answer().map_err(read_error)?
+            }
+        };
+
+        if !answer.is_empty() {
+            values.insert(var.name, answer);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads one trimmed line from stdin
+fn read_answer() -> std::io::Result<String> {
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
 /// Find a snippet by name or ID
 fn find_snippet<'a>(
     manager: &'a SnippetManager,
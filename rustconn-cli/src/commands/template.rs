@@ -2,7 +2,7 @@
 
 use std::path::Path;
 
-use rustconn_core::models::ConnectionTemplate;
+use rustconn_core::models::{Connection, ConnectionTemplate};
 
 use crate::cli::{OutputFormat, TemplateCommands};
 use crate::error::CliError;
@@ -33,6 +33,7 @@ pub(super) fn cmd_template(
             port,
             user,
             description,
+            parent,
         } => cmd_template_create(
             config_path,
             &name,
@@ -41,6 +42,7 @@ pub(super) fn cmd_template(
             port,
             user.as_deref(),
             description.as_deref(),
+            parent.as_deref(),
         ),
         TemplateCommands::Edit {
             name,
@@ -49,6 +51,7 @@ pub(super) fn cmd_template(
             port,
             user,
             description,
+            parent,
         } => cmd_template_edit(
             config_path,
             &name,
@@ -57,6 +60,7 @@ pub(super) fn cmd_template(
             port,
             user.as_deref(),
             description.as_deref(),
+            parent.as_deref(),
         ),
         TemplateCommands::Delete { name } => cmd_template_delete(config_path, &name),
         TemplateCommands::Apply {
@@ -172,6 +176,12 @@ fn cmd_template_show(config_path: Option<&Path>, name: &str) -> Result<(), CliEr
     if let Some(ref desc) = template.description {
         println!("  Description: {desc}");
     }
+    if let Some(parent_id) = template.parent_id {
+        let parent = manager
+            .get_template(parent_id)
+            .map_or_else(|| parent_id.to_string(), |p| p.name.clone());
+        println!("  Parent:   {parent}");
+    }
     if !template.tags.is_empty() {
         println!("  Tags:     {}", template.tags.join(", "));
     }
@@ -179,6 +189,10 @@ fn cmd_template_show(config_path: Option<&Path>, name: &str) -> Result<(), CliEr
     Ok(())
 }
 
+#[expect(
+    clippy::too_many_arguments,
+    reason = "function parameters mirror upstream API or struct fields 1:1; bundling into a struct only restates the field list"
+)]
 fn cmd_template_create(
     config_path: Option<&Path>,
     name: &str,
@@ -187,6 +201,7 @@ fn cmd_template_create(
     port: Option<u16>,
     user: Option<&str>,
     description: Option<&str>,
+    parent: Option<&str>,
 ) -> Result<(), CliError> {
     let mut manager = create_template_manager(config_path)?;
 
@@ -215,6 +230,10 @@ fn cmd_template_create(
     if let Some(d) = description {
         template = template.with_description(d);
     }
+    if let Some(p) = parent {
+        template = template.with_parent(find_template_in_manager(&manager, p)?.id);
+        check_template_chain(&manager, &template)?;
+    }
 
     let id = manager
         .create_template(template)
@@ -225,6 +244,10 @@ fn cmd_template_create(
     Ok(())
 }
 
+#[expect(
+    clippy::too_many_arguments,
+    reason = "function parameters mirror upstream API or struct fields 1:1; bundling into a struct only restates the field list"
+)]
 fn cmd_template_edit(
     config_path: Option<&Path>,
     name: &str,
@@ -233,6 +256,7 @@ fn cmd_template_edit(
     port: Option<u16>,
     user: Option<&str>,
     description: Option<&str>,
+    parent: Option<&str>,
 ) -> Result<(), CliError> {
     let mut manager = create_template_manager(config_path)?;
     let template = find_template_in_manager(&manager, name)?;
@@ -255,6 +279,14 @@ fn cmd_template_edit(
     if let Some(d) = description {
         updated.description = Some(d.to_string());
     }
+    if let Some(p) = parent {
+        updated.parent_id = if p.is_empty() {
+            None
+        } else {
+            Some(find_template_in_manager(&manager, p)?.id)
+        };
+        check_template_chain(&manager, &updated)?;
+    }
 
     manager
         .update_template(id, updated)
@@ -293,7 +325,11 @@ fn cmd_template_apply(
 
     let template = find_template_in_manager(&manager, template_name)?;
 
-    let mut connection = template.apply(conn_name.map(String::from));
+    let templates: Vec<ConnectionTemplate> =
+        manager.list_templates().into_iter().cloned().collect();
+    let mut connection =
+        Connection::new_from_template(template, &templates, conn_name.map(String::from))
+            .map_err(|e| CliError::Template(e.to_string()))?;
 
     if let Some(h) = host {
         connection.host = h.to_string();
@@ -325,6 +361,25 @@ fn cmd_template_apply(
     Ok(())
 }
 
+/// Checks that `template`'s parent chain resolves, with `template` replacing
+/// its stored version
+fn check_template_chain(
+    manager: &rustconn_core::TemplateManager,
+    template: &ConnectionTemplate,
+) -> Result<(), CliError> {
+    let mut templates: Vec<ConnectionTemplate> = manager
+        .list_templates()
+        .into_iter()
+        .filter(|t| t.id != template.id)
+        .cloned()
+        .collect();
+    templates.push(template.clone());
+    template
+        .resolve(&templates)
+        .map(drop)
+        .map_err(|e| CliError::Template(e.to_string()))
+}
+
 /// Find a template by name or ID using `TemplateManager`
 fn find_template_in_manager<'a>(
     manager: &'a rustconn_core::TemplateManager,
@@ -16,10 +16,15 @@ use crate::util::{create_config_manager, find_connection};
 ///   (and `name` is not the special value `"all"`)
 /// - [`CliError::TestFailed`] when the TCP probe fails or the host is unreachable
 ///   within `timeout` seconds
+///
+/// The optional `ping` (ICMP echo) and `udp` (RDP UDP transport) probes are
+/// reported in the result details and do not affect whether a test passes.
 pub(super) fn cmd_test(
     config_path: Option<&Path>,
     name: &str,
     timeout: u64,
+    ping: bool,
+    udp: bool,
     format: OutputFormat,
 ) -> Result<(), CliError> {
     let config_manager = create_config_manager(config_path)?;
@@ -41,7 +46,9 @@ pub(super) fn cmd_test(
 
     let tester = rustconn_core::testing::ConnectionTester::with_timeout(
         std::time::Duration::from_secs(timeout),
-    );
+    )
+    .ping(ping)
+    .udp(udp);
 
     let runtime = tokio::runtime::Runtime::new()
         .map_err(|e| CliError::TestFailed(format!("Failed to create async runtime: {e}")))?;
@@ -158,6 +165,14 @@ fn print_test_result_table(result: &rustconn_core::testing::TestResult) {
             print!(" [{protocol}]");
         }
 
+        if let Some(ping) = result.details.get("ping_latency_ms") {
+            print!(" {}ping {ping}ms{}", color::cyan(), color::reset());
+        }
+
+        if let Some(udp) = result.details.get("udp") {
+            print!(" udp:{udp}");
+        }
+
         println!();
     } else {
         print!("{}{}✗{} ", color::red(), color::bold(), color::reset());
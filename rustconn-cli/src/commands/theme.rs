@@ -0,0 +1,110 @@
+//! Terminal theme commands.
+
+use std::path::Path;
+
+use rustconn_core::terminal_themes::TerminalTheme;
+
+use crate::cli::{OutputFormat, ThemeCommands};
+use crate::color;
+use crate::error::CliError;
+use crate::format::escape_csv_field;
+
+/// Theme command dispatcher
+///
+/// Custom themes live in the user config directory shared with the GUI,
+/// not in the `--config` directory.
+///
+/// # Errors
+///
+/// Returns [`CliError::Theme`] when a color scheme cannot be imported or
+/// would replace a built-in theme.
+pub(super) fn cmd_theme(subcmd: ThemeCommands) -> Result<(), CliError> {
+    match subcmd {
+        ThemeCommands::List { format } => cmd_theme_list(format.effective()),
+        ThemeCommands::Import { file, name } => cmd_theme_import(&file, name.as_deref()),
+    }
+}
+
+/// List built-in and custom themes
+fn cmd_theme_list(format: OutputFormat) -> Result<(), CliError> {
+    let themes = TerminalTheme::all_themes();
+    let kind = |theme: &TerminalTheme| {
+        if theme.is_custom {
+            "custom"
+        } else {
+            "built-in"
+        }
+    };
+
+    match format {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&themes)
+                .map_err(|e| CliError::Config(format!("JSON serialization failed: {e}")))?;
+            println!("{json}");
+        }
+        OutputFormat::Csv => {
+            println!("name,kind,background,foreground");
+            for theme in &themes {
+                println!(
+                    "{},{},{},{}",
+                    escape_csv_field(&theme.name),
+                    kind(theme),
+                    theme.background.to_hex(),
+                    theme.foreground.to_hex()
+                );
+            }
+        }
+        OutputFormat::Table => {
+            println!(
+                "{}{:<24}  {:<8}  {:<10}  {}{}",
+                color::bold(),
+                "NAME",
+                "KIND",
+                "BACKGROUND",
+                "FOREGROUND",
+                color::reset(),
+            );
+            for theme in &themes {
+                println!(
+                    "{:<24}  {:<8}  {:<10}  {}",
+                    theme.name,
+                    kind(theme),
+                    theme.background.to_hex(),
+                    theme.foreground.to_hex()
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Import an `.itermcolors` or Base16 `.yaml` file as a custom theme
+fn cmd_theme_import(file: &Path, name: Option<&str>) -> Result<(), CliError> {
+    let is_base16 = file
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+    let imported = if is_base16 {
+        TerminalTheme::import_base16_file(file)
+    } else {
+        TerminalTheme::import_itermcolors_file(file)
+    };
+    let mut theme = imported.map_err(|e| CliError::Theme(format!("{}: {e}", file.display())))?;
+    if let Some(name) = name.map(str::trim) {
+        if name.is_empty() {
+            return Err(CliError::Theme("theme name cannot be empty".to_string()));
+        }
+        theme.name = name.to_string();
+    }
+    if TerminalTheme::is_builtin(&theme.name) {
+        return Err(CliError::Theme(format!(
+            "'{}' is a built-in theme; choose another name with --name",
+            theme.name
+        )));
+    }
+
+    let name = theme.name.clone();
+    TerminalTheme::save_custom_theme(theme);
+    println!("Imported theme '{name}'.");
+    Ok(())
+}
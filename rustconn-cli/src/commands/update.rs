@@ -3,11 +3,12 @@
 use std::path::Path;
 
 use rustconn_core::config::ConfigManager;
-use rustconn_core::models::RdpGateway;
+use rustconn_core::models::{PasswordSource, RdpGateway};
 
 use crate::commands::add::{
-    apply_jump_host_id, apply_ssh_wave2_fields, parse_auth_method, parse_resolution,
-    parse_shared_folder, parse_spice_image_compression,
+    apply_jump_host_id, apply_ssh_wave2_fields, parse_auth_method, parse_rdp_security,
+    parse_resolution, parse_shared_folder, parse_spice_image_compression,
+    parse_spice_shared_folder, parse_vnc_repeater,
 };
 use crate::error::CliError;
 use crate::util::{create_config_manager, find_connection};
@@ -62,6 +63,7 @@ pub(super) struct UpdateParams<'a> {
     pub domain: Option<&'a str>,
     pub window_mode: Option<&'a str>,
     pub skip_port_check: Option<bool>,
+    pub tailscale_dns: Option<bool>,
     pub x11_forwarding: bool,
     pub agent_forwarding: bool,
     pub compression: bool,
@@ -80,6 +82,7 @@ pub(super) struct UpdateParams<'a> {
     pub resolution: Option<&'a str>,
     pub color_depth: Option<u8>,
     pub disable_nla: bool,
+    pub rdp_security: Option<&'a str>,
     pub keyboard_layout: Option<u32>,
     pub audio_redirect: bool,
     pub printer: bool,
@@ -94,6 +97,8 @@ pub(super) struct UpdateParams<'a> {
     pub vnc_no_scaling: bool,
     pub vnc_no_clipboard: bool,
     pub vnc_custom_arg: &'a [String],
+    pub vnc_repeater: Option<&'a str>,
+    pub vnc_repeater_id: Option<&'a str>,
     // SPICE
     pub spice_tls: bool,
     pub spice_ca_cert: Option<&'a str>,
@@ -504,6 +509,7 @@ pub(super) fn cmd_update(
         || params.resolution.is_some()
         || params.color_depth.is_some()
         || params.disable_nla
+        || params.rdp_security.is_some()
         || params.keyboard_layout.is_some()
         || params.audio_redirect
         || params.printer
@@ -531,6 +537,8 @@ pub(super) fn cmd_update(
         || params.vnc_no_scaling
         || params.vnc_no_clipboard
         || !params.vnc_custom_arg.is_empty()
+        || params.vnc_repeater.is_some()
+        || params.vnc_repeater_id.is_some()
     {
         if let rustconn_core::models::ProtocolConfig::Vnc(ref mut cfg) = connection.protocol_config
         {
@@ -662,16 +670,11 @@ pub(super) fn cmd_update(
     }
 
     for tag in params.add_tag {
-        let trimmed = tag.trim();
-        if !trimmed.is_empty() && !connection.tags.iter().any(|t| t == trimmed) {
-            connection.tags.push(trimmed.to_string());
-        }
+        connection.add_tag(tag);
     }
 
-    if !params.remove_tag.is_empty() {
-        connection
-            .tags
-            .retain(|t| !params.remove_tag.iter().any(|r| r.trim() == t));
+    for tag in params.remove_tag {
+        connection.remove_tag(tag);
     }
 
     if let Some(desc) = params.description {
@@ -709,6 +712,10 @@ pub(super) fn cmd_update(
         connection.skip_port_check = flag;
     }
 
+    if let Some(flag) = params.tailscale_dns {
+        connection.resolve_via_tailscale = flag;
+    }
+
     // Resolve --group: find or create the group, then assign group_id (defer save)
     let group_to_save = if let Some(group_name) = params.group {
         let mut groups = config_manager
@@ -758,12 +765,23 @@ fn apply_rdp_fields_update(
     // Gateway
     if let Some(gw_host) = params.gateway {
         let port = params.gateway_port.unwrap_or(443);
+        // The gateway account's domain and password source have no flags;
+        // keep them while the gateway host stays the same
+        let (domain, password_source) = cfg
+            .gateway
+            .take()
+            .filter(|gw| gw.hostname == gw_host)
+            .map_or((None, PasswordSource::None), |gw| {
+                (gw.domain, gw.password_source)
+            });
         cfg.gateway = Some(RdpGateway {
             hostname: gw_host.to_string(),
             port,
             username: params
                 .gateway_username
                 .map(std::string::ToString::to_string),
+            domain,
+            password_source,
         });
     } else if params.gateway_port.is_some() || params.gateway_username.is_some() {
         // Update existing gateway fields if gateway already set
@@ -812,6 +830,11 @@ fn apply_rdp_fields_update(
         cfg.disable_nla = true;
     }
 
+    // Security protocol
+    if let Some(security) = params.rdp_security {
+        cfg.security_layer = parse_rdp_security(security);
+    }
+
     // Keyboard layout
     if let Some(klid) = params.keyboard_layout {
         cfg.keyboard_layout = Some(klid);
@@ -874,6 +897,24 @@ fn apply_vnc_fields_update(
     for arg in params.vnc_custom_arg {
         cfg.custom_args.push(arg.clone());
     }
+    if params.vnc_repeater.is_some() || params.vnc_repeater_id.is_some() {
+        // Either flag alone changes that part of the current repeater
+        let current = cfg.repeater.as_ref();
+        let address = params
+            .vnc_repeater
+            .map(str::to_string)
+            .or_else(|| current.map(|r| format!("{}:{}", r.host, r.port)));
+        let id = params
+            .vnc_repeater_id
+            .or_else(|| current.map(|r| r.id.as_str()));
+        let (Some(address), Some(id)) = (address, id) else {
+            return Err(CliError::Config(
+                "Connection has no VNC repeater; set both --vnc-repeater and --vnc-repeater-id"
+                    .into(),
+            ));
+        };
+        cfg.repeater = Some(parse_vnc_repeater(&address, id)?);
+    }
     Ok(())
 }
 
@@ -904,7 +945,7 @@ fn apply_spice_fields_update(
         cfg.proxy = Some(proxy.to_string());
     }
     for spec in params.spice_shared_folder {
-        cfg.shared_folders.push(parse_shared_folder(spec)?);
+        cfg.shared_folders.push(parse_spice_shared_folder(spec)?);
     }
     Ok(())
 }
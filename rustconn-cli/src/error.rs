@@ -80,6 +80,18 @@ pub enum CliError {
     #[error("Connection error: {0}")]
     Connection(String),
 
+    /// Password generation error
+    #[error("Password generation error: {0}")]
+    Password(String),
+
+    /// Search error
+    #[error("Search error: {0}")]
+    Search(String),
+
+    /// Terminal theme error
+    #[error("Theme error: {0}")]
+    Theme(String),
+
     /// IO error
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
@@ -128,7 +140,10 @@ impl CliError {
             | Self::SmartFolder(_)
             | Self::DynamicFolder(_)
             | Self::Recording(_)
-            | Self::Protocol(_) => exit_codes::GENERAL_ERROR,
+            | Self::Protocol(_)
+            | Self::Password(_)
+            | Self::Search(_)
+            | Self::Theme(_) => exit_codes::GENERAL_ERROR,
         }
     }
 }
@@ -24,14 +24,14 @@ mod format;
 mod util;
 
 use clap::Parser;
-use cli::Cli;
+use cli::{Cli, LogFormatArg};
 
 fn main() {
     let cli = Cli::parse();
     let config_path = cli.config.as_deref();
 
     color::init(cli.no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()));
-    setup_logging(cli.verbose, cli.quiet);
+    setup_logging(cli.verbose, cli.quiet, cli.log_format);
 
     let result = commands::dispatch(config_path, cli.command);
 
@@ -44,8 +44,12 @@ fn main() {
     }
 }
 
-/// Initializes `tracing-subscriber` with a level derived from `--verbose` / `--quiet`.
-fn setup_logging(verbose: u8, quiet: bool) {
+/// Initializes `tracing-subscriber` with a level derived from `--verbose` / `--quiet`
+/// and the line format from `--log-format`.
+fn setup_logging(verbose: u8, quiet: bool, format: LogFormatArg) {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
     let filter = match (quiet, verbose) {
         (true, _) => "error",
         (_, 0) => "warn",
@@ -53,12 +57,24 @@ fn setup_logging(verbose: u8, quiet: bool) {
         (_, 2) => "debug",
         _ => "trace",
     };
-    let _ = tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(filter)),
-        )
-        .with_writer(std::io::stderr)
-        .without_time()
-        .try_init();
+    let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(filter));
+
+    // The two builders return different error types; a second init is the
+    // only failure and is harmless, so both are ignored.
+    match format {
+        LogFormatArg::Text => {
+            let _ = tracing_subscriber::fmt()
+                .with_env_filter(env_filter)
+                .with_writer(std::io::stderr)
+                .without_time()
+                .try_init();
+        }
+        LogFormatArg::Json => {
+            let _ = tracing_subscriber::registry()
+                .with(env_filter)
+                .with(rustconn_core::tracing::json_layer(std::io::stderr))
+                .try_init();
+        }
+    }
 }
@@ -195,10 +195,276 @@ fn test_list_csv_format() {
     }
 }
 
+#[test]
+fn test_list_tag_filters() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+    for (name, tags) in [
+        ("payments", "PCI,on-call"),
+        ("ftp", "legacy,pci"),
+        ("wiki", "docs"),
+    ] {
+        let output = run_cli(
+            &[
+                "add",
+                "--name",
+                name,
+                "--host",
+                &format!("{name}.example.com"),
+                "--tags",
+                tags,
+            ],
+            Some(temp_dir.path()),
+        );
+        assert!(output.status.success(), "Add should succeed");
+    }
+
+    let names = |args: &[&str]| {
+        let mut full = vec!["list", "--format", "csv"];
+        full.extend_from_slice(args);
+        let output = run_cli(&full, Some(temp_dir.path()));
+        assert!(output.status.success(), "List should succeed");
+        let mut names: Vec<String> = stdout_str(&output)
+            .lines()
+            .skip(1)
+            .filter(|line| !line.is_empty())
+            .filter_map(|line| line.split(',').next())
+            .map(str::to_string)
+            .collect();
+        names.sort();
+        names
+    };
+
+    assert_eq!(names(&["--tag", "pci"]), ["ftp", "payments"]);
+    assert_eq!(names(&["--tag", "pci", "--tag", "On-Call"]), ["payments"]);
+    assert_eq!(names(&["-t", "pci,legacy"]), ["ftp"]);
+    assert!(names(&["--tag", "decommissioned"]).is_empty());
+}
+
+#[test]
+fn test_search_save_run_and_list() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+    for (name, protocol, tags) in [
+        ("pager", "ssh", "on-call"),
+        ("desk", "rdp", "on-call"),
+        ("wiki", "ssh", "docs"),
+    ] {
+        let output = run_cli(
+            &[
+                "add",
+                "--name",
+                name,
+                "--host",
+                &format!("{name}.example.com"),
+                "--protocol",
+                protocol,
+                "--tags",
+                tags,
+                "--group",
+                "Prod",
+            ],
+            Some(temp_dir.path()),
+        );
+        assert!(output.status.success(), "Add should succeed");
+    }
+
+    let names = |args: &[&str]| {
+        let mut full = vec!["search", "--format", "csv"];
+        full.extend_from_slice(args);
+        let output = run_cli(&full, Some(temp_dir.path()));
+        assert!(
+            output.status.success(),
+            "Search should succeed: {}",
+            stderr_str(&output)
+        );
+        stdout_str(&output)
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.split(',').next())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    let query = "group:Prod protocol:ssh tag:on-call";
+    assert_eq!(names(&[query, "--save", "on-call"]), ["pager"]);
+    // Saved searches persist in the settings file and are found by name
+    // in any case
+    assert_eq!(names(&["--run", "ON-CALL"]), ["pager"]);
+
+    let output = run_cli(
+        &["search", "--list-searches", "--format", "csv"],
+        Some(temp_dir.path()),
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        stdout_str(&output).trim(),
+        format!("name,mode,query\non-call,fuzzy,{query}")
+    );
+
+    let output = run_cli(&["search", "--run", "missing"], Some(temp_dir.path()));
+    assert!(!output.status.success());
+    assert!(stderr_str(&output).contains("No saved search named 'missing'"));
+
+    let output = run_cli(&["search", "--delete", "on-call"], Some(temp_dir.path()));
+    assert!(output.status.success());
+    let output = run_cli(&["search", "--run", "on-call"], Some(temp_dir.path()));
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_search_interactive_collapses_rapid_input() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    for name in ["web-01", "web-02", "db-01"] {
+        let output = run_cli(
+            &["add", "--name", name, "--host", &format!("{name}.lan")],
+            Some(temp_dir.path()),
+        );
+        assert!(output.status.success(), "Add should succeed");
+    }
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rustconn-cli"))
+        .env("RUSTCONN_CONFIG_DIR", temp_dir.path())
+        .args([
+            "search",
+            "--interactive",
+            "--mode",
+            "substring",
+            "--limit",
+            "1",
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute CLI");
+    // Typed faster than the search delay, so only the last query runs
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(b"w\nwe\nweb\n")
+        .expect("Failed to write queries");
+    let output = child.wait_with_output().expect("Failed to wait for CLI");
+
+    assert!(output.status.success(), "{}", stderr_str(&output));
+    let stdout = stdout_str(&output);
+    assert_eq!(stdout.matches("Results for").count(), 1, "{stdout}");
+    assert!(stdout.contains("Results for \"web\" (2 matches)"));
+    assert!(stdout.contains("[web]-0"));
+    assert!(stdout.contains("1 more"));
+    assert!(!stdout.contains("db-01"));
+}
+
+#[test]
+fn test_forward_profile_create_attach_and_replace() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let run = |args: &[&str]| {
+        let output = run_cli(args, Some(temp_dir.path()));
+        assert!(
+            output.status.success(),
+            "{args:?} should succeed: {}",
+            stderr_str(&output)
+        );
+        stdout_str(&output)
+    };
+
+    run(&["add", "--name", "bastion", "--host", "bastion.lan"]);
+    run(&[
+        "forward-profile",
+        "create",
+        "--name",
+        "db-tunnels",
+        "--local-forward",
+        "5432:db:5432",
+        "--remote-forward",
+        "9000:localhost:9000",
+        "--dynamic-forward",
+        "1080",
+    ]);
+    run(&["forward-profile", "attach", "db-tunnels", "bastion"]);
+
+    let list = run(&["forward-profile", "list", "--format", "csv"]);
+    assert!(list.contains("db-tunnels,-L 5432:db:5432; -R 9000:localhost:9000; -D 1080"));
+
+    let output = run_cli(
+        &["forward-profile", "create", "--name", "DB-Tunnels"],
+        Some(temp_dir.path()),
+    );
+    assert!(!output.status.success());
+    assert!(stderr_str(&output).contains("--replace"));
+
+    // Replacing keeps the ID, so the attached connection follows the profile
+    run(&[
+        "forward-profile",
+        "create",
+        "--name",
+        "db-tunnels",
+        "--local-forward",
+        "6432:db:5432",
+        "--replace",
+    ]);
+    let json = run(&["forward-profile", "list", "--format", "json"]);
+    let profiles: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+    assert_eq!(profiles.as_array().map(Vec::len), Some(1));
+    assert_eq!(profiles[0]["forwards"][0]["local_port"], 6432);
+
+    let connections = std::fs::read_to_string(temp_dir.path().join("connections.toml"))
+        .expect("connections.toml");
+    assert!(connections.contains(profiles[0]["id"].as_str().expect("profile id")));
+
+    run(&["forward-profile", "delete", "db-tunnels"]);
+    let connections = std::fs::read_to_string(temp_dir.path().join("connections.toml"))
+        .expect("connections.toml");
+    assert!(!connections.contains("port_forward_profiles"));
+}
+
 // ============================================================================
 // Feature-gated command tests
 // ============================================================================
 
+#[cfg(feature = "client-launch")]
+#[test]
+fn test_connect_dry_run_expands_forward_profiles() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    for args in [
+        &[
+            "add",
+            "--name",
+            "bastion",
+            "--host",
+            "bastion.lan",
+            "--local-forward",
+            "8080:web:80",
+        ][..],
+        &[
+            "forward-profile",
+            "create",
+            "--name",
+            "k8s",
+            "--local-forward",
+            "8443:dashboard:443",
+            "--dynamic-forward",
+            "1080",
+        ],
+        &["forward-profile", "attach", "k8s", "bastion"],
+    ] {
+        let output = run_cli(args, Some(temp_dir.path()));
+        assert!(output.status.success(), "{}", stderr_str(&output));
+    }
+
+    let output = run_cli(&["connect", "bastion", "--dry-run"], Some(temp_dir.path()));
+    assert!(output.status.success(), "{}", stderr_str(&output));
+    assert!(
+        stdout_str(&output).contains("-L 8080:web:80 -L 8443:dashboard:443 -D 1080"),
+        "{}",
+        stdout_str(&output)
+    );
+}
+
 #[cfg(feature = "client-launch")]
 #[test]
 fn test_connect_present_in_help() {
@@ -309,6 +575,53 @@ fn test_import_nonexistent_file() {
     );
 }
 
+#[test]
+fn test_import_dry_run_saves_nothing() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let output = run_cli(
+        &["add", "--name", "existing", "--host", "web.example.com"],
+        Some(temp_dir.path()),
+    );
+    assert!(output.status.success(), "Add should succeed");
+
+    let ssh_config = temp_dir.path().join("ssh_config");
+    std::fs::write(
+        &ssh_config,
+        "Host web\n    HostName web.example.com\n\nHost db\n    HostName db.example.com\n",
+    )
+    .expect("Failed to write ssh config");
+
+    let output = run_cli(
+        &[
+            "import",
+            "--format",
+            "ssh-config",
+            "--dry-run",
+            ssh_config.to_str().unwrap(),
+        ],
+        Some(temp_dir.path()),
+    );
+    assert!(output.status.success(), "Dry run should succeed");
+    let stdout = stdout_str(&output);
+    assert!(stdout.contains("[dry-run]"), "Got: {stdout}");
+    assert!(
+        stdout.contains("New: 1, Update: 0, Skip: 1"),
+        "Got: {stdout}"
+    );
+    assert!(
+        stdout.contains("web (ssh://web.example.com:22) (duplicate, skip)"),
+        "Got: {stdout}"
+    );
+
+    let output = run_cli(&["list", "--format", "csv"], Some(temp_dir.path()));
+    let stdout = stdout_str(&output);
+    assert!(stdout.contains("existing"), "Got: {stdout}");
+    assert!(
+        !stdout.contains("db"),
+        "Dry run must not save. Got: {stdout}"
+    );
+}
+
 #[test]
 fn test_export_invalid_format() {
     let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -411,6 +724,250 @@ fn test_test_nonexistent_connection() {
     );
 }
 
+// ============================================================================
+// Password Command Tests
+// ============================================================================
+
+#[test]
+fn test_password_passphrase() {
+    let output = run_cli(
+        &[
+            "password",
+            "--passphrase",
+            "--words",
+            "5",
+            "--separator",
+            ".",
+        ],
+        None,
+    );
+
+    assert!(
+        output.status.success(),
+        "Passphrase generation should succeed"
+    );
+
+    let stdout = stdout_str(&output);
+    let passphrase = stdout.trim();
+    assert_eq!(
+        passphrase.split('.').count(),
+        5,
+        "Passphrase should have 5 words. Got: {}",
+        passphrase
+    );
+    assert!(
+        stderr_str(&output).contains("bits"),
+        "Entropy estimate should be printed to stderr"
+    );
+}
+
+#[test]
+fn test_password_pronounceable() {
+    let output = run_cli(
+        &[
+            "password",
+            "--pronounceable",
+            "--length",
+            "11",
+            "--no-special",
+        ],
+        None,
+    );
+
+    assert!(
+        output.status.success(),
+        "Pronounceable generation should succeed"
+    );
+    let stdout = stdout_str(&output);
+    let password = stdout.trim();
+    assert_eq!(password.len(), 11, "Got: {}", password);
+    let letters: Vec<char> = password.chars().filter(char::is_ascii_alphabetic).collect();
+    assert!(
+        letters
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .all(|c| "aeiou".contains(*c)),
+        "Every second letter should be a vowel. Got: {}",
+        password
+    );
+
+    let output = run_cli(&["password", "--pronounceable", "--passphrase"], None);
+    assert!(
+        !output.status.success(),
+        "--pronounceable and --passphrase should conflict"
+    );
+}
+
+#[test]
+fn test_password_words_requires_passphrase() {
+    let output = run_cli(&["password", "--words", "5"], None);
+
+    assert!(
+        !output.status.success(),
+        "--words without --passphrase should be rejected"
+    );
+}
+
+// ============================================================================
+// Theme Tests
+// ============================================================================
+
+#[test]
+fn test_theme_import_malformed_file() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let scheme = temp_dir.path().join("Broken.itermcolors");
+    std::fs::write(&scheme, "<plist><dict><key>Ansi 0 Color</key>").unwrap();
+
+    let output = run_cli(
+        &["theme", "import", scheme.to_str().unwrap()],
+        Some(temp_dir.path()),
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = stderr_str(&output);
+    assert!(
+        stderr.contains("malformed plist"),
+        "Should describe the parse error. Got: {stderr}"
+    );
+}
+
+#[test]
+fn test_theme_list_includes_builtins() {
+    let output = run_cli(&["theme", "list", "--format", "csv"], None);
+
+    assert!(output.status.success());
+    let stdout = stdout_str(&output);
+    assert!(stdout.starts_with("name,kind,background,foreground"));
+    assert!(stdout.contains("Dracula,built-in,"));
+}
+
+// ============================================================================
+// Snippet Tests
+// ============================================================================
+
+#[test]
+fn test_snippet_run_prompt_variables() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+    let output = run_cli(
+        &[
+            "snippet",
+            "add",
+            "--name",
+            "Close ticket",
+            "--command",
+            "ticket close ${ticket_id} --env ${env} --token ${token}",
+            "--choice",
+            "env=staging,prod",
+            "--secret",
+            "token",
+        ],
+        Some(temp_dir.path()),
+    );
+    assert!(output.status.success(), "{}", stderr_str(&output));
+
+    // Without a terminal nothing is prompted, so missing values fail
+    let output = run_cli(
+        &[
+            "snippet",
+            "run",
+            "Close ticket",
+            "--var",
+            "ticket_id=OPS-42",
+        ],
+        Some(temp_dir.path()),
+    );
+    assert!(!output.status.success());
+    let stderr = stderr_str(&output);
+    assert!(
+        stderr.contains("env") && stderr.contains("token"),
+        "{stderr}"
+    );
+
+    let output = run_cli(
+        &[
+            "snippet",
+            "run",
+            "Close ticket",
+            "--var",
+            "ticket_id=OPS-42",
+            "--var",
+            "env=qa",
+            "--var",
+            "token=s3cret",
+        ],
+        Some(temp_dir.path()),
+    );
+    assert!(!output.status.success(), "qa is not an allowed choice");
+    assert!(stderr_str(&output).contains("staging, prod"));
+
+    let output = run_cli(
+        &[
+            "snippet",
+            "run",
+            "Close ticket",
+            "--var",
+            "ticket_id=OPS-42",
+            "--var",
+            "env=prod",
+            "--var",
+            "token=s3cret",
+        ],
+        Some(temp_dir.path()),
+    );
+    assert!(output.status.success(), "{}", stderr_str(&output));
+    assert_eq!(
+        stdout_str(&output).trim(),
+        "ticket close OPS-42 --env prod --token s3cret"
+    );
+}
+
+// ============================================================================
+// Diagnostics Tests
+// ============================================================================
+
+#[test]
+fn test_diagnostics_memory_json() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+    for name in ["web", "db"] {
+        let output = run_cli(
+            &[
+                "add",
+                "--name",
+                name,
+                "--host",
+                &format!("{name}.example.com"),
+                "--user",
+                "admin",
+            ],
+            Some(temp_dir.path()),
+        );
+        assert!(output.status.success(), "{}", stderr_str(&output));
+    }
+
+    let output = run_cli(&["diagnostics", "memory", "--json"], Some(temp_dir.path()));
+    assert!(output.status.success(), "{}", stderr_str(&output));
+
+    let report: serde_json::Value =
+        serde_json::from_str(&stdout_str(&output)).expect("Output should be JSON");
+    let breakdown = &report["breakdown"];
+    assert_eq!(breakdown["connections"]["count"], 2);
+    assert!(breakdown["connections"]["bytes"].as_u64().unwrap() > 0);
+    assert!(breakdown["total"]["count"].as_u64().unwrap() >= 2);
+    assert!(breakdown["total"]["bytes"].as_u64().unwrap() > 0);
+
+    // Protocol and username repeat across both connections
+    let interning = &report["interning"];
+    assert!(interning["intern_requests"].as_u64().unwrap() >= 6);
+    assert!(interning["hits"].as_u64().unwrap() >= 2);
+
+    let output = run_cli(&["diagnostics", "memory"], Some(temp_dir.path()));
+    assert!(output.status.success(), "{}", stderr_str(&output));
+    assert!(stdout_str(&output).contains("String Interning"));
+}
+
 // ============================================================================
 // Version Test
 // ============================================================================
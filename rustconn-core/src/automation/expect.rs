@@ -5,15 +5,42 @@
 //! - Regex pattern matching against terminal output
 //! - Automatic response sending when patterns match
 //! - Priority-based rule ordering
-//! - Timeout handling for patterns
-
+//! - Timeout handling for patterns, and an engine-wide timeout with an
+//!   optional fallback response
+//! - Chained rules that only become active once the previous prompt was
+//!   answered
+//! - Secret responses (e.g. `sudo` passwords) resolved only when the prompt
+//!   appears, with a bounded retry loop
+//!
+//! # Secret responses
+//!
+//! A rule with a [`SecretResponse`] never carries the secret itself: it names
+//! a secret global variable, and the engine reports a
+//! [`SecretPromptEvent::Send`] when the prompt shows up in new output. The
+//! caller then resolves the variable, sends [`SecretResponse::payload`], and
+//! drops it, which zeroizes the buffer. Secret rules are skipped by
+//! [`ExpectEngine::match_output`] and [`ExpectEngine::match_line`], so they
+//! can only be answered through [`ExpectEngine::process_secret_output`].
+//!
+//! # Timeouts and chaining
+//!
+//! [`ExpectEngine::with_timeout`] bounds how long the engine waits for any
+//! rule to match; [`ExpectEngine::expect`] then reports
+//! [`ExpectOutcome::TimedOut`] with the fallback response instead of waiting
+//! forever. A rule built with [`ExpectRule::then_expect`] adds its next rule
+//! to the engine when it fires, so a login sequence can be written as one
+//! chain of "send this, then expect that".
+
+use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use regex::Regex;
+use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
+use zeroize::Zeroizing;
 
 use crate::variables::{VariableManager, VariableScope};
 
@@ -53,6 +80,130 @@ pub enum ExpectError {
 /// Result type for expect operations
 pub type ExpectResult<T> = std::result::Result<T, ExpectError>;
 
+/// Default pattern for `sudo` password prompts
+pub const DEFAULT_SUDO_PROMPT_PATTERN: &str =
+    r"^\[sudo\] password for [^:]+:\s*$|^[Pp]assword( for [^:]+)?:\s*$";
+
+/// Default pattern for output reporting a rejected password
+pub const DEFAULT_SECRET_FAILURE_PATTERN: &str =
+    r"(?i)sorry, try again|incorrect password|authentication failure";
+
+/// Default pattern for one-time-password (2FA) prompts
+///
+/// Matches `Verification code:`, `One-time password (OATH) for 'alice':`,
+/// `OTP:` and similar keyboard-interactive prompts.
+pub const DEFAULT_OTP_PROMPT_PATTERN: &str = r"(?i)^(verification code|one[- ]time (password|code)|otp( code)?|token( code)?|2fa code|authenticator code)\b[^:]*:\s*$";
+
+/// Default pattern for output reporting a rejected one-time password
+pub const DEFAULT_OTP_FAILURE_PATTERN: &str = r"(?i)invalid (verification )?code|incorrect (code|otp)|authentication failure|permission denied";
+
+/// Default number of times a secret is sent before the rule gives up
+pub const DEFAULT_SECRET_ATTEMPTS: u8 = 3;
+
+/// A response taken from a secret instead of the rule itself
+///
+/// The secret is looked up only when the prompt is seen. After
+/// `max_attempts` consecutive rejections the rule is removed, so a wrong
+/// stored password cannot lock the account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecretResponse {
+    /// Name of the secret global variable holding the response
+    pub variable: String,
+    /// Regex matching output that reports the secret was rejected
+    #[serde(default = "default_failure_pattern")]
+    pub failure_pattern: String,
+    /// Maximum consecutive sends before giving up
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u8,
+    /// Ask the user for the response when the variable has no value
+    #[serde(default)]
+    pub prompt: bool,
+}
+
+fn default_failure_pattern() -> String {
+    DEFAULT_SECRET_FAILURE_PATTERN.to_string()
+}
+
+const fn default_max_attempts() -> u8 {
+    DEFAULT_SECRET_ATTEMPTS
+}
+
+impl SecretResponse {
+    /// Creates a secret response read from the named secret variable
+    #[must_use]
+    pub fn new(variable: impl Into<String>) -> Self {
+        Self {
+            variable: variable.into(),
+            failure_pattern: default_failure_pattern(),
+            max_attempts: DEFAULT_SECRET_ATTEMPTS,
+            prompt: false,
+        }
+    }
+
+    /// Sets the pattern for rejected-secret output
+    #[must_use]
+    pub fn with_failure_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.failure_pattern = pattern.into();
+        self
+    }
+
+    /// Sets the maximum number of consecutive sends
+    #[must_use]
+    pub const fn with_max_attempts(mut self, max_attempts: u8) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets whether the user is asked when the variable has no value
+    #[must_use]
+    pub const fn with_prompt(mut self, prompt: bool) -> Self {
+        self.prompt = prompt;
+        self
+    }
+
+    /// Returns the bytes to send for a resolved secret: the secret and a newline
+    ///
+    /// The buffer is zeroized when dropped.
+    #[must_use]
+    pub fn payload(secret: &SecretString) -> Zeroizing<Vec<u8>> {
+        let secret = secret.expose_secret().as_bytes();
+        let mut bytes = Zeroizing::new(Vec::with_capacity(secret.len() + 1));
+        bytes.extend_from_slice(secret);
+        bytes.push(b'\n');
+        bytes
+    }
+}
+
+/// An action requested by a secret rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretPromptEvent {
+    /// The prompt appeared: resolve the variable and send its payload now
+    Send {
+        /// The matching rule
+        rule_id: Uuid,
+        /// Secret variable to resolve
+        variable: String,
+        /// 1-based attempt number
+        attempt: u8,
+    },
+    /// The secret was rejected `attempts` times; the rule has been removed
+    Exhausted {
+        /// The removed rule
+        rule_id: Uuid,
+        /// Number of rejected sends
+        attempts: u8,
+    },
+}
+
+/// Per-rule progress of a secret rule
+#[derive(Debug, Clone, Copy, Default)]
+struct SecretState {
+    /// Consecutive rejected sends
+    failures: u8,
+    /// A send is waiting for the outcome
+    pending: bool,
+}
+
 /// An expect rule with pattern and response
 ///
 /// Expect rules define patterns to match against terminal output and
@@ -72,8 +223,16 @@ pub struct ExpectRule {
     /// Whether this rule is enabled
     pub enabled: bool,
     /// Whether this rule should only fire once (default: true)
+    ///
+    /// A secret rule is retired once its secret is accepted.
     #[serde(default = "default_one_shot")]
     pub one_shot: bool,
+    /// Send a secret instead of `response`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<SecretResponse>,
+    /// Rule added to the engine once this rule fires
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<ExpectRule>>,
 }
 
 /// Default value for `one_shot` — true for backward compatibility
@@ -98,6 +257,8 @@ impl ExpectRule {
             timeout_ms: None,
             enabled: true,
             one_shot: true,
+            secret: None,
+            next: None,
         }
     }
 
@@ -112,9 +273,50 @@ impl ExpectRule {
             timeout_ms: None,
             enabled: true,
             one_shot: true,
+            secret: None,
+            next: None,
         }
     }
 
+    /// Creates a rule answering `pattern` with a secret variable
+    #[must_use]
+    pub fn with_secret(pattern: impl Into<String>, secret: SecretResponse) -> Self {
+        let mut rule = Self::new(pattern, String::new());
+        rule.secret = Some(secret);
+        rule
+    }
+
+    /// Creates a rule answering `sudo` password prompts with a secret variable
+    ///
+    /// The rule stays active for later prompts in the same session.
+    #[must_use]
+    pub fn sudo(variable: impl Into<String>) -> Self {
+        Self::with_secret(DEFAULT_SUDO_PROMPT_PATTERN, SecretResponse::new(variable))
+            .with_one_shot(false)
+    }
+
+    /// Creates a rule answering one-time-password prompts during login
+    ///
+    /// The code is read from the secret variable, or asked from the user
+    /// when the variable is empty or undefined. The rule is retired once a
+    /// code is accepted.
+    #[must_use]
+    pub fn otp(variable: impl Into<String>) -> Self {
+        Self::with_secret(
+            DEFAULT_OTP_PROMPT_PATTERN,
+            SecretResponse::new(variable)
+                .with_failure_pattern(DEFAULT_OTP_FAILURE_PATTERN)
+                .with_prompt(true),
+        )
+        .with_priority(100)
+    }
+
+    /// Returns `true` if this rule sends a secret
+    #[must_use]
+    pub const fn is_secret(&self) -> bool {
+        self.secret.is_some()
+    }
+
     /// Sets the priority for this rule
     #[must_use]
     pub const fn with_priority(mut self, priority: i32) -> Self {
@@ -143,17 +345,28 @@ impl ExpectRule {
         self
     }
 
-    /// Validates the regex pattern
+    /// Chains `next` after this rule
+    ///
+    /// `next` is added to the engine when this rule fires, so it only
+    /// matches output that follows this rule's response. Chaining applies to
+    /// rules answered through [`ExpectEngine::expect`] or
+    /// [`ExpectEngine::rule_fired`].
+    #[must_use]
+    pub fn then_expect(mut self, next: Self) -> Self {
+        self.next = Some(Box::new(next));
+        self
+    }
+
+    /// Validates the regex pattern, the failure pattern of a secret rule, and
+    /// the patterns of chained rules
     ///
     /// # Errors
     ///
-    /// Returns `ExpectError::PatternCompilationFailed` if the pattern is invalid.
+    /// Returns `ExpectError::PatternCompilationFailed` if a pattern is invalid.
     pub fn validate_pattern(&self) -> ExpectResult<()> {
-        Regex::new(&self.pattern).map_err(|e| ExpectError::PatternCompilationFailed {
-            pattern: self.pattern.clone(),
-            reason: e.to_string(),
-        })?;
-        Ok(())
+        self.compile_pattern()?;
+        self.compile_failure_pattern()?;
+        self.next.as_deref().map_or(Ok(()), Self::validate_pattern)
     }
 
     /// Compiles the pattern into a Regex
@@ -167,6 +380,25 @@ impl ExpectRule {
             reason: e.to_string(),
         })
     }
+
+    /// Compiles the failure pattern of a secret rule
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExpectError::PatternCompilationFailed` if the pattern is invalid.
+    pub fn compile_failure_pattern(&self) -> ExpectResult<Option<Regex>> {
+        self.secret
+            .as_ref()
+            .map(|secret| {
+                Regex::new(&secret.failure_pattern).map_err(|e| {
+                    ExpectError::PatternCompilationFailed {
+                        pattern: secret.failure_pattern.clone(),
+                        reason: e.to_string(),
+                    }
+                })
+            })
+            .transpose()
+    }
 }
 
 impl PartialEq for ExpectRule {
@@ -178,6 +410,8 @@ impl PartialEq for ExpectRule {
             && self.timeout_ms == other.timeout_ms
             && self.enabled == other.enabled
             && self.one_shot == other.one_shot
+            && self.secret == other.secret
+            && self.next == other.next
     }
 }
 
@@ -190,6 +424,8 @@ pub struct CompiledRule {
     pub rule: ExpectRule,
     /// The compiled regex pattern
     pub regex: Regex,
+    /// The compiled failure pattern of a secret rule
+    pub failure_regex: Option<Regex>,
 }
 
 impl CompiledRule {
@@ -197,10 +433,19 @@ impl CompiledRule {
     ///
     /// # Errors
     ///
-    /// Returns an error if the pattern fails to compile.
+    /// Returns an error if the pattern, or the pattern of a chained rule,
+    /// fails to compile.
     pub fn new(rule: ExpectRule) -> ExpectResult<Self> {
         let regex = rule.compile_pattern()?;
-        Ok(Self { rule, regex })
+        let failure_regex = rule.compile_failure_pattern()?;
+        if let Some(next) = &rule.next {
+            next.validate_pattern()?;
+        }
+        Ok(Self {
+            rule,
+            regex,
+            failure_regex,
+        })
     }
 
     /// Checks if the output matches this rule's pattern
@@ -216,6 +461,25 @@ impl CompiledRule {
     }
 }
 
+/// What [`ExpectEngine::expect`] found in new output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpectOutcome {
+    /// A rule matched; `response` should be sent
+    Matched {
+        /// ID of the rule that matched
+        rule_id: Uuid,
+        /// Response of the rule, before escape processing
+        response: String,
+    },
+    /// No rule matched within the engine timeout
+    TimedOut {
+        /// Fallback response to send, if one is configured
+        fallback: Option<String>,
+    },
+    /// Nothing matched yet and the timeout has not elapsed
+    Pending,
+}
+
 /// Expect engine for pattern matching
 ///
 /// The expect engine manages a collection of expect rules and matches
@@ -224,6 +488,16 @@ impl CompiledRule {
 pub struct ExpectEngine {
     /// Compiled rules sorted by priority (highest first)
     rules: Vec<CompiledRule>,
+    /// Progress of secret rules, by rule ID
+    secret_state: HashMap<Uuid, SecretState>,
+    /// How long to wait for any rule to match
+    timeout: Option<Duration>,
+    /// Response reported when `timeout` elapses
+    fallback_response: Option<String>,
+    /// Start of the current wait, set on the first check after a match
+    window_start: Option<Instant>,
+    /// Whether the timeout already fired since the last match
+    timed_out: bool,
 }
 
 impl ExpectEngine {
@@ -246,6 +520,19 @@ impl ExpectEngine {
         Ok(engine)
     }
 
+    /// Sets how long the engine waits for any rule to match
+    ///
+    /// When no rule matches within `timeout` of the last match (or of the
+    /// first call to [`Self::expect`]), the engine reports
+    /// [`ExpectOutcome::TimedOut`] carrying `fallback` once, then waits for
+    /// the next match before the timeout can fire again.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration, fallback: Option<String>) -> Self {
+        self.timeout = Some(timeout);
+        self.fallback_response = fallback;
+        self
+    }
+
     /// Adds a rule to the engine
     ///
     /// # Errors
@@ -275,6 +562,7 @@ impl ExpectEngine {
             .iter()
             .position(|r| r.rule.id == id)
             .ok_or(ExpectError::RuleNotFound(id))?;
+        self.secret_state.remove(&id);
         Ok(self.rules.remove(pos).rule)
     }
 
@@ -342,12 +630,13 @@ impl ExpectEngine {
     /// Matches output against all enabled rules, returning the highest priority match
     ///
     /// Rules are checked in priority order (highest first). The first matching
-    /// enabled rule is returned.
+    /// enabled rule is returned. Secret rules are not considered; see
+    /// [`Self::process_secret_output`].
     #[must_use]
     pub fn match_output(&self, output: &str) -> Option<&ExpectRule> {
         self.rules
             .iter()
-            .filter(|r| r.rule.enabled)
+            .filter(|r| r.rule.enabled && !r.rule.is_secret())
             .find(|r| r.matches(output))
             .map(|r| &r.rule)
     }
@@ -391,21 +680,86 @@ impl ExpectEngine {
     ///
     /// Unlike `match_output`, this method also tries matching against the trimmed version
     /// of the line, which is useful for terminal output that may have leading/trailing whitespace.
+    /// Secret rules are not considered.
     #[must_use]
     pub fn match_line(&self, line: &str) -> Option<&CompiledRule> {
         let trimmed = line.trim();
         self.rules
             .iter()
-            .filter(|r| r.rule.enabled)
+            .filter(|r| r.rule.enabled && !r.rule.is_secret())
             .find(|r| r.matches(line) || r.matches(trimmed))
     }
 
+    /// Matches new output against the enabled rules and checks the timeout
+    ///
+    /// Lines are tried in order; the first line with a match wins, and the
+    /// matching rule is handled as in [`Self::rule_fired`]. Without a match,
+    /// this returns the result of [`Self::check_timeout`]. Secret rules are
+    /// not considered.
+    pub fn expect(&mut self, output: &str, now: Instant) -> ExpectOutcome {
+        let matched = output
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .find_map(|line| self.match_line(line))
+            .map(|compiled| (compiled.rule.id, compiled.rule.response.clone()));
+        let Some((rule_id, response)) = matched else {
+            return self.check_timeout(now);
+        };
+        self.rule_fired(rule_id, now);
+        ExpectOutcome::Matched { rule_id, response }
+    }
+
+    /// Reports [`ExpectOutcome::TimedOut`] if the engine timeout elapsed
+    ///
+    /// Returns [`ExpectOutcome::Pending`] when no timeout is set, while the
+    /// window is still open, and after the timeout fired until the next
+    /// match restarts the window.
+    pub fn check_timeout(&mut self, now: Instant) -> ExpectOutcome {
+        let Some(timeout) = self.timeout else {
+            return ExpectOutcome::Pending;
+        };
+        if self.timed_out {
+            return ExpectOutcome::Pending;
+        }
+        let start = *self.window_start.get_or_insert(now);
+        if now.duration_since(start) < timeout {
+            return ExpectOutcome::Pending;
+        }
+        self.timed_out = true;
+        self.window_start = None;
+        ExpectOutcome::TimedOut {
+            fallback: self.fallback_response.clone(),
+        }
+    }
+
+    /// Records that the rule `id` fired at `now`
+    ///
+    /// Removes the rule if it is one-shot, adds its chained
+    /// [`ExpectRule::next`] rule, and restarts the timeout window. Returns
+    /// the ID of the added rule; a chained rule that is already present is
+    /// not added again.
+    pub fn rule_fired(&mut self, id: Uuid, now: Instant) -> Option<Uuid> {
+        self.window_start = Some(now);
+        self.timed_out = false;
+
+        let rule = &self.rules.iter().find(|r| r.rule.id == id)?.rule;
+        let one_shot = rule.one_shot;
+        let next = rule.next.clone();
+        if one_shot {
+            self.remove_by_id(id);
+        }
+        let next = *next?;
+        let next_id = next.id;
+        self.add_rule(next).ok().map(|()| next_id)
+    }
+
     /// Removes a rule by ID without returning an error if not found
     ///
     /// Returns `true` if the rule was removed, `false` if not found.
     pub fn remove_by_id(&mut self, id: Uuid) -> bool {
         if let Some(pos) = self.rules.iter().position(|r| r.rule.id == id) {
             self.rules.remove(pos);
+            self.secret_state.remove(&id);
             true
         } else {
             false
@@ -453,12 +807,124 @@ impl ExpectEngine {
         before - self.rules.len()
     }
 
+    /// Returns `true` if any enabled rule sends a secret
+    #[must_use]
+    pub fn has_secret_rules(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|r| r.rule.enabled && r.rule.is_secret())
+    }
+
+    /// Feeds new terminal output to the secret rules
+    ///
+    /// `output` must contain only output not seen before (see
+    /// [`screen_delta`]), otherwise a prompt that was already answered is
+    /// answered again. Each non-empty line is checked against the enabled
+    /// secret rules in priority order:
+    ///
+    /// - a failure-pattern match marks the pending send as rejected;
+    /// - a prompt match yields [`SecretPromptEvent::Send`], or
+    ///   [`SecretPromptEvent::Exhausted`] (and removes the rule) once
+    ///   `max_attempts` consecutive sends were rejected. A prompt arriving
+    ///   while a send is still pending also counts as a rejection;
+    /// - any other line after a send means the secret was accepted, which
+    ///   resets the attempt count and retires one-shot rules.
+    pub fn process_secret_output(&mut self, output: &str) -> Vec<SecretPromptEvent> {
+        let mut events = Vec::new();
+        for line in output.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut retired = Vec::new();
+            for compiled in self.rules.iter().filter(|r| r.rule.enabled) {
+                let Some(secret) = &compiled.rule.secret else {
+                    continue;
+                };
+                let id = compiled.rule.id;
+                let state = self.secret_state.entry(id).or_default();
+                if compiled
+                    .failure_regex
+                    .as_ref()
+                    .is_some_and(|r| r.is_match(line))
+                {
+                    if state.pending {
+                        state.pending = false;
+                        state.failures = state.failures.saturating_add(1);
+                    }
+                    break;
+                }
+                if compiled.regex.is_match(line) {
+                    if state.pending {
+                        state.failures = state.failures.saturating_add(1);
+                    }
+                    if state.failures >= secret.max_attempts {
+                        events.push(SecretPromptEvent::Exhausted {
+                            rule_id: id,
+                            attempts: state.failures,
+                        });
+                        retired.push(id);
+                    } else {
+                        state.pending = true;
+                        events.push(SecretPromptEvent::Send {
+                            rule_id: id,
+                            variable: secret.variable.clone(),
+                            attempt: state.failures + 1,
+                        });
+                    }
+                    break;
+                }
+                if state.pending {
+                    *state = SecretState::default();
+                    if compiled.rule.one_shot {
+                        retired.push(id);
+                    }
+                }
+            }
+            for id in retired {
+                self.remove_by_id(id);
+            }
+        }
+        events
+    }
+
     /// Clears all rules from the engine
     pub fn clear(&mut self) {
         self.rules.clear();
+        self.secret_state.clear();
     }
 }
 
+/// Returns the part of `current` that was not already in `previous`
+///
+/// Both arguments are snapshots of a terminal screen. The longest tail of
+/// `previous` that reappears at the start of `current` (allowing for the
+/// screen having scrolled) is treated as already seen; what follows it is
+/// returned. The last line of `previous` may have grown since; it is then
+/// returned again in full. Trailing blank rows are ignored.
+#[must_use]
+pub fn screen_delta(previous: &str, current: &str) -> String {
+    let previous: Vec<&str> = previous.trim_end().lines().collect();
+    let current: Vec<&str> = current.trim_end().lines().collect();
+    for skip in 0..previous.len() {
+        let Some((last, body)) = previous[skip..].split_last() else {
+            break;
+        };
+        let Some(line) = current.get(body.len()) else {
+            continue;
+        };
+        if current[..body.len()] == *body && line.starts_with(last) {
+            let start = if line == last {
+                body.len() + 1
+            } else {
+                body.len()
+            };
+            return current[start..].join("\n");
+        }
+    }
+    current.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -764,4 +1230,202 @@ mod tests {
         assert_eq!(engine.len(), 1);
         assert!(engine.get_rule(id2).is_some());
     }
+
+    #[test]
+    fn test_secret_rule_not_matched_as_plain() {
+        let engine = ExpectEngine::from_rules(vec![ExpectRule::sudo("sudo_pw")]).unwrap();
+        assert!(engine.has_secret_rules());
+        assert!(engine.match_line("[sudo] password for alice:").is_none());
+        assert!(engine.match_output("Password:").is_none());
+    }
+
+    #[test]
+    fn test_secret_retry_loop() {
+        let rule = ExpectRule::with_secret(
+            DEFAULT_SUDO_PROMPT_PATTERN,
+            SecretResponse::new("sudo_pw").with_max_attempts(2),
+        );
+        let id = rule.id;
+        let mut engine = ExpectEngine::from_rules(vec![rule]).unwrap();
+
+        assert!(engine.process_secret_output("$ sudo ls").is_empty());
+        let send = |attempt| SecretPromptEvent::Send {
+            rule_id: id,
+            variable: "sudo_pw".to_string(),
+            attempt,
+        };
+        assert_eq!(
+            engine.process_secret_output("[sudo] password for alice: "),
+            vec![send(1)]
+        );
+        assert_eq!(
+            engine.process_secret_output("\nSorry, try again.\n[sudo] password for alice:"),
+            vec![send(2)]
+        );
+        assert_eq!(
+            engine.process_secret_output("Sorry, try again.\n[sudo] password for alice:"),
+            vec![SecretPromptEvent::Exhausted {
+                rule_id: id,
+                attempts: 2
+            }]
+        );
+        assert!(engine.is_empty());
+    }
+
+    #[test]
+    fn test_secret_accepted_retires_one_shot() {
+        let rule = ExpectRule::with_secret("^Password:", SecretResponse::new("pw"));
+        let mut engine = ExpectEngine::from_rules(vec![rule]).unwrap();
+        assert_eq!(engine.process_secret_output("Password:").len(), 1);
+        assert!(engine.process_secret_output("Welcome!").is_empty());
+        assert!(engine.is_empty());
+
+        // A persistent rule answers later prompts with a fresh attempt count
+        let mut engine = ExpectEngine::from_rules(vec![ExpectRule::sudo("pw")]).unwrap();
+        engine.process_secret_output("Password:\nSorry, try again.\nPassword:\nok");
+        assert!(matches!(
+            engine.process_secret_output("Password:")[..],
+            [SecretPromptEvent::Send { attempt: 1, .. }]
+        ));
+    }
+
+    #[test]
+    fn test_otp_rule_compiles_and_matches() {
+        let rule = ExpectRule::otp("otp_code");
+        assert!(rule.validate_pattern().is_ok());
+        let compiled = CompiledRule::new(rule.clone()).unwrap();
+        for prompt in [
+            "Verification code:",
+            "Verification code: ",
+            "One-time password (OATH) for 'alice':",
+            "OTP:",
+            "Token code for alice:",
+        ] {
+            assert!(compiled.matches(prompt), "{prompt}");
+        }
+        for line in [
+            "Password:",
+            "Your verification code was sent",
+            "otpd running",
+        ] {
+            assert!(!compiled.matches(line), "{line}");
+        }
+
+        let id = rule.id;
+        let mut engine = ExpectEngine::from_rules(vec![rule]).unwrap();
+        assert_eq!(
+            engine.process_secret_output("alice@bastion\nVerification code:"),
+            vec![SecretPromptEvent::Send {
+                rule_id: id,
+                variable: "otp_code".to_string(),
+                attempt: 1,
+            }]
+        );
+        assert!(matches!(
+            engine.process_secret_output("Invalid verification code\nVerification code:")[..],
+            [SecretPromptEvent::Send { attempt: 2, .. }]
+        ));
+        assert!(engine.process_secret_output("Last login: today").is_empty());
+        assert!(engine.is_empty());
+    }
+
+    #[test]
+    fn test_secret_payload() {
+        let payload = SecretResponse::payload(&SecretString::from("hunter2"));
+        assert_eq!(payload.as_slice(), b"hunter2\n");
+    }
+
+    #[test]
+    fn test_screen_delta() {
+        let prev = "$ sudo ls\n[sudo] password for a:\n\n\n";
+        let cur =
+            "$ sudo ls\n[sudo] password for a:\nSorry, try again.\n[sudo] password for a:\n\n";
+        assert_eq!(
+            screen_delta(prev, cur),
+            "Sorry, try again.\n[sudo] password for a:"
+        );
+        // Scrolled by one line, and the last line grew
+        assert_eq!(screen_delta("a\nb\nc", "b\ncd\ne"), "cd\ne");
+        assert_eq!(screen_delta("", "x\ny"), "x\ny");
+        assert_eq!(screen_delta("x\ny", "x\ny"), "");
+    }
+
+    #[test]
+    fn test_timeout_fires_once_with_fallback() {
+        let mut engine = ExpectEngine::from_rules(vec![ExpectRule::new(r"\$ $", "ls\n")])
+            .unwrap()
+            .with_timeout(Duration::from_secs(5), Some("\n".to_string()));
+        let start = Instant::now();
+
+        assert_eq!(
+            engine.expect("Connecting...", start),
+            ExpectOutcome::Pending
+        );
+        assert_eq!(
+            engine.expect("", start + Duration::from_secs(4)),
+            ExpectOutcome::Pending
+        );
+        assert_eq!(
+            engine.expect("Press any key", start + Duration::from_secs(5)),
+            ExpectOutcome::TimedOut {
+                fallback: Some("\n".to_string())
+            }
+        );
+        assert_eq!(
+            engine.check_timeout(start + Duration::from_secs(60)),
+            ExpectOutcome::Pending
+        );
+
+        // A match re-arms the timeout
+        let matched = engine.expect("user@host:~$ ", start + Duration::from_secs(61));
+        assert!(
+            matches!(matched, ExpectOutcome::Matched { ref response, .. } if response == "ls\n")
+        );
+        assert_eq!(
+            engine.check_timeout(start + Duration::from_secs(66)),
+            ExpectOutcome::TimedOut {
+                fallback: Some("\n".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_no_timeout_stays_pending() {
+        let mut engine =
+            ExpectEngine::from_rules(vec![ExpectRule::new("login:", "admin")]).unwrap();
+        let later = Instant::now() + Duration::from_secs(3600);
+        assert_eq!(engine.expect("banner", later), ExpectOutcome::Pending);
+    }
+
+    #[test]
+    fn test_chained_rule_activates_after_match() {
+        let login = ExpectRule::new("login:", "admin\n")
+            .then_expect(ExpectRule::new("Password:", "hunter2\n"));
+        let mut engine = ExpectEngine::from_rules(vec![login.clone()]).unwrap();
+        let now = Instant::now();
+
+        // The chained rule is not active before its predecessor fires
+        assert_eq!(engine.expect("Password:", now), ExpectOutcome::Pending);
+
+        assert_eq!(
+            engine.expect("server login:", now),
+            ExpectOutcome::Matched {
+                rule_id: login.id,
+                response: "admin\n".to_string(),
+            }
+        );
+        assert_eq!(engine.len(), 1);
+        assert!(matches!(
+            engine.expect("Password:", now),
+            ExpectOutcome::Matched { ref response, .. } if response == "hunter2\n"
+        ));
+        assert!(engine.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_chained_pattern_is_rejected() {
+        let rule = ExpectRule::new("login:", "admin").then_expect(ExpectRule::new("[bad", ""));
+        assert!(rule.validate_pattern().is_err());
+        assert!(ExpectEngine::from_rules(vec![rule]).is_err());
+    }
 }
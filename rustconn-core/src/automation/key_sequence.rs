@@ -5,10 +5,15 @@
 //! - Text literals
 //! - Special keys (Enter, Tab, Escape, function keys, etc.)
 //! - Wait commands for timing
+//! - Pacing commands that delay every following keystroke
 //! - Variable references for dynamic content
+//!
+//! Sequences are played into a [`KeySink`], and can be recorded from live
+//! keystrokes with [`KeySequenceRecorder`].
 
 use std::fmt;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -26,7 +31,7 @@ pub enum KeySequenceError {
     #[error("Unknown special key: {0}")]
     UnknownKey(String),
 
-    /// Invalid wait duration
+    /// Invalid wait or pace duration
     #[error("Invalid wait duration: {0}")]
     InvalidWaitDuration(String),
 
@@ -264,6 +269,9 @@ pub enum KeyElement {
     SpecialKey(SpecialKey),
     /// Wait for specified milliseconds
     Wait(u32),
+    /// Wait this many milliseconds before each following keystroke (`0`
+    /// turns pacing off); text is typed one character at a time while on
+    Pace(u32),
     /// Variable reference to be substituted
     Variable(String),
 }
@@ -285,6 +293,7 @@ impl fmt::Display for KeyElement {
             }
             Self::SpecialKey(key) => write!(f, "{key}"),
             Self::Wait(ms) => write!(f, "{{WAIT:{ms}}}"),
+            Self::Pace(ms) => write!(f, "{{PACE:{ms}}}"),
             Self::Variable(name) => write!(f, "${{{name}}}"),
         }
     }
@@ -296,6 +305,7 @@ impl fmt::Display for KeyElement {
 /// - Plain text: `hello world`
 /// - Special keys: `{ENTER}`, `{TAB}`, `{F1}`
 /// - Wait commands: `{WAIT:1000}` (milliseconds)
+/// - Pacing: `{PACE:50}` (milliseconds between following keystrokes)
 /// - Variable references: `${username}`
 /// - Escaped braces: `{{` for `{`, `}}` for `}`
 /// - Escaped dollar: `$$` for `$`
@@ -325,6 +335,7 @@ impl KeySequence {
     /// - Plain text: `hello world`
     /// - Special keys: `{ENTER}`, `{TAB}`, `{F1}`
     /// - Wait commands: `{WAIT:1000}` (milliseconds)
+    /// - Pacing: `{PACE:50}` (milliseconds between following keystrokes)
     /// - Variable references: `${username}`
     /// - Escaped braces: `{{` for literal `{`, `}}` for literal `}`
     /// - Escaped dollar: `$$` for literal `$`
@@ -449,14 +460,19 @@ impl KeySequence {
         Ok(Self { elements })
     }
 
-    /// Parses content inside braces (special key or wait command)
+    /// Parses content inside braces (special key, wait or pace command)
     fn parse_brace_content(content: &str, position: usize) -> KeySequenceResult<KeyElement> {
-        // Check for WAIT command
+        let parse_ms = |duration_str: &str| {
+            duration_str
+                .parse::<u32>()
+                .map_err(|_| KeySequenceError::InvalidWaitDuration(duration_str.to_string()))
+        };
+        // Check for WAIT and PACE commands
         if let Some(duration_str) = content.strip_prefix("WAIT:") {
-            let duration: u32 = duration_str
-                .parse()
-                .map_err(|_| KeySequenceError::InvalidWaitDuration(duration_str.to_string()))?;
-            return Ok(KeyElement::Wait(duration));
+            return parse_ms(duration_str).map(KeyElement::Wait);
+        }
+        if let Some(duration_str) = content.strip_prefix("PACE:") {
+            return parse_ms(duration_str).map(KeyElement::Pace);
         }
 
         // Try to parse as special key
@@ -587,6 +603,152 @@ impl KeySequence {
     ) -> KeySequenceResult<Self> {
         self.substitute_variables(manager.as_ref(), scope)
     }
+
+    /// Plays the sequence into `sink`
+    ///
+    /// Text is sent as UTF-8 and special keys as their VTE bytes. A
+    /// `{WAIT}` pauses once; after a non-zero `{PACE}`, every keystroke but
+    /// the first is preceded by the pace delay, and text is sent one
+    /// character at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeySequenceError::VariableError` if the sequence still
+    /// contains variable references; nothing is sent in that case.
+    pub fn play(&self, sink: &mut dyn KeySink) -> KeySequenceResult<()> {
+        if let Some(name) = self.variable_references().first() {
+            return Err(KeySequenceError::VariableError(format!(
+                "Unresolved variable: {name}"
+            )));
+        }
+
+        let mut pace = Duration::ZERO;
+        let mut sent_any = false;
+        for element in &self.elements {
+            match element {
+                KeyElement::Text(text) if pace.is_zero() => {
+                    Self::keystroke(sink, pace, &mut sent_any, text.as_bytes());
+                }
+                KeyElement::Text(text) => {
+                    let mut buf = [0; 4];
+                    for ch in text.chars() {
+                        let bytes = ch.encode_utf8(&mut buf).as_bytes();
+                        Self::keystroke(sink, pace, &mut sent_any, bytes);
+                    }
+                }
+                KeyElement::SpecialKey(key) => {
+                    Self::keystroke(sink, pace, &mut sent_any, key.to_vte_bytes());
+                }
+                KeyElement::Wait(ms) => sink.wait(Duration::from_millis(u64::from(*ms))),
+                KeyElement::Pace(ms) => pace = Duration::from_millis(u64::from(*ms)),
+                KeyElement::Variable(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends one keystroke, preceded by the pace delay unless it is the first
+    fn keystroke(sink: &mut dyn KeySink, pace: Duration, sent_any: &mut bool, bytes: &[u8]) {
+        if *sent_any && !pace.is_zero() {
+            sink.wait(pace);
+        }
+        sink.send(bytes);
+        *sent_any = true;
+    }
+
+    /// Serializes the sequence as JSON, e.g. to save a recording
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Deserializes and validates a sequence saved with [`Self::to_json`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeySequenceError::InvalidSyntax` if the JSON is malformed
+    /// or the sequence is invalid.
+    pub fn from_json(json: &str) -> KeySequenceResult<Self> {
+        let sequence: Self = serde_json::from_str(json)
+            .map_err(|e| KeySequenceError::InvalidSyntax(format!("Invalid JSON: {e}")))?;
+        sequence.validate()?;
+        Ok(sequence)
+    }
+}
+
+/// Destination of a key sequence during playback
+///
+/// The terminal implements this by feeding bytes to the child process;
+/// tests record the calls.
+pub trait KeySink {
+    /// Sends the bytes of one keystroke or run of text
+    fn send(&mut self, bytes: &[u8]);
+
+    /// Pauses before the next keystroke
+    fn wait(&mut self, duration: Duration);
+}
+
+/// Records live keystrokes as a [`KeySequence`]
+///
+/// Gaps between keystrokes of at least `min_wait_ms` become `{WAIT}`
+/// elements, so playback keeps the pauses the user made; consecutive text
+/// without such a gap is merged into one element.
+#[derive(Debug, Clone)]
+pub struct KeySequenceRecorder {
+    elements: Vec<KeyElement>,
+    last: Option<Instant>,
+    min_wait_ms: u32,
+}
+
+impl KeySequenceRecorder {
+    /// Creates a recorder that keeps gaps of at least `min_wait_ms`
+    #[must_use]
+    pub const fn new(min_wait_ms: u32) -> Self {
+        Self {
+            elements: Vec::new(),
+            last: None,
+            min_wait_ms,
+        }
+    }
+
+    /// Records text typed at `at`
+    pub fn record_text(&mut self, text: &str, at: Instant) {
+        if text.is_empty() {
+            return;
+        }
+        self.record_gap(at);
+        if let Some(KeyElement::Text(last)) = self.elements.last_mut() {
+            last.push_str(text);
+        } else {
+            self.elements.push(KeyElement::Text(text.to_string()));
+        }
+    }
+
+    /// Records a special key pressed at `at`
+    pub fn record_key(&mut self, key: SpecialKey, at: Instant) {
+        self.record_gap(at);
+        self.elements.push(KeyElement::SpecialKey(key));
+    }
+
+    /// Returns the recorded sequence
+    #[must_use]
+    pub fn finish(self) -> KeySequence {
+        KeySequence::from_elements(self.elements)
+    }
+
+    fn record_gap(&mut self, at: Instant) {
+        if let Some(last) = self.last {
+            let gap =
+                u32::try_from(at.saturating_duration_since(last).as_millis()).unwrap_or(u32::MAX);
+            if gap >= self.min_wait_ms.max(1) {
+                self.elements.push(KeyElement::Wait(gap));
+            }
+        }
+        self.last = Some(at);
+    }
 }
 
 impl fmt::Display for KeySequence {
@@ -867,4 +1029,91 @@ mod tests {
             KeySequence::parse("{CTRL+C}").unwrap()
         );
     }
+
+    #[derive(Debug, Default)]
+    struct MockSink(Vec<(u64, Vec<u8>)>, u64);
+
+    impl KeySink for MockSink {
+        fn send(&mut self, bytes: &[u8]) {
+            self.0.push((self.1, bytes.to_vec()));
+        }
+
+        fn wait(&mut self, duration: Duration) {
+            self.1 += u64::try_from(duration.as_millis()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_play_honors_waits_and_pacing() {
+        let seq = KeySequence::parse("ls{ENTER}{WAIT:500}{PACE:100}ab{HOME}{PACE:0}cd").unwrap();
+        let mut sink = MockSink::default();
+        seq.play(&mut sink).unwrap();
+
+        // (milliseconds since start, bytes)
+        let expected: [(u64, &[u8]); 6] = [
+            (0, b"ls"),
+            (0, b"\r"),
+            (600, b"a"),
+            (700, b"b"),
+            (800, b"\x1b[H"),
+            (800, b"cd"),
+        ];
+        let actual: Vec<(u64, &[u8])> = sink.0.iter().map(|(t, b)| (*t, b.as_slice())).collect();
+        assert_eq!(actual, expected);
+
+        let unresolved = KeySequence::parse("a${user}").unwrap();
+        let mut sink = MockSink::default();
+        assert!(matches!(
+            unresolved.play(&mut sink),
+            Err(KeySequenceError::VariableError(_))
+        ));
+        assert!(sink.0.is_empty());
+    }
+
+    #[test]
+    fn test_navigation_key_encoding() {
+        let cases: [(&str, &[u8]); 4] = [
+            ("PGUP", b"\x1b[5~"),
+            ("PageDown", b"\x1b[6~"),
+            ("home", b"\x1b[H"),
+            ("END", b"\x1b[F"),
+        ];
+        for (name, bytes) in cases {
+            let key = SpecialKey::parse(name).unwrap();
+            assert_eq!(key.to_vte_bytes(), bytes, "{name}");
+            assert_eq!(SpecialKey::parse(key.as_str()).unwrap(), key);
+        }
+    }
+
+    #[test]
+    fn test_recorded_sequence_roundtrip() {
+        let start = Instant::now();
+        let ms = |n| start + Duration::from_millis(n);
+        let mut recorder = KeySequenceRecorder::new(200);
+        recorder.record_text("ad", ms(0));
+        recorder.record_text("min", ms(50));
+        recorder.record_key(SpecialKey::Enter, ms(100));
+        recorder.record_key(SpecialKey::PageDown, ms(1_600));
+        let seq = recorder.finish();
+
+        assert_eq!(
+            seq.elements,
+            [
+                KeyElement::Text("admin".to_string()),
+                KeyElement::SpecialKey(SpecialKey::Enter),
+                KeyElement::Wait(1_500),
+                KeyElement::SpecialKey(SpecialKey::PageDown),
+            ]
+        );
+        assert_eq!(
+            KeySequence::from_json(&seq.to_json().unwrap()).unwrap(),
+            seq
+        );
+        assert_eq!(KeySequence::parse(&seq.to_string()).unwrap(), seq);
+        assert_eq!(
+            KeySequence::parse("{PACE:20}x").unwrap().to_string(),
+            "{PACE:20}x"
+        );
+        assert!(KeySequence::from_json("{\"elements\": 3}").is_err());
+    }
 }
@@ -1,8 +1,10 @@
 //! Automation system for `RustConn`
 //!
 //! This module provides automation capabilities including:
-//! - Key sequences for automated keystrokes after connection
-//! - Expect-style pattern matching for interactive prompts
+//! - Key sequences for automated keystrokes after connection, with pacing
+//!   and recording
+//! - Expect-style pattern matching for interactive prompts, including
+//!   secret responses such as `sudo` passwords and one-time passwords
 //! - Pre/post connection tasks
 
 mod expect;
@@ -10,10 +12,18 @@ mod key_sequence;
 mod tasks;
 mod templates;
 
-pub use expect::{CompiledRule, ExpectEngine, ExpectError, ExpectResult, ExpectRule};
-pub use key_sequence::{KeyElement, KeySequence, KeySequenceError, KeySequenceResult, SpecialKey};
+pub use expect::{
+    CompiledRule, DEFAULT_OTP_FAILURE_PATTERN, DEFAULT_OTP_PROMPT_PATTERN, DEFAULT_SECRET_ATTEMPTS,
+    DEFAULT_SECRET_FAILURE_PATTERN, DEFAULT_SUDO_PROMPT_PATTERN, ExpectEngine, ExpectError,
+    ExpectOutcome, ExpectResult, ExpectRule, SecretPromptEvent, SecretResponse, screen_delta,
+};
+pub use key_sequence::{
+    KeyElement, KeySequence, KeySequenceError, KeySequenceRecorder, KeySequenceResult, KeySink,
+    SpecialKey,
+};
 pub use tasks::{
-    ConnectionTask, FolderConnectionTracker, TaskCondition, TaskError, TaskExecutor, TaskResult,
-    TaskTiming,
+    ChainStep, ConnectionTask, DependencyOutcome, FolderConnectionTracker, TaskClock,
+    TaskCondition, TaskDependency, TaskError, TaskExecutor, TaskOutcome, TaskResult, TaskTiming,
+    TokioClock, resolve_task_order,
 };
 pub use templates::{AutomationTemplate, builtin_templates, templates_for_protocol};
@@ -6,16 +6,21 @@
 //! - Post-disconnect tasks (e.g., cleanup, logging)
 //! - Conditional execution based on folder connection state
 //! - Variable substitution in command strings
+//! - Retrying flaky commands with fixed or exponential backoff
+//! - Chaining tasks so one runs only after another succeeded or failed
 
 use std::collections::HashMap;
 use std::process::ExitStatus;
 use std::sync::Arc;
+use std::time::Duration;
 
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tokio::process::Command;
 use uuid::Uuid;
 
+use crate::connection::{DependencyError, RetryConfig, RetryState, resolve_launch_order};
 use crate::variables::{VariableManager, VariableScope};
 
 /// Errors that can occur during task operations
@@ -48,11 +53,54 @@ pub enum TaskError {
     /// I/O error during task execution
     #[error("I/O error: {0}")]
     IoError(String),
+
+    /// Task dependencies form a cycle
+    #[error("Task dependency cycle: {0}")]
+    DependencyCycle(String),
+
+    /// Every attempt allowed by the task's retry policy failed
+    #[error("Task failed after {attempts} attempts: {last_error}")]
+    RetriesExhausted {
+        /// Number of attempts made
+        attempts: u32,
+        /// Error of the last attempt
+        last_error: Box<TaskError>,
+    },
 }
 
 /// Result type for task operations
 pub type TaskResult<T> = std::result::Result<T, TaskError>;
 
+/// Outcome of a task that ran to completion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskOutcome {
+    /// Exit code of the last attempt (`0` when the task was skipped)
+    pub exit_code: i32,
+    /// Number of attempts made (`0` when the task was skipped)
+    pub attempts: u32,
+}
+
+/// Source of the delays between task retries
+///
+/// [`TokioClock`] sleeps for real; tests substitute a clock that only
+/// records the requested delays.
+#[async_trait]
+pub trait TaskClock: Send + Sync + std::fmt::Debug {
+    /// Waits for `duration`
+    async fn sleep(&self, duration: Duration);
+}
+
+/// [`TaskClock`] backed by `tokio::time::sleep`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioClock;
+
+#[async_trait]
+impl TaskClock for TokioClock {
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
 /// Task execution timing
 ///
 /// Defines when a task should be executed relative to the connection lifecycle.
@@ -152,6 +200,50 @@ impl TaskCondition {
     }
 }
 
+/// Outcome a prerequisite task must have for its dependent to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyOutcome {
+    /// The prerequisite ran and exited with code `0`
+    #[default]
+    Succeeded,
+    /// The prerequisite ran and failed or exited with a non-zero code
+    Failed,
+}
+
+/// Dependency of a task on another task in the same chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TaskDependency {
+    /// ID of the prerequisite task
+    pub task_id: Uuid,
+    /// Outcome the prerequisite must have
+    #[serde(default)]
+    pub outcome: DependencyOutcome,
+}
+
+/// What happened to a task in a chain run by [`TaskExecutor::execute_chain`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChainStep {
+    /// The task ran with this result
+    Ran(TaskResult<TaskOutcome>),
+    /// The task was skipped because its prerequisite was skipped or did not
+    /// have the expected outcome
+    Skipped,
+}
+
+impl ChainStep {
+    /// Returns the [`DependencyOutcome`] of the task, or `None` if it was
+    /// skipped
+    #[must_use]
+    pub const fn outcome(&self) -> Option<DependencyOutcome> {
+        match self {
+            Self::Ran(Ok(TaskOutcome { exit_code: 0, .. })) => Some(DependencyOutcome::Succeeded),
+            Self::Ran(_) => Some(DependencyOutcome::Failed),
+            Self::Skipped => None,
+        }
+    }
+}
+
 /// A connection task definition
 ///
 /// Connection tasks are commands that can be executed before connecting
@@ -177,6 +269,16 @@ pub struct ConnectionTask {
     /// Optional description for documentation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Retry policy for failed runs; `None` runs the task once
+    ///
+    /// A non-zero exit counts as a failure here even when
+    /// `abort_on_failure` is off.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryConfig>,
+    /// Task that must run first, and the outcome it must have for this task
+    /// to run (see [`TaskExecutor::execute_chain`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<TaskDependency>,
 }
 
 /// Default value for `abort_on_failure` (true for pre-connect tasks)
@@ -196,6 +298,8 @@ impl ConnectionTask {
             timeout_ms: None,
             abort_on_failure: true,
             description: None,
+            retry: None,
+            depends_on: None,
         }
     }
 
@@ -210,6 +314,8 @@ impl ConnectionTask {
             timeout_ms: None,
             abort_on_failure: false,
             description: None,
+            retry: None,
+            depends_on: None,
         }
     }
 
@@ -224,6 +330,8 @@ impl ConnectionTask {
             timeout_ms: None,
             abort_on_failure: matches!(timing, TaskTiming::PreConnect),
             description: None,
+            retry: None,
+            depends_on: None,
         }
     }
 
@@ -255,6 +363,22 @@ impl ConnectionTask {
         self
     }
 
+    /// Sets the retry policy for this task
+    ///
+    /// Use a backoff multiplier of `1.0` for a fixed delay between attempts.
+    #[must_use]
+    pub const fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Makes this task run only after `task_id` finished with `outcome`
+    #[must_use]
+    pub const fn with_dependency(mut self, task_id: Uuid, outcome: DependencyOutcome) -> Self {
+        self.depends_on = Some(TaskDependency { task_id, outcome });
+        self
+    }
+
     /// Returns true if this is a pre-connect task
     #[must_use]
     pub const fn is_pre_connect(&self) -> bool {
@@ -362,6 +486,8 @@ pub struct TaskExecutor {
     variable_manager: Arc<VariableManager>,
     /// Folder connection tracker for conditional execution
     folder_tracker: Arc<std::sync::Mutex<FolderConnectionTracker>>,
+    /// Clock used to wait between retries
+    clock: Arc<dyn TaskClock>,
 }
 
 impl TaskExecutor {
@@ -371,21 +497,30 @@ impl TaskExecutor {
         Self {
             variable_manager,
             folder_tracker: Arc::new(std::sync::Mutex::new(FolderConnectionTracker::new())),
+            clock: Arc::new(TokioClock),
         }
     }
 
     /// Creates a new task executor with a custom folder tracker
     #[must_use]
-    pub const fn with_tracker(
+    pub fn with_tracker(
         variable_manager: Arc<VariableManager>,
         folder_tracker: Arc<std::sync::Mutex<FolderConnectionTracker>>,
     ) -> Self {
         Self {
             variable_manager,
             folder_tracker,
+            clock: Arc::new(TokioClock),
         }
     }
 
+    /// Sets the clock used to wait between retries
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn TaskClock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Returns a reference to the folder tracker
     #[must_use]
     pub const fn folder_tracker(&self) -> &Arc<std::sync::Mutex<FolderConnectionTracker>> {
@@ -423,7 +558,8 @@ impl TaskExecutor {
     /// * `is_last` - Whether this is the last connection in the folder
     ///
     /// # Returns
-    /// The exit code of the command, or an error if execution failed
+    /// The exit code of the last attempt and the number of attempts made,
+    /// or an error if execution failed
     ///
     /// # Errors
     ///
@@ -432,6 +568,10 @@ impl TaskExecutor {
     /// - Command execution fails
     /// - Command returns non-zero exit code (for pre-connect with `abort_on_failure`)
     /// - Command times out
+    ///
+    /// When the task has a retry policy, a failure is retried after the
+    /// policy's backoff delay; once every attempt has failed the last error
+    /// is returned as [`TaskError::RetriesExhausted`].
     pub async fn execute(
         &self,
         task: &ConnectionTask,
@@ -439,18 +579,70 @@ impl TaskExecutor {
         _folder_id: Option<Uuid>,
         is_first: bool,
         is_last: bool,
-    ) -> TaskResult<i32> {
+    ) -> TaskResult<TaskOutcome> {
         // Check if task should execute based on conditions
         if !Self::should_execute_task(task, is_first, is_last) {
-            return Ok(0); // Skip execution, return success
+            // Skip execution, return success
+            return Ok(TaskOutcome {
+                exit_code: 0,
+                attempts: 0,
+            });
         }
 
         // Substitute variables in command
         let command = task.substitute_command_arc(&self.variable_manager, scope)?;
 
-        // Execute the command
-        self.execute_command(&command, task.timeout_ms, task.abort_on_failure)
-            .await
+        let Some(retry) = task.retry.clone().filter(|r| r.enabled) else {
+            let exit_code = self
+                .execute_command(&command, task.timeout_ms, task.abort_on_failure)
+                .await?;
+            return Ok(TaskOutcome {
+                exit_code,
+                attempts: 1,
+            });
+        };
+
+        let mut state = RetryState::new(retry);
+        loop {
+            let attempts = state.attempt_number();
+            let error = match self.execute_command(&command, task.timeout_ms, true).await {
+                Ok(exit_code) => {
+                    state.record_success();
+                    return Ok(TaskOutcome {
+                        exit_code,
+                        attempts,
+                    });
+                }
+                Err(e) => e,
+            };
+            let delay = state.next_delay();
+            state.record_failure(error.to_string());
+            match delay {
+                Some(delay) => {
+                    tracing::debug!(
+                        attempt = attempts,
+                        delay_ms = delay.as_millis(),
+                        %error,
+                        "Task failed, retrying"
+                    );
+                    self.clock.sleep(delay).await;
+                }
+                None => {
+                    return match error {
+                        TaskError::NonZeroExit(exit_code) if !task.abort_on_failure => {
+                            Ok(TaskOutcome {
+                                exit_code,
+                                attempts,
+                            })
+                        }
+                        error => Err(TaskError::RetriesExhausted {
+                            attempts,
+                            last_error: Box::new(error),
+                        }),
+                    };
+                }
+            }
+        }
     }
 
     /// Executes a command string and returns the exit code
@@ -521,6 +713,51 @@ impl TaskExecutor {
         status.code().ok_or(TaskError::Terminated)
     }
 
+    /// Executes a chain of tasks in dependency order
+    ///
+    /// Every task runs after its prerequisite; tasks without one keep their
+    /// relative order. A dependent is skipped when its prerequisite was
+    /// skipped or did not have the expected [`DependencyOutcome`], and a
+    /// failed task does not stop the others. Returns one step per task, in
+    /// execution order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskError::DependencyCycle`] if the dependencies form a
+    /// cycle, or [`TaskError::InvalidConfig`] if a task depends on a task
+    /// missing from `tasks`. Nothing is run in either case.
+    pub async fn execute_chain(
+        &self,
+        tasks: &[ConnectionTask],
+        scope: VariableScope,
+        folder_id: Option<Uuid>,
+        is_first: bool,
+        is_last: bool,
+    ) -> TaskResult<Vec<(Uuid, ChainStep)>> {
+        let order = resolve_task_order(tasks)?;
+        let mut steps: Vec<(Uuid, ChainStep)> = Vec::with_capacity(order.len());
+        for task in order {
+            let ready = task.depends_on.is_none_or(|dependency| {
+                steps
+                    .iter()
+                    .find(|(id, _)| *id == dependency.task_id)
+                    .and_then(|(_, step)| step.outcome())
+                    == Some(dependency.outcome)
+            });
+            let step = if ready {
+                ChainStep::Ran(
+                    self.execute(task, scope, folder_id, is_first, is_last)
+                        .await,
+                )
+            } else {
+                tracing::debug!(task = %task.id, "Skipping task: prerequisite outcome not met");
+                ChainStep::Skipped
+            };
+            steps.push((task.id, step));
+        }
+        Ok(steps)
+    }
+
     /// Executes a pre-connect task
     ///
     /// This is a convenience method that handles folder tracking automatically.
@@ -537,7 +774,7 @@ impl TaskExecutor {
         task: &ConnectionTask,
         scope: VariableScope,
         folder_id: Option<Uuid>,
-    ) -> TaskResult<i32> {
+    ) -> TaskResult<TaskOutcome> {
         let is_first = {
             let mut tracker = self
                 .folder_tracker
@@ -565,7 +802,7 @@ impl TaskExecutor {
         task: &ConnectionTask,
         scope: VariableScope,
         folder_id: Option<Uuid>,
-    ) -> TaskResult<i32> {
+    ) -> TaskResult<TaskOutcome> {
         let is_last = {
             let mut tracker = self
                 .folder_tracker
@@ -578,6 +815,51 @@ impl TaskExecutor {
     }
 }
 
+/// Orders `tasks` so that every task comes after its prerequisite
+///
+/// Tasks otherwise keep their relative order.
+///
+/// # Errors
+///
+/// Returns [`TaskError::DependencyCycle`] for cyclic dependencies and
+/// [`TaskError::InvalidConfig`] for a dependency on a task not in `tasks`.
+pub fn resolve_task_order(tasks: &[ConnectionTask]) -> TaskResult<Vec<&ConnectionTask>> {
+    let by_id: HashMap<Uuid, &ConnectionTask> = tasks.iter().map(|t| (t.id, t)).collect();
+    let mut order: Vec<&ConnectionTask> = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        let chain = resolve_launch_order(task.id, |id| {
+            by_id.get(&id).copied().map(|t| {
+                t.depends_on
+                    .as_ref()
+                    .map(|d| std::slice::from_ref(&d.task_id))
+                    .unwrap_or_default()
+            })
+        })
+        .map_err(|e| match e {
+            DependencyError::Cycle(cycle) => TaskError::DependencyCycle(
+                cycle
+                    .iter()
+                    .map(Uuid::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" -> "),
+            ),
+            DependencyError::MissingDependency {
+                connection,
+                dependency,
+            } => TaskError::InvalidConfig(format!(
+                "Task {connection} depends on missing task {dependency}"
+            )),
+            other => TaskError::InvalidConfig(other.to_string()),
+        })?;
+        for id in chain {
+            if !order.iter().any(|t| t.id == id) {
+                order.push(by_id[&id]);
+            }
+        }
+    }
+    Ok(order)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -745,4 +1027,161 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "echo ");
     }
+
+    /// Clock that records the requested delays instead of sleeping
+    #[derive(Debug, Default)]
+    struct RecordingClock(std::sync::Mutex<Vec<Duration>>);
+
+    #[async_trait]
+    impl TaskClock for RecordingClock {
+        async fn sleep(&self, duration: Duration) {
+            self.0.lock().unwrap().push(duration);
+        }
+    }
+
+    fn executor_with(clock: &Arc<RecordingClock>) -> TaskExecutor {
+        TaskExecutor::new(Arc::new(VariableManager::new()))
+            .with_clock(Arc::clone(clock) as Arc<dyn TaskClock>)
+    }
+
+    #[tokio::test]
+    async fn test_retry_exponential_backoff() {
+        let clock = Arc::new(RecordingClock::default());
+        let task = ConnectionTask::new_pre_connect("exit 3").with_retry(
+            RetryConfig::new()
+                .with_max_attempts(3)
+                .with_initial_delay_ms(200)
+                .with_backoff_multiplier(2.0)
+                .with_max_delay_ms(600),
+        );
+
+        let result = executor_with(&clock)
+            .execute(&task, VariableScope::Global, None, true, true)
+            .await;
+
+        assert_eq!(
+            result,
+            Err(TaskError::RetriesExhausted {
+                attempts: 4,
+                last_error: Box::new(TaskError::NonZeroExit(3)),
+            })
+        );
+        assert_eq!(
+            *clock.0.lock().unwrap(),
+            [200, 400, 600].map(Duration::from_millis)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_counts_attempts_until_success() {
+        let dir = tempfile::tempdir().unwrap();
+        let counter = dir.path().join("count");
+        let script = dir.path().join("flaky.sh");
+        std::fs::write(
+            &script,
+            format!(
+                "n=$(cat '{0}' 2>/dev/null || echo 0)\nn=$((n + 1))\necho $n > '{0}'\n[ $n -ge 3 ]\n",
+                counter.display()
+            ),
+        )
+        .unwrap();
+
+        let clock = Arc::new(RecordingClock::default());
+        let executor = executor_with(&clock);
+        let task = ConnectionTask::new_pre_connect(format!("sh '{}'", script.display()))
+            .with_retry(
+                RetryConfig::new()
+                    .with_max_attempts(5)
+                    .with_backoff_multiplier(1.0),
+            );
+
+        let outcome = executor
+            .execute(&task, VariableScope::Global, None, true, true)
+            .await
+            .unwrap();
+        assert_eq!(
+            outcome,
+            TaskOutcome {
+                exit_code: 0,
+                attempts: 3,
+            }
+        );
+        // Fixed backoff: the default initial delay before each retry
+        assert_eq!(*clock.0.lock().unwrap(), [Duration::from_secs(1); 2]);
+
+        // Without a policy the task runs once
+        let once = ConnectionTask::new_post_disconnect("exit 1");
+        let outcome = executor
+            .execute(&once, VariableScope::Global, None, true, true)
+            .await
+            .unwrap();
+        assert_eq!(outcome.attempts, 1);
+        assert_eq!(outcome.exit_code, 1);
+    }
+
+    #[tokio::test]
+    async fn test_chain_skips_dependents_on_unmet_outcome() {
+        let executor = TaskExecutor::new(Arc::new(VariableManager::new()));
+        let setup = ConnectionTask::new_pre_connect("exit 1");
+        let on_success = ConnectionTask::new_pre_connect("true")
+            .with_dependency(setup.id, DependencyOutcome::Succeeded);
+        let after_skipped = ConnectionTask::new_pre_connect("true")
+            .with_dependency(on_success.id, DependencyOutcome::Succeeded);
+        let on_failure = ConnectionTask::new_pre_connect("true")
+            .with_dependency(setup.id, DependencyOutcome::Failed);
+
+        // Dependents listed before their prerequisite still run after it
+        let tasks = [
+            after_skipped.clone(),
+            on_failure.clone(),
+            on_success.clone(),
+            setup.clone(),
+        ];
+        let steps = executor
+            .execute_chain(&tasks, VariableScope::Global, None, true, false)
+            .await
+            .unwrap();
+
+        let order: Vec<Uuid> = steps.iter().map(|(id, _)| *id).collect();
+        assert_eq!(
+            order,
+            [setup.id, on_success.id, after_skipped.id, on_failure.id]
+        );
+        assert_eq!(steps[0].1, ChainStep::Ran(Err(TaskError::NonZeroExit(1))));
+        assert_eq!(steps[1].1, ChainStep::Skipped);
+        assert_eq!(steps[2].1, ChainStep::Skipped);
+        assert_eq!(steps[3].1.outcome(), Some(DependencyOutcome::Succeeded));
+    }
+
+    #[tokio::test]
+    async fn test_chain_rejects_cycles_and_missing_tasks() {
+        let executor = TaskExecutor::new(Arc::new(VariableManager::new()));
+        let a_id = Uuid::new_v4();
+        let b = ConnectionTask::new_pre_connect("true")
+            .with_dependency(a_id, DependencyOutcome::Succeeded);
+        let a = ConnectionTask::with_id(a_id, TaskTiming::PreConnect, "true")
+            .with_dependency(b.id, DependencyOutcome::Failed);
+
+        let result = executor
+            .execute_chain(
+                &[a.clone(), b.clone()],
+                VariableScope::Global,
+                None,
+                true,
+                false,
+            )
+            .await;
+        assert_eq!(
+            result,
+            Err(TaskError::DependencyCycle(format!(
+                "{a_id} -> {} -> {a_id}",
+                b.id
+            )))
+        );
+
+        assert!(matches!(
+            resolve_task_order(&[b]),
+            Err(TaskError::InvalidConfig(_))
+        ));
+    }
 }
@@ -43,7 +43,7 @@ pub fn templates_for_protocol(protocol: &str) -> Vec<&'static AutomationTemplate
         .collect()
 }
 
-static TEMPLATES: [AutomationTemplate; 5] = [
+static TEMPLATES: [AutomationTemplate; 6] = [
     AutomationTemplate {
         id: "sudo_password",
         name: "Sudo Password",
@@ -57,6 +57,13 @@ static TEMPLATES: [AutomationTemplate; 5] = [
             ]
         },
     },
+    AutomationTemplate {
+        id: "sudo_secret",
+        name: "Sudo Password (Secret Variable)",
+        description: "Answer sudo prompts from the secret variable sudo_password, retrying up to 3 times",
+        protocol_hint: "ssh",
+        rules_fn: || vec![ExpectRule::sudo("sudo_password").with_priority(10)],
+    },
     AutomationTemplate {
         id: "ssh_host_key",
         name: "SSH Host Key Confirmation",
@@ -2,13 +2,30 @@
 //!
 //! This module provides cluster functionality for managing multiple connections
 //! as a group, including broadcast mode for sending input to all sessions simultaneously.
+//!
+//! Clusters can optionally use a staggered start: members are moved from
+//! `Pending` to `Connecting` one at a time (with a configurable delay) and
+//! never more than a fixed number at once, so opening a large cluster does
+//! not flood a shared bastion with simultaneous authentications.
+//!
+//! Broadcast input can be adapted per member through command templates:
+//! `${input}` in a member's template is replaced with the broadcast text and
+//! other `${variable}` references are resolved in that member's connection
+//! scope, so one broadcast can carry a different hostname or sudo path to
+//! each member.
 
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::variables::{VariableError, VariableManager, VariableResult, VariableScope};
+
+/// Placeholder in a member command template that receives the broadcast input
+pub const BROADCAST_INPUT_PLACEHOLDER: &str = "${input}";
+
 /// Errors related to cluster operations
 #[derive(Debug, Error)]
 pub enum ClusterError {
@@ -66,6 +83,8 @@ pub struct ClusterMemberState {
     pub status: ClusterSessionStatus,
     /// Error message if status is Error
     pub error_message: Option<String>,
+    /// When the member entered the `Connecting` state
+    pub connecting_since: Option<Instant>,
 }
 
 impl ClusterMemberState {
@@ -76,6 +95,7 @@ impl ClusterMemberState {
             connection_id,
             status: ClusterSessionStatus::Pending,
             error_message: None,
+            connecting_since: None,
         }
     }
 
@@ -83,24 +103,28 @@ impl ClusterMemberState {
     pub fn set_connecting(&mut self) {
         self.status = ClusterSessionStatus::Connecting;
         self.error_message = None;
+        self.connecting_since = Some(Instant::now());
     }
 
     /// Sets the status to connected
     pub fn set_connected(&mut self) {
         self.status = ClusterSessionStatus::Connected;
         self.error_message = None;
+        self.connecting_since = None;
     }
 
     /// Sets the status to disconnected
     pub fn set_disconnected(&mut self) {
         self.status = ClusterSessionStatus::Disconnected;
         self.error_message = None;
+        self.connecting_since = None;
     }
 
     /// Sets the status to error with a message
     pub fn set_error(&mut self, message: String) {
         self.status = ClusterSessionStatus::Error;
         self.error_message = Some(message);
+        self.connecting_since = None;
     }
 
     /// Returns true if the session is in an active state (connecting or connected)
@@ -124,6 +148,17 @@ pub struct Cluster {
     pub connection_ids: Vec<Uuid>,
     /// Whether broadcast mode is enabled by default
     pub broadcast_enabled: bool,
+    /// Delay between starting consecutive member connections, in milliseconds
+    /// (0 starts members without waiting)
+    #[serde(default)]
+    pub connect_delay_ms: u64,
+    /// Maximum number of members allowed in the `Connecting` state at once
+    /// (0 means unlimited)
+    #[serde(default)]
+    pub max_in_flight: usize,
+    /// Per-member command templates applied to broadcast input, keyed by connection ID
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub command_templates: HashMap<Uuid, String>,
 }
 
 impl Cluster {
@@ -135,20 +170,51 @@ impl Cluster {
             name,
             connection_ids: Vec::new(),
             broadcast_enabled: false,
+            connect_delay_ms: 0,
+            max_in_flight: 0,
+            command_templates: HashMap::new(),
         }
     }
 
     /// Creates a new cluster with specific ID (for deserialization)
     #[must_use]
-    pub const fn with_id(id: Uuid, name: String) -> Self {
+    pub fn with_id(id: Uuid, name: String) -> Self {
         Self {
             id,
             name,
             connection_ids: Vec::new(),
             broadcast_enabled: false,
+            connect_delay_ms: 0,
+            max_in_flight: 0,
+            command_templates: HashMap::new(),
         }
     }
 
+    /// Sets the staggered start parameters
+    #[must_use]
+    pub const fn with_staggered_start(
+        mut self,
+        connect_delay_ms: u64,
+        max_in_flight: usize,
+    ) -> Self {
+        self.connect_delay_ms = connect_delay_ms;
+        self.max_in_flight = max_in_flight;
+        self
+    }
+
+    /// Returns the delay between starting consecutive member connections
+    #[must_use]
+    pub const fn connect_delay(&self) -> Duration {
+        Duration::from_millis(self.connect_delay_ms)
+    }
+
+    /// Returns true if members should be started in throttled waves
+    /// rather than all at once
+    #[must_use]
+    pub const fn is_staggered(&self) -> bool {
+        self.connect_delay_ms > 0 || self.max_in_flight > 0
+    }
+
     /// Adds a connection to the cluster
     pub fn add_connection(&mut self, connection_id: Uuid) {
         if !self.connection_ids.contains(&connection_id) {
@@ -159,6 +225,28 @@ impl Cluster {
     /// Removes a connection from the cluster
     pub fn remove_connection(&mut self, connection_id: Uuid) {
         self.connection_ids.retain(|id| *id != connection_id);
+        self.command_templates.remove(&connection_id);
+    }
+
+    /// Sets the broadcast command template for a member
+    ///
+    /// An empty template removes the override so the member receives the
+    /// broadcast input unchanged.
+    pub fn set_command_template(&mut self, connection_id: Uuid, template: impl Into<String>) {
+        let template = template.into();
+        if template.is_empty() {
+            self.command_templates.remove(&connection_id);
+        } else {
+            self.command_templates.insert(connection_id, template);
+        }
+    }
+
+    /// Returns the broadcast command template for a member, if any
+    #[must_use]
+    pub fn command_template(&self, connection_id: Uuid) -> Option<&str> {
+        self.command_templates
+            .get(&connection_id)
+            .map(String::as_str)
     }
 
     /// Returns true if the cluster contains the given connection
@@ -180,6 +268,43 @@ impl Cluster {
     }
 }
 
+/// Broadcast input rendered for a single cluster member
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BroadcastTarget {
+    /// The member connection ID
+    pub connection_id: Uuid,
+    /// The text to send to this member
+    pub input: String,
+}
+
+/// Renders broadcast input for one member
+///
+/// Applies the member's template (if any), then resolves `${variable}`
+/// references in the member's connection scope. Unknown references are left
+/// untouched so shell syntax such as `${HOME}` reaches the remote shell intact.
+fn render_broadcast_input(
+    template: Option<&str>,
+    input: &str,
+    connection_id: Uuid,
+    variables: &VariableManager,
+) -> VariableResult<String> {
+    let text = template.map_or_else(
+        || input.to_string(),
+        |t| t.replace(BROADCAST_INPUT_PLACEHOLDER, input),
+    );
+    let scope = VariableScope::Connection(connection_id);
+
+    let mut result = text.clone();
+    for name in VariableManager::parse_references(&text)? {
+        match variables.resolve(&name, scope) {
+            Ok(value) => result = result.replace(&format!("${{{name}}}"), &value),
+            Err(VariableError::Undefined(_)) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(result)
+}
+
 /// An active cluster session managing multiple connection sessions
 #[derive(Debug)]
 pub struct ClusterSession {
@@ -189,8 +314,16 @@ pub struct ClusterSession {
     pub cluster_name: String,
     /// State of each member session, keyed by connection ID
     sessions: HashMap<Uuid, ClusterMemberState>,
+    /// Member connection IDs in cluster order (start order for staggered connects)
+    order: Vec<Uuid>,
     /// Whether broadcast mode is currently enabled
     broadcast_mode: bool,
+    /// Delay between starting consecutive members
+    connect_delay: Duration,
+    /// Maximum number of members connecting at once (0 = unlimited)
+    max_in_flight: usize,
+    /// Per-member broadcast command templates
+    command_templates: HashMap<Uuid, String>,
 }
 
 impl ClusterSession {
@@ -207,8 +340,92 @@ impl ClusterSession {
             cluster_id: cluster.id,
             cluster_name: cluster.name.clone(),
             sessions,
+            order: cluster.connection_ids.clone(),
             broadcast_mode: cluster.broadcast_enabled,
+            connect_delay: cluster.connect_delay(),
+            max_in_flight: cluster.max_in_flight,
+            command_templates: cluster.command_templates.clone(),
+        }
+    }
+
+    /// Returns the delay to wait between starting consecutive members
+    #[must_use]
+    pub const fn connect_delay(&self) -> Duration {
+        self.connect_delay
+    }
+
+    /// Returns the maximum number of members connecting at once (0 = unlimited)
+    #[must_use]
+    pub const fn max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Returns the number of members that have not been started yet
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.sessions
+            .values()
+            .filter(|s| s.status == ClusterSessionStatus::Pending)
+            .count()
+    }
+
+    /// Returns the number of members currently connecting
+    #[must_use]
+    pub fn in_flight_count(&self) -> usize {
+        self.sessions
+            .values()
+            .filter(|s| s.status == ClusterSessionStatus::Connecting)
+            .count()
+    }
+
+    /// Returns the next pending member to start and marks it as connecting.
+    ///
+    /// Members are handed out in cluster order. Returns `None` when every
+    /// member has been started or when the in-flight cap is reached; in the
+    /// latter case the caller should retry once a connecting member has
+    /// reached `Connected` or `Error`.
+    pub fn next_to_connect(&mut self) -> Option<Uuid> {
+        if self.max_in_flight > 0 && self.in_flight_count() >= self.max_in_flight {
+            return None;
         }
+
+        let next = self.order.iter().copied().find(|id| {
+            self.sessions
+                .get(id)
+                .is_some_and(|s| s.status == ClusterSessionStatus::Pending)
+        })?;
+
+        if let Some(state) = self.sessions.get_mut(&next) {
+            state.set_connecting();
+        }
+        Some(next)
+    }
+
+    /// Starts as many pending members as the in-flight cap allows
+    ///
+    /// Returns the connection IDs that were moved to `Connecting`.
+    pub fn next_wave(&mut self) -> Vec<Uuid> {
+        std::iter::from_fn(|| self.next_to_connect()).collect()
+    }
+
+    /// Marks members stuck in `Connecting` for at least `timeout` as failed
+    ///
+    /// Frees their in-flight slots so a staggered start cannot stall on a
+    /// connection that never reports back. Returns the expired connection IDs.
+    pub fn expire_stalled(&mut self, timeout: Duration) -> Vec<Uuid> {
+        let mut expired = Vec::new();
+        for id in &self.order {
+            if let Some(state) = self.sessions.get_mut(id)
+                && state.status == ClusterSessionStatus::Connecting
+                && state
+                    .connecting_since
+                    .is_some_and(|t| t.elapsed() >= timeout)
+            {
+                state.set_error("Timed out waiting for connection".to_string());
+                expired.push(*id);
+            }
+        }
+        expired
     }
 
     /// Returns whether broadcast mode is enabled
@@ -249,6 +466,8 @@ impl ClusterSession {
             if status != ClusterSessionStatus::Error {
                 state.error_message = None;
             }
+            state.connecting_since =
+                (status == ClusterSessionStatus::Connecting).then(Instant::now);
         }
     }
 
@@ -327,32 +546,56 @@ impl ClusterSession {
         })
     }
 
-    /// Queues input to be broadcast to all sessions
-    /// Returns the list of connection IDs that should receive the input
-    #[must_use]
-    pub fn broadcast_input(&self, _input: &str) -> Vec<Uuid> {
-        if !self.broadcast_mode {
-            return Vec::new();
-        }
-
-        // Return IDs of all connected sessions
-        self.sessions
-            .iter()
-            .filter(|(_, state)| state.status == ClusterSessionStatus::Connected)
-            .map(|(id, _)| *id)
+    /// Renders input to be broadcast to all connected sessions
+    ///
+    /// Returns one [`BroadcastTarget`] per connected member, in cluster order,
+    /// carrying the text that member should receive after its command
+    /// template and connection-scoped variables have been applied.
+    /// Returns an empty vec when broadcast mode is off.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable reference is circular or nested too deeply.
+    pub fn broadcast_input(
+        &self,
+        input: &str,
+        variables: &VariableManager,
+    ) -> VariableResult<Vec<BroadcastTarget>> {
+        self.get_input_targets()
+            .into_iter()
+            .map(|connection_id| {
+                let template = self.command_templates.get(&connection_id);
+                Ok(BroadcastTarget {
+                    connection_id,
+                    input: render_broadcast_input(
+                        template.map(String::as_str),
+                        input,
+                        connection_id,
+                        variables,
+                    )?,
+                })
+            })
             .collect()
     }
 
     /// Returns the IDs of all sessions that should receive input
-    /// In broadcast mode, returns all connected sessions
+    /// In broadcast mode, returns all connected sessions in cluster order
     /// Otherwise, returns an empty vec (caller should handle single session focus)
     #[must_use]
     pub fn get_input_targets(&self) -> Vec<Uuid> {
-        if self.broadcast_mode {
-            self.broadcast_input("")
-        } else {
-            Vec::new()
+        if !self.broadcast_mode {
+            return Vec::new();
         }
+
+        self.order
+            .iter()
+            .copied()
+            .filter(|id| {
+                self.sessions
+                    .get(id)
+                    .is_some_and(|s| s.status == ClusterSessionStatus::Connected)
+            })
+            .collect()
     }
 
     /// Returns connection IDs of sessions that failed
@@ -477,29 +720,197 @@ mod tests {
         cluster.add_connection(conn2);
 
         let mut session = ClusterSession::new(&cluster);
+        let variables = VariableManager::new();
 
         // Without broadcast mode, no targets
-        let targets = session.broadcast_input("test");
+        let targets = session.broadcast_input("test", &variables).unwrap();
         assert!(targets.is_empty());
 
         // Enable broadcast mode
         session.set_broadcast_mode(true);
 
         // Still no targets because sessions aren't connected
-        let targets = session.broadcast_input("test");
+        let targets = session.broadcast_input("test", &variables).unwrap();
         assert!(targets.is_empty());
 
         // Connect one session
         session.update_session_status(conn1, ClusterSessionStatus::Connected);
-        let targets = session.broadcast_input("test");
+        let targets = session.broadcast_input("test", &variables).unwrap();
         assert_eq!(targets.len(), 1);
-        assert!(targets.contains(&conn1));
+        assert_eq!(targets[0].connection_id, conn1);
+        assert_eq!(targets[0].input, "test");
 
         // Connect second session
         session.update_session_status(conn2, ClusterSessionStatus::Connected);
-        let targets = session.broadcast_input("test");
+        let targets = session.broadcast_input("test", &variables).unwrap();
         assert_eq!(targets.len(), 2);
     }
+
+    fn member_variables(members: &[(Uuid, &str, &str)]) -> VariableManager {
+        let mut variables = VariableManager::new();
+        for (id, host, user) in members {
+            variables.set_connection(*id, crate::Variable::new("host", *host));
+            variables.set_connection(*id, crate::Variable::new("user", *user));
+        }
+        variables
+    }
+
+    #[test]
+    fn test_broadcast_input_substitutes_per_member() {
+        let mut cluster = Cluster::new("Test".to_string());
+        let web = Uuid::new_v4();
+        let db = Uuid::new_v4();
+        cluster.add_connection(web);
+        cluster.add_connection(db);
+        cluster.broadcast_enabled = true;
+
+        let mut session = ClusterSession::new(&cluster);
+        session.update_session_status(web, ClusterSessionStatus::Connected);
+        session.update_session_status(db, ClusterSessionStatus::Connected);
+
+        let variables = member_variables(&[(web, "web01", "deploy"), (db, "db01", "postgres")]);
+        let targets = session
+            .broadcast_input("echo ${user}@${host} ${HOME}", &variables)
+            .unwrap();
+
+        assert_eq!(
+            targets,
+            vec![
+                BroadcastTarget {
+                    connection_id: web,
+                    input: "echo deploy@web01 ${HOME}".to_string(),
+                },
+                BroadcastTarget {
+                    connection_id: db,
+                    input: "echo postgres@db01 ${HOME}".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_broadcast_input_applies_member_template() {
+        let mut cluster = Cluster::new("Test".to_string());
+        let debian = Uuid::new_v4();
+        let bsd = Uuid::new_v4();
+        cluster.add_connection(debian);
+        cluster.add_connection(bsd);
+        cluster.set_command_template(bsd, "/usr/local/bin/sudo -u ${user} ${input}");
+        cluster.broadcast_enabled = true;
+
+        let mut session = ClusterSession::new(&cluster);
+        session.update_session_status(debian, ClusterSessionStatus::Connected);
+        session.update_session_status(bsd, ClusterSessionStatus::Connected);
+
+        let variables = member_variables(&[(debian, "deb01", "admin"), (bsd, "bsd01", "toor")]);
+        let targets = session
+            .broadcast_input("uptime # ${host}", &variables)
+            .unwrap();
+
+        assert_eq!(targets[0].input, "uptime # deb01");
+        assert_eq!(
+            targets[1].input,
+            "/usr/local/bin/sudo -u toor uptime # bsd01"
+        );
+    }
+
+    #[test]
+    fn test_command_template_lifecycle() {
+        let mut cluster = Cluster::new("Test".to_string());
+        let conn = Uuid::new_v4();
+        cluster.add_connection(conn);
+
+        cluster.set_command_template(conn, "sudo ${input}");
+        assert_eq!(cluster.command_template(conn), Some("sudo ${input}"));
+
+        cluster.set_command_template(conn, "");
+        assert_eq!(cluster.command_template(conn), None);
+
+        cluster.set_command_template(conn, "doas ${input}");
+        cluster.remove_connection(conn);
+        assert!(cluster.command_templates.is_empty());
+    }
+
+    fn staggered_cluster(members: usize, max_in_flight: usize) -> (Cluster, Vec<Uuid>) {
+        let mut cluster =
+            Cluster::new("Staggered".to_string()).with_staggered_start(0, max_in_flight);
+        let ids: Vec<Uuid> = (0..members).map(|_| Uuid::new_v4()).collect();
+        for id in &ids {
+            cluster.add_connection(*id);
+        }
+        (cluster, ids)
+    }
+
+    #[test]
+    fn test_staggered_start_respects_in_flight_cap() {
+        let (cluster, ids) = staggered_cluster(5, 2);
+        let mut session = ClusterSession::new(&cluster);
+
+        let wave = session.next_wave();
+        assert_eq!(wave, ids[..2]);
+        assert_eq!(session.in_flight_count(), 2);
+        assert_eq!(session.pending_count(), 3);
+        assert!(session.next_to_connect().is_none());
+
+        // Completing one member frees exactly one slot
+        session.update_session_status(ids[0], ClusterSessionStatus::Connected);
+        assert_eq!(session.next_wave(), vec![ids[2]]);
+        assert_eq!(session.in_flight_count(), 2);
+
+        // A failure frees a slot too
+        session.set_session_error(ids[1], "Auth failed".to_string());
+        assert_eq!(session.next_to_connect(), Some(ids[3]));
+        assert!(session.next_to_connect().is_none());
+
+        session.update_session_status(ids[2], ClusterSessionStatus::Connected);
+        session.update_session_status(ids[3], ClusterSessionStatus::Connected);
+        assert_eq!(session.next_wave(), vec![ids[4]]);
+        assert_eq!(session.pending_count(), 0);
+        assert!(session.next_to_connect().is_none());
+    }
+
+    #[test]
+    fn test_staggered_start_unlimited_releases_all_members() {
+        let (cluster, ids) = staggered_cluster(4, 0);
+        let mut session = ClusterSession::new(&cluster);
+
+        assert_eq!(session.next_wave(), ids);
+        assert_eq!(session.in_flight_count(), 4);
+        assert_eq!(session.pending_count(), 0);
+    }
+
+    #[test]
+    fn test_expire_stalled_frees_slots() {
+        let (cluster, ids) = staggered_cluster(3, 1);
+        let mut session = ClusterSession::new(&cluster);
+
+        assert_eq!(session.next_to_connect(), Some(ids[0]));
+        assert!(session.expire_stalled(Duration::from_secs(3600)).is_empty());
+        assert!(session.next_to_connect().is_none());
+
+        assert_eq!(session.expire_stalled(Duration::ZERO), vec![ids[0]]);
+        let state = session.get_session_state(ids[0]).unwrap();
+        assert_eq!(state.status, ClusterSessionStatus::Error);
+        assert!(state.error_message.is_some());
+        assert_eq!(session.next_to_connect(), Some(ids[1]));
+    }
+
+    #[test]
+    fn test_cluster_stagger_settings_default_when_missing() {
+        let json = format!(
+            r#"{{"id":"{}","name":"Legacy","connection_ids":[],"broadcast_enabled":false}}"#,
+            Uuid::new_v4()
+        );
+        let cluster: Cluster = serde_json::from_str(&json).unwrap();
+        assert_eq!(cluster.connect_delay_ms, 0);
+        assert_eq!(cluster.max_in_flight, 0);
+        assert!(!cluster.is_staggered());
+
+        let cluster = cluster.with_staggered_start(500, 4);
+        assert!(cluster.is_staggered());
+        assert_eq!(cluster.connect_delay(), Duration::from_millis(500));
+        assert_eq!(ClusterSession::new(&cluster).max_in_flight(), 4);
+    }
 }
 
 /// Manager for active cluster sessions
@@ -663,6 +1074,22 @@ impl ClusterManager {
         }
     }
 
+    /// Returns the next member of a staggered cluster start, marking it as
+    /// connecting (see [`ClusterSession::next_to_connect`])
+    pub fn next_to_connect(&mut self, cluster_id: Uuid) -> Option<Uuid> {
+        self.active_sessions
+            .get_mut(&cluster_id)
+            .and_then(ClusterSession::next_to_connect)
+    }
+
+    /// Returns how many members of an active cluster session are still pending
+    #[must_use]
+    pub fn pending_count(&self, cluster_id: Uuid) -> usize {
+        self.active_sessions
+            .get(&cluster_id)
+            .map_or(0, ClusterSession::pending_count)
+    }
+
     /// Gets the broadcast targets for a cluster (if in broadcast mode)
     #[must_use]
     pub fn get_broadcast_targets(&self, cluster_id: Uuid) -> Vec<Uuid> {
@@ -690,6 +1117,7 @@ impl ClusterManager {
                 cluster_name: session.cluster_name.clone(),
                 total_sessions: session.session_count(),
                 connected_count: session.connected_count(),
+                pending_count: session.pending_count(),
                 error_count: session.error_count(),
                 broadcast_mode: session.is_broadcast_mode(),
             })
@@ -707,6 +1135,8 @@ pub struct ClusterSessionSummary {
     pub total_sessions: usize,
     /// Number of connected sessions
     pub connected_count: usize,
+    /// Number of sessions not yet started
+    pub pending_count: usize,
     /// Number of sessions with errors
     pub error_count: usize,
     /// Whether broadcast mode is enabled
@@ -810,6 +1240,31 @@ mod manager_tests {
         assert_eq!(summary.cluster_name, "Test Cluster");
         assert_eq!(summary.total_sessions, 2);
         assert_eq!(summary.connected_count, 0);
+        assert_eq!(summary.pending_count, 2);
         assert!(summary.broadcast_mode);
     }
+
+    #[test]
+    fn test_cluster_manager_staggered_progress() {
+        let mut manager = ClusterManager::new();
+        let mut cluster = Cluster::new("Test".to_string()).with_staggered_start(100, 1);
+        let conn1 = Uuid::new_v4();
+        let conn2 = Uuid::new_v4();
+        cluster.add_connection(conn1);
+        cluster.add_connection(conn2);
+        let cluster_id = cluster.id;
+
+        manager.add_cluster(cluster);
+        manager.start_session(cluster_id).unwrap();
+        assert_eq!(manager.pending_count(cluster_id), 2);
+
+        assert_eq!(manager.next_to_connect(cluster_id), Some(conn1));
+        assert_eq!(manager.next_to_connect(cluster_id), None);
+        assert_eq!(manager.pending_count(cluster_id), 1);
+
+        manager.update_connection_status(cluster_id, conn1, ClusterSessionStatus::Connected);
+        assert_eq!(manager.next_to_connect(cluster_id), Some(conn2));
+        assert_eq!(manager.pending_count(cluster_id), 0);
+        assert_eq!(manager.next_to_connect(Uuid::new_v4()), None);
+    }
 }
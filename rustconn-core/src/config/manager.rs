@@ -14,8 +14,8 @@ use super::settings::AppSettings;
 use crate::cluster::Cluster;
 use crate::error::{ConfigError, ConfigResult};
 use crate::models::{
-    Connection, ConnectionGroup, ConnectionHistoryEntry, ConnectionTemplate, Snippet,
-    WorkspaceProfile,
+    Connection, ConnectionGroup, ConnectionHistoryEntry, ConnectionTemplate, PortForwardProfile,
+    Snippet, WorkspaceProfile,
 };
 use crate::sync::tombstone::Tombstone;
 
@@ -28,6 +28,7 @@ const TEMPLATES_FILE: &str = "templates.toml";
 const HISTORY_FILE: &str = "history.toml";
 const TRASH_FILE: &str = "trash.toml";
 const WORKSPACE_PROFILES_FILE: &str = "workspace_profiles.toml";
+const PORT_FORWARD_PROFILES_FILE: &str = "port_forward_profiles.toml";
 const TOMBSTONES_FILE: &str = "tombstones.toml";
 const CONFIG_FILE: &str = "config.toml";
 
@@ -96,6 +97,13 @@ struct WorkspaceProfilesFile {
     profiles: Vec<WorkspaceProfile>,
 }
 
+/// Wrapper for serializing port-forward profiles
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct PortForwardProfilesFile {
+    #[serde(default)]
+    profiles: Vec<PortForwardProfile>,
+}
+
 /// Configuration manager for `RustConn`
 ///
 /// Handles loading and saving configuration files in TOML format.
@@ -456,6 +464,36 @@ impl ConfigManager {
         self.save_toml_file(&path, &file)
     }
 
+    // ========== Port Forward Profiles ==========
+
+    /// Loads port-forward profiles from the configuration file
+    ///
+    /// Returns an empty vector if the file doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be parsed.
+    pub fn load_port_forward_profiles(&self) -> ConfigResult<Vec<PortForwardProfile>> {
+        let path = self.config_dir.join(PORT_FORWARD_PROFILES_FILE);
+        Self::load_toml_file::<PortForwardProfilesFile>(&path).map(|f| f.profiles)
+    }
+
+    /// Saves port-forward profiles to the configuration file
+    ///
+    /// Creates the configuration directory if it doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save_port_forward_profiles(&self, profiles: &[PortForwardProfile]) -> ConfigResult<()> {
+        self.ensure_config_dir()?;
+        let path = self.config_dir.join(PORT_FORWARD_PROFILES_FILE);
+        let file = PortForwardProfilesFile {
+            profiles: profiles.to_vec(),
+        };
+        self.save_toml_file(&path, &file)
+    }
+
     // ========== Connection History ==========
 
     /// Loads connection history from the configuration file
@@ -827,6 +865,20 @@ impl ConfigManager {
             });
         }
 
+        connection
+            .check_proxy()
+            .map_err(|e| ConfigError::Validation {
+                field: "proxy".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        connection
+            .check_access_tunnel()
+            .map_err(|e| ConfigError::Validation {
+                field: "access_tunnel".to_string(),
+                reason: e.to_string(),
+            })?;
+
         Ok(())
     }
 
@@ -1169,6 +1221,38 @@ mod tests {
         assert!(loaded.logging.enabled);
     }
 
+    #[test]
+    fn test_saved_searches_survive_reload() {
+        use crate::search::{SearchMode, saved::SavedSearch};
+
+        let (manager, _temp) = create_test_manager();
+
+        let mut settings = AppSettings::default();
+        settings.save_search(SavedSearch::new("on-call", "group:Prod tag:on-call"));
+        settings.save_search(SavedSearch::new("db", "^db-").with_mode(SearchMode::Regex));
+        // Saving under an existing name (any case) replaces the query
+        settings.save_search(SavedSearch::new(
+            "On-Call",
+            "group:Prod protocol:ssh tag:on-call",
+        ));
+        assert_eq!(settings.saved_searches.len(), 2);
+
+        manager.save_settings(&settings).unwrap();
+        let mut loaded = manager.load_settings().unwrap();
+
+        assert_eq!(loaded.saved_searches, settings.saved_searches);
+        let names: Vec<&str> = loaded
+            .list_searches()
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(names, ["db", "On-Call"]);
+
+        assert!(loaded.remove_saved_search("ON-CALL"));
+        assert!(!loaded.remove_saved_search("on-call"));
+        assert_eq!(loaded.saved_searches.len(), 1);
+    }
+
     #[test]
     fn test_validate_connection_empty_name() {
         let conn = Connection::new(
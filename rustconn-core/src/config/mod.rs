@@ -15,6 +15,6 @@ pub use manager::ConfigManager;
 pub use settings::{
     AppSettings, ColorScheme, ConnectionSettings, LoggingSettings, QuickConnectHistoryItem,
     SavedSession, SecretBackendType, SecretSettings, SessionRestoreSettings, StartupAction,
-    TerminalSettings, UiSettings,
+    TerminalSettings, UNLIMITED_SCROLLBACK, UiSettings, VTE_OSC52_PROPERTY,
 };
 // MonitoringSettings is re-exported from the monitoring module, not config
@@ -9,10 +9,12 @@ use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
 
 use crate::activity_monitor::ActivityMonitorDefaults;
-use crate::models::{HighlightRule, HistorySettings, SmartFolder};
+use crate::models::{Connection, HighlightRule, HistorySettings, SmartFolder};
 use crate::monitoring::MonitoringSettings;
+use crate::search::saved::SavedSearch;
 use crate::secret::CredentialStorage;
 use crate::sync::SyncSettings;
+use crate::terminal_themes::TerminalTheme;
 use crate::variables::Variable;
 
 /// Application-wide settings
@@ -67,6 +69,41 @@ pub struct AppSettings {
     /// Quick Connect history (protocol/host/port/username, no secrets)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub quick_connect_history: Vec<QuickConnectHistoryItem>,
+    /// Named searches, run again by name from the CLI
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub saved_searches: Vec<SavedSearch>,
+}
+
+impl AppSettings {
+    /// Saves a search, replacing any saved search with the same name
+    pub fn save_search(&mut self, search: SavedSearch) {
+        if let Some(existing) = self
+            .saved_searches
+            .iter_mut()
+            .find(|s| s.is_named(&search.name))
+        {
+            *existing = search;
+        } else {
+            self.saved_searches.push(search);
+        }
+    }
+
+    /// Removes the saved search called `name`
+    ///
+    /// Returns true if a search was removed.
+    pub fn remove_saved_search(&mut self, name: &str) -> bool {
+        let before = self.saved_searches.len();
+        self.saved_searches.retain(|s| !s.is_named(name));
+        self.saved_searches.len() != before
+    }
+
+    /// Returns the saved searches sorted by name
+    #[must_use]
+    pub fn list_searches(&self) -> Vec<&SavedSearch> {
+        let mut searches: Vec<&SavedSearch> = self.saved_searches.iter().collect();
+        searches.sort_by_key(|s| s.name.to_lowercase());
+        searches
+    }
 }
 
 /// Terminal-related settings
@@ -85,6 +122,9 @@ pub struct TerminalSettings {
     /// Scrollback buffer lines
     #[serde(default = "default_scrollback")]
     pub scrollback_lines: u32,
+    /// Keep the whole session in scrollback, ignoring `scrollback_lines`
+    #[serde(default)]
+    pub scrollback_unlimited: bool,
     /// Color theme
     #[serde(default = "default_color_theme")]
     pub color_theme: String,
@@ -149,6 +189,14 @@ pub struct TerminalSettings {
     /// On Linux this setting is ignored — Alt always sends ESC sequences.
     #[serde(default)]
     pub option_is_meta: bool,
+    /// Let remote programs set the local clipboard with OSC 52
+    ///
+    /// Off by default: any program on a remote host (or anything printed to
+    /// the terminal, such as a crafted log file) could otherwise overwrite
+    /// the clipboard. Connections may override it, see
+    /// [`Connection::osc52_clipboard`](crate::models::Connection::osc52_clipboard).
+    #[serde(default)]
+    pub osc52_clipboard: bool,
 }
 
 fn default_font_family() -> String {
@@ -208,12 +256,66 @@ const fn default_show_scrollbar() -> bool {
     true
 }
 
+/// Scrollback length meaning "keep every line", as VTE spells it
+pub const UNLIMITED_SCROLLBACK: i64 = -1;
+
+/// VTE property that controls OSC 52 clipboard writes
+///
+/// VTE builds without this property ignore OSC 52 altogether, so clipboard
+/// writes are already off there.
+pub const VTE_OSC52_PROPERTY: &str = "enable-osc52";
+
+impl TerminalSettings {
+    /// Returns the settings with a connection's overrides applied
+    ///
+    /// Overrides set on the connection win over the global values.
+    #[must_use]
+    pub fn for_connection(&self, connection: &Connection) -> Self {
+        let mut settings = self.clone();
+        if let Some(allow) = connection.osc52_clipboard {
+            settings.osc52_clipboard = allow;
+        }
+        if let Some(lines) = connection.scrollback_lines {
+            settings.scrollback_unlimited = lines < 0;
+            settings.scrollback_lines = u32::try_from(lines.max(0)).unwrap_or(u32::MAX);
+        }
+        settings
+    }
+
+    /// Returns the scrollback length to pass to VTE; `-1` means unlimited
+    #[must_use]
+    pub fn vte_scrollback_lines(&self) -> i64 {
+        if self.scrollback_unlimited {
+            UNLIMITED_SCROLLBACK
+        } else {
+            i64::from(self.scrollback_lines)
+        }
+    }
+
+    /// Returns the VTE property and value implementing
+    /// [`Self::osc52_clipboard`]
+    #[must_use]
+    pub const fn vte_osc52_property(&self) -> (&'static str, bool) {
+        (VTE_OSC52_PROPERTY, self.osc52_clipboard)
+    }
+
+    /// Returns the color theme terminals should use.
+    ///
+    /// The theme is the only part of the terminal settings that can be
+    /// re-applied to open terminals on its own (live preview).
+    #[must_use]
+    pub fn theme(&self) -> TerminalTheme {
+        TerminalTheme::resolve(&self.color_theme)
+    }
+}
+
 impl Default for TerminalSettings {
     fn default() -> Self {
         Self {
             font_family: default_font_family(),
             font_size: default_font_size(),
             scrollback_lines: default_scrollback(),
+            scrollback_unlimited: false,
             color_theme: default_color_theme(),
             cursor_shape: default_cursor_shape(),
             cursor_blink: default_cursor_blink(),
@@ -229,6 +331,7 @@ impl Default for TerminalSettings {
             local_shell_command: String::new(),
             close_on_clean_exit: false,
             option_is_meta: false,
+            osc52_clipboard: false,
         }
     }
 }
@@ -341,6 +444,12 @@ pub struct SecretSettings {
     /// Whether to save Bitwarden master password to libsecret
     #[serde(default)]
     pub bitwarden_save_to_keyring: bool,
+    /// Bitwarden server URL for self-hosted instances (e.g. Vaultwarden)
+    ///
+    /// When set, the `bw` CLI is pointed at this server before login or
+    /// unlock; `None` leaves the CLI's own server configuration untouched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bitwarden_server_url: Option<String>,
     /// Whether to save KeePass password to system keyring (libsecret/KWallet)
     #[serde(default)]
     pub kdbx_save_to_keyring: bool,
@@ -368,6 +477,22 @@ pub struct SecretSettings {
     /// Pass password store directory (defaults to ~/.password-store)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pass_store_dir: Option<PathBuf>,
+    /// HashiCorp Vault server address (defaults to `VAULT_ADDR`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vault_address: Option<String>,
+    /// Vault KV v2 mount path (defaults to `secret`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vault_mount: Option<String>,
+    /// Path prefix inside the Vault mount under which credentials are kept
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vault_path: Option<String>,
+    /// Vault token (NOT serialized - runtime only). When unset the token
+    /// comes from `VAULT_TOKEN`, `~/.vault-token` or a Vault Agent.
+    #[serde(skip)]
+    pub vault_token: Option<SecretString>,
+    /// Encrypted Vault token for persistence
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vault_token_encrypted: Option<String>,
 }
 
 const fn default_true() -> bool {
@@ -410,6 +535,7 @@ impl Default for SecretSettings {
             bitwarden_client_secret: None,
             bitwarden_client_secret_encrypted: None,
             bitwarden_save_to_keyring: false,
+            bitwarden_server_url: None,
             kdbx_save_to_keyring: false,
             onepassword_service_account_token: None,
             onepassword_service_account_token_encrypted: None,
@@ -419,6 +545,11 @@ impl Default for SecretSettings {
             passbolt_save_to_keyring: false,
             passbolt_server_url: None,
             pass_store_dir: None,
+            vault_address: None,
+            vault_mount: None,
+            vault_path: None,
+            vault_token: None,
+            vault_token_encrypted: None,
         }
     }
 }
@@ -439,6 +570,7 @@ impl PartialEq for SecretSettings {
             && self.bitwarden_client_id_encrypted == other.bitwarden_client_id_encrypted
             && self.bitwarden_client_secret_encrypted == other.bitwarden_client_secret_encrypted
             && self.bitwarden_save_to_keyring == other.bitwarden_save_to_keyring
+            && self.bitwarden_server_url == other.bitwarden_server_url
             && self.onepassword_service_account_token_encrypted
                 == other.onepassword_service_account_token_encrypted
             && self.onepassword_save_to_keyring == other.onepassword_save_to_keyring
@@ -446,9 +578,13 @@ impl PartialEq for SecretSettings {
             && self.passbolt_save_to_keyring == other.passbolt_save_to_keyring
             && self.passbolt_server_url == other.passbolt_server_url
             && self.pass_store_dir == other.pass_store_dir
+            && self.vault_address == other.vault_address
+            && self.vault_mount == other.vault_mount
+            && self.vault_path == other.vault_path
+            && self.vault_token_encrypted == other.vault_token_encrypted
         // Note: runtime-only SecretString fields (kdbx_password, bitwarden_password,
         // bitwarden_client_id, bitwarden_client_secret, onepassword_service_account_token,
-        // passbolt_passphrase) are intentionally excluded — they are #[serde(skip)]
+        // passbolt_passphrase, vault_token) are intentionally excluded — they are #[serde(skip)]
         // and not persisted, so they shouldn't affect settings equality.
     }
 }
@@ -482,6 +618,8 @@ pub enum SecretBackendType {
     /// as `"encrypted_file"` via `#[serde(rename_all = "snake_case")]`. Kept
     /// last so existing configs round-trip unchanged.
     EncryptedFile,
+    /// HashiCorp Vault KV v2 secrets engine (HTTP API)
+    Vault,
 }
 
 /// Color scheme preference
@@ -1078,6 +1216,36 @@ impl SecretSettings {
         false
     }
 
+    /// Encrypts the Vault token for storage using AES-256-GCM
+    pub fn encrypt_vault_token(&mut self) {
+        if let Some(ref token) = self.vault_token {
+            use secrecy::ExposeSecret;
+            if let Ok(encrypted) =
+                encrypt_credential(token.expose_secret().as_bytes(), &Self::get_machine_key())
+            {
+                self.vault_token_encrypted = Some(hex_encode(&encrypted));
+            }
+        }
+    }
+
+    /// Decrypts the stored Vault token
+    ///
+    /// Returns true if decryption was successful.
+    pub fn decrypt_vault_token(&mut self) -> bool {
+        if let Some(ref encrypted) = self.vault_token_encrypted
+            && let Some(decoded) = hex_decode(encrypted)
+        {
+            let key = Self::get_machine_key();
+            if let Ok(plaintext) = decrypt_credential(&decoded, &key)
+                && let Ok(token_str) = std::str::from_utf8(&plaintext)
+            {
+                self.vault_token = Some(SecretString::from(token_str.to_owned()));
+                return true;
+            }
+        }
+        false
+    }
+
     /// Gets a machine-specific key for encryption.
     ///
     /// Delegates to [`crate::secret::local_crypto::get_machine_key`].
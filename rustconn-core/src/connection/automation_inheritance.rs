@@ -5,6 +5,8 @@
 //! automation config (non-empty), it takes precedence. Otherwise, the first
 //! group in the parent chain with non-empty rules is used.
 //!
+//! The one-time-password toggle is per connection and never inherited.
+//!
 //! Cycle detection via `HashSet<Uuid>` ensures termination even with
 //! malformed parent_id chains.
 
@@ -51,6 +53,8 @@ pub fn resolve_automation(connection: &Connection, groups: &[ConnectionGroup]) -
     AutomationConfig {
         expect_rules,
         post_login_scripts,
+        answer_otp_prompts: connection.automation.answer_otp_prompts,
+        otp_variable: connection.automation.otp_variable.clone(),
     }
 }
 
@@ -209,4 +213,24 @@ mod tests {
         assert!(result.expect_rules.is_empty());
         assert!(result.post_login_scripts.is_empty());
     }
+
+    #[test]
+    fn otp_toggle_adds_session_rule() {
+        let mut group = ConnectionGroup::new("G".into());
+        group.expect_rules = vec![make_rule("password:", "secret")];
+
+        let mut conn = Connection::new_ssh("test".into(), "host".into(), 22);
+        conn.group_id = Some(group.id);
+        let result = resolve_automation(&conn, std::slice::from_ref(&group));
+        assert_eq!(result.session_rules().len(), 1);
+
+        conn.automation.answer_otp_prompts = true;
+        conn.automation.otp_variable = Some("bastion_otp".into());
+        let rules = resolve_automation(&conn, &[group]).session_rules();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].pattern, "password:");
+        let secret = rules[1].secret.as_ref().unwrap();
+        assert_eq!(secret.variable, "bastion_otp");
+        assert!(secret.prompt);
+    }
 }
@@ -0,0 +1,106 @@
+//! Bulk connection edits
+//!
+//! A [`BulkEdit`] describes one field change; `ConnectionManager::bulk_update`
+//! applies a list of them to many connections at once. Edits are applied to
+//! copies first and every result is validated, so either all connections
+//! change or none do.
+
+use uuid::Uuid;
+
+use crate::models::Connection;
+
+/// A single field change applied by `ConnectionManager::bulk_update`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkEdit {
+    /// Moves connections to a group (`None` for ungrouped)
+    SetGroup(Option<Uuid>),
+    /// Sets the port
+    SetPort(u16),
+    /// Replaces every occurrence of `find` in the host with `replace`
+    ReplaceInHost {
+        /// Text to search for (case-sensitive)
+        find: String,
+        /// Replacement text
+        replace: String,
+    },
+    /// Adds a tag unless the connection already has it
+    AddTag(String),
+    /// Removes a tag
+    RemoveTag(String),
+}
+
+impl BulkEdit {
+    /// Applies the edit to `connection`; returns `true` if anything changed
+    pub fn apply(&self, connection: &mut Connection) -> bool {
+        match self {
+            Self::SetGroup(group_id) => {
+                let changed = connection.group_id != *group_id;
+                connection.group_id = *group_id;
+                changed
+            }
+            Self::SetPort(port) => {
+                let changed = connection.port != *port;
+                connection.port = *port;
+                changed
+            }
+            Self::ReplaceInHost { find, replace } => {
+                if find.is_empty() || !connection.host.contains(find.as_str()) {
+                    return false;
+                }
+                connection.host = connection.host.replace(find.as_str(), replace);
+                true
+            }
+            Self::AddTag(tag) => {
+                if tag.trim().is_empty() || connection.tags.contains(tag) {
+                    return false;
+                }
+                connection.tags.push(tag.clone());
+                true
+            }
+            Self::RemoveTag(tag) => {
+                let before = connection.tags.len();
+                connection.tags.retain(|t| t != tag);
+                connection.tags.len() != before
+            }
+        }
+    }
+}
+
+/// Applies `edits` in order; returns `true` if any of them changed something
+pub(super) fn apply_all(edits: &[BulkEdit], connection: &mut Connection) -> bool {
+    edits
+        .iter()
+        .fold(false, |changed, edit| edit.apply(connection) || changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edits_report_changes() {
+        let mut conn = Connection::new_ssh("web".to_string(), "web.old.lan".to_string(), 22);
+        conn.tags = vec!["prod".to_string()];
+
+        assert!(!BulkEdit::SetPort(22).apply(&mut conn));
+        assert!(!BulkEdit::AddTag("prod".to_string()).apply(&mut conn));
+        assert!(!BulkEdit::RemoveTag("staging".to_string()).apply(&mut conn));
+        let noop = BulkEdit::ReplaceInHost {
+            find: String::new(),
+            replace: "x".to_string(),
+        };
+        assert!(!noop.apply(&mut conn));
+
+        let edits = [
+            BulkEdit::ReplaceInHost {
+                find: "old.lan".to_string(),
+                replace: "new.lan".to_string(),
+            },
+            BulkEdit::AddTag("migrated".to_string()),
+            BulkEdit::RemoveTag("prod".to_string()),
+        ];
+        assert!(apply_all(&edits, &mut conn));
+        assert_eq!(conn.host, "web.new.lan");
+        assert_eq!(conn.tags, ["migrated"]);
+    }
+}
@@ -0,0 +1,170 @@
+//! Connection dependencies
+//!
+//! A connection may list other connections in `depends_on` that must be
+//! connected before it (e.g. a database tunnel before the app server). This
+//! module resolves the launch order for a connection by walking its
+//! dependency graph depth-first, rejecting cycles and dangling references.
+
+use std::collections::HashSet;
+
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Error type for dependency resolution and dependency launches
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DependencyError {
+    /// The connection being launched does not exist
+    #[error("Connection not found: {0}")]
+    NotFound(Uuid),
+    /// A connection depends on a connection that does not exist
+    #[error("Connection {connection} depends on missing connection {dependency}")]
+    MissingDependency {
+        /// The connection declaring the dependency
+        connection: Uuid,
+        /// The dependency that could not be found
+        dependency: Uuid,
+    },
+    /// The dependency graph contains a cycle
+    #[error("Dependency cycle: {}", format_cycle(.0))]
+    Cycle(Vec<Uuid>),
+    /// A dependency failed to connect, so the dependent was not launched
+    #[error("Dependency {dependency} of connection {connection} failed: {reason}")]
+    DependencyFailed {
+        /// The connection that was being launched
+        connection: Uuid,
+        /// The dependency that failed
+        dependency: Uuid,
+        /// Why the dependency failed
+        reason: String,
+    },
+}
+
+fn format_cycle(cycle: &[Uuid]) -> String {
+    cycle
+        .iter()
+        .map(Uuid::to_string)
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+/// Resolves the order in which `root` and its dependencies must be connected.
+///
+/// `dependencies_of` returns a connection's `depends_on` list, or `None` if
+/// the connection does not exist. The result is topologically ordered:
+/// every connection appears after all of its dependencies, each connection
+/// appears once, and `root` is last. Dependencies are visited in the order
+/// they are declared.
+///
+/// # Errors
+///
+/// Returns `DependencyError::NotFound` if `root` does not exist,
+/// `DependencyError::MissingDependency` for a dangling reference, and
+/// `DependencyError::Cycle` (listing the cycle, first node repeated at the
+/// end) if the graph is cyclic.
+pub fn resolve_launch_order<'a, F>(
+    root: Uuid,
+    dependencies_of: F,
+) -> Result<Vec<Uuid>, DependencyError>
+where
+    F: Fn(Uuid) -> Option<&'a [Uuid]>,
+{
+    if dependencies_of(root).is_none() {
+        return Err(DependencyError::NotFound(root));
+    }
+
+    let mut order = Vec::new();
+    let mut done = HashSet::new();
+    // Explicit DFS stack of (connection, next dependency index); `path`
+    // mirrors the stack for cycle reporting.
+    let mut stack: Vec<(Uuid, usize)> = vec![(root, 0)];
+    let mut path: Vec<Uuid> = vec![root];
+
+    while let Some((id, next)) = stack.pop() {
+        let deps = dependencies_of(id).unwrap_or_default();
+        let Some(&dependency) = deps.get(next) else {
+            path.pop();
+            if done.insert(id) {
+                order.push(id);
+            }
+            continue;
+        };
+        stack.push((id, next + 1));
+
+        if done.contains(&dependency) {
+            continue;
+        }
+        if let Some(start) = path.iter().position(|&p| p == dependency) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(dependency);
+            return Err(DependencyError::Cycle(cycle));
+        }
+        if dependencies_of(dependency).is_none() {
+            return Err(DependencyError::MissingDependency {
+                connection: id,
+                dependency,
+            });
+        }
+        stack.push((dependency, 0));
+        path.push(dependency);
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn graph(edges: &[(Uuid, Vec<Uuid>)]) -> HashMap<Uuid, Vec<Uuid>> {
+        edges.iter().cloned().collect()
+    }
+
+    fn resolve(graph: &HashMap<Uuid, Vec<Uuid>>, root: Uuid) -> Result<Vec<Uuid>, DependencyError> {
+        resolve_launch_order(root, |id| graph.get(&id).map(Vec::as_slice))
+    }
+
+    #[test]
+    fn no_dependencies_launches_only_root() {
+        let a = Uuid::new_v4();
+        let g = graph(&[(a, vec![])]);
+        assert_eq!(resolve(&g, a), Ok(vec![a]));
+    }
+
+    #[test]
+    fn dependencies_come_first_and_once() {
+        // app -> [tunnel, db], db -> [tunnel]
+        let (app, db, tunnel) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let g = graph(&[
+            (app, vec![tunnel, db]),
+            (db, vec![tunnel]),
+            (tunnel, vec![]),
+        ]);
+        assert_eq!(resolve(&g, app), Ok(vec![tunnel, db, app]));
+    }
+
+    #[test]
+    fn cycle_is_reported() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let g = graph(&[(a, vec![b]), (b, vec![c]), (c, vec![b])]);
+        assert_eq!(resolve(&g, a), Err(DependencyError::Cycle(vec![b, c, b])));
+
+        let g = graph(&[(a, vec![a])]);
+        assert_eq!(resolve(&g, a), Err(DependencyError::Cycle(vec![a, a])));
+    }
+
+    #[test]
+    fn missing_dependency_and_root_are_reported() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let g = graph(&[(a, vec![b])]);
+        assert_eq!(
+            resolve(&g, a),
+            Err(DependencyError::MissingDependency {
+                connection: a,
+                dependency: b,
+            })
+        );
+        assert_eq!(resolve(&g, b), Err(DependencyError::NotFound(b)));
+    }
+}
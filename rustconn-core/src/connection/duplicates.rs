@@ -0,0 +1,144 @@
+//! Duplicate connection detection
+//!
+//! Repeated imports tend to leave several connections pointing at the same
+//! endpoint. Two connections are duplicates when they share protocol, host,
+//! port and username. Hosts are compared case-insensitively and a trailing
+//! dot (fully qualified `example.com.`) is ignored; usernames are compared
+//! exactly, since remote systems may treat them case-sensitively.
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::cluster::Cluster;
+use crate::models::{Connection, ConnectionGroup, ProtocolConfig, ProtocolType};
+
+/// Identity of a connection's endpoint used for duplicate matching
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DuplicateKey {
+    protocol: ProtocolType,
+    host: String,
+    port: u16,
+    username: Option<String>,
+}
+
+impl DuplicateKey {
+    /// Returns `None` for connections that cannot be matched (no host, or
+    /// generated by a dynamic folder and therefore regenerated on refresh)
+    fn of(connection: &Connection) -> Option<Self> {
+        let host = connection.host.trim().trim_end_matches('.');
+        if host.is_empty() || connection.is_dynamic {
+            return None;
+        }
+        Some(Self {
+            protocol: connection.protocol,
+            host: host.to_lowercase(),
+            port: connection.port,
+            username: connection
+                .username
+                .as_deref()
+                .map(str::trim)
+                .filter(|u| !u.is_empty())
+                .map(str::to_string),
+        })
+    }
+}
+
+/// Groups duplicate connections, oldest first within each set
+///
+/// Only sets with at least two connections are returned. Sets are ordered by
+/// their oldest connection; ties on `created_at` are broken by ID so the
+/// result is stable.
+#[must_use]
+pub fn find_duplicates<'a, I>(connections: I) -> Vec<Vec<Uuid>>
+where
+    I: IntoIterator<Item = &'a Connection>,
+{
+    let mut sets: HashMap<DuplicateKey, Vec<&Connection>> = HashMap::new();
+    for connection in connections {
+        if let Some(key) = DuplicateKey::of(connection) {
+            sets.entry(key).or_default().push(connection);
+        }
+    }
+
+    let mut sets: Vec<Vec<&Connection>> = sets.into_values().filter(|s| s.len() > 1).collect();
+    for set in &mut sets {
+        set.sort_by_key(|c| (c.created_at, c.id));
+    }
+    sets.sort_by_key(|set| (set[0].created_at, set[0].id));
+    sets.into_iter()
+        .map(|set| set.into_iter().map(|c| c.id).collect())
+        .collect()
+}
+
+/// Re-points references to merged connections at the connection kept
+///
+/// `replaced` maps each removed connection ID to the ID that replaces it.
+/// Updates `depends_on` lists and jump-host IDs of `connections`, group
+/// jump hosts, and cluster members (including their command templates; the
+/// kept connection's own template wins). Returns the IDs of the connections
+/// that changed.
+pub(super) fn repoint_references<'a>(
+    replaced: &HashMap<Uuid, Uuid>,
+    connections: impl IntoIterator<Item = &'a mut Connection>,
+    groups: impl IntoIterator<Item = &'a mut ConnectionGroup>,
+    clusters: &mut [Cluster],
+) -> Vec<Uuid> {
+    let mut changed = Vec::new();
+    for connection in connections {
+        let own_id = connection.id;
+        let mut depends_on = Vec::with_capacity(connection.depends_on.len());
+        for id in &connection.depends_on {
+            let id = replaced.get(id).copied().unwrap_or(*id);
+            if id != own_id && !depends_on.contains(&id) {
+                depends_on.push(id);
+            }
+        }
+        let mut dirty = depends_on != connection.depends_on;
+        connection.depends_on = depends_on;
+        if let Some(jump) = jump_host_id_mut(connection)
+            && let Some(kept) = jump.and_then(|id| replaced.get(&id))
+        {
+            *jump = Some(*kept);
+            dirty = true;
+        }
+        if dirty {
+            changed.push(own_id);
+        }
+    }
+
+    for group in groups {
+        if let Some(kept) = group.ssh_jump_host_id.and_then(|id| replaced.get(&id)) {
+            group.ssh_jump_host_id = Some(*kept);
+        }
+    }
+
+    for cluster in clusters {
+        let mut members = Vec::with_capacity(cluster.connection_ids.len());
+        for id in &cluster.connection_ids {
+            let id = replaced.get(id).copied().unwrap_or(*id);
+            if !members.contains(&id) {
+                members.push(id);
+            }
+        }
+        cluster.connection_ids = members;
+        for (removed, kept) in replaced {
+            if let Some(template) = cluster.command_templates.remove(removed) {
+                cluster.command_templates.entry(*kept).or_insert(template);
+            }
+        }
+    }
+
+    changed
+}
+
+/// Returns the connection's jump-host reference, for protocols that have one
+fn jump_host_id_mut(connection: &mut Connection) -> Option<&mut Option<Uuid>> {
+    match &mut connection.protocol_config {
+        ProtocolConfig::Ssh(c) | ProtocolConfig::Sftp(c) => Some(&mut c.jump_host_id),
+        ProtocolConfig::Rdp(c) => Some(&mut c.jump_host_id),
+        ProtocolConfig::Vnc(c) => Some(&mut c.jump_host_id),
+        ProtocolConfig::Spice(c) => Some(&mut c.jump_host_id),
+        _ => None,
+    }
+}
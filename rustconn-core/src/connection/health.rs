@@ -0,0 +1,308 @@
+//! Background connection health checks
+//!
+//! [`HealthCheckScheduler`] probes connections with [`ConnectionTester`] at a
+//! fixed interval and caches the latest outcome per connection ID, so the UI
+//! can show reachability without waiting on the network.
+//!
+//! Connections the user opted out of port checks for, Wake-on-LAN machines
+//! (which are expected to be asleep), and connections that cannot be probed
+//! directly (gateways, jump hosts, serial lines, …) are never probed.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::models::{Connection, ProtocolType};
+use crate::testing::{ConnectionTester, TestResult};
+
+/// Default interval between probe rounds (5 minutes)
+pub const DEFAULT_PROBE_INTERVAL_SECS: u64 = 300;
+
+/// Default number of connections probed at the same time
+pub const DEFAULT_PROBE_CONCURRENCY: usize = 4;
+
+/// Default timeout for a single probe
+pub const DEFAULT_PROBE_TIMEOUT_SECS: u64 = 5;
+
+/// Settings for [`HealthCheckScheduler`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthProbeConfig {
+    /// Time between the start of two probe rounds
+    pub interval: Duration,
+    /// Maximum number of probes in flight
+    pub max_concurrency: usize,
+    /// Timeout for a single probe
+    pub timeout: Duration,
+}
+
+impl HealthProbeConfig {
+    /// Sets the interval between probe rounds
+    #[must_use]
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the maximum number of probes in flight (at least one)
+    #[must_use]
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Sets the timeout for a single probe
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Default for HealthProbeConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(DEFAULT_PROBE_INTERVAL_SECS),
+            max_concurrency: DEFAULT_PROBE_CONCURRENCY,
+            timeout: Duration::from_secs(DEFAULT_PROBE_TIMEOUT_SECS),
+        }
+    }
+}
+
+/// Last known health of a connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectionHealth {
+    /// Whether the last probe succeeded
+    pub reachable: bool,
+    /// Latency of the last successful probe in milliseconds
+    pub latency_ms: Option<u64>,
+    /// Error of the last failed probe
+    pub error: Option<String>,
+    /// When the last probe finished
+    pub checked_at: DateTime<Utc>,
+}
+
+impl ConnectionHealth {
+    /// Builds a status from a tester result
+    #[must_use]
+    pub fn from_result(result: &TestResult, checked_at: DateTime<Utc>) -> Self {
+        Self {
+            reachable: result.success,
+            latency_ms: result.latency_ms,
+            error: result.error.clone(),
+            checked_at,
+        }
+    }
+}
+
+/// Periodically probes connections and caches the results
+///
+/// Clones share the same cache, so a clone can be moved into the background
+/// task while the original answers [`Self::health_status`] queries.
+#[derive(Debug, Clone, Default)]
+pub struct HealthCheckScheduler {
+    config: HealthProbeConfig,
+    cache: Arc<RwLock<HashMap<Uuid, ConnectionHealth>>>,
+}
+
+impl HealthCheckScheduler {
+    /// Creates a scheduler with an empty cache
+    #[must_use]
+    pub fn new(config: HealthProbeConfig) -> Self {
+        Self {
+            config,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Returns the scheduler settings
+    #[must_use]
+    pub const fn config(&self) -> &HealthProbeConfig {
+        &self.config
+    }
+
+    /// Returns the last probe result for a connection, if it was probed
+    #[must_use]
+    pub fn health_status(&self, id: Uuid) -> Option<ConnectionHealth> {
+        self.cache
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(&id)
+            .cloned()
+    }
+
+    /// Stores a probe result, replacing the previous one
+    pub fn record(&self, result: &TestResult, checked_at: DateTime<Utc>) {
+        self.cache
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(
+                result.connection_id,
+                ConnectionHealth::from_result(result, checked_at),
+            );
+    }
+
+    /// Drops cached results for connections not in `ids`
+    ///
+    /// Call this after connections are deleted so stale entries do not
+    /// accumulate.
+    pub fn retain(&self, ids: &[Uuid]) {
+        self.cache
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .retain(|id, _| ids.contains(id));
+    }
+
+    /// Returns `true` if the scheduler may probe `connection`
+    ///
+    /// Connections with `skip_port_check`, a Wake-on-LAN configuration, no
+    /// network endpoint (serial, Kubernetes), or that bypass direct probing
+    /// are skipped.
+    #[must_use]
+    pub fn should_probe(connection: &Connection) -> bool {
+        !connection.skip_port_check
+            && connection.wol_config.is_none()
+            && !connection.bypasses_direct_probe()
+            && !matches!(
+                connection.protocol,
+                ProtocolType::Serial | ProtocolType::Kubernetes
+            )
+            && !connection.host.trim().is_empty()
+            && connection.port != 0
+    }
+
+    /// Runs one probe round over the connections that may be probed
+    ///
+    /// At most `max_concurrency` probes run at once; each result is cached
+    /// as soon as it arrives. Returns the number of connections probed.
+    pub async fn probe(&self, connections: &[Connection]) -> usize {
+        let tester = ConnectionTester::with_timeout(self.config.timeout);
+        let probed = stream::iter(connections.iter().filter(|c| Self::should_probe(c)))
+            .map(|connection| tester.test_connection(connection))
+            .buffer_unordered(self.config.max_concurrency.max(1))
+            .fold(0, |count, result| async move {
+                self.record(&result, Utc::now());
+                count + 1
+            })
+            .await;
+        tracing::debug!(probed, "Connection health check round finished");
+        probed
+    }
+
+    /// Starts probing in the background every `interval`
+    ///
+    /// `connections` is called at the start of each round, so edits are
+    /// picked up without restarting the scheduler. The first round runs
+    /// immediately. Must be called from within a Tokio runtime.
+    #[must_use]
+    pub fn spawn<F>(&self, connections: F) -> HealthCheckHandle
+    where
+        F: Fn() -> Vec<Connection> + Send + 'static,
+    {
+        let scheduler = self.clone();
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(scheduler.config.interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let connections = connections();
+                scheduler.probe(&connections).await;
+            }
+        });
+        HealthCheckHandle { handle }
+    }
+}
+
+/// Handle to a running health check loop; dropping it stops the loop
+#[derive(Debug)]
+pub struct HealthCheckHandle {
+    handle: JoinHandle<()>,
+}
+
+impl HealthCheckHandle {
+    /// Stops probing. Cached results are kept.
+    pub fn stop(&self) {
+        self.handle.abort();
+    }
+}
+
+impl Drop for HealthCheckHandle {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wol::{MacAddress, WolConfig};
+
+    #[test]
+    fn test_record_updates_cache() {
+        let scheduler = HealthCheckScheduler::default();
+        let id = Uuid::new_v4();
+        assert_eq!(scheduler.health_status(id), None);
+
+        let first = Utc::now();
+        scheduler.record(&TestResult::success(id, "web".to_string(), 12), first);
+        let status = scheduler.health_status(id).unwrap();
+        assert!(status.reachable);
+        assert_eq!(status.latency_ms, Some(12));
+        assert_eq!(status.checked_at, first);
+
+        let second = first + chrono::Duration::seconds(60);
+        scheduler.record(
+            &TestResult::failure(id, "web".to_string(), "Connection refused"),
+            second,
+        );
+        let status = scheduler.clone().health_status(id).unwrap();
+        assert!(!status.reachable);
+        assert_eq!(status.error.as_deref(), Some("Connection refused"));
+        assert_eq!(status.checked_at, second);
+
+        scheduler.retain(&[]);
+        assert_eq!(scheduler.health_status(id), None);
+    }
+
+    #[test]
+    fn test_config_clamps_concurrency() {
+        let config = HealthProbeConfig::default().with_max_concurrency(0);
+        assert_eq!(config.max_concurrency, 1);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_connections_are_never_probed() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let enabled = Connection::new_rdp("up".to_string(), "127.0.0.1".to_string(), port);
+        let mut skipped = Connection::new_rdp("skip".to_string(), "127.0.0.1".to_string(), port);
+        skipped.skip_port_check = true;
+        let mut asleep = Connection::new_rdp("wol".to_string(), "127.0.0.1".to_string(), port);
+        asleep.wol_config = Some(WolConfig::new(MacAddress::new([
+            0, 0x11, 0x22, 0x33, 0x44, 0x55,
+        ])));
+        let serial = Connection::new_serial("console".to_string(), "/dev/ttyS0".to_string());
+
+        let scheduler = HealthCheckScheduler::new(
+            HealthProbeConfig::default().with_timeout(Duration::from_secs(2)),
+        );
+        let connections = [
+            enabled.clone(),
+            skipped.clone(),
+            asleep.clone(),
+            serial.clone(),
+        ];
+        assert_eq!(scheduler.probe(&connections).await, 1);
+
+        assert!(scheduler.health_status(enabled.id).unwrap().reachable);
+        for connection in [&skipped, &asleep, &serial] {
+            assert!(!HealthCheckScheduler::should_probe(connection));
+            assert_eq!(scheduler.health_status(connection.id), None);
+        }
+    }
+}
@@ -4,12 +4,17 @@
 //! loaded and provides methods to load children on demand. This improves startup
 //! performance for large connection databases by only loading root-level items
 //! initially and loading children when groups are expanded.
+//!
+//! [`LazyGroupLoader::visible_rows`] lists the rows of the expanded tree for a
+//! [`GroupedVirtualScroller`](crate::performance::GroupedVirtualScroller),
+//! loading only the groups that are open.
 
 use std::collections::HashSet;
 
 use uuid::Uuid;
 
 use crate::models::{Connection, ConnectionGroup};
+use crate::performance::RowKind;
 
 /// Tracks which groups have been loaded for lazy loading
 ///
@@ -153,6 +158,59 @@ impl LazyGroupLoader {
         (root_groups, ungrouped_connections)
     }
 
+    /// Lists the rows of the connection tree as the sidebar shows it
+    ///
+    /// Groups come first, sorted by sort order and then name, followed by
+    /// ungrouped connections. Inside a group, child groups precede its
+    /// connections. The children of a group are only listed, and the group
+    /// marked loaded, when it is in `expanded`; a collapsed group is a
+    /// single header row.
+    ///
+    /// # Arguments
+    ///
+    /// * `all_groups` - All groups in the database
+    /// * `all_connections` - All connections in the database
+    /// * `expanded` - IDs of the expanded groups
+    pub fn visible_rows(
+        &mut self,
+        all_groups: &[ConnectionGroup],
+        all_connections: &[Connection],
+        expanded: &HashSet<Uuid>,
+    ) -> Vec<RowKind> {
+        let (mut root_groups, ungrouped) = self.get_root_items_to_load(all_groups, all_connections);
+        sort_groups(&mut root_groups);
+        self.mark_root_loaded();
+
+        let mut rows = Vec::new();
+        for group in root_groups {
+            self.push_group_rows(group, all_groups, all_connections, expanded, &mut rows);
+        }
+        rows.extend(std::iter::repeat_n(RowKind::Item, ungrouped.len()));
+        rows
+    }
+
+    fn push_group_rows(
+        &mut self,
+        group: &ConnectionGroup,
+        all_groups: &[ConnectionGroup],
+        all_connections: &[Connection],
+        expanded: &HashSet<Uuid>,
+        rows: &mut Vec<RowKind>,
+    ) {
+        rows.push(RowKind::Header);
+        if !expanded.contains(&group.id) {
+            return;
+        }
+        let (mut child_groups, connections) =
+            self.get_children_to_load(group.id, all_groups, all_connections);
+        self.mark_group_loaded(group.id);
+        sort_groups(&mut child_groups);
+        for child in child_groups {
+            self.push_group_rows(child, all_groups, all_connections, expanded, rows);
+        }
+        rows.extend(std::iter::repeat_n(RowKind::Item, connections.len()));
+    }
+
     /// Checks if a group needs to be loaded
     ///
     /// Returns true if the group has not been loaded yet.
@@ -166,6 +224,15 @@ impl LazyGroupLoader {
     }
 }
 
+/// Sorts groups by sort order, then case-insensitively by name
+fn sort_groups(groups: &mut [&ConnectionGroup]) {
+    groups.sort_by(|a, b| {
+        a.sort_order
+            .cmp(&b.sort_order)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+}
+
 #[cfg(test)]
 #[expect(
     clippy::similar_names,
@@ -323,4 +390,69 @@ mod tests {
         assert!(!loader.is_group_loaded(group3));
         assert_eq!(loader.loaded_count(), 2);
     }
+
+    #[test]
+    fn test_visible_rows_follow_expanded_groups() {
+        use crate::performance::{GroupedVirtualScroller, VirtualScrollConfig};
+
+        let mut loader = LazyGroupLoader::new();
+        let mut prod = create_test_group("prod", None);
+        prod.sort_order = 1;
+        let staging = create_test_group("staging", None);
+        let db = create_test_group("db", Some(prod.id));
+
+        let mut connections: Vec<Connection> = (0..3)
+            .map(|i| create_test_connection(&format!("web{i}"), Some(prod.id)))
+            .collect();
+        connections.extend((0..4).map(|i| create_test_connection(&format!("pg{i}"), Some(db.id))));
+        connections.push(create_test_connection("stage", Some(staging.id)));
+        connections.push(create_test_connection("laptop", None));
+        let groups = vec![prod.clone(), staging.clone(), db.clone()];
+
+        // All collapsed: two headers, then the ungrouped connection
+        let collapsed = loader.visible_rows(&groups, &connections, &HashSet::new());
+        assert_eq!(collapsed, [RowKind::Header, RowKind::Header, RowKind::Item]);
+        assert!(loader.is_root_loaded());
+        assert_eq!(loader.loaded_count(), 0);
+
+        // staging (sort order 0) first; prod shows db collapsed, then its items
+        let expanded = HashSet::from([prod.id, staging.id]);
+        let rows = loader.visible_rows(&groups, &connections, &expanded);
+        assert_eq!(
+            rows,
+            [
+                RowKind::Header,
+                RowKind::Item,
+                RowKind::Header,
+                RowKind::Header,
+                RowKind::Item,
+                RowKind::Item,
+                RowKind::Item,
+                RowKind::Item,
+            ]
+        );
+        assert!(loader.is_group_loaded(prod.id));
+        assert!(loader.needs_loading(db.id));
+
+        let config = VirtualScrollConfig {
+            item_height: 20.0,
+            header_height: 30.0,
+            overscan: 1,
+        };
+        let mut scroller = GroupedVirtualScroller::from_config(60.0, config);
+        scroller.set_row_kinds(rows);
+        // 3 headers * 30 + 5 items * 20
+        assert!((scroller.total_height() - 190.0).abs() < f64::EPSILON);
+        scroller.set_scroll_offset(80.0);
+        // Viewport 80..140 covers rows 3..=5 (tops 80, 110, 130), plus overscan
+        assert_eq!(scroller.visible_range(), 2..7);
+
+        // Expanding db adds its four connections after its header
+        let expanded = HashSet::from([prod.id, staging.id, db.id]);
+        let rows = loader.visible_rows(&groups, &connections, &expanded);
+        assert_eq!(rows.len(), 12);
+        assert_eq!(rows[4..8], [RowKind::Item; 4]);
+        scroller.set_row_kinds(rows);
+        assert!((scroller.total_height() - 270.0).abs() < f64::EPSILON);
+    }
 }
@@ -3,18 +3,27 @@
 //! This module provides the `ConnectionManager` which handles creating, reading,
 //! updating, and deleting connections with persistence through `ConfigManager`.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use tokio::sync::{mpsc, watch};
 use uuid::Uuid;
 
+use super::{BulkEdit, DependencyError};
+use crate::cluster::Cluster;
 use crate::config::ConfigManager;
+use crate::document::{DocumentImportReport, DocumentManager, NameCollision};
 use crate::error::{ConfigError, ConfigResult};
-use crate::models::{Connection, ConnectionGroup, ProtocolConfig};
+use crate::export::NativeExport;
+use crate::import::{ImportPreview, ImportResult, MergeStrategy};
+use crate::models::{
+    ConnectGuardDecision, Connection, ConnectionGroup, ConnectionHistoryEntry, HistorySettings,
+    ProtocolConfig,
+};
 use crate::performance::interner;
-use crate::sync::SyncMode;
+use crate::sync::{PeerError, PeerInfo, PeerPullReport, SyncMode};
+use crate::variables::Variable;
 
 /// Tuple containing validation/creation, timestamp
 type TrashEntry<T> = (T, chrono::DateTime<Utc>);
@@ -24,6 +33,18 @@ type TrashData = (
     Vec<TrashEntry<ConnectionGroup>>,
 );
 
+/// Order for [`ConnectionManager::sorted`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectionSortKey {
+    /// Alphabetically by name, case-insensitive
+    #[default]
+    Name,
+    /// Most recently connected first; never-connected connections last
+    LastConnected,
+    /// Most connection attempts first
+    ConnectCount,
+}
+
 /// Manager for connection CRUD operations
 ///
 /// Provides in-memory storage with persistence through `ConfigManager`.
@@ -343,6 +364,40 @@ impl ConnectionManager {
         updated.window_geometry = existing.window_geometry;
         updated.is_pinned = existing.is_pinned;
         updated.pin_order = existing.pin_order;
+        updated.depends_on = existing.depends_on.clone();
+        updated.proxy = existing.proxy.clone();
+        updated.access_tunnel = existing.access_tunnel.clone();
+        updated.connect_guard = existing.connect_guard.clone();
+        updated.osc52_clipboard = existing.osc52_clipboard;
+        updated.scrollback_lines = existing.scrollback_lines;
+        if let (
+            ProtocolConfig::Ssh(new) | ProtocolConfig::Sftp(new),
+            ProtocolConfig::Ssh(old) | ProtocolConfig::Sftp(old),
+        ) = (&mut updated.protocol_config, &existing.protocol_config)
+        {
+            if new.jump_hosts.is_empty() {
+                new.jump_hosts.clone_from(&old.jump_hosts);
+            }
+            if new.port_forward_profiles.is_empty() {
+                new.port_forward_profiles
+                    .clone_from(&old.port_forward_profiles);
+            }
+        }
+        if let (ProtocolConfig::Rdp(new), ProtocolConfig::Rdp(old)) =
+            (&mut updated.protocol_config, &existing.protocol_config)
+            && let (Some(new_gw), Some(old_gw)) = (&mut new.gateway, &old.gateway)
+            && new_gw.hostname == old_gw.hostname
+            && !new_gw.has_own_credentials()
+        {
+            new_gw.domain.clone_from(&old_gw.domain);
+            new_gw.password_source = old_gw.password_source.clone();
+        }
+        if let (ProtocolConfig::Vnc(new), ProtocolConfig::Vnc(old)) =
+            (&mut updated.protocol_config, &existing.protocol_config)
+            && new.repeater.is_none()
+        {
+            new.repeater.clone_from(&old.repeater);
+        }
 
         // group_id is always taken from the updated connection — callers must set it explicitly
         // (None means "root/ungrouped", not "unchanged")
@@ -853,6 +908,88 @@ impl ConnectionManager {
         Ok(())
     }
 
+    /// Applies `edits` to every connection in `ids` as one operation
+    ///
+    /// Edits run in order on copies of the connections, and each result is
+    /// validated before anything is stored: an unknown connection or group
+    /// ID, or a result that fails validation (port 0, a host emptied by a
+    /// replacement, …), aborts the whole update and leaves all connections
+    /// untouched. Connections moved to another group are appended to it.
+    /// Returns the number of connections that changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Validation`] if an ID is unknown or an edited
+    /// connection is invalid, or an error if persistence fails.
+    pub fn bulk_update(&mut self, ids: &[Uuid], edits: &[BulkEdit]) -> ConfigResult<usize> {
+        for edit in edits {
+            if let BulkEdit::SetGroup(Some(gid)) = edit
+                && !self.groups.contains_key(gid)
+            {
+                return Err(ConfigError::Validation {
+                    field: "group_id".to_string(),
+                    reason: format!("Group with ID {gid} not found"),
+                });
+            }
+        }
+
+        let mut updated = Vec::new();
+        let mut seen = HashSet::new();
+        for id in ids {
+            if !seen.insert(*id) {
+                continue;
+            }
+            let mut connection = self
+                .connections
+                .get(id)
+                .ok_or_else(|| ConfigError::Validation {
+                    field: "id".to_string(),
+                    reason: format!("Connection with ID {id} not found"),
+                })?
+                .clone();
+            if !super::bulk::apply_all(edits, &mut connection) {
+                continue;
+            }
+            ConfigManager::validate_connection(&connection).map_err(|e| match e {
+                ConfigError::Validation { field, reason } => ConfigError::Validation {
+                    field,
+                    reason: format!("{}: {reason}", connection.name),
+                },
+                other => other,
+            })?;
+            updated.push(connection);
+        }
+        if updated.is_empty() {
+            return Ok(0);
+        }
+
+        let mut affected_groups = HashSet::new();
+        let count = updated.len();
+        for mut connection in updated {
+            let old_group = self
+                .connections
+                .get(&connection.id)
+                .and_then(|c| c.group_id);
+            if old_group != connection.group_id {
+                connection.sort_order = self.next_connection_sort_order(connection.group_id);
+                affected_groups.insert(old_group);
+            }
+            affected_groups.insert(connection.group_id);
+            connection.touch();
+            Self::intern_connection_strings(&connection);
+            self.connections.insert(connection.id, connection);
+        }
+
+        self.is_sorted = false;
+        self.persist_connections()?;
+        for group_id in affected_groups {
+            self.notify_sync_export(group_id);
+        }
+
+        tracing::info!(count, "Bulk-updated connections");
+        Ok(count)
+    }
+
     /// Gets a group by ID
     #[must_use]
     pub fn get_group(&self, id: Uuid) -> Option<&ConnectionGroup> {
@@ -942,18 +1079,27 @@ impl ConnectionManager {
             .collect()
     }
 
+    /// Returns all connections carrying `tag`, sorted by name
+    ///
+    /// Case-insensitive matching.
+    #[must_use]
+    pub fn connections_with_tag(&self, tag: &str) -> Vec<&Connection> {
+        let mut connections: Vec<&Connection> = self
+            .connections
+            .values()
+            .filter(|conn| conn.has_tag(tag))
+            .collect();
+        connections.sort_by_cached_key(|conn| conn.name.to_lowercase());
+        connections
+    }
+
     /// Filters connections by tag
     ///
     /// Returns all connections that have the specified tag.
     /// Case-insensitive matching.
     #[must_use]
     pub fn filter_by_tag(&self, tag: &str) -> Vec<&Connection> {
-        let tag_lower = tag.to_lowercase();
-
-        self.connections
-            .values()
-            .filter(|conn| conn.tags.iter().any(|t| t.to_lowercase() == tag_lower))
-            .collect()
+        self.connections_with_tag(tag)
     }
 
     /// Filters connections by multiple tags (AND logic)
@@ -1053,6 +1199,374 @@ impl ConnectionManager {
         true
     }
 
+    /// Returns the connections to open when launching `id`, in order.
+    ///
+    /// `depends_on` is not exposed by the edit dialog, so `update_connection`
+    /// preserves it; change it through `get_connection_mut`.
+    ///
+    /// Dependencies declared in `depends_on` (recursively) come first and
+    /// `id` itself is last. Callers connect them in sequence and stop at the
+    /// first failing dependency.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DependencyError`] if `id` or one of its dependencies does
+    /// not exist, or if the dependencies form a cycle.
+    pub fn resolve_launch_order(&self, id: Uuid) -> Result<Vec<Uuid>, DependencyError> {
+        super::resolve_launch_order(id, |conn_id| {
+            self.connections
+                .get(&conn_id)
+                .map(|c| c.depends_on.as_slice())
+        })
+    }
+
+    // ========== Duplicates ==========
+
+    /// Returns sets of duplicate connections, oldest first within each set
+    ///
+    /// Connections are duplicates when they share protocol, port, username
+    /// and host; hosts match case-insensitively and ignoring a trailing dot.
+    /// See [`super::find_duplicates`].
+    #[must_use]
+    pub fn find_duplicates(&self) -> Vec<Vec<Uuid>> {
+        super::find_duplicates(self.connections.values())
+    }
+
+    /// Merges each set of duplicates into its oldest connection
+    ///
+    /// The other connections of a set are moved to the trash. `depends_on`
+    /// lists, jump-host IDs of connections and groups, and the members of
+    /// `clusters` are re-pointed at the kept connection; the caller persists
+    /// `clusters`. Returns a map from each removed connection ID to the ID
+    /// that replaced it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if persistence fails.
+    pub fn merge_duplicates(
+        &mut self,
+        clusters: &mut [Cluster],
+    ) -> ConfigResult<HashMap<Uuid, Uuid>> {
+        let mut replaced = HashMap::new();
+        for set in self.find_duplicates() {
+            if let Some((kept, others)) = set.split_first() {
+                replaced.extend(others.iter().map(|id| (*id, *kept)));
+            }
+        }
+        if replaced.is_empty() {
+            return Ok(replaced);
+        }
+
+        let now = Utc::now();
+        let mut affected_groups = HashSet::new();
+        for id in replaced.keys() {
+            if let Some(conn) = self.connections.remove(id) {
+                affected_groups.insert(conn.group_id);
+                self.trash_connections.insert(*id, (conn, now));
+            }
+        }
+
+        let changed = super::duplicates::repoint_references(
+            &replaced,
+            self.connections.values_mut(),
+            self.groups.values_mut(),
+            clusters,
+        );
+        for id in changed {
+            if let Some(conn) = self.connections.get_mut(&id) {
+                conn.touch();
+            }
+        }
+
+        self.is_sorted = false;
+        self.persist_connections()?;
+        self.persist_groups()?;
+        self.persist_trash()?;
+        for group_id in affected_groups {
+            self.notify_sync_export(group_id);
+        }
+
+        tracing::info!(merged = replaced.len(), "Merged duplicate connections");
+        Ok(replaced)
+    }
+
+    // ========== Connect Guard ==========
+
+    /// Decides whether connecting to `id` at local time `now` may proceed
+    ///
+    /// Launch flows call this before opening a session: `Warn` is shown and
+    /// the connection proceeds, `Confirm` must be answered by the user, and
+    /// `Block` stops the launch. Connections without a guard always proceed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection does not exist.
+    pub fn check_connect_guard(
+        &self,
+        id: Uuid,
+        now: chrono::NaiveDateTime,
+    ) -> ConfigResult<ConnectGuardDecision> {
+        self.connections
+            .get(&id)
+            .map(|conn| conn.check_connect_guard(now))
+            .ok_or_else(|| ConfigError::Validation {
+                field: "id".to_string(),
+                reason: format!("Connection with ID {id} not found"),
+            })
+    }
+
+    // ========== Peer Pull ==========
+
+    /// Pulls connections from another instance and merges them in
+    ///
+    /// `code` is the pairing code shown by the sharing instance. Blocks on
+    /// the network exchange; GUI callers should run it off the main thread.
+    /// See [`Self::merge_native_export`] for how duplicates are handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PeerError`] if the transfer fails or the code is wrong,
+    /// or [`PeerError::Config`] if persisting the merged data fails.
+    pub fn pull_from_peer(
+        &mut self,
+        peer: &PeerInfo,
+        code: &str,
+        strategy: MergeStrategy,
+    ) -> Result<PeerPullReport, PeerError> {
+        let export = crate::sync::fetch_from_peer(peer, code)?;
+        Ok(self.merge_native_export(export, strategy)?)
+    }
+
+    /// Merges the connections and groups of a native export
+    ///
+    /// Groups are matched by name and parent and reused when they already
+    /// exist. Connections are deduplicated by host and port according to
+    /// `strategy` (`AskForEach` behaves like `SkipExisting`, since there is
+    /// no one to ask). Templates, snippets, and other non-connection data in
+    /// the export are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a connection or group fails validation or
+    /// persistence fails.
+    pub fn merge_native_export(
+        &mut self,
+        export: NativeExport,
+        strategy: MergeStrategy,
+    ) -> ConfigResult<PeerPullReport> {
+        self.merge_groups_and_connections(export.groups, export.connections, None, strategy, "peer")
+    }
+
+    /// Shared merge for peer pulls and document imports
+    ///
+    /// Groups whose parent is not part of the merge, and connections
+    /// without a group, are placed under `target_group` (the root if `None`).
+    fn merge_groups_and_connections(
+        &mut self,
+        groups: Vec<ConnectionGroup>,
+        connections: Vec<Connection>,
+        target_group: Option<Uuid>,
+        strategy: MergeStrategy,
+        source: &str,
+    ) -> ConfigResult<PeerPullReport> {
+        let mut report = PeerPullReport::default();
+
+        // Map imported group IDs to local ones, parents before children
+        let imported_groups: HashSet<Uuid> = groups.iter().map(|g| g.id).collect();
+        let mut group_ids: HashMap<Uuid, Uuid> = HashMap::new();
+        let mut pending = groups;
+        while !pending.is_empty() {
+            let (mut ready, mut waiting): (Vec<_>, Vec<_>) =
+                pending.into_iter().partition(|g: &ConnectionGroup| {
+                    g.parent_id
+                        .is_none_or(|p| group_ids.contains_key(&p) || !imported_groups.contains(&p))
+                });
+            if ready.is_empty() {
+                // Parent cycle in the import: attach the rest at the top
+                for group in &mut waiting {
+                    group.parent_id = None;
+                }
+                ready = std::mem::take(&mut waiting);
+            }
+            for mut group in ready {
+                let imported_id = group.id;
+                group.parent_id = group
+                    .parent_id
+                    .and_then(|p| {
+                        group_ids
+                            .get(&p)
+                            .copied()
+                            .or_else(|| self.groups.contains_key(&p).then_some(p))
+                    })
+                    .or(target_group);
+                if let Some(existing) = self
+                    .groups
+                    .values()
+                    .find(|e| e.name == group.name && e.parent_id == group.parent_id)
+                {
+                    group_ids.insert(imported_id, existing.id);
+                    continue;
+                }
+                if self.groups.contains_key(&group.id) {
+                    group.id = Uuid::new_v4();
+                }
+                group_ids.insert(imported_id, self.create_group_from(group)?);
+                report.groups_created += 1;
+            }
+            pending = waiting;
+        }
+
+        let connections: Vec<Connection> = connections
+            .into_iter()
+            .map(|mut conn| {
+                conn.group_id = conn
+                    .group_id
+                    .and_then(|g| {
+                        group_ids
+                            .get(&g)
+                            .copied()
+                            .or_else(|| self.groups.contains_key(&g).then_some(g))
+                    })
+                    .or(target_group);
+                conn
+            })
+            .collect();
+        let result = ImportResult {
+            connections,
+            ..ImportResult::default()
+        };
+        let existing: Vec<Connection> = self.connections.values().cloned().collect();
+        let preview = ImportPreview::from_result(&result, &existing, &[], strategy, source, source);
+        report.skipped = preview.skip_count;
+        let (to_create, to_update, _) = preview.apply();
+
+        for mut conn in to_create {
+            if self.connections.contains_key(&conn.id) {
+                conn.id = Uuid::new_v4();
+            }
+            self.create_connection_from(conn)?;
+            report.created += 1;
+        }
+        for (id, conn) in to_update {
+            self.update_connection(id, conn)?;
+            report.updated += 1;
+        }
+
+        Ok(report)
+    }
+
+    // ========== Document Import ==========
+
+    /// Copies a document's contents into the main configuration
+    ///
+    /// Groups and connections are merged as in [`Self::merge_native_export`];
+    /// when `target_group` is set, the document's top-level groups and
+    /// ungrouped connections are placed under it.
+    ///
+    /// Variables are added to the global variables. A variable whose name is
+    /// already used with a different value is skipped rather than renamed,
+    /// since the imported connections refer to it by name. Templates are
+    /// referenced by ID, so a template whose name is taken is imported as
+    /// "Name (Document)"; a template whose ID is already present is skipped.
+    /// Each collision is listed in the report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document or target group does not exist, a
+    /// connection or group fails validation, or persistence fails.
+    pub fn import_document_into_config(
+        &mut self,
+        documents: &DocumentManager,
+        doc_id: Uuid,
+        target_group: Option<Uuid>,
+        strategy: MergeStrategy,
+    ) -> ConfigResult<DocumentImportReport> {
+        let doc = documents
+            .get(doc_id)
+            .ok_or_else(|| ConfigError::Validation {
+                field: "document_id".to_string(),
+                reason: format!("Document with ID {doc_id} not found"),
+            })?;
+        if let Some(target) = target_group
+            && !self.groups.contains_key(&target)
+        {
+            return Err(ConfigError::Validation {
+                field: "target_group".to_string(),
+                reason: format!("Group with ID {target} not found"),
+            });
+        }
+
+        let merged = self.merge_groups_and_connections(
+            doc.groups.clone(),
+            doc.connections.clone(),
+            target_group,
+            strategy,
+            &doc.name,
+        )?;
+        let mut report = DocumentImportReport {
+            created: merged.created,
+            updated: merged.updated,
+            skipped: merged.skipped,
+            groups_created: merged.groups_created,
+            ..DocumentImportReport::default()
+        };
+
+        if !doc.variables.is_empty() {
+            let mut variables = self.config_manager.load_variables()?;
+            let mut imported: Vec<&Variable> = doc.variables.values().collect();
+            imported.sort_by(|a, b| a.name.cmp(&b.name));
+            for var in imported {
+                match variables.iter().find(|v| v.name == var.name) {
+                    Some(existing)
+                        if existing.value == var.value && existing.is_secret == var.is_secret => {}
+                    Some(_) => report
+                        .collisions
+                        .push(NameCollision::VariableSkipped(var.name.clone())),
+                    None => {
+                        variables.push(var.clone());
+                        report.variables_added += 1;
+                    }
+                }
+            }
+            if report.variables_added > 0 {
+                self.config_manager.save_variables(&variables)?;
+            }
+        }
+
+        if !doc.templates.is_empty() {
+            let mut templates = self.config_manager.load_templates()?;
+            for template in &doc.templates {
+                if templates.iter().any(|t| t.id == template.id) {
+                    report
+                        .collisions
+                        .push(NameCollision::TemplateSkipped(template.name.clone()));
+                    continue;
+                }
+                let mut template = template.clone();
+                if templates.iter().any(|t| t.name == template.name) {
+                    let base = format!("{} ({})", template.name, doc.name);
+                    let mut name = base.clone();
+                    let mut counter = 2u32;
+                    while templates.iter().any(|t| t.name == name) {
+                        name = format!("{base} {counter}");
+                        counter += 1;
+                    }
+                    report.collisions.push(NameCollision::TemplateRenamed {
+                        from: std::mem::replace(&mut template.name, name.clone()),
+                        to: name,
+                    });
+                }
+                templates.push(template);
+                report.templates_added += 1;
+            }
+            if report.templates_added > 0 {
+                self.config_manager.save_templates(&templates)?;
+            }
+        }
+
+        Ok(report)
+    }
+
     // ========== Cloud Sync Export Trigger ==========
 
     /// Wires up the export channel so that Master group changes are
@@ -1476,6 +1990,49 @@ impl ConnectionManager {
         Ok(())
     }
 
+    /// Returns all connections in the order given by `by`
+    ///
+    /// Last-connected times and connect counts come from `history`, like
+    /// [`Connection::last_connected_at`]; with history disabled in
+    /// `settings` every connection counts as never connected. Ties fall back
+    /// to the most recent connection, then to the name, so the order is
+    /// stable. The stored `sort_order` is not changed.
+    #[must_use]
+    pub fn sorted(
+        &self,
+        by: ConnectionSortKey,
+        history: &[ConnectionHistoryEntry],
+        settings: &HistorySettings,
+    ) -> Vec<&Connection> {
+        let mut usage: HashMap<Uuid, (u32, Option<DateTime<Utc>>)> = HashMap::new();
+        if settings.enabled {
+            for entry in history {
+                let (count, last) = usage.entry(entry.connection_id).or_default();
+                *count += 1;
+                *last = (*last).max(Some(entry.started_at));
+            }
+        }
+        let usage_of = |conn: &Connection| usage.get(&conn.id).copied().unwrap_or_default();
+        let by_name =
+            |a: &Connection, b: &Connection| a.name.to_lowercase().cmp(&b.name.to_lowercase());
+
+        let mut connections: Vec<&Connection> = self.connections.values().collect();
+        connections.sort_by(|a, b| {
+            let ((count_a, last_a), (count_b, last_b)) = (usage_of(a), usage_of(b));
+            let ordering = match by {
+                ConnectionSortKey::Name => std::cmp::Ordering::Equal,
+                ConnectionSortKey::LastConnected => last_b.cmp(&last_a),
+                ConnectionSortKey::ConnectCount => {
+                    count_b.cmp(&count_a).then_with(|| last_b.cmp(&last_a))
+                }
+            };
+            ordering
+                .then_with(|| by_name(a, b))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        connections
+    }
+
     // ========== Drag-Drop Reordering Operations ==========
 
     /// Reorders a connection to be positioned after another connection
@@ -1840,6 +2397,34 @@ mod tests {
         assert_eq!(conn.host, "new.example.com");
     }
 
+    #[tokio::test]
+    async fn test_update_connection_keeps_jump_hosts() {
+        let (mut manager, _temp) = create_test_manager();
+
+        let chain = vec![crate::models::JumpHost::new("bastion").with_user("admin")];
+        let id = manager
+            .create_connection(
+                "Test Server".to_string(),
+                "example.com".to_string(),
+                22,
+                ProtocolConfig::Ssh(SshConfig {
+                    jump_hosts: chain.clone(),
+                    ..SshConfig::default()
+                }),
+            )
+            .unwrap();
+
+        // The edit dialog rebuilds the SSH config without the chain
+        let mut updated = manager.get_connection(id).unwrap().clone();
+        updated.protocol_config = ProtocolConfig::Ssh(SshConfig::default());
+        manager.update_connection(id, updated).unwrap();
+
+        match &manager.get_connection(id).unwrap().protocol_config {
+            ProtocolConfig::Ssh(ssh) => assert_eq!(ssh.jump_hosts, chain),
+            other => panic!("unexpected config: {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn test_delete_connection() {
         let (mut manager, _temp) = create_test_manager();
@@ -1993,6 +2578,39 @@ mod tests {
         assert_eq!(results.len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_connections_with_tag() {
+        let (mut manager, _temp) = create_test_manager();
+
+        for (name, tags) in [
+            ("web02", &["PCI", "on-call"][..]),
+            ("legacy-ftp", &["legacy"]),
+            ("db01", &["pci"]),
+        ] {
+            let id = manager
+                .create_connection(
+                    name.to_string(),
+                    format!("{name}.example.com"),
+                    22,
+                    ProtocolConfig::Ssh(SshConfig::default()),
+                )
+                .unwrap();
+            let conn = manager.get_connection_mut(id).unwrap();
+            for tag in tags {
+                conn.add_tag(tag);
+            }
+        }
+
+        let names: Vec<&str> = manager
+            .connections_with_tag("pci")
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, ["db01", "web02"]);
+        assert_eq!(manager.connections_with_tag("On-Call").len(), 1);
+        assert!(manager.connections_with_tag("staging").is_empty());
+    }
+
     #[tokio::test]
     async fn test_get_group_path() {
         let (mut manager, _temp) = create_test_manager();
@@ -2244,4 +2862,421 @@ mod tests {
             "Should persist after flush"
         );
     }
+
+    #[tokio::test]
+    async fn test_resolve_launch_order() {
+        let (mut manager, _temp) = create_test_manager();
+        let tunnel = manager
+            .create_connection(
+                "Tunnel".to_string(),
+                "bastion.example.com".to_string(),
+                22,
+                ProtocolConfig::Ssh(SshConfig::default()),
+            )
+            .unwrap();
+        let mut app = Connection::new_ssh("App".to_string(), "app.example.com".to_string(), 22);
+        app.depends_on = vec![tunnel];
+        let app = manager.create_connection_from(app).unwrap();
+
+        assert_eq!(manager.resolve_launch_order(app), Ok(vec![tunnel, app]));
+        assert_eq!(manager.resolve_launch_order(tunnel), Ok(vec![tunnel]));
+
+        manager.get_connection_mut(tunnel).unwrap().depends_on = vec![app];
+        assert!(matches!(
+            manager.resolve_launch_order(app),
+            Err(DependencyError::Cycle(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_find_and_merge_duplicates() {
+        let (mut manager, _temp) = create_test_manager();
+        let created = Utc::now() - chrono::Duration::days(30);
+        let conn = |name: &str, host: &str, user: &str, age_days: i64| {
+            let mut conn = Connection::new_ssh(name.to_string(), host.to_string(), 22);
+            conn.username = Some(user.to_string());
+            conn.created_at = created + chrono::Duration::days(age_days);
+            conn
+        };
+
+        let oldest = manager
+            .create_connection_from(conn("Web", "web.example.com", "deploy", 0))
+            .unwrap();
+        let fqdn = manager
+            .create_connection_from(conn("Web (SSH)", "Web.Example.COM.", "deploy", 1))
+            .unwrap();
+        let copy = manager
+            .create_connection_from(conn("Web 2", "web.example.com", "deploy", 2))
+            .unwrap();
+        // Same host and port, different user: not a duplicate
+        let near = manager
+            .create_connection_from(conn("Web (root)", "web.example.com", "root", 3))
+            .unwrap();
+        let mut app = conn("App", "app.example.com", "deploy", 4);
+        app.depends_on = vec![copy, fqdn];
+        if let ProtocolConfig::Ssh(ref mut ssh) = app.protocol_config {
+            ssh.jump_host_id = Some(copy);
+        }
+        let app = manager.create_connection_from(app).unwrap();
+        let mut group = ConnectionGroup::new("Jumped".to_string());
+        group.ssh_jump_host_id = Some(fqdn);
+        let group = manager.create_group_from(group).unwrap();
+
+        assert_eq!(manager.find_duplicates(), vec![vec![oldest, fqdn, copy]]);
+
+        let mut cluster = Cluster::new("Web farm".to_string());
+        cluster.add_connection(fqdn);
+        cluster.add_connection(near);
+        cluster.add_connection(oldest);
+        cluster.set_command_template(copy, "uptime");
+        let mut clusters = vec![cluster];
+
+        let replaced = manager.merge_duplicates(&mut clusters).unwrap();
+        assert_eq!(replaced, HashMap::from([(fqdn, oldest), (copy, oldest)]));
+        assert_eq!(manager.connection_count(), 3);
+        assert!(manager.get_connection(near).is_some());
+        assert_eq!(manager.list_trash_connections().len(), 2);
+
+        let app = manager.get_connection(app).unwrap();
+        assert_eq!(app.depends_on, vec![oldest]);
+        let ProtocolConfig::Ssh(ref ssh) = app.protocol_config else {
+            panic!("expected SSH config");
+        };
+        assert_eq!(ssh.jump_host_id, Some(oldest));
+        assert_eq!(
+            manager.get_group(group).unwrap().ssh_jump_host_id,
+            Some(oldest)
+        );
+        assert_eq!(clusters[0].connection_ids, vec![oldest, near]);
+        assert_eq!(clusters[0].command_template(oldest), Some("uptime"));
+
+        assert!(manager.find_duplicates().is_empty());
+        assert!(manager.merge_duplicates(&mut clusters).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sorted_with_mixed_history() {
+        let (mut manager, _temp) = create_test_manager();
+        let mut ids = Vec::new();
+        for name in ["charlie", "Alpha", "bravo", "delta"] {
+            let conn = Connection::new_ssh(name.to_string(), format!("{name}.lan"), 22);
+            ids.push(manager.create_connection_from(conn).unwrap());
+        }
+        let [charlie, alpha, bravo, delta] = ids[..] else {
+            unreachable!()
+        };
+
+        let now = Utc::now();
+        let visit = |id: Uuid, hours_ago: i64| {
+            let conn = manager.get_connection(id).unwrap();
+            let mut entry = ConnectionHistoryEntry::new(
+                id,
+                conn.name.clone(),
+                conn.host.clone(),
+                conn.port,
+                "ssh".to_string(),
+                None,
+            );
+            entry.started_at = now - chrono::Duration::hours(hours_ago);
+            entry
+        };
+        // bravo: twice, long ago; delta: once, recently; alpha and charlie never
+        let history = vec![visit(bravo, 48), visit(delta, 1), visit(bravo, 24)];
+        let settings = HistorySettings::default();
+        let order = |by| -> Vec<Uuid> {
+            manager
+                .sorted(by, &history, &settings)
+                .into_iter()
+                .map(|c| c.id)
+                .collect()
+        };
+
+        assert_eq!(
+            order(ConnectionSortKey::Name),
+            vec![alpha, bravo, charlie, delta]
+        );
+        assert_eq!(
+            order(ConnectionSortKey::LastConnected),
+            vec![delta, bravo, alpha, charlie]
+        );
+        assert_eq!(
+            order(ConnectionSortKey::ConnectCount),
+            vec![bravo, delta, alpha, charlie]
+        );
+
+        let bravo_conn = manager.get_connection(bravo).unwrap();
+        assert_eq!(
+            bravo_conn.last_connected_at(&history, &settings),
+            Some(now - chrono::Duration::hours(24))
+        );
+        assert_eq!(
+            manager
+                .get_connection(alpha)
+                .unwrap()
+                .last_connected_at(&history, &settings),
+            None
+        );
+
+        let disabled = HistorySettings {
+            enabled: false,
+            ..HistorySettings::default()
+        };
+        assert_eq!(bravo_conn.last_connected_at(&history, &disabled), None);
+        assert_eq!(
+            manager
+                .sorted(ConnectionSortKey::LastConnected, &history, &disabled)
+                .into_iter()
+                .map(|c| c.id)
+                .collect::<Vec<_>>(),
+            vec![alpha, bravo, charlie, delta]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_replaces_host_domain() {
+        let (mut manager, _temp) = create_test_manager();
+        let group = manager.create_group("Migrated".to_string()).unwrap();
+        let ids: Vec<Uuid> = ["web", "db", "cache"]
+            .iter()
+            .map(|name| {
+                manager
+                    .create_connection(
+                        (*name).to_string(),
+                        format!("{name}.old.example.com"),
+                        22,
+                        ProtocolConfig::Ssh(SshConfig::default()),
+                    )
+                    .unwrap()
+            })
+            .collect();
+        let other = manager
+            .create_connection(
+                "other".to_string(),
+                "other.old.example.com".to_string(),
+                22,
+                ProtocolConfig::Ssh(SshConfig::default()),
+            )
+            .unwrap();
+
+        let edits = [
+            BulkEdit::ReplaceInHost {
+                find: ".old.example.com".to_string(),
+                replace: ".new.example.com".to_string(),
+            },
+            BulkEdit::SetGroup(Some(group)),
+            BulkEdit::AddTag("migrated".to_string()),
+        ];
+        assert_eq!(manager.bulk_update(&ids, &edits).unwrap(), 3);
+        // A second run only re-applies the group and tag, which already match
+        assert_eq!(manager.bulk_update(&ids, &edits).unwrap(), 0);
+
+        for id in &ids {
+            let conn = manager.get_connection(*id).unwrap();
+            assert!(conn.host.ends_with(".new.example.com"), "{}", conn.host);
+            assert_eq!(conn.group_id, Some(group));
+            assert_eq!(conn.tags, ["migrated"]);
+        }
+        let untouched = manager.get_connection(other).unwrap();
+        assert_eq!(untouched.host, "other.old.example.com");
+        assert_eq!(untouched.group_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_rolls_back_on_invalid_result() {
+        let (mut manager, _temp) = create_test_manager();
+        let ok = manager
+            .create_connection(
+                "ok".to_string(),
+                "host.example.com".to_string(),
+                22,
+                ProtocolConfig::Ssh(SshConfig::default()),
+            )
+            .unwrap();
+        let emptied = manager
+            .create_connection(
+                "emptied".to_string(),
+                "example.com".to_string(),
+                22,
+                ProtocolConfig::Ssh(SshConfig::default()),
+            )
+            .unwrap();
+        let before: Vec<Connection> = [ok, emptied]
+            .iter()
+            .map(|id| manager.get_connection(*id).unwrap().clone())
+            .collect();
+
+        // Replacing the whole host of the second connection leaves it empty
+        let edits = [
+            BulkEdit::SetPort(2222),
+            BulkEdit::ReplaceInHost {
+                find: "example.com".to_string(),
+                replace: String::new(),
+            },
+        ];
+        let err = manager.bulk_update(&[ok, emptied], &edits).unwrap_err();
+        assert!(matches!(err, ConfigError::Validation { ref field, .. } if field == "host"));
+
+        let err = manager
+            .bulk_update(&[ok], &[BulkEdit::SetPort(0)])
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::Validation { ref field, .. } if field == "port"));
+
+        let err = manager
+            .bulk_update(&[ok, Uuid::new_v4()], &[BulkEdit::SetPort(2222)])
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::Validation { ref field, .. } if field == "id"));
+
+        for conn in &before {
+            assert_eq!(manager.get_connection(conn.id), Some(conn));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merge_native_export() {
+        let (mut manager, _temp) = create_test_manager();
+        let local_group = manager.create_group("Prod".to_string()).unwrap();
+        let mut local = Connection::new_ssh("Web".to_string(), "web.lan".to_string(), 22);
+        local.group_id = Some(local_group);
+        manager.create_connection_from(local).unwrap();
+
+        // The peer has its own "Prod" group (different ID) with a child group
+        let peer_prod = ConnectionGroup::new("Prod".to_string());
+        let peer_db = ConnectionGroup::with_parent("Databases".to_string(), peer_prod.id);
+        let mut dup = Connection::new_ssh("Web (peer)".to_string(), "web.lan".to_string(), 22);
+        dup.group_id = Some(peer_prod.id);
+        let mut db = Connection::new_ssh("DB".to_string(), "db.lan".to_string(), 22);
+        db.group_id = Some(peer_db.id);
+
+        let mut export = NativeExport::new();
+        export.groups = vec![peer_db, peer_prod];
+        export.connections = vec![dup, db];
+
+        let report = manager
+            .merge_native_export(export.clone(), MergeStrategy::SkipExisting)
+            .unwrap();
+        assert_eq!(
+            report,
+            PeerPullReport {
+                created: 1,
+                updated: 0,
+                skipped: 1,
+                groups_created: 1,
+            }
+        );
+        assert_eq!(manager.group_count(), 2);
+        let db = manager
+            .list_connections()
+            .into_iter()
+            .find(|c| c.host == "db.lan")
+            .unwrap();
+        let db_group = manager.get_group(db.group_id.unwrap()).unwrap();
+        assert_eq!(db_group.name, "Databases");
+        assert_eq!(db_group.parent_id, Some(local_group));
+
+        // Pulling again with UpdateExisting updates both in place
+        let report = manager
+            .merge_native_export(export, MergeStrategy::UpdateExisting)
+            .unwrap();
+        assert_eq!((report.created, report.updated), (0, 2));
+        assert_eq!(report.groups_created, 0);
+        assert_eq!(manager.connection_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_import_document_into_config() {
+        use crate::models::ConnectionTemplate;
+
+        let (mut manager, _temp) = create_test_manager();
+        let imports = manager.create_group("Imports".to_string()).unwrap();
+        manager
+            .config_manager
+            .save_variables(&[Variable::new("domain", "corp.local")])
+            .unwrap();
+        manager
+            .config_manager
+            .save_templates(&[ConnectionTemplate::new_ssh("Linux".to_string())])
+            .unwrap();
+
+        let mut documents = DocumentManager::new();
+        let doc_id = documents.create("Lab".to_string());
+        let doc = documents.get_mut(doc_id).unwrap();
+        let lab = ConnectionGroup::new("Lab".to_string());
+        let mut web = Connection::new_ssh("Web".to_string(), "web.lab".to_string(), 22);
+        web.group_id = Some(lab.id);
+        doc.add_group(lab);
+        doc.add_connection(web);
+        doc.add_connection(Connection::new_ssh(
+            "Jump".to_string(),
+            "jump.lab".to_string(),
+            22,
+        ));
+        doc.set_variable(Variable::new("domain", "lab.local"));
+        doc.set_variable(Variable::new("user", "admin"));
+        doc.add_template(ConnectionTemplate::new_ssh("Linux".to_string()));
+
+        let report = manager
+            .import_document_into_config(
+                &documents,
+                doc_id,
+                Some(imports),
+                MergeStrategy::SkipExisting,
+            )
+            .unwrap();
+        assert_eq!((report.created, report.groups_created), (2, 1));
+        assert_eq!((report.variables_added, report.templates_added), (1, 1));
+        assert_eq!(
+            report.collisions,
+            vec![
+                NameCollision::VariableSkipped("domain".to_string()),
+                NameCollision::TemplateRenamed {
+                    from: "Linux".to_string(),
+                    to: "Linux (Lab)".to_string(),
+                },
+            ]
+        );
+
+        let jump = manager
+            .list_connections()
+            .into_iter()
+            .find(|c| c.host == "jump.lab")
+            .unwrap();
+        assert_eq!(jump.group_id, Some(imports));
+        let web = manager
+            .list_connections()
+            .into_iter()
+            .find(|c| c.host == "web.lab")
+            .unwrap();
+        let lab = manager.get_group(web.group_id.unwrap()).unwrap();
+        assert_eq!(lab.parent_id, Some(imports));
+        let domain = manager.config_manager.load_variables().unwrap();
+        assert_eq!(
+            domain.iter().find(|v| v.name == "domain").unwrap().value,
+            "corp.local"
+        );
+
+        // Importing again adds nothing
+        let report = manager
+            .import_document_into_config(
+                &documents,
+                doc_id,
+                Some(imports),
+                MergeStrategy::SkipExisting,
+            )
+            .unwrap();
+        assert_eq!(
+            (report.created, report.skipped, report.groups_created),
+            (0, 2, 0)
+        );
+        assert_eq!((report.variables_added, report.templates_added), (0, 0));
+        assert!(
+            manager
+                .import_document_into_config(
+                    &documents,
+                    Uuid::new_v4(),
+                    None,
+                    MergeStrategy::SkipExisting,
+                )
+                .is_err()
+        );
+    }
 }
@@ -9,24 +9,71 @@
 //! when dealing with large numbers of connections, and virtual scrolling helpers
 //! for efficient rendering of large connection lists.
 //!
+//! ## Bulk Edits
+//!
+//! `ConnectionManager::bulk_update` applies a list of [`BulkEdit`]s (group,
+//! port, host find/replace, tags) to many connections; if any result fails
+//! validation nothing is changed.
+//!
+//! ## Dependencies
+//!
+//! Connections may declare `depends_on`; `ConnectionManager::resolve_launch_order`
+//! returns the connections to open, dependencies first.
+//!
+//! ## Duplicates
+//!
+//! `ConnectionManager::find_duplicates` groups connections that share
+//! protocol, host, port and username; `merge_duplicates` keeps the oldest of
+//! each group and re-points references to the others.
+//!
+//! ## Health Checks
+//!
+//! `HealthCheckScheduler` probes connections in the background with
+//! `ConnectionTester` and caches the last result per connection ID.
+//!
+//! ## Proxies
+//!
+//! The `proxy` submodule connects through a connection's SOCKS/HTTP
+//! [`ProxyConfig`](crate::models::ProxyConfig); `check_port_with_proxy`
+//! probes the target through it.
+//!
+//! ## Tailscale
+//!
+//! Connections with `resolve_via_tailscale` set are port-checked against the
+//! peer's Tailscale IP from `tailscale status --json` (see [`probe_host`]),
+//! so split DNS cannot send the probe elsewhere.
+//!
 //! ## Retry Logic
 //!
 //! The `retry` submodule provides `RetryConfig` and `RetryState` for handling
 //! transient connection failures with exponential backoff.
 
 pub mod automation_inheritance;
+mod bulk;
+mod dependencies;
+mod duplicates;
+mod health;
 mod interning;
 pub mod knock;
 mod lazy_loader;
 mod manager;
 pub mod mptcp;
 mod port_check;
+pub mod proxy;
 mod retry;
 pub mod spa;
 pub mod ssh_inheritance;
 mod ssh_prompt;
+mod tailscale;
 mod virtual_scroll;
 
+pub use bulk::BulkEdit;
+pub use dependencies::{DependencyError, resolve_launch_order};
+pub use duplicates::find_duplicates;
+pub use health::{
+    ConnectionHealth, DEFAULT_PROBE_CONCURRENCY, DEFAULT_PROBE_INTERVAL_SECS,
+    DEFAULT_PROBE_TIMEOUT_SECS, HealthCheckHandle, HealthCheckScheduler, HealthProbeConfig,
+};
 pub use interning::{
     check_interning_stats, get_interning_stats, intern_connection_strings, intern_hostname,
     intern_protocol_name, intern_username, log_interning_stats, log_interning_stats_with_warning,
@@ -36,13 +83,22 @@ pub use knock::{
     execute_knock_sequence,
 };
 pub use lazy_loader::LazyGroupLoader;
-pub use manager::ConnectionManager;
+pub use manager::{ConnectionManager, ConnectionSortKey};
 pub use mptcp::{
     MptcpError, MptcpResult, connect_mptcp, connect_mptcp_async, is_mptcp_available,
     is_mptcpize_available,
 };
-pub use port_check::{PortCheckError, PortCheckResult, check_port, check_port_async};
-pub use retry::{DEFAULT_BACKOFF_MULTIPLIER, RetryConfig, RetryState};
+pub use port_check::{
+    PortCheckError, PortCheckResult, check_port, check_port_async, check_port_with_proxy,
+};
+pub use proxy::{ProxyError, ProxyResult, connect_via_proxy, connect_via_proxy_async};
+pub use retry::{
+    DEFAULT_BACKOFF_MULTIPLIER, DEFAULT_JITTER, DEFAULT_RECONNECT_JITTER, RetryConfig, RetryState,
+};
 pub use spa::{SpaError, SpaResult, build_spa_packet, send_spa};
 pub use ssh_prompt::looks_like_password_prompt;
+pub use tailscale::{
+    TailscalePeer, TailscaleStatus, probe_host, resolve_tailscale_host,
+    resolve_tailscale_host_async,
+};
 pub use virtual_scroll::SelectionState;
@@ -2,12 +2,17 @@
 //!
 //! Provides fast TCP port reachability check before launching external clients
 //! (RDP, VNC, SPICE) to give faster feedback when hosts are unreachable.
+//! Connections with a proxy are probed through it with
+//! [`check_port_with_proxy`].
 
 use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
 use std::time::Duration;
 
 use thiserror::Error;
 
+use super::proxy::connect_via_proxy;
+use crate::models::ProxyConfig;
+
 /// Error type for port check operations
 #[derive(Debug, Error)]
 pub enum PortCheckError {
@@ -29,6 +34,18 @@ pub enum PortCheckError {
         /// The reason for the failure
         reason: String,
     },
+    /// The target could not be reached through the connection's proxy
+    #[error("Port {port} on '{host}' is not reachable via proxy {proxy}: {reason}")]
+    ProxyFailed {
+        /// The target hostname
+        host: String,
+        /// The target port
+        port: u16,
+        /// The proxy `host:port`
+        proxy: String,
+        /// The reason for the failure
+        reason: String,
+    },
 }
 
 /// Result of a port check operation
@@ -99,6 +116,35 @@ pub fn check_port(
     })
 }
 
+/// Checks if a TCP port is reachable, going through `proxy` when set
+///
+/// The probe does not authenticate to the proxy: a proxy that requires
+/// credentials fails the check, so callers should skip it for such proxies
+/// (see `Connection::bypasses_direct_probe`).
+///
+/// # Errors
+/// * `PortCheckError::ProxyFailed` if the proxy is unreachable or refuses the target
+/// * Otherwise as [`check_port`]
+pub fn check_port_with_proxy(
+    proxy: Option<&ProxyConfig>,
+    host: &str,
+    port: u16,
+    timeout_secs: u32,
+) -> Result<PortCheckResult, PortCheckError> {
+    let Some(proxy) = proxy else {
+        return check_port(host, port, timeout_secs);
+    };
+    let timeout = Duration::from_secs(u64::from(timeout_secs));
+    connect_via_proxy(proxy, None, host, port, timeout)
+        .map(|_stream| PortCheckResult::Open)
+        .map_err(|e| PortCheckError::ProxyFailed {
+            host: host.to_string(),
+            port,
+            proxy: proxy.address(),
+            reason: e.to_string(),
+        })
+}
+
 /// Async version of port check using tokio
 ///
 /// # Arguments
@@ -183,4 +229,14 @@ mod tests {
             PortCheckError::Unreachable { .. }
         ));
     }
+
+    #[test]
+    fn test_check_port_via_unreachable_proxy() {
+        let proxy = ProxyConfig::new(crate::models::ProxyType::Socks5, "127.0.0.1", 59998);
+        let result = check_port_with_proxy(Some(&proxy), "example.com", 22, 1);
+        assert!(matches!(
+            result.unwrap_err(),
+            PortCheckError::ProxyFailed { .. }
+        ));
+    }
 }
@@ -0,0 +1,521 @@
+//! Outbound TCP connections through a SOCKS or HTTP proxy
+//!
+//! Implements the client side of SOCKS5 (RFC 1928, with RFC 1929
+//! username/password authentication), SOCKS4a and HTTP `CONNECT`. The
+//! returned stream is connected to the target and can be handed to any
+//! protocol client. Used by the embedded RDP/VNC clients, the pre-connect
+//! port check and the connection tester when a connection has a
+//! [`ProxyConfig`].
+//!
+//! The handshake is written once against blocking `std::net::TcpStream`;
+//! [`connect_via_proxy_async`] runs it on Tokio's blocking pool and converts
+//! the result, so both entry points behave identically.
+
+use std::io::{Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use secrecy::{ExposeSecret, SecretString};
+use thiserror::Error;
+
+use crate::models::{ProxyConfig, ProxySupportError, ProxyType};
+
+/// Longest HTTP `CONNECT` response header accepted from a proxy
+const MAX_HTTP_RESPONSE: usize = 8 * 1024;
+
+/// Errors from connecting through a proxy
+#[derive(Debug, Error)]
+pub enum ProxyError {
+    /// The proxy configuration is invalid
+    #[error(transparent)]
+    Config(#[from] ProxySupportError),
+    /// The proxy server could not be reached
+    #[error("Failed to connect to proxy {proxy}: {reason}")]
+    Unreachable {
+        /// Proxy `host:port`
+        proxy: String,
+        /// The reason for the failure
+        reason: String,
+    },
+    /// The proxy requires credentials that were not supplied
+    #[error("Proxy requires authentication")]
+    AuthRequired,
+    /// The proxy rejected the supplied credentials
+    #[error("Proxy rejected the credentials")]
+    AuthFailed,
+    /// The proxy refused to connect to the target
+    #[error("Proxy refused the connection to {target}: {reason}")]
+    Refused {
+        /// Target `host:port`
+        target: String,
+        /// Reason reported by the proxy
+        reason: String,
+    },
+    /// The proxy sent a malformed response
+    #[error("Invalid proxy response: {0}")]
+    Protocol(String),
+    /// I/O error during the handshake
+    #[error("Proxy I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Result type for proxy operations
+pub type ProxyResult<T> = Result<T, ProxyError>;
+
+/// Connects to `target_host:target_port` through `proxy`
+///
+/// `password` is the proxy password resolved from the secret backend; it is
+/// only sent when the proxy asks for authentication. The target hostname is
+/// passed to the proxy unresolved so DNS happens on the proxy side.
+///
+/// `timeout` bounds connecting to the proxy and each handshake read; the
+/// returned stream has no read or write timeout set.
+///
+/// # Errors
+///
+/// Returns an error if the proxy is unreachable, rejects the credentials,
+/// refuses the target, or speaks an unexpected protocol.
+pub fn connect_via_proxy(
+    proxy: &ProxyConfig,
+    password: Option<&SecretString>,
+    target_host: &str,
+    target_port: u16,
+    timeout: Duration,
+) -> ProxyResult<TcpStream> {
+    proxy.validate()?;
+    let mut stream = connect_to_proxy(proxy, timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+
+    let username = proxy.username.as_deref().unwrap_or_default();
+    let password = password.map_or("", |p| p.expose_secret());
+    match proxy.proxy_type {
+        ProxyType::Socks5 => {
+            socks5_handshake(&mut stream, username, password, target_host, target_port)?;
+        }
+        ProxyType::Socks4 => socks4_handshake(&mut stream, username, target_host, target_port)?,
+        ProxyType::Http => {
+            http_connect(&mut stream, username, password, target_host, target_port)?;
+        }
+    }
+
+    stream.set_read_timeout(None)?;
+    stream.set_write_timeout(None)?;
+    let _ = stream.set_nodelay(true);
+    tracing::debug!(
+        proxy = %proxy.address(),
+        proxy_type = %proxy.proxy_type,
+        target = %format!("{target_host}:{target_port}"),
+        "Connected through proxy"
+    );
+    Ok(stream)
+}
+
+/// Async version of [`connect_via_proxy`] for the embedded clients
+///
+/// # Errors
+///
+/// See [`connect_via_proxy`].
+pub async fn connect_via_proxy_async(
+    proxy: &ProxyConfig,
+    password: Option<&SecretString>,
+    target_host: &str,
+    target_port: u16,
+    timeout: Duration,
+) -> ProxyResult<tokio::net::TcpStream> {
+    let proxy = proxy.clone();
+    let password = password.cloned();
+    let target_host = target_host.to_string();
+    let stream = tokio::task::spawn_blocking(move || {
+        connect_via_proxy(
+            &proxy,
+            password.as_ref(),
+            &target_host,
+            target_port,
+            timeout,
+        )
+    })
+    .await
+    .map_err(|e| ProxyError::Io(std::io::Error::other(e.to_string())))??;
+    stream.set_nonblocking(true)?;
+    Ok(tokio::net::TcpStream::from_std(stream)?)
+}
+
+fn connect_to_proxy(proxy: &ProxyConfig, timeout: Duration) -> ProxyResult<TcpStream> {
+    let unreachable = |reason: String| ProxyError::Unreachable {
+        proxy: proxy.address(),
+        reason,
+    };
+    let addrs: Vec<SocketAddr> = (proxy.host.trim_matches(['[', ']']), proxy.port)
+        .to_socket_addrs()
+        .map_err(|e| unreachable(e.to_string()))?
+        .collect();
+    let mut last_error = "No addresses found".to_string();
+    for addr in addrs {
+        match TcpStream::connect_timeout(&addr, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = e.to_string(),
+        }
+    }
+    Err(unreachable(last_error))
+}
+
+fn socks5_handshake(
+    stream: &mut TcpStream,
+    username: &str,
+    password: &str,
+    target_host: &str,
+    target_port: u16,
+) -> ProxyResult<()> {
+    let has_credentials = !username.is_empty();
+    if has_credentials {
+        stream.write_all(&[0x05, 0x02, 0x00, 0x02])?;
+    } else {
+        stream.write_all(&[0x05, 0x01, 0x00])?;
+    }
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply)?;
+    if reply[0] != 0x05 {
+        return Err(ProxyError::Protocol(format!(
+            "expected SOCKS version 5, got {}",
+            reply[0]
+        )));
+    }
+    match reply[1] {
+        0x00 => {}
+        0x02 if has_credentials => {
+            let user = u8::try_from(username.len()).map_err(|_| {
+                ProxyError::Protocol("proxy username longer than 255 bytes".to_string())
+            })?;
+            let pass = u8::try_from(password.len()).map_err(|_| {
+                ProxyError::Protocol("proxy password longer than 255 bytes".to_string())
+            })?;
+            let mut auth = Vec::with_capacity(3 + username.len() + password.len());
+            auth.push(0x01);
+            auth.push(user);
+            auth.extend_from_slice(username.as_bytes());
+            auth.push(pass);
+            auth.extend_from_slice(password.as_bytes());
+            let written = stream.write_all(&auth);
+            zeroize::Zeroize::zeroize(&mut auth);
+            written?;
+            let mut status = [0u8; 2];
+            stream.read_exact(&mut status)?;
+            if status[1] != 0x00 {
+                return Err(ProxyError::AuthFailed);
+            }
+        }
+        0x02 | 0xff => return Err(ProxyError::AuthRequired),
+        other => {
+            return Err(ProxyError::Protocol(format!(
+                "unsupported SOCKS5 auth method {other:#04x}"
+            )));
+        }
+    }
+
+    let mut request = vec![0x05, 0x01, 0x00];
+    match target_host.trim_matches(['[', ']']).parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(0x01);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(0x04);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            let len = u8::try_from(target_host.len()).map_err(|_| {
+                ProxyError::Protocol("target hostname longer than 255 bytes".to_string())
+            })?;
+            request.push(0x03);
+            request.push(len);
+            request.extend_from_slice(target_host.as_bytes());
+        }
+    }
+    request.extend_from_slice(&target_port.to_be_bytes());
+    stream.write_all(&request)?;
+
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header)?;
+    if header[1] != 0x00 {
+        return Err(ProxyError::Refused {
+            target: format!("{target_host}:{target_port}"),
+            reason: socks5_reply_reason(header[1]).to_string(),
+        });
+    }
+    // Skip the bound address the proxy reports
+    let remaining = match header[3] {
+        0x01 => 4 + 2,
+        0x04 => 16 + 2,
+        0x03 => {
+            let mut len = [0u8; 1];
+            stream.read_exact(&mut len)?;
+            usize::from(len[0]) + 2
+        }
+        other => {
+            return Err(ProxyError::Protocol(format!(
+                "unknown SOCKS5 address type {other:#04x}"
+            )));
+        }
+    };
+    let mut bound = vec![0u8; remaining];
+    stream.read_exact(&mut bound)?;
+    Ok(())
+}
+
+const fn socks5_reply_reason(code: u8) -> &'static str {
+    match code {
+        0x01 => "general SOCKS server failure",
+        0x02 => "connection not allowed by ruleset",
+        0x03 => "network unreachable",
+        0x04 => "host unreachable",
+        0x05 => "connection refused",
+        0x06 => "TTL expired",
+        0x07 => "command not supported",
+        0x08 => "address type not supported",
+        _ => "unknown error",
+    }
+}
+
+fn socks4_handshake(
+    stream: &mut TcpStream,
+    user_id: &str,
+    target_host: &str,
+    target_port: u16,
+) -> ProxyResult<()> {
+    let mut request = vec![0x04, 0x01];
+    request.extend_from_slice(&target_port.to_be_bytes());
+    let domain = match target_host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            request.extend_from_slice(&ip.octets());
+            None
+        }
+        Ok(IpAddr::V6(_)) => {
+            return Err(ProxyError::Protocol(
+                "SOCKS4 cannot connect to IPv6 targets".to_string(),
+            ));
+        }
+        // SOCKS4a: an address of 0.0.0.x asks the proxy to resolve the name
+        Err(_) => {
+            request.extend_from_slice(&[0, 0, 0, 1]);
+            Some(target_host)
+        }
+    };
+    request.extend_from_slice(user_id.as_bytes());
+    request.push(0);
+    if let Some(domain) = domain {
+        request.extend_from_slice(domain.as_bytes());
+        request.push(0);
+    }
+    stream.write_all(&request)?;
+
+    let mut reply = [0u8; 8];
+    stream.read_exact(&mut reply)?;
+    if reply[0] != 0x00 {
+        return Err(ProxyError::Protocol(format!(
+            "expected SOCKS4 reply version 0, got {}",
+            reply[0]
+        )));
+    }
+    if reply[1] != 0x5a {
+        return Err(ProxyError::Refused {
+            target: format!("{target_host}:{target_port}"),
+            reason: format!("request rejected (code {:#04x})", reply[1]),
+        });
+    }
+    Ok(())
+}
+
+fn http_connect(
+    stream: &mut TcpStream,
+    username: &str,
+    password: &str,
+    target_host: &str,
+    target_port: u16,
+) -> ProxyResult<()> {
+    let authority = if target_host.contains(':') && !target_host.starts_with('[') {
+        format!("[{target_host}]:{target_port}")
+    } else {
+        format!("{target_host}:{target_port}")
+    };
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if !username.is_empty() {
+        let token = zeroize::Zeroizing::new(format!("{username}:{password}"));
+        request.push_str("Proxy-Authorization: Basic ");
+        request.push_str(&data_encoding::BASE64.encode(token.as_bytes()));
+        request.push_str("\r\n");
+    }
+    request.push_str("\r\n");
+    let written = stream.write_all(request.as_bytes());
+    zeroize::Zeroize::zeroize(&mut request);
+    written?;
+
+    // Read byte by byte so nothing past the header is consumed
+    let mut response = Vec::new();
+    let mut byte = [0u8; 1];
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_HTTP_RESPONSE {
+            return Err(ProxyError::Protocol(
+                "HTTP proxy response header too long".to_string(),
+            ));
+        }
+        if stream.read(&mut byte)? == 0 {
+            return Err(ProxyError::Protocol(
+                "HTTP proxy closed the connection".to_string(),
+            ));
+        }
+        response.push(byte[0]);
+    }
+
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    let mut parts = status_line.split_whitespace();
+    let version = parts.next().unwrap_or_default();
+    let status = parts.next().and_then(|s| s.parse::<u16>().ok());
+    if !version.starts_with("HTTP/") {
+        return Err(ProxyError::Protocol(format!(
+            "unexpected status line '{status_line}'"
+        )));
+    }
+    match status {
+        Some(200..=299) => Ok(()),
+        Some(407) if username.is_empty() => Err(ProxyError::AuthRequired),
+        Some(407) => Err(ProxyError::AuthFailed),
+        _ => Err(ProxyError::Refused {
+            target: authority,
+            reason: status_line.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Spawns a one-shot proxy that runs `handshake` and then echoes
+    fn mock_proxy(
+        handshake: impl FnOnce(&mut TcpStream) + Send + 'static,
+    ) -> (u16, thread::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            handshake(&mut stream);
+            let mut buf = [0u8; 4];
+            if stream.read_exact(&mut buf).is_ok() {
+                stream.write_all(&buf).unwrap();
+            }
+        });
+        (port, handle)
+    }
+
+    fn assert_echo(mut stream: TcpStream) {
+        stream.write_all(b"ping").unwrap();
+        let mut buf = [0u8; 4];
+        stream.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"ping");
+    }
+
+    #[test]
+    fn socks5_with_authentication() {
+        let (port, handle) = mock_proxy(|s| {
+            let mut greeting = [0u8; 4];
+            s.read_exact(&mut greeting).unwrap();
+            assert_eq!(greeting, [0x05, 0x02, 0x00, 0x02]);
+            s.write_all(&[0x05, 0x02]).unwrap();
+            let mut auth = [0u8; 13];
+            s.read_exact(&mut auth).unwrap();
+            assert_eq!(&auth, b"\x01\x05alice\x05s3cr3");
+            s.write_all(&[0x01, 0x00]).unwrap();
+            let mut request = [0u8; 5 + 11 + 2];
+            s.read_exact(&mut request).unwrap();
+            assert_eq!(&request[..5], &[0x05, 0x01, 0x00, 0x03, 11]);
+            assert_eq!(&request[5..16], b"db.internal");
+            assert_eq!(&request[16..], &5432u16.to_be_bytes());
+            s.write_all(&[0x05, 0x00, 0x00, 0x01, 0, 0, 0, 0, 0, 0])
+                .unwrap();
+        });
+        let proxy = ProxyConfig::new(ProxyType::Socks5, "127.0.0.1", port).with_username("alice");
+        let password = SecretString::from("s3cr3");
+        let stream =
+            connect_via_proxy(&proxy, Some(&password), "db.internal", 5432, TIMEOUT).unwrap();
+        assert_echo(stream);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn socks5_refusal_is_reported() {
+        let (port, handle) = mock_proxy(|s| {
+            let mut greeting = [0u8; 3];
+            s.read_exact(&mut greeting).unwrap();
+            s.write_all(&[0x05, 0x00]).unwrap();
+            let mut request = [0u8; 10];
+            s.read_exact(&mut request).unwrap();
+            s.write_all(&[0x05, 0x05, 0x00, 0x01, 0, 0, 0, 0, 0, 0])
+                .unwrap();
+        });
+        let proxy = ProxyConfig::new(ProxyType::Socks5, "127.0.0.1", port);
+        let err = connect_via_proxy(&proxy, None, "10.1.2.3", 22, TIMEOUT).unwrap_err();
+        assert!(
+            matches!(err, ProxyError::Refused { ref reason, .. } if reason == "connection refused")
+        );
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn socks4a_sends_hostname() {
+        let (port, handle) = mock_proxy(|s| {
+            let mut request = [0u8; 8 + 1 + 5];
+            s.read_exact(&mut request).unwrap();
+            assert_eq!(&request[..8], &[0x04, 0x01, 0x0d, 0x3d, 0, 0, 0, 1]);
+            assert_eq!(&request[8..], b"\0vnc1\0");
+            s.write_all(&[0x00, 0x5a, 0, 0, 0, 0, 0, 0]).unwrap();
+        });
+        let proxy = ProxyConfig::new(ProxyType::Socks4, "127.0.0.1", port);
+        let stream = connect_via_proxy(&proxy, None, "vnc1", 3389, TIMEOUT).unwrap();
+        assert_echo(stream);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn http_connect_with_basic_auth() {
+        let (port, handle) = mock_proxy(|s| {
+            let mut header = Vec::new();
+            let mut byte = [0u8; 1];
+            while !header.ends_with(b"\r\n\r\n") {
+                s.read_exact(&mut byte).unwrap();
+                header.push(byte[0]);
+            }
+            let header = String::from_utf8(header).unwrap();
+            assert!(header.starts_with("CONNECT rdp.lan:3389 HTTP/1.1\r\n"));
+            // "bob:pw" in base64
+            assert!(header.contains("Proxy-Authorization: Basic Ym9iOnB3\r\n"));
+            s.write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                .unwrap();
+        });
+        let proxy = ProxyConfig::new(ProxyType::Http, "127.0.0.1", port).with_username("bob");
+        let password = SecretString::from("pw");
+        let stream = connect_via_proxy(&proxy, Some(&password), "rdp.lan", 3389, TIMEOUT).unwrap();
+        assert_echo(stream);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn http_proxy_auth_required() {
+        let (port, handle) = mock_proxy(|s| {
+            let mut buf = [0u8; 256];
+            let _ = s.read(&mut buf).unwrap();
+            s.write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                .unwrap();
+        });
+        let proxy = ProxyConfig::new(ProxyType::Http, "127.0.0.1", port);
+        let err = connect_via_proxy(&proxy, None, "rdp.lan", 3389, TIMEOUT).unwrap_err();
+        assert!(matches!(err, ProxyError::AuthRequired));
+        handle.join().unwrap();
+    }
+}
@@ -2,6 +2,11 @@
 //!
 //! This module provides retry configuration and utilities for handling
 //! transient connection failures with automatic retry and exponential backoff.
+//!
+//! Each backoff interval can be spread by a random `jitter` fraction so that
+//! many sessions dropped at once (e.g. behind the same bastion) do not all
+//! reconnect in lockstep. The randomness comes from a per-[`RetryState`]
+//! seed; [`RetryState::with_seed`] makes the sequence reproducible.
 
 use std::time::Duration;
 
@@ -19,11 +24,22 @@ pub(super) const DEFAULT_MAX_DELAY_MS: u64 = 30_000;
 /// Default backoff multiplier (delay doubles each retry)
 pub const DEFAULT_BACKOFF_MULTIPLIER: f64 = 2.0;
 
+/// Default jitter fraction (no jitter)
+pub const DEFAULT_JITTER: f64 = 0.0;
+
+/// Jitter fraction used when reconnecting a dropped session without a
+/// per-connection retry config
+pub const DEFAULT_RECONNECT_JITTER: f64 = 0.2;
+
 /// Configuration for connection retry behavior
 ///
 /// Implements exponential backoff with configurable parameters.
 /// The delay between retries is calculated as:
 /// `min(initial_delay * multiplier^attempt, max_delay)`
+///
+/// With a non-zero `jitter`, each delay is then moved by up to that fraction
+/// in either direction, never above `max_delay`. `max_elapsed_ms` caps the
+/// sum of all delays; see [`RetryState::next_delay`].
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RetryConfig {
     /// Maximum number of retry attempts (0 = no retries)
@@ -36,6 +52,12 @@ pub struct RetryConfig {
     pub backoff_multiplier: f64,
     /// Whether retry is enabled
     pub enabled: bool,
+    /// Fraction of each delay applied as random jitter (0.0–1.0)
+    #[serde(default)]
+    pub jitter: f64,
+    /// Maximum total time spent waiting between attempts, in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_elapsed_ms: Option<u64>,
 }
 
 impl Default for RetryConfig {
@@ -46,6 +68,8 @@ impl Default for RetryConfig {
             max_delay_ms: DEFAULT_MAX_DELAY_MS,
             backoff_multiplier: DEFAULT_BACKOFF_MULTIPLIER,
             enabled: true,
+            jitter: DEFAULT_JITTER,
+            max_elapsed_ms: None,
         }
     }
 }
@@ -80,6 +104,8 @@ impl RetryConfig {
             max_delay_ms: 10_000,
             backoff_multiplier: 1.5,
             enabled: true,
+            jitter: DEFAULT_JITTER,
+            max_elapsed_ms: None,
         }
     }
 
@@ -92,6 +118,8 @@ impl RetryConfig {
             max_delay_ms: 60_000,
             backoff_multiplier: 3.0,
             enabled: true,
+            jitter: DEFAULT_JITTER,
+            max_elapsed_ms: None,
         }
     }
 
@@ -130,6 +158,62 @@ impl RetryConfig {
         self
     }
 
+    /// Returns the configuration used to reconnect a dropped session
+    ///
+    /// The defaults with [`DEFAULT_RECONNECT_JITTER`], so sessions dropped
+    /// together spread their reconnects.
+    #[must_use]
+    pub fn for_reconnect() -> Self {
+        Self::default().with_jitter(DEFAULT_RECONNECT_JITTER)
+    }
+
+    /// Sets the jitter fraction (0.0–1.0)
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the maximum total time spent waiting between attempts
+    #[must_use]
+    pub const fn with_max_elapsed_ms(mut self, max_elapsed_ms: u64) -> Self {
+        self.max_elapsed_ms = Some(max_elapsed_ms);
+        self
+    }
+
+    /// Returns the jitter fraction, clamped to 0.0–1.0
+    #[must_use]
+    pub fn jitter_fraction(&self) -> f64 {
+        if self.jitter.is_finite() {
+            self.jitter.clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the range a jittered delay for `attempt` falls in
+    ///
+    /// Both ends equal [`Self::delay_for_attempt`] when jitter is off. The
+    /// upper end never exceeds `max_delay_ms` (or the initial delay, if that
+    /// is larger). Returns `None` when no retry is made for `attempt`.
+    #[must_use]
+    pub fn delay_bounds(&self, attempt: u32) -> Option<(Duration, Duration)> {
+        let base = self.delay_for_attempt(attempt)?;
+        let jitter = self.jitter_fraction();
+        let cap = Duration::from_millis(self.max_delay_ms.max(self.initial_delay_ms.max(100)));
+        let lower = base.mul_f64(1.0 - jitter);
+        let upper = base.mul_f64(1.0 + jitter).min(cap).max(lower);
+        Some((lower, upper))
+    }
+
+    /// Returns the delay for `attempt` at `sample` (0.0–1.0) within
+    /// [`Self::delay_bounds`]
+    #[must_use]
+    pub fn jittered_delay(&self, attempt: u32, sample: f64) -> Option<Duration> {
+        let (lower, upper) = self.delay_bounds(attempt)?;
+        Some(lower + (upper - lower).mul_f64(sample.clamp(0.0, 1.0)))
+    }
+
     /// Calculates the delay for a given attempt number (0-indexed)
     ///
     /// Returns `None` if retry is disabled or attempt exceeds max_attempts.
@@ -176,16 +260,30 @@ pub struct RetryState {
     config: RetryConfig,
     /// Last error message (if any)
     last_error: Option<String>,
+    /// Seed for the jitter of each attempt
+    seed: u64,
+    /// Sum of the delays of all recorded failures
+    elapsed: Duration,
 }
 
 impl RetryState {
     /// Creates a new retry state with the given configuration
+    ///
+    /// The jitter seed is random, so concurrent states spread their retries.
     #[must_use]
     pub fn new(config: RetryConfig) -> Self {
+        Self::with_seed(config, random_seed())
+    }
+
+    /// Creates a retry state whose jittered delays are determined by `seed`
+    #[must_use]
+    pub const fn with_seed(config: RetryConfig, seed: u64) -> Self {
         Self {
             current_attempt: 0,
             config,
             last_error: None,
+            seed,
+            elapsed: Duration::ZERO,
         }
     }
 
@@ -214,22 +312,43 @@ impl RetryState {
     }
 
     /// Returns whether another retry should be attempted
+    ///
+    /// `false` once the attempts are used up or the next delay would exceed
+    /// `max_elapsed_ms`.
     #[must_use]
-    pub const fn should_retry(&self) -> bool {
-        self.config.should_retry(self.current_attempt)
+    pub fn should_retry(&self) -> bool {
+        self.next_delay().is_some()
     }
 
-    /// Returns the delay before the next retry attempt
+    /// Returns the delay before the next retry attempt, with jitter applied
+    ///
+    /// The same state always returns the same delay. Returns `None` when no
+    /// retry is left, including when waiting would take the total past
+    /// `max_elapsed_ms`.
     #[must_use]
     pub fn next_delay(&self) -> Option<Duration> {
-        self.config.delay_for_attempt(self.current_attempt)
+        let delay = self
+            .config
+            .jittered_delay(self.current_attempt, self.jitter_sample())?;
+        match self.config.max_elapsed_ms {
+            Some(max) if self.elapsed + delay > Duration::from_millis(max) => None,
+            _ => Some(delay),
+        }
+    }
+
+    /// Returns the total delay of the failures recorded so far
+    #[must_use]
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
     }
 
     /// Records a failed attempt and advances to the next retry
     ///
+    /// The pending [`Self::next_delay`] is added to [`Self::elapsed`].
     /// Returns `true` if another retry will be attempted, `false` if exhausted.
     pub fn record_failure(&mut self, error: impl Into<String>) -> bool {
         self.last_error = Some(error.into());
+        self.elapsed += self.next_delay().unwrap_or_default();
         self.current_attempt += 1;
         self.should_retry()
     }
@@ -243,6 +362,7 @@ impl RetryState {
     pub fn reset(&mut self) {
         self.current_attempt = 0;
         self.last_error = None;
+        self.elapsed = Duration::ZERO;
     }
 
     /// Returns the last error message
@@ -265,6 +385,31 @@ impl RetryState {
         }
         f64::from(self.current_attempt) / f64::from(self.config.total_attempts())
     }
+
+    /// Returns a value in 0.0–1.0 derived from the seed and current attempt
+    fn jitter_sample(&self) -> f64 {
+        // SplitMix64 finaliser
+        let mut x = self
+            .seed
+            .wrapping_add(u64::from(self.current_attempt).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        x ^= x >> 31;
+        (x >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Returns a random jitter seed
+fn random_seed() -> u64 {
+    use ring::rand::{SecureRandom, SystemRandom};
+
+    let mut bytes = [0u8; 8];
+    if SystemRandom::new().fill(&mut bytes).is_ok() {
+        return u64::from_le_bytes(bytes);
+    }
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos() as u64)
 }
 
 #[cfg(test)]
@@ -336,6 +481,75 @@ mod tests {
         assert!(!state.should_retry());
     }
 
+    #[test]
+    fn test_jittered_delays_within_bounds() {
+        let config = RetryConfig::new()
+            .with_initial_delay_ms(1000)
+            .with_max_delay_ms(5000)
+            .with_max_attempts(6)
+            .with_jitter(0.25);
+        assert_eq!(
+            config.delay_bounds(0),
+            Some((Duration::from_millis(750), Duration::from_millis(1250)))
+        );
+        // 4000ms * 1.25 would pass the 5000ms cap
+        assert_eq!(
+            config.delay_bounds(2),
+            Some((Duration::from_millis(3000), Duration::from_millis(5000)))
+        );
+
+        for seed in 0..50 {
+            let mut state = RetryState::with_seed(config.clone(), seed);
+            let mut attempt = 0;
+            while let Some(delay) = state.next_delay() {
+                let (lower, upper) = config.delay_bounds(attempt).unwrap();
+                assert!(delay >= lower && delay <= upper, "{delay:?} for {attempt}");
+                state.record_failure("Timeout");
+                attempt += 1;
+            }
+            assert_eq!(attempt, 6);
+        }
+    }
+
+    #[test]
+    fn test_seeded_jitter_is_deterministic() {
+        let config = RetryConfig::new().with_max_attempts(4).with_jitter(0.5);
+        let delays = |seed| {
+            let mut state = RetryState::with_seed(config.clone(), seed);
+            let mut delays = Vec::new();
+            while let Some(delay) = state.next_delay() {
+                delays.push(delay);
+                state.record_failure("Timeout");
+            }
+            delays
+        };
+        assert_eq!(delays(7), delays(7));
+        assert_ne!(delays(7), delays(8));
+
+        // Without jitter the seed does not matter
+        let plain = RetryState::with_seed(RetryConfig::new(), 7);
+        assert_eq!(plain.next_delay(), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_max_elapsed_caps_total_wait() {
+        let config = RetryConfig::new()
+            .with_initial_delay_ms(1000)
+            .with_max_attempts(10)
+            .with_max_elapsed_ms(8000);
+        let mut state = RetryState::with_seed(config, 1);
+        // 1s + 2s + 4s = 7s; the next 8s delay would pass the cap
+        assert!(state.record_failure("a"));
+        assert!(state.record_failure("b"));
+        assert!(!state.record_failure("c"));
+        assert_eq!(state.elapsed(), Duration::from_secs(7));
+        assert_eq!(state.next_delay(), None);
+
+        state.reset();
+        assert_eq!(state.elapsed(), Duration::ZERO);
+        assert!(state.should_retry());
+    }
+
     #[test]
     fn test_retry_state_reset() {
         let mut state = RetryState::with_defaults();
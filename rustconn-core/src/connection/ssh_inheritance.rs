@@ -64,6 +64,7 @@ fn ssh_config(connection: &Connection) -> Option<&crate::models::SshConfig> {
 ///
 /// 1. If the connection has `key_source = File { path }` → return `Some(path)`
 /// 2. If `key_source = Agent` or `Default` → return `None` (no file-based key)
+///    (`KeePassAttachment` also returns `None`; the session exports it)
 /// 3. If `key_source = Inherit` → walk the group chain for `ssh_key_path`
 /// 4. If no SSH config exists on the connection → walk the group chain
 #[must_use]
@@ -76,7 +77,7 @@ pub fn resolve_ssh_key_path(
             SshKeySource::File { path } if !path.as_os_str().is_empty() => {
                 return Some(path.clone());
             }
-            SshKeySource::Agent { .. } => return None,
+            SshKeySource::Agent { .. } | SshKeySource::KeePassAttachment { .. } => return None,
             SshKeySource::Default => {
                 // Legacy connections store key in key_path with key_source=Default
                 if let Some(ref key_path) = cfg.key_path
@@ -117,7 +118,8 @@ pub fn resolve_ssh_auth_method(
 
 /// Resolves the SSH proxy jump setting for a connection.
 ///
-/// Checks the connection's SSH `proxy_jump` first, then walks the group chain
+/// Checks the connection's SSH jump chain and `proxy_jump` first (see
+/// [`crate::models::SshConfig::proxy_jump_spec`]), then walks the group chain
 /// for `ssh_proxy_jump`.
 #[must_use]
 pub fn resolve_ssh_proxy_jump(
@@ -125,8 +127,8 @@ pub fn resolve_ssh_proxy_jump(
     groups: &[ConnectionGroup],
 ) -> Option<String> {
     if let Some(cfg) = ssh_config(connection) {
-        if cfg.proxy_jump.is_some() {
-            return cfg.proxy_jump.clone();
+        if let Some(spec) = cfg.proxy_jump_spec() {
+            return Some(spec);
         }
         // Only walk groups if key_source is Inherit (connection delegates to groups)
         if !matches!(cfg.key_source, SshKeySource::Inherit) {
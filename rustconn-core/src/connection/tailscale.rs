@@ -0,0 +1,252 @@
+//! Tailscale MagicDNS resolution for port checks
+//!
+//! With split DNS, the system resolver may answer a MagicDNS name such as
+//! `web` or `web.tail1234.ts.net` with an address outside the tailnet, so a
+//! pre-connect port check probes the wrong host. For connections with
+//! `Connection::resolve_via_tailscale` set, the peer's current Tailscale IP
+//! is read from `tailscale status --json` instead.
+//!
+//! Every failure — `tailscale` not installed, the daemon stopped or logged
+//! out, the peer unknown — yields `None`, and callers fall back to the
+//! configured host.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::process::Stdio;
+
+use serde::Deserialize;
+
+/// State of the local Tailscale node, from `tailscale status --json`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TailscaleStatus {
+    /// Daemon state, `Running` when connected to the tailnet
+    #[serde(default)]
+    pub backend_state: String,
+    /// This machine
+    #[serde(default, rename = "Self")]
+    pub self_node: Option<TailscalePeer>,
+    /// Other nodes of the tailnet, keyed by node key
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub peer: HashMap<String, TailscalePeer>,
+}
+
+/// A node of the tailnet
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TailscalePeer {
+    /// Machine hostname
+    #[serde(default)]
+    pub host_name: String,
+    /// Fully qualified MagicDNS name, with a trailing dot
+    #[serde(default, rename = "DNSName")]
+    pub dns_name: String,
+    /// Tailscale addresses
+    #[serde(default, rename = "TailscaleIPs", deserialize_with = "null_as_default")]
+    pub tailscale_ips: Vec<IpAddr>,
+}
+
+/// Reads `null` as the type's default; `tailscale` emits `null` for empty
+/// collections
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Option::unwrap_or_default)
+}
+
+impl TailscalePeer {
+    /// Returns `true` if `host` names this node
+    ///
+    /// Matches the full MagicDNS name, its first label, or the hostname,
+    /// ignoring case and a trailing dot.
+    fn matches(&self, host: &str) -> bool {
+        let dns_name = self.dns_name.trim_end_matches('.');
+        let short_name = dns_name.split('.').next().unwrap_or_default();
+        [dns_name, short_name, self.host_name.as_str()]
+            .iter()
+            .any(|name| !name.is_empty() && name.eq_ignore_ascii_case(host))
+    }
+
+    /// Returns the preferred address: the first IPv4 one, else the first
+    fn preferred_ip(&self) -> Option<IpAddr> {
+        self.tailscale_ips
+            .iter()
+            .find(|ip| ip.is_ipv4())
+            .or_else(|| self.tailscale_ips.first())
+            .copied()
+    }
+}
+
+impl TailscaleStatus {
+    /// Parses the output of `tailscale status --json`
+    ///
+    /// Returns `None` if `json` is not a status document.
+    #[must_use]
+    pub fn parse(json: &str) -> Option<Self> {
+        serde_json::from_str(json)
+            .inspect_err(|e| tracing::debug!(%e, "Failed to parse tailscale status"))
+            .ok()
+    }
+
+    /// Runs `tailscale status --json`
+    ///
+    /// Returns `None` if the command is missing or fails.
+    #[must_use]
+    pub fn query() -> Option<Self> {
+        let output = std::process::Command::new("tailscale")
+            .args(["status", "--json"])
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        Self::parse(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Async version of [`Self::query`]
+    pub async fn query_async() -> Option<Self> {
+        let output = tokio::process::Command::new("tailscale")
+            .args(["status", "--json"])
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .await
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        Self::parse(&String::from_utf8_lossy(&output.stdout))
+    }
+
+    /// Returns `true` if the node is connected to the tailnet
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.backend_state == "Running"
+    }
+
+    /// Returns the Tailscale IP of the node named `host`
+    ///
+    /// `None` if Tailscale is not running, `host` is already an IP address,
+    /// or no node matches.
+    #[must_use]
+    pub fn resolve(&self, host: &str) -> Option<IpAddr> {
+        let host = host.trim().trim_end_matches('.');
+        if !self.is_running() || host.is_empty() || host.parse::<IpAddr>().is_ok() {
+            return None;
+        }
+        self.peer
+            .values()
+            .chain(self.self_node.as_ref())
+            .find(|node| node.matches(host))
+            .and_then(TailscalePeer::preferred_ip)
+    }
+}
+
+/// Looks up the Tailscale IP of `host` with `tailscale status --json`
+///
+/// Blocks while `tailscale` runs; call it from a worker thread.
+#[must_use]
+pub fn resolve_tailscale_host(host: &str) -> Option<IpAddr> {
+    TailscaleStatus::query()?.resolve(host)
+}
+
+/// Async version of [`resolve_tailscale_host`]
+pub async fn resolve_tailscale_host_async(host: &str) -> Option<IpAddr> {
+    TailscaleStatus::query_async().await?.resolve(host)
+}
+
+/// Returns the address a port check should probe for `host`
+///
+/// With `tailscale` set, this is the peer's Tailscale IP when Tailscale
+/// knows it; otherwise `host` is returned unchanged. Blocks like
+/// [`resolve_tailscale_host`].
+#[must_use]
+pub fn probe_host(host: &str, tailscale: bool) -> Cow<'_, str> {
+    if tailscale && let Some(ip) = resolve_tailscale_host(host) {
+        tracing::debug!(host, %ip, "Resolved host through Tailscale");
+        return Cow::Owned(ip.to_string());
+    }
+    Cow::Borrowed(host)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUS: &str = r#"{
+  "Version": "1.76.1-t1234567",
+  "BackendState": "Running",
+  "TailscaleIPs": ["100.101.102.103", "fd7a:115c:a1e0::1"],
+  "Self": {
+    "ID": "n1",
+    "HostName": "laptop",
+    "DNSName": "laptop.tail1234.ts.net.",
+    "TailscaleIPs": ["100.101.102.103", "fd7a:115c:a1e0::1"],
+    "Online": true
+  },
+  "MagicDNSSuffix": "tail1234.ts.net",
+  "CurrentTailnet": {
+    "Name": "user@example.com",
+    "MagicDNSSuffix": "tail1234.ts.net",
+    "MagicDNSEnabled": true
+  },
+  "Peer": {
+    "nodekey:abc": {
+      "ID": "n2",
+      "HostName": "Web-Server",
+      "DNSName": "web.tail1234.ts.net.",
+      "TailscaleIPs": ["fd7a:115c:a1e0::5", "100.64.0.5"],
+      "Online": true
+    },
+    "nodekey:def": {
+      "ID": "n3",
+      "HostName": "db",
+      "DNSName": "db.tail1234.ts.net.",
+      "TailscaleIPs": ["fd7a:115c:a1e0::6"],
+      "Online": false
+    }
+  }
+}"#;
+
+    #[test]
+    fn test_resolve_peer_ip() {
+        let status = TailscaleStatus::parse(STATUS).unwrap();
+        assert!(status.is_running());
+        assert_eq!(status.peer.len(), 2);
+
+        let web: IpAddr = "100.64.0.5".parse().unwrap();
+        assert_eq!(status.resolve("web"), Some(web));
+        assert_eq!(status.resolve("web.tail1234.ts.net."), Some(web));
+        assert_eq!(status.resolve("WEB.tail1234.ts.net"), Some(web));
+        assert_eq!(status.resolve("web-server"), Some(web));
+        assert_eq!(
+            status.resolve("db"),
+            Some("fd7a:115c:a1e0::6".parse().unwrap())
+        );
+        assert_eq!(
+            status.resolve("laptop"),
+            Some("100.101.102.103".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_resolve_noop_cases() {
+        let status = TailscaleStatus::parse(STATUS).unwrap();
+        assert_eq!(status.resolve("mail.example.com"), None);
+        assert_eq!(status.resolve("100.64.0.5"), None);
+        assert_eq!(status.resolve(""), None);
+
+        let stopped = TailscaleStatus::parse(
+            r#"{"BackendState": "Stopped", "Self": {"TailscaleIPs": null}, "Peer": null}"#,
+        )
+        .unwrap();
+        assert!(!stopped.is_running());
+        assert_eq!(stopped.resolve("web"), None);
+        assert_eq!(TailscaleStatus::parse("not json"), None);
+    }
+}
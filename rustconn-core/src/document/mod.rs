@@ -9,8 +9,10 @@
 //!
 //! - Create independent containers for connections and groups
 //! - Password-based encryption for document protection
+//! - Optional gzip compression of the plaintext before encryption
 //! - Export/import for portable sharing
 //! - Dirty state tracking for unsaved changes
+//! - Merging two copies of a document with conflict detection
 //!
 //! # Example
 //!
@@ -28,6 +30,7 @@
 //! ```
 
 use std::collections::HashMap;
+use std::io::{Read, Write};
 use std::path::Path;
 
 use chrono::{DateTime, Utc};
@@ -89,6 +92,23 @@ const ENCRYPTED_MAGIC: &[u8] = b"RCDB_ENC";
 /// byte of a legacy salt, which had a ~1.2% collision probability.
 const ENCRYPTED_MAGIC_V2: &[u8] = b"RCDB_EN2";
 
+/// Magic bytes for V3 encrypted format — adds a compression byte after the
+/// strength byte and authenticates the header as AES-GCM associated data.
+///
+/// The trailing digit is the encrypted-header version, independent of
+/// [`DOCUMENT_FORMAT_VERSION`] (which versions the JSON schema). V3 is only
+/// written for compressed documents, so uncompressed files stay readable by
+/// releases that only know V2.
+const ENCRYPTED_MAGIC_V3: &[u8] = b"RCDB_EN3";
+
+/// Common prefix of all encrypted header versions. Files with this prefix
+/// but an unknown version digit are rejected with `InvalidFormat` instead of
+/// being parsed as plaintext.
+const ENCRYPTED_MAGIC_PREFIX: &[u8] = b"RCDB_EN";
+
+/// Upper bound for an inflated document, guarding against decompression bombs
+const MAX_DECOMPRESSED_SIZE: u64 = 256 * 1024 * 1024;
+
 /// A portable document containing connections, groups, variables, and templates
 ///
 /// Documents serve as independent containers that can be shared between users
@@ -329,6 +349,143 @@ impl Default for Document {
     }
 }
 
+/// How a variable or template name collision was resolved on import
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameCollision {
+    /// A document variable was not imported because a global variable with
+    /// the same name and a different value already exists
+    VariableSkipped(String),
+    /// A document template was imported under a new name
+    TemplateRenamed {
+        /// Name in the document
+        from: String,
+        /// Name in the main configuration
+        to: String,
+    },
+    /// A document template was not imported because it is already present
+    TemplateSkipped(String),
+}
+
+/// Outcome of importing a document into the main configuration
+///
+/// Created by `ConnectionManager::import_document_into_config`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentImportReport {
+    /// Connections added
+    pub created: usize,
+    /// Existing connections updated in place
+    pub updated: usize,
+    /// Connections skipped as duplicates
+    pub skipped: usize,
+    /// Groups added (groups matching an existing one by name are reused)
+    pub groups_created: usize,
+    /// Variables added to the global variables
+    pub variables_added: usize,
+    /// Templates added
+    pub templates_added: usize,
+    /// Variable and template name collisions, in import order
+    pub collisions: Vec<NameCollision>,
+}
+
+/// Identifies a single item inside a document for merge reporting
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MergeItem {
+    /// Connection, by ID
+    Connection(Uuid),
+    /// Group, by ID
+    Group(Uuid),
+    /// Variable, by name (variables have no ID)
+    Variable(String),
+    /// Template, by ID
+    Template(Uuid),
+}
+
+/// An item present in both documents with divergent content where neither
+/// side is strictly newer
+///
+/// The local version is left untouched. Call
+/// [`DocumentManager::accept_incoming`] to take the other side instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    /// The conflicting item
+    pub item: MergeItem,
+    /// Display name of the local version
+    pub name: String,
+    /// Last modification of the local version (`None` for variables)
+    pub local_updated_at: Option<DateTime<Utc>>,
+    /// Last modification of the incoming version (`None` for variables)
+    pub incoming_updated_at: Option<DateTime<Utc>>,
+}
+
+/// Outcome of merging another copy of a document
+///
+/// Created by [`DocumentManager::merge`]. Items whose content is identical on
+/// both sides are not listed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentMergeReport {
+    /// Items that only existed in the incoming document and were added
+    pub added: Vec<MergeItem>,
+    /// Items replaced by a strictly newer incoming version
+    pub updated: Vec<MergeItem>,
+    /// Items where the local version is strictly newer and was kept
+    pub kept: Vec<MergeItem>,
+    /// Items that need a decision from the user
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl DocumentMergeReport {
+    /// Returns true if the merge changed the local document
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.updated.is_empty()
+    }
+
+    /// Returns true if any item needs a decision from the user
+    #[must_use]
+    pub fn has_conflicts(&self) -> bool {
+        !self.conflicts.is_empty()
+    }
+}
+
+/// Merges one ID-keyed collection, newer `updated_at` wins
+///
+/// Equal timestamps with different content are conflicts. Item timestamps
+/// are preserved so a later merge compares the same values.
+fn merge_by_id<T: Clone + PartialEq>(
+    local: &mut Vec<T>,
+    incoming: &[T],
+    key: impl Fn(&T) -> (Uuid, DateTime<Utc>, &str),
+    item: impl Fn(Uuid) -> MergeItem,
+    report: &mut DocumentMergeReport,
+) {
+    for theirs in incoming {
+        let (id, their_updated_at, _) = key(theirs);
+        let Some(ours) = local.iter_mut().find(|o| key(o).0 == id) else {
+            local.push(theirs.clone());
+            report.added.push(item(id));
+            continue;
+        };
+        if ours == theirs {
+            continue;
+        }
+
+        let (_, our_updated_at, name) = key(ours);
+        if their_updated_at > our_updated_at {
+            *ours = theirs.clone();
+            report.updated.push(item(id));
+        } else if their_updated_at < our_updated_at {
+            report.kept.push(item(id));
+        } else {
+            report.conflicts.push(MergeConflict {
+                item: item(id),
+                name: name.to_string(),
+                local_updated_at: Some(our_updated_at),
+                incoming_updated_at: Some(their_updated_at),
+            });
+        }
+    }
+}
+
 /// Encryption strength presets for document protection.
 ///
 /// Higher strength increases resistance to brute-force attacks but takes
@@ -387,6 +544,79 @@ impl EncryptionStrength {
     }
 }
 
+/// Compression applied to the document JSON before it is encrypted
+///
+/// Encrypted output is indistinguishable from random data and cannot be
+/// compressed afterwards, so large documents must be compressed first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DocumentCompression {
+    /// No compression (V2 header, readable by older releases)
+    #[default]
+    None,
+    /// gzip (DEFLATE) compression (V3 header)
+    Gzip,
+}
+
+impl DocumentCompression {
+    /// Serializes this method to a single byte for the file header.
+    const fn to_byte(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Gzip => 1,
+        }
+    }
+
+    /// Deserializes a method from a header byte.
+    const fn from_byte(b: u8) -> Option<Self> {
+        match b {
+            0 => Some(Self::None),
+            1 => Some(Self::Gzip),
+            _ => None,
+        }
+    }
+
+    /// Compresses `data` with this method
+    fn compress(self, data: Vec<u8>) -> DocumentResult<Vec<u8>> {
+        match self {
+            Self::None => Ok(data),
+            Self::Gzip => {
+                use flate2::Compression;
+                use flate2::write::GzEncoder;
+
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder
+                    .write_all(&data)
+                    .and_then(|()| encoder.finish())
+                    .map_err(|e| DocumentError::SerializeError(format!("Compression failed: {e}")))
+            }
+        }
+    }
+
+    /// Inflates `data` that was compressed with this method
+    fn decompress(self, data: Vec<u8>) -> DocumentResult<Vec<u8>> {
+        match self {
+            Self::None => Ok(data),
+            Self::Gzip => {
+                use flate2::read::GzDecoder;
+
+                let mut inflated = Vec::new();
+                GzDecoder::new(data.as_slice())
+                    .take(MAX_DECOMPRESSED_SIZE + 1)
+                    .read_to_end(&mut inflated)
+                    .map_err(|e| {
+                        DocumentError::InvalidFormat(format!("Decompression failed: {e}"))
+                    })?;
+                if inflated.len() as u64 > MAX_DECOMPRESSED_SIZE {
+                    return Err(DocumentError::InvalidFormat(
+                        "Decompressed document exceeds size limit".to_string(),
+                    ));
+                }
+                Ok(inflated)
+            }
+        }
+    }
+}
+
 /// Manager for handling multiple documents with dirty state tracking
 ///
 /// The `DocumentManager` provides CRUD operations for documents and tracks
@@ -432,8 +662,7 @@ impl DocumentManager {
     pub fn load(&mut self, path: &Path, password: Option<&str>) -> DocumentResult<Uuid> {
         let content = std::fs::read(path).map_err(|e| DocumentError::IoError(e.to_string()))?;
 
-        let doc = if content.starts_with(ENCRYPTED_MAGIC_V2) || content.starts_with(ENCRYPTED_MAGIC)
-        {
+        let doc = if content.starts_with(ENCRYPTED_MAGIC_PREFIX) {
             // Document is encrypted
             let password = password.ok_or(DocumentError::PasswordRequired)?;
             decrypt_document(&content, password)?
@@ -472,11 +701,32 @@ impl DocumentManager {
         path: &Path,
         password: Option<&str>,
         strength: EncryptionStrength,
+    ) -> DocumentResult<()> {
+        self.save_with_compression(id, path, password, strength, DocumentCompression::None)
+    }
+
+    /// Saves a document, compressing the plaintext before encryption
+    ///
+    /// Compression only applies to encrypted saves; unencrypted documents
+    /// are always written as plain JSON. Compressed files use the V3
+    /// encrypted header and cannot be opened by releases that predate it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not found, cannot be compressed,
+    /// or cannot be written
+    pub fn save_with_compression(
+        &mut self,
+        id: Uuid,
+        path: &Path,
+        password: Option<&str>,
+        strength: EncryptionStrength,
+        compression: DocumentCompression,
     ) -> DocumentResult<()> {
         let doc = self.documents.get(&id).ok_or(DocumentError::NotFound(id))?;
 
         let content = if let Some(pwd) = password {
-            encrypt_document(doc, pwd, strength)?
+            encrypt_document(doc, pwd, strength, compression)?
         } else {
             doc.to_json()?.into_bytes()
         };
@@ -630,6 +880,119 @@ impl DocumentManager {
         self.dirty_flags.insert(id, true);
         id
     }
+
+    /// Merges another copy of a document into a loaded one
+    ///
+    /// Connections, groups and templates are matched by ID and variables by
+    /// name. Missing items are added and items with a strictly newer
+    /// `updated_at` replace the local version. Divergent items with equal
+    /// timestamps, and divergent variables (which carry no timestamp), are
+    /// reported as conflicts and left unchanged.
+    ///
+    /// The target is touched and marked dirty only if something was added
+    /// or updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DocumentError::NotFound` if `into` is not loaded
+    pub fn merge(&mut self, into: Uuid, other: &Document) -> DocumentResult<DocumentMergeReport> {
+        let doc = self
+            .documents
+            .get_mut(&into)
+            .ok_or(DocumentError::NotFound(into))?;
+        let mut report = DocumentMergeReport::default();
+
+        merge_by_id(
+            &mut doc.groups,
+            &other.groups,
+            |g| (g.id, g.updated_at, g.name.as_str()),
+            MergeItem::Group,
+            &mut report,
+        );
+        merge_by_id(
+            &mut doc.connections,
+            &other.connections,
+            |c| (c.id, c.updated_at, c.name.as_str()),
+            MergeItem::Connection,
+            &mut report,
+        );
+        merge_by_id(
+            &mut doc.templates,
+            &other.templates,
+            |t| (t.id, t.updated_at, t.name.as_str()),
+            MergeItem::Template,
+            &mut report,
+        );
+
+        for (name, theirs) in &other.variables {
+            match doc.variables.get(name) {
+                None => {
+                    doc.variables.insert(name.clone(), theirs.clone());
+                    report.added.push(MergeItem::Variable(name.clone()));
+                }
+                Some(ours) if ours != theirs => report.conflicts.push(MergeConflict {
+                    item: MergeItem::Variable(name.clone()),
+                    name: name.clone(),
+                    local_updated_at: None,
+                    incoming_updated_at: None,
+                }),
+                Some(_) => {}
+            }
+        }
+
+        if report.has_changes() {
+            doc.touch();
+            self.dirty_flags.insert(into, true);
+        }
+        Ok(report)
+    }
+
+    /// Resolves a merge conflict by taking the incoming version of an item
+    ///
+    /// Returns `false` if `other` does not contain the item.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DocumentError::NotFound` if `into` is not loaded
+    pub fn accept_incoming(
+        &mut self,
+        into: Uuid,
+        other: &Document,
+        item: &MergeItem,
+    ) -> DocumentResult<bool> {
+        let doc = self
+            .documents
+            .get_mut(&into)
+            .ok_or(DocumentError::NotFound(into))?;
+
+        let replaced = match item {
+            MergeItem::Connection(id) => other.get_connection(*id).is_some_and(|theirs| {
+                doc.connections.retain(|c| c.id != *id);
+                doc.connections.push(theirs.clone());
+                true
+            }),
+            MergeItem::Group(id) => other.get_group(*id).is_some_and(|theirs| {
+                doc.groups.retain(|g| g.id != *id);
+                doc.groups.push(theirs.clone());
+                true
+            }),
+            MergeItem::Template(id) => other.get_template(*id).is_some_and(|theirs| {
+                doc.templates.retain(|t| t.id != *id);
+                doc.templates.push(theirs.clone());
+                true
+            }),
+            MergeItem::Variable(name) => other.get_variable(name).is_some_and(|theirs| {
+                doc.variables.insert(name.clone(), theirs.clone());
+                true
+            }),
+        };
+
+        if replaced {
+            doc.touch();
+            self.dirty_flags.insert(into, true);
+        }
+        Ok(replaced)
+    }
 }
 
 /// Encrypts a document using password-based encryption
@@ -637,16 +1000,36 @@ impl DocumentManager {
 /// Uses AES-256-GCM with Argon2id key derivation. The encryption strength
 /// is stored as a single byte after the magic header so that decryption
 /// can automatically select the matching Argon2 parameters.
+///
+/// Uncompressed documents are written in the V2 layout. Compressed ones use
+/// V3, which adds a compression byte and authenticates the header.
 fn encrypt_document(
     doc: &Document,
     password: &str,
     strength: EncryptionStrength,
+    compression: DocumentCompression,
 ) -> DocumentResult<Vec<u8>> {
     use ring::aead::{AES_256_GCM, Aad, LessSafeKey, Nonce, UnboundKey};
     use ring::rand::{SecureRandom, SystemRandom};
 
-    // Serialize document to JSON
-    let plaintext = doc.to_json()?;
+    // Serialize document to JSON and compress it if requested
+    let plaintext = compression.compress(doc.to_json()?.into_bytes())?;
+
+    // V2: magic + strength_byte; V3: magic + strength_byte + compression_byte
+    let mut header = Vec::with_capacity(ENCRYPTED_MAGIC_V3.len() + 2);
+    if compression == DocumentCompression::None {
+        header.extend_from_slice(ENCRYPTED_MAGIC_V2);
+        header.push(strength.to_byte());
+    } else {
+        header.extend_from_slice(ENCRYPTED_MAGIC_V3);
+        header.push(strength.to_byte());
+        header.push(compression.to_byte());
+    }
+    let aad = if compression == DocumentCompression::None {
+        Aad::empty()
+    } else {
+        Aad::from(header.as_slice())
+    };
 
     // Generate random salt and nonce
     let rng = SystemRandom::new();
@@ -666,15 +1049,14 @@ fn encrypt_document(
     let less_safe_key = LessSafeKey::new(unbound_key);
     let nonce = Nonce::assume_unique_for_key(nonce_bytes);
 
-    let mut ciphertext = plaintext.into_bytes();
+    let mut ciphertext = plaintext;
     less_safe_key
-        .seal_in_place_append_tag(nonce, Aad::empty(), &mut ciphertext)
+        .seal_in_place_append_tag(nonce, aad, &mut ciphertext)
         .map_err(|_| DocumentError::EncryptionError("Encryption failed".to_string()))?;
 
-    // Build output: V2 magic + strength_byte + salt + nonce + ciphertext
-    let mut output = Vec::with_capacity(ENCRYPTED_MAGIC_V2.len() + 1 + 32 + 12 + ciphertext.len());
-    output.extend_from_slice(ENCRYPTED_MAGIC_V2);
-    output.push(strength.to_byte());
+    // Build output: header + salt + nonce + ciphertext
+    let mut output = Vec::with_capacity(header.len() + 32 + 12 + ciphertext.len());
+    output.extend_from_slice(&header);
     output.extend_from_slice(&salt);
     output.extend_from_slice(&nonce_bytes);
     output.extend_from_slice(&ciphertext);
@@ -690,13 +1072,19 @@ fn decrypt_document(data: &[u8], password: &str) -> DocumentResult<Document> {
     use ring::aead::{AES_256_GCM, Aad, LessSafeKey, Nonce, UnboundKey};
 
     // Determine format version from magic header
+    if data.starts_with(ENCRYPTED_MAGIC_V3) {
+        return decrypt_document_v3(data, password);
+    }
     let is_v2 = data.starts_with(ENCRYPTED_MAGIC_V2);
     let is_v1 = data.starts_with(ENCRYPTED_MAGIC);
 
     if !is_v2 && !is_v1 {
-        return Err(DocumentError::InvalidFormat(
-            "Not an encrypted document".to_string(),
-        ));
+        let reason = if data.starts_with(ENCRYPTED_MAGIC_PREFIX) {
+            "Unsupported encrypted document version"
+        } else {
+            "Not an encrypted document"
+        };
+        return Err(DocumentError::InvalidFormat(reason.to_string()));
     }
 
     let header_len = if is_v2 {
@@ -777,6 +1165,57 @@ fn decrypt_document(data: &[u8], password: &str) -> DocumentResult<Document> {
     Document::from_json(&json)
 }
 
+/// Decrypts a V3 encrypted document (strength and compression bytes, header
+/// authenticated as associated data).
+fn decrypt_document_v3(data: &[u8], password: &str) -> DocumentResult<Document> {
+    use ring::aead::{AES_256_GCM, Aad, LessSafeKey, Nonce, UnboundKey};
+
+    let magic_len = ENCRYPTED_MAGIC_V3.len();
+    let header_len = magic_len + 2;
+    if data.len() < header_len + 32 + 12 + AES_256_GCM.tag_len() {
+        return Err(DocumentError::InvalidFormat(
+            "Document too short".to_string(),
+        ));
+    }
+
+    let strength = EncryptionStrength::from_byte(data[magic_len]).ok_or_else(|| {
+        DocumentError::InvalidFormat(format!("Unknown encryption strength {}", data[magic_len]))
+    })?;
+    let compression = DocumentCompression::from_byte(data[magic_len + 1]).ok_or_else(|| {
+        DocumentError::InvalidFormat(format!(
+            "Unknown compression method {}",
+            data[magic_len + 1]
+        ))
+    })?;
+
+    let (header, body) = data.split_at(header_len);
+    let salt = &body[..32];
+    let nonce_bytes = &body[32..32 + 12];
+    let ciphertext = &body[32 + 12..];
+
+    let key = derive_key(password, salt, strength)?;
+
+    let unbound_key = UnboundKey::new(&AES_256_GCM, key.as_ref())
+        .map_err(|_| DocumentError::EncryptionError("Failed to create key".to_string()))?;
+    let less_safe_key = LessSafeKey::new(unbound_key);
+
+    let mut nonce_array = [0u8; 12];
+    nonce_array.copy_from_slice(nonce_bytes);
+    let nonce = Nonce::assume_unique_for_key(nonce_array);
+
+    let mut plaintext = ciphertext.to_vec();
+    less_safe_key
+        .open_in_place(nonce, Aad::from(header), &mut plaintext)
+        .map_err(|_| DocumentError::InvalidPassword)?;
+
+    let tag_len = AES_256_GCM.tag_len();
+    plaintext.truncate(plaintext.len() - tag_len);
+
+    let json = String::from_utf8(compression.decompress(plaintext)?)
+        .map_err(|e| DocumentError::ParseError(e.to_string()))?;
+    Document::from_json(&json)
+}
+
 /// Decrypts a legacy-format encrypted document (no strength byte).
 fn decrypt_document_legacy(data: &[u8], password: &str) -> DocumentResult<Document> {
     use ring::aead::{AES_256_GCM, Aad, LessSafeKey, Nonce, UnboundKey};
@@ -998,6 +1437,278 @@ mod tests {
         assert!(dirty_ids.contains(&id2));
     }
 
+    /// Builds a document with many similar connections, like a real inventory
+    fn large_document() -> Document {
+        let mut doc = Document::new("Inventory");
+        for i in 0..500 {
+            let mut conn = Connection::new_ssh(
+                format!("web-{i:03}.prod"),
+                format!("web-{i:03}.prod.example.com"),
+                22,
+            );
+            conn.username = Some("deploy".to_string());
+            doc.add_connection(conn);
+        }
+        doc
+    }
+
+    #[test]
+    fn test_compressed_encryption_round_trip() {
+        let doc = large_document();
+
+        let plain = encrypt_document(
+            &doc,
+            "secret",
+            EncryptionStrength::Standard,
+            DocumentCompression::None,
+        )
+        .unwrap();
+        let compressed = encrypt_document(
+            &doc,
+            "secret",
+            EncryptionStrength::Standard,
+            DocumentCompression::Gzip,
+        )
+        .unwrap();
+
+        assert!(plain.starts_with(ENCRYPTED_MAGIC_V2));
+        assert!(compressed.starts_with(ENCRYPTED_MAGIC_V3));
+        // Repetitive connection JSON deflates far below half its size
+        assert!(
+            compressed.len() * 4 < plain.len(),
+            "compressed {} bytes vs uncompressed {} bytes",
+            compressed.len(),
+            plain.len()
+        );
+
+        let decrypted = decrypt_document(&compressed, "secret").unwrap();
+        assert_eq!(decrypted.id, doc.id);
+        assert_eq!(decrypted.connection_count(), 500);
+        assert_eq!(
+            decrypt_document(&plain, "secret")
+                .unwrap()
+                .connection_count(),
+            500
+        );
+    }
+
+    #[test]
+    fn test_compressed_header_is_authenticated() {
+        let doc = large_document();
+        let mut data = encrypt_document(
+            &doc,
+            "secret",
+            EncryptionStrength::Standard,
+            DocumentCompression::Gzip,
+        )
+        .unwrap();
+
+        // Flipping the compression byte to "none" must fail authentication
+        data[ENCRYPTED_MAGIC_V3.len() + 1] = DocumentCompression::None.to_byte();
+        assert_eq!(
+            decrypt_document(&data, "secret"),
+            Err(DocumentError::InvalidPassword)
+        );
+    }
+
+    #[test]
+    fn test_unknown_encrypted_version_rejected() {
+        let mut data = b"RCDB_EN9".to_vec();
+        data.extend_from_slice(&[0u8; 64]);
+        assert!(matches!(
+            decrypt_document(&data, "secret"),
+            Err(DocumentError::InvalidFormat(_))
+        ));
+
+        let mut data = ENCRYPTED_MAGIC_V3.to_vec();
+        data.push(EncryptionStrength::Standard.to_byte());
+        data.push(0xFF);
+        data.extend_from_slice(&[0u8; 64]);
+        assert!(matches!(
+            decrypt_document(&data, "secret"),
+            Err(DocumentError::InvalidFormat(_))
+        ));
+    }
+
+    /// Returns a manager holding `doc` marked clean, plus a copy of `doc`
+    fn merge_fixture(doc: Document) -> (DocumentManager, Uuid, Document) {
+        let mut manager = DocumentManager::new();
+        let id = manager.insert(doc.clone());
+        manager.mark_clean(id);
+        (manager, id, doc)
+    }
+
+    #[test]
+    fn test_merge_add_only() {
+        let mut base = Document::new("Shared");
+        base.add_connection(Connection::new_ssh(
+            "db".to_string(),
+            "db.example.com".to_string(),
+            22,
+        ));
+        let (mut manager, id, mut other) = merge_fixture(base);
+        let modified_before = manager.get(id).unwrap().modified_at;
+
+        let web = Connection::new_ssh("web".to_string(), "web.example.com".to_string(), 22);
+        let web_id = web.id;
+        let group = ConnectionGroup::new("Prod".to_string());
+        let group_id = group.id;
+        other.add_connection(web);
+        other.add_group(group);
+        other.set_variable(Variable::new("env", "prod"));
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        let report = manager.merge(id, &other).unwrap();
+
+        assert_eq!(report.added.len(), 3);
+        assert!(report.added.contains(&MergeItem::Connection(web_id)));
+        assert!(report.added.contains(&MergeItem::Group(group_id)));
+        assert!(
+            report
+                .added
+                .contains(&MergeItem::Variable("env".to_string()))
+        );
+        assert!(report.updated.is_empty());
+        assert!(!report.has_conflicts());
+
+        let merged = manager.get(id).unwrap();
+        assert_eq!(merged.connection_count(), 2);
+        assert!(merged.modified_at > modified_before);
+        assert!(manager.is_dirty(id));
+    }
+
+    #[test]
+    fn test_merge_identical_is_noop() {
+        let mut base = Document::new("Shared");
+        base.add_connection(Connection::new_ssh(
+            "db".to_string(),
+            "db.example.com".to_string(),
+            22,
+        ));
+        let (mut manager, id, other) = merge_fixture(base);
+        let modified_before = manager.get(id).unwrap().modified_at;
+
+        let report = manager.merge(id, &other).unwrap();
+
+        assert_eq!(report, DocumentMergeReport::default());
+        assert_eq!(manager.get(id).unwrap().modified_at, modified_before);
+        assert!(!manager.is_dirty(id));
+    }
+
+    #[test]
+    fn test_merge_newer_wins() {
+        let mut base = Document::new("Shared");
+        let conn = Connection::new_ssh("db".to_string(), "db.example.com".to_string(), 22);
+        let conn_id = conn.id;
+        let stale = Connection::new_ssh("old".to_string(), "old.example.com".to_string(), 22);
+        let stale_id = stale.id;
+        base.add_connection(conn);
+        base.add_connection(stale);
+        let (mut manager, id, mut other) = merge_fixture(base);
+
+        // Incoming edited `db` later; local edited `old` later
+        let theirs = other.get_connection_mut(conn_id).unwrap();
+        theirs.host = "db2.example.com".to_string();
+        theirs.updated_at += chrono::Duration::seconds(5);
+        let ours = manager
+            .get_mut(id)
+            .unwrap()
+            .get_connection_mut(stale_id)
+            .unwrap();
+        ours.port = 2222;
+        ours.updated_at += chrono::Duration::seconds(5);
+        other.get_connection_mut(stale_id).unwrap().port = 2200;
+
+        let report = manager.merge(id, &other).unwrap();
+
+        assert_eq!(report.updated, vec![MergeItem::Connection(conn_id)]);
+        assert_eq!(report.kept, vec![MergeItem::Connection(stale_id)]);
+        assert!(!report.has_conflicts());
+
+        let merged = manager.get(id).unwrap();
+        let db = merged.get_connection(conn_id).unwrap();
+        assert_eq!(db.host, "db2.example.com");
+        // The incoming timestamp is kept, not reset by the merge
+        assert_eq!(
+            db.updated_at,
+            other.get_connection(conn_id).unwrap().updated_at
+        );
+        assert_eq!(merged.get_connection(stale_id).unwrap().port, 2222);
+    }
+
+    #[test]
+    fn test_merge_true_conflict() {
+        let mut base = Document::new("Shared");
+        let conn = Connection::new_ssh("db".to_string(), "db.example.com".to_string(), 22);
+        let conn_id = conn.id;
+        base.add_connection(conn);
+        base.set_variable(Variable::new("env", "prod"));
+        let (mut manager, id, mut other) = merge_fixture(base);
+        let modified_before = manager.get(id).unwrap().modified_at;
+
+        // Both sides edited without moving `updated_at` apart
+        other.get_connection_mut(conn_id).unwrap().host = "theirs.example.com".to_string();
+        other.set_variable(Variable::new("env", "staging"));
+        manager
+            .get_mut(id)
+            .unwrap()
+            .get_connection_mut(conn_id)
+            .unwrap()
+            .host = "ours.example.com".to_string();
+        manager.mark_clean(id);
+
+        let report = manager.merge(id, &other).unwrap();
+
+        assert!(!report.has_changes());
+        assert_eq!(report.conflicts.len(), 2);
+        let conflict = report
+            .conflicts
+            .iter()
+            .find(|c| c.item == MergeItem::Connection(conn_id))
+            .unwrap();
+        assert_eq!(conflict.name, "db");
+        assert_eq!(conflict.local_updated_at, conflict.incoming_updated_at);
+        assert!(
+            report
+                .conflicts
+                .iter()
+                .any(|c| c.item == MergeItem::Variable("env".to_string()))
+        );
+
+        // Conflicts are not resolved silently
+        let merged = manager.get(id).unwrap();
+        assert_eq!(
+            merged.get_connection(conn_id).unwrap().host,
+            "ours.example.com"
+        );
+        assert_eq!(merged.get_variable("env").unwrap().value, "prod");
+        assert_eq!(merged.modified_at, modified_before);
+        assert!(!manager.is_dirty(id));
+
+        // The caller resolves them explicitly
+        assert!(manager.accept_incoming(id, &other, &conflict.item).unwrap());
+        assert_eq!(
+            manager
+                .get(id)
+                .unwrap()
+                .get_connection(conn_id)
+                .unwrap()
+                .host,
+            "theirs.example.com"
+        );
+        assert!(manager.is_dirty(id));
+    }
+
+    #[test]
+    fn test_merge_unknown_document() {
+        let mut manager = DocumentManager::new();
+        let missing = Uuid::new_v4();
+        assert_eq!(
+            manager.merge(missing, &Document::new("Other")),
+            Err(DocumentError::NotFound(missing))
+        );
+    }
+
     #[test]
     fn test_document_touch_updates_modified_at() {
         let mut doc = Document::new("Test");
@@ -139,6 +139,38 @@ pub enum SecretError {
     /// Pass (passwordstore)-specific error
     #[error("Pass error: {0}")]
     Pass(String),
+
+    /// The master password supplied to unlock a vault was rejected
+    #[error("Invalid master password: {0}")]
+    InvalidPassword(String),
+
+    /// The CLI binary or library needed to reach the backend is not installed
+    #[error("Secret backend client not found: {0}")]
+    ClientMissing(String),
+
+    /// The backend client is present but its server could not be reached
+    #[error("Secret backend server unreachable: {0}")]
+    ServerUnreachable(String),
+
+    /// The backend is reachable but the vault must be unlocked first
+    #[error("Secret vault is locked: {0}")]
+    VaultLocked(String),
+
+    /// The backend CLI is installed but has no signed-in session
+    #[error("Not signed in to secret backend: {0}")]
+    NotSignedIn(String),
+
+    /// The backend rejected or did not receive an authentication token
+    #[error("Secret backend authentication failed: {0}")]
+    AuthFailed(String),
+
+    /// No secret exists at the requested path
+    #[error("Secret not found: {0}")]
+    NotFound(String),
+
+    /// The caller is authenticated but not allowed to access the path
+    #[error("Permission denied for secret: {0}")]
+    PermissionDenied(String),
 }
 
 /// Errors related to configuration import operations
@@ -2,8 +2,9 @@
 //!
 //! Exports `RustConn` connections to Ansible inventory format (INI and YAML).
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
+use std::path::Path;
 
 use uuid::Uuid;
 
@@ -12,6 +13,32 @@ use super::{
 };
 use crate::models::{Connection, ConnectionGroup, ProtocolConfig, ProtocolType};
 
+/// Ansible inventory file syntax
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnsibleInventoryFormat {
+    /// INI inventory (`[group]` sections)
+    #[default]
+    Ini,
+    /// YAML inventory (`all:` / `children:` mappings)
+    Yaml,
+}
+
+impl AnsibleInventoryFormat {
+    /// Picks the format from an output path: `.yml`/`.yaml` is YAML,
+    /// anything else is INI
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        if path
+            .extension()
+            .is_some_and(|ext| ext == "yml" || ext == "yaml")
+        {
+            Self::Yaml
+        } else {
+            Self::Ini
+        }
+    }
+}
+
 /// Ansible inventory exporter.
 ///
 /// Exports SSH connections to Ansible inventory format in both INI and YAML formats.
@@ -27,6 +54,10 @@ impl AnsibleExporter {
 
     /// Exports connections to INI format inventory.
     ///
+    /// Hosts are listed under the section of the group they belong to and
+    /// nested groups are linked to their parent through `[parent:children]`
+    /// sections. Groups without SSH hosts anywhere below them are omitted.
+    ///
     /// # Arguments
     ///
     /// * `connections` - The connections to export
@@ -40,50 +71,58 @@ impl AnsibleExporter {
         let mut output = String::new();
         output.push_str("# Ansible inventory exported from RustConn\n\n");
 
-        // Build group lookup map
-        let group_map: HashMap<Uuid, &ConnectionGroup> = groups.iter().map(|g| (g.id, g)).collect();
+        let tree = InventoryTree::new(connections, groups);
 
-        // Group connections by their group_id
-        let mut grouped: HashMap<Option<Uuid>, Vec<&Connection>> = HashMap::new();
-        for conn in connections {
-            if conn.protocol == ProtocolType::Ssh {
-                grouped.entry(conn.group_id).or_default().push(conn);
+        // Output ungrouped connections first
+        if !tree.ungrouped.is_empty() {
+            output.push_str("[ungrouped]\n");
+            for conn in &tree.ungrouped {
+                output.push_str(&Self::format_host_entry(conn));
+                output.push('\n');
             }
+            output.push('\n');
         }
 
-        // Output ungrouped connections first (under [all] or no section)
-        if let Some(ungrouped) = grouped.remove(&None)
-            && !ungrouped.is_empty()
-        {
-            output.push_str("[ungrouped]\n");
-            for conn in ungrouped {
+        for group in tree.child_groups(None) {
+            Self::write_ini_group(&mut output, &tree, group);
+        }
+
+        output
+    }
+
+    /// Writes the sections for a group and, recursively, its subgroups
+    fn write_ini_group(output: &mut String, tree: &InventoryTree<'_>, group: &ConnectionGroup) {
+        let group_name = sanitize_group_name(&group.name);
+
+        let hosts = tree.hosts(group.id);
+        if !hosts.is_empty() {
+            let _ = writeln!(output, "[{group_name}]");
+            for conn in hosts {
                 output.push_str(&Self::format_host_entry(conn));
                 output.push('\n');
             }
             output.push('\n');
         }
 
-        // Output grouped connections
-        for (group_id, conns) in grouped {
-            if let Some(group_id) = group_id {
-                let group_name = group_map
-                    .get(&group_id)
-                    .map_or_else(|| "unknown".to_string(), |g| sanitize_group_name(&g.name));
-
-                let _ = writeln!(output, "[{group_name}]");
-                for conn in conns {
-                    output.push_str(&Self::format_host_entry(conn));
-                    output.push('\n');
-                }
-                output.push('\n');
+        let children = tree.child_groups(Some(group.id));
+        if !children.is_empty() {
+            let _ = writeln!(output, "[{group_name}:children]");
+            for child in &children {
+                let _ = writeln!(output, "{}", sanitize_group_name(&child.name));
             }
+            output.push('\n');
         }
 
-        output
+        for child in children {
+            Self::write_ini_group(output, tree, child);
+        }
     }
 
     /// Exports connections to YAML format inventory.
     ///
+    /// Groups are nested under `children` following the connection group
+    /// hierarchy. Groups without SSH hosts anywhere below them are omitted.
+    ///
     /// # Arguments
     ///
     /// * `connections` - The connections to export
@@ -98,51 +137,55 @@ impl AnsibleExporter {
         output.push_str("---\n# Ansible inventory exported from RustConn\n\n");
         output.push_str("all:\n");
 
-        // Build group lookup map
-        let group_map: HashMap<Uuid, &ConnectionGroup> = groups.iter().map(|g| (g.id, g)).collect();
-
-        // Group connections by their group_id
-        let mut grouped: HashMap<Option<Uuid>, Vec<&Connection>> = HashMap::new();
-        for conn in connections {
-            if conn.protocol == ProtocolType::Ssh {
-                grouped.entry(conn.group_id).or_default().push(conn);
-            }
-        }
-
-        // Check if we have any grouped connections
-        let has_groups = grouped.keys().any(Option::is_some);
+        let tree = InventoryTree::new(connections, groups);
 
         // Output ungrouped hosts directly under 'all'
-        if let Some(ungrouped) = grouped.get(&None)
-            && !ungrouped.is_empty()
-        {
+        if !tree.ungrouped.is_empty() {
             output.push_str("  hosts:\n");
-            for conn in ungrouped {
+            for conn in &tree.ungrouped {
                 output.push_str(&Self::format_yaml_host(conn, 4));
             }
         }
 
         // Output grouped connections under 'children'
-        if has_groups {
+        let roots = tree.child_groups(None);
+        if !roots.is_empty() {
             output.push_str("  children:\n");
-            for (group_id, conns) in &grouped {
-                if let Some(group_id) = group_id {
-                    let group_name = group_map
-                        .get(group_id)
-                        .map_or_else(|| "unknown".to_string(), |g| sanitize_group_name(&g.name));
-
-                    let _ = writeln!(output, "    {group_name}:");
-                    output.push_str("      hosts:\n");
-                    for conn in conns {
-                        output.push_str(&Self::format_yaml_host(conn, 8));
-                    }
-                }
+            for group in roots {
+                Self::write_yaml_group(&mut output, &tree, group, 4);
             }
         }
 
         output
     }
 
+    /// Writes a group mapping and, recursively, its subgroups
+    fn write_yaml_group(
+        output: &mut String,
+        tree: &InventoryTree<'_>,
+        group: &ConnectionGroup,
+        indent: usize,
+    ) {
+        let indent_str = " ".repeat(indent);
+        let _ = writeln!(output, "{indent_str}{}:", sanitize_group_name(&group.name));
+
+        let hosts = tree.hosts(group.id);
+        if !hosts.is_empty() {
+            let _ = writeln!(output, "{indent_str}  hosts:");
+            for conn in hosts {
+                output.push_str(&Self::format_yaml_host(conn, indent + 4));
+            }
+        }
+
+        let children = tree.child_groups(Some(group.id));
+        if !children.is_empty() {
+            let _ = writeln!(output, "{indent_str}  children:");
+            for child in children {
+                Self::write_yaml_group(output, tree, child, indent + 4);
+            }
+        }
+    }
+
     /// Formats a single host entry for INI format.
     ///
     /// # Arguments
@@ -236,6 +279,78 @@ impl Default for AnsibleExporter {
     }
 }
 
+/// SSH hosts arranged along the connection group hierarchy
+struct InventoryTree<'a> {
+    /// Hosts without a (known) group
+    ungrouped: Vec<&'a Connection>,
+    /// Hosts keyed by the group they belong to
+    hosts: HashMap<Uuid, Vec<&'a Connection>>,
+    /// Groups keyed by parent, sorted by name; roots are under `None`
+    children: HashMap<Option<Uuid>, Vec<&'a ConnectionGroup>>,
+}
+
+impl<'a> InventoryTree<'a> {
+    fn new(connections: &'a [Connection], groups: &'a [ConnectionGroup]) -> Self {
+        let known: HashSet<Uuid> = groups.iter().map(|g| g.id).collect();
+
+        let mut ungrouped = Vec::new();
+        let mut hosts: HashMap<Uuid, Vec<&Connection>> = HashMap::new();
+        for conn in connections {
+            if conn.protocol != ProtocolType::Ssh {
+                continue;
+            }
+            match conn.group_id {
+                Some(id) if known.contains(&id) => hosts.entry(id).or_default().push(conn),
+                _ => ungrouped.push(conn),
+            }
+        }
+
+        // Groups whose parent is not part of the export become roots
+        let mut children: HashMap<Option<Uuid>, Vec<&ConnectionGroup>> = HashMap::new();
+        for group in groups {
+            let parent = group.parent_id.filter(|id| known.contains(id));
+            children.entry(parent).or_default().push(group);
+        }
+        for siblings in children.values_mut() {
+            siblings.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+
+        Self {
+            ungrouped,
+            hosts,
+            children,
+        }
+    }
+
+    /// Hosts that belong directly to `group_id`
+    fn hosts(&self, group_id: Uuid) -> &[&'a Connection] {
+        self.hosts
+            .get(&group_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Subgroups of `parent` that have at least one host below them
+    fn child_groups(&self, parent: Option<Uuid>) -> Vec<&'a ConnectionGroup> {
+        self.children.get(&parent).map_or_else(Vec::new, |groups| {
+            groups
+                .iter()
+                .copied()
+                .filter(|g| self.has_hosts(g.id))
+                .collect()
+        })
+    }
+
+    /// Returns true if `group_id` or any group below it holds a host
+    fn has_hosts(&self, group_id: Uuid) -> bool {
+        !self.hosts(group_id).is_empty()
+            || self
+                .children
+                .get(&Some(group_id))
+                .is_some_and(|groups| groups.iter().any(|g| self.has_hosts(g.id)))
+    }
+}
+
 impl ExportTarget for AnsibleExporter {
     fn format_id(&self) -> ExportFormat {
         ExportFormat::Ansible
@@ -279,15 +394,12 @@ impl ExportTarget for AnsibleExporter {
 
         let connections_vec: Vec<_> = ssh_connections.iter().copied().cloned().collect();
 
-        // Generate content based on file extension
-        let content = if options
-            .output_path
-            .extension()
-            .is_some_and(|ext| ext == "yml" || ext == "yaml")
+        let content = match options
+            .ansible_format
+            .unwrap_or_else(|| AnsibleInventoryFormat::from_path(&options.output_path))
         {
-            Self::export_yaml(&connections_vec, &filtered_groups)
-        } else {
-            Self::export_ini(&connections_vec, &filtered_groups)
+            AnsibleInventoryFormat::Yaml => Self::export_yaml(&connections_vec, &filtered_groups),
+            AnsibleInventoryFormat::Ini => Self::export_ini(&connections_vec, &filtered_groups),
         };
 
         // Write to file
@@ -449,6 +561,82 @@ mod tests {
         assert!(output.contains("webservers:"));
     }
 
+    /// `infra` > (`web`, `db`), plus an empty `legacy` group
+    fn nested_groups() -> Vec<ConnectionGroup> {
+        let infra = ConnectionGroup::new("infra".to_string());
+        let web = ConnectionGroup::with_parent("web".to_string(), infra.id);
+        let db = ConnectionGroup::with_parent("db".to_string(), infra.id);
+        let legacy = ConnectionGroup::new("legacy".to_string());
+        vec![infra, web, db, legacy]
+    }
+
+    #[test]
+    fn test_export_ini_nested_groups() {
+        let groups = nested_groups();
+        let connections = vec![
+            create_ssh_connection("web1", "10.0.0.1", 22).with_group(groups[1].id),
+            create_ssh_connection("db1", "10.0.0.2", 22).with_group(groups[2].id),
+        ];
+
+        let output = AnsibleExporter::export_ini(&connections, &groups);
+        assert!(output.contains("[infra:children]\ndb\nweb\n"));
+        assert!(output.contains("[web]\nweb1 ansible_host=10.0.0.1\n"));
+        assert!(output.contains("[db]\ndb1 ansible_host=10.0.0.2\n"));
+        // infra has no direct hosts and legacy has none at all
+        assert!(!output.contains("[infra]\n"));
+        assert!(!output.contains("legacy"));
+    }
+
+    #[test]
+    fn test_export_yaml_nested_groups() {
+        let groups = nested_groups();
+        let connections = vec![
+            create_ssh_connection("web1", "10.0.0.1", 22).with_group(groups[1].id),
+            create_ssh_connection("db1", "10.0.0.2", 22).with_group(groups[2].id),
+        ];
+
+        let output = AnsibleExporter::export_yaml(&connections, &groups);
+        let expected = "all:
+  children:
+    infra:
+      children:
+        db:
+          hosts:
+            db1:
+              ansible_host: 10.0.0.2
+        web:
+          hosts:
+            web1:
+              ansible_host: 10.0.0.1
+";
+        assert!(output.ends_with(expected), "unexpected YAML:\n{output}");
+    }
+
+    #[test]
+    fn test_export_unknown_group_is_ungrouped() {
+        let connections =
+            vec![create_ssh_connection("web1", "10.0.0.1", 22).with_group(Uuid::new_v4())];
+        let output = AnsibleExporter::export_ini(&connections, &[]);
+        assert!(output.contains("[ungrouped]\nweb1"));
+        assert!(!output.contains("unknown"));
+    }
+
+    #[test]
+    fn test_inventory_format_from_path() {
+        assert_eq!(
+            AnsibleInventoryFormat::from_path(Path::new("hosts.yml")),
+            AnsibleInventoryFormat::Yaml
+        );
+        assert_eq!(
+            AnsibleInventoryFormat::from_path(Path::new("hosts.yaml")),
+            AnsibleInventoryFormat::Yaml
+        );
+        assert_eq!(
+            AnsibleInventoryFormat::from_path(Path::new("hosts")),
+            AnsibleInventoryFormat::Ini
+        );
+    }
+
     #[test]
     fn test_sanitize_group_name() {
         assert_eq!(sanitize_group_name("web servers"), "web_servers");
@@ -123,69 +123,22 @@ impl BatchExporter {
     where
         F: Fn(&Connection) -> Result<String, ExportError>,
     {
-        let total = connections.len();
-        let mut result = ExportResult::new();
-        let mut batches_processed = 0;
-        let mut exported_data = Vec::with_capacity(total);
-
-        // Check for cancellation from progress reporter or internal flag
-        let check_cancelled =
-            || self.is_cancelled() || progress.is_some_and(ProgressReporter::is_cancelled);
-
-        if check_cancelled() {
-            return BatchExportResult::new(result, true, 0);
-        }
-
-        // Process in batches
-        for (batch_idx, chunk) in connections.chunks(self.batch_size).enumerate() {
-            // Check cancellation between batches
-            if check_cancelled() {
-                // Return partial results
-                result.exported_count = exported_data.len();
-                return BatchExportResult::new(result, true, batches_processed);
-            }
-
-            let batch_start = batch_idx * self.batch_size;
-
-            // Process each connection in the batch
-            for (idx, conn) in chunk.iter().enumerate() {
-                let current = batch_start + idx;
-
-                // Report progress
-                if let Some(reporter) = progress {
-                    reporter.report(
-                        current,
-                        total,
-                        &format!("Exporting connection {} of {}", current + 1, total),
-                    );
-                }
-
-                // Process the connection
-                match processor(conn) {
-                    Ok(data) => {
-                        exported_data.push(data);
-                        result.increment_exported();
-                    }
-                    Err(e) => {
-                        result.add_warning(format!("Failed to export '{}': {}", conn.name, e));
-                        result.increment_skipped();
-                    }
-                }
-            }
-
-            batches_processed += 1;
-        }
-
-        // Report completion
-        if let Some(reporter) = progress {
-            reporter.report(total, total, "Export complete");
-        }
-
-        BatchExportResult::new(result, false, batches_processed)
+        self.process_connections_with_data(connections, progress, processor)
+            .0
     }
 
     /// Processes connections for export in batches, collecting the exported data.
     ///
+    /// The reporter receives `(current, total)` before each connection, with
+    /// `current` counting the connections already handled, so values never
+    /// decrease. A final report with the number handled follows, whether the
+    /// export completed or was cancelled.
+    ///
+    /// Cancellation (through [`Self::cancel`], a [`BatchExportCancelHandle`]
+    /// or the reporter) is checked before every connection. A cancelled
+    /// export returns the data written so far; `result.exported_count`
+    /// says how many connections that is.
+    ///
     /// # Arguments
     ///
     /// * `connections` - The connections to export
@@ -214,27 +167,29 @@ impl BatchExporter {
         let check_cancelled =
             || self.is_cancelled() || progress.is_some_and(ProgressReporter::is_cancelled);
 
-        if check_cancelled() {
-            return (BatchExportResult::new(result, true, 0), exported_data);
-        }
-
         // Process in batches
         for (batch_idx, chunk) in connections.chunks(self.batch_size).enumerate() {
-            // Check cancellation between batches
-            if check_cancelled() {
-                result.exported_count = exported_data.len();
-                return (
-                    BatchExportResult::new(result, true, batches_processed),
-                    exported_data,
-                );
-            }
-
             let batch_start = batch_idx * self.batch_size;
 
             // Process each connection in the batch
             for (idx, conn) in chunk.iter().enumerate() {
                 let current = batch_start + idx;
 
+                // Stop promptly, returning partial results
+                if check_cancelled() {
+                    if let Some(reporter) = progress {
+                        reporter.report(
+                            current,
+                            total,
+                            &format!("Export cancelled after {current} of {total}"),
+                        );
+                    }
+                    return (
+                        BatchExportResult::new(result, true, batches_processed),
+                        exported_data,
+                    );
+                }
+
                 // Report progress
                 if let Some(reporter) = progress {
                     reporter.report(
@@ -260,6 +215,11 @@ impl BatchExporter {
             batches_processed += 1;
         }
 
+        // An empty export has nothing to cancel; still honour a prior request
+        if total == 0 && check_cancelled() {
+            return (BatchExportResult::new(result, true, 0), exported_data);
+        }
+
         // Report completion
         if let Some(reporter) = progress {
             reporter.report(total, total, "Export complete");
@@ -440,6 +400,64 @@ mod tests {
         assert_eq!(result.batches_processed, 0);
     }
 
+    #[test]
+    fn test_progress_is_monotonic() {
+        use crate::progress::CallbackProgressReporter;
+        use std::sync::Mutex;
+
+        let exporter = BatchExporter::new(3);
+        let connections: Vec<_> = (0..10)
+            .map(|i| create_test_connection(&format!("conn{i}")))
+            .collect();
+        let reports = Mutex::new(Vec::new());
+        let reporter = CallbackProgressReporter::new(|current, total, _: &str| {
+            reports.lock().unwrap().push((current, total));
+        });
+
+        let result =
+            exporter.process_connections(&connections, Some(&reporter), |c| Ok(c.name.clone()));
+
+        assert!(result.is_complete());
+        let reports = reports.into_inner().unwrap();
+        assert_eq!(reports.len(), 11);
+        assert!(reports.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert!(reports.iter().all(|&(_, total)| total == 10));
+        assert_eq!(reports.last(), Some(&(10, 10)));
+    }
+
+    #[test]
+    fn test_cancellation_mid_batch_yields_partial_result() {
+        use crate::progress::CallbackProgressReporter;
+        use std::sync::Mutex;
+
+        let exporter = BatchExporter::new(50);
+        let handle = exporter.cancel_handle();
+        let connections: Vec<_> = (0..100)
+            .map(|i| create_test_connection(&format!("conn{i}")))
+            .collect();
+        let last_report = Mutex::new(None);
+        let reporter = CallbackProgressReporter::new(|current, _, message: &str| {
+            *last_report.lock().unwrap() = Some((current, message.to_string()));
+        });
+
+        let (result, data) =
+            exporter.process_connections_with_data(&connections, Some(&reporter), |c| {
+                if c.name == "conn6" {
+                    handle.cancel();
+                }
+                Ok(c.name.clone())
+            });
+
+        // Stops inside the first batch, right after the item that cancelled
+        assert!(result.was_cancelled);
+        assert_eq!(result.batches_processed, 0);
+        assert_eq!(result.result.exported_count, 7);
+        assert_eq!(data.len(), 7);
+        let (current, message) = last_report.into_inner().unwrap().unwrap();
+        assert_eq!(current, 7);
+        assert!(message.contains("cancelled"));
+    }
+
     #[test]
     fn test_process_connections_with_data() {
         let exporter = BatchExporter::new(10);
@@ -0,0 +1,388 @@
+//! Apache Guacamole `user-mapping.xml` exporter.
+//!
+//! Exports SSH, Telnet, RDP and VNC connections as `<connection>` entries of
+//! a Guacamole `user-mapping.xml` file, for the default file-based
+//! authentication provider. Groups are not represented; Guacamole identifies
+//! connections by name, so repeated names get a ` (2)`, ` (3)`, … suffix.
+//!
+//! Passwords are written as [`SECRET_PLACEHOLDER`] unless
+//! [`ExportOptions::include_secrets`] is set and the caller supplied the
+//! secret with [`GuacamoleExporter::with_secrets`]. The `<authorize>` login
+//! itself is always a placeholder.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use secrecy::{ExposeSecret, SecretString};
+use tracing::{debug, info_span};
+use uuid::Uuid;
+
+use super::{
+    ExportError, ExportFormat, ExportOperationResult, ExportOptions, ExportResult, ExportTarget,
+};
+use crate::models::{
+    Connection, ConnectionGroup, PasswordSource, ProtocolConfig, ProtocolType, RdpSecurityLayer,
+};
+use crate::tracing::span_names;
+
+/// Value written in place of a password that is not exported
+pub const SECRET_PLACEHOLDER: &str = "CHANGE_ME";
+
+/// Guacamole `user-mapping.xml` exporter.
+#[derive(Default)]
+pub struct GuacamoleExporter {
+    /// Resolved connection passwords, keyed by connection ID
+    secrets: HashMap<Uuid, SecretString>,
+}
+
+impl GuacamoleExporter {
+    /// Creates a new Guacamole exporter without secrets
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Supplies connection passwords, keyed by connection ID
+    ///
+    /// They are only written when [`ExportOptions::include_secrets`] is set.
+    #[must_use]
+    pub fn with_secrets(mut self, secrets: HashMap<Uuid, SecretString>) -> Self {
+        self.secrets = secrets;
+        self
+    }
+
+    /// Exports connections to a `user-mapping.xml` document.
+    ///
+    /// Unsupported protocols are left out. Passwords are placeholders unless
+    /// `include_secrets` is `true` and a secret was supplied for the
+    /// connection.
+    #[must_use]
+    pub fn export_to_xml(&self, connections: &[Connection], include_secrets: bool) -> String {
+        let _span = info_span!(
+            span_names::EXPORT_EXECUTE,
+            format = "guacamole",
+            connection_count = connections.len()
+        )
+        .entered();
+
+        let mut output = String::new();
+        output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        output.push_str("<user-mapping>\n");
+        output.push_str("  <!-- Set the Guacamole login for these connections -->\n");
+        let _ = writeln!(
+            output,
+            "  <authorize username=\"{SECRET_PLACEHOLDER}\" password=\"{SECRET_PLACEHOLDER}\">"
+        );
+
+        let mut used_names = HashSet::new();
+        let mut exported_count = 0;
+        for conn in connections.iter().filter(|c| is_supported(c.protocol)) {
+            let name = unique_name(&conn.name, &mut used_names);
+            self.write_connection(&mut output, conn, &name, include_secrets);
+            exported_count += 1;
+        }
+
+        output.push_str("  </authorize>\n");
+        output.push_str("</user-mapping>\n");
+
+        debug!(exported = exported_count, "Guacamole export completed");
+        output
+    }
+
+    fn write_connection(
+        &self,
+        output: &mut String,
+        conn: &Connection,
+        name: &str,
+        include_secrets: bool,
+    ) {
+        let _ = writeln!(output, "    <connection name=\"{}\">", escape_xml(name));
+        let _ = writeln!(
+            output,
+            "      <protocol>{}</protocol>",
+            conn.protocol.as_str()
+        );
+
+        let mut params: Vec<(&str, String)> = vec![
+            ("hostname", conn.host.clone()),
+            ("port", conn.port.to_string()),
+        ];
+        if let Some(username) = conn.username.as_deref().filter(|u| !u.is_empty()) {
+            params.push(("username", username.to_string()));
+        }
+        // VNC authenticates with a password only, so always leave a slot for it
+        if conn.password_source != PasswordSource::None || conn.protocol == ProtocolType::Vnc {
+            params.push(("password", self.password(conn.id, include_secrets)));
+        }
+
+        match &conn.protocol_config {
+            ProtocolConfig::Rdp(rdp) => {
+                if let Some(domain) = conn.domain.as_deref().filter(|d| !d.is_empty()) {
+                    params.push(("domain", domain.to_string()));
+                }
+                let security = match rdp.security_layer {
+                    RdpSecurityLayer::Negotiate => "any",
+                    RdpSecurityLayer::Rdp => "rdp",
+                    RdpSecurityLayer::Tls => "tls",
+                    RdpSecurityLayer::Nla => "nla",
+                };
+                params.push(("security", security.to_string()));
+                if rdp.ignore_certificate {
+                    params.push(("ignore-cert", "true".to_string()));
+                }
+                if let Some(resolution) = &rdp.resolution {
+                    params.push(("width", resolution.width.to_string()));
+                    params.push(("height", resolution.height.to_string()));
+                }
+                if let Some(depth) = rdp.color_depth {
+                    params.push(("color-depth", depth.to_string()));
+                }
+                if !rdp.audio_redirect {
+                    params.push(("disable-audio", "true".to_string()));
+                }
+            }
+            ProtocolConfig::Vnc(vnc) if vnc.view_only => {
+                params.push(("read-only", "true".to_string()));
+            }
+            _ => {}
+        }
+
+        for (param, value) in params {
+            let _ = writeln!(
+                output,
+                "      <param name=\"{param}\">{}</param>",
+                escape_xml(&value)
+            );
+        }
+        output.push_str("    </connection>\n");
+    }
+
+    /// Returns the password to write for a connection
+    fn password(&self, id: Uuid, include_secrets: bool) -> String {
+        if include_secrets && let Some(secret) = self.secrets.get(&id) {
+            return secret.expose_secret().to_string();
+        }
+        SECRET_PLACEHOLDER.to_string()
+    }
+}
+
+impl ExportTarget for GuacamoleExporter {
+    fn format_id(&self) -> ExportFormat {
+        ExportFormat::Guacamole
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Apache Guacamole"
+    }
+
+    fn export(
+        &self,
+        connections: &[Connection],
+        _groups: &[ConnectionGroup],
+        options: &ExportOptions,
+    ) -> ExportOperationResult<ExportResult> {
+        let mut result = ExportResult::new();
+
+        let supported: Vec<Connection> = connections
+            .iter()
+            .filter(|c| {
+                if is_supported(c.protocol) {
+                    true
+                } else {
+                    result.increment_skipped();
+                    result.add_warning(format!(
+                        "Skipped unsupported connection '{}' (protocol: {})",
+                        c.name, c.protocol
+                    ));
+                    false
+                }
+            })
+            .cloned()
+            .collect();
+
+        let content = self.export_to_xml(&supported, options.include_secrets);
+        super::write_export_file(&options.output_path, &content)?;
+
+        result.exported_count = supported.len();
+        result.add_output_file(options.output_path.clone());
+
+        Ok(result)
+    }
+
+    fn export_connection(&self, connection: &Connection) -> ExportOperationResult<String> {
+        if !is_supported(connection.protocol) {
+            return Err(ExportError::UnsupportedProtocol(format!(
+                "{}",
+                connection.protocol
+            )));
+        }
+
+        let mut output = String::new();
+        self.write_connection(&mut output, connection, &connection.name, false);
+        Ok(output)
+    }
+
+    fn supports_protocol(&self, protocol: &ProtocolType) -> bool {
+        is_supported(*protocol)
+    }
+}
+
+/// Returns true for protocols Guacamole can connect with
+const fn is_supported(protocol: ProtocolType) -> bool {
+    matches!(
+        protocol,
+        ProtocolType::Ssh | ProtocolType::Telnet | ProtocolType::Rdp | ProtocolType::Vnc
+    )
+}
+
+/// Returns `name`, suffixed with ` (n)` if an earlier connection took it
+fn unique_name(name: &str, used: &mut HashSet<String>) -> String {
+    let mut candidate = name.to_string();
+    let mut n = 2;
+    while !used.insert(candidate.clone()) {
+        candidate = format!("{name} ({n})");
+        n += 1;
+    }
+    candidate
+}
+
+/// Escapes special XML characters in a string.
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use quick_xml::Reader;
+    use quick_xml::events::Event;
+
+    use super::*;
+
+    /// Parses the export into `(connection name, protocol, params)` tuples
+    fn parse(xml: &str) -> Vec<(String, String, HashMap<String, String>)> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+        let mut connections: Vec<(String, String, HashMap<String, String>)> = Vec::new();
+        let mut param = None;
+        let mut text = String::new();
+        loop {
+            match reader.read_event().unwrap() {
+                Event::Start(e) => {
+                    text.clear();
+                    let attribute = |name| {
+                        e.try_get_attribute(name)
+                            .unwrap()
+                            .map(|a| a.unescape_value().unwrap().into_owned())
+                    };
+                    match e.name().as_ref() {
+                        b"connection" => connections.push((
+                            attribute("name").unwrap(),
+                            String::new(),
+                            HashMap::new(),
+                        )),
+                        b"param" => param = attribute("name"),
+                        _ => {}
+                    }
+                }
+                Event::Text(e) => text.push_str(&String::from_utf8_lossy(&e)),
+                Event::GeneralRef(e) => {
+                    let entity = format!("&{};", String::from_utf8_lossy(&e));
+                    text.push_str(&quick_xml::escape::unescape(&entity).unwrap());
+                }
+                Event::End(e) => {
+                    let value = std::mem::take(&mut text);
+                    match e.name().as_ref() {
+                        b"protocol" => connections.last_mut().unwrap().1 = value,
+                        b"param" => {
+                            let name = param.take().unwrap();
+                            connections.last_mut().unwrap().2.insert(name, value);
+                        }
+                        _ => {}
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        connections
+    }
+
+    fn sample_connections() -> Vec<Connection> {
+        let mut ssh =
+            Connection::new_ssh("web & db".to_string(), "web.example.com".to_string(), 22)
+                .with_username("deploy");
+        ssh.password_source = PasswordSource::Vault;
+
+        let mut rdp = Connection::new_rdp("Desktop".to_string(), "10.0.0.5".to_string(), 3389)
+            .with_username("alice");
+        rdp.domain = Some("CORP".to_string());
+        if let ProtocolConfig::Rdp(ref mut config) = rdp.protocol_config {
+            config.security_layer = RdpSecurityLayer::Nla;
+            config.ignore_certificate = true;
+        }
+        vec![ssh, rdp]
+    }
+
+    #[test]
+    fn test_export_ssh_and_rdp() {
+        let connections = sample_connections();
+        let xml = GuacamoleExporter::new().export_to_xml(&connections, false);
+        let parsed = parse(&xml);
+        assert_eq!(parsed.len(), 2);
+
+        let (name, protocol, params) = &parsed[0];
+        assert_eq!((name.as_str(), protocol.as_str()), ("web & db", "ssh"));
+        assert_eq!(params["hostname"], "web.example.com");
+        assert_eq!(params["port"], "22");
+        assert_eq!(params["username"], "deploy");
+        assert_eq!(params["password"], SECRET_PLACEHOLDER);
+
+        let (name, protocol, params) = &parsed[1];
+        assert_eq!((name.as_str(), protocol.as_str()), ("Desktop", "rdp"));
+        assert_eq!(params["hostname"], "10.0.0.5");
+        assert_eq!(params["port"], "3389");
+        assert_eq!(params["username"], "alice");
+        assert_eq!(params["domain"], "CORP");
+        assert_eq!(params["security"], "nla");
+        assert_eq!(params["ignore-cert"], "true");
+        // No password source: no password parameter at all
+        assert!(!params.contains_key("password"));
+    }
+
+    #[test]
+    fn test_secrets_require_opt_in() {
+        let connections = sample_connections();
+        let exporter = GuacamoleExporter::new().with_secrets(HashMap::from([(
+            connections[0].id,
+            SecretString::from("s3cr3t<pw>".to_string()),
+        )]));
+
+        let xml = exporter.export_to_xml(&connections, false);
+        assert!(!xml.contains("s3cr3t"));
+        assert_eq!(parse(&xml)[0].2["password"], SECRET_PLACEHOLDER);
+
+        let xml = exporter.export_to_xml(&connections, true);
+        assert_eq!(parse(&xml)[0].2["password"], "s3cr3t<pw>");
+    }
+
+    #[test]
+    fn test_vnc_password_placeholder_and_unique_names() {
+        let mut vnc = Connection::new_vnc("lab".to_string(), "lab.lan".to_string(), 5901);
+        if let ProtocolConfig::Vnc(ref mut config) = vnc.protocol_config {
+            config.view_only = true;
+        }
+        let telnet = Connection::new_telnet("lab".to_string(), "switch.lan".to_string(), 23);
+        let serial = Connection::new_serial("console".to_string(), "/dev/ttyUSB0".to_string());
+
+        let parsed = parse(&GuacamoleExporter::new().export_to_xml(&[vnc, telnet, serial], false));
+        let names: Vec<&str> = parsed.iter().map(|(name, _, _)| name.as_str()).collect();
+        assert_eq!(names, ["lab", "lab (2)"]);
+        assert_eq!(parsed[0].2["password"], SECRET_PLACEHOLDER);
+        assert_eq!(parsed[0].2["read-only"], "true");
+        assert_eq!(parsed[1].1, "telnet");
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! This module provides functionality to export connections to various formats
 //! including Ansible inventory, SSH config, Remmina, Asbru-CM, MobaXterm,
-//! and `RustConn` native format.
+//! Windows Terminal fragments, Apache Guacamole `user-mapping.xml`, and
+//! `RustConn` native format.
 //!
 //! For large exports (more than 10 connections), use `BatchExporter` for
 //! efficient batch processing with progress reporting and cancellation support.
@@ -11,22 +12,25 @@ pub mod ansible;
 pub mod asbru;
 pub mod batch;
 pub mod csv_export;
+pub mod guacamole;
 pub mod mobaxterm;
 pub mod native;
 pub mod remmina;
 pub mod royalts;
 pub mod securecrt;
 pub mod ssh_config;
+pub mod windows_terminal;
 
 use std::path::{Path, PathBuf};
 
-pub use ansible::AnsibleExporter;
+pub use ansible::{AnsibleExporter, AnsibleInventoryFormat};
 pub use asbru::AsbruExporter;
 pub use batch::{
     BATCH_EXPORT_THRESHOLD, BatchExportCancelHandle, BatchExportResult, BatchExporter,
     DEFAULT_EXPORT_BATCH_SIZE,
 };
 pub use csv_export::{CsvExportField, CsvExportOptions, CsvExporter};
+pub use guacamole::GuacamoleExporter;
 pub use mobaxterm::MobaXtermExporter;
 pub use native::{NATIVE_FILE_EXTENSION, NATIVE_FORMAT_VERSION, NativeExport, NativeImportError};
 pub use remmina::RemminaExporter;
@@ -35,6 +39,7 @@ pub use securecrt::SecureCrtExporter;
 use serde::{Deserialize, Serialize};
 pub use ssh_config::SshConfigExporter;
 use thiserror::Error;
+pub use windows_terminal::WindowsTerminalExporter;
 
 use crate::models::{Connection, ConnectionGroup};
 use crate::progress::ProgressReporter;
@@ -61,6 +66,10 @@ pub enum ExportFormat {
     Csv,
     /// SecureCRT session INI format (directory of .ini files)
     SecureCrt,
+    /// Windows Terminal profile fragment (.json)
+    WindowsTerminal,
+    /// Apache Guacamole `user-mapping.xml`
+    Guacamole,
 }
 
 impl ExportFormat {
@@ -77,6 +86,8 @@ impl ExportFormat {
             Self::MobaXterm,
             Self::Csv,
             Self::SecureCrt,
+            Self::WindowsTerminal,
+            Self::Guacamole,
         ]
     }
 
@@ -93,6 +104,8 @@ impl ExportFormat {
             Self::MobaXterm => "MobaXterm",
             Self::Csv => "CSV",
             Self::SecureCrt => "SecureCRT",
+            Self::WindowsTerminal => "Windows Terminal",
+            Self::Guacamole => "Apache Guacamole",
         }
     }
 
@@ -109,6 +122,8 @@ impl ExportFormat {
             Self::MobaXterm => "mxtsessions",
             Self::Csv => "csv",
             Self::SecureCrt => "ini",
+            Self::WindowsTerminal => "json",
+            Self::Guacamole => "xml",
         }
     }
 
@@ -138,6 +153,12 @@ pub struct ExportOptions {
     pub csv_delimiter: Option<char>,
     /// Custom CSV fields to include (only for CSV format)
     pub csv_fields: Option<Vec<String>>,
+    /// Inventory syntax (only for Ansible format); `None` picks it from the
+    /// output file extension
+    pub ansible_format: Option<AnsibleInventoryFormat>,
+    /// Whether to write passwords in plain text (only for Guacamole format);
+    /// otherwise passwords are placeholders
+    pub include_secrets: bool,
 }
 
 impl ExportOptions {
@@ -150,6 +171,8 @@ impl ExportOptions {
             output_path,
             csv_delimiter: None,
             csv_fields: None,
+            ansible_format: None,
+            include_secrets: false,
         }
     }
 
@@ -159,6 +182,20 @@ impl ExportOptions {
         self.include_groups = include;
         self
     }
+
+    /// Sets whether to write passwords in plain text
+    #[must_use]
+    pub const fn with_secrets(mut self, include: bool) -> Self {
+        self.include_secrets = include;
+        self
+    }
+
+    /// Sets the Ansible inventory syntax
+    #[must_use]
+    pub const fn with_ansible_format(mut self, format: AnsibleInventoryFormat) -> Self {
+        self.ansible_format = Some(format);
+        self
+    }
 }
 
 /// Result of an export operation
@@ -384,7 +421,7 @@ mod tests {
     #[test]
     fn test_export_format_all() {
         let formats = ExportFormat::all();
-        assert_eq!(formats.len(), 9);
+        assert_eq!(formats.len(), 11);
         assert!(formats.contains(&ExportFormat::Ansible));
         assert!(formats.contains(&ExportFormat::SshConfig));
         assert!(formats.contains(&ExportFormat::Remmina));
@@ -393,6 +430,8 @@ mod tests {
         assert!(formats.contains(&ExportFormat::RoyalTs));
         assert!(formats.contains(&ExportFormat::MobaXterm));
         assert!(formats.contains(&ExportFormat::Csv));
+        assert!(formats.contains(&ExportFormat::WindowsTerminal));
+        assert!(formats.contains(&ExportFormat::Guacamole));
     }
 
     #[test]
@@ -406,6 +445,10 @@ mod tests {
         assert_eq!(ExportFormat::MobaXterm.display_name(), "MobaXterm");
         assert_eq!(ExportFormat::Csv.display_name(), "CSV");
         assert_eq!(ExportFormat::SecureCrt.display_name(), "SecureCRT");
+        assert_eq!(
+            ExportFormat::WindowsTerminal.display_name(),
+            "Windows Terminal"
+        );
     }
 
     #[test]
@@ -419,6 +462,8 @@ mod tests {
         assert_eq!(ExportFormat::MobaXterm.file_extension(), "mxtsessions");
         assert_eq!(ExportFormat::Csv.file_extension(), "csv");
         assert_eq!(ExportFormat::SecureCrt.file_extension(), "ini");
+        assert_eq!(ExportFormat::WindowsTerminal.file_extension(), "json");
+        assert_eq!(ExportFormat::Guacamole.file_extension(), "xml");
     }
 
     #[test]
@@ -432,6 +477,8 @@ mod tests {
         assert!(!ExportFormat::MobaXterm.exports_to_directory());
         assert!(!ExportFormat::Csv.exports_to_directory());
         assert!(ExportFormat::SecureCrt.exports_to_directory());
+        assert!(!ExportFormat::WindowsTerminal.exports_to_directory());
+        assert!(!ExportFormat::Guacamole.exports_to_directory());
     }
 
     #[test]
@@ -1,7 +1,10 @@
 //! SSH config file exporter.
 //!
 //! Exports `RustConn` connections to OpenSSH config format (~/.ssh/config).
+//! The output can be read back with
+//! [`SshConfigImporter`](crate::import::SshConfigImporter).
 
+use std::collections::HashSet;
 use std::fmt::Write;
 
 use tracing::{debug, info_span};
@@ -14,7 +17,8 @@ use crate::tracing::span_names;
 
 /// SSH config file exporter.
 ///
-/// Exports SSH connections to OpenSSH configuration file format.
+/// Exports SSH connections to OpenSSH configuration file format, one `Host`
+/// block per connection named after [`SshConfigExporter::host_alias`].
 /// Non-SSH connections are skipped with a warning.
 pub struct SshConfigExporter;
 
@@ -27,6 +31,9 @@ impl SshConfigExporter {
 
     /// Exports connections to SSH config format.
     ///
+    /// Non-SSH connections are left out and counted in a trailing comment.
+    /// Connections whose aliases collide get a `-2`, `-3`, … suffix.
+    ///
     /// # Arguments
     ///
     /// * `connections` - The connections to export
@@ -47,18 +54,63 @@ impl SshConfigExporter {
         output.push_str("# SSH config exported from RustConn\n\n");
 
         let mut exported_count = 0;
+        let mut skipped_count = 0;
+        let mut aliases = HashSet::new();
         for conn in connections {
-            if conn.protocol == ProtocolType::Ssh {
-                output.push_str(&Self::format_host_entry(conn));
-                output.push('\n');
-                exported_count += 1;
+            if conn.protocol != ProtocolType::Ssh {
+                skipped_count += 1;
+                continue;
+            }
+            let base = Self::host_alias(&conn.name);
+            let mut alias = base.clone();
+            let mut suffix = 2;
+            while !aliases.insert(alias.clone()) {
+                alias = format!("{base}-{suffix}");
+                suffix += 1;
             }
+            output.push_str(&Self::format_host_entry_as(conn, &alias));
+            output.push('\n');
+            exported_count += 1;
+        }
+        if skipped_count > 0 {
+            let _ = writeln!(output, "# {skipped_count} non-SSH connection(s) skipped");
         }
 
-        debug!(exported = exported_count, "SSH config export completed");
+        debug!(
+            exported = exported_count,
+            skipped = skipped_count,
+            "SSH config export completed"
+        );
         output
     }
 
+    /// Derives a `Host` alias from a connection name.
+    ///
+    /// Letters, digits, `.`, `_` and `-` are kept; every other run of
+    /// characters becomes a single `-`. Leading and trailing dashes are
+    /// dropped, and an empty result becomes `host`. The alias depends only on
+    /// the name, so repeated exports produce the same aliases.
+    #[must_use]
+    pub fn host_alias(name: &str) -> String {
+        let mut alias = String::with_capacity(name.len());
+        let mut replaced = false;
+        for c in name.chars() {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                alias.push(c);
+                replaced = false;
+            } else if !replaced {
+                alias.push('-');
+                replaced = true;
+            }
+        }
+        let alias = alias.trim_matches('-');
+        if alias.is_empty() {
+            "host".to_string()
+        } else {
+            alias.to_string()
+        }
+    }
+
     /// Formats a single Host entry for SSH config format.
     ///
     /// # Arguments
@@ -70,10 +122,13 @@ impl SshConfigExporter {
     /// A string containing the SSH config Host block.
     #[must_use]
     pub fn format_host_entry(connection: &Connection) -> String {
+        Self::format_host_entry_as(connection, &Self::host_alias(&connection.name))
+    }
+
+    /// Formats a Host block under the given (already sanitized) alias.
+    fn format_host_entry_as(connection: &Connection, host_alias: &str) -> String {
         let mut output = String::new();
 
-        // Host alias (use connection name, escaped if needed)
-        let host_alias = escape_value(&connection.name);
         let _ = writeln!(output, "Host {host_alias}");
 
         // HostName (always include)
@@ -102,16 +157,17 @@ impl SshConfigExporter {
 
             // ProxyJump (skip when ProxyCommand is set — it takes precedence)
             if ssh_config.proxy_command.is_none()
-                && let Some(ref proxy_jump) = ssh_config.proxy_jump
+                && let Some(proxy_jump) = ssh_config.proxy_jump_spec()
             {
-                let escaped_proxy = escape_value(proxy_jump);
+                let escaped_proxy = escape_value(&proxy_jump);
                 let _ = writeln!(output, "    ProxyJump {escaped_proxy}");
             }
 
-            // ProxyCommand
+            // ProxyCommand (OpenSSH passes the rest of the line to the shell
+            // as is, so it is written unquoted, on a single line)
             if let Some(ref proxy_cmd) = ssh_config.proxy_command {
-                let escaped_cmd = escape_value(proxy_cmd);
-                let _ = writeln!(output, "    ProxyCommand {escaped_cmd}");
+                let proxy_cmd = proxy_cmd.replace(['\n', '\r'], " ");
+                let _ = writeln!(output, "    ProxyCommand {}", proxy_cmd.trim());
             }
 
             // ControlMaster
@@ -124,6 +180,14 @@ impl SshConfigExporter {
                 let _ = writeln!(output, "    ForwardAgent yes");
             }
 
+            // ForwardX11 / ForwardX11Trusted
+            if ssh_config.x11_forwarding {
+                let _ = writeln!(output, "    ForwardX11 yes");
+                if ssh_config.x11_trusted {
+                    let _ = writeln!(output, "    ForwardX11Trusted yes");
+                }
+            }
+
             // Keep-alive settings (dedicated fields take priority over custom_options)
             if let Some(interval) = ssh_config.keep_alive_interval {
                 let _ = writeln!(output, "    ServerAliveInterval {interval}");
@@ -196,30 +260,25 @@ impl ExportTarget for SshConfigExporter {
     ) -> ExportOperationResult<ExportResult> {
         let mut result = ExportResult::new();
 
-        // Filter SSH connections and count skipped
-        let ssh_connections: Vec<&Connection> = connections
+        // Count skipped connections; `Self::export` leaves them out itself
+        for c in connections
             .iter()
-            .filter(|c| {
-                if c.protocol == ProtocolType::Ssh {
-                    true
-                } else {
-                    result.increment_skipped();
-                    result.add_warning(format!(
-                        "Skipped non-SSH connection '{}' (protocol: {})",
-                        c.name, c.protocol
-                    ));
-                    false
-                }
-            })
-            .collect();
+            .filter(|c| c.protocol != ProtocolType::Ssh)
+        {
+            result.increment_skipped();
+            result.add_warning(format!(
+                "Skipped non-SSH connection '{}' (protocol: {})",
+                c.name, c.protocol
+            ));
+        }
 
         // Generate content
-        let content = Self::export(&ssh_connections.iter().copied().cloned().collect::<Vec<_>>());
+        let content = Self::export(connections);
 
         // Write to file
         super::write_export_file(&options.output_path, &content)?;
 
-        result.exported_count = ssh_connections.len();
+        result.exported_count = connections.len() - result.skipped_count;
         result.add_output_file(options.output_path.clone());
 
         Ok(result)
@@ -382,6 +441,31 @@ mod tests {
         assert!(output.contains("Port 2222"));
     }
 
+    #[test]
+    fn test_host_alias_sanitized() {
+        assert_eq!(SshConfigExporter::host_alias("web-01.prod"), "web-01.prod");
+        assert_eq!(
+            SshConfigExporter::host_alias("My Server (EU)"),
+            "My-Server-EU"
+        );
+        assert_eq!(
+            SshConfigExporter::host_alias("db #1 / primary"),
+            "db-1-primary"
+        );
+        assert_eq!(SshConfigExporter::host_alias("  *?  "), "host");
+
+        let connections = vec![
+            create_ssh_connection("My Server", "10.0.0.1", 22),
+            create_ssh_connection("My  Server", "10.0.0.2", 22),
+            Connection::new_rdp("desktop".to_string(), "10.0.0.3".to_string(), 3389),
+        ];
+        let output = SshConfigExporter::export(&connections);
+        assert!(output.contains("Host My-Server\n"));
+        assert!(output.contains("Host My-Server-2\n"));
+        assert!(!output.contains("desktop"));
+        assert!(output.contains("# 1 non-SSH connection(s) skipped"));
+    }
+
     #[test]
     fn test_escape_value_simple() {
         assert_eq!(escape_value("simple"), "simple");
@@ -423,4 +507,17 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_format_host_entry_with_trusted_x11_forwarding() {
+        let mut conn = create_ssh_connection("desktop", "desktop.example.com", 22);
+        if let ProtocolConfig::Ssh(ref mut ssh_config) = conn.protocol_config {
+            ssh_config.x11_forwarding = true;
+            ssh_config.x11_trusted = true;
+        }
+        let entry = SshConfigExporter::format_host_entry(&conn);
+
+        assert!(entry.contains("ForwardX11 yes"));
+        assert!(entry.contains("ForwardX11Trusted yes"));
+    }
 }
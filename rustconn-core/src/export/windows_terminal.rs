@@ -0,0 +1,299 @@
+//! Windows Terminal fragment exporter.
+//!
+//! Exports terminal connections (SSH, Telnet, Serial) as a Windows Terminal
+//! JSON fragment with one profile per connection. Dropping the file into
+//! `%LOCALAPPDATA%\Microsoft\Windows Terminal\Fragments\RustConn\` adds the
+//! profiles to Windows Terminal.
+//!
+//! Profile GUIDs are derived from connection IDs, so exporting again updates
+//! the existing profiles instead of duplicating them.
+
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use super::{
+    ExportError, ExportFormat, ExportOperationResult, ExportOptions, ExportResult, ExportTarget,
+};
+use crate::models::{
+    Connection, ConnectionGroup, ProtocolConfig, ProtocolType, SerialConfig, SerialFlowControl,
+    SerialParity, SerialStopBits,
+};
+
+/// Namespace for the UUIDv5 profile GUIDs
+const PROFILE_NAMESPACE: Uuid = Uuid::from_u128(0x3ddd_dd3e_ebb7_4803_be6f_e03c_9d4b_c93e);
+
+/// Windows Terminal fragment exporter.
+///
+/// Exports SSH, Telnet and Serial connections as Windows Terminal profiles.
+/// Other protocols are skipped with a warning.
+pub struct WindowsTerminalExporter;
+
+impl WindowsTerminalExporter {
+    /// Creates a new Windows Terminal exporter
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Exports connections to a fragment JSON document.
+    ///
+    /// Connections with unsupported protocols are left out.
+    #[must_use]
+    pub fn export_fragment(connections: &[Connection]) -> String {
+        let profiles: Vec<Value> = connections.iter().filter_map(Self::profile).collect();
+        let mut output =
+            serde_json::to_string_pretty(&json!({ "profiles": profiles })).unwrap_or_default();
+        output.push('\n');
+        output
+    }
+
+    /// Returns the profile GUID for a connection, in the braced form
+    /// Windows Terminal uses
+    #[must_use]
+    pub fn profile_guid(connection: &Connection) -> String {
+        format!(
+            "{{{}}}",
+            Uuid::new_v5(&PROFILE_NAMESPACE, connection.id.as_bytes())
+        )
+    }
+
+    /// Builds the profile object for a connection
+    ///
+    /// Returns `None` for protocols Windows Terminal cannot launch.
+    #[must_use]
+    pub fn profile(connection: &Connection) -> Option<Value> {
+        let commandline = Self::command_line(connection)?;
+        Some(json!({
+            "guid": Self::profile_guid(connection),
+            "name": connection.name,
+            "commandline": commandline,
+            "icon": Self::icon(connection),
+        }))
+    }
+
+    /// Builds the command Windows Terminal runs for a connection
+    fn command_line(connection: &Connection) -> Option<String> {
+        match &connection.protocol_config {
+            ProtocolConfig::Ssh(_) => {
+                let target = connection.username.as_ref().map_or_else(
+                    || connection.host.clone(),
+                    |user| format!("{user}@{}", connection.host),
+                );
+                Some(format!("ssh {} -p {}", quote_arg(&target), connection.port))
+            }
+            ProtocolConfig::Telnet(_) => Some(format!(
+                "telnet {} {}",
+                quote_arg(&connection.host),
+                connection.port
+            )),
+            ProtocolConfig::Serial(config) => Some(format!(
+                "plink -serial {} -sercfg {}",
+                quote_arg(&config.device),
+                plink_serial_config(config)
+            )),
+            _ => None,
+        }
+    }
+
+    /// Picks the profile icon: the connection's emoji icon if it has one,
+    /// otherwise a per-protocol default
+    ///
+    /// GTK icon names mean nothing to Windows Terminal, so only non-ASCII
+    /// icons are kept.
+    fn icon(connection: &Connection) -> String {
+        if let Some(icon) = connection.icon.as_deref()
+            && !icon.is_ascii()
+        {
+            return icon.to_string();
+        }
+        match connection.protocol {
+            ProtocolType::Telnet => "📟",
+            ProtocolType::Serial => "🔌",
+            _ => "🔐",
+        }
+        .to_string()
+    }
+}
+
+impl Default for WindowsTerminalExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExportTarget for WindowsTerminalExporter {
+    fn format_id(&self) -> ExportFormat {
+        ExportFormat::WindowsTerminal
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Windows Terminal"
+    }
+
+    fn export(
+        &self,
+        connections: &[Connection],
+        _groups: &[ConnectionGroup],
+        options: &ExportOptions,
+    ) -> ExportOperationResult<ExportResult> {
+        let mut result = ExportResult::new();
+
+        for conn in connections {
+            if self.supports_protocol(&conn.protocol) {
+                result.increment_exported();
+            } else {
+                result.increment_skipped();
+                result.add_warning(format!(
+                    "Skipped non-terminal connection '{}' (protocol: {})",
+                    conn.name, conn.protocol
+                ));
+            }
+        }
+
+        let content = Self::export_fragment(connections);
+        super::write_export_file(&options.output_path, &content)?;
+        result.add_output_file(options.output_path.clone());
+
+        Ok(result)
+    }
+
+    fn export_connection(&self, connection: &Connection) -> ExportOperationResult<String> {
+        let profile = Self::profile(connection)
+            .ok_or_else(|| ExportError::UnsupportedProtocol(connection.protocol.to_string()))?;
+        serde_json::to_string_pretty(&profile)
+            .map_err(|e| ExportError::Serialization(e.to_string()))
+    }
+
+    fn supports_protocol(&self, protocol: &ProtocolType) -> bool {
+        matches!(
+            protocol,
+            ProtocolType::Ssh | ProtocolType::Telnet | ProtocolType::Serial
+        )
+    }
+}
+
+/// Formats serial settings for plink's `-sercfg` option
+fn plink_serial_config(config: &SerialConfig) -> String {
+    let parity = match config.parity {
+        SerialParity::None => 'n',
+        SerialParity::Odd => 'o',
+        SerialParity::Even => 'e',
+    };
+    let stop_bits = match config.stop_bits {
+        SerialStopBits::One => '1',
+        SerialStopBits::Two => '2',
+    };
+    let flow = match config.flow_control {
+        SerialFlowControl::None => 'N',
+        SerialFlowControl::Hardware => 'R',
+        SerialFlowControl::Software => 'X',
+    };
+    format!(
+        "{},{},{parity},{stop_bits},{flow}",
+        config.baud_rate.value(),
+        config.data_bits.value()
+    )
+}
+
+/// Quotes an argument for the Windows command line when it contains
+/// whitespace or quotes
+fn quote_arg(arg: &str) -> String {
+    if arg.is_empty() || arg.chars().any(|c| c.is_whitespace() || c == '"') {
+        format!("\"{}\"", arg.replace('"', "\\\""))
+    } else {
+        arg.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_id_ssh() -> Connection {
+        let mut conn = Connection::new_ssh("web".to_string(), "web.example.com".to_string(), 2222)
+            .with_username("admin");
+        conn.id = Uuid::parse_str("6f1c2a9e-3b4d-4e5f-8a7b-9c0d1e2f3a4b").unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_fragment_structure() {
+        let mut serial = Connection::new(
+            "console".to_string(),
+            String::new(),
+            0,
+            ProtocolConfig::Serial(SerialConfig {
+                device: "COM3".to_string(),
+                ..SerialConfig::default()
+            }),
+        );
+        serial.icon = Some("starred-symbolic".to_string());
+        let connections = vec![
+            fixed_id_ssh(),
+            Connection::new_rdp("desktop".to_string(), "10.0.0.3".to_string(), 3389),
+            serial,
+        ];
+
+        let output = WindowsTerminalExporter::export_fragment(&connections);
+        let value: Value = serde_json::from_str(&output).unwrap();
+        let profiles = value["profiles"].as_array().unwrap();
+        assert_eq!(profiles.len(), 2, "RDP must be skipped");
+
+        let ssh = &profiles[0];
+        assert_eq!(ssh["name"], "web");
+        assert_eq!(ssh["commandline"], "ssh admin@web.example.com -p 2222");
+        assert_eq!(ssh["icon"], "🔐");
+        assert_eq!(ssh.as_object().unwrap().len(), 4);
+
+        let console = &profiles[1];
+        assert_eq!(
+            console["commandline"],
+            "plink -serial COM3 -sercfg 115200,8,n,1,N"
+        );
+        // GTK icon names are replaced with the protocol default
+        assert_eq!(console["icon"], "🔌");
+    }
+
+    #[test]
+    fn test_profile_guid_is_stable() {
+        let conn = fixed_id_ssh();
+        assert_eq!(
+            WindowsTerminalExporter::profile_guid(&conn),
+            "{5c27c899-80ba-5c01-957a-92d5c36952f4}"
+        );
+        assert_eq!(
+            WindowsTerminalExporter::profile_guid(&conn),
+            WindowsTerminalExporter::profile_guid(&conn.clone())
+        );
+
+        let other = Connection::new_ssh("web".to_string(), "web.example.com".to_string(), 2222);
+        assert_ne!(
+            WindowsTerminalExporter::profile_guid(&conn),
+            WindowsTerminalExporter::profile_guid(&other)
+        );
+    }
+
+    #[test]
+    fn test_telnet_and_quoting() {
+        let mut telnet = Connection::new_telnet("switch".to_string(), "10.0.0.9".to_string(), 2323);
+        telnet.icon = Some("🏢".to_string());
+        let profile = WindowsTerminalExporter::profile(&telnet).unwrap();
+        assert_eq!(profile["commandline"], "telnet 10.0.0.9 2323");
+        assert_eq!(profile["icon"], "🏢");
+
+        assert_eq!(quote_arg("plain"), "plain");
+        assert_eq!(quote_arg("my host"), "\"my host\"");
+    }
+
+    #[test]
+    fn test_export_connection_unsupported() {
+        let exporter = WindowsTerminalExporter::new();
+        let rdp = Connection::new_rdp("desktop".to_string(), "10.0.0.3".to_string(), 3389);
+        assert!(matches!(
+            exporter.export_connection(&rdp),
+            Err(ExportError::UnsupportedProtocol(_))
+        ));
+        assert!(exporter.supports_protocol(&ProtocolType::Serial));
+        assert!(!exporter.supports_protocol(&ProtocolType::Vnc));
+    }
+}
@@ -217,9 +217,10 @@ where
 
 /// Polls a host until it comes online using exponential backoff from a `RetryConfig`.
 ///
-/// Each probe attempt uses the delay calculated by `RetryConfig::delay_for_attempt`.
-/// Returns `true` if the host came online within the configured attempts,
-/// `false` if all attempts were exhausted.
+/// Each probe attempt waits for `RetryState::next_delay`: the backoff delay
+/// with the config's jitter applied, from a random seed per call. Returns
+/// `true` if the host came online within the configured attempts, `false` if
+/// all attempts or the `max_elapsed_ms` budget were exhausted.
 ///
 /// # Arguments
 ///
@@ -48,10 +48,14 @@ impl AnsibleInventoryImporter {
     }
 
     /// Parses INI-style Ansible inventory
+    ///
+    /// `[parent:children]` sections nest the listed groups under `parent`.
     #[must_use]
     pub fn parse_ini_inventory(&self, content: &str, source_path: &str) -> ImportResult {
         let mut result = ImportResult::new();
-        let mut current_group: Option<(String, Uuid)> = None;
+        let mut group_ids: HashMap<String, Uuid> = HashMap::new();
+        let mut current_group: Option<Uuid> = None;
+        let mut children_of: Option<Uuid> = None;
         let mut group_vars: HashMap<String, HashMap<String, String>> = HashMap::new();
         let mut in_vars_section = false;
         let mut vars_group_name: Option<String> = None;
@@ -67,30 +71,20 @@ impl AnsibleInventoryImporter {
             // Check for group header
             if line.starts_with('[') && line.ends_with(']') {
                 let section = &line[1..line.len() - 1];
+                in_vars_section = false;
+                vars_group_name = None;
+                current_group = None;
+                children_of = None;
 
                 // Check if this is a :vars section
                 if section.contains(":vars") {
                     in_vars_section = true;
                     vars_group_name = Some(section.split(':').next().unwrap_or("").to_string());
-                    continue;
-                }
-
-                // Check if this is a :children section (skip for now)
-                if section.contains(":children") {
-                    in_vars_section = false;
-                    vars_group_name = None;
-                    current_group = None;
-                    continue;
+                } else if let Some(parent) = section.strip_suffix(":children") {
+                    children_of = Some(Self::ensure_group(&mut result, &mut group_ids, parent));
+                } else {
+                    current_group = Some(Self::ensure_group(&mut result, &mut group_ids, section));
                 }
-
-                in_vars_section = false;
-                vars_group_name = None;
-
-                // Create new group
-                let group = ConnectionGroup::new(section.to_string());
-                let group_id = group.id;
-                result.add_group(group);
-                current_group = Some((section.to_string(), group_id));
                 continue;
             }
 
@@ -107,14 +101,21 @@ impl AnsibleInventoryImporter {
                 continue;
             }
 
+            // Lines in a children section name subgroups, not hosts
+            if let Some(parent_id) = children_of {
+                let child_id = Self::ensure_group(&mut result, &mut group_ids, line);
+                if child_id != parent_id
+                    && let Some(child) = result.groups.iter_mut().find(|g| g.id == child_id)
+                {
+                    child.parent_id = Some(parent_id);
+                }
+                continue;
+            }
+
             // Parse host line
-            if let Some(connection) = self.parse_host_line(
-                line,
-                line_num + 1,
-                current_group.as_ref().map(|(_, id)| *id),
-                source_path,
-                &mut result,
-            ) {
+            if let Some(connection) =
+                self.parse_host_line(line, line_num + 1, current_group, source_path, &mut result)
+            {
                 result.add_connection(connection);
             }
         }
@@ -122,6 +123,20 @@ impl AnsibleInventoryImporter {
         result
     }
 
+    /// Returns the id of the group called `name`, creating it on first use
+    fn ensure_group(
+        result: &mut ImportResult,
+        group_ids: &mut HashMap<String, Uuid>,
+        name: &str,
+    ) -> Uuid {
+        *group_ids.entry(name.to_string()).or_insert_with(|| {
+            let group = ConnectionGroup::new(name.to_string());
+            let id = group.id;
+            result.add_group(group);
+            id
+        })
+    }
+
     /// Parses a variable assignment line
     fn parse_var_line(line: &str) -> Option<(String, String)> {
         if let Some(eq_pos) = line.find('=') {
@@ -553,6 +568,38 @@ all:
         assert_eq!(result.connections.len(), 3);
     }
 
+    #[test]
+    fn test_parse_ini_children_sections() {
+        let importer = AnsibleInventoryImporter::new();
+        let content = r"
+[web]
+web1 ansible_host=10.0.0.1
+
+[db]
+db1 ansible_host=10.0.0.2
+
+[prod:children]
+web
+db
+";
+
+        let result = importer.parse_ini_inventory(content, "test");
+        // Group names in the children section are not hosts
+        assert_eq!(result.connections.len(), 2);
+        assert_eq!(result.groups.len(), 3);
+
+        let prod = result.groups.iter().find(|g| g.name == "prod").unwrap();
+        assert_eq!(prod.parent_id, None);
+        for name in ["web", "db"] {
+            let group = result.groups.iter().find(|g| g.name == name).unwrap();
+            assert_eq!(
+                group.parent_id,
+                Some(prod.id),
+                "{name} should nest under prod"
+            );
+        }
+    }
+
     #[test]
     fn test_skip_host_ranges() {
         let importer = AnsibleInventoryImporter::new();
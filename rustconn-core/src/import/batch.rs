@@ -3,12 +3,14 @@
 //! This module provides `BatchImporter` for processing large numbers of connections
 //! efficiently using configurable batch sizes and progress reporting.
 
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
+use super::preview::{ImportPreview, MergeStrategy};
 use super::traits::ImportResult;
 use crate::error::ImportError;
-use crate::models::Connection;
+use crate::models::{Connection, ConnectionGroup};
 use crate::progress::ProgressReporter;
 
 /// Default batch size for import operations.
@@ -248,6 +250,72 @@ impl BatchImporter {
         BatchImportResult::new(result, false, batches_processed)
     }
 
+    /// Processes an import result without applying it (dry run).
+    ///
+    /// Runs the same batching as [`Self::process_import_result`] and returns
+    /// the preview of what would be imported: the connections and groups
+    /// matched against the existing ones using `strategy`, and the entries
+    /// the importer skipped. Nothing is inserted anywhere.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ImportError::Cancelled` if the operation was cancelled.
+    pub fn dry_run(
+        &self,
+        import_result: ImportResult,
+        existing_connections: &[Connection],
+        existing_groups: &[ConnectionGroup],
+        strategy: MergeStrategy,
+        progress: Option<&dyn ProgressReporter>,
+    ) -> Result<ImportPreview, ImportError> {
+        let batch = self.process_import_result(import_result, progress);
+        if batch.was_cancelled {
+            return Err(ImportError::Cancelled);
+        }
+        Ok(ImportPreview::from_result(
+            &batch.result,
+            existing_connections,
+            existing_groups,
+            strategy,
+            "batch",
+            "",
+        ))
+    }
+
+    /// Imports a file of unknown format, picking the importer from its contents.
+    ///
+    /// This backs the "auto" import source. When detection is ambiguous the
+    /// caller should present [`super::rank_formats_for_path`] to the user and
+    /// import with the chosen importer instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ImportError::UnsupportedFormat` if no importer clearly matches
+    /// the file, or any error from reading and parsing it.
+    pub fn import_auto(
+        &self,
+        path: &Path,
+        progress: Option<&dyn ProgressReporter>,
+    ) -> Result<BatchImportResult, ImportError> {
+        let ranked = super::rank_formats_for_path(path)?;
+        let importer = super::unambiguous(&ranked)
+            .and_then(|m| super::importer_for_source(m.source_id))
+            .ok_or_else(|| {
+                let candidates: Vec<&str> = ranked.iter().map(|m| m.source_id).collect();
+                ImportError::UnsupportedFormat(if candidates.is_empty() {
+                    format!("could not detect the format of {}", path.display())
+                } else {
+                    format!(
+                        "ambiguous format for {} (candidates: {})",
+                        path.display(),
+                        candidates.join(", ")
+                    )
+                })
+            })?;
+        let import_result = importer.import_from_path(path)?;
+        Ok(self.process_import_result(import_result, progress))
+    }
+
     /// Returns true if batch processing should be used for the given count.
     #[must_use]
     pub const fn should_use_batch(count: usize) -> bool {
@@ -333,6 +401,48 @@ mod tests {
         assert!(handle.is_cancelled());
     }
 
+    #[test]
+    fn test_dry_run_matches_import_without_mutation() {
+        let importer = BatchImporter::new(2);
+        let existing = vec![create_test_connection("existing")];
+
+        let parsed = || {
+            let mut result = ImportResult::new();
+            for i in 0..3 {
+                let mut conn = create_test_connection(&format!("conn{i}"));
+                conn.host = format!("host{i}.example.com");
+                result.add_connection(conn);
+            }
+            // Same host and port as the existing connection
+            result.add_connection(create_test_connection("again"));
+            result.add_skipped(crate::import::SkippedEntry::new("bad", "no host"));
+            result
+        };
+
+        let imported = importer.process_import_result(parsed(), None);
+        let preview = importer
+            .dry_run(parsed(), &existing, &[], MergeStrategy::SkipExisting, None)
+            .unwrap();
+
+        let names = |conns: Vec<&Connection>| -> Vec<String> {
+            conns.into_iter().map(|c| c.name.clone()).collect()
+        };
+        assert_eq!(
+            names(preview.connections.iter().map(|p| &p.connection).collect()),
+            names(imported.result.connections.iter().collect())
+        );
+        assert_eq!(preview.skipped.len(), imported.result.skipped.len());
+        assert_eq!(preview.new_count, 3);
+        assert_eq!(preview.skip_count, 1);
+        assert_eq!(preview.connections[3].existing_id, Some(existing[0].id));
+
+        importer.cancel();
+        assert!(matches!(
+            importer.dry_run(parsed(), &existing, &[], MergeStrategy::SkipExisting, None),
+            Err(ImportError::Cancelled)
+        ));
+    }
+
     #[test]
     fn test_should_use_batch() {
         assert!(!BatchImporter::should_use_batch(5));
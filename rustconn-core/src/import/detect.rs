@@ -0,0 +1,600 @@
+//! Import format detection.
+//!
+//! Sniffs file contents (INI section names, JSON/YAML/XML shape, `Host`
+//! lines, Asbru's `_is_group` keys, …) to pick the importer for a file from
+//! an unknown source. Used when the user selects "auto" in the import flow.
+
+use std::path::Path;
+
+use crate::error::ImportError;
+
+use super::putty::decode_text;
+use super::traits::read_import_bytes;
+use super::{
+    AnsibleInventoryImporter, AsbruImporter, CsvImporter, ImportSource, LibvirtXmlImporter,
+    MobaXtermImporter, MremoteNgImporter, PuttyImporter, RdmImporter, RdpFileImporter,
+    RemminaImporter, RoyalTsImporter, SecureCrtImporter, SshConfigImporter, TermiusImporter,
+    VirtViewerImporter,
+};
+
+/// Bytes inspected when sniffing content; format markers appear early.
+const SNIFF_LIMIT: usize = 64 * 1024;
+
+/// Minimum confidence for a format to be reported at all.
+const MIN_CONFIDENCE: u8 = 30;
+
+/// The best match must lead the runner-up by this much to be unambiguous.
+const AMBIGUITY_MARGIN: u8 = 15;
+
+/// Files sniffed per directory when looking for PuTTY session files.
+const DIRECTORY_SNIFF_FILES: usize = 8;
+
+/// A candidate import format with the detector's confidence in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatMatch {
+    /// `ImportSource::source_id` of the matching importer
+    pub source_id: &'static str,
+    /// Confidence from 0 to 100
+    pub confidence: u8,
+}
+
+/// Ranks all import formats that plausibly match `content`, best first.
+///
+/// `file_name` is an optional hint; its extension nudges the score but
+/// never outweighs the content. An empty result means no importer
+/// recognizes the data.
+#[must_use]
+pub fn rank_formats(content: &[u8], file_name: Option<&str>) -> Vec<FormatMatch> {
+    let prefix = &content[..content.len().min(SNIFF_LIMIT)];
+    let text = decode_text(prefix);
+    let text = text.trim_start_matches('\u{feff}');
+    let lower = text.to_lowercase();
+    let extension = file_name
+        .and_then(|name| Path::new(name).extension())
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase);
+
+    let mut matches: Vec<FormatMatch> = [
+        ("remmina", score_remmina(&lower), &["remmina"][..]),
+        ("virt_viewer", score_virt_viewer(&lower), &["vv"]),
+        ("mobaxterm", score_mobaxterm(&lower), &["mxtsessions"]),
+        ("securecrt", score_securecrt(&lower), &["ini"]),
+        ("putty", score_putty(&lower), &["reg"]),
+        ("rdp-file", score_rdp_file(&lower), &["rdp"]),
+        ("royalts", score_royalts(&lower), &["rtsz", "rts"]),
+        ("libvirt", score_libvirt(&lower), &["xml"]),
+        ("mremoteng", score_mremoteng(&lower), &["xml"]),
+        ("rdm", score_rdm(text), &["rdm", "json"]),
+        ("termius", score_termius(text), &["json"]),
+        ("asbru", score_asbru(&lower), &["yml", "yaml"]),
+        ("ansible", score_ansible(&lower), &["ini", "yml", "yaml"]),
+        ("ssh_config", score_ssh_config(&lower), &["conf", "config"]),
+        ("csv", score_csv(&lower), &["csv"]),
+    ]
+    .into_iter()
+    .filter(|&(_, score, _)| score > 0)
+    .map(|(source_id, score, extensions)| {
+        let hinted = extension
+            .as_deref()
+            .is_some_and(|ext| extensions.contains(&ext));
+        let bonus = if hinted { 10 } else { 0 };
+        FormatMatch {
+            source_id,
+            confidence: score.saturating_add(bonus).min(100),
+        }
+    })
+    .filter(|m| m.confidence >= MIN_CONFIDENCE)
+    .collect();
+
+    matches.sort_by_key(|m| std::cmp::Reverse(m.confidence));
+    matches
+}
+
+/// Returns the format of `content` when one importer clearly wins.
+///
+/// Returns `None` when nothing matches or when the top candidates are too
+/// close to call; use [`rank_formats`] to let the user choose in that case.
+#[must_use]
+pub fn detect_format(content: &[u8], file_name: Option<&str>) -> Option<FormatMatch> {
+    unambiguous(&rank_formats(content, file_name))
+}
+
+/// Ranks import formats for a file or directory on disk.
+///
+/// Directories are classified by the files they contain: a folder of
+/// `.remmina` files is a Remmina data directory, a folder of `.ini` files is
+/// a SecureCRT session tree, and a folder of `HostName=` files is PuTTY's
+/// `~/.putty/sessions`.
+///
+/// # Errors
+///
+/// Returns `ImportError::ParseError` if the file cannot be read or is larger
+/// than the import size limit, and `ImportError::FileNotFound` if the path
+/// does not exist.
+pub fn rank_formats_for_path(path: &Path) -> Result<Vec<FormatMatch>, ImportError> {
+    if !path.exists() {
+        return Err(ImportError::FileNotFound(path.to_path_buf()));
+    }
+    if path.is_dir() {
+        return Ok(rank_directory(path));
+    }
+    let content = read_import_bytes(path, "import file")?;
+    let file_name = path.file_name().and_then(|n| n.to_str());
+    Ok(rank_formats(&content, file_name))
+}
+
+/// Returns the best unambiguous match from a ranked list.
+#[must_use]
+pub fn unambiguous(ranked: &[FormatMatch]) -> Option<FormatMatch> {
+    let best = *ranked.first()?;
+    match ranked.get(1) {
+        Some(second) if best.confidence - second.confidence < AMBIGUITY_MARGIN => None,
+        _ => Some(best),
+    }
+}
+
+/// Creates the importer registered under `source_id`.
+///
+/// Covers every file-based importer that [`rank_formats`] can return.
+#[must_use]
+pub fn importer_for_source(source_id: &str) -> Option<Box<dyn ImportSource>> {
+    let importer: Box<dyn ImportSource> = match source_id {
+        "remmina" => Box::new(RemminaImporter::new()),
+        "virt_viewer" => Box::new(VirtViewerImporter::new()),
+        "mobaxterm" => Box::new(MobaXtermImporter::new()),
+        "securecrt" => Box::new(SecureCrtImporter::new()),
+        "putty" => Box::new(PuttyImporter::new()),
+        "rdp-file" => Box::new(RdpFileImporter::new()),
+        "royalts" => Box::new(RoyalTsImporter::new()),
+        "libvirt" => Box::new(LibvirtXmlImporter::new()),
+        "mremoteng" => Box::new(MremoteNgImporter::new()),
+        "rdm" => Box::new(RdmImporter::new()),
+        "termius" => Box::new(TermiusImporter::new()),
+        "asbru" => Box::new(AsbruImporter::new()),
+        "ansible" => Box::new(AnsibleInventoryImporter::new()),
+        "ssh_config" => Box::new(SshConfigImporter::new()),
+        "csv" => Box::new(CsvImporter::new()),
+        _ => return None,
+    };
+    Some(importer)
+}
+
+fn rank_directory(path: &Path) -> Vec<FormatMatch> {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return Vec::new();
+    };
+    let mut remmina = 0usize;
+    let mut ini = 0usize;
+    let mut putty = 0usize;
+    let mut sniffed = 0usize;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_lowercase);
+        if extension.as_deref() == Some("remmina") {
+            remmina += 1;
+        } else if extension.as_deref() == Some("ini") || path.is_dir() {
+            ini += 1;
+        } else if sniffed < DIRECTORY_SNIFF_FILES {
+            // PuTTY session files are named after the session, so only
+            // their contents identify them
+            sniffed += 1;
+            if read_import_bytes(&path, "import file")
+                .is_ok_and(|content| score_putty(&decode_text(&content).to_lowercase()) > 0)
+            {
+                putty += 1;
+            }
+        }
+    }
+
+    let mut matches = Vec::new();
+    if remmina > 0 {
+        matches.push(FormatMatch {
+            source_id: "remmina",
+            confidence: 90,
+        });
+    }
+    if ini > 0 {
+        matches.push(FormatMatch {
+            source_id: "securecrt",
+            confidence: if remmina > 0 { 40 } else { 70 },
+        });
+    }
+    if putty > 0 {
+        matches.push(FormatMatch {
+            source_id: "putty",
+            confidence: if remmina + ini > 0 { 40 } else { 80 },
+        });
+    }
+    matches
+}
+
+/// Yields trimmed, non-empty, non-comment lines.
+fn content_lines(lower: &str) -> impl Iterator<Item = &str> {
+    lower
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#') && !l.starts_with(';'))
+}
+
+fn score_remmina(lower: &str) -> u8 {
+    if content_lines(lower).any(|l| l == "[remmina]") {
+        90
+    } else {
+        0
+    }
+}
+
+fn score_virt_viewer(lower: &str) -> u8 {
+    if content_lines(lower).any(|l| l == "[virt-viewer]") {
+        90
+    } else {
+        0
+    }
+}
+
+fn score_mobaxterm(lower: &str) -> u8 {
+    let bookmarks = content_lines(lower).any(|l| l.starts_with("[bookmarks"));
+    let subrep = content_lines(lower).any(|l| l.starts_with("subrep="));
+    match (bookmarks, subrep) {
+        (true, true) => 90,
+        (true, false) => 60,
+        _ => 0,
+    }
+}
+
+fn score_securecrt(lower: &str) -> u8 {
+    let typed = content_lines(lower)
+        .filter(|l| l.starts_with("s:\"") || l.starts_with("d:\"") || l.starts_with("b:\""))
+        .count();
+    if typed == 0 {
+        return 0;
+    }
+    if lower.contains("s:\"hostname\"=") || lower.contains("s:\"protocol name\"=") {
+        90
+    } else {
+        60
+    }
+}
+
+fn score_putty(lower: &str) -> u8 {
+    if lower.contains("\\software\\simontatham\\putty\\sessions\\") {
+        return 95;
+    }
+    let keys = content_lines(lower)
+        .filter(|l| {
+            l.starts_with("hostname=") || l.starts_with("portnumber=") || l.starts_with("protocol=")
+        })
+        .count();
+    if keys >= 2 && !content_lines(lower).any(|l| l.starts_with('[')) {
+        70
+    } else {
+        0
+    }
+}
+
+fn score_rdp_file(lower: &str) -> u8 {
+    if content_lines(lower).any(|l| l.starts_with("full address:s:")) {
+        90
+    } else if content_lines(lower).any(|l| l.starts_with("screen mode id:i:")) {
+        60
+    } else {
+        0
+    }
+}
+
+fn score_royalts(lower: &str) -> u8 {
+    if lower.contains("<royalssh")
+        || lower.contains("<royalrds")
+        || lower.contains("<royalvnc")
+        || lower.contains("<royaldocument")
+    {
+        90
+    } else {
+        0
+    }
+}
+
+fn score_libvirt(lower: &str) -> u8 {
+    if !lower.contains("<domain") {
+        return 0;
+    }
+    if lower.contains("<graphics") { 90 } else { 70 }
+}
+
+fn score_mremoteng(lower: &str) -> u8 {
+    if lower.contains("<mrng:connections") || lower.contains("xmlns:mrng") {
+        return 95;
+    }
+    if lower.contains("<connections") && lower.contains("<node ") {
+        if lower.contains("confversion") {
+            90
+        } else {
+            60
+        }
+    } else {
+        0
+    }
+}
+
+fn score_rdm(text: &str) -> u8 {
+    let trimmed = text.trim_start();
+    if !trimmed.starts_with('{') {
+        return 0;
+    }
+    let has_connections = text.contains("\"Connections\"");
+    let has_type = text.contains("\"ConnectionType\"");
+    match (has_connections, has_type) {
+        (true, true) => 90,
+        (true, false) | (false, true) => 50,
+        _ => 0,
+    }
+}
+
+fn score_termius(text: &str) -> u8 {
+    if !text.trim_start().starts_with('{') || !text.contains("\"hosts\"") {
+        return 0;
+    }
+    let linked = [
+        "\"identities\"",
+        "\"ssh_keys\"",
+        "\"taghosts\"",
+        "\"ssh_config\"",
+    ]
+    .iter()
+    .filter(|marker| text.contains(*marker))
+    .count();
+    match linked {
+        0 if text.contains("\"address\"") => 50,
+        0 => 0,
+        1 => 80,
+        _ => 90,
+    }
+}
+
+fn score_asbru(lower: &str) -> u8 {
+    let is_group = content_lines(lower).any(|l| l.starts_with("_is_group:"));
+    let has_ip = content_lines(lower).any(|l| l.starts_with("ip:"));
+    let has_method = content_lines(lower).any(|l| l.starts_with("method:"));
+    match (is_group, has_ip || has_method) {
+        (true, true) => 95,
+        (true, false) => 80,
+        (false, true) if has_ip && has_method => 45,
+        _ => 0,
+    }
+}
+
+fn score_ansible(lower: &str) -> u8 {
+    if lower.contains("ansible_host") || lower.contains("ansible_user") {
+        return 90;
+    }
+    // YAML inventory shape: `all:` / `children:` / `hosts:` keys
+    let yaml_hosts = content_lines(lower).any(|l| l == "hosts:");
+    let yaml_root = content_lines(lower).any(|l| l == "all:" || l == "children:");
+    if yaml_hosts && yaml_root {
+        return 75;
+    }
+    // INI inventory: `[group]` sections followed by bare host lines
+    let mut in_section = false;
+    let mut host_lines = 0usize;
+    for line in content_lines(lower) {
+        if line.starts_with('[') && line.ends_with(']') {
+            in_section = true;
+        } else if in_section && !line.contains('=') && !line.contains(':') {
+            host_lines += 1;
+        } else if in_section && line.contains(":vars") {
+            in_section = false;
+        }
+    }
+    if host_lines > 0 { 40 } else { 0 }
+}
+
+fn score_ssh_config(lower: &str) -> u8 {
+    let mut host_lines = 0usize;
+    let mut hostname_lines = 0usize;
+    for line in content_lines(lower) {
+        let keyword = line
+            .split(|c: char| c.is_whitespace() || c == '=')
+            .next()
+            .unwrap_or("");
+        match keyword {
+            "host" if line.len() > "host ".len() => host_lines += 1,
+            "hostname" => hostname_lines += 1,
+            _ => {}
+        }
+    }
+    match (host_lines > 0, hostname_lines > 0) {
+        (true, true) => 85,
+        (true, false) => 60,
+        _ => 0,
+    }
+}
+
+fn score_csv(lower: &str) -> u8 {
+    let Some(header) = content_lines(lower).next() else {
+        return 0;
+    };
+    let delimiter = [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|&d| header.matches(d).count())
+        .unwrap_or(',');
+    let fields: Vec<&str> = header
+        .split(delimiter)
+        .map(|f| f.trim().trim_matches('"'))
+        .collect();
+    if fields.len() < 2 {
+        return 0;
+    }
+    let has_host = fields
+        .iter()
+        .any(|f| matches!(*f, "host" | "hostname" | "address" | "ip"));
+    let has_name = fields.iter().any(|f| matches!(*f, "name" | "label"));
+    match (has_host, has_name) {
+        (true, true) => 80,
+        (true, false) => 60,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn best(content: &str, file_name: Option<&str>) -> Option<&'static str> {
+        detect_format(content.as_bytes(), file_name).map(|m| m.source_id)
+    }
+
+    #[test]
+    fn detects_remmina() {
+        let content = "[remmina]\nname=Server\nprotocol=SSH\nserver=10.0.0.1\n";
+        assert_eq!(best(content, None), Some("remmina"));
+    }
+
+    #[test]
+    fn detects_ssh_config() {
+        let content = "Host web\n    HostName web.example.com\n    User deploy\n";
+        assert_eq!(best(content, Some("config")), Some("ssh_config"));
+    }
+
+    #[test]
+    fn detects_asbru() {
+        let content =
+            "conn-1:\n  _is_group: 0\n  name: \"Web\"\n  ip: \"10.0.0.1\"\n  method: SSH\n";
+        assert_eq!(best(content, Some("pac.yml")), Some("asbru"));
+    }
+
+    #[test]
+    fn detects_ansible_ini_and_yaml() {
+        let ini = "[web]\nweb1.example.com ansible_host=10.0.0.1\n";
+        assert_eq!(best(ini, Some("hosts.ini")), Some("ansible"));
+
+        let yaml = "all:\n  children:\n    web:\n      hosts:\n        web1:\n";
+        assert_eq!(best(yaml, Some("inventory.yml")), Some("ansible"));
+    }
+
+    #[test]
+    fn detects_putty_registry_export() {
+        let reg = "Windows Registry Editor Version 5.00\r\n\r\n\
+                   [HKEY_CURRENT_USER\\Software\\SimonTatham\\PuTTY\\Sessions\\web]\r\n\
+                   \"HostName\"=\"web.lan\"\r\n";
+        let mut utf16 = vec![0xFF, 0xFE];
+        utf16.extend(reg.encode_utf16().flat_map(u16::to_le_bytes));
+        assert_eq!(
+            detect_format(&utf16, Some("putty.reg")).map(|m| m.source_id),
+            Some("putty")
+        );
+        assert_eq!(
+            best("HostName=web.lan\nPortNumber=22\nProtocol=ssh\n", None),
+            Some("putty")
+        );
+    }
+
+    #[test]
+    fn detects_rdp_and_virt_viewer() {
+        assert_eq!(
+            best("full address:s:10.0.0.5:3389\nusername:s:admin\n", None),
+            Some("rdp-file")
+        );
+        assert_eq!(
+            best(
+                "[virt-viewer]\ntype=spice\nhost=10.0.0.1\nport=5900\n",
+                None
+            ),
+            Some("virt_viewer")
+        );
+    }
+
+    #[test]
+    fn detects_xml_formats() {
+        let royal = "<RoyalDocument><RoyalSSHConnection><Name>a</Name></RoyalSSHConnection></RoyalDocument>";
+        assert_eq!(best(royal, None), Some("royalts"));
+
+        let libvirt = "<domain type='kvm'><name>vm</name><devices><graphics type='spice'/></devices></domain>";
+        assert_eq!(best(libvirt, None), Some("libvirt"));
+
+        let mremoteng = r#"<mrng:Connections xmlns:mrng="http://mremoteng.org" ConfVersion="2.6"><Node Name="a" Type="Connection" Hostname="h" Protocol="RDP"/></mrng:Connections>"#;
+        assert_eq!(best(mremoteng, Some("confCons.xml")), Some("mremoteng"));
+    }
+
+    #[test]
+    fn detects_csv_and_rdm() {
+        assert_eq!(
+            best("name,host,port,protocol\nweb,10.0.0.1,22,ssh\n", None),
+            Some("csv")
+        );
+        let rdm = r#"{"Connections":[{"Name":"a","ConnectionType":"SSH","Host":"h"}]}"#;
+        assert_eq!(best(rdm, None), Some("rdm"));
+        let termius = r#"{"hosts":[{"id":1,"label":"a","address":"h","ssh_config":{"identity":2}}],"identities":[{"id":2,"username":"u"}]}"#;
+        assert_eq!(best(termius, Some("termius.json")), Some("termius"));
+    }
+
+    #[test]
+    fn ambiguous_content_returns_ranked_list() {
+        // Bare INI sections with host lines are only weak evidence of an
+        // Ansible inventory.
+        let content = "[web]\nweb1.example.com\nweb2.example.com\n";
+        let ranked = rank_formats(content.as_bytes(), None);
+        assert_eq!(ranked.first().map(|m| m.source_id), Some("ansible"));
+
+        let close = [
+            FormatMatch {
+                source_id: "ansible",
+                confidence: 60,
+            },
+            FormatMatch {
+                source_id: "ssh_config",
+                confidence: 55,
+            },
+        ];
+        assert!(unambiguous(&close).is_none());
+    }
+
+    #[test]
+    fn unknown_content_has_no_match() {
+        assert!(rank_formats(b"just some prose, nothing more", None).is_empty());
+        assert!(detect_format(&[0xff, 0x00, 0x13], None).is_none());
+    }
+
+    #[test]
+    fn importer_for_every_detected_source() {
+        for id in [
+            "remmina",
+            "virt_viewer",
+            "mobaxterm",
+            "securecrt",
+            "putty",
+            "rdp-file",
+            "royalts",
+            "libvirt",
+            "mremoteng",
+            "rdm",
+            "termius",
+            "asbru",
+            "ansible",
+            "ssh_config",
+            "csv",
+        ] {
+            let importer = importer_for_source(id).expect("importer");
+            assert_eq!(importer.source_id(), id);
+        }
+        assert!(importer_for_source("nope").is_none());
+    }
+
+    #[test]
+    fn detects_fixture_files() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let best_for = |name: &str| {
+            rank_formats_for_path(&fixtures.join(name))
+                .ok()
+                .and_then(|ranked| unambiguous(&ranked))
+                .map(|m| m.source_id)
+        };
+        assert_eq!(best_for("asbru.yml"), Some("asbru"));
+        assert_eq!(best_for("ansible_inventory.ini"), Some("ansible"));
+        assert_eq!(best_for("ssh_config"), Some("ssh_config"));
+        assert_eq!(best_for("remmina"), Some("remmina"));
+        assert_eq!(best_for("termius/termius_export.json"), Some("termius"));
+    }
+}
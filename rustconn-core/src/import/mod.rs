@@ -7,8 +7,15 @@
 //! - Ansible inventory files
 //! - Royal TS rJSON files
 //! - MobaXterm session files
+//! - PuTTY saved sessions (registry `.reg` exports and `~/.putty/sessions`)
 //! - Virt-viewer (.vv) files (SPICE/VNC from libvirt, Proxmox VE)
 //! - Libvirt domain XML files (VNC/SPICE/RDP from QEMU/KVM, GNOME Boxes)
+//! - SecureCRT session `.ini` files (SSH, Telnet, RDP, VNC, Serial)
+//! - mRemoteNG `confCons.xml` files (RDP, SSH, VNC, Telnet with folder hierarchy)
+//! - Termius JSON exports (SSH hosts with groups, identities, and tags)
+//!
+//! When the source is unknown, `detect_format` sniffs file contents and
+//! `importer_for_source` returns the matching importer.
 //!
 //! For large imports (more than 10 connections), use `BatchImporter` for
 //! efficient batch processing with progress reporting and cancellation support.
@@ -43,17 +50,21 @@ mod ansible;
 mod asbru;
 pub mod batch;
 mod csv_import;
+mod detect;
 mod libvirt;
 mod libvirt_daemon;
 mod mobaxterm;
+mod mremoteng;
 mod normalize;
 mod preview;
+mod putty;
 mod rdm;
 mod rdp_file;
 mod remmina;
 mod royalts;
 mod securecrt;
 mod ssh_config;
+mod termius;
 mod traits;
 mod vv;
 
@@ -64,20 +75,27 @@ pub use batch::{
     DEFAULT_IMPORT_BATCH_SIZE,
 };
 pub use csv_import::{CsvColumnMapping, CsvImporter, CsvParseOptions};
+pub use detect::{
+    FormatMatch, detect_format, importer_for_source, rank_formats, rank_formats_for_path,
+    unambiguous,
+};
 pub use libvirt::LibvirtXmlImporter;
 pub use libvirt_daemon::LibvirtDaemonImporter;
 pub use mobaxterm::MobaXtermImporter;
+pub use mremoteng::MremoteNgImporter;
 pub use normalize::{
     ImportNormalizer, NormalizeOptions, is_valid_hostname, looks_like_hostname, parse_host_port,
     sanitize_imported_value,
 };
 pub use preview::{DuplicateAction, ImportPreview, MergeStrategy, PreviewConnection, PreviewGroup};
+pub use putty::PuttyImporter;
 pub use rdm::RdmImporter;
 pub use rdp_file::RdpFileImporter;
 pub use remmina::RemminaImporter;
 pub use royalts::RoyalTsImporter;
 pub use securecrt::SecureCrtImporter;
 pub use ssh_config::SshConfigImporter;
+pub use termius::TermiusImporter;
 pub use traits::{
     ImportResult, ImportSource, ImportStatistics, SkippedEntry, SkippedField, SkippedFieldReason,
 };
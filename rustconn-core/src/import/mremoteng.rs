@@ -0,0 +1,533 @@
+//! mRemoteNG connection file importer.
+//!
+//! Parses mRemoteNG `confCons.xml` files. Connections are `<Node>` elements
+//! with `Type="Connection"`; folders are `<Node Type="Container">` elements
+//! whose children are nested inside them, so the folder tree is rebuilt as
+//! `ConnectionGroup`s from the element nesting.
+//!
+//! Supports RDP, SSH (SSH1/SSH2), VNC, and Telnet. Other protocols (ICA,
+//! HTTP, PowerShell, …) are reported as skipped entries.
+//!
+//! Stored passwords are AES-encrypted with a key derived from the file's
+//! master password, so they are never imported. Each connection with a
+//! password is reported as a skipped entry and set to prompt instead.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use uuid::Uuid;
+
+use super::traits::{ImportResult, ImportSource, SkippedEntry, read_import_file};
+use crate::error::ImportError;
+use crate::models::{
+    Connection, ConnectionGroup, PasswordSource, ProtocolConfig, RdpConfig, Resolution,
+    SharedFolder, SshConfig, TelnetConfig, VncConfig,
+};
+
+/// Source name used in errors and skipped entries.
+const SOURCE_NAME: &str = "mRemoteNG";
+
+/// Attributes of a single `<Node>` element, keyed by attribute name.
+#[derive(Debug, Default)]
+struct NodeAttributes(HashMap<String, String>);
+
+impl NodeAttributes {
+    fn from_element(element: &BytesStart<'_>) -> Self {
+        let mut attrs = HashMap::new();
+        for attr in element.attributes().flatten() {
+            let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
+            let raw = String::from_utf8_lossy(&attr.value);
+            let value = quick_xml::escape::unescape(&raw)
+                .map_or_else(|_| raw.to_string(), |v| v.into_owned());
+            attrs.insert(key, value);
+        }
+        Self(attrs)
+    }
+
+    /// Returns a trimmed, non-empty attribute value.
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(|v| v.trim()).filter(|v| !v.is_empty())
+    }
+
+    fn get_bool(&self, key: &str) -> bool {
+        self.get(key)
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+    }
+
+    fn name(&self) -> &str {
+        self.get("Name").unwrap_or("Unnamed")
+    }
+
+    fn is_container(&self) -> bool {
+        self.get("Type")
+            .is_some_and(|t| t.eq_ignore_ascii_case("Container"))
+    }
+}
+
+/// A folder on the current path through the node tree.
+#[derive(Debug)]
+struct OpenContainer {
+    group_id: Uuid,
+    username: Option<String>,
+    domain: Option<String>,
+}
+
+/// Importer for mRemoteNG `confCons.xml` files.
+pub struct MremoteNgImporter {
+    /// Custom path to import from
+    custom_path: Option<PathBuf>,
+}
+
+impl MremoteNgImporter {
+    /// Creates a new mRemoteNG importer.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { custom_path: None }
+    }
+
+    /// Creates a new importer with a custom path.
+    #[must_use]
+    pub const fn with_path(path: PathBuf) -> Self {
+        Self {
+            custom_path: Some(path),
+        }
+    }
+
+    /// Parses the contents of a `confCons.xml` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ImportError::ParseError` if the content is not well-formed
+    /// XML.
+    pub fn parse_xml(&self, content: &str, source_path: &str) -> Result<ImportResult, ImportError> {
+        let content = content.trim_start_matches('\u{feff}');
+        let mut reader = Reader::from_str(content);
+        reader.config_mut().trim_text(true);
+
+        let mut result = ImportResult::new();
+        // One entry per open <Node>; `None` for connections written with a
+        // closing tag so the stack stays balanced.
+        let mut open: Vec<Option<OpenContainer>> = Vec::new();
+
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(e)) => {
+                    if e.name().as_ref() == b"Node" {
+                        let attrs = NodeAttributes::from_element(&e);
+                        let container = Self::process_node(&attrs, &open, source_path, &mut result);
+                        open.push(container);
+                    } else if Self::is_root(&e) && Self::is_fully_encrypted(&e) {
+                        result.add_skipped(SkippedEntry::with_location(
+                            source_path,
+                            "File is fully encrypted; disable \"Encrypt complete connection \
+                             file\" in mRemoteNG and export again",
+                            source_path,
+                        ));
+                        return Ok(result);
+                    }
+                }
+                Ok(Event::Empty(e)) if e.name().as_ref() == b"Node" => {
+                    let attrs = NodeAttributes::from_element(&e);
+                    // An empty container still becomes a (childless) group
+                    let _ = Self::process_node(&attrs, &open, source_path, &mut result);
+                }
+                Ok(Event::End(e)) if e.name().as_ref() == b"Node" => {
+                    open.pop();
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => {
+                    return Err(ImportError::ParseError {
+                        source_name: SOURCE_NAME.to_string(),
+                        reason: format!(
+                            "Invalid XML at position {}: {e}",
+                            reader.buffer_position()
+                        ),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Returns true for the `<Connections>` root element (with or without
+    /// the `mrng:` namespace prefix used by newer versions).
+    fn is_root(element: &BytesStart<'_>) -> bool {
+        element.local_name().as_ref() == b"Connections"
+    }
+
+    fn is_fully_encrypted(element: &BytesStart<'_>) -> bool {
+        NodeAttributes::from_element(element).get_bool("FullFileEncryption")
+    }
+
+    /// Handles one `<Node>` element, returning the group it opens if it is
+    /// a container.
+    fn process_node(
+        attrs: &NodeAttributes,
+        open: &[Option<OpenContainer>],
+        source_path: &str,
+        result: &mut ImportResult,
+    ) -> Option<OpenContainer> {
+        let parent = open.iter().rev().find_map(Option::as_ref);
+
+        if attrs.is_container() {
+            let name = attrs.name().to_string();
+            let group = match parent {
+                Some(p) => ConnectionGroup::with_parent(name, p.group_id),
+                None => ConnectionGroup::new(name),
+            };
+            let container = OpenContainer {
+                group_id: group.id,
+                username: Self::inherited(
+                    attrs,
+                    "Username",
+                    parent.and_then(|p| p.username.as_deref()),
+                ),
+                domain: Self::inherited(attrs, "Domain", parent.and_then(|p| p.domain.as_deref())),
+            };
+            result.add_group(group);
+            return Some(container);
+        }
+
+        match Self::node_to_connection(attrs, parent) {
+            Ok(mut connection) => {
+                if attrs.get("Password").is_some() {
+                    connection.password_source = PasswordSource::Prompt;
+                    result.add_skipped(SkippedEntry::with_location(
+                        format!("{} (password)", connection.name),
+                        "Password is encrypted by mRemoteNG and was not imported; \
+                         you will be prompted for it on connect",
+                        source_path,
+                    ));
+                }
+                result.add_connection(connection);
+            }
+            Err(reason) => {
+                result.add_skipped(SkippedEntry::with_location(
+                    attrs.name(),
+                    reason,
+                    source_path,
+                ));
+            }
+        }
+        None
+    }
+
+    /// Resolves an attribute that may be inherited from the parent folder
+    /// via the matching `Inherit<Key>="true"` attribute.
+    fn inherited(attrs: &NodeAttributes, key: &str, parent: Option<&str>) -> Option<String> {
+        if attrs.get_bool(&format!("Inherit{key}")) {
+            parent.map(String::from)
+        } else {
+            attrs.get(key).map(String::from)
+        }
+    }
+
+    /// Converts a connection node into a `Connection`.
+    fn node_to_connection(
+        attrs: &NodeAttributes,
+        parent: Option<&OpenContainer>,
+    ) -> Result<Connection, String> {
+        let protocol = attrs.get("Protocol").unwrap_or("RDP");
+        let (protocol_config, default_port) = match protocol.to_uppercase().as_str() {
+            "RDP" => (ProtocolConfig::Rdp(Self::rdp_config(attrs)), 3389),
+            "SSH1" | "SSH2" => (ProtocolConfig::Ssh(SshConfig::default()), 22),
+            "VNC" => (ProtocolConfig::Vnc(VncConfig::default()), 5900),
+            "TELNET" => (ProtocolConfig::Telnet(TelnetConfig::default()), 23),
+            _ => return Err(format!("Unsupported protocol: {protocol}")),
+        };
+
+        let host = attrs.get("Hostname").ok_or("Missing hostname")?;
+        let port = attrs
+            .get("Port")
+            .and_then(|p| p.parse::<u16>().ok())
+            .filter(|&p| p > 0)
+            .unwrap_or(default_port);
+
+        let mut connection = Connection::new(
+            attrs.name().to_string(),
+            host.to_string(),
+            port,
+            protocol_config,
+        );
+        connection.description = attrs.get("Descr").map(String::from);
+        connection.username = Self::inherited(
+            attrs,
+            "Username",
+            parent.and_then(|p| p.username.as_deref()),
+        );
+        if matches!(connection.protocol_config, ProtocolConfig::Rdp(_)) {
+            connection.domain =
+                Self::inherited(attrs, "Domain", parent.and_then(|p| p.domain.as_deref()));
+        }
+        connection.group_id = parent.map(|p| p.group_id);
+
+        Ok(connection)
+    }
+
+    /// Translates mRemoteNG RDP options into an `RdpConfig`.
+    fn rdp_config(attrs: &NodeAttributes) -> RdpConfig {
+        let shared_folders = if Self::redirects_drives(attrs) {
+            dirs::home_dir()
+                .map(|home| {
+                    vec![SharedFolder {
+                        local_path: home,
+                        share_name: "Home".to_string(),
+                        read_only: false,
+                    }]
+                })
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        RdpConfig {
+            resolution: attrs.get("Resolution").and_then(Self::parse_resolution),
+            color_depth: attrs.get("Colors").and_then(Self::parse_color_depth),
+            audio_redirect: attrs
+                .get("RedirectSound")
+                .is_some_and(|v| v.eq_ignore_ascii_case("BringToThisComputer")),
+            printer_enabled: attrs.get_bool("RedirectPrinters"),
+            clipboard_enabled: attrs
+                .get("RedirectClipboard")
+                .is_none_or(|v| v.eq_ignore_ascii_case("true")),
+            shared_folders,
+            ..RdpConfig::default()
+        }
+    }
+
+    /// Older files store `RedirectDiskDrives` as a boolean; newer ones use
+    /// `None`, `Local`, `All`, or `Custom`.
+    fn redirects_drives(attrs: &NodeAttributes) -> bool {
+        attrs.get("RedirectDiskDrives").is_some_and(|v| {
+            v.eq_ignore_ascii_case("true")
+                || v.eq_ignore_ascii_case("All")
+                || v.eq_ignore_ascii_case("Local")
+        })
+    }
+
+    /// Parses fixed resolutions such as `Res1920x1080`; `FitToWindow`,
+    /// `Fullscreen`, and `SmartSize` leave the resolution automatic.
+    fn parse_resolution(value: &str) -> Option<Resolution> {
+        let (width, height) = value.strip_prefix("Res")?.split_once('x')?;
+        Some(Resolution::new(width.parse().ok()?, height.parse().ok()?))
+    }
+
+    fn parse_color_depth(value: &str) -> Option<u8> {
+        match value {
+            "Colors256" => Some(8),
+            "Colors15Bit" => Some(15),
+            "Colors16Bit" => Some(16),
+            "Colors24Bit" => Some(24),
+            "Colors32Bit" => Some(32),
+            _ => None,
+        }
+    }
+}
+
+impl Default for MremoteNgImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImportSource for MremoteNgImporter {
+    fn source_id(&self) -> &'static str {
+        "mremoteng"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "mRemoteNG"
+    }
+
+    fn is_available(&self) -> bool {
+        self.custom_path.as_ref().is_some_and(|p| p.exists())
+    }
+
+    fn default_paths(&self) -> Vec<PathBuf> {
+        // mRemoteNG is Windows-only; files are always imported explicitly
+        self.custom_path.iter().cloned().collect()
+    }
+
+    fn import(&self) -> Result<ImportResult, ImportError> {
+        let Some(path) = &self.custom_path else {
+            return Err(ImportError::FileNotFound(PathBuf::from(
+                "No mRemoteNG file specified",
+            )));
+        };
+        self.import_from_path(path)
+    }
+
+    fn import_from_path(&self, path: &Path) -> Result<ImportResult, ImportError> {
+        if !path.exists() {
+            return Err(ImportError::FileNotFound(path.to_path_buf()));
+        }
+
+        let content = read_import_file(path, SOURCE_NAME)?;
+        self.parse_xml(&content, &path.display().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> ImportResult {
+        MremoteNgImporter::new()
+            .parse_xml(content, "confCons.xml")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_ssh_connection() {
+        let result = parse(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<mrng:Connections xmlns:mrng="http://mremoteng.org" Name="Connections" ConfVersion="2.6">
+  <Node Name="bastion" Type="Connection" Hostname="bastion.example.com" Protocol="SSH2" Port="2222" Username="ops" Descr="Jump box &amp; logs" />
+</mrng:Connections>"#,
+        );
+
+        assert_eq!(result.connections.len(), 1);
+        let conn = &result.connections[0];
+        assert_eq!(conn.name, "bastion");
+        assert_eq!(conn.host, "bastion.example.com");
+        assert_eq!(conn.port, 2222);
+        assert_eq!(conn.username.as_deref(), Some("ops"));
+        assert_eq!(conn.description.as_deref(), Some("Jump box & logs"));
+        assert!(matches!(conn.protocol_config, ProtocolConfig::Ssh(_)));
+        assert!(conn.group_id.is_none());
+    }
+
+    #[test]
+    fn test_rdp_options() {
+        let result = parse(
+            r#"<Connections Name="Connections">
+  <Node Name="dc01" Type="Connection" Hostname="10.0.0.10" Protocol="RDP" Port="3389" Domain="CORP"
+        Resolution="Res1920x1080" Colors="Colors24Bit" RedirectPrinters="true"
+        RedirectDiskDrives="false" RedirectSound="DoNotPlay" RedirectClipboard="false" />
+  <Node Name="dc02" Type="Connection" Hostname="10.0.0.11" Protocol="RDP"
+        Resolution="FitToWindow" RedirectDiskDrives="true" RedirectSound="BringToThisComputer" />
+</Connections>"#,
+        );
+
+        assert_eq!(result.connections.len(), 2);
+        let ProtocolConfig::Rdp(ref dc01) = result.connections[0].protocol_config else {
+            panic!("expected RDP");
+        };
+        assert_eq!(dc01.resolution, Some(Resolution::new(1920, 1080)));
+        assert_eq!(dc01.color_depth, Some(24));
+        assert!(dc01.printer_enabled);
+        assert!(!dc01.clipboard_enabled);
+        assert!(!dc01.audio_redirect);
+        assert!(dc01.shared_folders.is_empty());
+        assert_eq!(result.connections[0].domain.as_deref(), Some("CORP"));
+
+        let ProtocolConfig::Rdp(ref dc02) = result.connections[1].protocol_config else {
+            panic!("expected RDP");
+        };
+        assert!(dc02.resolution.is_none());
+        assert!(dc02.audio_redirect);
+        assert!(dc02.clipboard_enabled);
+        assert_eq!(
+            dc02.shared_folders.len(),
+            usize::from(dirs::home_dir().is_some())
+        );
+        assert_eq!(result.connections[1].port, 3389);
+    }
+
+    #[test]
+    fn test_encrypted_password_is_skipped() {
+        let result = parse(
+            r#"<Connections>
+  <Node Name="vnc01" Type="Connection" Hostname="10.0.0.20" Protocol="VNC" Port="5901" Password="q8Xz1Yp2bW9uZ0VuY3J5cHRlZA==" />
+</Connections>"#,
+        );
+
+        assert_eq!(result.connections.len(), 1);
+        assert_eq!(
+            result.connections[0].password_source,
+            PasswordSource::Prompt
+        );
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].identifier, "vnc01 (password)");
+        assert!(result.skipped[0].reason.contains("encrypted"));
+    }
+
+    #[test]
+    fn test_unsupported_protocol_and_missing_host() {
+        let result = parse(
+            r#"<Connections>
+  <Node Name="citrix" Type="Connection" Hostname="ctx.example.com" Protocol="ICA" />
+  <Node Name="nohost" Type="Connection" Protocol="SSH2" />
+</Connections>"#,
+        );
+
+        assert!(result.connections.is_empty());
+        assert_eq!(result.skipped.len(), 2);
+        assert!(result.skipped[0].reason.contains("ICA"));
+        assert!(result.skipped[1].reason.contains("hostname"));
+    }
+
+    #[test]
+    fn test_inherited_username_and_nested_groups() {
+        let result = parse(
+            r#"<Connections>
+  <Node Name="Prod" Type="Container" Username="admin" Domain="CORP">
+    <Node Name="Web" Type="Container" InheritUsername="true" InheritDomain="true">
+      <Node Name="web01" Type="Connection" Hostname="web01" Protocol="RDP" InheritUsername="true" InheritDomain="true" />
+    </Node>
+    <Node Name="Empty" Type="Container" />
+  </Node>
+  <Node Name="loose" Type="Connection" Hostname="loose" Protocol="SSH2" InheritUsername="true" />
+</Connections>"#,
+        );
+
+        assert_eq!(result.groups.len(), 3);
+        let prod = &result.groups[0];
+        let web = &result.groups[1];
+        let empty = &result.groups[2];
+        assert!(prod.parent_id.is_none());
+        assert_eq!(web.parent_id, Some(prod.id));
+        assert_eq!(empty.parent_id, Some(prod.id));
+
+        let web01 = &result.connections[0];
+        assert_eq!(web01.group_id, Some(web.id));
+        assert_eq!(web01.username.as_deref(), Some("admin"));
+        assert_eq!(web01.domain.as_deref(), Some("CORP"));
+
+        let loose = &result.connections[1];
+        assert!(loose.group_id.is_none());
+        assert!(loose.username.is_none());
+    }
+
+    #[test]
+    fn test_fully_encrypted_file() {
+        let result = parse(
+            r#"<mrng:Connections xmlns:mrng="http://mremoteng.org" FullFileEncryption="true">b64ciphertext==</mrng:Connections>"#,
+        );
+        assert!(result.connections.is_empty());
+        assert_eq!(result.skipped.len(), 1);
+        assert!(result.skipped[0].reason.contains("fully encrypted"));
+    }
+
+    #[test]
+    fn test_malformed_xml_is_an_error() {
+        let err = MremoteNgImporter::new()
+            .parse_xml("<Connections><Node Name=\"a\"></Connections>", "x.xml")
+            .unwrap_err();
+        assert!(matches!(err, ImportError::ParseError { .. }));
+    }
+
+    #[test]
+    fn test_parse_resolution() {
+        assert_eq!(
+            MremoteNgImporter::parse_resolution("Res1280x1024"),
+            Some(Resolution::new(1280, 1024))
+        );
+        assert_eq!(MremoteNgImporter::parse_resolution("Fullscreen"), None);
+        assert_eq!(MremoteNgImporter::parse_resolution("SmartSize"), None);
+    }
+}
@@ -1,13 +1,15 @@
 //! Import preview and merge strategies.
 //!
 //! Provides functionality for previewing imports before applying them
-//! and handling duplicate connections during re-import.
+//! and handling duplicate connections during re-import. A preview is also
+//! the result of a dry run: it carries everything the import would do
+//! without touching the existing connections.
 
 use std::collections::HashMap;
 
 use uuid::Uuid;
 
-use super::ImportResult;
+use super::{ImportResult, SkippedEntry};
 use crate::models::{Connection, ConnectionGroup};
 
 /// Strategy for handling duplicate connections during import.
@@ -113,6 +115,10 @@ pub struct ImportPreview {
     pub new_count: usize,
     /// Number of duplicates that will be created
     pub duplicate_count: usize,
+    /// Entries the importer could not parse
+    pub skipped: Vec<SkippedEntry>,
+    /// Number of errors the importer reported
+    pub error_count: usize,
 }
 
 impl ImportPreview {
@@ -149,6 +155,8 @@ impl ImportPreview {
         source_path: impl Into<String>,
     ) -> Self {
         let mut preview = Self::new(source_id, source_path);
+        preview.skipped.clone_from(&result.skipped);
+        preview.error_count = result.errors.len();
 
         // Build lookup maps for existing items using references to avoid cloning
         let conn_lookup: HashMap<(&str, u16), &Connection> = existing_connections
@@ -244,12 +252,13 @@ impl ImportPreview {
     #[must_use]
     pub fn summary(&self) -> String {
         format!(
-            "New: {}, Update: {}, Skip: {}, Duplicates: {}, Groups: {}",
+            "New: {}, Update: {}, Skip: {}, Duplicates: {}, Groups: {}, Unparsed: {}",
             self.new_count,
             self.update_count,
             self.skip_count,
             self.duplicate_count,
-            self.groups.len()
+            self.groups.len(),
+            self.skipped.len()
         )
     }
 
@@ -588,5 +597,25 @@ mod tests {
         assert!(summary.contains("New: 1"));
         assert!(summary.contains("Skip: 1"));
         assert!(summary.contains("Groups: 1"));
+        assert!(summary.contains("Unparsed: 0"));
+    }
+
+    #[test]
+    fn test_preview_keeps_skipped_entries() {
+        let mut result = ImportResult::new();
+        result.add_connection(create_test_connection("Server 1", "host1.example.com", 22));
+        result.add_skipped(SkippedEntry::with_location(
+            "broken",
+            "missing host",
+            "hosts.yml:4",
+        ));
+
+        let preview =
+            ImportPreview::from_result(&result, &[], &[], MergeStrategy::default(), "test", "x");
+
+        assert_eq!(preview.skipped.len(), 1);
+        assert_eq!(preview.skipped[0].identifier, "broken");
+        assert_eq!(preview.error_count, 0);
+        assert!(preview.summary().contains("Unparsed: 1"));
     }
 }
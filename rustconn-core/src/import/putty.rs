@@ -0,0 +1,733 @@
+//! PuTTY session importer.
+//!
+//! Imports saved PuTTY sessions in either of the forms PuTTY stores them:
+//!
+//! 1. **Registry export** — a `.reg` file produced by `regedit` on Windows
+//!    from `HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions`. Modern
+//!    `regedit` writes UTF-16LE with a byte order mark; `REGEDIT4` exports
+//!    are plain 8-bit text. Both are accepted.
+//! 2. **Session directory** — `~/.putty/sessions` on Linux, one file per
+//!    session containing `Key=Value` lines. A single session file can also be
+//!    imported directly.
+//!
+//! Session names are URL-encoded by PuTTY (`My%20Server`) in both forms and
+//! are decoded on import. The `Default Settings` session is a template and
+//! is never imported.
+//!
+//! Only SSH sessions are imported. Telnet, raw, rlogin, serial and SUPDUP
+//! sessions are reported as skipped entries. PuTTY proxy settings map to the
+//! connection's [`ProxyConfig`] (SOCKS4/SOCKS5/HTTP), to `ProxyCommand` for
+//! a local proxy command, and to `ProxyJump` for an SSH proxy.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::normalize::parse_host_port;
+use super::traits::{ImportResult, ImportSource, SkippedEntry, read_import_bytes};
+use crate::error::ImportError;
+use crate::models::{
+    Connection, PortForward, PortForwardDirection, ProtocolConfig, ProxyConfig, ProxyType,
+    SshAuthMethod, SshConfig, SshKeySource,
+};
+
+/// Registry key holding saved sessions, lowercased for matching.
+const SESSIONS_KEY: &str = "\\software\\simontatham\\putty\\sessions\\";
+
+/// Name of PuTTY's template session.
+const DEFAULT_SESSION: &str = "Default Settings";
+
+/// Settings of one saved session, keyed by PuTTY setting name.
+///
+/// DWORD values are stored as decimal strings, which is also how the Unix
+/// session files store numbers.
+type PuttySession = HashMap<String, String>;
+
+/// Importer for PuTTY saved sessions.
+pub struct PuttyImporter {
+    /// Custom path to import from (`.reg` file, session file or directory)
+    custom_path: Option<PathBuf>,
+}
+
+impl PuttyImporter {
+    /// Creates a new PuTTY importer.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { custom_path: None }
+    }
+
+    /// Creates a new importer with a custom path.
+    #[must_use]
+    pub const fn with_path(path: PathBuf) -> Self {
+        Self {
+            custom_path: Some(path),
+        }
+    }
+
+    /// Parses the sessions of a registry export, in file order.
+    fn parse_reg_content(content: &str) -> Vec<(String, PuttySession)> {
+        let mut sessions: Vec<(String, PuttySession)> = Vec::new();
+        let mut in_session = false;
+
+        for line in content.lines() {
+            let line = line.trim();
+            if let Some(key) = line.strip_prefix('[') {
+                let key = key.trim_end_matches(']');
+                let name = key
+                    .to_lowercase()
+                    .find(SESSIONS_KEY)
+                    .map(|pos| &key[pos + SESSIONS_KEY.len()..])
+                    .filter(|name| !name.is_empty() && !name.contains('\\'));
+                in_session = !key.starts_with('-') && name.is_some();
+                if let Some(name) = name.filter(|_| in_session) {
+                    sessions.push((decode_session_name(name), PuttySession::new()));
+                }
+            } else if in_session
+                && let Some((key, value)) = parse_reg_value(line)
+                && let Some((_, session)) = sessions.last_mut()
+            {
+                session.insert(key, value);
+            }
+        }
+
+        sessions
+    }
+
+    /// Parses a Unix session file (`Key=Value` lines).
+    fn parse_session_file(content: &str) -> PuttySession {
+        content
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .filter(|(key, _)| !key.trim().is_empty())
+            .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+            .collect()
+    }
+
+    /// Converts a session into a connection.
+    ///
+    /// Returns the reason as an error when the session cannot be imported,
+    /// and any non-fatal notes alongside the connection.
+    fn session_to_connection(
+        name: &str,
+        session: &PuttySession,
+    ) -> Result<(Connection, Vec<String>), String> {
+        let get = |key: &str| {
+            session
+                .get(key)
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+        let get_number = |key: &str| get(key).and_then(|value| value.parse::<u32>().ok());
+        let get_flag = |key: &str| get_number(key).is_some_and(|value| value != 0);
+
+        let protocol = get("Protocol").unwrap_or("ssh").to_lowercase();
+        if protocol != "ssh" {
+            return Err(format!("PuTTY {protocol} sessions are not supported"));
+        }
+
+        let Some(host_name) = get("HostName") else {
+            return Err("No host name specified".to_string());
+        };
+        let (user_from_host, host) = match host_name.rsplit_once('@') {
+            Some((user, host)) => (Some(user.to_string()), host),
+            None => (None, host_name),
+        };
+        let port = get_number("PortNumber")
+            .and_then(|port| u16::try_from(port).ok())
+            .filter(|&port| port != 0)
+            .unwrap_or(22);
+
+        let mut notes = Vec::new();
+        let key_path = get("PublicKeyFile").map(PathBuf::from);
+        if let Some(path) = &key_path
+            && path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("ppk"))
+        {
+            notes.push(format!(
+                "Connection '{name}' uses the PuTTY key {}; convert it with \
+                 `puttygen <key>.ppk -O private-openssh -o <key>`",
+                path.display()
+            ));
+        }
+
+        let port_forwards = get("PortForwardings")
+            .map(|value| value.split(',').filter_map(parse_port_forward).collect())
+            .unwrap_or_default();
+
+        let mut ssh_config = SshConfig {
+            auth_method: if key_path.is_some() {
+                SshAuthMethod::PublicKey
+            } else {
+                SshAuthMethod::Password
+            },
+            key_path,
+            key_source: SshKeySource::Default,
+            agent_key_fingerprint: None,
+            identities_only: false,
+            jump_host_id: None,
+            proxy_jump: None,
+            proxy_command: None,
+            pkcs11_provider: None,
+            jump_hosts: Vec::new(),
+            use_control_master: false,
+            agent_forwarding: get_flag("AgentFwd"),
+            agent_forwarding_acknowledged: false,
+            x11_forwarding: get_flag("X11Forward"),
+            x11_trusted: false,
+            compression: get_flag("Compression"),
+            custom_options: HashMap::new(),
+            startup_command: None,
+            sftp_enabled: false,
+            port_forwards,
+            port_forward_profiles: Vec::new(),
+            waypipe: false,
+            ssh_agent_socket: None,
+            keep_alive_interval: get_number("PingIntervalSecs").filter(|&secs| secs != 0),
+            keep_alive_count_max: None,
+            verbose: false,
+            mptcp: false,
+            remote_path: None,
+        };
+
+        let username = user_from_host.or_else(|| get("UserName").map(str::to_string));
+        let proxy = Self::map_proxy(name, session, &mut ssh_config, &mut notes);
+
+        let mut connection = Connection::new(
+            name.to_string(),
+            host.to_string(),
+            port,
+            ProtocolConfig::Ssh(ssh_config),
+        );
+        connection.username = username;
+        connection.proxy = proxy;
+        connection.tags.push("imported:putty".to_string());
+
+        Ok((connection, notes))
+    }
+
+    /// Maps PuTTY's proxy settings onto the connection.
+    ///
+    /// `ProxyMethod` is 1 for SOCKS4, 2 for SOCKS5, 3 for HTTP, 4 for a
+    /// telnet proxy, 5 for a local command and 6 for an SSH proxy.
+    fn map_proxy(
+        name: &str,
+        session: &PuttySession,
+        ssh_config: &mut SshConfig,
+        notes: &mut Vec<String>,
+    ) -> Option<ProxyConfig> {
+        let get = |key: &str| {
+            session
+                .get(key)
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+        let method = get("ProxyMethod").and_then(|value| value.parse::<u32>().ok())?;
+        let host = get("ProxyHost");
+        let port = get("ProxyPort")
+            .and_then(|value| value.parse::<u16>().ok())
+            .filter(|&port| port != 0);
+        let username = get("ProxyUsername");
+
+        let proxy_type = match method {
+            1 => ProxyType::Socks4,
+            2 => ProxyType::Socks5,
+            3 => ProxyType::Http,
+            5 => {
+                ssh_config.proxy_command = get("ProxyTelnetCommand")
+                    .map(|command| translate_proxy_command(command, host, port));
+                return None;
+            }
+            6 => {
+                ssh_config.proxy_jump = host.map(|host| {
+                    let user = username.map(|u| format!("{u}@")).unwrap_or_default();
+                    match port.filter(|&port| port != 22) {
+                        Some(port) => format!("{user}{host}:{port}"),
+                        None => format!("{user}{host}"),
+                    }
+                });
+                return None;
+            }
+            4 => {
+                notes.push(format!(
+                    "Connection '{name}' uses a telnet proxy, which is not supported; \
+                     it will connect directly"
+                ));
+                return None;
+            }
+            _ => return None,
+        };
+
+        let proxy = ProxyConfig::new(proxy_type, host?, port.unwrap_or(80));
+        if username.is_some() {
+            notes.push(format!(
+                "Connection '{name}': proxy username dropped, SSH connections cannot \
+                 authenticate to a proxy"
+            ));
+        }
+        match proxy.validate() {
+            Ok(()) => Some(proxy),
+            Err(e) => {
+                notes.push(format!("Connection '{name}': proxy ignored ({e})"));
+                None
+            }
+        }
+    }
+
+    /// Imports parsed sessions into `result`, recording skips and notes.
+    fn import_sessions(
+        sessions: Vec<(String, PuttySession)>,
+        location: &Path,
+        result: &mut ImportResult,
+    ) {
+        for (name, session) in sessions {
+            if name == DEFAULT_SESSION {
+                continue;
+            }
+            match Self::session_to_connection(&name, &session) {
+                Ok((connection, notes)) => {
+                    for note in notes {
+                        result.record_warning(note);
+                    }
+                    result.add_connection(connection);
+                }
+                Err(reason) => result.add_skipped(SkippedEntry::with_location(
+                    name,
+                    reason,
+                    location.display().to_string(),
+                )),
+            }
+        }
+    }
+
+    /// Imports a `.reg` export or a single Unix session file.
+    fn import_file(path: &Path, result: &mut ImportResult) -> Result<(), ImportError> {
+        let content = decode_text(&read_import_bytes(path, "PuTTY")?);
+        let sessions = if looks_like_reg(&content) {
+            Self::parse_reg_content(&content)
+        } else {
+            let name = path
+                .file_name()
+                .map(|n| decode_session_name(&n.to_string_lossy()))
+                .unwrap_or_else(|| "Unknown".to_string());
+            vec![(name, Self::parse_session_file(&content))]
+        };
+        Self::import_sessions(sessions, path, result);
+        Ok(())
+    }
+}
+
+impl Default for PuttyImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImportSource for PuttyImporter {
+    fn source_id(&self) -> &'static str {
+        "putty"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "PuTTY"
+    }
+
+    fn is_available(&self) -> bool {
+        !self.default_paths().is_empty()
+    }
+
+    fn default_paths(&self) -> Vec<PathBuf> {
+        if let Some(ref path) = self.custom_path {
+            return vec![path.clone()];
+        }
+
+        dirs::home_dir()
+            .map(|home| home.join(".putty").join("sessions"))
+            .filter(|path| path.is_dir())
+            .into_iter()
+            .collect()
+    }
+
+    fn import(&self) -> Result<ImportResult, ImportError> {
+        let paths = self.default_paths();
+
+        if paths.is_empty() {
+            return Err(ImportError::FileNotFound(PathBuf::from(
+                "No PuTTY sessions found",
+            )));
+        }
+
+        let mut combined_result = ImportResult::new();
+
+        for path in paths {
+            match self.import_from_path(&path) {
+                Ok(result) => combined_result.merge(result),
+                Err(e) => combined_result.add_error(e),
+            }
+        }
+
+        Ok(combined_result)
+    }
+
+    fn import_from_path(&self, path: &Path) -> Result<ImportResult, ImportError> {
+        let mut result = ImportResult::new();
+
+        if path.is_dir() {
+            let entries = std::fs::read_dir(path).map_err(|e| ImportError::ParseError {
+                source_name: "PuTTY".to_string(),
+                reason: format!("Cannot read {}: {}", path.display(), e),
+            })?;
+            let mut files: Vec<PathBuf> = entries
+                .filter_map(std::result::Result::ok)
+                .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+                .map(|entry| entry.path())
+                .filter(|path| path.is_file())
+                .collect();
+            files.sort();
+
+            for file in files {
+                if let Err(e) = Self::import_file(&file, &mut result) {
+                    result.add_error(e);
+                }
+            }
+        } else if path.is_file() {
+            Self::import_file(path, &mut result)?;
+        } else {
+            return Err(ImportError::FileNotFound(path.to_path_buf()));
+        }
+
+        Ok(result)
+    }
+}
+
+/// Decodes file contents, honouring UTF-16 and UTF-8 byte order marks.
+///
+/// Without a BOM the content is read as UTF-8, replacing invalid bytes.
+pub(super) fn decode_text(bytes: &[u8]) -> String {
+    let decode_utf16 = |words: Vec<u16>| String::from_utf16_lossy(&words);
+    match bytes {
+        [0xFF, 0xFE, rest @ ..] => decode_utf16(
+            rest.chunks_exact(2)
+                .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+                .collect(),
+        ),
+        [0xFE, 0xFF, rest @ ..] => decode_utf16(
+            rest.chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect(),
+        ),
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8_lossy(rest).into_owned(),
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+/// Returns `true` if `content` is a registry export rather than a session file.
+fn looks_like_reg(content: &str) -> bool {
+    content.lines().map(str::trim).any(|line| {
+        line.starts_with("Windows Registry Editor")
+            || line == "REGEDIT4"
+            || line.to_lowercase().starts_with("[hkey_")
+    })
+}
+
+/// Decodes PuTTY's `%XX` escaping of session names.
+fn decode_session_name(encoded: &str) -> String {
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| encoded.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Parses a `"Name"=value` registry line.
+///
+/// String values are unescaped and DWORDs converted to decimal; other value
+/// types (binary, multi-string) are not used by sessions and are ignored.
+fn parse_reg_value(line: &str) -> Option<(String, String)> {
+    let (name, rest) = parse_reg_string(line)?;
+    let value = rest.strip_prefix('=')?;
+    if let Some(hex) = value.strip_prefix("dword:") {
+        let number = u32::from_str_radix(hex.trim(), 16).ok()?;
+        return Some((name, number.to_string()));
+    }
+    let (value, _) = parse_reg_string(value)?;
+    Some((name, value))
+}
+
+/// Parses a leading quoted registry string, returning it and the remainder.
+fn parse_reg_string(input: &str) -> Option<(String, &str)> {
+    let body = input.strip_prefix('"')?;
+    let mut value = String::new();
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &body[i + 1..])),
+            '\\' => value.push(chars.next()?.1),
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// Parses one `PortForwardings` entry such as `L8080=intranet:80`.
+///
+/// Entries may carry a `4`/`6` address family prefix and a bind address
+/// (`L127.0.0.1:8080=…`); dynamic forwards have no destination.
+fn parse_port_forward(entry: &str) -> Option<PortForward> {
+    let entry = entry.trim().trim_start_matches(['4', '6']);
+    let mut chars = entry.chars();
+    let kind = chars.next()?;
+    let (source, destination) = chars
+        .as_str()
+        .split_once('=')
+        .unwrap_or((chars.as_str(), ""));
+    let (bind_address, local_port) = match source.rsplit_once(':') {
+        Some((bind, port)) => (
+            Some(bind.trim_start_matches('[').trim_end_matches(']')),
+            port,
+        ),
+        None => (None, source),
+    };
+    let bind_address = bind_address
+        .map(str::trim)
+        .filter(|b| !b.is_empty())
+        .map(str::to_string);
+    let local_port = local_port.trim().parse::<u16>().ok()?;
+
+    let direction = match kind {
+        'L' => PortForwardDirection::Local,
+        'R' => PortForwardDirection::Remote,
+        'D' => {
+            return Some(PortForward {
+                direction: PortForwardDirection::Dynamic,
+                bind_address,
+                local_port,
+                remote_host: String::new(),
+                remote_port: 0,
+            });
+        }
+        _ => return None,
+    };
+    let (remote_host, remote_port) = parse_host_port(destination.trim());
+    Some(PortForward {
+        direction,
+        bind_address,
+        local_port,
+        remote_host,
+        remote_port: remote_port?,
+    })
+}
+
+/// Converts a PuTTY local proxy command to OpenSSH `ProxyCommand` syntax.
+///
+/// `%host`, `%port` and `%user` become `%h`, `%p` and `%r`; the proxy host
+/// and port are substituted directly.
+fn translate_proxy_command(
+    command: &str,
+    proxy_host: Option<&str>,
+    proxy_port: Option<u16>,
+) -> String {
+    command
+        .replace("%proxyhost", proxy_host.unwrap_or_default())
+        .replace(
+            "%proxyport",
+            &proxy_port.map(|p| p.to_string()).unwrap_or_default(),
+        )
+        .replace("%host", "%h")
+        .replace("%port", "%p")
+        .replace("%user", "%r")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_REG: &str = r#"Windows Registry Editor Version 5.00
+
+[HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions\Default%20Settings]
+"HostName"=""
+"Protocol"="ssh"
+
+[HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions\Prod%20Web]
+"HostName"="web.example.com"
+"PortNumber"=dword:00000916
+"Protocol"="ssh"
+"UserName"="deploy"
+"PublicKeyFile"="C:\\Users\\me\\keys\\deploy.ppk"
+"ProxyMethod"=dword:00000002
+"ProxyHost"="socks.example.com"
+"ProxyPort"=dword:00000438
+"Compression"=dword:00000001
+"AgentFwd"=dword:00000001
+"PortForwardings"="L8080=intranet:80,4R2222=localhost:22,D1080"
+
+[HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions\Switch]
+"HostName"="10.0.0.1"
+"PortNumber"=dword:00000017
+"Protocol"="telnet"
+
+[HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\SshHostKeys]
+"ssh-ed25519@22:web.example.com"="0x1234"
+"#;
+
+    fn to_utf16_with_bom(text: &str) -> Vec<u8> {
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+        bytes
+    }
+
+    #[test]
+    fn test_parse_reg_sessions() {
+        let sessions = PuttyImporter::parse_reg_content(SAMPLE_REG);
+        let names: Vec<&str> = sessions.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["Default Settings", "Prod Web", "Switch"]);
+        let prod = &sessions[1].1;
+        assert_eq!(prod["PortNumber"], "2326");
+        assert_eq!(prod["PublicKeyFile"], r"C:\Users\me\keys\deploy.ppk");
+    }
+
+    #[test]
+    fn test_import_utf16_reg_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("putty.reg");
+        std::fs::write(&path, to_utf16_with_bom(&SAMPLE_REG.replace('\n', "\r\n"))).unwrap();
+
+        let result = PuttyImporter::new().import_from_path(&path).unwrap();
+        assert_eq!(result.connections.len(), 1);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].identifier, "Switch");
+        assert!(result.skipped[0].reason.contains("telnet"));
+
+        let conn = &result.connections[0];
+        assert_eq!(conn.name, "Prod Web");
+        assert_eq!(conn.host, "web.example.com");
+        assert_eq!(conn.port, 2326);
+        assert_eq!(conn.username.as_deref(), Some("deploy"));
+        assert_eq!(
+            conn.proxy,
+            Some(ProxyConfig::new(
+                ProxyType::Socks5,
+                "socks.example.com",
+                1080
+            ))
+        );
+        let ProtocolConfig::Ssh(ssh) = &conn.protocol_config else {
+            panic!("expected SSH");
+        };
+        assert_eq!(ssh.auth_method, SshAuthMethod::PublicKey);
+        assert!(ssh.compression);
+        assert!(ssh.agent_forwarding);
+        assert_eq!(ssh.port_forwards.len(), 3);
+        assert_eq!(
+            ssh.port_forwards[0].to_ssh_arg(),
+            ["-L", "8080:intranet:80"]
+        );
+        assert_eq!(
+            ssh.port_forwards[1].to_ssh_arg(),
+            ["-R", "2222:localhost:22"]
+        );
+        assert_eq!(ssh.port_forwards[2].to_ssh_arg(), ["-D", "1080"]);
+        assert!(result.warnings.iter().any(|w| w.contains("puttygen")));
+    }
+
+    #[test]
+    fn test_import_session_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("db%2Dprimary"),
+            "HostName=admin@db.lan\nPortNumber=2222\nProtocol=ssh\nProxyMethod=6\n\
+             ProxyHost=bastion.lan\nProxyUsername=jump\nProxyPort=22\nX11Forward=1\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("console"),
+            "HostName=10.0.0.2\nProtocol=raw\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("Default%20Settings"),
+            "HostName=\nProtocol=ssh\n",
+        )
+        .unwrap();
+
+        let result = PuttyImporter::with_path(dir.path().to_path_buf())
+            .import()
+            .unwrap();
+        assert_eq!(result.connections.len(), 1);
+        assert_eq!(result.skipped.len(), 1);
+        assert_eq!(result.skipped[0].identifier, "console");
+
+        let conn = &result.connections[0];
+        assert_eq!(conn.name, "db-primary");
+        assert_eq!(conn.host, "db.lan");
+        assert_eq!(conn.port, 2222);
+        assert_eq!(conn.username.as_deref(), Some("admin"));
+        let ProtocolConfig::Ssh(ssh) = &conn.protocol_config else {
+            panic!("expected SSH");
+        };
+        assert_eq!(ssh.proxy_jump.as_deref(), Some("jump@bastion.lan"));
+        assert!(ssh.x11_forwarding);
+    }
+
+    #[test]
+    fn test_host_and_port_round_trip() {
+        for (host, port) in [
+            ("example.com", 22_u16),
+            ("10.1.2.3", 2222),
+            ("fe80::1", 65535),
+            ("host-with-dash.internal", 1),
+        ] {
+            let reg = format!(
+                "REGEDIT4\n\n[HKEY_CURRENT_USER\\Software\\SimonTatham\\PuTTY\\Sessions\\s]\n\
+                 \"HostName\"=\"{host}\"\n\"PortNumber\"=dword:{port:08x}\n"
+            );
+            let file = format!("HostName={host}\nPortNumber={port}\n");
+            for session in [
+                PuttyImporter::parse_reg_content(&reg).remove(0).1,
+                PuttyImporter::parse_session_file(&file),
+            ] {
+                let (conn, _) = PuttyImporter::session_to_connection("s", &session).unwrap();
+                assert_eq!((conn.host.as_str(), conn.port), (host, port));
+            }
+        }
+    }
+
+    #[test]
+    fn test_proxy_command_translation() {
+        let mut session = PuttyImporter::parse_session_file(
+            "HostName=h\nProxyMethod=5\nProxyHost=gw\nProxyPort=8080\n\
+             ProxyTelnetCommand=nc -X connect -x %proxyhost:%proxyport %host %port\n",
+        );
+        let (conn, _) = PuttyImporter::session_to_connection("h", &session).unwrap();
+        let ProtocolConfig::Ssh(ssh) = &conn.protocol_config else {
+            panic!("expected SSH");
+        };
+        assert_eq!(
+            ssh.proxy_command.as_deref(),
+            Some("nc -X connect -x gw:8080 %h %p")
+        );
+        assert!(conn.proxy.is_none());
+
+        session.insert("HostName".to_string(), String::new());
+        assert!(PuttyImporter::session_to_connection("h", &session).is_err());
+    }
+
+    #[test]
+    fn test_decode_text_encodings() {
+        assert_eq!(decode_text(&to_utf16_with_bom("Ключ")), "Ключ");
+        assert_eq!(decode_text(b"\xEF\xBB\xBFplain"), "plain");
+        assert_eq!(decode_session_name("Web%20%28prod%29"), "Web (prod)");
+        assert_eq!(decode_session_name("100%"), "100%");
+    }
+}
@@ -358,6 +358,7 @@ impl RdmImporter {
             remember_window_position: false,
             window_geometry: None,
             skip_port_check: false,
+            resolve_via_tailscale: false,
             is_pinned: false,
             pin_order: 0,
             icon: None,
@@ -370,6 +371,13 @@ impl RdmImporter {
             retry_config: None,
             knock_sequence: None,
             spa_config: None,
+            depends_on: Vec::new(),
+            proxy: None,
+            access_tunnel: None,
+            connect_guard: None,
+            env: Vec::new(),
+            osc52_clipboard: None,
+            scrollback_lines: None,
         })
     }
 }
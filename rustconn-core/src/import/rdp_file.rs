@@ -22,7 +22,9 @@ use std::path::Path;
 
 use super::traits::{ImportResult, ImportSource, read_import_file};
 use crate::error::ImportError;
-use crate::models::{Connection, ProtocolConfig, RdpConfig, RdpGateway, Resolution};
+use crate::models::{
+    Connection, PasswordSource, ProtocolConfig, RdpConfig, RdpGateway, Resolution,
+};
 
 /// Parsed contents of an `.rdp` file.
 #[derive(Debug, Default)]
@@ -138,6 +140,8 @@ impl RdpFileImporter {
                     username: fields
                         .get("gatewaycredentialssource")
                         .and_then(|_| username.clone()),
+                    domain: None,
+                    password_source: PasswordSource::None,
                 }
             });
 
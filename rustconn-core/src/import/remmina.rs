@@ -263,14 +263,18 @@ impl RemminaImporter {
                         proxy_jump: None,
                         proxy_command: None,
                         pkcs11_provider: None,
+                        jump_hosts: Vec::new(),
                         use_control_master: false,
                         agent_forwarding,
+                        agent_forwarding_acknowledged: false,
                         x11_forwarding,
+                        x11_trusted: false,
                         compression,
                         custom_options: HashMap::new(),
                         startup_command: None,
                         sftp_enabled: false,
                         port_forwards: Vec::new(),
+                        port_forward_profiles: Vec::new(),
                         waypipe: false,
                         ssh_agent_socket: None,
                         keep_alive_interval: None,
@@ -310,6 +314,8 @@ impl RemminaImporter {
                             hostname: gw_host,
                             port: gw_port.unwrap_or(443),
                             username: gw_username,
+                            domain: None,
+                            password_source: PasswordSource::None,
                         }
                     });
 
@@ -11,6 +11,9 @@
 //!
 //! The directory hierarchy under `Config/Sessions/` maps to connection groups.
 //!
+//! Sessions that tunnel through another session (`S:"Firewall Name"=Session:...`)
+//! get that session as their jump host when both are part of the same import.
+//!
 //! ## Supported formats
 //!
 //! 1. **Session directory** — a folder containing individual `.ini` files
@@ -25,10 +28,14 @@ use uuid::Uuid;
 use super::traits::{ImportResult, ImportSource, SkippedEntry, read_import_file};
 use crate::error::ImportError;
 use crate::models::{
-    Connection, ConnectionGroup, ProtocolConfig, RdpConfig, SshAuthMethod, SshConfig, SshKeySource,
-    TelnetConfig, VncConfig,
+    Connection, ConnectionGroup, ProtocolConfig, RdpConfig, SerialBaudRate, SerialConfig,
+    SerialDataBits, SerialFlowControl, SerialParity, SerialStopBits, SshAuthMethod, SshConfig,
+    SshKeySource, TelnetConfig, VncConfig,
 };
 
+/// Prefix of a `Firewall Name` value that refers to another session
+const FIREWALL_SESSION_PREFIX: &str = "Session:";
+
 /// SecureCRT protocol identifiers as stored in `S:"Protocol Name"`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ScrtProtocol {
@@ -72,6 +79,29 @@ struct ScrtSession {
     compression: bool,
     description: Option<String>,
     emulation: Option<String>,
+    firewall: Option<String>,
+    com_port: Option<String>,
+    baud_rate: Option<u32>,
+    data_bits: Option<u32>,
+    parity: Option<u32>,
+    stop_bits: Option<u32>,
+    cts_flow: bool,
+    xon_flow: bool,
+}
+
+/// A converted session together with the firewall it connects through.
+struct ImportedSession {
+    connection: Connection,
+    firewall: Option<String>,
+}
+
+/// Tracks sessions by path so firewall sessions can become jump hosts.
+#[derive(Default)]
+struct JumpLinks {
+    /// Session path relative to the import root (e.g. `Production/bastion`) → connection ID
+    sessions: HashMap<String, Uuid>,
+    /// Connections with a firewall, paired with the raw `Firewall Name` value
+    pending: Vec<(Uuid, String)>,
 }
 
 /// Importer for SecureCRT session files.
@@ -190,6 +220,30 @@ impl SecureCrtImporter {
                 ("S", "Emulation") if !value.is_empty() => {
                     session.emulation = Some(value.to_string());
                 }
+                ("S", "Firewall Name") if !value.is_empty() && value != "None" => {
+                    session.firewall = Some(value.to_string());
+                }
+                ("S", "Com Port") if !value.is_empty() => {
+                    session.com_port = Some(value.to_string());
+                }
+                ("D", "Baud Rate") => {
+                    session.baud_rate = Self::parse_dword(value);
+                }
+                ("D", "Data Bits") => {
+                    session.data_bits = Self::parse_dword(value);
+                }
+                ("D", "Parity") => {
+                    session.parity = Self::parse_dword(value);
+                }
+                ("D", "Stop Bits") => {
+                    session.stop_bits = Self::parse_dword(value);
+                }
+                ("D", "CTS Flow") => {
+                    session.cts_flow = Self::parse_dword(value) == Some(1);
+                }
+                ("D", "XON Flow") => {
+                    session.xon_flow = Self::parse_dword(value) == Some(1);
+                }
                 _ => {}
             }
         }
@@ -215,6 +269,7 @@ impl SecureCrtImporter {
         // Skip unsupported protocols
         match protocol {
             ScrtProtocol::Rlogin | ScrtProtocol::Raw => return Ok(None),
+            ScrtProtocol::Serial => return Self::serial_connection(session, name).map(Some),
             _ => {}
         }
 
@@ -257,14 +312,18 @@ impl SecureCrtImporter {
                     proxy_jump: None,
                     proxy_command: None,
                     pkcs11_provider: None,
+                    jump_hosts: Vec::new(),
                     use_control_master: false,
                     agent_forwarding: session.agent_forwarding,
+                    agent_forwarding_acknowledged: false,
                     x11_forwarding: session.x11_forwarding,
+                    x11_trusted: false,
                     compression: session.compression,
                     custom_options: HashMap::new(),
                     startup_command: None,
                     sftp_enabled: false,
                     port_forwards: Vec::new(),
+                    port_forward_profiles: Vec::new(),
                     waypipe: false,
                     ssh_agent_socket: None,
                     keep_alive_interval: None,
@@ -328,16 +387,11 @@ impl SecureCrtImporter {
                 conn.description = session.description.clone();
                 conn
             }
-            ScrtProtocol::Serial => {
-                // Serial connections don't have a meaningful host/port for RustConn
-                return Err("Serial connections are not supported".to_string());
-            }
-            ScrtProtocol::Rlogin | ScrtProtocol::Raw => {
-                // Defensive: Rlogin/Raw are already filtered out earlier with
-                // `Ok(None)` (see the protocol guard above). Return Ok(None)
-                // rather than panicking, so a future change to that guard can
-                // never make this branch reachable and crash on a (possibly
-                // imported, untrusted) session file.
+            ScrtProtocol::Serial | ScrtProtocol::Rlogin | ScrtProtocol::Raw => {
+                // Defensive: these are already handled by the protocol guard
+                // above. Return Ok(None) rather than panicking, so a future
+                // change to that guard can never make this branch reachable
+                // and crash on a (possibly imported, untrusted) session file.
                 return Ok(None);
             }
         };
@@ -345,13 +399,126 @@ impl SecureCrtImporter {
         Ok(Some(connection))
     }
 
+    /// Converts a parsed serial session into a serial Connection.
+    ///
+    /// Windows `COMn` ports are mapped to `/dev/ttyS{n-1}`; other device
+    /// paths are kept as-is. Line settings RustConn cannot represent (e.g.
+    /// mark/space parity or 1.5 stop bits) fall back to the defaults.
+    fn serial_connection(session: &ScrtSession, name: &str) -> Result<Connection, String> {
+        let Some(com_port) = session.com_port.as_deref() else {
+            return Err("No serial port specified".to_string());
+        };
+
+        let device = com_port
+            .strip_prefix("COM")
+            .and_then(|n| n.parse::<u32>().ok())
+            .filter(|&n| n > 0)
+            .map_or_else(|| com_port.to_string(), |n| format!("/dev/ttyS{}", n - 1));
+
+        let baud_rate = session
+            .baud_rate
+            .and_then(|rate| {
+                SerialBaudRate::all()
+                    .iter()
+                    .copied()
+                    .find(|b| b.value() == rate)
+            })
+            .unwrap_or_default();
+        let data_bits = match session.data_bits {
+            Some(5) => SerialDataBits::Five,
+            Some(6) => SerialDataBits::Six,
+            Some(7) => SerialDataBits::Seven,
+            _ => SerialDataBits::Eight,
+        };
+        let parity = match session.parity {
+            Some(1) => SerialParity::Odd,
+            Some(2) => SerialParity::Even,
+            _ => SerialParity::None,
+        };
+        let stop_bits = match session.stop_bits {
+            Some(2) => SerialStopBits::Two,
+            _ => SerialStopBits::One,
+        };
+        let flow_control = if session.cts_flow {
+            SerialFlowControl::Hardware
+        } else if session.xon_flow {
+            SerialFlowControl::Software
+        } else {
+            SerialFlowControl::None
+        };
+
+        let serial_config = SerialConfig {
+            device,
+            baud_rate,
+            data_bits,
+            stop_bits,
+            parity,
+            flow_control,
+            ..SerialConfig::default()
+        };
+        let mut conn = Connection::new(
+            name.to_string(),
+            String::new(),
+            0,
+            ProtocolConfig::Serial(serial_config),
+        );
+        conn.description = session.description.clone();
+        Ok(conn)
+    }
+
+    /// Builds the lookup key for a session file relative to the import root.
+    fn session_key(root: &Path, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(root).ok()?.with_extension("");
+        let parts: Vec<_> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect();
+        Some(parts.join("/"))
+    }
+
+    /// Links connections to their firewall sessions as jump hosts.
+    ///
+    /// Firewalls that are not sessions from this import (global firewall
+    /// definitions or sessions outside the imported tree) are reported as
+    /// warnings.
+    fn resolve_jump_hosts(links: &JumpLinks, result: &mut ImportResult) {
+        let mut warnings = Vec::new();
+        for (conn_id, firewall) in &links.pending {
+            let Some(conn) = result.connections.iter_mut().find(|c| c.id == *conn_id) else {
+                continue;
+            };
+
+            let jump_id = firewall
+                .strip_prefix(FIREWALL_SESSION_PREFIX)
+                .and_then(|path| {
+                    let key = path.replace('\\', "/");
+                    links.sessions.get(key.trim_matches('/')).copied()
+                });
+
+            match (jump_id, &mut conn.protocol_config) {
+                (Some(jump_id), ProtocolConfig::Ssh(ssh)) if jump_id != *conn_id => {
+                    ssh.jump_host_id = Some(jump_id);
+                }
+                _ => warnings.push(format!(
+                    "{}: firewall '{firewall}' was not imported as a jump host",
+                    conn.name
+                )),
+            }
+        }
+        for warning in warnings {
+            result.record_warning(warning);
+        }
+    }
+
     /// Recursively imports sessions from a directory tree.
     fn import_directory(
         &self,
+        root: &Path,
         dir: &Path,
         group_id: Option<Uuid>,
         result: &mut ImportResult,
         groups: &mut HashMap<PathBuf, Uuid>,
+        links: &mut JumpLinks,
     ) {
         let entries = match std::fs::read_dir(dir) {
             Ok(entries) => entries,
@@ -380,7 +547,7 @@ impl SecureCrtImporter {
                 result.add_group(group);
 
                 // Recurse into subdirectory
-                self.import_directory(&path, Some(new_group_id), result, groups);
+                self.import_directory(root, &path, Some(new_group_id), result, groups, links);
             } else if path.extension().is_some_and(|ext| ext == "ini") {
                 // Skip Default.ini if it has no hostname (it's a template)
                 let session_name = name.trim_end_matches(".ini");
@@ -391,8 +558,15 @@ impl SecureCrtImporter {
                 }
 
                 match self.import_single_ini(&path, session_name, group_id) {
-                    Ok(Some(connection)) => {
-                        result.add_connection(connection);
+                    Ok(Some(imported)) => {
+                        let conn_id = imported.connection.id;
+                        if let Some(key) = Self::session_key(root, &path) {
+                            links.sessions.insert(key, conn_id);
+                        }
+                        if let Some(firewall) = imported.firewall {
+                            links.pending.push((conn_id, firewall));
+                        }
+                        result.add_connection(imported.connection);
                     }
                     Ok(None) => {
                         // Unsupported protocol, silently skip
@@ -419,17 +593,19 @@ impl SecureCrtImporter {
         path: &Path,
         name: &str,
         group_id: Option<Uuid>,
-    ) -> Result<Option<Connection>, String> {
+    ) -> Result<Option<ImportedSession>, String> {
         let content = read_import_file(path, "SecureCRT").map_err(|e| e.to_string())?;
         let session = Self::parse_ini_content(&content);
-        let mut connection = Self::session_to_connection(&session, name)?;
+        let connection = Self::session_to_connection(&session, name)?;
 
-        if let Some(ref mut conn) = connection {
+        Ok(connection.map(|mut conn| {
             conn.group_id = group_id;
             conn.tags.push("imported:securecrt".to_string());
-        }
-
-        Ok(connection)
+            ImportedSession {
+                connection: conn,
+                firewall: session.firewall,
+            }
+        }))
     }
 }
 
@@ -508,7 +684,9 @@ impl ImportSource for SecureCrtImporter {
         if path.is_dir() {
             let mut result = ImportResult::new();
             let mut groups = HashMap::new();
-            self.import_directory(path, None, &mut result, &mut groups);
+            let mut links = JumpLinks::default();
+            self.import_directory(path, path, None, &mut result, &mut groups, &mut links);
+            Self::resolve_jump_hosts(&links, &mut result);
             Ok(result)
         } else if path.is_file() {
             // Single .ini file import
@@ -519,8 +697,13 @@ impl ImportSource for SecureCrtImporter {
 
             let mut result = ImportResult::new();
             match self.import_single_ini(path, &file_name, None) {
-                Ok(Some(connection)) => {
-                    result.add_connection(connection);
+                Ok(Some(imported)) => {
+                    let mut links = JumpLinks::default();
+                    if let Some(firewall) = imported.firewall {
+                        links.pending.push((imported.connection.id, firewall));
+                    }
+                    result.add_connection(imported.connection);
+                    Self::resolve_jump_hosts(&links, &mut result);
                 }
                 Ok(None) => {}
                 Err(reason) => {
@@ -767,4 +950,128 @@ S:"Description"=Line one\rLine two\rLine three
             Some("Line one\nLine two\nLine three")
         );
     }
+
+    const SAMPLE_SERIAL_SESSION: &str = r#"D:"Is Session"=00000001
+S:"Protocol Name"=Serial
+S:"Com Port"=COM3
+D:"Baud Rate"=00002580
+D:"Data Bits"=00000007
+D:"Parity"=00000002
+D:"Stop Bits"=00000002
+D:"CTS Flow"=00000001
+D:"XON Flow"=00000000
+"#;
+
+    #[test]
+    fn test_session_to_connection_serial() {
+        let session = SecureCrtImporter::parse_ini_content(SAMPLE_SERIAL_SESSION);
+        let conn = SecureCrtImporter::session_to_connection(&session, "Console")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(conn.name, "Console");
+        let ProtocolConfig::Serial(serial) = &conn.protocol_config else {
+            panic!("Expected serial config");
+        };
+        assert_eq!(serial.device, "/dev/ttyS2");
+        assert_eq!(serial.baud_rate, SerialBaudRate::B9600);
+        assert_eq!(serial.data_bits, SerialDataBits::Seven);
+        assert_eq!(serial.parity, SerialParity::Even);
+        assert_eq!(serial.stop_bits, SerialStopBits::Two);
+        assert_eq!(serial.flow_control, SerialFlowControl::Hardware);
+    }
+
+    #[test]
+    fn test_serial_device_path_kept() {
+        let content = r#"S:"Protocol Name"=Serial
+S:"Com Port"=/dev/ttyUSB0
+D:"Baud Rate"=0001c200
+"#;
+        let session = SecureCrtImporter::parse_ini_content(content);
+        let conn = SecureCrtImporter::session_to_connection(&session, "USB")
+            .unwrap()
+            .unwrap();
+
+        let ProtocolConfig::Serial(serial) = &conn.protocol_config else {
+            panic!("Expected serial config");
+        };
+        assert_eq!(serial.device, "/dev/ttyUSB0");
+        assert_eq!(serial.baud_rate, SerialBaudRate::B115200);
+        assert_eq!(serial.parity, SerialParity::None);
+    }
+
+    #[test]
+    fn test_serial_without_port_skipped() {
+        let session = SecureCrtImporter::parse_ini_content(r#"S:"Protocol Name"=Serial"#);
+        let result = SecureCrtImporter::session_to_connection(&session, "Console");
+        assert!(result.unwrap_err().contains("No serial port"));
+    }
+
+    #[test]
+    fn test_unknown_protocol_skipped_with_reason() {
+        use std::io::Write;
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("Weird.ini");
+        let mut file = std::fs::File::create(&file_path).unwrap();
+        write!(
+            file,
+            "S:\"Protocol Name\"=Carrier Pigeon\nS:\"Hostname\"=coop\n"
+        )
+        .unwrap();
+
+        let result = SecureCrtImporter::new()
+            .import_from_path(&file_path)
+            .unwrap();
+        assert!(result.connections.is_empty());
+        assert_eq!(result.skipped.len(), 1);
+        assert!(result.skipped[0].reason.contains("Unknown protocol"));
+    }
+
+    #[test]
+    fn test_firewall_session_becomes_jump_host() {
+        use std::io::Write;
+        let dir = tempfile::tempdir().unwrap();
+        let sessions_dir = dir.path().join("Sessions");
+        let infra_dir = sessions_dir.join("Infra");
+        std::fs::create_dir_all(&infra_dir).unwrap();
+
+        let mut f = std::fs::File::create(infra_dir.join("bastion.ini")).unwrap();
+        write!(
+            f,
+            "S:\"Protocol Name\"=SSH2\nS:\"Hostname\"=bastion.example.com\n"
+        )
+        .unwrap();
+
+        let mut f = std::fs::File::create(sessions_dir.join("app.ini")).unwrap();
+        write!(
+            f,
+            "S:\"Protocol Name\"=SSH2\nS:\"Hostname\"=10.1.0.5\nS:\"Firewall Name\"=Session:Infra\\bastion\n"
+        )
+        .unwrap();
+
+        let mut f = std::fs::File::create(sessions_dir.join("legacy.ini")).unwrap();
+        write!(
+            f,
+            "S:\"Protocol Name\"=SSH2\nS:\"Hostname\"=10.1.0.6\nS:\"Firewall Name\"=Corporate SOCKS\n"
+        )
+        .unwrap();
+
+        let result = SecureCrtImporter::new()
+            .import_from_path(&sessions_dir)
+            .unwrap();
+        let bastion = result
+            .connections
+            .iter()
+            .find(|c| c.name == "bastion")
+            .unwrap();
+        let app = result.connections.iter().find(|c| c.name == "app").unwrap();
+
+        let ProtocolConfig::Ssh(ssh) = &app.protocol_config else {
+            panic!("Expected SSH config");
+        };
+        assert_eq!(ssh.jump_host_id, Some(bastion.id));
+
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("Corporate SOCKS"));
+    }
 }
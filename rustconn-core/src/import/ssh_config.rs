@@ -137,8 +137,14 @@ impl SshConfigImporter {
                 current_host = Some(value.to_string());
                 current_options.clear();
             } else if current_host.is_some() {
-                // Add option to current host
-                current_options.insert(key_lower, value.to_string());
+                // Add option to current host. ProxyCommand is handed to the
+                // shell verbatim by OpenSSH, so its quotes are kept
+                let value = if key_lower == "proxycommand" {
+                    value.to_string()
+                } else {
+                    Self::unquote(value)
+                };
+                current_options.insert(key_lower, value);
             }
         }
 
@@ -235,26 +241,38 @@ impl SshConfigImporter {
 
     /// Parses a single line into key-value pair
     fn parse_line(line: &str) -> Option<(&str, &str)> {
-        // SSH config supports both "Key Value" and "Key=Value" formats
-        if let Some(eq_pos) = line.find('=') {
-            let key = line[..eq_pos].trim();
-            let value = line[eq_pos + 1..].trim();
-            if !key.is_empty() && !value.is_empty() {
-                return Some((key, value));
-            }
+        // SSH config supports "Key Value", "Key=Value" and "Key = Value";
+        // only the first separator counts, so values may contain '='
+        let key_end = line.find(|c: char| c.is_whitespace() || c == '=')?;
+        let key = &line[..key_end];
+        let rest = line[key_end..].trim_start();
+        let value = rest.strip_prefix('=').unwrap_or(rest).trim();
+        if key.is_empty() || value.is_empty() {
+            return None;
         }
+        Some((key, value))
+    }
 
-        // Space-separated format
-        let parts: Vec<&str> = line.splitn(2, char::is_whitespace).collect();
-        if parts.len() == 2 {
-            let key = parts[0].trim();
-            let value = parts[1].trim();
-            if !key.is_empty() && !value.is_empty() {
-                return Some((key, value));
+    /// Removes the double quotes around a value, as written by
+    /// [`SshConfigExporter`](crate::export::SshConfigExporter) for values with
+    /// spaces or special characters, and resolves `\"` and `\\` escapes
+    fn unquote(value: &str) -> String {
+        let Some(inner) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) else {
+            return value.to_string();
+        };
+        let mut unquoted = String::with_capacity(inner.len());
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            if c == '\\'
+                && let Some(next @ ('"' | '\\')) = chars.clone().next()
+            {
+                unquoted.push(next);
+                chars.next();
+            } else {
+                unquoted.push(c);
             }
         }
-
-        None
+        unquoted
     }
 
     /// Processes a host entry and adds it to the result
@@ -318,6 +336,12 @@ impl SshConfigImporter {
             .get("forwardx11")
             .is_some_and(|v| v.to_lowercase() == "yes");
 
+        // Check for trusted X11 forwarding option
+        let x11_trusted = x11_forwarding
+            && options
+                .get("forwardx11trusted")
+                .is_some_and(|v| v.to_lowercase() == "yes");
+
         // Check for compression option (also stored in custom_options for CLI args)
         let compression = options
             .get("compression")
@@ -334,16 +358,20 @@ impl SshConfigImporter {
             proxy_jump: options.get("proxyjump").cloned(),
             proxy_command: options.get("proxycommand").cloned(),
             pkcs11_provider: options.get("pkcs11provider").cloned(),
+            jump_hosts: Vec::new(),
             use_control_master: options
                 .get("controlmaster")
                 .is_some_and(|v| v.to_lowercase() == "auto" || v.to_lowercase() == "yes"),
             agent_forwarding,
+            agent_forwarding_acknowledged: false,
             x11_forwarding,
+            x11_trusted,
             compression,
             custom_options: self.extract_recognized_options(options),
             startup_command: None,
             sftp_enabled: false,
             port_forwards: Vec::new(),
+            port_forward_profiles: Vec::new(),
             waypipe: false,
             ssh_agent_socket: None,
             keep_alive_interval: options
@@ -693,6 +721,28 @@ Host bastion
         }
     }
 
+    #[test]
+    fn test_parse_forward_x11_trusted() {
+        let importer = SshConfigImporter::new();
+        let config = r"
+Host desktop
+    HostName desktop.example.com
+    ForwardX11 yes
+    ForwardX11Trusted yes
+";
+
+        let result = importer.parse_config(config, "test");
+        assert_eq!(result.connections.len(), 1);
+
+        let conn = &result.connections[0];
+        if let ProtocolConfig::Ssh(ssh_config) = &conn.protocol_config {
+            assert!(ssh_config.x11_forwarding);
+            assert!(ssh_config.x11_trusted);
+        } else {
+            panic!("Expected SSH config");
+        }
+    }
+
     #[test]
     fn test_include_directive_expands_files() {
         // Main config Includes another file via an absolute path; both the
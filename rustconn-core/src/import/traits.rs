@@ -9,6 +9,7 @@ use std::path::Path;
 
 use uuid::Uuid;
 
+use super::preview::{ImportPreview, MergeStrategy};
 use crate::error::ImportError;
 use crate::models::{Connection, ConnectionGroup, Credentials, SmartFolder, Snippet};
 use crate::progress::ProgressReporter;
@@ -37,6 +38,15 @@ const MAX_IMPORT_FILE_SIZE: u64 = 50 * 1024 * 1024;
 /// let content = read_import_file(path, "SSH config")?;
 /// ```
 pub(super) fn read_import_file(path: &Path, source_name: &str) -> Result<String, ImportError> {
+    check_import_file_size(path, source_name)?;
+    fs::read_to_string(path).map_err(|e| ImportError::ParseError {
+        source_name: source_name.to_string(),
+        reason: format!("Failed to read {}: {}", path.display(), e),
+    })
+}
+
+/// Fails if `path` cannot be inspected or exceeds the import size limit.
+fn check_import_file_size(path: &Path, source_name: &str) -> Result<(), ImportError> {
     let metadata = fs::metadata(path).map_err(|e| ImportError::ParseError {
         source_name: source_name.to_string(),
         reason: format!("Cannot read {}: {}", path.display(), e),
@@ -53,7 +63,20 @@ pub(super) fn read_import_file(path: &Path, source_name: &str) -> Result<String,
         });
     }
 
-    fs::read_to_string(path).map_err(|e| ImportError::ParseError {
+    Ok(())
+}
+
+/// Reads a file for import without requiring UTF-8 content.
+///
+/// Same size limit and error messages as [`read_import_file`]; used for
+/// formats such as Windows `.reg` exports that may be UTF-16 encoded.
+///
+/// # Errors
+/// Returns `ImportError::ParseError` if the file cannot be read or exceeds the
+/// size limit.
+pub(super) fn read_import_bytes(path: &Path, source_name: &str) -> Result<Vec<u8>, ImportError> {
+    check_import_file_size(path, source_name)?;
+    fs::read(path).map_err(|e| ImportError::ParseError {
         source_name: source_name.to_string(),
         reason: format!("Failed to read {}: {}", path.display(), e),
     })
@@ -469,6 +492,57 @@ pub trait ImportSource: Send + Sync {
     /// Returns an error if the import fails completely.
     fn import_from_path(&self, path: &std::path::Path) -> Result<ImportResult, ImportError>;
 
+    /// Parses the source without importing anything (dry run)
+    ///
+    /// Returns what [`Self::import`] would add, matched against
+    /// `existing_connections` and `existing_groups` using `strategy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the import fails completely.
+    fn preview(
+        &self,
+        existing_connections: &[Connection],
+        existing_groups: &[ConnectionGroup],
+        strategy: MergeStrategy,
+    ) -> Result<ImportPreview, ImportError> {
+        let result = self.import()?;
+        Ok(ImportPreview::from_result(
+            &result,
+            existing_connections,
+            existing_groups,
+            strategy,
+            self.source_id(),
+            self.display_name(),
+        ))
+    }
+
+    /// Parses a specific path without importing anything (dry run)
+    ///
+    /// Returns what [`Self::import_from_path`] would add, matched against
+    /// `existing_connections` and `existing_groups` using `strategy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the import fails completely.
+    fn preview_from_path(
+        &self,
+        path: &std::path::Path,
+        existing_connections: &[Connection],
+        existing_groups: &[ConnectionGroup],
+        strategy: MergeStrategy,
+    ) -> Result<ImportPreview, ImportError> {
+        let result = self.import_from_path(path)?;
+        Ok(ImportPreview::from_result(
+            &result,
+            existing_connections,
+            existing_groups,
+            strategy,
+            self.source_id(),
+            path.display().to_string(),
+        ))
+    }
+
     /// Imports connections from a specific path with progress reporting.
     ///
     /// This method allows callers to receive progress updates during the import
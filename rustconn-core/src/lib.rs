@@ -111,10 +111,11 @@ pub mod vnc_client;
 
 pub use activity_monitor::{ActivityMonitorConfig, ActivityMonitorDefaults, MonitorMode};
 pub use automation::{
-    AutomationTemplate, CompiledRule, ConnectionTask, ExpectEngine, ExpectError, ExpectResult,
-    ExpectRule, FolderConnectionTracker, KeyElement, KeySequence, KeySequenceError,
-    KeySequenceResult, SpecialKey, TaskCondition, TaskError, TaskExecutor, TaskResult, TaskTiming,
-    builtin_templates, templates_for_protocol,
+    AutomationTemplate, ChainStep, CompiledRule, ConnectionTask, DependencyOutcome, ExpectEngine,
+    ExpectError, ExpectOutcome, ExpectResult, ExpectRule, FolderConnectionTracker, KeyElement,
+    KeySequence, KeySequenceError, KeySequenceRecorder, KeySequenceResult, KeySink, SpecialKey,
+    TaskClock, TaskCondition, TaskDependency, TaskError, TaskExecutor, TaskOutcome, TaskResult,
+    TaskTiming, TokioClock, builtin_templates, templates_for_protocol,
 };
 pub use busy::{BusyGuard, BusyStack};
 pub use cache::{CacheRef, Cached, DEFAULT_CACHE_TTL_SECS, LoadCacheObject};
@@ -127,8 +128,8 @@ pub use cli_download::{
     uninstall_component,
 };
 pub use cluster::{
-    Cluster, ClusterError, ClusterManager, ClusterMemberState, ClusterResult, ClusterSession,
-    ClusterSessionStatus, ClusterSessionSummary,
+    BroadcastTarget, Cluster, ClusterError, ClusterManager, ClusterMemberState, ClusterResult,
+    ClusterSession, ClusterSessionStatus, ClusterSessionSummary,
 };
 pub use config::{
     AppSettings, ConfigManager, ConnectionSettings, KeybindingCategory, KeybindingDef,
@@ -136,15 +137,18 @@ pub use config::{
     default_passthrough_exceptions, is_valid_accelerator,
 };
 pub use connection::{
-    ConnectionManager, LazyGroupLoader, PortCheckError, PortCheckResult, RetryConfig, RetryState,
-    SelectionState, check_interning_stats, check_port, check_port_async, get_interning_stats,
-    intern_connection_strings, intern_hostname, intern_protocol_name, intern_username,
-    log_interning_stats, log_interning_stats_with_warning, looks_like_password_prompt,
+    BulkEdit, ConnectionHealth, ConnectionManager, ConnectionSortKey, HealthCheckHandle,
+    HealthCheckScheduler, HealthProbeConfig, LazyGroupLoader, PortCheckError, PortCheckResult,
+    RetryConfig, RetryState, SelectionState, check_interning_stats, check_port, check_port_async,
+    get_interning_stats, intern_connection_strings, intern_hostname, intern_protocol_name,
+    intern_username, log_interning_stats, log_interning_stats_with_warning,
+    looks_like_password_prompt,
 };
 pub use display_geometry::{DesktopRequest, desktop_request_for_area};
 pub use document::{
-    DOCUMENT_FORMAT_VERSION, Document, DocumentError, DocumentManager, DocumentResult,
-    EncryptionStrength,
+    DOCUMENT_FORMAT_VERSION, Document, DocumentCompression, DocumentError, DocumentImportReport,
+    DocumentManager, DocumentMergeReport, DocumentResult, EncryptionStrength, MergeConflict,
+    MergeItem, NameCollision,
 };
 pub use drag_drop::{
     DropConfig, DropPosition, ItemType, calculate_drop_position, calculate_indicator_y,
@@ -178,14 +182,15 @@ pub use import::{
 pub use models::{
     Connection, ConnectionGroup, ConnectionHistoryEntry, ConnectionStatistics, ConnectionTemplate,
     Credentials, CustomProperty, DynamicConnectionEntry, DynamicFolderConfig, DynamicFolderResult,
-    HighlightRule, HistorySettings, KubernetesConfig, MoshConfig, MoshPredictMode, PasswordSource,
-    PortForward, PortForwardDirection, PropertyType, ProtocolConfig, ProtocolType, RdpConfig,
-    RdpGateway, Resolution, ScaleOverride, SerialBaudRate, SerialConfig, SerialDataBits,
-    SerialFlowControl, SerialParity, SerialStopBits, Snippet, SnippetTarget, SnippetVariable,
+    HighlightRule, HistorySettings, JumpHost, KubernetesConfig, MoshConfig, MoshPredictMode,
+    PasswordSource, PortForward, PortForwardDirection, PortForwardProfile, PropertyType,
+    ProtocolConfig, ProtocolType, RdpConfig, RdpGateway, Resolution, ScaleOverride, SerialBaudRate,
+    SerialCaptureFormat, SerialConfig, SerialDataBits, SerialDeviceMatch, SerialFlowControl,
+    SerialParity, SerialStopBits, Snippet, SnippetTarget, SnippetVariable, SnippetVariableKind,
     SpiceConfig, SpiceImageCompression, SshAuthMethod, SshConfig, SshKeySource, StandaloneTunnel,
     TelnetBackspaceSends, TelnetConfig, TelnetDeleteSends, TemplateError, TunnelStatus, VncConfig,
-    WindowGeometry, WindowMode, WorkspaceEntry, WorkspaceProfile, WorkspaceSplitLayout,
-    collect_descendant_group_ids, group_templates_by_protocol,
+    VncRepeater, WindowGeometry, WindowMode, WorkspaceEntry, WorkspaceProfile,
+    WorkspaceSplitLayout, collect_descendant_group_ids, group_templates_by_protocol,
 };
 pub use monitoring::{
     CollectorHandle, CpuSnapshot, DiskMetrics, LoadAverage, METRICS_COMMAND, MemoryMetrics,
@@ -195,23 +200,28 @@ pub use monitoring::{
     close_dead_control_sockets, ssh_control_path, ssh_exec_factory, start_collector,
 };
 pub use password_generator::{
-    CharacterSet, PasswordGenerator, PasswordGeneratorConfig, PasswordGeneratorError,
-    PasswordGeneratorResult, PasswordStrength, estimate_crack_time,
+    CharacterSet, GenerationMode, MIN_PASSPHRASE_WORDS, MIN_PRONOUNCEABLE_LENGTH, PasswordFeedback,
+    PasswordGenerator, PasswordGeneratorConfig, PasswordGeneratorError, PasswordGeneratorResult,
+    PasswordStrength, PasswordSuggestion, RECOMMENDED_PASSWORD_LENGTH, analyze_password,
+    diceware_wordlist, estimate_crack_time,
+};
+pub use performance::{
+    Debouncer, InternerStats, ObjectPool, PoolStats, Poolable, StringInterner, interner,
 };
-pub use performance::{Debouncer, InternerStats, StringInterner, interner};
 pub use progress::{
     CallbackProgressReporter, CancelHandle, LocalProgressReporter, NoOpProgressReporter,
     ProgressReporter,
 };
 pub use protocol::{
-    ClientDetectionResult, ClientInfo, CloudProvider, FreeRdpConfig, KubernetesProtocol,
-    MoshProtocol, PROTOCOL_TAB_CSS_CLASSES, Protocol, ProtocolCapabilities, ProtocolRegistry,
-    ProviderIconCache, RdpProtocol, SerialProtocol, SftpProtocol, SpiceProtocol, SshProtocol,
-    TelnetProtocol, VncProtocol, build_freerdp_args, detect_aws_cli, detect_azure_cli,
-    detect_boundary, detect_cloudflared, detect_gcloud_cli, detect_hoop, detect_kubectl,
-    detect_mosh, detect_oci_cli, detect_picocom, detect_provider, detect_rdp_client,
-    detect_ssh_client, detect_tailscale, detect_teleport, detect_telnet_client, detect_vnc_client,
-    extract_geometry_from_args, get_protocol_color_rgb, get_protocol_icon,
+    ClientDetectionResult, ClientInfo, CloudProvider, FreeRdpConfig, FreeRdpGateway,
+    KubeConfigContexts, KubeContext, KubeTarget, KubernetesProtocol, MoshProtocol,
+    PROTOCOL_TAB_CSS_CLASSES, Protocol, ProtocolCapabilities, ProtocolRegistry, ProviderIconCache,
+    RdpProtocol, SerialDeviceResolver, SerialProtocol, SftpProtocol, SpiceProtocol, SshProtocol,
+    TelnetProtocol, VncProtocol, build_freerdp_args, build_freerdp_secret_args, detect_aws_cli,
+    detect_azure_cli, detect_boundary, detect_cloudflared, detect_gcloud_cli, detect_hoop,
+    detect_kubectl, detect_mosh, detect_oci_cli, detect_picocom, detect_provider,
+    detect_rdp_client, detect_ssh_client, detect_tailscale, detect_teleport, detect_telnet_client,
+    detect_vnc_client, extract_geometry_from_args, get_protocol_color_rgb, get_protocol_icon,
     get_protocol_icon_by_name, get_protocol_tab_css_class, get_zero_trust_provider_icon,
     has_decorations_flag,
 };
@@ -240,6 +250,8 @@ pub use rdp_client::{
         STANDARD_RESOLUTIONS,
         ctrl_alt_del_sequence,
         find_best_standard_resolution,
+        find_best_standard_resolutions,
+        fits_rdp_limits,
         generate_resize_request,
         is_modifier_keyval,
         is_printable_keyval,
@@ -255,9 +267,11 @@ pub use search::cache::SearchCache;
 pub use search::command_palette::{
     CommandPaletteAction, PaletteItem, PaletteMode, builtin_commands, parse_palette_input,
 };
+pub use search::incremental::IncrementalSearch;
+pub use search::saved::SavedSearch;
 pub use search::{
     ConnectionSearchResult, DebouncedSearchEngine, MatchHighlight, SearchEngine, SearchError,
-    SearchFilter, SearchQuery, SearchResult, benchmark,
+    SearchFilter, SearchMode, SearchQuery, SearchResult, benchmark,
 };
 // Host keyring backends are compiled only when explicitly requested. The
 // headless default keeps DBus/macOS Security.framework out of rustconn-core.
@@ -275,18 +289,21 @@ pub use session::{
     SessionState, SessionType,
 };
 pub use sftp::{
-    build_mc_sftp_command, build_sftp_browser_uri, build_sftp_command, build_sftp_uri,
-    build_sftp_uri_from_connection, ensure_key_in_agent, get_downloads_dir, get_ssh_key_path,
-    resolve_remote_home,
+    DownloadsLocation, ResumePlan, SftpTransfer, SftpTransferOptions, TransferDirection,
+    build_mc_sftp_command, build_mc_sftp_command_in, build_remote_sha256_command,
+    build_sftp_browser_uri, build_sftp_command, build_sftp_transfer_command, build_sftp_uri,
+    build_sftp_uri_from_connection, download_resume_plan, downloads_location, ensure_key_in_agent,
+    get_downloads_dir, get_ssh_key_path, local_sha256, parse_sha256sum_output, resolve_remote_home,
+    resume_plan, verify_sha256,
 };
 pub use snap::{
     get_config_dir, get_confinement_message, get_data_dir, get_known_hosts_path, get_ssh_dir,
     is_interface_connected, is_sandboxed, is_snap,
 };
-pub use snippet::SnippetManager;
+pub use snippet::{SnippetError, SnippetManager};
 pub use spice_client::{
     SpiceClientConfig, SpiceClientError, SpiceCompression, SpiceSecurityProtocol,
-    SpiceSharedFolder, build_spice_viewer_args, detect_spice_viewer,
+    SpiceSharedFolder, SpiceUsbFilter, build_spice_viewer_args, detect_spice_viewer,
 };
 // Split view types (tab-scoped layouts)
 pub use split::SplitDirection;
@@ -295,8 +312,8 @@ pub use split::{
     SplitLayoutModel, SplitNode, TabGroupManager, TabId,
 };
 pub use ssh_agent::{
-    AgentError, AgentKey, AgentResult, AgentStatus, SshAgentManager, parse_agent_output,
-    parse_key_list,
+    AddKeyOptions, AgentError, AgentKey, AgentResult, AgentStatus, SshAgentManager,
+    parse_agent_output, parse_key_list,
 };
 pub use sync::{
     Inventory, InventoryEntry, SYNC_TAG_PREFIX, SyncResult, default_port_for_protocol,
@@ -308,21 +325,24 @@ pub use template::{
 };
 pub use testing::{
     ConnectionTester, DEFAULT_CONCURRENCY, DEFAULT_TEST_TIMEOUT_SECS, TestError, TestResult,
-    TestSummary,
+    TestSummary, UdpPortState,
 };
-pub use tracing::span_names;
+pub use tracing::{LogFormat, field_names, span_names};
 pub use variables::{
     Variable, VariableError, VariableManager, VariableResult, VariableScope,
     variable_kdbx_lookup_key, variable_secret_key,
 };
-pub use vnc_client::is_embedded_vnc_available;
 #[cfg(feature = "vnc-embedded")]
 pub use vnc_client::{
     VncClient, VncClientCommand, VncClientConfig, VncClientError, VncClientEvent, VncCommandSender,
     VncEventReceiver, VncRect,
 };
+pub use vnc_client::{VncScalingMode, VncViewTransform, is_embedded_vnc_available};
 pub use wol::{
-    DEFAULT_BROADCAST_ADDRESS, DEFAULT_WOL_PORT, DEFAULT_WOL_WAIT_SECONDS, MAGIC_PACKET_SIZE,
-    MacAddress, WolConfig, WolError, WolResult, generate_magic_packet, send_magic_packet, send_wol,
+    DEFAULT_BROADCAST_ADDRESS, DEFAULT_IPV6_MULTICAST_ADDRESS, DEFAULT_WOL_ONLINE_TIMEOUT_SECONDS,
+    DEFAULT_WOL_POLL_INTERVAL_SECONDS, DEFAULT_WOL_PORT, DEFAULT_WOL_WAIT_SECONDS,
+    MAGIC_PACKET_SIZE, MacAddress, WolConfig, WolError, WolResult, create_socket,
+    generate_magic_packet, resolve_target, send_magic_packet, send_wol, wait_for_online,
+    wake_and_wait,
 };
 pub use workspace::WorkspaceProfileManager;
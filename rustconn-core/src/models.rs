@@ -3,6 +3,8 @@
 //! This module defines the primary data structures used throughout `RustConn`,
 //! including connections, groups, credentials, snippets, templates, and history.
 
+mod access_tunnel;
+mod connect_guard;
 mod connection;
 mod credentials;
 mod custom_property;
@@ -10,13 +12,17 @@ mod dynamic_folder;
 mod group;
 mod highlight;
 mod history;
+mod port_forward_profile;
 mod protocol;
+mod proxy;
 mod smart_folder;
 mod snippet;
 mod template;
 mod tunnel;
 mod workspace;
 
+pub use access_tunnel::{AccessTunnel, AccessTunnelError};
+pub use connect_guard::{ConnectGuard, ConnectGuardDecision, OutsideWindowAction, TimeWindow};
 pub use connection::{
     AutomationConfig, Connection, ConnectionThemeOverride, PasswordSource, WindowGeometry,
     WindowMode,
@@ -27,20 +33,23 @@ pub use dynamic_folder::{DynamicConnectionEntry, DynamicFolderConfig, DynamicFol
 pub use group::{ConnectionGroup, collect_descendant_group_ids};
 pub use highlight::HighlightRule;
 pub use history::{ConnectionHistoryEntry, ConnectionStatistics, HistorySettings};
+pub use port_forward_profile::PortForwardProfile;
 pub use protocol::{
     AwsSsmConfig, AzureBastionConfig, AzureSshConfig, BoundaryConfig, CloudflareAccessConfig,
-    GcpIapConfig, GenericZeroTrustConfig, HoopDevConfig, KubernetesConfig, MoshConfig,
-    MoshPredictMode, OciBastionConfig, PortForward, PortForwardDirection, ProtocolConfig,
-    ProtocolType, RdpClientMode, RdpConfig, RdpGateway, RdpPerformanceMode, RdpSecurityLayer,
-    Resolution, ScaleOverride, SerialBaudRate, SerialConfig, SerialDataBits, SerialFlowControl,
-    SerialParity, SerialStopBits, SharedFolder, SpiceConfig, SpiceImageCompression, SshAuthMethod,
-    SshConfig, SshKeySource, TailscaleSshConfig, TeleportConfig, TelnetBackspaceSends,
-    TelnetConfig, TelnetDeleteSends, VncClientMode, VncConfig, VncPerformanceMode, WebBrowserMode,
-    WebConfig, ZeroTrustConfig, ZeroTrustProvider, ZeroTrustProviderConfig,
-    build_remote_app_freerdp_args,
+    DEFAULT_SERIAL_CAPTURE_MAX_SIZE_MB, GcpIapConfig, GenericZeroTrustConfig, HoopDevConfig,
+    JumpHost, KubernetesConfig, MoshConfig, MoshPredictMode, OciBastionConfig, PortForward,
+    PortForwardDirection, ProtocolConfig, ProtocolType, RdpClientMode, RdpConfig, RdpGateway,
+    RdpPerformanceMode, RdpSecurityLayer, Resolution, ScaleOverride, SerialBaudRate,
+    SerialCaptureFormat, SerialConfig, SerialDataBits, SerialDeviceMatch, SerialFlowControl,
+    SerialParity, SerialStopBits, SerialViewMode, SharedFolder, SpiceConfig, SpiceImageCompression,
+    SshAuthMethod, SshConfig, SshKeySource, TailscaleSshConfig, TeleportConfig,
+    TelnetBackspaceSends, TelnetConfig, TelnetDeleteSends, VncClientMode, VncConfig,
+    VncPerformanceMode, VncRepeater, WebBrowserMode, WebConfig, ZeroTrustConfig, ZeroTrustProvider,
+    ZeroTrustProviderConfig, build_remote_app_freerdp_args,
 };
+pub use proxy::{ProxyConfig, ProxySupportError, ProxyType};
 pub use smart_folder::SmartFolder;
-pub use snippet::{ScriptDelivery, Snippet, SnippetTarget, SnippetVariable};
+pub use snippet::{ScriptDelivery, Snippet, SnippetTarget, SnippetVariable, SnippetVariableKind};
 pub use template::{ConnectionTemplate, TemplateError, group_templates_by_protocol};
 pub use tunnel::{StandaloneTunnel, TunnelStatus};
 pub use workspace::{WorkspaceEntry, WorkspaceProfile, WorkspaceSplitLayout};
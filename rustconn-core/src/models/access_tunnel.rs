@@ -0,0 +1,240 @@
+//! Zero-trust access tunnels for SSH connections
+//!
+//! An [`AccessTunnel`] sends an SSH connection through a zero-trust access
+//! provider's client instead of connecting to the host directly. The
+//! provider's client is run as the SSH `ProxyCommand`, so authentication,
+//! port forwarding and the other SSH options keep working:
+//!
+//! | Provider          | Client        | `ProxyCommand`                              |
+//! |-------------------|---------------|---------------------------------------------|
+//! | Cloudflare Access | `cloudflared` | `cloudflared access ssh --hostname %h`      |
+//! | Teleport          | `tsh`         | `tsh proxy ssh [--proxy ..] [--cluster ..] %r@%h:%p` |
+//!
+//! Only SSH connections can use a tunnel, and the tunnel replaces every other
+//! route: [`Connection::check_access_tunnel`](super::Connection::check_access_tunnel)
+//! rejects it alongside a jump host, a `ProxyCommand` or a per-connection
+//! proxy. Providers that need their own session command (AWS SSM, Boundary,
+//! …) are configured as Zero Trust connections instead.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::protocol::ProtocolType;
+use crate::protocol::{ClientInfo, CloudProvider, detect_cloudflared, detect_teleport};
+
+/// Errors for access tunnels a connection cannot use
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AccessTunnelError {
+    /// Only SSH connections can be tunnelled
+    #[error("{0} connections cannot use an access tunnel")]
+    ProtocolUnsupported(ProtocolType),
+    /// Another routing option already decides how the connection is made
+    #[error("An access tunnel cannot be combined with {0}")]
+    Conflict(&'static str),
+    /// A provider option contains characters that are not allowed
+    #[error("Invalid access tunnel {field}: '{value}'")]
+    InvalidValue {
+        /// Name of the option
+        field: &'static str,
+        /// The rejected value
+        value: String,
+    },
+}
+
+/// Zero-trust provider an SSH connection is tunnelled through
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum AccessTunnel {
+    /// Cloudflare Access, through `cloudflared access ssh`
+    Cloudflare {
+        /// Access application hostname; defaults to the connection host
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        hostname: Option<String>,
+    },
+    /// Teleport, through `tsh proxy ssh`
+    Teleport {
+        /// Teleport proxy address; defaults to the `tsh` profile
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        proxy: Option<String>,
+        /// Teleport cluster; defaults to the root cluster
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cluster: Option<String>,
+    },
+}
+
+impl AccessTunnel {
+    /// Returns the provider, for icons and display names
+    #[must_use]
+    pub const fn provider(&self) -> CloudProvider {
+        match self {
+            Self::Cloudflare { .. } => CloudProvider::Cloudflare,
+            Self::Teleport { .. } => CloudProvider::Teleport,
+        }
+    }
+
+    /// Returns the client program the tunnel runs
+    #[must_use]
+    pub const fn client(&self) -> &'static str {
+        match self {
+            Self::Cloudflare { .. } => "cloudflared",
+            Self::Teleport { .. } => "tsh",
+        }
+    }
+
+    /// Detects whether the provider's client is installed
+    #[must_use]
+    pub fn detect_client(&self) -> ClientInfo {
+        match self {
+            Self::Cloudflare { .. } => detect_cloudflared(),
+            Self::Teleport { .. } => detect_teleport(),
+        }
+    }
+
+    /// Validates the provider options
+    ///
+    /// The options end up inside an SSH `ProxyCommand`, which is run by a
+    /// shell, so only hostname characters are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccessTunnelError::InvalidValue`] for the first malformed
+    /// option.
+    pub fn validate(&self) -> Result<(), AccessTunnelError> {
+        let fields: Vec<(&'static str, &Option<String>)> = match self {
+            Self::Cloudflare { hostname } => vec![("hostname", hostname)],
+            Self::Teleport { proxy, cluster } => vec![("proxy", proxy), ("cluster", cluster)],
+        };
+        for (field, value) in fields {
+            if let Some(value) = value
+                && !is_safe_value(value)
+            {
+                return Err(AccessTunnelError::InvalidValue {
+                    field,
+                    value: value.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the SSH `ProxyCommand` value running the provider's client
+    ///
+    /// `%h`, `%p` and `%r` are expanded by OpenSSH.
+    #[must_use]
+    pub fn ssh_proxy_command(&self) -> String {
+        match self {
+            Self::Cloudflare { hostname } => format!(
+                "cloudflared access ssh --hostname {}",
+                non_empty(hostname.as_deref()).unwrap_or("%h")
+            ),
+            Self::Teleport { proxy, cluster } => {
+                let mut cmd = "tsh proxy ssh".to_string();
+                if let Some(proxy) = non_empty(proxy.as_deref()) {
+                    cmd.push_str(" --proxy ");
+                    cmd.push_str(proxy);
+                }
+                if let Some(cluster) = non_empty(cluster.as_deref()) {
+                    cmd.push_str(" --cluster ");
+                    cmd.push_str(cluster);
+                }
+                cmd.push_str(" %r@%h:%p");
+                cmd
+            }
+        }
+    }
+
+    /// Returns the `ssh` arguments that route a connection through the tunnel
+    ///
+    /// Callers insert these before the destination.
+    #[must_use]
+    pub fn ssh_args(&self) -> Vec<String> {
+        vec![
+            "-o".to_string(),
+            format!("ProxyCommand={}", self.ssh_proxy_command()),
+        ]
+    }
+}
+
+/// Returns `None` for missing or blank values
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// Returns `true` if `value` is safe to put in a `ProxyCommand`
+fn is_safe_value(value: &str) -> bool {
+    value
+        .trim()
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || ".-_:[]".contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cloudflare_proxy_command() {
+        let tunnel = AccessTunnel::Cloudflare { hostname: None };
+        assert_eq!(tunnel.client(), "cloudflared");
+        assert_eq!(tunnel.provider(), CloudProvider::Cloudflare);
+        assert_eq!(
+            tunnel.ssh_args(),
+            ["-o", "ProxyCommand=cloudflared access ssh --hostname %h"]
+        );
+
+        let tunnel = AccessTunnel::Cloudflare {
+            hostname: Some("ssh.example.com".to_string()),
+        };
+        assert_eq!(
+            tunnel.ssh_proxy_command(),
+            "cloudflared access ssh --hostname ssh.example.com"
+        );
+    }
+
+    #[test]
+    fn teleport_proxy_command() {
+        let tunnel = AccessTunnel::Teleport {
+            proxy: None,
+            cluster: Some(" ".to_string()),
+        };
+        assert_eq!(tunnel.client(), "tsh");
+        assert_eq!(tunnel.provider(), CloudProvider::Teleport);
+        assert_eq!(tunnel.ssh_proxy_command(), "tsh proxy ssh %r@%h:%p");
+
+        let tunnel = AccessTunnel::Teleport {
+            proxy: Some("teleport.example.com:443".to_string()),
+            cluster: Some("leaf".to_string()),
+        };
+        assert_eq!(
+            tunnel.ssh_proxy_command(),
+            "tsh proxy ssh --proxy teleport.example.com:443 --cluster leaf %r@%h:%p"
+        );
+    }
+
+    #[test]
+    fn validate_rejects_shell_metacharacters() {
+        let tunnel = AccessTunnel::Teleport {
+            proxy: Some("proxy;rm -rf ~".to_string()),
+            cluster: None,
+        };
+        assert!(matches!(
+            tunnel.validate(),
+            Err(AccessTunnelError::InvalidValue { field: "proxy", .. })
+        ));
+        let tunnel = AccessTunnel::Cloudflare {
+            hostname: Some("ssh.example.com".to_string()),
+        };
+        assert!(tunnel.validate().is_ok());
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let tunnel = AccessTunnel::Teleport {
+            proxy: None,
+            cluster: Some("leaf".to_string()),
+        };
+        let json = serde_json::to_string(&tunnel).unwrap();
+        assert_eq!(json, r#"{"provider":"teleport","cluster":"leaf"}"#);
+        assert_eq!(serde_json::from_str::<AccessTunnel>(&json).unwrap(), tunnel);
+    }
+}
@@ -0,0 +1,262 @@
+//! Connect guards — time windows and confirmation before connecting
+//!
+//! A [`ConnectGuard`] protects sensitive connections from being opened by
+//! accident. It can restrict connecting to allowed [`TimeWindow`]s (warning
+//! or blocking outside them), ask for confirmation with a custom message, and
+//! for the most dangerous hosts require the host name to be typed back.
+//!
+//! Windows are evaluated against local wall-clock time. A window whose end is
+//! before its start spans midnight, so `18:00`–`08:00` on weekdays covers
+//! weekday evenings and the following mornings.
+
+use std::fmt;
+
+use chrono::{Datelike, NaiveDateTime, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// A recurring span of time during which connecting is allowed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeWindow {
+    /// Days on which the window starts; empty means every day
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub days: Vec<Weekday>,
+    /// Start time (inclusive)
+    pub start: NaiveTime,
+    /// End time (exclusive); before `start` for windows spanning midnight
+    pub end: NaiveTime,
+}
+
+impl TimeWindow {
+    /// Creates a window applying every day
+    #[must_use]
+    pub const fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self {
+            days: Vec::new(),
+            start,
+            end,
+        }
+    }
+
+    /// Restricts the window to the given start days
+    #[must_use]
+    pub fn with_days(mut self, days: impl Into<Vec<Weekday>>) -> Self {
+        self.days = days.into();
+        self
+    }
+
+    /// Returns `true` if `now` falls inside the window
+    ///
+    /// A window with equal start and end covers the whole day.
+    #[must_use]
+    pub fn contains(&self, now: NaiveDateTime) -> bool {
+        let time = now.time();
+        let day = now.weekday();
+        let starts_on = |d: Weekday| self.days.is_empty() || self.days.contains(&d);
+        match self.start.cmp(&self.end) {
+            std::cmp::Ordering::Equal => starts_on(day),
+            std::cmp::Ordering::Less => starts_on(day) && time >= self.start && time < self.end,
+            std::cmp::Ordering::Greater => {
+                (time >= self.start && starts_on(day)) || (time < self.end && starts_on(day.pred()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for TimeWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, day) in self.days.iter().enumerate() {
+            f.write_str(if i == 0 { "" } else { ", " })?;
+            write!(f, "{day}")?;
+        }
+        if !self.days.is_empty() {
+            f.write_str(" ")?;
+        }
+        write!(
+            f,
+            "{}–{}",
+            self.start.format("%H:%M"),
+            self.end.format("%H:%M")
+        )
+    }
+}
+
+/// What happens when connecting outside every allowed window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutsideWindowAction {
+    /// Connect, but warn the user
+    #[default]
+    Warn,
+    /// Refuse to connect
+    Block,
+}
+
+/// Checks applied before a connection is opened
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectGuard {
+    /// Allowed time windows; empty means any time
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub windows: Vec<TimeWindow>,
+    /// Action when connecting outside every window
+    #[serde(default)]
+    pub outside_window: OutsideWindowAction,
+    /// Ask for confirmation before connecting
+    #[serde(default)]
+    pub require_confirmation: bool,
+    /// Message shown in the confirmation prompt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmation_message: Option<String>,
+    /// Require the host name to be typed to confirm (implies confirmation)
+    #[serde(default)]
+    pub require_typed_hostname: bool,
+}
+
+/// Outcome of evaluating a [`ConnectGuard`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectGuardDecision {
+    /// Connect without asking
+    Proceed,
+    /// Connect, after telling the user why this is unusual
+    Warn {
+        /// Why the connection is unusual
+        reason: String,
+    },
+    /// Ask the user before connecting
+    Confirm {
+        /// Message to show
+        message: String,
+        /// Additional warning, e.g. that it is outside the allowed hours
+        warning: Option<String>,
+        /// Text the user must type to confirm, if required
+        typed_hostname: Option<String>,
+    },
+    /// Do not connect
+    Block {
+        /// Why connecting is refused
+        reason: String,
+    },
+}
+
+impl ConnectGuardDecision {
+    /// Returns `true` if the connection may be opened without user input
+    #[must_use]
+    pub const fn proceeds_unattended(&self) -> bool {
+        matches!(self, Self::Proceed | Self::Warn { .. })
+    }
+}
+
+impl ConnectGuard {
+    /// Returns `true` if `now` is inside an allowed window (or none are set)
+    #[must_use]
+    pub fn allows_time(&self, now: NaiveDateTime) -> bool {
+        self.windows.is_empty() || self.windows.iter().any(|w| w.contains(now))
+    }
+
+    /// Evaluates the guard for connecting to `name` at `host` at local time `now`
+    ///
+    /// Blocking outside the windows wins over confirmation; otherwise the
+    /// time-window warning is folded into the confirmation prompt.
+    #[must_use]
+    pub fn evaluate(&self, name: &str, host: &str, now: NaiveDateTime) -> ConnectGuardDecision {
+        let outside = (!self.allows_time(now)).then(|| {
+            let windows: Vec<String> = self.windows.iter().map(ToString::to_string).collect();
+            format!(
+                "'{name}' is outside its allowed hours ({})",
+                windows.join("; ")
+            )
+        });
+        if let Some(reason) = &outside
+            && self.outside_window == OutsideWindowAction::Block
+        {
+            return ConnectGuardDecision::Block {
+                reason: reason.clone(),
+            };
+        }
+        if self.require_confirmation || self.require_typed_hostname {
+            return ConnectGuardDecision::Confirm {
+                message: self
+                    .confirmation_message
+                    .clone()
+                    .filter(|m| !m.trim().is_empty())
+                    .unwrap_or_else(|| format!("Connect to '{name}'?")),
+                warning: outside,
+                typed_hostname: self.require_typed_hostname.then(|| host.to_string()),
+            };
+        }
+        outside.map_or(ConnectGuardDecision::Proceed, |reason| {
+            ConnectGuardDecision::Warn { reason }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        // 2024-01-01 was a Monday
+        NaiveDate::from_ymd_opt(2024, 1, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn hm(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn overnight_window() {
+        let window = TimeWindow::new(hm(18, 0), hm(8, 0)).with_days([
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]);
+        assert!(window.contains(at(1, 19, 0))); // Monday evening
+        assert!(window.contains(at(2, 7, 59))); // Tuesday morning
+        assert!(!window.contains(at(2, 12, 0))); // Tuesday noon
+        assert!(window.contains(at(6, 7, 0))); // Saturday morning, Friday's window
+        assert!(!window.contains(at(6, 19, 0))); // Saturday evening
+        assert!(!window.contains(at(1, 7, 0))); // Monday morning, Sunday had none
+        assert_eq!(window.to_string(), "Mon, Tue, Wed, Thu, Fri 18:00–08:00");
+    }
+
+    #[test]
+    fn evaluate_decisions() {
+        let mut guard = ConnectGuard {
+            windows: vec![TimeWindow::new(hm(9, 0), hm(17, 0))],
+            ..ConnectGuard::default()
+        };
+        assert_eq!(
+            guard.evaluate("db", "db.lan", at(1, 10, 0)),
+            ConnectGuardDecision::Proceed
+        );
+        assert!(matches!(
+            guard.evaluate("db", "db.lan", at(1, 20, 0)),
+            ConnectGuardDecision::Warn { .. }
+        ));
+
+        guard.require_typed_hostname = true;
+        let ConnectGuardDecision::Confirm {
+            message,
+            warning,
+            typed_hostname,
+        } = guard.evaluate("db", "db.lan", at(1, 20, 0))
+        else {
+            panic!("expected confirmation");
+        };
+        assert_eq!(message, "Connect to 'db'?");
+        assert!(warning.is_some());
+        assert_eq!(typed_hostname.as_deref(), Some("db.lan"));
+
+        guard.outside_window = OutsideWindowAction::Block;
+        assert!(matches!(
+            guard.evaluate("db", "db.lan", at(1, 20, 0)),
+            ConnectGuardDecision::Block { .. }
+        ));
+    }
+}
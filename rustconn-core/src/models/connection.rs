@@ -6,15 +6,19 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::access_tunnel::{AccessTunnel, AccessTunnelError};
+use super::connect_guard::{ConnectGuard, ConnectGuardDecision};
 use super::custom_property::CustomProperty;
 use super::highlight::HighlightRule;
+use super::history::{ConnectionHistoryEntry, HistorySettings};
 use super::protocol::{ProtocolConfig, ProtocolType, RdpClientMode, VncClientMode};
+use super::proxy::{ProxyConfig, ProxySupportError};
 use crate::activity_monitor::ActivityMonitorConfig;
 use crate::automation::{ConnectionTask, ExpectRule, KeySequence};
 use crate::error::ConfigError;
 use crate::monitoring::MonitoringConfig;
 use crate::session::LogConfig;
-use crate::variables::Variable;
+use crate::variables::{Variable, VariableManager, VariableScope};
 use crate::wol::WolConfig;
 
 /// Automation configuration for a connection
@@ -26,6 +30,28 @@ pub struct AutomationConfig {
     /// Post-login scripts to execute
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub post_login_scripts: Vec<String>,
+    /// Answer one-time-password prompts (e.g. `Verification code:`) on connect
+    #[serde(default)]
+    pub answer_otp_prompts: bool,
+    /// Secret variable holding the one-time password; the user is asked
+    /// for the code when unset or empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub otp_variable: Option<String>,
+}
+
+impl AutomationConfig {
+    /// Returns the expect rules to run in a terminal session
+    ///
+    /// Appends an [`ExpectRule::otp`] rule when `answer_otp_prompts` is set.
+    #[must_use]
+    pub fn session_rules(&self) -> Vec<ExpectRule> {
+        let mut rules = self.expect_rules.clone();
+        if self.answer_otp_prompts {
+            let variable = self.otp_variable.as_deref().map_or("", str::trim);
+            rules.push(ExpectRule::otp(variable));
+        }
+        rules
+    }
 }
 
 /// Source of password/credentials for a connection
@@ -297,6 +323,11 @@ pub struct Connection {
     /// Skip pre-connect port check for this connection (overrides global setting)
     #[serde(default)]
     pub skip_port_check: bool,
+    /// Resolve the host through Tailscale MagicDNS for port checks
+    ///
+    /// See [`Self::probes_via_tailscale`].
+    #[serde(default)]
+    pub resolve_via_tailscale: bool,
     /// Whether this connection is pinned to favorites
     #[serde(default)]
     pub is_pinned: bool,
@@ -350,6 +381,42 @@ pub struct Connection {
     /// Sends an encrypted UDP packet to open a firewall rule for this client.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spa_config: Option<crate::connection::knock::SpaConfig>,
+    /// Connections that must be connected before this one, in order
+    ///
+    /// See [`ConnectionManager::resolve_launch_order`](crate::connection::ConnectionManager::resolve_launch_order).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<Uuid>,
+    /// SOCKS/HTTP proxy for the connection's outbound TCP traffic
+    ///
+    /// See [`ProxyConfig`] for which protocols support which proxy types.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+    /// Zero-trust access tunnel (Cloudflare Access, Teleport) for SSH
+    ///
+    /// See [`AccessTunnel`] for how the provider's client is launched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_tunnel: Option<AccessTunnel>,
+    /// Time windows and confirmation required before connecting
+    ///
+    /// See [`ConnectionManager::check_connect_guard`](crate::connection::ConnectionManager::check_connect_guard).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_guard: Option<ConnectGuard>,
+    /// Environment variables set for the terminal session, as `(name, value)`
+    ///
+    /// Values may reference variables as `${name}`; see [`Self::resolve_env`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<(String, String)>,
+    /// Whether remote programs may set the local clipboard with OSC 52
+    ///
+    /// `None` follows the global terminal setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub osc52_clipboard: Option<bool>,
+    /// Scrollback length for this connection's terminal
+    ///
+    /// A negative value (conventionally `-1`) keeps every line; `None`
+    /// follows the global terminal setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scrollback_lines: Option<i64>,
 }
 
 impl Connection {
@@ -386,6 +453,7 @@ impl Connection {
             remember_window_position: false,
             window_geometry: None,
             skip_port_check: false,
+            resolve_via_tailscale: false,
             is_pinned: false,
             pin_order: 0,
             icon: None,
@@ -398,6 +466,13 @@ impl Connection {
             retry_config: None,
             knock_sequence: None,
             spa_config: None,
+            depends_on: Vec::new(),
+            proxy: None,
+            access_tunnel: None,
+            connect_guard: None,
+            env: Vec::new(),
+            osc52_clipboard: None,
+            scrollback_lines: None,
         }
     }
 
@@ -538,6 +613,44 @@ impl Connection {
         self.protocol.default_port()
     }
 
+    /// Returns true if the connection carries `tag` (case-insensitive)
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = tag.trim().to_lowercase();
+        self.tags.iter().any(|t| t.to_lowercase() == tag)
+    }
+
+    /// Adds a tag unless the connection already has it
+    ///
+    /// Surrounding whitespace is trimmed and blank tags are ignored.
+    ///
+    /// # Returns
+    /// `true` if the tag was added, `false` otherwise
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        let tag = tag.trim();
+        if tag.is_empty() || self.has_tag(tag) {
+            return false;
+        }
+        self.tags.push(tag.to_string());
+        self.touch();
+        true
+    }
+
+    /// Removes a tag (case-insensitive)
+    ///
+    /// # Returns
+    /// `true` if a tag was removed, `false` otherwise
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let tag = tag.trim().to_lowercase();
+        let len_before = self.tags.len();
+        self.tags.retain(|t| t.to_lowercase() != tag);
+        let removed = self.tags.len() < len_before;
+        if removed {
+            self.touch();
+        }
+        removed
+    }
+
     /// Gets a custom property by name
     ///
     /// # Arguments
@@ -699,6 +812,64 @@ impl Connection {
         self
     }
 
+    /// Sets the terminal environment variables (builder pattern)
+    #[must_use]
+    pub fn with_env(mut self, env: Vec<(String, String)>) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Returns the terminal environment as `NAME=value` entries
+    ///
+    /// `${name}` references in values are expanded from the connection's
+    /// local variables, its built-ins (`host`, `port`, `user`, …) and then
+    /// `global_variables`; undefined variables expand to an empty string.
+    /// Entries with an invalid name, or whose value fails to expand (a cycle,
+    /// or control characters), are skipped with a warning.
+    #[must_use]
+    pub fn resolve_env(&self, global_variables: &[Variable]) -> Vec<String> {
+        if self.env.is_empty() {
+            return Vec::new();
+        }
+
+        let mut manager = VariableManager::new();
+        for var in global_variables {
+            manager.set_global(var.clone());
+        }
+        manager.set_connection_builtins(self);
+        for var in self.local_variables.values() {
+            manager.set_connection(self.id, var.clone());
+        }
+
+        let scope = VariableScope::Connection(self.id);
+        self.env
+            .iter()
+            .filter_map(|(name, value)| {
+                let name = name.trim();
+                if name.is_empty() || name.contains(['=', '\0']) {
+                    tracing::warn!(
+                        connection = %self.name,
+                        %name,
+                        "Skipping invalid environment variable name"
+                    );
+                    return None;
+                }
+                match manager.substitute_for_command(value, scope) {
+                    Ok(value) => Some(format!("{name}={value}")),
+                    Err(e) => {
+                        tracing::warn!(
+                            connection = %self.name,
+                            %name,
+                            error = %e,
+                            "Skipping environment variable"
+                        );
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
     /// Sets the session logging configuration for this connection
     #[must_use]
     pub fn with_log_config(mut self, config: LogConfig) -> Self {
@@ -916,10 +1087,18 @@ impl Connection {
     /// Returns `true` if this connection bypasses a direct TCP probe.
     ///
     /// Connections routed through a jump host, RDP Gateway, SSH ProxyCommand,
-    /// or SPICE proxy are not directly reachable, so a pre-connect port check
-    /// would always time out.
+    /// access tunnel, or SPICE proxy are not directly reachable, so a pre-connect port check
+    /// would always time out. Connections with an unauthenticated
+    /// [`ProxyConfig`] are probed through it instead (see
+    /// `check_port_with_proxy`); authenticated proxies are bypassed because
+    /// the probe has no credentials.
     #[must_use]
     pub fn bypasses_direct_probe(&self) -> bool {
+        if self.access_tunnel.is_some()
+            || self.proxy.as_ref().is_some_and(ProxyConfig::requires_auth)
+        {
+            return true;
+        }
         match &self.protocol_config {
             ProtocolConfig::Ssh(c) | ProtocolConfig::Sftp(c) => {
                 c.jump_host_id.is_some() || c.proxy_command.is_some()
@@ -932,6 +1111,105 @@ impl Connection {
         }
     }
 
+    /// Checks that the connection's proxy, if any, can be applied
+    ///
+    /// Besides [`ProxyConfig::validate`] and [`ProxyConfig::check_support`],
+    /// this rejects routing options that would make the proxy ineffective:
+    /// a jump host, an SSH `ProxyCommand`, an RDP Gateway, or a SPICE proxy
+    /// URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing why the proxy cannot be used.
+    pub fn check_proxy(&self) -> Result<(), ProxySupportError> {
+        let Some(proxy) = &self.proxy else {
+            return Ok(());
+        };
+        proxy.validate()?;
+        proxy.check_support(self.protocol)?;
+        match &self.protocol_config {
+            ProtocolConfig::Ssh(c) | ProtocolConfig::Sftp(c) => {
+                if c.proxy_command.is_some() {
+                    return Err(ProxySupportError::Conflict("an SSH ProxyCommand"));
+                }
+                if c.jump_host_id.is_some() || c.proxy_jump_spec().is_some() {
+                    return Err(ProxySupportError::Conflict("an SSH jump host"));
+                }
+            }
+            ProtocolConfig::Rdp(c) => {
+                if c.gateway.is_some() {
+                    return Err(ProxySupportError::Conflict("an RDP Gateway"));
+                }
+                if c.jump_host_id.is_some() {
+                    return Err(ProxySupportError::Conflict("a jump host"));
+                }
+            }
+            ProtocolConfig::Vnc(c) if c.jump_host_id.is_some() => {
+                return Err(ProxySupportError::Conflict("a jump host"));
+            }
+            ProtocolConfig::Spice(c) => {
+                if c.proxy.is_some() {
+                    return Err(ProxySupportError::Conflict("a SPICE proxy URL"));
+                }
+                if c.jump_host_id.is_some() {
+                    return Err(ProxySupportError::Conflict("a jump host"));
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Checks that the connection's access tunnel, if any, can be applied
+    ///
+    /// Besides [`AccessTunnel::validate`], this rejects non-SSH protocols
+    /// and routing options the tunnel would override: a jump host, an SSH
+    /// `ProxyCommand`, or a per-connection proxy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing why the tunnel cannot be used.
+    pub fn check_access_tunnel(&self) -> Result<(), AccessTunnelError> {
+        let Some(tunnel) = &self.access_tunnel else {
+            return Ok(());
+        };
+        tunnel.validate()?;
+        let ProtocolConfig::Ssh(c) = &self.protocol_config else {
+            return Err(AccessTunnelError::ProtocolUnsupported(self.protocol));
+        };
+        if c.proxy_command.is_some() {
+            return Err(AccessTunnelError::Conflict("an SSH ProxyCommand"));
+        }
+        if c.jump_host_id.is_some() || c.proxy_jump_spec().is_some() {
+            return Err(AccessTunnelError::Conflict("an SSH jump host"));
+        }
+        if self.proxy.is_some() {
+            return Err(AccessTunnelError::Conflict("a proxy"));
+        }
+        Ok(())
+    }
+
+    /// Evaluates the connection's connect guard at local time `now`
+    ///
+    /// Returns [`ConnectGuardDecision::Proceed`] when no guard is set.
+    #[must_use]
+    pub fn check_connect_guard(&self, now: chrono::NaiveDateTime) -> ConnectGuardDecision {
+        self.connect_guard
+            .as_ref()
+            .map_or(ConnectGuardDecision::Proceed, |guard| {
+                guard.evaluate(&self.name, &self.host, now)
+            })
+    }
+
+    /// Returns `true` if port checks should probe the host's Tailscale IP
+    ///
+    /// Requires [`Self::resolve_via_tailscale`]; connections probed through
+    /// a proxy leave name resolution to the proxy.
+    #[must_use]
+    pub const fn probes_via_tailscale(&self) -> bool {
+        self.resolve_via_tailscale && self.proxy.is_none()
+    }
+
     /// Returns `true` if a pre-connect TCP port check should be performed.
     ///
     /// Checks the global setting, per-connection override, and whether the
@@ -941,6 +1219,26 @@ impl Connection {
         settings.pre_connect_port_check && !self.skip_port_check && !self.bypasses_direct_probe()
     }
 
+    /// Returns when this connection was last started, according to `history`
+    ///
+    /// Failed attempts count as uses. Returns `None` when history tracking is
+    /// disabled in `settings` or `history` has no entry for this connection.
+    #[must_use]
+    pub fn last_connected_at(
+        &self,
+        history: &[ConnectionHistoryEntry],
+        settings: &HistorySettings,
+    ) -> Option<DateTime<Utc>> {
+        if !settings.enabled {
+            return None;
+        }
+        history
+            .iter()
+            .filter(|entry| entry.connection_id == self.id)
+            .map(|entry| entry.started_at)
+            .max()
+    }
+
     /// Toggles the pinned state of this connection
     pub fn toggle_pin(&mut self) {
         self.is_pinned = !self.is_pinned;
@@ -967,6 +1265,48 @@ mod tests {
         Connection::new_ssh("Test Server".to_string(), "example.com".to_string(), 22)
     }
 
+    #[test]
+    fn test_resolve_env_expands_variables() {
+        let mut conn = create_test_connection().with_env(vec![
+            (
+                "KUBECONFIG".to_string(),
+                "${kube_dir}/${cluster}.yaml".to_string(),
+            ),
+            ("LANG".to_string(), "C.UTF-8".to_string()),
+            ("TARGET".to_string(), "${user}@${host}".to_string()),
+            ("UNSET".to_string(), "x${missing}x".to_string()),
+        ]);
+        conn.username = Some("deploy".to_string());
+        conn.set_local_variable(Variable::new("cluster", "prod"));
+        let globals = [
+            Variable::new("kube_dir", "/home/deploy/.kube"),
+            Variable::new("cluster", "staging"),
+        ];
+
+        assert_eq!(
+            conn.resolve_env(&globals),
+            [
+                "KUBECONFIG=/home/deploy/.kube/prod.yaml",
+                "LANG=C.UTF-8",
+                "TARGET=deploy@example.com",
+                "UNSET=xx",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_env_skips_invalid_entries() {
+        let conn = create_test_connection().with_env(vec![
+            (String::new(), "value".to_string()),
+            ("A=B".to_string(), "value".to_string()),
+            ("MULTILINE".to_string(), "${text}".to_string()),
+            ("OK".to_string(), "1".to_string()),
+        ]);
+        let globals = [Variable::new("text", "line\nbreak")];
+        assert_eq!(conn.resolve_env(&globals), ["OK=1"]);
+        assert!(create_test_connection().resolve_env(&globals).is_empty());
+    }
+
     #[test]
     fn test_get_custom_property_not_found() {
         let conn = create_test_connection();
@@ -1102,4 +1442,43 @@ mod tests {
 
         assert!(conn.updated_at > initial_updated_at);
     }
+
+    #[test]
+    fn test_add_tag_trims_and_dedupes_case_insensitively() {
+        let mut conn = create_test_connection();
+
+        assert!(conn.add_tag(" pci "));
+        assert!(conn.add_tag("on-call"));
+        assert!(!conn.add_tag("PCI"));
+        assert!(!conn.add_tag("   "));
+
+        assert_eq!(conn.tags, ["pci", "on-call"]);
+        assert!(conn.has_tag("Pci"));
+        assert!(!conn.has_tag("legacy"));
+    }
+
+    #[test]
+    fn test_remove_tag() {
+        let mut conn =
+            create_test_connection().with_tags(vec!["Legacy".to_string(), "pci".to_string()]);
+
+        assert!(conn.remove_tag("legacy"));
+        assert!(!conn.remove_tag("legacy"));
+        assert_eq!(conn.tags, ["pci"]);
+    }
+
+    #[test]
+    fn test_tagless_connection_round_trip() {
+        let mut conn = create_test_connection();
+        let toml_str = toml::to_string(&conn).unwrap();
+        assert!(!toml_str.lines().any(|l| l.starts_with("tags")));
+
+        // Configs written before any tag was set have no `tags` key at all
+        let parsed: Connection = toml::from_str(&toml_str).unwrap();
+        assert!(parsed.tags.is_empty());
+
+        conn.add_tag("legacy");
+        let parsed: Connection = toml::from_str(&toml::to_string(&conn).unwrap()).unwrap();
+        assert_eq!(parsed.tags, ["legacy"]);
+    }
 }
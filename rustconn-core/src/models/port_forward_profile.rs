@@ -0,0 +1,167 @@
+//! Reusable port-forward profiles
+//!
+//! A [`PortForwardProfile`] is a named bundle of [`PortForward`] rules, such
+//! as "k8s-dashboard" or "db-tunnels", stored in
+//! `port_forward_profiles.toml`. SSH connections reference profiles by ID in
+//! [`SshConfig::port_forward_profiles`](super::SshConfig::port_forward_profiles)
+//! and the rules are expanded when the `ssh` command is built, so editing a
+//! profile changes every connection that uses it.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::protocol::PortForward;
+use crate::error::{ProtocolError, ProtocolResult};
+
+/// A named set of port forwarding rules shared by connections
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PortForwardProfile {
+    /// Unique identifier, referenced by connections
+    pub id: Uuid,
+    /// Display name; unique case-insensitively
+    pub name: String,
+    /// Optional description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Forwarding rules added to each connection using the profile
+    #[serde(default)]
+    pub forwards: Vec<PortForward>,
+}
+
+impl PortForwardProfile {
+    /// Creates an empty profile
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name: name.into(),
+            description: None,
+            forwards: Vec::new(),
+        }
+    }
+
+    /// Sets the description
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Adds a forwarding rule
+    #[must_use]
+    pub fn with_forward(mut self, forward: PortForward) -> Self {
+        self.forwards.push(forward);
+        self
+    }
+
+    /// Returns true if this profile is called `name` (case-insensitive)
+    #[must_use]
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name.trim())
+    }
+
+    /// Validates the name and every rule
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProtocolError::InvalidConfig` if the name is blank or a rule
+    /// is invalid (see [`PortForward::validate`]).
+    pub fn validate(&self) -> ProtocolResult<()> {
+        if self.name.trim().is_empty() {
+            return Err(ProtocolError::InvalidConfig(
+                "Port forward profile name cannot be empty".into(),
+            ));
+        }
+        self.forwards.iter().try_for_each(PortForward::validate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{PortForwardDirection, SshConfig};
+
+    fn forward(direction: PortForwardDirection, local_port: u16, remote: &str) -> PortForward {
+        let (remote_host, remote_port) = remote
+            .rsplit_once(':')
+            .map_or((String::new(), 0), |(h, p)| {
+                (h.to_string(), p.parse().unwrap())
+            });
+        PortForward {
+            direction,
+            bind_address: None,
+            local_port,
+            remote_host,
+            remote_port,
+        }
+    }
+
+    #[test]
+    fn test_profile_expands_into_ssh_args() {
+        let tunnels = PortForwardProfile::new("db-tunnels")
+            .with_forward(forward(PortForwardDirection::Local, 5432, "db:5432"))
+            .with_forward(forward(
+                PortForwardDirection::Remote,
+                9000,
+                "localhost:9000",
+            ));
+        let socks = PortForwardProfile::new("socks").with_forward(forward(
+            PortForwardDirection::Dynamic,
+            1080,
+            "",
+        ));
+
+        let mut ssh = SshConfig {
+            port_forwards: vec![forward(PortForwardDirection::Local, 8080, "web:80")],
+            port_forward_profiles: vec![socks.id, tunnels.id],
+            ..SshConfig::default()
+        };
+        ssh.expand_port_forward_profiles(&[tunnels, socks]);
+
+        assert!(ssh.port_forward_profiles.is_empty());
+        let args = ssh.build_command_args();
+        let forwards: Vec<&str> = args
+            .windows(2)
+            .filter(|w| matches!(w[0].as_str(), "-L" | "-R" | "-D"))
+            .flat_map(|w| [w[0].as_str(), w[1].as_str()])
+            .collect();
+        assert_eq!(
+            forwards,
+            [
+                "-L",
+                "8080:web:80",
+                "-D",
+                "1080",
+                "-L",
+                "5432:db:5432",
+                "-R",
+                "9000:localhost:9000",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_effective_forwards_skip_duplicates_and_unknown_profiles() {
+        let rule = forward(PortForwardDirection::Local, 5432, "db:5432");
+        let profile = PortForwardProfile::new("db").with_forward(rule.clone());
+        let ssh = SshConfig {
+            port_forwards: vec![rule.clone()],
+            port_forward_profiles: vec![Uuid::new_v4(), profile.id, profile.id],
+            ..SshConfig::default()
+        };
+        assert_eq!(ssh.effective_port_forwards(&[profile]), [rule]);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(PortForwardProfile::new(" ").validate().is_err());
+        let bad = PortForwardProfile::new("socks").with_forward(forward(
+            PortForwardDirection::Dynamic,
+            1080,
+            "host:22",
+        ));
+        assert!(bad.validate().is_err());
+        assert!(PortForwardProfile::new("empty").validate().is_ok());
+        assert!(PortForwardProfile::new("K8s-Dashboard").is_named(" k8s-dashboard"));
+    }
+}
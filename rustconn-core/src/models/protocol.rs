@@ -5,6 +5,9 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+use super::connection::PasswordSource;
+use super::port_forward_profile::PortForwardProfile;
+
 /// Protocol type identifier
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -608,15 +611,182 @@ impl SerialFlowControl {
     }
 }
 
+/// How serial session data is presented
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SerialViewMode {
+    /// Rendered terminal text (default)
+    #[default]
+    Terminal,
+    /// Classic hex dump with offset, 16 hex bytes and an ASCII gutter
+    HexDump,
+}
+
+impl SerialViewMode {
+    /// Returns all available view modes
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Terminal, Self::HexDump]
+    }
+
+    /// Returns the display name for this mode
+    #[must_use]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::Terminal => "Terminal",
+            Self::HexDump => "Hex + ASCII",
+        }
+    }
+
+    /// Returns the index of this mode in the `all()` array
+    #[must_use]
+    pub const fn index(self) -> u32 {
+        match self {
+            Self::Terminal => 0,
+            Self::HexDump => 1,
+        }
+    }
+
+    /// Creates a mode from an index
+    #[must_use]
+    pub const fn from_index(index: u32) -> Self {
+        match index {
+            1 => Self::HexDump,
+            _ => Self::Terminal,
+        }
+    }
+}
+
+/// What a serial capture writes besides the raw byte files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SerialCaptureFormat {
+    /// Raw `.rx.bin` / `.tx.bin` files only (default)
+    #[default]
+    Binary,
+    /// Also a `.hex.log` with a timestamped hex dump of every chunk
+    TimestampedHex,
+}
+
+impl SerialCaptureFormat {
+    /// Returns all available capture formats
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Binary, Self::TimestampedHex]
+    }
+
+    /// Returns the display name for this format
+    #[must_use]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::Binary => "Raw binary",
+            Self::TimestampedHex => "Raw binary + timestamped hex",
+        }
+    }
+
+    /// Returns the index of this format in the `all()` array
+    #[must_use]
+    pub const fn index(self) -> u32 {
+        match self {
+            Self::Binary => 0,
+            Self::TimestampedHex => 1,
+        }
+    }
+
+    /// Creates a format from an index
+    #[must_use]
+    pub const fn from_index(index: u32) -> Self {
+        match index {
+            1 => Self::TimestampedHex,
+            _ => Self::Binary,
+        }
+    }
+}
+
+/// Stable identity of a USB serial adapter
+///
+/// Adapters get a new device node when they are replugged (`ttyUSB0` may
+/// come back as `ttyUSB1`). A connection that names the adapter instead has
+/// its current node looked up when it connects.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "match", rename_all = "snake_case")]
+pub enum SerialDeviceMatch {
+    /// USB vendor and product ID, as shown by `lsusb`
+    UsbId {
+        /// Vendor ID (`idVendor`)
+        vendor_id: u16,
+        /// Product ID (`idProduct`)
+        product_id: u16,
+    },
+    /// Symlink name in `/dev/serial/by-id`
+    ById {
+        /// File name of the symlink, e.g. `usb-FTDI_FT232R_A50285BI-if00-port0`
+        name: String,
+    },
+}
+
+impl SerialDeviceMatch {
+    /// Parses `vvvv:pppp` (hexadecimal) as a USB ID, and anything else as a
+    /// by-id name; a leading `/dev/serial/by-id/` is stripped
+    ///
+    /// Returns `None` for empty input.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if let Some((vendor, product)) = value.split_once(':')
+            && vendor.len() == 4
+            && product.len() == 4
+            && let (Ok(vendor_id), Ok(product_id)) = (
+                u16::from_str_radix(vendor, 16),
+                u16::from_str_radix(product, 16),
+            )
+        {
+            return Some(Self::UsbId {
+                vendor_id,
+                product_id,
+            });
+        }
+        let name = value.strip_prefix("/dev/serial/by-id/").unwrap_or(value);
+        (!name.is_empty()).then(|| Self::ById {
+            name: name.to_string(),
+        })
+    }
+}
+
+impl std::fmt::Display for SerialDeviceMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UsbId {
+                vendor_id,
+                product_id,
+            } => write!(f, "{vendor_id:04x}:{product_id:04x}"),
+            Self::ById { name } => f.write_str(name),
+        }
+    }
+}
+
+/// Default size limit for serial capture files in megabytes
+pub const DEFAULT_SERIAL_CAPTURE_MAX_SIZE_MB: u32 = 10;
+
+const fn default_serial_capture_max_size_mb() -> u32 {
+    DEFAULT_SERIAL_CAPTURE_MAX_SIZE_MB
+}
+
 /// Serial console protocol configuration
 ///
 /// Configuration for serial port connections. Serial sessions are
 /// spawned via VTE terminal using an external serial client
 /// (`picocom`).
-#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SerialConfig {
     /// Serial device path (e.g., /dev/ttyUSB0, /dev/ttyACM0)
+    ///
+    /// Used as is without `device_match`, and as the fallback when the
+    /// matched adapter is not plugged in.
     pub device: String,
+    /// Adapter to look up at connect time instead of using `device`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_match: Option<SerialDeviceMatch>,
     /// Baud rate
     #[serde(default)]
     pub baud_rate: SerialBaudRate,
@@ -635,6 +805,42 @@ pub struct SerialConfig {
     /// Custom command-line arguments for the serial client
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub custom_args: Vec<String>,
+    /// Start capturing raw serial bytes (both directions) when connecting
+    #[serde(default)]
+    pub capture_on_connect: bool,
+    /// Capture path template without extension (same variables as session
+    /// log paths); `None` uses the default captures directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capture_path: Option<String>,
+    /// Maximum size of each capture file in megabytes before rotation (0 = no limit)
+    #[serde(default = "default_serial_capture_max_size_mb")]
+    pub capture_max_size_mb: u32,
+    /// Whether captures also get a timestamped hex log
+    #[serde(default)]
+    pub capture_format: SerialCaptureFormat,
+    /// How session data is presented
+    #[serde(default)]
+    pub view_mode: SerialViewMode,
+}
+
+impl Default for SerialConfig {
+    fn default() -> Self {
+        Self {
+            device: String::new(),
+            device_match: None,
+            baud_rate: SerialBaudRate::default(),
+            data_bits: SerialDataBits::default(),
+            stop_bits: SerialStopBits::default(),
+            parity: SerialParity::default(),
+            flow_control: SerialFlowControl::default(),
+            custom_args: Vec::new(),
+            capture_on_connect: false,
+            capture_path: None,
+            capture_max_size_mb: DEFAULT_SERIAL_CAPTURE_MAX_SIZE_MB,
+            capture_format: SerialCaptureFormat::default(),
+            view_mode: SerialViewMode::default(),
+        }
+    }
 }
 
 /// Direction of an SSH port forward
@@ -666,12 +872,18 @@ impl std::fmt::Display for PortForwardDirection {
 /// A single SSH port forwarding rule
 ///
 /// Supports local (`-L`), remote (`-R`), and dynamic (`-D`) forwarding.
-/// For dynamic forwarding only `local_port` is used (SOCKS proxy).
+/// Dynamic forwarding opens a SOCKS proxy on `[bind_address:]local_port`
+/// and has no destination; [`Self::validate`] rejects one with
+/// `remote_host` or `remote_port` set.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PortForward {
     /// Forwarding direction
     #[serde(default)]
     pub direction: PortForwardDirection,
+    /// Address to bind the listening port to (`None` uses the SSH default,
+    /// loopback unless `GatewayPorts` is set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bind_address: Option<String>,
     /// Local port to bind
     pub local_port: u16,
     /// Remote host to forward to (unused for dynamic)
@@ -686,29 +898,58 @@ impl PortForward {
     /// Builds the SSH command-line argument for this port forward rule
     #[must_use]
     pub fn to_ssh_arg(&self) -> Vec<String> {
+        let listen = self.listen_spec();
         match self.direction {
             PortForwardDirection::Local => {
                 vec![
                     "-L".to_string(),
-                    format!(
-                        "{}:{}:{}",
-                        self.local_port, self.remote_host, self.remote_port
-                    ),
+                    format!("{listen}:{}:{}", self.remote_host, self.remote_port),
                 ]
             }
             PortForwardDirection::Remote => {
                 vec![
                     "-R".to_string(),
-                    format!(
-                        "{}:{}:{}",
-                        self.local_port, self.remote_host, self.remote_port
-                    ),
+                    format!("{listen}:{}:{}", self.remote_host, self.remote_port),
                 ]
             }
-            PortForwardDirection::Dynamic => {
-                vec!["-D".to_string(), self.local_port.to_string()]
+            PortForwardDirection::Dynamic => vec!["-D".to_string(), listen],
+        }
+    }
+
+    /// Returns the listening side as `[bind_address:]port`
+    ///
+    /// IPv6 bind addresses are bracketed so the port separator stays
+    /// unambiguous.
+    #[must_use]
+    pub fn listen_spec(&self) -> String {
+        match self.bind_address.as_deref().map(str::trim) {
+            Some(bind) if !bind.is_empty() => {
+                if bind.contains(':') && !bind.starts_with('[') {
+                    format!("[{bind}]:{}", self.local_port)
+                } else {
+                    format!("{bind}:{}", self.local_port)
+                }
             }
+            _ => self.local_port.to_string(),
+        }
+    }
+
+    /// Validates the rule
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProtocolError::InvalidConfig` if a dynamic forward has a
+    /// destination host or port set.
+    pub fn validate(&self) -> crate::error::ProtocolResult<()> {
+        if self.direction == PortForwardDirection::Dynamic
+            && (!self.remote_host.trim().is_empty() || self.remote_port != 0)
+        {
+            return Err(crate::error::ProtocolError::InvalidConfig(format!(
+                "Dynamic forward on port {} must not have a destination host or port",
+                self.local_port
+            )));
         }
+        Ok(())
     }
 
     /// Returns a human-readable summary of this forwarding rule
@@ -728,9 +969,78 @@ impl PortForward {
                 )
             }
             PortForwardDirection::Dynamic => {
-                format!("D {} (SOCKS)", self.local_port)
+                format!("D {} (SOCKS)", self.listen_spec())
+            }
+        }
+    }
+}
+
+/// A single hop in an explicit SSH jump-host chain
+///
+/// Rendered as `[user@]host[:port]` for OpenSSH's `-J` option. IPv6 literals
+/// are bracketed so the port separator stays unambiguous.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JumpHost {
+    /// Hostname or IP address of the hop
+    pub host: String,
+    /// SSH port of the hop (`None` uses the default, 22)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Login user for the hop (`None` uses the local default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+impl JumpHost {
+    /// Creates a hop with the default port and user
+    #[must_use]
+    pub fn new(host: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            port: None,
+            user: None,
+        }
+    }
+
+    /// Sets the hop's SSH port
+    #[must_use]
+    pub const fn with_port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Sets the hop's login user
+    #[must_use]
+    pub fn with_user(mut self, user: impl Into<String>) -> Self {
+        self.user = Some(user.into());
+        self
+    }
+
+    /// Returns the port used to reach this hop
+    #[must_use]
+    pub fn effective_port(&self) -> u16 {
+        self.port.unwrap_or(22)
+    }
+
+    /// Formats the hop as a `-J` destination (`[user@]host[:port]`)
+    #[must_use]
+    pub fn to_spec(&self) -> String {
+        let host = self.host.trim();
+        let mut spec = String::new();
+        if let Some(user) = self.user.as_deref().map(str::trim)
+            && !user.is_empty()
+        {
+            spec.push_str(user);
+            spec.push('@');
+        }
+        match self.port {
+            Some(port) if host.contains(':') => {
+                spec.push_str(&format!("[{host}]:{port}"));
             }
+            Some(port) => spec.push_str(&format!("{host}:{port}")),
+            None => spec.push_str(host),
         }
+        spec
     }
 }
 
@@ -778,6 +1088,12 @@ pub struct SshConfig {
     /// `ProxyJump` configuration (host or user@host)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy_jump: Option<String>,
+    /// Explicit jump-host chain, ordered from the client outward
+    ///
+    /// `jump_hosts[0]` is the first hop SSH contacts. When non-empty this
+    /// takes precedence over the free-form [`Self::proxy_jump`] string.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub jump_hosts: Vec<JumpHost>,
     /// Enable SSH `ControlMaster` for connection multiplexing
     #[serde(default)]
     pub use_control_master: bool,
@@ -785,10 +1101,19 @@ pub struct SshConfig {
     /// Allows the remote host to use local SSH agent for authentication
     #[serde(default)]
     pub agent_forwarding: bool,
+    /// The user has acknowledged the security implications of agent forwarding.
+    /// Anyone with root on the remote host can use the forwarded agent, so the
+    /// UI warns once before agent forwarding is first enabled.
+    #[serde(default)]
+    pub agent_forwarding_acknowledged: bool,
     /// Enable X11 forwarding (`-X` flag)
     /// Allows running graphical applications on the remote host
     #[serde(default)]
     pub x11_forwarding: bool,
+    /// Use trusted X11 forwarding (`-Y` instead of `-X`).
+    /// Bypasses the X11 SECURITY extension; requires `x11_forwarding`.
+    #[serde(default)]
+    pub x11_trusted: bool,
     /// Enable compression (`-C` flag)
     /// Compresses all data for faster transfer over slow connections
     #[serde(default)]
@@ -809,6 +1134,11 @@ pub struct SshConfig {
     /// Port forwarding rules (local, remote, dynamic)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub port_forwards: Vec<PortForward>,
+    /// IDs of port-forward profiles whose rules are added after
+    /// `port_forwards` when connecting (see
+    /// [`Self::expand_port_forward_profiles`])
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub port_forward_profiles: Vec<uuid::Uuid>,
     /// Enable Wayland application forwarding via `waypipe`
     /// Wraps the SSH command with `waypipe ssh` for Wayland display forwarding
     #[serde(default)]
@@ -819,7 +1149,9 @@ pub struct SshConfig {
     pub ssh_agent_socket: Option<String>,
     /// Custom `ProxyCommand` for connections that require a proxy (e.g., Tor `.onion` hosts).
     /// When set, SSH uses this command instead of a direct TCP connection.
+    /// `{{host}}` and `{{port}}` stand for the destination, like `%h` and `%p`.
     /// Example: `ncat --proxy 127.0.0.1:9050 --proxy-type socks5 %h %p`
+    /// or `corkscrew proxy.lan 3128 {{host}} {{port}}`
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub proxy_command: Option<String>,
     /// Path to a PKCS#11 provider library for hardware-token authentication
@@ -882,6 +1214,38 @@ const fn default_autotype_delay() -> u32 {
 }
 
 impl SshConfig {
+    /// Returns the value for OpenSSH's `-J` option, if any
+    ///
+    /// The explicit [`Self::jump_hosts`] chain wins over the free-form
+    /// [`Self::proxy_jump`] string. Hops are joined in client-first order,
+    /// which is the order `-J` visits them.
+    #[must_use]
+    pub fn proxy_jump_spec(&self) -> Option<String> {
+        let hops: Vec<String> = self
+            .jump_hosts
+            .iter()
+            .filter(|hop| !hop.host.trim().is_empty())
+            .map(JumpHost::to_spec)
+            .collect();
+        if hops.is_empty() {
+            self.proxy_jump
+                .as_deref()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(ToString::to_string)
+        } else {
+            Some(hops.join(","))
+        }
+    }
+
+    /// Returns the first hop SSH connects to, if an explicit chain is set
+    #[must_use]
+    pub fn first_jump_host(&self) -> Option<&JumpHost> {
+        self.jump_hosts
+            .iter()
+            .find(|hop| !hop.host.trim().is_empty())
+    }
+
     /// Builds SSH command arguments based on the configuration
     ///
     /// Returns a vector of command-line arguments to pass to the SSH command.
@@ -909,8 +1273,11 @@ impl SshConfig {
         // Determine if we should add IdentitiesOnly based on key source
         // File auth method should always use IdentitiesOnly to prevent "Too many auth failures"
         // Agent auth method with a valid key path should also use IdentitiesOnly
-        let should_use_identities_only =
-            self.identities_only || matches!(self.key_source, SshKeySource::File { .. });
+        let should_use_identities_only = self.identities_only
+            || matches!(
+                self.key_source,
+                SshKeySource::File { .. } | SshKeySource::KeePassAttachment { .. }
+            );
 
         // Add identity file if specified via key_source (preferred) or key_path (legacy)
         match &self.key_source {
@@ -926,6 +1293,10 @@ impl SshConfig {
                 // Without -i, the agent offers keys naturally with a single prompt.
                 // See: https://github.com/totoshko88/RustConn/issues/125
             }
+            SshKeySource::KeePassAttachment { .. } => {
+                // The temporary key file only exists once the session starts;
+                // the caller passes it as the identity file.
+            }
             SshKeySource::Default | SshKeySource::File { .. } | SshKeySource::Inherit => {
                 // Default or File with empty path - check legacy key_path field
                 if let Some(ref key_path) = self.key_path
@@ -946,14 +1317,14 @@ impl SshConfig {
 
         // Add proxy jump if specified (skip when ProxyCommand is set — it takes precedence)
         if self.proxy_command.is_none()
-            && let Some(ref proxy) = self.proxy_jump
+            && let Some(proxy) = self.proxy_jump_spec()
         {
             args.push("-J".to_string());
-            args.push(proxy.clone());
+            args.push(proxy);
         }
 
         // Add ProxyCommand if specified (e.g., for Tor .onion hosts)
-        if let Some(ref proxy_cmd) = self.proxy_command {
+        if let Some(proxy_cmd) = self.resolved_proxy_command() {
             args.push("-o".to_string());
             args.push(format!("ProxyCommand={proxy_cmd}"));
         }
@@ -984,9 +1355,10 @@ impl SshConfig {
             args.push("-A".to_string());
         }
 
-        // Add X11 forwarding if enabled
+        // Add X11 forwarding if enabled (-Y for trusted, -X for untrusted)
         if self.x11_forwarding {
-            args.push("-X".to_string());
+            let flag = if self.x11_trusted { "-Y" } else { "-X" };
+            args.push(flag.to_string());
         }
 
         // Add compression if enabled
@@ -1049,6 +1421,104 @@ impl SshConfig {
         args
     }
 
+    /// Returns the `ProxyCommand` with `{{host}}` and `{{port}}` replaced by
+    /// the `%h` and `%p` tokens, so `ssh` fills in the final destination.
+    ///
+    /// Returns `None` when no command is set or it is blank.
+    #[must_use]
+    pub fn resolved_proxy_command(&self) -> Option<String> {
+        let command = self.proxy_command.as_deref()?.trim();
+        if command.is_empty() {
+            return None;
+        }
+        Some(command.replace("{{host}}", "%h").replace("{{port}}", "%p"))
+    }
+
+    /// Validates the custom `ProxyCommand`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProtocolError::InvalidConfig` if a proxy command is set but
+    /// blank.
+    pub fn validate_proxy_command(&self) -> crate::error::ProtocolResult<()> {
+        if self
+            .proxy_command
+            .as_deref()
+            .is_some_and(|c| c.trim().is_empty())
+        {
+            return Err(crate::error::ProtocolError::InvalidConfig(
+                "ProxyCommand cannot be empty".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the connection's own port forwards followed by those of its
+    /// profiles, in reference order
+    ///
+    /// Rules already present are not repeated. Profiles missing from
+    /// `profiles` are skipped with a warning.
+    #[must_use]
+    pub fn effective_port_forwards(&self, profiles: &[PortForwardProfile]) -> Vec<PortForward> {
+        let mut forwards = self.port_forwards.clone();
+        for id in &self.port_forward_profiles {
+            let Some(profile) = profiles.iter().find(|p| p.id == *id) else {
+                tracing::warn!(profile_id = %id, "Port forward profile not found");
+                continue;
+            };
+            for forward in &profile.forwards {
+                if !forwards.contains(forward) {
+                    forwards.push(forward.clone());
+                }
+            }
+        }
+        forwards
+    }
+
+    /// Replaces the profile references with the rules they stand for
+    ///
+    /// Call this on a copy of the configuration before building the `ssh`
+    /// command, so [`Self::build_command_args`] emits the profiles'
+    /// `-L`/`-R`/`-D` arguments.
+    pub fn expand_port_forward_profiles(&mut self, profiles: &[PortForwardProfile]) {
+        if self.port_forward_profiles.is_empty() {
+            return;
+        }
+        self.port_forwards = self.effective_port_forwards(profiles);
+        self.port_forward_profiles.clear();
+    }
+
+    /// Validates the agent and X11 forwarding toggles and the port
+    /// forwarding rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProtocolError::InvalidConfig` if trusted X11 forwarding is
+    /// enabled without X11 forwarding, or if a port forward is invalid (see
+    /// [`PortForward::validate`]).
+    pub fn validate_forwarding(&self) -> crate::error::ProtocolResult<()> {
+        if self.x11_trusted && !self.x11_forwarding {
+            return Err(crate::error::ProtocolError::InvalidConfig(
+                "Trusted X11 forwarding requires X11 forwarding to be enabled".into(),
+            ));
+        }
+        self.port_forwards
+            .iter()
+            .try_for_each(PortForward::validate)
+    }
+
+    /// Returns `true` if agent forwarding is enabled but its security
+    /// implications have not been acknowledged yet.
+    #[must_use]
+    pub const fn needs_agent_forwarding_warning(&self) -> bool {
+        self.agent_forwarding && !self.agent_forwarding_acknowledged
+    }
+
+    /// Records that the user has seen the agent-forwarding security warning.
+    pub const fn acknowledge_agent_forwarding(&mut self) {
+        self.agent_forwarding_acknowledged = true;
+    }
+
     /// Checks if this SSH config uses File authentication method
     ///
     /// Returns true if `key_source` is `SshKeySource::File` with a non-empty path.
@@ -1087,6 +1557,16 @@ pub enum SshKeySource {
     Default,
     /// Inherit SSH key from parent group chain
     Inherit,
+    /// Key stored as an attachment of a `KeePass` entry
+    ///
+    /// The attachment is exported to a private temporary file when the
+    /// session starts and removed when it ends.
+    KeePassAttachment {
+        /// Entry path in the database (e.g. `Servers/web`)
+        entry: String,
+        /// Attachment name (e.g. `id_ed25519`)
+        attachment: String,
+    },
 }
 
 /// Helper function for serde to skip serializing default key source
@@ -1122,15 +1602,40 @@ pub struct RdpGateway {
     /// Gateway username (if different from connection username)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
+    /// Gateway domain (if different from connection domain)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// Where the gateway password comes from
+    ///
+    /// `None` reuses the connection credentials for the gateway. `Vault`
+    /// looks up the entry `<connection key>/gateway` in the configured
+    /// secret backend; `Variable` and `Script` work as for the connection.
+    #[serde(default)]
+    pub password_source: PasswordSource,
+}
+
+impl RdpGateway {
+    /// Returns `true` if the gateway authenticates with its own account
+    /// instead of the connection credentials
+    #[must_use]
+    pub const fn has_own_credentials(&self) -> bool {
+        !matches!(
+            self.password_source,
+            PasswordSource::None | PasswordSource::Inherit
+        )
+    }
 }
 
-/// A shared folder for RDP connections
+/// A shared folder for RDP drive redirection and SPICE folder sharing
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SharedFolder {
     /// Local directory path to share
     pub local_path: PathBuf,
     /// Share name visible in the remote session
     pub share_name: String,
+    /// Share the folder read-only (SPICE only; RDP drives are always writable)
+    #[serde(default)]
+    pub read_only: bool,
 }
 
 const fn default_gateway_port() -> u16 {
@@ -1816,6 +2321,10 @@ pub struct VncConfig {
     /// Only applies to Embedded mode; External viewers handle their own sockets.
     #[serde(default)]
     pub mptcp: bool,
+
+    /// UltraVNC-style repeater to connect through instead of the server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repeater: Option<VncRepeater>,
 }
 
 impl VncConfig {
@@ -1842,6 +2351,84 @@ impl VncConfig {
     }
 }
 
+/// UltraVNC-style repeater in ID mode
+///
+/// The viewer dials the repeater instead of the server and names the server
+/// by the ID it registered with; the repeater then relays the RFB session.
+/// The connection's own host and port are not dialled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VncRepeater {
+    /// Repeater hostname
+    pub host: String,
+    /// Port the repeater accepts viewers on (default: 5901)
+    #[serde(default = "default_repeater_port")]
+    pub port: u16,
+    /// ID the server registered with, without the `ID:` prefix
+    pub id: String,
+}
+
+impl VncRepeater {
+    /// Length of the handshake a viewer sends after the repeater's banner
+    pub const HANDSHAKE_LEN: usize = 250;
+
+    /// Creates a repeater on the default port
+    #[must_use]
+    pub fn new(host: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            port: default_repeater_port(),
+            id: id.into(),
+        }
+    }
+
+    /// Sets the port
+    #[must_use]
+    pub const fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Returns the target the repeater is asked for, e.g. `ID:1234`
+    #[must_use]
+    pub fn target(&self) -> String {
+        format!("ID:{}", self.id)
+    }
+
+    /// Validates the repeater address and ID
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProtocolError::InvalidConfig` if the host is blank, the port
+    /// is 0, or the ID is not a number that fits the handshake.
+    pub fn validate(&self) -> crate::error::ProtocolResult<()> {
+        if self.host.trim().is_empty() {
+            return Err(crate::error::ProtocolError::InvalidConfig(
+                "VNC repeater host cannot be empty".into(),
+            ));
+        }
+        if self.port == 0 {
+            return Err(crate::error::ProtocolError::InvalidConfig(
+                "VNC repeater port cannot be 0".into(),
+            ));
+        }
+        // "ID:" plus the terminating NUL must fit the fixed-size handshake
+        if self.id.is_empty()
+            || !self.id.bytes().all(|b| b.is_ascii_digit())
+            || self.id.len() > Self::HANDSHAKE_LEN - 4
+        {
+            return Err(crate::error::ProtocolError::InvalidConfig(format!(
+                "Invalid VNC repeater ID '{}': expected a number",
+                self.id
+            )));
+        }
+        Ok(())
+    }
+}
+
+const fn default_repeater_port() -> u16 {
+    5901
+}
+
 /// SPICE image compression mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -1879,6 +2466,10 @@ pub struct SpiceConfig {
     /// Enable USB redirection
     #[serde(default)]
     pub usb_redirection: bool,
+    /// USB devices to auto-redirect, as hex `vendor:product` IDs
+    /// (e.g. `1050:0407`). When non-empty, only these devices are redirected.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub usb_redirect_filters: Vec<String>,
     /// Shared folders for folder sharing
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub shared_folders: Vec<SharedFolder>,
@@ -1913,6 +2504,7 @@ impl Default for SpiceConfig {
             ca_cert_path: None,
             skip_cert_verify: false,
             usb_redirection: false,
+            usb_redirect_filters: Vec::new(),
             shared_folders: Vec::new(),
             clipboard_enabled: true,
             image_compression: None,
@@ -2692,6 +3284,10 @@ pub struct KubernetesConfig {
     /// Container name within the pod (optional for single-container pods)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub container: Option<String>,
+    /// Command to run in the container instead of the interactive shell,
+    /// split into arguments with shell quoting rules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
     /// Shell to use inside the container
     #[serde(default = "default_shell")]
     pub shell: String,
@@ -2714,6 +3310,7 @@ impl Default for KubernetesConfig {
             namespace: None,
             pod: None,
             container: None,
+            command: None,
             shell: default_shell(),
             use_busybox: false,
             busybox_image: default_busybox_image(),
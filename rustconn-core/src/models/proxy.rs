@@ -0,0 +1,347 @@
+//! Per-connection proxy configuration
+//!
+//! A [`ProxyConfig`] routes a connection's TCP traffic through a SOCKS or
+//! HTTP `CONNECT` proxy. How the proxy is applied depends on the protocol:
+//!
+//! | Protocol   | SOCKS5 | SOCKS4 | HTTP | Authentication | Applied via                                  |
+//! |------------|--------|--------|------|----------------|----------------------------------------------|
+//! | SSH / SFTP | yes    | yes    | yes  | no             | `ProxyCommand` running `ncat --proxy`        |
+//! | RDP        | yes    | no     | yes  | embedded only  | embedded client socket, FreeRDP `/proxy:`    |
+//! | VNC        | yes    | yes    | yes  | yes            | embedded client socket                       |
+//! | SPICE      | no     | no     | yes  | no             | `remote-viewer --spice-proxy`                |
+//!
+//! Telnet, Serial, Kubernetes, MOSH (UDP), Web and Zero Trust connections
+//! cannot use a per-connection proxy; [`ProxyConfig::check_support`] reports
+//! these combinations as errors so they are rejected when the connection is
+//! saved rather than silently connecting directly.
+//!
+//! Proxy passwords are never stored here. When `password_source` is set, the
+//! password is looked up through the secret backend (see
+//! `CredentialResolver::resolve_proxy_password`). External clients receive the
+//! proxy on their command line, so authenticated proxies are only supported
+//! where the embedded client performs the handshake itself.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::connection::PasswordSource;
+use super::protocol::ProtocolType;
+
+/// Proxy protocol spoken to the proxy server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyType {
+    /// SOCKS5 (RFC 1928), with optional username/password authentication
+    #[default]
+    Socks5,
+    /// SOCKS4a — no authentication, hostname resolved by the proxy
+    Socks4,
+    /// HTTP `CONNECT` tunnel, with optional Basic authentication
+    Http,
+}
+
+impl ProxyType {
+    /// Returns all proxy types
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Socks5, Self::Socks4, Self::Http]
+    }
+
+    /// Returns the display name
+    #[must_use]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::Socks5 => "SOCKS5",
+            Self::Socks4 => "SOCKS4",
+            Self::Http => "HTTP",
+        }
+    }
+
+    /// Returns the URL scheme for this proxy type
+    ///
+    /// This is also the `ncat --proxy-type` value.
+    #[must_use]
+    pub const fn scheme(self) -> &'static str {
+        match self {
+            Self::Socks5 => "socks5",
+            Self::Socks4 => "socks4",
+            Self::Http => "http",
+        }
+    }
+
+    /// Returns the index of this type in the `all()` array
+    #[must_use]
+    pub const fn index(self) -> u32 {
+        match self {
+            Self::Socks5 => 0,
+            Self::Socks4 => 1,
+            Self::Http => 2,
+        }
+    }
+
+    /// Creates a proxy type from an index
+    #[must_use]
+    pub const fn from_index(index: u32) -> Self {
+        match index {
+            1 => Self::Socks4,
+            2 => Self::Http,
+            _ => Self::Socks5,
+        }
+    }
+}
+
+impl std::fmt::Display for ProxyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.display_name())
+    }
+}
+
+/// Errors for proxy configurations a connection cannot use
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ProxySupportError {
+    /// The protocol cannot be routed through any proxy
+    #[error("{protocol} connections cannot use a proxy")]
+    ProtocolUnsupported {
+        /// The connection protocol
+        protocol: ProtocolType,
+    },
+    /// The protocol supports proxies, but not this proxy type
+    #[error("{protocol} connections do not support {proxy_type} proxies")]
+    TypeUnsupported {
+        /// The connection protocol
+        protocol: ProtocolType,
+        /// The configured proxy type
+        proxy_type: ProxyType,
+    },
+    /// The protocol's client cannot authenticate to the proxy
+    #[error("{protocol} connections do not support authenticated proxies")]
+    AuthUnsupported {
+        /// The connection protocol
+        protocol: ProtocolType,
+    },
+    /// SOCKS4 has no authentication
+    #[error("SOCKS4 proxies do not support authentication")]
+    Socks4Auth,
+    /// Another routing option already decides how the connection is made
+    #[error("A proxy cannot be combined with {0}")]
+    Conflict(&'static str),
+    /// Host or port is missing or malformed
+    #[error("Invalid proxy address: {0}")]
+    InvalidAddress(String),
+}
+
+/// Proxy used for a connection's outbound TCP traffic
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    /// Proxy protocol
+    #[serde(default)]
+    pub proxy_type: ProxyType,
+    /// Proxy server hostname or IP address
+    pub host: String,
+    /// Proxy server port
+    pub port: u16,
+    /// Username for proxy authentication
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Where the proxy password comes from
+    ///
+    /// `Vault` looks up the entry `<connection key>/proxy` in the configured
+    /// secret backend; `Variable` reads a secret global variable.
+    #[serde(default)]
+    pub password_source: PasswordSource,
+}
+
+impl ProxyConfig {
+    /// Creates an unauthenticated proxy configuration
+    #[must_use]
+    pub fn new(proxy_type: ProxyType, host: impl Into<String>, port: u16) -> Self {
+        Self {
+            proxy_type,
+            host: host.into(),
+            port,
+            username: None,
+            password_source: PasswordSource::None,
+        }
+    }
+
+    /// Sets the username used to authenticate to the proxy
+    #[must_use]
+    pub fn with_username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Returns `true` if the proxy needs credentials
+    #[must_use]
+    pub fn requires_auth(&self) -> bool {
+        self.username.as_deref().is_some_and(|u| !u.is_empty())
+            || !matches!(self.password_source, PasswordSource::None)
+    }
+
+    /// Returns `host:port`, bracketing IPv6 literals
+    #[must_use]
+    pub fn address(&self) -> String {
+        if self.host.contains(':') && !self.host.starts_with('[') {
+            format!("[{}]:{}", self.host, self.port)
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+
+    /// Returns the proxy as a URL without credentials (`socks5://host:port`)
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("{}://{}", self.proxy_type.scheme(), self.address())
+    }
+
+    /// Validates the proxy address
+    ///
+    /// The host ends up inside an SSH `ProxyCommand`, which is run by a
+    /// shell, so only hostname and IP literal characters are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host is empty or malformed, the port is 0, or
+    /// credentials are configured for a SOCKS4 proxy.
+    pub fn validate(&self) -> Result<(), ProxySupportError> {
+        let host = self.host.trim();
+        if host.is_empty() {
+            return Err(ProxySupportError::InvalidAddress(
+                "host cannot be empty".to_string(),
+            ));
+        }
+        if !host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ".-_:[]".contains(c))
+        {
+            return Err(ProxySupportError::InvalidAddress(format!(
+                "'{host}' is not a valid hostname or IP address"
+            )));
+        }
+        if self.port == 0 {
+            return Err(ProxySupportError::InvalidAddress(
+                "port cannot be 0".to_string(),
+            ));
+        }
+        if self.proxy_type == ProxyType::Socks4 && self.requires_auth() {
+            return Err(ProxySupportError::Socks4Auth);
+        }
+        Ok(())
+    }
+
+    /// Checks that a connection of `protocol` can use this proxy
+    ///
+    /// See the module documentation for the support matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing why the combination is unsupported.
+    pub fn check_support(&self, protocol: ProtocolType) -> Result<(), ProxySupportError> {
+        let (types, auth): (&[ProxyType], bool) = match protocol {
+            ProtocolType::Ssh | ProtocolType::Sftp => (ProxyType::all(), false),
+            ProtocolType::Rdp => (&[ProxyType::Socks5, ProxyType::Http], true),
+            ProtocolType::Vnc => (ProxyType::all(), true),
+            ProtocolType::Spice => (&[ProxyType::Http], false),
+            ProtocolType::Telnet
+            | ProtocolType::Serial
+            | ProtocolType::Kubernetes
+            | ProtocolType::Mosh
+            | ProtocolType::Web
+            | ProtocolType::ZeroTrust => {
+                return Err(ProxySupportError::ProtocolUnsupported { protocol });
+            }
+        };
+        if !types.contains(&self.proxy_type) {
+            return Err(ProxySupportError::TypeUnsupported {
+                protocol,
+                proxy_type: self.proxy_type,
+            });
+        }
+        if !auth && self.requires_auth() {
+            return Err(ProxySupportError::AuthUnsupported { protocol });
+        }
+        Ok(())
+    }
+
+    /// Builds the SSH `ProxyCommand` value routing through this proxy
+    ///
+    /// Uses `ncat` from the Nmap project, which speaks all three proxy
+    /// types; `%h` and `%p` are expanded by OpenSSH.
+    #[must_use]
+    pub fn ssh_proxy_command(&self) -> String {
+        format!(
+            "ncat --proxy {} --proxy-type {} %h %p",
+            self.address(),
+            self.proxy_type.scheme()
+        )
+    }
+
+    /// Builds the FreeRDP `/proxy:` argument
+    #[must_use]
+    pub fn freerdp_arg(&self) -> String {
+        format!("/proxy:{}", self.url())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn support_matrix() {
+        let socks5 = ProxyConfig::new(ProxyType::Socks5, "proxy.lan", 1080);
+        let socks4 = ProxyConfig::new(ProxyType::Socks4, "proxy.lan", 1080);
+        let http = ProxyConfig::new(ProxyType::Http, "proxy.lan", 3128);
+        let authed = http.clone().with_username("alice");
+
+        assert!(socks4.check_support(ProtocolType::Ssh).is_ok());
+        assert!(authed.check_support(ProtocolType::Rdp).is_ok());
+        assert!(authed.check_support(ProtocolType::Vnc).is_ok());
+        assert!(http.check_support(ProtocolType::Spice).is_ok());
+        assert_eq!(
+            socks4.check_support(ProtocolType::Rdp),
+            Err(ProxySupportError::TypeUnsupported {
+                protocol: ProtocolType::Rdp,
+                proxy_type: ProxyType::Socks4,
+            })
+        );
+        assert_eq!(
+            authed.check_support(ProtocolType::Ssh),
+            Err(ProxySupportError::AuthUnsupported {
+                protocol: ProtocolType::Ssh
+            })
+        );
+        assert!(matches!(
+            socks5.check_support(ProtocolType::Telnet),
+            Err(ProxySupportError::ProtocolUnsupported { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_shell_metacharacters() {
+        let proxy = ProxyConfig::new(ProxyType::Socks5, "proxy;rm -rf ~", 1080);
+        assert!(matches!(
+            proxy.validate(),
+            Err(ProxySupportError::InvalidAddress(_))
+        ));
+        let proxy = ProxyConfig::new(ProxyType::Socks4, "proxy", 1080).with_username("bob");
+        assert_eq!(proxy.validate(), Err(ProxySupportError::Socks4Auth));
+        assert!(
+            ProxyConfig::new(ProxyType::Http, "::1", 3128)
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn client_arguments() {
+        let proxy = ProxyConfig::new(ProxyType::Socks5, "10.0.0.1", 1080);
+        assert_eq!(
+            proxy.ssh_proxy_command(),
+            "ncat --proxy 10.0.0.1:1080 --proxy-type socks5 %h %p"
+        );
+        assert_eq!(proxy.freerdp_arg(), "/proxy:socks5://10.0.0.1:1080");
+        let proxy = ProxyConfig::new(ProxyType::Http, "::1", 3128);
+        assert_eq!(proxy.url(), "http://[::1]:3128");
+    }
+}
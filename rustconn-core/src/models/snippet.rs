@@ -177,6 +177,45 @@ fn is_default_delivery(d: &ScriptDelivery) -> bool {
     matches!(d, ScriptDelivery::Auto)
 }
 
+/// How a snippet variable is prompted for when the snippet is run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum SnippetVariableKind {
+    /// Free-form text
+    #[default]
+    Text,
+    /// One of a fixed list of options
+    Choice {
+        /// Allowed values, in display order
+        options: Vec<String>,
+    },
+    /// Sensitive text, read without echo and masked in previews
+    Secret,
+}
+
+impl SnippetVariableKind {
+    /// Returns `true` for free-form text variables
+    #[must_use]
+    pub const fn is_text(&self) -> bool {
+        matches!(self, Self::Text)
+    }
+
+    /// Returns `true` for secret variables
+    #[must_use]
+    pub const fn is_secret(&self) -> bool {
+        matches!(self, Self::Secret)
+    }
+
+    /// Returns the allowed options of a choice variable
+    #[must_use]
+    pub fn options(&self) -> Option<&[String]> {
+        match self {
+            Self::Choice { options } => Some(options),
+            Self::Text | Self::Secret => None,
+        }
+    }
+}
+
 /// A variable placeholder in a snippet command
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SnippetVariable {
@@ -188,6 +227,9 @@ pub struct SnippetVariable {
     /// Default value for the variable
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_value: Option<String>,
+    /// How the value is prompted for and validated
+    #[serde(default, skip_serializing_if = "SnippetVariableKind::is_text")]
+    pub kind: SnippetVariableKind,
 }
 
 impl SnippetVariable {
@@ -198,9 +240,29 @@ impl SnippetVariable {
             name,
             description: None,
             default_value: None,
+            kind: SnippetVariableKind::Text,
         }
     }
 
+    /// Sets how the variable is prompted for
+    #[must_use]
+    pub fn with_kind(mut self, kind: SnippetVariableKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Makes this a choice variable with the given options
+    #[must_use]
+    pub fn with_choices<I, S>(self, options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.with_kind(SnippetVariableKind::Choice {
+            options: options.into_iter().map(Into::into).collect(),
+        })
+    }
+
     /// Sets the description for this variable
     #[must_use]
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
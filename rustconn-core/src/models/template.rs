@@ -2,6 +2,10 @@
 //!
 //! Templates allow users to define default settings for new connections,
 //! making it easy to create similar connections without repetitive configuration.
+//! A template may name a parent template; fields it leaves unset are taken
+//! from the parent chain (see [`ConnectionTemplate::resolve`]).
+
+use std::collections::HashSet;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -22,6 +26,9 @@ pub enum TemplateError {
     /// Invalid template configuration
     #[error("Invalid template: {0}")]
     Invalid(String),
+    /// The parent chain leads back to a template already visited
+    #[error("Template inheritance cycle at: {0}")]
+    Cycle(Uuid),
 }
 
 /// A connection template with default settings
@@ -41,6 +48,9 @@ pub struct ConnectionTemplate {
     /// Custom icon (emoji or GTK icon name)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
+    /// Parent template whose settings fill the fields this one leaves unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Uuid>,
     /// Protocol type (SSH, RDP, VNC, SPICE)
     pub protocol: ProtocolType,
     /// Default remote host address (can be empty for user to fill in)
@@ -93,6 +103,7 @@ impl ConnectionTemplate {
             name,
             description: None,
             icon: None,
+            parent_id: None,
             protocol,
             host: String::new(),
             port,
@@ -218,6 +229,13 @@ impl ConnectionTemplate {
         self
     }
 
+    /// Sets the parent template
+    #[must_use]
+    pub const fn with_parent(mut self, parent_id: Uuid) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+
     /// Updates the `updated_at` timestamp to now
     pub fn touch(&mut self) {
         self.updated_at = Utc::now();
@@ -270,6 +288,7 @@ impl ConnectionTemplate {
             remember_window_position: false,
             window_geometry: None,
             skip_port_check: false,
+            resolve_via_tailscale: false,
             is_pinned: false,
             pin_order: 0,
             icon: self.icon.clone(),
@@ -282,6 +301,89 @@ impl ConnectionTemplate {
             retry_config: None,
             knock_sequence: None,
             spa_config: None,
+            depends_on: Vec::new(),
+            proxy: None,
+            access_tunnel: None,
+            connect_guard: None,
+            env: Vec::new(),
+            osc52_clipboard: None,
+            scrollback_lines: None,
+        }
+    }
+
+    /// Resolves the parent chain into a single template
+    ///
+    /// Walks from this template up through `parent_id` links, looking each
+    /// parent up in `templates`. A field counts as unset, and is taken from
+    /// the nearest ancestor that sets it, when it is empty/`None`, when the
+    /// port is the protocol's default port, when the password source is
+    /// `None`, or when the protocol configuration is the protocol's default.
+    /// Identity fields (ID, name, timestamps) are always this template's own.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TemplateError::NotFound`] for a missing parent,
+    /// [`TemplateError::Cycle`] when the chain loops, and
+    /// [`TemplateError::Invalid`] when a parent uses another protocol.
+    pub fn resolve(&self, templates: &[Self]) -> Result<Self, TemplateError> {
+        let mut visited = HashSet::from([self.id]);
+        let mut resolved = self.clone();
+        let mut next = self.parent_id;
+        while let Some(parent_id) = next {
+            if !visited.insert(parent_id) {
+                return Err(TemplateError::Cycle(parent_id));
+            }
+            let parent = templates
+                .iter()
+                .find(|t| t.id == parent_id)
+                .ok_or(TemplateError::NotFound(parent_id))?;
+            if parent.protocol != self.protocol {
+                return Err(TemplateError::Invalid(format!(
+                    "parent template '{}' uses {} but '{}' uses {}",
+                    parent.name, parent.protocol, self.name, self.protocol
+                )));
+            }
+            resolved.inherit_from(parent);
+            next = parent.parent_id;
+        }
+        Ok(resolved)
+    }
+
+    /// Fills the fields this template leaves unset from `parent`
+    fn inherit_from(&mut self, parent: &Self) {
+        fn fill<T: Clone>(field: &mut Option<T>, parent: Option<&T>) {
+            if field.is_none() {
+                *field = parent.cloned();
+            }
+        }
+
+        fill(&mut self.description, parent.description.as_ref());
+        fill(&mut self.icon, parent.icon.as_ref());
+        fill(&mut self.username, parent.username.as_ref());
+        fill(&mut self.domain, parent.domain.as_ref());
+        fill(&mut self.pre_connect_task, parent.pre_connect_task.as_ref());
+        fill(
+            &mut self.post_disconnect_task,
+            parent.post_disconnect_task.as_ref(),
+        );
+        fill(&mut self.wol_config, parent.wol_config.as_ref());
+        if self.host.is_empty() {
+            self.host.clone_from(&parent.host);
+        }
+        if self.port == self.protocol.default_port() {
+            self.port = parent.port;
+        }
+        if self.tags.is_empty() {
+            self.tags.clone_from(&parent.tags);
+        }
+        if self.custom_properties.is_empty() {
+            self.custom_properties.clone_from(&parent.custom_properties);
+        }
+        if self.password_source == PasswordSource::None {
+            self.password_source = parent.password_source.clone();
+        }
+        if is_default_protocol_config(&self.protocol_config) {
+            self.protocol_config = parent.protocol_config.clone();
         }
     }
 
@@ -297,6 +399,7 @@ impl ConnectionTemplate {
             name: template_name,
             description: None,
             icon: connection.icon.clone(),
+            parent_id: None,
             protocol: connection.protocol,
             host: connection.host.clone(),
             port: connection.port,
@@ -315,6 +418,36 @@ impl ConnectionTemplate {
     }
 }
 
+/// Returns `true` if `config` is the default configuration of its protocol
+fn is_default_protocol_config(config: &ProtocolConfig) -> bool {
+    match config {
+        ProtocolConfig::Ssh(c) => *c == SshConfig::default(),
+        ProtocolConfig::Rdp(c) => *c == RdpConfig::default(),
+        ProtocolConfig::Vnc(c) => *c == VncConfig::default(),
+        ProtocolConfig::Spice(c) => *c == SpiceConfig::default(),
+        _ => false,
+    }
+}
+
+impl Connection {
+    /// Creates a connection from a template, resolving its parent chain
+    ///
+    /// `templates` holds the templates parents are looked up in. See
+    /// [`ConnectionTemplate::resolve`] for how fields are inherited and
+    /// [`ConnectionTemplate::apply`] for how the connection is built.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`ConnectionTemplate::resolve`].
+    pub fn new_from_template(
+        template: &ConnectionTemplate,
+        templates: &[ConnectionTemplate],
+        name: Option<String>,
+    ) -> Result<Self, TemplateError> {
+        Ok(template.resolve(templates)?.apply(name))
+    }
+}
+
 /// Groups templates by their protocol type
 ///
 /// Returns a map where keys are protocol types and values are vectors of templates.
@@ -481,4 +614,71 @@ mod tests {
         let grouped = group_templates_by_protocol(&templates);
         assert!(grouped.is_empty());
     }
+
+    #[test]
+    fn test_two_level_inheritance() {
+        let mut key_config = SshConfig::default();
+        key_config.key_path = Some("/home/ops/.ssh/id_ed25519".into());
+        let base =
+            ConnectionTemplate::new("Base".to_string(), ProtocolConfig::Ssh(key_config.clone()))
+                .with_username("ops")
+                .with_port(2222)
+                .with_tags(vec!["managed".to_string()]);
+        let region = ConnectionTemplate::new_ssh("EU".to_string())
+            .with_parent(base.id)
+            .with_host("eu.example.com")
+            .with_tags(vec!["eu".to_string()]);
+        let web = ConnectionTemplate::new_ssh("EU Web".to_string())
+            .with_parent(region.id)
+            .with_username("www");
+        let templates = vec![base.clone(), region.clone(), web.clone()];
+
+        let resolved = web.resolve(&templates).unwrap();
+        assert_eq!(resolved.id, web.id);
+        assert_eq!(resolved.name, "EU Web");
+        assert_eq!(resolved.username.as_deref(), Some("www"));
+        assert_eq!(resolved.host, "eu.example.com");
+        assert_eq!(resolved.tags, vec!["eu".to_string()]);
+        assert_eq!(resolved.port, 2222);
+        assert_eq!(resolved.protocol_config, ProtocolConfig::Ssh(key_config));
+
+        let connection =
+            Connection::new_from_template(&web, &templates, Some("web1".to_string())).unwrap();
+        assert_eq!(connection.name, "web1");
+        assert_eq!(connection.host, "eu.example.com");
+        assert_eq!(connection.port, 2222);
+
+        // Grouping looks at each template's own protocol, not the chain
+        let grouped = group_templates_by_protocol(&templates);
+        assert_eq!(grouped.get(&ProtocolType::Ssh).map(Vec::len), Some(3));
+    }
+
+    #[test]
+    fn test_inheritance_errors() {
+        let mut a = ConnectionTemplate::new_ssh("A".to_string());
+        let b = ConnectionTemplate::new_ssh("B".to_string()).with_parent(a.id);
+        a.parent_id = Some(b.id);
+        let templates = vec![a.clone(), b.clone()];
+        assert!(matches!(
+            b.resolve(&templates),
+            Err(TemplateError::Cycle(id)) if id == b.id
+        ));
+        assert!(matches!(
+            Connection::new_from_template(&a, &templates, None),
+            Err(TemplateError::Cycle(_))
+        ));
+
+        let orphan = ConnectionTemplate::new_ssh("Orphan".to_string()).with_parent(Uuid::new_v4());
+        assert!(matches!(
+            orphan.resolve(&templates),
+            Err(TemplateError::NotFound(_))
+        ));
+
+        let rdp = ConnectionTemplate::new_rdp("RDP".to_string());
+        let child = ConnectionTemplate::new_ssh("SSH".to_string()).with_parent(rdp.id);
+        assert!(matches!(
+            child.resolve(&[rdp]),
+            Err(TemplateError::Invalid(_))
+        ));
+    }
 }
@@ -135,6 +135,7 @@ mod tests {
         let conn_id = Uuid::new_v4();
         let tunnel = StandaloneTunnel::new("MySQL", conn_id).with_forward(PortForward {
             direction: PortForwardDirection::Local,
+            bind_address: None,
             local_port: 3306,
             remote_host: "db.internal".to_string(),
             remote_port: 3306,
@@ -149,12 +150,14 @@ mod tests {
         let tunnel = StandaloneTunnel::new("Multi", conn_id)
             .with_forward(PortForward {
                 direction: PortForwardDirection::Local,
+                bind_address: None,
                 local_port: 3306,
                 remote_host: "db.internal".to_string(),
                 remote_port: 3306,
             })
             .with_forward(PortForward {
                 direction: PortForwardDirection::Dynamic,
+                bind_address: None,
                 local_port: 1080,
                 remote_host: String::new(),
                 remote_port: 0,
@@ -177,6 +180,7 @@ mod tests {
         let conn_id = Uuid::new_v4();
         let tunnel = StandaloneTunnel::new("Test", conn_id).with_forward(PortForward {
             direction: PortForwardDirection::Local,
+            bind_address: None,
             local_port: 8080,
             remote_host: "localhost".to_string(),
             remote_port: 80,
@@ -478,6 +478,111 @@ async fn wait_for_control_socket(control_path: &str) -> bool {
     false
 }
 
+/// Connection details shared by every monitoring command of a session.
+struct MonitoringTarget {
+    host: String,
+    port: u16,
+    username: Option<String>,
+    identity_file: Option<String>,
+    jump_host: Option<String>,
+    /// SSH agent variables, see [`crate::sftp::agent_env_vars`]
+    agent_env: Vec<(&'static str, String)>,
+}
+
+/// Builds the `ssh` command that runs `command` on the monitored host.
+///
+/// `socket_ready` selects between joining the terminal session's
+/// ControlMaster and creating a short-lived master of our own.
+fn build_monitoring_command(
+    target: &MonitoringTarget,
+    command: &str,
+    socket_ready: bool,
+    password: Option<&SecretString>,
+    askpass_script: Option<&AskpassScript>,
+) -> Command {
+    let control_path = ssh_control_path(&target.host, target.port);
+    let mut cmd = Command::new("ssh");
+
+    // Same agent as the interactive session: without it, agent-only
+    // connections fail with "Permission denied (publickey)".
+    for (key, value) in &target.agent_env {
+        cmd.env(key, value);
+    }
+
+    if socket_ready {
+        // Socket exists — connect as slave only (no new auth needed).
+        cmd.arg("-o").arg("ControlMaster=no");
+    } else {
+        // Socket not found after timeout — fall back to creating our own
+        // master. This handles edge cases where the main session doesn't
+        // use ControlMaster (e.g., user disabled it in extra_args).
+        tracing::debug!(
+            %control_path,
+            "Monitoring: ControlMaster socket not found, creating own master"
+        );
+        cmd.arg("-o").arg("ControlMaster=auto");
+        cmd.arg("-o").arg("ControlPersist=30");
+    }
+    cmd.arg("-o").arg(format!("ControlPath={control_path}"));
+
+    if let (Some(pw), Some(script)) = (password, askpass_script) {
+        // SSH_ASKPASS mechanism: OpenSSH calls the script to get
+        // the password. DISPLAY must be set (even empty) and
+        // SSH_ASKPASS_REQUIRE=force skips the TTY check.
+        cmd.env("SSH_ASKPASS", &script.0);
+        cmd.env("SSH_ASKPASS_REQUIRE", "force");
+        cmd.env(ASKPASS_ENV_VAR, pw.expose_secret());
+        // Ensure DISPLAY is set so SSH considers ASKPASS
+        if std::env::var("DISPLAY").is_err() {
+            cmd.env("DISPLAY", "");
+        }
+    } else if password.is_none() {
+        // Batch mode only when NOT using password auth
+        cmd.arg("-o").arg("BatchMode=yes");
+    }
+
+    // Accept new host keys but reject changed ones (OpenSSH 7.6+).
+    // Using `accept-new` instead of `no` prevents MITM attacks on
+    // hosts whose key has changed while still allowing first-time
+    // connections without manual intervention.
+    cmd.arg("-o").arg("StrictHostKeyChecking=accept-new");
+
+    // In Flatpak, ~/.ssh is read-only — use writable known_hosts path
+    if let Some(kh_path) = crate::flatpak::get_flatpak_known_hosts_path() {
+        let kh_opt = format!("UserKnownHostsFile={}", kh_path.display());
+        cmd.arg("-o").arg(kh_opt);
+    }
+
+    // Short connection timeout
+    cmd.arg("-o").arg("ConnectTimeout=5");
+
+    // Jump host chain for tunneled connections
+    if let Some(ref jh) = target.jump_host {
+        build_jump_host_args(&mut cmd, jh, target.identity_file.as_deref());
+    }
+
+    if target.port != 22 {
+        cmd.arg("-p").arg(target.port.to_string());
+    }
+
+    if let Some(ref key) = target.identity_file {
+        cmd.arg("-i").arg(key);
+    }
+
+    let destination = if let Some(ref user) = target.username {
+        format!("{user}@{}", target.host)
+    } else {
+        target.host.clone()
+    };
+    cmd.arg(&destination);
+    cmd.arg(command);
+
+    // Suppress stderr to avoid noise
+    cmd.stderr(std::process::Stdio::piped());
+    cmd.stdout(std::process::Stdio::piped());
+    cmd
+}
+
 /// Builds an SSH exec closure for use with [`super::start_collector`].
 ///
 /// The returned closure spawns `ssh` with the given host/port/user and
@@ -488,6 +593,10 @@ async fn wait_for_control_socket(control_path: &str) -> bool {
 /// and `SSH_ASKPASS_REQUIRE=force` tells OpenSSH to invoke it. This
 /// replaces the previous `sshpass` dependency.
 ///
+/// The SSH agent environment is resolved exactly like the interactive
+/// terminal's (see [`crate::sftp::agent_env_vars`]): `ssh_agent_socket`
+/// wins, then the agent RustConn started, then the inherited environment.
+///
 /// # Arguments
 /// * `host` - Remote hostname or IP
 /// * `port` - SSH port
@@ -495,6 +604,7 @@ async fn wait_for_control_socket(control_path: &str) -> bool {
 /// * `identity_file` - Optional path to SSH private key
 /// * `password` - Optional password (as `SecretString`) for SSH_ASKPASS auth
 /// * `jump_host` - Optional jump host chain for `-J` flag (e.g. `"user@bastion:22"`)
+/// * `ssh_agent_socket` - Optional `SSH_AUTH_SOCK` override, as passed to the terminal
 pub fn ssh_exec_factory(
     host: String,
     port: u16,
@@ -502,6 +612,7 @@ pub fn ssh_exec_factory(
     identity_file: Option<String>,
     password: Option<SecretString>,
     jump_host: Option<String>,
+    ssh_agent_socket: Option<&str>,
 ) -> impl Fn(
     String,
 ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<String, String>> + Send>>
@@ -526,92 +637,32 @@ pub fn ssh_exec_factory(
         None
     };
 
+    let target = Arc::new(MonitoringTarget {
+        agent_env: crate::sftp::agent_env_vars(ssh_agent_socket, crate::sftp::get_agent_info()),
+        host,
+        port,
+        username,
+        identity_file,
+        jump_host,
+    });
+
     move |command: String| {
-        let host = host.clone();
-        let username = username.clone();
-        let identity_file = identity_file.clone();
+        let target = Arc::clone(&target);
         let password = password.clone();
-        let jump_host = jump_host.clone();
         let askpass_script = askpass_script.clone();
-        let control_path = ssh_control_path(&host, port);
 
         Box::pin(async move {
-            let mut cmd = Command::new("ssh");
-
             // Wait for the main SSH session's ControlMaster socket to appear.
+            let control_path = ssh_control_path(&target.host, target.port);
             let socket_ready = wait_for_control_socket(&control_path).await;
 
-            if socket_ready {
-                // Socket exists — connect as slave only (no new auth needed).
-                cmd.arg("-o").arg("ControlMaster=no");
-            } else {
-                // Socket not found after timeout — fall back to creating our own
-                // master. This handles edge cases where the main session doesn't
-                // use ControlMaster (e.g., user disabled it in extra_args).
-                tracing::debug!(
-                    %control_path,
-                    "Monitoring: ControlMaster socket not found, creating own master"
-                );
-                cmd.arg("-o").arg("ControlMaster=auto");
-                cmd.arg("-o").arg("ControlPersist=30");
-            }
-            cmd.arg("-o").arg(format!("ControlPath={control_path}"));
-
-            if let (Some(pw), Some(script)) = (&password, &askpass_script) {
-                // SSH_ASKPASS mechanism: OpenSSH calls the script to get
-                // the password. DISPLAY must be set (even empty) and
-                // SSH_ASKPASS_REQUIRE=force skips the TTY check.
-                cmd.env("SSH_ASKPASS", &script.0);
-                cmd.env("SSH_ASKPASS_REQUIRE", "force");
-                cmd.env(ASKPASS_ENV_VAR, pw.expose_secret());
-                // Ensure DISPLAY is set so SSH considers ASKPASS
-                if std::env::var("DISPLAY").is_err() {
-                    cmd.env("DISPLAY", "");
-                }
-            } else if password.is_none() {
-                // Batch mode only when NOT using password auth
-                cmd.arg("-o").arg("BatchMode=yes");
-            }
-
-            // Accept new host keys but reject changed ones (OpenSSH 7.6+).
-            // Using `accept-new` instead of `no` prevents MITM attacks on
-            // hosts whose key has changed while still allowing first-time
-            // connections without manual intervention.
-            cmd.arg("-o").arg("StrictHostKeyChecking=accept-new");
-
-            // In Flatpak, ~/.ssh is read-only — use writable known_hosts path
-            if let Some(kh_path) = crate::flatpak::get_flatpak_known_hosts_path() {
-                let kh_opt = format!("UserKnownHostsFile={}", kh_path.display());
-                cmd.arg("-o").arg(kh_opt);
-            }
-
-            // Short connection timeout
-            cmd.arg("-o").arg("ConnectTimeout=5");
-
-            // Jump host chain for tunneled connections
-            if let Some(ref jh) = jump_host {
-                build_jump_host_args(&mut cmd, jh, identity_file.as_deref());
-            }
-
-            if port != 22 {
-                cmd.arg("-p").arg(port.to_string());
-            }
-
-            if let Some(ref key) = identity_file {
-                cmd.arg("-i").arg(key);
-            }
-
-            let destination = if let Some(ref user) = username {
-                format!("{user}@{host}")
-            } else {
-                host.clone()
-            };
-            cmd.arg(&destination);
-            cmd.arg(&command);
-
-            // Suppress stderr to avoid noise
-            cmd.stderr(std::process::Stdio::piped());
-            cmd.stdout(std::process::Stdio::piped());
+            let mut cmd = build_monitoring_command(
+                &target,
+                &command,
+                socket_ready,
+                password.as_ref(),
+                askpass_script.as_deref(),
+            );
 
             let timeout = Duration::from_secs(SSH_EXEC_TIMEOUT_SECS);
 
@@ -637,3 +688,47 @@ pub fn ssh_exec_factory(
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sftp::{SshAgentInfo, agent_env_vars};
+
+    fn env_of(cmd: &Command, key: &str) -> Option<String> {
+        cmd.as_std()
+            .get_envs()
+            .find(|(k, _)| *k == key)
+            .and_then(|(_, v)| v.map(|v| v.to_string_lossy().into_owned()))
+    }
+
+    #[test]
+    fn monitoring_command_carries_agent_socket() {
+        let agent = SshAgentInfo {
+            socket_path: "/run/user/1000/rc-agent.sock".to_string(),
+            pid: Some("4242".to_string()),
+        };
+        let mut target = MonitoringTarget {
+            host: "agent-only.example".to_string(),
+            port: 22,
+            username: Some("ops".to_string()),
+            identity_file: None,
+            jump_host: None,
+            agent_env: agent_env_vars(None, Some(&agent)),
+        };
+        let cmd = build_monitoring_command(&target, "uptime", true, None, None);
+        assert_eq!(
+            env_of(&cmd, "SSH_AUTH_SOCK").as_deref(),
+            Some("/run/user/1000/rc-agent.sock")
+        );
+        assert_eq!(env_of(&cmd, "SSH_AGENT_PID").as_deref(), Some("4242"));
+
+        // An explicit override wins, just as in the terminal
+        target.agent_env = agent_env_vars(Some("/tmp/custom.sock"), Some(&agent));
+        let cmd = build_monitoring_command(&target, "uptime", true, None, None);
+        assert_eq!(
+            env_of(&cmd, "SSH_AUTH_SOCK").as_deref(),
+            Some("/tmp/custom.sock")
+        );
+        assert_eq!(env_of(&cmd, "SSH_AGENT_PID"), None);
+    }
+}
@@ -1,7 +1,15 @@
 //! Password generator module
 //!
 //! Provides secure password generation with configurable character sets,
-//! length, and entropy estimation.
+//! length, and entropy estimation, plus diceware-style passphrases drawn
+//! from the bundled EFF large wordlist and pronounceable consonant-vowel
+//! passwords.
+//!
+//! [`analyze_password`] rates passwords typed by the user and explains a low
+//! rating with [`PasswordSuggestion`]s (too short, missing character
+//! classes, sequences, repeats and keyboard walks).
+
+use std::sync::OnceLock;
 
 use ring::rand::{SecureRandom, SystemRandom};
 use thiserror::Error;
@@ -9,6 +17,35 @@ use thiserror::Error;
 /// Maximum number of attempts to generate a password meeting all requirements
 const MAX_ATTEMPTS: usize = 100;
 
+/// Minimum number of words in a generated passphrase
+pub const MIN_PASSPHRASE_WORDS: usize = 3;
+
+/// Minimum length of a pronounceable password
+pub const MIN_PRONOUNCEABLE_LENGTH: usize = 4;
+
+/// Consonants opening a pronounceable syllable (`q`, `x` and `y` are left
+/// out as hard to pronounce or read back)
+const SYLLABLE_CONSONANTS: &str = "bcdfghjklmnprstvwz";
+
+/// Vowels closing a pronounceable syllable
+const SYLLABLE_VOWELS: &str = "aeiou";
+
+/// Length below which [`PasswordSuggestion::TooShort`] is reported
+pub const RECOMMENDED_PASSWORD_LENGTH: usize = 12;
+
+/// Shortest run reported as a sequence or repeat (`abc`, `111`)
+const MIN_PATTERN_RUN: usize = 3;
+
+/// Shortest run reported as a keyboard walk (`qwer`)
+const MIN_KEYBOARD_WALK: usize = 4;
+
+/// Keyboard rows checked for walks, in both directions (the digit row is
+/// covered by [`PasswordSuggestion::Sequential`])
+const KEYBOARD_ROWS: &[&str] = &["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Bundled EFF large wordlist (7776 words, one per line, five dice per word)
+const EFF_LARGE_WORDLIST: &str = include_str!("../assets/eff_large_wordlist.txt");
+
 /// Password generator error types
 #[derive(Debug, Error)]
 pub enum PasswordGeneratorError {
@@ -24,6 +61,10 @@ pub enum PasswordGeneratorError {
     #[error("Failed to generate password meeting all requirements after {0} attempts")]
     GenerationFailed(usize),
 
+    /// Passphrase word count is too low
+    #[error("Passphrase must contain at least {0} words")]
+    WordCountTooLow(usize),
+
     /// Random number generation failed
     #[error("Random number generation failed")]
     RngError,
@@ -89,6 +130,22 @@ pub enum PasswordStrength {
 }
 
 impl PasswordStrength {
+    /// Maps an entropy estimate in bits to a strength level
+    #[must_use]
+    pub fn from_entropy(entropy_bits: f64) -> Self {
+        if entropy_bits < 28.0 {
+            Self::VeryWeak
+        } else if entropy_bits < 36.0 {
+            Self::Weak
+        } else if entropy_bits < 60.0 {
+            Self::Fair
+        } else if entropy_bits < 128.0 {
+            Self::Strong
+        } else {
+            Self::VeryStrong
+        }
+    }
+
     /// Returns a human-readable description of the strength
     #[must_use]
     pub const fn description(&self) -> &'static str {
@@ -113,6 +170,94 @@ impl PasswordStrength {
     }
 }
 
+/// Actionable reason behind a low password rating
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PasswordSuggestion {
+    /// Shorter than [`RECOMMENDED_PASSWORD_LENGTH`]
+    TooShort,
+    /// Only one letter case is used
+    NoMixedCase,
+    /// No digits
+    NoDigits,
+    /// No symbols
+    NoSymbols,
+    /// Contains a run such as `abc`, `cba` or `123`
+    Sequential,
+    /// Contains a run of one repeated character such as `aaa`
+    Repeated,
+    /// Contains adjacent keys such as `qwer` or `asdf`
+    KeyboardWalk,
+}
+
+impl PasswordSuggestion {
+    /// Returns a stable identifier for scripting and tests
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::TooShort => "too_short",
+            Self::NoMixedCase => "no_mixed_case",
+            Self::NoDigits => "no_digits",
+            Self::NoSymbols => "no_symbols",
+            Self::Sequential => "sequential",
+            Self::Repeated => "repeated",
+            Self::KeyboardWalk => "keyboard_walk",
+        }
+    }
+
+    /// Returns advice to show the user
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        match self {
+            Self::TooShort => "Use at least 12 characters",
+            Self::NoMixedCase => "Mix upper and lower case letters",
+            Self::NoDigits => "Add digits",
+            Self::NoSymbols => "Add symbols",
+            Self::Sequential => "Avoid sequences like abc or 123",
+            Self::Repeated => "Avoid repeated characters like aaa",
+            Self::KeyboardWalk => "Avoid keyboard patterns like qwerty",
+        }
+    }
+}
+
+/// Rating of a user-chosen password with the reasons behind it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordFeedback {
+    /// Estimated entropy in bits, after discounting detected patterns
+    pub entropy_bits: f64,
+    /// Strength derived from `entropy_bits`
+    pub strength: PasswordStrength,
+    /// Average time to guess the password, see [`estimate_crack_time`]
+    pub crack_time: String,
+    /// What would make the password stronger, most important first
+    pub suggestions: Vec<PasswordSuggestion>,
+}
+
+/// What kind of secret the generator produces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GenerationMode {
+    /// Random characters drawn from the selected character sets
+    #[default]
+    Characters,
+    /// Diceware-style passphrase of words from the EFF large wordlist
+    Passphrase,
+    /// Lowercase consonant-vowel syllables (`tovaributa`), with one digit
+    /// and one symbol placed between syllables when those sets are enabled
+    Pronounceable,
+}
+
+/// Returns the bundled diceware wordlist
+#[must_use]
+pub fn diceware_wordlist() -> &'static [&'static str] {
+    static WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+    WORDS.get_or_init(|| {
+        EFF_LARGE_WORDLIST
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect()
+    })
+}
+
 /// Configuration for password generation
 #[expect(
     clippy::struct_excessive_bools,
@@ -138,6 +283,16 @@ pub struct PasswordGeneratorConfig {
     pub exclude_chars: String,
     /// Require at least one character from each selected set
     pub require_all_sets: bool,
+    /// Character soup or diceware passphrase
+    pub mode: GenerationMode,
+    /// Number of words in a passphrase
+    pub word_count: usize,
+    /// Separator placed between passphrase words
+    pub word_separator: String,
+    /// Capitalize the first letter of every passphrase word
+    pub capitalize_words: bool,
+    /// Append a random digit to one randomly chosen passphrase word
+    pub include_number: bool,
 }
 
 impl Default for PasswordGeneratorConfig {
@@ -152,6 +307,11 @@ impl Default for PasswordGeneratorConfig {
             exclude_ambiguous: false,
             exclude_chars: String::new(),
             require_all_sets: true,
+            mode: GenerationMode::Characters,
+            word_count: 6,
+            word_separator: "-".to_string(),
+            capitalize_words: false,
+            include_number: false,
         }
     }
 }
@@ -226,6 +386,133 @@ impl PasswordGeneratorConfig {
         self
     }
 
+    /// Sets the generation mode
+    #[must_use]
+    pub const fn with_mode(mut self, mode: GenerationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Switches to pronounceable mode with the given length in characters
+    #[must_use]
+    pub const fn with_pronounceable(mut self, length: usize) -> Self {
+        self.mode = GenerationMode::Pronounceable;
+        self.length = length;
+        self
+    }
+
+    /// Switches to passphrase mode with the given number of words
+    #[must_use]
+    pub const fn with_passphrase(mut self, word_count: usize) -> Self {
+        self.mode = GenerationMode::Passphrase;
+        self.word_count = word_count;
+        self
+    }
+
+    /// Sets the number of passphrase words
+    #[must_use]
+    pub const fn with_word_count(mut self, word_count: usize) -> Self {
+        self.word_count = word_count;
+        self
+    }
+
+    /// Sets the separator between passphrase words
+    #[must_use]
+    pub fn with_word_separator(mut self, separator: &str) -> Self {
+        self.word_separator = separator.to_string();
+        self
+    }
+
+    /// Enables or disables capitalizing passphrase words
+    #[must_use]
+    pub const fn with_capitalize_words(mut self, enabled: bool) -> Self {
+        self.capitalize_words = enabled;
+        self
+    }
+
+    /// Enables or disables appending a digit to a passphrase word
+    #[must_use]
+    pub const fn with_include_number(mut self, enabled: bool) -> Self {
+        self.include_number = enabled;
+        self
+    }
+
+    /// Returns the entropy in bits of a passphrase with `word_count` words
+    ///
+    /// Each word contributes `log2(wordlist size)` bits. The optional digit
+    /// adds `log2(10)` for its value plus `log2(word_count)` for its position.
+    /// Capitalization is deterministic and adds nothing.
+    #[must_use]
+    pub fn passphrase_entropy(&self, word_count: usize) -> f64 {
+        if word_count == 0 {
+            return 0.0;
+        }
+
+        let per_word = (diceware_wordlist().len() as f64).log2();
+        let mut entropy = per_word * word_count as f64;
+        if self.include_number {
+            entropy += 10f64.log2() + (word_count as f64).log2();
+        }
+        entropy
+    }
+
+    /// Returns the entropy in bits of a pronounceable password of `length`
+    /// characters
+    ///
+    /// Letters alternate between the consonant and vowel alphabets, so each
+    /// contributes the entropy of its own alphabet rather than of the full
+    /// character pool. The injected digit and symbol add their alphabet plus
+    /// the choice of syllable boundary they are placed at.
+    #[must_use]
+    pub fn pronounceable_entropy(&self, length: usize) -> f64 {
+        let alphabet = self.syllable_alphabet();
+        let Some(letters) = length.checked_sub(alphabet.extras()) else {
+            return 0.0;
+        };
+        if letters == 0 || alphabet.consonants.is_empty() || alphabet.vowels.is_empty() {
+            return 0.0;
+        }
+
+        let consonants = letters.div_ceil(2) as f64;
+        let vowels = (letters / 2) as f64;
+        let mut entropy = consonants * (alphabet.consonants.len() as f64).log2()
+            + vowels * (alphabet.vowels.len() as f64).log2();
+        let mut units = letters.div_ceil(2);
+        for extra in [&alphabet.digits, &alphabet.symbols] {
+            if !extra.is_empty() {
+                entropy += (extra.len() as f64).log2() + ((units + 1) as f64).log2();
+                units += 1;
+            }
+        }
+        entropy
+    }
+
+    /// Returns the alphabets used in pronounceable mode, after exclusions
+    fn syllable_alphabet(&self) -> SyllableAlphabet {
+        let ambiguous = "0O1lI";
+        let keep = |chars: &str| -> Vec<char> {
+            chars
+                .chars()
+                .filter(|c| !(self.exclude_ambiguous && ambiguous.contains(*c)))
+                .filter(|c| !self.exclude_chars.contains(*c))
+                .collect()
+        };
+        SyllableAlphabet {
+            consonants: keep(SYLLABLE_CONSONANTS),
+            vowels: keep(SYLLABLE_VOWELS),
+            digits: if self.use_digits {
+                keep(CharacterSet::Digits.chars())
+            } else {
+                Vec::new()
+            },
+            symbols: if self.use_special {
+                keep(CharacterSet::Special.chars())
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
     /// Returns the minimum required length based on selected character sets
     #[must_use]
     pub fn min_length(&self) -> usize {
@@ -285,6 +572,23 @@ impl PasswordGeneratorConfig {
     }
 }
 
+/// Alphabets of a pronounceable password
+struct SyllableAlphabet {
+    consonants: Vec<char>,
+    vowels: Vec<char>,
+    /// Empty when no digit is injected
+    digits: Vec<char>,
+    /// Empty when no symbol is injected
+    symbols: Vec<char>,
+}
+
+impl SyllableAlphabet {
+    /// Number of non-letter characters injected
+    fn extras(&self) -> usize {
+        usize::from(!self.digits.is_empty()) + usize::from(!self.symbols.is_empty())
+    }
+}
+
 /// Password generator
 pub struct PasswordGenerator {
     config: PasswordGeneratorConfig,
@@ -321,12 +625,20 @@ impl PasswordGenerator {
     /// Returns an error if:
     /// - No character sets are selected
     /// - Password length is too short
+    /// - Passphrase word count is below [`MIN_PASSPHRASE_WORDS`]
+    /// - Pronounceable length is below [`MIN_PRONOUNCEABLE_LENGTH`]
     /// - Failed to generate a password meeting all requirements
     ///
     /// # Panics
     ///
     /// Panics if the system random number generator fails (extremely rare).
     pub fn generate(&self) -> PasswordGeneratorResult<String> {
+        match self.config.mode {
+            GenerationMode::Passphrase => return self.generate_passphrase(),
+            GenerationMode::Pronounceable => return self.generate_pronounceable(),
+            GenerationMode::Characters => {}
+        }
+
         let sets = self.config.selected_sets();
         if sets.is_empty() {
             return Err(PasswordGeneratorError::NoCharacterSets);
@@ -349,13 +661,7 @@ impl PasswordGenerator {
             // Each iteration generates `length` chars; we collect into Result so
             // a single RNG failure short-circuits this attempt.
             let password = (0..self.config.length)
-                .map(|_| {
-                    let mut buf = [0u8; 4];
-                    rng.fill(&mut buf)
-                        .map_err(|_| PasswordGeneratorError::RngError)?;
-                    let idx = u32::from_le_bytes(buf) as usize % pool_chars.len();
-                    Ok::<char, PasswordGeneratorError>(pool_chars[idx])
-                })
+                .map(|_| random_index(&rng, pool_chars.len()).map(|idx| pool_chars[idx]))
                 .collect::<Result<String, _>>()?;
 
             if !self.config.require_all_sets || self.meets_requirements(&password, &sets) {
@@ -366,6 +672,72 @@ impl PasswordGenerator {
         Err(PasswordGeneratorError::GenerationFailed(MAX_ATTEMPTS))
     }
 
+    /// Generates a diceware passphrase from the bundled wordlist
+    fn generate_passphrase(&self) -> PasswordGeneratorResult<String> {
+        let word_count = self.config.word_count;
+        if word_count < MIN_PASSPHRASE_WORDS {
+            return Err(PasswordGeneratorError::WordCountTooLow(
+                MIN_PASSPHRASE_WORDS,
+            ));
+        }
+
+        let wordlist = diceware_wordlist();
+        let rng = SystemRandom::new();
+
+        let mut words = (0..word_count)
+            .map(|_| random_index(&rng, wordlist.len()).map(|idx| wordlist[idx].to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if self.config.capitalize_words {
+            for word in &mut words {
+                *word = capitalize(word);
+            }
+        }
+
+        if self.config.include_number {
+            let position = random_index(&rng, word_count)?;
+            let digit = random_index(&rng, 10)?;
+            words[position].push_str(&digit.to_string());
+        }
+
+        Ok(words.join(&self.config.word_separator))
+    }
+
+    /// Generates consonant-vowel syllables with the digit and symbol placed
+    /// at random syllable boundaries
+    fn generate_pronounceable(&self) -> PasswordGeneratorResult<String> {
+        if self.config.length < MIN_PRONOUNCEABLE_LENGTH {
+            return Err(PasswordGeneratorError::LengthTooShort(
+                MIN_PRONOUNCEABLE_LENGTH,
+            ));
+        }
+        let alphabet = self.config.syllable_alphabet();
+        if alphabet.consonants.is_empty() || alphabet.vowels.is_empty() {
+            return Err(PasswordGeneratorError::NoCharacterSets);
+        }
+
+        let rng = SystemRandom::new();
+        let pick = |chars: &[char]| random_index(&rng, chars.len()).map(|idx| chars[idx]);
+
+        let letters = self.config.length - alphabet.extras();
+        let mut units = Vec::with_capacity(letters.div_ceil(2) + 2);
+        for start in (0..letters).step_by(2) {
+            let mut syllable = String::from(pick(&alphabet.consonants)?);
+            if start + 1 < letters {
+                syllable.push(pick(&alphabet.vowels)?);
+            }
+            units.push(syllable);
+        }
+        for extra in [&alphabet.digits, &alphabet.symbols] {
+            if !extra.is_empty() {
+                let position = random_index(&rng, units.len() + 1)?;
+                units.insert(position, pick(extra)?.to_string());
+            }
+        }
+
+        Ok(units.concat())
+    }
+
     /// Checks if a password meets all character set requirements
     fn meets_requirements(&self, password: &str, sets: &[CharacterSet]) -> bool {
         let ambiguous = "0O1lI";
@@ -398,12 +770,29 @@ impl PasswordGenerator {
     }
 
     /// Calculates the entropy of a password in bits
+    ///
+    /// In pronounceable mode the estimate uses the smaller syllable
+    /// alphabets, see [`PasswordGeneratorConfig::pronounceable_entropy`].
+    /// In passphrase mode the estimate is based on the configured word
+    /// count rather than the number of characters, since each word is one
+    /// pick from the wordlist. The passphrase is not split on the separator
+    /// because a few wordlist entries (`yo-yo`, `t-shirt`) contain hyphens.
     #[must_use]
     pub fn calculate_entropy(&self, password: &str) -> f64 {
         if password.is_empty() {
             return 0.0;
         }
 
+        match self.config.mode {
+            GenerationMode::Passphrase => {
+                return self.config.passphrase_entropy(self.config.word_count);
+            }
+            GenerationMode::Pronounceable => {
+                return self.config.pronounceable_entropy(password.chars().count());
+            }
+            GenerationMode::Characters => {}
+        }
+
         let pool_size = self.config.build_char_pool().len();
         if pool_size == 0 {
             return 0.0;
@@ -416,22 +805,26 @@ impl PasswordGenerator {
     /// Evaluates the strength of a password
     #[must_use]
     pub fn evaluate_strength(&self, password: &str) -> PasswordStrength {
-        let entropy = self.calculate_entropy(password);
-
-        if entropy < 28.0 {
-            PasswordStrength::VeryWeak
-        } else if entropy < 36.0 {
-            PasswordStrength::Weak
-        } else if entropy < 60.0 {
-            PasswordStrength::Fair
-        } else if entropy < 128.0 {
-            PasswordStrength::Strong
-        } else {
-            PasswordStrength::VeryStrong
-        }
+        PasswordStrength::from_entropy(self.calculate_entropy(password))
     }
 }
 
+/// Picks a random index below `bound` from the system RNG
+fn random_index(rng: &SystemRandom, bound: usize) -> PasswordGeneratorResult<usize> {
+    let mut buf = [0u8; 4];
+    rng.fill(&mut buf)
+        .map_err(|_| PasswordGeneratorError::RngError)?;
+    Ok(u32::from_le_bytes(buf) as usize % bound)
+}
+
+/// Uppercases the first character of a word
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
 /// Estimates the time to crack a password given attempts per second
 #[must_use]
 pub fn estimate_crack_time(entropy_bits: f64, attempts_per_second: f64) -> String {
@@ -445,6 +838,147 @@ pub fn estimate_crack_time(entropy_bits: f64, attempts_per_second: f64) -> Strin
     format_duration(seconds)
 }
 
+/// Rates a user-chosen password and explains what weakens it
+///
+/// Entropy is estimated from the character classes actually used, with each
+/// sequence, repeat or keyboard walk counted as a single character, so
+/// `abcdefgh` scores like a one-letter password. Missing character classes
+/// are only suggested while the password is rated below
+/// [`PasswordStrength::Strong`]; a long passphrase is not asked for symbols.
+#[must_use]
+pub fn analyze_password(password: &str, attempts_per_second: f64) -> PasswordFeedback {
+    let chars: Vec<char> = password.chars().collect();
+    let has_lower = chars.iter().any(char::is_ascii_lowercase);
+    let has_upper = chars.iter().any(char::is_ascii_uppercase);
+    let has_digit = chars.iter().any(char::is_ascii_digit);
+    let has_symbol = chars.iter().any(|c| !c.is_ascii_alphanumeric());
+
+    let pool: usize = [
+        (has_lower, CharacterSet::Lowercase.len()),
+        (has_upper, CharacterSet::Uppercase.len()),
+        (has_digit, CharacterSet::Digits.len()),
+        (
+            has_symbol,
+            CharacterSet::Special.len() + CharacterSet::ExtendedSpecial.len(),
+        ),
+    ]
+    .iter()
+    .filter(|(present, _)| *present)
+    .map(|(_, size)| size)
+    .sum();
+
+    let lowered: Vec<char> = chars.iter().map(char::to_ascii_lowercase).collect();
+    let mut in_pattern = vec![false; chars.len()];
+    let mut pattern_runs = 0;
+    let mut suggestions = Vec::new();
+    for (suggestion, runs) in [
+        (PasswordSuggestion::Sequential, sequential_runs(&lowered)),
+        (PasswordSuggestion::Repeated, repeated_runs(&lowered)),
+        (PasswordSuggestion::KeyboardWalk, keyboard_walks(&lowered)),
+    ] {
+        if runs.is_empty() {
+            continue;
+        }
+        suggestions.push(suggestion);
+        for (start, len) in runs {
+            if in_pattern[start..start + len].iter().all(|marked| !marked) {
+                pattern_runs += 1;
+            }
+            in_pattern[start..start + len].fill(true);
+        }
+    }
+    let free_chars = in_pattern.iter().filter(|marked| !**marked).count();
+    let effective_len = free_chars + pattern_runs;
+
+    let entropy_bits = if pool == 0 {
+        0.0
+    } else {
+        (pool as f64).log2() * effective_len as f64
+    };
+    let strength = PasswordStrength::from_entropy(entropy_bits);
+
+    let mut weaknesses = Vec::new();
+    if chars.len() < RECOMMENDED_PASSWORD_LENGTH {
+        weaknesses.push(PasswordSuggestion::TooShort);
+    }
+    if strength < PasswordStrength::Strong {
+        if has_lower != has_upper {
+            weaknesses.push(PasswordSuggestion::NoMixedCase);
+        }
+        if !has_digit {
+            weaknesses.push(PasswordSuggestion::NoDigits);
+        }
+        if !has_symbol {
+            weaknesses.push(PasswordSuggestion::NoSymbols);
+        }
+    }
+    weaknesses.extend(suggestions);
+
+    PasswordFeedback {
+        entropy_bits,
+        strength,
+        crack_time: estimate_crack_time(entropy_bits, attempts_per_second),
+        suggestions: weaknesses,
+    }
+}
+
+/// Finds runs like `abc`, `zyx` or `123` as `(start, len)`
+fn sequential_runs(chars: &[char]) -> Vec<(usize, usize)> {
+    // Direction of a one-step move within letters or digits, if any
+    let step = |a: char, b: char| {
+        let same_class = (a.is_ascii_lowercase() && b.is_ascii_lowercase())
+            || (a.is_ascii_digit() && b.is_ascii_digit());
+        (same_class && u32::from(a).abs_diff(u32::from(b)) == 1).then_some(b > a)
+    };
+    runs_where(chars, MIN_PATTERN_RUN, |window| {
+        let first = step(window[0], window[1]);
+        first.is_some() && window.windows(2).all(|p| step(p[0], p[1]) == first)
+    })
+}
+
+/// Finds runs of one repeated character like `aaa` as `(start, len)`
+fn repeated_runs(chars: &[char]) -> Vec<(usize, usize)> {
+    runs_where(chars, MIN_PATTERN_RUN, |window| {
+        window.iter().all(|c| *c == window[0])
+    })
+}
+
+/// Finds adjacent keys on one keyboard row, either direction, as `(start, len)`
+fn keyboard_walks(chars: &[char]) -> Vec<(usize, usize)> {
+    let rows: Vec<Vec<char>> = KEYBOARD_ROWS
+        .iter()
+        .flat_map(|row| [row.chars().collect(), row.chars().rev().collect()])
+        .collect();
+    runs_where(chars, MIN_KEYBOARD_WALK, |window| {
+        rows.iter()
+            .any(|row| row.windows(window.len()).any(|keys| keys == window))
+    })
+}
+
+/// Returns maximal runs of at least `min_len` characters where `matches`
+/// holds for every window of the run
+fn runs_where(
+    chars: &[char],
+    min_len: usize,
+    matches: impl Fn(&[char]) -> bool,
+) -> Vec<(usize, usize)> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+    while start + min_len <= chars.len() {
+        if !matches(&chars[start..start + min_len]) {
+            start += 1;
+            continue;
+        }
+        let mut end = start + min_len;
+        while end < chars.len() && matches(&chars[start..=end]) {
+            end += 1;
+        }
+        runs.push((start, end - start));
+        start = end;
+    }
+    runs
+}
+
 /// Formats a duration in seconds to a human-readable string
 fn format_duration(seconds: f64) -> String {
     const MINUTE: f64 = 60.0;
@@ -579,4 +1113,199 @@ mod tests {
         let time = estimate_crack_time(128.0, 1_000_000_000.0);
         assert!(time.contains("years") || time.contains("centuries"));
     }
+
+    #[test]
+    fn test_wordlist_size() {
+        // Five dice select one word: 6^5 entries
+        assert_eq!(diceware_wordlist().len(), 7776);
+    }
+
+    #[test]
+    fn test_generate_passphrase() {
+        let config = PasswordGeneratorConfig::new()
+            .with_passphrase(5)
+            .with_word_separator(".");
+        let generator = PasswordGenerator::new(config);
+        let passphrase = generator.generate().unwrap();
+        let words: Vec<&str> = passphrase.split('.').collect();
+        assert_eq!(words.len(), 5);
+        for word in words {
+            assert!(diceware_wordlist().contains(&word));
+        }
+    }
+
+    #[test]
+    fn test_passphrase_capitalize_and_number() {
+        let config = PasswordGeneratorConfig::new()
+            .with_passphrase(4)
+            .with_word_separator(" ")
+            .with_capitalize_words(true)
+            .with_include_number(true);
+        let generator = PasswordGenerator::new(config);
+        let passphrase = generator.generate().unwrap();
+        let words: Vec<&str> = passphrase.split(' ').collect();
+        assert_eq!(words.len(), 4);
+        assert!(
+            words
+                .iter()
+                .all(|w| w.starts_with(|c: char| c.is_ascii_uppercase()))
+        );
+        assert_eq!(passphrase.chars().filter(char::is_ascii_digit).count(), 1);
+    }
+
+    #[test]
+    fn test_pronounceable_alternates_syllables() {
+        let config = PasswordGeneratorConfig::new()
+            .with_pronounceable(13)
+            .with_special(false);
+        let generator = PasswordGenerator::new(config);
+        for _ in 0..20 {
+            let password = generator.generate().unwrap();
+            assert_eq!(password.chars().count(), 13);
+            assert_eq!(password.chars().filter(char::is_ascii_digit).count(), 1);
+
+            let letters: Vec<char> = password.chars().filter(char::is_ascii_alphabetic).collect();
+            assert_eq!(letters.len(), 12);
+            for (i, c) in letters.iter().enumerate() {
+                let alphabet = if i % 2 == 0 {
+                    SYLLABLE_CONSONANTS
+                } else {
+                    SYLLABLE_VOWELS
+                };
+                assert!(alphabet.contains(*c), "{password}: {c} at {i}");
+            }
+            // The digit sits between syllables, never inside one
+            let digit_at = password.find(|c: char| c.is_ascii_digit()).unwrap();
+            assert_eq!(digit_at % 2, 0, "{password}");
+        }
+
+        let too_short = PasswordGeneratorConfig::new().with_pronounceable(3);
+        assert!(matches!(
+            PasswordGenerator::new(too_short).generate(),
+            Err(PasswordGeneratorError::LengthTooShort(
+                MIN_PRONOUNCEABLE_LENGTH
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_pronounceable_entropy_uses_syllable_space() {
+        let letters_only = PasswordGeneratorConfig::new()
+            .with_pronounceable(10)
+            .with_digits(false)
+            .with_special(false);
+        let generator = PasswordGenerator::new(letters_only);
+        let password = generator.generate().unwrap();
+        let syllable_bits = (18f64 * 5.0).log2();
+        assert!((generator.calculate_entropy(&password) - 5.0 * syllable_bits).abs() < 1e-9);
+        // Far below the 10 × log2(26) of random lowercase letters
+        assert!(generator.calculate_entropy(&password) < 10.0 * 26f64.log2());
+
+        // 11 letters (6 syllables), then a digit among 7 boundaries and a
+        // symbol among 8
+        let config = PasswordGeneratorConfig::new().with_pronounceable(13);
+        let expected = 6.0 * 18f64.log2()
+            + 5.0 * 5f64.log2()
+            + 10f64.log2()
+            + 7f64.log2()
+            + 12f64.log2()
+            + 8f64.log2();
+        assert!((config.pronounceable_entropy(13) - expected).abs() < 1e-9);
+        assert_eq!(
+            estimate_crack_time(config.pronounceable_entropy(13), 1e10),
+            estimate_crack_time(expected, 1e10)
+        );
+    }
+
+    #[test]
+    fn test_passphrase_word_count_too_low() {
+        let config = PasswordGeneratorConfig::new().with_passphrase(2);
+        let generator = PasswordGenerator::new(config);
+        assert!(matches!(
+            generator.generate(),
+            Err(PasswordGeneratorError::WordCountTooLow(
+                MIN_PASSPHRASE_WORDS
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_passphrase_entropy_scales_with_word_count() {
+        let per_word = 7776f64.log2();
+        let mut previous = 0.0;
+        for words in 3..=10 {
+            let config = PasswordGeneratorConfig::new().with_passphrase(words);
+            let generator = PasswordGenerator::new(config);
+            let passphrase = generator.generate().unwrap();
+            let entropy = generator.calculate_entropy(&passphrase);
+            assert!((entropy - per_word * words as f64).abs() < 1e-9);
+            assert!(entropy > previous);
+            previous = entropy;
+        }
+    }
+
+    fn codes(password: &str) -> Vec<&'static str> {
+        analyze_password(password, 10_000_000_000.0)
+            .suggestions
+            .iter()
+            .map(PasswordSuggestion::code)
+            .collect()
+    }
+
+    #[test]
+    fn test_weak_passwords_explain_themselves() {
+        assert_eq!(
+            codes("password"),
+            ["too_short", "no_mixed_case", "no_digits", "no_symbols"]
+        );
+        assert!(codes("Xabcdefgh!9").contains(&"sequential"));
+        assert!(codes("Secret987654!").contains(&"sequential"));
+        assert!(codes("Zaaaaaa!7kq").contains(&"repeated"));
+        assert_eq!(
+            codes("qwertyuiop"),
+            [
+                "too_short",
+                "no_mixed_case",
+                "no_digits",
+                "no_symbols",
+                "keyboard_walk"
+            ]
+        );
+        assert!(codes("Mylkjhgfdsa#42").contains(&"keyboard_walk"));
+
+        let walk = analyze_password("qwertyuiop", 1e10);
+        assert_eq!(walk.strength, PasswordStrength::VeryWeak);
+        assert_eq!(walk.crack_time, "instant");
+    }
+
+    #[test]
+    fn test_strong_password_has_no_suggestions() {
+        let feedback = analyze_password("x7#Kp2!vQm9@Lr4$", 1e10);
+        assert!(feedback.suggestions.is_empty(), "{feedback:?}");
+        assert_eq!(feedback.strength, PasswordStrength::Strong);
+
+        // Long enough lowercase passphrases are not asked for symbols
+        let feedback = analyze_password("correct horse battery staple", 1e10);
+        assert!(feedback.suggestions.is_empty(), "{feedback:?}");
+    }
+
+    #[test]
+    fn test_passphrase_strength() {
+        // 6 words ≈ 77.5 bits, well above a 12-letter lowercase password
+        let config = PasswordGeneratorConfig::new().with_passphrase(6);
+        let generator = PasswordGenerator::new(config);
+        let passphrase = generator.generate().unwrap();
+        assert_eq!(
+            generator.evaluate_strength(&passphrase),
+            PasswordStrength::Strong
+        );
+
+        let config = PasswordGeneratorConfig::new().with_passphrase(3);
+        let generator = PasswordGenerator::new(config);
+        let passphrase = generator.generate().unwrap();
+        assert_eq!(
+            generator.evaluate_strength(&passphrase),
+            PasswordStrength::Fair
+        );
+    }
 }
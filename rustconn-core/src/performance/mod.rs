@@ -1,17 +1,26 @@
 //! Performance utilities for `RustConn`.
 //!
-//! Two utilities are in active use:
 //! - [`StringInterner`] — deduplicates frequently repeated connection strings
 //!   (protocol names, hostnames, usernames) to reduce memory usage.
 //! - [`Debouncer`] — rate-limits rapid operations (e.g. search input).
+//! - [`ObjectPool`] — reuses values that are costly to build, such as the
+//!   widget scaffolding of terminal tabs. See [`pool`] for the reset contract.
+//! - [`GroupedVirtualScroller`] — visible-range and sticky-header computation
+//!   for large grouped lists.
 
 use std::sync::{Mutex, MutexGuard, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 mod debouncer;
 pub mod interner;
+pub mod pool;
+mod virtual_scroller;
 
 pub use debouncer::Debouncer;
 pub use interner::{InternerStats, StringInterner};
+pub use pool::{ObjectPool, PoolStats, Poolable};
+pub use virtual_scroller::{
+    GroupedVirtualScroller, RowKind, ScrollWindow, StickyHeader, VirtualScrollConfig,
+};
 
 /// Acquires a `Mutex` lock, logging and returning `None` on poison.
 pub(crate) fn lock_mutex<'a, T>(mutex: &'a Mutex<T>, name: &str) -> Option<MutexGuard<'a, T>> {
@@ -0,0 +1,201 @@
+//! Object pool for reusing expensive-to-build values.
+//!
+//! # Reset contract
+//!
+//! A pooled value must be indistinguishable from a freshly created one when
+//! it is acquired again. [`ObjectPool::release`] calls [`Poolable::reset`]
+//! before storing a value, and `reset` must:
+//!
+//! - drop all per-use content (children, buffers, scrollback),
+//! - disconnect every callback or signal handler added while in use,
+//! - restore any state a caller may have changed to its initial value.
+//!
+//! Values that cannot be fully reset should not be pooled; return `false`
+//! from [`Poolable::reset`] and the pool drops them instead.
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::lock_mutex;
+
+/// A value that can be returned to an [`ObjectPool`]
+pub trait Poolable {
+    /// Restores the value to its freshly created state
+    ///
+    /// Returns `false` if that is not possible; the value is then dropped
+    /// instead of pooled.
+    fn reset(&mut self) -> bool;
+}
+
+/// Statistics for an object pool
+#[derive(Debug, Default)]
+pub struct PoolStats {
+    /// Values created by `acquire` because the pool was empty
+    pub created: AtomicUsize,
+    /// Values handed out from the pool
+    pub reused: AtomicUsize,
+    /// Values created by `warm`
+    pub warmed: AtomicUsize,
+    /// Values returned to the pool
+    pub released: AtomicUsize,
+    /// Values dropped on release because the pool was full or reset failed
+    pub discarded: AtomicUsize,
+}
+
+/// Bounded pool of reusable values
+///
+/// Holds at most `capacity` idle values. Values are handed out most
+/// recently released first.
+#[derive(Debug)]
+pub struct ObjectPool<T> {
+    /// Idle values
+    items: Mutex<Vec<T>>,
+    /// Maximum number of idle values
+    capacity: usize,
+    /// Statistics
+    stats: PoolStats,
+}
+
+impl<T: Poolable> ObjectPool<T> {
+    /// Creates an empty pool holding at most `capacity` idle values
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            items: Mutex::new(Vec::with_capacity(capacity)),
+            capacity,
+            stats: PoolStats::default(),
+        }
+    }
+
+    /// Takes an idle value, or builds one with `create` if the pool is empty
+    pub fn acquire(&self, create: impl FnOnce() -> T) -> T {
+        let pooled = lock_mutex(&self.items, "object_pool").and_then(|mut items| items.pop());
+        if let Some(item) = pooled {
+            self.stats.reused.fetch_add(1, Ordering::Relaxed);
+            item
+        } else {
+            self.stats.created.fetch_add(1, Ordering::Relaxed);
+            create()
+        }
+    }
+
+    /// Resets `item` and returns it to the pool
+    ///
+    /// Returns `false` if the item was dropped because the pool is full or
+    /// [`Poolable::reset`] failed.
+    pub fn release(&self, mut item: T) -> bool {
+        let Some(mut items) = lock_mutex(&self.items, "object_pool") else {
+            self.stats.discarded.fetch_add(1, Ordering::Relaxed);
+            return false;
+        };
+        if items.len() >= self.capacity || !item.reset() {
+            self.stats.discarded.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+        items.push(item);
+        self.stats.released.fetch_add(1, Ordering::Relaxed);
+        true
+    }
+
+    /// Fills the pool up to `count` idle values (at most its capacity)
+    ///
+    /// Returns the number of values created.
+    pub fn warm(&self, count: usize, mut create: impl FnMut() -> T) -> usize {
+        let Some(mut items) = lock_mutex(&self.items, "object_pool") else {
+            return 0;
+        };
+        let target = count.min(self.capacity);
+        let missing = target.saturating_sub(items.len());
+        items.extend((0..missing).map(|_| create()));
+        self.stats.warmed.fetch_add(missing, Ordering::Relaxed);
+        missing
+    }
+
+    /// Returns the number of idle values
+    #[must_use]
+    pub fn available(&self) -> usize {
+        lock_mutex(&self.items, "object_pool").map_or(0, |items| items.len())
+    }
+
+    /// Returns the maximum number of idle values
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Gets the pool statistics
+    #[must_use]
+    pub const fn stats(&self) -> &PoolStats {
+        &self.stats
+    }
+
+    /// Drops all idle values
+    pub fn clear(&self) {
+        if let Some(mut items) = lock_mutex(&self.items, "object_pool") {
+            items.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Buffer whose contents must be cleared before reuse
+    #[derive(Debug, Default)]
+    struct Buffer {
+        data: Vec<u8>,
+        poisoned: bool,
+    }
+
+    impl Poolable for Buffer {
+        fn reset(&mut self) -> bool {
+            self.data.clear();
+            !self.poisoned
+        }
+    }
+
+    #[test]
+    fn test_acquire_release_accounting() {
+        let pool = ObjectPool::<Buffer>::new(2);
+        let mut first = pool.acquire(Buffer::default);
+        assert_eq!(pool.stats().created.load(Ordering::Relaxed), 1);
+        first.data.extend_from_slice(b"scrollback");
+
+        assert!(pool.release(first));
+        assert_eq!(pool.available(), 1);
+
+        // The reused value was reset on release
+        let reused = pool.acquire(|| unreachable!("pool is not empty"));
+        assert!(reused.data.is_empty());
+        assert_eq!(pool.stats().reused.load(Ordering::Relaxed), 1);
+        assert_eq!(pool.available(), 0);
+
+        let poisoned = Buffer {
+            poisoned: true,
+            ..Buffer::default()
+        };
+        assert!(!pool.release(poisoned));
+        assert!(pool.release(reused));
+        assert_eq!(pool.stats().released.load(Ordering::Relaxed), 2);
+        assert_eq!(pool.stats().discarded.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_warm_respects_capacity() {
+        let pool = ObjectPool::<Buffer>::new(3);
+        assert_eq!(pool.warm(2, Buffer::default), 2);
+        assert_eq!(pool.warm(5, Buffer::default), 1);
+        assert_eq!(pool.available(), 3);
+        assert_eq!(pool.stats().warmed.load(Ordering::Relaxed), 3);
+
+        // Full pool drops released values
+        assert!(!pool.release(Buffer::default()));
+        assert_eq!(pool.available(), 3);
+
+        let _ = pool.acquire(Buffer::default);
+        assert_eq!(pool.stats().created.load(Ordering::Relaxed), 0);
+        pool.clear();
+        assert_eq!(pool.available(), 0);
+    }
+}
@@ -0,0 +1,397 @@
+//! Virtual scrolling over grouped lists with sticky group headers.
+//!
+//! [`GroupedVirtualScroller`] is the data layer behind a virtualized sidebar:
+//! it knows the height of every row (group headers and items may differ),
+//! computes which rows intersect the viewport for a scroll offset, and
+//! reports which group header should be pinned to the top of the viewport.
+//! Rendering is left to the GUI.
+//!
+//! [`VirtualScrollConfig`] sets the header and item heights and the overscan;
+//! with [`GroupedVirtualScroller::from_config`], rows are then given by kind
+//! alone through [`GroupedVirtualScroller::set_row_kinds`].
+
+use std::ops::Range;
+
+/// Kind of a row in a grouped list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowKind {
+    /// A group header; items after it belong to this group until the next header
+    Header,
+    /// A regular item row
+    Item,
+}
+
+/// Header pinned to the top of the viewport.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StickyHeader {
+    /// Row index of the header
+    pub row: usize,
+    /// Vertical offset of the pinned header relative to the viewport top.
+    ///
+    /// `0.0` while the header is fully pinned; negative while the next group's
+    /// header pushes it up and out of the viewport.
+    pub offset: f64,
+}
+
+/// Result of a viewport computation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrollWindow {
+    /// Rows to render, including overscan
+    pub range: Range<usize>,
+    /// Header to draw pinned at the top of the viewport, if any
+    pub sticky: Option<StickyHeader>,
+}
+
+/// Row heights and overscan for a [`GroupedVirtualScroller`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VirtualScrollConfig {
+    /// Height of an item row in pixels
+    pub item_height: f64,
+    /// Height of a group header row in pixels
+    pub header_height: f64,
+    /// Extra rows rendered above and below the viewport
+    pub overscan: usize,
+}
+
+impl Default for VirtualScrollConfig {
+    fn default() -> Self {
+        Self {
+            item_height: 36.0,
+            header_height: 36.0,
+            overscan: 10,
+        }
+    }
+}
+
+impl VirtualScrollConfig {
+    /// Returns the height of a row of `kind`
+    #[must_use]
+    pub const fn row_height(&self, kind: RowKind) -> f64 {
+        match kind {
+            RowKind::Header => self.header_height,
+            RowKind::Item => self.item_height,
+        }
+    }
+}
+
+/// Virtual scroller for lists that interleave group headers and items.
+///
+/// Row heights may vary. Offsets are kept as prefix sums, so lookups are
+/// `O(log n)` and safe for lists with thousands of rows.
+#[derive(Debug, Clone)]
+pub struct GroupedVirtualScroller {
+    kinds: Vec<RowKind>,
+    /// `offsets[i]` is the top of row `i`; the last entry is the total height.
+    offsets: Vec<f64>,
+    /// Row indices of all headers, ascending
+    headers: Vec<usize>,
+    viewport_height: f64,
+    scroll_offset: f64,
+    overscan: usize,
+    /// Row heights used by `set_row_kinds`
+    config: VirtualScrollConfig,
+}
+
+impl GroupedVirtualScroller {
+    /// Creates an empty scroller for a viewport of the given height.
+    #[must_use]
+    pub fn new(viewport_height: f64) -> Self {
+        Self {
+            kinds: Vec::new(),
+            offsets: vec![0.0],
+            headers: Vec::new(),
+            viewport_height: viewport_height.max(0.0),
+            scroll_offset: 0.0,
+            overscan: 0,
+            config: VirtualScrollConfig::default(),
+        }
+    }
+
+    /// Creates an empty scroller whose row heights and overscan come from
+    /// `config`.
+    #[must_use]
+    pub fn from_config(viewport_height: f64, config: VirtualScrollConfig) -> Self {
+        let mut scroller = Self::new(viewport_height).with_overscan(config.overscan);
+        scroller.config = config;
+        scroller
+    }
+
+    /// Returns the configuration used by [`Self::set_row_kinds`].
+    #[must_use]
+    pub const fn config(&self) -> &VirtualScrollConfig {
+        &self.config
+    }
+
+    /// Sets how many extra rows to render above and below the viewport.
+    #[must_use]
+    pub const fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Replaces all rows with `(kind, height)` pairs in display order.
+    ///
+    /// Negative heights are treated as zero. The scroll offset is re-clamped
+    /// to the new content height.
+    pub fn set_rows(&mut self, rows: impl IntoIterator<Item = (RowKind, f64)>) {
+        self.kinds.clear();
+        self.headers.clear();
+        self.offsets.clear();
+        self.offsets.push(0.0);
+
+        let mut top = 0.0;
+        for (index, (kind, height)) in rows.into_iter().enumerate() {
+            if kind == RowKind::Header {
+                self.headers.push(index);
+            }
+            self.kinds.push(kind);
+            top += height.max(0.0);
+            self.offsets.push(top);
+        }
+        self.scroll_offset = self.clamp_offset(self.scroll_offset);
+    }
+
+    /// Replaces all rows, taking each row's height from the configuration.
+    pub fn set_row_kinds(&mut self, kinds: impl IntoIterator<Item = RowKind>) {
+        let config = self.config;
+        self.set_rows(
+            kinds
+                .into_iter()
+                .map(|kind| (kind, config.row_height(kind))),
+        );
+    }
+
+    /// Updates the viewport height (e.g. after a window resize).
+    pub fn set_viewport_height(&mut self, height: f64) {
+        self.viewport_height = height.max(0.0);
+        self.scroll_offset = self.clamp_offset(self.scroll_offset);
+    }
+
+    /// Sets the scroll offset, clamped to the scrollable range.
+    pub fn set_scroll_offset(&mut self, offset: f64) {
+        self.scroll_offset = self.clamp_offset(offset);
+    }
+
+    /// Returns the current scroll offset.
+    #[must_use]
+    pub const fn scroll_offset(&self) -> f64 {
+        self.scroll_offset
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.kinds.len()
+    }
+
+    /// Returns `true` when there are no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.kinds.is_empty()
+    }
+
+    /// Returns the kind of the row at `index`.
+    #[must_use]
+    pub fn row_kind(&self, index: usize) -> Option<RowKind> {
+        self.kinds.get(index).copied()
+    }
+
+    /// Returns the total content height.
+    #[must_use]
+    pub fn total_height(&self) -> f64 {
+        self.offsets.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the top offset of the row at `index`.
+    #[must_use]
+    pub fn row_top(&self, index: usize) -> Option<f64> {
+        (index < self.kinds.len()).then(|| self.offsets[index])
+    }
+
+    /// Returns the height of the row at `index`.
+    #[must_use]
+    pub fn row_height(&self, index: usize) -> Option<f64> {
+        (index < self.kinds.len()).then(|| self.offsets[index + 1] - self.offsets[index])
+    }
+
+    /// Returns the row that contains the content offset `y`.
+    #[must_use]
+    pub fn row_at(&self, y: f64) -> Option<usize> {
+        if self.kinds.is_empty() || y < 0.0 || y >= self.total_height() {
+            return None;
+        }
+        // Last row whose top is <= y; zero-height rows are skipped over.
+        let index = self.offsets[..self.kinds.len()].partition_point(|&top| top <= y);
+        Some(index.saturating_sub(1))
+    }
+
+    /// Returns the rows intersecting the viewport, extended by the overscan.
+    #[must_use]
+    pub fn visible_range(&self) -> Range<usize> {
+        let len = self.kinds.len();
+        if len == 0 {
+            return 0..0;
+        }
+        let top = self.scroll_offset;
+        let bottom = top + self.viewport_height;
+        let first = self.row_at(top).unwrap_or(len);
+        let last = self.offsets[..len].partition_point(|&row_top| row_top < bottom);
+
+        let start = first.saturating_sub(self.overscan);
+        let end = (last + self.overscan).min(len);
+        start..end.max(start)
+    }
+
+    /// Returns the header to pin at the top of the viewport.
+    ///
+    /// This is the header of the group containing the first visible row.
+    /// When the next group's header scrolls within the pinned header's
+    /// height, the returned offset goes negative so the GUI can slide the
+    /// pinned header up as the next one pushes it out.
+    #[must_use]
+    pub fn sticky_header(&self) -> Option<StickyHeader> {
+        let first = self.row_at(self.scroll_offset)?;
+        let position = self.headers.partition_point(|&h| h <= first);
+        let row = *self.headers.get(position.checked_sub(1)?)?;
+        let height = self.offsets[row + 1] - self.offsets[row];
+
+        let offset = self
+            .headers
+            .get(position)
+            .map(|&next| self.offsets[next] - self.scroll_offset)
+            .filter(|&next_top| next_top < height)
+            .map_or(0.0, |next_top| next_top - height);
+
+        Some(StickyHeader { row, offset })
+    }
+
+    /// Returns the visible range together with the sticky header.
+    #[must_use]
+    pub fn window(&self) -> ScrollWindow {
+        ScrollWindow {
+            range: self.visible_range(),
+            sticky: self.sticky_header(),
+        }
+    }
+
+    fn clamp_offset(&self, offset: f64) -> f64 {
+        let max = (self.total_height() - self.viewport_height).max(0.0);
+        if offset.is_nan() {
+            0.0
+        } else {
+            offset.clamp(0.0, max)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two groups: header (30) + 5 items (20) each → 130 per group.
+    fn two_groups() -> GroupedVirtualScroller {
+        let mut scroller = GroupedVirtualScroller::new(100.0);
+        let group = std::iter::once((RowKind::Header, 30.0))
+            .chain(std::iter::repeat_n((RowKind::Item, 20.0), 5));
+        scroller.set_rows(group.clone().chain(group));
+        scroller
+    }
+
+    #[test]
+    fn empty_scroller_has_no_rows() {
+        let scroller = GroupedVirtualScroller::new(100.0);
+        assert!(scroller.is_empty());
+        assert_eq!(scroller.visible_range(), 0..0);
+        assert!(scroller.sticky_header().is_none());
+    }
+
+    #[test]
+    fn row_lookup_handles_variable_heights() {
+        let scroller = two_groups();
+        assert!((scroller.total_height() - 260.0).abs() < f64::EPSILON);
+        assert_eq!(scroller.row_at(0.0), Some(0));
+        assert_eq!(scroller.row_at(29.9), Some(0));
+        assert_eq!(scroller.row_at(30.0), Some(1));
+        assert_eq!(scroller.row_at(130.0), Some(6));
+        assert_eq!(scroller.row_at(260.0), None);
+        assert_eq!(scroller.row_height(6), Some(30.0));
+    }
+
+    #[test]
+    fn visible_range_respects_overscan() {
+        let mut scroller = two_groups().with_overscan(1);
+        scroller.set_scroll_offset(50.0);
+        // Viewport 50..150 covers rows 2..=6, plus one row of overscan each side.
+        assert_eq!(scroller.visible_range(), 1..8);
+    }
+
+    #[test]
+    fn sticky_header_tracks_current_group() {
+        let mut scroller = two_groups();
+        scroller.set_scroll_offset(60.0);
+        assert_eq!(
+            scroller.sticky_header(),
+            Some(StickyHeader {
+                row: 0,
+                offset: 0.0
+            })
+        );
+
+        scroller.set_scroll_offset(140.0);
+        assert_eq!(scroller.sticky_header().map(|h| h.row), Some(6));
+    }
+
+    #[test]
+    fn next_header_pushes_sticky_header_up() {
+        let mut scroller = two_groups();
+        // Second header starts at 130; at offset 110 it is 20px below the top,
+        // overlapping the 30px pinned header by 10px.
+        scroller.set_scroll_offset(110.0);
+        let sticky = scroller.sticky_header().expect("sticky header");
+        assert_eq!(sticky.row, 0);
+        assert!((sticky.offset + 10.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn items_before_first_header_have_no_sticky_header() {
+        let mut scroller = GroupedVirtualScroller::new(50.0);
+        scroller.set_rows([
+            (RowKind::Item, 20.0),
+            (RowKind::Item, 20.0),
+            (RowKind::Header, 30.0),
+            (RowKind::Item, 20.0),
+        ]);
+        assert!(scroller.sticky_header().is_none());
+        scroller.set_scroll_offset(40.0);
+        assert_eq!(scroller.sticky_header().map(|h| h.row), Some(2));
+    }
+
+    #[test]
+    fn config_drives_heights_and_overscan() {
+        let config = VirtualScrollConfig {
+            item_height: 20.0,
+            header_height: 30.0,
+            overscan: 2,
+        };
+        let mut scroller = GroupedVirtualScroller::from_config(100.0, config);
+        let group = std::iter::once(RowKind::Header).chain(std::iter::repeat_n(RowKind::Item, 5));
+        scroller.set_row_kinds(group.clone().chain(group));
+
+        assert!((scroller.total_height() - 260.0).abs() < f64::EPSILON);
+        assert_eq!(scroller.row_height(6), Some(30.0));
+        scroller.set_scroll_offset(50.0);
+        // Viewport 50..150 covers rows 2..=6, plus two rows of overscan each side.
+        assert_eq!(scroller.visible_range(), 0..9);
+    }
+
+    #[test]
+    fn scroll_offset_is_clamped() {
+        let mut scroller = two_groups();
+        scroller.set_scroll_offset(10_000.0);
+        assert!((scroller.scroll_offset() - 160.0).abs() < f64::EPSILON);
+        scroller.set_scroll_offset(-5.0);
+        assert!(scroller.scroll_offset().abs() < f64::EPSILON);
+        scroller.set_viewport_height(500.0);
+        assert_eq!(scroller.window().range, 0..12);
+    }
+}
@@ -8,6 +8,9 @@ use std::process::Command;
 use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
+use super::VncProtocol;
+use crate::models::VncConfig;
+
 /// Information about a detected protocol client
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ClientInfo {
@@ -252,7 +255,8 @@ pub fn detect_rdp_client() -> ClientInfo {
 /// Detects the VNC client on the system
 ///
 /// Checks for various VNC viewer binaries and extracts version information.
-/// Supported viewers: vncviewer (TigerVNC/TightVNC), gvncviewer, xvnc4viewer, vinagre, remmina, krdc
+/// Supported viewers: vncviewer (TigerVNC/TightVNC), gvncviewer, xvnc4viewer, ssvncviewer, vinagre,
+/// remmina, krdc
 #[must_use]
 pub fn detect_vnc_client() -> ClientInfo {
     // Try vncviewer (TigerVNC/TightVNC) - most common
@@ -275,6 +279,11 @@ pub fn detect_vnc_client() -> ClientInfo {
         return info;
     }
 
+    // Try ssvncviewer (SSVNC, the only viewer that supports repeaters)
+    if let Some(info) = try_detect_client("SSVNC Viewer", "ssvncviewer", &["-h"]) {
+        return info;
+    }
+
     // Try vinagre (GNOME Remote Desktop Viewer - deprecated but still available)
     if let Some(info) = try_detect_client("Vinagre", "vinagre", &["--version"]) {
         return info;
@@ -323,6 +332,7 @@ const VNC_VIEWERS: &[&str] = &[
     "tigervnc",    // TigerVNC specific binary name
     "gvncviewer",  // GTK-VNC viewer
     "xvnc4viewer", // RealVNC
+    "ssvncviewer", // SSVNC (TightVNC-based, supports repeaters)
     "vinagre",     // GNOME Vinagre (deprecated but still available)
     "remmina",     // Remmina (supports VNC)
     "krdc",        // KDE Remote Desktop Client
@@ -355,6 +365,19 @@ pub fn detect_vnc_viewer_name() -> Option<String> {
         .map(|viewer| (*viewer).to_string())
 }
 
+/// Returns the name of the VNC viewer to launch for `config`
+///
+/// Connections through a repeater prefer [`VncProtocol::REPEATER_VIEWER`],
+/// the only viewer that speaks the repeater handshake; otherwise this is
+/// [`detect_vnc_viewer_name`].
+#[must_use]
+pub fn detect_vnc_viewer_name_for(config: &VncConfig) -> Option<String> {
+    if config.repeater.is_some() && which_binary(VncProtocol::REPEATER_VIEWER).is_some() {
+        return Some(VncProtocol::REPEATER_VIEWER.to_string());
+    }
+    detect_vnc_viewer_name()
+}
+
 // ============================================================================
 // Zero Trust CLI Detection
 // ============================================================================
@@ -654,6 +677,7 @@ fn parse_version(output: &str) -> Option<String> {
         // vncviewer: "TigerVNC Viewer 64-bit v1.12.0"
         // remote-viewer: "remote-viewer version 11.0"
         // waypipe: "waypipe 0.11.0"
+        // mosh: "mosh 1.4.0 [build mosh 1.4.0]"
 
         // SSH: extract "OpenSSH_X.Yp1" before the comma
         if line.contains("OpenSSH") {
@@ -687,6 +711,14 @@ fn parse_version(output: &str) -> Option<String> {
             }
         }
 
+        // mosh: "mosh 1.4.0 [build mosh 1.4.0]" → "1.4.0"
+        if let Some(rest) = line.strip_prefix("mosh ")
+            && let Some(ver) = rest.split_whitespace().next()
+            && ver.starts_with(|c: char| c.is_ascii_digit())
+        {
+            return Some(ver.to_string());
+        }
+
         // Return the first meaningful line as version info
         if line.contains("version")
             || line.contains("rdesktop")
@@ -837,6 +869,13 @@ mod tests {
         assert_eq!(version.as_deref(), Some("0.11.0"));
     }
 
+    #[test]
+    fn test_parse_version_mosh() {
+        let output = "mosh 1.4.0 [build mosh 1.4.0]\nCopyright 2012 Keith Winstein <mosh-devel@mit.edu>\nLicense GPLv3+: GNU GPL version 3 or later <http://gnu.org/licenses/gpl.html>.";
+        let version = parse_version(output);
+        assert_eq!(version.as_deref(), Some("1.4.0"));
+    }
+
     #[test]
     fn test_parse_version_openssh_no_distro() {
         let output = "OpenSSH_10.3p1, OpenSSL 3.5.6 7 Apr 2026";
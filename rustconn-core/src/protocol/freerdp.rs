@@ -6,9 +6,10 @@
 
 use std::path::PathBuf;
 
-use secrecy::SecretString;
+use secrecy::{ExposeSecret, SecretString};
 
-use crate::models::WindowGeometry;
+use crate::models::{Credentials, RdpSecurityLayer, WindowGeometry};
+use crate::rdp_client::{MonitorLayout, RdpClientError, validate_redirected_drive};
 
 /// A shared folder for RDP drive redirection
 #[derive(Debug, Clone)]
@@ -19,6 +20,54 @@ pub struct SharedFolder {
     pub share_name: String,
 }
 
+impl SharedFolder {
+    /// Checks that the folder can be passed as `/drive:share_name,path`
+    ///
+    /// # Errors
+    ///
+    /// See [`validate_redirected_drive`].
+    pub fn validate(&self) -> Result<(), RdpClientError> {
+        validate_redirected_drive(&self.share_name, &self.local_path)
+    }
+}
+
+/// An RD Gateway for `FreeRDP` external mode
+#[derive(Debug, Clone)]
+pub struct FreeRdpGateway {
+    /// Gateway hostname
+    pub hostname: String,
+    /// Gateway port (default: 443)
+    pub port: u16,
+    /// Gateway account; `None` reuses the session credentials
+    pub credentials: Option<Credentials>,
+}
+
+impl FreeRdpGateway {
+    /// Creates a gateway on port 443 that reuses the session credentials
+    #[must_use]
+    pub fn new(hostname: impl Into<String>) -> Self {
+        Self {
+            hostname: hostname.into(),
+            port: 443,
+            credentials: None,
+        }
+    }
+
+    /// Sets the port
+    #[must_use]
+    pub const fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Sets the gateway account, separate from the session credentials
+    #[must_use]
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+}
+
 /// Configuration for `FreeRDP` external mode
 #[derive(Debug, Clone, Default)]
 pub struct FreeRdpConfig {
@@ -32,6 +81,8 @@ pub struct FreeRdpConfig {
     pub password: Option<SecretString>,
     /// Domain for authentication
     pub domain: Option<String>,
+    /// Security protocol; `Negotiate` lets client and server pick
+    pub security_layer: RdpSecurityLayer,
     /// Desired width in pixels
     pub width: u32,
     /// Desired height in pixels
@@ -48,6 +99,10 @@ pub struct FreeRdpConfig {
     pub remember_window_position: bool,
     /// Whether to ignore certificate errors (skip verification)
     pub ignore_certificate: bool,
+    /// Multi-monitor layout; `None` uses a single window
+    pub monitor_layout: Option<MonitorLayout>,
+    /// RD Gateway to tunnel through
+    pub gateway: Option<FreeRdpGateway>,
 }
 
 impl FreeRdpConfig {
@@ -60,6 +115,7 @@ impl FreeRdpConfig {
             username: None,
             password: None,
             domain: None,
+            security_layer: RdpSecurityLayer::Negotiate,
             width: 1280,
             height: 720,
             clipboard_enabled: true,
@@ -68,6 +124,8 @@ impl FreeRdpConfig {
             window_geometry: None,
             remember_window_position: true,
             ignore_certificate: false,
+            monitor_layout: None,
+            gateway: None,
         }
     }
 
@@ -99,6 +157,13 @@ impl FreeRdpConfig {
         self
     }
 
+    /// Sets the security protocol (`/sec:`)
+    #[must_use]
+    pub const fn with_security_layer(mut self, layer: RdpSecurityLayer) -> Self {
+        self.security_layer = layer;
+        self
+    }
+
     /// Sets the resolution
     #[must_use]
     pub const fn with_resolution(mut self, width: u32, height: u32) -> Self {
@@ -135,6 +200,35 @@ impl FreeRdpConfig {
         self
     }
 
+    /// Checks every shared folder
+    ///
+    /// [`build_freerdp_args`] skips folders that fail this check; call it
+    /// first to report them instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`RdpClientError::SharedFolderError`] from
+    /// [`SharedFolder::validate`].
+    pub fn validate_shared_folders(&self) -> Result<(), RdpClientError> {
+        self.shared_folders
+            .iter()
+            .try_for_each(SharedFolder::validate)
+    }
+
+    /// Sets the multi-monitor layout
+    #[must_use]
+    pub fn with_monitor_layout(mut self, layout: MonitorLayout) -> Self {
+        self.monitor_layout = Some(layout);
+        self
+    }
+
+    /// Sets the RD Gateway
+    #[must_use]
+    pub fn with_gateway(mut self, gateway: FreeRdpGateway) -> Self {
+        self.gateway = Some(gateway);
+        self
+    }
+
     /// Sets whether to remember window position
     #[must_use]
     pub const fn with_remember_window_position(mut self, remember: bool) -> Self {
@@ -147,8 +241,10 @@ impl FreeRdpConfig {
 ///
 /// This function generates the command-line arguments for `FreeRDP` (xfreerdp/wlfreerdp)
 /// based on the provided configuration. It includes:
-/// - Authentication options (username, password, domain)
-/// - Display options (resolution, dynamic resolution)
+/// - Authentication options (username, domain, `/sec:` security protocol)
+/// - RD Gateway options (`/g:`, plus `/gu:` and `/gd:` for a separate
+///   gateway account)
+/// - Display options (resolution, dynamic resolution, monitors)
 /// - Window options (decorations, geometry)
 /// - Feature options (clipboard)
 ///
@@ -180,6 +276,29 @@ pub fn build_freerdp_args(config: &FreeRdpConfig) -> Vec<String> {
     // never appears on argv or stdin. The caller is responsible for writing
     // the args file and passing /args-from:file:<path> separately.
 
+    // Security protocol; negotiated when unset
+    if let Some(sec_arg) = config.security_layer.freerdp_arg() {
+        args.push(sec_arg.to_string());
+    }
+
+    // RD Gateway. Its account is kept apart from the session's `/u:`/`/d:`;
+    // without one FreeRDP reuses the session credentials for the gateway.
+    // The gateway password goes with the session password, see
+    // `build_freerdp_secret_args`.
+    if let Some(ref gateway) = config.gateway {
+        args.push(format!("/g:{}:{}", gateway.hostname, gateway.port));
+        if let Some(ref credentials) = gateway.credentials {
+            if let Some(ref username) = credentials.username {
+                args.push(format!("/gu:{username}"));
+            }
+            if let Some(ref domain) = credentials.domain
+                && !domain.is_empty()
+            {
+                args.push(format!("/gd:{domain}"));
+            }
+        }
+    }
+
     // Resolution
     args.push(format!("/w:{}", config.width));
     args.push(format!("/h:{}", config.height));
@@ -192,8 +311,19 @@ pub fn build_freerdp_args(config: &FreeRdpConfig) -> Vec<String> {
         args.push("/cert:tofu".to_string());
     }
 
-    // Dynamic resolution
-    args.push("/dynamic-resolution".to_string());
+    // Multi-monitor: /multimon, /monitors:<ids> or /span. The desktop
+    // follows the monitors, so dynamic resolution is left off.
+    let monitor_args = config
+        .monitor_layout
+        .as_ref()
+        .map(MonitorLayout::freerdp_args)
+        .unwrap_or_default();
+    if monitor_args.is_empty() {
+        // Dynamic resolution
+        args.push("/dynamic-resolution".to_string());
+    } else {
+        args.extend(monitor_args);
+    }
 
     // Decorations flag for window controls
     args.push("/decorations".to_string());
@@ -213,7 +343,9 @@ pub fn build_freerdp_args(config: &FreeRdpConfig) -> Vec<String> {
 
     // Shared folders (drive redirection)
     for folder in &config.shared_folders {
-        if folder.local_path.exists() {
+        if let Err(e) = folder.validate() {
+            tracing::warn!(%e, "Skipping shared folder");
+        } else {
             // FreeRDP format: /drive:share_name,/path/to/folder
             args.push(format!(
                 "/drive:{},{}",
@@ -224,7 +356,7 @@ pub fn build_freerdp_args(config: &FreeRdpConfig) -> Vec<String> {
     }
 
     // Extra arguments — filter dangerous prefixes matching rdp.rs custom_args
-    let dangerous_prefixes = ["/p:", "/password:", "/shell:", "/proxy:"];
+    let dangerous_prefixes = ["/p:", "/password:", "/gp:", "/shell:", "/proxy:"];
     for arg in &config.extra_args {
         let lower = arg.to_lowercase();
         if dangerous_prefixes.iter().any(|p| lower.starts_with(p)) {
@@ -244,6 +376,28 @@ pub fn build_freerdp_args(config: &FreeRdpConfig) -> Vec<String> {
     args
 }
 
+/// Returns the secret `FreeRDP` arguments as `(flag, secret)` pairs
+///
+/// These are written to the ephemeral args file as `/<flag>:<secret>`
+/// instead of the command line: `p` for the session password and `gp` for
+/// the password of a separate gateway account. Empty passwords are skipped.
+#[must_use]
+pub fn build_freerdp_secret_args(config: &FreeRdpConfig) -> Vec<(&'static str, &SecretString)> {
+    let gateway_password = config
+        .gateway
+        .as_ref()
+        .and_then(|g| g.credentials.as_ref())
+        .and_then(|c| c.password.as_ref());
+    [("p", config.password.as_ref()), ("gp", gateway_password)]
+        .into_iter()
+        .filter_map(|(flag, secret)| {
+            secret
+                .filter(|s| !s.expose_secret().is_empty())
+                .map(|s| (flag, s))
+        })
+        .collect()
+}
+
 /// Checks if the `FreeRDP` arguments contain the decorations flag
 ///
 /// # Arguments
@@ -317,6 +471,111 @@ mod tests {
         assert!(args.contains(&"/d:CORP".to_string()));
     }
 
+    #[test]
+    fn test_build_freerdp_args_security_layer() {
+        let sec_args = |layer: RdpSecurityLayer| -> Vec<String> {
+            build_freerdp_args(&FreeRdpConfig::new("server.example.com").with_security_layer(layer))
+                .into_iter()
+                .filter(|a| a.starts_with("/sec:"))
+                .collect()
+        };
+        assert!(sec_args(RdpSecurityLayer::Negotiate).is_empty());
+        assert_eq!(sec_args(RdpSecurityLayer::Nla), ["/sec:nla"]);
+        assert_eq!(sec_args(RdpSecurityLayer::Tls), ["/sec:tls"]);
+        assert_eq!(sec_args(RdpSecurityLayer::Rdp), ["/sec:rdp"]);
+        assert_eq!(
+            FreeRdpConfig::default().security_layer,
+            RdpSecurityLayer::Negotiate
+        );
+    }
+
+    #[test]
+    fn test_build_freerdp_args_gateway_credentials_are_separate() {
+        let gateway = FreeRdpGateway::new("gw.example.com").with_credentials(Credentials {
+            username: Some("gw-user".to_string()),
+            password: Some(SecretString::from("gw-secret")),
+            key_passphrase: None,
+            domain: Some("GWDOM".to_string()),
+        });
+        let config = FreeRdpConfig::new("server.example.com")
+            .with_username("admin")
+            .with_password("host-secret")
+            .with_domain("CORP")
+            .with_gateway(gateway);
+
+        let args = build_freerdp_args(&config);
+        let group = |prefixes: &[&str]| -> Vec<&str> {
+            args.iter()
+                .map(String::as_str)
+                .filter(|a| prefixes.iter().any(|p| a.starts_with(p)))
+                .collect()
+        };
+        assert_eq!(group(&["/u:", "/d:"]), ["/d:CORP", "/u:admin"]);
+        assert_eq!(
+            group(&["/g:", "/gu:", "/gd:"]),
+            ["/g:gw.example.com:443", "/gu:gw-user", "/gd:GWDOM"]
+        );
+        // No secret reaches the plain argument list
+        assert!(!args.iter().any(|a| a.contains("secret")));
+
+        let secrets: Vec<(&str, &str)> = build_freerdp_secret_args(&config)
+            .into_iter()
+            .map(|(flag, secret)| (flag, secret.expose_secret()))
+            .collect();
+        assert_eq!(secrets, [("p", "host-secret"), ("gp", "gw-secret")]);
+    }
+
+    #[test]
+    fn test_build_freerdp_args_gateway_shares_session_credentials() {
+        let config = FreeRdpConfig::new("server.example.com")
+            .with_username("admin")
+            .with_password("host-secret")
+            .with_gateway(FreeRdpGateway::new("gw.example.com").with_port(8443));
+
+        let args = build_freerdp_args(&config);
+        assert!(args.contains(&"/g:gw.example.com:8443".to_string()));
+        assert!(
+            !args
+                .iter()
+                .any(|a| a.starts_with("/gu:") || a.starts_with("/gd:"))
+        );
+
+        let secrets = build_freerdp_secret_args(&config);
+        assert_eq!(secrets.len(), 1);
+        assert_eq!(secrets[0].0, "p");
+    }
+
+    #[test]
+    fn test_build_freerdp_args_multimonitor() {
+        use crate::rdp_client::MonitorDefinition;
+
+        let layout = MonitorLayout::all(vec![
+            MonitorDefinition::new(0, 0, 0, 1920, 1080).with_primary(true),
+            MonitorDefinition::new(1, 1920, 0, 3840, 1080),
+        ]);
+        let args = build_freerdp_args(
+            &FreeRdpConfig::new("server.example.com").with_monitor_layout(layout.clone()),
+        );
+        assert!(args.contains(&"/multimon".to_string()));
+        assert!(!args.contains(&"/dynamic-resolution".to_string()));
+        assert_eq!(args.last().unwrap(), "/v:server.example.com");
+
+        let mut selected = layout;
+        selected.use_all_monitors = false;
+        selected.selected_monitors = vec![1];
+        let args = build_freerdp_args(
+            &FreeRdpConfig::new("server.example.com").with_monitor_layout(selected),
+        );
+        assert!(args.contains(&"/monitors:1".to_string()));
+
+        let single = build_freerdp_args(
+            &FreeRdpConfig::new("server.example.com")
+                .with_monitor_layout(MonitorLayout::single(1920, 1080)),
+        );
+        assert!(single.contains(&"/dynamic-resolution".to_string()));
+        assert!(!single.iter().any(|a| a == "/multimon" || a == "/span"));
+    }
+
     #[test]
     fn test_build_freerdp_args_with_geometry() {
         let geometry = WindowGeometry::new(100, 200, 1920, 1080);
@@ -423,4 +682,42 @@ mod tests {
         // Non-existent paths should be skipped
         assert!(!args.iter().any(|a| a.starts_with("/drive:")));
     }
+
+    #[test]
+    fn test_validate_shared_folders() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("file.txt");
+        std::fs::write(&file, "x").unwrap();
+
+        let valid =
+            FreeRdpConfig::new("server.example.com").with_shared_folders(vec![SharedFolder {
+                share_name: "Work".to_string(),
+                local_path: dir.path().to_path_buf(),
+            }]);
+        assert!(valid.validate_shared_folders().is_ok());
+
+        let not_dir = FreeRdpConfig::new("server.example.com").with_shared_folders(vec![
+            SharedFolder {
+                share_name: "Work".to_string(),
+                local_path: dir.path().to_path_buf(),
+            },
+            SharedFolder {
+                share_name: "File".to_string(),
+                local_path: file,
+            },
+        ]);
+        assert!(matches!(
+            not_dir.validate_shared_folders(),
+            Err(RdpClientError::SharedFolderError(msg)) if msg.contains("not a directory")
+        ));
+        // Only the valid drive is emitted
+        let drive_args: Vec<String> = build_freerdp_args(&not_dir)
+            .into_iter()
+            .filter(|a| a.starts_with("/drive:"))
+            .collect();
+        assert_eq!(
+            drive_args,
+            [format!("/drive:Work,{}", dir.path().display())]
+        );
+    }
 }
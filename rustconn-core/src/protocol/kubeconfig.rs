@@ -0,0 +1,364 @@
+//! Kubeconfig contexts and namespaces
+//!
+//! Reads the contexts a Kubernetes session can switch to. Only the
+//! `contexts` and `current-context` keys are parsed; clusters, users and
+//! credentials are left to `kubectl`.
+//!
+//! Files are located the way `kubectl` does: an explicit `--kubeconfig`
+//! path, otherwise every entry of `$KUBECONFIG` (colon-separated), otherwise
+//! `~/.kube/config`. When several files are merged, the first file to
+//! define a context or `current-context` wins.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::ProtocolResult;
+use crate::error::ProtocolError;
+use crate::models::KubernetesConfig;
+
+/// A named context from a kubeconfig
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KubeContext {
+    /// Context name passed to `--context`
+    pub name: String,
+    /// Cluster the context points at
+    pub cluster: String,
+    /// User the context authenticates as
+    pub user: Option<String>,
+    /// Default namespace of the context
+    pub namespace: Option<String>,
+}
+
+/// Contexts available in one or more kubeconfig files
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KubeConfigContexts {
+    /// Value of `current-context`, if set
+    pub current_context: Option<String>,
+    /// Contexts in file order
+    pub contexts: Vec<KubeContext>,
+}
+
+#[derive(Deserialize)]
+struct RawKubeConfig {
+    #[serde(default, rename = "current-context")]
+    current_context: Option<String>,
+    #[serde(default)]
+    contexts: Option<Vec<RawNamedContext>>,
+}
+
+#[derive(Deserialize)]
+struct RawNamedContext {
+    name: String,
+    #[serde(default)]
+    context: Option<RawContext>,
+}
+
+#[derive(Default, Deserialize)]
+struct RawContext {
+    #[serde(default)]
+    cluster: String,
+    #[serde(default)]
+    user: Option<String>,
+    #[serde(default)]
+    namespace: Option<String>,
+}
+
+/// Returns `None` for missing or blank values
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.trim().is_empty())
+}
+
+impl KubeConfigContexts {
+    /// Parses the contexts of a kubeconfig document
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProtocolError::InvalidConfig`] if `yaml` is not a valid
+    /// kubeconfig.
+    pub fn parse(yaml: &str) -> ProtocolResult<Self> {
+        if yaml.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        let raw: RawKubeConfig = serde_yaml::from_str(yaml)
+            .map_err(|e| ProtocolError::InvalidConfig(format!("Invalid kubeconfig: {e}")))?;
+        let contexts = raw
+            .contexts
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|c| !c.name.is_empty())
+            .map(|c| {
+                let context = c.context.unwrap_or_default();
+                KubeContext {
+                    name: c.name,
+                    cluster: context.cluster,
+                    user: non_empty(context.user),
+                    namespace: non_empty(context.namespace),
+                }
+            })
+            .collect();
+        Ok(Self {
+            current_context: non_empty(raw.current_context),
+            contexts,
+        })
+    }
+
+    /// Loads the contexts visible to a session
+    ///
+    /// `kubeconfig` is the connection's explicit kubeconfig path. Files
+    /// that do not exist are skipped, as `kubectl` does.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProtocolError::InvalidConfig`] if a file cannot be read or
+    /// parsed.
+    pub fn load(kubeconfig: Option<&Path>) -> ProtocolResult<Self> {
+        let mut merged = Self::default();
+        for path in kubeconfig_paths(kubeconfig) {
+            let yaml = match std::fs::read_to_string(&path) {
+                Ok(yaml) => yaml,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    return Err(ProtocolError::InvalidConfig(format!(
+                        "Failed to read kubeconfig {}: {e}",
+                        path.display()
+                    )));
+                }
+            };
+            merged.merge(Self::parse(&yaml)?);
+        }
+        Ok(merged)
+    }
+
+    /// Merges `other` into `self`; values already present win
+    pub fn merge(&mut self, other: Self) {
+        if self.current_context.is_none() {
+            self.current_context = other.current_context;
+        }
+        for context in other.contexts {
+            if self.get(&context.name).is_none() {
+                self.contexts.push(context);
+            }
+        }
+    }
+
+    /// Returns the context named `name`
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&KubeContext> {
+        self.contexts.iter().find(|c| c.name == name)
+    }
+
+    /// Returns the context names in file order
+    #[must_use]
+    pub fn names(&self) -> Vec<&str> {
+        self.contexts.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    /// Returns the distinct namespaces named by any context, sorted
+    #[must_use]
+    pub fn namespaces(&self) -> Vec<String> {
+        let mut namespaces: Vec<String> = self
+            .contexts
+            .iter()
+            .filter_map(|c| c.namespace.clone())
+            .collect();
+        namespaces.sort();
+        namespaces.dedup();
+        namespaces
+    }
+}
+
+/// Returns the kubeconfig files `kubectl` would read, in merge order
+#[must_use]
+pub fn kubeconfig_paths(kubeconfig: Option<&Path>) -> Vec<PathBuf> {
+    if let Some(path) = kubeconfig {
+        return vec![path.to_path_buf()];
+    }
+    if let Some(value) = std::env::var_os("KUBECONFIG") {
+        let paths: Vec<PathBuf> = std::env::split_paths(&value)
+            .filter(|p| !p.as_os_str().is_empty())
+            .collect();
+        if !paths.is_empty() {
+            return paths;
+        }
+    }
+    dirs::home_dir()
+        .map(|home| vec![home.join(".kube").join("config")])
+        .unwrap_or_default()
+}
+
+/// Parses the output of `kubectl get namespaces -o name`
+///
+/// Lines look like `namespace/kube-system`; the result is sorted.
+#[must_use]
+pub fn parse_namespace_list(output: &str) -> Vec<String> {
+    let mut namespaces: Vec<String> = output
+        .lines()
+        .map(str::trim)
+        .map(|line| line.strip_prefix("namespace/").unwrap_or(line))
+        .filter(|ns| !ns.is_empty())
+        .map(str::to_string)
+        .collect();
+    namespaces.sort();
+    namespaces.dedup();
+    namespaces
+}
+
+/// Context and namespace a Kubernetes session runs against
+///
+/// `None` means "whatever the kubeconfig selects": the current context, or
+/// the context's default namespace.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KubeTarget {
+    /// Context passed to `--context`
+    pub context: Option<String>,
+    /// Namespace passed to `--namespace`
+    pub namespace: Option<String>,
+}
+
+impl KubeTarget {
+    /// Creates a target; blank values are treated as unset
+    #[must_use]
+    pub fn new(context: Option<String>, namespace: Option<String>) -> Self {
+        Self {
+            context: non_empty(context),
+            namespace: non_empty(namespace),
+        }
+    }
+
+    /// Returns the target stored in a connection's config
+    #[must_use]
+    pub fn of(config: &KubernetesConfig) -> Self {
+        Self::new(config.context.clone(), config.namespace.clone())
+    }
+
+    /// Writes the target into `config`
+    pub fn apply_to(&self, config: &mut KubernetesConfig) {
+        config.context.clone_from(&self.context);
+        config.namespace.clone_from(&self.namespace);
+    }
+
+    /// Tab title for a session on this target, e.g. `web (prod/payments)`
+    ///
+    /// Returns `name` unchanged when neither value is set.
+    #[must_use]
+    pub fn session_title(&self, name: &str) -> String {
+        match (self.context.as_deref(), self.namespace.as_deref()) {
+            (None, None) => name.to_string(),
+            (Some(context), None) => format!("{name} ({context})"),
+            (None, Some(namespace)) => format!("{name} ({namespace})"),
+            (Some(context), Some(namespace)) => format!("{name} ({context}/{namespace})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KUBECONFIG: &str = r"
+apiVersion: v1
+kind: Config
+current-context: staging
+clusters:
+  - name: prod-cluster
+    cluster:
+      server: https://prod.example.com:6443
+contexts:
+  - name: prod
+    context:
+      cluster: prod-cluster
+      user: admin
+      namespace: payments
+  - name: staging
+    context:
+      cluster: staging-cluster
+      user: dev
+      namespace: payments
+  - name: kind
+    context:
+      cluster: kind-kind
+      namespace: ''
+users:
+  - name: admin
+    user:
+      token: secret
+";
+
+    #[test]
+    fn test_parse_contexts() {
+        let parsed = KubeConfigContexts::parse(KUBECONFIG).unwrap();
+        assert_eq!(parsed.current_context.as_deref(), Some("staging"));
+        assert_eq!(parsed.names(), ["prod", "staging", "kind"]);
+
+        let prod = parsed.get("prod").unwrap();
+        assert_eq!(prod.cluster, "prod-cluster");
+        assert_eq!(prod.user.as_deref(), Some("admin"));
+        assert_eq!(prod.namespace.as_deref(), Some("payments"));
+        assert_eq!(parsed.get("kind").unwrap().namespace, None);
+        assert_eq!(parsed.namespaces(), ["payments"]);
+    }
+
+    #[test]
+    fn test_parse_empty_and_invalid() {
+        assert_eq!(
+            KubeConfigContexts::parse("").unwrap(),
+            KubeConfigContexts::default()
+        );
+        let no_contexts = KubeConfigContexts::parse("apiVersion: v1\ncontexts:\n").unwrap();
+        assert!(no_contexts.contexts.is_empty());
+        assert!(KubeConfigContexts::parse("contexts: [").is_err());
+    }
+
+    #[test]
+    fn test_merge_first_file_wins() {
+        let mut merged = KubeConfigContexts::parse(KUBECONFIG).unwrap();
+        let other = KubeConfigContexts::parse(
+            "current-context: dev\ncontexts:\n  - name: prod\n    context:\n      cluster: other\n      namespace: web\n  - name: dev\n    context:\n      cluster: dev-cluster\n      namespace: sandbox\n",
+        )
+        .unwrap();
+        merged.merge(other);
+        assert_eq!(merged.current_context.as_deref(), Some("staging"));
+        assert_eq!(merged.names(), ["prod", "staging", "kind", "dev"]);
+        assert_eq!(merged.get("prod").unwrap().cluster, "prod-cluster");
+        assert_eq!(merged.namespaces(), ["payments", "sandbox"]);
+    }
+
+    #[test]
+    fn test_load_explicit_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config");
+        std::fs::write(&path, KUBECONFIG).unwrap();
+        let loaded = KubeConfigContexts::load(Some(&path)).unwrap();
+        assert_eq!(loaded.contexts.len(), 3);
+
+        let missing = KubeConfigContexts::load(Some(&dir.path().join("absent"))).unwrap();
+        assert!(missing.contexts.is_empty());
+    }
+
+    #[test]
+    fn test_parse_namespace_list() {
+        let output = "namespace/kube-system\nnamespace/default\n\nnamespace/payments\n";
+        assert_eq!(
+            parse_namespace_list(output),
+            ["default", "kube-system", "payments"]
+        );
+    }
+
+    #[test]
+    fn test_target_title_and_apply() {
+        let target = KubeTarget::new(Some("prod".to_string()), Some(" ".to_string()));
+        assert_eq!(target.namespace, None);
+        assert_eq!(target.session_title("web"), "web (prod)");
+        assert_eq!(KubeTarget::default().session_title("web"), "web");
+
+        let mut config = KubernetesConfig {
+            namespace: Some("old".to_string()),
+            ..Default::default()
+        };
+        let target = KubeTarget::new(Some("prod".to_string()), Some("payments".to_string()));
+        target.apply_to(&mut config);
+        assert_eq!(KubeTarget::of(&config), target);
+        assert_eq!(target.session_title("web"), "web (prod/payments)");
+    }
+}
@@ -58,6 +58,21 @@ impl Protocol for KubernetesProtocol {
             ));
         }
 
+        // A container only makes sense inside a named pod
+        if config.container.as_deref().is_some_and(|c| !c.is_empty())
+            && config.pod.as_deref().is_none_or(str::is_empty)
+        {
+            return Err(ProtocolError::InvalidConfig(
+                "Container requires a pod name".to_string(),
+            ));
+        }
+
+        if let Some(ref command) = config.command {
+            shell_words::split(command).map_err(|e| {
+                ProtocolError::InvalidConfig(format!("Invalid Kubernetes command: {e}"))
+            })?;
+        }
+
         // Shell must not be empty
         if config.shell.is_empty() {
             return Err(ProtocolError::InvalidConfig(
@@ -102,7 +117,32 @@ impl KubernetesProtocol {
             || value.contains('\n')
     }
 
-    /// Builds `kubectl exec -it <pod> -- <shell>` command
+    /// Returns what runs in the container: the configured command split
+    /// into arguments, or the shell when no command is set
+    fn remote_command(config: &KubernetesConfig) -> Option<Vec<String>> {
+        let Some(command) = config
+            .command
+            .as_deref()
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+        else {
+            return Some(vec![config.shell.clone()]);
+        };
+        let args = match shell_words::split(command) {
+            Ok(args) => args,
+            Err(e) => {
+                tracing::warn!(%e, "Rejecting unparsable Kubernetes command");
+                return None;
+            }
+        };
+        if args.iter().any(|a| a.contains('\0') || a.contains('\n')) {
+            tracing::warn!("Rejecting Kubernetes command with unsafe characters");
+            return None;
+        }
+        Some(args)
+    }
+
+    /// Builds `kubectl exec -it <pod> [-c <container>] -- <command or shell>`
     fn build_exec_command(config: &KubernetesConfig) -> Option<Vec<String>> {
         // Validate shell value before building command
         if Self::contains_shell_metachar(&config.shell) {
@@ -140,9 +180,9 @@ impl KubernetesProtocol {
             cmd.push(arg.clone());
         }
 
-        // Shell command after --
+        // Command (or shell) after --
         cmd.push("--".to_string());
-        cmd.push(config.shell.clone());
+        cmd.extend(Self::remote_command(config)?);
 
         Some(cmd)
     }
@@ -191,13 +231,35 @@ impl KubernetesProtocol {
             cmd.push(arg.clone());
         }
 
-        // Shell command after --
+        // Command (or shell) after --
         cmd.push("--".to_string());
-        cmd.push(config.shell.clone());
+        cmd.extend(Self::remote_command(config)?);
 
         Some(cmd)
     }
 
+    /// Builds `kubectl get namespaces -o name` for a context
+    ///
+    /// Uses the connection's kubeconfig; `context` overrides the configured
+    /// one. Parse the output with [`super::parse_namespace_list`].
+    #[must_use]
+    pub fn build_namespace_list_command(
+        config: &KubernetesConfig,
+        context: Option<&str>,
+    ) -> Vec<String> {
+        let target = KubernetesConfig {
+            context: context
+                .map(str::to_string)
+                .or_else(|| config.context.clone()),
+            namespace: None,
+            ..config.clone()
+        };
+        let mut cmd = vec!["kubectl".to_string()];
+        Self::append_global_args(&mut cmd, &target);
+        cmd.extend(["get", "namespaces", "-o", "name"].map(str::to_string));
+        cmd
+    }
+
     /// Appends kubeconfig, context, and namespace args
     fn append_global_args(cmd: &mut Vec<String>, config: &KubernetesConfig) {
         if let Some(ref kubeconfig) = config.kubeconfig {
@@ -295,6 +357,80 @@ mod tests {
         assert!(cmd.contains(&"/bin/sh".to_string()));
     }
 
+    #[test]
+    fn test_build_exec_command_argv() {
+        let protocol = KubernetesProtocol::new();
+        let config = KubernetesConfig {
+            context: Some("prod".to_string()),
+            pod: Some("web-abc123".to_string()),
+            container: Some("app".to_string()),
+            command: Some("tail -f '/var/log/app log.txt'".to_string()),
+            ..Default::default()
+        };
+        let cmd = protocol
+            .build_command(&create_k8s_connection(config))
+            .unwrap();
+        assert_eq!(
+            cmd,
+            [
+                "kubectl",
+                "--context",
+                "prod",
+                "exec",
+                "-it",
+                "web-abc123",
+                "-c",
+                "app",
+                "--",
+                "tail",
+                "-f",
+                "/var/log/app log.txt"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_exec_command_falls_back_to_shell() {
+        let protocol = KubernetesProtocol::new();
+        let config = KubernetesConfig {
+            pod: Some("web-abc123".to_string()),
+            command: Some("  ".to_string()),
+            shell: "/bin/bash".to_string(),
+            ..Default::default()
+        };
+        let cmd = protocol
+            .build_command(&create_k8s_connection(config))
+            .unwrap();
+        assert_eq!(
+            cmd,
+            ["kubectl", "exec", "-it", "web-abc123", "--", "/bin/bash"]
+        );
+    }
+
+    #[test]
+    fn test_validate_container_requires_pod() {
+        let protocol = KubernetesProtocol::new();
+        let config = KubernetesConfig {
+            use_busybox: true,
+            container: Some("app".to_string()),
+            ..Default::default()
+        };
+        assert!(
+            protocol
+                .validate_connection(&create_k8s_connection(config))
+                .is_err()
+        );
+
+        let config = KubernetesConfig {
+            pod: Some("web".to_string()),
+            command: Some("echo 'unterminated".to_string()),
+            ..Default::default()
+        };
+        let conn = create_k8s_connection(config);
+        assert!(protocol.validate_connection(&conn).is_err());
+        assert!(protocol.build_command(&conn).is_none());
+    }
+
     #[test]
     fn test_build_busybox_command() {
         let protocol = KubernetesProtocol::new();
@@ -330,4 +466,28 @@ mod tests {
         assert!(cmd.contains(&"--context".to_string()));
         assert!(cmd.contains(&"staging-ctx".to_string()));
     }
+
+    #[test]
+    fn test_build_namespace_list_command() {
+        let config = KubernetesConfig {
+            kubeconfig: Some("/home/user/.kube/staging".into()),
+            context: Some("staging-ctx".to_string()),
+            namespace: Some("web".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            KubernetesProtocol::build_namespace_list_command(&config, Some("prod")),
+            [
+                "kubectl",
+                "--kubeconfig",
+                "/home/user/.kube/staging",
+                "--context",
+                "prod",
+                "get",
+                "namespaces",
+                "-o",
+                "name"
+            ]
+        );
+    }
 }
@@ -2,12 +2,14 @@
 //!
 //! This module provides the Protocol trait and implementations for
 //! SSH, RDP, VNC, SPICE, Telnet, Serial, SFTP, and Kubernetes protocols.
-//! Each protocol handler is responsible for validation and protocol metadata.
+//! Each protocol handler is responsible for validation, protocol metadata,
+//! and reporting its [`ProtocolCapabilities`].
 
 mod cli;
 mod detection;
 pub mod freerdp;
 pub mod icons;
+mod kubeconfig;
 mod kubernetes;
 mod mosh;
 mod rdp;
@@ -27,21 +29,25 @@ pub use detection::{
     detect_boundary, detect_cloudflared, detect_gcloud_cli, detect_hoop, detect_kubectl,
     detect_mosh, detect_oci_cli, detect_picocom, detect_rdp_client, detect_spice_client,
     detect_ssh_client, detect_tailscale, detect_teleport, detect_telnet_client, detect_vnc_client,
-    detect_vnc_viewer_name, detect_vnc_viewer_path, detect_waypipe,
+    detect_vnc_viewer_name, detect_vnc_viewer_name_for, detect_vnc_viewer_path, detect_waypipe,
 };
 pub use freerdp::{
-    FreeRdpConfig, build_freerdp_args, extract_geometry_from_args, has_decorations_flag,
+    FreeRdpConfig, FreeRdpGateway, build_freerdp_args, build_freerdp_secret_args,
+    extract_geometry_from_args, has_decorations_flag,
 };
 pub use icons::{
     CloudProvider, PROTOCOL_TAB_CSS_CLASSES, ProviderIconCache, all_protocol_icons,
     detect_provider, get_protocol_color_rgb, get_protocol_icon, get_protocol_icon_by_name,
     get_protocol_tab_css_class, get_zero_trust_provider_icon,
 };
+pub use kubeconfig::{
+    KubeConfigContexts, KubeContext, KubeTarget, kubeconfig_paths, parse_namespace_list,
+};
 pub use kubernetes::KubernetesProtocol;
 pub use mosh::MoshProtocol;
 pub use rdp::RdpProtocol;
 pub use registry::ProtocolRegistry;
-pub use serial::SerialProtocol;
+pub use serial::{SERIAL_BY_ID_DIR, SerialDeviceResolver, SerialProtocol, format_hex_dump};
 pub use sftp::SftpProtocol;
 pub use spice::SpiceProtocol;
 pub use ssh::SshProtocol;
@@ -75,6 +81,8 @@ pub struct ProtocolCapabilities {
     pub audio: bool,
     /// Supports clipboard sharing
     pub clipboard: bool,
+    /// Supports copying files through the clipboard (RDP `FileGroupDescriptorW`)
+    pub clipboard_files: bool,
     /// Can be used inside a split-view panel
     pub split_view: bool,
     /// Runs inside a VTE terminal (SSH, Telnet)
@@ -84,6 +92,9 @@ pub struct ProtocolCapabilities {
     pub multi_monitor: bool,
     /// Supports USB device redirection (SPICE)
     pub usb_redirection: bool,
+    /// Follows the local window size (RDP Display Control, VNC
+    /// `SetDesktopSize`, PTY window size for terminals)
+    pub dynamic_resize: bool,
     /// Supports local/remote/dynamic port forwarding (SSH)
     pub port_forwarding: bool,
     /// Supports Wayland forwarding (SSH + waypipe)
@@ -98,6 +109,9 @@ pub struct ProtocolCapabilities {
     pub command_snippets: bool,
     /// Supports Wake-on-LAN
     pub wake_on_lan: bool,
+    /// Can be routed through a zero-trust access tunnel; needs the
+    /// provider's client (`cloudflared`, `tsh`) at connect time
+    pub access_tunnel: bool,
 }
 
 impl ProtocolCapabilities {
@@ -112,10 +126,12 @@ impl ProtocolCapabilities {
             file_transfer: false,
             audio: false,
             clipboard: false,
+            clipboard_files: false,
             split_view: true,
             terminal_based: true,
             multi_monitor: false,
             usb_redirection: false,
+            dynamic_resize: true,
             port_forwarding: false,
             wayland_forwarding: false,
             x11_forwarding: false,
@@ -123,6 +139,7 @@ impl ProtocolCapabilities {
             remote_monitoring: true,
             command_snippets: true,
             wake_on_lan: true,
+            access_tunnel: false,
         }
     }
 
@@ -137,10 +154,12 @@ impl ProtocolCapabilities {
             file_transfer,
             audio,
             clipboard,
+            clipboard_files: false,
             split_view: false,
             terminal_based: false,
             multi_monitor: false,
             usb_redirection: false,
+            dynamic_resize: true,
             port_forwarding: false,
             wayland_forwarding: false,
             x11_forwarding: false,
@@ -148,6 +167,7 @@ impl ProtocolCapabilities {
             remote_monitoring: false,
             command_snippets: false,
             wake_on_lan: true,
+            access_tunnel: false,
         }
     }
 
@@ -159,10 +179,12 @@ impl ProtocolCapabilities {
             file_transfer: false,
             audio: false,
             clipboard,
+            clipboard_files: false,
             split_view: false,
             terminal_based: false,
             multi_monitor: false,
             usb_redirection: false,
+            dynamic_resize: false,
             port_forwarding: false,
             wayland_forwarding: false,
             x11_forwarding: false,
@@ -170,6 +192,109 @@ impl ProtocolCapabilities {
             remote_monitoring: false,
             command_snippets: false,
             wake_on_lan: true,
+            access_tunnel: false,
+        }
+    }
+}
+
+/// A single feature that can be queried on [`ProtocolCapabilities`].
+///
+/// Lets UI code ask "does this protocol support X?" generically, e.g. to
+/// enable or hide a control, without matching on `ProtocolType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProtocolFeature {
+    /// Built-in embedded viewer
+    Embedded,
+    /// External CLI client fallback
+    ExternalFallback,
+    /// File transfer / shared folders
+    FileTransfer,
+    /// Audio redirection
+    Audio,
+    /// Clipboard sharing
+    Clipboard,
+    /// File copy through the clipboard
+    ClipboardFiles,
+    /// Split-view panel
+    SplitView,
+    /// VTE terminal session
+    TerminalBased,
+    /// Multi-monitor / multi-display
+    MultiMonitor,
+    /// USB device redirection
+    UsbRedirection,
+    /// Resizing the remote display with the local window
+    DynamicResize,
+    /// Port forwarding
+    PortForwarding,
+    /// Wayland forwarding
+    WaylandForwarding,
+    /// X11 forwarding
+    X11Forwarding,
+    /// Session recording / logging
+    SessionRecording,
+    /// Remote system monitoring
+    RemoteMonitoring,
+    /// Command snippets
+    CommandSnippets,
+    /// Wake-on-LAN
+    WakeOnLan,
+    /// Zero-trust access tunnel
+    AccessTunnel,
+}
+
+impl ProtocolFeature {
+    /// Returns every feature, in declaration order
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[
+            Self::Embedded,
+            Self::ExternalFallback,
+            Self::FileTransfer,
+            Self::Audio,
+            Self::Clipboard,
+            Self::ClipboardFiles,
+            Self::SplitView,
+            Self::TerminalBased,
+            Self::MultiMonitor,
+            Self::UsbRedirection,
+            Self::DynamicResize,
+            Self::PortForwarding,
+            Self::WaylandForwarding,
+            Self::X11Forwarding,
+            Self::SessionRecording,
+            Self::RemoteMonitoring,
+            Self::CommandSnippets,
+            Self::WakeOnLan,
+            Self::AccessTunnel,
+        ]
+    }
+}
+
+impl ProtocolCapabilities {
+    /// Returns whether the protocol supports `feature`
+    #[must_use]
+    pub const fn supports(&self, feature: ProtocolFeature) -> bool {
+        match feature {
+            ProtocolFeature::Embedded => self.embedded,
+            ProtocolFeature::ExternalFallback => self.external_fallback,
+            ProtocolFeature::FileTransfer => self.file_transfer,
+            ProtocolFeature::Audio => self.audio,
+            ProtocolFeature::Clipboard => self.clipboard,
+            ProtocolFeature::ClipboardFiles => self.clipboard_files,
+            ProtocolFeature::SplitView => self.split_view,
+            ProtocolFeature::TerminalBased => self.terminal_based,
+            ProtocolFeature::MultiMonitor => self.multi_monitor,
+            ProtocolFeature::UsbRedirection => self.usb_redirection,
+            ProtocolFeature::DynamicResize => self.dynamic_resize,
+            ProtocolFeature::PortForwarding => self.port_forwarding,
+            ProtocolFeature::WaylandForwarding => self.wayland_forwarding,
+            ProtocolFeature::X11Forwarding => self.x11_forwarding,
+            ProtocolFeature::SessionRecording => self.session_recording,
+            ProtocolFeature::RemoteMonitoring => self.remote_monitoring,
+            ProtocolFeature::CommandSnippets => self.command_snippets,
+            ProtocolFeature::WakeOnLan => self.wake_on_lan,
+            ProtocolFeature::AccessTunnel => self.access_tunnel,
         }
     }
 }
@@ -203,11 +328,10 @@ pub trait Protocol: Send + Sync {
 
     /// Returns the set of features this protocol supports.
     ///
-    /// The default implementation returns a terminal-based capability set.
-    /// Override in each protocol handler to reflect actual capabilities.
-    fn capabilities(&self) -> ProtocolCapabilities {
-        ProtocolCapabilities::terminal()
-    }
+    /// This is the authoritative source for feature gating: the GUI and CLI
+    /// query it (see [`ProtocolCapabilities::supports`]) instead of matching
+    /// on `ProtocolType`.
+    fn capabilities(&self) -> ProtocolCapabilities;
 
     /// Builds the CLI command arguments for launching this protocol.
     ///
@@ -60,8 +60,12 @@ impl Protocol for MoshProtocol {
         let mut cmd = vec!["mosh".to_string()];
 
         if let ProtocolConfig::Mosh(ref config) = connection.protocol_config {
-            // --ssh "ssh -p PORT"
-            if let Some(ssh_port) = config.ssh_port {
+            // --ssh "ssh -p PORT"; the connection port is the SSH port
+            // unless the MOSH settings override it
+            let ssh_port = config
+                .ssh_port
+                .or_else(|| (connection.port != 22).then_some(connection.port));
+            if let Some(ssh_port) = ssh_port {
                 cmd.push("--ssh".to_string());
                 cmd.push(format!("ssh -p {ssh_port}"));
             }
@@ -78,10 +82,9 @@ impl Protocol for MoshProtocol {
                 cmd.push(format!("--server={server}"));
             }
 
-            // -p PORT_RANGE
+            // --port=PORT[:PORT2] (UDP port range of mosh-server)
             if let Some(ref port_range) = config.port_range {
-                cmd.push("-p".to_string());
-                cmd.push(port_range.clone());
+                cmd.push(format!("--port={port_range}"));
             }
 
             // Custom args (sanitized)
@@ -180,7 +183,44 @@ mod tests {
         assert!(cmd.contains(&"ssh -p 2222".to_string()));
         assert!(cmd.contains(&"--predict=always".to_string()));
         assert!(cmd.contains(&"--server=/usr/local/bin/mosh-server".to_string()));
-        assert!(cmd.contains(&"-p".to_string()));
-        assert!(cmd.contains(&"60000:60010".to_string()));
+        assert!(cmd.contains(&"--port=60000:60010".to_string()));
+    }
+
+    #[test]
+    fn test_build_command_argv_order() {
+        let protocol = MoshProtocol::new();
+        let mut connection = create_mosh_connection(MoshConfig {
+            port_range: Some("60001".to_string()),
+            ..MoshConfig::default()
+        });
+        connection.port = 2222;
+        connection.username = Some("admin".to_string());
+        let cmd = protocol.build_command(&connection).unwrap();
+        assert_eq!(
+            cmd,
+            [
+                "mosh",
+                "--ssh",
+                "ssh -p 2222",
+                "--port=60001",
+                "admin@example.com"
+            ]
+        );
+
+        // An explicit SSH port in the MOSH settings wins over the connection port
+        if let ProtocolConfig::Mosh(ref mut config) = connection.protocol_config {
+            config.ssh_port = Some(2200);
+        }
+        let cmd = protocol.build_command(&connection).unwrap();
+        assert_eq!(cmd[2], "ssh -p 2200");
+    }
+
+    #[test]
+    fn test_capabilities_terminal_without_forwarding() {
+        let caps = MoshProtocol::new().capabilities();
+        assert!(caps.terminal_based);
+        assert!(caps.embedded);
+        assert!(!caps.port_forwarding);
+        assert!(!caps.x11_forwarding);
     }
 }
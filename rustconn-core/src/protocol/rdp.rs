@@ -78,6 +78,7 @@ impl Protocol for RdpProtocol {
     fn capabilities(&self) -> ProtocolCapabilities {
         ProtocolCapabilities {
             multi_monitor: true,
+            clipboard_files: true,
             ..ProtocolCapabilities::graphical(true, true, true)
         }
     }
@@ -128,10 +129,16 @@ impl RdpProtocol {
                 if let Some(ref gw_user) = gateway.username {
                     args.push(format!("/gu:{gw_user}"));
                 }
+                if let Some(ref gw_domain) = gateway.domain {
+                    args.push(format!("/gd:{gw_domain}"));
+                }
             }
             for folder in &rdp_config.shared_folders {
-                if folder.share_name.contains(',') || folder.share_name.contains('/') {
-                    tracing::warn!(share_name = %folder.share_name, "Skipping shared folder with invalid share name");
+                if let Err(e) = crate::rdp_client::validate_redirected_drive(
+                    &folder.share_name,
+                    &folder.local_path,
+                ) {
+                    tracing::warn!(share_name = %folder.share_name, %e, "Skipping shared folder");
                     continue;
                 }
                 args.push(format!(
@@ -140,6 +147,17 @@ impl RdpProtocol {
                     folder.local_path.display()
                 ));
             }
+            // Credentials never go on the command line; authenticated
+            // proxies need the embedded client (see models::ProxyConfig)
+            if let Some(ref proxy) = connection.proxy {
+                if proxy.requires_auth() {
+                    tracing::warn!(
+                        proxy = %proxy.address(),
+                        "FreeRDP cannot authenticate to the proxy; use the embedded client"
+                    );
+                }
+                args.push(proxy.freerdp_arg());
+            }
             let dangerous_prefixes = ["/p:", "/password:", "/shell:", "/proxy:"];
             for arg in &rdp_config.custom_args {
                 let lower = arg.to_lowercase();
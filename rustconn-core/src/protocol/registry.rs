@@ -4,8 +4,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use super::{
-    KubernetesProtocol, MoshProtocol, Protocol, RdpProtocol, SerialProtocol, SftpProtocol,
-    SpiceProtocol, SshProtocol, TelnetProtocol, VncProtocol, WebProtocol, ZeroTrustProtocol,
+    KubernetesProtocol, MoshProtocol, Protocol, ProtocolCapabilities, ProtocolFeature, RdpProtocol,
+    SerialProtocol, SftpProtocol, SpiceProtocol, SshProtocol, TelnetProtocol, VncProtocol,
+    WebProtocol, ZeroTrustProtocol,
 };
 use crate::models::ProtocolType;
 
@@ -93,6 +94,21 @@ impl ProtocolRegistry {
         self.protocols.get(id).cloned()
     }
 
+    /// Returns the capabilities of the handler for a protocol type
+    #[must_use]
+    pub fn capabilities(&self, protocol_type: ProtocolType) -> Option<ProtocolCapabilities> {
+        self.get_by_type(protocol_type).map(|p| p.capabilities())
+    }
+
+    /// Returns whether a protocol type supports `feature`
+    ///
+    /// Unregistered protocol types support nothing.
+    #[must_use]
+    pub fn supports(&self, protocol_type: ProtocolType, feature: ProtocolFeature) -> bool {
+        self.capabilities(protocol_type)
+            .is_some_and(|caps| caps.supports(feature))
+    }
+
     /// Returns all registered protocol IDs
     #[must_use]
     pub fn protocol_ids(&self) -> Vec<&'static str> {
@@ -117,3 +133,83 @@ impl Default for ProtocolRegistry {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_TYPES: [ProtocolType; 11] = [
+        ProtocolType::Ssh,
+        ProtocolType::Rdp,
+        ProtocolType::Vnc,
+        ProtocolType::Spice,
+        ProtocolType::Telnet,
+        ProtocolType::Serial,
+        ProtocolType::ZeroTrust,
+        ProtocolType::Sftp,
+        ProtocolType::Kubernetes,
+        ProtocolType::Mosh,
+        ProtocolType::Web,
+    ];
+
+    #[test]
+    fn every_protocol_reports_capabilities() {
+        let registry = ProtocolRegistry::new();
+        assert_eq!(registry.len(), ALL_TYPES.len());
+
+        for protocol_type in ALL_TYPES {
+            let caps = registry
+                .capabilities(protocol_type)
+                .unwrap_or_else(|| panic!("{protocol_type:?} has no handler"));
+
+            // Every protocol must be openable somehow
+            assert!(
+                caps.embedded || caps.external_fallback,
+                "{protocol_type:?} can be neither embedded nor launched externally"
+            );
+            // Terminal sessions are always embedded in VTE
+            if caps.terminal_based {
+                assert!(
+                    caps.embedded,
+                    "{protocol_type:?}: terminal but not embedded"
+                );
+            }
+            if caps.clipboard_files {
+                assert!(
+                    caps.clipboard,
+                    "{protocol_type:?}: clipboard files without clipboard"
+                );
+            }
+            // `supports` must agree with the fields for every feature
+            let enabled = ProtocolFeature::all()
+                .iter()
+                .filter(|f| registry.supports(protocol_type, **f))
+                .count();
+            let expected = ProtocolFeature::all()
+                .iter()
+                .filter(|f| caps.supports(**f))
+                .count();
+            assert_eq!(enabled, expected, "{protocol_type:?}");
+        }
+    }
+
+    #[test]
+    fn feature_flags_match_protocols() {
+        let registry = ProtocolRegistry::new();
+        let has = |t, f| registry.supports(t, f);
+
+        assert!(has(ProtocolType::Rdp, ProtocolFeature::ClipboardFiles));
+        assert!(has(ProtocolType::Rdp, ProtocolFeature::MultiMonitor));
+        assert!(has(ProtocolType::Rdp, ProtocolFeature::DynamicResize));
+        assert!(has(ProtocolType::Spice, ProtocolFeature::UsbRedirection));
+        assert!(has(ProtocolType::Vnc, ProtocolFeature::DynamicResize));
+        assert!(has(ProtocolType::Ssh, ProtocolFeature::PortForwarding));
+        assert!(!has(ProtocolType::Vnc, ProtocolFeature::ClipboardFiles));
+        assert!(!has(ProtocolType::Rdp, ProtocolFeature::UsbRedirection));
+        assert!(!has(ProtocolType::Telnet, ProtocolFeature::PortForwarding));
+        assert!(!has(ProtocolType::Serial, ProtocolFeature::DynamicResize));
+        assert!(!has(ProtocolType::Sftp, ProtocolFeature::SplitView));
+        assert!(has(ProtocolType::Ssh, ProtocolFeature::AccessTunnel));
+        assert!(!has(ProtocolType::Mosh, ProtocolFeature::AccessTunnel));
+    }
+}
@@ -1,8 +1,133 @@
 //! Serial protocol handler
+//!
+//! USB adapters get a new device node when replugged, so a [`SerialConfig`]
+//! may name the adapter with a [`SerialDeviceMatch`] instead.
+//! [`SerialDeviceResolver`] finds its current node through the
+//! `/dev/serial/by-id` symlinks that udev maintains, reading vendor and
+//! product IDs from sysfs.
+
+use std::path::{Path, PathBuf};
 
 use super::{Protocol, ProtocolCapabilities, ProtocolResult};
 use crate::error::ProtocolError;
-use crate::models::{Connection, ProtocolConfig, SerialFlowControl, SerialParity, SerialStopBits};
+use crate::models::{
+    Connection, ProtocolConfig, SerialConfig, SerialDeviceMatch, SerialFlowControl, SerialParity,
+    SerialStopBits,
+};
+
+/// Directory of stable serial device symlinks maintained by udev
+pub const SERIAL_BY_ID_DIR: &str = "/dev/serial/by-id";
+
+/// Sysfs directory with one entry per TTY device
+const SYS_CLASS_TTY_DIR: &str = "/sys/class/tty";
+
+/// How many parents of a TTY's sysfs device are searched for USB IDs
+///
+/// `ttyUSB*` sits two levels below the USB device (interface, then port),
+/// `ttyACM*` one level.
+const USB_ID_SEARCH_DEPTH: usize = 4;
+
+/// Finds the current device node of a matched serial adapter
+#[derive(Debug, Clone)]
+pub struct SerialDeviceResolver {
+    by_id_dir: PathBuf,
+    sys_class_tty: PathBuf,
+}
+
+impl Default for SerialDeviceResolver {
+    fn default() -> Self {
+        Self::with_roots(SERIAL_BY_ID_DIR, SYS_CLASS_TTY_DIR)
+    }
+}
+
+impl SerialDeviceResolver {
+    /// Creates a resolver reading the given by-id and `/sys/class/tty`
+    /// directories instead of the system ones
+    #[must_use]
+    pub fn with_roots(by_id_dir: impl Into<PathBuf>, sys_class_tty: impl Into<PathBuf>) -> Self {
+        Self {
+            by_id_dir: by_id_dir.into(),
+            sys_class_tty: sys_class_tty.into(),
+        }
+    }
+
+    /// Returns the device node `device_match` currently points to, or
+    /// `None` if no such adapter is plugged in
+    ///
+    /// USB IDs are matched in by-id name order, so with two identical
+    /// adapters the result is stable but arbitrary; match by name to pick
+    /// one.
+    #[must_use]
+    pub fn find(&self, device_match: &SerialDeviceMatch) -> Option<PathBuf> {
+        match device_match {
+            SerialDeviceMatch::ById { name } => {
+                // Only plain symlink names; no path components or `..`
+                if name.contains('/') || name.starts_with('.') {
+                    return None;
+                }
+                std::fs::canonicalize(self.by_id_dir.join(name)).ok()
+            }
+            SerialDeviceMatch::UsbId {
+                vendor_id,
+                product_id,
+            } => {
+                let mut links: Vec<PathBuf> = std::fs::read_dir(&self.by_id_dir)
+                    .ok()?
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .collect();
+                links.sort();
+                links
+                    .into_iter()
+                    .filter_map(|link| std::fs::canonicalize(link).ok())
+                    .find(|node| self.usb_id(node) == Some((*vendor_id, *product_id)))
+            }
+        }
+    }
+
+    /// Returns the device to open for `config`
+    ///
+    /// Without a `device_match` this is `config.device`. Otherwise it is the
+    /// matched adapter's current node, falling back to `config.device`
+    /// (which may be empty) when the adapter is not plugged in.
+    #[must_use]
+    pub fn resolve(&self, config: &SerialConfig) -> String {
+        let Some(device_match) = &config.device_match else {
+            return config.device.clone();
+        };
+        if let Some(node) = self.find(device_match) {
+            tracing::debug!(%device_match, node = %node.display(), "Resolved serial adapter");
+            return node.to_string_lossy().into_owned();
+        }
+        tracing::warn!(
+            %device_match,
+            fallback = %config.device,
+            "Serial adapter not found, using configured device path"
+        );
+        config.device.clone()
+    }
+
+    /// Reads the USB vendor and product ID of a TTY device node from sysfs
+    fn usb_id(&self, node: &Path) -> Option<(u16, u16)> {
+        let tty = node.file_name()?;
+        let device = std::fs::canonicalize(self.sys_class_tty.join(tty).join("device")).ok()?;
+        device
+            .ancestors()
+            .take(USB_ID_SEARCH_DEPTH)
+            .find_map(|dir| {
+                Some((
+                    read_hex_id(&dir.join("idVendor"))?,
+                    read_hex_id(&dir.join("idProduct"))?,
+                ))
+            })
+    }
+}
+
+/// Reads a four-digit hexadecimal ID file such as `idVendor`
+fn read_hex_id(path: &Path) -> Option<u16> {
+    let text = std::fs::read_to_string(path).ok()?;
+    u16::from_str_radix(text.trim(), 16).ok()
+}
 
 /// Serial protocol handler
 ///
@@ -40,7 +165,7 @@ impl Protocol for SerialProtocol {
 
     fn validate_connection(&self, connection: &Connection) -> ProtocolResult<()> {
         if let ProtocolConfig::Serial(ref config) = connection.protocol_config {
-            if config.device.is_empty() {
+            if config.device.is_empty() && config.device_match.is_none() {
                 return Err(ProtocolError::InvalidConfig(
                     "Device path cannot be empty".to_string(),
                 ));
@@ -55,7 +180,11 @@ impl Protocol for SerialProtocol {
     }
 
     fn capabilities(&self) -> ProtocolCapabilities {
-        ProtocolCapabilities::terminal()
+        // A serial line has no window-size signalling
+        ProtocolCapabilities {
+            dynamic_resize: false,
+            ..ProtocolCapabilities::terminal()
+        }
     }
 
     fn build_command(&self, connection: &Connection) -> Option<Vec<String>> {
@@ -91,8 +220,9 @@ impl Protocol for SerialProtocol {
             .to_string(),
         ];
 
-        if !config.device.starts_with("/dev/") {
-            tracing::warn!(device = %config.device, "Rejecting non-/dev/ serial device path");
+        let device = SerialDeviceResolver::default().resolve(config);
+        if !device.starts_with("/dev/") {
+            tracing::warn!(%device, "Rejecting non-/dev/ serial device path");
             return None;
         }
 
@@ -103,16 +233,61 @@ impl Protocol for SerialProtocol {
             }
             cmd.push(arg.clone());
         }
-        cmd.push(config.device.clone());
+        cmd.push(device);
 
         Some(cmd)
     }
 }
 
+/// Bytes per hex dump line
+const HEX_DUMP_WIDTH: usize = 16;
+
+/// Formats bytes as a classic hex dump (`hexdump -C` layout).
+///
+/// Each line shows the offset, 16 bytes in hex (split into two groups of
+/// eight), and an ASCII gutter where non-printable bytes appear as `.`.
+/// `base_offset` is the offset of `data[0]` within the capture, so a range
+/// of a larger stream can be dumped with correct offsets. Every line ends
+/// with a newline; empty input yields an empty string.
+#[must_use]
+pub fn format_hex_dump(data: &[u8], base_offset: u64) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::with_capacity(data.len().div_ceil(HEX_DUMP_WIDTH) * 80);
+    let mut offset = base_offset;
+    for chunk in data.chunks(HEX_DUMP_WIDTH) {
+        let _ = write!(out, "{offset:08x} ");
+        for i in 0..HEX_DUMP_WIDTH {
+            if i == HEX_DUMP_WIDTH / 2 {
+                out.push(' ');
+            }
+            match chunk.get(i) {
+                Some(byte) => {
+                    let _ = write!(out, " {byte:02x}");
+                }
+                None => out.push_str("   "),
+            }
+        }
+        out.push_str("  |");
+        out.extend(chunk.iter().map(|&b| {
+            if b.is_ascii_graphic() || b == b' ' {
+                char::from(b)
+            } else {
+                '.'
+            }
+        }));
+        out.push_str("|\n");
+        offset += chunk.len() as u64;
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
+    use std::os::unix::fs::symlink;
+
     use super::*;
-    use crate::models::{ProtocolConfig, SerialBaudRate, SerialConfig};
+    use crate::models::{ProtocolConfig, SerialBaudRate};
 
     fn create_serial_connection(config: SerialConfig) -> Connection {
         Connection::new(
@@ -185,4 +360,152 @@ mod tests {
         let cmd = protocol.build_command(&connection).unwrap();
         assert_eq!(cmd[2], "9600");
     }
+
+    #[test]
+    fn test_hex_dump_layout() {
+        let dump = format_hex_dump(b"Hello, serial!\x00\x01\xffAT", 0);
+        assert_eq!(
+            dump,
+            "00000000  48 65 6c 6c 6f 2c 20 73  65 72 69 61 6c 21 00 01  |Hello, serial!..|\n\
+             00000010  ff 41 54                                          |.AT|\n"
+        );
+    }
+
+    #[test]
+    fn test_hex_dump_offset_and_empty() {
+        assert!(format_hex_dump(&[], 0).is_empty());
+        assert!(format_hex_dump(&[0x7f], 0x1230).starts_with("00001230  7f "));
+    }
+
+    #[test]
+    fn test_hex_dump_line_boundaries() {
+        let data: Vec<u8> = (0x30..0x41).collect();
+        let dump = format_hex_dump(&data, 0xfff0);
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "0000fff0  30 31 32 33 34 35 36 37  38 39 3a 3b 3c 3d 3e 3f  |0123456789:;<=>?|",
+                "00010000  40                                                |@|",
+            ]
+        );
+        // The ASCII gutter starts in the same column on short lines
+        let short = format_hex_dump(&data[..8], 0);
+        assert_eq!(short.find('|'), lines[0].find('|'));
+    }
+
+    /// Lays out a fake `/dev` and `/sys` with a CH340 on `ttyUSB1` and an
+    /// FTDI adapter on `ttyUSB0`, both plugged in through USB hubs
+    fn fake_tree(root: &Path) -> SerialDeviceResolver {
+        let by_id = root.join("dev/serial/by-id");
+        let class_tty = root.join("sys/class/tty");
+        std::fs::create_dir_all(&by_id).unwrap();
+        for (tty, usb, vendor, product, name) in [
+            (
+                "ttyUSB0",
+                "1-2",
+                "0403",
+                "6001",
+                "usb-FTDI_FT232R_A50285BI-if00-port0",
+            ),
+            (
+                "ttyUSB1",
+                "1-3",
+                "1a86",
+                "7523",
+                "usb-1a86_USB_Serial-if00-port0",
+            ),
+        ] {
+            std::fs::write(root.join("dev").join(tty), "").unwrap();
+            symlink(format!("../../{tty}"), by_id.join(name)).unwrap();
+
+            let usb_dir = root.join("sys/devices/usb1").join(usb);
+            let port_dir = usb_dir.join(format!("{usb}:1.0")).join(tty);
+            std::fs::create_dir_all(&port_dir).unwrap();
+            std::fs::write(usb_dir.join("idVendor"), format!("{vendor}\n")).unwrap();
+            std::fs::write(usb_dir.join("idProduct"), format!("{product}\n")).unwrap();
+            std::fs::create_dir_all(class_tty.join(tty)).unwrap();
+            symlink(&port_dir, class_tty.join(tty).join("device")).unwrap();
+        }
+        SerialDeviceResolver::with_roots(by_id, class_tty)
+    }
+
+    #[test]
+    fn test_resolver_matches_usb_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let resolver = fake_tree(dir.path());
+        let dev = std::fs::canonicalize(dir.path().join("dev")).unwrap();
+
+        let ch340 = SerialDeviceMatch::parse("1a86:7523").unwrap();
+        assert_eq!(resolver.find(&ch340), Some(dev.join("ttyUSB1")));
+        let ftdi = SerialDeviceMatch::UsbId {
+            vendor_id: 0x0403,
+            product_id: 0x6001,
+        };
+        assert_eq!(resolver.find(&ftdi), Some(dev.join("ttyUSB0")));
+        let absent = SerialDeviceMatch::parse("10c4:ea60").unwrap();
+        assert_eq!(resolver.find(&absent), None);
+    }
+
+    #[test]
+    fn test_resolver_matches_by_id_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let resolver = fake_tree(dir.path());
+        let dev = std::fs::canonicalize(dir.path().join("dev")).unwrap();
+
+        let by_id =
+            SerialDeviceMatch::parse("/dev/serial/by-id/usb-FTDI_FT232R_A50285BI-if00-port0")
+                .unwrap();
+        assert_eq!(resolver.find(&by_id), Some(dev.join("ttyUSB0")));
+        let escape = SerialDeviceMatch::ById {
+            name: "../../ttyUSB1".to_string(),
+        };
+        assert_eq!(resolver.find(&escape), None);
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_device() {
+        let dir = tempfile::tempdir().unwrap();
+        let resolver = fake_tree(dir.path());
+        let config = SerialConfig {
+            device: "/dev/ttyUSB0".to_string(),
+            device_match: SerialDeviceMatch::parse("10c4:ea60"),
+            ..SerialConfig::default()
+        };
+        assert_eq!(resolver.resolve(&config), "/dev/ttyUSB0");
+    }
+
+    #[test]
+    fn test_device_match_parse_and_display() {
+        assert_eq!(
+            SerialDeviceMatch::parse(" 1A86:7523 "),
+            Some(SerialDeviceMatch::UsbId {
+                vendor_id: 0x1a86,
+                product_id: 0x7523,
+            })
+        );
+        assert_eq!(
+            SerialDeviceMatch::parse("1a86:7523").unwrap().to_string(),
+            "1a86:7523"
+        );
+        assert!(matches!(
+            SerialDeviceMatch::parse("usb-1a86_USB_Serial-if00-port0"),
+            Some(SerialDeviceMatch::ById { .. })
+        ));
+        assert_eq!(SerialDeviceMatch::parse("  "), None);
+    }
+
+    #[test]
+    fn test_validate_accepts_device_match_without_path() {
+        let config = SerialConfig {
+            device_match: SerialDeviceMatch::parse("1a86:7523"),
+            ..SerialConfig::default()
+        };
+        let connection = create_serial_connection(config);
+        assert!(
+            SerialProtocol::new()
+                .validate_connection(&connection)
+                .is_ok()
+        );
+    }
 }
@@ -2,7 +2,8 @@
 
 use super::{Protocol, ProtocolCapabilities, ProtocolResult};
 use crate::error::ProtocolError;
-use crate::models::{Connection, ProtocolConfig, SpiceConfig};
+use crate::models::{Connection, ProtocolConfig, SharedFolder, SpiceConfig};
+use crate::spice_client::{SpiceSharedFolder, SpiceUsbFilter, viewer_shared_dirs};
 
 /// SPICE protocol handler
 ///
@@ -27,6 +28,33 @@ impl SpiceProtocol {
             )),
         }
     }
+
+    /// Validates shared folders have non-empty paths and names
+    ///
+    /// Folders whose directory does not exist only log a warning: the viewer
+    /// starts without them (see [`viewer_shared_dirs`]).
+    fn validate_shared_folders(folders: &[SharedFolder]) -> ProtocolResult<()> {
+        for folder in folders {
+            if folder.local_path.as_os_str().is_empty() {
+                return Err(ProtocolError::InvalidConfig(
+                    "Shared folder local path cannot be empty".to_string(),
+                ));
+            }
+            if folder.share_name.is_empty() {
+                return Err(ProtocolError::InvalidConfig(
+                    "Shared folder share name cannot be empty".to_string(),
+                ));
+            }
+            if !folder.local_path.is_dir() {
+                tracing::warn!(
+                    share = %folder.share_name,
+                    path = %folder.local_path.display(),
+                    "SPICE shared folder does not exist"
+                );
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Default for SpiceProtocol {
@@ -51,6 +79,10 @@ impl Protocol for SpiceProtocol {
     fn validate_connection(&self, connection: &Connection) -> ProtocolResult<()> {
         let spice_config = Self::get_spice_config(connection)?;
 
+        // USB device filters apply in both socket and TCP mode
+        SpiceUsbFilter::parse_list(&spice_config.usb_redirect_filters)
+            .map_err(|e| ProtocolError::InvalidConfig(e.to_string()))?;
+
         // Unix socket mode — skip host/port validation
         if let Some(ref socket_path) = spice_config.unix_socket_path {
             if socket_path.as_os_str().is_empty() {
@@ -59,19 +91,7 @@ impl Protocol for SpiceProtocol {
                 ));
             }
             // Validate shared folders even in socket mode
-            for folder in &spice_config.shared_folders {
-                if folder.local_path.as_os_str().is_empty() {
-                    return Err(ProtocolError::InvalidConfig(
-                        "Shared folder local path cannot be empty".to_string(),
-                    ));
-                }
-                if folder.share_name.is_empty() {
-                    return Err(ProtocolError::InvalidConfig(
-                        "Shared folder share name cannot be empty".to_string(),
-                    ));
-                }
-            }
-            return Ok(());
+            return Self::validate_shared_folders(&spice_config.shared_folders);
         }
 
         // TCP mode — validate host and port
@@ -98,27 +118,14 @@ impl Protocol for SpiceProtocol {
             )));
         }
 
-        // Validate shared folders have non-empty paths and names
-        for folder in &spice_config.shared_folders {
-            if folder.local_path.as_os_str().is_empty() {
-                return Err(ProtocolError::InvalidConfig(
-                    "Shared folder local path cannot be empty".to_string(),
-                ));
-            }
-            if folder.share_name.is_empty() {
-                return Err(ProtocolError::InvalidConfig(
-                    "Shared folder share name cannot be empty".to_string(),
-                ));
-            }
-        }
-
-        Ok(())
+        Self::validate_shared_folders(&spice_config.shared_folders)
     }
 
     fn capabilities(&self) -> ProtocolCapabilities {
         ProtocolCapabilities {
             multi_monitor: true,
             usb_redirection: true,
+            dynamic_resize: true,
             audio: true,
             ..ProtocolCapabilities::external_only(true)
         }
@@ -152,15 +159,34 @@ impl Protocol for SpiceProtocol {
         {
             cmd.push(format!("--spice-ca-file={}", ca_cert.display()));
         }
-        if spice_config.usb_redirection {
-            cmd.push("--spice-usbredir-auto-redirect-filter".to_string());
-            cmd.push(crate::spice_client::SPICE_USB_AUTO_REDIRECT_FILTER.to_string());
+        match SpiceUsbFilter::parse_list(&spice_config.usb_redirect_filters) {
+            Ok(filters) if !filters.is_empty() => {
+                cmd.push("--spice-usbredir-auto-redirect-filter".to_string());
+                cmd.push(SpiceUsbFilter::auto_redirect_filter(&filters));
+            }
+            Ok(_) if spice_config.usb_redirection => {
+                cmd.push("--spice-usbredir-auto-redirect-filter".to_string());
+                cmd.push(crate::spice_client::SPICE_USB_AUTO_REDIRECT_FILTER.to_string());
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!(error = %e, "Invalid SPICE USB filter, skipping USB redirection")
+            }
         }
-        for folder in &spice_config.shared_folders {
-            cmd.push(format!(
-                "--spice-shared-dir={}",
-                folder.local_path.display()
-            ));
+        let folders: Vec<SpiceSharedFolder> = spice_config
+            .shared_folders
+            .iter()
+            .map(SpiceSharedFolder::from)
+            .collect();
+        for dir in viewer_shared_dirs(&folders) {
+            cmd.push(format!("--spice-shared-dir={}", dir.display()));
+        }
+        // Only HTTP proxies are supported (Connection::check_proxy)
+        if let Some(ref proxy) = connection.proxy
+            && proxy.proxy_type == crate::models::ProxyType::Http
+            && spice_config.proxy.is_none()
+        {
+            cmd.push(format!("--spice-proxy={}", proxy.url()));
         }
         if let Some(ref proxy) = spice_config.proxy {
             if proxy
@@ -182,7 +208,7 @@ mod tests {
     use std::path::PathBuf;
 
     use super::*;
-    use crate::models::{ProtocolConfig, SharedFolder, SpiceImageCompression};
+    use crate::models::{ProtocolConfig, SpiceImageCompression};
 
     fn create_spice_connection(config: SpiceConfig) -> Connection {
         Connection::new(
@@ -267,6 +293,7 @@ mod tests {
             shared_folders: vec![SharedFolder {
                 local_path: PathBuf::from("/home/user/share"),
                 share_name: "MyShare".to_string(),
+                read_only: false,
             }],
             ..Default::default()
         };
@@ -281,6 +308,7 @@ mod tests {
             shared_folders: vec![SharedFolder {
                 local_path: PathBuf::new(),
                 share_name: "MyShare".to_string(),
+                read_only: false,
             }],
             ..Default::default()
         };
@@ -295,6 +323,7 @@ mod tests {
             shared_folders: vec![SharedFolder {
                 local_path: PathBuf::from("/home/user/share"),
                 share_name: String::new(),
+                read_only: false,
             }],
             ..Default::default()
         };
@@ -368,6 +397,7 @@ mod tests {
             shared_folders: vec![SharedFolder {
                 local_path: PathBuf::new(),
                 share_name: "Share".to_string(),
+                read_only: false,
             }],
             ..Default::default()
         };
@@ -390,4 +420,69 @@ mod tests {
         // USB flag must match build_spice_viewer_args (no divergence).
         assert!(cmd.contains(&"--spice-usbredir-auto-redirect-filter".to_string()));
     }
+
+    #[test]
+    fn test_shared_folders_skip_missing_and_read_only() {
+        let share = tempfile::tempdir().unwrap();
+        let folder = |path: &std::path::Path, name: &str, read_only| SharedFolder {
+            local_path: path.to_path_buf(),
+            share_name: name.to_string(),
+            read_only,
+        };
+        let config = SpiceConfig {
+            shared_folders: vec![
+                folder(&share.path().join("missing"), "Gone", false),
+                folder(share.path(), "Docs", true),
+                folder(share.path(), "MyShare", false),
+            ],
+            ..Default::default()
+        };
+        let connection = create_spice_connection(config);
+
+        // A missing directory only warns
+        assert!(
+            SpiceProtocol::new()
+                .validate_connection(&connection)
+                .is_ok()
+        );
+        let cmd = SpiceProtocol::new()
+            .build_command(&connection)
+            .expect("command");
+        let dirs: Vec<&String> = cmd
+            .iter()
+            .filter(|a| a.starts_with("--spice-shared-dir="))
+            .collect();
+        assert_eq!(
+            dirs,
+            [&format!("--spice-shared-dir={}", share.path().display())]
+        );
+    }
+
+    #[test]
+    fn test_usb_redirect_filters() {
+        let protocol = SpiceProtocol::new();
+        let config = SpiceConfig {
+            usb_redirect_filters: vec!["1050:0407".to_string()],
+            ..Default::default()
+        };
+        let connection = create_spice_connection(config);
+        assert!(protocol.validate_connection(&connection).is_ok());
+        let cmd = protocol.build_command(&connection).expect("command");
+        let pos = cmd
+            .iter()
+            .position(|a| a == "--spice-usbredir-auto-redirect-filter")
+            .expect("filter flag");
+        assert_eq!(cmd[pos + 1], "-1,0x1050,0x0407,-1,1|-1,-1,-1,-1,0");
+
+        let config = SpiceConfig {
+            usb_redirection: true,
+            usb_redirect_filters: vec!["yubikey".to_string()],
+            ..Default::default()
+        };
+        let connection = create_spice_connection(config);
+        let err = protocol
+            .validate_connection(&connection)
+            .expect_err("invalid filter must fail");
+        assert!(err.to_string().contains("invalid USB filter 'yubikey'"));
+    }
 }
@@ -63,6 +63,12 @@ impl Protocol for SshProtocol {
             return Err(ProtocolError::InvalidConfig("Port cannot be 0".to_string()));
         }
 
+        ssh_config.validate_forwarding()?;
+        ssh_config.validate_proxy_command()?;
+        connection
+            .check_access_tunnel()
+            .map_err(|e| ProtocolError::InvalidConfig(e.to_string()))?;
+
         // Validate key path exists if using public key or security key auth
         if matches!(
             ssh_config.auth_method,
@@ -85,6 +91,7 @@ impl Protocol for SshProtocol {
             port_forwarding: true,
             wayland_forwarding: true,
             x11_forwarding: true,
+            access_tunnel: true,
             ..ProtocolCapabilities::terminal()
         }
     }
@@ -109,6 +116,23 @@ impl Protocol for SshProtocol {
             cmd.push(connection.port.to_string());
         }
 
+        // Zero-trust access tunnel; Connection::check_access_tunnel rejects
+        // it alongside any other route
+        if let Some(ref tunnel) = connection.access_tunnel {
+            cmd.extend(tunnel.ssh_args());
+        }
+
+        // Per-connection proxy, unless ProxyCommand or a jump host already
+        // decides the route (Connection::check_proxy rejects that combination)
+        if let Some(ref proxy) = connection.proxy
+            && connection.access_tunnel.is_none()
+            && ssh_config.proxy_command.is_none()
+            && ssh_config.proxy_jump_spec().is_none()
+        {
+            cmd.push("-o".to_string());
+            cmd.push(format!("ProxyCommand={}", proxy.ssh_proxy_command()));
+        }
+
         // Delegate SSH-specific args to SshConfig::build_command_args()
         cmd.extend(ssh_config.build_command_args());
 
@@ -137,7 +161,9 @@ mod tests {
     use std::path::PathBuf;
 
     use super::*;
-    use crate::models::ProtocolConfig;
+    use crate::models::{
+        AccessTunnel, PortForward, PortForwardDirection, ProtocolConfig, ProxyConfig, ProxyType,
+    };
 
     fn create_ssh_connection(config: SshConfig) -> Connection {
         Connection::new(
@@ -245,6 +271,115 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_build_command_proxy_command_placeholders() {
+        let config = SshConfig {
+            proxy_command: Some("corkscrew proxy.lan 3128 {{host}} {{port}}".to_string()),
+            ..Default::default()
+        };
+        let mut connection = create_ssh_connection(config);
+        connection.port = 2222;
+        let cmd = SshProtocol::new().build_command(&connection).unwrap();
+        let pos = cmd
+            .iter()
+            .position(|a| a == "ProxyCommand=corkscrew proxy.lan 3128 %h %p")
+            .unwrap();
+        assert_eq!(cmd[pos - 1], "-o");
+        assert!(!cmd.iter().any(|a| a.contains("{{")));
+    }
+
+    #[test]
+    fn test_validate_rejects_blank_proxy_command() {
+        let protocol = SshProtocol::new();
+        let connection = create_ssh_connection(SshConfig {
+            proxy_command: Some("   ".to_string()),
+            ..Default::default()
+        });
+        assert!(matches!(
+            protocol.validate_connection(&connection),
+            Err(ProtocolError::InvalidConfig(_))
+        ));
+        let cmd = protocol.build_command(&connection).unwrap();
+        assert!(!cmd.iter().any(|a| a.starts_with("ProxyCommand=")));
+    }
+
+    #[test]
+    fn test_build_command_with_connection_proxy() {
+        let mut conn = create_ssh_connection(SshConfig::default());
+        conn.proxy = Some(ProxyConfig::new(ProxyType::Http, "proxy.lan", 3128));
+        let cmd = SshProtocol::new().build_command(&conn).unwrap();
+        assert!(cmd.contains(
+            &"ProxyCommand=ncat --proxy proxy.lan:3128 --proxy-type http %h %p".to_string()
+        ));
+
+        // An explicit ProxyCommand wins
+        let mut conn = create_ssh_connection(SshConfig {
+            proxy_command: Some("nc -X 5 -x 127.0.0.1:9050 %h %p".to_string()),
+            ..Default::default()
+        });
+        conn.proxy = Some(ProxyConfig::new(ProxyType::Http, "proxy.lan", 3128));
+        let cmd = SshProtocol::new().build_command(&conn).unwrap();
+        assert_eq!(
+            cmd.iter()
+                .filter(|a| a.starts_with("ProxyCommand="))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_build_command_with_access_tunnel() {
+        let protocol = SshProtocol::new();
+        let mut conn = create_ssh_connection(SshConfig::default());
+        conn.username = Some("alice".to_string());
+
+        conn.access_tunnel = Some(AccessTunnel::Cloudflare { hostname: None });
+        let cmd = protocol.build_command(&conn).unwrap();
+        assert_eq!(
+            cmd,
+            [
+                "ssh",
+                "-o",
+                "ProxyCommand=cloudflared access ssh --hostname %h",
+                "alice@example.com"
+            ]
+        );
+
+        conn.access_tunnel = Some(AccessTunnel::Teleport {
+            proxy: Some("teleport.example.com:443".to_string()),
+            cluster: None,
+        });
+        let cmd = protocol.build_command(&conn).unwrap();
+        assert!(cmd.contains(
+            &"ProxyCommand=tsh proxy ssh --proxy teleport.example.com:443 %r@%h:%p".to_string()
+        ));
+        assert_eq!(cmd.last().map(String::as_str), Some("alice@example.com"));
+    }
+
+    #[test]
+    fn test_validate_access_tunnel_conflicts() {
+        let protocol = SshProtocol::new();
+        let mut conn = create_ssh_connection(SshConfig {
+            proxy_jump: Some("bastion.example.com".to_string()),
+            ..Default::default()
+        });
+        conn.access_tunnel = Some(AccessTunnel::Cloudflare { hostname: None });
+        assert!(matches!(
+            protocol.validate_connection(&conn),
+            Err(ProtocolError::InvalidConfig(_))
+        ));
+
+        let mut conn = create_ssh_connection(SshConfig::default());
+        conn.access_tunnel = Some(AccessTunnel::Teleport {
+            proxy: None,
+            cluster: None,
+        });
+        assert!(protocol.validate_connection(&conn).is_ok());
+        conn.proxy = Some(ProxyConfig::new(ProxyType::Socks5, "proxy.lan", 1080));
+        assert!(protocol.validate_connection(&conn).is_err());
+        assert!(protocol.capabilities().access_tunnel);
+    }
+
     #[test]
     fn test_build_command_proxy_command_overrides_proxy_jump() {
         let protocol = SshProtocol::new();
@@ -260,4 +395,100 @@ mod tests {
         // -J should NOT be present (ProxyCommand takes precedence)
         assert!(!cmd.contains(&"-J".to_string()));
     }
+
+    #[test]
+    fn test_validate_trusted_x11_requires_x11_forwarding() {
+        let protocol = SshProtocol::new();
+        let config = SshConfig {
+            x11_trusted: true,
+            ..Default::default()
+        };
+        let connection = create_ssh_connection(config);
+        assert!(protocol.validate_connection(&connection).is_err());
+    }
+
+    #[test]
+    fn test_build_command_forwarding_flags() {
+        let protocol = SshProtocol::new();
+        let config = SshConfig {
+            agent_forwarding: true,
+            x11_forwarding: true,
+            x11_trusted: true,
+            ..Default::default()
+        };
+        let connection = create_ssh_connection(config);
+        let cmd = protocol.build_command(&connection).unwrap();
+        assert!(cmd.contains(&"-A".to_string()));
+        assert!(cmd.contains(&"-Y".to_string()));
+        assert!(!cmd.contains(&"-X".to_string()));
+    }
+
+    #[test]
+    fn test_build_command_dynamic_forward() {
+        let protocol = SshProtocol::new();
+        let config = SshConfig {
+            port_forwards: vec![
+                PortForward {
+                    direction: PortForwardDirection::Dynamic,
+                    bind_address: None,
+                    local_port: 1080,
+                    remote_host: String::new(),
+                    remote_port: 0,
+                },
+                PortForward {
+                    direction: PortForwardDirection::Dynamic,
+                    bind_address: Some("::1".to_string()),
+                    local_port: 1081,
+                    remote_host: String::new(),
+                    remote_port: 0,
+                },
+            ],
+            ..Default::default()
+        };
+        let connection = create_ssh_connection(config);
+        assert!(protocol.validate_connection(&connection).is_ok());
+        let cmd = protocol.build_command(&connection).unwrap();
+        let first = cmd.iter().position(|a| a == "-D").unwrap();
+        assert_eq!(cmd[first + 1], "1080");
+        let second = cmd.iter().rposition(|a| a == "-D").unwrap();
+        assert_eq!(cmd[second + 1], "[::1]:1081");
+    }
+
+    #[test]
+    fn test_validate_dynamic_forward_rejects_destination() {
+        let protocol = SshProtocol::new();
+        let forward = PortForward {
+            direction: PortForwardDirection::Dynamic,
+            bind_address: Some("127.0.0.1".to_string()),
+            local_port: 1080,
+            remote_host: "intranet".to_string(),
+            remote_port: 0,
+        };
+        let connection = create_ssh_connection(SshConfig {
+            port_forwards: vec![forward.clone()],
+            ..Default::default()
+        });
+        assert!(protocol.validate_connection(&connection).is_err());
+
+        let connection = create_ssh_connection(SshConfig {
+            port_forwards: vec![PortForward {
+                remote_host: String::new(),
+                remote_port: 80,
+                ..forward
+            }],
+            ..Default::default()
+        });
+        assert!(protocol.validate_connection(&connection).is_err());
+    }
+
+    #[test]
+    fn test_agent_forwarding_warning_shown_once() {
+        let mut config = SshConfig {
+            agent_forwarding: true,
+            ..Default::default()
+        };
+        assert!(config.needs_agent_forwarding_warning());
+        config.acknowledge_agent_forwarding();
+        assert!(!config.needs_agent_forwarding_warning());
+    }
 }
@@ -28,6 +28,13 @@ impl VncProtocol {
         }
     }
 
+    /// External viewer that can connect through a VNC repeater
+    ///
+    /// `ssvncviewer` (the SSVNC-enhanced TightVNC viewer) takes the repeater
+    /// as its server address and the target as `-repeater ID:<id>`; the other
+    /// supported viewers cannot use a repeater.
+    pub const REPEATER_VIEWER: &'static str = "ssvncviewer";
+
     /// Builds the server address string for an external viewer and port.
     ///
     /// TigerVNC-family viewers expect a display-number form (`host:0`) for the
@@ -36,7 +43,7 @@ impl VncProtocol {
     #[must_use]
     fn external_server_address(viewer: &str, host: &str, port: u16) -> String {
         match viewer {
-            "vncviewer" | "tigervnc" | "xvnc4viewer" | "gvncviewer" => {
+            "vncviewer" | "tigervnc" | "xvnc4viewer" | "gvncviewer" | "ssvncviewer" => {
                 // These viewers use display-number format for standard ports.
                 if port == 5900 {
                     format!("{host}:0")
@@ -64,6 +71,10 @@ impl VncProtocol {
     /// function assembles arguments only. Passwords are intentionally never
     /// placed on the command line.
     ///
+    /// With a [`VncConfig::repeater`], [`Self::REPEATER_VIEWER`] is pointed
+    /// at the repeater; other viewers cannot use one and connect to
+    /// `host:port` directly, with a warning.
+    ///
     /// `custom_args` containing NUL or newline bytes are skipped.
     #[must_use]
     pub fn build_external_viewer_command(
@@ -72,9 +83,22 @@ impl VncProtocol {
         port: u16,
         config: &VncConfig,
     ) -> (String, Vec<String>) {
-        let server = Self::external_server_address(viewer, host, port);
+        let mut server = Self::external_server_address(viewer, host, port);
         let mut args: Vec<String> = Vec::new();
 
+        if let Some(ref repeater) = config.repeater {
+            if viewer == Self::REPEATER_VIEWER {
+                server = Self::external_server_address(viewer, &repeater.host, repeater.port);
+            } else {
+                tracing::warn!(
+                    viewer,
+                    repeater = %repeater.host,
+                    "VNC viewer cannot use a repeater; connecting to the server directly. \
+                     Install ssvncviewer or use the embedded client"
+                );
+            }
+        }
+
         match viewer {
             "vncviewer" | "tigervnc" | "xvnc4viewer" => {
                 // TigerVNC/TightVNC/RealVNC style.
@@ -108,6 +132,29 @@ impl VncProtocol {
                 // GTK-VNC viewer.
                 args.push(server);
             }
+            "ssvncviewer" => {
+                // SSVNC-enhanced TightVNC viewer.
+                if let Some(ref encoding) = config.encoding {
+                    args.push("-encodings".to_string());
+                    args.push(encoding.trim().replace(',', " "));
+                }
+                if let Some(quality) = config.quality {
+                    args.push("-quality".to_string());
+                    args.push(quality.to_string());
+                }
+                if let Some(compression) = config.compression {
+                    args.push("-compresslevel".to_string());
+                    args.push(compression.to_string());
+                }
+                if config.view_only {
+                    args.push("-viewonly".to_string());
+                }
+                if let Some(ref repeater) = config.repeater {
+                    args.push("-repeater".to_string());
+                    args.push(repeater.target());
+                }
+                args.push(server);
+            }
             "remmina" => {
                 // Remmina uses a different connection format.
                 args.push("-c".to_string());
@@ -188,6 +235,10 @@ impl Protocol for VncProtocol {
             )));
         }
 
+        if let Some(ref repeater) = vnc_config.repeater {
+            repeater.validate()?;
+        }
+
         Ok(())
     }
 
@@ -196,7 +247,21 @@ impl Protocol for VncProtocol {
     }
 
     fn build_command(&self, connection: &Connection) -> Option<Vec<String>> {
+        // External viewers have no proxy option; connecting directly would
+        // silently bypass the proxy, so only the embedded client can be used
+        if let Some(ref proxy) = connection.proxy {
+            tracing::error!(
+                proxy = %proxy.address(),
+                "External VNC viewers cannot use a proxy; use the embedded client"
+            );
+            return None;
+        }
+
         let mut args = Vec::new();
+        let repeater = match connection.protocol_config {
+            ProtocolConfig::Vnc(ref vnc_config) => vnc_config.repeater.as_ref(),
+            _ => None,
+        };
 
         if let ProtocolConfig::Vnc(ref vnc_config) = connection.protocol_config {
             if let Some(ref encoding) = vnc_config.encoding {
@@ -211,8 +276,8 @@ impl Protocol for VncProtocol {
                 args.push("-quality".to_string());
                 args.push(quality.to_string());
             }
-            // Accept untrusted TLS certificates (VeNCrypt)
-            if vnc_config.accept_certificate {
+            // Accept untrusted TLS certificates (VeNCrypt); TigerVNC only
+            if vnc_config.accept_certificate && repeater.is_none() {
                 args.push("-SecurityTypes".to_string());
                 args.push("VeNCrypt,TLSVnc,X509Vnc,VncAuth,None".to_string());
             }
@@ -240,6 +305,20 @@ impl Protocol for VncProtocol {
             }
         }
 
+        // A repeater is dialled instead of the server and told its ID
+        if let Some(repeater) = repeater {
+            args.push("-repeater".to_string());
+            args.push(repeater.target());
+            args.push(Self::external_server_address(
+                Self::REPEATER_VIEWER,
+                &repeater.host,
+                repeater.port,
+            ));
+            let mut cmd = vec![Self::REPEATER_VIEWER.to_string()];
+            cmd.extend(args);
+            return Some(cmd);
+        }
+
         let display = if connection.port >= 5900 {
             connection.port - 5900
         } else {
@@ -256,7 +335,7 @@ impl Protocol for VncProtocol {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::ProtocolConfig;
+    use crate::models::{ProtocolConfig, VncRepeater};
 
     fn create_vnc_connection(config: VncConfig) -> Connection {
         Connection::new(
@@ -442,6 +521,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_repeater_viewer_args() {
+        let config = VncConfig {
+            view_only: true,
+            repeater: Some(VncRepeater::new("repeater.example.com", "1234")),
+            ..Default::default()
+        };
+        let (program, args) =
+            VncProtocol::build_external_viewer_command("ssvncviewer", "desktop", 5900, &config);
+        assert_eq!(program, "ssvncviewer");
+        assert_eq!(
+            args,
+            [
+                "-viewonly",
+                "-repeater",
+                "ID:1234",
+                "repeater.example.com:1"
+            ]
+        );
+
+        // Viewers without repeater support still get the server
+        let (_program, args) =
+            VncProtocol::build_external_viewer_command("vncviewer", "desktop", 5900, &config);
+        assert_eq!(args.last().map(String::as_str), Some("desktop:0"));
+
+        let conn = create_vnc_connection(config);
+        assert!(VncProtocol::new().validate_connection(&conn).is_ok());
+        let cmd = VncProtocol::new().build_command(&conn).unwrap();
+        assert_eq!(
+            cmd,
+            [
+                "ssvncviewer",
+                "-repeater",
+                "ID:1234",
+                "repeater.example.com:1"
+            ]
+        );
+
+        let bad = create_vnc_connection(VncConfig {
+            repeater: Some(VncRepeater::new("repeater.example.com", "ID:1234")),
+            ..Default::default()
+        });
+        assert!(VncProtocol::new().validate_connection(&bad).is_err());
+    }
+
     #[test]
     fn test_build_external_viewer_command_filters_unsafe_custom_args() {
         let config = VncConfig {
@@ -88,6 +88,7 @@ impl Protocol for WebProtocol {
             file_transfer: false,
             audio: false,
             clipboard: false,
+            clipboard_files: false,
             #[cfg(feature = "web-embedded")]
             split_view: true,
             #[cfg(not(feature = "web-embedded"))]
@@ -95,6 +96,10 @@ impl Protocol for WebProtocol {
             terminal_based: false,
             multi_monitor: false,
             usb_redirection: false,
+            #[cfg(feature = "web-embedded")]
+            dynamic_resize: true,
+            #[cfg(not(feature = "web-embedded"))]
+            dynamic_resize: false,
             port_forwarding: false,
             wayland_forwarding: false,
             x11_forwarding: false,
@@ -102,6 +107,7 @@ impl Protocol for WebProtocol {
             remote_monitoring: false,
             command_snippets: false,
             wake_on_lan: false,
+            access_tunnel: false,
         }
     }
 
@@ -218,6 +218,31 @@ pub(super) async fn process_command<W: FramedWrite>(
             let formats = vec![super::super::ClipboardFormatInfo::unicode_text()];
             handle_clipboard_copy(active_stage, writer, formats).await;
         }
+        RdpClientCommand::ClipboardImage(png) => {
+            // Convert the GUI's PNG to CF_DIB under the backend's size cap,
+            // store it for on_format_data_request and announce CF_DIB.
+            let Some(backend) = active_stage
+                .get_svc_processor_mut::<CliprdrClient>()
+                .and_then(|cliprdr| {
+                    cliprdr
+                        .downcast_backend_mut::<super::super::clipboard::RustConnClipboardBackend>()
+                })
+            else {
+                return Ok(false);
+            };
+            match super::super::png_to_dib(&png, backend.max_image_bytes()) {
+                Ok(dib) => {
+                    tracing::debug!(bytes = dib.len(), "Setting local clipboard image");
+                    backend.set_pending_copy_data(super::super::ClipboardFormatInfo::DIB, dib);
+                }
+                Err(e) => {
+                    tracing::warn!("Not sending local clipboard image: {}", e);
+                    return Ok(false);
+                }
+            }
+            let formats = vec![super::super::ClipboardFormatInfo::dib()];
+            handle_clipboard_copy(active_stage, writer, formats).await;
+        }
         RdpClientCommand::Authenticate { .. } => {}
         RdpClientCommand::AutotypeText {
             text,
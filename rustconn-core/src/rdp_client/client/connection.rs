@@ -5,7 +5,10 @@ use ironrdp::cliprdr::CliprdrClient;
 use ironrdp::connector::{
     BitmapConfig, ClientConnector, Config, ConnectionResult, Credentials, DesktopSize, ServerName,
 };
-use ironrdp::dvc::DrdynvcClient;
+use ironrdp::displaycontrol::pdu::{
+    DisplayControlMonitorLayout, DisplayControlPdu, MonitorLayoutEntry,
+};
+use ironrdp::dvc::{DrdynvcClient, DvcMessage};
 use ironrdp::echo::client::EchoClient;
 use ironrdp::pdu::gcc::KeyboardType;
 use ironrdp::pdu::rdp::capability_sets::{
@@ -26,7 +29,8 @@ use super::super::clipboard::RustConnClipboardBackend;
 #[cfg(feature = "gfx-h264")]
 use super::super::gfx_handler::{GfxFrameUpdate, RustConnGfxHandler, try_load_openh264};
 use super::super::rdpdr::{RustConnRdpdrBackend, cups_default_printer, list_cups_printers};
-use super::super::{RdpClientConfig, RdpClientError, RdpClientEvent};
+use super::super::{MonitorLayout, RdpClientConfig, RdpClientError, RdpClientEvent};
+use crate::models::ProxyConfig;
 
 /// Transport layer: either a direct TCP connection or a gateway tunnel.
 enum GatewayOrTcp {
@@ -35,6 +39,23 @@ enum GatewayOrTcp {
     Gateway(ironrdp_mstsgu::GwClient),
 }
 
+/// Opens the TCP connection to the server through the configured proxy
+async fn connect_proxied(
+    config: &RdpClientConfig,
+    proxy: &ProxyConfig,
+    connect_timeout: std::time::Duration,
+) -> std::io::Result<TcpStream> {
+    crate::connection::connect_via_proxy_async(
+        proxy,
+        config.proxy_password.as_ref(),
+        &config.host,
+        config.port,
+        connect_timeout,
+    )
+    .await
+    .map_err(|e| std::io::Error::other(e.to_string()))
+}
+
 /// Helper trait that combines `AsyncRead + AsyncWrite + Unpin + Send + Sync` for type-erased streams.
 pub(super) trait AsyncReadWrite:
     tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + Send + Sync
@@ -154,7 +175,11 @@ pub(super) async fn establish_connection(
             }
         }
     } else {
-        let tcp_result = timeout(connect_timeout, TcpStream::connect(&server_addr)).await;
+        let tcp_result = if let Some(ref proxy) = config.proxy {
+            Ok(connect_proxied(config, proxy, connect_timeout).await)
+        } else {
+            timeout(connect_timeout, TcpStream::connect(&server_addr)).await
+        };
         let stream = match tcp_result {
             Ok(Ok(stream)) => {
                 let _ = stream.set_nodelay(true);
@@ -177,7 +202,9 @@ pub(super) async fn establish_connection(
 
     #[cfg(not(feature = "rd-gateway"))]
     let (stream, client_addr) = {
-        let tcp_result = if config.mptcp {
+        let tcp_result = if let Some(ref proxy) = config.proxy {
+            Ok(connect_proxied(config, proxy, connect_timeout).await)
+        } else if config.mptcp {
             // MPTCP path: resolve hostname then use MPTCP socket
             let resolved = timeout(connect_timeout, async {
                 let addr = tokio::net::lookup_host(&server_addr)
@@ -236,7 +263,8 @@ pub(super) async fn establish_connection(
 
     // Phase 2.5: Add clipboard channel if enabled
     if config.clipboard_enabled {
-        let clipboard_backend = RustConnClipboardBackend::new(event_tx.clone());
+        let clipboard_backend = RustConnClipboardBackend::new(event_tx.clone())
+            .with_max_image_bytes(config.max_clipboard_image_bytes);
         let cliprdr: CliprdrClient = ironrdp::cliprdr::Cliprdr::new(Box::new(clipboard_backend));
         connector.static_channels.insert(cliprdr);
         tracing::debug!("Clipboard channel enabled");
@@ -350,10 +378,12 @@ pub(super) async fn establish_connection(
     // DisplayControlClient is also registered here for dynamic resolution changes (MS-RDPEDISP).
     // The Echo channel (MS-RDPEECO) responds to server echo requests, enabling the server
     // to measure round-trip time and report it back via Auto-Detect PDU.
-    // ironrdp 0.16: DisplayControlClient::new takes a capabilities callback. We send the
-    // monitor layout on demand via ActiveStage::encode_resize, so nothing is emitted when
-    // capabilities arrive — return an empty message set.
+    // ironrdp 0.16: DisplayControlClient::new takes a capabilities callback. For a
+    // single monitor the layout is sent on demand via ActiveStage::encode_resize, so
+    // nothing is emitted when capabilities arrive. A multi-monitor layout is sent
+    // here, as soon as the channel opens.
     let dc_ready_tx = event_tx.clone();
+    let monitor_layout = config.monitor_layout.clone();
     #[cfg_attr(
         not(feature = "gfx-h264"),
         expect(unused_mut, reason = "mut needed when gfx-h264 adds a channel")
@@ -364,10 +394,10 @@ pub(super) async fn establish_connection(
                 // Capabilities have arrived → the Display Control channel is
                 // ready for MS-RDPEDISP resize. Signal the GUI so the initial
                 // "snap to settled size" goes over Display Control instead of a
-                // premature reconnect. No monitor layout is emitted here — it is
-                // sent on demand via ActiveStage::encode_resize.
+                // premature reconnect. Single-monitor layouts are sent on demand
+                // via ActiveStage::encode_resize.
                 let _ = dc_ready_tx.send(RdpClientEvent::DisplayControlReady);
-                Ok(Vec::new())
+                Ok(monitor_layout_messages(&monitor_layout))
             },
         ))
         .with_dynamic_channel(EchoClient::new());
@@ -587,6 +617,45 @@ pub(super) async fn establish_connection(
     }
 }
 
+/// Builds the MS-RDPEDISP monitor layout PDU for a multi-monitor session
+///
+/// Returns no messages for a single monitor, or if `IronRDP` rejects the
+/// layout (the session then keeps the single desktop it connected with).
+fn monitor_layout_messages(layout: &MonitorLayout) -> Vec<DvcMessage> {
+    if !layout.is_multimonitor() {
+        return Vec::new();
+    }
+
+    let entries: Option<Vec<MonitorLayoutEntry>> = layout
+        .negotiation_monitors()
+        .iter()
+        .map(|monitor| {
+            let entry = if monitor.is_primary {
+                MonitorLayoutEntry::new_primary(monitor.width(), monitor.height())
+            } else {
+                MonitorLayoutEntry::new_secondary(monitor.width(), monitor.height())
+            };
+            entry
+                .and_then(|entry| entry.with_position(monitor.left, monitor.top))
+                .ok()
+        })
+        .collect();
+
+    match entries.map(|entries| DisplayControlMonitorLayout::new(&entries)) {
+        Some(Ok(pdu)) => {
+            tracing::debug!(
+                monitors = layout.active_monitors().len(),
+                "Sending multi-monitor layout over Display Control"
+            );
+            vec![Box::new(DisplayControlPdu::from(pdu))]
+        }
+        _ => {
+            tracing::warn!("Monitor layout rejected; continuing with a single desktop");
+            Vec::new()
+        }
+    }
+}
+
 /// Builds `IronRDP` connector configuration from our config
 fn build_connector_config(config: &RdpClientConfig) -> Config {
     // Always use UsernamePassword credentials
@@ -629,11 +698,13 @@ fn build_connector_config(config: &RdpClientConfig) -> Config {
     // Build performance flags based on performance mode
     let performance_flags = build_performance_flags(config.performance_mode);
 
+    let (enable_tls, enable_credssp) = config.security_flags();
+
     Config {
         credentials,
         domain: config.domain.clone(),
-        enable_tls: true,
-        enable_credssp: config.nla_enabled,
+        enable_tls,
+        enable_credssp,
         keyboard_type: KeyboardType::IbmEnhanced,
         keyboard_subtype: 0,
         keyboard_functional_keys_count: 12,
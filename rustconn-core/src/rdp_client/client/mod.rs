@@ -78,10 +78,13 @@ impl RdpClient {
     /// # Errors
     ///
     /// Returns `RdpClientError::AlreadyConnected` if already connected.
+    /// Returns `RdpClientError::SharedFolderError` if a redirected drive is
+    /// not an existing directory or has an invalid label.
     pub fn connect(&mut self) -> Result<(), RdpClientError> {
         if self.connected.load(Ordering::SeqCst) {
             return Err(RdpClientError::AlreadyConnected);
         }
+        self.config.validate_shared_folders()?;
 
         self.shutdown_signal.store(false, Ordering::SeqCst);
 
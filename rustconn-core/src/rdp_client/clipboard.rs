@@ -6,14 +6,16 @@
 //! # Bidirectional Clipboard Support
 //!
 //! The clipboard supports both directions:
-//! - Server → Client: `on_remote_copy` → `on_format_data_response` → `ClipboardText` event
+//! - Server → Client: `on_remote_copy` → `on_format_data_response` → `ClipboardText`
+//!   or `ClipboardImage` event
 //! - Client → Server: `ClipboardCopy` command → `on_format_data_request` → `ClipboardData` command
 //!
 //! # Supported Formats
 //!
 //! - `CF_UNICODETEXT` (13): Unicode text (UTF-16LE)
 //! - `CF_TEXT` (1): ANSI text
-//! - `CF_DIB` (8): Device-independent bitmap (future)
+//! - `CF_DIB` (8) and registered `PNG`: images, exchanged with the GUI as PNG
+//!   (see [`super::clipboard_image`]) and capped at `max_image_bytes`
 //! - `CF_HDROP` (15): File list (future)
 
 use std::collections::HashMap;
@@ -27,6 +29,7 @@ use ironrdp::cliprdr::pdu::{
 use ironrdp::core::impl_as_any;
 use tracing::{debug, trace, warn};
 
+use super::clipboard_image::{ClipboardImage, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES};
 use super::{ClipboardFileInfo, ClipboardFormatInfo, RdpClientEvent};
 
 /// Proxy for sending clipboard messages to the main event loop
@@ -109,6 +112,10 @@ pub struct RustConnClipboardBackend {
     /// Local file paths for client → server file transfer (DnD).
     /// Indexed by file_index as announced in `FileGroupDescriptorW`.
     local_file_paths: Vec<std::path::PathBuf>,
+    /// ID the server registered for the `PNG` format in its last format list
+    remote_png_format: Option<ClipboardFormatId>,
+    /// Cap for decoded clipboard images in bytes
+    max_image_bytes: usize,
 }
 
 impl_as_any!(RustConnClipboardBackend);
@@ -124,9 +131,24 @@ impl RustConnClipboardBackend {
             pending_copy_data: HashMap::new(),
             server_capabilities: ClipboardGeneralCapabilityFlags::empty(),
             local_file_paths: Vec::new(),
+            remote_png_format: None,
+            max_image_bytes: DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES,
         }
     }
 
+    /// Sets the cap for decoded clipboard images in bytes
+    #[must_use]
+    pub const fn with_max_image_bytes(mut self, bytes: usize) -> Self {
+        self.max_image_bytes = bytes;
+        self
+    }
+
+    /// Returns the cap for decoded clipboard images in bytes
+    #[must_use]
+    pub const fn max_image_bytes(&self) -> usize {
+        self.max_image_bytes
+    }
+
     /// Returns true if the clipboard is ready
     #[must_use]
     pub const fn is_ready(&self) -> bool {
@@ -265,6 +287,15 @@ impl CliprdrBackend for RustConnClipboardBackend {
             .event_tx
             .send(RdpClientEvent::ClipboardFormatsAvailable(format_infos));
 
+        self.remote_png_format = available_formats
+            .iter()
+            .find(|f| {
+                f.name
+                    .as_ref()
+                    .is_some_and(|n| n.value() == ClipboardFormatInfo::PNG_NAME)
+            })
+            .map(|f| f.id);
+
         // Check if text format is available and auto-request it
         let text_format = available_formats
             .iter()
@@ -283,8 +314,49 @@ impl CliprdrBackend for RustConnClipboardBackend {
             self.pending_paste_format = Some(format.id);
             self.proxy
                 .send_clipboard_message(ClipboardMessage::SendInitiatePaste(format.id));
+        } else if let Some(format_id) = self.remote_png_format.or_else(|| {
+            available_formats
+                .iter()
+                .find(|f| f.id == ClipboardFormatId::CF_DIB)
+                .map(|f| f.id)
+        }) {
+            // No text: a screenshot or image copy. PNG keeps transparency,
+            // CF_DIB is what every Windows application provides.
+            debug!(
+                "Image format available (id={}), requesting paste",
+                format_id.value()
+            );
+            self.pending_paste_format = Some(format_id);
+            self.proxy
+                .send_clipboard_message(ClipboardMessage::SendInitiatePaste(format_id));
         } else {
-            debug!("No text format available in clipboard");
+            debug!("No text or image format available in clipboard");
+        }
+    }
+
+    /// Converts a received image to PNG and forwards it to the GUI
+    ///
+    /// Images over the size cap are dropped with a warning.
+    fn forward_remote_image(&self, format_id: ClipboardFormatId, data: &[u8]) {
+        let image = if format_id == ClipboardFormatId::CF_DIB {
+            ClipboardImage::from_dib(data, self.max_image_bytes)
+        } else {
+            ClipboardImage::from_png(data, self.max_image_bytes)
+        };
+        match image {
+            Ok(image) => {
+                debug!("Clipboard image decoded: {}x{}", image.width, image.height);
+                let png = if format_id == ClipboardFormatId::CF_DIB {
+                    image.to_png()
+                } else {
+                    data.to_vec()
+                };
+                let _ = self
+                    .proxy
+                    .event_tx
+                    .send(RdpClientEvent::ClipboardImage(png));
+            }
+            Err(e) => warn!("Dropping remote clipboard image: {}", e),
         }
     }
 
@@ -343,6 +415,13 @@ impl CliprdrBackend for RustConnClipboardBackend {
             return;
         }
 
+        if let Some(id) = format_id
+            && (id == ClipboardFormatId::CF_DIB || Some(id) == self.remote_png_format)
+        {
+            self.forward_remote_image(id, data);
+            return;
+        }
+
         match format_id {
             Some(ClipboardFormatId::CF_UNICODETEXT) | None => {
                 if let Ok(text) = string_from_utf16(data) {
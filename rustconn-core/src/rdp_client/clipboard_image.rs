@@ -0,0 +1,606 @@
+//! Clipboard image conversion between RDP and GTK
+//!
+//! Windows exchanges clipboard images as `CF_DIB` (a `BITMAPINFOHEADER`
+//! followed by bottom-up BGR(A) rows), while GTK clipboards carry
+//! `image/png`. This module converts between the two without pulling in an
+//! image crate: PNG encoding and decoding only need zlib (`flate2`).
+//!
+//! Supported inputs:
+//! - DIB: 24 and 32 bits per pixel, `BI_RGB` or `BI_BITFIELDS` with the
+//!   standard BGRA masks, `BITMAPINFOHEADER`/`V4`/`V5` headers, top-down or
+//!   bottom-up
+//! - PNG: 8-bit grayscale, gray+alpha, RGB and RGBA, non-interlaced
+//!
+//! Every conversion is bounded by a byte limit on the decoded RGBA buffer so
+//! a huge remote screenshot cannot cause a memory spike.
+
+use std::io::{Read, Write};
+
+use flate2::Compression;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use thiserror::Error;
+
+/// Default cap for decoded clipboard images (64 MiB of RGBA, e.g. 4096x4096)
+pub const DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES: usize = 64 * 1024 * 1024;
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+const BITMAPINFOHEADER_SIZE: usize = 40;
+const BI_RGB: u32 = 0;
+const BI_BITFIELDS: u32 = 3;
+
+/// Errors from clipboard image conversion
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ClipboardImageError {
+    /// The decoded image would exceed the configured size cap
+    #[error("Clipboard image of {size} bytes exceeds the {limit} byte limit")]
+    TooLarge {
+        /// Decoded size in bytes
+        size: usize,
+        /// Configured limit in bytes
+        limit: usize,
+    },
+    /// The DIB data is truncated or malformed
+    #[error("Invalid DIB: {0}")]
+    InvalidDib(String),
+    /// The PNG data is truncated or malformed
+    #[error("Invalid PNG: {0}")]
+    InvalidPng(String),
+    /// The image uses a valid but unsupported encoding
+    #[error("Unsupported clipboard image: {0}")]
+    Unsupported(String),
+}
+
+/// Result type for clipboard image conversion
+pub type ClipboardImageResult<T> = Result<T, ClipboardImageError>;
+
+/// A decoded clipboard image with straight (non-premultiplied) RGBA pixels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardImage {
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// Top-down RGBA rows, `width * height * 4` bytes
+    pub rgba: Vec<u8>,
+}
+
+impl ClipboardImage {
+    /// Creates an image from RGBA pixels
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClipboardImageError::InvalidDib`] if the buffer length does
+    /// not match the dimensions.
+    pub fn new(width: u32, height: u32, rgba: Vec<u8>) -> ClipboardImageResult<Self> {
+        if Some(rgba.len()) != rgba_len(width, height) {
+            return Err(ClipboardImageError::InvalidDib(format!(
+                "{} bytes do not match {width}x{height} RGBA",
+                rgba.len()
+            )));
+        }
+        Ok(Self {
+            width,
+            height,
+            rgba,
+        })
+    }
+
+    /// Decodes a `CF_DIB` payload
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the DIB is malformed, uses an unsupported pixel
+    /// format, or decodes to more than `max_bytes`.
+    pub fn from_dib(dib: &[u8], max_bytes: usize) -> ClipboardImageResult<Self> {
+        let invalid = |msg: &str| ClipboardImageError::InvalidDib(msg.to_string());
+
+        let header_size = read_u32_le(dib, 0).ok_or_else(|| invalid("missing header"))? as usize;
+        if header_size < BITMAPINFOHEADER_SIZE || dib.len() < header_size {
+            return Err(invalid("truncated header"));
+        }
+        let width = read_i32_le(dib, 4).ok_or_else(|| invalid("missing width"))?;
+        let height = read_i32_le(dib, 8).ok_or_else(|| invalid("missing height"))?;
+        let bit_count = read_u16_le(dib, 14).ok_or_else(|| invalid("missing bit count"))?;
+        let compression = read_u32_le(dib, 16).ok_or_else(|| invalid("missing compression"))?;
+        let colors_used = read_u32_le(dib, 32).ok_or_else(|| invalid("missing color count"))?;
+
+        if width <= 0 || height == 0 {
+            return Err(invalid("empty image"));
+        }
+        let top_down = height < 0;
+        let width = width.unsigned_abs();
+        let height = height.unsigned_abs();
+        let size = checked_rgba_len(width, height, max_bytes)?;
+
+        let mut pixel_offset = header_size;
+        match (compression, bit_count) {
+            (BI_RGB, 24 | 32) => {}
+            (BI_BITFIELDS, 32) => {
+                // BITMAPINFOHEADER stores the masks after the header; V4/V5
+                // headers contain them at the same offset
+                if header_size == BITMAPINFOHEADER_SIZE {
+                    pixel_offset += 12;
+                }
+                let masks = (
+                    read_u32_le(dib, 40),
+                    read_u32_le(dib, 44),
+                    read_u32_le(dib, 48),
+                );
+                if masks != (Some(0x00FF_0000), Some(0x0000_FF00), Some(0x0000_00FF)) {
+                    return Err(ClipboardImageError::Unsupported(
+                        "non-standard BI_BITFIELDS masks".to_string(),
+                    ));
+                }
+            }
+            _ => {
+                return Err(ClipboardImageError::Unsupported(format!(
+                    "{bit_count} bpp with compression {compression}"
+                )));
+            }
+        }
+        pixel_offset += colors_used as usize * 4;
+
+        let bytes_per_pixel = usize::from(bit_count / 8);
+        let stride = (width as usize * bytes_per_pixel).div_ceil(4) * 4;
+        let pixels = dib
+            .get(pixel_offset..)
+            .filter(|p| p.len() >= stride * height as usize)
+            .ok_or_else(|| invalid("truncated pixel data"))?;
+
+        let mut rgba = Vec::with_capacity(size);
+        for y in 0..height as usize {
+            let src_row = if top_down { y } else { height as usize - 1 - y };
+            let row = &pixels[src_row * stride..][..width as usize * bytes_per_pixel];
+            for px in row.chunks_exact(bytes_per_pixel) {
+                let alpha = if bytes_per_pixel == 4 { px[3] } else { 0xFF };
+                rgba.extend_from_slice(&[px[2], px[1], px[0], alpha]);
+            }
+        }
+
+        // 32 bpp BI_RGB leaves the fourth byte undefined and most Windows
+        // applications write zero there; treat a fully transparent bitmap
+        // as opaque instead of pasting an invisible image
+        if bytes_per_pixel == 4 && rgba.chunks_exact(4).all(|px| px[3] == 0) {
+            for px in rgba.chunks_exact_mut(4) {
+                px[3] = 0xFF;
+            }
+        }
+
+        Ok(Self {
+            width,
+            height,
+            rgba,
+        })
+    }
+
+    /// Encodes the image as a 32 bpp bottom-up `CF_DIB` payload
+    #[must_use]
+    pub fn to_dib(&self) -> Vec<u8> {
+        let image_size = self.rgba.len();
+        let mut dib = Vec::with_capacity(BITMAPINFOHEADER_SIZE + image_size);
+        dib.extend_from_slice(&(BITMAPINFOHEADER_SIZE as u32).to_le_bytes());
+        dib.extend_from_slice(&self.width.cast_signed().to_le_bytes());
+        dib.extend_from_slice(&self.height.cast_signed().to_le_bytes());
+        dib.extend_from_slice(&1u16.to_le_bytes()); // planes
+        dib.extend_from_slice(&32u16.to_le_bytes()); // bits per pixel
+        dib.extend_from_slice(&BI_RGB.to_le_bytes());
+        dib.extend_from_slice(&u32::try_from(image_size).unwrap_or(0).to_le_bytes());
+        dib.extend_from_slice(&[0; 16]); // resolution and color table counts
+
+        let row_len = self.width as usize * 4;
+        for row in self.rgba.chunks_exact(row_len.max(1)).rev() {
+            for px in row.chunks_exact(4) {
+                dib.extend_from_slice(&[px[2], px[1], px[0], px[3]]);
+            }
+        }
+        dib
+    }
+
+    /// Decodes a PNG image
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the PNG is malformed, uses an unsupported color
+    /// type, bit depth or interlacing, or decodes to more than `max_bytes`.
+    pub fn from_png(png: &[u8], max_bytes: usize) -> ClipboardImageResult<Self> {
+        let invalid = |msg: &str| ClipboardImageError::InvalidPng(msg.to_string());
+
+        if !png.starts_with(&PNG_SIGNATURE) {
+            return Err(invalid("missing signature"));
+        }
+
+        let mut header = None;
+        let mut idat = Vec::new();
+        let mut pos = PNG_SIGNATURE.len();
+        loop {
+            let len = read_u32_be(png, pos).ok_or_else(|| invalid("truncated chunk"))? as usize;
+            let kind = png
+                .get(pos + 4..pos + 8)
+                .ok_or_else(|| invalid("truncated chunk"))?;
+            let data = png
+                .get(pos + 8..)
+                .and_then(|rest| rest.get(..len))
+                .ok_or_else(|| invalid("truncated chunk"))?;
+            match kind {
+                b"IHDR" => header = Some(PngHeader::parse(data)?),
+                b"IDAT" => {
+                    if idat.len() + data.len() > max_bytes {
+                        return Err(ClipboardImageError::TooLarge {
+                            size: idat.len() + data.len(),
+                            limit: max_bytes,
+                        });
+                    }
+                    idat.extend_from_slice(data);
+                }
+                b"IEND" => break,
+                _ => {}
+            }
+            pos += 12 + len;
+        }
+
+        let header = header.ok_or_else(|| invalid("missing IHDR"))?;
+        let size = checked_rgba_len(header.width, header.height, max_bytes)?;
+        let channels = header.channels;
+        let stride = header.width as usize * channels;
+        let expected = (stride + 1) * header.height as usize;
+
+        let mut raw = Vec::with_capacity(expected);
+        ZlibDecoder::new(idat.as_slice())
+            .take(expected as u64 + 1)
+            .read_to_end(&mut raw)
+            .map_err(|e| ClipboardImageError::InvalidPng(e.to_string()))?;
+        if raw.len() != expected {
+            return Err(invalid("image data size does not match header"));
+        }
+
+        let mut rgba = Vec::with_capacity(size);
+        let mut previous = vec![0u8; stride];
+        let mut current = vec![0u8; stride];
+        for row in raw.chunks_exact(stride + 1) {
+            current.copy_from_slice(&row[1..]);
+            unfilter_row(row[0], &mut current, &previous, channels)?;
+            for px in current.chunks_exact(channels) {
+                let pixel = match channels {
+                    1 => [px[0], px[0], px[0], 0xFF],
+                    2 => [px[0], px[0], px[0], px[1]],
+                    3 => [px[0], px[1], px[2], 0xFF],
+                    _ => [px[0], px[1], px[2], px[3]],
+                };
+                rgba.extend_from_slice(&pixel);
+            }
+            std::mem::swap(&mut previous, &mut current);
+        }
+
+        Ok(Self {
+            width: header.width,
+            height: header.height,
+            rgba,
+        })
+    }
+
+    /// Encodes the image as an 8-bit RGBA PNG
+    #[must_use]
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&self.width.to_be_bytes());
+        ihdr.extend_from_slice(&self.height.to_be_bytes());
+        // 8-bit depth, RGBA, deflate, adaptive filtering, no interlace
+        ihdr.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+        let row_len = self.width as usize * 4;
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::fast());
+        for row in self.rgba.chunks_exact(row_len.max(1)) {
+            // Writing into a Vec cannot fail
+            let _ = encoder.write_all(&[0]);
+            let _ = encoder.write_all(row);
+        }
+        let idat = encoder.finish().unwrap_or_default();
+
+        let mut png = Vec::with_capacity(PNG_SIGNATURE.len() + idat.len() + 64);
+        png.extend_from_slice(&PNG_SIGNATURE);
+        write_png_chunk(&mut png, b"IHDR", &ihdr);
+        write_png_chunk(&mut png, b"IDAT", &idat);
+        write_png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+/// Converts a `CF_DIB` payload to PNG
+///
+/// # Errors
+///
+/// Returns the errors of [`ClipboardImage::from_dib`].
+pub fn dib_to_png(dib: &[u8], max_bytes: usize) -> ClipboardImageResult<Vec<u8>> {
+    ClipboardImage::from_dib(dib, max_bytes).map(|image| image.to_png())
+}
+
+/// Converts a PNG image to a `CF_DIB` payload
+///
+/// # Errors
+///
+/// Returns the errors of [`ClipboardImage::from_png`].
+pub fn png_to_dib(png: &[u8], max_bytes: usize) -> ClipboardImageResult<Vec<u8>> {
+    ClipboardImage::from_png(png, max_bytes).map(|image| image.to_dib())
+}
+
+/// The parts of a PNG `IHDR` chunk needed for decoding
+struct PngHeader {
+    width: u32,
+    height: u32,
+    channels: usize,
+}
+
+impl PngHeader {
+    fn parse(data: &[u8]) -> ClipboardImageResult<Self> {
+        let invalid = || ClipboardImageError::InvalidPng("truncated IHDR".to_string());
+        let width = read_u32_be(data, 0).ok_or_else(invalid)?;
+        let height = read_u32_be(data, 4).ok_or_else(invalid)?;
+        let Some(&[bit_depth, color_type, _compression, _filter, interlace]) = data.get(8..13)
+        else {
+            return Err(invalid());
+        };
+
+        if width == 0 || height == 0 {
+            return Err(ClipboardImageError::InvalidPng("empty image".to_string()));
+        }
+        if bit_depth != 8 || interlace != 0 {
+            return Err(ClipboardImageError::Unsupported(format!(
+                "PNG bit depth {bit_depth}, interlace {interlace}"
+            )));
+        }
+        let channels = match color_type {
+            0 => 1,
+            2 => 3,
+            4 => 2,
+            6 => 4,
+            _ => {
+                return Err(ClipboardImageError::Unsupported(format!(
+                    "PNG color type {color_type}"
+                )));
+            }
+        };
+        Ok(Self {
+            width,
+            height,
+            channels,
+        })
+    }
+}
+
+/// Reverses PNG filtering for one row in place
+fn unfilter_row(
+    filter: u8,
+    row: &mut [u8],
+    previous: &[u8],
+    bpp: usize,
+) -> ClipboardImageResult<()> {
+    for i in 0..row.len() {
+        let left = if i >= bpp { row[i - bpp] } else { 0 };
+        let up = previous[i];
+        let up_left = if i >= bpp { previous[i - bpp] } else { 0 };
+        let predictor = match filter {
+            0 => 0,
+            1 => left,
+            2 => up,
+            3 => u8::try_from((u16::from(left) + u16::from(up)) / 2).unwrap_or(0),
+            4 => paeth(left, up, up_left),
+            _ => {
+                return Err(ClipboardImageError::InvalidPng(format!(
+                    "unknown filter type {filter}"
+                )));
+            }
+        };
+        row[i] = row[i].wrapping_add(predictor);
+    }
+    Ok(())
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = i16::from(a) + i16::from(b) - i16::from(c);
+    let pa = (p - i16::from(a)).abs();
+    let pb = (p - i16::from(b)).abs();
+    let pc = (p - i16::from(c)).abs();
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+fn write_png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&u32::try_from(data.len()).unwrap_or(0).to_be_bytes());
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let mut crc = flate2::Crc::new();
+    crc.update(kind);
+    crc.update(data);
+    out.extend_from_slice(&crc.sum().to_be_bytes());
+}
+
+fn rgba_len(width: u32, height: u32) -> Option<usize> {
+    (width as usize)
+        .checked_mul(height as usize)?
+        .checked_mul(4)
+}
+
+fn checked_rgba_len(width: u32, height: u32, max_bytes: usize) -> ClipboardImageResult<usize> {
+    let size = rgba_len(width, height).unwrap_or(usize::MAX);
+    if size > max_bytes {
+        return Err(ClipboardImageError::TooLarge {
+            size,
+            limit: max_bytes,
+        });
+    }
+    Ok(size)
+}
+
+fn read_u16_le(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32_le(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_i32_le(data: &[u8], offset: usize) -> Option<i32> {
+    Some(i32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32_be(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2x2 image: red, green / blue, semi-transparent white
+    fn sample() -> ClipboardImage {
+        ClipboardImage::new(
+            2,
+            2,
+            vec![
+                255, 0, 0, 255, 0, 255, 0, 255, //
+                0, 0, 255, 255, 255, 255, 255, 128,
+            ],
+        )
+        .unwrap()
+    }
+
+    /// Builds a 24 bpp bottom-up DIB with padded rows
+    fn dib24(width: i32, height: i32, rows_bottom_up: &[&[u8]]) -> Vec<u8> {
+        let mut dib = Vec::new();
+        dib.extend_from_slice(&40u32.to_le_bytes());
+        dib.extend_from_slice(&width.to_le_bytes());
+        dib.extend_from_slice(&height.to_le_bytes());
+        dib.extend_from_slice(&1u16.to_le_bytes());
+        dib.extend_from_slice(&24u16.to_le_bytes());
+        dib.extend_from_slice(&[0; 24]);
+        for row in rows_bottom_up {
+            dib.extend_from_slice(row);
+        }
+        dib
+    }
+
+    #[test]
+    fn test_dib_png_round_trip() {
+        let image = sample();
+
+        let png = image.to_png();
+        assert!(png.starts_with(&PNG_SIGNATURE));
+        let dib = png_to_dib(&png, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES).unwrap();
+        assert_eq!(dib.len(), 40 + 16);
+        assert_eq!(
+            ClipboardImage::from_dib(&dib, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES).unwrap(),
+            image
+        );
+
+        let png = dib_to_png(&image.to_dib(), DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES).unwrap();
+        assert_eq!(
+            ClipboardImage::from_png(&png, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES).unwrap(),
+            image
+        );
+    }
+
+    #[test]
+    fn test_dib_24bpp_bottom_up_with_padding() {
+        // 1x2: bottom row blue, top row red (BGR order), each padded to 4 bytes
+        let dib = dib24(1, 2, &[&[255, 0, 0, 0], &[0, 0, 255, 0]]);
+        let image = ClipboardImage::from_dib(&dib, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES).unwrap();
+        assert_eq!((image.width, image.height), (1, 2));
+        assert_eq!(image.rgba, vec![255, 0, 0, 255, 0, 0, 255, 255]);
+
+        let top_down = dib24(1, -2, &[&[255, 0, 0, 0], &[0, 0, 255, 0]]);
+        let image = ClipboardImage::from_dib(&top_down, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES).unwrap();
+        assert_eq!(image.rgba, vec![0, 0, 255, 255, 255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_dib_zero_alpha_is_opaque() {
+        let mut dib = ClipboardImage::new(1, 1, vec![10, 20, 30, 255])
+            .unwrap()
+            .to_dib();
+        dib[43] = 0;
+        let image = ClipboardImage::from_dib(&dib, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES).unwrap();
+        assert_eq!(image.rgba, vec![10, 20, 30, 255]);
+    }
+
+    #[test]
+    fn test_png_filters_and_color_types() {
+        // 2x2 RGB with Sub and Up filters: rows [1,2,3 | 4,6,8] and [2,3,4 | 5,7,9]
+        let raw = [1, 1, 2, 3, 3, 4, 5, 2, 1, 1, 1, 1, 1, 1];
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&raw).unwrap();
+        let idat = encoder.finish().unwrap();
+
+        let mut png = PNG_SIGNATURE.to_vec();
+        let mut ihdr = Vec::new();
+        ihdr.extend_from_slice(&2u32.to_be_bytes());
+        ihdr.extend_from_slice(&2u32.to_be_bytes());
+        ihdr.extend_from_slice(&[8, 2, 0, 0, 0]);
+        write_png_chunk(&mut png, b"IHDR", &ihdr);
+        write_png_chunk(&mut png, b"IDAT", &idat);
+        write_png_chunk(&mut png, b"IEND", &[]);
+
+        let image = ClipboardImage::from_png(&png, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES).unwrap();
+        assert_eq!(
+            image.rgba,
+            vec![
+                1, 2, 3, 255, 4, 6, 8, 255, //
+                2, 3, 4, 255, 5, 7, 9, 255,
+            ]
+        );
+        assert_eq!(paeth(10, 20, 15), 15);
+    }
+
+    #[test]
+    fn test_size_cap() {
+        let image = sample();
+        assert_eq!(
+            dib_to_png(&image.to_dib(), 15),
+            Err(ClipboardImageError::TooLarge {
+                size: 16,
+                limit: 15
+            })
+        );
+        assert!(matches!(
+            png_to_dib(&image.to_png(), 15),
+            Err(ClipboardImageError::TooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        assert!(matches!(
+            dib_to_png(&[0; 10], DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES),
+            Err(ClipboardImageError::InvalidDib(_))
+        ));
+        let mut truncated = sample().to_dib();
+        truncated.truncate(48);
+        assert!(matches!(
+            dib_to_png(&truncated, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES),
+            Err(ClipboardImageError::InvalidDib(_))
+        ));
+        let mut paletted = dib24(1, 1, &[&[0; 4]]);
+        paletted[14] = 8;
+        assert!(matches!(
+            dib_to_png(&paletted, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES),
+            Err(ClipboardImageError::Unsupported(_))
+        ));
+        assert!(matches!(
+            png_to_dib(b"not a png", DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES),
+            Err(ClipboardImageError::InvalidPng(_))
+        ));
+    }
+}
@@ -6,16 +6,18 @@
     reason = "module-wide override for legacy code; refactored case by case"
 )]
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 
+use super::clipboard_image::DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES;
+use super::error::RdpClientError;
 use super::gateway::GatewayConfig;
 use super::graphics::{GraphicsMode, GraphicsQuality};
-use super::multimonitor::MonitorLayout;
+use super::multimonitor::{MonitorDefinition, MonitorLayout, MonitorLayoutError};
 use super::reconnect::ReconnectPolicy;
-use crate::models::RdpPerformanceMode;
+use crate::models::{ProxyConfig, RdpPerformanceMode, RdpSecurityLayer};
 
 /// Shared folder configuration for RDP drive redirection
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -45,6 +47,51 @@ impl SharedFolder {
         self.read_only = read_only;
         self
     }
+
+    /// Checks that the folder can be redirected
+    ///
+    /// # Errors
+    ///
+    /// See [`validate_redirected_drive`].
+    pub fn validate(&self) -> Result<(), RdpClientError> {
+        validate_redirected_drive(&self.name, &self.path)
+    }
+}
+
+/// Checks a redirected drive before it is offered to the server
+///
+/// Shared by the embedded client and the `FreeRDP` `/drive:label,path`
+/// option, which cannot carry a label containing `,` or `/`.
+///
+/// # Errors
+///
+/// Returns [`RdpClientError::SharedFolderError`] if the label is empty or
+/// contains `,` or `/`, or if the path is not an existing directory.
+pub fn validate_redirected_drive(label: &str, path: &Path) -> Result<(), RdpClientError> {
+    if label.trim().is_empty() {
+        return Err(RdpClientError::SharedFolderError(format!(
+            "Drive for {} has no label",
+            path.display()
+        )));
+    }
+    if label.contains([',', '/']) {
+        return Err(RdpClientError::SharedFolderError(format!(
+            "Drive label '{label}' cannot contain ',' or '/'"
+        )));
+    }
+    if !path.exists() {
+        return Err(RdpClientError::SharedFolderError(format!(
+            "Drive '{label}': {} does not exist",
+            path.display()
+        )));
+    }
+    if !path.is_dir() {
+        return Err(RdpClientError::SharedFolderError(format!(
+            "Drive '{label}': {} is not a directory",
+            path.display()
+        )));
+    }
+    Ok(())
 }
 
 /// Configuration for RDP client connection
@@ -78,6 +125,11 @@ pub struct RdpClientConfig {
     /// Enable clipboard sharing
     pub clipboard_enabled: bool,
 
+    /// Largest clipboard image accepted in either direction, in bytes of
+    /// decoded RGBA pixels
+    #[serde(default = "default_max_clipboard_image_bytes")]
+    pub max_clipboard_image_bytes: usize,
+
     /// Enable audio redirection
     pub audio_enabled: bool,
 
@@ -166,6 +218,15 @@ pub struct RdpClientConfig {
     /// Requires kernel MPTCP support (Linux 5.6+). Falls back to regular TCP.
     #[serde(default)]
     pub mptcp: bool,
+
+    /// SOCKS/HTTP proxy the TCP connection is routed through.
+    /// Ignored when an RD Gateway is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+
+    /// Password for proxy authentication (resolved from the secret backend)
+    #[serde(skip)]
+    pub proxy_password: Option<SecretString>,
 }
 
 const fn default_true() -> bool {
@@ -176,6 +237,10 @@ const fn default_scale_factor() -> u32 {
     100
 }
 
+const fn default_max_clipboard_image_bytes() -> usize {
+    DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES
+}
+
 /// RemoteApp configuration for running individual applications
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RemoteAppConfig {
@@ -232,6 +297,18 @@ pub enum RdpSecurityProtocol {
     Ext,
 }
 
+impl From<RdpSecurityLayer> for RdpSecurityProtocol {
+    /// Maps the per-connection security layer to the embedded client's protocol
+    fn from(layer: RdpSecurityLayer) -> Self {
+        match layer {
+            RdpSecurityLayer::Negotiate => Self::Auto,
+            RdpSecurityLayer::Rdp => Self::Rdp,
+            RdpSecurityLayer::Tls => Self::Tls,
+            RdpSecurityLayer::Nla => Self::Nla,
+        }
+    }
+}
+
 impl Default for RdpClientConfig {
     fn default() -> Self {
         Self {
@@ -244,6 +321,7 @@ impl Default for RdpClientConfig {
             height: 720,
             color_depth: 32,
             clipboard_enabled: true,
+            max_clipboard_image_bytes: DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES,
             audio_enabled: false,
             timeout_secs: 30,
             ignore_certificate: false,
@@ -267,6 +345,8 @@ impl Default for RdpClientConfig {
             connection_name: None,
             keyboard_layout: None,
             mptcp: false,
+            proxy: None,
+            proxy_password: None,
         }
     }
 }
@@ -331,6 +411,13 @@ impl RdpClientConfig {
         self
     }
 
+    /// Sets the clipboard image size cap in bytes of decoded RGBA pixels
+    #[must_use]
+    pub const fn with_max_clipboard_image_bytes(mut self, bytes: usize) -> Self {
+        self.max_clipboard_image_bytes = bytes;
+        self
+    }
+
     /// Enables or disables NLA
     #[must_use]
     pub const fn with_nla(mut self, enabled: bool) -> Self {
@@ -338,6 +425,29 @@ impl RdpClientConfig {
         self
     }
 
+    /// Sets the security protocol
+    #[must_use]
+    pub const fn with_security_protocol(mut self, protocol: RdpSecurityProtocol) -> Self {
+        self.security_protocol = protocol;
+        self
+    }
+
+    /// Returns whether the connector enables TLS and `CredSSP`, in that order
+    ///
+    /// `Auto`, `Nla` and `Ext` use TLS with `CredSSP` unless NLA is disabled
+    /// (e.g. because the credentials are incomplete); `Tls` never uses
+    /// `CredSSP` and `Rdp` uses neither.
+    #[must_use]
+    pub const fn security_flags(&self) -> (bool, bool) {
+        match self.security_protocol {
+            RdpSecurityProtocol::Auto | RdpSecurityProtocol::Nla | RdpSecurityProtocol::Ext => {
+                (true, self.nla_enabled)
+            }
+            RdpSecurityProtocol::Tls => (true, false),
+            RdpSecurityProtocol::Rdp => (false, false),
+        }
+    }
+
     /// Adds a shared folder for drive redirection
     #[must_use]
     pub fn with_shared_folder(mut self, folder: SharedFolder) -> Self {
@@ -394,6 +504,14 @@ impl RdpClientConfig {
         self
     }
 
+    /// Routes the connection through a proxy
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: ProxyConfig, password: Option<SecretString>) -> Self {
+        self.proxy = Some(proxy);
+        self.proxy_password = password;
+        self
+    }
+
     /// Sets the monitor layout
     #[must_use]
     pub fn with_monitor_layout(mut self, layout: MonitorLayout) -> Self {
@@ -401,6 +519,18 @@ impl RdpClientConfig {
         self
     }
 
+    /// Uses every given monitor, sizing the desktop to cover them all
+    #[must_use]
+    pub fn with_all_monitors(mut self, monitors: Vec<MonitorDefinition>) -> Self {
+        self.monitor_layout = MonitorLayout::all(monitors);
+        let (width, height) = self.monitor_layout.virtual_desktop_size();
+        if width > 0 && height > 0 {
+            self.width = u16::try_from(width).unwrap_or(u16::MAX);
+            self.height = u16::try_from(height).unwrap_or(u16::MAX);
+        }
+        self
+    }
+
     /// Sets the reconnection policy
     #[must_use]
     pub const fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
@@ -485,6 +615,18 @@ impl RdpClientConfig {
         self.monitor_layout.is_multimonitor()
     }
 
+    /// Checks every redirected drive
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`RdpClientError::SharedFolderError`] from
+    /// [`SharedFolder::validate`].
+    pub fn validate_shared_folders(&self) -> Result<(), RdpClientError> {
+        self.shared_folders
+            .iter()
+            .try_for_each(SharedFolder::validate)
+    }
+
     /// Validates the configuration
     ///
     /// # Errors
@@ -511,6 +653,10 @@ impl RdpClientConfig {
             .validate()
             .map_err(|e| ConfigValidationError::GatewayError(e.to_string()))?;
 
+        if !self.monitor_layout.monitors.is_empty() {
+            self.monitor_layout.validate()?;
+        }
+
         Ok(())
     }
 }
@@ -531,6 +677,7 @@ impl PartialEq for RdpClientConfig {
             && self.height == other.height
             && self.color_depth == other.color_depth
             && self.clipboard_enabled == other.clipboard_enabled
+            && self.max_clipboard_image_bytes == other.max_clipboard_image_bytes
             && self.audio_enabled == other.audio_enabled
             && self.timeout_secs == other.timeout_secs
             && self.ignore_certificate == other.ignore_certificate
@@ -552,6 +699,7 @@ impl PartialEq for RdpClientConfig {
             && self.remote_app == other.remote_app
             && self.connection_name == other.connection_name
             && self.keyboard_layout == other.keyboard_layout
+            && self.proxy == other.proxy
     }
 }
 
@@ -577,6 +725,10 @@ pub enum ConfigValidationError {
     /// Gateway configuration error
     #[error("Gateway configuration error: {0}")]
     GatewayError(String),
+
+    /// Invalid multi-monitor layout
+    #[error("Invalid monitor layout: {0}")]
+    MonitorLayout(#[from] MonitorLayoutError),
 }
 
 #[cfg(test)]
@@ -609,6 +761,37 @@ mod tests {
         assert_eq!(config.color_depth, 24);
     }
 
+    #[test]
+    fn test_security_layer_maps_to_connector_flags() {
+        let flags = |layer: RdpSecurityLayer| {
+            let config = RdpClientConfig::new("host")
+                .with_security_protocol(RdpSecurityProtocol::from(layer));
+            (config.security_protocol, config.security_flags())
+        };
+        assert_eq!(
+            flags(RdpSecurityLayer::Negotiate),
+            (RdpSecurityProtocol::Auto, (true, true))
+        );
+        assert_eq!(
+            flags(RdpSecurityLayer::Nla),
+            (RdpSecurityProtocol::Nla, (true, true))
+        );
+        assert_eq!(
+            flags(RdpSecurityLayer::Tls),
+            (RdpSecurityProtocol::Tls, (true, false))
+        );
+        assert_eq!(
+            flags(RdpSecurityLayer::Rdp),
+            (RdpSecurityProtocol::Rdp, (false, false))
+        );
+
+        // Incomplete credentials turn CredSSP off even when NLA is selected
+        let config = RdpClientConfig::new("host")
+            .with_security_protocol(RdpSecurityProtocol::Nla)
+            .with_nla(false);
+        assert_eq!(config.security_flags(), (true, false));
+    }
+
     #[test]
     fn test_server_address() {
         let config = RdpClientConfig::new("localhost").with_port(3389);
@@ -621,6 +804,59 @@ mod tests {
         assert_eq!(config.port, 3389);
         assert_eq!(config.color_depth, 32);
         assert!(config.clipboard_enabled);
+        assert_eq!(
+            config.max_clipboard_image_bytes,
+            DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES
+        );
         assert!(config.nla_enabled);
     }
+
+    #[test]
+    fn test_with_all_monitors() {
+        let config = RdpClientConfig::new("host").with_all_monitors(vec![
+            MonitorDefinition::new(0, 0, 0, 2560, 1440).with_primary(true),
+            MonitorDefinition::new(1, 2560, 0, 4480, 1080),
+        ]);
+        assert!(config.is_multimonitor());
+        assert!(config.monitor_layout.use_all_monitors);
+        assert_eq!((config.width, config.height), (4480, 1440));
+        assert!(config.validate().is_ok());
+
+        let too_wide = RdpClientConfig::new("host").with_all_monitors(vec![
+            MonitorDefinition::new(0, 0, 0, 3840, 2160).with_primary(true),
+            MonitorDefinition::new(1, 3840, 0, 7680, 2160),
+            MonitorDefinition::new(2, 7680, 0, 11520, 2160),
+        ]);
+        assert!(matches!(
+            too_wide.validate(),
+            Err(ConfigValidationError::MonitorLayout(
+                MonitorLayoutError::DesktopTooLarge { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_validate_shared_folders() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("notes.txt");
+        std::fs::write(&file, "x").unwrap();
+
+        let config = RdpClientConfig::new("host")
+            .with_shared_folder(SharedFolder::new("Home", dir.path()))
+            .with_shared_folder(SharedFolder::new("Temp", std::env::temp_dir()));
+        assert!(config.validate_shared_folders().is_ok());
+
+        for folder in [
+            SharedFolder::new("Missing", dir.path().join("missing")),
+            SharedFolder::new("File", &file),
+            SharedFolder::new("", dir.path()),
+            SharedFolder::new("a,b", dir.path()),
+        ] {
+            let config = RdpClientConfig::new("host").with_shared_folder(folder);
+            assert!(matches!(
+                config.validate_shared_folders(),
+                Err(RdpClientError::SharedFolderError(_))
+            ));
+        }
+    }
 }
@@ -169,6 +169,10 @@ impl ClipboardFormatInfo {
     pub const HTML: u32 = 0xC0A0;
     /// File list format (`CF_HDROP`)
     pub const FILE_LIST: u32 = 15;
+    /// Device-independent bitmap (`CF_DIB`)
+    pub const DIB: u32 = 8;
+    /// Registered name of the PNG clipboard format
+    pub const PNG_NAME: &'static str = "PNG";
 
     /// Creates a new clipboard format info
     #[must_use]
@@ -185,11 +189,26 @@ impl ClipboardFormatInfo {
         }
     }
 
+    /// Creates a device-independent bitmap format
+    #[must_use]
+    pub const fn dib() -> Self {
+        Self {
+            id: Self::DIB,
+            name: None,
+        }
+    }
+
     /// Returns true if this is a text format
     #[must_use]
     pub const fn is_text(&self) -> bool {
         matches!(self.id, Self::TEXT | Self::UNICODE_TEXT)
     }
+
+    /// Returns true if this is an image format (`CF_DIB` or registered PNG)
+    #[must_use]
+    pub fn is_image(&self) -> bool {
+        self.id == Self::DIB || self.name.as_deref() == Some(Self::PNG_NAME)
+    }
 }
 
 /// Rectangle coordinates for RDP operations
@@ -516,6 +535,9 @@ pub enum RdpClientEvent {
     /// Server clipboard text
     ClipboardText(String),
 
+    /// Server clipboard image, converted to PNG
+    ClipboardImage(Vec<u8>),
+
     /// Server clipboard data available (formats list)
     ClipboardFormatsAvailable(Vec<ClipboardFormatInfo>),
 
@@ -729,6 +751,9 @@ pub enum RdpClientCommand {
     /// Send clipboard text to server
     ClipboardText(String),
 
+    /// Send a PNG clipboard image to server (announced as `CF_DIB`)
+    ClipboardImage(Vec<u8>),
+
     /// Send clipboard data to server (response to `ClipboardDataRequest`)
     ClipboardData {
         /// Format ID
@@ -899,6 +924,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_clipboard_image_formats() {
+        assert!(ClipboardFormatInfo::dib().is_image());
+        assert!(ClipboardFormatInfo::new(0xC123, Some("PNG".to_string())).is_image());
+        assert!(!ClipboardFormatInfo::unicode_text().is_image());
+        assert!(!ClipboardFormatInfo::dib().is_text());
+    }
+
     #[test]
     fn test_pixel_format_bytes_per_pixel() {
         assert_eq!(PixelFormat::Bgra.bytes_per_pixel(), 4);
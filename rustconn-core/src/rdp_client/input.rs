@@ -352,6 +352,44 @@ pub fn find_best_standard_resolution(width: u32, height: u32) -> (u32, u32) {
     best
 }
 
+/// Returns true if a desktop of this size is within the RDP limits
+#[must_use]
+pub const fn fits_rdp_limits(width: u32, height: u32) -> bool {
+    width >= MIN_RDP_WIDTH as u32
+        && width <= MAX_RDP_WIDTH as u32
+        && height >= MIN_RDP_HEIGHT as u32
+        && height <= MAX_RDP_HEIGHT as u32
+}
+
+/// Finds the best standard resolution for each monitor of a side-by-side
+/// multi-monitor desktop
+///
+/// Each monitor is snapped with [`find_best_standard_resolution`]. Returns
+/// `None` if the combined desktop (widths summed, tallest height) exceeds
+/// [`MAX_RDP_WIDTH`] x [`MAX_RDP_HEIGHT`].
+///
+/// # Example
+///
+/// ```
+/// use rustconn_core::rdp_client::input::find_best_standard_resolutions;
+///
+/// let sizes = find_best_standard_resolutions(&[(1920, 1080), (1900, 1200)]).unwrap();
+/// assert_eq!(sizes, vec![(1920, 1080), (1680, 1050)]);
+///
+/// // Three 4K monitors side by side are wider than 8192 pixels
+/// assert!(find_best_standard_resolutions(&[(3840, 2160); 3]).is_none());
+/// ```
+#[must_use]
+pub fn find_best_standard_resolutions(monitors: &[(u32, u32)]) -> Option<Vec<(u32, u32)>> {
+    let sizes: Vec<(u32, u32)> = monitors
+        .iter()
+        .map(|&(w, h)| find_best_standard_resolution(w, h))
+        .collect();
+    let total_width: u32 = sizes.iter().map(|&(w, _)| w).sum();
+    let max_height = sizes.iter().map(|&(_, h)| h).max().unwrap_or(0);
+    fits_rdp_limits(total_width, max_height).then_some(sizes)
+}
+
 /// Generates a resize request for the RDP server
 ///
 /// This function takes the new widget dimensions and generates appropriate
@@ -487,6 +525,21 @@ mod tests {
         assert_eq!(find_best_standard_resolution(100, 100), (640, 480));
     }
 
+    #[test]
+    fn test_find_best_standard_resolutions_checks_total() {
+        assert_eq!(
+            find_best_standard_resolutions(&[(2560, 1440), (1920, 1080)]),
+            Some(vec![(2560, 1440), (1920, 1080)])
+        );
+        // 2 x 3840 = 7680 still fits
+        assert!(find_best_standard_resolutions(&[(3840, 2160); 2]).is_some());
+        // 3 x 3840 = 11520 is wider than MAX_RDP_WIDTH
+        assert!(find_best_standard_resolutions(&[(3840, 2160); 3]).is_none());
+        assert!(!fits_rdp_limits(8193, 1080));
+        assert!(!fits_rdp_limits(1920, 100));
+        assert!(fits_rdp_limits(8192, 8192));
+    }
+
     #[test]
     fn test_generate_resize_request() {
         // Standard resolution
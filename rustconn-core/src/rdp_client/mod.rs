@@ -53,6 +53,7 @@ pub mod backend;
 mod client;
 #[cfg(feature = "rdp-embedded")]
 pub mod clipboard;
+pub mod clipboard_image;
 mod config;
 #[cfg(feature = "rdp-embedded")]
 pub mod dir_watcher;
@@ -74,8 +75,12 @@ pub mod quick_actions;
 pub use backend::{BackendDetectionResult, RdpBackend, RdpBackendSelector};
 #[cfg(feature = "rdp-embedded")]
 pub use client::{RdpClient, RdpClientState, RdpCommandSender, RdpEventReceiver};
+pub use clipboard_image::{
+    ClipboardImage, ClipboardImageError, DEFAULT_MAX_CLIPBOARD_IMAGE_BYTES, dib_to_png, png_to_dib,
+};
 pub use config::{
     ConfigValidationError, RdpClientConfig, RdpSecurityProtocol, RemoteAppConfig, SharedFolder,
+    validate_redirected_drive,
 };
 pub use error::RdpClientError;
 pub use event::{
@@ -88,7 +93,7 @@ pub use graphics::{
     FrameStatistics, GraphicsError, GraphicsMode, GraphicsQuality, ServerGraphicsCapabilities,
 };
 pub use keyboard_layout::{LAYOUT_US_ENGLISH, detect_keyboard_layout, xkb_name_to_klid};
-pub use multimonitor::{MonitorArrangement, MonitorDefinition, MonitorLayout};
+pub use multimonitor::{MonitorArrangement, MonitorDefinition, MonitorLayout, MonitorLayoutError};
 pub use quick_actions::{
     QUICK_ACTIONS, QuickAction, build_enter_sequence, build_hotkey_sequence, build_open_run_dialog,
     run_command_for,
@@ -1,8 +1,9 @@
 //! Multi-monitor support for RDP sessions
 //!
 //! This module provides structures and utilities for multi-monitor RDP sessions.
-//! While IronRDP doesn't fully support multi-monitor yet, this prepares the
-//! infrastructure for when it becomes available.
+//! The embedded client sends the active monitors over the Display Control
+//! channel (MS-RDPEDISP) once it opens; the external client maps the layout
+//! to `FreeRDP`'s `/multimon`, `/monitors:` and `/span` options.
 //!
 //! # RDP Multi-Monitor Protocol
 //!
@@ -18,6 +19,44 @@
 )]
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::input::{MAX_RDP_HEIGHT, MAX_RDP_WIDTH, fits_rdp_limits};
+
+/// Errors from validating a [`MonitorLayout`]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MonitorLayoutError {
+    /// No monitor is selected for the session
+    #[error("No monitors selected")]
+    NoActiveMonitors,
+
+    /// More than one active monitor is marked primary
+    #[error("Only one monitor can be primary")]
+    MultiplePrimary,
+
+    /// A monitor is smaller or larger than RDP allows
+    #[error(
+        "Monitor {index} is {width}x{height}; each monitor must be between \
+         200x200 and 8192x8192"
+    )]
+    InvalidMonitorSize {
+        /// Monitor index
+        index: u32,
+        /// Monitor width in pixels
+        width: u32,
+        /// Monitor height in pixels
+        height: u32,
+    },
+
+    /// The combined desktop exceeds the RDP maximum
+    #[error("Combined desktop is {width}x{height}; RDP allows at most 8192x8192")]
+    DesktopTooLarge {
+        /// Virtual desktop width in pixels
+        width: u32,
+        /// Virtual desktop height in pixels
+        height: u32,
+    },
+}
 
 /// Monitor definition for RDP multi-monitor support
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -159,6 +198,18 @@ impl MonitorLayout {
         }
     }
 
+    /// Creates a layout that uses every given monitor
+    #[must_use]
+    pub fn all(monitors: Vec<MonitorDefinition>) -> Self {
+        let selected_monitors = monitors.iter().map(|m| m.index).collect();
+        Self {
+            monitors,
+            use_all_monitors: true,
+            selected_monitors,
+            span_monitors: false,
+        }
+    }
+
     /// Adds a monitor to the layout
     pub fn add_monitor(&mut self, monitor: MonitorDefinition) {
         self.monitors.push(monitor);
@@ -218,6 +269,100 @@ impl MonitorLayout {
     }
 }
 
+impl MonitorLayout {
+    /// Checks the active monitors against the RDP limits
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MonitorLayoutError`] if no monitor is active, several are
+    /// primary, a monitor has an invalid size, or the combined desktop is
+    /// larger than [`MAX_RDP_WIDTH`] x [`MAX_RDP_HEIGHT`].
+    pub fn validate(&self) -> Result<(), MonitorLayoutError> {
+        let active = self.active_monitors();
+        if active.is_empty() {
+            return Err(MonitorLayoutError::NoActiveMonitors);
+        }
+        if active.iter().filter(|m| m.is_primary).count() > 1 {
+            return Err(MonitorLayoutError::MultiplePrimary);
+        }
+
+        for monitor in &active {
+            let valid = monitor.right > monitor.left
+                && monitor.bottom > monitor.top
+                && fits_rdp_limits(monitor.width(), monitor.height());
+            if !valid {
+                return Err(MonitorLayoutError::InvalidMonitorSize {
+                    index: monitor.index,
+                    width: monitor.right.saturating_sub(monitor.left).max(0) as u32,
+                    height: monitor.bottom.saturating_sub(monitor.top).max(0) as u32,
+                });
+            }
+        }
+
+        let left = active.iter().map(|m| m.left).min().unwrap_or(0);
+        let top = active.iter().map(|m| m.top).min().unwrap_or(0);
+        let right = active.iter().map(|m| m.right).max().unwrap_or(0);
+        let bottom = active.iter().map(|m| m.bottom).max().unwrap_or(0);
+        let width = right.abs_diff(left);
+        let height = bottom.abs_diff(top);
+        if width > u32::from(MAX_RDP_WIDTH) || height > u32::from(MAX_RDP_HEIGHT) {
+            return Err(MonitorLayoutError::DesktopTooLarge { width, height });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the active monitors as sent to the server
+    ///
+    /// MS-RDPEDISP requires exactly one primary monitor with its top-left
+    /// corner at (0, 0), so positions are shifted relative to the primary
+    /// (the first active monitor if none is marked) and it is listed first.
+    #[must_use]
+    pub fn negotiation_monitors(&self) -> Vec<MonitorDefinition> {
+        let mut monitors: Vec<MonitorDefinition> =
+            self.active_monitors().into_iter().cloned().collect();
+        let primary = monitors.iter().position(|m| m.is_primary).unwrap_or(0);
+        let Some((dx, dy)) = monitors.get(primary).map(|m| (m.left, m.top)) else {
+            return monitors;
+        };
+
+        for (i, monitor) in monitors.iter_mut().enumerate() {
+            monitor.left -= dx;
+            monitor.right -= dx;
+            monitor.top -= dy;
+            monitor.bottom -= dy;
+            monitor.is_primary = i == primary;
+        }
+        monitors.swap(0, primary);
+        monitors
+    }
+
+    /// Returns the `FreeRDP` arguments for this layout
+    ///
+    /// Empty for a single monitor. Spanning gives `/span`; otherwise
+    /// `/multimon`, plus `/monitors:<ids>` when only some are selected.
+    #[must_use]
+    pub fn freerdp_args(&self) -> Vec<String> {
+        if !self.is_multimonitor() {
+            return Vec::new();
+        }
+        if self.span_monitors {
+            return vec!["/span".to_string()];
+        }
+
+        let mut args = vec!["/multimon".to_string()];
+        if !self.use_all_monitors {
+            let ids: Vec<String> = self
+                .active_monitors()
+                .iter()
+                .map(|m| m.index.to_string())
+                .collect();
+            args.push(format!("/monitors:{}", ids.join(",")));
+        }
+        args
+    }
+}
+
 /// Monitor arrangement for multi-monitor sessions
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum MonitorArrangement {
@@ -311,4 +456,101 @@ mod tests {
         assert_eq!(active.len(), 1);
         assert!(active[0].is_primary);
     }
+
+    fn dual_layout() -> MonitorLayout {
+        MonitorLayout::all(vec![
+            MonitorDefinition::new(0, -2560, 0, 0, 1440),
+            MonitorDefinition::new(1, 0, 0, 1920, 1080).with_primary(true),
+        ])
+    }
+
+    #[test]
+    fn test_validate_layout() {
+        assert_eq!(dual_layout().validate(), Ok(()));
+        assert_eq!(
+            MonitorLayout::new().validate(),
+            Err(MonitorLayoutError::NoActiveMonitors)
+        );
+
+        let mut two_primary = dual_layout();
+        two_primary.monitors[0].is_primary = true;
+        assert_eq!(
+            two_primary.validate(),
+            Err(MonitorLayoutError::MultiplePrimary)
+        );
+
+        let tiny = MonitorLayout::all(vec![MonitorDefinition::new(3, 0, 0, 100, 1080)]);
+        assert_eq!(
+            tiny.validate(),
+            Err(MonitorLayoutError::InvalidMonitorSize {
+                index: 3,
+                width: 100,
+                height: 1080
+            })
+        );
+
+        let wall = MonitorLayout::all(vec![
+            MonitorDefinition::new(0, 0, 0, 3840, 2160).with_primary(true),
+            MonitorDefinition::new(1, 3840, 0, 7680, 2160),
+            MonitorDefinition::new(2, 7680, 0, 11520, 2160),
+        ]);
+        assert_eq!(
+            wall.validate(),
+            Err(MonitorLayoutError::DesktopTooLarge {
+                width: 11520,
+                height: 2160
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_ignores_unselected_monitors() {
+        let mut layout = dual_layout();
+        layout.add_monitor(MonitorDefinition::new(2, 1920, 0, 9000, 1080));
+        layout.use_all_monitors = false;
+        layout.selected_monitors = vec![0, 1];
+        assert_eq!(layout.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_negotiation_monitors_put_primary_at_origin() {
+        let monitors = dual_layout().negotiation_monitors();
+        assert_eq!(monitors.len(), 2);
+
+        assert!(monitors[0].is_primary);
+        assert_eq!(monitors[0].index, 1);
+        assert_eq!((monitors[0].left, monitors[0].top), (0, 0));
+
+        assert!(!monitors[1].is_primary);
+        assert_eq!((monitors[1].left, monitors[1].right), (-2560, 0));
+        assert_eq!(monitors[1].width(), 2560);
+    }
+
+    #[test]
+    fn test_negotiation_monitors_without_primary() {
+        let layout = MonitorLayout::all(vec![
+            MonitorDefinition::new(0, 100, 50, 2020, 1130),
+            MonitorDefinition::new(1, 2020, 50, 3940, 1130),
+        ]);
+        let monitors = layout.negotiation_monitors();
+        assert!(monitors[0].is_primary);
+        assert_eq!((monitors[0].left, monitors[0].top), (0, 0));
+        assert_eq!((monitors[1].left, monitors[1].top), (1920, 0));
+    }
+
+    #[test]
+    fn test_freerdp_args() {
+        assert!(MonitorLayout::single(1920, 1080).freerdp_args().is_empty());
+        assert_eq!(dual_layout().freerdp_args(), ["/multimon"]);
+
+        let mut selected = dual_layout();
+        selected.add_monitor(MonitorDefinition::new(2, 1920, 0, 3840, 1080));
+        selected.use_all_monitors = false;
+        selected.selected_monitors = vec![1, 2];
+        assert_eq!(selected.freerdp_args(), ["/multimon", "/monitors:1,2"]);
+
+        let mut span = dual_layout();
+        span.span_monitors = true;
+        assert_eq!(span.freerdp_args(), ["/span"]);
+    }
 }
@@ -0,0 +1,198 @@
+//! Search-as-you-type on top of [`DebouncedSearchEngine`]
+//!
+//! [`IncrementalSearch`] takes every edit of the query text but searches
+//! only once the input has been quiet for the engine's debounce delay, so a
+//! burst of edits collapses into a single search for the last text. The
+//! caller owns the clock: it forwards edits with [`IncrementalSearch::input`]
+//! and calls [`IncrementalSearch::poll`] whenever it has waited for
+//! [`IncrementalSearch::delay`] without new input.
+
+use std::time::{Duration, Instant};
+
+use super::{
+    ConnectionSearchResult, DebouncedSearchEngine, SearchEngine, SearchMode, SearchResult,
+};
+use crate::models::{Connection, ConnectionGroup};
+
+/// Debounced search over a stream of query edits
+pub struct IncrementalSearch {
+    /// Engine running the searches and rate-limiting them
+    engine: DebouncedSearchEngine,
+    /// How query text is matched
+    mode: SearchMode,
+    /// Latest text not searched yet
+    pending: Option<String>,
+    /// When the latest edit arrived
+    last_input: Option<Instant>,
+    /// Number of searches run
+    searches: usize,
+}
+
+impl IncrementalSearch {
+    /// Creates an incremental search with the default search delay (100ms)
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_engine(DebouncedSearchEngine::for_search())
+    }
+
+    /// Creates an incremental search running on `engine`
+    #[must_use]
+    pub const fn with_engine(engine: DebouncedSearchEngine) -> Self {
+        Self {
+            engine,
+            mode: SearchMode::Fuzzy,
+            pending: None,
+            last_input: None,
+            searches: 0,
+        }
+    }
+
+    /// Sets how query text is matched
+    #[must_use]
+    pub const fn with_mode(mut self, mode: SearchMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Records an edit of the query text, replacing any unsearched text
+    pub fn input(&mut self, text: &str) {
+        self.pending = Some(text.trim().to_string());
+        self.last_input = Some(Instant::now());
+    }
+
+    /// Searches for the latest text once the input has been quiet for
+    /// [`Self::delay`]
+    ///
+    /// Returns `None` while the input is still settling or nothing is
+    /// pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`SearchEngine::parse_query`] and
+    /// [`SearchEngine::search`]; the failed text is dropped.
+    pub fn poll(
+        &mut self,
+        connections: &[&Connection],
+        groups: &[ConnectionGroup],
+    ) -> SearchResult<Option<Vec<ConnectionSearchResult>>> {
+        if self
+            .last_input
+            .is_some_and(|at| at.elapsed() < self.engine.delay())
+        {
+            return Ok(None);
+        }
+        let Some(text) = self.pending.take() else {
+            return Ok(None);
+        };
+        let query = SearchEngine::parse_query(&text)?.with_mode(self.mode);
+        let results = self.engine.search_debounced(&query, connections, groups)?;
+        if results.is_some() {
+            self.searches += 1;
+        } else {
+            // Rate-limited by the engine; retry on the next poll
+            self.pending = Some(text);
+        }
+        Ok(results)
+    }
+
+    /// Searches for the latest text right away, e.g. at end of input
+    ///
+    /// Returns `None` if nothing is pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Self::poll`].
+    pub fn flush(
+        &mut self,
+        connections: &[&Connection],
+        groups: &[ConnectionGroup],
+    ) -> SearchResult<Option<Vec<ConnectionSearchResult>>> {
+        let Some(text) = self.pending.take() else {
+            return Ok(None);
+        };
+        let query = SearchEngine::parse_query(&text)?.with_mode(self.mode);
+        let results = self.engine.search(&query, connections, groups)?;
+        self.searches += 1;
+        Ok(Some(results))
+    }
+
+    /// Returns the text waiting to be searched
+    #[must_use]
+    pub fn pending(&self) -> Option<&str> {
+        self.pending.as_deref()
+    }
+
+    /// Returns the number of searches run so far
+    #[must_use]
+    pub const fn searches(&self) -> usize {
+        self.searches
+    }
+
+    /// Gets the quiet period after which pending text is searched
+    #[must_use]
+    pub const fn delay(&self) -> Duration {
+        self.engine.delay()
+    }
+}
+
+impl Default for IncrementalSearch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connections() -> Vec<Connection> {
+        vec![
+            Connection::new_ssh("web-01".to_string(), "10.0.0.1".to_string(), 22),
+            Connection::new_ssh("db-01".to_string(), "10.0.0.2".to_string(), 22),
+        ]
+    }
+
+    #[test]
+    fn test_rapid_inputs_collapse_to_one_search() {
+        let connections = connections();
+        let refs: Vec<&Connection> = connections.iter().collect();
+        let mut search =
+            IncrementalSearch::with_engine(DebouncedSearchEngine::new(Duration::from_millis(30)));
+
+        for text in ["w", "we", "web", "web-0"] {
+            search.input(text);
+            assert!(search.poll(&refs, &[]).unwrap().is_none());
+        }
+        assert_eq!(search.searches(), 0);
+        assert_eq!(search.pending(), Some("web-0"));
+
+        std::thread::sleep(Duration::from_millis(40));
+        let results = search.poll(&refs, &[]).unwrap().unwrap();
+        assert_eq!(search.searches(), 1);
+        assert_eq!(results[0].connection_id, connections[0].id);
+
+        // Nothing left to search
+        assert!(search.poll(&refs, &[]).unwrap().is_none());
+        assert_eq!(search.searches(), 1);
+    }
+
+    #[test]
+    fn test_flush_searches_without_waiting() {
+        let connections = connections();
+        let refs: Vec<&Connection> = connections.iter().collect();
+        let mut search = IncrementalSearch::new().with_mode(SearchMode::Substring);
+
+        search.input("db");
+        search.input("db-");
+        let results = search.flush(&refs, &[]).unwrap().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].connection_id, connections[1].id);
+        assert_eq!(search.searches(), 1);
+        assert!(search.flush(&refs, &[]).unwrap().is_none());
+
+        // Malformed operators are reported and dropped
+        search.input("protocol:nope");
+        assert!(search.flush(&refs, &[]).is_err());
+        assert_eq!(search.pending(), None);
+    }
+}
@@ -2,9 +2,13 @@
 //!
 //! This module provides fuzzy search capabilities for connections with support
 //! for search operators, result ranking, and custom property search.
+//! Queries can also use plain substring or regular expression matching via
+//! [`SearchMode`].
 //!
 //! The [`command_palette`] submodule provides types for a VS Code-style
-//! command palette (Ctrl+P / Ctrl+Shift+P).
+//! command palette (Ctrl+P / Ctrl+Shift+P), [`saved`] holds the named
+//! searches kept in `AppSettings`, and [`incremental`] drives
+//! search-as-you-type from a stream of query edits.
 //!
 //! ## Performance Optimizations
 //!
@@ -34,6 +38,8 @@
 
 pub mod cache;
 pub mod command_palette;
+pub mod incremental;
+pub mod saved;
 
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -41,6 +47,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, info_span};
@@ -49,6 +56,7 @@ use uuid::Uuid;
 use crate::models::{Connection, ConnectionGroup, ProtocolType};
 use crate::performance::Debouncer;
 use crate::tracing::span_names;
+use saved::SavedSearch;
 
 /// Error type for search operations
 #[derive(Debug, Error)]
@@ -69,6 +77,10 @@ pub enum SearchError {
     /// Invalid regex pattern
     #[error("Invalid regex pattern: {0}")]
     InvalidPattern(String),
+
+    /// No saved search has the requested name
+    #[error("No saved search named '{0}'")]
+    SavedSearchNotFound(String),
 }
 
 /// Result type for search operations
@@ -87,6 +99,35 @@ pub enum SearchFilter {
     GroupName(String),
     /// Search within custom properties
     InCustomProperty(String),
+    /// Filter by host substring (e.g., host:db01)
+    Host(String),
+    /// Filter by username substring (e.g., user:root)
+    User(String),
+}
+
+/// How the query text is matched against connection fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchMode {
+    /// Fuzzy matching with substring and prefix bonuses
+    #[default]
+    Fuzzy,
+    /// Literal substring matching
+    Substring,
+    /// Regular expression matching
+    Regex,
+}
+
+impl SearchMode {
+    /// Returns a stable identifier for this mode
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Fuzzy => "fuzzy",
+            Self::Substring => "substring",
+            Self::Regex => "regex",
+        }
+    }
 }
 
 /// A parsed search query with text and filters
@@ -96,6 +137,8 @@ pub struct SearchQuery {
     pub text: String,
     /// Filters extracted from operators
     pub filters: Vec<SearchFilter>,
+    /// How `text` is matched
+    pub mode: SearchMode,
 }
 
 impl SearchQuery {
@@ -111,9 +154,26 @@ impl SearchQuery {
         Self {
             text: text.into(),
             filters: Vec::new(),
+            mode: SearchMode::default(),
         }
     }
 
+    /// Sets the matching mode
+    #[must_use]
+    pub const fn with_mode(mut self, mode: SearchMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the key used to cache results for this query
+    ///
+    /// The key includes the mode so fuzzy and regex results for the same
+    /// text are cached separately.
+    #[must_use]
+    pub fn cache_key(&self) -> String {
+        format!("{}:{}", self.mode.as_str(), self.text)
+    }
+
     /// Adds a filter to the query
     #[must_use]
     pub fn with_filter(mut self, filter: SearchFilter) -> Self {
@@ -195,6 +255,16 @@ impl ConnectionSearchResult {
     }
 }
 
+/// Query text prepared for matching in a given `SearchMode`
+enum TextMatcher<'q> {
+    /// Fuzzy match against the raw query text
+    Fuzzy(&'q str),
+    /// Literal substring match against the raw query text
+    Substring(&'q str),
+    /// Compiled regular expression
+    Regex(Regex),
+}
+
 /// Search engine for connections
 pub struct SearchEngine {
     /// Whether to use case-sensitive matching
@@ -229,6 +299,11 @@ impl SearchEngine {
     /// - `protocol:ssh` - filter by protocol
     /// - `tag:production` - filter by tag
     /// - `group:servers` - filter by group name
+    /// - `host:db01` - filter by host substring
+    /// - `user:root` - filter by username substring
+    ///
+    /// Terms without an operator are kept as text and matched against all
+    /// fields.
     ///
     /// # Errors
     ///
@@ -269,6 +344,24 @@ impl SearchEngine {
                                 .push(SearchFilter::GroupName(value.to_string()));
                         }
                     }
+                    "host" => {
+                        if value.is_empty() {
+                            return Err(SearchError::InvalidOperator {
+                                operator: "host".to_string(),
+                                reason: "host value cannot be empty".to_string(),
+                            });
+                        }
+                        query.filters.push(SearchFilter::Host(value.to_string()));
+                    }
+                    "user" | "username" => {
+                        if value.is_empty() {
+                            return Err(SearchError::InvalidOperator {
+                                operator: operator.to_string(),
+                                reason: "user value cannot be empty".to_string(),
+                            });
+                        }
+                        query.filters.push(SearchFilter::User(value.to_string()));
+                    }
                     "prop" | "property" => {
                         if value.is_empty() {
                             return Err(SearchError::InvalidOperator {
@@ -427,13 +520,17 @@ impl SearchEngine {
     /// # Returns
     ///
     /// A vector of search results sorted by relevance score (highest first)
-    #[must_use]
+    ///
+    /// # Errors
+    ///
+    /// Returns `SearchError::InvalidPattern` if the query is in regex mode
+    /// and its text is not a valid regular expression.
     pub fn search(
         &self,
         query: &SearchQuery,
         connections: &[&Connection],
         groups: &[ConnectionGroup],
-    ) -> Vec<ConnectionSearchResult> {
+    ) -> SearchResult<Vec<ConnectionSearchResult>> {
         let _span = info_span!(
             span_names::SEARCH_EXECUTE,
             query = %query.text,
@@ -444,16 +541,18 @@ impl SearchEngine {
 
         if query.is_empty() {
             debug!("Empty query, returning no results");
-            return Vec::new();
+            return Ok(Vec::new());
         }
 
+        let matcher = self.text_matcher(query)?;
+
         // Index groups by id once: group scoring/filtering is O(1) per
         // connection instead of a linear scan (was O(connections × groups)).
         let group_map: HashMap<Uuid, &ConnectionGroup> = groups.iter().map(|g| (g.id, g)).collect();
 
         let mut results: Vec<ConnectionSearchResult> = connections
             .iter()
-            .filter_map(|conn| self.score_connection(query, conn, &group_map))
+            .filter_map(|conn| self.score_connection(query, &matcher, conn, &group_map))
             .collect();
 
         // Sort by score descending
@@ -464,7 +563,89 @@ impl SearchEngine {
         });
 
         debug!(result_count = results.len(), "Search completed");
-        results
+        Ok(results)
+    }
+
+    /// Looks up a saved search by name (case-insensitive) and parses it
+    ///
+    /// # Errors
+    ///
+    /// Returns `SearchError::SavedSearchNotFound` if no search has that
+    /// name, or a parse error if the stored query is no longer valid.
+    pub fn load_saved(saved: &[SavedSearch], name: &str) -> SearchResult<SearchQuery> {
+        saved
+            .iter()
+            .find(|s| s.is_named(name))
+            .ok_or_else(|| SearchError::SavedSearchNotFound(name.trim().to_string()))?
+            .to_query()
+    }
+
+    /// Runs the saved search called `name`
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Self::load_saved`] and [`Self::search`].
+    pub fn run_saved(
+        &self,
+        saved: &[SavedSearch],
+        name: &str,
+        connections: &[&Connection],
+        groups: &[ConnectionGroup],
+    ) -> SearchResult<Vec<ConnectionSearchResult>> {
+        let query = Self::load_saved(saved, name)?;
+        self.search(&query, connections, groups)
+    }
+
+    /// Prepares the query text for matching, compiling it in regex mode
+    fn text_matcher<'q>(&self, query: &'q SearchQuery) -> SearchResult<TextMatcher<'q>> {
+        match query.mode {
+            SearchMode::Fuzzy => Ok(TextMatcher::Fuzzy(&query.text)),
+            SearchMode::Substring => Ok(TextMatcher::Substring(&query.text)),
+            SearchMode::Regex => RegexBuilder::new(&query.text)
+                .case_insensitive(!self.case_sensitive)
+                .build()
+                .map(TextMatcher::Regex)
+                .map_err(|e| SearchError::InvalidPattern(e.to_string())),
+        }
+    }
+
+    /// Scores a single field and returns its highlight span, if any
+    ///
+    /// Regex matches score like substring matches, with the match span
+    /// standing in for the query; empty regex matches are ignored.
+    fn match_field(
+        &self,
+        matcher: &TextMatcher<'_>,
+        target: &str,
+    ) -> (f32, Option<(usize, usize)>) {
+        match matcher {
+            TextMatcher::Fuzzy(text) => {
+                let score = self.fuzzy_score(text, target);
+                if score > 0.0 {
+                    (score, self.find_highlight(text, target))
+                } else {
+                    (0.0, None)
+                }
+            }
+            TextMatcher::Substring(text) => self
+                .find_highlight(text, target)
+                .map_or((0.0, None), |span| {
+                    (self.fuzzy_score(text, target), Some(span))
+                }),
+            TextMatcher::Regex(regex) => {
+                let Some(m) = regex.find_iter(target).find(|m| !m.is_empty()) else {
+                    return (0.0, None);
+                };
+                let score = if m.len() == target.len() {
+                    1.0
+                } else {
+                    let ratio = m.len() as f32 / target.len() as f32;
+                    let prefix_bonus = if m.start() == 0 { 0.1 } else { 0.0 };
+                    ratio.mul_add(0.4, 0.5 + prefix_bonus).min(0.99)
+                };
+                (score, Some((m.start(), m.end())))
+            }
+        }
     }
 
     /// Scores a single connection against the query
@@ -472,16 +653,16 @@ impl SearchEngine {
     /// the match (weight 0.5 — below name/host/tags, above custom properties).
     fn score_description(
         &self,
-        query: &SearchQuery,
+        matcher: &TextMatcher<'_>,
         description: &str,
         max_score: &mut f32,
         result: &mut ConnectionSearchResult,
     ) {
-        let desc_score = self.fuzzy_score(&query.text, description);
+        let (desc_score, desc_highlight) = self.match_field(matcher, description);
         if desc_score > 0.0 {
             *max_score = max_score.max(desc_score * 0.5);
             result.matched_fields.push(Cow::Borrowed("description"));
-            if let Some(highlight) = self.find_highlight(&query.text, description) {
+            if let Some(highlight) = desc_highlight {
                 result.highlights.push(MatchHighlight::new(
                     "description",
                     highlight.0,
@@ -494,6 +675,7 @@ impl SearchEngine {
     fn score_connection(
         &self,
         query: &SearchQuery,
+        matcher: &TextMatcher<'_>,
         connection: &Connection,
         groups: &HashMap<Uuid, &ConnectionGroup>,
     ) -> Option<ConnectionSearchResult> {
@@ -511,11 +693,11 @@ impl SearchEngine {
         let mut max_score: f32 = 0.0;
 
         // Score against name (highest weight)
-        let name_score = self.fuzzy_score(&query.text, &connection.name);
+        let (name_score, name_highlight) = self.match_field(matcher, &connection.name);
         if name_score > 0.0 {
             max_score = max_score.max(name_score * 1.0);
             result.matched_fields.push(Cow::Borrowed("name"));
-            if let Some(highlight) = self.find_highlight(&query.text, &connection.name) {
+            if let Some(highlight) = name_highlight {
                 result
                     .highlights
                     .push(MatchHighlight::new("name", highlight.0, highlight.1));
@@ -523,11 +705,11 @@ impl SearchEngine {
         }
 
         // Score against host
-        let host_score = self.fuzzy_score(&query.text, &connection.host);
+        let (host_score, host_highlight) = self.match_field(matcher, &connection.host);
         if host_score > 0.0 {
             max_score = max_score.max(host_score * 0.9);
             result.matched_fields.push(Cow::Borrowed("host"));
-            if let Some(highlight) = self.find_highlight(&query.text, &connection.host) {
+            if let Some(highlight) = host_highlight {
                 result
                     .highlights
                     .push(MatchHighlight::new("host", highlight.0, highlight.1));
@@ -536,18 +718,18 @@ impl SearchEngine {
 
         // Score against description/notes
         if let Some(description) = &connection.description {
-            self.score_description(query, description, &mut max_score, &mut result);
+            self.score_description(matcher, description, &mut max_score, &mut result);
         }
 
         // Score against tags
         for tag in &connection.tags {
-            let tag_score = self.fuzzy_score(&query.text, tag);
+            let (tag_score, tag_highlight) = self.match_field(matcher, tag);
             if tag_score > 0.0 {
                 max_score = max_score.max(tag_score * 0.8);
                 if !result.matched_fields.iter().any(|f| f.as_ref() == "tags") {
                     result.matched_fields.push(Cow::Borrowed("tags"));
                 }
-                if let Some(highlight) = self.find_highlight(&query.text, tag) {
+                if let Some(highlight) = tag_highlight {
                     result
                         .highlights
                         .push(MatchHighlight::new("tags", highlight.0, highlight.1));
@@ -559,11 +741,11 @@ impl SearchEngine {
         if let Some(group_id) = connection.group_id
             && let Some(group) = groups.get(&group_id)
         {
-            let group_score = self.fuzzy_score(&query.text, &group.name);
+            let (group_score, group_highlight) = self.match_field(matcher, &group.name);
             if group_score > 0.0 {
                 max_score = max_score.max(group_score * 0.7);
                 result.matched_fields.push(Cow::Borrowed("group"));
-                if let Some(highlight) = self.find_highlight(&query.text, &group.name) {
+                if let Some(highlight) = group_highlight {
                     result
                         .highlights
                         .push(MatchHighlight::new("group", highlight.0, highlight.1));
@@ -574,7 +756,7 @@ impl SearchEngine {
         // Score against custom properties
         for prop in &connection.custom_properties {
             // Score against property name
-            let name_score = self.fuzzy_score(&query.text, &prop.name);
+            let (name_score, _) = self.match_field(matcher, &prop.name);
             if name_score > 0.0 {
                 max_score = max_score.max(name_score * 0.6);
                 let field_name = format!("custom_property:{}", prop.name);
@@ -589,7 +771,7 @@ impl SearchEngine {
 
             // Score against property value (skip protected properties)
             if !prop.is_protected() {
-                let value_score = self.fuzzy_score(&query.text, &prop.value);
+                let (value_score, _) = self.match_field(matcher, &prop.value);
                 if value_score > 0.0 {
                     max_score = max_score.max(value_score * 0.6);
                     let field_name = format!("custom_property:{}", prop.name);
@@ -606,7 +788,7 @@ impl SearchEngine {
 
         // Score against username if present
         if let Some(ref username) = connection.username {
-            let username_score = self.fuzzy_score(&query.text, username);
+            let (username_score, _) = self.match_field(matcher, username);
             if username_score > 0.0 {
                 max_score = max_score.max(username_score * 0.5);
                 result.matched_fields.push(Cow::Borrowed("username"));
@@ -640,12 +822,7 @@ impl SearchEngine {
                     }
                 }
                 SearchFilter::Tag(tag) => {
-                    let tag_lower = tag.to_lowercase();
-                    if !connection
-                        .tags
-                        .iter()
-                        .any(|t| t.to_lowercase() == tag_lower)
-                    {
+                    if !connection.has_tag(tag) {
                         return false;
                     }
                 }
@@ -674,6 +851,25 @@ impl SearchEngine {
                         return false;
                     }
                 }
+                SearchFilter::Host(host) => {
+                    if !connection
+                        .host
+                        .to_lowercase()
+                        .contains(&host.to_lowercase())
+                    {
+                        return false;
+                    }
+                }
+                SearchFilter::User(user) => {
+                    let user_lower = user.to_lowercase();
+                    let matches = connection
+                        .username
+                        .as_deref()
+                        .is_some_and(|u| u.to_lowercase().contains(&user_lower));
+                    if !matches {
+                        return false;
+                    }
+                }
             }
         }
         true
@@ -831,7 +1027,7 @@ impl SearchEngine {
 ///
 /// // First search proceeds immediately
 /// let query = SearchQuery::with_text("server");
-/// let results = engine.search(&query, &[], &[]);
+/// let results = engine.search(&query, &[], &[]).unwrap();
 ///
 /// // Rapid subsequent searches are debounced
 /// // Only the last one will actually execute after the delay
@@ -853,9 +1049,14 @@ impl DebouncedSearchEngine {
     /// Creates a new debounced search engine with the specified delay
     #[must_use]
     pub fn new(delay: Duration) -> Self {
+        Self::with_debouncer(Debouncer::new(delay))
+    }
+
+    /// Creates a debounced search engine gated by `debouncer`
+    fn with_debouncer(debouncer: Debouncer) -> Self {
         Self {
             engine: SearchEngine::new(),
-            debouncer: Debouncer::new(delay),
+            debouncer,
             last_query: Arc::new(Mutex::new(None)),
             search_pending: AtomicBool::new(false),
             search_cache: Arc::new(Mutex::new(cache::SearchCache::with_defaults())),
@@ -880,7 +1081,7 @@ impl DebouncedSearchEngine {
     /// Creates a debounced search engine with default search delay (100ms)
     #[must_use]
     pub fn for_search() -> Self {
-        Self::new(Duration::from_millis(100))
+        Self::with_debouncer(Debouncer::for_search())
     }
 
     /// Sets whether matching should be case-sensitive
@@ -896,13 +1097,17 @@ impl DebouncedSearchEngine {
     /// The actual search will be performed after the debounce delay.
     ///
     /// Returns `Some(results)` if search was performed, `None` if debounced.
-    #[must_use]
+    ///
+    /// # Errors
+    ///
+    /// Returns `SearchError::InvalidPattern` if the query is in regex mode
+    /// and its text is not a valid regular expression.
     pub fn search_debounced(
         &self,
         query: &SearchQuery,
         connections: &[&Connection],
         groups: &[ConnectionGroup],
-    ) -> Option<Vec<ConnectionSearchResult>> {
+    ) -> SearchResult<Option<Vec<ConnectionSearchResult>>> {
         // Store the query for potential deferred execution
         if let Ok(mut last) = self.last_query.lock() {
             *last = Some(query.text.clone());
@@ -910,58 +1115,64 @@ impl DebouncedSearchEngine {
 
         // Check if we should proceed with the search
         if self.debouncer.should_proceed() {
+            let cache_key = query.cache_key();
+
             // Check cache first
             {
                 if let Ok(cache) = self.search_cache.lock()
-                    && let Some(cached_results) = cache.get(&query.text)
+                    && let Some(cached_results) = cache.get(&cache_key)
                 {
                     self.search_pending.store(false, Ordering::SeqCst);
-                    return Some(cached_results.to_vec());
+                    return Ok(Some(cached_results.to_vec()));
                 }
             }
 
             // Execute search
-            let results = self.engine.search(query, connections, groups);
+            let results = self.engine.search(query, connections, groups)?;
 
             // Cache the results
             {
                 if let Ok(mut cache) = self.search_cache.lock() {
-                    cache.insert(query.text.clone(), results.clone());
+                    cache.insert(cache_key, results.clone());
                 }
             }
 
             self.search_pending.store(false, Ordering::SeqCst);
-            Some(results)
+            Ok(Some(results))
         } else {
             self.search_pending.store(true, Ordering::SeqCst);
-            None
+            Ok(None)
         }
     }
 
     /// Performs a search without debouncing
     ///
     /// Use this when you need immediate results regardless of timing.
-    #[must_use]
+    ///
+    /// # Errors
+    ///
+    /// Returns `SearchError::InvalidPattern` if the query is in regex mode
+    /// and its text is not a valid regular expression.
     pub fn search(
         &self,
         query: &SearchQuery,
         connections: &[&Connection],
         groups: &[ConnectionGroup],
-    ) -> Vec<ConnectionSearchResult> {
+    ) -> SearchResult<Vec<ConnectionSearchResult>> {
         self.engine.search(query, connections, groups)
     }
 
     /// Returns cached results if available and still valid
     ///
-    /// Results are considered valid if they match the current query
+    /// Results are considered valid if they match the query text and mode
     /// and haven't exceeded the cache TTL.
     #[must_use]
-    pub fn get_cached_results(&self, query_text: &str) -> Option<Vec<ConnectionSearchResult>> {
+    pub fn get_cached_results(&self, query: &SearchQuery) -> Option<Vec<ConnectionSearchResult>> {
         let Ok(cache) = self.search_cache.lock() else {
             return None;
         };
         cache
-            .get(query_text)
+            .get(&query.cache_key())
             .map(<[ConnectionSearchResult]>::to_vec)
     }
 
@@ -1160,6 +1371,72 @@ mod tests {
         assert_eq!(query.filters.len(), 2);
     }
 
+    #[test]
+    fn test_parse_query_with_host_and_user_filters() {
+        let query = SearchEngine::parse_query("host:db01 user:root primary").unwrap();
+        assert_eq!(query.text, "primary");
+        assert_eq!(query.filters.len(), 2);
+        assert!(matches!(&query.filters[0], SearchFilter::Host(h) if h == "db01"));
+        assert!(matches!(&query.filters[1], SearchFilter::User(u) if u == "root"));
+    }
+
+    #[test]
+    fn test_parse_query_empty_host_and_user() {
+        assert!(SearchEngine::parse_query("host:").is_err());
+        assert!(SearchEngine::parse_query("user:").is_err());
+    }
+
+    #[test]
+    fn test_search_field_filters_with_bare_term() {
+        let engine = SearchEngine::new();
+        let group = ConnectionGroup::new("Staging".to_string());
+
+        let mut primary = create_test_connection("primary", "db01.example.com", ProtocolType::Ssh);
+        primary.username = Some("root".to_string());
+        primary.group_id = Some(group.id);
+
+        // Same name and user but a different host
+        let mut replica = create_test_connection("primary", "db02.example.com", ProtocolType::Ssh);
+        replica.username = Some("root".to_string());
+        replica.group_id = Some(group.id);
+
+        // Host matches but the bare term does not
+        let mut other = create_test_connection("backup", "db01.example.com", ProtocolType::Ssh);
+        other.username = Some("root".to_string());
+        other.group_id = Some(group.id);
+
+        // Everything matches except the user
+        let mut admin = create_test_connection("primary", "db01.example.com", ProtocolType::Ssh);
+        admin.username = Some("admin".to_string());
+        admin.group_id = Some(group.id);
+
+        let connections = vec![primary.clone(), replica, other, admin];
+        let query =
+            SearchEngine::parse_query("host:db01 user:root group:staging protocol:ssh primary")
+                .unwrap();
+        let results = engine
+            .search(&query, &refs(&connections), &[group])
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].connection_id, primary.id);
+        assert!(results[0].matched_fields.iter().any(|f| f == "name"));
+    }
+
+    #[test]
+    fn test_search_host_filter_does_not_match_name() {
+        let engine = SearchEngine::new();
+        let connections = vec![
+            create_test_connection("db01", "10.0.0.5", ProtocolType::Ssh),
+            create_test_connection("web", "db01.internal", ProtocolType::Ssh),
+        ];
+        let query = SearchEngine::parse_query("host:db01").unwrap();
+        let results = engine.search(&query, &refs(&connections), &[]).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].connection_id, connections[1].id);
+    }
+
     #[test]
     fn test_parse_query_invalid_protocol() {
         let result = SearchEngine::parse_query("protocol:invalid");
@@ -1218,7 +1495,7 @@ mod tests {
         let groups = vec![];
 
         let query = SearchQuery::with_text("web");
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].connection_id, connections[0].id);
@@ -1234,7 +1511,7 @@ mod tests {
         let groups = vec![];
 
         let query = SearchQuery::with_text("production");
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].connection_id, connections[0].id);
@@ -1256,7 +1533,7 @@ mod tests {
         let groups = vec![];
 
         let query = SearchQuery::with_text("web.example");
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].connection_id, connections[0].id);
@@ -1273,7 +1550,7 @@ mod tests {
 
         let query =
             SearchQuery::with_text("server").with_filter(SearchFilter::Protocol(ProtocolType::Ssh));
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].connection_id, connections[0].id);
@@ -1291,12 +1568,42 @@ mod tests {
 
         let query = SearchQuery::with_text("server")
             .with_filter(SearchFilter::Tag("production".to_string()));
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].connection_id, connections[0].id);
     }
 
+    #[test]
+    fn test_search_tag_queries_combine_case_insensitively() {
+        let engine = SearchEngine::new();
+        let mut pci = create_test_connection("payments", "10.0.0.1", ProtocolType::Ssh);
+        pci.tags = vec!["PCI".to_string(), "on-call".to_string()];
+        let mut legacy = create_test_connection("ftp", "10.0.0.2", ProtocolType::Ssh);
+        legacy.tags = vec!["legacy".to_string(), "pci".to_string()];
+        let untagged = create_test_connection("wiki", "10.0.0.3", ProtocolType::Ssh);
+        let connections = vec![pci, legacy, untagged];
+
+        let search = |text: &str| {
+            let query = SearchEngine::parse_query(text).unwrap();
+            let mut ids: Vec<Uuid> = engine
+                .search(&query, &refs(&connections), &[])
+                .unwrap()
+                .iter()
+                .map(|r| r.connection_id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        let mut both = vec![connections[0].id, connections[1].id];
+        both.sort();
+        assert_eq!(search("tag:pci"), both);
+        assert_eq!(search("tag:pci tag:On-Call"), [connections[0].id]);
+        assert_eq!(search("tag:pci ftp"), [connections[1].id]);
+        assert!(search("tag:decommissioned").is_empty());
+    }
+
     #[test]
     fn test_search_results_sorted_by_score() {
         let engine = SearchEngine::new();
@@ -1308,7 +1615,7 @@ mod tests {
         let groups = vec![];
 
         let query = SearchQuery::with_text("web");
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
 
         assert_eq!(results.len(), 3);
         // Exact match should be first
@@ -1331,7 +1638,7 @@ mod tests {
         let groups = vec![];
 
         let query = SearchQuery::with_text("production");
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
 
         assert_eq!(results.len(), 1);
         assert!(
@@ -1353,7 +1660,7 @@ mod tests {
         let groups = vec![];
 
         let query = SearchQuery::new();
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
 
         assert!(results.is_empty());
     }
@@ -1368,7 +1675,7 @@ mod tests {
         let groups = vec![];
 
         let query = SearchQuery::new().with_filter(SearchFilter::Protocol(ProtocolType::Ssh));
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].connection_id, connections[0].id);
@@ -1444,7 +1751,9 @@ mod tests {
         let groups = vec![];
 
         let query = SearchQuery::with_text("server");
-        let result = engine.search_debounced(&query, &refs(&connections), &groups);
+        let result = engine
+            .search_debounced(&query, &refs(&connections), &groups)
+            .unwrap();
 
         assert!(result.is_some());
         assert!(!result.unwrap().is_empty());
@@ -1463,11 +1772,15 @@ mod tests {
         let query = SearchQuery::with_text("server");
 
         // First call should proceed
-        let result1 = engine.search_debounced(&query, &refs(&connections), &groups);
+        let result1 = engine
+            .search_debounced(&query, &refs(&connections), &groups)
+            .unwrap();
         assert!(result1.is_some());
 
         // Immediate second call should be debounced
-        let result2 = engine.search_debounced(&query, &refs(&connections), &groups);
+        let result2 = engine
+            .search_debounced(&query, &refs(&connections), &groups)
+            .unwrap();
         assert!(result2.is_none());
         assert!(engine.has_pending_search());
     }
@@ -1491,7 +1804,9 @@ mod tests {
         std::thread::sleep(Duration::from_millis(20));
 
         // Should proceed now
-        let result = engine.search_debounced(&query, &refs(&connections), &groups);
+        let result = engine
+            .search_debounced(&query, &refs(&connections), &groups)
+            .unwrap();
         assert!(result.is_some());
     }
 
@@ -1511,7 +1826,7 @@ mod tests {
         let _ = engine.search_debounced(&query, &refs(&connections), &groups);
 
         // Get cached results
-        let cached = engine.get_cached_results("server");
+        let cached = engine.get_cached_results(&query);
         assert!(cached.is_some());
         assert!(!cached.unwrap().is_empty());
     }
@@ -1535,7 +1850,7 @@ mod tests {
         engine.reset();
 
         // Cached results should be cleared
-        let cached = engine.get_cached_results("server");
+        let cached = engine.get_cached_results(&query);
         assert!(cached.is_none());
     }
 
@@ -1574,7 +1889,7 @@ mod tests {
         let query = SearchQuery::with_text("server-25");
 
         let start = std::time::Instant::now();
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
         let elapsed = start.elapsed();
 
         // Should complete within 100ms for 500 connections
@@ -1645,8 +1960,155 @@ mod tests {
 
         // ASCII query against Unicode names — must not panic
         let query = SearchQuery::with_text("ser");
-        let results = engine.search(&query, &refs(&connections), &groups);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
         // Should at least find "web-server"
         assert!(!results.is_empty());
     }
+
+    // ========== Tests for search modes ==========
+
+    #[test]
+    fn test_regex_search_matches_host_pattern() {
+        let engine = SearchEngine::new();
+        let connections = vec![
+            create_test_connection("orders", "prod-eu-db12", ProtocolType::Ssh),
+            create_test_connection("billing", "prod-eu-db12-replica", ProtocolType::Ssh),
+            create_test_connection("staging", "stage-eu-db3", ProtocolType::Ssh),
+        ];
+        let groups = vec![];
+
+        let query = SearchQuery::with_text(r"^prod-.*-db\d+$").with_mode(SearchMode::Regex);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].connection_id, connections[0].id);
+        assert!(results[0].matched_fields.iter().any(|f| f == "host"));
+    }
+
+    #[test]
+    fn test_regex_search_highlights_match_span() {
+        let engine = SearchEngine::new();
+        let connections = vec![create_test_connection(
+            "web-042-eu",
+            "10.0.0.1",
+            ProtocolType::Ssh,
+        )];
+        let groups = vec![];
+
+        let query = SearchQuery::with_text(r"\d{3}").with_mode(SearchMode::Regex);
+        let results = engine.search(&query, &refs(&connections), &groups).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert!(
+            results[0]
+                .highlights
+                .contains(&MatchHighlight::new("name", 4, 7))
+        );
+    }
+
+    #[test]
+    fn test_regex_search_invalid_pattern_returns_error() {
+        let engine = SearchEngine::new();
+        let connections = vec![create_test_connection(
+            "server",
+            "192.168.1.1",
+            ProtocolType::Ssh,
+        )];
+        let groups = vec![];
+
+        let query = SearchQuery::with_text("prod-(db").with_mode(SearchMode::Regex);
+        let result = engine.search(&query, &refs(&connections), &groups);
+
+        assert!(matches!(result, Err(SearchError::InvalidPattern(_))));
+    }
+
+    #[test]
+    fn test_debounced_search_invalid_pattern_returns_error() {
+        let engine = DebouncedSearchEngine::for_search();
+        let groups = vec![];
+
+        let query = SearchQuery::with_text("[unclosed").with_mode(SearchMode::Regex);
+        let result = engine.search_debounced(&query, &[], &groups);
+
+        assert!(matches!(result, Err(SearchError::InvalidPattern(_))));
+        assert_eq!(engine.cache_size(), 0);
+    }
+
+    #[test]
+    fn test_substring_search_does_not_fuzzy_match() {
+        let engine = SearchEngine::new();
+        let connections = vec![create_test_connection(
+            "production",
+            "192.168.1.1",
+            ProtocolType::Ssh,
+        )];
+        let groups = vec![];
+
+        let fuzzy = SearchQuery::with_text("pdn");
+        assert_eq!(
+            engine
+                .search(&fuzzy, &refs(&connections), &groups)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let substring = fuzzy.clone().with_mode(SearchMode::Substring);
+        assert!(
+            engine
+                .search(&substring, &refs(&connections), &groups)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_run_saved_search_by_name() {
+        let engine = SearchEngine::new();
+        let prod = ConnectionGroup::new("Prod".to_string());
+        let mut pager = create_test_connection("pager", "10.0.0.1", ProtocolType::Ssh);
+        pager.group_id = Some(prod.id);
+        pager.tags = vec!["on-call".to_string()];
+        let mut desk = create_test_connection("desk", "10.0.0.2", ProtocolType::Rdp);
+        desk.group_id = Some(prod.id);
+        desk.tags = vec!["on-call".to_string()];
+        let connections = vec![pager, desk];
+        let groups = vec![prod];
+
+        let saved = vec![SavedSearch::new(
+            "On-Call",
+            "group:Prod protocol:ssh tag:on-call",
+        )];
+        let results = engine
+            .run_saved(&saved, "on-call", &refs(&connections), &groups)
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].connection_id, connections[0].id);
+
+        assert!(matches!(
+            engine.run_saved(&saved, "missing", &refs(&connections), &groups),
+            Err(SearchError::SavedSearchNotFound(name)) if name == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_cache_key_includes_mode() {
+        let fuzzy = SearchQuery::with_text("db");
+        let regex = SearchQuery::with_text("db").with_mode(SearchMode::Regex);
+        assert_ne!(fuzzy.cache_key(), regex.cache_key());
+
+        let engine = DebouncedSearchEngine::new(Duration::ZERO);
+        let connections = vec![create_test_connection(
+            "db-primary",
+            "10.0.0.5",
+            ProtocolType::Ssh,
+        )];
+        let groups = vec![];
+
+        let _ = engine
+            .search_debounced(&fuzzy, &refs(&connections), &groups)
+            .unwrap();
+        assert!(engine.get_cached_results(&fuzzy).is_some());
+        assert!(engine.get_cached_results(&regex).is_none());
+    }
 }
@@ -0,0 +1,95 @@
+//! Named searches persisted in `AppSettings`
+//!
+//! A saved search keeps the raw query string (operators included) rather than
+//! the parsed [`SearchQuery`], so it follows later changes to the query
+//! syntax and group names are resolved afresh on every run.
+
+use serde::{Deserialize, Serialize};
+
+use super::{SearchEngine, SearchMode, SearchQuery, SearchResult};
+
+/// A search query saved under a name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSearch {
+    /// Name used to run the search; unique case-insensitively
+    pub name: String,
+    /// Query string as typed, e.g. `group:Prod protocol:ssh tag:on-call`
+    pub query: String,
+    /// How the query text is matched
+    #[serde(default)]
+    pub mode: SearchMode,
+}
+
+impl SavedSearch {
+    /// Creates a fuzzy saved search
+    #[must_use]
+    pub fn new(name: impl Into<String>, query: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            query: query.into(),
+            mode: SearchMode::default(),
+        }
+    }
+
+    /// Sets the matching mode
+    #[must_use]
+    pub const fn with_mode(mut self, mode: SearchMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns true if this search is called `name` (case-insensitive)
+    #[must_use]
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name.trim())
+    }
+
+    /// Parses the stored query string
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`SearchEngine::parse_query`].
+    pub fn to_query(&self) -> SearchResult<SearchQuery> {
+        SearchEngine::parse_query(&self.query).map(|query| query.with_mode(self.mode))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProtocolType;
+    use crate::search::SearchFilter;
+
+    #[test]
+    fn test_to_query_keeps_operators_and_mode() {
+        let saved = SavedSearch::new("on-call", "group:Prod protocol:ssh tag:on-call db")
+            .with_mode(SearchMode::Substring);
+        let query = saved.to_query().unwrap();
+
+        assert_eq!(query.text, "db");
+        assert_eq!(query.mode, SearchMode::Substring);
+        assert_eq!(
+            query.filters,
+            vec![
+                SearchFilter::GroupName("Prod".to_string()),
+                SearchFilter::Protocol(ProtocolType::Ssh),
+                SearchFilter::Tag("on-call".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_named_ignores_case() {
+        let saved = SavedSearch::new("On-Call", "tag:on-call");
+        assert!(saved.is_named("on-call"));
+        assert!(saved.is_named(" ON-CALL "));
+        assert!(!saved.is_named("on"));
+    }
+
+    #[test]
+    fn test_mode_defaults_when_missing() {
+        let saved: SavedSearch =
+            serde_json::from_str(r#"{"name":"web","query":"host:web"}"#).unwrap();
+        assert_eq!(saved.mode, SearchMode::Fuzzy);
+    }
+}
@@ -3,7 +3,13 @@
 //! This module defines the `SecretBackend` trait that all secret storage
 //! implementations must implement.
 
+use std::sync::Arc;
+use std::time::Duration;
+
 use async_trait::async_trait;
+use secrecy::SecretString;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
 
 use crate::error::SecretResult;
 use crate::models::Credentials;
@@ -23,6 +29,20 @@ pub enum BackendAvailability {
     ServiceUnavailable,
 }
 
+/// Snapshot of a backend's lock state, suitable for a UI lock indicator.
+///
+/// Produced by [`SecretBackend::status`] so the UI can learn that a vault is
+/// locked before a connection attempt needs a credential from it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackendStatus {
+    /// The backend client is installed and reachable
+    pub available: bool,
+    /// The vault is unlocked and credentials can be read without a prompt
+    pub unlocked: bool,
+    /// Server the backend talks to, for backends with a remote vault
+    pub server_url: Option<String>,
+}
+
 /// Abstraction over secret storage backends
 ///
 /// This trait defines the interface for storing, retrieving, and deleting
@@ -81,6 +101,41 @@ pub trait SecretBackend: Send + Sync {
         }
     }
 
+    /// Reports whether the backend is available and unlocked.
+    ///
+    /// Cheap enough to poll on a timer (see [`spawn_status_poller`]). The
+    /// default implementation treats every available backend as unlocked,
+    /// which is correct for stores without a separate lock step.
+    async fn status(&self) -> BackendStatus {
+        let available = self.is_available().await;
+        BackendStatus {
+            available,
+            unlocked: available,
+            server_url: None,
+        }
+    }
+
+    /// Unlocks the backend with the user's master password.
+    ///
+    /// CLI-backed vaults run their unlock command and keep the resulting
+    /// session token internally, so later calls on this backend succeed
+    /// without further prompts. Backends without a lock step accept any
+    /// password and return `Ok(())`.
+    ///
+    /// # Errors
+    /// Returns [`SecretError::InvalidPassword`] when the password is rejected,
+    /// [`SecretError::ClientMissing`] when the CLI is not installed, and
+    /// [`SecretError::ServerUnreachable`] when the vault server cannot be
+    /// contacted.
+    ///
+    /// [`SecretError::InvalidPassword`]: crate::error::SecretError::InvalidPassword
+    /// [`SecretError::ClientMissing`]: crate::error::SecretError::ClientMissing
+    /// [`SecretError::ServerUnreachable`]: crate::error::SecretError::ServerUnreachable
+    async fn unlock(&self, password: &SecretString) -> SecretResult<()> {
+        let _ = password;
+        Ok(())
+    }
+
     /// Returns the backend identifier
     ///
     /// # Returns
@@ -93,3 +148,165 @@ pub trait SecretBackend: Send + Sync {
     /// A static string with the display name (e.g., "`KeePassXC`", "GNOME Keyring")
     fn display_name(&self) -> &'static str;
 }
+
+/// Background task that polls [`SecretBackend::status`] on a fixed interval.
+///
+/// The latest status is published through a [`watch`] channel; the task is
+/// aborted when the poller is stopped or dropped.
+#[derive(Debug)]
+pub struct BackendStatusPoller {
+    handle: JoinHandle<()>,
+}
+
+impl BackendStatusPoller {
+    /// Stops polling. Receivers keep the last published status.
+    pub fn stop(&self) {
+        self.handle.abort();
+    }
+}
+
+impl Drop for BackendStatusPoller {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Starts polling `backend` every `interval` and returns the status receiver.
+///
+/// The first poll happens immediately. Only changed statuses wake receivers,
+/// so the UI can update its lock indicator from `changed().await`.
+/// Must be called from within a Tokio runtime.
+#[must_use]
+pub fn spawn_status_poller(
+    backend: Arc<dyn SecretBackend>,
+    interval: Duration,
+) -> (BackendStatusPoller, watch::Receiver<BackendStatus>) {
+    let (tx, rx) = watch::channel(BackendStatus::default());
+
+    let handle = tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            let status = backend.status().await;
+            tx.send_if_modified(|current| {
+                if *current == status {
+                    false
+                } else {
+                    *current = status;
+                    true
+                }
+            });
+            if tx.is_closed() {
+                break;
+            }
+        }
+    });
+
+    (BackendStatusPoller { handle }, rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct LockableBackend {
+        unlocked: AtomicBool,
+    }
+
+    #[async_trait]
+    impl SecretBackend for LockableBackend {
+        async fn store(&self, _: &str, _: &Credentials) -> SecretResult<()> {
+            Ok(())
+        }
+        async fn retrieve(&self, _: &str) -> SecretResult<Option<Credentials>> {
+            Ok(None)
+        }
+        async fn delete(&self, _: &str) -> SecretResult<()> {
+            Ok(())
+        }
+        async fn is_available(&self) -> bool {
+            true
+        }
+        async fn status(&self) -> BackendStatus {
+            BackendStatus {
+                available: true,
+                unlocked: self.unlocked.load(Ordering::SeqCst),
+                server_url: Some("https://vault.example.org".to_string()),
+            }
+        }
+        async fn unlock(&self, _: &SecretString) -> SecretResult<()> {
+            self.unlocked.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+        fn backend_id(&self) -> &'static str {
+            "lockable"
+        }
+        fn display_name(&self) -> &'static str {
+            "Lockable"
+        }
+    }
+
+    struct PlainBackend;
+
+    #[async_trait]
+    impl SecretBackend for PlainBackend {
+        async fn store(&self, _: &str, _: &Credentials) -> SecretResult<()> {
+            Ok(())
+        }
+        async fn retrieve(&self, _: &str) -> SecretResult<Option<Credentials>> {
+            Ok(None)
+        }
+        async fn delete(&self, _: &str) -> SecretResult<()> {
+            Ok(())
+        }
+        async fn is_available(&self) -> bool {
+            true
+        }
+        fn backend_id(&self) -> &'static str {
+            "plain"
+        }
+        fn display_name(&self) -> &'static str {
+            "Plain"
+        }
+    }
+
+    #[tokio::test]
+    async fn default_status_treats_available_as_unlocked() {
+        let status = PlainBackend.status().await;
+        assert!(status.available);
+        assert!(status.unlocked);
+        assert!(status.server_url.is_none());
+        assert!(
+            PlainBackend
+                .unlock(&SecretString::from("anything"))
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn poller_publishes_unlock_transition() {
+        let backend = Arc::new(LockableBackend {
+            unlocked: AtomicBool::new(false),
+        });
+        let (poller, mut rx) = spawn_status_poller(
+            Arc::clone(&backend) as Arc<dyn SecretBackend>,
+            Duration::from_millis(10),
+        );
+
+        rx.changed().await.expect("first poll");
+        assert!(rx.borrow_and_update().available);
+        assert!(!rx.borrow().unlocked);
+
+        backend
+            .unlock(&SecretString::from("master"))
+            .await
+            .expect("unlock");
+        rx.changed().await.expect("status after unlock");
+        assert!(rx.borrow().unlocked);
+
+        poller.stop();
+    }
+}
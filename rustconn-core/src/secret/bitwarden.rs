@@ -46,7 +46,7 @@ use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 use tokio::process::Command;
 
-use super::backend::SecretBackend;
+use super::backend::{BackendStatus, SecretBackend};
 use crate::error::{SecretError, SecretResult};
 use crate::models::Credentials;
 
@@ -69,6 +69,12 @@ static BW_SESSION_STORE: RwLock<Option<SecretString>> = RwLock::new(None);
 /// Falls back to `"bw"` when no custom path has been stored.
 static BW_CMD_STORE: RwLock<Option<String>> = RwLock::new(None);
 
+/// Server URL (normalized) the `bw` CLI was last verified or configured for.
+///
+/// Lets [`ensure_server`] skip `bw status` on every unlock once the CLI is
+/// known to point at the configured server.
+static BW_CONFIGURED_SERVER: RwLock<Option<String>> = RwLock::new(None);
+
 /// Timestamp of the last successful vault unlock/status verification.
 ///
 /// Used to skip redundant `bw status` calls when the session key is
@@ -301,6 +307,8 @@ pub struct BitwardenStatus {
         reason = "Deserialized from `bw status` JSON but not used directly"
     )]
     user_email: Option<String>,
+    #[serde(rename = "serverUrl", default)]
+    server_url: Option<String>,
 }
 
 impl BitwardenBackend {
@@ -405,7 +413,7 @@ impl BitwardenBackend {
         .await
         .map_err(|_| {
             tracing::warn!(args = ?args, "Bitwarden run_command: timed out after 30s");
-            SecretError::ConnectionFailed(format!(
+            SecretError::ServerUnreachable(format!(
                 "bw command timed out after 30s (args: {args:?})"
             ))
         })?
@@ -418,9 +426,7 @@ impl BitwardenBackend {
                 stderr = %stderr,
                 "Bitwarden run_command: failed"
             );
-            return Err(SecretError::ConnectionFailed(format!(
-                "bw command failed: {stderr}"
-            )));
+            return Err(classify_command_failure(&stderr));
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
@@ -628,7 +634,7 @@ impl SecretBackend for BitwardenBackend {
         // Check if vault is unlocked
         if !self.is_unlocked_fast().await {
             tracing::error!("Bitwarden store: vault is locked");
-            return Err(SecretError::BackendUnavailable(
+            return Err(SecretError::VaultLocked(
                 "Bitwarden vault is locked. Please unlock with 'bw unlock'".to_string(),
             ));
         }
@@ -724,7 +730,7 @@ impl SecretBackend for BitwardenBackend {
 
         // Fast unlock check — skips `bw status` if recently verified
         if !self.is_unlocked_fast().await {
-            return Err(SecretError::BackendUnavailable(
+            return Err(SecretError::VaultLocked(
                 "Bitwarden vault is locked. Please unlock with 'bw unlock'".to_string(),
             ));
         }
@@ -766,7 +772,7 @@ impl SecretBackend for BitwardenBackend {
     async fn delete(&self, connection_id: &str) -> SecretResult<()> {
         // Check if vault is unlocked
         if !self.is_unlocked_fast().await {
-            return Err(SecretError::BackendUnavailable(
+            return Err(SecretError::VaultLocked(
                 "Bitwarden vault is locked. Please unlock with 'bw unlock'".to_string(),
             ));
         }
@@ -800,6 +806,52 @@ impl SecretBackend for BitwardenBackend {
             .unwrap_or(false)
     }
 
+    async fn status(&self) -> BackendStatus {
+        let installed = bw_command(&self.bw_cmd)
+            .arg("--version")
+            .output()
+            .await
+            .is_ok_and(|o| o.status.success());
+        if !installed {
+            return BackendStatus {
+                available: false,
+                unlocked: false,
+                server_url: self.server_url.clone(),
+            };
+        }
+
+        let status = self.get_status().await.ok();
+        let server_url = self
+            .server_url
+            .clone()
+            .or_else(|| status.as_ref().and_then(|s| s.server_url.clone()));
+        let available = status
+            .as_ref()
+            .is_some_and(|s| s.status != "unauthenticated");
+        // `bw status` misreports "locked" on CLI v2026.4+ when only a session
+        // key is held, so a stored session counts as unlocked.
+        let has_session = self.session_key.is_some() || get_session_key().is_some();
+        let unlocked =
+            available && (has_session || status.as_ref().is_some_and(|s| s.status == "unlocked"));
+
+        BackendStatus {
+            available,
+            unlocked,
+            server_url,
+        }
+    }
+
+    async fn unlock(&self, password: &SecretString) -> SecretResult<()> {
+        if let Some(ref url) = self.server_url {
+            ensure_server(url).await?;
+        }
+        let session_key = unlock_vault(password).await?;
+        set_session_key(session_key);
+        mark_verified();
+        let _ = self.sync().await;
+        Ok(())
+    }
+
     fn backend_id(&self) -> &'static str {
         "bitwarden"
     }
@@ -875,7 +927,7 @@ fn unlock_vault_sync(password: &str) -> SecretResult<SecretString> {
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
         .output()
-        .map_err(|e| SecretError::ConnectionFailed(format!("Failed to run bw unlock: {e}")))?;
+        .map_err(|e| spawn_error(&bw_cmd, &e))?;
 
     if output.status.success() {
         let session_key = String::from_utf8_lossy(&output.stdout).trim().to_string();
@@ -896,7 +948,7 @@ fn unlock_vault_sync(password: &str) -> SecretResult<SecretString> {
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
         .output()
-        .map_err(|e| SecretError::ConnectionFailed(format!("Failed to run bw unlock: {e}")))?;
+        .map_err(|e| spawn_error(&bw_cmd, &e))?;
 
     if output.status.success() {
         let stdout = String::from_utf8_lossy(&output.stdout);
@@ -918,7 +970,7 @@ fn unlock_vault_sync(password: &str) -> SecretResult<SecretString> {
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
         .spawn()
-        .map_err(|e| SecretError::ConnectionFailed(format!("Failed to run bw unlock: {e}")))?;
+        .map_err(|e| spawn_error(&bw_cmd, &e))?;
 
     if let Some(mut stdin) = child.stdin.take() {
         use std::io::Write;
@@ -952,9 +1004,72 @@ fn unlock_vault_sync(password: &str) -> SecretResult<SecretString> {
         ));
     }
 
-    Err(SecretError::ConnectionFailed(format!(
-        "Failed to unlock vault: {stderr}"
-    )))
+    Err(classify_unlock_failure(&stderr))
+}
+
+/// Maps a failure to spawn `bw` to [`SecretError::ClientMissing`] when the
+/// binary does not exist, and to a generic connection error otherwise.
+fn spawn_error(bw_cmd: &str, e: &std::io::Error) -> SecretError {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        SecretError::ClientMissing(format!("Bitwarden CLI '{bw_cmd}' not found"))
+    } else {
+        SecretError::ConnectionFailed(format!("Failed to run bw unlock: {e}"))
+    }
+}
+
+/// Substrings of `bw` network failures (Node and Rust CLI variants).
+const NETWORK_MARKERS: &[&str] = &[
+    "econnrefused",
+    "enotfound",
+    "etimedout",
+    "econnreset",
+    "getaddrinfo",
+    "fetch failed",
+    "failed to fetch",
+    "network error",
+];
+
+/// Substrings of `bw` errors caused by a locked vault.
+const LOCKED_MARKERS: &[&str] = &["vault is locked", "unlock your vault"];
+
+/// Returns an unreachable-server error if `stderr` reports a network failure.
+fn network_failure(stderr: &str) -> Option<SecretError> {
+    let lower = stderr.to_lowercase();
+    NETWORK_MARKERS
+        .iter()
+        .any(|marker| lower.contains(marker))
+        .then(|| {
+            SecretError::ServerUnreachable(format!(
+                "Bitwarden server could not be reached: {}",
+                stderr.trim()
+            ))
+        })
+}
+
+/// Classifies `bw unlock` stderr into a wrong-password, unreachable-server,
+/// or generic failure so the UI can show an actionable message.
+fn classify_unlock_failure(stderr: &str) -> SecretError {
+    let lower = stderr.to_lowercase();
+    if lower.contains("invalid master password") || lower.contains("invalid password") {
+        return SecretError::InvalidPassword("Bitwarden rejected the master password".to_string());
+    }
+    if let Some(err) = network_failure(stderr) {
+        return err;
+    }
+    SecretError::ConnectionFailed(format!("Failed to unlock vault: {stderr}"))
+}
+
+/// Classifies stderr of a failed data command (`list`, `get`, `create`, …)
+/// so an unreachable server is not mistaken for a locked vault.
+fn classify_command_failure(stderr: &str) -> SecretError {
+    if let Some(err) = network_failure(stderr) {
+        return err;
+    }
+    let lower = stderr.to_lowercase();
+    if LOCKED_MARKERS.iter().any(|marker| lower.contains(marker)) {
+        return SecretError::VaultLocked(format!("Bitwarden vault is locked: {}", stderr.trim()));
+    }
+    SecretError::ConnectionFailed(format!("bw command failed: {stderr}"))
 }
 
 /// Extracts session key from verbose `bw unlock` output.
@@ -1093,9 +1208,68 @@ pub async fn configure_server(server_url: &str) -> SecretResult<()> {
         )));
     }
 
+    remember_configured_server(server_url);
     Ok(())
 }
 
+/// Normalizes a server URL for comparison (case, whitespace, trailing `/`).
+fn normalize_server_url(url: &str) -> String {
+    url.trim().trim_end_matches('/').to_lowercase()
+}
+
+/// Records the server the CLI was configured for in this process.
+fn remember_configured_server(server_url: &str) {
+    if let Ok(mut guard) = BW_CONFIGURED_SERVER.write() {
+        *guard = Some(normalize_server_url(server_url));
+    }
+}
+
+/// Points the Bitwarden CLI at `server_url` if it uses a different server.
+///
+/// The CLI's current server is read from `bw status`. `bw config server`
+/// refuses to switch while logged in, so an existing login (which belongs
+/// to the other server) is logged out first and the session key cleared.
+/// Once a URL has been checked, later calls for the same URL return without
+/// running `bw`.
+///
+/// Returns `true` if the CLI was reconfigured.
+///
+/// # Errors
+/// Returns `SecretError` if `bw status`, `bw logout` or `bw config server`
+/// fails.
+pub async fn ensure_server(server_url: &str) -> SecretResult<bool> {
+    let wanted = normalize_server_url(server_url);
+    if wanted.is_empty() {
+        return Ok(false);
+    }
+    let already = BW_CONFIGURED_SERVER
+        .read()
+        .ok()
+        .and_then(|guard| guard.clone());
+    if already.as_deref() == Some(wanted.as_str()) {
+        return Ok(false);
+    }
+
+    let status = BitwardenBackend::new().get_status().await?;
+    let current = status.server_url.as_deref().map(normalize_server_url);
+    if current.as_deref() == Some(wanted.as_str()) {
+        remember_configured_server(&wanted);
+        return Ok(false);
+    }
+
+    tracing::info!(
+        current = ?current,
+        wanted = %wanted,
+        "Bitwarden: CLI server differs from settings, reconfiguring"
+    );
+    if status.status != "unauthenticated" {
+        logout().await?;
+    }
+    clear_session_key();
+    configure_server(server_url.trim()).await?;
+    Ok(true)
+}
+
 // ============================================================================
 // Keyring storage for Bitwarden credentials
 // ============================================================================
@@ -1305,13 +1479,37 @@ async fn try_relogin_and_unlock(
 /// 4. Master password from system keyring
 /// 5. Master password from encrypted settings
 ///
+/// When `settings.bitwarden_server_url` is set, the CLI is first pointed at
+/// that server (see [`ensure_server`]).
+///
 /// # Errors
-/// Returns `SecretError::BackendUnavailable` if all strategies fail.
+/// Returns `SecretError::ServerUnreachable` if the server cannot be reached,
+/// `SecretError::VaultLocked` if no saved credential unlocks the vault, and
+/// `SecretError::BackendUnavailable` if the vault is not logged in.
+pub async fn auto_unlock(
+    settings: &crate::config::SecretSettings,
+) -> SecretResult<BitwardenBackend> {
+    let server_url = settings
+        .bitwarden_server_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|url| !url.is_empty());
+    if let Some(url) = server_url {
+        ensure_server(url).await?;
+    }
+    let backend = unlock_with_saved_credentials(settings).await?;
+    Ok(match server_url {
+        Some(url) => backend.with_server_url(url),
+        None => backend,
+    })
+}
+
+/// The unlock strategies of [`auto_unlock`], after the server is configured.
 #[expect(
     clippy::too_many_lines,
     reason = "long match/dispatch over many enum variants; splitting per variant only relocates the boilerplate"
 )] // multi-strategy unlock with ordered fallbacks
-pub async fn auto_unlock(
+async fn unlock_with_saved_credentials(
     settings: &crate::config::SecretSettings,
 ) -> SecretResult<BitwardenBackend> {
     // 0. Fast path: if session key exists and was recently verified, skip
@@ -1437,7 +1635,7 @@ pub async fn auto_unlock(
         }
     }
 
-    Err(SecretError::BackendUnavailable(
+    Err(SecretError::VaultLocked(
         "Bitwarden vault is locked. Unlock it in Settings → Secrets \
          or run 'bw unlock' in terminal."
             .to_string(),
@@ -1466,6 +1664,78 @@ impl std::fmt::Debug for BitwardenBackend {
 mod debug_tests {
     use super::*;
 
+    #[test]
+    fn unlock_failure_distinguishes_wrong_password() {
+        let err = classify_unlock_failure("Invalid master password.");
+        assert!(matches!(err, SecretError::InvalidPassword(_)));
+    }
+
+    #[test]
+    fn unlock_failure_distinguishes_unreachable_server() {
+        let err = classify_unlock_failure(
+            "request to https://vault.example.org/identity failed, reason: getaddrinfo ENOTFOUND",
+        );
+        assert!(matches!(err, SecretError::ServerUnreachable(_)));
+    }
+
+    #[test]
+    fn unlock_failure_falls_back_to_connection_error() {
+        let err = classify_unlock_failure("You are not logged in.");
+        assert!(matches!(err, SecretError::ConnectionFailed(_)));
+    }
+
+    #[test]
+    fn command_failure_separates_unreachable_from_locked() {
+        assert!(matches!(
+            classify_command_failure(
+                "FetchError: request to https://vault.internal failed, reason: getaddrinfo ENOTFOUND"
+            ),
+            SecretError::ServerUnreachable(_)
+        ));
+        assert!(matches!(
+            classify_command_failure("Vault is locked."),
+            SecretError::VaultLocked(_)
+        ));
+        assert!(matches!(
+            classify_command_failure("Not found."),
+            SecretError::ConnectionFailed(_)
+        ));
+    }
+
+    #[test]
+    fn server_urls_compare_normalized() {
+        assert_eq!(
+            normalize_server_url(" https://Vault.Internal/ "),
+            normalize_server_url("https://vault.internal")
+        );
+        assert_ne!(
+            normalize_server_url("https://vault.internal"),
+            normalize_server_url("https://vault.bitwarden.com")
+        );
+    }
+
+    #[test]
+    fn missing_binary_maps_to_client_missing() {
+        let io = std::io::Error::from(std::io::ErrorKind::NotFound);
+        assert!(matches!(
+            spawn_error("bw", &io),
+            SecretError::ClientMissing(_)
+        ));
+    }
+
+    #[test]
+    fn status_parses_server_url() {
+        let status: BitwardenStatus = serde_json::from_str(
+            r#"{"serverUrl":"https://vault.example.org","userEmail":null,"status":"locked"}"#,
+        )
+        .expect("status json");
+        assert_eq!(
+            status.server_url.as_deref(),
+            Some("https://vault.example.org")
+        );
+        assert_eq!(status.status, "locked");
+    }
+
     #[test]
     fn debug_does_not_leak_secret() {
         let session = SecretString::from("hunter2-bw-session".to_string());
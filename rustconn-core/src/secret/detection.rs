@@ -900,6 +900,17 @@ pub fn get_password_manager_launch_command(
             // launch. (Correct as-is; not a 2.5 placeholder.)
             None
         }
+        crate::config::SecretBackendType::Vault => {
+            // Vault ships a web UI under /ui on the API address
+            let address = std::env::var("VAULT_ADDR")
+                .ok()
+                .filter(|a| !a.is_empty())
+                .unwrap_or_else(|| super::DEFAULT_VAULT_ADDRESS.to_string());
+            Some((
+                url_open_command().to_string(),
+                vec![format!("{}/ui/", address.trim_end_matches('/'))],
+            ))
+        }
     }
 }
 
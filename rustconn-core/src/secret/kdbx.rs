@@ -4,14 +4,16 @@
 //! KDBX file format. Since implementing full KDBX encryption is complex, this module
 //! exports to an XML format that can be imported into `KeePassXC`.
 
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::path::Path;
 
 use chrono::Utc;
 use secrecy::{ExposeSecret, SecretString};
 
+use super::hierarchy::{KEEPASS_ROOT_GROUP, KeePassHierarchy, PATH_SEPARATOR};
 use crate::error::{SecretError, SecretResult};
-use crate::models::{Connection, Credentials};
+use crate::models::{Connection, ConnectionGroup, Credentials};
 
 /// Entry for KDBX export
 #[derive(Debug, Clone)]
@@ -26,7 +28,8 @@ pub struct KdbxEntry {
     pub url: Option<String>,
     /// Notes
     pub notes: Option<String>,
-    /// Group path (e.g., "RustConn/SSH")
+    /// Group path (e.g., "RustConn/SSH"); each `/`-separated part becomes
+    /// a nested `KeePass` group
     pub group: String,
 }
 
@@ -68,9 +71,32 @@ impl KdbxEntry {
                 .map(|s| SecretString::from(s.to_string())),
             url: Some(url),
             notes: Some(notes),
-            group: format!("RustConn/{protocol_name}"),
+            group: format!("{KEEPASS_ROOT_GROUP}/{protocol_name}"),
         }
     }
+
+    /// Creates an entry placed in the `KeePass` group mirroring the
+    /// connection's group, as resolved by [`KeePassHierarchy`]
+    #[must_use]
+    pub fn from_connection_in_groups(
+        connection: &Connection,
+        credentials: &Credentials,
+        groups: &[ConnectionGroup],
+    ) -> Self {
+        let mut entry = Self::from_connection(connection, credentials);
+        entry.group = group_path(connection.group_id, groups);
+        entry
+    }
+}
+
+/// Returns the `KeePass` group path for a connection group (or the root
+/// group for ungrouped connections)
+fn group_path(group_id: Option<uuid::Uuid>, groups: &[ConnectionGroup]) -> String {
+    let mut parts = vec![KEEPASS_ROOT_GROUP.to_string()];
+    if let Some(id) = group_id {
+        parts.extend(KeePassHierarchy::resolve_group_path(id, groups));
+    }
+    parts.join(&PATH_SEPARATOR.to_string())
 }
 
 /// KDBX exporter for credential export
@@ -80,6 +106,8 @@ impl KdbxEntry {
 pub struct KdbxExporter {
     /// Entries to export
     entries: Vec<KdbxEntry>,
+    /// Group paths written even when they hold no entries
+    group_paths: Vec<String>,
     /// Database name
     database_name: String,
 }
@@ -90,6 +118,7 @@ impl KdbxExporter {
     pub fn new(database_name: impl Into<String>) -> Self {
         Self {
             entries: Vec::new(),
+            group_paths: Vec::new(),
             database_name: database_name.into(),
         }
     }
@@ -105,6 +134,28 @@ impl KdbxExporter {
             .push(KdbxEntry::from_connection(connection, credentials));
     }
 
+    /// Mirrors the connection group tree as `KeePass` groups under
+    /// [`KEEPASS_ROOT_GROUP`], so empty groups are exported too
+    pub fn add_groups(&mut self, groups: &[ConnectionGroup]) {
+        self.group_paths
+            .extend(groups.iter().map(|g| group_path(Some(g.id), groups)));
+    }
+
+    /// Adds a connection with credentials, placed in the `KeePass` group
+    /// matching its connection group
+    pub fn add_connection_in_groups(
+        &mut self,
+        connection: &Connection,
+        credentials: &Credentials,
+        groups: &[ConnectionGroup],
+    ) {
+        self.entries.push(KdbxEntry::from_connection_in_groups(
+            connection,
+            credentials,
+            groups,
+        ));
+    }
+
     /// Exports to `KeePass` XML format
     ///
     /// # Arguments
@@ -149,86 +200,25 @@ impl KdbxExporter {
     }
 
     /// Generates the `KeePass` XML content
-    #[expect(
-        clippy::format_push_string,
-        reason = "incremental format! into String is clearer than write! macro chaining for this report builder"
-    )]
+    ///
+    /// Group paths are split on [`PATH_SEPARATOR`] and written as nested
+    /// groups, sorted by name.
     fn generate_xml(&self) -> String {
         let now = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
 
+        let mut tree = GroupNode::default();
+        for path in &self.group_paths {
+            tree.node_mut(path);
+        }
+        for entry in &self.entries {
+            tree.node_mut(&entry.group).entries.push(entry);
+        }
+
         let mut xml = String::new();
         xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
         xml.push_str("<KeePassFile>\n");
         xml.push_str("\t<Root>\n");
-        xml.push_str("\t\t<Group>\n");
-        xml.push_str(&format!(
-            "\t\t\t<Name>{}</Name>\n",
-            escape_xml(&self.database_name)
-        ));
-        xml.push_str("\t\t\t<IsExpanded>True</IsExpanded>\n");
-
-        // Group entries by their group path
-        let mut groups: std::collections::HashMap<String, Vec<&KdbxEntry>> =
-            std::collections::HashMap::new();
-
-        for entry in &self.entries {
-            groups.entry(entry.group.clone()).or_default().push(entry);
-        }
-
-        // Write groups and entries
-        for (group_path, entries) in &groups {
-            xml.push_str("\t\t\t<Group>\n");
-            xml.push_str(&format!(
-                "\t\t\t\t<Name>{}</Name>\n",
-                escape_xml(group_path)
-            ));
-            xml.push_str("\t\t\t\t<IsExpanded>True</IsExpanded>\n");
-
-            for entry in entries {
-                xml.push_str("\t\t\t\t<Entry>\n");
-                xml.push_str(&format!(
-                    "\t\t\t\t\t<String><Key>Title</Key><Value>{}</Value></String>\n",
-                    escape_xml(&entry.title)
-                ));
-
-                if let Some(username) = &entry.username {
-                    xml.push_str(&format!(
-                        "\t\t\t\t\t<String><Key>UserName</Key><Value>{}</Value></String>\n",
-                        escape_xml(username)
-                    ));
-                }
-
-                if let Some(password) = &entry.password {
-                    xml.push_str(&format!(
-                        "\t\t\t\t\t<String><Key>Password</Key><Value Protected=\"True\">{}</Value></String>\n",
-                        escape_xml(password.expose_secret())
-                    ));
-                }
-
-                if let Some(url) = &entry.url {
-                    xml.push_str(&format!(
-                        "\t\t\t\t\t<String><Key>URL</Key><Value>{}</Value></String>\n",
-                        escape_xml(url)
-                    ));
-                }
-
-                if let Some(notes) = &entry.notes {
-                    xml.push_str(&format!(
-                        "\t\t\t\t\t<String><Key>Notes</Key><Value>{}</Value></String>\n",
-                        escape_xml(notes)
-                    ));
-                }
-
-                xml.push_str(&format!(
-                    "\t\t\t\t\t<Times><CreationTime>{now}</CreationTime></Times>\n"
-                ));
-                xml.push_str("\t\t\t\t</Entry>\n");
-            }
-
-            xml.push_str("\t\t\t</Group>\n");
-        }
-
-        xml.push_str("\t\t</Group>\n");
+        tree.write_xml(&mut xml, &self.database_name, 2, &now);
         xml.push_str("\t</Root>\n");
         xml.push_str("</KeePassFile>\n");
 
@@ -254,6 +244,81 @@ impl Default for KdbxExporter {
     }
 }
 
+/// A `KeePass` group with its subgroups and entries, used while writing XML
+#[derive(Default)]
+struct GroupNode<'a> {
+    children: BTreeMap<&'a str, GroupNode<'a>>,
+    entries: Vec<&'a KdbxEntry>,
+}
+
+impl<'a> GroupNode<'a> {
+    /// Returns the node for `path`, creating missing groups along the way
+    fn node_mut(&mut self, path: &'a str) -> &mut Self {
+        path.split(PATH_SEPARATOR)
+            .filter(|part| !part.is_empty())
+            .fold(self, |node, part| node.children.entry(part).or_default())
+    }
+
+    /// Writes this group as `<Group>` at `depth` tabs of indentation
+    #[expect(
+        clippy::format_push_string,
+        reason = "incremental format! into String is clearer than write! macro chaining for this report builder"
+    )]
+    fn write_xml(&self, xml: &mut String, name: &str, depth: usize, now: &str) {
+        let tabs = "\t".repeat(depth);
+        xml.push_str(&format!("{tabs}<Group>\n"));
+        xml.push_str(&format!("{tabs}\t<Name>{}</Name>\n", escape_xml(name)));
+        xml.push_str(&format!("{tabs}\t<IsExpanded>True</IsExpanded>\n"));
+
+        for entry in &self.entries {
+            xml.push_str(&format!("{tabs}\t<Entry>\n"));
+            xml.push_str(&format!(
+                "{tabs}\t\t<String><Key>Title</Key><Value>{}</Value></String>\n",
+                escape_xml(&entry.title)
+            ));
+
+            if let Some(username) = &entry.username {
+                xml.push_str(&format!(
+                    "{tabs}\t\t<String><Key>UserName</Key><Value>{}</Value></String>\n",
+                    escape_xml(username)
+                ));
+            }
+
+            if let Some(password) = &entry.password {
+                xml.push_str(&format!(
+                    "{tabs}\t\t<String><Key>Password</Key><Value Protected=\"True\">{}</Value></String>\n",
+                    escape_xml(password.expose_secret())
+                ));
+            }
+
+            if let Some(url) = &entry.url {
+                xml.push_str(&format!(
+                    "{tabs}\t\t<String><Key>URL</Key><Value>{}</Value></String>\n",
+                    escape_xml(url)
+                ));
+            }
+
+            if let Some(notes) = &entry.notes {
+                xml.push_str(&format!(
+                    "{tabs}\t\t<String><Key>Notes</Key><Value>{}</Value></String>\n",
+                    escape_xml(notes)
+                ));
+            }
+
+            xml.push_str(&format!(
+                "{tabs}\t\t<Times><CreationTime>{now}</CreationTime></Times>\n"
+            ));
+            xml.push_str(&format!("{tabs}\t</Entry>\n"));
+        }
+
+        for (child_name, child) in &self.children {
+            child.write_xml(xml, child_name, depth + 1, now);
+        }
+
+        xml.push_str(&format!("{tabs}</Group>\n"));
+    }
+}
+
 /// Escapes special XML characters
 fn escape_xml(s: &str) -> String {
     s.replace('&', "&amp;")
@@ -308,4 +373,44 @@ mod tests {
         assert!(xml.contains("Server 1"));
         assert!(xml.contains("user1"));
     }
+
+    #[test]
+    fn test_exporter_mirrors_group_tree() {
+        let production = ConnectionGroup::new("Production".to_string());
+        let web = ConnectionGroup::with_parent("Web".to_string(), production.id);
+        let staging = ConnectionGroup::new("Staging".to_string());
+        let groups = [production.clone(), web.clone(), staging];
+
+        let mut nginx = Connection::new_ssh("nginx-01".to_string(), "10.0.0.1".to_string(), 22);
+        nginx.group_id = Some(web.id);
+        let mut db = Connection::new_ssh("db".to_string(), "10.0.0.2".to_string(), 22);
+        db.group_id = Some(production.id);
+        let loose = Connection::new_ssh("loose".to_string(), "10.0.0.3".to_string(), 22);
+
+        let mut exporter = KdbxExporter::new("Test DB");
+        exporter.add_groups(&groups);
+        for conn in [&nginx, &db, &loose] {
+            exporter.add_connection_in_groups(conn, &Credentials::default(), &groups);
+        }
+
+        let xml = exporter.generate_xml();
+        let expected = [
+            "\t\t<Group>\n\t\t\t<Name>Test DB</Name>",
+            "\t\t\t<Group>\n\t\t\t\t<Name>RustConn</Name>",
+            "\t\t\t\t\t<String><Key>Title</Key><Value>loose</Value></String>",
+            "\t\t\t\t<Group>\n\t\t\t\t\t<Name>Production</Name>",
+            "\t\t\t\t\t\t<String><Key>Title</Key><Value>db</Value></String>",
+            "\t\t\t\t\t<Group>\n\t\t\t\t\t\t<Name>Web</Name>",
+            "\t\t\t\t\t\t\t<String><Key>Title</Key><Value>nginx-01</Value></String>",
+            "\t\t\t\t<Group>\n\t\t\t\t\t<Name>Staging</Name>",
+        ];
+        let mut from = 0;
+        for needle in expected {
+            let at = xml[from..]
+                .find(needle)
+                .unwrap_or_else(|| panic!("missing {needle:?} after byte {from} in:\n{xml}"));
+            from += at + needle.len();
+        }
+        assert_eq!(xml.matches("<Name>Web</Name>").count(), 1);
+    }
 }
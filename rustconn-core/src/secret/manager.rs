@@ -226,7 +226,11 @@ impl SecretManager {
 
         match settings.preferred_backend {
             SecretBackendType::Bitwarden => {
-                backends.push(Arc::new(super::BitwardenBackend::new()));
+                let mut backend = super::BitwardenBackend::new();
+                if let Some(ref url) = settings.bitwarden_server_url {
+                    backend = backend.with_server_url(url.clone());
+                }
+                backends.push(Arc::new(backend));
             }
             SecretBackendType::OnePassword => {
                 let mut backend = super::OnePasswordBackend::new();
@@ -300,6 +304,11 @@ impl SecretManager {
                 // app-managed (flat-key) backends.
                 backends.push(Arc::new(super::EncryptedFileBackend::new()));
             }
+            SecretBackendType::Vault => {
+                backends.push(Arc::new(super::VaultBackend::from_secret_settings(
+                    settings,
+                )));
+            }
         }
 
         // Register the application-managed encrypted file as the terminal
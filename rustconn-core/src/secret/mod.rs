@@ -5,11 +5,13 @@
 //! - libsecret / macOS Keychain integration when the `system-keyring` feature is enabled
 //! - Bitwarden CLI integration
 //! - 1Password CLI integration
+//! - HashiCorp Vault KV v2 over the HTTP API
 //!
 //! The `SecretManager` provides a unified interface with automatic fallback
 //! when the primary backend is unavailable.
 
 mod async_resolver;
+mod attachment;
 mod backend;
 mod bitwarden;
 mod detection;
@@ -31,16 +33,22 @@ mod resolver;
 pub mod script_resolver;
 pub mod serde_helpers;
 mod status;
+mod vault;
 mod verification;
 
 pub use async_resolver::{
     AsyncCredentialResolver, AsyncCredentialResult, CancellationToken, PendingCredentialResolution,
     resolve_with_callback, spawn_credential_resolution,
 };
-pub use backend::{BackendAvailability, SecretBackend};
+pub use attachment::{
+    AttachmentFetcher, KeePassXcAttachments, TempIdentityFile, export_attachment_key,
+};
+pub use backend::{
+    BackendAvailability, BackendStatus, BackendStatusPoller, SecretBackend, spawn_status_poller,
+};
 pub use bitwarden::{
     BitwardenBackend, BitwardenVersion, auto_unlock, clear_session_key, configure_server,
-    delete_api_credentials_from_keyring, delete_master_password_from_keyring,
+    delete_api_credentials_from_keyring, delete_master_password_from_keyring, ensure_server,
     get_api_credentials_from_keyring, get_bitwarden_version, get_bw_cmd,
     get_master_password_from_keyring, get_session_key, lock_vault, login_with_api_key, logout,
     resolve_bw_cmd, set_bw_cmd, set_session_key, store_api_credentials_in_keyring,
@@ -81,6 +89,7 @@ pub use passbolt::{
 };
 pub use resolver::CredentialResolver;
 pub use status::{KeePassStatus, parse_keepassxc_version};
+pub use vault::{DEFAULT_VAULT_ADDRESS, DEFAULT_VAULT_MOUNT, VaultBackend};
 pub use verification::{
     CredentialStatus, CredentialVerificationManager, DialogPreFillData, VerifiedCredentials,
 };
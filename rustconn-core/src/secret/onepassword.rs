@@ -22,6 +22,19 @@ use super::backend::SecretBackend;
 use crate::error::{SecretError, SecretResult};
 use crate::models::Credentials;
 
+/// Lowercased stderr fragments `op` prints when there is no usable session
+const NOT_SIGNED_IN_MARKERS: &[&str] = &[
+    "not currently signed in",
+    "not signed in",
+    "session expired",
+    "no accounts configured",
+    "authorization prompt dismissed",
+];
+
+/// Hint shown when a command needs a 1Password session
+const SIGN_IN_HINT: &str =
+    "Not signed in to 1Password. Run 'op signin' or enable desktop app integration";
+
 /// 1Password CLI backend
 ///
 /// This backend uses the `op` command-line utility to interact with
@@ -167,9 +180,7 @@ impl OnePasswordBackend {
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(SecretError::ConnectionFailed(format!(
-                "op command failed: {stderr}"
-            )));
+            return Err(classify_command_failure(&stderr));
         }
 
         Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -211,9 +222,7 @@ impl OnePasswordBackend {
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(SecretError::ConnectionFailed(format!(
-                "op command failed: {stderr}"
-            )));
+            return Err(classify_command_failure(&stderr));
         }
 
         Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -234,6 +243,15 @@ impl OnePasswordBackend {
         self.whoami().await.is_ok()
     }
 
+    /// Fails with `SecretError::NotSignedIn` unless `op` has a usable session
+    async fn ensure_signed_in(&self) -> SecretResult<()> {
+        match self.whoami().await {
+            Ok(_) => Ok(()),
+            Err(SecretError::NotSignedIn(_)) => Err(SecretError::NotSignedIn(SIGN_IN_HINT.into())),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Gets or creates the RustConn vault
     async fn get_or_create_vault(&self) -> SecretResult<String> {
         // List vaults
@@ -322,13 +340,7 @@ impl Default for OnePasswordBackend {
 #[async_trait]
 impl SecretBackend for OnePasswordBackend {
     async fn store(&self, connection_id: &str, credentials: &Credentials) -> SecretResult<()> {
-        // Check if signed in
-        if !self.is_signed_in().await {
-            return Err(SecretError::BackendUnavailable(
-                "Not signed in to 1Password. Run 'op signin' or enable desktop app integration"
-                    .to_string(),
-            ));
-        }
+        self.ensure_signed_in().await?;
 
         // Get or create vault
         let vault_id = self.get_or_create_vault().await?;
@@ -388,13 +400,7 @@ impl SecretBackend for OnePasswordBackend {
     }
 
     async fn retrieve(&self, connection_id: &str) -> SecretResult<Option<Credentials>> {
-        // Check if signed in
-        if !self.is_signed_in().await {
-            return Err(SecretError::BackendUnavailable(
-                "Not signed in to 1Password. Run 'op signin' or enable desktop app integration"
-                    .to_string(),
-            ));
-        }
+        self.ensure_signed_in().await?;
 
         let item = match self.find_item(connection_id).await? {
             Some(item) => item,
@@ -430,13 +436,7 @@ impl SecretBackend for OnePasswordBackend {
     }
 
     async fn delete(&self, connection_id: &str) -> SecretResult<()> {
-        // Check if signed in
-        if !self.is_signed_in().await {
-            return Err(SecretError::BackendUnavailable(
-                "Not signed in to 1Password. Run 'op signin' or enable desktop app integration"
-                    .to_string(),
-            ));
-        }
+        self.ensure_signed_in().await?;
 
         let item = match self.find_item(connection_id).await? {
             Some(item) => item,
@@ -567,9 +567,7 @@ pub async fn get_onepassword_status() -> OnePasswordStatus {
         }
         Ok(output) => {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            let message = if stderr.contains("not signed in") || stderr.contains("sign in") {
-                "Not signed in"
-            } else if stderr.contains("session expired") {
+            let message = if stderr.to_lowercase().contains("session expired") {
                 "Session expired"
             } else {
                 "Not signed in"
@@ -616,6 +614,19 @@ pub async fn signout() -> SecretResult<()> {
     Ok(())
 }
 
+/// Classifies stderr of a failed `op` command so a missing session is
+/// reported as `SecretError::NotSignedIn` rather than a generic failure.
+fn classify_command_failure(stderr: &str) -> SecretError {
+    let lower = stderr.to_lowercase();
+    if NOT_SIGNED_IN_MARKERS
+        .iter()
+        .any(|marker| lower.contains(marker))
+    {
+        return SecretError::NotSignedIn(format!("{SIGN_IN_HINT}: {}", stderr.trim()));
+    }
+    SecretError::ConnectionFailed(format!("op command failed: {stderr}"))
+}
+
 // ============================================================================
 // Keyring storage for 1Password credentials
 // ============================================================================
@@ -686,4 +697,22 @@ mod debug_tests {
         assert!(rendered.contains("OnePasswordBackend"));
         assert!(rendered.contains("service_account_token_present"));
     }
+
+    #[test]
+    fn command_failure_detects_missing_session() {
+        assert!(matches!(
+            classify_command_failure(
+                "[ERROR] 2024/01/01 12:00:00 You are not currently signed in. Please run `op signin --help` for instructions"
+            ),
+            SecretError::NotSignedIn(_)
+        ));
+        assert!(matches!(
+            classify_command_failure("[ERROR] session expired, sign in to create a new session"),
+            SecretError::NotSignedIn(_)
+        ));
+        assert!(matches!(
+            classify_command_failure("[ERROR] \"nope\" isn't an item in the \"RustConn\" vault"),
+            SecretError::ConnectionFailed(_)
+        ));
+    }
 }
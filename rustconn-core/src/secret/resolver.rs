@@ -5,6 +5,7 @@
 
 use std::sync::Arc;
 
+use secrecy::SecretString;
 use tracing::{debug, warn};
 
 use super::hierarchy::KeePassHierarchy;
@@ -12,7 +13,7 @@ use super::manager::SecretManager;
 use super::verification::{CredentialStatus, CredentialVerificationManager, VerifiedCredentials};
 use crate::config::{SecretBackendType, SecretSettings};
 use crate::error::SecretResult;
-use crate::models::{Connection, ConnectionGroup, Credentials, PasswordSource};
+use crate::models::{Connection, ConnectionGroup, Credentials, PasswordSource, ProtocolConfig};
 
 /// Resolves credentials for a connection based on configuration
 ///
@@ -139,6 +140,115 @@ impl CredentialResolver {
         result
     }
 
+    /// Generates the lookup key for a connection's proxy password
+    ///
+    /// The key format is `{generate_lookup_key}/proxy`, e.g. `rustconn/web01/proxy`.
+    #[must_use]
+    pub fn generate_proxy_lookup_key(connection: &Connection) -> String {
+        format!("{}/proxy", Self::generate_lookup_key(connection))
+    }
+
+    /// Resolves the password for the connection's proxy
+    ///
+    /// Resolution based on the proxy's `password_source`:
+    /// - `Vault` → entry [`Self::generate_proxy_lookup_key`] in the secret backend
+    /// - `Variable(name)` → secret variable stored in vault
+    /// - `Script(command)` → output of the command
+    /// - anything else → `None` (the caller prompts if needed)
+    ///
+    /// # Errors
+    /// Returns `SecretError` if backend operations fail
+    pub async fn resolve_proxy_password(
+        &self,
+        connection: &Connection,
+    ) -> SecretResult<Option<SecretString>> {
+        let Some(proxy) = &connection.proxy else {
+            return Ok(None);
+        };
+        let credentials = match proxy.password_source {
+            PasswordSource::Vault => {
+                let lookup_key = Self::generate_proxy_lookup_key(connection);
+                self.secret_manager.retrieve(&lookup_key).await?
+            }
+            PasswordSource::Variable(ref name) => self.resolve_from_variable(name).await?,
+            PasswordSource::Script(ref command) => {
+                super::script_resolver::resolve_script(command).await?
+            }
+            PasswordSource::None | PasswordSource::Prompt | PasswordSource::Inherit => None,
+        };
+        Ok(credentials.and_then(|c| c.password))
+    }
+
+    /// Generates the lookup key for a connection's RD Gateway password
+    ///
+    /// The key format is `{generate_lookup_key}/gateway`, e.g. `rustconn/web01/gateway`.
+    #[must_use]
+    pub fn generate_gateway_lookup_key(connection: &Connection) -> String {
+        format!("{}/gateway", Self::generate_lookup_key(connection))
+    }
+
+    /// Resolves the credentials for the connection's RD Gateway
+    ///
+    /// Returns `None` when the connection has no gateway or the gateway
+    /// reuses the connection credentials (see
+    /// [`RdpGateway::has_own_credentials`](crate::models::RdpGateway::has_own_credentials)).
+    /// Otherwise the password is resolved from the gateway's own
+    /// `password_source`, independently of the connection's:
+    /// - `Vault` → entry [`Self::generate_gateway_lookup_key`] in the secret backend
+    /// - `Variable(name)` → secret variable stored in vault
+    /// - `Script(command)` → output of the command
+    /// - `Prompt` → no password (the caller prompts if needed)
+    ///
+    /// The username and domain always come from the gateway settings.
+    ///
+    /// # Errors
+    /// Returns `SecretError` if backend operations fail
+    pub async fn resolve_gateway_credentials(
+        &self,
+        connection: &Connection,
+    ) -> SecretResult<Option<Credentials>> {
+        let ProtocolConfig::Rdp(ref rdp) = connection.protocol_config else {
+            return Ok(None);
+        };
+        let Some(gateway) = rdp.gateway.as_ref().filter(|g| g.has_own_credentials()) else {
+            return Ok(None);
+        };
+        let resolved = match gateway.password_source {
+            PasswordSource::Vault => {
+                let lookup_key = Self::generate_gateway_lookup_key(connection);
+                self.secret_manager.retrieve(&lookup_key).await?
+            }
+            PasswordSource::Variable(ref name) => self.resolve_from_variable(name).await?,
+            PasswordSource::Script(ref command) => {
+                super::script_resolver::resolve_script(command).await?
+            }
+            PasswordSource::None | PasswordSource::Prompt | PasswordSource::Inherit => None,
+        };
+        Ok(Some(Credentials {
+            username: gateway.username.clone(),
+            password: resolved.and_then(|c| c.password),
+            key_passphrase: None,
+            domain: gateway.domain.clone(),
+        }))
+    }
+
+    /// Resolves a secret global variable for a secret expect response
+    ///
+    /// Intended to be called when the prompt appears, so the secret is only
+    /// fetched when it is actually needed.
+    ///
+    /// # Errors
+    /// Returns `SecretError` if backend operations fail
+    pub async fn resolve_secret_variable(
+        &self,
+        variable_name: &str,
+    ) -> SecretResult<Option<SecretString>> {
+        Ok(self
+            .resolve_from_variable(variable_name)
+            .await?
+            .and_then(|c| c.password))
+    }
+
     /// Resolves credentials from the configured vault backend
     ///
     /// Delegates to the appropriate backend based on `preferred_backend`
@@ -160,7 +270,7 @@ impl CredentialResolver {
             SecretBackendType::OnePassword => self.resolve_from_onepassword(connection).await,
             SecretBackendType::Passbolt => self.resolve_from_passbolt(connection).await,
             SecretBackendType::Pass => self.resolve_from_pass(connection).await,
-            SecretBackendType::EncryptedFile => {
+            SecretBackendType::EncryptedFile | SecretBackendType::Vault => {
                 // Flat-key backend: retrieve via the secret manager using the
                 // same flat lookup key as store_unified, keeping store/resolve
                 // symmetric with the other app-managed backends.
@@ -387,6 +497,7 @@ impl CredentialResolver {
             // EncryptedFile is a flat-key backend (no hierarchy); identity
             // mapping mirrors the other app-managed flat-key backends above.
             SecretBackendType::EncryptedFile => SecretBackendType::EncryptedFile,
+            SecretBackendType::Vault => SecretBackendType::Vault,
         }
     }
 
@@ -582,7 +693,8 @@ impl CredentialResolver {
             SecretBackendType::Bitwarden
             | SecretBackendType::OnePassword
             | SecretBackendType::Passbolt
-            | SecretBackendType::Pass => {
+            | SecretBackendType::Pass
+            | SecretBackendType::Vault => {
                 // For Bitwarden/1Password/Passbolt/Pass, use connection name as identifier
                 let lookup_key = Self::generate_lookup_key(connection);
                 self.secret_manager.store(&lookup_key, credentials).await
@@ -625,7 +737,8 @@ impl CredentialResolver {
             SecretBackendType::Bitwarden
             | SecretBackendType::OnePassword
             | SecretBackendType::Passbolt
-            | SecretBackendType::Pass => {
+            | SecretBackendType::Pass
+            | SecretBackendType::Vault => {
                 // Non-KeePass backends use flat keys — group hierarchy is not
                 // encoded in the lookup key, so moves/renames don't break lookups.
                 let lookup_key = Self::generate_lookup_key(connection);
@@ -885,7 +998,7 @@ impl CredentialResolver {
             SecretBackendType::OnePassword => self.resolve_from_onepassword(connection).await,
             SecretBackendType::Passbolt => self.resolve_from_passbolt(connection).await,
             SecretBackendType::Pass => self.resolve_from_pass(connection).await,
-            SecretBackendType::EncryptedFile => {
+            SecretBackendType::EncryptedFile | SecretBackendType::Vault => {
                 // Flat-key backend with no hierarchy: use the flat lookup key
                 // (symmetric with store_unified_with_hierarchy).
                 let lookup_key = Self::generate_lookup_key(connection);
@@ -1019,7 +1132,8 @@ impl CredentialResolver {
             | SecretBackendType::OnePassword
             | SecretBackendType::Passbolt
             | SecretBackendType::Pass
-            | SecretBackendType::EncryptedFile => {
+            | SecretBackendType::EncryptedFile
+            | SecretBackendType::Vault => {
                 // Flat keys — no rename needed on group move.
                 // (EncryptedFile is flat-key; grouping here is correct, not a
                 // 2.5 placeholder.)
@@ -1083,7 +1197,8 @@ impl CredentialResolver {
                     SecretBackendType::Bitwarden
                     | SecretBackendType::OnePassword
                     | SecretBackendType::Passbolt
-                    | SecretBackendType::Pass => {
+                    | SecretBackendType::Pass
+                    | SecretBackendType::Vault => {
                         // These backends use "rustconn/{name}" flat key
                         let old_key = Self::generate_lookup_key(&old_connection);
                         let new_key = Self::generate_lookup_key(connection);
@@ -1257,6 +1372,7 @@ mod tests {
             remember_window_position: false,
             window_geometry: None,
             skip_port_check: false,
+            resolve_via_tailscale: false,
             is_pinned: false,
             pin_order: 0,
             icon: None,
@@ -1269,6 +1385,13 @@ mod tests {
             retry_config: None,
             knock_sequence: None,
             spa_config: None,
+            depends_on: Vec::new(),
+            proxy: None,
+            access_tunnel: None,
+            connect_guard: None,
+            env: Vec::new(),
+            osc52_clipboard: None,
+            scrollback_lines: None,
         }
     }
 
@@ -1286,6 +1409,70 @@ mod tests {
         assert_eq!(key, "rustconn/192.168.1.1");
     }
 
+    #[test]
+    fn test_generate_proxy_lookup_key() {
+        let conn = create_test_connection("My Server", "192.168.1.1");
+        let key = CredentialResolver::generate_proxy_lookup_key(&conn);
+        assert_eq!(key, "rustconn/My Server/proxy");
+    }
+
+    #[test]
+    fn test_generate_gateway_lookup_key() {
+        let conn = create_test_connection("My Server", "192.168.1.1");
+        let key = CredentialResolver::generate_gateway_lookup_key(&conn);
+        assert_eq!(key, "rustconn/My Server/gateway");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_gateway_credentials_independently() {
+        use crate::models::{RdpConfig, RdpGateway};
+        use secrecy::ExposeSecret;
+
+        let mut conn = Connection::new_rdp("rdp".to_string(), "10.0.0.5".to_string(), 3389);
+        conn.password_source = PasswordSource::Script("echo host-secret".to_string());
+        let mut gateway = RdpGateway {
+            hostname: "gw.example.com".to_string(),
+            port: 443,
+            username: Some("gw-user".to_string()),
+            domain: Some("GW".to_string()),
+            password_source: PasswordSource::None,
+        };
+        let resolver = CredentialResolver::new(
+            Arc::new(SecretManager::new(Vec::new())),
+            SecretSettings::default(),
+        );
+
+        // A gateway without its own password source shares the session credentials
+        conn.protocol_config = ProtocolConfig::Rdp(RdpConfig {
+            gateway: Some(gateway.clone()),
+            ..RdpConfig::default()
+        });
+        assert!(
+            resolver
+                .resolve_gateway_credentials(&conn)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        gateway.password_source = PasswordSource::Script("echo gw-secret".to_string());
+        conn.protocol_config = ProtocolConfig::Rdp(RdpConfig {
+            gateway: Some(gateway),
+            ..RdpConfig::default()
+        });
+        let gw = resolver
+            .resolve_gateway_credentials(&conn)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(gw.username.as_deref(), Some("gw-user"));
+        assert_eq!(gw.domain.as_deref(), Some("GW"));
+        assert_eq!(gw.password.unwrap().expose_secret(), "gw-secret");
+
+        let host = resolver.resolve(&conn).await.unwrap().unwrap();
+        assert_eq!(host.password.unwrap().expose_secret(), "host-secret");
+    }
+
     #[test]
     fn test_generate_lookup_key_with_whitespace_name() {
         let conn = create_test_connection("   ", "example.com");
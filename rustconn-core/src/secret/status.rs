@@ -1095,6 +1095,106 @@ impl KeePassStatus {
         }
     }
 
+    /// Exports a named attachment of an entry using `keepassxc-cli attachment-export`
+    ///
+    /// The entry is looked up at `entry_path` as-is (no `RustConn/` prefix),
+    /// like [`Self::get_password_from_kdbx_exact`]. Attachment bytes are
+    /// streamed over stdout and never touch the disk here.
+    ///
+    /// # Returns
+    /// * `Ok(Some(bytes))` with the attachment contents
+    /// * `Ok(None)` if the entry or attachment does not exist, or is empty
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::KeePassXC`] if `keepassxc-cli` is not
+    /// installed or cannot be run, or the database cannot be unlocked.
+    pub fn get_attachment_from_kdbx(
+        kdbx_path: &Path,
+        db_password: Option<&SecretString>,
+        key_file: Option<&Path>,
+        entry_path: &str,
+        attachment_name: &str,
+    ) -> SecretResult<Option<zeroize::Zeroizing<Vec<u8>>>> {
+        use std::io::Write as IoWrite;
+        use std::process::Stdio;
+
+        Self::validate_kdbx_path(kdbx_path)?;
+
+        let cli_path = Self::find_keepassxc_cli().ok_or_else(|| {
+            SecretError::KeePassXC("keepassxc-cli not found. Please install KeePassXC.".to_string())
+        })?;
+
+        let mut args = vec![
+            "attachment-export".to_string(),
+            "-q".to_string(),
+            "--stdout".to_string(),
+        ];
+
+        if db_password.is_none() && key_file.is_some() {
+            args.push("--no-password".to_string());
+        }
+
+        if let Some(kf) = key_file {
+            args.push("--key-file".to_string());
+            args.push(kf.display().to_string());
+        }
+
+        args.push(kdbx_path.display().to_string());
+        args.push(entry_path.to_string());
+        args.push(attachment_name.to_string());
+
+        tracing::debug!("get_attachment: exporting '{attachment_name}' from '{entry_path}'");
+
+        let mut child = Self::keepassxc_command(&cli_path)
+            .args(&args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| SecretError::KeePassXC(format!("Failed to run keepassxc-cli: {e}")))?;
+
+        if let Some(mut stdin) = child.stdin.take()
+            && let Some(db_pwd) = db_password
+        {
+            stdin
+                .write_all(db_pwd.expose_secret().as_bytes())
+                .map_err(|e| SecretError::KeePassXC(format!("Failed to send password: {e}")))?;
+            stdin
+                .write_all(b"\n")
+                .map_err(|e| SecretError::KeePassXC(format!("Failed to send password: {e}")))?;
+        }
+
+        let output = child.wait_with_output().map_err(|e| {
+            SecretError::KeePassXC(format!("Failed to wait for keepassxc-cli: {e}"))
+        })?;
+
+        if output.status.success() {
+            let bytes = zeroize::Zeroizing::new(output.stdout);
+            if bytes.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(bytes))
+            }
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("Invalid credentials") || stderr.contains("wrong password") {
+                Err(SecretError::KeePassXC(
+                    "Invalid database password".to_string(),
+                ))
+            } else {
+                tracing::debug!(
+                    "get_attachment: '{}' on '{}' not found: exit={:?}, stderr='{}'",
+                    attachment_name,
+                    entry_path,
+                    output.status.code(),
+                    stderr.trim()
+                );
+                Ok(None)
+            }
+        }
+    }
+
     /// Renames an entry in KDBX database by moving it from old path to new path
     ///
     /// This method retrieves the entry from the old path, creates a new entry at the new path
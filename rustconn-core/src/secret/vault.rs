@@ -0,0 +1,639 @@
+//! HashiCorp Vault backend for password management
+//!
+//! Stores credentials in a Vault KV version 2 secrets engine over the HTTP
+//! API. Each connection maps to one secret whose `username` and `password`
+//! keys (plus optional `domain` and `key_passphrase`) hold the credential.
+//!
+//! # Authentication
+//!
+//! Requests carry an `X-Vault-Token` header. The token is taken from the
+//! settings, then `VAULT_TOKEN`, then the CLI's `~/.vault-token` file. With
+//! no token at all, requests go out unauthenticated, which is how a local
+//! Vault Agent listener with `use_auto_auth_token` expects to be called.
+//!
+//! # Paths
+//!
+//! A credential for `rustconn/web-01` with mount `secret` and path prefix
+//! `team/ops` is read from `/v1/secret/data/team/ops/rustconn/web-01`.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Method, StatusCode, Url};
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+
+use super::backend::{BackendAvailability, BackendStatus, SecretBackend};
+use crate::error::{SecretError, SecretResult};
+use crate::models::Credentials;
+
+/// Address used when neither the settings nor `VAULT_ADDR` name a server
+pub const DEFAULT_VAULT_ADDRESS: &str = "http://127.0.0.1:8200";
+
+/// KV v2 mount used when the settings leave it empty
+pub const DEFAULT_VAULT_MOUNT: &str = "secret";
+
+/// Per-request timeout, so an unreachable server does not stall a connect
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// HashiCorp Vault KV v2 backend
+pub struct VaultBackend {
+    /// Server address, e.g. `https://vault.example.org:8200`
+    address: String,
+    /// KV v2 mount path, e.g. `secret`
+    mount: String,
+    /// Optional path prefix inside the mount
+    path_prefix: Option<String>,
+    /// Client token; `None` relies on a Vault Agent injecting one
+    token: Option<SecretString>,
+    client: reqwest::Client,
+}
+
+/// KV v2 read response (`GET /v1/{mount}/data/{path}`)
+#[derive(Debug, Deserialize)]
+struct KvReadResponse {
+    data: KvReadData,
+}
+
+#[derive(Debug, Deserialize)]
+struct KvReadData {
+    /// `null` when the latest version has been soft-deleted
+    #[serde(default)]
+    data: Option<VaultSecret>,
+}
+
+/// Secret payload keys mapped to [`Credentials`]
+#[derive(Debug, Default, Deserialize)]
+struct VaultSecret {
+    #[serde(default)]
+    username: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_secret"
+    )]
+    password: Option<SecretString>,
+    #[serde(default)]
+    domain: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_secret"
+    )]
+    key_passphrase: Option<SecretString>,
+}
+
+/// Error body returned by the Vault API
+#[derive(Debug, Default, Deserialize)]
+struct VaultErrors {
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+impl VaultBackend {
+    /// Creates a backend for the server at `address` using the default mount
+    #[must_use]
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            mount: DEFAULT_VAULT_MOUNT.to_string(),
+            path_prefix: None,
+            token: None,
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Creates a backend from the secret settings
+    ///
+    /// Empty settings fall back to `VAULT_ADDR`, `VAULT_TOKEN` and
+    /// `~/.vault-token`, matching the `vault` CLI.
+    #[must_use]
+    pub fn from_secret_settings(settings: &crate::config::SecretSettings) -> Self {
+        let address = non_empty(settings.vault_address.as_deref())
+            .map(String::from)
+            .or_else(|| std::env::var("VAULT_ADDR").ok().filter(|a| !a.is_empty()))
+            .unwrap_or_else(|| DEFAULT_VAULT_ADDRESS.to_string());
+
+        let mut backend = Self::new(address);
+        if let Some(mount) = non_empty(settings.vault_mount.as_deref()) {
+            backend = backend.with_mount(mount);
+        }
+        if let Some(prefix) = non_empty(settings.vault_path.as_deref()) {
+            backend = backend.with_path_prefix(prefix);
+        }
+        if let Some(token) = settings.vault_token.clone().or_else(default_token) {
+            backend = backend.with_token(token);
+        }
+        backend
+    }
+
+    /// Sets the KV v2 mount path
+    #[must_use]
+    pub fn with_mount(mut self, mount: impl Into<String>) -> Self {
+        self.mount = mount.into();
+        self
+    }
+
+    /// Sets a path prefix inside the mount under which secrets are kept
+    #[must_use]
+    pub fn with_path_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.path_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Sets the client token sent as `X-Vault-Token`
+    #[must_use]
+    pub fn with_token(mut self, token: SecretString) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Returns the configured server address
+    #[must_use]
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Builds an API URL from path segments, percent-encoding each one
+    fn api_url<'a>(&self, segments: impl IntoIterator<Item = &'a str>) -> SecretResult<Url> {
+        let mut url = Url::parse(&self.address).map_err(|e| {
+            SecretError::ConnectionFailed(format!("Invalid Vault address {}: {e}", self.address))
+        })?;
+        url.path_segments_mut()
+            .map_err(|()| {
+                SecretError::ConnectionFailed(format!("Invalid Vault address {}", self.address))
+            })?
+            .pop_if_empty()
+            .push("v1")
+            .extend(segments.into_iter().filter(|s| !s.is_empty()));
+        Ok(url)
+    }
+
+    /// URL of a connection's secret under `data/` or `metadata/`
+    fn secret_url(&self, kind: &str, connection_id: &str) -> SecretResult<Url> {
+        let prefix = self.path_prefix.as_deref().unwrap_or_default();
+        self.api_url(
+            self.mount
+                .split('/')
+                .chain(std::iter::once(kind))
+                .chain(prefix.split('/'))
+                .chain(connection_id.split('/')),
+        )
+    }
+
+    /// Sends a request, attaching the token when one is configured
+    async fn send(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<String>,
+    ) -> SecretResult<reqwest::Response> {
+        let mut request = self.client.request(method, url);
+        if let Some(ref token) = self.token {
+            request = request.header("X-Vault-Token", token.expose_secret());
+        }
+        if let Some(body) = body {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+        request
+            .send()
+            .await
+            .map_err(|e| SecretError::ServerUnreachable(format!("Vault at {}: {e}", self.address)))
+    }
+
+    /// Turns a non-success response into the matching `SecretError`
+    ///
+    /// Vault answers 403 both for a bad token and for a valid token whose
+    /// policy lacks the capability; a token self-lookup tells them apart.
+    async fn error_for(&self, response: reqwest::Response, path: &str) -> SecretError {
+        let status = response.status();
+        let errors = response
+            .text()
+            .await
+            .ok()
+            .and_then(|body| serde_json::from_str::<VaultErrors>(&body).ok())
+            .unwrap_or_default()
+            .errors
+            .join("; ");
+
+        match status {
+            StatusCode::NOT_FOUND => SecretError::NotFound(path.to_string()),
+            StatusCode::UNAUTHORIZED => SecretError::AuthFailed(errors),
+            StatusCode::FORBIDDEN if self.token.is_none() => {
+                SecretError::AuthFailed("no Vault token configured".to_string())
+            }
+            StatusCode::FORBIDDEN if self.token_is_valid().await => {
+                SecretError::PermissionDenied(path.to_string())
+            }
+            StatusCode::FORBIDDEN => {
+                SecretError::AuthFailed("Vault rejected the token".to_string())
+            }
+            _ => SecretError::ConnectionFailed(format!("Vault returned {status}: {errors}")),
+        }
+    }
+
+    /// Checks the token with `auth/token/lookup-self`
+    async fn token_is_valid(&self) -> bool {
+        let Ok(url) = self.api_url(["auth", "token", "lookup-self"]) else {
+            return false;
+        };
+        self.send(Method::GET, url, None)
+            .await
+            .is_ok_and(|r| r.status().is_success())
+    }
+
+    /// Checks `sys/health`; standby nodes count as healthy
+    async fn health(&self) -> SecretResult<bool> {
+        let mut url = self.api_url(["sys", "health"])?;
+        url.set_query(Some("standbyok=true&perfstandbyok=true"));
+        let response = self.send(Method::GET, url, None).await?;
+        Ok(response.status().is_success())
+    }
+
+    /// Reads the secret for a connection
+    ///
+    /// # Errors
+    /// Returns [`SecretError::NotFound`] when no secret exists at the path,
+    /// [`SecretError::AuthFailed`] or [`SecretError::PermissionDenied`] when
+    /// Vault refuses the request.
+    async fn read(&self, connection_id: &str) -> SecretResult<Credentials> {
+        let url = self.secret_url("data", connection_id)?;
+        let path = url.path().to_string();
+        let response = self.send(Method::GET, url, None).await?;
+        if !response.status().is_success() {
+            return Err(self.error_for(response, &path).await);
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| SecretError::RetrieveFailed(e.to_string()))?;
+        let parsed: KvReadResponse = serde_json::from_str(&body).map_err(|e| {
+            SecretError::RetrieveFailed(format!("Unexpected Vault response for {path}: {e}"))
+        })?;
+        let secret = parsed
+            .data
+            .data
+            .ok_or_else(|| SecretError::NotFound(path.clone()))?;
+
+        Ok(Credentials {
+            username: secret.username.filter(|u| !u.is_empty()),
+            password: secret.password.filter(|p| !p.expose_secret().is_empty()),
+            key_passphrase: secret
+                .key_passphrase
+                .filter(|p| !p.expose_secret().is_empty()),
+            domain: secret.domain.filter(|d| !d.is_empty()),
+        })
+    }
+}
+
+impl std::fmt::Debug for VaultBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultBackend")
+            .field("address", &self.address)
+            .field("mount", &self.mount)
+            .field("path_prefix", &self.path_prefix)
+            .field("token", &self.token.as_ref().map(|_| "[REDACTED]"))
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl SecretBackend for VaultBackend {
+    async fn store(&self, connection_id: &str, credentials: &Credentials) -> SecretResult<()> {
+        let mut data = serde_json::Map::new();
+        if let Some(ref username) = credentials.username {
+            data.insert("username".into(), username.clone().into());
+        }
+        if let Some(password) = credentials.expose_password() {
+            data.insert("password".into(), password.into());
+        }
+        if let Some(ref domain) = credentials.domain {
+            data.insert("domain".into(), domain.clone().into());
+        }
+        if let Some(passphrase) = credentials.expose_key_passphrase() {
+            data.insert("key_passphrase".into(), passphrase.into());
+        }
+        let body = serde_json::json!({ "data": data }).to_string();
+
+        let url = self.secret_url("data", connection_id)?;
+        let path = url.path().to_string();
+        let response = self.send(Method::POST, url, Some(body)).await?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(self.error_for(response, &path).await)
+        }
+    }
+
+    async fn retrieve(&self, connection_id: &str) -> SecretResult<Option<Credentials>> {
+        match self.read(connection_id).await {
+            Ok(credentials) => Ok(Some(credentials)),
+            Err(SecretError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete(&self, connection_id: &str) -> SecretResult<()> {
+        // Deleting the metadata removes every version, not just the latest
+        let url = self.secret_url("metadata", connection_id)?;
+        let path = url.path().to_string();
+        let response = self.send(Method::DELETE, url, None).await?;
+        if response.status().is_success() {
+            return Ok(());
+        }
+        match self.error_for(response, &path).await {
+            SecretError::NotFound(_) => Ok(()),
+            e => Err(e),
+        }
+    }
+
+    async fn is_available(&self) -> bool {
+        self.health().await.unwrap_or(false)
+    }
+
+    async fn availability(&self) -> BackendAvailability {
+        // There is no client binary to be missing; only the server can fail
+        if self.is_available().await {
+            BackendAvailability::Available
+        } else {
+            BackendAvailability::ServiceUnavailable
+        }
+    }
+
+    async fn status(&self) -> BackendStatus {
+        let available = self.is_available().await;
+        BackendStatus {
+            available,
+            unlocked: available && self.token_is_valid().await,
+            server_url: Some(self.address.clone()),
+        }
+    }
+
+    fn backend_id(&self) -> &'static str {
+        "vault"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "HashiCorp Vault"
+    }
+}
+
+/// Returns the trimmed value when it is not blank
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// Reads the token the `vault` CLI would use: `VAULT_TOKEN`, then
+/// `~/.vault-token`
+fn default_token() -> Option<SecretString> {
+    if let Ok(token) = std::env::var("VAULT_TOKEN")
+        && !token.trim().is_empty()
+    {
+        return Some(SecretString::from(token.trim().to_owned()));
+    }
+    let content = std::fs::read_to_string(dirs::home_dir()?.join(".vault-token")).ok()?;
+    non_empty(Some(content.as_str())).map(|t| SecretString::from(t.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::task::JoinHandle;
+
+    /// Serves the canned `(status, body)` responses in order, one per
+    /// connection, and returns the raw requests it received
+    async fn mock_vault(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                requests.push(read_request(&mut stream).await);
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+                stream.shutdown().await.ok();
+            }
+            requests
+        });
+        (address, handle)
+    }
+
+    /// Reads one HTTP request, including a `Content-Length` body
+    async fn read_request(stream: &mut tokio::net::TcpStream) -> String {
+        let mut raw = Vec::new();
+        let mut buf = [0u8; 4096];
+        loop {
+            let n = stream.read(&mut buf).await.unwrap();
+            raw.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&raw).to_string();
+            if let Some(end) = text.find("\r\n\r\n") {
+                let length = text[..end]
+                    .lines()
+                    .find_map(|l| {
+                        let (name, value) = l.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length")
+                            .then(|| value.trim().parse::<usize>().ok())?
+                    })
+                    .unwrap_or(0);
+                if raw.len() >= end + 4 + length || n == 0 {
+                    return text;
+                }
+            } else if n == 0 {
+                return text;
+            }
+        }
+    }
+
+    fn backend(address: &str) -> VaultBackend {
+        VaultBackend::new(address).with_token(SecretString::from("s.test-token"))
+    }
+
+    const SECRET: &str = r#"{"data":{"data":{"username":"admin","password":"hunter2"},
+        "metadata":{"version":3}}}"#;
+    const DENIED: &str = r#"{"errors":["permission denied"]}"#;
+
+    #[tokio::test]
+    async fn test_retrieve_maps_username_and_password() {
+        let (address, server) = mock_vault(vec![(200, SECRET)]).await;
+        let creds = backend(&address)
+            .retrieve("rustconn/web-01")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(creds.username.as_deref(), Some("admin"));
+        assert_eq!(creds.expose_password(), Some("hunter2"));
+        assert!(creds.domain.is_none());
+
+        let requests = server.await.unwrap();
+        assert!(requests[0].starts_with("GET /v1/secret/data/rustconn/web-01 "));
+        assert!(
+            requests[0]
+                .to_lowercase()
+                .contains("x-vault-token: s.test-token")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mount_and_prefix_build_encoded_path() {
+        let (address, server) = mock_vault(vec![(200, SECRET)]).await;
+        backend(&address)
+            .with_mount("kv/")
+            .with_path_prefix("team/ops")
+            .retrieve("rustconn/db primary")
+            .await
+            .unwrap();
+        let requests = server.await.unwrap();
+        assert!(
+            requests[0].starts_with("GET /v1/kv/data/team/ops/rustconn/db%20primary "),
+            "{}",
+            requests[0]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_secret_is_none() {
+        let (address, _server) = mock_vault(vec![(404, r#"{"errors":[]}"#)]).await;
+        assert!(
+            backend(&address)
+                .retrieve("rustconn/nope")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_soft_deleted_secret_is_none() {
+        let (address, _server) =
+            mock_vault(vec![(200, r#"{"data":{"data":null,"metadata":{}}}"#)]).await;
+        assert!(
+            backend(&address)
+                .retrieve("rustconn/gone")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejected_token_is_auth_failure() {
+        // The read and the token self-lookup are both refused
+        let (address, server) = mock_vault(vec![(403, DENIED), (403, DENIED)]).await;
+        let err = backend(&address)
+            .retrieve("rustconn/web-01")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SecretError::AuthFailed(_)), "{err:?}");
+        let requests = server.await.unwrap();
+        assert!(requests[1].starts_with("GET /v1/auth/token/lookup-self "));
+    }
+
+    #[tokio::test]
+    async fn test_policy_denial_is_permission_denied() {
+        let (address, _server) =
+            mock_vault(vec![(403, DENIED), (200, r#"{"data":{"ttl":3600}}"#)]).await;
+        let err = backend(&address)
+            .retrieve("rustconn/web-01")
+            .await
+            .unwrap_err();
+        match err {
+            SecretError::PermissionDenied(path) => {
+                assert_eq!(path, "/v1/secret/data/rustconn/web-01");
+            }
+            other => panic!("expected PermissionDenied, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_store_writes_kv_v2_payload() {
+        let (address, server) = mock_vault(vec![(200, r#"{"data":{"version":1}}"#)]).await;
+        let creds = Credentials::with_password("deploy", "s3cret");
+        backend(&address)
+            .store("rustconn/web-01", &creds)
+            .await
+            .unwrap();
+
+        let requests = server.await.unwrap();
+        assert!(requests[0].starts_with("POST /v1/secret/data/rustconn/web-01 "));
+        let body = requests[0].split("\r\n\r\n").nth(1).unwrap();
+        let json: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(json["data"]["username"], "deploy");
+        assert_eq!(json["data"]["password"], "s3cret");
+        assert!(json["data"].get("domain").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_store_permission_denied_on_read_only_policy() {
+        let (address, _server) =
+            mock_vault(vec![(403, DENIED), (200, r#"{"data":{"ttl":0}}"#)]).await;
+        let err = backend(&address)
+            .store("rustconn/web-01", &Credentials::with_username("x"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SecretError::PermissionDenied(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_metadata_and_ignores_missing() {
+        let (address, server) = mock_vault(vec![(204, ""), (404, r#"{"errors":[]}"#)]).await;
+        let vault = backend(&address);
+        vault.delete("rustconn/web-01").await.unwrap();
+        vault.delete("rustconn/web-01").await.unwrap();
+        let requests = server.await.unwrap();
+        assert!(requests[0].starts_with("DELETE /v1/secret/metadata/rustconn/web-01 "));
+    }
+
+    #[tokio::test]
+    async fn test_no_token_sends_no_header() {
+        let (address, server) = mock_vault(vec![(200, SECRET)]).await;
+        VaultBackend::new(&address)
+            .retrieve("rustconn/web-01")
+            .await
+            .unwrap();
+        let requests = server.await.unwrap();
+        assert!(!requests[0].to_lowercase().contains("x-vault-token"));
+    }
+
+    #[tokio::test]
+    async fn test_sealed_vault_is_unavailable() {
+        let (address, server) = mock_vault(vec![(503, r#"{"sealed":true}"#)]).await;
+        assert_eq!(
+            backend(&address).availability().await,
+            BackendAvailability::ServiceUnavailable
+        );
+        let requests = server.await.unwrap();
+        assert!(requests[0].starts_with("GET /v1/sys/health?standbyok=true"));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_server() {
+        // Bind then drop a listener so the port is closed
+        let address = {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let vault = backend(&address);
+        assert!(!vault.is_available().await);
+        let err = vault.retrieve("rustconn/web-01").await.unwrap_err();
+        assert!(matches!(err, SecretError::ServerUnreachable(_)), "{err:?}");
+    }
+
+    #[test]
+    fn test_debug_does_not_leak_token() {
+        let rendered = format!("{:?}", backend("https://vault.example.org"));
+        assert!(rendered.contains("vault.example.org"));
+        assert!(!rendered.contains("s.test-token"), "{rendered}");
+        assert!(rendered.contains("[REDACTED]"));
+    }
+}
@@ -0,0 +1,205 @@
+//! Plain-text cleanup of terminal output
+//!
+//! Removes ANSI escape sequences (CSI, OSC, DCS and friends) from captured
+//! terminal output and applies carriage returns and backspaces the way a
+//! terminal would, so progress bars and redrawn prompts collapse to the text
+//! that was last visible on each line.
+
+/// Escape-sequence parser state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum State {
+    /// Plain text
+    #[default]
+    Ground,
+    /// After `ESC`
+    Escape,
+    /// After `ESC` and one or more intermediate bytes (e.g. `ESC ( B`)
+    EscapeIntermediate,
+    /// Inside a CSI sequence (`ESC [` … final byte)
+    Csi,
+    /// Inside an OSC/DCS/SOS/PM/APC string, terminated by BEL or ST
+    String,
+    /// After `ESC` inside a string (possible `ESC \` terminator)
+    StringEscape,
+}
+
+/// Streaming ANSI stripper
+///
+/// Feed output in arbitrary chunks with [`Self::push`]; escape sequences
+/// split across chunks are handled. Completed lines are returned as they
+/// finish and the line in progress is held back until a newline arrives or
+/// [`Self::finish`] is called.
+#[derive(Debug, Clone, Default)]
+pub struct AnsiStripper {
+    state: State,
+    /// Current line, as it would appear on screen
+    line: Vec<char>,
+    /// Cursor column within `line`
+    col: usize,
+}
+
+impl AnsiStripper {
+    /// Creates a stripper in the ground state
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processes a chunk of output and returns the lines it completed
+    ///
+    /// Each returned line ends with `\n`.
+    pub fn push(&mut self, input: &str) -> String {
+        let mut out = String::new();
+        for c in input.chars() {
+            self.state = match self.state {
+                State::Ground => self.ground(c, &mut out),
+                State::Escape => match c {
+                    '[' => State::Csi,
+                    ']' | 'P' | 'X' | '^' | '_' => State::String,
+                    '\u{20}'..='\u{2f}' => State::EscapeIntermediate,
+                    // Any other byte completes a two-byte sequence
+                    _ => State::Ground,
+                },
+                State::EscapeIntermediate => match c {
+                    '\u{20}'..='\u{2f}' => State::EscapeIntermediate,
+                    _ => State::Ground,
+                },
+                State::Csi => match c {
+                    // Parameter and intermediate bytes
+                    '\u{20}'..='\u{3f}' => State::Csi,
+                    // Final byte (and anything malformed) ends the sequence
+                    _ => State::Ground,
+                },
+                State::String => match c {
+                    '\u{07}' | '\u{9c}' => State::Ground,
+                    '\u{1b}' => State::StringEscape,
+                    _ => State::String,
+                },
+                State::StringEscape => match c {
+                    '\\' => State::Ground,
+                    '\u{1b}' => State::StringEscape,
+                    _ => State::String,
+                },
+            };
+        }
+        out
+    }
+
+    /// Returns the unfinished line, if any, and resets the stripper
+    pub fn finish(&mut self) -> String {
+        let rest: String = self.line.drain(..).collect();
+        self.col = 0;
+        self.state = State::Ground;
+        rest
+    }
+
+    /// Handles a character outside any escape sequence
+    fn ground(&mut self, c: char, out: &mut String) -> State {
+        match c {
+            '\u{1b}' => return State::Escape,
+            '\u{9b}' => return State::Csi,
+            '\u{90}' | '\u{98}' | '\u{9d}' | '\u{9e}' | '\u{9f}' => return State::String,
+            '\n' => {
+                out.extend(self.line.drain(..));
+                out.push('\n');
+                self.col = 0;
+            }
+            '\r' => self.col = 0,
+            '\u{08}' => self.col = self.col.saturating_sub(1),
+            '\t' => self.put('\t'),
+            c if c.is_control() => {}
+            c => self.put(c),
+        }
+        State::Ground
+    }
+
+    /// Writes a visible character at the cursor, overwriting what was there
+    fn put(&mut self, c: char) {
+        if self.col < self.line.len() {
+            self.line[self.col] = c;
+        } else {
+            self.line.push(c);
+        }
+        self.col += 1;
+    }
+}
+
+/// Strips ANSI escape sequences and collapses carriage-return overwrites
+///
+/// One-shot form of [`AnsiStripper`] for complete transcripts.
+#[must_use]
+pub fn strip_ansi(input: &str) -> String {
+    let mut stripper = AnsiStripper::new();
+    let mut out = stripper.push(input);
+    out.push_str(&stripper.finish());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_sgr_colors() {
+        assert_eq!(
+            strip_ansi("\x1b[1;31merror\x1b[0m: \x1b[38;5;208mdisk\x1b[m full\n"),
+            "error: disk full\n"
+        );
+    }
+
+    #[test]
+    fn test_strip_cursor_movement_and_erase() {
+        assert_eq!(
+            strip_ansi("\x1b[2J\x1b[H\x1b[?25lready\x1b[K\x1b[?25h"),
+            "ready"
+        );
+    }
+
+    #[test]
+    fn test_strip_osc_title_both_terminators() {
+        assert_eq!(strip_ansi("\x1b]0;user@host: ~\x07$ ls\n"), "$ ls\n");
+        assert_eq!(
+            strip_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\\n"),
+            "link\n"
+        );
+    }
+
+    #[test]
+    fn test_strip_dcs_and_charset_designation() {
+        assert_eq!(strip_ansi("\x1bP1$r0m\x1b\\\x1b(Bplain\x1b=\n"), "plain\n");
+    }
+
+    #[test]
+    fn test_carriage_return_overwrites_collapse() {
+        assert_eq!(
+            strip_ansi("Progress  10%\rProgress  55%\rProgress 100%\n"),
+            "Progress 100%\n"
+        );
+        // A shorter overwrite keeps the tail, as on a real terminal
+        assert_eq!(strip_ansi("abcdef\rXY\n"), "XYcdef\n");
+        // CRLF line endings are plain newlines
+        assert_eq!(strip_ansi("one\r\ntwo\r\n"), "one\ntwo\n");
+    }
+
+    #[test]
+    fn test_backspace_and_bell() {
+        assert_eq!(strip_ansi("pasx\x08s\x07wd\n"), "passwd\n");
+    }
+
+    #[test]
+    fn test_streaming_split_sequences() {
+        let mut stripper = AnsiStripper::new();
+        assert_eq!(stripper.push("\x1b["), "");
+        assert_eq!(stripper.push("32mok"), "");
+        assert_eq!(stripper.push("\x1b]0;ti"), "");
+        assert_eq!(stripper.push("tle\x07 done\n"), "ok done\n");
+        assert_eq!(stripper.push("partial"), "");
+        assert_eq!(stripper.finish(), "partial");
+    }
+
+    #[test]
+    fn test_plain_text_unchanged() {
+        let text = "Привіт\tworld\n";
+        assert_eq!(strip_ansi(text), text);
+    }
+}
@@ -4,14 +4,17 @@
 //! terminal output to timestamped log files with configurable rotation
 //! and retention policies.
 
+use std::borrow::Cow;
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
-use chrono::{Local, Utc};
+use chrono::{Local, SecondsFormat, Utc};
 use thiserror::Error;
 
+use super::ansi::AnsiStripper;
+use super::search::{LogSearch, SearchMatch, SearchOptions};
 use crate::variables::{VariableManager, VariableScope};
 
 /// Errors that can occur during logging operations
@@ -45,6 +48,10 @@ pub enum LogError {
     #[error("Failed to expand path template: {0}")]
     TemplateExpansion(String),
 
+    /// Invalid search pattern
+    #[error("Invalid search pattern: {0}")]
+    InvalidPattern(String),
+
     /// I/O error
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
@@ -53,6 +60,32 @@ pub enum LogError {
 /// Result type for logging operations
 pub type LogResult<T> = std::result::Result<T, LogError>;
 
+/// Form of the terminal output written to a session log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptMode {
+    /// Raw terminal output, escape sequences included
+    #[default]
+    Raw,
+    /// Plain text with escape sequences stripped (see [`super::strip_ansi`])
+    Clean,
+    /// Raw log plus a plain-text copy next to it (`<name>.clean.<ext>`)
+    Both,
+}
+
+/// Clock and format used for log line timestamps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampMode {
+    /// Local time formatted with `LogConfig::timestamp_format`
+    #[default]
+    Local,
+    /// ISO-8601 UTC with milliseconds (`2026-01-31T09:15:02.123Z`)
+    Iso8601,
+    /// Milliseconds since the Unix epoch
+    EpochMillis,
+}
+
 /// Log configuration for session logging
 ///
 /// Defines how session output should be logged, including file paths,
@@ -67,12 +100,17 @@ pub struct LogConfig {
     pub enabled: bool,
     /// Path template for log files (supports variables like `${connection_name}`, `${date}`, `${time}`, `${protocol}`)
     pub path_template: String,
-    /// Timestamp format string (strftime format)
+    /// Timestamp format string (strftime format, used by `TimestampMode::Local`)
     pub timestamp_format: String,
+    /// Clock and format for timestamps
+    pub timestamp_mode: TimestampMode,
     /// Maximum log file size in megabytes (0 = no limit)
     pub max_size_mb: u32,
     /// Number of days to retain log files (0 = no limit)
     pub retention_days: u32,
+    /// Number of size-rotated files to keep as `<log>.1` … `<log>.N`,
+    /// newest first (0 = timestamp-suffixed rotation, pruned by retention)
+    pub max_rotated_files: u32,
     /// Log terminal activity (change counts) - default mode
     pub log_activity: bool,
     /// Log user input (commands typed)
@@ -81,6 +119,8 @@ pub struct LogConfig {
     pub log_output: bool,
     /// Prepend `[HH:MM:SS]` timestamps to each log line
     pub log_timestamps: bool,
+    /// Raw output, a plain-text transcript, or both
+    pub transcript: TranscriptMode,
 }
 
 impl Default for LogConfig {
@@ -91,12 +131,15 @@ impl Default for LogConfig {
                 "${HOME}/.local/share/rustconn/logs/${connection_name}_${date}.log",
             ),
             timestamp_format: String::from("%Y-%m-%d %H:%M:%S"),
+            timestamp_mode: TimestampMode::Local,
             max_size_mb: 10,
             retention_days: 30,
+            max_rotated_files: 0,
             log_activity: true,
             log_input: false,
             log_output: false,
             log_timestamps: false,
+            transcript: TranscriptMode::Raw,
         }
     }
 }
@@ -126,6 +169,13 @@ impl LogConfig {
         self
     }
 
+    /// Sets the clock and format for timestamps
+    #[must_use]
+    pub const fn with_timestamp_mode(mut self, mode: TimestampMode) -> Self {
+        self.timestamp_mode = mode;
+        self
+    }
+
     /// Sets the maximum log file size in megabytes
     #[must_use]
     pub const fn with_max_size_mb(mut self, max_size_mb: u32) -> Self {
@@ -140,6 +190,13 @@ impl LogConfig {
         self
     }
 
+    /// Sets how many numbered rotated files to keep (0 = timestamped rotation)
+    #[must_use]
+    pub const fn with_max_rotated_files(mut self, max_rotated_files: u32) -> Self {
+        self.max_rotated_files = max_rotated_files;
+        self
+    }
+
     /// Sets whether to log terminal activity (change counts)
     #[must_use]
     pub const fn with_log_activity(mut self, enabled: bool) -> Self {
@@ -168,6 +225,13 @@ impl LogConfig {
         self
     }
 
+    /// Sets whether to write raw output, a plain-text transcript, or both
+    #[must_use]
+    pub const fn with_transcript(mut self, transcript: TranscriptMode) -> Self {
+        self.transcript = transcript;
+        self
+    }
+
     /// Validates the configuration
     ///
     /// # Errors
@@ -190,16 +254,19 @@ impl serde::Serialize for LogConfig {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("LogConfig", 9)?;
+        let mut state = serializer.serialize_struct("LogConfig", 12)?;
         state.serialize_field("enabled", &self.enabled)?;
         state.serialize_field("path_template", &self.path_template)?;
         state.serialize_field("timestamp_format", &self.timestamp_format)?;
+        state.serialize_field("timestamp_mode", &self.timestamp_mode)?;
         state.serialize_field("max_size_mb", &self.max_size_mb)?;
         state.serialize_field("retention_days", &self.retention_days)?;
+        state.serialize_field("max_rotated_files", &self.max_rotated_files)?;
         state.serialize_field("log_activity", &self.log_activity)?;
         state.serialize_field("log_input", &self.log_input)?;
         state.serialize_field("log_output", &self.log_output)?;
         state.serialize_field("log_timestamps", &self.log_timestamps)?;
+        state.serialize_field("transcript", &self.transcript)?;
         state.end()
     }
 }
@@ -218,8 +285,12 @@ impl<'de> serde::Deserialize<'de> for LogConfig {
             enabled: bool,
             path_template: String,
             timestamp_format: String,
+            #[serde(default)]
+            timestamp_mode: TimestampMode,
             max_size_mb: u32,
             retention_days: u32,
+            #[serde(default)]
+            max_rotated_files: u32,
             #[serde(default = "default_log_activity")]
             log_activity: bool,
             #[serde(default)]
@@ -228,6 +299,8 @@ impl<'de> serde::Deserialize<'de> for LogConfig {
             log_output: bool,
             #[serde(default)]
             log_timestamps: bool,
+            #[serde(default)]
+            transcript: TranscriptMode,
         }
 
         fn default_log_activity() -> bool {
@@ -239,12 +312,15 @@ impl<'de> serde::Deserialize<'de> for LogConfig {
             enabled: helper.enabled,
             path_template: helper.path_template,
             timestamp_format: helper.timestamp_format,
+            timestamp_mode: helper.timestamp_mode,
             max_size_mb: helper.max_size_mb,
             retention_days: helper.retention_days,
+            max_rotated_files: helper.max_rotated_files,
             log_activity: helper.log_activity,
             log_input: helper.log_input,
             log_output: helper.log_output,
             log_timestamps: helper.log_timestamps,
+            transcript: helper.transcript,
         })
     }
 }
@@ -296,6 +372,14 @@ pub struct SessionLogger {
     bytes_written: u64,
     /// Rotation counter for current session
     rotation_count: u32,
+    /// Files rotated out during this session, oldest first
+    rotated_paths: Vec<PathBuf>,
+    /// Escape-sequence stripper when this log is a plain-text transcript
+    stripper: Option<AnsiStripper>,
+    /// Plain-text copy written alongside a raw log (`TranscriptMode::Both`)
+    companion: Option<Box<Self>>,
+    /// Whether the next byte written starts a new line (and needs a stamp)
+    at_line_start: bool,
 }
 
 impl SessionLogger {
@@ -324,6 +408,10 @@ impl SessionLogger {
                 writer: None,
                 bytes_written: 0,
                 rotation_count: 0,
+                rotated_paths: Vec::new(),
+                stripper: None,
+                companion: None,
+                at_line_start: true,
             });
         }
 
@@ -338,7 +426,24 @@ impl SessionLogger {
             })?;
         }
 
-        // Create the log file
+        let companion = if config.transcript == TranscriptMode::Both {
+            let clean_config = LogConfig {
+                transcript: TranscriptMode::Clean,
+                ..config.clone()
+            };
+            let clean_path = clean_transcript_path(&log_path);
+            Some(Box::new(Self::open(clean_config, clean_path)?))
+        } else {
+            None
+        };
+
+        let mut logger = Self::open(config, log_path)?;
+        logger.companion = companion;
+        Ok(logger)
+    }
+
+    /// Opens (or creates) the log file at an already expanded path
+    fn open(config: LogConfig, log_path: PathBuf) -> LogResult<Self> {
         let file = OpenOptions::new()
             .create(true)
             .append(true)
@@ -351,6 +456,7 @@ impl SessionLogger {
 
         // Get current file size
         let bytes_written = fs::metadata(&log_path).map(|m| m.len()).unwrap_or(0);
+        let stripper = (config.transcript == TranscriptMode::Clean).then(AnsiStripper::new);
 
         Ok(Self {
             config,
@@ -358,6 +464,10 @@ impl SessionLogger {
             writer: Some(writer),
             bytes_written,
             rotation_count: 0,
+            rotated_paths: Vec::new(),
+            stripper,
+            companion: None,
+            at_line_start: true,
         })
     }
 
@@ -445,7 +555,11 @@ impl SessionLogger {
     /// Returns the current timestamp formatted according to config
     #[must_use]
     pub fn current_timestamp(&self) -> String {
-        self.format_timestamp(&self.config.timestamp_format)
+        match self.config.timestamp_mode {
+            TimestampMode::Local => self.format_timestamp(&self.config.timestamp_format),
+            TimestampMode::Iso8601 => Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            TimestampMode::EpochMillis => Utc::now().timestamp_millis().to_string(),
+        }
     }
 
     /// Returns the log file path
@@ -487,32 +601,53 @@ impl SessionLogger {
             return Err(LogError::WriteError("Log file not open".to_string()));
         }
 
+        if let Some(companion) = self.companion.as_mut() {
+            companion.write(data)?;
+        }
+
         // Check if rotation is needed before writing
         self.rotate_if_needed()?;
 
-        // Write lines, optionally with timestamp prefix
+        // Write lines, optionally with timestamp prefix. A plain-text
+        // transcript only receives lines once they are complete.
         let data_str = String::from_utf8_lossy(data);
+        let text = match self.stripper.as_mut() {
+            Some(stripper) => Cow::Owned(stripper.push(&data_str)),
+            None => data_str,
+        };
 
-        for line in data_str.lines() {
-            let formatted = if self.config.log_timestamps {
+        // A line split across writes is stamped once, when it starts
+        let mut formatted = String::with_capacity(text.len());
+        for segment in text.split_inclusive('\n') {
+            if self.config.log_timestamps && self.at_line_start {
                 let timestamp = self.current_timestamp();
-                format!("[{timestamp}] {line}\n")
-            } else {
-                format!("{line}\n")
-            };
-            let bytes = formatted.as_bytes();
+                formatted.push_str(&format!("[{timestamp}] "));
+            }
+            match segment.strip_suffix('\n') {
+                Some(line) => {
+                    formatted.push_str(line.strip_suffix('\r').unwrap_or(line));
+                    formatted.push('\n');
+                    self.at_line_start = true;
+                }
+                None => {
+                    formatted.push_str(segment);
+                    self.at_line_start = false;
+                }
+            }
+        }
+        let bytes = formatted.as_bytes();
 
-            // Get writer (may have changed after rotation)
-            let writer = self.writer.as_mut().ok_or_else(|| {
-                LogError::WriteError("Log file not open after rotation".to_string())
-            })?;
+        // Get writer (may have changed after rotation)
+        let writer = self
+            .writer
+            .as_mut()
+            .ok_or_else(|| LogError::WriteError("Log file not open after rotation".to_string()))?;
 
-            writer
-                .write_all(bytes)
-                .map_err(|e| LogError::WriteError(format!("Failed to write: {e}")))?;
+        writer
+            .write_all(bytes)
+            .map_err(|e| LogError::WriteError(format!("Failed to write: {e}")))?;
 
-            self.bytes_written += bytes.len() as u64;
-        }
+        self.bytes_written += bytes.len() as u64;
 
         Ok(())
     }
@@ -527,9 +662,22 @@ impl SessionLogger {
             return Ok(());
         }
 
+        if let Some(companion) = self.companion.as_mut() {
+            companion.write_raw(data)?;
+        }
+
         // Check if rotation is needed before writing
         self.rotate_if_needed()?;
 
+        let cleaned;
+        let data = match self.stripper.as_mut() {
+            Some(stripper) => {
+                cleaned = stripper.push(&String::from_utf8_lossy(data));
+                cleaned.as_bytes()
+            }
+            None => data,
+        };
+
         let writer = self
             .writer
             .as_mut()
@@ -549,6 +697,9 @@ impl SessionLogger {
     ///
     /// Returns an error if flushing fails.
     pub fn flush(&mut self) -> LogResult<()> {
+        if let Some(companion) = self.companion.as_mut() {
+            companion.flush()?;
+        }
         if let Some(writer) = self.writer.as_mut() {
             writer
                 .flush()
@@ -574,7 +725,13 @@ impl SessionLogger {
 
     /// Rotates the log file
     ///
-    /// Creates a new log file with a rotation suffix and updates the writer.
+    /// Moves the current log aside and starts a new one. With
+    /// `max_rotated_files` set, older files shift to `<log>.1` … `<log>.N`
+    /// and the oldest is dropped; otherwise the rotated file gets a
+    /// timestamp suffix and old logs are pruned by the retention policy.
+    ///
+    /// Every step is a single `rename`, so a crash mid-rotation leaves the
+    /// active log either in place or as `<log>.1`, never deleted.
     ///
     /// # Errors
     ///
@@ -584,24 +741,23 @@ impl SessionLogger {
             return Ok(());
         }
 
+        if let Some(companion) = self.companion.as_mut() {
+            companion.rotate()?;
+        }
+
         // Flush and close current file
         self.flush()?;
         self.writer = None;
-
-        // Generate rotated filename
         self.rotation_count += 1;
-        let rotated_path = self.generate_rotated_path();
 
-        // Rename current log to rotated name
-        if self.log_path.exists() {
-            fs::rename(&self.log_path, &rotated_path).map_err(|e| {
-                LogError::RotationError(format!(
-                    "Failed to rename {} to {}: {}",
-                    self.log_path.display(),
-                    rotated_path.display(),
-                    e
-                ))
-            })?;
+        let keep = self.config.max_rotated_files;
+        if keep > 0 {
+            self.rotate_numbered(keep)?;
+        } else if self.log_path.exists() {
+            // Rename current log to a timestamped name
+            let rotated_path = self.generate_rotated_path();
+            rename_log(&self.log_path, &rotated_path)?;
+            self.rotated_paths.push(rotated_path);
         }
 
         // Create new log file
@@ -619,13 +775,55 @@ impl SessionLogger {
 
         self.writer = Some(BufWriter::new(file));
         self.bytes_written = 0;
+        self.at_line_start = true;
 
-        // Clean up old rotated files based on retention policy
-        self.cleanup_old_logs();
+        // Numbered rotation is bounded by count; timestamped files by age
+        if keep == 0 {
+            self.cleanup_old_logs();
+        }
 
         Ok(())
     }
 
+    /// Shifts `<log>.1` … `<log>.N-1` up by one and moves the active log
+    /// to `<log>.1`
+    ///
+    /// Renaming onto `<log>.N` replaces the oldest file in the same step.
+    fn rotate_numbered(&mut self, keep: u32) -> LogResult<()> {
+        // Files beyond the limit are left over from a larger setting
+        for n in keep + 1.. {
+            let path = self.numbered_path(n);
+            if !path.exists() {
+                break;
+            }
+            fs::remove_file(&path).map_err(|e| {
+                LogError::RotationError(format!("Failed to remove {}: {e}", path.display()))
+            })?;
+        }
+
+        for n in (1..keep).rev() {
+            let from = self.numbered_path(n);
+            if from.exists() {
+                rename_log(&from, &self.numbered_path(n + 1))?;
+            }
+        }
+
+        if self.log_path.exists() {
+            rename_log(&self.log_path, &self.numbered_path(1))?;
+            // This session's rotated files moved up by one slot
+            let count = (self.rotated_paths.len() + 1).min(keep as usize) as u32;
+            self.rotated_paths = (1..=count).rev().map(|n| self.numbered_path(n)).collect();
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the `n`th numbered rotated file (`<log>.n`)
+    fn numbered_path(&self, n: u32) -> PathBuf {
+        let mut name = self.log_path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".{n}"));
+        self.log_path.with_file_name(name)
+    }
+
     /// Generates a path for a rotated log file
     fn generate_rotated_path(&self) -> PathBuf {
         let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
@@ -675,13 +873,66 @@ impl SessionLogger {
         }
     }
 
+    /// Searches everything logged during this session
+    ///
+    /// Scans files rotated out during this session, then the live log, so
+    /// history beyond the terminal's scrollback is included. Pending output
+    /// is flushed first. Line numbers and byte ranges refer to log lines,
+    /// including the `[timestamp]` prefix when `log_timestamps` is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query is an invalid regex or the log cannot
+    /// be read.
+    pub fn search(&mut self, query: &str, options: SearchOptions) -> LogResult<Vec<SearchMatch>> {
+        self.search_incremental(query, options)?.collect_all()
+    }
+
+    /// Starts an incremental search over everything logged during this session
+    ///
+    /// Drive the returned [`LogSearch`] with `next_batch` from an idle
+    /// callback to keep the UI responsive on very large logs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query is an invalid regex or pending output
+    /// cannot be flushed.
+    pub fn search_incremental(
+        &mut self,
+        query: &str,
+        options: SearchOptions,
+    ) -> LogResult<LogSearch> {
+        self.flush()?;
+        let files = if self.config.enabled {
+            self.rotated_paths
+                .iter()
+                .cloned()
+                .chain(std::iter::once(self.log_path.clone()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        LogSearch::new(files, query, options)
+    }
+
     /// Closes the log file, flushing any buffered data
     ///
     /// # Errors
     ///
     /// Returns an error if flushing fails.
     pub fn close(&mut self) -> LogResult<()> {
+        if let Some(mut companion) = self.companion.take() {
+            companion.close()?;
+        }
         if let Some(mut writer) = self.writer.take() {
+            // Emit a transcript's unfinished last line
+            if let Some(stripper) = self.stripper.as_mut() {
+                let rest = stripper.finish();
+                if !rest.is_empty() {
+                    let _ = writer.write_all(format!("{rest}\n").as_bytes());
+                }
+            }
+
             // Write session end marker
             let timestamp = self.current_timestamp();
             let end_marker = format!("\n[{timestamp}] === Session ended ===\n");
@@ -702,6 +953,32 @@ impl Drop for SessionLogger {
     }
 }
 
+/// Returns the path of the plain-text copy kept next to a raw log
+///
+/// `session.log` becomes `session.clean.log`.
+fn clean_transcript_path(log_path: &Path) -> PathBuf {
+    let stem = log_path
+        .file_stem()
+        .map_or_else(|| "log".to_string(), |s| s.to_string_lossy().to_string());
+    let name = match log_path.extension() {
+        Some(ext) => format!("{stem}.clean.{}", ext.to_string_lossy()),
+        None => format!("{stem}.clean"),
+    };
+    log_path.with_file_name(name)
+}
+
+/// Renames a log file, mapping failures to `LogError::RotationError`
+fn rename_log(from: &Path, to: &Path) -> LogResult<()> {
+    fs::rename(from, to).map_err(|e| {
+        LogError::RotationError(format!(
+            "Failed to rename {} to {}: {}",
+            from.display(),
+            to.display(),
+            e
+        ))
+    })
+}
+
 /// Sanitizes a filename by removing or replacing invalid characters
 fn sanitize_filename(name: &str) -> String {
     name.chars()
@@ -1085,13 +1362,162 @@ mod tests {
         assert!(log_content.contains("Session ended"));
     }
 
+    #[test]
+    fn test_session_logger_search_includes_rotated_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("test.log");
+
+        let config = LogConfig::new(log_path.to_string_lossy().to_string()).with_enabled(true);
+        let log_ctx = LogContext::new("test", "ssh");
+
+        let mut logger = SessionLogger::new(config, &log_ctx, None).unwrap();
+        logger.write(b"first Needle\nfiller").unwrap();
+        logger.rotate().unwrap();
+        logger.write(b"second needle").unwrap();
+
+        let matches = logger.search("needle", SearchOptions::default()).unwrap();
+        assert_eq!(
+            matches,
+            vec![
+                SearchMatch {
+                    line: 0,
+                    byte_range: 6..12
+                },
+                SearchMatch {
+                    line: 2,
+                    byte_range: 7..13
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_session_logger_numbered_rotation_keeps_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("session.log");
+
+        let config = LogConfig::new(log_path.to_string_lossy().to_string())
+            .with_max_size_mb(1)
+            .with_max_rotated_files(2);
+        let log_ctx = LogContext::new("test", "ssh");
+        let mut logger = SessionLogger::new(config, &log_ctx, None).unwrap();
+
+        // Each chunk fills the 1 MB cap, so every later write rotates first
+        let chunk = vec![b'x'; 1024 * 1024];
+        for marker in [b"first\n", b"secnd\n", b"third\n", b"forth\n"] {
+            logger.write_raw(marker).unwrap();
+            logger.write_raw(&chunk).unwrap();
+        }
+        logger.flush().unwrap();
+
+        let rotated = |n: u32| temp_dir.path().join(format!("session.log.{n}"));
+        assert!(rotated(1).exists());
+        assert!(rotated(2).exists());
+        assert!(!rotated(3).exists());
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 3);
+
+        // Newest rotated file is .1; the oldest ("first") was dropped
+        assert!(fs::read(rotated(1)).unwrap().starts_with(b"third"));
+        assert!(fs::read(rotated(2)).unwrap().starts_with(b"secnd"));
+        assert!(fs::read(&log_path).unwrap().starts_with(b"forth"));
+        assert_eq!(logger.rotated_paths, vec![rotated(2), rotated(1)]);
+    }
+
+    #[test]
+    fn test_session_logger_numbered_rotation_drops_files_beyond_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("session.log");
+        for n in 1..=4 {
+            fs::write(temp_dir.path().join(format!("session.log.{n}")), b"old").unwrap();
+        }
+
+        let config =
+            LogConfig::new(log_path.to_string_lossy().to_string()).with_max_rotated_files(2);
+        let log_ctx = LogContext::new("test", "ssh");
+        let mut logger = SessionLogger::new(config, &log_ctx, None).unwrap();
+        logger.write_raw(b"active").unwrap();
+        logger.rotate().unwrap();
+
+        assert_eq!(
+            fs::read(temp_dir.path().join("session.log.1")).unwrap(),
+            b"active"
+        );
+        assert!(temp_dir.path().join("session.log.2").exists());
+        assert!(!temp_dir.path().join("session.log.3").exists());
+        assert!(!temp_dir.path().join("session.log.4").exists());
+    }
+
+    #[test]
+    fn test_session_logger_timestamped_rotation_unchanged() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("session.log");
+
+        let config = LogConfig::new(log_path.to_string_lossy().to_string());
+        let log_ctx = LogContext::new("test", "ssh");
+        let mut logger = SessionLogger::new(config, &log_ctx, None).unwrap();
+        logger.write_raw(b"before").unwrap();
+        logger.rotate().unwrap();
+
+        assert!(!temp_dir.path().join("session.log.1").exists());
+        assert_eq!(logger.rotated_paths.len(), 1);
+        let name = logger.rotated_paths[0]
+            .file_name()
+            .unwrap()
+            .to_string_lossy();
+        assert!(
+            name.starts_with("session.") && name.ends_with(".1.log"),
+            "{name}"
+        );
+    }
+
+    #[test]
+    fn test_session_logger_clean_transcript() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("session.log");
+
+        let config = LogConfig::new(log_path.to_string_lossy().to_string())
+            .with_transcript(TranscriptMode::Clean);
+        let log_ctx = LogContext::new("test", "ssh");
+        let mut logger = SessionLogger::new(config, &log_ctx, None).unwrap();
+        logger
+            .write(b"\x1b]0;host\x07\x1b[32mok\x1b[0m\r\n10%\r")
+            .unwrap();
+        logger.write(b"100%\n$ ").unwrap();
+        logger.close().unwrap();
+
+        let content = fs::read_to_string(&log_path).unwrap();
+        assert!(content.starts_with("ok\n100%\n$ \n"), "{content:?}");
+        assert!(!content.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_session_logger_both_transcripts() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("session.log");
+
+        let config = LogConfig::new(log_path.to_string_lossy().to_string())
+            .with_transcript(TranscriptMode::Both);
+        let log_ctx = LogContext::new("test", "ssh");
+        let mut logger = SessionLogger::new(config, &log_ctx, None).unwrap();
+        logger.write_raw(b"\x1b[1mbold\x1b[0m\n").unwrap();
+        logger.flush().unwrap();
+
+        let raw = fs::read(&log_path).unwrap();
+        assert_eq!(raw, b"\x1b[1mbold\x1b[0m\n");
+        let clean = fs::read(temp_dir.path().join("session.clean.log")).unwrap();
+        assert_eq!(clean, b"bold\n");
+    }
+
     #[test]
     fn test_log_config_serialization() {
         let config = LogConfig::new("/tmp/test.log")
             .with_enabled(true)
             .with_timestamp_format("%H:%M:%S")
             .with_max_size_mb(5)
-            .with_retention_days(7);
+            .with_retention_days(7)
+            .with_max_rotated_files(3)
+            .with_transcript(TranscriptMode::Both)
+            .with_timestamp_mode(TimestampMode::EpochMillis);
 
         let json = serde_json::to_string(&config).unwrap();
         let parsed: LogConfig = serde_json::from_str(&json).unwrap();
@@ -1118,6 +1544,91 @@ mod tests {
         assert_eq!(timestamp.chars().nth(7), Some('-'));
     }
 
+    fn read_stamped_lines(mode: TimestampMode, writes: &[&[u8]]) -> Vec<String> {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("test.log");
+
+        let config = LogConfig::new(log_path.to_string_lossy().to_string())
+            .with_log_timestamps(true)
+            .with_timestamp_mode(mode);
+        let context = LogContext::new("test", "ssh");
+        let mut logger = SessionLogger::new(config, &context, None).unwrap();
+        for data in writes {
+            logger.write(data).unwrap();
+        }
+        logger.flush().unwrap();
+
+        fs::read_to_string(&log_path)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Splits `[timestamp] text` into its parts
+    fn split_stamp(line: &str) -> (&str, &str) {
+        let rest = line.strip_prefix('[').expect("line is stamped");
+        rest.split_once("] ").expect("stamp is closed")
+    }
+
+    #[test]
+    fn test_timestamps_split_line_writes_stamped_once() {
+        let lines = read_stamped_lines(
+            TimestampMode::Iso8601,
+            &[b"hel", b"lo\r\nwor", b"ld\n", b"$ ", b"ls\n"],
+        );
+
+        let texts: Vec<&str> = lines.iter().map(|l| split_stamp(l).1).collect();
+        assert_eq!(texts, ["hello", "world", "$ ls"]);
+        assert!(
+            lines.iter().all(|l| l.matches('[').count() == 1),
+            "{lines:?}"
+        );
+    }
+
+    #[test]
+    fn test_timestamps_partial_line_not_terminated() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("test.log");
+
+        let config = LogConfig::new(log_path.to_string_lossy().to_string())
+            .with_log_timestamps(true)
+            .with_timestamp_mode(TimestampMode::EpochMillis);
+        let context = LogContext::new("test", "ssh");
+        let mut logger = SessionLogger::new(config, &context, None).unwrap();
+        logger.write(b"user@host:~$ ").unwrap();
+        logger.flush().unwrap();
+
+        let content = fs::read_to_string(&log_path).unwrap();
+        assert!(content.ends_with("] user@host:~$ "), "{content:?}");
+        assert!(!content.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_timestamp_mode_iso8601_utc() {
+        let lines = read_stamped_lines(TimestampMode::Iso8601, &[b"one\n"]);
+        let (stamp, text) = split_stamp(&lines[0]);
+
+        assert_eq!(text, "one");
+        assert!(stamp.ends_with('Z'), "{stamp}");
+        // 2026-01-31T09:15:02.123Z
+        assert_eq!(stamp.len(), 24, "{stamp}");
+        assert!(chrono::DateTime::parse_from_rfc3339(stamp).is_ok());
+    }
+
+    #[test]
+    fn test_timestamp_mode_epoch_millis() {
+        let before = Utc::now().timestamp_millis();
+        let lines = read_stamped_lines(TimestampMode::EpochMillis, &[b"one\ntwo\n"]);
+        let after = Utc::now().timestamp_millis();
+
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            let millis: i64 = split_stamp(line).0.parse().unwrap();
+            assert!((before..=after).contains(&millis));
+        }
+    }
+
     #[test]
     fn test_sanitize_output_disabled() {
         let config = SanitizeConfig::disabled();
@@ -11,6 +11,7 @@ use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 use super::logger::{LogConfig, LogContext, SessionLogger};
+use super::search::{SearchMatch, SearchOptions};
 use super::session::{Session, SessionState, SessionType};
 use crate::error::{SessionError, SessionResult};
 use crate::models::Connection;
@@ -463,6 +464,26 @@ impl SessionManager {
         Ok(())
     }
 
+    /// Searches a session's logged output (full history, not just the viewport)
+    ///
+    /// # Errors
+    /// Returns an error if the session has no logger, the query is an
+    /// invalid regex, or the log cannot be read
+    pub fn search_session_log(
+        &mut self,
+        session_id: Uuid,
+        query: &str,
+        options: SearchOptions,
+    ) -> SessionResult<Vec<SearchMatch>> {
+        let logger = self
+            .session_loggers
+            .get_mut(&session_id)
+            .ok_or_else(|| SessionError::NotFound(session_id.to_string()))?;
+        logger
+            .search(query, options)
+            .map_err(|e| SessionError::LoggingError(format!("Failed to search log: {e}")))
+    }
+
     /// Checks if logging is enabled for a session
     #[must_use]
     pub fn is_logging_enabled_for_session(&self, session_id: Uuid) -> bool {
@@ -3,20 +3,24 @@
 //! This module provides session lifecycle management for active connections,
 //! including process handling, logging, and terminal integration.
 
+mod ansi;
 mod logger;
 mod manager;
 /// Session recording in `scriptreplay`-compatible format.
 pub mod recording;
 mod restore;
+mod search;
+mod serial_capture;
 #[expect(
     clippy::module_inception,
     reason = "internal `mod foo` inside `foo.rs` keeps the file private and re-exports curated items"
 )]
 mod session;
 
+pub use ansi::{AnsiStripper, strip_ansi};
 pub use logger::{
-    LogConfig, LogContext, LogError, LogResult, SanitizeConfig, SessionLogger,
-    contains_sensitive_prompt, sanitize_output,
+    LogConfig, LogContext, LogError, LogResult, SanitizeConfig, SessionLogger, TimestampMode,
+    TranscriptMode, contains_sensitive_prompt, sanitize_output,
 };
 pub use manager::{
     DEFAULT_HEALTH_CHECK_INTERVAL_SECS, HealthCheckConfig, HealthCheckEvent, HealthStatus,
@@ -26,4 +30,9 @@ pub use restore::{
     PanelRestoreData, RESTORE_STATE_VERSION, SessionRestoreData, SessionRestoreError,
     SessionRestoreState, SplitLayoutRestoreData,
 };
+pub use search::{DEFAULT_SEARCH_BATCH_LINES, LogSearch, SearchMatch, SearchOptions};
+pub use serial_capture::{
+    CaptureDirection, DEFAULT_SERIAL_CAPTURE_TEMPLATE, SerialCapture, SerialCaptureTap,
+    format_capture_record,
+};
 pub use session::{Session, SessionState, SessionType};
@@ -0,0 +1,275 @@
+//! Scrollback search over session logs
+//!
+//! The terminal widget only keeps a bounded scrollback, but the session
+//! logger sees every byte of output. [`LogSearch`] scans a session's log
+//! files (rotated files first, then the live one) for a literal string or
+//! regex and reports matches as line numbers plus byte ranges the GUI can
+//! scroll to and highlight. Scanning is incremental: each call to
+//! [`LogSearch::next_batch`] reads a bounded number of lines, so very large
+//! logs can be searched from an idle callback without blocking the UI.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::ops::Range;
+use std::path::PathBuf;
+
+use regex::bytes::{Regex, RegexBuilder};
+
+use super::logger::{LogError, LogResult};
+
+/// Default number of lines scanned per [`LogSearch::next_batch`] call
+pub const DEFAULT_SEARCH_BATCH_LINES: usize = 10_000;
+
+/// Options controlling how a scrollback search matches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// Match case exactly (default: case-insensitive)
+    pub case_sensitive: bool,
+    /// Treat the query as a regular expression instead of a literal string
+    pub regex: bool,
+}
+
+/// A single search hit in the session log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Zero-based line number across all of the session's log files
+    pub line: usize,
+    /// Byte range of the match within the line (newline excluded)
+    pub byte_range: Range<usize>,
+}
+
+/// Incremental search over a sequence of log files
+///
+/// Files that no longer exist (e.g. removed by retention cleanup) are
+/// skipped. Invalid UTF-8 is matched byte-wise, so byte ranges always refer
+/// to the raw log contents.
+#[derive(Debug)]
+pub struct LogSearch {
+    matcher: Option<Regex>,
+    files: VecDeque<PathBuf>,
+    reader: Option<BufReader<File>>,
+    line: usize,
+    buf: Vec<u8>,
+}
+
+impl LogSearch {
+    /// Creates a search over `files`, scanned in the given order
+    ///
+    /// An empty query matches nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LogError::InvalidPattern` if `options.regex` is set and the
+    /// query is not a valid regular expression.
+    pub fn new(
+        files: impl IntoIterator<Item = PathBuf>,
+        query: &str,
+        options: SearchOptions,
+    ) -> LogResult<Self> {
+        let matcher = if query.is_empty() {
+            None
+        } else {
+            let pattern = if options.regex {
+                query.to_string()
+            } else {
+                regex::escape(query)
+            };
+            Some(
+                RegexBuilder::new(&pattern)
+                    .case_insensitive(!options.case_sensitive)
+                    .build()
+                    .map_err(|e| LogError::InvalidPattern(e.to_string()))?,
+            )
+        };
+
+        Ok(Self {
+            matcher,
+            files: files.into_iter().collect(),
+            reader: None,
+            line: 0,
+            buf: Vec::new(),
+        })
+    }
+
+    /// Scans up to `max_lines` more lines and returns the matches found
+    ///
+    /// Returns an empty batch once the search is finished; check
+    /// [`Self::is_finished`] to tell "no matches in this batch" from "done".
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a log file cannot be read.
+    pub fn next_batch(&mut self, max_lines: usize) -> LogResult<Vec<SearchMatch>> {
+        let Some(regex) = self.matcher.as_ref() else {
+            self.files.clear();
+            return Ok(Vec::new());
+        };
+
+        let mut matches = Vec::new();
+        let mut scanned = 0;
+        while scanned < max_lines {
+            if self.reader.is_none() {
+                let Some(path) = self.files.pop_front() else {
+                    break;
+                };
+                match File::open(&path) {
+                    Ok(file) => self.reader = Some(BufReader::new(file)),
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                    Err(e) => return Err(LogError::Io(e)),
+                }
+            }
+            let Some(reader) = self.reader.as_mut() else {
+                break;
+            };
+
+            self.buf.clear();
+            if reader.read_until(b'\n', &mut self.buf)? == 0 {
+                self.reader = None;
+                continue;
+            }
+            let mut content = self.buf.as_slice();
+            if let Some(stripped) = content.strip_suffix(b"\n") {
+                content = stripped.strip_suffix(b"\r").unwrap_or(stripped);
+            }
+            matches.extend(regex.find_iter(content).map(|m| SearchMatch {
+                line: self.line,
+                byte_range: m.range(),
+            }));
+            self.line += 1;
+            scanned += 1;
+        }
+
+        Ok(matches)
+    }
+
+    /// Runs the search to completion and returns all matches
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a log file cannot be read.
+    pub fn collect_all(mut self) -> LogResult<Vec<SearchMatch>> {
+        let mut matches = Vec::new();
+        while !self.is_finished() {
+            matches.extend(self.next_batch(DEFAULT_SEARCH_BATCH_LINES)?);
+        }
+        Ok(matches)
+    }
+
+    /// Returns `true` once every file has been scanned
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.reader.is_none() && self.files.is_empty()
+    }
+
+    /// Returns the number of lines scanned so far
+    #[must_use]
+    pub const fn lines_scanned(&self) -> usize {
+        self.line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn write_file(dir: &TempDir, name: &str, content: &[u8]) -> PathBuf {
+        let path = dir.path().join(name);
+        File::create(&path).unwrap().write_all(content).unwrap();
+        path
+    }
+
+    #[test]
+    fn literal_search_is_case_insensitive_by_default() {
+        let dir = TempDir::new().unwrap();
+        let log = write_file(&dir, "a.log", b"Error one\nok\nanother error here\n");
+        let matches = LogSearch::new([log], "error", SearchOptions::default())
+            .unwrap()
+            .collect_all()
+            .unwrap();
+        assert_eq!(
+            matches,
+            vec![
+                SearchMatch {
+                    line: 0,
+                    byte_range: 0..5
+                },
+                SearchMatch {
+                    line: 2,
+                    byte_range: 8..13
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn case_sensitive_and_regex_options() {
+        let dir = TempDir::new().unwrap();
+        let log = write_file(&dir, "a.log", b"Error E42\nerror e7\r\n");
+        let options = SearchOptions {
+            case_sensitive: true,
+            regex: true,
+        };
+        let matches = LogSearch::new([log.clone()], r"E\d+", options)
+            .unwrap()
+            .collect_all()
+            .unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].byte_range, 6..9);
+
+        // A literal query escapes regex metacharacters
+        let literal = LogSearch::new([log], r"E\d+", SearchOptions::default())
+            .unwrap()
+            .collect_all()
+            .unwrap();
+        assert!(literal.is_empty());
+    }
+
+    #[test]
+    fn invalid_regex_is_rejected() {
+        let options = SearchOptions {
+            regex: true,
+            ..SearchOptions::default()
+        };
+        let err = LogSearch::new(Vec::new(), "(unclosed", options).unwrap_err();
+        assert!(matches!(err, LogError::InvalidPattern(_)));
+    }
+
+    #[test]
+    fn line_numbers_span_files_and_batches() {
+        let dir = TempDir::new().unwrap();
+        let rotated = write_file(&dir, "a.1.log", b"x\nneedle\n");
+        let missing = dir.path().join("gone.log");
+        let current = write_file(&dir, "a.log", b"y\nz\nneedle again\n");
+
+        let mut search = LogSearch::new(
+            [rotated, missing, current],
+            "needle",
+            SearchOptions::default(),
+        )
+        .unwrap();
+        let first = search.next_batch(2).unwrap();
+        assert_eq!(first.iter().map(|m| m.line).collect::<Vec<_>>(), vec![1]);
+        assert!(!search.is_finished());
+
+        let mut rest = Vec::new();
+        while !search.is_finished() {
+            rest.extend(search.next_batch(1).unwrap());
+        }
+        assert_eq!(rest.iter().map(|m| m.line).collect::<Vec<_>>(), vec![4]);
+        assert_eq!(search.lines_scanned(), 5);
+    }
+
+    #[test]
+    fn empty_query_matches_nothing() {
+        let dir = TempDir::new().unwrap();
+        let log = write_file(&dir, "a.log", b"anything\n");
+        let mut search = LogSearch::new([log], "", SearchOptions::default()).unwrap();
+        assert!(search.next_batch(10).unwrap().is_empty());
+        assert!(search.is_finished());
+    }
+}
@@ -0,0 +1,608 @@
+//! Raw serial data capture
+//!
+//! Terminal text extraction loses control bytes and anything that is not
+//! valid UTF-8, which is exactly what matters when debugging embedded
+//! devices. [`SerialCapture`] tees the raw byte stream of a serial session
+//! to disk, one binary file per direction (`.rx.bin` for data received from
+//! the device, `.tx.bin` for data sent to it), with the same size-based
+//! rotation as the session logger. With
+//! [`SerialCaptureFormat::TimestampedHex`] every chunk is also written to a
+//! `.hex.log` as a timestamped hex dump, both directions interleaved.
+//!
+//! Sessions run `picocom`, which owns the device. [`SerialCaptureTap`] reads
+//! picocom's `--logfile` through a FIFO instead of opening the device a
+//! second time, which would steal bytes from the session.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Read, Write};
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use super::logger::{LogContext, LogError, LogResult, SessionLogger};
+use crate::models::{SerialCaptureFormat, SerialConfig};
+use crate::protocol::format_hex_dump;
+use crate::variables::VariableManager;
+
+/// Default capture path template (without extension)
+pub const DEFAULT_SERIAL_CAPTURE_TEMPLATE: &str =
+    "${HOME}/.local/share/rustconn/captures/${connection_name}_${datetime}";
+
+/// Direction of serial data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureDirection {
+    /// Data received from the device
+    Rx,
+    /// Data sent to the device
+    Tx,
+}
+
+impl CaptureDirection {
+    const fn extension(self) -> &'static str {
+        match self {
+            Self::Rx => "rx.bin",
+            Self::Tx => "tx.bin",
+        }
+    }
+
+    /// Short label used in hex logs
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Rx => "RX",
+            Self::Tx => "TX",
+        }
+    }
+}
+
+/// Extension of the timestamped hex log
+const HEX_LOG_EXTENSION: &str = "hex.log";
+
+/// Formats one chunk of a hex log
+///
+/// A header line with the RFC 3339 timestamp (microseconds, UTC), the
+/// direction and the chunk length is followed by a [`format_hex_dump`] of
+/// `data`. `offset` is the position of the chunk in its direction's byte
+/// stream, so offsets continue across chunks.
+#[must_use]
+pub fn format_capture_record(
+    direction: CaptureDirection,
+    at: DateTime<Utc>,
+    offset: u64,
+    data: &[u8],
+) -> String {
+    format!(
+        "{} {} {} bytes\n{}",
+        at.to_rfc3339_opts(SecondsFormat::Micros, true),
+        direction.label(),
+        data.len(),
+        format_hex_dump(data, offset)
+    )
+}
+
+/// One rotating capture file
+struct CaptureFile {
+    path: PathBuf,
+    extension: &'static str,
+    writer: Option<BufWriter<File>>,
+    bytes_written: u64,
+    rotation_count: u32,
+}
+
+impl CaptureFile {
+    fn open(base: &Path, extension: &'static str) -> LogResult<Self> {
+        let mut name = base.as_os_str().to_os_string();
+        name.push(".");
+        name.push(extension);
+        let path = PathBuf::from(name);
+
+        let writer = Some(BufWriter::new(open_append(&path)?));
+        let bytes_written = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        Ok(Self {
+            path,
+            extension,
+            writer,
+            bytes_written,
+            rotation_count: 0,
+        })
+    }
+
+    fn write(&mut self, data: &[u8], max_bytes: u64) -> LogResult<()> {
+        if max_bytes > 0
+            && self.bytes_written > 0
+            && self.bytes_written + data.len() as u64 > max_bytes
+        {
+            self.rotate()?;
+        }
+        let writer = self
+            .writer
+            .as_mut()
+            .ok_or_else(|| LogError::WriteError("Capture file not open".to_string()))?;
+        writer
+            .write_all(data)
+            .map_err(|e| LogError::WriteError(format!("Failed to write capture: {e}")))?;
+        self.bytes_written += data.len() as u64;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> LogResult<()> {
+        if let Some(writer) = self.writer.as_mut() {
+            writer
+                .flush()
+                .map_err(|e| LogError::FlushError(format!("Failed to flush capture: {e}")))?;
+        }
+        Ok(())
+    }
+
+    /// Moves the current file to `<name>.<timestamp>.<n>.<extension>` and reopens
+    fn rotate(&mut self) -> LogResult<()> {
+        self.flush()?;
+        self.writer = None;
+        self.rotation_count += 1;
+
+        let file_name = self
+            .path
+            .file_name()
+            .map_or_else(String::new, |n| n.to_string_lossy().to_string());
+        // Keep the two-part `.rx.bin` / `.tx.bin` / `.hex.log` suffix at the end
+        let stem = file_name
+            .strip_suffix(self.extension)
+            .and_then(|s| s.strip_suffix('.'))
+            .unwrap_or(&file_name);
+        let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
+        let rotated = self.path.with_file_name(format!(
+            "{stem}.{timestamp}.{}.{}",
+            self.rotation_count, self.extension
+        ));
+
+        fs::rename(&self.path, &rotated).map_err(|e| {
+            LogError::RotationError(format!(
+                "Failed to rename {} to {}: {}",
+                self.path.display(),
+                rotated.display(),
+                e
+            ))
+        })?;
+
+        self.writer = Some(BufWriter::new(open_append(&self.path)?));
+        self.bytes_written = 0;
+        Ok(())
+    }
+}
+
+fn open_append(path: &Path) -> LogResult<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| LogError::FileCreation(format!("Failed to open {}: {}", path.display(), e)))
+}
+
+/// Lossless capture of a serial session's raw bytes
+pub struct SerialCapture {
+    base: PathBuf,
+    rx: CaptureFile,
+    tx: CaptureFile,
+    hex: Option<CaptureFile>,
+    /// Bytes recorded so far per direction, for hex log offsets
+    rx_offset: u64,
+    tx_offset: u64,
+    max_bytes: u64,
+}
+
+impl SerialCapture {
+    /// Opens capture files for a serial session
+    ///
+    /// The path comes from `config.capture_path` (or
+    /// [`DEFAULT_SERIAL_CAPTURE_TEMPLATE`]) expanded like a session log path;
+    /// `.rx.bin` and `.tx.bin` are appended for the two directions, and
+    /// `.hex.log` for the hex log if `config.capture_format` asks for one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the template cannot be expanded or the files
+    /// cannot be created.
+    pub fn new(
+        config: &SerialConfig,
+        context: &LogContext,
+        variable_manager: Option<&VariableManager>,
+    ) -> LogResult<Self> {
+        let template = config
+            .capture_path
+            .as_deref()
+            .filter(|t| !t.trim().is_empty())
+            .unwrap_or(DEFAULT_SERIAL_CAPTURE_TEMPLATE);
+        let base = SessionLogger::expand_path_template(template, context, variable_manager)?;
+        let capture = Self::with_base_path(&base, config.capture_max_size_mb)?;
+        match config.capture_format {
+            SerialCaptureFormat::Binary => Ok(capture),
+            SerialCaptureFormat::TimestampedHex => capture.with_hex_log(),
+        }
+    }
+
+    /// Opens capture files at `<base>.rx.bin` and `<base>.tx.bin`
+    ///
+    /// `max_size_mb` limits each file before it is rotated (0 = no limit).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the files cannot be created.
+    pub fn with_base_path(base: &Path, max_size_mb: u32) -> LogResult<Self> {
+        if let Some(parent) = base.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                LogError::DirectoryCreation(format!("Failed to create {}: {}", parent.display(), e))
+            })?;
+        }
+        Ok(Self {
+            base: base.to_path_buf(),
+            rx: CaptureFile::open(base, CaptureDirection::Rx.extension())?,
+            tx: CaptureFile::open(base, CaptureDirection::Tx.extension())?,
+            hex: None,
+            rx_offset: 0,
+            tx_offset: 0,
+            max_bytes: u64::from(max_size_mb) * 1024 * 1024,
+        })
+    }
+
+    /// Also writes a timestamped hex log next to the raw files
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hex log cannot be created.
+    pub fn with_hex_log(mut self) -> LogResult<Self> {
+        self.hex = Some(CaptureFile::open(&self.base, HEX_LOG_EXTENSION)?);
+        Ok(self)
+    }
+
+    /// Appends raw bytes for one direction, rotating first if the file is full
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing or rotation fails.
+    pub fn record(&mut self, direction: CaptureDirection, data: &[u8]) -> LogResult<()> {
+        self.record_at(direction, data, Utc::now())
+    }
+
+    /// Like [`Self::record`], with the time the chunk arrived
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing or rotation fails.
+    pub fn record_at(
+        &mut self,
+        direction: CaptureDirection,
+        data: &[u8],
+        at: DateTime<Utc>,
+    ) -> LogResult<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let max_bytes = self.max_bytes;
+        self.file_mut(direction).write(data, max_bytes)?;
+
+        let offset = match direction {
+            CaptureDirection::Rx => &mut self.rx_offset,
+            CaptureDirection::Tx => &mut self.tx_offset,
+        };
+        let start = *offset;
+        *offset += data.len() as u64;
+        if let Some(hex) = self.hex.as_mut() {
+            let record = format_capture_record(direction, at, start, data);
+            hex.write(record.as_bytes(), max_bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the current capture file for a direction
+    #[must_use]
+    pub fn path(&self, direction: CaptureDirection) -> &Path {
+        match direction {
+            CaptureDirection::Rx => &self.rx.path,
+            CaptureDirection::Tx => &self.tx.path,
+        }
+    }
+
+    /// Returns the current hex log, if one is written
+    #[must_use]
+    pub fn hex_log_path(&self) -> Option<&Path> {
+        self.hex.as_ref().map(|hex| hex.path.as_path())
+    }
+
+    /// Flushes all capture files to disk
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if flushing fails.
+    pub fn flush(&mut self) -> LogResult<()> {
+        self.rx.flush()?;
+        self.tx.flush()?;
+        self.hex.as_mut().map_or(Ok(()), CaptureFile::flush)
+    }
+
+    const fn file_mut(&mut self, direction: CaptureDirection) -> &mut CaptureFile {
+        match direction {
+            CaptureDirection::Rx => &mut self.rx,
+            CaptureDirection::Tx => &mut self.tx,
+        }
+    }
+}
+
+impl Drop for SerialCapture {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+/// Feeds a [`SerialCapture`] from picocom's log file
+///
+/// The tap creates a FIFO and a thread that records every chunk read from
+/// it as [`CaptureDirection::Rx`], until picocom closes it. Pass
+/// [`Self::picocom_args`] to picocom. picocom only logs data received from
+/// the device, so tapped captures have no TX data.
+///
+/// Dropping the tap removes the FIFO and releases a thread still waiting
+/// for picocom to open it; a running thread finishes when picocom exits.
+#[derive(Debug)]
+pub struct SerialCaptureTap {
+    fifo: PathBuf,
+}
+
+impl SerialCaptureTap {
+    /// Starts a tap with its FIFO in `$XDG_RUNTIME_DIR`, or the system temp
+    /// directory when that is unavailable
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FIFO or the reader thread cannot be created.
+    pub fn spawn(capture: SerialCapture) -> LogResult<Self> {
+        let dir = std::env::var_os("XDG_RUNTIME_DIR")
+            .map(PathBuf::from)
+            .filter(|p| p.is_dir())
+            .unwrap_or_else(std::env::temp_dir);
+        Self::spawn_in(&dir, capture)
+    }
+
+    /// Starts a tap with its FIFO in `dir`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FIFO or the reader thread cannot be created.
+    pub fn spawn_in(dir: &Path, mut capture: SerialCapture) -> LogResult<Self> {
+        let fifo = dir.join(format!("rustconn-serial-{}.fifo", uuid::Uuid::new_v4()));
+        nix::unistd::mkfifo(
+            &fifo,
+            nix::sys::stat::Mode::S_IRUSR | nix::sys::stat::Mode::S_IWUSR,
+        )
+        .map_err(|e| {
+            LogError::FileCreation(format!("Failed to create FIFO {}: {e}", fifo.display()))
+        })?;
+        let tap = Self { fifo };
+
+        let path = tap.fifo.clone();
+        std::thread::Builder::new()
+            .name("serial-capture".to_string())
+            .spawn(move || {
+                // Blocks until picocom opens its log file
+                let mut reader = match File::open(&path) {
+                    Ok(reader) => reader,
+                    Err(e) => {
+                        tracing::warn!(path = %path.display(), %e, "Failed to open capture FIFO");
+                        return;
+                    }
+                };
+                let mut buf = [0u8; 4096];
+                loop {
+                    let n = match reader.read(&mut buf) {
+                        Ok(0) => break,
+                        Ok(n) => n,
+                        Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                        Err(e) => {
+                            tracing::warn!(%e, "Failed to read capture FIFO");
+                            break;
+                        }
+                    };
+                    if let Err(e) = capture
+                        .record(CaptureDirection::Rx, &buf[..n])
+                        .and_then(|()| capture.flush())
+                    {
+                        tracing::warn!(%e, "Failed to write serial capture");
+                        break;
+                    }
+                }
+                tracing::debug!(path = %capture.path(CaptureDirection::Rx).display(), "Serial capture finished");
+            })
+            .map_err(|e| LogError::FileCreation(format!("Failed to start capture thread: {e}")))?;
+        Ok(tap)
+    }
+
+    /// Path of the FIFO picocom writes to
+    #[must_use]
+    pub fn fifo_path(&self) -> &Path {
+        &self.fifo
+    }
+
+    /// picocom arguments that send its log to this tap
+    #[must_use]
+    pub fn picocom_args(&self) -> [String; 2] {
+        [
+            "--logfile".to_string(),
+            self.fifo.to_string_lossy().into_owned(),
+        ]
+    }
+}
+
+impl Drop for SerialCaptureTap {
+    fn drop(&mut self) {
+        // Opening the write end wakes a reader still blocked in open(); it
+        // sees end-of-file as soon as this handle is closed. Fails with
+        // ENXIO when no reader is waiting, which is fine.
+        let _ = OpenOptions::new()
+            .write(true)
+            .custom_flags(nix::fcntl::OFlag::O_NONBLOCK.bits())
+            .open(&self.fifo);
+        if let Err(e) = fs::remove_file(&self.fifo)
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            tracing::warn!(path = %self.fifo.display(), %e, "Failed to remove capture FIFO");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn capture_is_binary_safe_per_direction() {
+        let dir = TempDir::new().unwrap();
+        let base = dir.path().join("board");
+        let mut capture = SerialCapture::with_base_path(&base, 0).unwrap();
+
+        let rx = [0x00, 0xff, 0x1b, b'[', b'0', b'm', 0xc3];
+        capture.record(CaptureDirection::Rx, &rx).unwrap();
+        capture.record(CaptureDirection::Tx, b"AT\r").unwrap();
+        capture.flush().unwrap();
+
+        assert_eq!(fs::read(dir.path().join("board.rx.bin")).unwrap(), rx);
+        assert_eq!(fs::read(dir.path().join("board.tx.bin")).unwrap(), b"AT\r");
+        assert_eq!(
+            capture.path(CaptureDirection::Tx),
+            dir.path().join("board.tx.bin")
+        );
+    }
+
+    #[test]
+    fn capture_rotates_at_size_limit() {
+        let dir = TempDir::new().unwrap();
+        let base = dir.path().join("board");
+        let mut capture = SerialCapture::with_base_path(&base, 1).unwrap();
+
+        let chunk = vec![0xaa; 700 * 1024];
+        capture.record(CaptureDirection::Rx, &chunk).unwrap();
+        capture.record(CaptureDirection::Rx, &chunk).unwrap();
+        capture.flush().unwrap();
+
+        let rotated: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .filter_map(Result::ok)
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .filter(|n| n.starts_with("board.") && n.ends_with(".1.rx.bin"))
+            .collect();
+        assert_eq!(rotated.len(), 1);
+        assert_eq!(
+            fs::metadata(dir.path().join("board.rx.bin")).unwrap().len(),
+            chunk.len() as u64
+        );
+    }
+
+    #[test]
+    fn hex_log_has_timestamped_records() {
+        let dir = TempDir::new().unwrap();
+        let base = dir.path().join("board");
+        let mut capture = SerialCapture::with_base_path(&base, 0)
+            .unwrap()
+            .with_hex_log()
+            .unwrap();
+        let at = DateTime::parse_from_rfc3339("2026-10-15T08:30:00.250Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        capture
+            .record_at(CaptureDirection::Rx, b"U-Boot\r\n", at)
+            .unwrap();
+        capture
+            .record_at(CaptureDirection::Tx, b"\x03", at)
+            .unwrap();
+        capture.record_at(CaptureDirection::Rx, b"=> ", at).unwrap();
+        capture.flush().unwrap();
+
+        assert_eq!(
+            capture.hex_log_path(),
+            Some(dir.path().join("board.hex.log").as_path())
+        );
+        let log = fs::read_to_string(dir.path().join("board.hex.log")).unwrap();
+        let lines: Vec<&str> = log.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0], "2026-10-15T08:30:00.250000Z RX 8 bytes");
+        assert!(lines[1].starts_with("00000000  55 2d 42 6f 6f 74 0d 0a "));
+        assert!(lines[1].ends_with("|U-Boot..|"));
+        assert_eq!(lines[2], "2026-10-15T08:30:00.250000Z TX 1 bytes");
+        assert!(lines[3].starts_with("00000000  03 "));
+        // RX offsets continue from the first chunk
+        assert!(lines[5].starts_with("00000008  3d 3e 20 "));
+        // The raw files stay lossless
+        assert_eq!(
+            fs::read(dir.path().join("board.rx.bin")).unwrap(),
+            b"U-Boot\r\n=> "
+        );
+    }
+
+    #[test]
+    fn capture_record_format() {
+        let at = DateTime::parse_from_rfc3339("2026-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            format_capture_record(CaptureDirection::Tx, at, 0x10, b"AT\r"),
+            "2026-01-02T03:04:05.000000Z TX 3 bytes\n\
+             00000010  41 54 0d                                          |AT.|\n"
+        );
+    }
+
+    #[test]
+    fn tap_records_fifo_data() {
+        let dir = TempDir::new().unwrap();
+        let capture = SerialCapture::with_base_path(&dir.path().join("board"), 0).unwrap();
+        let tap = SerialCaptureTap::spawn_in(dir.path(), capture).unwrap();
+        assert_eq!(tap.picocom_args()[0], "--logfile");
+
+        // Stand in for picocom writing its log
+        let mut writer = OpenOptions::new()
+            .write(true)
+            .open(tap.fifo_path())
+            .unwrap();
+        writer.write_all(&[0x00, 0xff, b'O', b'K']).unwrap();
+        drop(writer);
+
+        let rx = dir.path().join("board.rx.bin");
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while fs::read(&rx).unwrap().len() < 4 && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(fs::read(&rx).unwrap(), [0x00, 0xff, b'O', b'K']);
+
+        let fifo = tap.fifo_path().to_path_buf();
+        drop(tap);
+        assert!(!fifo.exists());
+    }
+
+    #[test]
+    fn dropping_unused_tap_removes_fifo() {
+        let dir = TempDir::new().unwrap();
+        let capture = SerialCapture::with_base_path(&dir.path().join("idle"), 0).unwrap();
+        let tap = SerialCaptureTap::spawn_in(dir.path(), capture).unwrap();
+        let fifo = tap.fifo_path().to_path_buf();
+        assert!(fifo.exists());
+        drop(tap);
+        assert!(!fifo.exists());
+    }
+
+    #[test]
+    fn capture_path_uses_template() {
+        let dir = TempDir::new().unwrap();
+        let config = SerialConfig {
+            device: "/dev/ttyUSB0".to_string(),
+            capture_path: Some(format!("{}/${{connection_name}}", dir.path().display())),
+            ..SerialConfig::default()
+        };
+        let context = LogContext::new("My Board", "serial");
+        let capture = SerialCapture::new(&config, &context, None).unwrap();
+        assert_eq!(
+            capture.path(CaptureDirection::Rx),
+            dir.path().join("My_Board.rx.bin")
+        );
+    }
+}
@@ -8,7 +8,7 @@ use std::process::Child;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use tracing::{debug, info_span};
+use tracing::{debug, info, info_span};
 use uuid::Uuid;
 
 use crate::tracing::span_names;
@@ -122,8 +122,7 @@ impl Session {
             match process.try_wait() {
                 Ok(Some(_)) => {
                     // Process has exited
-                    self.state = SessionState::Terminated;
-                    self.ended_at = Some(Utc::now());
+                    self.mark_terminated();
                     false
                 }
                 Ok(None) => {
@@ -157,8 +156,7 @@ impl Session {
             let _ = process.wait();
         }
 
-        self.state = SessionState::Terminated;
-        self.ended_at = Some(Utc::now());
+        self.mark_terminated();
         Ok(())
     }
 
@@ -174,11 +172,26 @@ impl Session {
             let _ = process.wait();
         }
 
-        self.state = SessionState::Terminated;
-        self.ended_at = Some(Utc::now());
+        self.mark_terminated();
         Ok(())
     }
 
+    /// Marks the session terminated and logs its duration
+    fn mark_terminated(&mut self) {
+        let ended_at = Utc::now();
+        self.state = SessionState::Terminated;
+        self.ended_at = Some(ended_at);
+
+        let duration_ms = (ended_at - self.started_at).num_milliseconds().max(0) as u64;
+        let _span = info_span!(span_names::SESSION_END, session_id = %self.id).entered();
+        info!(
+            connection_id = %self.connection_id,
+            protocol = %self.protocol,
+            duration_ms,
+            "Session ended"
+        );
+    }
+
     /// Takes ownership of the process handle
     pub const fn take_process(&mut self) -> Option<Child> {
         self.process.take()
@@ -3,10 +3,11 @@
 //! Provides utilities for building SFTP URIs and CLI commands
 //! for SSH connections with SFTP enabled.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 use crate::models::{Connection, ConnectionGroup, SshKeySource};
+use crate::ssh_agent::AddKeyOptions;
 
 /// Information about a running ssh-agent instance.
 ///
@@ -57,6 +58,34 @@ pub fn apply_agent_env(cmd: &mut std::process::Command) {
     }
 }
 
+/// Returns the SSH agent variables a spawned `ssh` process should receive.
+///
+/// Sources (in priority order):
+/// 1. `socket_override` — a resolved per-connection or global socket path
+///    (only `SSH_AUTH_SOCK` is set)
+/// 2. `agent` — usually [`get_agent_info`], the agent RustConn started
+/// 3. Empty — the child inherits the parent environment
+///
+/// Both the interactive terminal and the monitoring collector build their
+/// environment from this, so the two SSH processes reach the same agent.
+#[must_use]
+pub fn agent_env_vars(
+    socket_override: Option<&str>,
+    agent: Option<&SshAgentInfo>,
+) -> Vec<(&'static str, String)> {
+    if let Some(socket) = socket_override.filter(|s| !s.is_empty()) {
+        return vec![("SSH_AUTH_SOCK", socket.to_string())];
+    }
+    let Some(info) = agent.filter(|i| !i.socket_path.is_empty()) else {
+        return Vec::new();
+    };
+    let mut vars = vec![("SSH_AUTH_SOCK", info.socket_path.clone())];
+    if let Some(ref pid) = info.pid {
+        vars.push(("SSH_AGENT_PID", pid.clone()));
+    }
+    vars
+}
+
 /// Result of validating a socket path.
 ///
 /// Used by the GUI to provide real-time feedback on socket path entries.
@@ -438,12 +467,16 @@ pub fn build_sftp_browser_uri(
 ///
 /// Uses SSH inheritance resolution for proxy jump settings.
 ///
+/// With `options.resume` the command gets `-a`, so `get`/`put` continue
+/// interrupted transfers.
+///
 /// The returned `Vec` has the program name as the first element,
 /// followed by arguments: `["sftp", "-P", "port", "user@host"]`.
 #[must_use]
 pub fn build_sftp_command(
     connection: &Connection,
     groups: &[ConnectionGroup],
+    options: &SftpTransferOptions,
 ) -> Option<Vec<String>> {
     if !matches!(
         connection.protocol_config,
@@ -454,6 +487,10 @@ pub fn build_sftp_command(
 
     let mut cmd = vec!["sftp".to_string()];
 
+    if options.resume {
+        cmd.push("-a".to_string());
+    }
+
     // Add proxy jump from inheritance chain if available
     if let Some(proxy_jump) =
         crate::connection::ssh_inheritance::resolve_ssh_proxy_jump(connection, groups)
@@ -475,16 +512,266 @@ pub fn build_sftp_command(
         cmd.push(key_path.to_string_lossy().into_owned());
     }
 
-    let target = if let Some(ref user) = connection.username {
-        format!("{user}@{}", connection.host)
-    } else {
-        connection.host.clone()
-    };
-    cmd.push(target);
+    cmd.push(sftp_target(connection));
+
+    Some(cmd)
+}
+
+/// Options for `sftp` file transfers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SftpTransferOptions {
+    /// Continue interrupted transfers (`sftp -a`, `reget`/`reput`)
+    pub resume: bool,
+    /// Compare SHA-256 checksums after the transfer
+    /// (see [`build_remote_sha256_command`] and [`verify_sha256`])
+    pub verify_checksum: bool,
+}
+
+impl SftpTransferOptions {
+    /// Continues interrupted transfers instead of starting over
+    #[must_use]
+    pub const fn with_resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    /// Requests SHA-256 verification after the transfer
+    #[must_use]
+    pub const fn with_verify_checksum(mut self, verify: bool) -> Self {
+        self.verify_checksum = verify;
+        self
+    }
+}
+
+/// Direction of an SFTP transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferDirection {
+    /// Remote file to local file (`get`)
+    Download,
+    /// Local file to remote file (`put`)
+    Upload,
+}
+
+/// A single file transfer run through `sftp -b -`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SftpTransfer {
+    /// Transfer direction
+    pub direction: TransferDirection,
+    /// Path on the server
+    pub remote_path: String,
+    /// Path on this machine
+    pub local_path: PathBuf,
+}
+
+impl SftpTransfer {
+    /// Creates a download of `remote_path` to `local_path`
+    #[must_use]
+    pub fn download(remote_path: impl Into<String>, local_path: impl Into<PathBuf>) -> Self {
+        Self {
+            direction: TransferDirection::Download,
+            remote_path: remote_path.into(),
+            local_path: local_path.into(),
+        }
+    }
+
+    /// Creates an upload of `local_path` to `remote_path`
+    #[must_use]
+    pub fn upload(local_path: impl Into<PathBuf>, remote_path: impl Into<String>) -> Self {
+        Self {
+            direction: TransferDirection::Upload,
+            remote_path: remote_path.into(),
+            local_path: local_path.into(),
+        }
+    }
+
+    /// Returns the `sftp` batch line for this transfer
+    ///
+    /// Uses `reget`/`reput` when resuming, which append from the size of
+    /// the partial destination file.
+    #[must_use]
+    pub fn batch_command(&self, resume: bool) -> String {
+        let remote = quote_batch_arg(&self.remote_path);
+        let local = quote_batch_arg(&self.local_path.to_string_lossy());
+        match (self.direction, resume) {
+            (TransferDirection::Download, false) => format!("get {remote} {local}"),
+            (TransferDirection::Download, true) => format!("reget {remote} {local}"),
+            (TransferDirection::Upload, false) => format!("put {local} {remote}"),
+            (TransferDirection::Upload, true) => format!("reput {local} {remote}"),
+        }
+    }
+}
+
+/// What to do with an existing partial destination file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumePlan {
+    /// No partial file: transfer from the start
+    Fresh,
+    /// Append from this byte offset
+    Resume {
+        /// Bytes already transferred
+        offset: u64,
+    },
+    /// The destination already has every byte
+    Complete,
+    /// The destination is larger than the source, so it is not a prefix
+    /// of it: transfer from the start
+    Restart,
+}
+
+/// Decides how to continue a transfer from the partial destination size
+/// and the source size
+#[must_use]
+pub const fn resume_plan(partial_size: Option<u64>, source_size: u64) -> ResumePlan {
+    match partial_size {
+        None | Some(0) => ResumePlan::Fresh,
+        Some(size) if size < source_size => ResumePlan::Resume { offset: size },
+        Some(size) if size == source_size => ResumePlan::Complete,
+        Some(_) => ResumePlan::Restart,
+    }
+}
 
+/// Plans resuming a download into `local_path` from the remote file size
+#[must_use]
+pub fn download_resume_plan(local_path: &std::path::Path, remote_size: u64) -> ResumePlan {
+    let partial_size = std::fs::metadata(local_path)
+        .ok()
+        .filter(std::fs::Metadata::is_file)
+        .map(|m| m.len());
+    resume_plan(partial_size, remote_size)
+}
+
+/// Builds a non-interactive `sftp -b -` command for a file transfer.
+///
+/// The batch line from [`SftpTransfer::batch_command`] must be written to
+/// the process's stdin. Returns `None` if the connection is not SSH.
+#[must_use]
+pub fn build_sftp_transfer_command(
+    connection: &Connection,
+    groups: &[ConnectionGroup],
+    options: &SftpTransferOptions,
+) -> Option<Vec<String>> {
+    let mut cmd = build_sftp_command(connection, groups, options)?;
+    // Insert before the target, which is always last
+    let target_index = cmd.len() - 1;
+    cmd.splice(
+        target_index..target_index,
+        ["-b".to_string(), "-".to_string()],
+    );
+    Some(cmd)
+}
+
+/// Builds an `ssh` command that prints the remote file's `sha256sum` line.
+///
+/// Returns `None` if the connection is not SSH.
+#[must_use]
+pub fn build_remote_sha256_command(
+    connection: &Connection,
+    groups: &[ConnectionGroup],
+    remote_path: &str,
+) -> Option<Vec<String>> {
+    if !matches!(
+        connection.protocol_config,
+        crate::models::ProtocolConfig::Ssh(_) | crate::models::ProtocolConfig::Sftp(_)
+    ) {
+        return None;
+    }
+
+    let mut cmd = vec![
+        "ssh".to_string(),
+        "-o".to_string(),
+        "BatchMode=yes".to_string(),
+    ];
+    if let Some(proxy_jump) =
+        crate::connection::ssh_inheritance::resolve_ssh_proxy_jump(connection, groups)
+    {
+        cmd.push("-J".to_string());
+        cmd.push(proxy_jump);
+    }
+    if connection.port != 22 {
+        cmd.push("-p".to_string());
+        cmd.push(connection.port.to_string());
+    }
+    if let Some(key_path) =
+        crate::connection::ssh_inheritance::resolve_ssh_key_path(connection, groups)
+    {
+        cmd.push("-i".to_string());
+        cmd.push(key_path.to_string_lossy().into_owned());
+    }
+    cmd.push(sftp_target(connection));
+    cmd.push(format!("sha256sum -- {}", shell_escape(remote_path)));
     Some(cmd)
 }
 
+/// Extracts the digest from `sha256sum` output (`<hex>  <path>`)
+///
+/// Returns `None` unless the first word is 64 hex digits.
+#[must_use]
+pub fn parse_sha256sum_output(output: &str) -> Option<String> {
+    let digest = output.split_whitespace().next()?;
+    (digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit()))
+        .then(|| digest.to_ascii_lowercase())
+}
+
+/// Computes the SHA-256 of a local file as lowercase hex
+///
+/// # Errors
+///
+/// Returns an I/O error if the file cannot be read.
+pub fn local_sha256(path: &std::path::Path) -> std::io::Result<String> {
+    use std::io::Read;
+
+    use ring::digest::{Context, SHA256};
+
+    let mut file = std::fs::File::open(path)?;
+    let mut context = Context::new(&SHA256);
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        context.update(&buf[..n]);
+    }
+    Ok(hex::encode(context.finish().as_ref()))
+}
+
+/// Checks a transferred file against the remote `sha256sum` output
+///
+/// Returns `Ok(true)` when the checksums match.
+///
+/// # Errors
+///
+/// Returns [`std::io::ErrorKind::InvalidData`] if the remote output holds
+/// no digest, or an I/O error if the local file cannot be read.
+pub fn verify_sha256(local_path: &std::path::Path, remote_output: &str) -> std::io::Result<bool> {
+    let remote = parse_sha256sum_output(remote_output).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "no SHA-256 digest in sha256sum output: {}",
+                remote_output.trim()
+            ),
+        )
+    })?;
+    Ok(local_sha256(local_path)? == remote)
+}
+
+/// Returns `user@host` or `host` for `ssh`/`sftp`
+fn sftp_target(connection: &Connection) -> String {
+    connection.username.as_ref().map_or_else(
+        || connection.host.clone(),
+        |user| format!("{user}@{}", connection.host),
+    )
+}
+
+/// Quotes a path for an `sftp` batch line
+///
+/// `sftp` splits batch arguments on whitespace and honours double quotes
+/// with backslash escapes.
+fn quote_batch_arg(arg: &str) -> String {
+    format!("\"{}\"", arg.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 /// Extracts the SSH key file path from a connection's config.
 ///
 /// Uses SSH inheritance resolution: checks the connection-level setting
@@ -670,11 +957,16 @@ pub fn ensure_ssh_agent() -> Option<SshAgentInfo> {
 /// This is needed before opening SFTP via mc or file managers,
 /// because neither can pass an identity file directly.
 ///
-/// Uses SSH inheritance resolution to find the key path.
+/// Uses SSH inheritance resolution to find the key path. `options`
+/// sets the key lifetime (`-t`) and confirm-on-use (`-c`).
 ///
 /// Returns `true` if the key was added (or no key is needed),
 /// `false` if `ssh-add` failed.
-pub fn ensure_key_in_agent(connection: &Connection, groups: &[ConnectionGroup]) -> bool {
+pub fn ensure_key_in_agent(
+    connection: &Connection,
+    groups: &[ConnectionGroup],
+    options: &AddKeyOptions,
+) -> bool {
     let Some(key_path) = get_ssh_key_path(connection, groups) else {
         // No key configured — ssh-agent may already have the
         // right key, or password auth is used. Proceed anyway.
@@ -697,7 +989,8 @@ pub fn ensure_key_in_agent(connection: &Connection, groups: &[ConnectionGroup])
 
     tracing::info!(?key_path, "Adding SSH key to agent for SFTP");
     let mut cmd = std::process::Command::new("ssh-add");
-    cmd.arg(&key_path)
+    cmd.args(options.ssh_add_args())
+        .arg(&key_path)
         .stdin(std::process::Stdio::null())
         .stdout(std::process::Stdio::null())
         .stderr(std::process::Stdio::piped())
@@ -708,6 +1001,7 @@ pub fn ensure_key_in_agent(connection: &Connection, groups: &[ConnectionGroup])
     match cmd.output() {
         Ok(output) if output.status.success() => {
             tracing::info!(?key_path, "SSH key added to agent");
+            crate::ssh_agent::remember_added_key(&key_path, options);
             true
         }
         Ok(output) => {
@@ -745,6 +1039,42 @@ pub fn get_downloads_dir() -> String {
     path.to_string_lossy().into_owned()
 }
 
+/// Local directory that SFTP downloads go to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadsLocation {
+    /// Use this directory
+    Directory(String),
+    /// Ask for a directory through the XDG file chooser portal first
+    ///
+    /// Inside Flatpak, files only reach the host in a directory the user
+    /// granted through the document portal; elsewhere they stay in the
+    /// sandbox and seem to vanish.
+    Portal,
+}
+
+/// Picks the local directory for SFTP downloads
+///
+/// Outside Flatpak this is always [`get_downloads_dir`]. Inside Flatpak it
+/// is `granted`, a directory the user picked through the portal earlier, as
+/// long as it still exists (portal paths go stale when the app is
+/// reinstalled); otherwise [`DownloadsLocation::Portal`].
+#[must_use]
+pub fn downloads_location(granted: Option<&Path>) -> DownloadsLocation {
+    select_downloads_location(crate::flatpak::is_flatpak(), granted)
+}
+
+fn select_downloads_location(in_flatpak: bool, granted: Option<&Path>) -> DownloadsLocation {
+    if !in_flatpak {
+        return DownloadsLocation::Directory(get_downloads_dir());
+    }
+    match granted {
+        Some(dir) if dir.is_dir() => {
+            DownloadsLocation::Directory(dir.to_string_lossy().into_owned())
+        }
+        _ => DownloadsLocation::Portal,
+    }
+}
+
 /// Builds a Midnight Commander command to open an SFTP panel.
 ///
 /// Returns `None` if the connection is not SSH.
@@ -755,8 +1085,21 @@ pub fn get_downloads_dir() -> String {
 /// ssh-agent beforehand.
 #[must_use]
 pub fn build_mc_sftp_command(
+    connection: &Connection,
+    groups: &[ConnectionGroup],
+) -> Option<Vec<String>> {
+    build_mc_sftp_command_in(connection, groups, &get_downloads_dir())
+}
+
+/// Builds a Midnight Commander SFTP command whose left panel shows
+/// `local_dir`, e.g. a directory from [`downloads_location`].
+///
+/// Returns `None` if the connection is not SSH.
+#[must_use]
+pub fn build_mc_sftp_command_in(
     connection: &Connection,
     _groups: &[ConnectionGroup],
+    local_dir: &str,
 ) -> Option<Vec<String>> {
     if !matches!(
         connection.protocol_config,
@@ -779,8 +1122,6 @@ pub fn build_mc_sftp_command(
         format!("sh://{}:{}/~", connection.host, connection.port)
     };
 
-    let local_dir = get_downloads_dir();
-
     // In Flatpak, /app/bin/mc is a shell wrapper script that sources
     // mc-wrapper.sh for directory-change-on-exit. Use mc.bin (the real
     // binary) directly to avoid the extra shell layer — the wrapper's
@@ -801,7 +1142,12 @@ pub fn build_mc_sftp_command(
     // encoding which mc's ncurses may not parse correctly, causing
     // raw escape sequences to leak as text artifacts. Normal tracking
     // mode is simpler and universally supported.
-    Some(vec![mc_binary, "-g".to_string(), local_dir, target])
+    Some(vec![
+        mc_binary,
+        "-g".to_string(),
+        local_dir.to_string(),
+        target,
+    ])
 }
 
 /// Ensures an SSH wrapper exists for mc FISH connections in Flatpak.
@@ -867,7 +1213,6 @@ pub fn ensure_flatpak_mc_ssh_wrapper() -> Option<String> {
 ///
 /// Single quotes inside the value are escaped as `'\''` (end quote,
 /// escaped literal quote, start quote).
-#[cfg(test)]
 fn shell_escape(s: &str) -> String {
     format!("'{}'", s.replace('\'', "'\\''"))
 }
@@ -946,7 +1291,7 @@ mod tests {
             Connection::new_ssh("Test".to_string(), "server.example.com".to_string(), 22);
         conn.username = Some("admin".to_string());
 
-        let cmd = build_sftp_command(&conn, &[]).unwrap();
+        let cmd = build_sftp_command(&conn, &[], &SftpTransferOptions::default()).unwrap();
         assert_eq!(cmd, vec!["sftp", "admin@server.example.com"]);
     }
 
@@ -955,14 +1300,14 @@ mod tests {
         let mut conn = Connection::new_ssh("Test".to_string(), "host.local".to_string(), 2222);
         conn.username = Some("root".to_string());
 
-        let cmd = build_sftp_command(&conn, &[]).unwrap();
+        let cmd = build_sftp_command(&conn, &[], &SftpTransferOptions::default()).unwrap();
         assert_eq!(cmd, vec!["sftp", "-P", "2222", "root@host.local"]);
     }
 
     #[test]
     fn test_build_sftp_command_non_ssh() {
         let conn = Connection::new_rdp("Test".to_string(), "server.example.com".to_string(), 3389);
-        assert!(build_sftp_command(&conn, &[]).is_none());
+        assert!(build_sftp_command(&conn, &[], &SftpTransferOptions::default()).is_none());
     }
 
     #[test]
@@ -1007,6 +1352,38 @@ mod tests {
         assert_eq!(cmd[3], "sh://root@host.local:2222/~");
     }
 
+    #[test]
+    fn test_downloads_location_asks_portal_in_flatpak() {
+        let granted = tempfile::tempdir().unwrap();
+        let stale = granted.path().join("stale");
+        let granted_dir = granted.path().to_string_lossy().into_owned();
+
+        // Outside Flatpak the downloads directory is used as before
+        let downloads = DownloadsLocation::Directory(get_downloads_dir());
+        assert_eq!(select_downloads_location(false, None), downloads);
+        assert_eq!(
+            select_downloads_location(false, Some(granted.path())),
+            downloads
+        );
+
+        assert_eq!(
+            select_downloads_location(true, None),
+            DownloadsLocation::Portal
+        );
+        assert_eq!(
+            select_downloads_location(true, Some(&stale)),
+            DownloadsLocation::Portal
+        );
+        assert_eq!(
+            select_downloads_location(true, Some(granted.path())),
+            DownloadsLocation::Directory(granted_dir.clone())
+        );
+
+        let conn = Connection::new_ssh("Test".to_string(), "host.local".to_string(), 22);
+        let cmd = build_mc_sftp_command_in(&conn, &[], &granted_dir).unwrap();
+        assert_eq!(cmd[2], granted_dir);
+    }
+
     #[test]
     fn test_build_mc_sftp_command_non_ssh() {
         let conn = Connection::new_rdp("Test".to_string(), "server.example.com".to_string(), 3389);
@@ -1115,4 +1492,101 @@ mod tests {
             assert!(!path.is_empty());
         }
     }
+
+    #[test]
+    fn test_build_sftp_command_resume() {
+        let mut conn = Connection::new_ssh("Test".to_string(), "host.local".to_string(), 2222);
+        conn.username = Some("root".to_string());
+        let options = SftpTransferOptions::default().with_resume(true);
+
+        let cmd = build_sftp_command(&conn, &[], &options).unwrap();
+        assert_eq!(cmd, vec!["sftp", "-a", "-P", "2222", "root@host.local"]);
+
+        let cmd = build_sftp_transfer_command(&conn, &[], &options).unwrap();
+        assert_eq!(
+            cmd,
+            vec!["sftp", "-a", "-P", "2222", "-b", "-", "root@host.local"]
+        );
+    }
+
+    #[test]
+    fn test_transfer_batch_commands() {
+        let download = SftpTransfer::download("/var/log/big file.tar", "/tmp/big.tar");
+        assert_eq!(
+            download.batch_command(false),
+            r#"get "/var/log/big file.tar" "/tmp/big.tar""#
+        );
+        assert_eq!(
+            download.batch_command(true),
+            r#"reget "/var/log/big file.tar" "/tmp/big.tar""#
+        );
+
+        let upload = SftpTransfer::upload("/tmp/say \"hi\".txt", "upload.txt");
+        assert_eq!(
+            upload.batch_command(true),
+            r#"reput "/tmp/say \"hi\".txt" "upload.txt""#
+        );
+    }
+
+    #[test]
+    fn test_resume_plan() {
+        assert_eq!(resume_plan(None, 1000), ResumePlan::Fresh);
+        assert_eq!(resume_plan(Some(0), 1000), ResumePlan::Fresh);
+        assert_eq!(
+            resume_plan(Some(400), 1000),
+            ResumePlan::Resume { offset: 400 }
+        );
+        assert_eq!(resume_plan(Some(1000), 1000), ResumePlan::Complete);
+        assert_eq!(resume_plan(Some(1200), 1000), ResumePlan::Restart);
+    }
+
+    #[test]
+    fn test_download_resume_plan_reads_partial_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let partial = dir.path().join("partial.bin");
+        assert_eq!(download_resume_plan(&partial, 10), ResumePlan::Fresh);
+
+        std::fs::write(&partial, [0u8; 4]).unwrap();
+        assert_eq!(
+            download_resume_plan(&partial, 10),
+            ResumePlan::Resume { offset: 4 }
+        );
+    }
+
+    #[test]
+    fn test_build_remote_sha256_command() {
+        let mut conn = Connection::new_ssh("Test".to_string(), "host.local".to_string(), 2222);
+        conn.username = Some("root".to_string());
+
+        let cmd = build_remote_sha256_command(&conn, &[], "/srv/it's.iso").unwrap();
+        assert_eq!(
+            cmd,
+            vec![
+                "ssh",
+                "-o",
+                "BatchMode=yes",
+                "-p",
+                "2222",
+                "root@host.local",
+                "sha256sum -- '/srv/it'\\''s.iso'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_sha256() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("hello.txt");
+        std::fs::write(&file, b"hello\n").unwrap();
+        let digest = "5891b5b522d5df086d0ff0b110fbd9d21bb4fc7163af34d08286a2e846f6be03";
+
+        assert_eq!(local_sha256(&file).unwrap(), digest);
+        assert!(verify_sha256(&file, &format!("{digest}  /srv/hello.txt\n")).unwrap());
+        assert!(!verify_sha256(&file, &format!("{}  x", "0".repeat(64))).unwrap());
+        assert!(verify_sha256(&file, "sha256sum: /srv/hello.txt: No such file").is_err());
+        assert_eq!(
+            parse_sha256sum_output(&digest.to_ascii_uppercase()).as_deref(),
+            Some(digest)
+        );
+    }
 }
@@ -7,11 +7,15 @@ use std::collections::{HashMap, HashSet};
 
 use uuid::Uuid;
 
+use super::{SnippetError, SnippetResult};
 use crate::config::ConfigManager;
 use crate::error::{ConfigError, ConfigResult};
 use crate::models::{Snippet, SnippetVariable};
 use crate::variables::VARIABLE_REGEX;
 
+/// Placeholder shown instead of secret values in previews
+const MASKED_VALUE: &str = "********";
+
 /// Manager for snippet CRUD operations
 ///
 /// Provides in-memory storage with persistence through `ConfigManager`.
@@ -484,6 +488,78 @@ impl SnippetManager {
             .collect()
     }
 
+    /// Returns the variables of `snippet` that still need a value, sorted by
+    /// name
+    ///
+    /// Placeholders without a definition in `snippet.variables` are returned
+    /// as plain text variables. Variables with a default are included, so a
+    /// prompt can offer the default; [`Self::expand`] falls back to it.
+    #[must_use]
+    pub fn unset_variables(
+        snippet: &Snippet,
+        values: &HashMap<String, String>,
+    ) -> Vec<SnippetVariable> {
+        Self::extract_variables(&snippet.command)
+            .into_iter()
+            .filter(|name| !values.contains_key(name))
+            .map(|name| {
+                snippet
+                    .variables
+                    .iter()
+                    .find(|v| v.name == name)
+                    .cloned()
+                    .unwrap_or_else(|| SnippetVariable::new(name))
+            })
+            .collect()
+    }
+
+    /// Expands a snippet after checking that every variable has a valid value
+    ///
+    /// Values come from `values`, falling back to the variable defaults.
+    /// Choice variables must resolve to one of their options.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnippetError::MissingVariables`] if any placeholder has
+    /// neither a value nor a default, and [`SnippetError::InvalidChoice`] if a
+    /// choice variable has a value outside its options.
+    pub fn expand(snippet: &Snippet, values: &HashMap<String, String>) -> SnippetResult<String> {
+        let missing = Self::get_missing_variables(snippet, values);
+        if !missing.is_empty() {
+            return Err(SnippetError::MissingVariables(missing));
+        }
+
+        for var in &snippet.variables {
+            let Some(options) = var.kind.options() else {
+                continue;
+            };
+            let value = values.get(&var.name).or(var.default_value.as_ref());
+            if let Some(value) = value
+                && !options.contains(value)
+            {
+                return Err(SnippetError::InvalidChoice {
+                    name: var.name.clone(),
+                    value: value.clone(),
+                    options: options.to_vec(),
+                });
+            }
+        }
+
+        Ok(Self::substitute_with_defaults(snippet, values))
+    }
+
+    /// Expands a snippet for display, with secret variables masked
+    ///
+    /// Performs no validation; use [`Self::expand`] for the command to run.
+    #[must_use]
+    pub fn expand_masked(snippet: &Snippet, values: &HashMap<String, String>) -> String {
+        let mut masked = values.clone();
+        for var in snippet.variables.iter().filter(|v| v.kind.is_secret()) {
+            masked.insert(var.name.clone(), MASKED_VALUE.to_string());
+        }
+        Self::substitute_with_defaults(snippet, &masked)
+    }
+
     // ========== Persistence ==========
 
     /// Persists all snippets to storage
@@ -511,6 +587,7 @@ mod tests {
     use tempfile::TempDir;
 
     use super::*;
+    use crate::models::SnippetVariableKind;
 
     fn create_test_manager() -> (SnippetManager, TempDir) {
         let temp_dir = TempDir::new().unwrap();
@@ -588,6 +665,38 @@ mod tests {
         assert_eq!(net_snippets.len(), 1);
     }
 
+    #[test]
+    fn test_load_snippets_without_category_or_tags() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("snippets.toml"),
+            r#"
+[[snippets]]
+id = "5b0c1c2e-7d4a-4f6e-9a43-2f1e0b8c6d11"
+name = "Uptime"
+command = "uptime"
+
+[[snippets]]
+id = "0d9e8f7a-6b5c-4d3e-8f21-a0b1c2d3e4f5"
+name = "Tail syslog"
+command = "tail -f /var/log/syslog"
+category = "logs"
+"#,
+        )
+        .unwrap();
+
+        let config_manager = ConfigManager::with_config_dir(temp_dir.path().to_path_buf());
+        let manager = SnippetManager::new(config_manager).unwrap();
+
+        assert_eq!(manager.snippet_count(), 2);
+        let uncategorized = manager.get_uncategorized();
+        assert_eq!(uncategorized.len(), 1);
+        assert_eq!(uncategorized[0].name, "Uptime");
+        assert!(uncategorized[0].tags.is_empty());
+        assert_eq!(manager.get_all_categories(), vec!["logs".to_string()]);
+        assert_eq!(manager.get_by_category("Logs").len(), 1);
+    }
+
     #[test]
     fn test_filter_by_tag() {
         let (mut manager, _temp) = create_test_manager();
@@ -785,4 +894,75 @@ mod tests {
         assert!(tags.contains(&"tag1".to_string()));
         assert!(tags.contains(&"tag2".to_string()));
     }
+
+    #[test]
+    fn test_expand_requires_prompt_variables() {
+        let snippet = Snippet::new(
+            "Close ticket".to_string(),
+            "ticket close ${ticket_id} --env ${env} --token ${token}".to_string(),
+        )
+        .with_variables(vec![
+            SnippetVariable::new("ticket_id".to_string()),
+            SnippetVariable::new("env".to_string())
+                .with_choices(["staging", "prod"])
+                .with_default("staging"),
+            SnippetVariable::new("token".to_string()).with_kind(SnippetVariableKind::Secret),
+        ]);
+
+        let mut values = HashMap::new();
+        values.insert("ticket_id".to_string(), "OPS-42".to_string());
+        assert_eq!(
+            SnippetManager::expand(&snippet, &values),
+            Err(SnippetError::MissingVariables(vec!["token".to_string()]))
+        );
+
+        let unset: Vec<String> = SnippetManager::unset_variables(&snippet, &values)
+            .into_iter()
+            .map(|v| v.name)
+            .collect();
+        assert_eq!(unset, vec!["env", "token"]);
+
+        values.insert("token".to_string(), "s3cret".to_string());
+        assert_eq!(
+            SnippetManager::expand(&snippet, &values).unwrap(),
+            "ticket close OPS-42 --env staging --token s3cret"
+        );
+        assert_eq!(
+            SnippetManager::expand_masked(&snippet, &values),
+            "ticket close OPS-42 --env staging --token ********"
+        );
+    }
+
+    #[test]
+    fn test_expand_rejects_unknown_choice() {
+        let snippet = Snippet::new("Deploy".to_string(), "deploy ${env}".to_string())
+            .with_variables(vec![
+                SnippetVariable::new("env".to_string()).with_choices(["staging", "prod"]),
+            ]);
+
+        let mut values = HashMap::new();
+        values.insert("env".to_string(), "qa".to_string());
+        assert!(matches!(
+            SnippetManager::expand(&snippet, &values),
+            Err(SnippetError::InvalidChoice { ref name, .. }) if name == "env"
+        ));
+
+        values.insert("env".to_string(), "prod".to_string());
+        assert_eq!(
+            SnippetManager::expand(&snippet, &values).unwrap(),
+            "deploy prod"
+        );
+    }
+
+    #[test]
+    fn test_variable_kind_serialization() {
+        let choice = SnippetVariable::new("env".to_string()).with_choices(["a", "b"]);
+        let toml = toml::to_string(&choice).unwrap();
+        let parsed: SnippetVariable = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed, choice);
+
+        // Text variables keep the old on-disk format
+        let text = toml::to_string(&SnippetVariable::new("host".to_string())).unwrap();
+        assert!(!text.contains("kind"));
+    }
 }
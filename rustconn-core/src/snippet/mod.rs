@@ -6,3 +6,26 @@
 mod manager;
 
 pub use manager::SnippetManager;
+use thiserror::Error;
+
+/// Errors that can occur while expanding a snippet
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum SnippetError {
+    /// Variables without a value or default
+    #[error("Missing required variables: {}", .0.join(", "))]
+    MissingVariables(Vec<String>),
+
+    /// A choice variable was given a value outside its options
+    #[error("Invalid value '{value}' for '{name}'; expected one of: {}", .options.join(", "))]
+    InvalidChoice {
+        /// Variable name
+        name: String,
+        /// Rejected value
+        value: String,
+        /// Allowed options
+        options: Vec<String>,
+    },
+}
+
+/// Result type for snippet expansion
+pub type SnippetResult<T> = std::result::Result<T, SnippetError>;
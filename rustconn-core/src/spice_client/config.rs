@@ -6,11 +6,16 @@
     reason = "module-wide override for legacy code; refactored case by case"
 )]
 
+use std::fmt;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use secrecy::SecretString;
 use serde::{Deserialize, Serialize};
 
+use super::SpiceClientError;
+use crate::models::SharedFolder;
+
 /// Configuration for SPICE client connection
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpiceClientConfig {
@@ -45,6 +50,11 @@ pub struct SpiceClientConfig {
     /// Enable USB redirection
     pub usb_redirection: bool,
 
+    /// USB devices to redirect automatically on connect. When non-empty,
+    /// only these devices are auto-redirected.
+    #[serde(default)]
+    pub usb_filters: Vec<SpiceUsbFilter>,
+
     /// Shared folders for webdav
     pub shared_folders: Vec<SpiceSharedFolder>,
 
@@ -132,6 +142,117 @@ impl SpiceSharedFolder {
         self.read_only = read_only;
         self
     }
+
+    /// Returns `true` if the local directory exists
+    #[must_use]
+    pub fn is_available(&self) -> bool {
+        self.local_path.is_dir()
+    }
+}
+
+impl From<&SharedFolder> for SpiceSharedFolder {
+    fn from(folder: &SharedFolder) -> Self {
+        Self::new(&folder.local_path, &folder.share_name).with_read_only(folder.read_only)
+    }
+}
+
+/// A USB device to redirect, identified as `vendor:product`
+///
+/// Parsed from and displayed as four-digit hex IDs, e.g. `1050:0407` for a
+/// `YubiKey`. A `0x` prefix on either half is accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SpiceUsbFilter {
+    /// USB vendor ID
+    pub vendor_id: u16,
+    /// USB product ID
+    pub product_id: u16,
+}
+
+impl SpiceUsbFilter {
+    /// Creates a filter for one device
+    #[must_use]
+    pub const fn new(vendor_id: u16, product_id: u16) -> Self {
+        Self {
+            vendor_id,
+            product_id,
+        }
+    }
+
+    /// Parses a list of `vendor:product` filters
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SpiceClientError::UsbRedirectionError`] for the first
+    /// invalid entry.
+    pub fn parse_list<I, S>(filters: I) -> Result<Vec<Self>, SpiceClientError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        filters.into_iter().map(|f| f.as_ref().parse()).collect()
+    }
+
+    /// Returns the `class,vendor,product,version,allow` rule that allows
+    /// this device
+    #[must_use]
+    pub fn rule(&self) -> String {
+        format!("-1,0x{:04x},0x{:04x},-1,1", self.vendor_id, self.product_id)
+    }
+
+    /// Builds an auto-redirect filter that allows exactly `filters` and
+    /// rejects every other device
+    #[must_use]
+    pub fn auto_redirect_filter(filters: &[Self]) -> String {
+        let mut rules: Vec<String> = filters.iter().map(Self::rule).collect();
+        rules.push("-1,-1,-1,-1,0".to_string());
+        rules.join("|")
+    }
+}
+
+impl FromStr for SpiceUsbFilter {
+    type Err = SpiceClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            SpiceClientError::UsbRedirectionError(format!(
+                "invalid USB filter '{s}', expected vendor:product in hex (e.g. 1050:0407)"
+            ))
+        };
+        let parse_id = |part: &str| {
+            let hex = part
+                .strip_prefix("0x")
+                .or_else(|| part.strip_prefix("0X"))
+                .unwrap_or(part);
+            if hex.is_empty() || hex.len() > 4 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(invalid());
+            }
+            u16::from_str_radix(hex, 16).map_err(|_| invalid())
+        };
+
+        let (vendor, product) = s.trim().split_once(':').ok_or_else(invalid)?;
+        Ok(Self::new(parse_id(vendor)?, parse_id(product)?))
+    }
+}
+
+impl fmt::Display for SpiceUsbFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04x}:{:04x}", self.vendor_id, self.product_id)
+    }
+}
+
+impl TryFrom<String> for SpiceUsbFilter {
+    type Error = SpiceClientError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<SpiceUsbFilter> for String {
+    fn from(filter: SpiceUsbFilter) -> Self {
+        filter.to_string()
+    }
 }
 
 impl Default for SpiceClientConfig {
@@ -147,6 +268,7 @@ impl Default for SpiceClientConfig {
             skip_cert_verify: false,
             clipboard_enabled: true,
             usb_redirection: false,
+            usb_filters: Vec::new(),
             shared_folders: Vec::new(),
             image_compression: SpiceImageCompression::default(),
             audio_playback: true,
@@ -227,6 +349,27 @@ impl SpiceClientConfig {
         self
     }
 
+    /// Adds a USB device to auto-redirect
+    #[must_use]
+    pub fn with_usb_filter(mut self, filter: SpiceUsbFilter) -> Self {
+        self.usb_filters.push(filter);
+        self
+    }
+
+    /// Returns the `remote-viewer` auto-redirect filter, or `None` when USB
+    /// redirection is off and no device filters are set
+    ///
+    /// Device filters allow exactly the listed devices; plain USB
+    /// redirection uses [`super::SPICE_USB_AUTO_REDIRECT_FILTER`].
+    #[must_use]
+    pub fn usb_auto_redirect_filter(&self) -> Option<String> {
+        if !self.usb_filters.is_empty() {
+            return Some(SpiceUsbFilter::auto_redirect_filter(&self.usb_filters));
+        }
+        self.usb_redirection
+            .then(|| super::SPICE_USB_AUTO_REDIRECT_FILTER.to_string())
+    }
+
     /// Adds a shared folder
     #[must_use]
     pub fn with_shared_folder(mut self, folder: SpiceSharedFolder) -> Self {
@@ -303,15 +446,7 @@ impl SpiceClientConfig {
                 return Err("Unix socket path cannot be empty".to_string());
             }
             // Shared folders still need validation
-            for folder in &self.shared_folders {
-                if folder.share_name.is_empty() {
-                    return Err("Shared folder name cannot be empty".to_string());
-                }
-                if folder.local_path.as_os_str().is_empty() {
-                    return Err("Shared folder path cannot be empty".to_string());
-                }
-            }
-            return Ok(());
+            return self.validate_shared_folders();
         }
 
         // TCP mode — validate host:port
@@ -327,6 +462,22 @@ impl SpiceClientConfig {
                     .to_string(),
             );
         }
+        self.validate_shared_folders()
+    }
+
+    /// Returns the shared folders whose local directory does not exist
+    #[must_use]
+    pub fn missing_shared_folders(&self) -> Vec<&SpiceSharedFolder> {
+        self.shared_folders
+            .iter()
+            .filter(|folder| !folder.is_available())
+            .collect()
+    }
+
+    /// Rejects shared folders without a name or path and warns about
+    /// missing directories, which are left out of the session rather than
+    /// failing it (e.g. a share on a drive that is not mounted)
+    fn validate_shared_folders(&self) -> Result<(), String> {
         for folder in &self.shared_folders {
             if folder.share_name.is_empty() {
                 return Err("Shared folder name cannot be empty".to_string());
@@ -335,6 +486,13 @@ impl SpiceClientConfig {
                 return Err("Shared folder path cannot be empty".to_string());
             }
         }
+        for folder in self.missing_shared_folders() {
+            tracing::warn!(
+                share = %folder.share_name,
+                path = %folder.local_path.display(),
+                "SPICE shared folder does not exist"
+            );
+        }
         Ok(())
     }
 }
@@ -343,6 +501,25 @@ impl SpiceClientConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_missing_shared_folders_warn_without_failing() {
+        let share = tempfile::tempdir().unwrap();
+        let missing = share.path().join("missing");
+        let config = SpiceClientConfig::new("localhost")
+            .with_shared_folder(SpiceSharedFolder::new(share.path(), "MyShare"))
+            .with_shared_folder(SpiceSharedFolder::new(&missing, "Gone").with_read_only(true));
+
+        assert!(config.validate().is_ok());
+        let missing_folders = config.missing_shared_folders();
+        assert_eq!(missing_folders.len(), 1);
+        assert_eq!(missing_folders[0].local_path, missing);
+
+        let unnamed = config
+            .clone()
+            .with_shared_folder(SpiceSharedFolder::new(share.path(), ""));
+        assert!(unnamed.validate().is_err());
+    }
+
     #[test]
     fn test_config_builder() {
         let config = SpiceClientConfig::new("192.168.1.100")
@@ -463,6 +640,74 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_usb_filter_parsing() {
+        assert_eq!(
+            "1050:0407".parse::<SpiceUsbFilter>().unwrap(),
+            SpiceUsbFilter::new(0x1050, 0x0407)
+        );
+        assert_eq!(
+            " 0x08E6:0X3437 ".parse::<SpiceUsbFilter>().unwrap(),
+            SpiceUsbFilter::new(0x08e6, 0x3437)
+        );
+        assert_eq!(SpiceUsbFilter::new(0x1050, 0x7).to_string(), "1050:0007");
+
+        for bad in [
+            "",
+            "1050",
+            "1050:",
+            ":0407",
+            "10500:0407",
+            "xyz:0407",
+            "1050:0407:1",
+        ] {
+            assert!(
+                matches!(
+                    bad.parse::<SpiceUsbFilter>(),
+                    Err(SpiceClientError::UsbRedirectionError(_))
+                ),
+                "{bad:?} must be rejected"
+            );
+        }
+
+        assert_eq!(
+            SpiceUsbFilter::parse_list(["1050:0407", "08e6:3437"])
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(SpiceUsbFilter::parse_list(["1050:0407", "bogus"]).is_err());
+    }
+
+    #[test]
+    fn test_usb_filter_serde_as_string() {
+        let json = serde_json::to_string(&SpiceUsbFilter::new(0x1050, 0x0407)).unwrap();
+        assert_eq!(json, "\"1050:0407\"");
+        assert!(serde_json::from_str::<SpiceUsbFilter>("\"nope\"").is_err());
+    }
+
+    #[test]
+    fn test_usb_auto_redirect_filter() {
+        assert_eq!(
+            SpiceClientConfig::default().usb_auto_redirect_filter(),
+            None
+        );
+        assert_eq!(
+            SpiceClientConfig::default()
+                .with_usb_redirection(true)
+                .usb_auto_redirect_filter()
+                .as_deref(),
+            Some(super::super::SPICE_USB_AUTO_REDIRECT_FILTER)
+        );
+        let config = SpiceClientConfig::default()
+            .with_usb_filter(SpiceUsbFilter::new(0x1050, 0x0407))
+            .with_usb_filter(SpiceUsbFilter::new(0x08e6, 0x3437));
+        assert_eq!(
+            config.usb_auto_redirect_filter().as_deref(),
+            Some("-1,0x1050,0x0407,-1,1|-1,0x08e6,0x3437,-1,1|-1,-1,-1,-1,0")
+        );
+    }
+
     #[test]
     fn test_security_protocol_default() {
         assert_eq!(
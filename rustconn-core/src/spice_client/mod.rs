@@ -23,7 +23,7 @@ use std::path::Path;
 
 pub use config::{
     SpiceClientConfig, SpiceImageCompression as SpiceCompression, SpiceSecurityProtocol,
-    SpiceSharedFolder,
+    SpiceSharedFolder, SpiceUsbFilter,
 };
 pub use error::SpiceClientError;
 
@@ -52,6 +52,38 @@ pub fn build_spice_uri(
     }
 }
 
+/// Returns the directories to share with the viewer via `--spice-shared-dir`
+///
+/// Folders whose directory is missing are skipped with a warning so the
+/// session still starts. `remote-viewer` has no option to share a directory
+/// read-only, so read-only folders are skipped with a warning too instead of
+/// being shared writable.
+#[must_use]
+pub fn viewer_shared_dirs(folders: &[SpiceSharedFolder]) -> Vec<&Path> {
+    folders
+        .iter()
+        .filter(|folder| {
+            if !folder.is_available() {
+                tracing::warn!(
+                    share = %folder.share_name,
+                    path = %folder.local_path.display(),
+                    "SPICE shared folder does not exist, not sharing it"
+                );
+                return false;
+            }
+            if folder.read_only {
+                tracing::warn!(
+                    share = %folder.share_name,
+                    "SPICE viewer cannot share folders read-only, not sharing it"
+                );
+                return false;
+            }
+            true
+        })
+        .map(|folder| folder.local_path.as_path())
+        .collect()
+}
+
 /// Detects available SPICE viewer applications for fallback mode
 ///
 /// Returns the path to the first available SPICE viewer, or None if none found.
@@ -114,16 +146,16 @@ pub fn build_spice_viewer_args(config: &SpiceClientConfig) -> Vec<String> {
         args.push(format!("SPICE: {}", config.host));
     }
 
-    // USB redirection
-    if config.usb_redirection {
+    // USB redirection: listed devices only, or the default filter
+    if let Some(filter) = config.usb_auto_redirect_filter() {
         args.push("--spice-usbredir-auto-redirect-filter".to_string());
-        args.push(SPICE_USB_AUTO_REDIRECT_FILTER.to_string());
+        args.push(filter);
     }
 
     // Shared folders (webdav)
-    for folder in &config.shared_folders {
+    for dir in viewer_shared_dirs(&config.shared_folders) {
         args.push("--spice-shared-dir".to_string());
-        args.push(folder.local_path.to_string_lossy().to_string());
+        args.push(dir.to_string_lossy().to_string());
     }
 
     // TLS options
@@ -187,14 +219,44 @@ mod tests {
         assert!(args.contains(&"--spice-usbredir-auto-redirect-filter".to_string()));
     }
 
+    #[test]
+    fn test_build_spice_viewer_args_with_usb_filters() {
+        let config =
+            SpiceClientConfig::new("localhost").with_usb_filter("1050:0407".parse().unwrap());
+        let args = build_spice_viewer_args(&config);
+
+        let pos = args
+            .iter()
+            .position(|a| a == "--spice-usbredir-auto-redirect-filter")
+            .expect("filter flag emitted without the USB switch");
+        assert_eq!(args[pos + 1], "-1,0x1050,0x0407,-1,1|-1,-1,-1,-1,0");
+        assert_eq!(
+            args.iter()
+                .filter(|a| *a == "--spice-usbredir-auto-redirect-filter")
+                .count(),
+            1
+        );
+    }
+
     #[test]
     fn test_build_spice_viewer_args_with_shared_folder() {
-        let folder = SpiceSharedFolder::new("/home/user/share", "MyShare");
-        let config = SpiceClientConfig::new("localhost").with_shared_folder(folder);
+        let share = tempfile::tempdir().unwrap();
+        let read_only = tempfile::tempdir().unwrap();
+        let missing = share.path().join("missing");
+        let config = SpiceClientConfig::new("localhost")
+            .with_shared_folder(SpiceSharedFolder::new(share.path(), "MyShare"))
+            .with_shared_folder(SpiceSharedFolder::new(&missing, "Gone"))
+            .with_shared_folder(
+                SpiceSharedFolder::new(read_only.path(), "Docs").with_read_only(true),
+            );
         let args = build_spice_viewer_args(&config);
 
-        assert!(args.contains(&"--spice-shared-dir".to_string()));
-        assert!(args.contains(&"/home/user/share".to_string()));
+        let dirs: Vec<&str> = args
+            .windows(2)
+            .filter(|w| w[0] == "--spice-shared-dir")
+            .map(|w| w[1].as_str())
+            .collect();
+        assert_eq!(dirs, [share.path().to_str().unwrap()]);
     }
 
     #[test]
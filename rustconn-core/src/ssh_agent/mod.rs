@@ -3,7 +3,10 @@
 //! This module provides functionality for interacting with the SSH agent,
 //! including starting the agent, managing keys, and parsing agent output.
 
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -30,6 +33,61 @@ pub struct AgentKey {
     pub key_type: String,
     /// Key comment (usually the key file path or email)
     pub comment: String,
+    /// Seconds until the agent drops the key, if it was added with a lifetime
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lifetime_remaining: Option<u64>,
+    /// Whether the agent asks for confirmation before each use of the key
+    #[serde(default)]
+    pub confirm: bool,
+}
+
+/// Constraints applied when adding a key to the agent
+///
+/// Maps to the `-t` and `-c` options of `ssh-add`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AddKeyOptions {
+    /// Drop the key from the agent after this long
+    pub lifetime: Option<Duration>,
+    /// Ask for confirmation (via `ssh-askpass`) each time the key is used
+    pub confirm: bool,
+}
+
+impl AddKeyOptions {
+    /// Sets the key lifetime
+    #[must_use]
+    pub const fn with_lifetime(mut self, lifetime: Duration) -> Self {
+        self.lifetime = Some(lifetime);
+        self
+    }
+
+    /// Requires confirmation before each use of the key
+    #[must_use]
+    pub const fn with_confirm(mut self, confirm: bool) -> Self {
+        self.confirm = confirm;
+        self
+    }
+
+    /// Returns true if no constraint is set
+    #[must_use]
+    pub const fn is_unconstrained(&self) -> bool {
+        self.lifetime.is_none() && !self.confirm
+    }
+
+    /// Returns the `ssh-add` arguments for these constraints
+    ///
+    /// Lifetimes are rounded down to whole seconds, with a minimum of one.
+    #[must_use]
+    pub fn ssh_add_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(lifetime) = self.lifetime {
+            args.push("-t".to_string());
+            args.push(lifetime.as_secs().max(1).to_string());
+        }
+        if self.confirm {
+            args.push("-c".to_string());
+        }
+        args
+    }
 }
 
 /// Errors related to SSH agent operations
@@ -281,12 +339,321 @@ pub fn parse_key_list(output: &str) -> AgentResult<Vec<AgentKey>> {
             bits,
             key_type,
             comment,
+            lifetime_remaining: None,
+            confirm: false,
         });
     }
 
     Ok(keys)
 }
 
+// ============================================================================
+// Key Constraints
+// ============================================================================
+
+/// Constraints `RustConn` applied to a key it added, keyed by fingerprint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct KeyConstraints {
+    expires_at: Option<Instant>,
+    confirm: bool,
+}
+
+/// `ssh-add -l` does not report key constraints, so the ones `RustConn` sets
+/// are remembered here for the lifetime of the process.
+static KEY_CONSTRAINTS: OnceLock<Mutex<HashMap<String, KeyConstraints>>> = OnceLock::new();
+
+fn key_constraints() -> &'static Mutex<HashMap<String, KeyConstraints>> {
+    KEY_CONSTRAINTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Records the constraints a key was added with
+fn record_key_constraints(fingerprint: String, options: &AddKeyOptions, now: Instant) {
+    let constraints = KeyConstraints {
+        expires_at: options.lifetime.map(|lifetime| now + lifetime),
+        confirm: options.confirm,
+    };
+    if let Ok(mut map) = key_constraints().lock() {
+        if options.is_unconstrained() {
+            map.remove(&fingerprint);
+        } else {
+            map.insert(fingerprint, constraints);
+        }
+    }
+}
+
+/// Fills in lifetime and confirmation for listed keys from recorded
+/// constraints, dropping records whose lifetime has run out
+fn apply_key_constraints(
+    keys: &mut [AgentKey],
+    constraints: &mut HashMap<String, KeyConstraints>,
+    now: Instant,
+) {
+    constraints.retain(|_, c| c.expires_at.is_none_or(|at| at > now));
+    for key in keys {
+        if let Some(c) = constraints.get(&key.fingerprint) {
+            key.lifetime_remaining = c
+                .expires_at
+                .map(|at| at.saturating_duration_since(now).as_secs());
+            key.confirm = c.confirm;
+        }
+    }
+}
+
+/// Returns the fingerprint of a key file using `ssh-keygen -l`
+///
+/// Works without the passphrase: `ssh-keygen` reads the public half of
+/// the key or the adjacent `.pub` file.
+fn key_file_fingerprint(key_path: &std::path::Path) -> Option<String> {
+    let output = std::process::Command::new("ssh-keygen")
+        .arg("-l")
+        .arg("-f")
+        .arg(key_path)
+        .stdin(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_key_list(&String::from_utf8_lossy(&output.stdout))
+        .ok()?
+        .into_iter()
+        .next()
+        .map(|key| key.fingerprint)
+}
+
+/// Remembers the constraints of a key that was just added to the agent
+pub(crate) fn remember_added_key(key_path: &std::path::Path, options: &AddKeyOptions) {
+    if let Some(fingerprint) = key_file_fingerprint(key_path) {
+        record_key_constraints(fingerprint, options, Instant::now());
+    } else if !options.is_unconstrained() {
+        tracing::debug!(
+            ?key_path,
+            "Could not fingerprint key; constraints not tracked"
+        );
+    }
+}
+
+// ============================================================================
+// ssh-add Invocation
+// ============================================================================
+
+/// Environment variable the askpass helper reads the passphrase from
+const ASKPASS_SECRET_ENV: &str = "RUSTCONN_ASKPASS_SECRET";
+
+/// Askpass helper: prints the passphrase from the environment, so the
+/// script itself holds no secret
+const ASKPASS_SCRIPT: &str = "#!/bin/sh\nprintf '%s\\n' \"$RUSTCONN_ASKPASS_SECRET\"\n";
+
+/// Writes the askpass helper into `dir` (created if missing) and returns
+/// its path. On Unix the script is made executable by the owner only.
+fn write_askpass_script(dir: &std::path::Path) -> AgentResult<PathBuf> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| AgentError::AddKeyFailed(format!("mkdir askpass: {e}")))?;
+    let script_path = dir.join("askpass.sh");
+
+    std::fs::write(&script_path, ASKPASS_SCRIPT.as_bytes())
+        .map_err(|e| AgentError::AddKeyFailed(format!("write askpass: {e}")))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o700))
+            .map_err(|e| AgentError::AddKeyFailed(format!("chmod askpass: {e}")))?;
+    }
+
+    Ok(script_path)
+}
+
+/// Builds `ssh-add [-t N] [-c] <key>` against the given agent socket
+fn ssh_add_command(
+    socket_path: &str,
+    key_path: &std::path::Path,
+    options: &AddKeyOptions,
+) -> std::process::Command {
+    let mut cmd = std::process::Command::new("ssh-add");
+    cmd.args(options.ssh_add_args())
+        .arg(key_path)
+        .env("SSH_AUTH_SOCK", socket_path);
+    cmd
+}
+
+/// Builds an `ssh-add` command that reads the passphrase through the
+/// askpass helper rather than a terminal. The caller sets
+/// [`ASKPASS_SECRET_ENV`].
+fn askpass_command(
+    socket_path: &str,
+    key_path: &std::path::Path,
+    options: &AddKeyOptions,
+    script_path: &std::path::Path,
+) -> std::process::Command {
+    use std::process::Stdio;
+
+    let mut cmd = ssh_add_command(socket_path, key_path, options);
+    cmd.env("SSH_ASKPASS", script_path)
+        .env("SSH_ASKPASS_REQUIRE", "force")
+        .env("DISPLAY", ":0")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    cmd
+}
+
+#[cfg(test)]
+mod ssh_add_tests {
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    use super::*;
+
+    fn env_value<'a>(cmd: &'a std::process::Command, key: &str) -> Option<&'a OsStr> {
+        cmd.get_envs()
+            .find(|(k, _)| *k == OsStr::new(key))
+            .and_then(|(_, v)| v)
+    }
+
+    #[test]
+    fn test_askpass_script_holds_no_secret() {
+        let dir = tempfile::tempdir().unwrap();
+        let script_path = write_askpass_script(&dir.path().join("askpass")).unwrap();
+
+        let script = std::fs::read_to_string(&script_path).unwrap();
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains(&format!("\"${ASKPASS_SECRET_ENV}\"")));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&script_path)
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o700);
+        }
+    }
+
+    #[test]
+    fn test_ssh_add_command_args() {
+        let options = AddKeyOptions::default()
+            .with_lifetime(Duration::from_secs(900))
+            .with_confirm(true);
+        let cmd = ssh_add_command("/tmp/agent.sock", Path::new("/keys/id_ed25519"), &options);
+
+        assert_eq!(cmd.get_program(), "ssh-add");
+        let args: Vec<&OsStr> = cmd.get_args().collect();
+        assert_eq!(args, ["-t", "900", "-c", "/keys/id_ed25519"]);
+        assert_eq!(
+            env_value(&cmd, "SSH_AUTH_SOCK"),
+            Some(OsStr::new("/tmp/agent.sock"))
+        );
+    }
+
+    #[test]
+    fn test_askpass_command_env() {
+        let cmd = askpass_command(
+            "/tmp/agent.sock",
+            Path::new("/keys/id_rsa"),
+            &AddKeyOptions::default(),
+            Path::new("/tmp/helper/askpass.sh"),
+        );
+
+        let args: Vec<&OsStr> = cmd.get_args().collect();
+        assert_eq!(args, ["/keys/id_rsa"]);
+        assert_eq!(
+            env_value(&cmd, "SSH_ASKPASS"),
+            Some(OsStr::new("/tmp/helper/askpass.sh"))
+        );
+        assert_eq!(
+            env_value(&cmd, "SSH_ASKPASS_REQUIRE"),
+            Some(OsStr::new("force"))
+        );
+        // The passphrase is only added right before spawning
+        assert_eq!(env_value(&cmd, ASKPASS_SECRET_ENV), None);
+    }
+}
+
+#[cfg(test)]
+mod constraint_tests {
+    use super::*;
+
+    const LISTING: &str = "256 SHA256:short /home/user/.ssh/id_ed25519 (ED25519)\n\
+                           4096 SHA256:confirmed deploy@ci (RSA)\n\
+                           256 SHA256:forever user@host (ED25519)";
+
+    #[test]
+    fn test_ssh_add_args() {
+        assert!(AddKeyOptions::default().ssh_add_args().is_empty());
+        assert!(AddKeyOptions::default().is_unconstrained());
+
+        let options = AddKeyOptions::default()
+            .with_lifetime(Duration::from_secs(3600))
+            .with_confirm(true);
+        assert_eq!(options.ssh_add_args(), ["-t", "3600", "-c"]);
+
+        let sub_second = AddKeyOptions::default().with_lifetime(Duration::from_millis(200));
+        assert_eq!(sub_second.ssh_add_args(), ["-t", "1"]);
+    }
+
+    #[test]
+    fn test_listing_with_lifetime_limited_keys() {
+        let now = Instant::now();
+        let mut constraints = HashMap::from([
+            (
+                "SHA256:short".to_string(),
+                KeyConstraints {
+                    expires_at: Some(now + Duration::from_secs(600)),
+                    confirm: false,
+                },
+            ),
+            (
+                "SHA256:confirmed".to_string(),
+                KeyConstraints {
+                    expires_at: Some(now + Duration::from_secs(60)),
+                    confirm: true,
+                },
+            ),
+        ]);
+
+        let mut keys = parse_key_list(LISTING).unwrap();
+        apply_key_constraints(&mut keys, &mut constraints, now + Duration::from_secs(30));
+
+        assert_eq!(keys[0].lifetime_remaining, Some(570));
+        assert!(!keys[0].confirm);
+        assert_eq!(keys[1].lifetime_remaining, Some(30));
+        assert!(keys[1].confirm);
+        assert_eq!(keys[2].lifetime_remaining, None);
+        assert!(!keys[2].confirm);
+    }
+
+    #[test]
+    fn test_expired_constraints_are_dropped() {
+        let now = Instant::now();
+        let mut constraints = HashMap::from([(
+            "SHA256:short".to_string(),
+            KeyConstraints {
+                expires_at: Some(now + Duration::from_secs(10)),
+                confirm: true,
+            },
+        )]);
+
+        let mut keys = parse_key_list(LISTING).unwrap();
+        apply_key_constraints(&mut keys, &mut constraints, now + Duration::from_secs(10));
+
+        assert!(constraints.is_empty());
+        assert_eq!(keys[0].lifetime_remaining, None);
+        assert!(!keys[0].confirm);
+    }
+
+    #[test]
+    fn test_agent_key_deserializes_without_constraints() {
+        let key: AgentKey = serde_json::from_str(
+            r#"{"fingerprint":"SHA256:x","bits":256,"key_type":"ED25519","comment":"c"}"#,
+        )
+        .unwrap();
+        assert_eq!(key.lifetime_remaining, None);
+        assert!(!key.confirm);
+    }
+}
+
 #[cfg(test)]
 mod key_list_tests {
     use super::*;
@@ -550,7 +917,10 @@ impl SshAgentManager {
                     || stdout.contains("no identities")
                     || stderr.contains("no identities")
                 {
-                    let keys = parse_key_list(&stdout).unwrap_or_default();
+                    let mut keys = parse_key_list(&stdout).unwrap_or_default();
+                    if let Ok(mut constraints) = key_constraints().lock() {
+                        apply_key_constraints(&mut keys, &mut constraints, Instant::now());
+                    }
                     Ok(AgentStatus {
                         running: true,
                         socket_path: Some(socket_path),
@@ -575,10 +945,14 @@ impl SshAgentManager {
 
     /// Adds a key to the SSH agent.
     ///
+    /// With a passphrase this is [`Self::add_key_with_passphrase`];
+    /// without one `ssh-add` runs as is.
+    ///
     /// # Arguments
     ///
     /// * `key_path` - Path to the private key file
     /// * `passphrase` - Optional passphrase for encrypted keys (as `SecretString`)
+    /// * `options` - Lifetime and confirmation constraints for the key
     ///
     /// # Errors
     ///
@@ -588,84 +962,84 @@ impl SshAgentManager {
         &self,
         key_path: &std::path::Path,
         passphrase: Option<&secrecy::SecretString>,
+        options: &AddKeyOptions,
     ) -> AgentResult<()> {
-        use std::process::{Command, Stdio};
+        if let Some(pass) = passphrase {
+            return self.add_key_with_passphrase(key_path, pass, options);
+        }
 
         let socket_path = self.socket_path.as_ref().ok_or(AgentError::NotRunning)?;
+        let output = ssh_add_command(socket_path, key_path, options)
+            .output()
+            .map_err(|e| AgentError::AddKeyFailed(e.to_string()))?;
 
-        if let Some(pass) = passphrase {
-            use secrecy::ExposeSecret;
-            use zeroize::Zeroizing;
-
-            // SEC-2: Pass the passphrase via an environment variable instead of
-            // writing it to a temp file. On CoW filesystems (btrfs, APFS) overwriting
-            // with zeros is unreliable — the original data persists in old extents.
-            //
-            // The SSH_ASKPASS script only references the env var by name; the actual
-            // secret lives solely in the child process's memory and is never written
-            // to disk. The env var is not visible in /proc/<pid>/cmdline.
-            let script_dir =
-                std::env::temp_dir().join(format!("rustconn-askpass-{}", uuid::Uuid::new_v4()));
-            std::fs::create_dir_all(&script_dir)
-                .map_err(|e| AgentError::AddKeyFailed(format!("mkdir askpass: {e}")))?;
-            let script_path = script_dir.join("askpass.sh");
-
-            // The script reads the passphrase from an env var — no secret on disk.
-            let script_content = "#!/bin/sh\nprintf '%s\\n' \"$RUSTCONN_ASKPASS_SECRET\"\n";
-            std::fs::write(&script_path, script_content.as_bytes())
-                .map_err(|e| AgentError::AddKeyFailed(format!("write askpass: {e}")))?;
-
-            #[cfg(unix)]
-            {
-                use std::os::unix::fs::PermissionsExt;
-                std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o700))
-                    .map_err(|e| AgentError::AddKeyFailed(format!("chmod askpass: {e}")))?;
-            }
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(AgentError::AddKeyFailed(stderr.to_string()));
+        }
 
-            let output = Command::new("ssh-add")
-                .arg(key_path)
-                .env("SSH_AUTH_SOCK", socket_path)
-                .env("SSH_ASKPASS", &script_path)
-                .env("SSH_ASKPASS_REQUIRE", "force")
-                .env("DISPLAY", ":0")
-                // Pass the passphrase via env var — never touches disk.
-                // Wrap in Zeroizing so the String is zeroed when dropped.
-                .env(
-                    "RUSTCONN_ASKPASS_SECRET",
-                    Zeroizing::new(pass.expose_secret().to_string()).as_str(),
-                )
-                .stdin(Stdio::null())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .output()
-                .map_err(|e| AgentError::AddKeyFailed(e.to_string()));
+        remember_added_key(key_path, options);
+        Ok(())
+    }
 
-            // Clean up the helper script (contains no secrets, just env var reference)
-            let _ = std::fs::remove_dir_all(&script_dir);
+    /// Adds a passphrase-protected key to the SSH agent without a prompt.
+    ///
+    /// `ssh-add` is pointed at a generated askpass helper through
+    /// `SSH_ASKPASS` with `SSH_ASKPASS_REQUIRE=force`, so the passphrase
+    /// can come from a secret backend instead of a terminal or desktop
+    /// prompt. The copy of the passphrase made for the child is zeroed
+    /// once `ssh-add` exits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AgentError::NotRunning` if no socket is configured.
+    /// Returns `AgentError::AddKeyFailed` if the helper cannot be written,
+    /// the passphrase is wrong, or the key cannot be added.
+    pub fn add_key_with_passphrase(
+        &self,
+        key_path: &std::path::Path,
+        passphrase: &secrecy::SecretString,
+        options: &AddKeyOptions,
+    ) -> AgentResult<()> {
+        use secrecy::ExposeSecret;
+        use zeroize::Zeroizing;
 
-            let output = output?;
+        let socket_path = self.socket_path.as_ref().ok_or(AgentError::NotRunning)?;
 
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                if stderr.contains("passphrase") || stderr.contains("bad passphrase") {
-                    return Err(AgentError::AddKeyFailed("Incorrect passphrase".to_string()));
-                }
-                return Err(AgentError::AddKeyFailed(stderr.to_string()));
-            }
-        } else {
-            // No passphrase - simple ssh-add
-            let output = Command::new("ssh-add")
-                .arg(key_path)
-                .env("SSH_AUTH_SOCK", socket_path)
+        // SEC-2: Pass the passphrase via an environment variable instead of
+        // writing it to a temp file. On CoW filesystems (btrfs, APFS) overwriting
+        // with zeros is unreliable — the original data persists in old extents.
+        //
+        // The SSH_ASKPASS script only references the env var by name; the actual
+        // secret lives solely in the child process's memory and is never written
+        // to disk. The env var is not visible in /proc/<pid>/cmdline.
+        let script_dir =
+            std::env::temp_dir().join(format!("rustconn-askpass-{}", uuid::Uuid::new_v4()));
+        let script_path = write_askpass_script(&script_dir)?;
+
+        let output = {
+            // Wrap in Zeroizing so our copy is zeroed when dropped.
+            let secret = Zeroizing::new(passphrase.expose_secret().to_string());
+            askpass_command(socket_path, key_path, options, &script_path)
+                .env(ASKPASS_SECRET_ENV, secret.as_str())
                 .output()
-                .map_err(|e| AgentError::AddKeyFailed(e.to_string()))?;
+                .map_err(|e| AgentError::AddKeyFailed(e.to_string()))
+        };
 
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                return Err(AgentError::AddKeyFailed(stderr.to_string()));
+        // Clean up the helper script (contains no secrets, just env var reference)
+        let _ = std::fs::remove_dir_all(&script_dir);
+
+        let output = output?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("passphrase") || stderr.contains("bad passphrase") {
+                return Err(AgentError::AddKeyFailed("Incorrect passphrase".to_string()));
             }
+            return Err(AgentError::AddKeyFailed(stderr.to_string()));
         }
 
+        remember_added_key(key_path, options);
         Ok(())
     }
 
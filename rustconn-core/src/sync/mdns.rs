@@ -0,0 +1,525 @@
+//! Minimal mDNS / DNS-SD support for peer discovery.
+//!
+//! Only what peer pull needs is implemented: answering PTR queries for
+//! [`SERVICE_TYPE`] with PTR + SRV + TXT + A records, and browsing for those
+//! answers. Names are written uncompressed and parsed with compression
+//! pointer support (RFC 1035 §4.1.4), which is all responders in the wild
+//! use for this record set.
+
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4, UdpSocket};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use socket2::{Domain, Protocol, Socket, Type};
+
+use super::peer::{PeerError, PeerInfo};
+
+/// DNS-SD service type advertised by sharing instances.
+pub const SERVICE_TYPE: &str = "_rustconn._tcp.local";
+
+const MDNS_GROUP: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 251);
+const MDNS_PORT: u16 = 5353;
+
+const TYPE_A: u16 = 1;
+const TYPE_PTR: u16 = 12;
+const TYPE_TXT: u16 = 16;
+const TYPE_SRV: u16 = 33;
+const CLASS_IN: u16 = 1;
+/// Cache-flush bit for unique records, unicast-response bit for questions.
+const CLASS_TOP_BIT: u16 = 0x8000;
+const FLAGS_RESPONSE: u16 = 0x8400;
+const RECORD_TTL: u32 = 120;
+const MAX_PACKET: usize = 9000;
+const MAX_POINTER_JUMPS: usize = 16;
+
+/// Poll interval of the advertiser thread, bounding how long `Drop` waits.
+const ADVERTISER_POLL: Duration = Duration::from_millis(250);
+
+/// Advertises a sharing instance on the local network until dropped.
+pub struct PeerAdvertiser {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl PeerAdvertiser {
+    /// Starts answering mDNS queries for an instance listening on `port`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mDNS socket cannot be bound or the multicast
+    /// group cannot be joined.
+    pub fn start(instance_name: &str, port: u16) -> Result<Self, PeerError> {
+        let socket = multicast_socket()?;
+        let host = host_label();
+        let address = local_ipv4().unwrap_or(Ipv4Addr::LOCALHOST);
+        let record = ServiceRecord {
+            instance: label(instance_name),
+            host,
+            port,
+            address,
+        };
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let thread = std::thread::Builder::new()
+            .name("rustconn-mdns".to_string())
+            .spawn(move || respond_loop(&socket, &record, &thread_stop))?;
+
+        tracing::info!(instance = %instance_name, port, "Advertising peer share via mDNS");
+        Ok(Self {
+            stop,
+            thread: Some(thread),
+        })
+    }
+}
+
+impl Drop for PeerAdvertiser {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Browses the local network for sharing instances.
+///
+/// Sends one query and collects answers until `timeout` elapses.
+///
+/// # Errors
+///
+/// Returns an error if the query cannot be sent.
+pub fn discover_peers(timeout: Duration) -> Result<Vec<PeerInfo>, PeerError> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+    socket.set_multicast_ttl_v4(255)?;
+    socket.send_to(&build_query(), SocketAddrV4::new(MDNS_GROUP, MDNS_PORT))?;
+
+    let deadline = Instant::now() + timeout;
+    let mut peers: Vec<PeerInfo> = Vec::new();
+    let mut buf = vec![0u8; MAX_PACKET];
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        socket.set_read_timeout(Some(remaining))?;
+        let (len, from) = match socket.recv_from(&mut buf) {
+            Ok(received) => received,
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                ) =>
+            {
+                break;
+            }
+            Err(e) => return Err(e.into()),
+        };
+        for peer in parse_response(&buf[..len], from) {
+            if !peers.iter().any(|p| p.addr == peer.addr) {
+                peers.push(peer);
+            }
+        }
+    }
+    Ok(peers)
+}
+
+/// Data needed to answer a query.
+struct ServiceRecord {
+    instance: String,
+    host: String,
+    port: u16,
+    address: Ipv4Addr,
+}
+
+fn respond_loop(socket: &UdpSocket, record: &ServiceRecord, stop: &AtomicBool) {
+    let mut buf = vec![0u8; MAX_PACKET];
+    while !stop.load(Ordering::Relaxed) {
+        let (len, from) = match socket.recv_from(&mut buf) {
+            Ok(received) => received,
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                ) =>
+            {
+                continue;
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "mDNS advertiser stopped");
+                return;
+            }
+        };
+        let Some(query) = parse_query(&buf[..len]) else {
+            continue;
+        };
+        // Unicast when asked to (QU bit) or for legacy one-shot resolvers
+        // that don't listen on 5353 (RFC 6762 §5.4, §6.7).
+        let unicast = query.unicast || from.port() != MDNS_PORT;
+        let response = build_response(record, unicast.then_some(query.id));
+        let target = if unicast {
+            from
+        } else {
+            SocketAddr::V4(SocketAddrV4::new(MDNS_GROUP, MDNS_PORT))
+        };
+        if let Err(e) = socket.send_to(&response, target) {
+            tracing::debug!(error = %e, %target, "Failed to send mDNS response");
+        }
+    }
+}
+
+fn multicast_socket() -> Result<UdpSocket, PeerError> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    #[cfg(unix)]
+    socket.set_reuse_port(true)?;
+    socket.bind(&SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, MDNS_PORT).into())?;
+    socket.join_multicast_v4(&MDNS_GROUP, &Ipv4Addr::UNSPECIFIED)?;
+    socket.set_multicast_ttl_v4(255)?;
+    socket.set_read_timeout(Some(ADVERTISER_POLL))?;
+    Ok(socket.into())
+}
+
+/// Returns the address of the interface used to reach the multicast group.
+fn local_ipv4() -> Option<Ipv4Addr> {
+    let probe = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).ok()?;
+    probe.connect((MDNS_GROUP, MDNS_PORT)).ok()?;
+    match probe.local_addr().ok()? {
+        SocketAddr::V4(addr) if !addr.ip().is_unspecified() => Some(*addr.ip()),
+        _ => None,
+    }
+}
+
+fn host_label() -> String {
+    let host = hostname::get()
+        .ok()
+        .and_then(|h| h.into_string().ok())
+        .unwrap_or_else(|| "rustconn".to_string());
+    let sanitized: String = host
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    if sanitized.is_empty() {
+        "rustconn".to_string()
+    } else {
+        label(&sanitized)
+    }
+}
+
+/// Truncates a string to a single DNS label (at most 63 bytes).
+fn label(name: &str) -> String {
+    let mut end = name.len().min(63);
+    while !name.is_char_boundary(end) {
+        end -= 1;
+    }
+    name[..end].replace('.', " ")
+}
+
+// --- Wire format ---
+
+struct Query {
+    id: u16,
+    unicast: bool,
+}
+
+fn build_query() -> Vec<u8> {
+    let mut packet = header(0, 0, 1, 0);
+    write_name(&mut packet, SERVICE_TYPE);
+    packet.extend_from_slice(&TYPE_PTR.to_be_bytes());
+    packet.extend_from_slice(&(CLASS_IN | CLASS_TOP_BIT).to_be_bytes());
+    packet
+}
+
+fn build_response(record: &ServiceRecord, echo_id: Option<u16>) -> Vec<u8> {
+    let instance = format!("{}.{SERVICE_TYPE}", record.instance);
+    let host = format!("{}.local", record.host);
+    let questions = u16::from(echo_id.is_some());
+    let mut packet = header(echo_id.unwrap_or(0), FLAGS_RESPONSE, questions, 4);
+    if echo_id.is_some() {
+        write_name(&mut packet, SERVICE_TYPE);
+        packet.extend_from_slice(&TYPE_PTR.to_be_bytes());
+        packet.extend_from_slice(&CLASS_IN.to_be_bytes());
+    }
+
+    let mut ptr = Vec::new();
+    write_name(&mut ptr, &instance);
+    write_record(&mut packet, SERVICE_TYPE, TYPE_PTR, CLASS_IN, &ptr);
+
+    let mut srv = Vec::new();
+    srv.extend_from_slice(&0u16.to_be_bytes()); // priority
+    srv.extend_from_slice(&0u16.to_be_bytes()); // weight
+    srv.extend_from_slice(&record.port.to_be_bytes());
+    write_name(&mut srv, &host);
+    write_record(
+        &mut packet,
+        &instance,
+        TYPE_SRV,
+        CLASS_IN | CLASS_TOP_BIT,
+        &srv,
+    );
+
+    let txt = b"\x03v=1";
+    write_record(
+        &mut packet,
+        &instance,
+        TYPE_TXT,
+        CLASS_IN | CLASS_TOP_BIT,
+        txt,
+    );
+
+    let a = record.address.octets();
+    write_record(&mut packet, &host, TYPE_A, CLASS_IN | CLASS_TOP_BIT, &a);
+    packet
+}
+
+fn header(id: u16, flags: u16, questions: u16, answers: u16) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(512);
+    for field in [id, flags, questions, answers, 0, 0] {
+        packet.extend_from_slice(&field.to_be_bytes());
+    }
+    packet
+}
+
+fn write_name(out: &mut Vec<u8>, name: &str) {
+    for part in name.split('.').filter(|p| !p.is_empty()) {
+        let bytes = &part.as_bytes()[..part.len().min(63)];
+        out.push(u8::try_from(bytes.len()).unwrap_or(63));
+        out.extend_from_slice(bytes);
+    }
+    out.push(0);
+}
+
+fn write_record(out: &mut Vec<u8>, name: &str, rtype: u16, class: u16, data: &[u8]) {
+    write_name(out, name);
+    out.extend_from_slice(&rtype.to_be_bytes());
+    out.extend_from_slice(&class.to_be_bytes());
+    out.extend_from_slice(&RECORD_TTL.to_be_bytes());
+    let len = u16::try_from(data.len()).unwrap_or(u16::MAX);
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(data);
+}
+
+/// Returns the query if it is a question for our service type.
+fn parse_query(packet: &[u8]) -> Option<Query> {
+    let id = read_u16(packet, 0)?;
+    let flags = read_u16(packet, 2)?;
+    if flags & 0x8000 != 0 {
+        return None; // a response, not a query
+    }
+    let questions = read_u16(packet, 4)?;
+    let mut pos = 12;
+    for _ in 0..questions {
+        let (name, next) = read_name(packet, pos)?;
+        let qtype = read_u16(packet, next)?;
+        let qclass = read_u16(packet, next + 2)?;
+        pos = next + 4;
+        if name.eq_ignore_ascii_case(SERVICE_TYPE) && qtype == TYPE_PTR {
+            return Some(Query {
+                id,
+                unicast: qclass & CLASS_TOP_BIT != 0,
+            });
+        }
+    }
+    None
+}
+
+/// Extracts advertised peers from a response packet.
+///
+/// Falls back to the sender's address when the response carries no A record
+/// for the SRV target.
+fn parse_response(packet: &[u8], from: SocketAddr) -> Vec<PeerInfo> {
+    let parse = || -> Option<Vec<PeerInfo>> {
+        let flags = read_u16(packet, 2)?;
+        if flags & 0x8000 == 0 {
+            return None;
+        }
+        let questions = read_u16(packet, 4)?;
+        let records = usize::from(read_u16(packet, 6)?)
+            + usize::from(read_u16(packet, 8)?)
+            + usize::from(read_u16(packet, 10)?);
+
+        let mut pos = 12;
+        for _ in 0..questions {
+            pos = read_name(packet, pos)?.1 + 4;
+        }
+
+        let mut instances = Vec::new();
+        let mut services: HashMap<String, (u16, String)> = HashMap::new();
+        let mut addresses: HashMap<String, Ipv4Addr> = HashMap::new();
+        for _ in 0..records {
+            let (name, next) = read_name(packet, pos)?;
+            let rtype = read_u16(packet, next)?;
+            let len = usize::from(read_u16(packet, next + 8)?);
+            let data = next + 10;
+            packet.get(data..data + len)?;
+            match rtype {
+                TYPE_PTR if name.eq_ignore_ascii_case(SERVICE_TYPE) => {
+                    instances.push(read_name(packet, data)?.0);
+                }
+                TYPE_SRV => {
+                    let port = read_u16(packet, data + 4)?;
+                    let target = read_name(packet, data + 6)?.0;
+                    services.insert(name.to_ascii_lowercase(), (port, target));
+                }
+                TYPE_A if len == 4 => {
+                    let ip = Ipv4Addr::new(
+                        packet[data],
+                        packet[data + 1],
+                        packet[data + 2],
+                        packet[data + 3],
+                    );
+                    addresses.insert(name.to_ascii_lowercase(), ip);
+                }
+                _ => {}
+            }
+            pos = data + len;
+        }
+
+        Some(
+            instances
+                .into_iter()
+                .filter_map(|instance| {
+                    let (port, target) = services.get(&instance.to_ascii_lowercase())?;
+                    let ip = addresses
+                        .get(&target.to_ascii_lowercase())
+                        .map_or_else(|| from.ip(), |ip| (*ip).into());
+                    let name = instance
+                        .strip_suffix(SERVICE_TYPE)
+                        .and_then(|n| n.strip_suffix('.'))
+                        .unwrap_or(&instance)
+                        .to_string();
+                    Some(PeerInfo {
+                        name,
+                        addr: SocketAddr::new(ip, *port),
+                    })
+                })
+                .collect(),
+        )
+    };
+    parse().unwrap_or_default()
+}
+
+fn read_u16(packet: &[u8], pos: usize) -> Option<u16> {
+    let bytes = packet.get(pos..pos + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+/// Reads a possibly compressed name; returns it and the position after it.
+fn read_name(packet: &[u8], start: usize) -> Option<(String, usize)> {
+    let mut labels: Vec<String> = Vec::new();
+    let mut pos = start;
+    let mut end = None;
+    let mut jumps = 0;
+    loop {
+        let len = *packet.get(pos)?;
+        match len {
+            0 => {
+                return Some((labels.join("."), end.unwrap_or(pos + 1)));
+            }
+            l if l & 0xc0 == 0xc0 => {
+                jumps += 1;
+                if jumps > MAX_POINTER_JUMPS {
+                    return None;
+                }
+                let offset = usize::from(read_u16(packet, pos)? & 0x3fff);
+                end.get_or_insert(pos + 2);
+                pos = offset;
+            }
+            l => {
+                let len = usize::from(l);
+                let bytes = packet.get(pos + 1..pos + 1 + len)?;
+                labels.push(String::from_utf8_lossy(bytes).into_owned());
+                pos += 1 + len;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> ServiceRecord {
+        ServiceRecord {
+            instance: "Main Box".to_string(),
+            host: "mainbox".to_string(),
+            port: 47_010,
+            address: Ipv4Addr::new(192, 168, 1, 20),
+        }
+    }
+
+    #[test]
+    fn query_round_trips_to_peer() {
+        let query = parse_query(&build_query()).unwrap();
+        assert!(query.unicast);
+
+        let response = build_response(&record(), Some(query.id));
+        let from = "192.168.1.20:5353".parse().unwrap();
+        let peers = parse_response(&response, from);
+        assert_eq!(
+            peers,
+            vec![PeerInfo {
+                name: "Main Box".to_string(),
+                addr: "192.168.1.20:47010".parse().unwrap(),
+            }]
+        );
+    }
+
+    #[test]
+    fn unrelated_queries_are_ignored() {
+        let mut packet = header(7, 0, 1, 0);
+        write_name(&mut packet, "_ssh._tcp.local");
+        packet.extend_from_slice(&TYPE_PTR.to_be_bytes());
+        packet.extend_from_slice(&CLASS_IN.to_be_bytes());
+        assert!(parse_query(&packet).is_none());
+        // Our own responses must not be treated as queries
+        assert!(parse_query(&build_response(&record(), None)).is_none());
+    }
+
+    #[test]
+    fn compressed_names_are_followed() {
+        // PTR answer whose owner name is a pointer to the question name
+        let mut packet = header(0, FLAGS_RESPONSE, 1, 2);
+        write_name(&mut packet, SERVICE_TYPE);
+        packet.extend_from_slice(&TYPE_PTR.to_be_bytes());
+        packet.extend_from_slice(&CLASS_IN.to_be_bytes());
+        let mut rdata = vec![4];
+        rdata.extend_from_slice(b"peer");
+        rdata.extend_from_slice(&[0xc0, 12]);
+        packet.extend_from_slice(&[0xc0, 12]);
+        packet.extend_from_slice(&TYPE_PTR.to_be_bytes());
+        packet.extend_from_slice(&CLASS_IN.to_be_bytes());
+        packet.extend_from_slice(&RECORD_TTL.to_be_bytes());
+        packet.extend_from_slice(&u16::try_from(rdata.len()).unwrap().to_be_bytes());
+        packet.extend_from_slice(&rdata);
+
+        let mut srv = vec![0, 0, 0, 0];
+        srv.extend_from_slice(&9000u16.to_be_bytes());
+        write_name(&mut srv, "peerhost.local");
+        write_record(
+            &mut packet,
+            "peer._rustconn._tcp.local",
+            TYPE_SRV,
+            CLASS_IN,
+            &srv,
+        );
+
+        // No A record: the sender's address is used
+        let from = "10.0.0.5:5353".parse().unwrap();
+        let peers = parse_response(&packet, from);
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0].name, "peer");
+        assert_eq!(peers[0].addr, "10.0.0.5:9000".parse().unwrap());
+    }
+
+    #[test]
+    fn pointer_loops_are_rejected() {
+        let packet = [0xc0, 0x00];
+        assert!(read_name(&packet, 0).is_none());
+    }
+}
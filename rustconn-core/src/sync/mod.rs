@@ -19,6 +19,12 @@
 //!   and name-based merge for team collaboration.
 //! - **Simple Sync** — single-file bidirectional sync with UUID-based merge
 //!   and tombstones for personal multi-device use.
+//!
+//! ## Peer Pull
+//!
+//! One-shot transfer of connections from another running instance on the
+//! local network, discovered over mDNS and authorized with a pairing code.
+//! See [`peer`].
 
 // --- Inventory sync (existing) ---
 pub mod inventory;
@@ -34,6 +40,8 @@ pub mod full_export;
 pub mod full_merge;
 pub mod group_merge;
 pub mod manager;
+pub mod mdns;
+pub mod peer;
 pub mod tombstone;
 pub mod watcher;
 
@@ -53,6 +61,11 @@ pub use inventory::{
     load_inventory, parse_inventory_json, parse_inventory_yaml, sync_inventory, sync_tag,
 };
 pub use manager::{GroupSyncState, SyncManager, SyncReport};
+pub use mdns::{PeerAdvertiser, SERVICE_TYPE, discover_peers};
+pub use peer::{
+    MAX_PAIRING_ATTEMPTS, PEER_PROTOCOL_VERSION, PeerError, PeerInfo, PeerPullReport, PeerShare,
+    fetch_from_peer, normalize_pairing_code,
+};
 pub use settings::{SyncMode, SyncSettings};
 pub use tombstone::{SyncEntityType, Tombstone, cleanup_tombstones};
 pub use variable_template::VariableTemplate;
@@ -0,0 +1,559 @@
+//! Pulling connections from another running `RustConn` instance.
+//!
+//! The source instance opts in by creating a [`PeerShare`], which listens on
+//! TCP, is advertised over mDNS (see [`super::mdns`]) and shows a one-time
+//! [pairing code](PeerShare::pairing_code) to the user. The target discovers
+//! it, asks the user for the code and calls [`fetch_from_peer`] (or
+//! [`ConnectionManager::pull_from_peer`](crate::connection::ConnectionManager::pull_from_peer),
+//! which also merges the result).
+//!
+//! # Exchange
+//!
+//! Messages are length-prefixed JSON frames:
+//!
+//! 1. target → source: `hello` with the protocol version
+//! 2. source → target: `challenge` with the key-derivation salt and a fresh
+//!    random challenge
+//! 3. target → source: `proof`, an HMAC-SHA256 of the challenge
+//! 4. source → target: `bundle`, the [`NativeExport`] JSON sealed with
+//!    AES-256-GCM (the challenge is the associated data), or `rejected`
+//!
+//! Both keys are derived from the pairing code with Argon2id, the same KDF
+//! used for encrypted documents. The bundle is only sent once the proof
+//! verifies, and the share stops accepting connections after one delivery or
+//! [`MAX_PAIRING_ATTEMPTS`] wrong codes, so the code cannot be guessed online.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use ring::aead::{AES_256_GCM, Aad, LessSafeKey, Nonce, UnboundKey};
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use crate::error::ConfigError;
+use crate::export::NativeExport;
+
+/// Version of the peer exchange protocol.
+pub const PEER_PROTOCOL_VERSION: u32 = 1;
+
+/// Wrong pairing codes accepted before a share shuts down.
+pub const MAX_PAIRING_ATTEMPTS: u32 = 3;
+
+/// Number of digits in a pairing code.
+const PAIRING_CODE_DIGITS: usize = 8;
+
+/// Largest frame accepted from the network (64 MiB).
+const MAX_FRAME_LEN: usize = 64 * 1024 * 1024;
+
+/// Read/write timeout for an established peer connection.
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Accept-loop poll interval, bounding how quickly cancellation is noticed.
+const ACCEPT_POLL: Duration = Duration::from_millis(100);
+
+/// Errors that can occur while sharing with or pulling from a peer.
+#[derive(Debug, thiserror::Error)]
+pub enum PeerError {
+    /// Network I/O error.
+    #[error("Peer network error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The peer sent something that does not follow the exchange.
+    #[error("Peer protocol error: {0}")]
+    Protocol(String),
+
+    /// The peer speaks a different protocol version.
+    #[error("Unsupported peer protocol version {version}, expected {expected}")]
+    UnsupportedVersion {
+        /// Version announced by the peer.
+        version: u32,
+        /// Version this build supports.
+        expected: u32,
+    },
+
+    /// The pairing code was wrong.
+    #[error("Invalid pairing code")]
+    InvalidPairingCode,
+
+    /// The share stopped after too many wrong pairing codes.
+    #[error("Too many invalid pairing codes; sharing stopped")]
+    TooManyAttempts,
+
+    /// Key derivation, encryption, or decryption failed.
+    #[error("Peer encryption error: {0}")]
+    Crypto(String),
+
+    /// The export bundle could not be encoded or decoded.
+    #[error("Peer export error: {0}")]
+    Export(String),
+
+    /// Sharing was cancelled.
+    #[error("Sharing cancelled")]
+    Cancelled,
+
+    /// Merging the pulled connections failed.
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+}
+
+/// A sharing instance found on the network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerInfo {
+    /// Instance name advertised by the source (usually its host name).
+    pub name: String,
+    /// Address of the share's TCP listener.
+    pub addr: SocketAddr,
+}
+
+/// Outcome of merging a pulled export into the local connections.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PeerPullReport {
+    /// Connections added.
+    pub created: usize,
+    /// Existing connections updated in place.
+    pub updated: usize,
+    /// Connections skipped as duplicates.
+    pub skipped: usize,
+    /// Groups added (groups matching an existing one by name are reused).
+    pub groups_created: usize,
+}
+
+/// Wire messages, one per frame.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Message {
+    Hello { version: u32 },
+    Challenge { salt: String, challenge: String },
+    Proof { mac: String },
+    Bundle { nonce: String, ciphertext: String },
+    Rejected { reason: RejectReason, version: u32 },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RejectReason {
+    InvalidCode,
+    UnsupportedVersion,
+}
+
+/// Encryption and authentication keys derived from a pairing code.
+struct PeerKeys {
+    encryption: Zeroizing<[u8; 32]>,
+    mac: hmac::Key,
+}
+
+impl PeerKeys {
+    fn derive(code: &str, salt: &[u8]) -> Result<Self, PeerError> {
+        use argon2::{Algorithm, Argon2, Params, Version};
+
+        let (m_cost, t_cost, p_cost) = argon2_params();
+        let params = Params::new(m_cost, t_cost, p_cost, Some(64))
+            .map_err(|e| PeerError::Crypto(format!("Invalid Argon2 params: {e}")))?;
+        let mut output = Zeroizing::new([0u8; 64]);
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(
+                normalize_pairing_code(code).as_bytes(),
+                salt,
+                output.as_mut(),
+            )
+            .map_err(|e| PeerError::Crypto(format!("Key derivation failed: {e}")))?;
+
+        let mut encryption = Zeroizing::new([0u8; 32]);
+        encryption.copy_from_slice(&output[..32]);
+        Ok(Self {
+            encryption,
+            mac: hmac::Key::new(hmac::HMAC_SHA256, &output[32..]),
+        })
+    }
+
+    fn cipher(&self) -> Result<LessSafeKey, PeerError> {
+        UnboundKey::new(&AES_256_GCM, self.encryption.as_ref())
+            .map(LessSafeKey::new)
+            .map_err(|_| PeerError::Crypto("Failed to create key".to_string()))
+    }
+}
+
+/// Argon2id `(m_cost, t_cost, p_cost)`, matching standard document encryption.
+///
+/// Test builds use lighter parameters to keep the suite fast.
+const fn argon2_params() -> (u32, u32, u32) {
+    if cfg!(test) {
+        (4096, 2, 1)
+    } else {
+        (65536, 3, 4)
+    }
+}
+
+/// Strips everything but digits so `1234-5678` and `1234 5678` both work.
+#[must_use]
+pub fn normalize_pairing_code(code: &str) -> String {
+    code.chars().filter(char::is_ascii_digit).collect()
+}
+
+fn random_bytes<const N: usize>() -> Result<[u8; N], PeerError> {
+    let mut bytes = [0u8; N];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| PeerError::Crypto("Failed to generate random bytes".to_string()))?;
+    Ok(bytes)
+}
+
+fn generate_pairing_code() -> Result<String, PeerError> {
+    let mut digits = String::with_capacity(PAIRING_CODE_DIGITS + 1);
+    while digits.len() < PAIRING_CODE_DIGITS + 1 {
+        // Rejection sampling keeps every digit uniformly likely
+        for byte in random_bytes::<16>()? {
+            if byte < 250 && digits.len() < PAIRING_CODE_DIGITS + 1 {
+                if digits.len() == PAIRING_CODE_DIGITS / 2 {
+                    digits.push('-');
+                }
+                digits.push(char::from(b'0' + byte % 10));
+            }
+        }
+    }
+    Ok(digits)
+}
+
+fn write_frame(stream: &mut TcpStream, message: &Message) -> Result<(), PeerError> {
+    let payload = serde_json::to_vec(message).map_err(|e| PeerError::Protocol(e.to_string()))?;
+    let len = u32::try_from(payload.len())
+        .ok()
+        .filter(|len| *len as usize <= MAX_FRAME_LEN)
+        .ok_or_else(|| PeerError::Protocol("Frame too large".to_string()))?;
+    stream.write_all(&len.to_be_bytes())?;
+    stream.write_all(&payload)?;
+    stream.flush()?;
+    Ok(())
+}
+
+fn read_frame(stream: &mut TcpStream) -> Result<Message, PeerError> {
+    let mut len = [0u8; 4];
+    stream.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(PeerError::Protocol(format!(
+            "Frame of {len} bytes too large"
+        )));
+    }
+    let mut payload = vec![0u8; len];
+    stream.read_exact(&mut payload)?;
+    serde_json::from_slice(&payload).map_err(|e| PeerError::Protocol(e.to_string()))
+}
+
+fn unexpected(message: &Message) -> PeerError {
+    PeerError::Protocol(format!("Unexpected message: {message:?}"))
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>, PeerError> {
+    hex::decode(value).map_err(|e| PeerError::Protocol(format!("Invalid hex: {e}")))
+}
+
+/// What happened to one incoming connection.
+enum Attempt {
+    Delivered,
+    WrongCode,
+    Aborted,
+}
+
+/// An opt-in share of this instance's connections.
+///
+/// The export is snapshotted when the share is created. Advertise it with
+/// [`PeerAdvertiser`](super::mdns::PeerAdvertiser) on
+/// [`local_addr`](Self::local_addr)'s port and show
+/// [`pairing_code`](Self::pairing_code) to the user, then call
+/// [`serve`](Self::serve).
+pub struct PeerShare {
+    listener: TcpListener,
+    pairing_code: String,
+    salt: [u8; 32],
+    keys: PeerKeys,
+    payload: Zeroizing<Vec<u8>>,
+    failed_attempts: u32,
+}
+
+impl PeerShare {
+    /// Starts listening on `addr` and generates a pairing code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener cannot be bound or the export cannot
+    /// be serialized.
+    pub fn bind(addr: impl ToSocketAddrs, export: &NativeExport) -> Result<Self, PeerError> {
+        let payload = export
+            .to_json_compact()
+            .map_err(|e| PeerError::Export(e.to_string()))?;
+        let pairing_code = generate_pairing_code()?;
+        let salt = random_bytes::<32>()?;
+        let keys = PeerKeys::derive(&pairing_code, &salt)?;
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            pairing_code,
+            salt,
+            keys,
+            payload: Zeroizing::new(payload.into_bytes()),
+            failed_attempts: 0,
+        })
+    }
+
+    /// Returns the code the user must enter on the pulling instance.
+    #[must_use]
+    pub fn pairing_code(&self) -> &str {
+        &self.pairing_code
+    }
+
+    /// Returns the address the share listens on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket address cannot be read.
+    pub fn local_addr(&self) -> Result<SocketAddr, PeerError> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Accepts peers until one receives the export.
+    ///
+    /// Returns the address of the peer that pulled. Set `cancel` from another
+    /// thread to stop waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PeerError::TooManyAttempts`] after [`MAX_PAIRING_ATTEMPTS`]
+    /// wrong codes, [`PeerError::Cancelled`] when cancelled, or an I/O error
+    /// if the listener fails.
+    pub fn serve(&mut self, cancel: &AtomicBool) -> Result<SocketAddr, PeerError> {
+        self.listener.set_nonblocking(true)?;
+        loop {
+            if cancel.load(Ordering::Relaxed) {
+                return Err(PeerError::Cancelled);
+            }
+            let (stream, addr) = match self.listener.accept() {
+                Ok(accepted) => accepted,
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    std::thread::sleep(ACCEPT_POLL);
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
+            match self.handle(stream) {
+                Ok(Attempt::Delivered) => {
+                    tracing::info!(%addr, "Connections pulled by peer");
+                    return Ok(addr);
+                }
+                Ok(Attempt::WrongCode) => {
+                    self.failed_attempts += 1;
+                    tracing::warn!(%addr, attempts = self.failed_attempts, "Peer sent wrong pairing code");
+                    if self.failed_attempts >= MAX_PAIRING_ATTEMPTS {
+                        return Err(PeerError::TooManyAttempts);
+                    }
+                }
+                Ok(Attempt::Aborted) => {}
+                Err(e) => tracing::warn!(%addr, error = %e, "Peer exchange failed"),
+            }
+        }
+    }
+
+    fn handle(&self, mut stream: TcpStream) -> Result<Attempt, PeerError> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(IO_TIMEOUT))?;
+        stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+        match read_frame(&mut stream)? {
+            Message::Hello { version } if version == PEER_PROTOCOL_VERSION => {}
+            Message::Hello { .. } => {
+                write_frame(
+                    &mut stream,
+                    &Message::Rejected {
+                        reason: RejectReason::UnsupportedVersion,
+                        version: PEER_PROTOCOL_VERSION,
+                    },
+                )?;
+                return Ok(Attempt::Aborted);
+            }
+            other => return Err(unexpected(&other)),
+        }
+
+        let challenge = random_bytes::<32>()?;
+        write_frame(
+            &mut stream,
+            &Message::Challenge {
+                salt: hex::encode(self.salt),
+                challenge: hex::encode(challenge),
+            },
+        )?;
+
+        let mac = match read_frame(&mut stream)? {
+            Message::Proof { mac } => decode_hex(&mac)?,
+            other => return Err(unexpected(&other)),
+        };
+        if hmac::verify(&self.keys.mac, &challenge, &mac).is_err() {
+            write_frame(
+                &mut stream,
+                &Message::Rejected {
+                    reason: RejectReason::InvalidCode,
+                    version: PEER_PROTOCOL_VERSION,
+                },
+            )?;
+            return Ok(Attempt::WrongCode);
+        }
+
+        let nonce = random_bytes::<12>()?;
+        let mut ciphertext = self.payload.to_vec();
+        self.keys
+            .cipher()?
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(challenge),
+                &mut ciphertext,
+            )
+            .map_err(|_| PeerError::Crypto("Encryption failed".to_string()))?;
+        write_frame(
+            &mut stream,
+            &Message::Bundle {
+                nonce: hex::encode(nonce),
+                ciphertext: data_encoding::BASE64.encode(&ciphertext),
+            },
+        )?;
+        Ok(Attempt::Delivered)
+    }
+}
+
+/// Pulls the export from a sharing peer using the code it displays.
+///
+/// # Errors
+///
+/// Returns [`PeerError::InvalidPairingCode`] if the peer rejects the code,
+/// or an error if the connection, decryption, or decoding fails.
+pub fn fetch_from_peer(peer: &PeerInfo, code: &str) -> Result<NativeExport, PeerError> {
+    let mut stream = TcpStream::connect_timeout(&peer.addr, IO_TIMEOUT)?;
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+
+    write_frame(
+        &mut stream,
+        &Message::Hello {
+            version: PEER_PROTOCOL_VERSION,
+        },
+    )?;
+    let (salt, challenge) = match read_frame(&mut stream)? {
+        Message::Challenge { salt, challenge } => (decode_hex(&salt)?, decode_hex(&challenge)?),
+        Message::Rejected { version, .. } => {
+            return Err(PeerError::UnsupportedVersion {
+                version,
+                expected: PEER_PROTOCOL_VERSION,
+            });
+        }
+        other => return Err(unexpected(&other)),
+    };
+
+    let keys = PeerKeys::derive(code, &salt)?;
+    let mac = hmac::sign(&keys.mac, &challenge);
+    write_frame(
+        &mut stream,
+        &Message::Proof {
+            mac: hex::encode(mac.as_ref()),
+        },
+    )?;
+
+    let (nonce, ciphertext) = match read_frame(&mut stream)? {
+        Message::Bundle { nonce, ciphertext } => (decode_hex(&nonce)?, ciphertext),
+        Message::Rejected {
+            reason: RejectReason::InvalidCode,
+            ..
+        } => return Err(PeerError::InvalidPairingCode),
+        other => return Err(unexpected(&other)),
+    };
+    let nonce: [u8; 12] = nonce
+        .try_into()
+        .map_err(|_| PeerError::Protocol("Invalid nonce length".to_string()))?;
+    let mut data = Zeroizing::new(
+        data_encoding::BASE64
+            .decode(ciphertext.as_bytes())
+            .map_err(|e| PeerError::Protocol(format!("Invalid base64: {e}")))?,
+    );
+    let plaintext = keys
+        .cipher()?
+        .open_in_place(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(challenge),
+            &mut data,
+        )
+        .map_err(|_| PeerError::Crypto("Failed to decrypt bundle".to_string()))?;
+    let json = std::str::from_utf8(plaintext).map_err(|e| PeerError::Export(e.to_string()))?;
+    NativeExport::from_json(json).map_err(|e| PeerError::Export(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::models::Connection;
+
+    fn share() -> PeerShare {
+        let mut export = NativeExport::new();
+        export.connections.push(Connection::new_ssh(
+            "web".to_string(),
+            "web.lan".to_string(),
+            22,
+        ));
+        PeerShare::bind("127.0.0.1:0", &export).unwrap()
+    }
+
+    fn peer(share: &PeerShare) -> PeerInfo {
+        PeerInfo {
+            name: "test".to_string(),
+            addr: share.local_addr().unwrap(),
+        }
+    }
+
+    #[test]
+    fn pairing_code_format() {
+        let code = generate_pairing_code().unwrap();
+        assert_eq!(code.len(), 9);
+        assert_eq!(code.as_bytes()[4], b'-');
+        assert_eq!(normalize_pairing_code(&code).len(), 8);
+        assert_eq!(normalize_pairing_code(" 1234 5678\n"), "12345678");
+    }
+
+    #[test]
+    fn pull_with_correct_code() {
+        let mut share = share();
+        let peer = peer(&share);
+        let code = share.pairing_code().replace('-', " ");
+        let cancel = Arc::new(AtomicBool::new(false));
+        let server = std::thread::spawn(move || share.serve(&cancel).map(|_| ()));
+
+        let export = fetch_from_peer(&peer, &code).unwrap();
+        assert_eq!(export.connections.len(), 1);
+        assert_eq!(export.connections[0].host, "web.lan");
+        server.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn wrong_codes_are_rejected_then_share_stops() {
+        let mut share = share();
+        let peer = peer(&share);
+        let cancel = Arc::new(AtomicBool::new(false));
+        let server = std::thread::spawn(move || share.serve(&cancel));
+
+        for _ in 0..MAX_PAIRING_ATTEMPTS {
+            let err = fetch_from_peer(&peer, "0000-0000").unwrap_err();
+            assert!(matches!(err, PeerError::InvalidPairingCode), "{err}");
+        }
+        assert!(matches!(
+            server.join().unwrap(),
+            Err(PeerError::TooManyAttempts)
+        ));
+    }
+
+    #[test]
+    fn serve_can_be_cancelled() {
+        let mut share = share();
+        let cancel = Arc::new(AtomicBool::new(true));
+        assert!(matches!(share.serve(&cancel), Err(PeerError::Cancelled)));
+    }
+}
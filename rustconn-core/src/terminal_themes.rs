@@ -3,12 +3,20 @@
 //! This module defines color themes for VTE terminals.
 //! Built-in themes are always available; user-created custom themes
 //! are persisted to `~/.config/rustconn/custom_themes.json`.
+//! iTerm2 `.itermcolors` and Base16 YAML schemes can be imported as custom
+//! themes, and a few popular Base16 schemes ship as built-ins.
+
+mod base16;
+mod iterm2;
 
 use std::path::PathBuf;
 use std::sync::Mutex;
 
 use serde::{Deserialize, Serialize};
 
+pub use base16::Base16Scheme;
+pub use iterm2::ThemeImportError;
+
 /// RGB color representation
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Color {
@@ -158,17 +166,19 @@ fn get_custom_themes() -> Vec<TerminalTheme> {
 }
 
 impl TerminalTheme {
-    /// Returns built-in themes only.
+    /// Returns built-in themes only, including the bundled Base16 schemes.
     #[must_use]
     pub fn builtin_themes() -> Vec<Self> {
-        vec![
+        let mut themes = vec![
             Self::dark_theme(),
             Self::light_theme(),
             Self::solarized_dark_theme(),
             Self::solarized_light_theme(),
             Self::monokai_theme(),
             Self::dracula_theme(),
-        ]
+        ];
+        themes.extend(Self::base16_themes());
+        themes
     }
 
     /// Gets all available themes (built-in + custom).
@@ -185,6 +195,14 @@ impl TerminalTheme {
         Self::all_themes().into_iter().find(|t| t.name == name)
     }
 
+    /// Gets theme by name, falling back to the dark theme for unknown names.
+    ///
+    /// This is the theme terminals actually show for a configured name.
+    #[must_use]
+    pub fn resolve(name: &str) -> Self {
+        Self::by_name(name).unwrap_or_else(Self::dark_theme)
+    }
+
     /// Gets all theme names (built-in + custom).
     #[must_use]
     pub fn theme_names() -> Vec<String> {
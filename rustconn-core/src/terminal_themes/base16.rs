@@ -0,0 +1,276 @@
+//! Base16 scheme support
+//!
+//! Base16 schemes define sixteen colors, `base00` … `base0F`, which map to
+//! the terminal palette the same way `base16-shell` does: `base00` is the
+//! background, `base05` the foreground, and the accents `base08` … `base0E`
+//! fill both the normal and bright ANSI colors.
+//!
+//! Both YAML layouts are accepted: the classic one with `scheme`, `author` and
+//! top-level `baseXX` keys, and the newer one with `name`, `author` and a
+//! `palette` mapping. Colors may be written with or without a leading `#`.
+
+use std::path::Path;
+
+use serde_yaml::{Mapping, Value};
+
+use super::{Color, TerminalTheme, ThemeImportError};
+
+/// Built-in schemes shipped with `RustConn`
+const BUILTIN_SCHEMES: &[&str] = &[
+    include_str!("../../assets/base16/gruvbox-dark-medium.yaml"),
+    include_str!("../../assets/base16/nord.yaml"),
+    include_str!("../../assets/base16/ocean.yaml"),
+];
+
+/// Base16 color index used for each of the 16 terminal colors
+const TERMINAL_MAPPING: [usize; 16] = [
+    0x00, 0x08, 0x0B, 0x0A, 0x0D, 0x0E, 0x0C, 0x05, // normal
+    0x03, 0x08, 0x0B, 0x0A, 0x0D, 0x0E, 0x0C, 0x07, // bright
+];
+
+/// A parsed Base16 color scheme
+#[derive(Debug, Clone, PartialEq)]
+pub struct Base16Scheme {
+    /// Scheme name, if the file has one
+    pub name: Option<String>,
+    /// Scheme author, if the file has one
+    pub author: Option<String>,
+    /// Colors `base00` … `base0F`
+    pub colors: [Color; 16],
+}
+
+impl Base16Scheme {
+    /// Parses a Base16 YAML scheme
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeImportError::Yaml`] for invalid YAML,
+    /// [`ThemeImportError::MissingColor`] when a `baseXX` key is absent and
+    /// [`ThemeImportError::InvalidColor`] when a value is not a `RRGGBB`
+    /// hex color.
+    pub fn from_yaml(content: &str) -> Result<Self, ThemeImportError> {
+        let root: Mapping =
+            serde_yaml::from_str(content).map_err(|e| ThemeImportError::Yaml(e.to_string()))?;
+        let text = |map: &Mapping, key: &str| {
+            map.get(key)
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+        };
+        let palette = match root.get("palette") {
+            Some(Value::Mapping(palette)) => palette,
+            Some(_) => {
+                return Err(ThemeImportError::Yaml(
+                    "'palette' must be a mapping".to_string(),
+                ));
+            }
+            None => &root,
+        };
+
+        let mut colors = Vec::with_capacity(16);
+        for index in 0..16 {
+            let key = format!("base{index:02X}");
+            let value = palette
+                .get(key.as_str())
+                .ok_or_else(|| ThemeImportError::MissingColor(key.clone()))?;
+            colors.push(parse_hex(&key, value)?);
+        }
+        let colors: [Color; 16] = colors
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("exactly 16 colors pushed"));
+
+        Ok(Self {
+            name: text(&root, "scheme").or_else(|| text(&root, "name")),
+            author: text(&root, "author"),
+            colors,
+        })
+    }
+
+    /// Returns the schemes bundled with `RustConn`
+    #[must_use]
+    pub fn builtin() -> Vec<Self> {
+        BUILTIN_SCHEMES
+            .iter()
+            .filter_map(|yaml| Self::from_yaml(yaml).ok())
+            .collect()
+    }
+
+    /// Maps the scheme onto a built-in terminal theme
+    ///
+    /// Unnamed schemes are called `Base16`.
+    #[must_use]
+    pub fn to_theme(&self) -> TerminalTheme {
+        TerminalTheme {
+            name: self.name.clone().unwrap_or_else(|| "Base16".to_string()),
+            background: self.colors[0x00].clone(),
+            foreground: self.colors[0x05].clone(),
+            cursor: self.colors[0x05].clone(),
+            palette: TERMINAL_MAPPING.map(|index| self.colors[index].clone()),
+            is_custom: false,
+        }
+    }
+}
+
+impl TerminalTheme {
+    /// Returns the built-in Base16 themes
+    #[must_use]
+    pub fn base16_themes() -> Vec<Self> {
+        Base16Scheme::builtin()
+            .iter()
+            .map(Base16Scheme::to_theme)
+            .collect()
+    }
+
+    /// Builds a custom theme from a Base16 YAML scheme
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Base16Scheme::from_yaml`].
+    pub fn from_base16_yaml(content: &str) -> Result<Self, ThemeImportError> {
+        let mut theme = Base16Scheme::from_yaml(content)?.to_theme();
+        theme.is_custom = true;
+        Ok(theme)
+    }
+
+    /// Reads a Base16 YAML file; unnamed schemes are named after the file
+    ///
+    /// The theme is not saved; pass it to [`TerminalTheme::save_custom_theme`]
+    /// to persist it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeImportError::Io`] if the file cannot be read, otherwise
+    /// the errors of [`Base16Scheme::from_yaml`].
+    pub fn import_base16_file(path: &Path) -> Result<Self, ThemeImportError> {
+        let content = std::fs::read_to_string(path)?;
+        let mut scheme = Base16Scheme::from_yaml(&content)?;
+        if scheme.name.is_none() {
+            scheme.name = path.file_stem().map(|s| s.to_string_lossy().into_owned());
+        }
+        let mut theme = scheme.to_theme();
+        theme.is_custom = true;
+        Ok(theme)
+    }
+}
+
+/// Parses a strict `RRGGBB` / `#RRGGBB` color
+fn parse_hex(key: &str, value: &Value) -> Result<Color, ThemeImportError> {
+    let invalid = || ThemeImportError::InvalidColor {
+        key: key.to_string(),
+        reason: format!("expected a RRGGBB hex color, got {}", describe(value)),
+    };
+    let hex = value.as_str().ok_or_else(invalid)?.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    Ok(Color::from_hex(hex))
+}
+
+/// Short rendering of a YAML value for error messages
+fn describe(value: &Value) -> String {
+    match value {
+        Value::String(s) => format!("'{s}'"),
+        Value::Number(n) => n.to_string(),
+        Value::Null => "nothing".to_string(),
+        _ => "a nested value".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOMORROW_NIGHT: &str = r##"
+scheme: "Tomorrow Night"
+author: "Chris Kempson (http://chriskempson.com)"
+base00: "1d1f21"
+base01: "282a2e"
+base02: "373b41"
+base03: "969896"
+base04: "b4b7b4"
+base05: "c5c8c6"
+base06: "e0e0e0"
+base07: "ffffff"
+base08: "cc6666"
+base09: "de935f"
+base0A: "f0c674"
+base0B: "b5bd68"
+base0C: "8abeb7"
+base0D: "81a2be"
+base0E: "b294bb"
+base0F: "a3685a"
+"##;
+
+    #[test]
+    fn test_known_scheme_resolves_to_theme() {
+        let theme = TerminalTheme::from_base16_yaml(TOMORROW_NIGHT).unwrap();
+        assert_eq!(theme.name, "Tomorrow Night");
+        assert!(theme.is_custom);
+        assert_eq!(theme.background.to_hex(), "#1D1F21");
+        assert_eq!(theme.foreground.to_hex(), "#C5C8C6");
+        assert_eq!(theme.cursor, theme.foreground);
+
+        let palette: Vec<String> = theme.palette.iter().map(Color::to_hex).collect();
+        assert_eq!(
+            palette,
+            [
+                "#1D1F21", "#CC6666", "#B5BD68", "#F0C674", "#81A2BE", "#B294BB", "#8ABEB7",
+                "#C5C8C6", "#969896", "#CC6666", "#B5BD68", "#F0C674", "#81A2BE", "#B294BB",
+                "#8ABEB7", "#FFFFFF",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_palette_layout_and_hash_prefix() {
+        let mut yaml = String::from("system: base16\nname: Grey\npalette:\n");
+        for index in 0..16 {
+            yaml.push_str(&format!(
+                "  base{index:02X}: \"#{index:02x}{index:02x}{index:02x}\"\n"
+            ));
+        }
+        let scheme = Base16Scheme::from_yaml(&yaml).unwrap();
+        assert_eq!(scheme.name.as_deref(), Some("Grey"));
+        assert_eq!(scheme.author, None);
+        assert_eq!(scheme.colors[0x0F].to_hex(), "#0F0F0F");
+        assert_eq!(scheme.to_theme().palette[15].to_hex(), "#070707");
+    }
+
+    #[test]
+    fn test_invalid_schemes() {
+        let missing = TOMORROW_NIGHT.replace("base0C: \"8abeb7\"\n", "");
+        assert!(matches!(
+            Base16Scheme::from_yaml(&missing),
+            Err(ThemeImportError::MissingColor(ref key)) if key == "base0C"
+        ));
+
+        let bad = TOMORROW_NIGHT.replace("\"cc6666\"", "\"red\"");
+        let err = Base16Scheme::from_yaml(&bad).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid color 'base08': expected a RRGGBB hex color, got 'red'"
+        );
+
+        assert!(matches!(
+            Base16Scheme::from_yaml("scheme: [unclosed"),
+            Err(ThemeImportError::Yaml(_))
+        ));
+        assert!(matches!(
+            Base16Scheme::from_yaml("- just\n- a list\n"),
+            Err(ThemeImportError::Yaml(_))
+        ));
+    }
+
+    #[test]
+    fn test_builtin_schemes_parse() {
+        let names: Vec<String> = TerminalTheme::base16_themes()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, ["Gruvbox dark, medium", "Nord", "Ocean"]);
+        assert_eq!(Base16Scheme::builtin().len(), BUILTIN_SCHEMES.len());
+        assert!(TerminalTheme::is_builtin("Nord"));
+    }
+}
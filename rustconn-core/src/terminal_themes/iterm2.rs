@@ -0,0 +1,366 @@
+//! iTerm2 `.itermcolors` import
+//!
+//! An `.itermcolors` file is an XML property list whose root dictionary maps
+//! color names (`Ansi 0 Color` … `Ansi 15 Color`, `Foreground Color`,
+//! `Background Color`, `Cursor Color`) to dictionaries holding
+//! `Red/Green/Blue Component` reals in the 0.0–1.0 range. Other keys
+//! (selection, bold, badge colors, color spaces) are ignored.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use thiserror::Error;
+
+use super::{Color, TerminalTheme};
+
+/// Errors from importing an iTerm2 or Base16 color scheme
+#[derive(Debug, Error)]
+pub enum ThemeImportError {
+    /// The file could not be read
+    #[error("failed to read color scheme: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The XML is malformed or truncated
+    #[error("malformed plist: {0}")]
+    Xml(String),
+
+    /// The YAML is malformed or not a mapping
+    #[error("malformed YAML: {0}")]
+    Yaml(String),
+
+    /// The root element is not a `<plist>` wrapping a `<dict>`
+    #[error("not an iTerm2 color scheme: expected a <plist> with a root <dict>")]
+    NotAColorScheme,
+
+    /// A required color key is absent
+    #[error("missing color '{0}'")]
+    MissingColor(String),
+
+    /// A color entry is not a dictionary or has a bad component
+    #[error("invalid color '{key}': {reason}")]
+    InvalidColor {
+        /// Color key, e.g. `Ansi 3 Color`
+        key: String,
+        /// What is wrong with it
+        reason: String,
+    },
+}
+
+/// A parsed property list value; only what color schemes need is kept
+#[derive(Debug)]
+enum PlistValue {
+    Dict(HashMap<String, PlistValue>),
+    Scalar(String),
+    Other,
+}
+
+impl TerminalTheme {
+    /// Builds a custom theme from the contents of an `.itermcolors` file
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeImportError`] when the plist is malformed, lacks one
+    /// of the 16 ANSI, foreground or background colors, or has a color
+    /// component that is not a number. A missing cursor color falls back to
+    /// the foreground.
+    pub fn from_itermcolors(name: &str, content: &str) -> Result<Self, ThemeImportError> {
+        let root = parse_plist(content)?;
+        let color = |key: &str| -> Result<Color, ThemeImportError> {
+            root.get(key)
+                .ok_or_else(|| ThemeImportError::MissingColor(key.to_string()))
+                .and_then(|value| parse_color(key, value))
+        };
+
+        let foreground = color("Foreground Color")?;
+        let background = color("Background Color")?;
+        let cursor = match root.get("Cursor Color") {
+            Some(value) => parse_color("Cursor Color", value)?,
+            None => foreground.clone(),
+        };
+        let mut palette = Vec::with_capacity(16);
+        for index in 0..16 {
+            palette.push(color(&format!("Ansi {index} Color"))?);
+        }
+        let palette: [Color; 16] = palette
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("exactly 16 colors pushed"));
+
+        Ok(Self {
+            name: name.to_string(),
+            background,
+            foreground,
+            cursor,
+            palette,
+            is_custom: true,
+        })
+    }
+
+    /// Reads an `.itermcolors` file, naming the theme after the file stem
+    ///
+    /// The theme is not saved; pass it to [`TerminalTheme::save_custom_theme`]
+    /// to persist it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeImportError::Io`] if the file cannot be read, otherwise
+    /// the errors of [`TerminalTheme::from_itermcolors`].
+    pub fn import_itermcolors_file(path: &Path) -> Result<Self, ThemeImportError> {
+        let content = std::fs::read_to_string(path)?;
+        let name = path.file_stem().map_or_else(
+            || "Imported".to_string(),
+            |s| s.to_string_lossy().into_owned(),
+        );
+        Self::from_itermcolors(&name, &content)
+    }
+}
+
+/// Converts a color dictionary to a [`Color`]
+fn parse_color(key: &str, value: &PlistValue) -> Result<Color, ThemeImportError> {
+    let invalid = |reason: String| ThemeImportError::InvalidColor {
+        key: key.to_string(),
+        reason,
+    };
+    let PlistValue::Dict(dict) = value else {
+        return Err(invalid("expected a dictionary".to_string()));
+    };
+    let component = |name: &str| -> Result<f32, ThemeImportError> {
+        let Some(PlistValue::Scalar(text)) = dict.get(name) else {
+            return Err(invalid(format!("missing {name}")));
+        };
+        let component: f32 = text
+            .trim()
+            .parse()
+            .map_err(|_| invalid(format!("{name} '{text}' is not a number")))?;
+        if !component.is_finite() {
+            return Err(invalid(format!("{name} '{text}' is not a number")));
+        }
+        Ok(component.clamp(0.0, 1.0))
+    };
+
+    Ok(Color::new(
+        component("Red Component")?,
+        component("Green Component")?,
+        component("Blue Component")?,
+    ))
+}
+
+/// Parses the root dictionary of an XML property list
+fn parse_plist(content: &str) -> Result<HashMap<String, PlistValue>, ThemeImportError> {
+    let content = content.trim_start_matches('\u{feff}');
+    let mut reader = Reader::from_str(content);
+    reader.config_mut().trim_text(true);
+
+    let mut in_plist = false;
+    loop {
+        match read_event(&mut reader)? {
+            Event::Start(e) if !in_plist && e.name().as_ref() == b"plist" => in_plist = true,
+            Event::Start(e) if in_plist => {
+                return match parse_value(&mut reader, &e)? {
+                    PlistValue::Dict(dict) => Ok(dict),
+                    _ => Err(ThemeImportError::NotAColorScheme),
+                };
+            }
+            Event::Start(_) | Event::Empty(_) | Event::End(_) | Event::Text(_) | Event::Eof => {
+                return Err(ThemeImportError::NotAColorScheme);
+            }
+            _ => {} // Skip Decl, DocType, Comment, PI
+        }
+    }
+}
+
+/// Parses the value whose start tag was just read
+fn parse_value(
+    reader: &mut Reader<&[u8]>,
+    start: &BytesStart<'_>,
+) -> Result<PlistValue, ThemeImportError> {
+    match start.name().as_ref() {
+        b"dict" => parse_dict(reader).map(PlistValue::Dict),
+        b"array" => {
+            loop {
+                match read_event(reader)? {
+                    Event::Start(e) => {
+                        parse_value(reader, &e)?;
+                    }
+                    Event::End(_) => break,
+                    Event::Eof => return Err(truncated()),
+                    _ => {}
+                }
+            }
+            Ok(PlistValue::Other)
+        }
+        b"real" | b"integer" | b"string" | b"date" | b"data" | b"key" => {
+            read_text(reader).map(PlistValue::Scalar)
+        }
+        other => Err(ThemeImportError::Xml(format!(
+            "unexpected element <{}>",
+            String::from_utf8_lossy(other)
+        ))),
+    }
+}
+
+/// Parses `<key>`/value pairs up to the closing `</dict>`
+fn parse_dict(reader: &mut Reader<&[u8]>) -> Result<HashMap<String, PlistValue>, ThemeImportError> {
+    let mut dict = HashMap::new();
+    loop {
+        match read_event(reader)? {
+            Event::Start(e) if e.name().as_ref() == b"key" => {
+                let key = read_text(reader)?;
+                let value = loop {
+                    match read_event(reader)? {
+                        Event::Start(e) => break parse_value(reader, &e)?,
+                        // <true/>, <false/>, <string/>, <dict/>
+                        Event::Empty(e) if e.name().as_ref() == b"dict" => {
+                            break PlistValue::Dict(HashMap::new());
+                        }
+                        Event::Empty(_) => break PlistValue::Other,
+                        Event::Comment(_) => {}
+                        _ => {
+                            return Err(ThemeImportError::Xml(format!("key '{key}' has no value")));
+                        }
+                    }
+                };
+                dict.insert(key, value);
+            }
+            Event::End(e) if e.name().as_ref() == b"dict" => return Ok(dict),
+            Event::Comment(_) => {}
+            Event::Eof => return Err(truncated()),
+            _ => {
+                return Err(ThemeImportError::Xml(
+                    "expected <key> inside <dict>".to_string(),
+                ));
+            }
+        }
+    }
+}
+
+/// Reads the text of a simple element up to its end tag
+fn read_text(reader: &mut Reader<&[u8]>) -> Result<String, ThemeImportError> {
+    let mut text = String::new();
+    loop {
+        match read_event(reader)? {
+            Event::Text(e) => {
+                let raw = String::from_utf8_lossy(&e);
+                let value = quick_xml::escape::unescape(&raw)
+                    .map_or_else(|_| raw.to_string(), |v| v.into_owned());
+                text.push_str(&value);
+            }
+            Event::GeneralRef(e) => {
+                let raw = format!("&{};", String::from_utf8_lossy(&e));
+                let value = quick_xml::escape::unescape(&raw)
+                    .map_err(|err| ThemeImportError::Xml(err.to_string()))?;
+                text.push_str(&value);
+            }
+            Event::CData(e) => text.push_str(&String::from_utf8_lossy(&e)),
+            Event::End(_) => return Ok(text),
+            Event::Eof => return Err(truncated()),
+            Event::Comment(_) => {}
+            _ => {
+                return Err(ThemeImportError::Xml(
+                    "unexpected element inside a value".to_string(),
+                ));
+            }
+        }
+    }
+}
+
+fn read_event<'a>(reader: &mut Reader<&'a [u8]>) -> Result<Event<'a>, ThemeImportError> {
+    reader
+        .read_event()
+        .map_err(|e| ThemeImportError::Xml(format!("{e} at byte {}", reader.error_position())))
+}
+
+fn truncated() -> ThemeImportError {
+    ThemeImportError::Xml("unexpected end of file".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color_dict(r: f32, g: f32, b: f32) -> String {
+        format!(
+            "<dict><key>Color Space</key><string>sRGB</string>\
+             <key>Red Component</key><real>{r}</real>\
+             <key>Green Component</key><real>{g}</real>\
+             <key>Blue Component</key><real>{b}</real></dict>"
+        )
+    }
+
+    fn scheme(skip: &str) -> String {
+        let mut body = String::new();
+        for index in 0..16 {
+            let key = format!("Ansi {index} Color");
+            if key != skip {
+                body.push_str(&format!("<key>{key}</key>{}", color_dict(0.0, 0.0, 1.0)));
+            }
+        }
+        for key in ["Foreground Color", "Background Color"] {
+            if key != skip {
+                body.push_str(&format!("<key>{key}</key>{}", color_dict(1.0, 1.0, 1.0)));
+            }
+        }
+        format!(r#"<?xml version="1.0"?><plist version="1.0"><dict>{body}</dict></plist>"#)
+    }
+
+    #[test]
+    fn test_cursor_falls_back_to_foreground() {
+        let theme = TerminalTheme::from_itermcolors("Plain", &scheme("")).unwrap();
+        assert_eq!(theme.name, "Plain");
+        assert!(theme.is_custom);
+        assert_eq!(theme.cursor, theme.foreground);
+        assert_eq!(theme.palette[15].to_hex(), "#0000FF");
+    }
+
+    #[test]
+    fn test_missing_color() {
+        let err = TerminalTheme::from_itermcolors("x", &scheme("Ansi 7 Color")).unwrap_err();
+        assert!(matches!(err, ThemeImportError::MissingColor(ref k) if k == "Ansi 7 Color"));
+        let err = TerminalTheme::from_itermcolors("x", &scheme("Background Color")).unwrap_err();
+        assert_eq!(err.to_string(), "missing color 'Background Color'");
+    }
+
+    #[test]
+    fn test_invalid_component() {
+        let content = scheme("").replacen("<real>1</real>", "<real>bright</real>", 1);
+        let err = TerminalTheme::from_itermcolors("x", &content).unwrap_err();
+        assert!(
+            matches!(err, ThemeImportError::InvalidColor { ref reason, .. } if reason.contains("'bright'")),
+            "{err}"
+        );
+
+        let content = scheme("").replacen(&color_dict(0.0, 0.0, 1.0), "<string>blue</string>", 1);
+        let err = TerminalTheme::from_itermcolors("x", &content).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid color 'Ansi 0 Color': expected a dictionary"
+        );
+    }
+
+    #[test]
+    fn test_malformed_plists() {
+        let truncated = scheme("");
+        let truncated = &truncated[..truncated.len() / 2];
+        assert!(matches!(
+            TerminalTheme::from_itermcolors("x", truncated),
+            Err(ThemeImportError::Xml(_))
+        ));
+        for content in [
+            "",
+            "not xml at all",
+            "<html><body/></html>",
+            "<plist><array/></plist>",
+            "<plist><string>x</string></plist>",
+        ] {
+            assert!(
+                TerminalTheme::from_itermcolors("x", content).is_err(),
+                "{content:?} must be rejected"
+            );
+        }
+        assert!(matches!(
+            TerminalTheme::from_itermcolors("x", "<plist><dict><real>1</real></dict></plist>"),
+            Err(ThemeImportError::Xml(_))
+        ));
+    }
+}
@@ -0,0 +1,236 @@
+//! ICMP echo ("ping") probe for the connection tester.
+//!
+//! Prefers the unprivileged ICMP datagram socket that Linux provides when
+//! `net.ipv4.ping_group_range` covers the caller's group, and falls back to a
+//! raw socket (root or `CAP_NET_RAW`). When neither is allowed the probe
+//! reports [`TestError::PingNotPermitted`] so callers can carry on with the
+//! TCP test alone.
+
+use std::io::{ErrorKind, Read};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::time::{Duration, Instant};
+
+use socket2::{Domain, Protocol, Socket, Type};
+
+use super::{TestError, TestResult2};
+
+const ICMPV4_ECHO_REPLY: u8 = 0;
+const ICMPV4_ECHO_REQUEST: u8 = 8;
+const ICMPV6_ECHO_REQUEST: u8 = 128;
+const ICMPV6_ECHO_REPLY: u8 = 129;
+
+/// Payload carried in every echo request
+const PAYLOAD: &[u8] = b"RustConn ping";
+
+/// Sequence numbers keep concurrent probes from matching each other's replies
+static SEQUENCE: AtomicU16 = AtomicU16::new(1);
+
+/// Sends one echo request to `ip` and waits for the matching reply
+///
+/// Returns the round-trip time.
+pub(super) fn ping(ip: IpAddr, timeout: Duration) -> TestResult2<Duration> {
+    let ipv6 = ip.is_ipv6();
+    let (mut socket, raw) = open_socket(ipv6)?;
+    let identifier = (std::process::id() & 0xffff) as u16;
+    let sequence = SEQUENCE.fetch_add(1, Ordering::Relaxed);
+    let packet = build_echo_request(ipv6, identifier, sequence);
+
+    let start = Instant::now();
+    socket
+        .send_to(&packet, &SocketAddr::new(ip, 0).into())
+        .map_err(|e| map_io_error(ip, &e))?;
+
+    let mut buf = [0u8; 1500];
+    loop {
+        let remaining = timeout.saturating_sub(start.elapsed());
+        if remaining.is_zero() {
+            return Err(TestError::Timeout(timeout.as_secs()));
+        }
+        socket
+            .set_read_timeout(Some(remaining))
+            .map_err(|e| TestError::IoError(e.to_string()))?;
+
+        match socket.read(&mut buf) {
+            Ok(len) => {
+                if is_echo_reply(&buf[..len], ipv6, raw, identifier, sequence) {
+                    return Ok(start.elapsed());
+                }
+            }
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                return Err(TestError::Timeout(timeout.as_secs()));
+            }
+            Err(e) => return Err(map_io_error(ip, &e)),
+        }
+    }
+}
+
+/// Opens an ICMP socket, preferring the unprivileged datagram kind
+///
+/// Returns the socket and whether it is a raw socket.
+fn open_socket(ipv6: bool) -> TestResult2<(Socket, bool)> {
+    let (domain, protocol) = if ipv6 {
+        (Domain::IPV6, Protocol::ICMPV6)
+    } else {
+        (Domain::IPV4, Protocol::ICMPV4)
+    };
+
+    let dgram_err = match Socket::new(domain, Type::DGRAM, Some(protocol)) {
+        Ok(socket) => return Ok((socket, false)),
+        Err(e) => e,
+    };
+
+    match Socket::new(domain, Type::RAW, Some(protocol)) {
+        Ok(socket) => Ok((socket, true)),
+        Err(e)
+            if e.kind() == ErrorKind::PermissionDenied
+                || dgram_err.kind() == ErrorKind::PermissionDenied =>
+        {
+            Err(TestError::PingNotPermitted(
+                "ICMP sockets need CAP_NET_RAW or a group in net.ipv4.ping_group_range".to_string(),
+            ))
+        }
+        Err(e) => Err(TestError::IoError(e.to_string())),
+    }
+}
+
+/// Maps a socket error to the matching `TestError`
+fn map_io_error(ip: IpAddr, e: &std::io::Error) -> TestError {
+    match e.kind() {
+        ErrorKind::HostUnreachable | ErrorKind::NetworkUnreachable => {
+            TestError::HostUnreachable(ip.to_string())
+        }
+        ErrorKind::PermissionDenied => TestError::PingNotPermitted(e.to_string()),
+        _ => TestError::IoError(e.to_string()),
+    }
+}
+
+/// Builds an ICMP echo request packet
+fn build_echo_request(ipv6: bool, identifier: u16, sequence: u16) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(8 + PAYLOAD.len());
+    packet.push(if ipv6 {
+        ICMPV6_ECHO_REQUEST
+    } else {
+        ICMPV4_ECHO_REQUEST
+    });
+    packet.push(0); // code
+    packet.extend_from_slice(&[0, 0]); // checksum
+    packet.extend_from_slice(&identifier.to_be_bytes());
+    packet.extend_from_slice(&sequence.to_be_bytes());
+    packet.extend_from_slice(PAYLOAD);
+
+    // The kernel computes the ICMPv6 checksum, which covers a pseudo-header
+    if !ipv6 {
+        let sum = checksum(&packet);
+        packet[2..4].copy_from_slice(&sum.to_be_bytes());
+    }
+    packet
+}
+
+/// Internet checksum (RFC 1071)
+fn checksum(data: &[u8]) -> u16 {
+    let mut sum: u32 = data
+        .chunks(2)
+        .map(|pair| {
+            u32::from(u16::from_be_bytes([
+                pair[0],
+                pair.get(1).copied().unwrap_or(0),
+            ]))
+        })
+        .sum();
+    while sum >> 16 != 0 {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+/// Returns true if `buf` is the echo reply for `sequence`
+///
+/// Raw IPv4 sockets deliver the IP header ahead of the ICMP message. The
+/// identifier is only compared on raw sockets because the kernel rewrites it
+/// on datagram sockets.
+fn is_echo_reply(buf: &[u8], ipv6: bool, raw: bool, identifier: u16, sequence: u16) -> bool {
+    let icmp = if raw && !ipv6 {
+        let header_len = usize::from(buf.first().map_or(0, |b| b & 0x0f)) * 4;
+        buf.get(header_len..).unwrap_or_default()
+    } else {
+        buf
+    };
+    if icmp.len() < 8 {
+        return false;
+    }
+
+    let expected = if ipv6 {
+        ICMPV6_ECHO_REPLY
+    } else {
+        ICMPV4_ECHO_REPLY
+    };
+    icmp[0] == expected
+        && (!raw || u16::from_be_bytes([icmp[4], icmp[5]]) == identifier)
+        && u16::from_be_bytes([icmp[6], icmp[7]]) == sequence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reply(kind: u8, identifier: u16, sequence: u16) -> Vec<u8> {
+        let mut packet = vec![kind, 0, 0, 0];
+        packet.extend_from_slice(&identifier.to_be_bytes());
+        packet.extend_from_slice(&sequence.to_be_bytes());
+        packet.extend_from_slice(PAYLOAD);
+        packet
+    }
+
+    #[test]
+    fn test_checksum_rfc1071_example() {
+        assert_eq!(
+            checksum(&[0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7]),
+            0x220d
+        );
+    }
+
+    #[test]
+    fn test_echo_request_checksum_verifies() {
+        let packet = build_echo_request(false, 0x1234, 7);
+        assert_eq!(packet[0], ICMPV4_ECHO_REQUEST);
+        assert_eq!(&packet[4..8], &[0x12, 0x34, 0x00, 0x07]);
+        // A packet carrying a correct checksum sums to zero
+        assert_eq!(checksum(&packet), 0);
+
+        let packet = build_echo_request(true, 0x1234, 7);
+        assert_eq!(packet[0], ICMPV6_ECHO_REQUEST);
+        assert_eq!(&packet[2..4], &[0, 0]);
+    }
+
+    #[test]
+    fn test_is_echo_reply_datagram_ignores_identifier() {
+        let buf = reply(ICMPV4_ECHO_REPLY, 0xbeef, 3);
+        assert!(is_echo_reply(&buf, false, false, 0x1234, 3));
+        assert!(!is_echo_reply(&buf, false, false, 0x1234, 4));
+        assert!(!is_echo_reply(&buf[..6], false, false, 0x1234, 3));
+    }
+
+    #[test]
+    fn test_is_echo_reply_raw_skips_ip_header() {
+        let mut buf = vec![0x45];
+        buf.resize(20, 0);
+        buf.extend(reply(ICMPV4_ECHO_REPLY, 0x1234, 3));
+        assert!(is_echo_reply(&buf, false, true, 0x1234, 3));
+        assert!(!is_echo_reply(&buf, false, true, 0x4321, 3));
+
+        // Our own request looped back on a raw socket is not a reply
+        let mut request = vec![0x45];
+        request.resize(20, 0);
+        request.extend(reply(ICMPV4_ECHO_REQUEST, 0x1234, 3));
+        assert!(!is_echo_reply(&request, false, true, 0x1234, 3));
+    }
+
+    #[test]
+    fn test_is_echo_reply_ipv6() {
+        let buf = reply(ICMPV6_ECHO_REPLY, 0x1234, 9);
+        assert!(is_echo_reply(&buf, true, true, 0x1234, 9));
+        assert!(!is_echo_reply(&buf, false, false, 0x1234, 9));
+    }
+}
@@ -1,21 +1,35 @@
 //! Connection testing module for verifying connectivity.
 //!
 //! This module provides functionality to test connections by verifying
-//! port accessibility and protocol handshakes.
+//! port accessibility and protocol handshakes. Connections with a proxy
+//! are tested through it; the tester has no access to the secret backend,
+//! so proxies that require authentication report a proxy error.
+//!
+//! An optional ICMP echo probe distinguishes hosts that are down from hosts
+//! that are up with the target port closed or filtered, and an optional UDP
+//! probe checks the UDP side of protocols that have one.
 
 // cast_precision_loss, cast_possible_truncation allowed at workspace level
 
+mod icmp;
+
 use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::net::IpAddr;
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
-use tokio::net::TcpStream;
+use tokio::net::{TcpStream, UdpSocket};
 use tokio::time::timeout;
 use uuid::Uuid;
 
-use crate::models::{Connection, ProtocolType};
+use crate::connection::{connect_via_proxy_async, resolve_tailscale_host_async};
+use crate::models::{
+    Connection, JumpHost, PortForward, PortForwardDirection, ProtocolConfig, ProtocolType,
+    ProxyConfig,
+};
 
 /// Default timeout for connection tests (10 seconds)
 pub const DEFAULT_TEST_TIMEOUT_SECS: u64 = 10;
@@ -53,11 +67,46 @@ pub enum TestError {
     /// Invalid connection configuration
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
+
+    /// The connection's proxy failed or refused the target
+    #[error("Proxy error: {0}")]
+    ProxyError(String),
+
+    /// This process may not open ICMP sockets
+    #[error("ICMP ping not permitted: {0}")]
+    PingNotPermitted(String),
 }
 
 /// Result type alias for testing operations
 pub type TestResult2<T> = std::result::Result<T, TestError>;
 
+/// Outcome of probing a UDP port
+///
+/// UDP has no handshake, so silence is ambiguous: a port that drops unknown
+/// datagrams looks the same as one behind a firewall. `Filtered` therefore
+/// means "no answer" and may hide an open port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UdpPortState {
+    /// The service answered the probe after the given round-trip time
+    Open(Duration),
+    /// The host answered with ICMP port unreachable
+    Closed,
+    /// Nothing came back before the timeout (open or filtered)
+    Filtered,
+}
+
+impl UdpPortState {
+    /// Returns the state as used in `TestResult::details`
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Open(_) => "open",
+            Self::Closed => "closed",
+            Self::Filtered => "filtered",
+        }
+    }
+}
+
 /// Result of testing a single connection
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestResult {
@@ -238,6 +287,10 @@ pub struct ConnectionTester {
     timeout: Duration,
     /// Maximum concurrent tests for batch operations
     concurrency: usize,
+    /// Whether to send an ICMP echo before the port test
+    ping: bool,
+    /// Whether to probe the UDP port of protocols that have a UDP transport
+    udp: bool,
 }
 
 impl ConnectionTester {
@@ -247,6 +300,8 @@ impl ConnectionTester {
         Self {
             timeout: Duration::from_secs(DEFAULT_TEST_TIMEOUT_SECS),
             concurrency: DEFAULT_CONCURRENCY,
+            ping: false,
+            udp: false,
         }
     }
 
@@ -256,6 +311,8 @@ impl ConnectionTester {
         Self {
             timeout,
             concurrency: DEFAULT_CONCURRENCY,
+            ping: false,
+            udp: false,
         }
     }
 
@@ -273,6 +330,29 @@ impl ConnectionTester {
         self
     }
 
+    /// Enables an ICMP echo probe alongside the port test
+    ///
+    /// The probe's outcome is recorded in the result details (`ping`,
+    /// `ping_latency_ms`, `ping_error`); it never changes whether the test
+    /// passes. Connections that use a proxy are not pinged.
+    #[must_use]
+    pub const fn ping(mut self, enabled: bool) -> Self {
+        self.ping = enabled;
+        self
+    }
+
+    /// Enables a UDP probe for protocols with a UDP transport
+    ///
+    /// Currently this is RDP, whose UDP transport shares the TCP port. The
+    /// probe's state is recorded in the result details (`udp`,
+    /// `udp_latency_ms`, `udp_error`) and never changes whether the test
+    /// passes. Connections that use a proxy are not probed.
+    #[must_use]
+    pub const fn udp(mut self, enabled: bool) -> Self {
+        self.udp = enabled;
+        self
+    }
+
     /// Gets the current timeout setting
     #[must_use]
     pub const fn get_timeout(&self) -> Duration {
@@ -285,11 +365,34 @@ impl ConnectionTester {
         self.concurrency
     }
 
+    /// Returns whether the ICMP echo probe is enabled
+    #[must_use]
+    pub const fn get_ping(&self) -> bool {
+        self.ping
+    }
+
+    /// Returns whether the UDP probe is enabled
+    #[must_use]
+    pub const fn get_udp(&self) -> bool {
+        self.udp
+    }
+
     /// Tests a single connection
     ///
     /// This method tests connectivity to the specified connection by:
-    /// 1. Testing TCP port accessibility
-    /// 2. For SSH connections, verifying the SSH banner exchange
+    /// 1. Sending an ICMP echo, if enabled with [`Self::ping`]
+    /// 2. Testing TCP port accessibility (the first hop's SSH port for
+    ///    connections behind an explicit jump-host chain)
+    /// 3. For SSH connections, verifying the SSH banner exchange
+    /// 4. Probing the UDP port, if enabled with [`Self::udp`] and the
+    ///    protocol has a UDP transport
+    ///
+    /// SSH connections with dynamic (SOCKS) forwards list their listening
+    /// specs in the `dynamic_forward` detail; the forwards are not tested.
+    ///
+    /// Connections with `resolve_via_tailscale` set are tested against the
+    /// peer's Tailscale IP, recorded in the `tailscale_ip` detail, when
+    /// Tailscale knows the host.
     ///
     /// # Arguments
     ///
@@ -299,10 +402,67 @@ impl ConnectionTester {
     ///
     /// A `TestResult` indicating success or failure with details
     pub async fn test_connection(&self, connection: &Connection) -> TestResult {
+        let tailscale_ip = if connection.probes_via_tailscale() {
+            timeout(self.timeout, resolve_tailscale_host_async(&connection.host))
+                .await
+                .ok()
+                .flatten()
+        } else {
+            None
+        };
+        let resolved;
+        let connection = match tailscale_ip {
+            Some(ip) => {
+                resolved = Connection {
+                    host: ip.to_string(),
+                    ..connection.clone()
+                };
+                &resolved
+            }
+            None => connection,
+        };
+
+        let direct = connection.proxy.is_none() && first_jump_host(connection).is_none();
+        let ping = if self.ping && direct {
+            Some(self.test_ping(&connection.host).await)
+        } else {
+            None
+        };
+
+        let result = self.test_connection_port(connection).await;
+        let result = with_ping_details(result, ping.as_ref());
+        let result = with_forward_details(result, connection);
+        let result = match tailscale_ip {
+            Some(ip) => result.with_detail("tailscale_ip", ip.to_string()),
+            None => result,
+        };
+
+        match udp_probe_port(connection) {
+            Some(port) if self.udp && direct => {
+                let udp = self.test_udp_port(&connection.host, port).await;
+                with_udp_details(result, &udp)
+            }
+            _ => result,
+        }
+    }
+
+    /// Runs the port (and SSH handshake) part of [`Self::test_connection`]
+    async fn test_connection_port(&self, connection: &Connection) -> TestResult {
+        if let Some(hop) = first_jump_host(connection) {
+            return self.test_first_jump_host(connection, hop).await;
+        }
+
         let start = std::time::Instant::now();
 
         // First test port connectivity
-        match self.test_port(&connection.host, connection.port).await {
+        let port_result = match connection.proxy {
+            Some(ref proxy) => {
+                self.test_port_via_proxy(proxy, &connection.host, connection.port)
+                    .await
+            }
+            None => self.test_port(&connection.host, connection.port).await,
+        };
+        match port_result {
             Ok(latency) => {
                 // For SSH, also verify the protocol handshake
                 if connection.protocol == ProtocolType::Ssh {
@@ -334,6 +494,31 @@ impl ConnectionTester {
         }
     }
 
+    /// Tests reachability of the first hop of a jump-host chain
+    ///
+    /// The target usually sits behind the chain, so only the first hop's SSH
+    /// port is checked; the result records the hop in `jump_host`.
+    async fn test_first_jump_host(&self, connection: &Connection, hop: &JumpHost) -> TestResult {
+        let spec = hop.to_spec();
+        let hops = match &connection.protocol_config {
+            ProtocolConfig::Ssh(c) | ProtocolConfig::Sftp(c) => c.jump_hosts.len(),
+            _ => 1,
+        };
+        match self.test_port(hop.host.trim(), hop.effective_port()).await {
+            Ok(latency) => TestResult::success(
+                connection.id,
+                connection.name.clone(),
+                latency.as_millis() as u64,
+            )
+            .with_detail("protocol", connection.protocol.to_string())
+            .with_detail("jump_host", spec)
+            .with_detail("jump_hops", hops.to_string()),
+            Err(e) => TestResult::from_error(connection.id, connection.name.clone(), &e)
+                .with_detail("jump_host", spec)
+                .with_detail("jump_hops", hops.to_string()),
+        }
+    }
+
     /// Tests TCP port connectivity
     ///
     /// # Arguments
@@ -380,6 +565,116 @@ impl ConnectionTester {
         }
     }
 
+    /// Sends an ICMP echo request to the host
+    ///
+    /// # Returns
+    ///
+    /// The round-trip time on success
+    ///
+    /// # Errors
+    ///
+    /// Returns `TestError::PingNotPermitted` if this process may not open
+    /// ICMP sockets, `TestError::DnsResolutionFailed` if the host does not
+    /// resolve, `TestError::Timeout` if no reply arrives in time, and
+    /// `TestError::HostUnreachable` or `TestError::IoError` for network errors.
+    pub async fn test_ping(&self, host: &str) -> TestResult2<Duration> {
+        let ip = match host.parse::<IpAddr>() {
+            Ok(ip) => ip,
+            Err(_) => timeout(self.timeout, tokio::net::lookup_host((host, 0)))
+                .await
+                .map_err(|_| TestError::Timeout(self.timeout.as_secs()))?
+                .ok()
+                .and_then(|mut addrs| addrs.next())
+                .map(|addr| addr.ip())
+                .ok_or_else(|| TestError::DnsResolutionFailed(host.to_string()))?,
+        };
+
+        let ping_timeout = self.timeout;
+        tokio::task::spawn_blocking(move || icmp::ping(ip, ping_timeout))
+            .await
+            .map_err(|e| TestError::IoError(e.to_string()))?
+    }
+
+    /// Probes a UDP port
+    ///
+    /// Sends a single small datagram from a connected socket and waits up to
+    /// the configured timeout. The heuristic is:
+    /// - any datagram back means the port is `Open`;
+    /// - an ICMP port-unreachable, which the kernel reports on a connected
+    ///   socket as "connection refused", means `Closed`;
+    /// - silence means `Filtered`, which is ambiguous because many UDP
+    ///   services ignore datagrams they do not understand.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TestError::DnsResolutionFailed` if the host does not resolve,
+    /// `TestError::HostUnreachable` if the network reports it unreachable,
+    /// and `TestError::IoError` for other socket errors.
+    pub async fn test_udp_port(&self, host: &str, port: u16) -> TestResult2<UdpPortState> {
+        let map_err = |e: std::io::Error| match e.kind() {
+            ErrorKind::HostUnreachable | ErrorKind::NetworkUnreachable => {
+                TestError::HostUnreachable(host.to_string())
+            }
+            _ => TestError::IoError(e.to_string()),
+        };
+
+        let target = match timeout(self.timeout, tokio::net::lookup_host((host, port))).await {
+            Ok(Ok(mut addrs)) => addrs.next(),
+            Ok(Err(_)) => None,
+            Err(_) => return Err(TestError::Timeout(self.timeout.as_secs())),
+        }
+        .ok_or_else(|| TestError::DnsResolutionFailed(host.to_string()))?;
+
+        let bind_addr = if target.is_ipv6() {
+            "[::]:0"
+        } else {
+            "0.0.0.0:0"
+        };
+        let socket = UdpSocket::bind(bind_addr).await.map_err(map_err)?;
+        socket.connect(target).await.map_err(map_err)?;
+
+        let start = std::time::Instant::now();
+        socket.send(b"\r\n").await.map_err(map_err)?;
+
+        let mut buf = [0u8; 512];
+        match timeout(self.timeout, socket.recv(&mut buf)).await {
+            Ok(Ok(_)) => Ok(UdpPortState::Open(start.elapsed())),
+            Ok(Err(e)) if e.kind() == ErrorKind::ConnectionRefused => Ok(UdpPortState::Closed),
+            Ok(Err(e)) => Err(map_err(e)),
+            Err(_) => Ok(UdpPortState::Filtered),
+        }
+    }
+
+    /// Tests TCP port connectivity through a proxy
+    ///
+    /// # Returns
+    ///
+    /// The time to complete the proxy handshake on success
+    ///
+    /// # Errors
+    ///
+    /// Returns `TestError::ProxyError` if the proxy is unreachable, requires
+    /// authentication, or refuses the target, and `TestError::Timeout` if
+    /// the handshake does not finish in time.
+    pub async fn test_port_via_proxy(
+        &self,
+        proxy: &ProxyConfig,
+        host: &str,
+        port: u16,
+    ) -> TestResult2<Duration> {
+        let start = std::time::Instant::now();
+        match timeout(
+            self.timeout,
+            connect_via_proxy_async(proxy, None, host, port, self.timeout),
+        )
+        .await
+        {
+            Ok(Ok(_stream)) => Ok(start.elapsed()),
+            Ok(Err(e)) => Err(TestError::ProxyError(e.to_string())),
+            Err(_) => Err(TestError::Timeout(self.timeout.as_secs())),
+        }
+    }
+
     /// Tests SSH protocol handshake
     ///
     /// Verifies that the remote host responds with a valid SSH banner.
@@ -399,9 +694,25 @@ impl ConnectionTester {
     pub async fn test_ssh(&self, connection: &Connection) -> TestResult2<()> {
         let addr = format!("{}:{}", connection.host, connection.port);
 
-        let stream = match timeout(self.timeout, TcpStream::connect(&addr)).await {
+        let connect = async {
+            match connection.proxy {
+                Some(ref proxy) => connect_via_proxy_async(
+                    proxy,
+                    None,
+                    &connection.host,
+                    connection.port,
+                    self.timeout,
+                )
+                .await
+                .map_err(|e| TestError::ProxyError(e.to_string())),
+                None => TcpStream::connect(&addr)
+                    .await
+                    .map_err(|e| TestError::IoError(e.to_string())),
+            }
+        };
+        let stream = match timeout(self.timeout, connect).await {
             Ok(Ok(s)) => s,
-            Ok(Err(e)) => return Err(TestError::IoError(e.to_string())),
+            Ok(Err(e)) => return Err(e),
             Err(_) => return Err(TestError::Timeout(self.timeout.as_secs())),
         };
 
@@ -460,6 +771,88 @@ impl ConnectionTester {
     }
 }
 
+/// Returns the first hop of the connection's explicit jump-host chain
+fn first_jump_host(connection: &Connection) -> Option<&JumpHost> {
+    match &connection.protocol_config {
+        ProtocolConfig::Ssh(c) | ProtocolConfig::Sftp(c) if c.proxy_command.is_none() => {
+            c.first_jump_host()
+        }
+        _ => None,
+    }
+}
+
+/// Merges the outcome of an ICMP probe into a test result's details
+///
+/// A successful ping on a failed port test also records `port_open = false`,
+/// so the result reads as "host up, port closed".
+fn with_ping_details(result: TestResult, ping: Option<&TestResult2<Duration>>) -> TestResult {
+    match ping {
+        None => result,
+        Some(Ok(rtt)) => {
+            let port_closed = result.is_failure() && !result.details.contains_key("port_open");
+            let result = result
+                .with_detail("ping", "ok")
+                .with_detail("ping_latency_ms", (rtt.as_millis() as u64).to_string());
+            if port_closed {
+                result.with_detail("port_open", "false")
+            } else {
+                result
+            }
+        }
+        Some(Err(TestError::PingNotPermitted(reason))) => result
+            .with_detail("ping", "unavailable")
+            .with_detail("ping_error", reason.clone()),
+        Some(Err(e)) => result
+            .with_detail("ping", "failed")
+            .with_detail("ping_error", e.to_string()),
+    }
+}
+
+/// Notes the dynamic (SOCKS) forwards of an SSH connection in the details
+fn with_forward_details(result: TestResult, connection: &Connection) -> TestResult {
+    let ProtocolConfig::Ssh(ref ssh) = connection.protocol_config else {
+        return result;
+    };
+    let dynamic: Vec<String> = ssh
+        .port_forwards
+        .iter()
+        .filter(|pf| pf.direction == PortForwardDirection::Dynamic)
+        .map(PortForward::listen_spec)
+        .collect();
+    if dynamic.is_empty() {
+        result
+    } else {
+        result.with_detail("dynamic_forward", dynamic.join(", "))
+    }
+}
+
+/// Returns the UDP port to probe for protocols with a UDP transport
+///
+/// RDP's UDP transport (MS-RDPEUDP) listens on the same port as TCP.
+fn udp_probe_port(connection: &Connection) -> Option<u16> {
+    match connection.protocol {
+        ProtocolType::Rdp => Some(connection.port),
+        _ => None,
+    }
+}
+
+/// Merges the outcome of a UDP probe into a test result's details
+fn with_udp_details(result: TestResult, udp: &TestResult2<UdpPortState>) -> TestResult {
+    match udp {
+        Ok(state) => {
+            let result = result.with_detail("udp", state.as_str());
+            if let UdpPortState::Open(rtt) = state {
+                result.with_detail("udp_latency_ms", (rtt.as_millis() as u64).to_string())
+            } else {
+                result
+            }
+        }
+        Err(e) => result
+            .with_detail("udp", "error")
+            .with_detail("udp_error", e.to_string()),
+    }
+}
+
 impl Default for ConnectionTester {
     fn default() -> Self {
         Self::new()
@@ -675,5 +1068,225 @@ mod tests {
             TestError::ProtocolError("Invalid banner".to_string()).to_string(),
             "Protocol handshake failed: Invalid banner"
         );
+        assert_eq!(
+            TestError::PingNotPermitted("no CAP_NET_RAW".to_string()).to_string(),
+            "ICMP ping not permitted: no CAP_NET_RAW"
+        );
+    }
+
+    #[test]
+    fn test_connection_tester_ping_builder() {
+        assert!(!ConnectionTester::new().get_ping());
+        assert!(ConnectionTester::new().ping(true).get_ping());
+    }
+
+    #[test]
+    fn test_ping_details_host_up_port_closed() {
+        let id = Uuid::new_v4();
+        let result = TestResult::from_error(id, "S1".to_string(), &TestError::ConnectionRefused);
+        let result = with_ping_details(result, Some(&Ok(Duration::from_millis(12))));
+
+        assert!(result.is_failure());
+        assert_eq!(result.details.get("ping"), Some(&"ok".to_string()));
+        assert_eq!(
+            result.details.get("ping_latency_ms"),
+            Some(&"12".to_string())
+        );
+        assert_eq!(result.details.get("port_open"), Some(&"false".to_string()));
+    }
+
+    #[test]
+    fn test_ping_details_do_not_change_outcome() {
+        let id = Uuid::new_v4();
+        let ok = with_ping_details(
+            TestResult::success(id, "S1".to_string(), 5),
+            Some(&Err(TestError::Timeout(1))),
+        );
+        assert!(ok.is_success());
+        assert_eq!(ok.details.get("ping"), Some(&"failed".to_string()));
+        assert!(!ok.details.contains_key("port_open"));
+
+        let unprivileged = with_ping_details(
+            TestResult::success(id, "S1".to_string(), 5),
+            Some(&Err(TestError::PingNotPermitted("denied".to_string()))),
+        );
+        assert_eq!(
+            unprivileged.details.get("ping"),
+            Some(&"unavailable".to_string())
+        );
+
+        let skipped = with_ping_details(TestResult::success(id, "S1".to_string(), 5), None);
+        assert!(skipped.details.is_empty());
+    }
+
+    #[test]
+    fn test_udp_probe_port_by_protocol() {
+        let rdp = Connection::new_rdp("Win".to_string(), "win.lan".to_string(), 3390);
+        assert_eq!(udp_probe_port(&rdp), Some(3390));
+
+        let ssh = Connection::new_ssh("Linux".to_string(), "linux.lan".to_string(), 22);
+        assert_eq!(udp_probe_port(&ssh), None);
+    }
+
+    #[test]
+    fn test_udp_details() {
+        let id = Uuid::new_v4();
+        let open = with_udp_details(
+            TestResult::success(id, "S1".to_string(), 5),
+            &Ok(UdpPortState::Open(Duration::from_millis(3))),
+        );
+        assert_eq!(open.details.get("udp"), Some(&"open".to_string()));
+        assert_eq!(open.details.get("udp_latency_ms"), Some(&"3".to_string()));
+
+        let filtered = with_udp_details(
+            TestResult::success(id, "S1".to_string(), 5),
+            &Ok(UdpPortState::Filtered),
+        );
+        assert!(filtered.is_success());
+        assert_eq!(filtered.details.get("udp"), Some(&"filtered".to_string()));
+        assert!(!filtered.details.contains_key("udp_latency_ms"));
+    }
+
+    #[tokio::test]
+    async fn test_udp_port_open_against_echo_socket() {
+        let echo = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = echo.local_addr().unwrap().port();
+        let echo_thread = std::thread::spawn(move || {
+            let mut buf = [0u8; 512];
+            let (len, from) = echo.recv_from(&mut buf).unwrap();
+            echo.send_to(&buf[..len], from).unwrap();
+        });
+
+        let tester = ConnectionTester::with_timeout(Duration::from_secs(2));
+        let state = tester.test_udp_port("127.0.0.1", port).await.unwrap();
+        assert!(matches!(state, UdpPortState::Open(_)), "{state:?}");
+        echo_thread.join().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_udp_port_closed() {
+        let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = socket.local_addr().unwrap().port();
+        drop(socket);
+
+        let tester = ConnectionTester::with_timeout(Duration::from_secs(2));
+        let state = tester.test_udp_port("127.0.0.1", port).await.unwrap();
+        assert_eq!(state, UdpPortState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_udp_port_silent_is_filtered() {
+        // Bound but never answers: indistinguishable from a firewall drop
+        let silent = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = silent.local_addr().unwrap().port();
+
+        let tester = ConnectionTester::with_timeout(Duration::from_millis(200));
+        let state = tester.test_udp_port("127.0.0.1", port).await.unwrap();
+        assert_eq!(state, UdpPortState::Filtered);
+        drop(silent);
+    }
+
+    #[tokio::test]
+    async fn test_ping_loopback() {
+        let tester = ConnectionTester::with_timeout(Duration::from_secs(2));
+
+        // Sandboxes often forbid ICMP sockets; that must surface as a
+        // dedicated error rather than a panic or a generic I/O failure.
+        match tester.test_ping("127.0.0.1").await {
+            Ok(rtt) => assert!(rtt < Duration::from_secs(2)),
+            Err(TestError::PingNotPermitted(_)) => {}
+            Err(e) => panic!("unexpected loopback ping error: {e}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connection_with_ping_reports_loopback_details() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let conn = Connection::new_ssh("Loopback".to_string(), "127.0.0.1".to_string(), port);
+        let tester = ConnectionTester::with_timeout(Duration::from_secs(2)).ping(true);
+        let result = tester.test_connection(&conn).await;
+
+        assert!(result.is_failure());
+        match result.details.get("ping").map(String::as_str) {
+            Some("ok") => {
+                assert!(result.details.contains_key("ping_latency_ms"));
+                assert_eq!(result.details.get("port_open"), Some(&"false".to_string()));
+            }
+            Some("unavailable") => {}
+            other => panic!("unexpected ping detail: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connection_notes_dynamic_forward() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let mut conn = Connection::new_ssh("Bastion".to_string(), "127.0.0.1".to_string(), port);
+        let tester = ConnectionTester::with_timeout(Duration::from_secs(2));
+        let result = tester.test_connection(&conn).await;
+        assert!(!result.details.contains_key("dynamic_forward"));
+
+        if let ProtocolConfig::Ssh(ref mut ssh) = conn.protocol_config {
+            ssh.port_forwards = vec![PortForward {
+                direction: PortForwardDirection::Dynamic,
+                bind_address: Some("127.0.0.1".to_string()),
+                local_port: 1080,
+                remote_host: String::new(),
+                remote_port: 0,
+            }];
+        }
+        let result = tester.test_connection(&conn).await;
+        assert_eq!(
+            result.details.get("dynamic_forward").map(String::as_str),
+            Some("127.0.0.1:1080")
+        );
+    }
+
+    fn with_jump_chain(port: u16) -> Connection {
+        let mut conn = Connection::new_ssh("Behind".to_string(), "target.invalid".to_string(), 22);
+        if let ProtocolConfig::Ssh(ref mut ssh) = conn.protocol_config {
+            ssh.jump_hosts = vec![
+                JumpHost::new("127.0.0.1").with_port(port),
+                JumpHost::new("inner.invalid").with_user("ops"),
+            ];
+        }
+        conn
+    }
+
+    #[tokio::test]
+    async fn test_connection_checks_first_jump_host() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let tester = ConnectionTester::with_timeout(Duration::from_secs(2));
+        let result = tester.test_connection(&with_jump_chain(port)).await;
+
+        assert!(result.is_success(), "{:?}", result.error);
+        assert_eq!(
+            result.details.get("jump_host"),
+            Some(&format!("127.0.0.1:{port}"))
+        );
+        assert_eq!(result.details.get("jump_hops"), Some(&"2".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_connection_unreachable_first_jump_host_fails() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let tester = ConnectionTester::with_timeout(Duration::from_secs(2));
+        let result = tester.test_connection(&with_jump_chain(port)).await;
+
+        assert!(result.is_failure());
+        assert_eq!(
+            result.details.get("jump_host"),
+            Some(&format!("127.0.0.1:{port}"))
+        );
     }
 }
@@ -2,10 +2,21 @@
 //!
 //! Provides the canonical span names used across the core operations
 //! (connection, search, import/export, credential resolution, session
-//! lifecycle) so log spans are named consistently. The `tracing` subscriber
+//! lifecycle) so log spans are named consistently, the structured field names
+//! events carry, and the JSON-lines formatting layer. The `tracing` subscriber
 //! itself is initialised by the application entry point via `tracing_subscriber`
 //! (see `rustconn/src/main.rs`).
 
+use std::str::FromStr;
+
+use tracing::Subscriber;
+use tracing_subscriber::Layer;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Environment variable selecting the log format (`text` or `json`)
+pub const LOG_FORMAT_ENV: &str = "RUSTCONN_LOG_FORMAT";
+
 /// Standard span names for `RustConn` operations
 pub mod span_names {
     /// Connection establishment span
@@ -33,3 +44,141 @@ pub mod span_names {
     /// Session end span
     pub const SESSION_END: &str = "session.end";
 }
+
+/// Standard structured field names for `RustConn` events and spans
+///
+/// Record these as fields (`connection_id = %id`) rather than interpolating
+/// them into the message, so JSON output exposes them as typed keys.
+pub mod field_names {
+    /// Connection UUID
+    pub const CONNECTION_ID: &str = "connection_id";
+    /// Connection display name
+    pub const CONNECTION_NAME: &str = "connection_name";
+    /// Protocol identifier (`ssh`, `rdp`, …)
+    pub const PROTOCOL: &str = "protocol";
+    /// Session UUID
+    pub const SESSION_ID: &str = "session_id";
+    /// Target host
+    pub const HOST: &str = "host";
+    /// Elapsed time in milliseconds, recorded as an integer
+    pub const DURATION_MS: &str = "duration_ms";
+}
+
+/// Log output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Human-readable text lines
+    #[default]
+    Text,
+    /// One JSON object per line, for log shippers such as Loki
+    Json,
+}
+
+impl LogFormat {
+    /// Reads the format from [`LOG_FORMAT_ENV`], falling back to text when
+    /// unset or unrecognised
+    #[must_use]
+    pub fn from_env() -> Self {
+        std::env::var(LOG_FORMAT_ENV)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            other => Err(format!("unknown log format '{other}'")),
+        }
+    }
+}
+
+/// Builds a JSON-lines formatting layer writing to `writer`
+///
+/// Event fields are flattened to top-level keys next to `timestamp`,
+/// `level`, `target` and `message`; the fields of the current span are
+/// nested under `span`. Integer and boolean fields keep their JSON types.
+pub fn json_layer<S, W>(writer: W) -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt::layer()
+        .json()
+        .flatten_event(true)
+        .with_current_span(true)
+        .with_span_list(false)
+        .with_writer(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use tracing_subscriber::layer::SubscriberExt;
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().expect("capture lock").extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'w> MakeWriter<'w> for Capture {
+        type Writer = Self;
+
+        fn make_writer(&'w self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_json_layer_emits_typed_fields() {
+        let capture = Capture::default();
+        let subscriber = tracing_subscriber::registry().with(json_layer(capture.clone()));
+        let connection_id = Uuid::new_v4();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(
+                connection_id = %connection_id,
+                protocol = "ssh",
+                duration_ms = 42_u64,
+                "Session ended"
+            );
+        });
+
+        let output = String::from_utf8(capture.0.lock().expect("capture lock").clone())
+            .expect("utf-8 output");
+        let line = output.lines().next().expect("one event");
+        let event: serde_json::Value = serde_json::from_str(line).expect("valid JSON");
+
+        assert_eq!(event[field_names::CONNECTION_ID], connection_id.to_string());
+        assert_eq!(event[field_names::PROTOCOL], "ssh");
+        assert_eq!(event[field_names::DURATION_MS], 42);
+        assert_eq!(event["message"], "Session ended");
+        assert_eq!(event["level"], "INFO");
+    }
+
+    #[test]
+    fn test_log_format_parse() {
+        assert_eq!("json".parse::<LogFormat>(), Ok(LogFormat::Json));
+        assert_eq!(" TEXT ".parse::<LogFormat>(), Ok(LogFormat::Text));
+        assert!("xml".parse::<LogFormat>().is_err());
+    }
+}
@@ -40,6 +40,7 @@ pub struct TunnelPreviewParams<'a> {
 ///     username: Some("user"),
 ///     forwards: &[PortForward {
 ///         direction: PortForwardDirection::Local,
+///         bind_address: None,
 ///         local_port: 8080,
 ///         remote_host: "localhost".to_string(),
 ///         remote_port: 80,
@@ -97,6 +98,7 @@ mod tests {
             username: Some("user"),
             forwards: &[PortForward {
                 direction: PortForwardDirection::Local,
+                bind_address: None,
                 local_port: 8080,
                 remote_host: "localhost".to_string(),
                 remote_port: 80,
@@ -116,6 +118,7 @@ mod tests {
             username: Some("admin"),
             forwards: &[PortForward {
                 direction: PortForwardDirection::Local,
+                bind_address: None,
                 local_port: 3306,
                 remote_host: "db.internal".to_string(),
                 remote_port: 3306,
@@ -139,6 +142,7 @@ mod tests {
             username: Some("user"),
             forwards: &[PortForward {
                 direction: PortForwardDirection::Dynamic,
+                bind_address: None,
                 local_port: 1080,
                 remote_host: String::new(),
                 remote_port: 0,
@@ -159,18 +163,21 @@ mod tests {
             forwards: &[
                 PortForward {
                     direction: PortForwardDirection::Local,
+                    bind_address: None,
                     local_port: 3306,
                     remote_host: "db".to_string(),
                     remote_port: 3306,
                 },
                 PortForward {
                     direction: PortForwardDirection::Remote,
+                    bind_address: None,
                     local_port: 8080,
                     remote_host: "web".to_string(),
                     remote_port: 80,
                 },
                 PortForward {
                     direction: PortForwardDirection::Dynamic,
+                    bind_address: None,
                     local_port: 1080,
                     remote_host: String::new(),
                     remote_port: 0,
@@ -208,6 +215,7 @@ mod tests {
             username: None,
             forwards: &[PortForward {
                 direction: PortForwardDirection::Local,
+                bind_address: None,
                 local_port: 5432,
                 remote_host: "localhost".to_string(),
                 remote_port: 5432,
@@ -228,6 +236,7 @@ mod tests {
             username: Some("user"),
             forwards: &[PortForward {
                 direction: PortForwardDirection::Local,
+                bind_address: None,
                 local_port: 8080,
                 remote_host: "localhost".to_string(),
                 remote_port: 80,
@@ -249,6 +258,7 @@ mod tests {
             username: Some("deploy"),
             forwards: &[PortForward {
                 direction: PortForwardDirection::Local,
+                bind_address: None,
                 local_port: 9090,
                 remote_host: "app".to_string(),
                 remote_port: 9090,
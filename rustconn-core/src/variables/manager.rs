@@ -7,9 +7,19 @@ use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
 
 use regex::Regex;
+use secrecy::SecretString;
 use uuid::Uuid;
+use zeroize::Zeroizing;
 
-use super::{MAX_NESTING_DEPTH, Variable, VariableError, VariableResult, VariableScope};
+use super::{
+    MAX_NESTING_DEPTH, Variable, VariableError, VariableResult, VariableScope, variable_secret_key,
+};
+use crate::error::SecretError;
+use crate::models::{Connection, Credentials};
+use crate::secret::SecretBackend;
+
+/// Secret values fetched from a backend for a single expansion, by name
+type SecretValues = HashMap<String, Zeroizing<String>>;
 
 /// Cached regex for variable extraction: matches `${var_name}` patterns
 pub static VARIABLE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
@@ -71,6 +81,21 @@ impl VariableManager {
             .insert(connection_id, document_id);
     }
 
+    /// Sets the synthetic connection-scoped variables for a connection
+    ///
+    /// Defines `host`, `port`, `name` and, when the connection has a
+    /// username, both `username` and its shorthand `user`.
+    pub fn set_connection_builtins(&mut self, connection: &Connection) {
+        let id = connection.id;
+        self.set_connection(id, Variable::new("host", &connection.host));
+        self.set_connection(id, Variable::new("port", connection.port.to_string()));
+        if let Some(ref user) = connection.username {
+            self.set_connection(id, Variable::new("username", user));
+            self.set_connection(id, Variable::new("user", user));
+        }
+        self.set_connection(id, Variable::new("name", &connection.name));
+    }
+
     /// Gets a global variable by name
     #[must_use]
     pub fn get_global(&self, name: &str) -> Option<&Variable> {
@@ -158,7 +183,7 @@ impl VariableManager {
     ///
     /// Returns `VariableError::Undefined` if the variable is not found in any scope.
     pub fn resolve(&self, name: &str, scope: VariableScope) -> VariableResult<String> {
-        self.resolve_with_depth(name, scope, 0, &mut HashSet::new())
+        self.resolve_with_depth(name, scope, 0, &mut Vec::new(), &SecretValues::new())
     }
 
     /// Internal resolution with depth tracking and cycle detection
@@ -167,14 +192,15 @@ impl VariableManager {
         name: &str,
         scope: VariableScope,
         depth: usize,
-        visited: &mut HashSet<String>,
+        path: &mut Vec<String>,
+        secrets: &SecretValues,
     ) -> VariableResult<String> {
         if depth > MAX_NESTING_DEPTH {
             return Err(VariableError::MaxDepthExceeded(MAX_NESTING_DEPTH));
         }
 
-        if visited.contains(name) {
-            return Err(VariableError::CircularReference(name.to_string()));
+        if path.iter().any(|entry| entry == name) {
+            return Err(Self::cycle_error(path, name));
         }
 
         // Look up the variable in the scope chain
@@ -182,16 +208,22 @@ impl VariableManager {
 
         match variable {
             Some(var) => {
+                // Backend-stored secrets take the value fetched for this call
+                let value = match secrets.get(name) {
+                    Some(secret) if var.is_secret && var.value.is_empty() => secret.as_str(),
+                    _ => var.value.as_str(),
+                };
+
                 // Check if the value contains nested variable references
-                let refs = Self::parse_references(&var.value)?;
+                let refs = Self::parse_references(value)?;
                 if refs.is_empty() {
-                    Ok(var.value.clone())
+                    Ok(value.to_string())
                 } else {
                     // Resolve nested references
-                    visited.insert(name.to_string());
+                    path.push(name.to_string());
                     let result =
-                        self.substitute_with_depth(&var.value, scope, depth + 1, visited)?;
-                    visited.remove(name);
+                        self.substitute_with_depth(value, scope, depth + 1, path, secrets)?;
+                    path.pop();
                     Ok(result)
                 }
             }
@@ -256,7 +288,7 @@ impl VariableManager {
     /// - Circular reference is detected
     /// - Maximum nesting depth is exceeded
     pub fn substitute(&self, input: &str, scope: VariableScope) -> VariableResult<String> {
-        self.substitute_with_depth(input, scope, 0, &mut HashSet::new())
+        self.substitute_with_depth(input, scope, 0, &mut Vec::new(), &SecretValues::new())
     }
 
     /// Internal substitution with depth tracking
@@ -265,7 +297,8 @@ impl VariableManager {
         input: &str,
         scope: VariableScope,
         depth: usize,
-        visited: &mut HashSet<String>,
+        path: &mut Vec<String>,
+        secrets: &SecretValues,
     ) -> VariableResult<String> {
         if depth > MAX_NESTING_DEPTH {
             return Err(VariableError::MaxDepthExceeded(MAX_NESTING_DEPTH));
@@ -278,7 +311,7 @@ impl VariableManager {
         let refs = Self::parse_references(input)?;
 
         for var_name in refs {
-            match self.resolve_with_depth(&var_name, scope, depth, visited) {
+            match self.resolve_with_depth(&var_name, scope, depth, path, secrets) {
                 Ok(value) => {
                     let pattern = format!("${{{var_name}}}");
                     result = result.replace(&pattern, &value);
@@ -302,6 +335,147 @@ impl VariableManager {
         Ok(result)
     }
 
+    // ========== Backend-Stored Secrets ==========
+
+    /// Sets a variable in `scope`, moving a secret value into `backend`
+    ///
+    /// The value of a secret variable is stored under
+    /// [`variable_secret_key`] and cleared from the stored [`Variable`], so
+    /// serializing the manager's variables never includes it. Use
+    /// [`Self::substitute_with_secrets`] to expand such variables. Non-secret
+    /// variables and secrets without a value are stored as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VariableError::SecretBackendLocked`] if the backend is locked
+    /// and [`VariableError::SecretUnavailable`] if storing the value fails.
+    pub async fn set_secret(
+        &mut self,
+        scope: VariableScope,
+        mut variable: Variable,
+        backend: &dyn SecretBackend,
+    ) -> VariableResult<()> {
+        if variable.is_secret && !variable.value.is_empty() {
+            Self::ensure_unlocked(backend, &variable.name).await?;
+            let credentials = Credentials {
+                username: None,
+                password: Some(SecretString::from(std::mem::take(&mut variable.value))),
+                key_passphrase: None,
+                domain: None,
+            };
+            backend
+                .store(&variable_secret_key(&variable.name), &credentials)
+                .await
+                .map_err(|e| Self::backend_error(backend, &variable.name, &e))?;
+        }
+
+        match scope {
+            VariableScope::Global => self.set_global(variable),
+            VariableScope::Document(id) => self.set_document(id, variable),
+            VariableScope::Connection(id) => self.set_connection(id, variable),
+        }
+        Ok(())
+    }
+
+    /// Substitutes all variable references, fetching backend-stored secret
+    /// values on demand
+    ///
+    /// Behaves like [`Self::substitute`], except that secret variables whose
+    /// value lives in `backend` (see [`Self::set_secret`]) are retrieved for
+    /// this call only and never cached. The backend is not contacted when
+    /// `input` references no such variable, directly or through nesting.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Self::substitute`], plus
+    /// [`VariableError::SecretBackendLocked`] when the backend is locked and
+    /// [`VariableError::SecretUnavailable`] when a value cannot be retrieved.
+    pub async fn substitute_with_secrets(
+        &self,
+        input: &str,
+        scope: VariableScope,
+        backend: &dyn SecretBackend,
+    ) -> VariableResult<String> {
+        let mut names = Vec::new();
+        self.collect_stored_secrets(input, scope, &mut names, &mut HashSet::new())?;
+
+        let mut secrets = SecretValues::new();
+        if let Some(first) = names.first() {
+            Self::ensure_unlocked(backend, first).await?;
+        }
+        for name in names {
+            let credentials = backend
+                .retrieve(&variable_secret_key(&name))
+                .await
+                .map_err(|e| Self::backend_error(backend, &name, &e))?;
+            let Some(value) = credentials.as_ref().and_then(Credentials::expose_password) else {
+                return Err(VariableError::SecretUnavailable {
+                    name,
+                    reason: format!("no value stored in {}", backend.display_name()),
+                });
+            };
+            secrets.insert(name, Zeroizing::new(value.to_string()));
+        }
+
+        self.substitute_with_depth(input, scope, 0, &mut Vec::new(), &secrets)
+    }
+
+    /// Collects the backend-stored secret variables reachable from `input`
+    fn collect_stored_secrets(
+        &self,
+        input: &str,
+        scope: VariableScope,
+        names: &mut Vec<String>,
+        seen: &mut HashSet<String>,
+    ) -> VariableResult<()> {
+        for name in Self::parse_references(input)? {
+            if !seen.insert(name.clone()) {
+                continue;
+            }
+            if let Some(var) = self.lookup_in_scope_chain(&name, scope) {
+                if var.is_secret && var.value.is_empty() {
+                    names.push(name);
+                } else {
+                    self.collect_stored_secrets(&var.value, scope, names, seen)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Fails with [`VariableError::SecretBackendLocked`] unless `backend` is
+    /// unlocked
+    async fn ensure_unlocked(backend: &dyn SecretBackend, name: &str) -> VariableResult<()> {
+        if backend.status().await.unlocked {
+            Ok(())
+        } else {
+            Err(VariableError::SecretBackendLocked {
+                backend: backend.display_name().to_string(),
+                name: name.to_string(),
+            })
+        }
+    }
+
+    /// Maps a backend error for variable `name`
+    fn backend_error(
+        backend: &dyn SecretBackend,
+        name: &str,
+        error: &SecretError,
+    ) -> VariableError {
+        match error {
+            SecretError::VaultLocked(_) | SecretError::NotSignedIn(_) => {
+                VariableError::SecretBackendLocked {
+                    backend: backend.display_name().to_string(),
+                    name: name.to_string(),
+                }
+            }
+            other => VariableError::SecretUnavailable {
+                name: name.to_string(),
+                reason: other.to_string(),
+            },
+        }
+    }
+
     // ========== Parsing ==========
 
     /// Parses variable references from a string
@@ -351,27 +525,25 @@ impl VariableManager {
     ///
     /// # Errors
     ///
-    /// Returns `VariableError::CircularReference` if a cycle is detected.
+    /// Returns `VariableError::CircularReference` listing the cycle (e.g.
+    /// `a -> b -> a`) if one is detected.
     pub fn detect_cycles(&self) -> VariableResult<()> {
         // Check global variables for cycles
         for name in self.global_vars.keys() {
-            let mut visited = HashSet::new();
-            self.check_cycle_from(name, VariableScope::Global, &mut visited)?;
+            self.check_cycle_from(name, VariableScope::Global, &mut Vec::new())?;
         }
 
         // Check document variables for cycles
         for (doc_id, vars) in &self.document_vars {
             for name in vars.keys() {
-                let mut visited = HashSet::new();
-                self.check_cycle_from(name, VariableScope::Document(*doc_id), &mut visited)?;
+                self.check_cycle_from(name, VariableScope::Document(*doc_id), &mut Vec::new())?;
             }
         }
 
         // Check connection variables for cycles
         for (conn_id, vars) in &self.connection_vars {
             for name in vars.keys() {
-                let mut visited = HashSet::new();
-                self.check_cycle_from(name, VariableScope::Connection(*conn_id), &mut visited)?;
+                self.check_cycle_from(name, VariableScope::Connection(*conn_id), &mut Vec::new())?;
             }
         }
 
@@ -383,26 +555,35 @@ impl VariableManager {
         &self,
         name: &str,
         scope: VariableScope,
-        visited: &mut HashSet<String>,
+        path: &mut Vec<String>,
     ) -> VariableResult<()> {
-        if visited.contains(name) {
-            return Err(VariableError::CircularReference(name.to_string()));
+        if path.iter().any(|entry| entry == name) {
+            return Err(Self::cycle_error(path, name));
         }
 
         if let Some(var) = self.lookup_in_scope_chain(name, scope) {
             let refs = Self::parse_references(&var.value)?;
             if !refs.is_empty() {
-                visited.insert(name.to_string());
+                path.push(name.to_string());
                 for ref_name in refs {
-                    self.check_cycle_from(&ref_name, scope, visited)?;
+                    self.check_cycle_from(&ref_name, scope, path)?;
                 }
-                visited.remove(name);
+                path.pop();
             }
         }
 
         Ok(())
     }
 
+    /// Builds the error for a reference back to `name`, listing the cycle
+    /// from its first occurrence in `path`, e.g. `a -> b -> a`
+    fn cycle_error(path: &[String], name: &str) -> VariableError {
+        let start = path.iter().position(|entry| entry == name).unwrap_or(0);
+        let mut cycle: Vec<&str> = path[start..].iter().map(String::as_str).collect();
+        cycle.push(name);
+        VariableError::CircularReference(cycle.join(" -> "))
+    }
+
     // ========== Command-Safe Substitution ==========
 
     /// Substitutes variables and validates the result is safe for use as a
@@ -498,6 +679,62 @@ impl VariableManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::SecretResult;
+    use crate::secret::BackendStatus;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// In-memory secret backend that can be locked
+    #[derive(Default)]
+    struct MemoryBackend {
+        entries: Mutex<HashMap<String, String>>,
+        locked: AtomicBool,
+    }
+
+    #[async_trait]
+    impl SecretBackend for MemoryBackend {
+        async fn store(&self, key: &str, credentials: &Credentials) -> SecretResult<()> {
+            let password = credentials
+                .expose_password()
+                .unwrap_or_default()
+                .to_string();
+            self.entries
+                .lock()
+                .expect("entries lock")
+                .insert(key.to_string(), password);
+            Ok(())
+        }
+        async fn retrieve(&self, key: &str) -> SecretResult<Option<Credentials>> {
+            let entries = self.entries.lock().expect("entries lock");
+            Ok(entries.get(key).map(|password| Credentials {
+                username: None,
+                password: Some(SecretString::from(password.clone())),
+                key_passphrase: None,
+                domain: None,
+            }))
+        }
+        async fn delete(&self, key: &str) -> SecretResult<()> {
+            self.entries.lock().expect("entries lock").remove(key);
+            Ok(())
+        }
+        async fn is_available(&self) -> bool {
+            true
+        }
+        async fn status(&self) -> BackendStatus {
+            BackendStatus {
+                available: true,
+                unlocked: !self.locked.load(Ordering::SeqCst),
+                server_url: None,
+            }
+        }
+        fn backend_id(&self) -> &'static str {
+            "memory"
+        }
+        fn display_name(&self) -> &'static str {
+            "Memory"
+        }
+    }
 
     fn create_test_manager() -> VariableManager {
         let mut manager = VariableManager::new();
@@ -510,6 +747,32 @@ mod tests {
         manager
     }
 
+    #[test]
+    fn test_connection_builtins() {
+        let mut manager = create_test_manager();
+        let mut conn = Connection::new_ssh("Web".to_string(), "web01.lan".to_string(), 2222);
+        conn.username = Some("deploy".to_string());
+        manager.set_connection_builtins(&conn);
+
+        let result = manager
+            .substitute(
+                "${user}@${host}:${port} (${name}, ${username})",
+                VariableScope::Connection(conn.id),
+            )
+            .unwrap();
+        assert_eq!(result, "deploy@web01.lan:2222 (Web, deploy)");
+
+        // Without a username the global `user` still applies
+        let anon = Connection::new_ssh("Anon".to_string(), "anon.lan".to_string(), 22);
+        manager.set_connection_builtins(&anon);
+        assert_eq!(
+            manager
+                .resolve("user", VariableScope::Connection(anon.id))
+                .unwrap(),
+            "admin"
+        );
+    }
+
     #[test]
     fn test_resolve_global_variable() {
         let manager = create_test_manager();
@@ -695,6 +958,57 @@ mod tests {
         assert!(matches!(result, Err(VariableError::CircularReference(_))));
     }
 
+    #[test]
+    fn test_circular_reference_lists_cycle() {
+        let mut manager = VariableManager::new();
+        manager.set_global(Variable::new("url", "${base}/api"));
+        manager.set_global(Variable::new("base", "https://${host}"));
+        manager.set_global(Variable::new("host", "${url}"));
+
+        assert_eq!(
+            manager.substitute("GET ${url}", VariableScope::Global),
+            Err(VariableError::CircularReference(
+                "url -> base -> host -> url".to_string()
+            ))
+        );
+        assert_eq!(
+            manager.resolve("base", VariableScope::Global),
+            Err(VariableError::CircularReference(
+                "base -> host -> url -> base".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_nested_resolution_respects_scope_precedence() {
+        let mut manager = VariableManager::new();
+        let doc_id = Uuid::new_v4();
+        let conn_id = Uuid::new_v4();
+        manager.set_connection_document(conn_id, doc_id);
+
+        manager.set_global(Variable::new("url", "${scheme}://${host}/${path}"));
+        manager.set_global(Variable::new("scheme", "http"));
+        manager.set_global(Variable::new("host", "global.example.com"));
+        manager.set_global(Variable::new("path", "api/${version}"));
+        manager.set_global(Variable::new("version", "v1"));
+        manager.set_document(doc_id, Variable::new("scheme", "https"));
+        manager.set_document(doc_id, Variable::new("host", "doc.example.com"));
+        manager.set_connection(conn_id, Variable::new("host", "conn.example.com"));
+
+        assert_eq!(
+            manager.resolve("url", VariableScope::Connection(conn_id)),
+            Ok("https://conn.example.com/api/v1".to_string())
+        );
+        assert_eq!(
+            manager.resolve("url", VariableScope::Document(doc_id)),
+            Ok("https://doc.example.com/api/v1".to_string())
+        );
+        assert_eq!(
+            manager.resolve("url", VariableScope::Global),
+            Ok("http://global.example.com/api/v1".to_string())
+        );
+    }
+
     #[test]
     fn test_self_reference_detection() {
         let mut manager = VariableManager::new();
@@ -829,4 +1143,108 @@ mod tests {
             .unwrap();
         assert_eq!(result, "prefix__suffix");
     }
+
+    #[tokio::test]
+    async fn test_secret_variable_stored_in_backend() {
+        let backend = MemoryBackend::default();
+        let mut manager = VariableManager::new();
+        manager.set_global(Variable::new("user", "deploy"));
+        manager
+            .set_secret(
+                VariableScope::Global,
+                Variable::new_secret("api_token", "tok-9f8e7d"),
+                &backend,
+            )
+            .await
+            .unwrap();
+
+        let stored = manager.get_global("api_token").unwrap();
+        let json = serde_json::to_string(stored).unwrap();
+        assert!(!json.contains("tok-9f8e7d"));
+        assert!(stored.is_secret());
+        assert_eq!(
+            backend
+                .entries
+                .lock()
+                .unwrap()
+                .get(&variable_secret_key("api_token"))
+                .map(String::as_str),
+            Some("tok-9f8e7d")
+        );
+
+        let result = manager
+            .substitute_with_secrets("${user}:${api_token}", VariableScope::Global, &backend)
+            .await
+            .unwrap();
+        assert_eq!(result, "deploy:tok-9f8e7d");
+    }
+
+    #[tokio::test]
+    async fn test_secret_variable_nested_reference() {
+        let backend = MemoryBackend::default();
+        let mut manager = VariableManager::new();
+        manager.set_global(Variable::new("auth", "Bearer ${api_token}"));
+        manager
+            .set_secret(
+                VariableScope::Global,
+                Variable::new_secret("api_token", "abc"),
+                &backend,
+            )
+            .await
+            .unwrap();
+
+        let result = manager
+            .substitute_with_secrets("${auth}", VariableScope::Global, &backend)
+            .await
+            .unwrap();
+        assert_eq!(result, "Bearer abc");
+    }
+
+    #[tokio::test]
+    async fn test_secret_expansion_fails_when_backend_locked() {
+        let backend = MemoryBackend::default();
+        let mut manager = VariableManager::new();
+        manager.set_global(Variable::new("host", "example.com"));
+        manager
+            .set_secret(
+                VariableScope::Global,
+                Variable::new_secret("api_token", "abc"),
+                &backend,
+            )
+            .await
+            .unwrap();
+        backend.locked.store(true, Ordering::SeqCst);
+
+        let result = manager
+            .substitute_with_secrets("${api_token}", VariableScope::Global, &backend)
+            .await;
+        assert_eq!(
+            result,
+            Err(VariableError::SecretBackendLocked {
+                backend: "Memory".to_string(),
+                name: "api_token".to_string(),
+            })
+        );
+
+        // Inputs without backend-stored secrets do not need the backend
+        let plain = manager
+            .substitute_with_secrets("${host}", VariableScope::Global, &backend)
+            .await;
+        assert_eq!(plain, Ok("example.com".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_secret_expansion_missing_entry() {
+        let backend = MemoryBackend::default();
+        let mut manager = VariableManager::new();
+        manager.set_global(Variable::new_secret("api_token", ""));
+
+        let result = manager
+            .substitute_with_secrets("${api_token}", VariableScope::Global, &backend)
+            .await;
+        assert!(matches!(
+            result,
+            Err(VariableError::SecretUnavailable { ref name, .. }) if name == "api_token"
+        ));
+    }
 }
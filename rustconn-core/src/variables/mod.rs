@@ -200,6 +200,25 @@ pub enum VariableError {
     #[error("Empty variable name")]
     EmptyName,
 
+    /// The secret backend holding a secret variable's value is locked
+    #[error("Secret backend {backend} is locked; unlock it to expand '{name}'")]
+    SecretBackendLocked {
+        /// Backend display name
+        backend: String,
+        /// Variable name
+        name: String,
+    },
+
+    /// A secret variable's value could not be stored in or read from the
+    /// secret backend
+    #[error("Secret variable '{name}' is unavailable: {reason}")]
+    SecretUnavailable {
+        /// Variable name
+        name: String,
+        /// Backend error or missing-entry description
+        reason: String,
+    },
+
     /// Resolved value contains characters unsafe for command arguments
     #[error("Variable '{name}' contains unsafe characters for command use: {reason}")]
     UnsafeValue {
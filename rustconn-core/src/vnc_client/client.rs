@@ -12,7 +12,9 @@ use vnc::{
     ClientKeyEvent, ClientMouseEvent, PixelFormat, VncConnector, VncEncoding, VncEvent, X11Event,
 };
 
-use super::{VncClientCommand, VncClientConfig, VncClientError, VncClientEvent, VncRect};
+use super::{
+    VncClientCommand, VncClientConfig, VncClientError, VncClientEvent, VncRect, VncViewTransform,
+};
 
 /// Sender for commands to the VNC client (thread-safe, non-async)
 pub type VncCommandSender = mpsc::Sender<VncClientCommand>;
@@ -125,6 +127,10 @@ impl VncClient {
     ///
     /// Returns error if not connected or channel is closed.
     pub fn send_command(&self, command: VncClientCommand) -> Result<(), VncClientError> {
+        if self.config.view_only && command.is_input() {
+            tracing::trace!(?command, "[VNC] View-only: dropping input command");
+            return Ok(());
+        }
         let tx = self
             .command_tx
             .as_ref()
@@ -144,9 +150,6 @@ impl VncClient {
     ///
     /// Returns error if not connected or channel is closed.
     pub fn send_key(&self, keysym: u32, pressed: bool) -> Result<(), VncClientError> {
-        if self.config.view_only {
-            return Ok(());
-        }
         self.send_command(VncClientCommand::KeyEvent { keysym, pressed })
     }
 
@@ -156,9 +159,6 @@ impl VncClient {
     ///
     /// Returns error if not connected or channel is closed.
     pub fn send_pointer(&self, x: u16, y: u16, buttons: u8) -> Result<(), VncClientError> {
-        if self.config.view_only {
-            return Ok(());
-        }
         self.send_command(VncClientCommand::PointerEvent { x, y, buttons })
     }
 
@@ -208,6 +208,25 @@ impl VncClient {
         &self.config
     }
 
+    /// Returns the framebuffer-to-widget mapping for the configured scaling
+    /// mode
+    #[must_use]
+    pub fn view_transform(
+        &self,
+        fb_width: u32,
+        fb_height: u32,
+        widget_width: f64,
+        widget_height: f64,
+    ) -> VncViewTransform {
+        VncViewTransform::new(
+            self.config.scaling,
+            fb_width,
+            fb_height,
+            widget_width,
+            widget_height,
+        )
+    }
+
     /// Returns the event receiver for external polling
     ///
     /// This allows the caller to set up their own event polling mechanism.
@@ -235,17 +254,34 @@ async fn run_vnc_client(
     event_tx: std::sync::mpsc::Sender<VncClientEvent>,
     mut command_rx: mpsc::Receiver<VncClientCommand>,
 ) -> Result<(), VncClientError> {
-    // Connect to the server with timeout
+    // Connect to the server (or the repeater in front of it) with timeout
     tracing::warn!(
         host = %config.host,
         port = %config.port,
         "VNC connection is unencrypted. Consider using SSH tunnel for security."
     );
     let connect_timeout = std::time::Duration::from_secs(config.timeout_secs);
-    let tcp = if config.mptcp {
+    let (dial_host, dial_port) = config.dial_target();
+    let repeater_handshake = config
+        .repeater
+        .as_ref()
+        .map(super::repeater_handshake)
+        .transpose()
+        .map_err(|e| VncClientError::ConnectionFailed(e.to_string()))?;
+    let mut tcp = if let Some(ref proxy) = config.proxy {
+        crate::connection::connect_via_proxy_async(
+            proxy,
+            config.proxy_password.as_ref(),
+            dial_host,
+            dial_port,
+            connect_timeout,
+        )
+        .await
+        .map_err(|e| VncClientError::ConnectionFailed(e.to_string()))?
+    } else if config.mptcp {
         // MPTCP path: resolve hostname then use MPTCP socket
         tokio::time::timeout(connect_timeout, async {
-            let addr = tokio::net::lookup_host(config.server_address())
+            let addr = tokio::net::lookup_host((dial_host, dial_port))
                 .await
                 .map_err(|e| VncClientError::ConnectionFailed(format!("Failed to resolve: {e}")))?
                 .next()
@@ -264,7 +300,7 @@ async fn run_vnc_client(
             ))
         })??
     } else {
-        tokio::time::timeout(connect_timeout, TcpStream::connect(config.server_address()))
+        tokio::time::timeout(connect_timeout, TcpStream::connect((dial_host, dial_port)))
             .await
             .map_err(|_| {
                 VncClientError::ConnectionFailed(format!(
@@ -275,6 +311,22 @@ async fn run_vnc_client(
             .map_err(|e| VncClientError::ConnectionFailed(e.to_string()))?
     };
 
+    // Ask the repeater for the server; its RFB negotiation follows on `tcp`
+    if let Some(ref handshake) = repeater_handshake {
+        tokio::time::timeout(
+            connect_timeout,
+            super::negotiate_repeater(&mut tcp, handshake),
+        )
+        .await
+        .map_err(|_| {
+            VncClientError::ConnectionFailed(format!(
+                "VNC repeater did not answer within {}s",
+                config.timeout_secs
+            ))
+        })?
+        .map_err(|e| VncClientError::ConnectionFailed(format!("VNC repeater: {e}")))?;
+    }
+
     // Build the VNC connector
     //
     // The vnc connector's auth setter consumes an owned plain `String`; the
@@ -364,6 +416,10 @@ async fn run_vnc_client(
             cmd = command_rx.recv() => {
                 match cmd {
                     Some(VncClientCommand::Disconnect) | None => break,
+                    // Senders obtained via command_sender() bypass send_command
+                    Some(cmd) if config.view_only && cmd.is_input() => {
+                        tracing::trace!(?cmd, "[VNC] View-only: dropping input command");
+                    }
                     Some(cmd) => {
                         match cmd {
                             VncClientCommand::KeyEvent { keysym, pressed } => {
@@ -563,6 +619,50 @@ mod tests {
         assert!(!client.is_connected());
     }
 
+    #[test]
+    fn test_view_only_drops_input_commands() {
+        let client = VncClient::new(VncClientConfig::new("localhost").with_view_only(true));
+        // Dropped before the channel is consulted, so no NotConnected error
+        assert!(client.send_key(0x61, true).is_ok());
+        assert!(
+            client
+                .send_command(VncClientCommand::TypeText("x".into()))
+                .is_ok()
+        );
+
+        let (tx, mut rx) = mpsc::channel(8);
+        let mut client = client;
+        client.command_tx = Some(tx);
+        client.send_pointer(10, 10, 1).unwrap();
+        client.send_ctrl_alt_del().unwrap();
+        client
+            .send_command(VncClientCommand::RefreshScreen)
+            .unwrap();
+        assert!(matches!(rx.try_recv(), Ok(VncClientCommand::RefreshScreen)));
+        assert!(rx.try_recv().is_err());
+
+        let mut client = VncClient::new(VncClientConfig::new("localhost"));
+        let (tx, mut rx) = mpsc::channel(8);
+        client.command_tx = Some(tx);
+        client.send_key(0x61, true).unwrap();
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(VncClientCommand::KeyEvent { keysym: 0x61, .. })
+        ));
+    }
+
+    #[test]
+    fn test_view_transform_uses_configured_scaling() {
+        let config = VncClientConfig::new("localhost")
+            .with_scaling(super::super::VncScalingMode::FixedScale(2.0));
+        let client = VncClient::new(config);
+        let t = client.view_transform(640, 480, 1920.0, 1080.0);
+        assert!((t.scale - 2.0).abs() < 1e-9);
+        let (x, y) = t.widget_to_framebuffer(340.0, 80.0);
+        assert!((x - 10.0).abs() < 1e-9);
+        assert!((y - 10.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_convert_resolution_event() {
         // Create a mock screen struct similar to vnc-rs
@@ -3,6 +3,9 @@
 use secrecy::SecretString;
 use serde::{Deserialize, Serialize};
 
+use super::VncScalingMode;
+use crate::models::{ProxyConfig, VncRepeater};
+
 /// Configuration for VNC client connection
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VncClientConfig {
@@ -28,6 +31,10 @@ pub struct VncClientConfig {
     /// View-only mode (no input forwarding)
     pub view_only: bool,
 
+    /// How the framebuffer is scaled inside the viewer widget
+    #[serde(default)]
+    pub scaling: VncScalingMode,
+
     /// Connection timeout in seconds
     pub timeout_secs: u64,
 
@@ -36,6 +43,18 @@ pub struct VncClientConfig {
     /// Requires kernel MPTCP support (Linux 5.6+). Falls back to regular TCP.
     #[serde(default)]
     pub mptcp: bool,
+
+    /// SOCKS/HTTP proxy the TCP connection is routed through
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+
+    /// Password for proxy authentication (resolved from the secret backend)
+    #[serde(skip)]
+    pub proxy_password: Option<SecretString>,
+
+    /// Repeater dialled instead of `host:port`, selecting the server by ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repeater: Option<VncRepeater>,
 }
 
 impl Default for VncClientConfig {
@@ -64,8 +83,12 @@ impl Default for VncClientConfig {
             ],
             shared: true,
             view_only: false,
+            scaling: VncScalingMode::default(),
             timeout_secs: 30,
             mptcp: false,
+            proxy: None,
+            proxy_password: None,
+            repeater: None,
         }
     }
 }
@@ -101,6 +124,13 @@ impl VncClientConfig {
         self
     }
 
+    /// Sets the framebuffer scaling mode
+    #[must_use]
+    pub const fn with_scaling(mut self, scaling: VncScalingMode) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
     /// Sets shared session mode
     #[must_use]
     pub const fn with_shared(mut self, shared: bool) -> Self {
@@ -108,11 +138,37 @@ impl VncClientConfig {
         self
     }
 
+    /// Routes the connection through a proxy
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: ProxyConfig, password: Option<SecretString>) -> Self {
+        self.proxy = Some(proxy);
+        self.proxy_password = password;
+        self
+    }
+
+    /// Connects through a repeater instead of directly to the server
+    #[must_use]
+    pub fn with_repeater(mut self, repeater: VncRepeater) -> Self {
+        self.repeater = Some(repeater);
+        self
+    }
+
     /// Returns the server address as "host:port"
     #[must_use]
     pub fn server_address(&self) -> String {
         format!("{}:{}", self.host, self.port)
     }
+
+    /// Returns the host and port the TCP connection is made to: the
+    /// repeater if one is set, otherwise the server
+    #[must_use]
+    pub fn dial_target(&self) -> (&str, u16) {
+        self.repeater
+            .as_ref()
+            .map_or((self.host.as_str(), self.port), |r| {
+                (r.host.as_str(), r.port)
+            })
+    }
 }
 
 /// Pixel format for VNC framebuffer
@@ -160,6 +216,7 @@ mod tests {
             .with_port(5901)
             .with_password("secret")
             .with_view_only(true)
+            .with_scaling(VncScalingMode::FixedScale(0.75))
             .with_shared(false);
 
         assert_eq!(config.host, "192.168.1.100");
@@ -169,6 +226,7 @@ mod tests {
             Some("secret")
         );
         assert!(config.view_only);
+        assert_eq!(config.scaling, VncScalingMode::FixedScale(0.75));
         assert!(!config.shared);
     }
 
@@ -178,6 +236,16 @@ mod tests {
         assert_eq!(config.server_address(), "localhost:5900");
     }
 
+    #[test]
+    fn test_dial_target_prefers_repeater() {
+        let config = VncClientConfig::new("desktop-07").with_port(5900);
+        assert_eq!(config.dial_target(), ("desktop-07", 5900));
+
+        let config = config.with_repeater(VncRepeater::new("repeater.example.com", "1234"));
+        assert_eq!(config.dial_target(), ("repeater.example.com", 5901));
+        assert_eq!(config.server_address(), "desktop-07:5900");
+    }
+
     #[test]
     fn test_default_encodings() {
         let config = VncClientConfig::default();
@@ -1,5 +1,7 @@
 //! VNC client events and commands
 
+use super::VncViewTransform;
+
 /// Rectangle coordinates for VNC operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct VncRect {
@@ -24,6 +26,19 @@ impl VncRect {
             height,
         }
     }
+
+    /// Returns the widget-space rectangle `(x, y, width, height)` covered by
+    /// this framebuffer rectangle under `transform`
+    #[must_use]
+    pub fn to_widget(&self, transform: &VncViewTransform) -> (f64, f64, f64, f64) {
+        let (x, y) = transform.framebuffer_to_widget(f64::from(self.x), f64::from(self.y));
+        (
+            x,
+            y,
+            f64::from(self.width) * transform.scale,
+            f64::from(self.height) * transform.scale,
+        )
+    }
 }
 
 /// Events emitted by the VNC client to the GUI
@@ -128,6 +143,23 @@ pub enum VncClientCommand {
     TypeText(String),
 }
 
+impl VncClientCommand {
+    /// Returns true for commands that act on the remote session as the user
+    /// would (keyboard, pointer, typed text, clipboard), which view-only
+    /// sessions drop
+    #[must_use]
+    pub const fn is_input(&self) -> bool {
+        matches!(
+            self,
+            Self::KeyEvent { .. }
+                | Self::PointerEvent { .. }
+                | Self::ClipboardText(_)
+                | Self::SendCtrlAltDel
+                | Self::TypeText(_)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +173,38 @@ mod tests {
         assert_eq!(rect.height, 200);
     }
 
+    #[test]
+    fn test_vnc_rect_to_widget_scaled() {
+        let transform = VncViewTransform::new(
+            super::super::VncScalingMode::FixedScale(0.5),
+            1920,
+            1080,
+            1000.0,
+            1000.0,
+        );
+        // 960x540 centered in 1000x1000: offset (20, 230)
+        let (x, y, w, h) = VncRect::new(100, 200, 300, 400).to_widget(&transform);
+        assert!((x - 70.0).abs() < 1e-9);
+        assert!((y - 330.0).abs() < 1e-9);
+        assert!((w - 150.0).abs() < 1e-9);
+        assert!((h - 200.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_command_is_input() {
+        assert!(
+            VncClientCommand::PointerEvent {
+                x: 1,
+                y: 2,
+                buttons: 0
+            }
+            .is_input()
+        );
+        assert!(VncClientCommand::SendCtrlAltDel.is_input());
+        assert!(!VncClientCommand::RefreshScreen.is_input());
+        assert!(!VncClientCommand::Disconnect.is_input());
+    }
+
     #[test]
     fn test_event_variants() {
         let event = VncClientEvent::Connected;
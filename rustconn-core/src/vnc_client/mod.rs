@@ -1,8 +1,9 @@
 //! Optional embedded VNC client integration boundary
 //!
-//! A headless build keeps this module as an availability boundary only. The
-//! `vnc-rs` runtime, VNC config, events, and client types are compiled only with
-//! the `vnc-embedded` feature.
+//! A headless build keeps this module as an availability boundary plus the
+//! pure [`scaling`] helpers and the [`repeater`] handshake. The `vnc-rs`
+//! runtime, VNC config, events, and client types are compiled only with the
+//! `vnc-embedded` feature.
 //!
 //! # Architecture
 //!
@@ -19,6 +20,8 @@ mod config;
 mod error;
 #[cfg(feature = "vnc-embedded")]
 mod event;
+pub mod repeater;
+pub mod scaling;
 
 #[cfg(feature = "vnc-embedded")]
 pub use client::{VncClient, VncCommandSender, VncEventReceiver};
@@ -28,6 +31,8 @@ pub use config::{VncClientConfig, VncEncoding};
 pub use error::VncClientError;
 #[cfg(feature = "vnc-embedded")]
 pub use event::{VncClientCommand, VncClientEvent, VncRect};
+pub use repeater::{REPEATER_BANNER_LEN, negotiate_repeater, repeater_handshake};
+pub use scaling::{VncScalingMode, VncViewTransform};
 
 /// Check if embedded VNC support is available
 #[must_use]
@@ -0,0 +1,109 @@
+//! UltraVNC repeater handshake (ID mode)
+//!
+//! A viewer behind a repeater dials the repeater, reads its 12-byte
+//! `RFB 000.000\n` banner and answers with a fixed 250-byte block holding
+//! `ID:<id>` padded with NUL bytes. The repeater then pairs the viewer with
+//! the server registered under that ID and relays its bytes unchanged, so the
+//! normal RFB negotiation follows on the same stream. This module has no
+//! `vnc-rs` dependency, so it is available without the `vnc-embedded`
+//! feature.
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::error::ProtocolResult;
+use crate::models::VncRepeater;
+
+/// Length of the banner the repeater sends first
+pub const REPEATER_BANNER_LEN: usize = 12;
+
+/// Builds the block a viewer sends to select the server
+///
+/// # Errors
+///
+/// Returns the errors of [`VncRepeater::validate`].
+pub fn repeater_handshake(
+    repeater: &VncRepeater,
+) -> ProtocolResult<[u8; VncRepeater::HANDSHAKE_LEN]> {
+    repeater.validate()?;
+    let target = repeater.target();
+    let mut block = [0u8; VncRepeater::HANDSHAKE_LEN];
+    block[..target.len()].copy_from_slice(target.as_bytes());
+    Ok(block)
+}
+
+/// Selects the server on a stream connected to the repeater
+///
+/// Reads the repeater's banner and sends `handshake`; the stream then
+/// carries the server's RFB negotiation.
+///
+/// # Errors
+///
+/// Returns an I/O error if the stream fails or the banner is not an RFB
+/// protocol version message.
+pub async fn negotiate_repeater<S>(
+    stream: &mut S,
+    handshake: &[u8; VncRepeater::HANDSHAKE_LEN],
+) -> std::io::Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut banner = [0u8; REPEATER_BANNER_LEN];
+    stream.read_exact(&mut banner).await?;
+    if !banner.starts_with(b"RFB ") || banner[REPEATER_BANNER_LEN - 1] != b'\n' {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "VNC repeater did not send an RFB banner",
+        ));
+    }
+    tracing::debug!(
+        banner = %String::from_utf8_lossy(&banner).trim_end(),
+        "[VNC] Repeater banner received"
+    );
+    stream.write_all(handshake).await?;
+    stream.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handshake_is_nul_padded_id() {
+        let block = repeater_handshake(&VncRepeater::new("repeater", "1234")).unwrap();
+        assert_eq!(block.len(), 250);
+        assert_eq!(&block[..7], b"ID:1234");
+        assert!(block[7..].iter().all(|&b| b == 0));
+
+        assert!(repeater_handshake(&VncRepeater::new("repeater", "")).is_err());
+        assert!(repeater_handshake(&VncRepeater::new("repeater", "12a4")).is_err());
+        assert!(repeater_handshake(&VncRepeater::new("repeater", "1".repeat(247))).is_err());
+        assert!(repeater_handshake(&VncRepeater::new(" ", "1234")).is_err());
+        let longest = repeater_handshake(&VncRepeater::new("repeater", "9".repeat(246))).unwrap();
+        assert_eq!(longest[249], 0);
+    }
+
+    #[tokio::test]
+    async fn test_negotiate_repeater_sends_handshake_after_banner() {
+        let handshake = repeater_handshake(&VncRepeater::new("repeater", "42")).unwrap();
+        let (mut viewer, mut repeater) = tokio::io::duplex(512);
+
+        let relay = tokio::spawn(async move {
+            repeater.write_all(b"RFB 000.000\n").await.unwrap();
+            let mut received = [0u8; VncRepeater::HANDSHAKE_LEN];
+            repeater.read_exact(&mut received).await.unwrap();
+            // The server's greeting follows once the pair is made
+            repeater.write_all(b"RFB 003.008\n").await.unwrap();
+            received
+        });
+
+        negotiate_repeater(&mut viewer, &handshake).await.unwrap();
+        assert_eq!(relay.await.unwrap(), handshake);
+        let mut greeting = [0u8; 12];
+        viewer.read_exact(&mut greeting).await.unwrap();
+        assert_eq!(&greeting, b"RFB 003.008\n");
+
+        let (mut viewer, mut repeater) = tokio::io::duplex(512);
+        repeater.write_all(b"HTTP/1.1 400").await.unwrap();
+        assert!(negotiate_repeater(&mut viewer, &handshake).await.is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! Framebuffer scaling for embedded VNC sessions
+//!
+//! [`VncScalingMode`] says how the remote framebuffer is sized inside the
+//! widget; [`VncViewTransform`] is the resulting mapping for one widget and
+//! framebuffer size, used both for drawing and for translating pointer
+//! positions back to framebuffer coordinates. This module has no `vnc-rs`
+//! dependency, so it is available without the `vnc-embedded` feature.
+
+use serde::{Deserialize, Serialize};
+
+/// How the remote framebuffer is scaled inside the viewer widget
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum VncScalingMode {
+    /// Draw at 1:1, anchored at the top-left when larger than the widget
+    None,
+    /// Scale to fit the widget, keeping the aspect ratio
+    #[default]
+    FitWindow,
+    /// Draw at a fixed factor (e.g. `0.5` or `2.0`)
+    FixedScale(f32),
+}
+
+impl VncScalingMode {
+    /// Returns the scale factor for a framebuffer inside a widget
+    ///
+    /// Degenerate sizes and non-positive or non-finite fixed factors fall
+    /// back to `1.0`.
+    #[must_use]
+    pub fn scale_for(
+        self,
+        fb_width: u32,
+        fb_height: u32,
+        widget_width: f64,
+        widget_height: f64,
+    ) -> f64 {
+        let scale = match self {
+            Self::None => 1.0,
+            Self::FitWindow => {
+                if fb_width == 0 || fb_height == 0 {
+                    return 1.0;
+                }
+                (widget_width / f64::from(fb_width)).min(widget_height / f64::from(fb_height))
+            }
+            Self::FixedScale(factor) => f64::from(factor),
+        };
+        if scale.is_finite() && scale > 0.0 {
+            scale
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Mapping between framebuffer and widget coordinates
+///
+/// Widget position = framebuffer position × `scale` + offset. The
+/// framebuffer is centered along each axis where it is smaller than the
+/// widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VncViewTransform {
+    /// Widget pixels per framebuffer pixel
+    pub scale: f64,
+    /// Horizontal offset of the framebuffer origin in the widget
+    pub offset_x: f64,
+    /// Vertical offset of the framebuffer origin in the widget
+    pub offset_y: f64,
+    fb_width: u32,
+    fb_height: u32,
+}
+
+impl VncViewTransform {
+    /// Computes the transform for a framebuffer drawn in a widget
+    #[must_use]
+    pub fn new(
+        mode: VncScalingMode,
+        fb_width: u32,
+        fb_height: u32,
+        widget_width: f64,
+        widget_height: f64,
+    ) -> Self {
+        let scale = mode.scale_for(fb_width, fb_height, widget_width, widget_height);
+        let offset_x = (f64::from(fb_width).mul_add(-scale, widget_width) / 2.0).max(0.0);
+        let offset_y = (f64::from(fb_height).mul_add(-scale, widget_height) / 2.0).max(0.0);
+        Self {
+            scale,
+            offset_x,
+            offset_y,
+            fb_width,
+            fb_height,
+        }
+    }
+
+    /// Maps a framebuffer point to widget coordinates
+    #[must_use]
+    pub fn framebuffer_to_widget(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            x.mul_add(self.scale, self.offset_x),
+            y.mul_add(self.scale, self.offset_y),
+        )
+    }
+
+    /// Maps a widget point to framebuffer coordinates, clamped to the
+    /// framebuffer
+    #[must_use]
+    pub fn widget_to_framebuffer(&self, x: f64, y: f64) -> (f64, f64) {
+        let max_x = f64::from(self.fb_width.saturating_sub(1));
+        let max_y = f64::from(self.fb_height.saturating_sub(1));
+        (
+            ((x - self.offset_x) / self.scale).clamp(0.0, max_x),
+            ((y - self.offset_y) / self.scale).clamp(0.0, max_y),
+        )
+    }
+
+    /// Returns the size of the drawn framebuffer in widget pixels
+    #[must_use]
+    pub fn scaled_size(&self) -> (f64, f64) {
+        (
+            f64::from(self.fb_width) * self.scale,
+            f64::from(self.fb_height) * self.scale,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: (f64, f64), b: (f64, f64)) -> bool {
+        (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_fit_window_downscale_centers() {
+        // 1920x1080 in a 960x800 widget: scale 0.5, letterboxed vertically
+        let t = VncViewTransform::new(VncScalingMode::FitWindow, 1920, 1080, 960.0, 800.0);
+        assert!((t.scale - 0.5).abs() < 1e-9);
+        assert!(approx((t.offset_x, t.offset_y), (0.0, 130.0)));
+        assert!(approx(t.framebuffer_to_widget(100.0, 100.0), (50.0, 180.0)));
+        assert!(approx(t.widget_to_framebuffer(50.0, 180.0), (100.0, 100.0)));
+        assert!(approx(t.scaled_size(), (960.0, 540.0)));
+    }
+
+    #[test]
+    fn test_fixed_scale_round_trip() {
+        let t = VncViewTransform::new(VncScalingMode::FixedScale(1.5), 800, 600, 1600.0, 900.0);
+        assert!((t.scale - 1.5).abs() < 1e-9);
+        assert!(approx((t.offset_x, t.offset_y), (200.0, 0.0)));
+        let widget = t.framebuffer_to_widget(400.0, 300.0);
+        assert!(approx(widget, (800.0, 450.0)));
+        assert!(approx(
+            t.widget_to_framebuffer(widget.0, widget.1),
+            (400.0, 300.0)
+        ));
+
+        // Larger than the widget: anchored top-left, pointer clamped
+        let t = VncViewTransform::new(VncScalingMode::FixedScale(2.0), 800, 600, 1000.0, 700.0);
+        assert!(approx((t.offset_x, t.offset_y), (0.0, 0.0)));
+        assert!(approx(
+            t.widget_to_framebuffer(999.0, 699.0),
+            (499.5, 349.5)
+        ));
+        assert!(approx(t.widget_to_framebuffer(5000.0, -10.0), (799.0, 0.0)));
+    }
+
+    #[test]
+    fn test_no_scaling_and_invalid_factors() {
+        let t = VncViewTransform::new(VncScalingMode::None, 640, 480, 1280.0, 960.0);
+        assert!((t.scale - 1.0).abs() < 1e-9);
+        assert!(approx(t.widget_to_framebuffer(330.0, 250.0), (10.0, 10.0)));
+
+        for factor in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(
+                (VncScalingMode::FixedScale(factor).scale_for(640, 480, 100.0, 100.0) - 1.0).abs()
+                    < 1e-9
+            );
+        }
+        assert!((VncScalingMode::FitWindow.scale_for(0, 0, 100.0, 100.0) - 1.0).abs() < 1e-9);
+    }
+}
@@ -2,10 +2,15 @@
 //!
 //! This module provides functionality to wake sleeping machines before connecting
 //! by sending magic packets to their MAC addresses.
+//!
+//! Packets are sent over IPv4 broadcast by default. An IPv6 multicast target
+//! (e.g. `ff02::1%eth0`) can be configured instead; the socket family is
+//! chosen from the configured address.
 
 use std::fmt;
-use std::net::UdpSocket;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6, ToSocketAddrs, UdpSocket};
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -32,6 +37,21 @@ pub enum WolError {
     /// Failed to set socket options
     #[error("Failed to set socket options: {0}")]
     SocketOptionError(String),
+
+    /// Target address could not be parsed or resolved
+    #[error("Invalid target address: {0}")]
+    InvalidAddress(String),
+
+    /// Host did not start accepting connections before the timeout
+    #[error("{host}:{port} did not come online within {seconds}s")]
+    OnlineTimeout {
+        /// The host that was polled
+        host: String,
+        /// The port that was polled
+        port: u16,
+        /// The timeout that elapsed, in seconds
+        seconds: u64,
+    },
 }
 
 /// Result type alias for WOL operations
@@ -167,15 +187,35 @@ pub const DEFAULT_WOL_PORT: u16 = 9;
 /// Default broadcast address
 pub const DEFAULT_BROADCAST_ADDRESS: &str = "255.255.255.255";
 
+/// IPv6 all-nodes link-local multicast address
+///
+/// Link-local multicast needs an interface scope, e.g. `ff02::1%eth0`.
+pub const DEFAULT_IPV6_MULTICAST_ADDRESS: &str = "ff02::1";
+
 /// Default wait time in seconds after sending WOL packet
 pub const DEFAULT_WOL_WAIT_SECONDS: u32 = 30;
 
+/// Default time in seconds to poll for the host to come online after waking it
+pub const DEFAULT_WOL_ONLINE_TIMEOUT_SECONDS: u32 = 120;
+
+/// Default interval in seconds between online checks
+pub const DEFAULT_WOL_POLL_INTERVAL_SECONDS: u32 = 5;
+
+/// Number of magic packets sent per wake attempt
+const WAKE_PACKET_COUNT: u8 = 3;
+
+/// Delay between magic packets within a wake attempt
+const WAKE_PACKET_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Wake On LAN configuration for a connection
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WolConfig {
     /// MAC address of the target machine
     pub mac_address: MacAddress,
     /// Broadcast address to send the magic packet to
+    ///
+    /// Either an IPv4 broadcast address, an IPv6 multicast address with an
+    /// optional `%interface` scope, or a resolvable host name.
     #[serde(default = "default_broadcast_address")]
     pub broadcast_address: String,
     /// UDP port to send the magic packet to
@@ -184,6 +224,15 @@ pub struct WolConfig {
     /// Seconds to wait after sending the packet before attempting connection
     #[serde(default = "default_wait_seconds")]
     pub wait_seconds: u32,
+    /// Poll the connection port after waking and connect once it answers
+    #[serde(default)]
+    pub wait_for_online: bool,
+    /// Maximum seconds to poll for the host to come online
+    #[serde(default = "default_online_timeout_seconds")]
+    pub online_timeout_seconds: u32,
+    /// Seconds between online checks
+    #[serde(default = "default_poll_interval_seconds")]
+    pub poll_interval_seconds: u32,
 }
 
 fn default_broadcast_address() -> String {
@@ -198,6 +247,14 @@ const fn default_wait_seconds() -> u32 {
     DEFAULT_WOL_WAIT_SECONDS
 }
 
+const fn default_online_timeout_seconds() -> u32 {
+    DEFAULT_WOL_ONLINE_TIMEOUT_SECONDS
+}
+
+const fn default_poll_interval_seconds() -> u32 {
+    DEFAULT_WOL_POLL_INTERVAL_SECONDS
+}
+
 impl WolConfig {
     /// Creates a new WOL configuration with the given MAC address
     #[must_use]
@@ -207,6 +264,9 @@ impl WolConfig {
             broadcast_address: DEFAULT_BROADCAST_ADDRESS.to_string(),
             port: DEFAULT_WOL_PORT,
             wait_seconds: DEFAULT_WOL_WAIT_SECONDS,
+            wait_for_online: false,
+            online_timeout_seconds: DEFAULT_WOL_ONLINE_TIMEOUT_SECONDS,
+            poll_interval_seconds: DEFAULT_WOL_POLL_INTERVAL_SECONDS,
         }
     }
 
@@ -230,6 +290,47 @@ impl WolConfig {
         self.wait_seconds = seconds;
         self
     }
+
+    /// Enables or disables polling for the host to come online after waking
+    #[must_use]
+    pub const fn with_wait_for_online(mut self, wait: bool) -> Self {
+        self.wait_for_online = wait;
+        self
+    }
+
+    /// Sets the maximum time in seconds to poll for the host to come online
+    #[must_use]
+    pub const fn with_online_timeout_seconds(mut self, seconds: u32) -> Self {
+        self.online_timeout_seconds = seconds;
+        self
+    }
+
+    /// Sets the interval in seconds between online checks
+    #[must_use]
+    pub const fn with_poll_interval_seconds(mut self, seconds: u32) -> Self {
+        self.poll_interval_seconds = seconds;
+        self
+    }
+
+    /// Returns the online polling timeout as a `Duration`
+    #[must_use]
+    pub const fn online_timeout(&self) -> Duration {
+        Duration::from_secs(self.online_timeout_seconds as u64)
+    }
+
+    /// Returns the online polling interval as a `Duration`
+    #[must_use]
+    pub const fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.poll_interval_seconds as u64)
+    }
+
+    /// Resolves the configured broadcast address and port to a socket address
+    ///
+    /// # Errors
+    /// Returns `WolError::InvalidAddress` if the address cannot be resolved.
+    pub fn target_addr(&self) -> WolResult<SocketAddr> {
+        resolve_target(&self.broadcast_address, self.port)
+    }
 }
 
 /// Magic packet size: 6 bytes of 0xFF + 16 repetitions of 6-byte MAC address
@@ -259,30 +360,101 @@ pub fn generate_magic_packet(mac: &MacAddress) -> [u8; MAGIC_PACKET_SIZE] {
     packet
 }
 
+/// Resolves a broadcast or multicast address and port to a socket address
+///
+/// Accepts IPv4 addresses, IPv6 addresses (optionally in brackets and with a
+/// `%scope` suffix naming an interface or its index), and host names.
+///
+/// # Errors
+/// Returns `WolError::InvalidAddress` if the address cannot be parsed or resolved.
+pub fn resolve_target(address: &str, port: u16) -> WolResult<SocketAddr> {
+    let trimmed = address.trim();
+    let unbracketed = trimmed
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or(trimmed);
+    let (host, scope) = unbracketed
+        .split_once('%')
+        .map_or((unbracketed, None), |(host, scope)| (host, Some(scope)));
+
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return match (ip, scope) {
+            (IpAddr::V4(_), Some(_)) => Err(WolError::InvalidAddress(format!(
+                "Scope is only valid for IPv6 addresses: '{trimmed}'"
+            ))),
+            (IpAddr::V4(v4), None) => Ok(SocketAddr::from((v4, port))),
+            (IpAddr::V6(v6), scope) => {
+                let scope_id = scope.map_or(Ok(0), resolve_scope_id)?;
+                Ok(SocketAddr::V6(SocketAddrV6::new(v6, port, 0, scope_id)))
+            }
+        };
+    }
+
+    (host, port)
+        .to_socket_addrs()
+        .map_err(|e| WolError::InvalidAddress(format!("'{trimmed}': {e}")))?
+        .next()
+        .ok_or_else(|| WolError::InvalidAddress(format!("'{trimmed}' did not resolve")))
+}
+
+/// Resolves an IPv6 scope to an interface index
+///
+/// The scope may be a numeric index or an interface name.
+fn resolve_scope_id(scope: &str) -> WolResult<u32> {
+    if let Ok(index) = scope.parse::<u32>() {
+        return Ok(index);
+    }
+    if scope.is_empty() || scope.contains(['/', '.']) {
+        return Err(WolError::InvalidAddress(format!(
+            "Invalid interface name: '{scope}'"
+        )));
+    }
+    std::fs::read_to_string(format!("/sys/class/net/{scope}/ifindex"))
+        .ok()
+        .and_then(|index| index.trim().parse().ok())
+        .ok_or_else(|| WolError::InvalidAddress(format!("Unknown network interface: '{scope}'")))
+}
+
+/// Creates a UDP socket suited to the target's address family
+///
+/// IPv4 targets get a broadcast-enabled socket; IPv6 targets get an IPv6
+/// socket, since IPv6 has no broadcast and relies on multicast instead.
+///
+/// # Errors
+/// Returns an error if the socket cannot be bound or configured.
+pub fn create_socket(target: &SocketAddr) -> WolResult<UdpSocket> {
+    match target {
+        SocketAddr::V4(_) => {
+            let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))
+                .map_err(|e| WolError::SocketError(e.to_string()))?;
+            socket
+                .set_broadcast(true)
+                .map_err(|e| WolError::SocketOptionError(e.to_string()))?;
+            Ok(socket)
+        }
+        SocketAddr::V6(_) => UdpSocket::bind((Ipv6Addr::UNSPECIFIED, 0))
+            .map_err(|e| WolError::SocketError(e.to_string())),
+    }
+}
+
 /// Sends a Wake On LAN magic packet to wake a sleeping machine
 ///
 /// # Arguments
 /// * `mac` - The MAC address of the target machine
-/// * `broadcast` - The broadcast address to send to (e.g., "255.255.255.255")
+/// * `broadcast` - The broadcast or multicast address to send to
+///   (e.g., "255.255.255.255" or "ff02::1%eth0")
 /// * `port` - The UDP port to send to (typically 9 or 7)
 ///
 /// # Errors
-/// Returns an error if the socket cannot be created or the packet cannot be sent.
+/// Returns an error if the address is invalid, the socket cannot be created,
+/// or the packet cannot be sent.
 pub fn send_magic_packet(mac: &MacAddress, broadcast: &str, port: u16) -> WolResult<()> {
     let packet = generate_magic_packet(mac);
+    let target = resolve_target(broadcast, port)?;
+    let socket = create_socket(&target)?;
 
-    // Create UDP socket
-    let socket = UdpSocket::bind("0.0.0.0:0").map_err(|e| WolError::SocketError(e.to_string()))?;
-
-    // Enable broadcast
-    socket
-        .set_broadcast(true)
-        .map_err(|e| WolError::SocketOptionError(e.to_string()))?;
-
-    // Send the magic packet
-    let target = format!("{broadcast}:{port}");
     socket
-        .send_to(&packet, &target)
+        .send_to(&packet, target)
         .map_err(|e| WolError::SendError(e.to_string()))?;
 
     Ok(())
@@ -312,6 +484,67 @@ pub fn send_wol_with_retry(config: &WolConfig, count: u8, interval_ms: u64) -> W
     Ok(())
 }
 
+/// Polls a TCP port until it accepts connections or the timeout elapses
+///
+/// Each probe uses `connection::check_port_async`; resolution failures and
+/// refused connections are treated as "not online yet" while the machine
+/// boots. Probes are spaced `interval` apart.
+///
+/// # Returns
+/// How long it took for the port to answer.
+///
+/// # Errors
+/// Returns `WolError::OnlineTimeout` if the port did not answer in time.
+pub async fn wait_for_online(
+    host: &str,
+    port: u16,
+    timeout: Duration,
+    interval: Duration,
+) -> WolResult<Duration> {
+    let start = Instant::now();
+    let probe_secs = u32::try_from(interval.as_secs()).unwrap_or(u32::MAX).max(1);
+
+    loop {
+        if crate::connection::check_port_async(host, port, probe_secs)
+            .await
+            .is_ok()
+        {
+            return Ok(start.elapsed());
+        }
+
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            return Err(WolError::OnlineTimeout {
+                host: host.to_string(),
+                port,
+                seconds: timeout.as_secs(),
+            });
+        }
+        tokio::time::sleep(interval.min(timeout - elapsed)).await;
+    }
+}
+
+/// Wakes a machine and waits until the given port accepts connections
+///
+/// Sends the magic packet a few times, then polls with the timeout and
+/// interval from `config`.
+///
+/// # Returns
+/// How long it took for the port to answer after the packets were sent.
+///
+/// # Errors
+/// Returns an error if the packet cannot be sent or the host does not come
+/// online before `config.online_timeout_seconds` elapses.
+pub async fn wake_and_wait(config: &WolConfig, host: &str, port: u16) -> WolResult<Duration> {
+    for i in 0..WAKE_PACKET_COUNT {
+        send_wol(config)?;
+        if i + 1 < WAKE_PACKET_COUNT {
+            tokio::time::sleep(WAKE_PACKET_INTERVAL).await;
+        }
+    }
+    wait_for_online(host, port, config.online_timeout(), config.poll_interval()).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -387,6 +620,9 @@ mod tests {
         assert_eq!(config.broadcast_address, "255.255.255.255");
         assert_eq!(config.port, 9);
         assert_eq!(config.wait_seconds, 30);
+        assert!(!config.wait_for_online);
+        assert_eq!(config.online_timeout_seconds, 120);
+        assert_eq!(config.poll_interval_seconds, 5);
     }
 
     #[test]
@@ -423,4 +659,112 @@ mod tests {
         let parsed: MacAddress = serde_json::from_str(&json).unwrap();
         assert_eq!(mac, parsed);
     }
+
+    #[test]
+    fn test_resolve_target_ipv4() {
+        let target = resolve_target("192.168.1.255", 9).unwrap();
+        assert_eq!(target, "192.168.1.255:9".parse().unwrap());
+    }
+
+    #[test]
+    fn test_resolve_target_ipv6() {
+        let target = resolve_target(DEFAULT_IPV6_MULTICAST_ADDRESS, 9).unwrap();
+        assert!(target.is_ipv6());
+        assert_eq!(target.port(), 9);
+
+        let bracketed = resolve_target("[ff02::1]", 7).unwrap();
+        assert_eq!(bracketed.ip(), target.ip());
+        assert_eq!(bracketed.port(), 7);
+    }
+
+    #[test]
+    fn test_resolve_target_ipv6_numeric_scope() {
+        let SocketAddr::V6(target) = resolve_target("ff02::1%3", 9).unwrap() else {
+            panic!("expected an IPv6 target");
+        };
+        assert_eq!(target.scope_id(), 3);
+    }
+
+    #[test]
+    fn test_resolve_target_invalid_scope() {
+        assert!(matches!(
+            resolve_target("ff02::1%../lo", 9),
+            Err(WolError::InvalidAddress(_))
+        ));
+        assert!(matches!(
+            resolve_target("192.168.1.255%eth0", 9),
+            Err(WolError::InvalidAddress(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_socket_ipv4() {
+        let target = resolve_target(DEFAULT_BROADCAST_ADDRESS, DEFAULT_WOL_PORT).unwrap();
+        let socket = create_socket(&target).unwrap();
+        assert!(socket.local_addr().unwrap().is_ipv4());
+        assert!(socket.broadcast().unwrap());
+    }
+
+    #[test]
+    fn test_create_socket_ipv6_for_multicast_target() {
+        let mac = MacAddress::new([0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF]);
+        let config = WolConfig::new(mac).with_broadcast_address(DEFAULT_IPV6_MULTICAST_ADDRESS);
+
+        let packet = generate_magic_packet(&config.mac_address);
+        assert_eq!(packet.len(), MAGIC_PACKET_SIZE);
+
+        let target = config.target_addr().unwrap();
+        assert!(target.ip().is_multicast());
+
+        let socket = create_socket(&target).unwrap();
+        assert!(socket.local_addr().unwrap().is_ipv6());
+    }
+
+    #[test]
+    fn test_wol_config_online_polling_defaults_on_deserialize() {
+        let json = r#"{"mac_address":"AA:BB:CC:DD:EE:FF"}"#;
+        let config: WolConfig = serde_json::from_str(json).unwrap();
+
+        assert!(!config.wait_for_online);
+        assert_eq!(config.online_timeout(), Duration::from_secs(120));
+        assert_eq!(config.poll_interval(), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_online_open_port() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let waited = wait_for_online(
+            "127.0.0.1",
+            port,
+            Duration::from_secs(5),
+            Duration::from_millis(100),
+        )
+        .await
+        .unwrap();
+        assert!(waited < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_online_times_out_on_closed_port() {
+        // Bind then drop to get a port that is very likely closed
+        let port = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let timeout = Duration::from_millis(600);
+
+        let start = Instant::now();
+        let result = wait_for_online("127.0.0.1", port, timeout, Duration::from_millis(100)).await;
+
+        assert!(start.elapsed() >= timeout);
+        match result {
+            Err(WolError::OnlineTimeout { host, port: p, .. }) => {
+                assert_eq!(host, "127.0.0.1");
+                assert_eq!(p, port);
+            }
+            other => panic!("expected OnlineTimeout, got {other:?}"),
+        }
+    }
 }
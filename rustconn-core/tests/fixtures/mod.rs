@@ -27,15 +27,19 @@ pub fn sample_ssh_connection_with_key() -> Connection {
         proxy_jump: None,
         proxy_command: None,
         pkcs11_provider: None,
+        jump_hosts: Vec::new(),
         use_control_master: false,
         agent_forwarding: false,
+        agent_forwarding_acknowledged: false,
         x11_forwarding: false,
+        x11_trusted: false,
         compression: false,
         custom_options: std::collections::HashMap::new(),
         startup_command: None,
         jump_host_id: None,
         sftp_enabled: false,
         port_forwards: Vec::new(),
+        port_forward_profiles: Vec::new(),
         waypipe: false,
         ssh_agent_socket: None,
         keep_alive_interval: None,
@@ -71,15 +75,19 @@ pub fn sample_ssh_connection_custom_port() -> Connection {
         proxy_jump: None,
         proxy_command: None,
         pkcs11_provider: None,
+        jump_hosts: Vec::new(),
         use_control_master: false,
         agent_forwarding: false,
+        agent_forwarding_acknowledged: false,
         x11_forwarding: false,
+        x11_trusted: false,
         compression: false,
         custom_options: std::collections::HashMap::new(),
         startup_command: None,
         jump_host_id: None,
         sftp_enabled: false,
         port_forwards: Vec::new(),
+        port_forward_profiles: Vec::new(),
         waypipe: false,
         ssh_agent_socket: None,
         keep_alive_interval: None,
@@ -113,15 +121,19 @@ pub fn sample_ssh_connection_with_proxy() -> Connection {
         proxy_jump: Some("bastion.example.com".to_string()),
         proxy_command: None,
         pkcs11_provider: None,
+        jump_hosts: Vec::new(),
         use_control_master: false,
         agent_forwarding: false,
+        agent_forwarding_acknowledged: false,
         x11_forwarding: false,
+        x11_trusted: false,
         compression: false,
         custom_options: std::collections::HashMap::new(),
         startup_command: None,
         jump_host_id: None,
         sftp_enabled: false,
         port_forwards: Vec::new(),
+        port_forward_profiles: Vec::new(),
         waypipe: false,
         ssh_agent_socket: None,
         keep_alive_interval: None,
@@ -213,6 +225,7 @@ pub fn sample_vnc_connection_custom_port() -> Connection {
         jump_host_id: None,
         accept_certificate: false,
         mptcp: false,
+        repeater: None,
     };
 
     Connection::new(
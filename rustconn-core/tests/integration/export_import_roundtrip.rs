@@ -15,11 +15,11 @@
 use std::path::PathBuf;
 
 use rustconn_core::export::{
-    AnsibleExporter, AsbruExporter, ExportFormat, ExportOptions, ExportTarget, RemminaExporter,
-    SshConfigExporter,
+    AnsibleExporter, AnsibleInventoryFormat, AsbruExporter, ExportFormat, ExportOptions,
+    ExportResult, ExportTarget, RemminaExporter, SshConfigExporter,
 };
 use rustconn_core::import::{
-    AnsibleInventoryImporter, AsbruImporter, RemminaImporter, SshConfigImporter,
+    AnsibleInventoryImporter, AsbruImporter, ImportResult, RemminaImporter, SshConfigImporter,
 };
 use rustconn_core::models::{Connection, ConnectionGroup, ProtocolConfig, ProtocolType};
 use tempfile::TempDir;
@@ -214,6 +214,95 @@ fn test_ansible_export_skips_non_ssh() {
     );
 }
 
+/// Exports `infra` > (`web`, `db`) plus an ungrouped host and an RDP
+/// connection through [`ExportTarget`] and re-imports the written file
+fn ansible_nested_roundtrip(format: AnsibleInventoryFormat) -> (ExportResult, ImportResult) {
+    let infra = ConnectionGroup::new("infra".to_string());
+    let web = ConnectionGroup::with_parent("web".to_string(), infra.id);
+    let db = ConnectionGroup::with_parent("db".to_string(), infra.id);
+
+    let mut web1 =
+        Connection::new_ssh("web1".to_string(), "10.0.0.1".to_string(), 22).with_group(web.id);
+    web1.username = Some("deploy".to_string());
+    let connections = vec![
+        web1,
+        Connection::new_ssh("db1".to_string(), "10.0.0.2".to_string(), 2222).with_group(db.id),
+        Connection::new_ssh("bastion".to_string(), "bastion.example.com".to_string(), 22),
+        Connection::new_rdp("desktop".to_string(), "10.0.0.3".to_string(), 3389).with_group(web.id),
+    ];
+    let groups = vec![infra, web, db];
+
+    // The extension says INI; the explicit option must win for YAML
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let path = temp_dir.path().join("inventory");
+    let options =
+        ExportOptions::new(ExportFormat::Ansible, path.clone()).with_ansible_format(format);
+    let export_result = AnsibleExporter::new()
+        .export(&connections, &groups, &options)
+        .expect("Ansible export should succeed");
+
+    let content = std::fs::read_to_string(&path).expect("inventory should be written");
+    let importer = AnsibleInventoryImporter::new();
+    let import_result = match format {
+        AnsibleInventoryFormat::Ini => importer.parse_ini_inventory(&content, "test"),
+        AnsibleInventoryFormat::Yaml => importer.parse_yaml_inventory(&content, "test"),
+    };
+    (export_result, import_result)
+}
+
+fn assert_ansible_nested_roundtrip(format: AnsibleInventoryFormat) {
+    let (exported, imported) = ansible_nested_roundtrip(format);
+
+    assert_eq!(exported.exported_count, 3);
+    assert_eq!(exported.skipped_count, 1);
+    assert!(
+        exported.warnings.iter().any(|w| w.contains("desktop")),
+        "Omitted RDP connection should be noted: {:?}",
+        exported.warnings
+    );
+
+    assert!(imported.errors.is_empty(), "{:?}", imported.errors);
+    assert_eq!(imported.connections.len(), 3);
+
+    let group_named = |name: &str| {
+        imported
+            .groups
+            .iter()
+            .find(|g| g.name == name)
+            .unwrap_or_else(|| panic!("group '{name}' missing after round-trip"))
+    };
+    let infra = group_named("infra");
+    assert_eq!(infra.parent_id, None);
+    assert_eq!(group_named("web").parent_id, Some(infra.id));
+    assert_eq!(group_named("db").parent_id, Some(infra.id));
+
+    let conn_named = |name: &str| {
+        imported
+            .connections
+            .iter()
+            .find(|c| c.name == name)
+            .unwrap_or_else(|| panic!("connection '{name}' missing after round-trip"))
+    };
+    let web1 = conn_named("web1");
+    assert_eq!(web1.host, "10.0.0.1");
+    assert_eq!(web1.username.as_deref(), Some("deploy"));
+    assert_eq!(web1.group_id, Some(group_named("web").id));
+    let db1 = conn_named("db1");
+    assert_eq!(db1.port, 2222);
+    assert_eq!(db1.group_id, Some(group_named("db").id));
+    assert_eq!(conn_named("bastion").host, "bastion.example.com");
+}
+
+#[test]
+fn test_ansible_ini_nested_groups_roundtrip() {
+    assert_ansible_nested_roundtrip(AnsibleInventoryFormat::Ini);
+}
+
+#[test]
+fn test_ansible_yaml_nested_groups_roundtrip() {
+    assert_ansible_nested_roundtrip(AnsibleInventoryFormat::Yaml);
+}
+
 // ============================================================================
 // SSH Config Export/Import Round-Trip Tests
 // ============================================================================
@@ -296,6 +385,60 @@ fn test_ssh_config_export_skips_non_ssh() {
     );
 }
 
+#[test]
+fn test_ssh_config_roundtrip_sanitized_aliases() {
+    let mut web = Connection::new_ssh(
+        "Web Server (EU)".to_string(),
+        "web.eu.example.com".to_string(),
+        2202,
+    );
+    web.username = Some("deploy".to_string());
+    if let ProtocolConfig::Ssh(ref mut ssh) = web.protocol_config {
+        ssh.key_path = Some(PathBuf::from("/home/user/.ssh/keys/web key"));
+        ssh.proxy_jump = Some("ops@bastion.example.com:2222".to_string());
+    }
+    let mut tunnel =
+        Connection::new_ssh("tunnel".to_string(), "hidden.example.com".to_string(), 22);
+    if let ProtocolConfig::Ssh(ref mut ssh) = tunnel.protocol_config {
+        ssh.proxy_command = Some("ssh -o Compression=yes gw nc %h %p".to_string());
+    }
+    let connections = vec![
+        web.clone(),
+        tunnel.clone(),
+        Connection::new_rdp("desktop".to_string(), "10.0.0.3".to_string(), 3389),
+    ];
+
+    let exported = SshConfigExporter::export(&connections);
+    assert!(exported.contains("Host Web-Server-EU\n"), "{exported}");
+    assert!(exported.contains("# 1 non-SSH connection(s) skipped"));
+    assert_eq!(
+        exported,
+        SshConfigExporter::export(&connections),
+        "aliases must be stable across exports"
+    );
+
+    let result = SshConfigImporter::new().parse_config(&exported, "test");
+    assert_eq!(result.connections.len(), 2, "{exported}");
+    for (original, alias) in [(&web, "Web-Server-EU"), (&tunnel, "tunnel")] {
+        let reimported = result
+            .connections
+            .iter()
+            .find(|c| c.name == alias)
+            .unwrap_or_else(|| panic!("'{alias}' not found after round-trip:\n{exported}"));
+        assert_eq!(reimported.host, original.host);
+        assert_eq!(reimported.port, original.port);
+        assert_eq!(reimported.username, original.username);
+        let (ProtocolConfig::Ssh(orig_ssh), ProtocolConfig::Ssh(reimp_ssh)) =
+            (&original.protocol_config, &reimported.protocol_config)
+        else {
+            panic!("expected SSH configs");
+        };
+        assert_eq!(reimp_ssh.key_path, orig_ssh.key_path);
+        assert_eq!(reimp_ssh.proxy_jump, orig_ssh.proxy_jump);
+        assert_eq!(reimp_ssh.proxy_command, orig_ssh.proxy_command);
+    }
+}
+
 #[test]
 fn test_ssh_config_preserves_proxy_jump() {
     let mut conn = Connection::new_ssh(
@@ -3,10 +3,18 @@
 //! These tests verify that importers can handle real-world data files
 //! and edge cases correctly.
 
+use std::path::PathBuf;
+
 use rustconn_core::import::{
-    AsbruImporter, RdmImporter, RemminaImporter, RoyalTsImporter, SshConfigImporter,
+    AsbruImporter, ImportSource, MremoteNgImporter, RdmImporter, RemminaImporter, RoyalTsImporter,
+    SecureCrtImporter, SshConfigImporter, TermiusImporter,
+};
+use rustconn_core::models::{
+    PasswordSource, ProtocolConfig, ProtocolType, Resolution, SerialBaudRate, SerialFlowControl,
+    SerialParity, SshAuthMethod, SshKeySource,
 };
-use rustconn_core::models::ProtocolType;
+use rustconn_core::terminal_themes::TerminalTheme;
+use secrecy::ExposeSecret;
 
 // ============================================================================
 // RDM JSON Import Integration Tests
@@ -716,3 +724,316 @@ fn test_large_import_performance() {
     // Should complete within reasonable time (adjust threshold as needed)
     assert!(duration.as_secs() < 5, "Import took too long: {duration:?}");
 }
+
+// ============================================================================
+// SecureCRT Session Import Integration Tests
+// ============================================================================
+
+fn securecrt_sessions_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("securecrt")
+        .join("Sessions")
+}
+
+#[test]
+fn test_securecrt_import_ssh2_fixture() {
+    let dir = securecrt_sessions_dir();
+    let result = SecureCrtImporter::with_path(dir.clone())
+        .import_from_path(&dir)
+        .unwrap();
+
+    let conn = result
+        .connections
+        .iter()
+        .find(|c| c.name == "prod-web")
+        .expect("SSH2 session should be imported");
+    assert_eq!(conn.host, "web01.example.com");
+    assert_eq!(conn.port, 2222);
+    assert_eq!(conn.username.as_deref(), Some("deploy"));
+    assert_eq!(
+        conn.description.as_deref(),
+        Some("Production web frontend\nBehind the load balancer")
+    );
+    assert!(conn.group_id.is_none());
+
+    let ProtocolConfig::Ssh(ssh) = &conn.protocol_config else {
+        panic!("Expected SSH config");
+    };
+    assert_eq!(ssh.auth_method, SshAuthMethod::PublicKey);
+    assert!(ssh.agent_forwarding);
+    assert!(ssh.compression);
+    assert!(ssh.jump_host_id.is_none());
+}
+
+#[test]
+fn test_securecrt_import_serial_fixture() {
+    let dir = securecrt_sessions_dir();
+    let result = SecureCrtImporter::with_path(dir.clone())
+        .import_from_path(&dir)
+        .unwrap();
+
+    assert_eq!(result.groups.len(), 1);
+    assert_eq!(result.groups[0].name, "Network");
+
+    let conn = result
+        .connections
+        .iter()
+        .find(|c| c.name == "console-switch")
+        .expect("Serial session should be imported");
+    assert_eq!(conn.group_id, Some(result.groups[0].id));
+
+    let ProtocolConfig::Serial(serial) = &conn.protocol_config else {
+        panic!("Expected serial config");
+    };
+    assert_eq!(serial.device, "/dev/ttyS0");
+    assert_eq!(serial.baud_rate, SerialBaudRate::B38400);
+    assert_eq!(serial.parity, SerialParity::Odd);
+    assert_eq!(serial.flow_control, SerialFlowControl::Software);
+}
+
+// ============================================================================
+// mRemoteNG Import Integration Tests
+// ============================================================================
+
+fn mremoteng_fixture() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("mremoteng")
+        .join("confCons.xml")
+}
+
+#[test]
+fn test_mremoteng_rebuilds_nested_group_tree() {
+    let path = mremoteng_fixture();
+    let result = MremoteNgImporter::with_path(path.clone())
+        .import_from_path(&path)
+        .unwrap();
+
+    let group = |name: &str| {
+        result
+            .groups
+            .iter()
+            .find(|g| g.name == name)
+            .unwrap_or_else(|| panic!("group {name} should be imported"))
+    };
+    let datacenter = group("Datacenter");
+    let windows = group("Windows");
+    let linux = group("Linux");
+    assert_eq!(result.groups.len(), 3);
+    assert!(datacenter.parent_id.is_none());
+    assert_eq!(windows.parent_id, Some(datacenter.id));
+    assert_eq!(linux.parent_id, Some(datacenter.id));
+
+    let conn = |name: &str| {
+        result
+            .connections
+            .iter()
+            .find(|c| c.name == name)
+            .unwrap_or_else(|| panic!("connection {name} should be imported"))
+    };
+    assert_eq!(conn("dc01").group_id, Some(windows.id));
+    assert_eq!(conn("fs01").group_id, Some(windows.id));
+    assert_eq!(conn("web01").group_id, Some(linux.id));
+    assert_eq!(conn("console").group_id, Some(linux.id));
+    assert_eq!(result.connections.len(), 4);
+
+    // Username and domain inherited through two folder levels
+    assert_eq!(conn("dc01").username.as_deref(), Some("svc-admin"));
+    assert_eq!(conn("dc01").domain.as_deref(), Some("CORP"));
+    assert_eq!(conn("fs01").username.as_deref(), Some("backup"));
+}
+
+#[test]
+fn test_mremoteng_fixture_protocols_and_rdp_options() {
+    let path = mremoteng_fixture();
+    let result = MremoteNgImporter::new().import_from_path(&path).unwrap();
+    let conn = |name: &str| result.connections.iter().find(|c| c.name == name).unwrap();
+
+    let dc01 = conn("dc01");
+    assert_eq!(
+        dc01.description.as_deref(),
+        Some("Primary domain controller")
+    );
+    let ProtocolConfig::Rdp(rdp) = &dc01.protocol_config else {
+        panic!("Expected RDP config");
+    };
+    assert_eq!(rdp.resolution, Some(Resolution::new(1920, 1080)));
+    assert_eq!(rdp.color_depth, Some(32));
+    assert!(rdp.printer_enabled);
+    assert!(rdp.audio_redirect);
+
+    let fs01 = conn("fs01");
+    assert_eq!(fs01.port, 3390);
+    let ProtocolConfig::Rdp(rdp) = &fs01.protocol_config else {
+        panic!("Expected RDP config");
+    };
+    assert!(rdp.resolution.is_none());
+    assert!(rdp.shared_folders.is_empty());
+    assert!(!rdp.printer_enabled);
+
+    assert_eq!(conn("web01").protocol, ProtocolType::Ssh);
+    assert_eq!(conn("console").protocol, ProtocolType::Vnc);
+    assert_eq!(conn("console").port, 5901);
+}
+
+#[test]
+fn test_mremoteng_skips_encrypted_passwords_and_unsupported_protocols() {
+    let path = mremoteng_fixture();
+    let result = MremoteNgImporter::new().import_from_path(&path).unwrap();
+
+    assert!(result.errors.is_empty());
+    let dc01 = result
+        .connections
+        .iter()
+        .find(|c| c.name == "dc01")
+        .unwrap();
+    assert_eq!(dc01.password_source, PasswordSource::Prompt);
+    assert!(result.credentials.is_empty());
+
+    let skipped: Vec<&str> = result
+        .skipped
+        .iter()
+        .map(|s| s.identifier.as_str())
+        .collect();
+    assert_eq!(skipped, ["dc01 (password)", "Citrix portal"]);
+}
+
+// ============================================================================
+// Termius Import Integration Tests
+// ============================================================================
+
+fn termius_fixture() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("termius")
+        .join("termius_export.json")
+}
+
+#[test]
+fn test_termius_rebuilds_groups_and_tags() {
+    let path = termius_fixture();
+    let result = TermiusImporter::with_path(path.clone())
+        .import_from_path(&path)
+        .unwrap();
+
+    let group = |name: &str| result.groups.iter().find(|g| g.name == name).unwrap();
+    let production = group("Production");
+    let web = group("Web");
+    let staging = group("Staging");
+    assert_eq!(result.groups.len(), 3);
+    assert!(production.parent_id.is_none());
+    assert_eq!(web.parent_id, Some(production.id));
+    assert!(staging.parent_id.is_none());
+
+    let conn = |name: &str| {
+        result
+            .connections
+            .iter()
+            .find(|c| c.name == name)
+            .unwrap_or_else(|| panic!("connection {name} should be imported"))
+    };
+    assert_eq!(result.connections.len(), 4);
+    assert_eq!(conn("web-01").group_id, Some(web.id));
+    assert_eq!(conn("db-primary").group_id, Some(production.id));
+    assert_eq!(conn("staging-app").group_id, Some(staging.id));
+    assert!(conn("router").group_id.is_none());
+
+    assert_eq!(conn("web-01").tags, ["prod", "web"]);
+    assert_eq!(conn("db-primary").tags, ["prod", "database"]);
+    assert!(conn("router").tags.is_empty());
+    assert!(
+        result
+            .connections
+            .iter()
+            .all(|c| c.protocol == ProtocolType::Ssh)
+    );
+}
+
+#[test]
+fn test_termius_joins_identities_and_keys() {
+    let path = termius_fixture();
+    let result = TermiusImporter::new().import_from_path(&path).unwrap();
+    let conn = |name: &str| result.connections.iter().find(|c| c.name == name).unwrap();
+    let ssh = |name: &str| match &conn(name).protocol_config {
+        ProtocolConfig::Ssh(ssh) => ssh.clone(),
+        _ => panic!("Expected SSH config"),
+    };
+
+    // web-01 has no SSH config; it inherits the Production identity
+    let web = conn("web-01");
+    assert_eq!(web.username.as_deref(), Some("deploy"));
+    assert_eq!(web.port, 22);
+    assert_eq!(ssh("web-01").auth_method, SshAuthMethod::PublicKey);
+    let SshKeySource::File { path: key_path } = ssh("web-01").key_source else {
+        panic!("Expected key file");
+    };
+    assert!(key_path.ends_with(".ssh/deploy-ed25519"));
+
+    let db = conn("db-primary");
+    assert_eq!(db.username.as_deref(), Some("postgres"));
+    assert_eq!(db.port, 2222);
+    assert_eq!(ssh("db-primary").key_source, SshKeySource::Default);
+
+    let staging = conn("staging-app");
+    assert_eq!(staging.username.as_deref(), Some("admin"));
+    assert_eq!(staging.password_source, PasswordSource::Vault);
+    let creds = &result.credentials[&staging.id];
+    assert_eq!(
+        creds.password.as_ref().map(ExposeSecret::expose_secret),
+        Some("staging-pass")
+    );
+    assert_eq!(result.credentials.len(), 1);
+
+    assert!(conn("router").username.is_none());
+}
+
+#[test]
+fn test_termius_skips_keys_encrypted_in_export() {
+    let path = termius_fixture();
+    let result = TermiusImporter::new().import_from_path(&path).unwrap();
+
+    assert!(result.errors.is_empty());
+    let skipped: Vec<&str> = result
+        .skipped
+        .iter()
+        .map(|s| s.identifier.as_str())
+        .collect();
+    assert_eq!(skipped, ["dba-rsa (SSH key)"]);
+    assert_eq!(result.warnings.len(), 1);
+    assert!(result.warnings[0].contains("deploy-ed25519"));
+}
+
+// ============================================================================
+// iTerm2 Color Scheme Import Integration Tests
+// ============================================================================
+
+fn itermcolors_fixture() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("iterm2")
+        .join("Tomorrow Night.itermcolors")
+}
+
+#[test]
+fn test_itermcolors_maps_ansi_palette() {
+    let theme = TerminalTheme::import_itermcolors_file(&itermcolors_fixture()).unwrap();
+
+    assert_eq!(theme.name, "Tomorrow Night");
+    assert!(theme.is_custom);
+    let palette: Vec<String> = theme.palette.iter().map(|c| c.to_hex()).collect();
+    assert_eq!(
+        palette,
+        [
+            "#1D1F21", "#CC6666", "#B5BD68", "#F0C674", "#81A2BE", "#B294BB", "#8ABEB7", "#C5C8C6",
+            "#666666", "#D54E53", "#B9CA4A", "#E7C547", "#7AA6DA", "#C397D8", "#70C0B1", "#EAEAEA",
+        ]
+    );
+    assert_eq!(theme.background.to_hex(), "#1D1F21");
+    assert_eq!(theme.foreground.to_hex(), "#C5C8C6");
+    assert_eq!(theme.cursor.to_hex(), "#AEAFAD");
+}
@@ -5,6 +5,7 @@
 
 use proptest::prelude::*;
 use rustconn_core::cluster::{Cluster, ClusterManager, ClusterSession, ClusterSessionStatus};
+use rustconn_core::variables::VariableManager;
 use uuid::Uuid;
 
 // ============================================================================
@@ -373,7 +374,12 @@ proptest! {
         }
 
         // Get broadcast targets
-        let targets = session.broadcast_input("test input");
+        let targets: Vec<Uuid> = session
+            .broadcast_input("test input", &VariableManager::new())
+            .unwrap()
+            .into_iter()
+            .map(|t| t.connection_id)
+            .collect();
 
         // Should have exactly the number of connected sessions
         prop_assert_eq!(
@@ -417,7 +423,9 @@ proptest! {
         }
 
         // Get broadcast targets - should be empty
-        let targets = session.broadcast_input("test input");
+        let targets = session
+            .broadcast_input("test input", &VariableManager::new())
+            .unwrap();
         prop_assert!(
             targets.is_empty(),
             "Broadcast should return no targets when disabled"
@@ -513,7 +521,12 @@ fn test_broadcast_excludes_inactive_sessions() {
     session.update_session_status(conn3, ClusterSessionStatus::Connected);
     session.set_session_error(conn4, "Error".to_string());
 
-    let targets = session.broadcast_input("test");
+    let targets: Vec<Uuid> = session
+        .broadcast_input("test", &VariableManager::new())
+        .unwrap()
+        .into_iter()
+        .map(|t| t.connection_id)
+        .collect();
 
     // Should only include connected sessions
     assert_eq!(targets.len(), 2);
@@ -16,8 +16,9 @@ use rustconn_core::config::{
 };
 use rustconn_core::sync::SyncSettings;
 use rustconn_core::{
-    Connection, ConnectionGroup, HistorySettings, ProtocolConfig, RdpConfig, RdpGateway,
-    Resolution, Snippet, SnippetVariable, SshAuthMethod, SshConfig, SshKeySource, VncConfig,
+    Connection, ConnectionGroup, HistorySettings, PasswordSource, ProtocolConfig, RdpConfig,
+    RdpGateway, Resolution, Snippet, SnippetVariable, SshAuthMethod, SshConfig, SshKeySource,
+    VncConfig,
 };
 use tempfile::TempDir;
 
@@ -105,15 +106,19 @@ fn arb_ssh_config() -> impl Strategy<Value = SshConfig> {
                     proxy_jump,
                     proxy_command: None,
                     pkcs11_provider: None,
+                    jump_hosts: Vec::new(),
                     use_control_master,
                     agent_forwarding: false,
+                    agent_forwarding_acknowledged: false,
                     x11_forwarding: false,
+                    x11_trusted: false,
                     compression: false,
                     custom_options,
                     startup_command,
                     jump_host_id: None,
                     sftp_enabled: false,
                     port_forwards: Vec::new(),
+                    port_forward_profiles: Vec::new(),
                     waypipe: false,
                     ssh_agent_socket: None,
                     keep_alive_interval: None,
@@ -152,6 +157,8 @@ fn arb_optional_gateway() -> impl Strategy<Value = Option<RdpGateway>> {
                     hostname,
                     port,
                     username,
+                    domain: None,
+                    password_source: PasswordSource::None,
                 })
             }
         ),
@@ -248,6 +255,7 @@ fn arb_vnc_config() -> impl Strategy<Value = VncConfig> {
             jump_host_id: None,
             accept_certificate: false,
             mptcp: false,
+            repeater: None,
         })
 }
 
@@ -379,6 +387,7 @@ fn arb_secret_backend_type() -> impl Strategy<Value = SecretBackendType> {
         Just(SecretBackendType::Passbolt),
         Just(SecretBackendType::Pass),
         Just(SecretBackendType::MacOsKeychain),
+        Just(SecretBackendType::Vault),
     ]
 }
 
@@ -417,6 +426,7 @@ fn arb_secret_settings() -> impl Strategy<Value = SecretSettings> {
                 bitwarden_client_secret: None,
                 bitwarden_client_secret_encrypted: None,
                 bitwarden_save_to_keyring: false,
+                bitwarden_server_url: None,
                 kdbx_save_to_keyring: false,
                 onepassword_service_account_token: None,
                 onepassword_service_account_token_encrypted: None,
@@ -426,6 +436,11 @@ fn arb_secret_settings() -> impl Strategy<Value = SecretSettings> {
                 passbolt_save_to_keyring: false,
                 passbolt_server_url: None,
                 pass_store_dir: None,
+                vault_address: None,
+                vault_mount: None,
+                vault_path: None,
+                vault_token: None,
+                vault_token_encrypted: None,
             },
         )
 }
@@ -500,6 +515,7 @@ fn arb_full_settings() -> impl Strategy<Value = AppSettings> {
                         bitwarden_client_secret: None,
                         bitwarden_client_secret_encrypted: None,
                         bitwarden_save_to_keyring: false,
+                        bitwarden_server_url: None,
                         kdbx_save_to_keyring: false,
                         onepassword_service_account_token: None,
                         onepassword_service_account_token_encrypted: None,
@@ -509,6 +525,11 @@ fn arb_full_settings() -> impl Strategy<Value = AppSettings> {
                         passbolt_save_to_keyring: false,
                         passbolt_server_url: None,
                         pass_store_dir: None,
+                        vault_address: None,
+                        vault_mount: None,
+                        vault_path: None,
+                        vault_token: None,
+                        vault_token_encrypted: None,
                     },
                     ui: UiSettings {
                         color_scheme: ColorScheme::default(),
@@ -545,6 +566,7 @@ fn arb_full_settings() -> impl Strategy<Value = AppSettings> {
                     sync: SyncSettings::default(),
                     standalone_tunnels: Vec::new(),
                     quick_connect_history: Vec::new(),
+                    saved_searches: Vec::new(),
                 }
             },
         )
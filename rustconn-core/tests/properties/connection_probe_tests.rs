@@ -46,6 +46,8 @@ fn arb_rdp_connection() -> impl Strategy<Value = Connection> {
                         hostname: "gw.example.com".to_string(),
                         port: 443,
                         username: None,
+                        domain: None,
+                        password_source: rustconn_core::models::PasswordSource::None,
                     });
                 }
             }
@@ -94,6 +96,8 @@ proptest! {
                 hostname: "gw".to_string(),
                 port: 443,
                 username: None,
+                domain: None,
+                password_source: rustconn_core::models::PasswordSource::None,
             });
         }
         conn.skip_port_check = skip;
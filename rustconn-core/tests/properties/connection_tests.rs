@@ -8,8 +8,8 @@ use std::path::PathBuf;
 
 use proptest::prelude::*;
 use rustconn_core::{
-    ConfigManager, Connection, ConnectionManager, ProtocolConfig, RdpConfig, RdpGateway,
-    Resolution, SshAuthMethod, SshConfig, SshKeySource, TelnetConfig, VncConfig,
+    ConfigManager, Connection, ConnectionManager, PasswordSource, ProtocolConfig, RdpConfig,
+    RdpGateway, Resolution, SshAuthMethod, SshConfig, SshKeySource, TelnetConfig, VncConfig,
 };
 use tempfile::TempDir;
 use uuid::Uuid;
@@ -98,15 +98,19 @@ fn arb_ssh_config() -> impl Strategy<Value = SshConfig> {
                     proxy_jump,
                     proxy_command: None,
                     pkcs11_provider: None,
+                    jump_hosts: Vec::new(),
                     use_control_master,
                     agent_forwarding: false,
+                    agent_forwarding_acknowledged: false,
                     x11_forwarding: false,
+                    x11_trusted: false,
                     compression: false,
                     custom_options,
                     startup_command,
                     jump_host_id: None,
                     sftp_enabled: false,
                     port_forwards: Vec::new(),
+                    port_forward_profiles: Vec::new(),
                     waypipe: false,
                     ssh_agent_socket: None,
                     keep_alive_interval: None,
@@ -145,6 +149,8 @@ fn arb_optional_gateway() -> impl Strategy<Value = Option<RdpGateway>> {
                     hostname,
                     port,
                     username,
+                    domain: None,
+                    password_source: PasswordSource::None,
                 })
             }
         ),
@@ -241,6 +247,7 @@ fn arb_vnc_config() -> impl Strategy<Value = VncConfig> {
             jump_host_id: None,
             accept_certificate: false,
             mptcp: false,
+            repeater: None,
         })
 }
 
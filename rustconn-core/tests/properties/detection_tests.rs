@@ -224,6 +224,7 @@ proptest! {
             "tigervnc",
             "gvncviewer",
             "xvnc4viewer",
+            "ssvncviewer",
             "vinagre",
             "remmina",
             "krdc",
@@ -570,6 +571,7 @@ mod unit_tests {
             "tigervnc",
             "gvncviewer",
             "xvnc4viewer",
+            "ssvncviewer",
             "vinagre",
             "remmina",
             "krdc",
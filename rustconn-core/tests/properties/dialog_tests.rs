@@ -314,15 +314,19 @@ fn arb_ssh_config() -> impl Strategy<Value = SshConfig> {
         proxy_jump: None,
         proxy_command: None,
         pkcs11_provider: None,
+        jump_hosts: Vec::new(),
         use_control_master: false,
         agent_forwarding: false,
+        agent_forwarding_acknowledged: false,
         x11_forwarding: false,
+        x11_trusted: false,
         compression: false,
         custom_options: HashMap::new(),
         startup_command: None,
         jump_host_id: None,
         sftp_enabled: false,
         port_forwards: Vec::new(),
+        port_forward_profiles: Vec::new(),
         waypipe: false,
         ssh_agent_socket: None,
         keep_alive_interval: None,
@@ -10,7 +10,7 @@ proptest! {
     #[test]
     fn export_format_all_returns_all(_dummy in 0..1) {
         let all = ExportFormat::all();
-        prop_assert_eq!(all.len(), 9);
+        prop_assert_eq!(all.len(), 11);
         prop_assert!(all.contains(&ExportFormat::Ansible));
         prop_assert!(all.contains(&ExportFormat::SshConfig));
         prop_assert!(all.contains(&ExportFormat::Remmina));
@@ -20,6 +20,8 @@ proptest! {
         prop_assert!(all.contains(&ExportFormat::MobaXterm));
         prop_assert!(all.contains(&ExportFormat::Csv));
         prop_assert!(all.contains(&ExportFormat::SecureCrt));
+        prop_assert!(all.contains(&ExportFormat::WindowsTerminal));
+        prop_assert!(all.contains(&ExportFormat::Guacamole));
     }
 
     /// Property: Each ExportFormat has a non-empty display name
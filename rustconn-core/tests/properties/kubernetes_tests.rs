@@ -72,6 +72,7 @@ fn arb_k8s_exec_config() -> impl Strategy<Value = KubernetesConfig> {
                 namespace,
                 pod: Some(pod),
                 container,
+                command: None,
                 shell,
                 use_busybox: false,
                 busybox_image: "busybox:latest".to_string(),
@@ -96,6 +97,7 @@ fn arb_k8s_busybox_config() -> impl Strategy<Value = KubernetesConfig> {
                 namespace,
                 pod: None,
                 container: None,
+                command: None,
                 shell,
                 use_busybox: true,
                 busybox_image,
@@ -8,9 +8,9 @@ use std::path::PathBuf;
 
 use proptest::prelude::*;
 use rustconn_core::models::{
-    Connection, PortForward, PortForwardDirection, ProtocolConfig, RdpConfig, RdpGateway,
-    Resolution, SharedFolder, SpiceConfig, SpiceImageCompression, SshAuthMethod, SshConfig,
-    SshKeySource, VncConfig,
+    Connection, JumpHost, PasswordSource, PortForward, PortForwardDirection, ProtocolConfig,
+    RdpConfig, RdpGateway, Resolution, SharedFolder, SpiceConfig, SpiceImageCompression,
+    SshAuthMethod, SshConfig, SshKeySource, VncConfig,
 };
 use rustconn_core::protocol::{Protocol, RdpProtocol, SshProtocol, VncProtocol};
 
@@ -60,15 +60,19 @@ fn arb_ssh_config() -> impl Strategy<Value = SshConfig> {
                     proxy_jump,
                     proxy_command: None,
                     pkcs11_provider: None,
+                    jump_hosts: Vec::new(),
                     use_control_master,
                     agent_forwarding: false,
+                    agent_forwarding_acknowledged: false,
                     x11_forwarding: false,
+                    x11_trusted: false,
                     compression: false,
                     custom_options,
                     startup_command,
                     jump_host_id: None,
                     sftp_enabled: false,
                     port_forwards: Vec::new(),
+                    port_forward_profiles: Vec::new(),
                     waypipe: false,
                     ssh_agent_socket: None,
                     keep_alive_interval: None,
@@ -119,6 +123,8 @@ fn arb_rdp_gateway() -> impl Strategy<Value = RdpGateway> {
             hostname,
             port,
             username,
+            domain: None,
+            password_source: PasswordSource::None,
         })
 }
 
@@ -130,6 +136,7 @@ fn arb_shared_folder() -> impl Strategy<Value = SharedFolder> {
         .prop_map(|(path, name)| SharedFolder {
             local_path: std::path::PathBuf::from(path),
             share_name: name,
+            read_only: false,
         })
 }
 
@@ -237,6 +244,7 @@ fn arb_vnc_config() -> impl Strategy<Value = VncConfig> {
                 jump_host_id: None,
                 accept_certificate: false,
                 mptcp,
+                repeater: None,
             },
         )
 }
@@ -611,6 +619,7 @@ fn arb_spice_config() -> impl Strategy<Value = SpiceConfig> {
                 ca_cert_path,
                 skip_cert_verify,
                 usb_redirection,
+                usb_redirect_filters: Vec::new(),
                 shared_folders,
                 clipboard_enabled,
                 image_compression,
@@ -679,6 +688,7 @@ proptest! {
         config.shared_folders.push(SharedFolder {
             local_path: PathBuf::new(),
             share_name: "test".to_string(),
+            read_only: false,
         });
         let result = validate_spice_config(&config);
         prop_assert!(result.is_err(), "Empty shared folder path should be rejected");
@@ -695,6 +705,7 @@ proptest! {
         config.shared_folders.push(SharedFolder {
             local_path: PathBuf::from("/tmp/test"),
             share_name: String::new(),
+            read_only: false,
         });
         let result = validate_spice_config(&config);
         prop_assert!(result.is_err(), "Empty shared folder name should be rejected");
@@ -854,15 +865,19 @@ fn arb_ssh_config_with_identities_only() -> impl Strategy<Value = SshConfig> {
                     proxy_jump,
                     proxy_command: None,
                     pkcs11_provider: None,
+                    jump_hosts: Vec::new(),
                     use_control_master,
                     agent_forwarding: false,
+                    agent_forwarding_acknowledged: false,
                     x11_forwarding: false,
+                    x11_trusted: false,
                     compression: false,
                     custom_options,
                     startup_command: None,
                     jump_host_id: None,
                     sftp_enabled: false,
                     port_forwards: Vec::new(),
+                    port_forward_profiles: Vec::new(),
                     waypipe: false,
                     ssh_agent_socket: None,
                     keep_alive_interval: None,
@@ -899,15 +914,19 @@ fn arb_ssh_config_with_agent_fingerprint() -> impl Strategy<Value = SshConfig> {
                 proxy_jump: None,
                 proxy_command: None,
                 pkcs11_provider: None,
+                jump_hosts: Vec::new(),
                 use_control_master: false,
                 agent_forwarding: false,
+                agent_forwarding_acknowledged: false,
                 x11_forwarding: false,
+                x11_trusted: false,
                 compression: false,
                 custom_options: HashMap::new(),
                 startup_command: None,
                 jump_host_id: None,
                 sftp_enabled: false,
                 port_forwards: Vec::new(),
+                port_forward_profiles: Vec::new(),
                 waypipe: false,
                 ssh_agent_socket: None,
                 keep_alive_interval: None,
@@ -1064,15 +1083,19 @@ fn arb_ssh_config_with_file_key_source() -> impl Strategy<Value = SshConfig> {
                     proxy_jump,
                     proxy_command: None,
                     pkcs11_provider: None,
+                    jump_hosts: Vec::new(),
                     use_control_master,
                     agent_forwarding: false,
+                    agent_forwarding_acknowledged: false,
                     x11_forwarding: false,
+                    x11_trusted: false,
                     compression: false,
                     custom_options,
                     startup_command: None,
                     jump_host_id: None,
                     sftp_enabled: false,
                     port_forwards: Vec::new(),
+                    port_forward_profiles: Vec::new(),
                     waypipe: false,
                     ssh_agent_socket: None,
                     keep_alive_interval: None,
@@ -1108,15 +1131,19 @@ fn arb_ssh_config_with_agent_key_source() -> impl Strategy<Value = SshConfig> {
                     proxy_jump,
                     proxy_command: None,
                     pkcs11_provider: None,
+                    jump_hosts: Vec::new(),
                     use_control_master,
                     agent_forwarding: false,
+                    agent_forwarding_acknowledged: false,
                     x11_forwarding: false,
+                    x11_trusted: false,
                     compression: false,
                     custom_options,
                     startup_command: None,
                     jump_host_id: None,
                     sftp_enabled: false,
                     port_forwards: Vec::new(),
+                    port_forward_profiles: Vec::new(),
                     waypipe: false,
                     ssh_agent_socket: None,
                     keep_alive_interval: None,
@@ -1250,6 +1277,83 @@ fn pkcs11_provider_value_is_trimmed() {
     );
 }
 
+// ============================================================================
+// Unit Tests for explicit jump-host chains
+// ============================================================================
+
+/// A two-hop chain is emitted as a single client-first `-J` value with each
+/// hop's own user and port.
+#[test]
+fn jump_hosts_two_hop_chain_emits_proxy_jump() {
+    let config = SshConfig {
+        jump_hosts: vec![
+            JumpHost::new("bastion.example.com").with_user("admin"),
+            JumpHost::new("10.0.0.5").with_port(2222).with_user("ops"),
+        ],
+        ..Default::default()
+    };
+    let args = config.build_command_args();
+
+    let pos = args.iter().position(|a| a == "-J").expect("missing -J");
+    assert_eq!(args[pos + 1], "admin@bastion.example.com,ops@10.0.0.5:2222");
+    assert_eq!(args.iter().filter(|a| *a == "-J").count(), 1);
+}
+
+/// The structured chain wins over the free-form `proxy_jump` string, and
+/// `ProxyCommand` still overrides both.
+#[test]
+fn jump_hosts_precedence() {
+    let mut config = SshConfig {
+        proxy_jump: Some("legacy.example.com".to_string()),
+        jump_hosts: vec![JumpHost::new("hop1"), JumpHost::new("hop2").with_port(22)],
+        ..Default::default()
+    };
+    assert_eq!(config.proxy_jump_spec().as_deref(), Some("hop1,hop2:22"));
+
+    config.proxy_command = Some("nc %h %p".to_string());
+    assert!(!config.build_command_args().iter().any(|a| a == "-J"));
+}
+
+/// IPv6 hops with a port are bracketed; blank hops are ignored.
+#[test]
+fn jump_host_spec_formatting() {
+    assert_eq!(JumpHost::new("fd00::1").to_spec(), "fd00::1");
+    assert_eq!(
+        JumpHost::new("fd00::1").with_port(2200).to_spec(),
+        "[fd00::1]:2200"
+    );
+    assert_eq!(JumpHost::new("h").with_user(" ").to_spec(), "h");
+
+    let config = SshConfig {
+        jump_hosts: vec![JumpHost::new("  "), JumpHost::new("only")],
+        ..Default::default()
+    };
+    assert_eq!(config.proxy_jump_spec().as_deref(), Some("only"));
+    assert_eq!(
+        config.first_jump_host().map(|h| h.host.as_str()),
+        Some("only")
+    );
+}
+
+/// The chain round-trips through a serialized `Connection` and is omitted
+/// from the JSON when empty.
+#[test]
+fn jump_hosts_serde_round_trip() {
+    let mut conn = Connection::new_ssh("Target".to_string(), "target.lan".to_string(), 22);
+    let empty = serde_json::to_string(&conn).unwrap();
+    assert!(!empty.contains("jump_hosts"));
+
+    if let ProtocolConfig::Ssh(ref mut ssh) = conn.protocol_config {
+        ssh.jump_hosts = vec![
+            JumpHost::new("bastion").with_user("admin"),
+            JumpHost::new("inner").with_port(2222),
+        ];
+    }
+    let json = serde_json::to_string(&conn).unwrap();
+    let restored: Connection = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.protocol_config, conn.protocol_config);
+}
+
 use rustconn_core::protocol::icons::{CloudProvider, detect_provider};
 
 /// Generator for AWS-style commands
@@ -1674,14 +1778,17 @@ fn arb_port_forward() -> impl Strategy<Value = PortForward> {
         "[a-z]{1,15}",
         1u16..=65535u16,
     )
-        .prop_map(
-            |(direction, local_port, remote_host, remote_port)| PortForward {
+        .prop_map(|(direction, local_port, remote_host, remote_port)| {
+            // Dynamic forwards have no destination
+            let dynamic = direction == PortForwardDirection::Dynamic;
+            PortForward {
                 direction,
+                bind_address: None,
                 local_port,
-                remote_host,
-                remote_port,
-            },
-        )
+                remote_host: if dynamic { String::new() } else { remote_host },
+                remote_port: if dynamic { 0 } else { remote_port },
+            }
+        })
 }
 
 /// Generator for SSH config with port forwards
@@ -1699,15 +1806,19 @@ fn arb_ssh_config_with_port_forwards() -> impl Strategy<Value = SshConfig> {
             proxy_jump: None,
             proxy_command: None,
             pkcs11_provider: None,
+            jump_hosts: Vec::new(),
             use_control_master: false,
             agent_forwarding: false,
+            agent_forwarding_acknowledged: false,
             x11_forwarding: false,
+            x11_trusted: false,
             compression: false,
             custom_options: HashMap::new(),
             startup_command: None,
             jump_host_id: None,
             sftp_enabled: false,
             port_forwards,
+            port_forward_profiles: Vec::new(),
             waypipe: false,
             ssh_agent_socket: None,
             keep_alive_interval: None,
@@ -1763,15 +1874,19 @@ proptest! {
             proxy_jump: None,
             proxy_command: None,
             pkcs11_provider: None,
+            jump_hosts: Vec::new(),
             use_control_master: false,
             agent_forwarding: false,
+            agent_forwarding_acknowledged: false,
             x11_forwarding: x11,
+            x11_trusted: false,
             compression,
             custom_options: HashMap::new(),
             startup_command: None,
             jump_host_id: None,
             sftp_enabled: false,
             port_forwards: Vec::new(),
+            port_forward_profiles: Vec::new(),
             waypipe: false,
             ssh_agent_socket: None,
             keep_alive_interval: None,
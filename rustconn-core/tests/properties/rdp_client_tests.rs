@@ -135,6 +135,8 @@ fn arb_rdp_client_config() -> impl Strategy<Value = RdpClientConfig> {
                     connection_name: None,
                     keyboard_layout: None,
                     mptcp: false,
+                    proxy: None,
+                    proxy_password: None,
                 }
             },
         )
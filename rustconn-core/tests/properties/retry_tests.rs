@@ -227,6 +227,68 @@ fn test_preset_configs() {
     assert!(conservative.initial_delay_ms > default.initial_delay_ms);
 }
 
+proptest! {
+    /// Property: Jittered delays stay within the configured bounds
+    #[test]
+    fn jittered_delay_within_bounds(
+        config in retry_config_strategy(),
+        jitter in 0.0f64..1.0,
+        seed in any::<u64>(),
+    ) {
+        let config = config.with_jitter(jitter);
+        let max_delay = Duration::from_millis(
+            config.max_delay_ms.max(config.initial_delay_ms.max(100)),
+        );
+        let mut state = RetryState::with_seed(config.clone(), seed);
+        let mut attempt = 0;
+        while let Some(delay) = state.next_delay() {
+            let base = config.delay_for_attempt(attempt).unwrap();
+            prop_assert!(delay >= base.mul_f64(1.0 - jitter));
+            prop_assert!(delay <= base.mul_f64(1.0 + jitter).min(max_delay));
+            state.record_failure("Connection refused");
+            attempt += 1;
+        }
+        prop_assert_eq!(attempt, config.max_attempts * u32::from(config.enabled));
+    }
+
+    /// Property: The same seed yields the same delays
+    #[test]
+    fn seeded_jitter_is_deterministic(
+        config in retry_config_strategy(),
+        jitter in 0.0f64..1.0,
+        seed in any::<u64>(),
+    ) {
+        let config = config.with_jitter(jitter);
+        let mut a = RetryState::with_seed(config.clone(), seed);
+        let mut b = RetryState::with_seed(config, seed);
+        while a.should_retry() {
+            prop_assert_eq!(a.next_delay(), b.next_delay());
+            a.record_failure("Timeout");
+            b.record_failure("Timeout");
+        }
+        prop_assert!(!b.should_retry());
+    }
+
+    /// Property: Total waiting time never exceeds `max_elapsed_ms`
+    #[test]
+    fn max_elapsed_is_never_exceeded(
+        config in retry_config_strategy(),
+        jitter in 0.0f64..1.0,
+        max_elapsed_ms in 0u64..300_000,
+        seed in any::<u64>(),
+    ) {
+        let config = config.with_jitter(jitter).with_max_elapsed_ms(max_elapsed_ms);
+        let mut state = RetryState::with_seed(config, seed);
+        let mut waited = Duration::ZERO;
+        while let Some(delay) = state.next_delay() {
+            waited += delay;
+            state.record_failure("Timeout");
+        }
+        prop_assert!(waited <= Duration::from_millis(max_elapsed_ms));
+        prop_assert_eq!(state.elapsed(), waited);
+    }
+}
+
 #[test]
 fn test_exponential_backoff_sequence() {
     let config = RetryConfig::new()
@@ -91,6 +91,7 @@ fn arb_serial_config() -> impl Strategy<Value = SerialConfig> {
                     parity,
                     flow_control,
                     custom_args,
+                    ..SerialConfig::default()
                 }
             },
         )
@@ -9,8 +9,8 @@ use std::path::PathBuf;
 use proptest::prelude::*;
 use rustconn_core::models::SharedFolder;
 use rustconn_core::{
-    Connection, ProtocolConfig, RdpConfig, RdpGateway, Resolution, SpiceConfig,
-    SpiceImageCompression, SshAuthMethod, SshConfig, SshKeySource, VncConfig,
+    Connection, PasswordSource, ProtocolConfig, RdpConfig, RdpGateway, Resolution, SpiceConfig,
+    SpiceImageCompression, SshAuthMethod, SshConfig, SshKeySource, VncConfig, VncRepeater,
 };
 
 // Strategy for generating valid connection names
@@ -95,15 +95,19 @@ fn arb_ssh_config() -> impl Strategy<Value = SshConfig> {
                     proxy_jump,
                     proxy_command: None,
                     pkcs11_provider: None,
+                    jump_hosts: Vec::new(),
                     use_control_master,
                     agent_forwarding: false,
+                    agent_forwarding_acknowledged: false,
                     x11_forwarding: false,
+                    x11_trusted: false,
                     compression: false,
                     custom_options,
                     startup_command,
                     jump_host_id: None,
                     sftp_enabled: false,
                     port_forwards: Vec::new(),
+                    port_forward_profiles: Vec::new(),
                     waypipe: false,
                     ssh_agent_socket: None,
                     keep_alive_interval: None,
@@ -136,15 +140,26 @@ fn arb_optional_color_depth() -> impl Strategy<Value = Option<u8>> {
 fn arb_optional_gateway() -> impl Strategy<Value = Option<RdpGateway>> {
     prop_oneof![
         Just(None),
-        (arb_host(), 1u16..65535u16, arb_optional_string()).prop_map(
-            |(hostname, port, username)| {
+        (
+            arb_host(),
+            1u16..65535u16,
+            arb_optional_string(),
+            arb_optional_string(),
+            any::<bool>(),
+        )
+            .prop_map(|(hostname, port, username, domain, vault)| {
                 Some(RdpGateway {
                     hostname,
                     port,
                     username,
+                    domain,
+                    password_source: if vault {
+                        PasswordSource::Vault
+                    } else {
+                        PasswordSource::None
+                    },
                 })
-            }
-        ),
+            }),
     ]
 }
 
@@ -222,23 +237,28 @@ fn arb_vnc_config() -> impl Strategy<Value = VncConfig> {
         arb_optional_level(),
         arb_optional_level(),
         arb_custom_args(),
+        proptest::option::of(1u32..=999_999),
     )
-        .prop_map(|(encoding, compression, quality, custom_args)| VncConfig {
-            client_mode: Default::default(),
-            performance_mode: Default::default(),
-            encoding,
-            compression,
-            quality,
-            view_only: false,
-            scaling: true,
-            clipboard_enabled: true,
-            custom_args,
-            scale_override: Default::default(),
-            show_local_cursor: true,
-            jump_host_id: None,
-            accept_certificate: false,
-            mptcp: false,
-        })
+        .prop_map(
+            |(encoding, compression, quality, custom_args, repeater_id)| VncConfig {
+                client_mode: Default::default(),
+                performance_mode: Default::default(),
+                encoding,
+                compression,
+                quality,
+                view_only: false,
+                scaling: true,
+                clipboard_enabled: true,
+                custom_args,
+                scale_override: Default::default(),
+                show_local_cursor: true,
+                jump_host_id: None,
+                accept_certificate: false,
+                mptcp: false,
+                repeater: repeater_id
+                    .map(|id| VncRepeater::new("repeater.example.com", id.to_string())),
+            },
+        )
 }
 
 // Strategy for SPICE image compression
@@ -259,10 +279,12 @@ fn arb_shared_folders() -> impl Strategy<Value = Vec<SharedFolder>> {
         (
             "/[a-z]{1,10}(/[a-z]{1,10}){0,2}",
             "[A-Za-z][A-Za-z0-9_]{0,10}",
+            any::<bool>(),
         )
-            .prop_map(|(path, name)| SharedFolder {
+            .prop_map(|(path, name, read_only)| SharedFolder {
                 local_path: PathBuf::from(path),
                 share_name: name,
+                read_only,
             }),
         0..3,
     )
@@ -293,6 +315,7 @@ fn arb_spice_config() -> impl Strategy<Value = SpiceConfig> {
                 ca_cert_path,
                 skip_cert_verify,
                 usb_redirection,
+                usb_redirect_filters: Vec::new(),
                 shared_folders,
                 clipboard_enabled,
                 image_compression,
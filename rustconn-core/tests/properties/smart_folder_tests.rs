@@ -78,6 +78,7 @@ fn make_connection(
         remember_window_position: false,
         window_geometry: None,
         skip_port_check: false,
+        resolve_via_tailscale: false,
         is_pinned: false,
         pin_order: 0,
         icon: None,
@@ -90,6 +91,13 @@ fn make_connection(
         retry_config: None,
         knock_sequence: None,
         spa_config: None,
+        depends_on: Vec::new(),
+        proxy: None,
+        access_tunnel: None,
+        connect_guard: None,
+        env: Vec::new(),
+        osc52_clipboard: None,
+        scrollback_lines: None,
     }
 }
 
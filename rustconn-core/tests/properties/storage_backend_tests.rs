@@ -91,6 +91,7 @@ fn create_test_connection(id: Uuid, name: &str, host: &str) -> Connection {
         remember_window_position: false,
         window_geometry: None,
         skip_port_check: false,
+        resolve_via_tailscale: false,
         is_pinned: false,
         pin_order: 0,
         icon: None,
@@ -103,6 +104,13 @@ fn create_test_connection(id: Uuid, name: &str, host: &str) -> Connection {
         retry_config: None,
         knock_sequence: None,
         spa_config: None,
+        depends_on: Vec::new(),
+        proxy: None,
+        access_tunnel: None,
+        connect_guard: None,
+        env: Vec::new(),
+        osc52_clipboard: None,
+        scrollback_lines: None,
     }
 }
 
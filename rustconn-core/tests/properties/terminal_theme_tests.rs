@@ -1,6 +1,8 @@
 //! Property tests for terminal themes
 
 use proptest::prelude::*;
+use rustconn_core::Connection;
+use rustconn_core::config::{TerminalSettings, UNLIMITED_SCROLLBACK, VTE_OSC52_PROPERTY};
 use rustconn_core::terminal_themes::{Color, TerminalTheme};
 
 // ============================================================================
@@ -156,6 +158,48 @@ fn by_name_finds_existing_themes() {
     }
 }
 
+#[test]
+fn resolve_falls_back_to_dark_theme() {
+    assert_eq!(
+        TerminalTheme::resolve("NonExistentTheme"),
+        TerminalTheme::dark_theme()
+    );
+    for name in TerminalTheme::theme_names() {
+        assert_eq!(TerminalTheme::resolve(&name).name, name);
+    }
+}
+
+#[test]
+fn settings_theme_palette_is_stable() {
+    for name in TerminalTheme::theme_names() {
+        let settings = TerminalSettings {
+            color_theme: name.clone(),
+            ..TerminalSettings::default()
+        };
+        let first = settings.theme();
+        let second = settings.theme();
+        assert_eq!(
+            first, second,
+            "Theme '{name}' must resolve the same way twice"
+        );
+
+        // Hex round-trip keeps every palette entry on the same 8-bit color,
+        // so a previewed theme matches the one applied after saving
+        for color in &first.palette {
+            assert_eq!(Color::from_hex(&color.to_hex()).to_hex(), color.to_hex());
+        }
+    }
+
+    let fallback = TerminalSettings {
+        color_theme: "Removed Theme".to_string(),
+        ..TerminalSettings::default()
+    };
+    assert_eq!(
+        fallback.theme().palette,
+        TerminalTheme::dark_theme().palette
+    );
+}
+
 #[test]
 fn by_name_returns_none_for_unknown() {
     assert!(TerminalTheme::by_name("NonExistentTheme").is_none());
@@ -273,3 +317,58 @@ fn theme_clone_is_independent() {
     assert_eq!(theme1.name, theme2.name);
     assert_eq!(theme1.palette.len(), theme2.palette.len());
 }
+
+#[test]
+fn osc52_clipboard_maps_to_vte_property() {
+    let global = TerminalSettings::default();
+    assert_eq!(global.vte_osc52_property(), (VTE_OSC52_PROPERTY, false));
+
+    let mut conn = Connection::new_ssh("shared".to_string(), "shared.lan".to_string(), 22);
+    assert_eq!(
+        global.for_connection(&conn).vte_osc52_property(),
+        (VTE_OSC52_PROPERTY, false)
+    );
+    conn.osc52_clipboard = Some(true);
+    assert_eq!(
+        global.for_connection(&conn).vte_osc52_property(),
+        (VTE_OSC52_PROPERTY, true)
+    );
+
+    let allowed = TerminalSettings {
+        osc52_clipboard: true,
+        ..TerminalSettings::default()
+    };
+    assert_eq!(allowed.vte_osc52_property(), (VTE_OSC52_PROPERTY, true));
+    conn.osc52_clipboard = Some(false);
+    assert_eq!(
+        allowed.for_connection(&conn).vte_osc52_property(),
+        (VTE_OSC52_PROPERTY, false)
+    );
+}
+
+#[test]
+fn connection_scrollback_overrides_global() {
+    let global = TerminalSettings {
+        scrollback_lines: 5000,
+        ..TerminalSettings::default()
+    };
+    let mut conn = Connection::new_ssh("logs".to_string(), "logs.lan".to_string(), 22);
+    assert_eq!(global.for_connection(&conn).vte_scrollback_lines(), 5000);
+
+    conn.scrollback_lines = Some(100_000);
+    assert_eq!(global.for_connection(&conn).vte_scrollback_lines(), 100_000);
+
+    conn.scrollback_lines = Some(UNLIMITED_SCROLLBACK);
+    let merged = global.for_connection(&conn);
+    assert!(merged.scrollback_unlimited);
+    assert_eq!(merged.vte_scrollback_lines(), UNLIMITED_SCROLLBACK);
+
+    // A finite connection value also wins over a global "unlimited"
+    let unlimited = TerminalSettings {
+        scrollback_unlimited: true,
+        ..global
+    };
+    assert_eq!(unlimited.vte_scrollback_lines(), UNLIMITED_SCROLLBACK);
+    conn.scrollback_lines = Some(1000);
+    assert_eq!(unlimited.for_connection(&conn).vte_scrollback_lines(), 1000);
+}
@@ -1001,7 +1001,8 @@ fn refresh_sidebar_secret_status(
         | rustconn_core::config::SecretBackendType::OnePassword
         | rustconn_core::config::SecretBackendType::Passbolt
         | rustconn_core::config::SecretBackendType::Pass
-        | rustconn_core::config::SecretBackendType::EncryptedFile => (true, true),
+        | rustconn_core::config::SecretBackendType::EncryptedFile
+        | rustconn_core::config::SecretBackendType::Vault => (true, true),
         rustconn_core::config::SecretBackendType::KeePassXc
         | rustconn_core::config::SecretBackendType::KdbxFile => {
             let kdbx_enabled = settings.secrets.kdbx_enabled;
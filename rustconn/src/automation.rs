@@ -3,19 +3,32 @@
 //! This module provides "Expect"-like functionality for terminal sessions,
 //! allowing automatic responses to specific text patterns in the output.
 //! Pattern matching logic is delegated to `ExpectEngine` from `rustconn-core`.
+//! Secret rules (e.g. `sudo` passwords) are resolved only when their prompt
+//! appears, and neither the secret nor the bytes sent are logged. Rules that
+//! allow it (one-time passwords) ask the user when the variable has no value.
 
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::time::{Duration, Instant};
 
+use adw::prelude::*;
 use gtk4::glib;
 use gtk4::glib::ControlFlow;
-use rustconn_core::automation::{ExpectEngine, ExpectRule};
+use libadwaita as adw;
+use rustconn_core::automation::{
+    ExpectEngine, ExpectRule, SecretPromptEvent, SecretResponse, screen_delta,
+};
+use secrecy::SecretString;
 use uuid::Uuid;
 use vte4::prelude::*;
 use vte4::{Format, Terminal};
 
+use crate::i18n::i18n;
+
+/// Looks up a secret variable by name when a secret rule's prompt appears
+pub type SecretLookup = Box<dyn Fn(&str) -> Option<SecretString>>;
+
 /// Shared state for automation engine
 struct AutomationState {
     /// The expect engine that handles pattern matching and priority sorting
@@ -26,6 +39,8 @@ struct AutomationState {
     last_content: String,
     /// Counter for polling cycles
     poll_count: u32,
+    /// Resolves secret variables for secret rules
+    secret_lookup: SecretLookup,
 }
 
 /// Manages automation for a terminal session
@@ -55,9 +70,21 @@ impl AutomationSession {
     /// Creates a new automation session from pre-resolved expect rules
     ///
     /// Rules should already have variable substitution applied to their responses.
-    pub fn new(terminal: Terminal, rules: Vec<ExpectRule>) -> Self {
+    /// `secret_lookup` is called only when a secret rule's prompt appears.
+    pub fn new(terminal: Terminal, rules: Vec<ExpectRule>, secret_lookup: SecretLookup) -> Self {
         tracing::info!("AutomationSession: Created with {} rules", rules.len());
         for rule in &rules {
+            if let Some(secret) = &rule.secret {
+                tracing::info!(
+                    "AutomationSession: Rule id={}, pattern='{}', secret variable='{}', priority={}, one_shot={}",
+                    rule.id,
+                    rule.pattern,
+                    secret.variable,
+                    rule.priority,
+                    rule.one_shot,
+                );
+                continue;
+            }
             tracing::info!(
                 "AutomationSession: Rule id={}, pattern='{}', response='{}', priority={}, one_shot={}",
                 rule.id,
@@ -87,6 +114,7 @@ impl AutomationSession {
             created_at,
             last_content: String::new(),
             poll_count: 0,
+            secret_lookup,
         }));
 
         // Start polling timer to check terminal content
@@ -218,6 +246,45 @@ impl AutomationSession {
             return;
         }
 
+        // Secret rules only look at output that was not there before, so an
+        // answered prompt still on screen is not answered again
+        let mut secrets = Vec::new();
+        let mut prompt_user = false;
+        if state_ref.engine.has_secret_rules() {
+            let delta = screen_delta(&state_ref.last_content, &content);
+            for event in state_ref.engine.process_secret_output(&delta) {
+                match event {
+                    SecretPromptEvent::Send {
+                        rule_id,
+                        variable,
+                        attempt,
+                    } => match (state_ref.secret_lookup)(&variable) {
+                        Some(secret) => {
+                            tracing::info!(
+                                "AutomationSession: Sending secret '{variable}' for rule id={rule_id} (attempt {attempt})"
+                            );
+                            secrets.push(SecretResponse::payload(&secret));
+                        }
+                        None if Self::rule_prompts(&state_ref.engine, rule_id) => {
+                            tracing::info!(
+                                "AutomationSession: Asking user for rule id={rule_id} (attempt {attempt})"
+                            );
+                            prompt_user = true;
+                        }
+                        None => tracing::warn!(
+                            "AutomationSession: Secret variable '{variable}' for rule id={rule_id} has no value"
+                        ),
+                    },
+                    SecretPromptEvent::Exhausted { rule_id, attempts } => {
+                        state_ref.created_at.remove(&rule_id);
+                        tracing::warn!(
+                            "AutomationSession: Secret for rule id={rule_id} rejected {attempts} times, giving up"
+                        );
+                    }
+                }
+            }
+        }
+
         state_ref.last_content = content.clone();
 
         // Collect matches: (rule_id, response, one_shot)
@@ -254,21 +321,72 @@ impl AutomationSession {
             }
         }
 
-        // Remove one-shot rules that matched
+        // Retire one-shot rules that matched and activate chained rules
+        let now = Instant::now();
         for &(id, _, one_shot) in &matches {
             if one_shot {
-                state_ref.engine.remove_by_id(id);
                 state_ref.created_at.remove(&id);
             }
+            if let Some(next_id) = state_ref.engine.rule_fired(id, now) {
+                state_ref.created_at.insert(next_id, now);
+            }
         }
 
         // Drop borrow before sending
         drop(state_ref);
 
-        // Send responses
+        // Send responses; secret payloads are zeroized when dropped
+        for payload in secrets {
+            terminal.feed_child(&payload);
+        }
         for (_, response, _) in matches {
             terminal.feed_child(response.as_bytes());
         }
+        if prompt_user {
+            Self::prompt_for_secret(terminal);
+        }
+    }
+
+    /// Returns `true` if the secret rule asks the user when its variable is empty
+    fn rule_prompts(engine: &ExpectEngine, rule_id: Uuid) -> bool {
+        engine
+            .rules()
+            .iter()
+            .find(|r| r.id == rule_id)
+            .and_then(|r| r.secret.as_ref())
+            .is_some_and(|s| s.prompt)
+    }
+
+    /// Asks the user for a one-time code and sends it to the terminal
+    fn prompt_for_secret(terminal: &Terminal) {
+        let alert = adw::AlertDialog::new(
+            Some(&i18n("Verification Code")),
+            Some(&i18n("The server is asking for a one-time password.")),
+        );
+        alert.add_response("cancel", &i18n("Cancel"));
+        alert.add_response("send", &i18n("Send"));
+        alert.set_response_appearance("send", adw::ResponseAppearance::Suggested);
+        alert.set_default_response(Some("send"));
+        alert.set_close_response("cancel");
+
+        let entry = gtk4::PasswordEntry::builder()
+            .activates_default(true)
+            .show_peek_icon(true)
+            .build();
+        alert.set_extra_child(Some(&entry));
+
+        let terminal_weak = terminal.downgrade();
+        alert.connect_response(None, move |_, response| {
+            if response != "send" {
+                return;
+            }
+            let code = SecretString::from(entry.text().to_string());
+            entry.set_text("");
+            if let Some(terminal) = terminal_weak.upgrade() {
+                terminal.feed_child(&SecretResponse::payload(&code));
+            }
+        });
+        alert.present(Some(terminal));
     }
 }
 
@@ -296,6 +414,12 @@ pub fn prepare_rules_from_config(
             continue;
         }
 
+        // Secret rules carry no response text; the secret is looked up on match
+        if rule.secret.is_some() {
+            prepared.push(rule.clone());
+            continue;
+        }
+
         // Substitute ${VAR} references in the response text
         let resolved_response = var_manager
             .substitute_for_command(
@@ -319,6 +443,14 @@ pub fn prepare_rules_from_config(
             timeout_ms: rule.timeout_ms,
             enabled: true,
             one_shot: rule.one_shot,
+            secret: None,
+            next: rule
+                .next
+                .as_deref()
+                .and_then(|next| {
+                    prepare_rules_from_config(std::slice::from_ref(next), var_manager).pop()
+                })
+                .map(Box::new),
         });
     }
 
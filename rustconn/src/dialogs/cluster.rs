@@ -7,6 +7,7 @@
 //! screens, centered floating sheet on wide screens.
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 use adw::prelude::*;
@@ -28,9 +29,13 @@ pub type ClusterCallback = Rc<RefCell<Option<Box<dyn Fn(Option<Cluster>)>>>>;
 pub struct ClusterDialog {
     dialog: adw::Dialog,
     name_entry: gtk4::Entry,
+    connect_delay_row: adw::SpinRow,
+    max_in_flight_row: adw::SpinRow,
     connections_list: ListBox,
     connection_rows: Rc<RefCell<Vec<ConnectionSelectionRow>>>,
     editing_id: Rc<RefCell<Option<Uuid>>>,
+    /// Broadcast command templates of the cluster being edited
+    command_templates: Rc<RefCell<HashMap<Uuid, String>>>,
     on_save: ClusterCallback,
     select_all_btn: Button,
     deselect_all_btn: Button,
@@ -111,6 +116,32 @@ impl ClusterDialog {
 
         content.append(&details_group);
 
+        // === Staggered Start ===
+        let stagger_group = adw::PreferencesGroup::builder()
+            .title(i18n("Staggered Start"))
+            .description(i18n(
+                "Open members gradually to avoid flooding a shared bastion with logins",
+            ))
+            .build();
+
+        let connect_delay_row = adw::SpinRow::builder()
+            .title(i18n("Delay Between Connections (ms)"))
+            .subtitle(i18n("0 = no delay"))
+            .adjustment(&gtk4::Adjustment::new(
+                0.0, 0.0, 60_000.0, 100.0, 1000.0, 0.0,
+            ))
+            .build();
+        stagger_group.add(&connect_delay_row);
+
+        let max_in_flight_row = adw::SpinRow::builder()
+            .title(i18n("Max Simultaneous Connections"))
+            .subtitle(i18n("0 = unlimited"))
+            .adjustment(&gtk4::Adjustment::new(0.0, 0.0, 100.0, 1.0, 5.0, 0.0))
+            .build();
+        stagger_group.add(&max_in_flight_row);
+
+        content.append(&stagger_group);
+
         // Connections selection section
         let (connections_group, connections_list, select_all_btn, deselect_all_btn) =
             Self::create_connections_section();
@@ -120,6 +151,8 @@ impl ClusterDialog {
         let connection_rows: Rc<RefCell<Vec<ConnectionSelectionRow>>> =
             Rc::new(RefCell::new(Vec::new()));
         let editing_id: Rc<RefCell<Option<Uuid>>> = Rc::new(RefCell::new(None));
+        let command_templates: Rc<RefCell<HashMap<Uuid, String>>> =
+            Rc::new(RefCell::new(HashMap::new()));
 
         // Connect save button
         let dialog_clone = dialog.clone();
@@ -127,6 +160,9 @@ impl ClusterDialog {
         let name_entry_clone = name_entry.clone();
         let connection_rows_clone = connection_rows.clone();
         let editing_id_clone = editing_id.clone();
+        let connect_delay_clone = connect_delay_row.clone();
+        let max_in_flight_clone = max_in_flight_row.clone();
+        let command_templates_clone = command_templates.clone();
         save_btn.connect_clicked(move |_| {
             let name = name_entry_clone.text().trim().to_string();
             if name.is_empty() {
@@ -156,12 +192,19 @@ impl ClusterDialog {
                 Cluster::with_id(id, name)
             } else {
                 Cluster::new(name)
-            };
+            }
+            .with_staggered_start(
+                connect_delay_clone.value() as u64,
+                max_in_flight_clone.value() as usize,
+            );
 
             // broadcast_enabled is left at its default (false). Cluster broadcast
             // was removed in 0.14.8 — broadcast is now a split-view feature.
             for conn_id in selected_ids {
                 cluster.add_connection(conn_id);
+                if let Some(template) = command_templates_clone.borrow().get(&conn_id) {
+                    cluster.set_command_template(conn_id, template.clone());
+                }
             }
 
             if let Some(ref cb) = *on_save_clone.borrow() {
@@ -175,9 +218,12 @@ impl ClusterDialog {
         Self {
             dialog,
             name_entry,
+            connect_delay_row,
+            max_in_flight_row,
             connections_list,
             connection_rows,
             editing_id,
+            command_templates,
             on_save,
             select_all_btn,
             deselect_all_btn,
@@ -296,8 +342,15 @@ impl ClusterDialog {
     /// Sets the cluster to edit (for editing existing clusters)
     pub fn set_cluster(&self, cluster: &Cluster) {
         *self.editing_id.borrow_mut() = Some(cluster.id);
+        self.command_templates
+            .borrow_mut()
+            .clone_from(&cluster.command_templates);
         self.dialog.set_title(&i18n("Edit Cluster"));
         self.name_entry.set_text(&cluster.name);
+        self.connect_delay_row
+            .set_value(cluster.connect_delay_ms as f64);
+        self.max_in_flight_row
+            .set_value(cluster.max_in_flight as f64);
 
         // Select the connections that are in the cluster
         for row in self.connection_rows.borrow().iter() {
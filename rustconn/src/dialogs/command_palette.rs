@@ -452,7 +452,9 @@ impl CommandPaletteDialog {
 
         let search_query = SearchQuery::with_text(query);
         let conn_refs: Vec<&Connection> = connections.iter().collect();
-        let results = engine.search(&search_query, &conn_refs, groups);
+        let results = engine
+            .search(&search_query, &conn_refs, groups)
+            .unwrap_or_default();
         results
             .iter()
             .filter_map(|r| {
@@ -10,7 +10,10 @@ use gtk4::{
     ListBox, Orientation, ScrolledWindow, SpinButton, StringList,
 };
 use libadwaita as adw;
-use rustconn_core::wol::{DEFAULT_BROADCAST_ADDRESS, DEFAULT_WOL_PORT, DEFAULT_WOL_WAIT_SECONDS};
+use rustconn_core::wol::{
+    DEFAULT_BROADCAST_ADDRESS, DEFAULT_WOL_ONLINE_TIMEOUT_SECONDS,
+    DEFAULT_WOL_POLL_INTERVAL_SECONDS, DEFAULT_WOL_PORT, DEFAULT_WOL_WAIT_SECONDS,
+};
 
 use crate::i18n::i18n;
 
@@ -30,6 +33,9 @@ pub(super) fn create_advanced_tab() -> (
     Entry,
     SpinButton,
     SpinButton,
+    adw::SwitchRow,
+    SpinButton,
+    SpinButton,
     ColorDialogButton,
     ColorDialogButton,
     ColorDialogButton,
@@ -47,6 +53,9 @@ pub(super) fn create_advanced_tab() -> (
     adw::SpinRow,
     adw::SpinRow,
     adw::SpinRow,
+    adw::SpinRow,
+    adw::SpinRow,
+    adw::SwitchRow,
     adw::SwitchRow,
     gtk4::Entry,
     // SPA fields
@@ -422,16 +431,38 @@ pub(super) fn create_advanced_tab() -> (
         .build();
     retry_expander.add_row(&retry_max_delay_spin);
 
+    let retry_jitter_adj = gtk4::Adjustment::new(20.0, 0.0, 100.0, 5.0, 10.0, 0.0);
+    let retry_jitter_spin = adw::SpinRow::builder()
+        .title(i18n("Jitter (%)"))
+        .subtitle(i18n(
+            "Randomly vary each delay so dropped sessions do not reconnect at once",
+        ))
+        .adjustment(&retry_jitter_adj)
+        .build();
+    retry_expander.add_row(&retry_jitter_spin);
+
+    let retry_max_elapsed_adj = gtk4::Adjustment::new(0.0, 0.0, 3600.0, 10.0, 60.0, 0.0);
+    let retry_max_elapsed_spin = adw::SpinRow::builder()
+        .title(i18n("Give up after (s)"))
+        .subtitle(i18n("Total waiting time before giving up, 0 for no limit"))
+        .adjustment(&retry_max_elapsed_adj)
+        .build();
+    retry_expander.add_row(&retry_max_elapsed_spin);
+
     // Wire sensitivity: show/hide spin rows based on enabled toggle
     {
         let max_attempts = retry_max_attempts_spin.clone();
         let initial_delay = retry_initial_delay_spin.clone();
         let max_delay = retry_max_delay_spin.clone();
+        let jitter = retry_jitter_spin.clone();
+        let max_elapsed = retry_max_elapsed_spin.clone();
         retry_enabled_toggle.connect_active_notify(move |toggle| {
             let active = toggle.is_active();
             max_attempts.set_sensitive(active);
             initial_delay.set_sensitive(active);
             max_delay.set_sensitive(active);
+            jitter.set_sensitive(active);
+            max_elapsed.set_sensitive(active);
         });
     }
 
@@ -464,6 +495,15 @@ pub(super) fn create_advanced_tab() -> (
         .build();
     connection_group.add(&skip_port_check_toggle);
 
+    let tailscale_dns_toggle = adw::SwitchRow::builder()
+        .title(i18n("Resolve host through Tailscale"))
+        .subtitle(i18n(
+            "Probe the peer's Tailscale IP instead of the DNS answer. Useful when split DNS resolves MagicDNS names elsewhere.",
+        ))
+        .active(false)
+        .build();
+    connection_group.add(&tailscale_dns_toggle);
+
     // Port knock sequence entry with inline validation
     let knock_sequence_entry = gtk4::Entry::builder()
         .hexpand(true)
@@ -727,6 +767,62 @@ pub(super) fn create_advanced_tab() -> (
     wait_row.add_suffix(&wait_spin);
     wol_expander.add_row(&wait_row);
 
+    let wait_online_switch = adw::SwitchRow::builder()
+        .title(i18n("Wait Until Online"))
+        .subtitle(i18n("Poll the connection port and connect once it answers"))
+        .build();
+    wol_expander.add_row(&wait_online_switch);
+
+    let online_timeout_adjustment = gtk4::Adjustment::new(
+        f64::from(DEFAULT_WOL_ONLINE_TIMEOUT_SECONDS),
+        1.0,
+        3600.0,
+        1.0,
+        10.0,
+        0.0,
+    );
+    let online_timeout_spin = SpinButton::builder()
+        .adjustment(&online_timeout_adjustment)
+        .digits(0)
+        .valign(gtk4::Align::Center)
+        .build();
+
+    let online_timeout_row = adw::ActionRow::builder()
+        .title(i18n("Online Timeout (sec)"))
+        .subtitle(i18n("Give up if the host does not answer in time"))
+        .build();
+    online_timeout_row.add_suffix(&online_timeout_spin);
+    wol_expander.add_row(&online_timeout_row);
+
+    let poll_interval_adjustment = gtk4::Adjustment::new(
+        f64::from(DEFAULT_WOL_POLL_INTERVAL_SECONDS),
+        1.0,
+        60.0,
+        1.0,
+        5.0,
+        0.0,
+    );
+    let poll_interval_spin = SpinButton::builder()
+        .adjustment(&poll_interval_adjustment)
+        .digits(0)
+        .valign(gtk4::Align::Center)
+        .build();
+
+    let poll_interval_row = adw::ActionRow::builder()
+        .title(i18n("Poll Interval (sec)"))
+        .build();
+    poll_interval_row.add_suffix(&poll_interval_spin);
+    wol_expander.add_row(&poll_interval_row);
+
+    wait_online_switch
+        .bind_property("active", &online_timeout_row, "sensitive")
+        .sync_create()
+        .build();
+    wait_online_switch
+        .bind_property("active", &poll_interval_row, "sensitive")
+        .sync_create()
+        .build();
+
     wol_group.add(&wol_expander);
     content.append(&wol_group);
 
@@ -743,6 +839,9 @@ pub(super) fn create_advanced_tab() -> (
         broadcast_entry,
         port_spin,
         wait_spin,
+        wait_online_switch,
+        online_timeout_spin,
+        poll_interval_spin,
         theme_bg_button,
         theme_fg_button,
         theme_cursor_button,
@@ -760,7 +859,10 @@ pub(super) fn create_advanced_tab() -> (
         retry_max_attempts_spin,
         retry_initial_delay_spin,
         retry_max_delay_spin,
+        retry_jitter_spin,
+        retry_max_elapsed_spin,
         skip_port_check_toggle,
+        tailscale_dns_toggle,
         knock_sequence_entry,
         spa_enabled_toggle,
         spa_rij_key_entry,
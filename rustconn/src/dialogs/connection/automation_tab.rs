@@ -1,7 +1,8 @@
 //! Automation tab for the connection dialog
 //!
 //! Contains the Expect Rules section (auto-respond to terminal patterns),
-//! a pattern tester, and pre-connect / post-disconnect task configuration.
+//! a pattern tester, one-time-password prompt handling, and pre-connect /
+//! post-disconnect task configuration.
 
 use adw::prelude::*;
 use gtk4::prelude::*;
@@ -25,6 +26,10 @@ pub(super) struct AutomationTabWidgets {
     pub(super) expect_pattern_test_entry: Entry,
     /// Label showing pattern test results.
     pub(super) expect_test_result_label: Label,
+    /// Answer one-time-password prompts switch.
+    pub(super) otp_prompt_switch: adw::SwitchRow,
+    /// Secret variable holding the one-time password.
+    pub(super) otp_variable_entry: adw::EntryRow,
     /// Pre-connect task enabled switch.
     pub(super) pre_connect_enabled_switch: adw::SwitchRow,
     /// Pre-connect command entry.
@@ -178,6 +183,28 @@ pub(super) fn create_automation_combined_tab() -> AutomationTabWidgets {
     tester_group.add(&tester_expander);
     content.append(&tester_group);
 
+    // === Two-Factor Prompts Section ===
+    let otp_group = adw::PreferencesGroup::builder()
+        .title(i18n("Two-Factor Prompts"))
+        .build();
+    let otp_prompt_switch = adw::SwitchRow::builder()
+        .title(i18n("Answer Verification Code Prompts"))
+        .subtitle(i18n(
+            "Respond to one-time password prompts while connecting",
+        ))
+        .build();
+    otp_group.add(&otp_prompt_switch);
+    let otp_variable_entry = adw::EntryRow::builder()
+        .title(i18n("Secret Variable (empty = ask each time)"))
+        .sensitive(false)
+        .build();
+    otp_prompt_switch
+        .bind_property("active", &otp_variable_entry, "sensitive")
+        .sync_create()
+        .build();
+    otp_group.add(&otp_variable_entry);
+    content.append(&otp_group);
+
     // === Pre-Connect Task Section ===
     let (
         pre_connect_group,
@@ -213,6 +240,8 @@ pub(super) fn create_automation_combined_tab() -> AutomationTabWidgets {
         template_list_box,
         expect_pattern_test_entry: test_entry,
         expect_test_result_label: result_label,
+        otp_prompt_switch,
+        otp_variable_entry,
         pre_connect_enabled_switch,
         pre_connect_command_entry,
         pre_connect_timeout_spin,
@@ -57,6 +57,8 @@ pub(super) struct ConnectionDialogData<'a> {
     pub ssh_key_source_dropdown: &'a DropDown,
     pub ssh_key_entry: &'a Entry,
     pub ssh_agent_key_dropdown: &'a DropDown,
+    pub ssh_keepass_entry_row: &'a adw::EntryRow,
+    pub ssh_keepass_attachment_row: &'a adw::EntryRow,
     pub ssh_agent_keys: &'a Rc<RefCell<Vec<rustconn_core::ssh_agent::AgentKey>>>,
     pub ssh_proxy_entry: &'a Entry,
     pub ssh_proxy_command_entry: &'a Entry,
@@ -65,6 +67,7 @@ pub(super) struct ConnectionDialogData<'a> {
     pub ssh_agent_forwarding: &'a CheckButton,
     pub ssh_waypipe: &'a CheckButton,
     pub ssh_x11_forwarding: &'a CheckButton,
+    pub ssh_x11_trusted: &'a CheckButton,
     pub ssh_compression: &'a CheckButton,
     pub ssh_verbose: &'a CheckButton,
     pub ssh_mptcp: &'a CheckButton,
@@ -127,6 +130,7 @@ pub(super) struct ConnectionDialogData<'a> {
     pub spice_ca_cert_entry: &'a Entry,
     pub spice_skip_verify_check: &'a adw::SwitchRow,
     pub spice_usb_check: &'a adw::SwitchRow,
+    pub spice_usb_filters_entry: &'a Entry,
     pub spice_clipboard_check: &'a adw::SwitchRow,
     pub spice_show_local_cursor_check: &'a adw::SwitchRow,
     pub spice_compression_dropdown: &'a DropDown,
@@ -171,18 +175,23 @@ pub(super) struct ConnectionDialogData<'a> {
     pub telnet_delete_dropdown: &'a DropDown,
     // Serial fields
     pub serial_device_entry: &'a Entry,
+    pub serial_device_match_entry: &'a Entry,
     pub serial_baud_dropdown: &'a DropDown,
     pub serial_data_bits_dropdown: &'a DropDown,
     pub serial_stop_bits_dropdown: &'a DropDown,
     pub serial_parity_dropdown: &'a DropDown,
     pub serial_flow_control_dropdown: &'a DropDown,
     pub serial_custom_args_entry: &'a Entry,
+    pub serial_capture_switch: &'a adw::SwitchRow,
+    pub serial_capture_format_dropdown: &'a DropDown,
+    pub serial_view_mode_dropdown: &'a DropDown,
     // Kubernetes fields
     pub k8s_kubeconfig_entry: &'a Entry,
     pub k8s_context_entry: &'a Entry,
     pub k8s_namespace_entry: &'a Entry,
     pub k8s_pod_entry: &'a Entry,
     pub k8s_container_entry: &'a Entry,
+    pub k8s_command_entry: &'a Entry,
     pub k8s_shell_dropdown: &'a DropDown,
     pub k8s_busybox_check: &'a CheckButton,
     pub k8s_busybox_image_entry: &'a Entry,
@@ -200,6 +209,8 @@ pub(super) struct ConnectionDialogData<'a> {
     pub local_variables: &'a HashMap<String, Variable>,
     pub logging_tab: &'a logging_tab::LoggingTab,
     pub expect_rules: &'a Vec<ExpectRule>,
+    pub otp_prompt_switch: &'a adw::SwitchRow,
+    pub otp_variable_entry: &'a adw::EntryRow,
     // Task fields
     pub pre_connect_enabled_switch: &'a adw::SwitchRow,
     pub pre_connect_command_entry: &'a Entry,
@@ -218,6 +229,9 @@ pub(super) struct ConnectionDialogData<'a> {
     pub wol_broadcast_entry: &'a Entry,
     pub wol_port_spin: &'a SpinButton,
     pub wol_wait_spin: &'a SpinButton,
+    pub wol_wait_online_switch: &'a adw::SwitchRow,
+    pub wol_online_timeout_spin: &'a SpinButton,
+    pub wol_poll_interval_spin: &'a SpinButton,
     // Terminal theme fields
     pub theme_bg_button: &'a ColorDialogButton,
     pub theme_fg_button: &'a ColorDialogButton,
@@ -243,8 +257,12 @@ pub(super) struct ConnectionDialogData<'a> {
     pub retry_max_attempts_spin: &'a adw::SpinRow,
     pub retry_initial_delay_spin: &'a adw::SpinRow,
     pub retry_max_delay_spin: &'a adw::SpinRow,
+    pub retry_jitter_spin: &'a adw::SpinRow,
+    pub retry_max_elapsed_spin: &'a adw::SpinRow,
     // Skip pre-connect TCP port check for this connection
     pub skip_port_check_toggle: &'a adw::SwitchRow,
+    // Resolve the host through Tailscale MagicDNS for port checks
+    pub tailscale_dns_toggle: &'a adw::SwitchRow,
     pub knock_sequence_entry: &'a gtk4::Entry,
     // SPA (fwknop) fields
     pub spa_enabled_toggle: &'a adw::SwitchRow,
@@ -291,10 +309,11 @@ impl ConnectionDialogData<'_> {
             }
         }
 
-        // Serial requires a device path
+        // Serial requires a device path or an adapter to look up
         if is_serial {
             let device = self.serial_device_entry.text();
-            if device.trim().is_empty() {
+            let device_match = self.serial_device_match_entry.text();
+            if device.trim().is_empty() && device_match.trim().is_empty() {
                 return Err(i18n("Device path is required for serial connections"));
             }
         }
@@ -318,6 +337,12 @@ impl ConnectionDialogData<'_> {
                         ));
                     }
                 }
+                if key_source_idx == 4
+                    && (self.ssh_keepass_entry_row.text().trim().is_empty()
+                        || self.ssh_keepass_attachment_row.text().trim().is_empty())
+                {
+                    return Err(i18n("KeePass entry and attachment name are required"));
+                }
             }
             // SSH-1: Warn when auth=Password but password_source=None
             if auth_idx == 0 {
@@ -332,6 +357,13 @@ impl ConnectionDialogData<'_> {
             }
         }
 
+        // SPICE (3): USB device filters must be vendor:product hex IDs
+        if protocol_idx == 3 {
+            let filters = self.spice_usb_filters_entry.text();
+            rustconn_core::spice_client::SpiceUsbFilter::parse_list(split_usb_filters(&filters))
+                .map_err(|e| e.to_string())?;
+        }
+
         // K8S-1: Kubernetes pod validation
         if is_kubernetes && !self.k8s_busybox_check.is_active() {
             let pod = self.k8s_pod_entry.text();
@@ -339,6 +371,16 @@ impl ConnectionDialogData<'_> {
                 return Err(i18n("Pod name is required when Busybox mode is disabled"));
             }
         }
+        if is_kubernetes {
+            if self.k8s_pod_entry.text().trim().is_empty()
+                && !self.k8s_container_entry.text().trim().is_empty()
+            {
+                return Err(i18n("Container requires a pod name"));
+            }
+            if shell_words::split(&self.k8s_command_entry.text()).is_err() {
+                return Err(i18n("Command has unbalanced quotes"));
+            }
+        }
         // RDP (1) and VNC (2) use native embedding, no client validation needed
 
         // WOL validation
@@ -545,6 +587,9 @@ impl ConnectionDialogData<'_> {
             .filter(|r| !r.pattern.is_empty())
             .cloned()
             .collect();
+        conn.automation.answer_otp_prompts = self.otp_prompt_switch.is_active();
+        conn.automation.otp_variable =
+            Some(self.otp_variable_entry.text().trim().to_string()).filter(|v| !v.is_empty());
 
         // Set pre-connect task if enabled
         conn.pre_connect_task = self.build_pre_connect_task();
@@ -607,6 +652,7 @@ impl ConnectionDialogData<'_> {
 
         // Set skip-port-check override
         conn.skip_port_check = self.skip_port_check_toggle.is_active();
+        conn.resolve_via_tailscale = self.tailscale_dns_toggle.is_active();
 
         // Set port knock sequence (parse from entry text)
         let knock_text = self.knock_sequence_entry.text().to_string();
@@ -703,10 +749,16 @@ impl ConnectionDialogData<'_> {
             let max_attempts = self.retry_max_attempts_spin.value() as u32;
             let initial_delay_ms = self.retry_initial_delay_spin.value() as u64;
             let max_delay_ms = self.retry_max_delay_spin.value() as u64;
+            let jitter = self.retry_jitter_spin.value() / 100.0;
+            let max_elapsed_ms = (self.retry_max_elapsed_spin.value() as u64) * 1000;
 
             // Store None if all defaults to keep config clean
-            let is_default =
-                enabled && max_attempts == 3 && initial_delay_ms == 1000 && max_delay_ms == 30_000;
+            let is_default = enabled
+                && max_attempts == 3
+                && initial_delay_ms == 1000
+                && max_delay_ms == 30_000
+                && (jitter - rustconn_core::connection::DEFAULT_RECONNECT_JITTER).abs() < 0.005
+                && max_elapsed_ms == 0;
             conn.retry_config = if is_default {
                 None
             } else {
@@ -716,6 +768,8 @@ impl ConnectionDialogData<'_> {
                     max_delay_ms,
                     backoff_multiplier: rustconn_core::connection::DEFAULT_BACKOFF_MULTIPLIER,
                     enabled,
+                    jitter,
+                    max_elapsed_ms: (max_elapsed_ms > 0).then_some(max_elapsed_ms),
                 })
             };
         }
@@ -779,12 +833,27 @@ impl ConnectionDialogData<'_> {
             reason = "value range fits the target type and is non-negative by construction in this code path"
         )]
         let wait_seconds = self.wol_wait_spin.value() as u32;
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "value range fits the target type and is non-negative by construction in this code path"
+        )]
+        let online_timeout_seconds = self.wol_online_timeout_spin.value() as u32;
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "value range fits the target type and is non-negative by construction in this code path"
+        )]
+        let poll_interval_seconds = self.wol_poll_interval_spin.value() as u32;
 
         Some(WolConfig {
             mac_address,
             broadcast_address,
             port,
             wait_seconds,
+            wait_for_online: self.wol_wait_online_switch.is_active(),
+            online_timeout_seconds,
+            poll_interval_seconds,
         })
     }
 
@@ -1081,6 +1150,9 @@ impl ConnectionDialogData<'_> {
         };
         rustconn_core::models::SerialConfig {
             device,
+            device_match: rustconn_core::models::SerialDeviceMatch::parse(
+                &self.serial_device_match_entry.text(),
+            ),
             baud_rate: rustconn_core::SerialBaudRate::from_index(
                 self.serial_baud_dropdown.selected(),
             ),
@@ -1095,6 +1167,14 @@ impl ConnectionDialogData<'_> {
                 self.serial_flow_control_dropdown.selected(),
             ),
             custom_args,
+            capture_on_connect: self.serial_capture_switch.is_active(),
+            capture_format: rustconn_core::models::SerialCaptureFormat::from_index(
+                self.serial_capture_format_dropdown.selected(),
+            ),
+            view_mode: rustconn_core::models::SerialViewMode::from_index(
+                self.serial_view_mode_dropdown.selected(),
+            ),
+            ..Default::default()
         }
     }
 
@@ -1139,6 +1219,14 @@ impl ConnectionDialogData<'_> {
                 Some(text.trim().to_string())
             }
         };
+        let command = {
+            let text = self.k8s_command_entry.text();
+            if text.trim().is_empty() {
+                None
+            } else {
+                Some(text.trim().to_string())
+            }
+        };
         let shells = ["/bin/sh", "/bin/bash", "/bin/ash", "/bin/zsh"];
         let shell_idx = self.k8s_shell_dropdown.selected() as usize;
         let shell = shells.get(shell_idx).unwrap_or(&"/bin/sh").to_string();
@@ -1157,6 +1245,7 @@ impl ConnectionDialogData<'_> {
             namespace,
             pod,
             container,
+            command,
             shell,
             use_busybox: self.k8s_busybox_check.is_active(),
             busybox_image,
@@ -1302,6 +1391,14 @@ impl ConnectionDialogData<'_> {
                 }
                 2 => self.ssh_agent_key_selection(),
                 3 => (SshKeySource::Inherit, None, None),
+                4 => (
+                    SshKeySource::KeePassAttachment {
+                        entry: self.ssh_keepass_entry_row.text().trim().to_string(),
+                        attachment: self.ssh_keepass_attachment_row.text().trim().to_string(),
+                    },
+                    None,
+                    None,
+                ),
                 // Default (0) or any other value
                 _ => (SshKeySource::Default, None, None),
             },
@@ -1409,10 +1506,16 @@ impl ConnectionDialogData<'_> {
             proxy_command: proxy_command_opt,
             pkcs11_provider,
             jump_host_id, // Add this field
+            jump_hosts: Vec::new(),
             use_control_master: self.ssh_control_master.is_active(),
             agent_forwarding: self.ssh_agent_forwarding.is_active(),
+            // The Session group shows the agent-forwarding risk next to the
+            // toggle, so saving it enabled from the dialog counts as seen.
+            agent_forwarding_acknowledged: self.ssh_agent_forwarding.is_active(),
+            port_forward_profiles: Vec::new(),
             waypipe: self.ssh_waypipe.is_active(),
             x11_forwarding: self.ssh_x11_forwarding.is_active(),
+            x11_trusted: self.ssh_x11_trusted.is_active(),
             compression: self.ssh_compression.is_active(),
             verbose: self.ssh_verbose.is_active(),
             mptcp: self.ssh_mptcp.is_active(),
@@ -1472,6 +1575,8 @@ impl ConnectionDialogData<'_> {
                     hostname: text.trim().to_string(),
                     port,
                     username,
+                    domain: None,
+                    password_source: PasswordSource::None,
                 })
             }
         };
@@ -1608,6 +1713,7 @@ impl ConnectionDialogData<'_> {
             },
             accept_certificate: self.vnc_accept_certificate_check.is_active(),
             mptcp: self.vnc_mptcp_check.is_active(),
+            repeater: None,
         }
     }
 
@@ -1646,6 +1752,9 @@ impl ConnectionDialogData<'_> {
             ca_cert_path,
             skip_cert_verify: self.spice_skip_verify_check.is_active(),
             usb_redirection: self.spice_usb_check.is_active(),
+            usb_redirect_filters: split_usb_filters(&self.spice_usb_filters_entry.text())
+                .map(str::to_string)
+                .collect(),
             shared_folders: self.spice_shared_folders.borrow().clone(),
             clipboard_enabled: self.spice_clipboard_check.is_active(),
             image_compression,
@@ -1692,3 +1801,10 @@ impl ConnectionDialogData<'_> {
 fn default_ssh_pub_key_path() -> PathBuf {
     dirs::home_dir().unwrap_or_default().join(".ssh/id_rsa.pub")
 }
+
+/// Splits the SPICE USB filter entry into `vendor:product` items
+pub(super) fn split_usb_filters(text: &str) -> impl Iterator<Item = &str> {
+    text.split([',', ' '])
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
@@ -104,6 +104,8 @@ impl ConnectionDialog {
         let ssh_key_entry = ssh_widgets.key_entry;
         let ssh_key_button = ssh_widgets.key_button;
         let ssh_agent_key_dropdown = ssh_widgets.agent_key_dropdown;
+        let ssh_keepass_entry_row = ssh_widgets.keepass_entry_row;
+        let ssh_keepass_attachment_row = ssh_widgets.keepass_attachment_row;
         let ssh_jump_host_dropdown = ssh_widgets.jump_host_dropdown;
         let ssh_proxy_entry = ssh_widgets.proxy_entry;
         let ssh_proxy_command_entry = ssh_widgets.proxy_command_entry;
@@ -112,6 +114,7 @@ impl ConnectionDialog {
         let ssh_agent_forwarding = ssh_widgets.agent_forwarding;
         let ssh_waypipe = ssh_widgets.waypipe;
         let ssh_x11_forwarding = ssh_widgets.x11_forwarding;
+        let ssh_x11_trusted = ssh_widgets.x11_trusted;
         let ssh_compression = ssh_widgets.compression;
         let ssh_verbose = ssh_widgets.verbose;
         let ssh_mptcp = ssh_widgets.mptcp;
@@ -201,6 +204,7 @@ impl ConnectionDialog {
             spice_ca_cert_entry,
             spice_skip_verify_check,
             spice_usb_check,
+            spice_usb_filters_entry,
             spice_clipboard_check,
             spice_compression_dropdown,
             spice_proxy_entry,
@@ -261,12 +265,16 @@ impl ConnectionDialog {
         let (
             serial_box,
             serial_device_entry,
+            serial_device_match_entry,
             serial_baud_dropdown,
             serial_data_bits_dropdown,
             serial_stop_bits_dropdown,
             serial_parity_dropdown,
             serial_flow_control_dropdown,
             serial_custom_args_entry,
+            serial_capture_switch,
+            serial_capture_format_dropdown,
+            serial_view_mode_dropdown,
         ) = crate::dialogs::connection::serial::create_serial_options();
         protocol_stack.add_named(&serial_box, Some("serial"));
 
@@ -278,6 +286,7 @@ impl ConnectionDialog {
             k8s_namespace_entry,
             k8s_pod_entry,
             k8s_container_entry,
+            k8s_command_entry,
             k8s_shell_dropdown,
             k8s_busybox_check,
             k8s_busybox_image_entry,
@@ -364,6 +373,9 @@ impl ConnectionDialog {
             wol_broadcast_entry,
             wol_port_spin,
             wol_wait_spin,
+            wol_wait_online_switch,
+            wol_online_timeout_spin,
+            wol_poll_interval_spin,
             theme_bg_button,
             theme_fg_button,
             theme_cursor_button,
@@ -381,7 +393,10 @@ impl ConnectionDialog {
             retry_max_attempts_spin,
             retry_initial_delay_spin,
             retry_max_delay_spin,
+            retry_jitter_spin,
+            retry_max_elapsed_spin,
             skip_port_check_toggle,
+            tailscale_dns_toggle,
             knock_sequence_entry,
             spa_enabled_toggle,
             spa_rij_key_entry,
@@ -472,6 +487,8 @@ impl ConnectionDialog {
             &ssh_key_source_dropdown,
             &ssh_key_entry,
             &ssh_agent_key_dropdown,
+            &ssh_keepass_entry_row,
+            &ssh_keepass_attachment_row,
             &ssh_agent_keys,
             &ssh_jump_host_dropdown,
             &ssh_proxy_entry,
@@ -481,6 +498,7 @@ impl ConnectionDialog {
             &ssh_agent_forwarding,
             &ssh_waypipe,
             &ssh_x11_forwarding,
+            &ssh_x11_trusted,
             &ssh_compression,
             &ssh_verbose,
             &ssh_mptcp,
@@ -543,6 +561,7 @@ impl ConnectionDialog {
             &spice_ca_cert_entry,
             &spice_skip_verify_check,
             &spice_usb_check,
+            &spice_usb_filters_entry,
             &spice_clipboard_check,
             &spice_show_local_cursor_check,
             &spice_compression_dropdown,
@@ -584,17 +603,22 @@ impl ConnectionDialog {
             &telnet_backspace_dropdown,
             &telnet_delete_dropdown,
             &serial_device_entry,
+            &serial_device_match_entry,
             &serial_baud_dropdown,
             &serial_data_bits_dropdown,
             &serial_stop_bits_dropdown,
             &serial_parity_dropdown,
             &serial_flow_control_dropdown,
             &serial_custom_args_entry,
+            &serial_capture_switch,
+            &serial_capture_format_dropdown,
+            &serial_view_mode_dropdown,
             &k8s_kubeconfig_entry,
             &k8s_context_entry,
             &k8s_namespace_entry,
             &k8s_pod_entry,
             &k8s_container_entry,
+            &k8s_command_entry,
             &k8s_shell_dropdown,
             &k8s_busybox_check,
             &k8s_busybox_image_entry,
@@ -610,6 +634,8 @@ impl ConnectionDialog {
             &variables_rows,
             &logging_tab_struct,
             &expect_rules,
+            &automation_widgets.otp_prompt_switch,
+            &automation_widgets.otp_variable_entry,
             &automation_widgets.pre_connect_enabled_switch,
             &automation_widgets.pre_connect_command_entry,
             &automation_widgets.pre_connect_timeout_spin,
@@ -625,6 +651,9 @@ impl ConnectionDialog {
             &wol_broadcast_entry,
             &wol_port_spin,
             &wol_wait_spin,
+            &wol_wait_online_switch,
+            &wol_online_timeout_spin,
+            &wol_poll_interval_spin,
             &theme_bg_button,
             &theme_fg_button,
             &theme_cursor_button,
@@ -640,7 +669,10 @@ impl ConnectionDialog {
             &retry_max_attempts_spin,
             &retry_initial_delay_spin,
             &retry_max_delay_spin,
+            &retry_jitter_spin,
+            &retry_max_elapsed_spin,
             &skip_port_check_toggle,
+            &tailscale_dns_toggle,
             &knock_sequence_entry,
             &spa_enabled_toggle,
             &spa_rij_key_entry,
@@ -686,6 +718,8 @@ impl ConnectionDialog {
             ssh_key_entry,
             ssh_key_button,
             ssh_agent_key_dropdown,
+            ssh_keepass_entry_row,
+            ssh_keepass_attachment_row,
             ssh_agent_keys,
             pending_agent_selection,
             ssh_jump_host_dropdown,
@@ -696,6 +730,7 @@ impl ConnectionDialog {
             ssh_agent_forwarding,
             ssh_waypipe,
             ssh_x11_forwarding,
+            ssh_x11_trusted,
             ssh_compression,
             ssh_verbose,
             ssh_mptcp,
@@ -765,6 +800,7 @@ impl ConnectionDialog {
             spice_ca_cert_entry,
             spice_skip_verify_check,
             spice_usb_check,
+            spice_usb_filters_entry,
             spice_clipboard_check,
             spice_show_local_cursor_check,
             spice_compression_dropdown,
@@ -808,17 +844,22 @@ impl ConnectionDialog {
             telnet_backspace_dropdown,
             telnet_delete_dropdown,
             serial_device_entry,
+            serial_device_match_entry,
             serial_baud_dropdown,
             serial_data_bits_dropdown,
             serial_stop_bits_dropdown,
             serial_parity_dropdown,
             serial_flow_control_dropdown,
             serial_custom_args_entry,
+            serial_capture_switch,
+            serial_capture_format_dropdown,
+            serial_view_mode_dropdown,
             k8s_kubeconfig_entry,
             k8s_context_entry,
             k8s_namespace_entry,
             k8s_pod_entry,
             k8s_container_entry,
+            k8s_command_entry,
             k8s_shell_dropdown,
             k8s_busybox_check,
             k8s_busybox_image_entry,
@@ -836,6 +877,8 @@ impl ConnectionDialog {
             add_expect_rule_button: automation_widgets.add_expect_rule_button,
             expect_pattern_test_entry: automation_widgets.expect_pattern_test_entry,
             expect_test_result_label: automation_widgets.expect_test_result_label,
+            otp_prompt_switch: automation_widgets.otp_prompt_switch,
+            otp_variable_entry: automation_widgets.otp_variable_entry,
             pre_connect_enabled_switch: automation_widgets.pre_connect_enabled_switch,
             pre_connect_command_entry: automation_widgets.pre_connect_command_entry,
             pre_connect_timeout_spin: automation_widgets.pre_connect_timeout_spin,
@@ -853,6 +896,9 @@ impl ConnectionDialog {
             wol_broadcast_entry,
             wol_port_spin,
             wol_wait_spin,
+            wol_wait_online_switch,
+            wol_online_timeout_spin,
+            wol_poll_interval_spin,
             theme_bg_button,
             theme_fg_button,
             theme_cursor_button,
@@ -870,7 +916,10 @@ impl ConnectionDialog {
             retry_max_attempts_spin,
             retry_initial_delay_spin,
             retry_max_delay_spin,
+            retry_jitter_spin,
+            retry_max_elapsed_spin,
             skip_port_check_toggle,
+            tailscale_dns_toggle,
             knock_sequence_entry,
             spa_enabled_toggle,
             spa_rij_key_entry,
@@ -131,6 +131,8 @@ pub struct ConnectionDialog {
     ssh_key_entry: Entry,
     ssh_key_button: Button,
     ssh_agent_key_dropdown: DropDown,
+    ssh_keepass_entry_row: adw::EntryRow,
+    ssh_keepass_attachment_row: adw::EntryRow,
     ssh_agent_keys: Rc<RefCell<Vec<rustconn_core::ssh_agent::AgentKey>>>,
     /// Pending agent key selection (fingerprint, comment) to restore after refresh
     pending_agent_selection: Rc<RefCell<Option<(String, String)>>>,
@@ -142,6 +144,7 @@ pub struct ConnectionDialog {
     ssh_agent_forwarding: CheckButton,
     ssh_waypipe: CheckButton,
     ssh_x11_forwarding: CheckButton,
+    ssh_x11_trusted: CheckButton,
     ssh_compression: CheckButton,
     ssh_verbose: CheckButton,
     ssh_mptcp: CheckButton,
@@ -209,6 +212,7 @@ pub struct ConnectionDialog {
     spice_ca_cert_entry: Entry,
     spice_skip_verify_check: adw::SwitchRow,
     spice_usb_check: adw::SwitchRow,
+    spice_usb_filters_entry: Entry,
     spice_clipboard_check: adw::SwitchRow,
     spice_show_local_cursor_check: adw::SwitchRow,
     spice_compression_dropdown: DropDown,
@@ -271,18 +275,23 @@ pub struct ConnectionDialog {
     mosh_server_binary_entry: Entry,
     // Serial fields
     serial_device_entry: Entry,
+    serial_device_match_entry: Entry,
     serial_baud_dropdown: DropDown,
     serial_data_bits_dropdown: DropDown,
     serial_stop_bits_dropdown: DropDown,
     serial_parity_dropdown: DropDown,
     serial_flow_control_dropdown: DropDown,
     serial_custom_args_entry: Entry,
+    serial_capture_switch: adw::SwitchRow,
+    serial_capture_format_dropdown: DropDown,
+    serial_view_mode_dropdown: DropDown,
     // Kubernetes fields
     k8s_kubeconfig_entry: Entry,
     k8s_context_entry: Entry,
     k8s_namespace_entry: Entry,
     k8s_pod_entry: Entry,
     k8s_container_entry: Entry,
+    k8s_command_entry: Entry,
     k8s_shell_dropdown: DropDown,
     k8s_busybox_check: CheckButton,
     k8s_busybox_image_entry: Entry,
@@ -310,6 +319,8 @@ pub struct ConnectionDialog {
     expect_pattern_test_entry: Entry,
     /// Label showing pattern test results - wired up in `wire_pattern_tester()`
     expect_test_result_label: Label,
+    otp_prompt_switch: adw::SwitchRow,
+    otp_variable_entry: adw::EntryRow,
     // Connection tasks fields
     pre_connect_enabled_switch: adw::SwitchRow,
     pre_connect_command_entry: Entry,
@@ -331,6 +342,9 @@ pub struct ConnectionDialog {
     wol_broadcast_entry: Entry,
     wol_port_spin: SpinButton,
     wol_wait_spin: SpinButton,
+    wol_wait_online_switch: adw::SwitchRow,
+    wol_online_timeout_spin: SpinButton,
+    wol_poll_interval_spin: SpinButton,
     // Terminal theme fields
     theme_bg_button: ColorDialogButton,
     theme_fg_button: ColorDialogButton,
@@ -355,8 +369,12 @@ pub struct ConnectionDialog {
     retry_max_attempts_spin: adw::SpinRow,
     retry_initial_delay_spin: adw::SpinRow,
     retry_max_delay_spin: adw::SpinRow,
+    retry_jitter_spin: adw::SpinRow,
+    retry_max_elapsed_spin: adw::SpinRow,
     // Skip pre-connect TCP port check for this connection
     skip_port_check_toggle: adw::SwitchRow,
+    // Resolve the host through Tailscale MagicDNS for port checks
+    tailscale_dns_toggle: adw::SwitchRow,
     // Port knock sequence entry
     knock_sequence_entry: gtk4::Entry,
     // SPA (fwknop) fields
@@ -406,6 +406,16 @@ impl ConnectionDialog {
                                                 .map_err(|e| format!("{e}"))
                                         })?
                                     }
+                                    SecretBackendType::Vault => {
+                                        let backend =
+                                            rustconn_core::secret::VaultBackend::from_secret_settings(
+                                                &secret_settings,
+                                            );
+                                        crate::async_utils::with_runtime(|rt| {
+                                            rt.block_on(backend.retrieve(&flat_lookup_key))
+                                                .map_err(|e| format!("{e}"))
+                                        })?
+                                    }
                                 }
                             },
                             move |result: Result<
@@ -26,7 +26,8 @@ use rustconn_core::models::{
 use rustconn_core::session::LogConfig;
 use rustconn_core::variables::Variable;
 use rustconn_core::wol::{
-    DEFAULT_BROADCAST_ADDRESS, DEFAULT_WOL_PORT, DEFAULT_WOL_WAIT_SECONDS, WolConfig,
+    DEFAULT_BROADCAST_ADDRESS, DEFAULT_WOL_ONLINE_TIMEOUT_SECONDS,
+    DEFAULT_WOL_POLL_INTERVAL_SECONDS, DEFAULT_WOL_PORT, DEFAULT_WOL_WAIT_SECONDS, WolConfig,
 };
 use uuid::Uuid;
 
@@ -211,6 +212,13 @@ impl ConnectionDialog {
                 self.protocol_dropdown.set_selected(6); // Serial
                 self.protocol_stack.set_visible_child_name("serial");
                 self.serial_device_entry.set_text(&serial_config.device);
+                self.serial_device_match_entry.set_text(
+                    &serial_config
+                        .device_match
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                );
                 self.serial_baud_dropdown
                     .set_selected(serial_config.baud_rate.index());
                 self.serial_data_bits_dropdown
@@ -223,6 +231,12 @@ impl ConnectionDialog {
                     .set_selected(serial_config.flow_control.index());
                 let args_text = serial_config.custom_args.join(" ");
                 self.serial_custom_args_entry.set_text(&args_text);
+                self.serial_capture_switch
+                    .set_active(serial_config.capture_on_connect);
+                self.serial_capture_format_dropdown
+                    .set_selected(serial_config.capture_format.index());
+                self.serial_view_mode_dropdown
+                    .set_selected(serial_config.view_mode.index());
             }
             ProtocolConfig::Sftp(ssh) => {
                 self.protocol_dropdown.set_selected(7); // SFTP
@@ -255,6 +269,10 @@ impl ConnectionDialog {
 
         // Set expect rules
         self.set_expect_rules(&conn.automation.expect_rules);
+        self.otp_prompt_switch
+            .set_active(conn.automation.answer_otp_prompts);
+        self.otp_variable_entry
+            .set_text(conn.automation.otp_variable.as_deref().unwrap_or_default());
 
         // Set connection tasks
         self.set_pre_connect_task(conn.pre_connect_task.as_ref());
@@ -302,6 +320,8 @@ impl ConnectionDialog {
 
         // Set skip-port-check toggle (per-connection override)
         self.skip_port_check_toggle.set_active(conn.skip_port_check);
+        self.tailscale_dns_toggle
+            .set_active(conn.resolve_via_tailscale);
 
         // Set port knock sequence entry
         if let Some(ref knock_seq) = conn.knock_sequence {
@@ -372,11 +392,22 @@ impl ConnectionDialog {
             )]
             self.retry_max_delay_spin
                 .set_value(config.max_delay_ms as f64);
+            self.retry_jitter_spin
+                .set_value(config.jitter_fraction() * 100.0);
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "f64 conversion is intentional for display/UI arithmetic where sub-integer precision is irrelevant"
+            )]
+            self.retry_max_elapsed_spin
+                .set_value(config.max_elapsed_ms.unwrap_or(0) as f64 / 1000.0);
         } else {
             self.retry_enabled_toggle.set_active(true);
             self.retry_max_attempts_spin.set_value(3.0);
             self.retry_initial_delay_spin.set_value(1000.0);
             self.retry_max_delay_spin.set_value(30_000.0);
+            self.retry_jitter_spin
+                .set_value(rustconn_core::connection::DEFAULT_RECONNECT_JITTER * 100.0);
+            self.retry_max_elapsed_spin.set_value(0.0);
         }
     }
 
@@ -518,6 +549,11 @@ impl ConnectionDialog {
             self.wol_broadcast_entry.set_text(&wol.broadcast_address);
             self.wol_port_spin.set_value(f64::from(wol.port));
             self.wol_wait_spin.set_value(f64::from(wol.wait_seconds));
+            self.wol_wait_online_switch.set_active(wol.wait_for_online);
+            self.wol_online_timeout_spin
+                .set_value(f64::from(wol.online_timeout_seconds));
+            self.wol_poll_interval_spin
+                .set_value(f64::from(wol.poll_interval_seconds));
         } else {
             self.wol_enabled_check.set_active(false);
             self.wol_mac_entry.set_text("");
@@ -525,6 +561,11 @@ impl ConnectionDialog {
             self.wol_port_spin.set_value(f64::from(DEFAULT_WOL_PORT));
             self.wol_wait_spin
                 .set_value(f64::from(DEFAULT_WOL_WAIT_SECONDS));
+            self.wol_wait_online_switch.set_active(false);
+            self.wol_online_timeout_spin
+                .set_value(f64::from(DEFAULT_WOL_ONLINE_TIMEOUT_SECONDS));
+            self.wol_poll_interval_spin
+                .set_value(f64::from(DEFAULT_WOL_POLL_INTERVAL_SECONDS));
         }
     }
 
@@ -903,6 +944,14 @@ impl ConnectionDialog {
                 self.ssh_key_button.set_sensitive(false);
                 self.ssh_agent_key_dropdown.set_sensitive(false);
             }
+            SshKeySource::KeePassAttachment { entry, attachment } => {
+                self.ssh_key_source_dropdown.set_selected(4);
+                self.ssh_keepass_entry_row.set_text(entry);
+                self.ssh_keepass_attachment_row.set_text(attachment);
+                self.ssh_key_entry.set_sensitive(false);
+                self.ssh_key_button.set_sensitive(false);
+                self.ssh_agent_key_dropdown.set_sensitive(false);
+            }
         }
 
         // Also set key_path for backward compatibility
@@ -947,6 +996,7 @@ impl ConnectionDialog {
         self.ssh_agent_forwarding.set_active(ssh.agent_forwarding);
         self.ssh_waypipe.set_active(ssh.waypipe);
         self.ssh_x11_forwarding.set_active(ssh.x11_forwarding);
+        self.ssh_x11_trusted.set_active(ssh.x11_trusted);
         self.ssh_compression.set_active(ssh.compression);
         self.ssh_verbose.set_active(ssh.verbose);
         self.ssh_mptcp.set_active(ssh.mptcp);
@@ -1281,6 +1331,8 @@ impl ConnectionDialog {
         self.spice_skip_verify_check
             .set_active(spice.skip_cert_verify);
         self.spice_usb_check.set_active(spice.usb_redirection);
+        self.spice_usb_filters_entry
+            .set_text(&spice.usb_redirect_filters.join(", "));
         self.spice_clipboard_check
             .set_active(spice.clipboard_enabled);
         self.spice_show_local_cursor_check
@@ -1465,6 +1517,9 @@ impl ConnectionDialog {
         if let Some(ref container) = k8s.container {
             self.k8s_container_entry.set_text(container);
         }
+        if let Some(ref command) = k8s.command {
+            self.k8s_command_entry.set_text(command);
+        }
         let shell_idx = match k8s.shell.as_str() {
             "/bin/sh" => 0,
             "/bin/bash" => 1,
@@ -55,6 +55,8 @@ impl ConnectionDialog {
         ssh_key_source_dropdown: &DropDown,
         ssh_key_entry: &Entry,
         ssh_agent_key_dropdown: &DropDown,
+        ssh_keepass_entry_row: &adw::EntryRow,
+        ssh_keepass_attachment_row: &adw::EntryRow,
         ssh_agent_keys: &Rc<RefCell<Vec<rustconn_core::ssh_agent::AgentKey>>>,
         ssh_jump_host_dropdown: &DropDown,
         ssh_proxy_entry: &Entry,
@@ -64,6 +66,7 @@ impl ConnectionDialog {
         ssh_agent_forwarding: &CheckButton,
         ssh_waypipe: &CheckButton,
         ssh_x11_forwarding: &CheckButton,
+        ssh_x11_trusted: &CheckButton,
         ssh_compression: &CheckButton,
         ssh_verbose: &CheckButton,
         ssh_mptcp: &CheckButton,
@@ -126,6 +129,7 @@ impl ConnectionDialog {
         spice_ca_cert_entry: &Entry,
         spice_skip_verify_check: &adw::SwitchRow,
         spice_usb_check: &adw::SwitchRow,
+        spice_usb_filters_entry: &Entry,
         spice_clipboard_check: &adw::SwitchRow,
         spice_show_local_cursor_check: &adw::SwitchRow,
         spice_compression_dropdown: &DropDown,
@@ -167,17 +171,22 @@ impl ConnectionDialog {
         telnet_backspace_dropdown: &DropDown,
         telnet_delete_dropdown: &DropDown,
         serial_device_entry: &Entry,
+        serial_device_match_entry: &Entry,
         serial_baud_dropdown: &DropDown,
         serial_data_bits_dropdown: &DropDown,
         serial_stop_bits_dropdown: &DropDown,
         serial_parity_dropdown: &DropDown,
         serial_flow_control_dropdown: &DropDown,
         serial_custom_args_entry: &Entry,
+        serial_capture_switch: &adw::SwitchRow,
+        serial_capture_format_dropdown: &DropDown,
+        serial_view_mode_dropdown: &DropDown,
         k8s_kubeconfig_entry: &Entry,
         k8s_context_entry: &Entry,
         k8s_namespace_entry: &Entry,
         k8s_pod_entry: &Entry,
         k8s_container_entry: &Entry,
+        k8s_command_entry: &Entry,
         k8s_shell_dropdown: &DropDown,
         k8s_busybox_check: &CheckButton,
         k8s_busybox_image_entry: &Entry,
@@ -193,6 +202,8 @@ impl ConnectionDialog {
         variables_rows: &Rc<RefCell<Vec<LocalVariableRow>>>,
         logging_tab: &logging_tab::LoggingTab,
         expect_rules: &Rc<RefCell<Vec<ExpectRule>>>,
+        otp_prompt_switch: &adw::SwitchRow,
+        otp_variable_entry: &adw::EntryRow,
         pre_connect_enabled_switch: &adw::SwitchRow,
         pre_connect_command_entry: &Entry,
         pre_connect_timeout_spin: &SpinButton,
@@ -208,6 +219,9 @@ impl ConnectionDialog {
         wol_broadcast_entry: &Entry,
         wol_port_spin: &SpinButton,
         wol_wait_spin: &SpinButton,
+        wol_wait_online_switch: &adw::SwitchRow,
+        wol_online_timeout_spin: &SpinButton,
+        wol_poll_interval_spin: &SpinButton,
         theme_bg_button: &ColorDialogButton,
         theme_fg_button: &ColorDialogButton,
         theme_cursor_button: &ColorDialogButton,
@@ -223,7 +237,10 @@ impl ConnectionDialog {
         retry_max_attempts_spin: &adw::SpinRow,
         retry_initial_delay_spin: &adw::SpinRow,
         retry_max_delay_spin: &adw::SpinRow,
+        retry_jitter_spin: &adw::SpinRow,
+        retry_max_elapsed_spin: &adw::SpinRow,
         skip_port_check_toggle: &adw::SwitchRow,
+        tailscale_dns_toggle: &adw::SwitchRow,
         knock_sequence_entry: &gtk4::Entry,
         spa_enabled_toggle: &adw::SwitchRow,
         spa_rij_key_entry: &adw::PasswordEntryRow,
@@ -253,6 +270,8 @@ impl ConnectionDialog {
         let ssh_key_source_dropdown = ssh_key_source_dropdown.clone();
         let ssh_key_entry = ssh_key_entry.clone();
         let ssh_agent_key_dropdown = ssh_agent_key_dropdown.clone();
+        let ssh_keepass_entry_row = ssh_keepass_entry_row.clone();
+        let ssh_keepass_attachment_row = ssh_keepass_attachment_row.clone();
         let ssh_agent_keys = ssh_agent_keys.clone();
         let ssh_jump_host_dropdown = ssh_jump_host_dropdown.clone();
         let ssh_proxy_entry = ssh_proxy_entry.clone();
@@ -262,6 +281,7 @@ impl ConnectionDialog {
         let ssh_agent_forwarding = ssh_agent_forwarding.clone();
         let ssh_waypipe = ssh_waypipe.clone();
         let ssh_x11_forwarding = ssh_x11_forwarding.clone();
+        let ssh_x11_trusted = ssh_x11_trusted.clone();
         let ssh_compression = ssh_compression.clone();
         let ssh_verbose = ssh_verbose.clone();
         let ssh_mptcp = ssh_mptcp.clone();
@@ -324,6 +344,7 @@ impl ConnectionDialog {
         let spice_ca_cert_entry = spice_ca_cert_entry.clone();
         let spice_skip_verify_check = spice_skip_verify_check.clone();
         let spice_usb_check = spice_usb_check.clone();
+        let spice_usb_filters_entry = spice_usb_filters_entry.clone();
         let spice_clipboard_check = spice_clipboard_check.clone();
         let spice_show_local_cursor_check = spice_show_local_cursor_check.clone();
         let spice_compression_dropdown = spice_compression_dropdown.clone();
@@ -365,17 +386,22 @@ impl ConnectionDialog {
         let telnet_backspace_dropdown = telnet_backspace_dropdown.clone();
         let telnet_delete_dropdown = telnet_delete_dropdown.clone();
         let serial_device_entry = serial_device_entry.clone();
+        let serial_device_match_entry = serial_device_match_entry.clone();
         let serial_baud_dropdown = serial_baud_dropdown.clone();
         let serial_data_bits_dropdown = serial_data_bits_dropdown.clone();
         let serial_stop_bits_dropdown = serial_stop_bits_dropdown.clone();
         let serial_parity_dropdown = serial_parity_dropdown.clone();
         let serial_flow_control_dropdown = serial_flow_control_dropdown.clone();
         let serial_custom_args_entry = serial_custom_args_entry.clone();
+        let serial_capture_switch = serial_capture_switch.clone();
+        let serial_capture_format_dropdown = serial_capture_format_dropdown.clone();
+        let serial_view_mode_dropdown = serial_view_mode_dropdown.clone();
         let k8s_kubeconfig_entry = k8s_kubeconfig_entry.clone();
         let k8s_context_entry = k8s_context_entry.clone();
         let k8s_namespace_entry = k8s_namespace_entry.clone();
         let k8s_pod_entry = k8s_pod_entry.clone();
         let k8s_container_entry = k8s_container_entry.clone();
+        let k8s_command_entry = k8s_command_entry.clone();
         let k8s_shell_dropdown = k8s_shell_dropdown.clone();
         let k8s_busybox_check = k8s_busybox_check.clone();
         let k8s_busybox_image_entry = k8s_busybox_image_entry.clone();
@@ -394,11 +420,15 @@ impl ConnectionDialog {
         let logging_timestamp_dropdown = logging_tab.timestamp_dropdown.clone();
         let logging_max_size_spin = logging_tab.max_size_spin.clone();
         let logging_retention_spin = logging_tab.retention_spin.clone();
+        let logging_rotated_files_spin = logging_tab.rotated_files_spin.clone();
         let logging_activity_switch = logging_tab.log_activity_switch.clone();
         let logging_input_switch = logging_tab.log_input_switch.clone();
         let logging_output_switch = logging_tab.log_output_switch.clone();
         let logging_timestamps_switch = logging_tab.log_timestamps_switch.clone();
+        let logging_transcript_dropdown = logging_tab.transcript_dropdown.clone();
         let expect_rules = expect_rules.clone();
+        let otp_prompt_switch = otp_prompt_switch.clone();
+        let otp_variable_entry = otp_variable_entry.clone();
         let pre_connect_enabled_switch = pre_connect_enabled_switch.clone();
         let pre_connect_command_entry = pre_connect_command_entry.clone();
         let pre_connect_timeout_spin = pre_connect_timeout_spin.clone();
@@ -414,6 +444,9 @@ impl ConnectionDialog {
         let wol_broadcast_entry = wol_broadcast_entry.clone();
         let wol_port_spin = wol_port_spin.clone();
         let wol_wait_spin = wol_wait_spin.clone();
+        let wol_wait_online_switch = wol_wait_online_switch.clone();
+        let wol_online_timeout_spin = wol_online_timeout_spin.clone();
+        let wol_poll_interval_spin = wol_poll_interval_spin.clone();
         let theme_bg_button = theme_bg_button.clone();
         let theme_fg_button = theme_fg_button.clone();
         let theme_cursor_button = theme_cursor_button.clone();
@@ -430,7 +463,10 @@ impl ConnectionDialog {
         let retry_max_attempts_spin = retry_max_attempts_spin.clone();
         let retry_initial_delay_spin = retry_initial_delay_spin.clone();
         let retry_max_delay_spin = retry_max_delay_spin.clone();
+        let retry_jitter_spin = retry_jitter_spin.clone();
+        let retry_max_elapsed_spin = retry_max_elapsed_spin.clone();
         let skip_port_check_toggle = skip_port_check_toggle.clone();
+        let tailscale_dns_toggle = tailscale_dns_toggle.clone();
         let knock_sequence_entry = knock_sequence_entry.clone();
         let spa_enabled_toggle = spa_enabled_toggle.clone();
         let spa_rij_key_entry = spa_rij_key_entry.clone();
@@ -464,6 +500,8 @@ impl ConnectionDialog {
                 ssh_key_source_dropdown: &ssh_key_source_dropdown,
                 ssh_key_entry: &ssh_key_entry,
                 ssh_agent_key_dropdown: &ssh_agent_key_dropdown,
+                ssh_keepass_entry_row: &ssh_keepass_entry_row,
+                ssh_keepass_attachment_row: &ssh_keepass_attachment_row,
                 ssh_agent_keys: &ssh_agent_keys,
                 ssh_jump_host_dropdown: &ssh_jump_host_dropdown,
                 ssh_proxy_entry: &ssh_proxy_entry,
@@ -473,6 +511,7 @@ impl ConnectionDialog {
                 ssh_agent_forwarding: &ssh_agent_forwarding,
                 ssh_waypipe: &ssh_waypipe,
                 ssh_x11_forwarding: &ssh_x11_forwarding,
+                ssh_x11_trusted: &ssh_x11_trusted,
                 ssh_compression: &ssh_compression,
                 ssh_verbose: &ssh_verbose,
                 ssh_mptcp: &ssh_mptcp,
@@ -533,6 +572,7 @@ impl ConnectionDialog {
                 spice_ca_cert_entry: &spice_ca_cert_entry,
                 spice_skip_verify_check: &spice_skip_verify_check,
                 spice_usb_check: &spice_usb_check,
+                spice_usb_filters_entry: &spice_usb_filters_entry,
                 spice_clipboard_check: &spice_clipboard_check,
                 spice_show_local_cursor_check: &spice_show_local_cursor_check,
                 spice_compression_dropdown: &spice_compression_dropdown,
@@ -574,17 +614,22 @@ impl ConnectionDialog {
                 telnet_backspace_dropdown: &telnet_backspace_dropdown,
                 telnet_delete_dropdown: &telnet_delete_dropdown,
                 serial_device_entry: &serial_device_entry,
+                serial_device_match_entry: &serial_device_match_entry,
                 serial_baud_dropdown: &serial_baud_dropdown,
                 serial_data_bits_dropdown: &serial_data_bits_dropdown,
                 serial_stop_bits_dropdown: &serial_stop_bits_dropdown,
                 serial_parity_dropdown: &serial_parity_dropdown,
                 serial_flow_control_dropdown: &serial_flow_control_dropdown,
                 serial_custom_args_entry: &serial_custom_args_entry,
+                serial_capture_switch: &serial_capture_switch,
+                serial_capture_format_dropdown: &serial_capture_format_dropdown,
+                serial_view_mode_dropdown: &serial_view_mode_dropdown,
                 k8s_kubeconfig_entry: &k8s_kubeconfig_entry,
                 k8s_context_entry: &k8s_context_entry,
                 k8s_namespace_entry: &k8s_namespace_entry,
                 k8s_pod_entry: &k8s_pod_entry,
                 k8s_container_entry: &k8s_container_entry,
+                k8s_command_entry: &k8s_command_entry,
                 k8s_shell_dropdown: &k8s_shell_dropdown,
                 k8s_busybox_check: &k8s_busybox_check,
                 k8s_busybox_image_entry: &k8s_busybox_image_entry,
@@ -604,12 +649,16 @@ impl ConnectionDialog {
                     timestamp_dropdown: logging_timestamp_dropdown.clone(),
                     max_size_spin: logging_max_size_spin.clone(),
                     retention_spin: logging_retention_spin.clone(),
+                    rotated_files_spin: logging_rotated_files_spin.clone(),
                     log_activity_switch: logging_activity_switch.clone(),
                     log_input_switch: logging_input_switch.clone(),
                     log_output_switch: logging_output_switch.clone(),
                     log_timestamps_switch: logging_timestamps_switch.clone(),
+                    transcript_dropdown: logging_transcript_dropdown.clone(),
                 },
                 expect_rules: &collected_expect_rules,
+                otp_prompt_switch: &otp_prompt_switch,
+                otp_variable_entry: &otp_variable_entry,
                 pre_connect_enabled_switch: &pre_connect_enabled_switch,
                 pre_connect_command_entry: &pre_connect_command_entry,
                 pre_connect_timeout_spin: &pre_connect_timeout_spin,
@@ -625,6 +674,9 @@ impl ConnectionDialog {
                 wol_broadcast_entry: &wol_broadcast_entry,
                 wol_port_spin: &wol_port_spin,
                 wol_wait_spin: &wol_wait_spin,
+                wol_wait_online_switch: &wol_wait_online_switch,
+                wol_online_timeout_spin: &wol_online_timeout_spin,
+                wol_poll_interval_spin: &wol_poll_interval_spin,
                 theme_bg_button: &theme_bg_button,
                 theme_fg_button: &theme_fg_button,
                 theme_cursor_button: &theme_cursor_button,
@@ -642,7 +694,10 @@ impl ConnectionDialog {
                 retry_max_attempts_spin: &retry_max_attempts_spin,
                 retry_initial_delay_spin: &retry_initial_delay_spin,
                 retry_max_delay_spin: &retry_max_delay_spin,
+                retry_jitter_spin: &retry_jitter_spin,
+                retry_max_elapsed_spin: &retry_max_elapsed_spin,
                 skip_port_check_toggle: &skip_port_check_toggle,
+                tailscale_dns_toggle: &tailscale_dns_toggle,
                 knock_sequence_entry: &knock_sequence_entry,
                 spa_enabled_toggle: &spa_enabled_toggle,
                 spa_rij_key_entry: &spa_rij_key_entry,
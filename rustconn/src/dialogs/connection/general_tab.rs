@@ -299,6 +299,31 @@ pub(super) fn create_basic_tab() -> BasicTabWidgets {
     password_value_row.add_suffix(&vault_test_button);
     auth_group.add(&password_value_row);
 
+    // Explain weak passwords below the value as the user types
+    {
+        let row = password_value_row.clone();
+        password_entry.connect_changed(move |entry| {
+            let password = entry.text();
+            if password.is_empty() {
+                row.set_subtitle("");
+                row.set_tooltip_text(None);
+                return;
+            }
+            let feedback = rustconn_core::analyze_password(&password, 10_000_000_000.0);
+            let advice: Vec<String> = feedback
+                .suggestions
+                .iter()
+                .map(|s| i18n(s.message()))
+                .collect();
+            let mut hint = i18n(feedback.strength.description());
+            if let Some(first) = advice.first() {
+                hint = format!("{hint} — {first}");
+            }
+            row.set_subtitle(&hint);
+            row.set_tooltip_text((!advice.is_empty()).then(|| advice.join("\n")).as_deref());
+        });
+    }
+
     // Password row visibility controller (hidden GtkBox for bind_property)
     let password_row = GtkBox::new(Orientation::Horizontal, 0);
     password_row.set_visible(false);
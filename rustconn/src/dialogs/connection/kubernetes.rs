@@ -20,6 +20,7 @@ use crate::i18n::i18n;
 /// - Namespace entry
 /// - Pod entry
 /// - Container entry
+/// - Command entry
 /// - Shell dropdown
 /// - Busybox toggle
 /// - Busybox image entry
@@ -31,6 +32,7 @@ pub type KubernetesOptionsWidgets = (
     Entry,
     Entry,
     Entry,
+    Entry,
     DropDown,
     CheckButton,
     Entry,
@@ -78,6 +80,12 @@ pub fn create_kubernetes_options() -> KubernetesOptionsWidgets {
         .build();
     connection_group.add(&container_row);
 
+    let (command_row, command_entry) = EntryRowBuilder::new(i18n("Command"))
+        .subtitle(i18n("Run instead of the shell (optional)"))
+        .placeholder("tail -f /var/log/app.log")
+        .build();
+    connection_group.add(&command_row);
+
     // Shell dropdown
     let shell_model = StringList::new(&["/bin/sh", "/bin/bash", "/bin/ash", "/bin/zsh"]);
     let shell_dropdown = DropDown::builder().model(&shell_model).selected(0).build();
@@ -147,6 +155,7 @@ pub fn create_kubernetes_options() -> KubernetesOptionsWidgets {
         namespace_entry,
         pod_entry,
         container_entry,
+        command_entry,
         shell_dropdown,
         busybox_check,
         busybox_image_entry,
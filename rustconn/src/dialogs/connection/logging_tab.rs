@@ -7,7 +7,7 @@ use gtk4::prelude::*;
 use gtk4::{Box as GtkBox, DropDown, Entry, Orientation, ScrolledWindow, SpinButton, StringList};
 use libadwaita as adw;
 use libadwaita::prelude::*;
-use rustconn_core::session::LogConfig;
+use rustconn_core::session::{LogConfig, TimestampMode, TranscriptMode};
 
 use crate::i18n::i18n;
 
@@ -20,6 +20,19 @@ const TIMESTAMP_FORMATS: [&str; 5] = [
     "%d/%m/%Y %H:%M:%S",
 ];
 
+/// UTC timestamp modes listed after the local-time formats
+const UTC_TIMESTAMP_MODES: [(TimestampMode, &str); 2] = [
+    (TimestampMode::Iso8601, "ISO-8601 (UTC)"),
+    (TimestampMode::EpochMillis, "Unix epoch (ms)"),
+];
+
+/// Transcript modes matching the dropdown order
+const TRANSCRIPT_MODES: [TranscriptMode; 3] = [
+    TranscriptMode::Raw,
+    TranscriptMode::Clean,
+    TranscriptMode::Both,
+];
+
 /// Logging tab widget group
 pub struct LoggingTab {
     pub enabled_switch: adw::SwitchRow,
@@ -27,10 +40,12 @@ pub struct LoggingTab {
     pub timestamp_dropdown: DropDown,
     pub max_size_spin: SpinButton,
     pub retention_spin: SpinButton,
+    pub rotated_files_spin: SpinButton,
     pub log_activity_switch: adw::SwitchRow,
     pub log_input_switch: adw::SwitchRow,
     pub log_output_switch: adw::SwitchRow,
     pub log_timestamps_switch: adw::SwitchRow,
+    pub transcript_dropdown: DropDown,
 }
 
 impl LoggingTab {
@@ -94,6 +109,9 @@ impl LoggingTab {
         settings_group.add(&path_row);
 
         let timestamp_list = StringList::new(&TIMESTAMP_FORMATS);
+        for (_, label) in UTC_TIMESTAMP_MODES {
+            timestamp_list.append(&i18n(label));
+        }
         let timestamp_dropdown = DropDown::new(Some(timestamp_list), gtk4::Expression::NONE);
         timestamp_dropdown.set_selected(0);
         timestamp_dropdown.set_valign(gtk4::Align::Center);
@@ -138,6 +156,24 @@ impl LoggingTab {
         retention_row.add_suffix(&retention_spin);
         settings_group.add(&retention_row);
 
+        let rotated_files_adj = gtk4::Adjustment::new(0.0, 0.0, 100.0, 1.0, 5.0, 0.0);
+        let rotated_files_spin = SpinButton::builder()
+            .adjustment(&rotated_files_adj)
+            .climb_rate(1.0)
+            .digits(0)
+            .valign(gtk4::Align::Center)
+            .sensitive(false)
+            .build();
+
+        let rotated_files_row = adw::ActionRow::builder()
+            .title(i18n("Rotated Files"))
+            .subtitle(i18n(
+                "Keep this many full logs as .1, .2, … (0 = timestamped, pruned by retention)",
+            ))
+            .build();
+        rotated_files_row.add_suffix(&rotated_files_spin);
+        settings_group.add(&rotated_files_row);
+
         content.append(&settings_group);
 
         // === Content Options Group ===
@@ -179,6 +215,22 @@ impl LoggingTab {
             .build();
         content_group.add(&log_timestamps_switch);
 
+        let transcript_list =
+            StringList::new(&[&i18n("Raw"), &i18n("Plain text"), &i18n("Raw + plain text")]);
+        let transcript_dropdown = DropDown::new(Some(transcript_list), gtk4::Expression::NONE);
+        transcript_dropdown.set_selected(0);
+        transcript_dropdown.set_valign(gtk4::Align::Center);
+        transcript_dropdown.set_sensitive(false);
+
+        let transcript_row = adw::ActionRow::builder()
+            .title(i18n("Transcript"))
+            .subtitle(i18n(
+                "Plain text strips colors and cursor codes; both keeps a .clean copy",
+            ))
+            .build();
+        transcript_row.add_suffix(&transcript_dropdown);
+        content_group.add(&transcript_row);
+
         content.append(&content_group);
 
         // Wire enabled toggle
@@ -186,24 +238,28 @@ impl LoggingTab {
         let ts_clone = timestamp_dropdown.clone();
         let size_clone = max_size_spin.clone();
         let ret_clone = retention_spin.clone();
+        let rotated_clone = rotated_files_spin.clone();
         let sg_clone = settings_group.clone();
         let cg_clone = content_group.clone();
         let activity_clone = log_activity_switch.clone();
         let input_clone = log_input_switch.clone();
         let output_clone = log_output_switch.clone();
         let timestamps_clone = log_timestamps_switch.clone();
+        let transcript_clone = transcript_dropdown.clone();
         enabled_switch.connect_active_notify(move |switch| {
             let on = switch.is_active();
             path_clone.set_sensitive(on);
             ts_clone.set_sensitive(on);
             size_clone.set_sensitive(on);
             ret_clone.set_sensitive(on);
+            rotated_clone.set_sensitive(on);
             sg_clone.set_sensitive(on);
             cg_clone.set_sensitive(on);
             activity_clone.set_sensitive(on);
             input_clone.set_sensitive(on);
             output_clone.set_sensitive(on);
             timestamps_clone.set_sensitive(on);
+            transcript_clone.set_sensitive(on);
         });
         settings_group.set_sensitive(false);
 
@@ -219,10 +275,12 @@ impl LoggingTab {
             timestamp_dropdown,
             max_size_spin,
             retention_spin,
+            rotated_files_spin,
             log_activity_switch,
             log_input_switch,
             log_output_switch,
             log_timestamps_switch,
+            transcript_dropdown,
         };
         (vbox, tab)
     }
@@ -233,46 +291,65 @@ impl LoggingTab {
             self.enabled_switch.set_active(c.enabled);
             self.path_entry.set_text(&c.path_template);
 
-            let idx = TIMESTAMP_FORMATS
+            let idx = UTC_TIMESTAMP_MODES
                 .iter()
-                .position(|&f| f == c.timestamp_format)
+                .position(|&(m, _)| m == c.timestamp_mode)
+                .map(|i| TIMESTAMP_FORMATS.len() + i)
+                .or_else(|| {
+                    TIMESTAMP_FORMATS
+                        .iter()
+                        .position(|&f| f == c.timestamp_format)
+                })
                 .unwrap_or(0);
             self.timestamp_dropdown.set_selected(idx as u32);
             self.max_size_spin.set_value(f64::from(c.max_size_mb));
             self.retention_spin.set_value(f64::from(c.retention_days));
+            self.rotated_files_spin
+                .set_value(f64::from(c.max_rotated_files));
             self.log_activity_switch.set_active(c.log_activity);
             self.log_input_switch.set_active(c.log_input);
             self.log_output_switch.set_active(c.log_output);
             self.log_timestamps_switch.set_active(c.log_timestamps);
+            let transcript_idx = TRANSCRIPT_MODES
+                .iter()
+                .position(|&m| m == c.transcript)
+                .unwrap_or(0);
+            self.transcript_dropdown.set_selected(transcript_idx as u32);
 
             let on = c.enabled;
             self.path_entry.set_sensitive(on);
             self.timestamp_dropdown.set_sensitive(on);
             self.max_size_spin.set_sensitive(on);
             self.retention_spin.set_sensitive(on);
+            self.rotated_files_spin.set_sensitive(on);
             self.log_activity_switch.set_sensitive(on);
             self.log_input_switch.set_sensitive(on);
             self.log_output_switch.set_sensitive(on);
             self.log_timestamps_switch.set_sensitive(on);
+            self.transcript_dropdown.set_sensitive(on);
         } else {
             self.enabled_switch.set_active(false);
             self.path_entry.set_text("");
             self.timestamp_dropdown.set_selected(0);
             self.max_size_spin.set_value(10.0);
             self.retention_spin.set_value(30.0);
+            self.rotated_files_spin.set_value(0.0);
             self.log_activity_switch.set_active(true);
             self.log_input_switch.set_active(false);
             self.log_output_switch.set_active(false);
             self.log_timestamps_switch.set_active(false);
+            self.transcript_dropdown.set_selected(0);
 
             self.path_entry.set_sensitive(false);
             self.timestamp_dropdown.set_sensitive(false);
             self.max_size_spin.set_sensitive(false);
             self.retention_spin.set_sensitive(false);
+            self.rotated_files_spin.set_sensitive(false);
             self.log_activity_switch.set_sensitive(false);
             self.log_input_switch.set_sensitive(false);
             self.log_output_switch.set_sensitive(false);
             self.log_timestamps_switch.set_sensitive(false);
+            self.transcript_dropdown.set_sensitive(false);
         }
     }
 
@@ -297,6 +374,10 @@ impl LoggingTab {
             .get(idx)
             .unwrap_or(&TIMESTAMP_FORMATS[0])
             .to_string();
+        let timestamp_mode = idx
+            .checked_sub(TIMESTAMP_FORMATS.len())
+            .and_then(|i| UTC_TIMESTAMP_MODES.get(i))
+            .map_or(TimestampMode::Local, |&(m, _)| m);
 
         #[expect(
             clippy::cast_sign_loss,
@@ -308,17 +389,28 @@ impl LoggingTab {
             reason = "value is non-negative by construction in this code path"
         )]
         let retention_days = self.retention_spin.value() as u32;
+        #[expect(
+            clippy::cast_sign_loss,
+            reason = "value is non-negative by construction in this code path"
+        )]
+        let max_rotated_files = self.rotated_files_spin.value() as u32;
 
         Some(LogConfig {
             enabled: true,
             path_template,
             timestamp_format,
+            timestamp_mode,
             max_size_mb,
             retention_days,
+            max_rotated_files,
             log_activity: self.log_activity_switch.is_active(),
             log_input: self.log_input_switch.is_active(),
             log_output: self.log_output_switch.is_active(),
             log_timestamps: self.log_timestamps_switch.is_active(),
+            transcript: TRANSCRIPT_MODES
+                .get(self.transcript_dropdown.selected() as usize)
+                .copied()
+                .unwrap_or_default(),
         })
     }
 }
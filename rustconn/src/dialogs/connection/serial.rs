@@ -1,13 +1,14 @@
 //! Serial protocol options for the connection dialog
 //!
 //! UI panel for Serial connections with device path, baud rate,
-//! data bits, stop bits, parity, and flow control settings.
+//! data bits, stop bits, parity, flow control, and raw capture settings.
 //! Serial uses an external `picocom` CLI client via VTE terminal.
 
 use adw::prelude::*;
 use gtk4::prelude::*;
 use gtk4::{Box as GtkBox, DropDown, Entry, StringList};
 use libadwaita as adw;
+use rustconn_core::models::{SerialCaptureFormat, SerialViewMode};
 use rustconn_core::{
     SerialBaudRate, SerialDataBits, SerialFlowControl, SerialParity, SerialStopBits,
 };
@@ -21,21 +22,29 @@ use crate::i18n::{i18n, i18n_f};
 /// Contains:
 /// - Container box
 /// - Device entry
+/// - Adapter match entry
 /// - Baud rate dropdown
 /// - Data bits dropdown
 /// - Stop bits dropdown
 /// - Parity dropdown
 /// - Flow control dropdown
 /// - Custom args entry
+/// - Capture-on-connect switch
+/// - Capture format dropdown
+/// - View mode dropdown
 pub type SerialOptionsWidgets = (
     GtkBox,
     Entry,
+    Entry,
     DropDown,
     DropDown,
     DropDown,
     DropDown,
     DropDown,
     Entry,
+    adw::SwitchRow,
+    DropDown,
+    DropDown,
 );
 
 /// Creates the Serial options panel using libadwaita components.
@@ -60,6 +69,15 @@ pub fn create_serial_options() -> SerialOptionsWidgets {
         .build();
     device_group.add(&device_row);
 
+    let (device_match_row, device_match_entry) = EntryRowBuilder::new(i18n("USB Adapter"))
+        .subtitle(i18n(
+            "Vendor:product ID or /dev/serial/by-id name; looked up on each connect, \
+             so the adapter is found after replugging",
+        ))
+        .placeholder("1a86:7523")
+        .build();
+    device_group.add(&device_match_row);
+
     // SERIAL-1: Detect available serial devices
     let detect_button = gtk4::Button::builder()
         .label(i18n("Detect Devices"))
@@ -217,14 +235,75 @@ pub fn create_serial_options() -> SerialOptionsWidgets {
 
     content.append(&line_group);
 
+    // === Capture Group ===
+    let capture_group = adw::PreferencesGroup::builder()
+        .title(i18n("Raw Data"))
+        .build();
+
+    let capture_switch = adw::SwitchRow::builder()
+        .title(i18n("Capture on Connect"))
+        .subtitle(i18n(
+            "Save raw bytes received from the device to a binary file",
+        ))
+        .build();
+    capture_group.add(&capture_switch);
+
+    let capture_format_model = StringList::new(
+        &SerialCaptureFormat::all()
+            .iter()
+            .map(|f| i18n(f.display_name()))
+            .collect::<Vec<_>>()
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>(),
+    );
+    let capture_format_dropdown = DropDown::builder()
+        .model(&capture_format_model)
+        .selected(SerialCaptureFormat::default().index())
+        .build();
+    let capture_format_row = adw::ActionRow::builder()
+        .title(i18n("Capture Format"))
+        .subtitle(i18n("Optionally add a hex log with a timestamp per chunk"))
+        .build();
+    capture_format_row.add_suffix(&capture_format_dropdown);
+    capture_format_row.set_activatable_widget(Some(&capture_format_dropdown));
+    capture_group.add(&capture_format_row);
+
+    let view_mode_model = StringList::new(
+        &SerialViewMode::all()
+            .iter()
+            .map(|m| i18n(m.display_name()))
+            .collect::<Vec<_>>()
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>(),
+    );
+    let view_mode_dropdown = DropDown::builder()
+        .model(&view_mode_model)
+        .selected(SerialViewMode::default().index())
+        .build();
+    let view_mode_row = adw::ActionRow::builder()
+        .title(i18n("View Mode"))
+        .subtitle(i18n("Show data as terminal text or as a hex dump"))
+        .build();
+    view_mode_row.add_suffix(&view_mode_dropdown);
+    view_mode_row.set_activatable_widget(Some(&view_mode_dropdown));
+    capture_group.add(&view_mode_row);
+
+    content.append(&capture_group);
+
     (
         container,
         device_entry,
+        device_match_entry,
         baud_dropdown,
         data_bits_dropdown,
         stop_bits_dropdown,
         parity_dropdown,
         flow_dropdown,
         custom_args_entry,
+        capture_switch,
+        capture_format_dropdown,
+        view_mode_dropdown,
     )
 }
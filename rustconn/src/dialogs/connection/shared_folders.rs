@@ -44,6 +44,7 @@ pub fn connect_add_folder_button(
                     let folder = SharedFolder {
                         local_path: path.clone(),
                         share_name: share_name.clone(),
+                        read_only: false,
                     };
 
                     shared_folders.borrow_mut().push(folder);
@@ -3,7 +3,7 @@
 //! This module provides the SPICE-specific UI components including:
 //! - TLS encryption settings
 //! - CA certificate configuration
-//! - USB redirection
+//! - USB redirection and device filters
 //! - Clipboard sharing
 //! - Image compression settings
 //! - Shared folders management
@@ -34,6 +34,7 @@ pub(super) fn create_spice_options() -> (
     adw::SwitchRow,
     Entry,
     adw::SwitchRow,
+    Entry,
     adw::SwitchRow,
     adw::SwitchRow,
     DropDown,
@@ -166,6 +167,36 @@ pub(super) fn create_spice_options() -> (
         .build();
     features_group.add(&usb_check);
 
+    // USB device filters (vendor:product, comma separated)
+    let usb_filters_entry = Entry::builder()
+        .hexpand(true)
+        .valign(gtk4::Align::Center)
+        .placeholder_text("1050:0407, 08e6:3437")
+        .build();
+    let usb_filters_row = adw::ActionRow::builder()
+        .title(i18n("USB Device Filters"))
+        .subtitle(i18n("Only redirect these vendor:product IDs"))
+        .build();
+    usb_filters_row.add_suffix(&usb_filters_entry);
+    features_group.add(&usb_filters_row);
+
+    // Inline validation for USB device filters
+    usb_filters_entry.connect_changed(|entry| {
+        let text = entry.text();
+        match rustconn_core::spice_client::SpiceUsbFilter::parse_list(
+            super::builders::split_usb_filters(&text),
+        ) {
+            Ok(_) => {
+                entry.remove_css_class("error");
+                entry.set_tooltip_text(None);
+            }
+            Err(e) => {
+                entry.add_css_class("error");
+                entry.set_tooltip_text(Some(&e.to_string()));
+            }
+        }
+    });
+
     // Clipboard sharing
     let clipboard_check = adw::SwitchRow::builder()
         .title(i18n("Clipboard Sharing"))
@@ -403,6 +434,7 @@ pub(super) fn create_spice_options() -> (
         ca_cert_entry,
         skip_verify_check,
         usb_check,
+        usb_filters_entry,
         clipboard_check,
         compression_dropdown,
         proxy_entry,
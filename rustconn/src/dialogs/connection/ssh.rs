@@ -35,6 +35,10 @@ pub struct SshOptionsWidgets {
     pub key_entry: Entry,
     pub key_button: Button,
     pub agent_key_dropdown: DropDown,
+    /// KeePass entry path holding the key attachment
+    pub keepass_entry_row: adw::EntryRow,
+    /// Name of the key attachment on that entry
+    pub keepass_attachment_row: adw::EntryRow,
     pub jump_host_dropdown: DropDown,
     pub proxy_entry: Entry,
     pub proxy_command_entry: Entry,
@@ -43,6 +47,7 @@ pub struct SshOptionsWidgets {
     pub agent_forwarding: CheckButton,
     pub waypipe: CheckButton,
     pub x11_forwarding: CheckButton,
+    pub x11_trusted: CheckButton,
     pub compression: CheckButton,
     pub verbose: CheckButton,
     pub mptcp: CheckButton,
@@ -79,6 +84,8 @@ pub fn create_ssh_options() -> SshOptionsWidgets {
         key_entry,
         key_button,
         agent_key_dropdown,
+        keepass_entry_row,
+        keepass_attachment_row,
     ) = create_authentication_group();
     content.append(&auth_group);
 
@@ -101,6 +108,7 @@ pub fn create_ssh_options() -> SshOptionsWidgets {
         agent_forwarding,
         waypipe,
         x11_forwarding,
+        x11_trusted,
         compression,
         verbose,
         mptcp,
@@ -159,6 +167,8 @@ pub fn create_ssh_options() -> SshOptionsWidgets {
         key_entry,
         key_button,
         agent_key_dropdown,
+        keepass_entry_row,
+        keepass_attachment_row,
         jump_host_dropdown,
         proxy_entry,
         proxy_command_entry,
@@ -167,6 +177,7 @@ pub fn create_ssh_options() -> SshOptionsWidgets {
         agent_forwarding,
         waypipe,
         x11_forwarding,
+        x11_trusted,
         compression,
         verbose,
         mptcp,
@@ -193,6 +204,8 @@ fn create_authentication_group() -> (
     Entry,
     Button,
     DropDown,
+    adw::EntryRow,
+    adw::EntryRow,
 ) {
     let auth_group = adw::PreferencesGroup::builder()
         .title(i18n("Authentication"))
@@ -218,12 +231,13 @@ fn create_authentication_group() -> (
     auth_row.add_suffix(&auth_dropdown);
     auth_group.add(&auth_row);
 
-    // Key source dropdown (0=Default, 1=File, 2=Agent, 3=Inherit)
+    // Key source dropdown (0=Default, 1=File, 2=Agent, 3=Inherit, 4=KeePass attachment)
     let key_source_items: Vec<String> = vec![
         i18n("Default"),
         i18n("File"),
         i18n("Agent"),
         i18n("Inherit from group"),
+        i18n("KeePass attachment"),
     ];
     let key_source_refs: Vec<&str> = key_source_items.iter().map(String::as_str).collect();
     let key_source_list = StringList::new(&key_source_refs);
@@ -278,6 +292,20 @@ fn create_authentication_group() -> (
     agent_key_row.add_suffix(&agent_key_dropdown);
     auth_group.add(&agent_key_row);
 
+    // KeePass attachment: the key is exported to a private temp file per session
+    let keepass_entry_row = adw::EntryRow::builder()
+        .title(i18n("KeePass Entry"))
+        .build();
+    keepass_entry_row.set_tooltip_text(Some(&i18n(
+        "Entry path in the KeePass database, e.g. Servers/web",
+    )));
+    auth_group.add(&keepass_entry_row);
+    let keepass_attachment_row = adw::EntryRow::builder().title(i18n("Attachment")).build();
+    keepass_attachment_row.set_tooltip_text(Some(&i18n(
+        "Name of the private key attachment, e.g. id_ed25519",
+    )));
+    auth_group.add(&keepass_attachment_row);
+
     // Connect key source dropdown to show/hide appropriate fields
     connect_key_source_visibility(
         &key_source_dropdown,
@@ -286,6 +314,7 @@ fn create_authentication_group() -> (
         &key_entry,
         &key_button,
         &agent_key_dropdown,
+        &[&keepass_entry_row, &keepass_attachment_row],
     );
 
     // Inline validation: check key file exists when path is entered manually
@@ -322,16 +351,20 @@ fn create_authentication_group() -> (
     // Connect auth method dropdown to show/hide key-related rows
     connect_auth_method_visibility(
         &auth_dropdown,
+        &key_source_dropdown,
         &key_source_row,
         &key_file_row,
         &agent_key_row,
         &agent_key_dropdown,
+        &[&keepass_entry_row, &keepass_attachment_row],
     );
 
     // Set initial state (Password selected - hide key source)
     key_source_row.set_visible(false);
     key_file_row.set_visible(false);
     agent_key_row.set_visible(false);
+    keepass_entry_row.set_visible(false);
+    keepass_attachment_row.set_visible(false);
     key_entry.set_sensitive(false);
     key_button.set_sensitive(false);
     agent_key_dropdown.set_sensitive(false);
@@ -344,6 +377,8 @@ fn create_authentication_group() -> (
         key_entry,
         key_button,
         agent_key_dropdown,
+        keepass_entry_row,
+        keepass_attachment_row,
     )
 }
 
@@ -355,18 +390,23 @@ fn connect_key_source_visibility(
     key_entry: &Entry,
     key_button: &Button,
     agent_key_dropdown: &DropDown,
+    keepass_rows: &[&adw::EntryRow],
 ) {
     let key_file_row_clone = key_file_row.clone();
     let agent_key_row_clone = agent_key_row.clone();
     let key_entry_clone = key_entry.clone();
     let key_button_clone = key_button.clone();
     let agent_key_dropdown_clone = agent_key_dropdown.clone();
+    let keepass_rows: Vec<adw::EntryRow> = keepass_rows.iter().map(|r| (*r).clone()).collect();
 
     key_source_dropdown.connect_selected_notify(move |dropdown| {
         let selected = dropdown.selected();
+        for row in &keepass_rows {
+            row.set_visible(selected == 4);
+        }
         match selected {
-            0 | 3 => {
-                // Default or Inherit - hide both rows
+            0 | 3 | 4 => {
+                // Default, Inherit or KeePass attachment - hide both rows
                 key_file_row_clone.set_visible(false);
                 agent_key_row_clone.set_visible(false);
                 key_entry_clone.set_sensitive(false);
@@ -397,18 +437,27 @@ fn connect_key_source_visibility(
 /// Connects auth method dropdown to show/hide key-related rows
 fn connect_auth_method_visibility(
     auth_dropdown: &DropDown,
+    key_source_dropdown: &DropDown,
     key_source_row: &adw::ActionRow,
     key_file_row: &adw::ActionRow,
     agent_key_row: &adw::ActionRow,
     agent_key_dropdown: &DropDown,
+    keepass_rows: &[&adw::EntryRow],
 ) {
+    let key_source_dropdown_clone = key_source_dropdown.clone();
     let key_source_row_clone = key_source_row.clone();
     let key_file_row_clone = key_file_row.clone();
     let agent_key_row_clone = agent_key_row.clone();
     let agent_key_dropdown_clone = agent_key_dropdown.clone();
+    let keepass_rows: Vec<adw::EntryRow> = keepass_rows.iter().map(|r| (*r).clone()).collect();
 
     auth_dropdown.connect_selected_notify(move |dropdown| {
         let selected = dropdown.selected();
+        // KeePass attachment rows belong to the Public Key key-source choice
+        let show_keepass = selected == 1 && key_source_dropdown_clone.selected() == 4;
+        for row in &keepass_rows {
+            row.set_visible(show_keepass);
+        }
         match selected {
             0 | 2 => {
                 // Password / Keyboard Interactive - hide all key-related rows
@@ -536,6 +585,7 @@ fn create_session_group() -> (
     CheckButton,
     CheckButton,
     CheckButton,
+    CheckButton,
     Entry,
     Entry,
     adw::EntryRow,
@@ -549,7 +599,9 @@ fn create_session_group() -> (
     // Agent Forwarding switch
     let (agent_forwarding_row, agent_forwarding) =
         CheckboxRowBuilder::new(i18n("Agent Forwarding"))
-            .subtitle(i18n("Forward SSH agent to remote host (-A)"))
+            .subtitle(i18n(
+                "Forward SSH agent to remote host (-A). Anyone with root on the host can use your keys while connected",
+            ))
             .build();
     session_group.add(&agent_forwarding_row);
 
@@ -565,6 +617,18 @@ fn create_session_group() -> (
         .build();
     session_group.add(&x11_forwarding_row);
 
+    // Trusted X11 forwarding (-Y) — only meaningful with X11 forwarding on
+    let (x11_trusted_row, x11_trusted) = CheckboxRowBuilder::new(i18n("Trusted X11"))
+        .subtitle(i18n(
+            "Bypass X11 SECURITY extension restrictions (-Y). Remote clients get full access to your display",
+        ))
+        .build();
+    x11_forwarding
+        .bind_property("active", &x11_trusted_row, "sensitive")
+        .sync_create()
+        .build();
+    session_group.add(&x11_trusted_row);
+
     // Compression switch
     let (compression_row, compression) = CheckboxRowBuilder::new(i18n("Compression"))
         .subtitle(i18n("Enable compression for slow connections (-C)"))
@@ -681,6 +745,7 @@ fn create_session_group() -> (
         agent_forwarding,
         waypipe,
         x11_forwarding,
+        x11_trusted,
         compression,
         verbose,
         mptcp,
@@ -839,6 +904,7 @@ pub fn create_port_forwarding_group(
 
         let pf = rustconn_core::models::PortForward {
             direction,
+            bind_address: None,
             local_port,
             remote_host,
             remote_port,
@@ -18,8 +18,9 @@ use libadwaita as adw;
 use rustconn_core::cluster::Cluster;
 use rustconn_core::export::{
     AnsibleExporter, AsbruExporter, CsvExportField, CsvExportOptions, CsvExporter, ExportFormat,
-    ExportOptions, ExportResult, ExportTarget, MobaXtermExporter, NativeExport, RemminaExporter,
-    RoyalTsExporter, SecureCrtExporter, SshConfigExporter,
+    ExportOptions, ExportResult, ExportTarget, GuacamoleExporter, MobaXtermExporter, NativeExport,
+    RemminaExporter, RoyalTsExporter, SecureCrtExporter, SshConfigExporter,
+    WindowsTerminalExporter,
 };
 use rustconn_core::models::{
     Connection, ConnectionGroup, ConnectionTemplate, SmartFolder, Snippet,
@@ -269,6 +270,8 @@ impl ExportDialog {
             &i18n("Royal TS (.rtsz)"),
             &i18n("SecureCRT (.ini)"),
             &i18n("SSH Config"),
+            &i18n("Windows Terminal (.json)"),
+            &i18n("Apache Guacamole (user-mapping.xml)"),
         ]);
         let format_dropdown = DropDown::new(Some(format_list), gtk4::Expression::NONE);
         format_dropdown.set_selected(0);
@@ -631,6 +634,8 @@ impl ExportDialog {
             6 => ExportFormat::RoyalTs,
             7 => ExportFormat::SecureCrt,
             8 => ExportFormat::SshConfig,
+            9 => ExportFormat::WindowsTerminal,
+            10 => ExportFormat::Guacamole,
             _ => ExportFormat::Native,
         }
     }
@@ -724,6 +729,18 @@ impl ExportDialog {
                     .export(connections, groups, options)
                     .map_err(|e| e.to_string())
             }
+            ExportFormat::WindowsTerminal => {
+                let exporter = WindowsTerminalExporter;
+                exporter
+                    .export(connections, groups, options)
+                    .map_err(|e| e.to_string())
+            }
+            ExportFormat::Guacamole => {
+                let exporter = GuacamoleExporter::new();
+                exporter
+                    .export(connections, groups, options)
+                    .map_err(|e| e.to_string())
+            }
         }
     }
 
@@ -894,6 +911,14 @@ impl ExportDialog {
                         filter.add_pattern("*.ini");
                         filter.set_name(Some(&i18n("SecureCRT Sessions (*.ini)")));
                     }
+                    ExportFormat::WindowsTerminal => {
+                        filter.add_pattern("*.json");
+                        filter.set_name(Some(&i18n("Windows Terminal Fragment (*.json)")));
+                    }
+                    ExportFormat::Guacamole => {
+                        filter.add_pattern("*.xml");
+                        filter.set_name(Some(&i18n("Guacamole User Mapping (*.xml)")));
+                    }
                 }
 
                 let filters = gtk4::gio::ListStore::new::<gtk4::FileFilter>();
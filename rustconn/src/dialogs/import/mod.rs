@@ -20,7 +20,7 @@ use gtk4::{
 use libadwaita as adw;
 use rustconn_core::import::{
     AnsibleInventoryImporter, AsbruImporter, ImportResult, ImportSource, LibvirtDaemonImporter,
-    LibvirtXmlImporter, RemminaImporter, SshConfigImporter,
+    LibvirtXmlImporter, PuttyImporter, RemminaImporter, SshConfigImporter,
 };
 use rustconn_core::progress::LocalProgressReporter;
 
@@ -285,6 +285,10 @@ impl ImportDialog {
                 let importer = AnsibleInventoryImporter::new();
                 Self::import_or_error(importer.import(), "Ansible inventory")
             }
+            "putty" => {
+                let importer = PuttyImporter::new();
+                Self::import_or_error(importer.import(), "PuTTY")
+            }
             "libvirt_daemon" => {
                 let importer = LibvirtDaemonImporter::new();
                 Self::import_or_error(importer.import(), "Libvirt Daemon")
@@ -629,6 +633,21 @@ impl ImportDialog {
                     return;
                 }
 
+                if source_id == "putty_file" {
+                    Self::handle_putty_file_import(
+                        parent_window.as_ref(),
+                        &stack,
+                        &progress_bar,
+                        &progress_label,
+                        &result_label,
+                        &result_details,
+                        &result_cell,
+                        &source_name_cell,
+                        btn,
+                    );
+                    return;
+                }
+
                 if source_id == "libvirt_file" {
                     Self::handle_libvirt_file_import(
                         parent_window.as_ref(),
@@ -923,6 +942,24 @@ impl ImportDialog {
 
                 Self::import_or_error(importer.import(), "Libvirt")
             }
+            "putty" => {
+                let importer = PuttyImporter::new();
+                let paths = importer.default_paths();
+                let total = paths.len().max(1);
+
+                for (i, path) in paths.iter().enumerate() {
+                    reporter.report(
+                        i,
+                        total,
+                        &i18n_f("Importing from {}...", &[&path.display().to_string()]),
+                    );
+                    if reporter.is_cancelled() {
+                        return ImportResult::default();
+                    }
+                }
+
+                Self::import_or_error(importer.import(), "PuTTY")
+            }
             "libvirt_daemon" => {
                 reporter.report(0, 1, &i18n("Querying libvirt daemon..."));
                 if reporter.is_cancelled() {
@@ -13,9 +13,9 @@ use gtk4::{
 use rustconn_core::export::NativeExport;
 use rustconn_core::import::{
     AnsibleInventoryImporter, AsbruImporter, CsvImporter, CsvParseOptions, ImportResult,
-    ImportSource, LibvirtDaemonImporter, LibvirtXmlImporter, MobaXtermImporter, RdmImporter,
-    RdpFileImporter, RemminaImporter, RoyalTsImporter, SecureCrtImporter, SshConfigImporter,
-    VirtViewerImporter,
+    ImportSource, LibvirtDaemonImporter, LibvirtXmlImporter, MobaXtermImporter, MremoteNgImporter,
+    PuttyImporter, RdmImporter, RdpFileImporter, RemminaImporter, RoyalTsImporter,
+    SecureCrtImporter, SshConfigImporter, TermiusImporter, VirtViewerImporter,
 };
 
 use super::ImportDialog;
@@ -107,6 +107,18 @@ impl ImportDialog {
                 i18n("Import from a MobaXterm session export file"),
                 true,
             ),
+            (
+                "putty",
+                i18n("PuTTY"),
+                i18n("Import SSH sessions from ~/.putty/sessions"),
+                PuttyImporter::new().is_available(),
+            ),
+            (
+                "putty_file",
+                i18n("PuTTY Registry Export (.reg)"),
+                i18n("Import SSH sessions from a Windows PuTTY registry export"),
+                true,
+            ),
             (
                 "rdp_file",
                 i18n("RDP File (.rdp)"),
@@ -245,6 +257,8 @@ impl ImportDialog {
             "royalts_file" => i18n("Royal TS"),
             "rdm_file" => i18n("Remote Desktop Manager"),
             "mobaxterm_file" => i18n("MobaXterm"),
+            "putty" => i18n("PuTTY"),
+            "putty_file" => i18n("PuTTY File"),
             "vv_file" => i18n("Virt-Viewer"),
             "multi_file" => i18n("Multiple Files"),
             "libvirt" => i18n("Libvirt / GNOME Boxes"),
@@ -981,6 +995,97 @@ impl ImportDialog {
         );
     }
 
+    /// Handles importing from a PuTTY registry export (`.reg`)
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "function parameters mirror upstream API or struct fields 1:1; bundling into a struct only restates the field list"
+    )]
+    pub(super) fn handle_putty_file_import(
+        parent_window: Option<&gtk4::Window>,
+        stack: &Stack,
+        progress_bar: &ProgressBar,
+        progress_label: &Label,
+        result_label: &Label,
+        result_details: &Label,
+        result_cell: &Rc<RefCell<Option<ImportResult>>>,
+        source_name_cell: &Rc<RefCell<String>>,
+        btn: &Button,
+    ) {
+        let file_dialog = gtk4::FileDialog::builder()
+            .title(i18n("Select PuTTY Registry Export"))
+            .modal(true)
+            .build();
+
+        let filter = gtk4::FileFilter::new();
+        filter.add_pattern("*.reg");
+        filter.set_name(Some(&i18n("Registry Files (*.reg)")));
+        let filters = gtk4::gio::ListStore::new::<gtk4::FileFilter>();
+        filters.append(&filter);
+        file_dialog.set_filters(Some(&filters));
+
+        let stack_clone = stack.clone();
+        let progress_bar_clone = progress_bar.clone();
+        let progress_label_clone = progress_label.clone();
+        let result_label_clone = result_label.clone();
+        let result_details_clone = result_details.clone();
+        let result_cell_clone = result_cell.clone();
+        let source_name_cell_clone = source_name_cell.clone();
+        let btn_clone = btn.clone();
+
+        file_dialog.open(
+            parent_window,
+            gtk4::gio::Cancellable::NONE,
+            move |file_result| {
+                if let Ok(file) = file_result {
+                    if let Some(path) = file.path() {
+                        stack_clone.set_visible_child_name("progress");
+                        btn_clone.set_sensitive(false);
+                        progress_bar_clone.set_fraction(0.5);
+                        progress_label_clone
+                            .set_text(&i18n_f("Importing from {}...", &[&path.display().to_string()]));
+
+                        let importer = PuttyImporter::with_path(path.clone());
+                        let result = Self::import_or_error(
+                            importer.import_from_path(&path),
+                            "PuTTY",
+                        );
+
+                        // Extract filename for display
+                        let filename = path.file_name().map_or_else(
+                            || i18n("PuTTY"),
+                            |n| n.to_string_lossy().to_string(),
+                        );
+
+                        source_name_cell_clone.borrow_mut().clone_from(&filename);
+
+                        progress_bar_clone.set_fraction(1.0);
+
+                        // Show results
+                        let conn_count = result.connections.len();
+                        let group_count = result.groups.len();
+                        let summary = i18n_f(
+                            "Successfully imported {} connection(s) and {} group(s).\nConnections will be added to '{} Import' group.",
+                            &[&conn_count.to_string(), &group_count.to_string(), &filename],
+                        );
+                        result_label_clone.set_text(&summary);
+
+                        let details = Self::format_import_details(&result);
+                        result_details_clone.set_text(&details);
+
+                        *result_cell_clone.borrow_mut() = Some(result);
+                        stack_clone.set_visible_child_name("result");
+                        btn_clone.set_label(&i18n("Done"));
+                        btn_clone.set_sensitive(true);
+                    }
+                } else {
+                    // User cancelled file selection - return to source page
+                    stack_clone.set_visible_child_name("source");
+                    btn_clone.set_sensitive(true);
+                }
+            },
+        );
+    }
+
     /// Handles importing from a libvirt domain XML file
     #[expect(
         clippy::too_many_arguments,
@@ -1400,6 +1505,7 @@ impl ImportDialog {
         filter.add_pattern("*.yaml");
         filter.add_pattern("*.yml");
         filter.add_pattern("*.ini");
+        filter.add_pattern("*.reg");
         filter.set_name(Some(&i18n("All supported formats")));
         let filters = gtk4::gio::ListStore::new::<gtk4::FileFilter>();
         filters.append(&filter);
@@ -1585,9 +1691,18 @@ impl ImportDialog {
                 }
             }
             "json" => {
-                // Try RDM JSON format
-                let importer = RdmImporter::new();
-                Self::import_or_error(importer.import_from_path(path), "RDM JSON")
+                // Termius exports; anything else is RDM JSON
+                let is_termius = rustconn_core::import::rank_formats_for_path(path)
+                    .ok()
+                    .and_then(|ranked| rustconn_core::import::unambiguous(&ranked))
+                    .is_some_and(|m| m.source_id == "termius");
+                if is_termius {
+                    let importer = TermiusImporter::new();
+                    Self::import_or_error(importer.import_from_path(path), "Termius")
+                } else {
+                    let importer = RdmImporter::new();
+                    Self::import_or_error(importer.import_from_path(path), "RDM JSON")
+                }
             }
             "rtsz" => {
                 let importer = RoyalTsImporter::new();
@@ -1598,8 +1713,18 @@ impl ImportDialog {
                 Self::import_or_error(importer.import_from_path(path), "MobaXterm")
             }
             "xml" => {
-                let importer = LibvirtXmlImporter::new();
-                Self::import_or_error(importer.import_from_path(path), "Libvirt XML")
+                // confCons.xml from mRemoteNG; anything else is libvirt
+                let is_mremoteng = rustconn_core::import::rank_formats_for_path(path)
+                    .ok()
+                    .and_then(|ranked| rustconn_core::import::unambiguous(&ranked))
+                    .is_some_and(|m| m.source_id == "mremoteng");
+                if is_mremoteng {
+                    let importer = MremoteNgImporter::new();
+                    Self::import_or_error(importer.import_from_path(path), "mRemoteNG")
+                } else {
+                    let importer = LibvirtXmlImporter::new();
+                    Self::import_or_error(importer.import_from_path(path), "Libvirt XML")
+                }
             }
             "yaml" | "yml" => {
                 // Try Asbru format first, then Ansible
@@ -1616,6 +1741,10 @@ impl ImportDialog {
                 let importer = SecureCrtImporter::new();
                 Self::import_or_error(importer.import_from_path(path), "SecureCRT")
             }
+            "reg" => {
+                let importer = PuttyImporter::new();
+                Self::import_or_error(importer.import_from_path(path), "PuTTY")
+            }
             _ => {
                 // Try SSH config as fallback
                 let importer = SshConfigImporter::new();
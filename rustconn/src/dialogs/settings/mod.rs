@@ -30,6 +30,7 @@ pub use monitoring_tab::*;
 use rustconn_core::config::AppSettings;
 use rustconn_core::models::Connection;
 use rustconn_core::ssh_agent::SshAgentManager;
+use rustconn_core::terminal_themes::TerminalTheme;
 pub use secrets_tab::*;
 pub use ssh_agent_tab::*;
 pub use terminal_tab::*;
@@ -40,6 +41,9 @@ use crate::i18n::i18n;
 /// Callback type for settings save
 pub type SettingsCallback = Option<Rc<dyn Fn(AppSettings)>>;
 
+/// Callback type for live terminal theme preview (receives the theme name)
+pub type ThemePreviewCallback = Option<Rc<dyn Fn(&str)>>;
+
 /// Moves all `PreferencesGroup` children from `source` page to `target` page.
 fn move_groups(source: &adw::PreferencesPage, target: &adw::PreferencesPage) {
     // PreferencesPage stores groups inside an internal GtkBox/ListBox.
@@ -91,6 +95,8 @@ pub struct SettingsDialog {
     local_shell_command_entry: Entry,
     close_on_clean_exit_check: adw::SwitchRow,
     option_is_meta_check: adw::SwitchRow,
+    osc52_clipboard_check: adw::SwitchRow,
+    scrollback_unlimited_check: adw::SwitchRow,
     // Logging settings
     logging_enabled_row: adw::SwitchRow,
     log_dir_entry: Entry,
@@ -160,6 +166,8 @@ pub struct SettingsDialog {
     connections: Rc<RefCell<Vec<Connection>>>,
     // Callback
     on_save: SettingsCallback,
+    // Live theme preview callback
+    on_theme_preview: ThemePreviewCallback,
 }
 
 impl SettingsDialog {
@@ -212,6 +220,8 @@ impl SettingsDialog {
             local_shell_command_entry,
             close_on_clean_exit_check,
             option_is_meta_check,
+            osc52_clipboard_check,
+            scrollback_unlimited_check,
         ) = create_terminal_page();
         mark("terminal_page");
 
@@ -647,6 +657,8 @@ impl SettingsDialog {
             local_shell_command_entry,
             close_on_clean_exit_check,
             option_is_meta_check,
+            osc52_clipboard_check,
+            scrollback_unlimited_check,
             logging_enabled_row,
             log_dir_entry,
             retention_spin,
@@ -694,6 +706,7 @@ impl SettingsDialog {
             was_restored,
             connections: Rc::new(RefCell::new(Vec::new())),
             on_save: None,
+            on_theme_preview: None,
         }
     }
 
@@ -705,6 +718,15 @@ impl SettingsDialog {
         self.on_save = Some(Rc::new(callback));
     }
 
+    /// Sets the callback invoked when the terminal theme selection changes,
+    /// so open terminals can preview it before the dialog is closed
+    pub fn set_on_theme_preview<F>(&mut self, callback: F)
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.on_theme_preview = Some(Rc::new(callback));
+    }
+
     /// Sets the current settings
     pub fn set_settings(&mut self, settings: AppSettings) {
         *self.settings.borrow_mut() = settings;
@@ -864,6 +886,19 @@ impl SettingsDialog {
             "settings dialog load_settings done"
         );
 
+        // Live theme preview — connected after loading so the initial
+        // selection does not trigger a redundant repaint
+        if let Some(preview) = self.on_theme_preview.clone() {
+            self.color_theme_dropdown
+                .connect_selected_notify(move |dropdown| {
+                    if let Some(name) =
+                        TerminalTheme::theme_names().get(dropdown.selected() as usize)
+                    {
+                        preview(name);
+                    }
+                });
+        }
+
         // Connect SSH Agent Add Key button handler
         {
             let manager_clone = self.ssh_agent_manager.clone();
@@ -979,6 +1014,8 @@ impl SettingsDialog {
             &self.local_shell_command_entry,
             &self.close_on_clean_exit_check,
             &self.option_is_meta_check,
+            &self.osc52_clipboard_check,
+            &self.scrollback_unlimited_check,
             &settings.terminal,
         );
 
@@ -1102,6 +1139,8 @@ impl SettingsDialog {
         let local_shell_command_entry_clone = self.local_shell_command_entry.clone();
         let close_on_clean_exit_check_clone = self.close_on_clean_exit_check.clone();
         let option_is_meta_check_clone = self.option_is_meta_check.clone();
+        let osc52_clipboard_check_clone = self.osc52_clipboard_check.clone();
+        let scrollback_unlimited_check_clone = self.scrollback_unlimited_check.clone();
 
         // Logging controls
         let logging_enabled_row_clone = self.logging_enabled_row.clone();
@@ -1137,10 +1176,16 @@ impl SettingsDialog {
         let passbolt_storage_combo_clone = self.secrets_widgets.passbolt_storage_combo.clone();
         let passbolt_server_url_entry_clone =
             self.secrets_widgets.passbolt_server_url_entry.clone();
+        let bitwarden_server_url_entry_clone =
+            self.secrets_widgets.bitwarden_server_url_entry.clone();
         let onepassword_token_entry_clone = self.secrets_widgets.onepassword_token_entry.clone();
         let onepassword_storage_combo_clone =
             self.secrets_widgets.onepassword_storage_combo.clone();
         let pass_store_dir_entry_clone = self.secrets_widgets.pass_store_dir_entry.clone();
+        let vault_address_entry_clone = self.secrets_widgets.vault_address_entry.clone();
+        let vault_mount_entry_clone = self.secrets_widgets.vault_mount_entry.clone();
+        let vault_path_entry_clone = self.secrets_widgets.vault_path_entry.clone();
+        let vault_token_entry_clone = self.secrets_widgets.vault_token_entry.clone();
 
         // UI controls
         let color_scheme_box_clone = self.color_scheme_box.clone();
@@ -1223,6 +1268,8 @@ impl SettingsDialog {
                 &local_shell_command_entry_clone,
                 &close_on_clean_exit_check_clone,
                 &option_is_meta_check_clone,
+                &osc52_clipboard_check_clone,
+                &scrollback_unlimited_check_clone,
                 log_timestamps_check_clone.is_active(),
             );
 
@@ -1259,6 +1306,7 @@ impl SettingsDialog {
                 password_row: adw::PasswordEntryRow::new(), // dummy
                 key_file_row: adw::ActionRow::new(),      // dummy
                 bitwarden_group: adw::PreferencesGroup::new(), // dummy
+                bitwarden_server_url_entry: bitwarden_server_url_entry_clone.clone(),
                 bitwarden_status_label: Label::new(None), // dummy
                 bitwarden_unlock_button: Button::new(),   // dummy
                 bitwarden_password_entry: bitwarden_password_entry_clone.clone(),
@@ -1284,6 +1332,11 @@ impl SettingsDialog {
                 pass_store_dir_entry: pass_store_dir_entry_clone.clone(),
                 pass_store_dir_browse_button: Button::new(), // dummy, не використовується при збиранні
                 pass_status_label: Label::new(None), // dummy, не використовується при збиранні
+                vault_group: adw::PreferencesGroup::new(), // dummy
+                vault_address_entry: vault_address_entry_clone.clone(),
+                vault_mount_entry: vault_mount_entry_clone.clone(),
+                vault_path_entry: vault_path_entry_clone.clone(),
+                vault_token_entry: vault_token_entry_clone.clone(),
             };
             let secrets = collect_secret_settings(&secrets_widgets_for_collect, &settings_clone);
 
@@ -1387,6 +1440,7 @@ impl SettingsDialog {
                 },
                 standalone_tunnels: settings_clone.borrow().standalone_tunnels.clone(),
                 quick_connect_history: settings_clone.borrow().quick_connect_history.clone(),
+                saved_searches: settings_clone.borrow().saved_searches.clone(),
             };
 
             // Update stored settings
@@ -55,6 +55,7 @@ pub struct SecretsPageWidgets {
     pub key_file_row: adw::ActionRow,
     // Bitwarden widgets
     pub bitwarden_group: adw::PreferencesGroup,
+    pub bitwarden_server_url_entry: Entry,
     pub bitwarden_status_label: Label,
     pub bitwarden_unlock_button: Button,
     pub bitwarden_password_entry: adw::PasswordEntryRow,
@@ -90,6 +91,12 @@ pub struct SecretsPageWidgets {
     pub pass_store_dir_entry: Entry,
     pub pass_store_dir_browse_button: Button,
     pub pass_status_label: Label,
+    // HashiCorp Vault widgets
+    pub vault_group: adw::PreferencesGroup,
+    pub vault_address_entry: Entry,
+    pub vault_mount_entry: Entry,
+    pub vault_path_entry: Entry,
+    pub vault_token_entry: adw::PasswordEntryRow,
 }
 
 /// Index in the storage `StringList` for [`CredentialStorage::None`].
@@ -200,9 +207,10 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
         .build();
 
     // Simplified: KeePassXC, libsecret, Bitwarden, 1Password, Passbolt, Pass,
-    // Encrypted file. Index 1 is the system keyring: libsecret on Linux/BSD, the
-    // native Keychain on macOS (libsecret does not exist there). Index 6 is the
-    // application-managed encrypted-file backend (no system keyring required).
+    // Encrypted file, HashiCorp Vault. Index 1 is the system keyring: libsecret
+    // on Linux/BSD, the native Keychain on macOS (libsecret does not exist
+    // there). Index 6 is the application-managed encrypted-file backend (no
+    // system keyring required).
     #[cfg(target_os = "macos")]
     let system_keyring_label = "macOS Keychain";
     #[cfg(not(target_os = "macos"))]
@@ -217,6 +225,7 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
         "Passbolt",
         "Pass",
         encrypted_file_label.as_str(),
+        "HashiCorp Vault",
     ]);
     let secret_backend_dropdown = DropDown::builder()
         .model(&backend_strings)
@@ -306,6 +315,22 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
         .description(i18n("Configure Bitwarden CLI integration"))
         .build();
 
+    // Server URL entry for self-hosted instances (Vaultwarden etc.)
+    let bitwarden_server_url_entry = Entry::builder()
+        .placeholder_text("https://vault.bitwarden.com")
+        .hexpand(true)
+        .valign(gtk4::Align::Center)
+        .build();
+    let bw_url_row = adw::ActionRow::builder()
+        .title(i18n("Server URL"))
+        .subtitle(i18n(
+            "Self-hosted server; leave empty to keep the CLI setting",
+        ))
+        .build();
+    bw_url_row.add_suffix(&bitwarden_server_url_entry);
+    bw_url_row.set_activatable_widget(Some(&bitwarden_server_url_entry));
+    bitwarden_group.add(&bw_url_row);
+
     // Password entry for unlocking (PasswordEntryRow: built-in peek icon,
     // caps-lock warning and focus on row click)
     let bitwarden_password_entry = adw::PasswordEntryRow::builder()
@@ -408,6 +433,7 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
         let password_entry = bitwarden_password_entry.clone();
         let bw_cmd = bitwarden_cmd.clone();
         let storage_combo = bitwarden_storage_combo.clone();
+        let server_url_entry = bitwarden_server_url_entry.clone();
         bitwarden_unlock_button.connect_clicked(move |button| {
             let password_text = password_entry.text();
             let save_to_keyring =
@@ -434,6 +460,21 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
             button.set_sensitive(false);
             update_status_label(&status_label, &i18n("Unlocking..."), "dim-label");
 
+            // Point the CLI at the configured server before unlocking
+            let server_url = server_url_entry.text().trim().to_string();
+            if !server_url.is_empty()
+                && let Err(e) = crate::async_utils::with_runtime(|rt| {
+                    rt.block_on(rustconn_core::secret::ensure_server(&server_url))
+                        .map_err(|e| e.to_string())
+                })
+                .and_then(|result| result)
+            {
+                tracing::warn!(error = %e, "Bitwarden GUI: server configuration failed");
+                update_status_label(&status_label, &i18n("Server configuration failed"), "error");
+                button.set_sensitive(true);
+                return;
+            }
+
             let bw_cmd_str = bw_cmd.borrow().clone();
 
             // Note: do not log password length — it leaks bruteforce metadata.
@@ -804,6 +845,10 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
 
     page.add(&pass_group);
 
+    let (vault_group, vault_address_entry, vault_mount_entry, vault_path_entry, vault_token_entry) =
+        create_vault_group();
+    page.add(&vault_group);
+
     // === KeePass Database Group ===
     let kdbx_group = adw::PreferencesGroup::builder()
         .title(i18n("KeePass Database"))
@@ -995,6 +1040,7 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
     let onepassword_group_clone = onepassword_group.clone();
     let passbolt_group_clone = passbolt_group.clone();
     let pass_group_clone = pass_group.clone();
+    let vault_group_clone = vault_group.clone();
     let kdbx_group_clone = kdbx_group.clone();
     let auth_group_clone2 = auth_group.clone();
     let status_group_clone2 = status_group.clone();
@@ -1026,6 +1072,8 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
         passbolt_group_clone.set_visible(selected == 4);
         // Show Pass group only when Pass is selected (index 5)
         pass_group_clone.set_visible(selected == 5);
+        // Show Vault group only when HashiCorp Vault is selected (index 7)
+        vault_group_clone.set_visible(selected == 7);
         // Show KDBX groups only when KeePassXC is selected (index 0)
         let show_kdbx = selected == 0;
         kdbx_group_clone.set_visible(show_kdbx);
@@ -1185,6 +1233,7 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
     onepassword_group.set_visible(false);
     passbolt_group.set_visible(false);
     pass_group.set_visible(false);
+    vault_group.set_visible(false);
 
     // Initial version display set above as "Detecting..."
 
@@ -1481,6 +1530,7 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
         password_row,
         key_file_row,
         bitwarden_group,
+        bitwarden_server_url_entry,
         bitwarden_status_label,
         bitwarden_unlock_button,
         bitwarden_password_entry,
@@ -1506,9 +1556,79 @@ pub fn create_secrets_page() -> SecretsPageWidgets {
         pass_store_dir_entry,
         pass_store_dir_browse_button,
         pass_status_label,
+        vault_group,
+        vault_address_entry,
+        vault_mount_entry,
+        vault_path_entry,
+        vault_token_entry,
     }
 }
 
+/// Builds the HashiCorp Vault group: server address, KV mount, path prefix
+/// and token
+fn create_vault_group() -> (
+    adw::PreferencesGroup,
+    Entry,
+    Entry,
+    Entry,
+    adw::PasswordEntryRow,
+) {
+    let vault_group = adw::PreferencesGroup::builder()
+        .title(i18n("HashiCorp Vault"))
+        .description(i18n(
+            "Store credentials in a Vault KV version 2 secrets engine",
+        ))
+        .build();
+
+    let entry_row = |title: String, subtitle: String, placeholder: &str| {
+        let entry = Entry::builder()
+            .placeholder_text(placeholder)
+            .hexpand(true)
+            .valign(gtk4::Align::Center)
+            .build();
+        let row = adw::ActionRow::builder()
+            .title(title)
+            .subtitle(subtitle)
+            .build();
+        row.add_suffix(&entry);
+        row.set_activatable_widget(Some(&entry));
+        vault_group.add(&row);
+        entry
+    };
+
+    let vault_address_entry = entry_row(
+        i18n("Server Address"),
+        i18n("Leave empty to use VAULT_ADDR"),
+        rustconn_core::secret::DEFAULT_VAULT_ADDRESS,
+    );
+    let vault_mount_entry = entry_row(
+        i18n("KV Mount"),
+        i18n("Mount path of the KV v2 secrets engine"),
+        rustconn_core::secret::DEFAULT_VAULT_MOUNT,
+    );
+    let vault_path_entry = entry_row(
+        i18n("Path Prefix"),
+        i18n("Optional folder inside the mount"),
+        "team/ops",
+    );
+
+    let vault_token_entry = adw::PasswordEntryRow::builder()
+        .title(i18n("Token"))
+        .tooltip_text(i18n(
+            "Leave empty to use VAULT_TOKEN, ~/.vault-token or a Vault Agent",
+        ))
+        .build();
+    vault_group.add(&vault_token_entry);
+
+    (
+        vault_group,
+        vault_address_entry,
+        vault_mount_entry,
+        vault_path_entry,
+        vault_token_entry,
+    )
+}
+
 /// Gets CLI version from command output
 fn update_status_label(label: &Label, text: &str, css_class: &str) {
     label.set_text(text);
@@ -1558,6 +1678,7 @@ pub fn load_secret_settings(widgets: &SecretsPageWidgets, settings: &SecretSetti
         SecretBackendType::Passbolt => 4,
         SecretBackendType::Pass => 5,
         SecretBackendType::EncryptedFile => 6,
+        SecretBackendType::Vault => 7,
     };
     widgets.secret_backend_dropdown.set_selected(backend_index);
     widgets.enable_fallback.set_active(settings.enable_fallback);
@@ -1608,6 +1729,11 @@ pub fn load_secret_settings(widgets: &SecretsPageWidgets, settings: &SecretSetti
             .set_text(client_secret.expose_secret());
     }
 
+    // Load Bitwarden server URL
+    if let Some(ref url) = settings.bitwarden_server_url {
+        widgets.bitwarden_server_url_entry.set_text(url);
+    }
+
     // Load Passbolt server URL
     if let Some(ref url) = settings.passbolt_server_url {
         widgets.passbolt_server_url_entry.set_text(url);
@@ -1635,6 +1761,23 @@ pub fn load_secret_settings(widgets: &SecretsPageWidgets, settings: &SecretSetti
             .set_text(&path.display().to_string());
     }
 
+    // Load HashiCorp Vault configuration
+    let set_optional = |entry: &Entry, value: Option<&String>| {
+        if let Some(value) = value {
+            entry.set_text(value);
+        }
+    };
+    set_optional(
+        &widgets.vault_address_entry,
+        settings.vault_address.as_ref(),
+    );
+    set_optional(&widgets.vault_mount_entry, settings.vault_mount.as_ref());
+    set_optional(&widgets.vault_path_entry, settings.vault_path.as_ref());
+    if let Some(ref token) = settings.vault_token {
+        use secrecy::ExposeSecret;
+        widgets.vault_token_entry.set_text(token.expose_secret());
+    }
+
     // Show/hide groups based on selected backend
     let show_kdbx = backend_index == 0;
     widgets.kdbx_group.set_visible(show_kdbx);
@@ -1648,6 +1791,7 @@ pub fn load_secret_settings(widgets: &SecretsPageWidgets, settings: &SecretSetti
     widgets.onepassword_group.set_visible(backend_index == 3);
     widgets.passbolt_group.set_visible(backend_index == 4);
     widgets.pass_group.set_visible(backend_index == 5);
+    widgets.vault_group.set_visible(backend_index == 7);
     widgets.password_row.set_visible(settings.kdbx_use_password);
     widgets
         .kdbx_storage_combo
@@ -1701,10 +1845,12 @@ pub fn load_secret_settings(widgets: &SecretsPageWidgets, settings: &SecretSetti
         SecretBackendType::LibSecret
         | SecretBackendType::MacOsKeychain
         | SecretBackendType::Pass
-        | SecretBackendType::EncryptedFile => {
+        | SecretBackendType::EncryptedFile
+        | SecretBackendType::Vault => {
             // Stateless backends — nothing to load from keyring.
             // (EncryptedFile keeps its entries in its own file; no settings-tab
-            // credential fields to populate.)
+            // credential fields to populate. The Vault token is decrypted from
+            // the settings file at startup.)
         }
     }
 }
@@ -1880,7 +2026,7 @@ pub fn collect_secret_settings(
     widgets: &SecretsPageWidgets,
     settings: &Rc<RefCell<rustconn_core::config::AppSettings>>,
 ) -> SecretSettings {
-    // Indices: 0=KeePassXC, 1=libsecret/Keychain, 2=Bitwarden, 3=1Password, 4=Passbolt, 5=Pass, 6=Encrypted file
+    // Indices: 0=KeePassXC, 1=libsecret/Keychain, 2=Bitwarden, 3=1Password, 4=Passbolt, 5=Pass, 6=Encrypted file, 7=Vault
     let preferred_backend = match widgets.secret_backend_dropdown.selected() {
         0 => SecretBackendType::KeePassXc,
         // Index 1 is the platform system keyring (see create_secrets_page).
@@ -1893,6 +2039,7 @@ pub fn collect_secret_settings(
         4 => SecretBackendType::Passbolt,
         5 => SecretBackendType::Pass,
         6 => SecretBackendType::EncryptedFile,
+        7 => SecretBackendType::Vault,
         _ => SecretBackendType::default(),
     };
 
@@ -2083,6 +2230,24 @@ pub fn collect_secret_settings(
         CredentialStorage::SystemKeyring | CredentialStorage::None => (None, None),
     };
 
+    // Collect Vault token; an empty entry clears the saved token
+    let vault_token_text = widgets.vault_token_entry.text();
+    let (vault_token, vault_token_encrypted) = if vault_token_text.is_empty() {
+        (None, None)
+    } else {
+        (
+            Some(secrecy::SecretString::new(
+                vault_token_text.to_string().into(),
+            )),
+            settings.borrow().secrets.vault_token_encrypted.clone(),
+        )
+    };
+    let entry_value = |entry: &Entry| {
+        let text = entry.text();
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    };
+
     // Save credentials to keyring when SystemKeyring storage is selected
     let kdbx_storage = storage_combo_value(&widgets.kdbx_storage_combo);
     if bitwarden_storage == CredentialStorage::SystemKeyring {
@@ -2128,6 +2293,15 @@ pub fn collect_secret_settings(
         bitwarden_client_secret,
         bitwarden_client_secret_encrypted,
         bitwarden_save_to_keyring,
+        bitwarden_server_url: {
+            let url_text = widgets.bitwarden_server_url_entry.text();
+            let url_text = url_text.trim();
+            if url_text.is_empty() {
+                None
+            } else {
+                Some(url_text.to_string())
+            }
+        },
         kdbx_save_to_keyring: kdbx_storage == CredentialStorage::SystemKeyring,
         onepassword_service_account_token,
         onepassword_service_account_token_encrypted,
@@ -2152,5 +2326,10 @@ pub fn collect_secret_settings(
                 Some(std::path::PathBuf::from(path_text.as_str()))
             }
         },
+        vault_address: entry_value(&widgets.vault_address_entry),
+        vault_mount: entry_value(&widgets.vault_mount_entry),
+        vault_path: entry_value(&widgets.vault_path_entry),
+        vault_token,
+        vault_token_encrypted,
     }
 }
@@ -521,7 +521,11 @@ fn add_key_with_passphrase_dialog(
         };
 
         let manager = manager_clone.borrow();
-        match manager.add_key(&key_path_clone, passphrase.as_ref()) {
+        match manager.add_key(
+            &key_path_clone,
+            passphrase.as_ref(),
+            &rustconn_core::ssh_agent::AddKeyOptions::default(),
+        ) {
             Ok(()) => {
                 tracing::info!("Key added successfully: {}", key_path_clone.display());
                 dialog_clone2.close();
@@ -607,11 +611,20 @@ fn create_loaded_key_row(
     ssh_agent_socket_label: &Label,
 ) -> adw::ActionRow {
     let title = format!("{} ({} bits)", key.key_type, key.bits);
-    let subtitle = if key.comment.is_empty() {
+    let mut subtitle = if key.comment.is_empty() {
         format!("SHA256:{}", key.fingerprint)
     } else {
         format!("{} • SHA256:{}", key.comment, key.fingerprint)
     };
+    if let Some(secs) = key.lifetime_remaining {
+        let minutes = secs.div_ceil(60).to_string();
+        subtitle.push_str(" • ");
+        subtitle.push_str(&i18n_f("expires in {} min", &[&minutes]));
+    }
+    if key.confirm {
+        subtitle.push_str(" • ");
+        subtitle.push_str(&i18n("confirm on use"));
+    }
 
     let row = adw::ActionRow::builder()
         .title(&title)
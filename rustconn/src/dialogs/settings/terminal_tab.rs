@@ -35,6 +35,8 @@ pub fn create_terminal_page() -> (
     Entry,          // local_shell_command
     adw::SwitchRow, // close_on_clean_exit
     adw::SwitchRow, // option_is_meta (macOS)
+    adw::SwitchRow, // osc52_clipboard
+    adw::SwitchRow, // scrollback_unlimited
 ) {
     let page = adw::PreferencesPage::builder()
         .title(i18n("Terminal"))
@@ -350,6 +352,18 @@ pub fn create_terminal_page() -> (
     scrollback_row.set_activatable_widget(Some(&scrollback_spin));
     scrolling_group.add(&scrollback_row);
 
+    // Unlimited scrollback (disables the line count)
+    let scrollback_unlimited_row = adw::SwitchRow::builder()
+        .title(i18n("Unlimited history"))
+        .subtitle(i18n("Keep every line; memory use grows with output"))
+        .build();
+    scrollback_unlimited_row
+        .bind_property("active", &scrollback_row, "sensitive")
+        .invert_boolean()
+        .sync_create()
+        .build();
+    scrolling_group.add(&scrollback_unlimited_row);
+
     // Scroll on output
     let scroll_on_output_row = adw::SwitchRow::builder()
         .title(i18n("On output"))
@@ -454,6 +468,15 @@ pub fn create_terminal_page() -> (
     option_is_meta_row.set_visible(false);
     behavior_group.add(&option_is_meta_row);
 
+    // OSC 52 clipboard writes
+    let osc52_clipboard_row = adw::SwitchRow::builder()
+        .title(i18n("Remote clipboard access"))
+        .subtitle(i18n(
+            "Let remote programs set the clipboard (OSC 52); connections can override this",
+        ))
+        .build();
+    behavior_group.add(&osc52_clipboard_row);
+
     page.add(&behavior_group);
 
     // === Local Shell Group ===
@@ -496,6 +519,8 @@ pub fn create_terminal_page() -> (
         local_shell_command_entry,
         close_on_clean_exit_row,
         option_is_meta_row,
+        osc52_clipboard_row,
+        scrollback_unlimited_row,
     )
 }
 
@@ -522,6 +547,8 @@ pub fn load_terminal_settings(
     local_shell_command_entry: &Entry,
     close_on_clean_exit_row: &adw::SwitchRow,
     option_is_meta_row: &adw::SwitchRow,
+    osc52_clipboard_row: &adw::SwitchRow,
+    scrollback_unlimited_row: &adw::SwitchRow,
     settings: &TerminalSettings,
 ) {
     font_family_entry.set_text(&settings.font_family);
@@ -566,6 +593,8 @@ pub fn load_terminal_settings(
     local_shell_command_entry.set_text(&settings.local_shell_command);
     close_on_clean_exit_row.set_active(settings.close_on_clean_exit);
     option_is_meta_row.set_active(settings.option_is_meta);
+    osc52_clipboard_row.set_active(settings.osc52_clipboard);
+    scrollback_unlimited_row.set_active(settings.scrollback_unlimited);
 }
 
 /// Sets the active toggle index.
@@ -669,6 +698,8 @@ pub fn collect_terminal_settings(
     local_shell_command_entry: &Entry,
     close_on_clean_exit_row: &adw::SwitchRow,
     option_is_meta_row: &adw::SwitchRow,
+    osc52_clipboard_row: &adw::SwitchRow,
+    scrollback_unlimited_row: &adw::SwitchRow,
     log_timestamps: bool,
 ) -> TerminalSettings {
     let theme_names = TerminalTheme::theme_names();
@@ -698,6 +729,7 @@ pub fn collect_terminal_settings(
         font_family: font_family_entry.text().to_string(),
         font_size: (font_size_spin.value() as u32).max(1),
         scrollback_lines: scrollback_spin.value() as u32,
+        scrollback_unlimited: scrollback_unlimited_row.is_active(),
         color_theme,
         cursor_shape,
         cursor_blink: cursor_blink_mode,
@@ -713,6 +745,7 @@ pub fn collect_terminal_settings(
         local_shell_command: local_shell_command_entry.text().trim().to_string(),
         close_on_clean_exit: close_on_clean_exit_row.is_active(),
         option_is_meta: option_is_meta_row.is_active(),
+        osc52_clipboard: osc52_clipboard_row.is_active(),
     }
 }
 
@@ -16,7 +16,7 @@ use gtk4::{
     TextView,
 };
 use libadwaita as adw;
-use rustconn_core::models::{Snippet, SnippetVariable};
+use rustconn_core::models::{Snippet, SnippetVariable, SnippetVariableKind};
 use uuid::Uuid;
 
 use crate::i18n::i18n;
@@ -56,6 +56,8 @@ struct VariableRow {
     desc_entry: Entry,
     /// Entry widget for the default value
     default_entry: Entry,
+    /// Prompt kind, kept from the loaded snippet (not editable here)
+    kind: SnippetVariableKind,
 }
 
 impl SnippetDialog {
@@ -380,6 +382,7 @@ impl SnippetDialog {
             name_entry,
             desc_entry,
             default_entry,
+            kind: SnippetVariableKind::Text,
         }
     }
 
@@ -424,11 +427,12 @@ impl SnippetDialog {
         self.variables.borrow_mut().clear();
 
         for var in &snippet.variables {
-            let row = Self::create_variable_row(
+            let mut row = Self::create_variable_row(
                 &var.name,
                 var.description.as_deref(),
                 var.default_value.as_deref(),
             );
+            row.kind = var.kind.clone();
             self.variables_list.append(&row.row);
             self.variables.borrow_mut().push(row);
         }
@@ -640,6 +644,7 @@ impl SnippetDialog {
                     } else {
                         Some(default.trim().to_string())
                     },
+                    kind: v.kind.clone(),
                 }
             })
             .collect();
@@ -115,6 +115,9 @@ pub struct TemplateDialog {
     zt_custom_args_entry: Entry,
     // State
     editing_id: Rc<RefCell<Option<Uuid>>>,
+    /// Parent of the edited template; the dialog has no parent picker, so
+    /// it is carried over unchanged
+    editing_parent_id: Rc<RefCell<Option<Uuid>>>,
     // Callback
     on_save: TemplateCallback,
 }
@@ -323,6 +326,7 @@ impl TemplateDialog {
 
         let on_save: TemplateCallback = Rc::new(RefCell::new(None));
         let editing_id: Rc<RefCell<Option<Uuid>>> = Rc::new(RefCell::new(None));
+        let editing_parent_id: Rc<RefCell<Option<Uuid>>> = Rc::new(RefCell::new(None));
 
         // Connect save button
         Self::connect_save_button(
@@ -330,6 +334,7 @@ impl TemplateDialog {
             &dialog,
             &on_save,
             &editing_id,
+            &editing_parent_id,
             &name_entry,
             &description_entry,
             &icon_entry,
@@ -469,6 +474,7 @@ impl TemplateDialog {
             zt_generic_command_entry,
             zt_custom_args_entry,
             editing_id,
+            editing_parent_id,
             on_save,
         }
     }
@@ -1936,6 +1942,7 @@ impl TemplateDialog {
         dialog: &adw::Dialog,
         on_save: &TemplateCallback,
         editing_id: &Rc<RefCell<Option<Uuid>>>,
+        editing_parent_id: &Rc<RefCell<Option<Uuid>>>,
         name_entry: &adw::EntryRow,
         description_entry: &adw::EntryRow,
         icon_entry: &adw::EntryRow,
@@ -2005,6 +2012,7 @@ impl TemplateDialog {
         let dialog = dialog.clone();
         let on_save = on_save.clone();
         let editing_id = editing_id.clone();
+        let editing_parent_id = editing_parent_id.clone();
         let name_entry = name_entry.clone();
         let description_entry = description_entry.clone();
         let icon_entry = icon_entry.clone();
@@ -2187,6 +2195,7 @@ impl TemplateDialog {
             if let Some(id) = *editing_id.borrow() {
                 template.id = id;
             }
+            template.parent_id = *editing_parent_id.borrow();
 
             if let Some(ref cb) = *on_save.borrow() {
                 cb(Some(template));
@@ -2384,9 +2393,12 @@ impl TemplateDialog {
             proxy_command: None,
             pkcs11_provider: None,
             identities_only: identities_only.is_active(),
+            jump_hosts: Vec::new(),
             use_control_master: control_master.is_active(),
             agent_forwarding: agent_forwarding.is_active(),
+            agent_forwarding_acknowledged: agent_forwarding.is_active(),
             x11_forwarding: false,
+            x11_trusted: false,
             compression: false,
             startup_command: if startup_command.is_empty() {
                 None
@@ -2396,6 +2408,7 @@ impl TemplateDialog {
             custom_options: std::collections::HashMap::new(),
             sftp_enabled: true,
             port_forwards: Vec::new(),
+            port_forward_profiles: Vec::new(),
             waypipe: false,
             ssh_agent_socket: None,
             keep_alive_interval: None,
@@ -2480,6 +2493,8 @@ impl TemplateDialog {
                     hostname: gateway_text.to_string(),
                     port: 443,
                     username: None,
+                    domain: None,
+                    password_source: rustconn_core::models::PasswordSource::None,
                 })
             },
             shared_folders: Vec::new(),
@@ -2561,6 +2576,7 @@ impl TemplateDialog {
             jump_host_id: None,
             accept_certificate: false,
             mptcp: false,
+            repeater: None,
         })
     }
 
@@ -2590,6 +2606,7 @@ impl TemplateDialog {
             },
             skip_cert_verify: skip_verify_check.is_active(),
             usb_redirection: usb_check.is_active(),
+            usb_redirect_filters: Vec::new(),
             shared_folders: Vec::new(),
             clipboard_enabled: clipboard_check.is_active(),
             image_compression: compression,
@@ -2748,6 +2765,7 @@ impl TemplateDialog {
         self.dialog.set_title(&i18n("Edit Template"));
         self.save_button.set_label(&i18n("Save"));
         *self.editing_id.borrow_mut() = Some(template.id);
+        *self.editing_parent_id.borrow_mut() = template.parent_id;
 
         self.name_entry.set_text(&template.name);
         if let Some(ref desc) = template.description {
@@ -2823,7 +2841,8 @@ impl TemplateDialog {
             SshKeySource::Default => 0,
             SshKeySource::File { .. } => 1,
             SshKeySource::Agent { .. } => 2,
-            SshKeySource::Inherit => 0, // Inherit treated like Default in template UI
+            // Inherit and KeePass attachments are treated like Default in template UI
+            SshKeySource::Inherit | SshKeySource::KeePassAttachment { .. } => 0,
         };
         self.ssh_key_source_dropdown.set_selected(key_source_idx);
 
@@ -62,8 +62,17 @@ impl ForwardRuleWidgets {
         )]
         let remote_port = self.remote_port_spin.value() as u16;
 
+        // Dynamic forwards have no destination; drop values left over from
+        // switching the direction
+        let (remote_host, remote_port) = if direction == PortForwardDirection::Dynamic {
+            (String::new(), 0)
+        } else {
+            (remote_host, remote_port)
+        };
+
         PortForward {
             direction,
+            bind_address: None,
             local_port,
             remote_host,
             remote_port,
@@ -496,7 +496,8 @@ impl super::EmbeddedRdpWidget {
             .with_printer(config.printer_enabled)
             .with_performance_mode(config.performance_mode)
             .with_color_depth(config.performance_mode.color_depth())
-            .with_scale_factor(rdp_scale_percent);
+            .with_scale_factor(rdp_scale_percent)
+            .with_security_protocol(config.security_layer.into());
 
         if let Some(ref username) = config.username {
             client_config = client_config.with_username(username);
@@ -918,6 +919,36 @@ impl super::EmbeddedRdpWidget {
                                             "[Clipboard] Local clipboard changed, \
                                              announcing to server"
                                         );
+                                        // Images go to the server as PNG; the RDP
+                                        // client converts them to CF_DIB
+                                        if cb
+                                            .formats()
+                                            .contains_type(gtk4::gdk::Texture::static_type())
+                                        {
+                                            let tx_inner = tx.clone();
+                                            cb.read_texture_async(
+                                                None::<&gtk4::gio::Cancellable>,
+                                                move |result| {
+                                                    if let Ok(Some(texture)) = result
+                                                        && let Some(ref sender) = *tx_inner.borrow()
+                                                    {
+                                                        let png = texture.save_to_png_bytes();
+                                                        let _ = sender.send(
+                                                            RdpClientCommand::ClipboardImage(
+                                                                png.to_vec(),
+                                                            ),
+                                                        );
+                                                        tracing::debug!(
+                                                            width = texture.width(),
+                                                            height = texture.height(),
+                                                            "[Clipboard] Sent local clipboard \
+                                                             image to server"
+                                                        );
+                                                    }
+                                                },
+                                            );
+                                            return;
+                                        }
                                         // Read local clipboard text and send to server
                                         let tx_inner = tx.clone();
                                         cb.read_text_async(
@@ -1130,6 +1161,45 @@ impl super::EmbeddedRdpWidget {
                                     "[Clipboard] Auto-synced server text to local clipboard"
                                 );
                             }
+                            RdpClientEvent::ClipboardImage(png) => {
+                                // Server copied an image (already converted to PNG
+                                // and size-capped) - put it on the local clipboard
+                                match gtk4::gdk::Texture::from_bytes(&glib::Bytes::from_owned(png))
+                                {
+                                    Ok(texture) => {
+                                        *clipboard_sync_suppressed.borrow_mut() = true;
+                                        let clipboard = if let Some(root) = drawing_area.root()
+                                            && let Some(window) =
+                                                root.downcast_ref::<gtk4::Window>()
+                                        {
+                                            gtk4::prelude::WidgetExt::display(window).clipboard()
+                                        } else {
+                                            drawing_area.display().clipboard()
+                                        };
+                                        clipboard.set_texture(&texture);
+                                        let suppressed = clipboard_sync_suppressed.clone();
+                                        glib::timeout_add_local_once(
+                                            std::time::Duration::from_millis(100),
+                                            move || {
+                                                *suppressed.borrow_mut() = false;
+                                            },
+                                        );
+                                        tracing::debug!(
+                                            width = texture.width(),
+                                            height = texture.height(),
+                                            "[Clipboard] Auto-synced server image to local \
+                                             clipboard"
+                                        );
+                                    }
+                                    Err(e) => {
+                                        tracing::warn!(
+                                            protocol = "rdp",
+                                            %e,
+                                            "Failed to load clipboard image from server"
+                                        );
+                                    }
+                                }
+                            }
                             RdpClientEvent::ClipboardFormatsAvailable(formats) => {
                                 tracing::debug!(
                                     protocol = "rdp",
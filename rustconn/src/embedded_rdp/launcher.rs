@@ -164,20 +164,31 @@ impl SafeFreeRdpLauncher {
         // therefore written into the file, one per line. This also improves
         // security: nothing is visible in `/proc/<pid>/cmdline`.
         //
-        // The RD Gateway no longer needs a separate secret here: FreeRDP
-        // reuses the session credentials (`/u:`, `/d:` and the `/p:` from
-        // the args file) for the gateway, matching the working manual command
-        // `xfreerdp /gateway:g:HOST /u:NAME /d:DOMAIN`. The previous `/gp:`
-        // path used a FreeRDP 2.x alias that FreeRDP 3.x rejects (issue #187).
+        // Without a separate gateway account FreeRDP reuses the session
+        // credentials (`/u:`, `/d:` and the `/p:` from the args file) for the
+        // RD Gateway, matching the working manual command
+        // `xfreerdp /gateway:g:HOST /u:NAME /d:DOMAIN`. A separate account
+        // carries its own password as the `p:` sub-option of `/gateway:`, so
+        // the whole option is secret and is written here rather than by
+        // `build_connection_args`. The FreeRDP 2.x `/gp:` alias is rejected by
+        // FreeRDP 3.x (issue #187).
         let session_password = config
             .password
             .as_ref()
             .filter(|p| !p.expose_secret().is_empty());
+        let gateway_secret = Self::gateway_password(config).and_then(|password| {
+            Self::gateway_option(config).map(|gateway| {
+                SecretString::from(format!("{gateway},p:{}", password.expose_secret()))
+            })
+        });
 
         let mut secret_args: Vec<(&str, &SecretString)> = Vec::new();
         if let Some(p) = session_password {
             secret_args.push(("p", p));
         }
+        if let Some(ref gateway) = gateway_secret {
+            secret_args.push(("gateway", gateway));
+        }
 
         // Collect all plain-text connection arguments into a Vec<String>
         let plain_args = Self::build_connection_args(config);
@@ -325,29 +336,12 @@ impl SafeFreeRdpLauncher {
             args.push(arg.clone());
         }
 
-        // Add gateway configuration for RD Gateway connections.
-        //
-        // FreeRDP 3.x removed the short `/g:` / `/gu:` / `/gp:` aliases in
-        // favour of the unified `/gateway:` option (see xfreerdp3(1)); the old
-        // aliases are rejected as "Unexpected keyword" and the client exits
-        // before connecting (issue #187). FreeRDP reuses the session
-        // credentials (`/u:`, `/d:` and the `/p:` from the args file) for the
-        // gateway, exactly like the working manual command
-        // `xfreerdp /gateway:g:HOST /u:NAME /d:DOMAIN`. We only add an explicit
-        // gateway user when it differs from the session user; a distinct
-        // gateway account would also need its own password, which RustConn does
-        // not store yet (future work).
-        if let Some(ref gw_host) = config.gateway_hostname
-            && !gw_host.is_empty()
+        // Add gateway configuration for RD Gateway connections (see
+        // `gateway_option`). With a separate gateway password `launch()`
+        // writes the option as a secret instead.
+        if Self::gateway_password(config).is_none()
+            && let Some(gateway) = Self::gateway_option(config)
         {
-            let mut gateway = format!("g:{gw_host}:{}", config.gateway_port);
-            if let Some(ref gw_user) = config.gateway_username
-                && !gw_user.is_empty()
-                && config.username.as_deref() != Some(gw_user.as_str())
-            {
-                gateway.push_str(",u:");
-                gateway.push_str(gw_user);
-            }
             args.push(format!("/gateway:{gateway}"));
         }
 
@@ -377,6 +371,45 @@ impl SafeFreeRdpLauncher {
         args
     }
 
+    /// Builds the value of the `/gateway:` option without the password
+    ///
+    /// FreeRDP 3.x removed the short `/g:` / `/gu:` / `/gd:` / `/gp:` aliases
+    /// in favour of the unified `/gateway:` option (see xfreerdp3(1)); the old
+    /// aliases are rejected as "Unexpected keyword" and the client exits
+    /// before connecting (issue #187). The gateway user and domain are only
+    /// added when they differ from the session's; otherwise FreeRDP reuses
+    /// the session credentials.
+    fn gateway_option(config: &RdpConfig) -> Option<String> {
+        let gw_host = config
+            .gateway_hostname
+            .as_deref()
+            .filter(|h| !h.is_empty())?;
+        let mut gateway = format!("g:{gw_host}:{}", config.gateway_port);
+        if let Some(ref gw_user) = config.gateway_username
+            && !gw_user.is_empty()
+            && config.username.as_deref() != Some(gw_user.as_str())
+        {
+            gateway.push_str(",u:");
+            gateway.push_str(gw_user);
+        }
+        if let Some(ref gw_domain) = config.gateway_domain
+            && !gw_domain.is_empty()
+            && config.domain.as_deref() != Some(gw_domain.as_str())
+        {
+            gateway.push_str(",d:");
+            gateway.push_str(gw_domain);
+        }
+        Some(gateway)
+    }
+
+    /// Returns the password of a separate gateway account, if any
+    fn gateway_password(config: &RdpConfig) -> Option<&SecretString> {
+        config
+            .gateway_password
+            .as_ref()
+            .filter(|p| !p.expose_secret().is_empty())
+    }
+
     /// Adds connection arguments to the command
     ///
     /// Legacy wrapper around [`Self::build_connection_args`] for code that
@@ -439,29 +472,9 @@ impl SafeFreeRdpLauncher {
             cmd.arg(arg);
         }
 
-        // Add gateway configuration for RD Gateway connections.
-        //
-        // FreeRDP 3.x removed the short `/g:` / `/gu:` / `/gp:` aliases in
-        // favour of the unified `/gateway:` option (see xfreerdp3(1)); the old
-        // aliases are rejected as "Unexpected keyword" and the client exits
-        // before connecting (issue #187). FreeRDP reuses the session
-        // credentials (`/u:`, `/d:` and the `/p:` from the args file) for the
-        // gateway, exactly like the working manual command
-        // `xfreerdp /gateway:g:HOST /u:NAME /d:DOMAIN`. We only add an explicit
-        // gateway user when it differs from the session user; a distinct
-        // gateway account would also need its own password, which RustConn does
-        // not store yet (future work).
-        if let Some(ref gw_host) = config.gateway_hostname
-            && !gw_host.is_empty()
-        {
-            let mut gateway = format!("g:{gw_host}:{}", config.gateway_port);
-            if let Some(ref gw_user) = config.gateway_username
-                && !gw_user.is_empty()
-                && config.username.as_deref() != Some(gw_user.as_str())
-            {
-                gateway.push_str(",u:");
-                gateway.push_str(gw_user);
-            }
+        // Add gateway configuration for RD Gateway connections (see
+        // `gateway_option`); a separate gateway password is not passed here.
+        if let Some(gateway) = Self::gateway_option(config) {
             cmd.arg(format!("/gateway:{gateway}"));
         }
 
@@ -627,4 +640,46 @@ mod tests {
             "expected gateway option with distinct user, got {args:?}"
         );
     }
+
+    #[test]
+    fn test_gateway_account_kept_apart_from_session() {
+        let config = RdpConfig {
+            host: "vm1.example.com".to_string(),
+            username: Some("alice".to_string()),
+            domain: Some("CORP".to_string()),
+            password: Some(SecretString::from("host-secret")),
+            gateway_hostname: Some("gw.example.com".to_string()),
+            gateway_username: Some("gwadmin".to_string()),
+            gateway_domain: Some("DMZ".to_string()),
+            gateway_password: Some(SecretString::from("gw-secret")),
+            ..RdpConfig::default()
+        };
+
+        let args = SafeFreeRdpLauncher::build_connection_args(&config);
+        assert!(args.contains(&"/u:alice".to_string()));
+        assert!(args.contains(&"/d:CORP".to_string()));
+        // The gateway option carries the gateway password, so it is left to
+        // the secret part of the args file
+        assert!(
+            !args.iter().any(|a| a.starts_with("/gateway:")),
+            "gateway option must not be a plain argument: {args:?}"
+        );
+        assert!(!args.iter().any(|a| a.contains("secret")));
+        assert_eq!(
+            SafeFreeRdpLauncher::gateway_option(&config).as_deref(),
+            Some("g:gw.example.com:443,u:gwadmin,d:DMZ")
+        );
+
+        // Without a gateway password the option stays plain
+        let shared = RdpConfig {
+            gateway_password: None,
+            ..config
+        };
+        let args = SafeFreeRdpLauncher::build_connection_args(&shared);
+        assert!(
+            args.iter()
+                .any(|a| a == "/gateway:g:gw.example.com:443,u:gwadmin,d:DMZ"),
+            "expected plain gateway option, got {args:?}"
+        );
+    }
 }
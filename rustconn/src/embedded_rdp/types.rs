@@ -143,6 +143,10 @@ pub struct RdpConfig {
     pub gateway_port: u16,
     /// Gateway username (if different from connection username)
     pub gateway_username: Option<String>,
+    /// Gateway domain (if different from connection domain)
+    pub gateway_domain: Option<String>,
+    /// Password of a separate gateway account; `None` reuses the session password
+    pub gateway_password: Option<SecretString>,
     /// Whether to ignore TLS certificate validation (default: false).
     /// When false, uses TOFU (trust-on-first-use) like SSH known_hosts.
     pub ignore_certificate: bool,
@@ -215,6 +219,8 @@ impl Default for RdpConfig {
             gateway_hostname: None,
             gateway_port: 443,
             gateway_username: None,
+            gateway_domain: None,
+            gateway_password: None,
             ignore_certificate: false,
             security_layer: rustconn_core::models::RdpSecurityLayer::default(),
             tls_security_level: None,
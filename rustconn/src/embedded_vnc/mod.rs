@@ -358,7 +358,8 @@ impl EmbeddedVncWidget {
         let vnc_config = VncClientConfig::new(&config.host)
             .with_port(config.port)
             .with_shared(true)
-            .with_view_only(config.view_only);
+            .with_view_only(config.view_only)
+            .with_scaling(config.scaling);
 
         let mut vnc_config = if let Some(ref password) = config.password {
             use secrecy::ExposeSecret;
@@ -370,6 +371,7 @@ impl EmbeddedVncWidget {
         // Enable MPTCP if the user toggled it — the client will attempt to
         // create an MPTCP socket and fall back to regular TCP transparently.
         vnc_config.mptcp = config.mptcp;
+        vnc_config.repeater.clone_from(&config.repeater);
 
         // Honor the user's preferred encoding — the one performance knob the
         // embedded client can negotiate — by moving it to the front of the
@@ -723,6 +725,14 @@ impl EmbeddedVncWidget {
 
     /// Connects using external mode (vncviewer)
     fn connect_external(&self, config: &VncConfig) -> Result<(), EmbeddedVncError> {
+        // This fallback cannot reach a server behind a repeater; the session
+        // widget's external path launches a viewer that can
+        if config.repeater.is_some() {
+            return Err(EmbeddedVncError::Connection(
+                "VNC repeater requires the embedded client or ssvncviewer".to_string(),
+            ));
+        }
+
         let binary = Self::detect_vnc_viewer().ok_or_else(|| {
             EmbeddedVncError::VncClientInit(
                 "No VNC viewer found. Install vncviewer, gvncviewer, or remmina.".to_string(),
@@ -13,6 +13,7 @@ use gtk4::{
     Box as GtkBox, Button, DrawingArea, EventControllerKey, EventControllerMotion, GestureClick,
     Label, Orientation, gdk, glib,
 };
+use rustconn_core::{VncScalingMode, VncViewTransform};
 
 #[cfg(feature = "vnc-embedded")]
 use super::VncClientCommand;
@@ -227,16 +228,20 @@ impl EmbeddedVncWidget {
                         && buffer.has_data()
                         && let Some(surface) = buffer.surface()
                     {
-                        let scale_x = f64::from(width) / f64::from(buf_width);
-                        let scale_y = f64::from(height) / f64::from(buf_height);
-                        let scale = scale_x.min(scale_y);
-
-                        let offset_x = f64::from(buf_width).mul_add(-scale, f64::from(width)) / 2.0;
-                        let offset_y =
-                            f64::from(buf_height).mul_add(-scale, f64::from(height)) / 2.0;
+                        let scaling = config
+                            .borrow()
+                            .as_ref()
+                            .map_or_else(VncScalingMode::default, |c| c.scaling);
+                        let transform = VncViewTransform::new(
+                            scaling,
+                            buf_width,
+                            buf_height,
+                            f64::from(width),
+                            f64::from(height),
+                        );
 
-                        cr.translate(offset_x, offset_y);
-                        cr.scale(scale, scale);
+                        cr.translate(transform.offset_x, transform.offset_y);
+                        cr.scale(transform.scale, transform.scale);
                         let _ = cr.set_source_surface(surface, 0.0, 0.0);
                         let _ = cr.paint();
                     }
@@ -395,8 +400,12 @@ impl EmbeddedVncWidget {
                 let vnc_w = f64::from(*vnc_width_motion.borrow());
                 let vnc_h = f64::from(*vnc_height_motion.borrow());
 
+                let scaling = config
+                    .borrow()
+                    .as_ref()
+                    .map_or_else(VncScalingMode::default, |c| c.scaling);
                 let (vnc_x, vnc_y) =
-                    transform_widget_to_vnc(x, y, widget_w, widget_h, vnc_w, vnc_h);
+                    transform_widget_to_vnc(x, y, widget_w, widget_h, vnc_w, vnc_h, scaling);
 
                 let vnc_x = crate::utils::coord_to_u16(vnc_x);
                 let vnc_y = crate::utils::coord_to_u16(vnc_y);
@@ -447,8 +456,12 @@ impl EmbeddedVncWidget {
                 let vnc_w = f64::from(*vnc_width_press.borrow());
                 let vnc_h = f64::from(*vnc_height_press.borrow());
 
+                let scaling = config
+                    .borrow()
+                    .as_ref()
+                    .map_or_else(VncScalingMode::default, |c| c.scaling);
                 let (vnc_x, vnc_y) =
-                    transform_widget_to_vnc(x, y, widget_w, widget_h, vnc_w, vnc_h);
+                    transform_widget_to_vnc(x, y, widget_w, widget_h, vnc_w, vnc_h, scaling);
 
                 let vnc_x = crate::utils::coord_to_u16(vnc_x);
                 let vnc_y = crate::utils::coord_to_u16(vnc_y);
@@ -493,8 +506,12 @@ impl EmbeddedVncWidget {
                 let vnc_w = f64::from(*vnc_width_release.borrow());
                 let vnc_h = f64::from(*vnc_height_release.borrow());
 
+                let scaling = config
+                    .borrow()
+                    .as_ref()
+                    .map_or_else(VncScalingMode::default, |c| c.scaling);
                 let (vnc_x, vnc_y) =
-                    transform_widget_to_vnc(x, y, widget_w, widget_h, vnc_w, vnc_h);
+                    transform_widget_to_vnc(x, y, widget_w, widget_h, vnc_w, vnc_h, scaling);
 
                 let vnc_x = crate::utils::coord_to_u16(vnc_x);
                 let vnc_y = crate::utils::coord_to_u16(vnc_y);
@@ -767,13 +784,14 @@ impl EmbeddedVncWidget {
 /// Transforms widget coordinates to VNC framebuffer coordinates
 ///
 /// This function handles the coordinate transformation needed when the widget
-/// size differs from the VNC framebuffer size. It maintains aspect ratio and
-/// centers the content.
+/// size differs from the VNC framebuffer size, following the active scaling
+/// mode (see [`VncViewTransform`]).
 ///
 /// # Arguments
 /// * `x`, `y` - Widget coordinates (from mouse event)
 /// * `widget_w`, `widget_h` - Current widget dimensions
 /// * `vnc_w`, `vnc_h` - VNC framebuffer dimensions
+/// * `scaling` - How the framebuffer is scaled inside the widget
 ///
 /// # Returns
 /// Tuple of (vnc_x, vnc_y) clamped to valid framebuffer coordinates
@@ -785,19 +803,10 @@ pub fn transform_widget_to_vnc(
     widget_h: f64,
     vnc_w: f64,
     vnc_h: f64,
+    scaling: VncScalingMode,
 ) -> (f64, f64) {
-    // Calculate scale factor maintaining aspect ratio
-    let scale = (widget_w / vnc_w).min(widget_h / vnc_h);
-
-    // Calculate centering offsets
-    let offset_x = vnc_w.mul_add(-scale, widget_w) / 2.0;
-    let offset_y = vnc_h.mul_add(-scale, widget_h) / 2.0;
-
-    // Transform and clamp to valid range
-    let vnc_x = ((x - offset_x) / scale).clamp(0.0, vnc_w - 1.0);
-    let vnc_y = ((y - offset_y) / scale).clamp(0.0, vnc_h - 1.0);
-
-    (vnc_x, vnc_y)
+    VncViewTransform::new(scaling, vnc_w as u32, vnc_h as u32, widget_w, widget_h)
+        .widget_to_framebuffer(x, y)
 }
 
 /// Converts GTK button number to VNC button mask bit
@@ -818,10 +827,12 @@ pub const fn gtk_button_to_vnc_mask(gtk_button: u32) -> u8 {
 mod tests {
     use super::*;
 
+    const FIT: VncScalingMode = VncScalingMode::FitWindow;
+
     #[test]
     fn test_transform_widget_to_vnc_centered() {
         // Widget and VNC same size - no transformation needed
-        let (x, y) = transform_widget_to_vnc(100.0, 100.0, 1920.0, 1080.0, 1920.0, 1080.0);
+        let (x, y) = transform_widget_to_vnc(100.0, 100.0, 1920.0, 1080.0, 1920.0, 1080.0, FIT);
         assert!((x - 100.0).abs() < 0.001);
         assert!((y - 100.0).abs() < 0.001);
     }
@@ -829,7 +840,7 @@ mod tests {
     #[test]
     fn test_transform_widget_to_vnc_scaled() {
         // Widget is 2x larger than VNC
-        let (x, y) = transform_widget_to_vnc(200.0, 200.0, 3840.0, 2160.0, 1920.0, 1080.0);
+        let (x, y) = transform_widget_to_vnc(200.0, 200.0, 3840.0, 2160.0, 1920.0, 1080.0, FIT);
         assert!((x - 100.0).abs() < 0.001);
         assert!((y - 100.0).abs() < 0.001);
     }
@@ -837,15 +848,44 @@ mod tests {
     #[test]
     fn test_transform_widget_to_vnc_clamped() {
         // Coordinates outside VNC area should be clamped
-        let (x, y) = transform_widget_to_vnc(-100.0, -100.0, 1920.0, 1080.0, 1920.0, 1080.0);
+        let (x, y) = transform_widget_to_vnc(-100.0, -100.0, 1920.0, 1080.0, 1920.0, 1080.0, FIT);
         assert!(x >= 0.0);
         assert!(y >= 0.0);
 
-        let (x, y) = transform_widget_to_vnc(10000.0, 10000.0, 1920.0, 1080.0, 1920.0, 1080.0);
+        let (x, y) = transform_widget_to_vnc(10000.0, 10000.0, 1920.0, 1080.0, 1920.0, 1080.0, FIT);
         assert!(x <= 1919.0);
         assert!(y <= 1079.0);
     }
 
+    #[test]
+    fn test_transform_widget_to_vnc_fixed_scale() {
+        // 1024x768 at 0.5x in a 1920x1080 widget: centered at (704, 348)
+        let (x, y) = transform_widget_to_vnc(
+            754.0,
+            398.0,
+            1920.0,
+            1080.0,
+            1024.0,
+            768.0,
+            VncScalingMode::FixedScale(0.5),
+        );
+        assert!((x - 100.0).abs() < 0.001);
+        assert!((y - 100.0).abs() < 0.001);
+
+        // No scaling: a larger framebuffer stays anchored top-left
+        let (x, y) = transform_widget_to_vnc(
+            50.0,
+            60.0,
+            800.0,
+            600.0,
+            1920.0,
+            1080.0,
+            VncScalingMode::None,
+        );
+        assert!((x - 50.0).abs() < 0.001);
+        assert!((y - 60.0).abs() < 0.001);
+    }
+
     #[test]
     fn test_gtk_button_to_vnc_mask() {
         assert_eq!(gtk_button_to_vnc_mask(1), 0x01); // Left
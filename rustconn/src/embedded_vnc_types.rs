@@ -2,7 +2,8 @@
 //!
 //! This module contains types, enums, and helper structs used by the embedded VNC widget.
 
-use rustconn_core::models::ScaleOverride;
+use rustconn_core::VncScalingMode;
+use rustconn_core::models::{ScaleOverride, VncRepeater};
 use thiserror::Error;
 
 /// Standard VNC/display resolutions (width, height)
@@ -133,6 +134,8 @@ pub struct VncConfig {
     pub view_only: bool,
     /// Display scale override for embedded mode
     pub scale_override: ScaleOverride,
+    /// How the framebuffer is scaled inside the widget
+    pub scaling: VncScalingMode,
     /// Additional VNC viewer arguments
     pub extra_args: Vec<String>,
     /// Show local mouse cursor over embedded viewer (disable to avoid double cursor)
@@ -143,6 +146,8 @@ pub struct VncConfig {
     /// Uses multiple network paths for seamless mobility and bandwidth aggregation.
     /// Requires kernel MPTCP support (Linux 5.6+). Falls back to regular TCP.
     pub mptcp: bool,
+    /// Repeater dialled instead of the server (UltraVNC ID mode)
+    pub repeater: Option<VncRepeater>,
 }
 
 impl VncConfig {
@@ -161,10 +166,12 @@ impl VncConfig {
             clipboard_enabled: true,
             view_only: false,
             scale_override: ScaleOverride::default(),
+            scaling: VncScalingMode::default(),
             extra_args: Vec::new(),
             show_local_cursor: true,
             accept_certificate: false,
             mptcp: false,
+            repeater: None,
         }
     }
 
@@ -225,6 +232,13 @@ impl VncConfig {
         self
     }
 
+    /// Sets the framebuffer scaling mode
+    #[must_use]
+    pub const fn with_scaling(mut self, scaling: VncScalingMode) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
     /// Adds extra VNC viewer arguments
     #[must_use]
     pub fn with_extra_args(mut self, args: Vec<String>) -> Self {
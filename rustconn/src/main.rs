@@ -505,7 +505,21 @@ fn main() -> gtk4::glib::ExitCode {
                 .expect("compile-time constant directive"),
         );
 
-    tracing_subscriber::fmt().with_env_filter(filter).init();
+    // RUSTCONN_LOG_FORMAT=json switches to JSON lines for log shippers
+    match rustconn_core::LogFormat::from_env() {
+        rustconn_core::LogFormat::Text => {
+            tracing_subscriber::fmt().with_env_filter(filter).init();
+        }
+        rustconn_core::LogFormat::Json => {
+            use tracing_subscriber::layer::SubscriberExt;
+            use tracing_subscriber::util::SubscriberInitExt;
+
+            tracing_subscriber::registry()
+                .with(filter)
+                .with(rustconn_core::tracing::json_layer(std::io::stderr))
+                .init();
+        }
+    }
 
     // Drop the flood of harmless CSS theme-parser warnings GTK4 emits when it
     // reads the libadwaita ≥1.9 stylesheet (it uses CSS syntax the older GTK4
@@ -493,6 +493,7 @@ struct MonitoringParams {
     identity_file: Option<String>,
     password: Option<secrecy::SecretString>,
     jump_host: Option<String>,
+    ssh_agent_socket: Option<String>,
 }
 
 use std::collections::HashMap;
@@ -526,6 +527,7 @@ impl MonitoringCoordinator {
     /// * `identity_file` - Optional SSH key path
     /// * `password` - Optional password for sshpass authentication
     /// * `jump_host` - Optional jump host chain for `-J` flag
+    /// * `ssh_agent_socket` - Resolved `SSH_AUTH_SOCK` override, as passed to `spawn_ssh`
     #[expect(
         clippy::too_many_arguments,
         reason = "function parameters mirror upstream API or struct fields 1:1; bundling into a struct only restates the field list"
@@ -541,6 +543,7 @@ impl MonitoringCoordinator {
         identity_file: Option<&str>,
         password: Option<secrecy::SecretString>,
         jump_host: Option<&str>,
+        ssh_agent_socket: Option<&str>,
     ) {
         // Don't start if monitoring is disabled
         if !settings.enabled {
@@ -567,6 +570,7 @@ impl MonitoringCoordinator {
             identity_file.map(String::from),
             password,
             jump_host.map(String::from),
+            ssh_agent_socket,
         );
 
         let (handle, mut rx) =
@@ -625,6 +629,7 @@ impl MonitoringCoordinator {
             identity_file,
             password_clone,
             jump_host,
+            ssh_agent_socket,
         );
 
         tracing::info!(
@@ -726,6 +731,7 @@ impl MonitoringCoordinator {
             params.identity_file.as_deref(),
             params.password,
             params.jump_host.as_deref(),
+            params.ssh_agent_socket.as_deref(),
         );
     }
 
@@ -750,6 +756,7 @@ impl MonitoringCoordinator {
         identity_file: Option<&str>,
         password: Option<secrecy::SecretString>,
         jump_host: Option<&str>,
+        ssh_agent_socket: Option<&str>,
     ) {
         self.suspended.borrow_mut().insert(
             session_id,
@@ -761,6 +768,7 @@ impl MonitoringCoordinator {
                 identity_file: identity_file.map(String::from),
                 password,
                 jump_host: jump_host.map(String::from),
+                ssh_agent_socket: ssh_agent_socket.map(String::from),
             },
         );
     }
@@ -14,7 +14,7 @@ use gtk4::{Align, Box as GtkBox, Label, Orientation, Overlay};
 #[cfg(feature = "adw-1-6")]
 use libadwaita as adw;
 use rustconn_core::models::{VncClientMode, VncConfig};
-use rustconn_core::protocol::{VncProtocol, detect_vnc_client, detect_vnc_viewer_name};
+use rustconn_core::protocol::{VncProtocol, detect_vnc_client, detect_vnc_viewer_name_for};
 
 use super::{SessionError, SessionState};
 use crate::embedded_vnc::{EmbeddedVncWidget, VncConfig as EmbeddedVncConfig, VncConnectionState};
@@ -138,15 +138,16 @@ impl VncSessionWidget {
         widget
     }
 
-    /// Detects if a VNC viewer is installed on the system
+    /// Detects if a VNC viewer for `config` is installed on the system
     ///
     /// Returns the name of the detected VNC viewer, or None if no viewer is found.
+    /// Connections through a repeater prefer a viewer that supports one.
     ///
     /// # Returns
     /// `Some(String)` with the viewer name, or `None` if no viewer is installed
     #[must_use]
-    pub fn detect_vnc_viewer() -> Option<String> {
-        detect_vnc_viewer_name()
+    pub fn detect_vnc_viewer(config: &VncConfig) -> Option<String> {
+        detect_vnc_viewer_name_for(config)
     }
 
     /// Returns information about the installed VNC client
@@ -282,6 +283,7 @@ impl VncSessionWidget {
             embedded_config.scale_override = config.scale_override;
             embedded_config.show_local_cursor = config.show_local_cursor;
             embedded_config.accept_certificate = config.accept_certificate;
+            embedded_config.repeater.clone_from(&config.repeater);
 
             let embedded_config = if let Some(pwd) = password {
                 embedded_config.with_password(pwd)
@@ -321,7 +323,7 @@ impl VncSessionWidget {
         password: Option<&str>,
         config: &VncConfig,
     ) -> Result<(), SessionError> {
-        let viewer = Self::detect_vnc_viewer().ok_or_else(|| {
+        let viewer = Self::detect_vnc_viewer(config).ok_or_else(|| {
             let client_info = Self::get_vnc_client_info();
             let hint = client_info.install_hint.unwrap_or_else(|| {
                 "Install TigerVNC: sudo apt install tigervnc-viewer".to_string()
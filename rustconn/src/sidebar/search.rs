@@ -31,6 +31,8 @@ pub fn create_search_help_popover() -> Popover {
         crate::i18n::i18n_f("{}: Search by IP", &["1.2.3.4"]),
         crate::i18n::i18n_f("protocol:{}: Filter by protocol", &["ssh"]),
         crate::i18n::i18n_f("group:{}: Search in group", &["name"]),
+        crate::i18n::i18n_f("host:{}: Filter by host", &["db01"]),
+        crate::i18n::i18n_f("user:{}: Filter by username", &["root"]),
     ];
 
     for line in &help_lines {
@@ -329,7 +331,7 @@ pub fn toggle_protocol_filter(
 /// Compiles a case-insensitive regex for the given search query.
 ///
 /// Returns `None` if the query is empty, is a pure protocol/operator filter
-/// (e.g. `protocol:ssh`, `protocols:rdp,vnc`, `group:name`, `#tag`),
+/// (e.g. `protocol:ssh`, `protocols:rdp,vnc`, `group:name`, `host:db01`, `#tag`),
 /// or the regex fails to compile.
 ///
 /// Only free-text portions of the query produce highlighting so that
@@ -348,6 +350,8 @@ pub fn compile_highlight_regex(query: &str) -> Option<regex::Regex> {
         || trimmed.starts_with("p:")
         || trimmed.starts_with("group:")
         || trimmed.starts_with("g:")
+        || trimmed.starts_with("host:")
+        || trimmed.starts_with("user:")
         || trimmed.starts_with('#')
         || trimmed.starts_with('@')
     {
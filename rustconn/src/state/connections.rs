@@ -219,6 +219,26 @@ impl AppState {
         self.connection_manager.get_connection(id)
     }
 
+    /// Resolves the connections to open for `id`, dependencies first
+    pub fn resolve_launch_order(
+        &self,
+        id: Uuid,
+    ) -> Result<Vec<Uuid>, rustconn_core::connection::DependencyError> {
+        self.connection_manager.resolve_launch_order(id)
+    }
+
+    /// Evaluates a connection's connect guard at the current local time
+    ///
+    /// Returns `None` if the connection does not exist.
+    pub fn check_connect_guard(
+        &self,
+        id: Uuid,
+    ) -> Option<rustconn_core::models::ConnectGuardDecision> {
+        self.connection_manager
+            .check_connect_guard(id, chrono::Local::now().naive_local())
+            .ok()
+    }
+
     /// Finds a connection by name (case-insensitive)
     ///
     /// Returns the first match. Used by CLI `--connect <name>` resolution.
@@ -346,7 +346,7 @@ impl AppState {
         // to startup which runs asynchronously after the
         // main window is presented. This avoids blocking the UI on startup.
 
-        // Decrypt 1Password / Passbolt credentials at startup (decrypt is ~instant).
+        // Decrypt 1Password / Passbolt / Vault credentials at startup (decrypt is ~instant).
         // Only for the preferred backend — lazy init principle.
         match settings.secrets.preferred_backend {
             rustconn_core::config::SecretBackendType::OnePassword => {
@@ -452,6 +452,13 @@ impl AppState {
                     }
                 }
             }
+            rustconn_core::config::SecretBackendType::Vault => {
+                if settings.secrets.vault_token_encrypted.is_some()
+                    && settings.secrets.decrypt_vault_token()
+                {
+                    tracing::info!("Vault token restored from encrypted storage");
+                }
+            }
             _ => {
                 // Bitwarden: handled in app.rs idle_add_local_once
                 // KeePass: handled above
@@ -769,6 +776,43 @@ impl AppState {
         }
     }
 
+    /// Resolves the credentials of a connection's separate RD Gateway
+    /// account synchronously
+    ///
+    /// Returns `None` when the connection has no gateway, the gateway reuses
+    /// the session credentials, or resolution fails (logged). See
+    /// [`CredentialResolver::resolve_gateway_credentials`].
+    ///
+    /// Performs a blocking vault call; GTK-thread callers MUST NOT hold any
+    /// other `AppState` borrow across it.
+    pub(crate) fn resolve_gateway_credentials_blocking(
+        &self,
+        connection: &Connection,
+    ) -> Option<rustconn_core::models::Credentials> {
+        let resolver = CredentialResolver::new(
+            Arc::new(self.secret_manager.clone()),
+            self.settings.secrets.clone(),
+        );
+        let result = with_runtime(|rt| {
+            rt.block_on(async {
+                match tokio::time::timeout(
+                    std::time::Duration::from_secs(30),
+                    resolver.resolve_gateway_credentials(connection),
+                )
+                .await
+                {
+                    Ok(result) => result.map_err(|e| e.to_string()),
+                    Err(_) => Err("timed out after 30s".to_string()),
+                }
+            })
+        })
+        .and_then(|result| result);
+        result.unwrap_or_else(|e| {
+            tracing::warn!(connection_id = %connection.id, error = %e, "Failed to resolve RD Gateway credentials");
+            None
+        })
+    }
+
     /// Internal blocking credential resolution (runs in background thread)
     ///
     /// This is extracted from `resolve_credentials` to be callable from a background
@@ -1263,6 +1307,11 @@ impl AppState {
             settings.secrets.encrypt_bitwarden_api_credentials();
         }
 
+        // Encrypt Vault token before saving if present
+        if settings.secrets.vault_token.is_some() {
+            settings.secrets.encrypt_vault_token();
+        }
+
         self.config_manager
             .save_settings(&settings)
             .map_err(|e| format!("Failed to save settings: {e}"))?;
@@ -4,7 +4,7 @@
 
 use std::collections::HashMap;
 
-use rustconn_core::cluster::Cluster;
+use rustconn_core::cluster::{Cluster, ClusterSessionStatus};
 use rustconn_core::models::{Connection, ConnectionHistoryEntry, ConnectionStatistics, Snippet};
 use rustconn_core::session::Session;
 use uuid::Uuid;
@@ -115,6 +115,39 @@ impl AppState {
         self.cluster_manager.end_session(cluster_id);
     }
 
+    /// Returns the next member of a staggered cluster start, if a slot is free
+    pub fn next_cluster_member(&mut self, cluster_id: Uuid) -> Option<Uuid> {
+        self.cluster_manager.next_to_connect(cluster_id)
+    }
+
+    /// Returns how many members of a cluster session have not been started yet
+    pub fn cluster_pending_count(&self, cluster_id: Uuid) -> usize {
+        self.cluster_manager.pending_count(cluster_id)
+    }
+
+    /// Updates the status of a member within an active cluster session
+    pub fn update_cluster_member_status(
+        &mut self,
+        cluster_id: Uuid,
+        connection_id: Uuid,
+        status: ClusterSessionStatus,
+    ) {
+        self.cluster_manager
+            .update_connection_status(cluster_id, connection_id, status);
+    }
+
+    /// Fails cluster members that have been connecting longer than `timeout`
+    pub fn expire_stalled_cluster_members(
+        &mut self,
+        cluster_id: Uuid,
+        timeout: std::time::Duration,
+    ) -> Vec<Uuid> {
+        self.cluster_manager
+            .get_session_mut(cluster_id)
+            .map(|session| session.expire_stalled(timeout))
+            .unwrap_or_default()
+    }
+
     /// Saves clusters to disk
     fn save_clusters(&self) -> Result<(), String> {
         let clusters = self.cluster_manager.clusters_to_vec();
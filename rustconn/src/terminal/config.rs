@@ -34,7 +34,7 @@ pub fn configure_terminal_with_settings(terminal: &Terminal, settings: &Terminal
     // Scrolling behavior
     terminal.set_scroll_on_output(settings.scroll_on_output);
     terminal.set_scroll_on_keystroke(settings.scroll_on_keystroke);
-    terminal.set_scrollback_lines(i64::from(settings.scrollback_lines));
+    terminal.set_scrollback_lines(settings.vte_scrollback_lines());
 
     // Input handling
     terminal.set_input_enabled(true);
@@ -51,6 +51,14 @@ pub fn configure_terminal_with_settings(terminal: &Terminal, settings: &Terminal
     // Bell
     terminal.set_audible_bell(settings.audible_bell);
 
+    // OSC 52 clipboard writes from remote programs (off unless enabled)
+    let (osc52_property, allow_osc52) = settings.vte_osc52_property();
+    if terminal.find_property(osc52_property).is_some() {
+        terminal.set_property(osc52_property, allow_osc52);
+    } else if allow_osc52 {
+        tracing::debug!("VTE does not support OSC 52; clipboard writes stay disabled");
+    }
+
     // Copy on select (X11-style auto-copy)
     if settings.copy_on_select {
         setup_copy_on_select(terminal);
@@ -72,7 +80,7 @@ pub fn configure_terminal_with_settings(terminal: &Terminal, settings: &Terminal
     // (see `setup_context_menu`).
 
     // Colors and font
-    setup_colors_with_theme(terminal, &settings.color_theme);
+    apply_theme(terminal, &settings.theme());
     setup_font_with_settings(terminal, settings);
 }
 
@@ -265,10 +273,11 @@ fn color_to_rgba(color: &Color) -> gdk::RGBA {
     gdk::RGBA::new(color.r, color.g, color.b, 1.0)
 }
 
-/// Sets up terminal colors with theme
-fn setup_colors_with_theme(terminal: &Terminal, theme_name: &str) {
-    let theme = TerminalTheme::by_name(theme_name).unwrap_or_else(TerminalTheme::dark_theme);
-
+/// Applies a theme's colors and palette to a terminal.
+///
+/// Only touches colors, so it can be called on live terminals (theme
+/// preview) without re-running the rest of the terminal setup.
+pub fn apply_theme(terminal: &Terminal, theme: &TerminalTheme) {
     let bg_color = color_to_rgba(&theme.background);
     let fg_color = color_to_rgba(&theme.foreground);
     let cursor_color = color_to_rgba(&theme.cursor);
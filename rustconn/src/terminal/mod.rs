@@ -7,12 +7,14 @@
 //!
 //! - `types` - Data structures for sessions
 //! - `config` - Terminal appearance and behavior configuration
+//! - `shell_pool` - Reusable widget scaffolding for terminal tabs
 
 mod config;
 pub mod file_drop;
 pub mod highlight_overlay;
 pub mod playback;
 mod recording;
+mod shell_pool;
 pub mod tab_container;
 mod tab_menu;
 mod types;
@@ -42,18 +44,19 @@ use vte4::prelude::*;
 /// `_vte_regex_has_multiline_compile_flag(regex)` check failed.
 const PCRE2_MULTILINE: u32 = 0x0000_0400;
 
-use rustconn_core::automation::{KeyElement, KeySequence};
+use rustconn_core::automation::{KeySequence, KeySink};
 use rustconn_core::highlight::CompiledHighlightRules;
 use rustconn_core::models::HighlightRule;
 use rustconn_core::session::recording::{RecordingMetadata, metadata_path, write_metadata};
 use rustconn_core::split::tab_groups::TabGroupManager;
 
 use crate::activity_coordinator::ActivityCoordinator;
-use crate::automation::{AutomationSession, prepare_rules_from_config};
+use crate::automation::{AutomationSession, SecretLookup, prepare_rules_from_config};
 use crate::embedded_rdp::EmbeddedRdpWidget;
 use crate::i18n::{i18n, i18n_f};
 use crate::session::{SessionState, SessionWidget, VncSessionWidget};
 use crate::terminal::highlight_overlay::HighlightOverlay;
+use crate::terminal::shell_pool::{SHELL_POOL_CAPACITY, SHELL_POOL_WARM, ShellPool, TerminalShell};
 use crate::terminal::tab_container::TabPageContainer;
 
 /// SSH connection parameters needed for remote recording file retrieval.
@@ -206,6 +209,10 @@ pub struct TerminalNotebook {
     /// SSH tunnels for jump-host connections (RDP, VNC, SPICE, Telnet).
     /// Killed automatically when the tab is closed.
     ssh_tunnels: Rc<RefCell<HashMap<Uuid, rustconn_core::ssh_tunnel::SshTunnel>>>,
+    /// Raw byte captures of serial sessions, released when the tab is closed
+    serial_captures: Rc<RefCell<HashMap<Uuid, rustconn_core::session::SerialCaptureTap>>>,
+    /// Context/namespace a Kubernetes session was switched to, used on reconnect
+    kubernetes_targets: Rc<RefCell<HashMap<Uuid, rustconn_core::KubeTarget>>>,
     /// Activity coordinator for terminal activity/silence monitoring (set after construction)
     activity_coordinator: Rc<RefCell<Option<Rc<ActivityCoordinator>>>>,
     /// Per-session tab page containers (session_id → TabPageContainer).
@@ -228,6 +235,34 @@ pub struct TerminalNotebook {
     /// Whether to show the Welcome tab when no sessions are open (issue #232).
     /// Shared with signal handlers via `Rc<Cell<bool>>`.
     show_welcome: Rc<std::cell::Cell<bool>>,
+    /// Scaffolding of closed terminal tabs, reused by new tabs
+    shell_pool: Rc<ShellPool>,
+}
+
+/// Feeds key sequence playback to a terminal, scheduling each keystroke on
+/// a glib timer after the waits that precede it
+struct ScheduledFeed {
+    terminal: Terminal,
+    delay_ms: u64,
+}
+
+impl KeySink for ScheduledFeed {
+    fn send(&mut self, bytes: &[u8]) {
+        if self.delay_ms == 0 {
+            self.terminal.feed_child(bytes);
+            return;
+        }
+        let terminal = self.terminal.clone();
+        let bytes = bytes.to_vec();
+        glib::timeout_add_local_once(std::time::Duration::from_millis(self.delay_ms), move || {
+            terminal.feed_child(&bytes);
+        });
+    }
+
+    fn wait(&mut self, duration: std::time::Duration) {
+        let ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+        self.delay_ms = self.delay_ms.saturating_add(ms);
+    }
 }
 
 impl TerminalNotebook {
@@ -319,13 +354,19 @@ impl TerminalNotebook {
             remote_recordings: RefCell::new(HashMap::new()),
             poll_cancel_tokens: Rc::new(RefCell::new(HashMap::new())),
             ssh_tunnels: Rc::new(RefCell::new(HashMap::new())),
+            serial_captures: Rc::new(RefCell::new(HashMap::new())),
+            kubernetes_targets: Rc::new(RefCell::new(HashMap::new())),
             activity_coordinator: Rc::new(RefCell::new(None)),
             tab_containers: Rc::new(RefCell::new(HashMap::new())),
             parked_in_split: Rc::new(RefCell::new(HashSet::new())),
             snippet_menu_section: Rc::new(gio::Menu::new()),
             vte_child_pids: Rc::new(RefCell::new(HashMap::new())),
             show_welcome: Rc::new(std::cell::Cell::new(show_welcome)),
+            shell_pool: Rc::new(ShellPool::new(SHELL_POOL_CAPACITY)),
         };
+        term_notebook
+            .shell_pool
+            .warm(SHELL_POOL_WARM, TerminalShell::new);
 
         term_notebook.setup_tab_view_signals();
         term_notebook.setup_tab_context_menu();
@@ -349,10 +390,13 @@ impl TerminalNotebook {
         let highlight_overlays = self.highlight_overlays.clone();
         let terminal_overlays = self.terminal_overlays.clone();
         let ssh_tunnels = self.ssh_tunnels.clone();
+        let serial_captures = self.serial_captures.clone();
+        let kubernetes_targets = self.kubernetes_targets.clone();
         let tab_containers = self.tab_containers.clone();
         let parked_in_split = self.parked_in_split.clone();
         let vte_child_pids = self.vte_child_pids.clone();
         let show_welcome_on_close = self.show_welcome.clone();
+        let shell_pool = self.shell_pool.clone();
 
         // Handle create-window signal - we must connect this to prevent the default
         // behavior which causes CRITICAL warnings. Returning None cancels the tearoff.
@@ -433,8 +477,16 @@ impl TerminalNotebook {
                 // Remove highlight overlay for this session
                 highlight_overlays.borrow_mut().remove(&session_id);
 
-                // Remove terminal overlay widget for this session
-                terminal_overlays.borrow_mut().remove(&session_id);
+                // Remove terminal overlay widget for this session and return
+                // its scaffolding to the pool once the page is gone
+                if let Some(overlay) = terminal_overlays.borrow_mut().remove(&session_id)
+                    && let Some(shell) = TerminalShell::from_overlay(&overlay)
+                {
+                    let shell_pool = shell_pool.clone();
+                    glib::idle_add_local_once(move || {
+                        shell_pool.release(shell);
+                    });
+                }
 
                 // Disconnect embedded widgets before removing
                 if let Some(widget_storage) = session_widgets.borrow_mut().remove(&session_id) {
@@ -516,6 +568,8 @@ impl TerminalNotebook {
 
                 // Drop SSH tunnel — the SshTunnel::drop impl kills the SSH process
                 ssh_tunnels.borrow_mut().remove(&session_id);
+                serial_captures.borrow_mut().remove(&session_id);
+                kubernetes_targets.borrow_mut().remove(&session_id);
 
                 // Remove tab page container
                 tab_containers.borrow_mut().remove(&session_id);
@@ -634,14 +688,28 @@ impl TerminalNotebook {
             mgr
         };
 
-        // Setup automation if configured
+        // Setup automation if configured (expect rules, or answering
+        // one-time-password prompts while connecting)
         if let Some(cfg) = automation
-            && !cfg.expect_rules.is_empty()
+            && (!cfg.expect_rules.is_empty() || cfg.answer_otp_prompts)
         {
-            let rules = prepare_rules_from_config(&cfg.expect_rules, &var_manager);
+            let rules = prepare_rules_from_config(&cfg.session_rules(), &var_manager);
 
             if !rules.is_empty() {
-                let session = AutomationSession::new(terminal.clone(), rules);
+                // Secret rules read their secret variable only when the
+                // prompt appears; keep just the secret variables for that
+                let secret_vars: Vec<rustconn_core::Variable> = global_variables
+                    .iter()
+                    .filter(|v| v.is_secret)
+                    .cloned()
+                    .collect();
+                let secret_lookup: SecretLookup = Box::new(move |name| {
+                    secret_vars
+                        .iter()
+                        .find(|v| v.name == name && !v.value.is_empty())
+                        .map(|v| secrecy::SecretString::from(v.value.clone()))
+                });
+                let session = AutomationSession::new(terminal.clone(), rules, secret_lookup);
                 self.automation_sessions
                     .borrow_mut()
                     .insert(session_id, session);
@@ -653,9 +721,7 @@ impl TerminalNotebook {
 
         // Apply per-connection theme override (if present) on top of the global theme
         if let Some(override_colors) = theme_override {
-            let base_theme = TerminalTheme::by_name(&settings.color_theme)
-                .unwrap_or_else(TerminalTheme::dark_theme);
-            config::apply_theme_override_with_base(&terminal, override_colors, &base_theme);
+            config::apply_theme_override_with_base(&terminal, override_colors, &settings.theme());
         }
 
         // VTE implements GtkScrollable natively — no ScrolledWindow needed.
@@ -663,30 +729,18 @@ impl TerminalNotebook {
         // ncurses apps (mc, htop) that rely on VTE's internal mouse handling.
         // Instead, pair VTE with a standalone GtkScrollbar connected to its
         // vadjustment — the same approach used by GNOME Terminal.
-        let terminal_row = GtkBox::new(Orientation::Horizontal, 0);
-        terminal_row.set_hexpand(true);
-        terminal_row.set_vexpand(true);
-        terminal_row.append(&terminal);
-
-        if settings.show_scrollbar {
-            let scrollbar =
-                gtk4::Scrollbar::new(Orientation::Vertical, terminal.vadjustment().as_ref());
-            terminal_row.append(&scrollbar);
-        }
-
-        // Wrap terminal_row in an Overlay so the highlight DrawingArea can
-        // be layered on top without interfering with VTE input.
-        let terminal_overlay = gtk4::Overlay::new();
-        terminal_overlay.set_child(Some(&terminal_row));
-        terminal_overlay.set_hexpand(true);
-        terminal_overlay.set_vexpand(true);
-
-        // Outer vertical container: terminal row on top, monitoring bar below.
+        //
+        // The row sits in an Overlay so the highlight DrawingArea can be
+        // layered on top without interfering with VTE input, inside the
+        // outer vertical container: terminal row on top, monitoring bar below.
         // get_session_container() returns this box so monitoring can append to it.
-        let container = GtkBox::new(Orientation::Vertical, 0);
-        container.set_hexpand(true);
-        container.set_vexpand(true);
-        container.append(&terminal_overlay);
+        let shell = self.shell_pool.acquire(TerminalShell::new);
+        shell.attach(&terminal, settings.show_scrollbar);
+        let TerminalShell {
+            container,
+            overlay: terminal_overlay,
+            ..
+        } = shell;
 
         // Right-click context menu actions installed on the terminal widget
         // so they follow it when reparented between TabView and split view.
@@ -1155,22 +1209,15 @@ impl TerminalNotebook {
         }
 
         // Inject SSH agent env: custom socket override takes priority,
-        // then OnceLock agent info, then inherited environment.
-        if let Some(custom_socket) = ssh_agent_socket {
-            env_vec.retain(|e| !e.starts_with("SSH_AUTH_SOCK="));
-            env_vec.push(glib::GString::from(format!(
-                "SSH_AUTH_SOCK={custom_socket}"
-            )));
-        } else if let Some(agent_info) = rustconn_core::sftp::get_agent_info() {
-            env_vec.retain(|e| !e.starts_with("SSH_AUTH_SOCK="));
-            env_vec.push(glib::GString::from(format!(
-                "SSH_AUTH_SOCK={}",
-                agent_info.socket_path
-            )));
-            if let Some(ref pid) = agent_info.pid {
-                env_vec.retain(|e| !e.starts_with("SSH_AGENT_PID="));
-                env_vec.push(glib::GString::from(format!("SSH_AGENT_PID={pid}")));
-            }
+        // then OnceLock agent info, then inherited environment. The
+        // monitoring collector resolves its environment the same way.
+        for (key, value) in rustconn_core::sftp::agent_env_vars(
+            ssh_agent_socket,
+            rustconn_core::sftp::get_agent_info(),
+        ) {
+            let prefix = format!("{key}=");
+            env_vec.retain(|e| !e.starts_with(&prefix));
+            env_vec.push(glib::GString::from(format!("{key}={value}")));
         }
 
         // Strip host SSH_ASKPASS — RustConn handles password input via
@@ -1301,14 +1348,7 @@ impl TerminalNotebook {
 
         // Layer caller-provided variables (override parent values)
         if let Some(user_env) = envv {
-            for e in user_env {
-                // Remove any existing entry with the same key
-                if let Some(eq_pos) = e.find('=') {
-                    let key_prefix = &e[..=eq_pos];
-                    env_vec.retain(|existing| !existing.starts_with(key_prefix));
-                }
-                env_vec.push(glib::GString::from(*e));
-            }
+            layer_env(&mut env_vec, user_env);
         }
 
         let env_refs: Vec<&str> = env_vec.iter().map(gtk4::glib::GString::as_str).collect();
@@ -1655,6 +1695,10 @@ impl TerminalNotebook {
     /// Supports configurable backspace/delete key behavior via VTE
     /// `EraseBinding`. Settings are applied directly on the terminal
     /// widget before spawning the telnet process.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "function parameters mirror upstream API or struct fields 1:1; bundling into a struct only restates the field list"
+    )]
     pub fn spawn_telnet(
         &self,
         session_id: Uuid,
@@ -1663,6 +1707,7 @@ impl TerminalNotebook {
         extra_args: &[&str],
         backspace_sends: rustconn_core::models::TelnetBackspaceSends,
         delete_sends: rustconn_core::models::TelnetDeleteSends,
+        extra_env: Option<&[&str]>,
     ) -> bool {
         use rustconn_core::models::{TelnetBackspaceSends, TelnetDeleteSends};
         use vte4::EraseBinding;
@@ -1699,16 +1744,37 @@ impl TerminalNotebook {
         argv.push(host);
         let port_str = port.to_string();
         argv.push(&port_str);
-        self.spawn_command(session_id, &argv, None, None, None)
+        self.spawn_command(session_id, &argv, extra_env, None, None)
     }
 
     /// Spawns a serial connection using picocom in the terminal tab.
     ///
     /// Builds the picocom command from the `SerialConfig` and spawns it
     /// directly in the VTE terminal (no shell wrapper).
-    pub fn spawn_serial(&self, session_id: Uuid, command: &[String]) -> bool {
+    pub fn spawn_serial(
+        &self,
+        session_id: Uuid,
+        command: &[String],
+        extra_env: Option<&[&str]>,
+    ) -> bool {
         let argv: Vec<&str> = command.iter().map(String::as_str).collect();
-        self.spawn_command(session_id, &argv, None, None, None)
+        self.spawn_command(session_id, &argv, extra_env, None, None)
+    }
+
+    /// Spawns a MOSH session in the terminal tab.
+    ///
+    /// `command` comes from `MoshProtocol::build_command`. Like
+    /// [`Self::spawn_ssh`], the SSH agent environment is injected so the
+    /// SSH handshake that bootstraps `mosh-server` can use agent keys.
+    pub fn spawn_mosh(
+        &self,
+        session_id: Uuid,
+        command: &[String],
+        ssh_agent_socket: Option<&str>,
+        extra_env: Option<&[&str]>,
+    ) -> bool {
+        let argv: Vec<&str> = command.iter().map(String::as_str).collect();
+        self.spawn_command(session_id, &argv, extra_env, None, ssh_agent_socket)
     }
 
     /// Closes a terminal tab by session ID
@@ -2272,7 +2338,7 @@ impl TerminalNotebook {
     /// Executes a key sequence on a terminal session
     ///
     /// Sends text, special keys (as VTE escape codes), and handles
-    /// `{WAIT:ms}` delays using glib timers.
+    /// `{WAIT:ms}` and `{PACE:ms}` delays using glib timers.
     pub fn execute_key_sequence(&self, session_id: Uuid, sequence: &KeySequence) {
         let Some(terminal) = self.get_terminal(session_id) else {
             tracing::warn!(%session_id, "Cannot execute key sequence: terminal not found");
@@ -2285,54 +2351,13 @@ impl TerminalNotebook {
             "Executing key sequence"
         );
 
-        // Collect elements and schedule them with cumulative delay
-        let elements: Vec<KeyElement> = sequence.elements.clone();
-        let mut cumulative_delay_ms: u64 = 0;
-
-        for element in elements {
-            if let KeyElement::Wait(ms) = &element {
-                cumulative_delay_ms += u64::from(*ms);
-            } else {
-                let terminal_clone = terminal.clone();
-                let delay = cumulative_delay_ms;
-
-                match &element {
-                    KeyElement::Text(text) => {
-                        let text = text.clone();
-                        if delay == 0 {
-                            terminal_clone.feed_child(text.as_bytes());
-                        } else {
-                            glib::timeout_add_local_once(
-                                std::time::Duration::from_millis(delay),
-                                move || {
-                                    terminal_clone.feed_child(text.as_bytes());
-                                },
-                            );
-                        }
-                    }
-                    KeyElement::SpecialKey(key) => {
-                        let bytes = key.to_vte_bytes();
-                        if delay == 0 {
-                            terminal_clone.feed_child(bytes);
-                        } else {
-                            glib::timeout_add_local_once(
-                                std::time::Duration::from_millis(delay),
-                                move || {
-                                    terminal_clone.feed_child(bytes);
-                                },
-                            );
-                        }
-                    }
-                    KeyElement::Variable(name) => {
-                        // Variables should be substituted before reaching here
-                        tracing::warn!(
-                            variable = %name,
-                            "Unresolved variable in key sequence"
-                        );
-                    }
-                    KeyElement::Wait(_) => unreachable!(),
-                }
-            }
+        let mut sink = ScheduledFeed {
+            terminal,
+            delay_ms: 0,
+        };
+        if let Err(e) = sequence.play(&mut sink) {
+            // Variables should be substituted before reaching here
+            tracing::warn!(%session_id, error = %e, "Key sequence not sent");
         }
     }
 
@@ -2354,6 +2379,60 @@ impl TerminalNotebook {
         self.ssh_tunnels.borrow_mut().insert(session_id, tunnel);
     }
 
+    /// Stores the raw byte capture of a serial session, replacing the one of
+    /// a previous connection in the same tab. Released when the tab closes.
+    pub fn store_serial_capture(
+        &self,
+        session_id: Uuid,
+        tap: rustconn_core::session::SerialCaptureTap,
+    ) {
+        self.serial_captures.borrow_mut().insert(session_id, tap);
+    }
+
+    /// Records the context/namespace a Kubernetes session was switched to
+    pub fn set_kubernetes_target(&self, session_id: Uuid, target: rustconn_core::KubeTarget) {
+        self.kubernetes_targets
+            .borrow_mut()
+            .insert(session_id, target);
+    }
+
+    /// Returns the context/namespace a Kubernetes session was switched to,
+    /// or `None` if it still runs against the saved connection settings
+    #[must_use]
+    pub fn kubernetes_target(&self, session_id: Uuid) -> Option<rustconn_core::KubeTarget> {
+        self.kubernetes_targets.borrow().get(&session_id).cloned()
+    }
+
+    /// Sets the tab title and tooltip of a session, keeping its group prefix
+    pub fn set_tab_title(&self, session_id: Uuid, title: &str) {
+        if let Some(page) = self.sessions.borrow().get(&session_id) {
+            let group = self
+                .session_info
+                .borrow()
+                .get(&session_id)
+                .and_then(|info| info.tab_group.clone());
+            if let Some(group) = group {
+                page.set_title(&format!("[{group}] {title}"));
+                page.set_tooltip(&format!("{title}\n[{group}]"));
+            } else {
+                page.set_title(title);
+                page.set_tooltip(title);
+            }
+        }
+    }
+
+    /// Sends `SIGHUP` to the process group running in a session's terminal
+    ///
+    /// Used before spawning a replacement process in the same tab. VTE only
+    /// reports `child-exited` for the most recently spawned child, so the
+    /// old process exiting does not mark the tab disconnected.
+    pub fn hang_up_child(&self, session_id: Uuid) {
+        if let Some(pid) = self.vte_child_pids.borrow_mut().remove(&session_id) {
+            let pgid = nix::unistd::Pid::from_raw(-pid);
+            let _ = nix::sys::signal::kill(pgid, nix::sys::signal::Signal::SIGHUP);
+        }
+    }
+
     /// Gets the page container widget for a session
     ///
     /// Returns the `GtkBox` that holds the terminal.
@@ -2762,6 +2841,18 @@ impl TerminalNotebook {
         }
     }
 
+    /// Applies a color theme to every open terminal without touching the
+    /// other terminal settings.
+    ///
+    /// Used for live theme preview; per-connection overrides must be
+    /// re-applied afterwards with [`Self::reapply_theme_overrides`].
+    pub fn apply_theme(&self, theme: &TerminalTheme) {
+        let terminals = self.terminals.borrow();
+        for terminal in terminals.values() {
+            config::apply_theme(terminal, theme);
+        }
+    }
+
     /// Re-applies per-connection theme overrides after global settings change.
     ///
     /// When global terminal settings are applied, they overwrite any
@@ -2772,8 +2863,7 @@ impl TerminalNotebook {
     where
         F: Fn(Uuid) -> Option<rustconn_core::models::ConnectionThemeOverride>,
     {
-        let base_theme =
-            TerminalTheme::by_name(theme_name).unwrap_or_else(TerminalTheme::dark_theme);
+        let base_theme = TerminalTheme::resolve(theme_name);
         let terminals = self.terminals.borrow();
         let session_info = self.session_info.borrow();
         for (session_id, terminal) in terminals.iter() {
@@ -2819,22 +2909,15 @@ impl TerminalNotebook {
         // Rebuild a fresh single-terminal content box and switch TabPageContainer
         // back to single mode. This correctly handles the case where the tab was
         // previously in split mode (TabPageContainer contained the split bridge widget).
-        let container = GtkBox::new(Orientation::Vertical, 0);
-        container.set_hexpand(true);
-        container.set_vexpand(true);
-
-        // Re-wrap terminal with scrollbar (matching create_terminal_tab_with_settings layout)
-        let terminal_row = GtkBox::new(Orientation::Horizontal, 0);
-        terminal_row.set_hexpand(true);
-        terminal_row.set_vexpand(true);
-        terminal_row.append(&terminal);
-
-        // Re-create overlay for highlight support
-        let terminal_overlay = gtk4::Overlay::new();
-        terminal_overlay.set_child(Some(&terminal_row));
-        terminal_overlay.set_hexpand(true);
-        terminal_overlay.set_vexpand(true);
-        container.append(&terminal_overlay);
+        // The shell matches the create_terminal_tab_with_settings layout,
+        // including the overlay for highlight support.
+        let shell = self.shell_pool.acquire(TerminalShell::new);
+        shell.attach(&terminal, false);
+        let TerminalShell {
+            container,
+            overlay: terminal_overlay,
+            ..
+        } = shell;
 
         // Update terminal overlay tracking
         self.terminal_overlays
@@ -3288,6 +3371,11 @@ impl TerminalNotebook {
             .insert(connection_id, cluster_id);
     }
 
+    /// Returns true if `connection_id` is still waiting for its cluster tab
+    pub fn is_cluster_pending(&self, connection_id: Uuid) -> bool {
+        self.cluster_pending.borrow().contains_key(&connection_id)
+    }
+
     /// Resolves a pending cluster registration for a freshly created session.
     ///
     /// Called internally by `create_terminal_tab_with_settings`. If the
@@ -3360,6 +3448,55 @@ fn cursor_line_text(terminal: &Terminal) -> Option<String> {
     })
 }
 
+/// Layers `NAME=value` entries over an environment
+///
+/// An entry replaces any existing entry with the same name; later entries
+/// win over earlier ones.
+fn layer_env(env: &mut Vec<glib::GString>, overrides: &[&str]) {
+    for e in overrides {
+        // Remove any existing entry with the same key
+        if let Some(eq_pos) = e.find('=') {
+            let key_prefix = &e[..=eq_pos];
+            env.retain(|existing| !existing.starts_with(key_prefix));
+        }
+        env.push(glib::GString::from(*e));
+    }
+}
+
+#[cfg(test)]
+mod env_tests {
+    use gtk4::glib;
+
+    use super::layer_env;
+
+    #[test]
+    fn connection_env_overrides_parent_values() {
+        let mut env: Vec<glib::GString> = [
+            "LANG=en_US.UTF-8",
+            "LANGUAGE=en",
+            "TERM=xterm-256color",
+            "HOME=/home/u",
+        ]
+        .into_iter()
+        .map(glib::GString::from)
+        .collect();
+        layer_env(&mut env, &["LANG=C.UTF-8", "KUBECONFIG=/home/u/.kube/prod"]);
+
+        // LANGUAGE shares a prefix with LANG but is a different variable
+        let env: Vec<&str> = env.iter().map(glib::GString::as_str).collect();
+        assert_eq!(
+            env,
+            [
+                "LANGUAGE=en",
+                "TERM=xterm-256color",
+                "HOME=/home/u",
+                "LANG=C.UTF-8",
+                "KUBECONFIG=/home/u/.kube/prod",
+            ]
+        );
+    }
+}
+
 #[cfg(test)]
 mod split_eligibility_tests {
     use std::cell::RefCell;
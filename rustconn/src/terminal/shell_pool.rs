@@ -0,0 +1,144 @@
+//! Reusable widget scaffolding for terminal tabs
+//!
+//! Every VTE tab wraps its terminal in the same three widgets: a horizontal
+//! row (terminal + scrollbar), an overlay for the highlight layer, and the
+//! vertical content box that also takes the monitoring bar. Building them
+//! for each tab and freeing them on close makes rapid tab churn janky, so
+//! closed tabs return their [`TerminalShell`] to an [`ObjectPool`] that is
+//! warmed when the notebook is created.
+//!
+//! The VTE terminal itself is not pooled: its signal handlers capture the
+//! session they belong to and it holds the session's scrollback, so it is
+//! dropped with the session. [`TerminalShell::reset`] removes it together
+//! with every other per-session child, which satisfies the pool's reset
+//! contract because no signal handlers are ever connected to the shell
+//! widgets themselves.
+
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, Orientation};
+use rustconn_core::{ObjectPool, Poolable};
+use vte4::Terminal;
+
+/// Idle shells kept for reuse
+pub(super) const SHELL_POOL_CAPACITY: usize = 8;
+
+/// Shells built when the notebook is created
+pub(super) const SHELL_POOL_WARM: usize = 2;
+
+/// Pool of terminal tab scaffolding
+pub(super) type ShellPool = ObjectPool<TerminalShell>;
+
+/// Content box, overlay and terminal row of one VTE tab
+#[derive(Debug)]
+pub(super) struct TerminalShell {
+    /// Outer vertical box: terminal overlay, then monitoring bar
+    pub container: GtkBox,
+    /// Overlay layering the highlight drawing area over the terminal row
+    pub overlay: gtk4::Overlay,
+    /// Horizontal box holding the terminal and its scrollbar
+    pub row: GtkBox,
+}
+
+impl TerminalShell {
+    /// Builds an empty shell
+    pub fn new() -> Self {
+        let row = GtkBox::new(Orientation::Horizontal, 0);
+        row.set_hexpand(true);
+        row.set_vexpand(true);
+
+        let overlay = gtk4::Overlay::new();
+        overlay.set_child(Some(&row));
+        overlay.set_hexpand(true);
+        overlay.set_vexpand(true);
+
+        let container = GtkBox::new(Orientation::Vertical, 0);
+        container.set_hexpand(true);
+        container.set_vexpand(true);
+        container.append(&overlay);
+
+        Self {
+            container,
+            overlay,
+            row,
+        }
+    }
+
+    /// Recovers the shell around a session's terminal overlay
+    ///
+    /// Returns `None` if the overlay is no longer wrapped as built by
+    /// [`Self::new`], e.g. after the session was moved into a split.
+    pub fn from_overlay(overlay: &gtk4::Overlay) -> Option<Self> {
+        let row = overlay.child()?.downcast::<GtkBox>().ok()?;
+        let container = overlay.parent()?.downcast::<GtkBox>().ok()?;
+        Some(Self {
+            container,
+            overlay: overlay.clone(),
+            row,
+        })
+    }
+
+    /// Puts `terminal` into the row, followed by a scrollbar if requested
+    pub fn attach(&self, terminal: &Terminal, show_scrollbar: bool) {
+        self.row.append(terminal);
+        if show_scrollbar {
+            let scrollbar =
+                gtk4::Scrollbar::new(Orientation::Vertical, terminal.vadjustment().as_ref());
+            self.row.append(&scrollbar);
+        }
+    }
+}
+
+impl Poolable for TerminalShell {
+    /// Detaches the shell from its tab and removes every per-session child
+    ///
+    /// Fails, so the shell is dropped, if the widgets are no longer nested
+    /// as built by [`TerminalShell::new`].
+    fn reset(&mut self) -> bool {
+        if let Some(parent) = self.container.parent() {
+            let Some(parent) = parent.downcast_ref::<GtkBox>() else {
+                return false;
+            };
+            parent.remove(&self.container);
+        }
+        if self.overlay.parent().as_ref() != Some(self.container.upcast_ref::<gtk4::Widget>())
+            || self.overlay.child().as_ref() != Some(self.row.upcast_ref::<gtk4::Widget>())
+        {
+            return false;
+        }
+
+        // Monitoring bar, reconnect banner
+        remove_children_except(&self.container, Some(self.overlay.upcast_ref()));
+        // Highlight drawing area
+        let mut child = self.overlay.first_child();
+        while let Some(widget) = child {
+            child = widget.next_sibling();
+            if widget != *self.row.upcast_ref::<gtk4::Widget>() {
+                self.overlay.remove_overlay(&widget);
+            }
+        }
+        // Terminal and scrollbar; the terminal goes with its scrollback
+        remove_children_except(&self.row, None);
+
+        for widget in [
+            self.container.upcast_ref::<gtk4::Widget>(),
+            self.overlay.upcast_ref(),
+            self.row.upcast_ref(),
+        ] {
+            widget.set_visible(true);
+            widget.set_sensitive(true);
+            widget.set_css_classes(&[]);
+        }
+        true
+    }
+}
+
+/// Removes every child of `parent` other than `keep`
+fn remove_children_except(parent: &GtkBox, keep: Option<&gtk4::Widget>) {
+    let mut child = parent.first_child();
+    while let Some(widget) = child {
+        child = widget.next_sibling();
+        if Some(&widget) != keep {
+            parent.remove(&widget);
+        }
+    }
+}
@@ -31,7 +31,7 @@ impl TerminalNotebook {
             *context_page_setup.borrow_mut() = page.cloned();
 
             // Determine the current monitor mode and group membership for the right-clicked tab
-            let (current_mode, has_group, is_pinned, any_groups_exist) = page
+            let (current_mode, has_group, is_pinned, any_groups_exist, kubernetes_session) = page
                 .map(|page| {
                     let sessions = sessions_for_menu.borrow();
                     let session_id = sessions.iter().find(|(_, p)| *p == page).map(|(id, _)| *id);
@@ -47,9 +47,14 @@ impl TerminalNotebook {
                     // Check if ANY tab has a group assigned (for showing group-related actions)
                     let groups_exist = info_ref.values().any(|i| i.tab_group.is_some());
                     let pinned = page.is_pinned();
-                    (mode, in_group, pinned, groups_exist)
+                    let kubernetes = session_id.filter(|sid| {
+                        info_ref
+                            .get(sid)
+                            .is_some_and(|i| i.protocol == "kubernetes")
+                    });
+                    (mode, in_group, pinned, groups_exist, kubernetes)
                 })
-                .unwrap_or((None, false, false, false));
+                .unwrap_or((None, false, false, false, None));
 
             // Mutate the existing menu in-place (clear + re-populate)
             menu_for_setup.remove_all();
@@ -59,6 +64,7 @@ impl TerminalNotebook {
                 has_group,
                 is_pinned,
                 any_groups_exist,
+                kubernetes_session,
             );
         });
 
@@ -562,6 +568,7 @@ impl TerminalNotebook {
         has_group: bool,
         is_pinned: bool,
         any_groups_exist: bool,
+        kubernetes_session: Option<Uuid>,
     ) {
         use rustconn_core::activity_monitor::MonitorMode;
 
@@ -593,6 +600,16 @@ impl TerminalNotebook {
         monitor_section.append(Some(&label), Some("tab.cycle-monitor"));
         menu.append_section(None, &monitor_section);
 
+        // Kubernetes section — handled by the window, which owns the app state
+        if let Some(session_id) = kubernetes_session {
+            let kubernetes_section = gio::Menu::new();
+            kubernetes_section.append(
+                Some(&i18n("Switch Context/Namespace...")),
+                Some(&format!("win.kubernetes-switch::{session_id}")),
+            );
+            menu.append_section(None, &kubernetes_section);
+        }
+
         // Close section — minimal by default, expanded when groups exist
         let close_section = gio::Menu::new();
         close_section.append(Some(&i18n("Close Tab")), Some("tab.close"));
@@ -429,7 +429,8 @@ pub fn rename_vault_credential(
             SecretBackendType::Bitwarden
             | SecretBackendType::OnePassword
             | SecretBackendType::Passbolt
-            | SecretBackendType::Pass => {
+            | SecretBackendType::Pass
+            | SecretBackendType::Vault => {
                 // These backends use "rustconn/{name}" format
                 let old_identifier = if old_name.trim().is_empty() {
                     &updated_conn.host
@@ -944,6 +945,18 @@ fn retrieve_by_vault_entry_name(
                                 .map(|p| zeroize::Zeroizing::new(p.to_string()))
                         }))
                     }
+                    SecretBackendType::Vault => {
+                        let backend =
+                            rustconn_core::secret::VaultBackend::from_secret_settings(settings);
+                        let creds = backend
+                            .retrieve(entry_name)
+                            .await
+                            .map_err(|e| format!("{e}"))?;
+                        Ok(creds.and_then(|c| {
+                            c.expose_password()
+                                .map(|p| zeroize::Zeroizing::new(p.to_string()))
+                        }))
+                    }
                     _ => {
                         // System keyring — lookup by entry_name as attribute.
                         // macOS uses the Keychain; LibSecretBackend (oo7) is
@@ -1322,6 +1335,9 @@ pub fn dispatch_vault_op(
                 // lookup key, same as the other app-managed backends.
                 std::sync::Arc::new(rustconn_core::secret::EncryptedFileBackend::new())
             }
+            SecretBackendType::Vault => std::sync::Arc::new(
+                rustconn_core::secret::VaultBackend::from_secret_settings(secret_settings),
+            ),
         };
 
         match op {
@@ -1410,6 +1426,7 @@ pub fn select_backend_for_load(
         // EncryptedFile is a flat-key backend; identity mapping mirrors the
         // other app-managed backends above. (Allowed flat-key wiring for 2.4.)
         SecretBackendType::EncryptedFile => SecretBackendType::EncryptedFile,
+        SecretBackendType::Vault => SecretBackendType::Vault,
     }
 }
 
@@ -1492,6 +1509,12 @@ mod tests {
         assert_eq!(select_backend_for_load(&s), SecretBackendType::Pass);
     }
 
+    #[test]
+    fn select_backend_vault() {
+        let s = default_secret_settings(SecretBackendType::Vault);
+        assert_eq!(select_backend_for_load(&s), SecretBackendType::Vault);
+    }
+
     #[test]
     fn select_backend_libsecret() {
         let s = default_secret_settings(SecretBackendType::LibSecret);
@@ -3,9 +3,14 @@
 //! This module contains methods for managing connection clusters,
 //! including cluster dialogs and related functionality.
 
+use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::Duration;
 
+use gtk4::glib;
+use gtk4::glib::ControlFlow;
 use gtk4::prelude::*;
+use rustconn_core::cluster::ClusterSessionStatus;
 use uuid::Uuid;
 
 use super::MainWindow;
@@ -302,9 +307,13 @@ fn connect_cluster(
     cluster_id: Uuid,
 ) {
     // Get cluster info
-    let (connection_ids, cluster_name) = if let Ok(state_ref) = state.try_borrow() {
+    let (connection_ids, cluster_name, staggered) = if let Ok(state_ref) = state.try_borrow() {
         if let Some(cluster) = state_ref.get_cluster(cluster_id) {
-            (cluster.connection_ids.clone(), cluster.name.clone())
+            (
+                cluster.connection_ids.clone(),
+                cluster.name.clone(),
+                cluster.is_staggered().then(|| cluster.connect_delay()),
+            )
         } else {
             return;
         }
@@ -338,6 +347,19 @@ fn connect_cluster(
         notebook.mark_cluster_pending(cluster_id, *conn_id);
     }
 
+    if let Some(delay) = staggered {
+        connect_cluster_staggered(
+            state,
+            notebook,
+            sidebar,
+            monitoring,
+            cluster_id,
+            cluster_name,
+            delay,
+        );
+        return;
+    }
+
     // Kick off each connection. We don't care whether `start_connection`
     // returns Started, Pending or Failed — registration is driven by the
     // callback in `create_terminal_tab_with_settings`.
@@ -358,6 +380,124 @@ fn connect_cluster(
     );
 }
 
+/// How long a staggered member may stay in `Connecting` before its slot is freed
+const STAGGER_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Poll interval used when a staggered cluster has a cap but no delay
+const STAGGER_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Starts cluster members in throttled waves.
+///
+/// Members are handed out by the core `ClusterSession` in cluster order:
+/// with a delay configured one member is started per tick, otherwise every
+/// free in-flight slot is filled on each tick. A member frees its slot once
+/// its terminal tab appears (the notebook resolves the pending registration),
+/// when `start_connection` fails, or after `STAGGER_CONNECT_TIMEOUT`.
+fn connect_cluster_staggered(
+    state: &SharedAppState,
+    notebook: &SharedNotebook,
+    sidebar: &SharedSidebar,
+    monitoring: &super::types::SharedMonitoring,
+    cluster_id: Uuid,
+    cluster_name: String,
+    delay: Duration,
+) {
+    let total = state
+        .try_borrow()
+        .map_or(0, |s| s.cluster_pending_count(cluster_id));
+    crate::toast::show_info_toast_on_active_window(&i18n_f(
+        "Connecting {} cluster members in waves",
+        &[&total.to_string()],
+    ));
+
+    let state = state.clone();
+    let notebook = notebook.clone();
+    let sidebar = sidebar.clone();
+    let monitoring = monitoring.clone();
+    let in_flight: Rc<RefCell<Vec<Uuid>>> = Rc::new(RefCell::new(Vec::new()));
+    let interval = if delay.is_zero() {
+        STAGGER_POLL_INTERVAL
+    } else {
+        delay
+    };
+
+    glib::timeout_add_local(interval, move || {
+        // Members whose tab has appeared are connected
+        in_flight.borrow_mut().retain(|conn_id| {
+            if notebook.is_cluster_pending(*conn_id) {
+                return true;
+            }
+            if let Ok(mut state_mut) = state.try_borrow_mut() {
+                state_mut.update_cluster_member_status(
+                    cluster_id,
+                    *conn_id,
+                    ClusterSessionStatus::Connected,
+                );
+            }
+            false
+        });
+
+        if let Ok(mut state_mut) = state.try_borrow_mut() {
+            for conn_id in
+                state_mut.expire_stalled_cluster_members(cluster_id, STAGGER_CONNECT_TIMEOUT)
+            {
+                tracing::warn!(cluster = %cluster_name, %conn_id, "Cluster member timed out");
+                in_flight.borrow_mut().retain(|id| *id != conn_id);
+            }
+        }
+
+        loop {
+            let next = state
+                .try_borrow_mut()
+                .ok()
+                .and_then(|mut s| s.next_cluster_member(cluster_id));
+            let Some(conn_id) = next else {
+                break;
+            };
+
+            let result =
+                MainWindow::start_connection(&state, &notebook, &sidebar, &monitoring, conn_id);
+            let status = match result {
+                super::types::ConnectionStartResult::Started(_) => {
+                    Some(ClusterSessionStatus::Connected)
+                }
+                super::types::ConnectionStartResult::Failed => Some(ClusterSessionStatus::Error),
+                super::types::ConnectionStartResult::Pending => {
+                    in_flight.borrow_mut().push(conn_id);
+                    None
+                }
+            };
+            if let Some(status) = status
+                && let Ok(mut state_mut) = state.try_borrow_mut()
+            {
+                state_mut.update_cluster_member_status(cluster_id, conn_id, status);
+            }
+
+            // With a delay, start a single member per tick
+            if !delay.is_zero() {
+                break;
+            }
+        }
+
+        let pending = state
+            .try_borrow()
+            .map_or(0, |s| s.cluster_pending_count(cluster_id));
+        tracing::debug!(
+            cluster = %cluster_name,
+            pending,
+            in_flight = in_flight.borrow().len(),
+            "Staggered cluster connect progress"
+        );
+
+        if pending == 0 && in_flight.borrow().is_empty() {
+            tracing::info!(cluster = %cluster_name, "Staggered cluster connect finished");
+            ControlFlow::Break
+        } else {
+            ControlFlow::Continue
+        }
+    });
+}
+
 /// Disconnects all connections in a cluster
 fn disconnect_cluster(notebook: &SharedNotebook, cluster_id: Uuid) {
     let session_ids = notebook.get_cluster_sessions(cluster_id);
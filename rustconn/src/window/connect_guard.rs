@@ -0,0 +1,87 @@
+//! Connect guard enforcement before a connection is launched
+//!
+//! Evaluates the connection's `connect_guard` (allowed time windows and
+//! confirmation) and only hands off to the launch flow when it allows it.
+
+use rustconn_core::models::ConnectGuardDecision;
+
+use super::*;
+use crate::i18n::{i18n, i18n_f};
+
+impl MainWindow {
+    /// Applies the connection's connect guard, then calls `proceed` if allowed
+    ///
+    /// `Warn` shows a warning toast and proceeds, `Block` shows an error toast,
+    /// and `Confirm` asks in a dialog (with the host name typed back when the
+    /// guard requires it) before proceeding.
+    pub(crate) fn with_connect_guard(
+        state: &SharedAppState,
+        connection_id: Uuid,
+        proceed: impl FnOnce() + 'static,
+    ) {
+        let decision = state
+            .try_borrow()
+            .ok()
+            .and_then(|s| s.check_connect_guard(connection_id));
+        match decision {
+            None | Some(ConnectGuardDecision::Proceed) => proceed(),
+            Some(ConnectGuardDecision::Warn { reason }) => {
+                crate::toast::show_warning_toast_on_active_window(&reason);
+                proceed();
+            }
+            Some(ConnectGuardDecision::Block { reason }) => {
+                tracing::info!(%connection_id, "Connect guard blocked connection");
+                crate::toast::show_error_toast_on_active_window(&reason);
+            }
+            Some(ConnectGuardDecision::Confirm {
+                message,
+                warning,
+                typed_hostname,
+            }) => Self::show_connect_guard_dialog(&message, warning, typed_hostname, proceed),
+        }
+    }
+
+    /// Shows the confirmation dialog for a guarded connection
+    fn show_connect_guard_dialog(
+        message: &str,
+        warning: Option<String>,
+        typed_hostname: Option<String>,
+        proceed: impl FnOnce() + 'static,
+    ) {
+        let body = warning.map_or_else(|| message.to_string(), |w| format!("{message}\n\n{w}"));
+        let alert = adw::AlertDialog::new(Some(&i18n("Confirm Connection")), Some(&body));
+        alert.add_response("cancel", &i18n("Cancel"));
+        alert.add_response("connect", &i18n("Connect"));
+        alert.set_response_appearance("connect", adw::ResponseAppearance::Destructive);
+        alert.set_default_response(Some("cancel"));
+        alert.set_close_response("cancel");
+
+        if let Some(host) = typed_hostname {
+            let entry = gtk4::Entry::builder()
+                .placeholder_text(i18n_f("Type {} to confirm", &[&host]))
+                .build();
+            alert.set_response_enabled("connect", false);
+            let alert_weak = alert.downgrade();
+            entry.connect_changed(move |entry| {
+                if let Some(alert) = alert_weak.upgrade() {
+                    alert.set_response_enabled("connect", entry.text() == host.as_str());
+                }
+            });
+            alert.set_extra_child(Some(&entry));
+        }
+
+        let proceed = RefCell::new(Some(proceed));
+        alert.connect_response(None, move |_, response| {
+            if response == "connect"
+                && let Some(proceed) = proceed.borrow_mut().take()
+            {
+                proceed();
+            }
+        });
+
+        let window = gio::Application::default()
+            .and_then(|app| app.downcast::<gtk4::Application>().ok())
+            .and_then(|app| app.active_window());
+        alert.present(window.as_ref());
+    }
+}
@@ -140,7 +140,7 @@ pub fn show_new_connection_dialog_internal(
 
     // If template provided, pre-populate the dialog
     if let Some(ref tmpl) = template {
-        let connection = tmpl.apply(None);
+        let connection = super::templates::connection_from_template(&state, tmpl);
         dialog.set_connection(&connection);
         dialog
             .dialog()
@@ -779,6 +779,23 @@ pub fn show_new_group_dialog_with_parent(
                                     })
                                 })?
                             }
+                            SecretBackendType::Vault => {
+                                let backend =
+                                    rustconn_core::secret::VaultBackend::from_secret_settings(
+                                        &secret_settings,
+                                    );
+                                crate::async_utils::with_runtime(|rt| {
+                                    rt.block_on(async {
+                                        tokio::time::timeout(
+                                            VAULT_RETRIEVE_TIMEOUT,
+                                            backend.retrieve(&lookup_key),
+                                        )
+                                        .await
+                                        .map_err(|_| "Vault retrieve timed out".to_string())?
+                                        .map_err(|e| format!("{e}"))
+                                    })
+                                })?
+                            }
                         }
                     },
                     move |result: Result<Option<rustconn_core::models::Credentials>, String>| {
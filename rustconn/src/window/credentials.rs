@@ -15,6 +15,7 @@ impl MainWindow {
     /// 4. Prompt user if no credentials found and required
     ///
     /// Uses async credential resolution to avoid blocking the GTK main thread.
+    /// The connection's connect guard is applied first (see `with_connect_guard`).
     pub(crate) fn start_connection_with_credential_resolution(
         state: SharedAppState,
         notebook: SharedNotebook,
@@ -23,6 +24,30 @@ impl MainWindow {
         monitoring: types::SharedMonitoring,
         connection_id: Uuid,
         activity: Option<types::SharedActivityCoordinator>,
+    ) {
+        let guard_state = state.clone();
+        Self::with_connect_guard(&guard_state, connection_id, move || {
+            Self::resolve_credentials_and_start(
+                state,
+                notebook,
+                split_view,
+                sidebar,
+                monitoring,
+                connection_id,
+                activity,
+            );
+        });
+    }
+
+    /// Credential resolution and launch, after the connect guard allowed it
+    fn resolve_credentials_and_start(
+        state: SharedAppState,
+        notebook: SharedNotebook,
+        split_view: SharedSplitView,
+        sidebar: SharedSidebar,
+        monitoring: types::SharedMonitoring,
+        connection_id: Uuid,
+        activity: Option<types::SharedActivityCoordinator>,
     ) {
         // Acquire busy guard — spinner shows while connection is in progress.
         // The guard is moved into closures so it stays alive until the
@@ -280,6 +305,10 @@ impl MainWindow {
                                     names.push("macOS Keychain".to_string());
                                     types.push(preferred);
                                 }
+                                rustconn_core::config::SecretBackendType::Vault => {
+                                    names.push("HashiCorp Vault".to_string());
+                                    types.push(preferred);
+                                }
                                 rustconn_core::config::SecretBackendType::LibSecret => {}
                                 rustconn_core::config::SecretBackendType::EncryptedFile => {
                                     // App-managed encrypted file: a real,
@@ -569,7 +598,7 @@ impl MainWindow {
         cached_credentials: Option<(String, zeroize::Zeroizing<String>, String)>,
     ) {
         // Check if port check is needed BEFORE prompting for credentials
-        let (should_check, host, port, timeout) = {
+        let (should_check, host, port, proxy, tailscale, timeout) = {
             let state_ref = state.borrow();
             let settings = state_ref.settings();
             let conn = state_ref.get_connection(connection_id);
@@ -587,6 +616,8 @@ impl MainWindow {
                     should,
                     conn.host.clone(),
                     conn.port,
+                    conn.proxy.clone(),
+                    conn.probes_via_tailscale(),
                     settings.connection.port_check_timeout_secs,
                 )
             } else {
@@ -602,7 +633,14 @@ impl MainWindow {
             let sidebar_clone = sidebar.clone();
 
             crate::utils::spawn_blocking_with_callback(
-                move || rustconn_core::check_port(&host, port, timeout),
+                move || {
+                    rustconn_core::connection::check_port_with_proxy(
+                        proxy.as_ref(),
+                        &rustconn_core::connection::probe_host(&host, tailscale),
+                        port,
+                        timeout,
+                    )
+                },
                 move |result| {
                     match result {
                         Ok(_) => {
@@ -790,7 +828,7 @@ impl MainWindow {
         cached_credentials: Option<(String, zeroize::Zeroizing<String>, String)>,
     ) {
         // Check if port check is needed BEFORE prompting for credentials
-        let (should_check, host, port, timeout) = {
+        let (should_check, host, port, proxy, tailscale, timeout) = {
             let state_ref = state.borrow();
             let settings = state_ref.settings();
             let conn = state_ref.get_connection(connection_id);
@@ -800,6 +838,8 @@ impl MainWindow {
                     should,
                     conn.host.clone(),
                     conn.port,
+                    conn.proxy.clone(),
+                    conn.probes_via_tailscale(),
                     settings.connection.port_check_timeout_secs,
                 )
             } else {
@@ -816,7 +856,14 @@ impl MainWindow {
             let monitoring_clone = monitoring.clone();
 
             crate::utils::spawn_blocking_with_callback(
-                move || rustconn_core::check_port(&host, port, timeout),
+                move || {
+                    rustconn_core::connection::check_port_with_proxy(
+                        proxy.as_ref(),
+                        &rustconn_core::connection::probe_host(&host, tailscale),
+                        port,
+                        timeout,
+                    )
+                },
                 move |result| {
                     match result {
                         Ok(_) => {
@@ -627,7 +627,7 @@ impl MainWindow {
         let toast_clone = self.toast_overlay.clone();
         let notebook_clone = self.terminal_notebook.clone();
         let split_view_clone = self.split_view.clone();
-        sftp_action.connect_activate(move |_, _| {
+        sftp_action.connect_activate(move |action, _| {
             let Some(item) = sidebar_clone.get_selected_item() else {
                 return;
             };
@@ -666,8 +666,22 @@ impl MainWindow {
             );
 
             if use_mc {
+                // In Flatpak, the downloads folder is picked through the
+                // portal first; the action runs again once it is granted
+                let parent = notebook_clone
+                    .widget()
+                    .root()
+                    .and_downcast::<gtk4::Window>();
+                let retry = action.clone();
+                let Some(downloads) =
+                    sftp_downloads_dir(parent.as_ref(), move || retry.activate(None))
+                else {
+                    return;
+                };
+
                 // Open mc in a local shell tab with SFTP panel
-                let mc_cmd = rustconn_core::sftp::build_mc_sftp_command(conn, &groups);
+                let mc_cmd =
+                    rustconn_core::sftp::build_mc_sftp_command_in(conn, &groups, &downloads);
                 let conn_name = conn.name.clone();
                 let terminal_settings = state_ref.settings().terminal.clone();
                 drop(state_ref);
@@ -759,8 +773,6 @@ impl MainWindow {
                     &[],
                 );
 
-                let downloads = rustconn_core::sftp::get_downloads_dir();
-
                 // Delay mc spawn slightly so GTK allocates the VTE widget's
                 // final size before mc reads terminal dimensions at startup.
                 let nb = notebook_clone.clone();
@@ -1153,7 +1165,7 @@ impl MainWindow {
         connection_id: Uuid,
     ) {
         // Pre-connect SSH port check before opening SFTP
-        let (should_check, host, port, timeout) = {
+        let (should_check, host, port, proxy, tailscale, timeout) = {
             let state_ref = state.borrow();
             let Some(conn) = state_ref.get_connection(connection_id) else {
                 return;
@@ -1164,6 +1176,8 @@ impl MainWindow {
                 should,
                 conn.host.clone(),
                 conn.port,
+                conn.proxy.clone(),
+                conn.probes_via_tailscale(),
                 settings.connection.port_check_timeout_secs,
             )
         };
@@ -1175,7 +1189,14 @@ impl MainWindow {
             let split_view_clone = split_view.cloned();
 
             crate::utils::spawn_blocking_with_callback(
-                move || rustconn_core::check_port(&host, port, timeout),
+                move || {
+                    rustconn_core::connection::check_port_with_proxy(
+                        proxy.as_ref(),
+                        &rustconn_core::connection::probe_host(&host, tailscale),
+                        port,
+                        timeout,
+                    )
+                },
                 move |result| match result {
                     Ok(_) => {
                         Self::handle_sftp_connect_internal(
@@ -1237,7 +1258,27 @@ impl MainWindow {
             .and_then(|p| rustconn_core::resolve_key_path(&p));
 
         if use_mc {
-            let mc_cmd = rustconn_core::sftp::build_mc_sftp_command(conn, &groups);
+            let parent = notebook.widget().root().and_downcast::<gtk4::Window>();
+            let retry = {
+                let state = state.clone();
+                let notebook = notebook.clone();
+                let sidebar = sidebar.cloned();
+                let split_view = split_view.cloned();
+                move || {
+                    Self::handle_sftp_connect_internal(
+                        &state,
+                        &notebook,
+                        sidebar.as_ref(),
+                        split_view.as_ref(),
+                        connection_id,
+                    );
+                }
+            };
+            let Some(downloads) = sftp_downloads_dir(parent.as_ref(), retry) else {
+                return;
+            };
+
+            let mc_cmd = rustconn_core::sftp::build_mc_sftp_command_in(conn, &groups, &downloads);
             let conn_name = conn.name.clone();
             let terminal_settings = state_ref.settings().terminal.clone();
             drop(state_ref);
@@ -1297,8 +1338,6 @@ impl MainWindow {
                 &[],
             );
 
-            let downloads = rustconn_core::sftp::get_downloads_dir();
-
             // Delay mc spawn slightly so GTK allocates the VTE widget's
             // final size before mc reads terminal dimensions at startup.
             let notebook_clone = notebook.clone();
@@ -1414,3 +1453,40 @@ impl MainWindow {
         }
     }
 }
+
+thread_local! {
+    /// Folder granted through the file chooser portal for SFTP downloads
+    /// in Flatpak; asked for once per run
+    static SFTP_DOWNLOADS_DIR: RefCell<Option<std::path::PathBuf>> = const { RefCell::new(None) };
+}
+
+/// Returns the local directory for an mc SFTP session
+///
+/// In Flatpak, files only reach the host in a folder granted through the
+/// document portal, so the first session asks for one with the portal
+/// file chooser and returns `None`; `retry` runs once a folder is picked.
+fn sftp_downloads_dir(
+    parent: Option<&gtk4::Window>,
+    retry: impl FnOnce() + 'static,
+) -> Option<String> {
+    let granted = SFTP_DOWNLOADS_DIR.with_borrow(Clone::clone);
+    match rustconn_core::sftp::downloads_location(granted.as_deref()) {
+        rustconn_core::sftp::DownloadsLocation::Directory(dir) => Some(dir),
+        rustconn_core::sftp::DownloadsLocation::Portal => {
+            let dialog = gtk4::FileDialog::builder()
+                .title(crate::i18n::i18n("Choose Folder for SFTP Downloads"))
+                .modal(true)
+                .build();
+            dialog.select_folder(parent, gtk4::gio::Cancellable::NONE, move |result| {
+                if let Ok(folder) = result
+                    && let Some(path) = folder.path()
+                {
+                    tracing::info!(path = %path.display(), "SFTP downloads folder granted");
+                    SFTP_DOWNLOADS_DIR.with_borrow_mut(|dir| *dir = Some(path));
+                    retry();
+                }
+            });
+            None
+        }
+    }
+}
@@ -472,6 +472,7 @@ fn start_quick_telnet(
         &[],
         rustconn_core::models::TelnetBackspaceSends::Automatic,
         rustconn_core::models::TelnetDeleteSends::Automatic,
+        None,
     );
 }
 
@@ -2116,6 +2116,12 @@ fn add_group_expect_rule_row(
     let rule_id = rule.map_or_else(Uuid::new_v4, |r| {
         pattern_entry.set_text(&r.pattern);
         response_entry.set_text(&r.response);
+        if let Some(secret) = &r.secret {
+            // The secret is resolved when the prompt appears; nothing to edit here
+            response_entry.set_sensitive(false);
+            response_entry
+                .set_placeholder_text(Some(&i18n_f("Secret variable: {}", &[&secret.variable])));
+        }
         priority_spin.set_value(f64::from(r.priority));
         timeout_spin.set_value(f64::from(r.timeout_ms.unwrap_or(0)));
         enabled_check.set_active(r.enabled);
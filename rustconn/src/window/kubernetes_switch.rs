@@ -0,0 +1,301 @@
+//! Context and namespace switching for Kubernetes sessions
+//!
+//! The tab context menu of a Kubernetes session offers "Switch
+//! Context/Namespace…". Contexts come from the session's kubeconfig; the
+//! namespaces of the chosen context are listed with `kubectl get namespaces`,
+//! falling back to the namespaces named in the kubeconfig. Applying a choice
+//! re-runs `kubectl` in the same tab. The saved connection is not changed.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gtk4::prelude::*;
+use libadwaita as adw;
+use libadwaita::prelude::*;
+use rustconn_core::protocol::{
+    KubeConfigContexts, KubeTarget, KubernetesProtocol, detect_kubectl, parse_namespace_list,
+};
+use uuid::Uuid;
+
+use super::protocols::{SharedNotebook, SharedSidebar, reconnect_generic_vte_in_place};
+use crate::i18n::{i18n, i18n_f};
+use crate::state::SharedAppState;
+use crate::toast::{ToastType, show_toast_on_window};
+use crate::utils::spawn_blocking_with_timeout;
+
+/// How long to wait for `kubectl get namespaces`
+const NAMESPACE_LIST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Shows the context/namespace chooser for a Kubernetes session
+pub(super) fn show_kubernetes_switch_dialog(
+    window: &adw::ApplicationWindow,
+    state: &SharedAppState,
+    notebook: &SharedNotebook,
+    sidebar: &SharedSidebar,
+    session_id: Uuid,
+) {
+    let Some(info) = notebook.get_session_info(session_id) else {
+        return;
+    };
+    let connection_id = info.connection_id;
+    let Some((conn_name, config)) = state.try_borrow().ok().and_then(|s| {
+        let conn = s.get_connection(connection_id)?;
+        match &conn.protocol_config {
+            rustconn_core::ProtocolConfig::Kubernetes(config) => {
+                Some((conn.name.clone(), config.clone()))
+            }
+            _ => None,
+        }
+    }) else {
+        return;
+    };
+
+    let kubectl_available = if rustconn_core::is_sandboxed() {
+        rustconn_core::flatpak::is_host_command_available("kubectl")
+    } else {
+        detect_kubectl().installed
+    };
+    if !kubectl_available {
+        crate::toast::show_missing_cli_toast(
+            window,
+            &i18n("Install kubectl for Kubernetes connections"),
+        );
+        return;
+    }
+
+    let contexts = match KubeConfigContexts::load(config.kubeconfig.as_deref()) {
+        Ok(contexts) if !contexts.contexts.is_empty() => contexts,
+        Ok(_) => {
+            show_toast_on_window(
+                window,
+                &i18n("No contexts found in kubeconfig"),
+                ToastType::Warning,
+            );
+            return;
+        }
+        Err(e) => {
+            tracing::warn!(%e, "Failed to read kubeconfig");
+            show_toast_on_window(window, &i18n("Failed to read kubeconfig"), ToastType::Error);
+            return;
+        }
+    };
+
+    let current = notebook
+        .kubernetes_target(session_id)
+        .unwrap_or_else(|| KubeTarget::of(&config));
+    let context_names: Vec<String> = contexts.names().into_iter().map(str::to_string).collect();
+    let selected_context = current
+        .context
+        .as_deref()
+        .or(contexts.current_context.as_deref())
+        .and_then(|name| context_names.iter().position(|n| n == name))
+        .unwrap_or(0);
+
+    let dialog = adw::AlertDialog::builder()
+        .heading(i18n("Switch Context/Namespace"))
+        .body(i18n_f(
+            "Reconnect “{}” to another context or namespace",
+            &[&conn_name],
+        ))
+        .build();
+
+    let group = adw::PreferencesGroup::new();
+    let context_list =
+        gtk4::StringList::new(&context_names.iter().map(String::as_str).collect::<Vec<_>>());
+    let context_row = adw::ComboRow::builder()
+        .title(i18n("Context"))
+        .model(&context_list)
+        .selected(u32::try_from(selected_context).unwrap_or(0))
+        .build();
+    group.add(&context_row);
+
+    let namespace_list = gtk4::StringList::new(&[]);
+    let namespace_row = adw::ComboRow::builder()
+        .title(i18n("Namespace"))
+        .subtitle(i18n("Loading namespaces…"))
+        .model(&namespace_list)
+        .build();
+    group.add(&namespace_row);
+    dialog.set_extra_child(Some(&group));
+
+    // Namespaces currently shown, in model order
+    let namespaces: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+    let contexts = Rc::new(contexts);
+    let context_names = Rc::new(context_names);
+
+    let refresh_namespaces = {
+        let config = config.clone();
+        let contexts = contexts.clone();
+        let context_names = context_names.clone();
+        let namespaces = namespaces.clone();
+        let context_row = context_row.clone();
+        let namespace_row = namespace_row.clone();
+        let namespace_list = namespace_list.clone();
+        let preferred = current.namespace.clone();
+        Rc::new(move || {
+            let Some(context) = context_names.get(context_row.selected() as usize).cloned() else {
+                return;
+            };
+            let context_default = contexts.get(&context).and_then(|c| c.namespace.clone());
+            let mut fallback = contexts.namespaces();
+            fallback.push("default".to_string());
+            fallback.extend(preferred.clone());
+            fallback.sort();
+            fallback.dedup();
+            fill_namespaces(
+                &namespace_row,
+                &namespace_list,
+                &namespaces,
+                fallback,
+                preferred.as_deref().or(context_default.as_deref()),
+            );
+            namespace_row.set_subtitle(&i18n("Loading namespaces…"));
+
+            let command = KubernetesProtocol::build_namespace_list_command(&config, Some(&context));
+            let context_names = context_names.clone();
+            let context_row = context_row.clone();
+            let namespace_row = namespace_row.clone();
+            let namespace_list = namespace_list.clone();
+            let namespaces = namespaces.clone();
+            let preferred = preferred.clone();
+            spawn_blocking_with_timeout(
+                move || list_namespaces(&command),
+                NAMESPACE_LIST_TIMEOUT,
+                move |result| {
+                    // Ignore results for a context that is no longer selected
+                    if context_names
+                        .get(context_row.selected() as usize)
+                        .is_none_or(|c| *c != context)
+                    {
+                        return;
+                    }
+                    match result.flatten() {
+                        Some(live) if !live.is_empty() => {
+                            namespace_row.set_subtitle("");
+                            fill_namespaces(
+                                &namespace_row,
+                                &namespace_list,
+                                &namespaces,
+                                live,
+                                preferred.as_deref().or(context_default.as_deref()),
+                            );
+                        }
+                        _ => {
+                            namespace_row.set_subtitle(&i18n(
+                                "Could not list namespaces; showing those from the kubeconfig",
+                            ));
+                        }
+                    }
+                },
+            );
+        })
+    };
+    refresh_namespaces();
+    {
+        let refresh_namespaces = refresh_namespaces.clone();
+        context_row.connect_selected_notify(move |_| refresh_namespaces());
+    }
+
+    dialog.add_response("cancel", &i18n("Cancel"));
+    dialog.add_response("switch", &i18n("Switch"));
+    dialog.set_response_appearance("switch", adw::ResponseAppearance::Suggested);
+    dialog.set_default_response(Some("switch"));
+    dialog.set_close_response("cancel");
+
+    let state = state.clone();
+    let notebook = notebook.clone();
+    let sidebar = sidebar.clone();
+    dialog.connect_response(None, move |_, response| {
+        if response != "switch" {
+            return;
+        }
+        let context = context_names.get(context_row.selected() as usize).cloned();
+        let namespace = namespaces
+            .borrow()
+            .get(namespace_row.selected() as usize)
+            .cloned();
+        let target = KubeTarget::new(context, namespace);
+        switch_kubernetes_session(
+            &state,
+            &notebook,
+            &sidebar,
+            session_id,
+            connection_id,
+            &conn_name,
+            target,
+        );
+    });
+
+    dialog.present(Some(window));
+}
+
+/// Replaces the namespace choices, keeping `preferred` selected if listed
+fn fill_namespaces(
+    row: &adw::ComboRow,
+    list: &gtk4::StringList,
+    shown: &Rc<RefCell<Vec<String>>>,
+    namespaces: Vec<String>,
+    preferred: Option<&str>,
+) {
+    let names: Vec<&str> = namespaces.iter().map(String::as_str).collect();
+    list.splice(0, list.n_items(), &names);
+    let selected = preferred
+        .and_then(|p| namespaces.iter().position(|n| n == p))
+        .or_else(|| namespaces.iter().position(|n| n == "default"))
+        .unwrap_or(0);
+    row.set_selected(u32::try_from(selected).unwrap_or(0));
+    *shown.borrow_mut() = namespaces;
+}
+
+/// Runs `kubectl get namespaces -o name`; `None` if it fails
+fn list_namespaces(command: &[String]) -> Option<Vec<String>> {
+    let (program, args) = command.split_first()?;
+    let output = std::process::Command::new(program)
+        .args(args)
+        .stdin(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        tracing::debug!(
+            stderr = %String::from_utf8_lossy(&output.stderr).trim(),
+            "kubectl get namespaces failed"
+        );
+        return None;
+    }
+    Some(parse_namespace_list(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Re-runs `kubectl` in the session's tab against `target`
+fn switch_kubernetes_session(
+    state: &SharedAppState,
+    notebook: &SharedNotebook,
+    sidebar: &SharedSidebar,
+    session_id: Uuid,
+    connection_id: Uuid,
+    conn_name: &str,
+    target: KubeTarget,
+) {
+    tracing::info!(
+        %session_id,
+        context = ?target.context,
+        namespace = ?target.namespace,
+        "Switching Kubernetes session target"
+    );
+    let title = target.session_title(conn_name);
+
+    // The running kubectl is replaced; its exit is not reported, so release
+    // its sidebar session count here
+    let was_connected = !notebook.is_reconnect_shown(session_id);
+    notebook.hang_up_child(session_id);
+    if was_connected {
+        sidebar.decrement_session_count(&connection_id.to_string(), false);
+    }
+
+    notebook.set_kubernetes_target(session_id, target);
+    if reconnect_generic_vte_in_place(state, notebook, sidebar, session_id, connection_id) {
+        notebook.set_tab_title(session_id, &title);
+    }
+}
@@ -5,6 +5,7 @@
 
 mod batch_edit;
 mod clusters;
+mod connect_guard;
 mod connection_actions;
 mod connection_dialogs;
 mod credentials;
@@ -14,6 +15,7 @@ mod edit_dialogs;
 mod edit_group;
 mod groups;
 mod history_actions;
+mod kubernetes_switch;
 mod navigation_actions;
 mod network_monitor;
 mod operations;
@@ -1634,7 +1636,8 @@ impl MainWindow {
             | rustconn_core::config::SecretBackendType::OnePassword
             | rustconn_core::config::SecretBackendType::Passbolt
             | rustconn_core::config::SecretBackendType::Pass
-            | rustconn_core::config::SecretBackendType::EncryptedFile => (true, true),
+            | rustconn_core::config::SecretBackendType::EncryptedFile
+            | rustconn_core::config::SecretBackendType::Vault => (true, true),
             rustconn_core::config::SecretBackendType::KeePassXc
             | rustconn_core::config::SecretBackendType::KdbxFile => {
                 let kdbx_enabled = settings.secrets.kdbx_enabled;
@@ -1759,8 +1762,10 @@ impl MainWindow {
                 }
             };
 
-            // Perform search with ranking
-            let results = search_engine.search(&parsed_query, &connections, &groups);
+            // Perform search with ranking (fuzzy mode never fails to compile)
+            let results = search_engine
+                .search(&parsed_query, &connections, &groups)
+                .unwrap_or_default();
 
             // Index by id once so result lookup is O(1) instead of O(n) per hit.
             let conn_by_id: std::collections::HashMap<_, _> =
@@ -1814,7 +1819,18 @@ impl MainWindow {
 
         let id_str = conn_item.id();
         if let Ok(conn_id) = Uuid::parse_str(&id_str) {
-            Self::start_connection(state, notebook, sidebar, monitoring, conn_id);
+            let (state_c, notebook, sidebar, monitoring) = (
+                state.clone(),
+                notebook.clone(),
+                sidebar.clone(),
+                monitoring.clone(),
+            );
+            Self::with_connect_guard(state, conn_id, move || {
+                let state = &state_c;
+                if Self::start_dependencies(state, &notebook, &sidebar, &monitoring, conn_id) {
+                    Self::start_connection(state, &notebook, &sidebar, &monitoring, conn_id);
+                }
+            });
         }
     }
 
@@ -1955,6 +1971,66 @@ impl MainWindow {
         true
     }
 
+    /// Starts the declared dependencies of a connection, in launch order.
+    ///
+    /// Dependencies that already have a live session are skipped. Like a
+    /// cluster, each one is dispatched through `start_connection`. Returns
+    /// `false` (after showing an error toast) when the dependency graph is
+    /// invalid or a dependency fails to start, so the dependent is not opened.
+    fn start_dependencies(
+        state: &SharedAppState,
+        notebook: &SharedNotebook,
+        sidebar: &SharedSidebar,
+        monitoring: &types::SharedMonitoring,
+        connection_id: Uuid,
+    ) -> bool {
+        let order = match state
+            .try_borrow()
+            .map(|s| s.resolve_launch_order(connection_id))
+        {
+            Ok(Ok(order)) => order,
+            Ok(Err(e)) => {
+                tracing::warn!(%connection_id, error = %e, "Invalid connection dependencies");
+                crate::toast::show_error_toast_on_active_window(&crate::i18n::i18n_f(
+                    "Cannot open connection: {}",
+                    &[&e.to_string()],
+                ));
+                return false;
+            }
+            Err(_) => return true,
+        };
+
+        let live: std::collections::HashSet<Uuid> = notebook
+            .get_all_sessions()
+            .into_iter()
+            .map(|s| s.connection_id)
+            .collect();
+
+        for dep_id in order
+            .into_iter()
+            .filter(|&id| id != connection_id && !live.contains(&id))
+        {
+            sidebar.update_connection_status(&dep_id.to_string(), "connecting");
+            if matches!(
+                Self::start_connection(state, notebook, sidebar, monitoring, dep_id),
+                types::ConnectionStartResult::Failed
+            ) {
+                sidebar.update_connection_status(&dep_id.to_string(), "failed");
+                let name = state
+                    .try_borrow()
+                    .ok()
+                    .and_then(|s| s.get_connection(dep_id).map(|c| c.name.clone()))
+                    .unwrap_or_else(|| dep_id.to_string());
+                crate::toast::show_error_toast_on_active_window(&crate::i18n::i18n_f(
+                    "Dependency ‘{}’ failed to connect",
+                    &[&name],
+                ));
+                return false;
+            }
+        }
+        true
+    }
+
     /// Starts a connection with split view integration
     pub fn start_connection_with_split(
         state: &SharedAppState,
@@ -1965,6 +2041,10 @@ impl MainWindow {
         connection_id: Uuid,
         activity: Option<&types::SharedActivityCoordinator>,
     ) -> Option<Uuid> {
+        if !Self::start_dependencies(state, notebook, sidebar, monitoring, connection_id) {
+            return None;
+        }
+
         // Update status to connecting
         sidebar.update_connection_status(&connection_id.to_string(), "connecting");
 
@@ -1995,9 +2075,13 @@ impl MainWindow {
 
         // Get session info to check protocol
         if let Some(info) = notebook.get_session_info(session_id) {
-            // VNC, RDP, and SPICE sessions are displayed directly in notebook tab
-            if info.protocol == "vnc" || info.protocol == "rdp" || info.protocol == "spice" {
-                // Hide split view and expand notebook for VNC/RDP/SPICE
+            // Sessions that can't live in a split panel (VNC, RDP, SPICE) are
+            // displayed directly in the notebook tab
+            let split_capable = rustconn_core::protocol::ProtocolRegistry::new()
+                .get(&info.protocol)
+                .is_none_or(|p| p.capabilities().split_view);
+            if !split_capable {
+                // Hide split view and expand notebook for graphical sessions
                 split_view.widget().set_visible(false);
                 split_view.widget().set_vexpand(false);
                 notebook.widget().set_vexpand(true);
@@ -2083,7 +2167,6 @@ impl MainWindow {
         };
 
         // Auto-WoL: send magic packet before connecting if configured
-        // Fire-and-forget on background thread to avoid blocking GTK
         if let Some(wol_config) = conn.get_wol_config() {
             let wol_config = wol_config.clone();
             let conn_name = conn.name.clone();
@@ -2092,6 +2175,60 @@ impl MainWindow {
                 "Sending auto-WoL before connecting to {}",
                 conn_name,
             );
+
+            // Wake and poll the connection port on a background thread, then
+            // continue connecting once the host answers
+            if wol_config.wait_for_online {
+                let host = conn.host.clone();
+                let port = conn.port;
+                drop(state_ref);
+
+                let state = state.clone();
+                let notebook = notebook.clone();
+                let sidebar = sidebar.clone();
+                let monitoring = monitoring.clone();
+                crate::utils::spawn_blocking_with_callback(
+                    move || {
+                        crate::async_utils::with_runtime(|rt| {
+                            rt.block_on(rustconn_core::wol::wake_and_wait(&wol_config, &host, port))
+                        })
+                        .and_then(|result| result.map_err(|e| e.to_string()))
+                    },
+                    move |result| match result {
+                        Ok(waited) => {
+                            tracing::info!(
+                                waited_secs = waited.as_secs(),
+                                "{} is online after auto-WoL",
+                                conn_name,
+                            );
+                            if matches!(
+                                Self::start_connection_after_wol(
+                                    &state,
+                                    &notebook,
+                                    &sidebar,
+                                    &monitoring,
+                                    connection_id,
+                                ),
+                                types::ConnectionStartResult::Failed
+                            ) {
+                                sidebar
+                                    .update_connection_status(&connection_id.to_string(), "failed");
+                            }
+                        }
+                        Err(e) => {
+                            tracing::warn!(%e, "Auto-WoL failed for {}", conn_name);
+                            sidebar.update_connection_status(&connection_id.to_string(), "failed");
+                            crate::toast::show_error_toast_on_active_window(&crate::i18n::i18n_f(
+                                "Wake-on-LAN failed: {}",
+                                &[&e],
+                            ));
+                        }
+                    },
+                );
+                return types::ConnectionStartResult::Pending;
+            }
+
+            // Fire-and-forget on background thread to avoid blocking GTK
             std::thread::spawn(move || {
                 if let Err(e) = rustconn_core::wol::send_wol_with_retry(&wol_config, 3, 500) {
                     tracing::warn!(?e, "Auto-WoL failed for {}", conn_name,);
@@ -2099,6 +2236,24 @@ impl MainWindow {
             });
         }
 
+        drop(state_ref);
+        Self::start_connection_after_wol(state, notebook, sidebar, monitoring, connection_id)
+    }
+
+    /// Continues starting a connection once any Wake-on-LAN step is done
+    fn start_connection_after_wol(
+        state: &SharedAppState,
+        notebook: &SharedNotebook,
+        sidebar: &SharedSidebar,
+        monitoring: &types::SharedMonitoring,
+        connection_id: Uuid,
+    ) -> types::ConnectionStartResult {
+        let state_ref = state.borrow();
+
+        let Some(conn) = state_ref.get_connection(connection_id) else {
+            return types::ConnectionStartResult::Failed;
+        };
+
         let protocol = get_protocol_string(&conn.protocol_config);
         let logging_enabled = state_ref.settings().logging.enabled;
 
@@ -2126,21 +2281,7 @@ impl MainWindow {
             }
             // Add connection-scoped synthetic variables (host, port, username, name)
             let conn_id = conn_clone.id;
-            var_manager.set_connection(
-                conn_id,
-                rustconn_core::Variable::new("host", &conn_clone.host),
-            );
-            var_manager.set_connection(
-                conn_id,
-                rustconn_core::Variable::new("port", conn_clone.port.to_string()),
-            );
-            if let Some(ref user) = conn_clone.username {
-                var_manager.set_connection(conn_id, rustconn_core::Variable::new("username", user));
-            }
-            var_manager.set_connection(
-                conn_id,
-                rustconn_core::Variable::new("name", &conn_clone.name),
-            );
+            var_manager.set_connection_builtins(&conn_clone);
 
             let folder_tracker = state
                 .try_borrow()
@@ -2863,6 +3004,15 @@ impl MainWindow {
         tracing::debug!("settings action activated");
         let mut dialog = SettingsDialog::new(None);
 
+        // Preview theme changes on open terminals while the dialog is open
+        {
+            let notebook = notebook.clone();
+            let state = state.clone();
+            dialog.set_on_theme_preview(move |theme_name| {
+                Self::apply_terminal_theme(&notebook, &state, theme_name);
+            });
+        }
+
         // Load current settings and connections
         {
             let state_ref = state.borrow();
@@ -2976,7 +3126,8 @@ impl MainWindow {
                                 | rustconn_core::config::SecretBackendType::OnePassword
                                 | rustconn_core::config::SecretBackendType::Passbolt
                                 | rustconn_core::config::SecretBackendType::Pass
-                                | rustconn_core::config::SecretBackendType::EncryptedFile => true,
+                                | rustconn_core::config::SecretBackendType::EncryptedFile
+                                | rustconn_core::config::SecretBackendType::Vault => true,
                                 rustconn_core::config::SecretBackendType::KeePassXc
                                 | rustconn_core::config::SecretBackendType::KdbxFile => {
                                     keepass_enabled && kdbx_path_exists
@@ -2988,10 +3139,30 @@ impl MainWindow {
                 } else {
                     tracing::error!("Failed to borrow state for settings update");
                 }
+            } else {
+                // Nothing saved: drop any previewed theme
+                let theme_name = state.borrow().settings().terminal.color_theme.clone();
+                Self::apply_terminal_theme(&notebook, &state, &theme_name);
             }
         });
     }
 
+    /// Applies a terminal theme to every open terminal, then restores
+    /// per-connection theme overrides on top of it
+    fn apply_terminal_theme(notebook: &SharedNotebook, state: &SharedAppState, theme_name: &str) {
+        notebook.apply_theme(&rustconn_core::terminal_themes::TerminalTheme::resolve(
+            theme_name,
+        ));
+        let Ok(state_ref) = state.try_borrow() else {
+            return;
+        };
+        notebook.reapply_theme_overrides(theme_name, |connection_id| {
+            state_ref
+                .get_connection(connection_id)
+                .and_then(|c| c.theme_override.clone())
+        });
+    }
+
     /// Edits the selected connection or group
     fn edit_selected_connection(
         window: &adw::ApplicationWindow,
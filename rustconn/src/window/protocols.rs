@@ -7,7 +7,9 @@ use std::rc::Rc;
 
 use gtk4::glib;
 use gtk4::prelude::*;
-use rustconn_core::connection::{automation_inheritance, check_port, ssh_inheritance};
+use rustconn_core::connection::{
+    automation_inheritance, check_port, check_port_with_proxy, probe_host, ssh_inheritance,
+};
 use rustconn_core::models::AutomationConfig;
 use rustconn_core::variables::{Variable, VariableManager, VariableScope};
 use uuid::Uuid;
@@ -61,6 +63,25 @@ pub(super) fn substitute_variables(input: &str, global_variables: &[Variable]) -
         .unwrap_or_else(|_| input.to_string())
 }
 
+/// Resolves a connection's terminal environment as `NAME=value` entries
+///
+/// See [`rustconn_core::Connection::resolve_env`]. Global variables are only
+/// loaded (which may query the secret backend) when the connection sets
+/// environment variables.
+pub(super) fn resolve_connection_env(
+    state: &SharedAppState,
+    conn: &rustconn_core::Connection,
+) -> Vec<String> {
+    if conn.env.is_empty() {
+        return Vec::new();
+    }
+    state
+        .try_borrow()
+        .ok()
+        .map(|s| conn.resolve_env(&crate::state::resolve_global_variables(s.settings())))
+        .unwrap_or_default()
+}
+
 /// Known external viewers that hand control to a daemon or a separate process
 /// and then exit their initial child (a *detaching viewer*, R5.7).
 ///
@@ -440,6 +461,8 @@ pub fn start_vnc_connection(
     if should_check {
         let host = conn.host.clone();
         let port = conn.port;
+        let proxy = conn.proxy.clone();
+        let tailscale = conn.probes_via_tailscale();
         let timeout = settings.connection.port_check_timeout_secs;
         let state_clone = state.clone();
         let notebook_clone = notebook.clone();
@@ -448,7 +471,9 @@ pub fn start_vnc_connection(
 
         // Run port check in background thread
         spawn_blocking_with_callback(
-            move || check_port(&host, port, timeout),
+            move || {
+                check_port_with_proxy(proxy.as_ref(), &probe_host(&host, tailscale), port, timeout)
+            },
             move |result| {
                 match result {
                     Ok(_) => {
@@ -539,7 +564,7 @@ fn start_vnc_connection_internal(
     // viewer and register it so the sidebar surfaces it without a dead tab
     // (R1.1). The password is handled by the viewer, never on the command line.
     if conn.uses_external_viewer() {
-        let Some(viewer) = crate::session::VncSessionWidget::detect_vnc_viewer() else {
+        let Some(viewer) = crate::session::VncSessionWidget::detect_vnc_viewer(&vnc_config) else {
             tracing::error!(connection = %conn_name, "No external VNC viewer installed");
             crate::toast::show_error_toast_on_active_window(&i18n(
                 "No VNC viewer found. Install TigerVNC or Remmina.",
@@ -679,6 +704,8 @@ pub fn start_spice_connection(
     if should_check {
         let host = conn.host.clone();
         let port = conn.port;
+        let proxy = conn.proxy.clone();
+        let tailscale = conn.probes_via_tailscale();
         let timeout = settings.connection.port_check_timeout_secs;
         let state_clone = state.clone();
         let notebook_clone = notebook.clone();
@@ -687,7 +714,9 @@ pub fn start_spice_connection(
 
         // Run port check in background thread
         spawn_blocking_with_callback(
-            move || check_port(&host, port, timeout),
+            move || {
+                check_port_with_proxy(proxy.as_ref(), &probe_host(&host, tailscale), port, timeout)
+            },
             move |result| {
                 match result {
                     Ok(_) => {
@@ -742,7 +771,7 @@ fn start_spice_connection_internal(
     conn: &rustconn_core::Connection,
 ) -> Option<Uuid> {
     use rustconn_core::spice_client::{
-        SpiceClientConfig, build_spice_viewer_args, detect_spice_viewer,
+        SpiceClientConfig, SpiceUsbFilter, build_spice_viewer_args, detect_spice_viewer,
     };
 
     let conn_name = conn.name.clone();
@@ -880,6 +909,22 @@ fn start_spice_connection_internal(
         if let Some(ref socket_path) = opts.unix_socket_path {
             config = config.with_unix_socket(socket_path);
         }
+        for folder in &opts.shared_folders {
+            config = config.with_shared_folder(folder.into());
+        }
+        match SpiceUsbFilter::parse_list(&opts.usb_redirect_filters) {
+            Ok(filters) => {
+                for filter in filters {
+                    config = config.with_usb_filter(filter);
+                }
+            }
+            Err(e) => {
+                tracing::error!(connection = %conn_name, error = %e, "Invalid SPICE USB filter");
+                crate::toast::show_error_toast_on_active_window(&e.to_string());
+                sidebar.update_connection_status(&connection_id.to_string(), "failed");
+                return None;
+            }
+        }
     }
     let args = build_spice_viewer_args(&config);
     spawn_and_register_external_viewer(
@@ -925,7 +970,7 @@ pub fn reconnect_generic_vte_in_place(
     // Show "connecting" status in sidebar immediately
     sidebar.update_connection_status(&connection_id.to_string(), "connecting");
 
-    let conn = {
+    let mut conn = {
         let Ok(state_ref) = state.try_borrow() else {
             return false;
         };
@@ -935,6 +980,13 @@ pub fn reconnect_generic_vte_in_place(
         }
     };
 
+    // Keep the context/namespace a Kubernetes session was switched to
+    if let Some(target) = notebook.kubernetes_target(session_id)
+        && let rustconn_core::ProtocolConfig::Kubernetes(ref mut k8s_config) = conn.protocol_config
+    {
+        target.apply_to(k8s_config);
+    }
+
     // Re-apply highlight rules
     {
         let global_rules = state
@@ -954,6 +1006,9 @@ pub fn reconnect_generic_vte_in_place(
     // Re-wire child-exited handler
     MainWindow::setup_child_exited_handler(state, notebook, sidebar, session_id, connection_id);
 
+    let conn_env = resolve_connection_env(state, &conn);
+    let env_refs: Vec<&str> = conn_env.iter().map(String::as_str).collect();
+
     // Build and spawn command based on protocol
     match &conn.protocol_config {
         rustconn_core::ProtocolConfig::ZeroTrust(zt_config) => {
@@ -973,7 +1028,7 @@ pub fn reconnect_generic_vte_in_place(
             notebook.spawn_command(
                 session_id,
                 &[&shell, "-c", &spawn_command],
-                None,
+                Some(&env_refs),
                 None,
                 None,
             );
@@ -990,26 +1045,23 @@ pub fn reconnect_generic_vte_in_place(
                 &[],
                 telnet_config.backspace_sends,
                 telnet_config.delete_sends,
+                Some(&env_refs),
             );
         }
         rustconn_core::ProtocolConfig::Serial(_) => {
             let serial = SerialProtocol::new();
-            let Some(command) = serial.build_command(&conn) else {
+            let Some(mut command) = serial.build_command(&conn) else {
                 tracing::warn!(%session_id, "Failed to build Serial command for reconnect");
                 return false;
             };
-            let serial_config =
-                if let rustconn_core::ProtocolConfig::Serial(ref cfg) = conn.protocol_config {
-                    cfg
-                } else {
-                    return false;
-                };
-
-            let conn_msg = format_connection_message("Serial", &serial_config.device);
+            attach_serial_capture(notebook, session_id, &conn, &mut command);
+            // The adapter may be back under a different device node
+            let device = command.last().map_or("", String::as_str);
+            let conn_msg = format_connection_message("Serial", device);
             let cmd_msg = format_command_message(&command.join(" "));
             notebook.display_output(session_id, &format!("{conn_msg}\r\n{cmd_msg}\r\n\r\n"));
 
-            notebook.spawn_serial(session_id, &command);
+            notebook.spawn_serial(session_id, &command, Some(&env_refs));
         }
         rustconn_core::ProtocolConfig::Kubernetes(_) => {
             let k8s = KubernetesProtocol::new();
@@ -1028,7 +1080,7 @@ pub fn reconnect_generic_vte_in_place(
             // kubectl runs in-sandbox under Flatpak (Flatpak Components), so the
             // old flatpak-spawn wrapper is no longer needed — same as Mosh below.
             let argv: Vec<&str> = command.iter().map(String::as_str).collect();
-            notebook.spawn_command(session_id, &argv, None, None, None);
+            notebook.spawn_command(session_id, &argv, Some(&env_refs), None, None);
         }
         rustconn_core::ProtocolConfig::Mosh(_) => {
             let mosh = MoshProtocol::new();
@@ -1042,8 +1094,18 @@ pub fn reconnect_generic_vte_in_place(
             notebook.display_output(session_id, &format!("{conn_msg}\r\n{cmd_msg}\r\n\r\n"));
 
             // Mosh uses direct exec (no shell wrapper needed)
-            let argv: Vec<&str> = command.iter().map(String::as_str).collect();
-            notebook.spawn_command(session_id, &argv, None, None, None);
+            let groups: Vec<rustconn_core::ConnectionGroup> = state
+                .try_borrow()
+                .ok()
+                .map(|s| s.list_groups().into_iter().cloned().collect())
+                .unwrap_or_default();
+            let agent_socket = ssh_inheritance::resolve_ssh_agent_socket(&conn, &groups);
+            notebook.spawn_mosh(
+                session_id,
+                &command,
+                agent_socket.as_deref(),
+                Some(&env_refs),
+            );
         }
         _ => {
             tracing::warn!("Unsupported protocol for generic VTE reconnect");
@@ -1098,6 +1160,8 @@ pub fn start_telnet_connection(
     if should_check {
         let host = conn.host.clone();
         let port = conn.port;
+        let proxy = conn.proxy.clone();
+        let tailscale = conn.probes_via_tailscale();
         let timeout = settings.connection.port_check_timeout_secs;
         let state_clone = state.clone();
         let notebook_clone = notebook.clone();
@@ -1106,7 +1170,9 @@ pub fn start_telnet_connection(
 
         // Run port check in background thread
         spawn_blocking_with_callback(
-            move || check_port(&host, port, timeout),
+            move || {
+                check_port_with_proxy(proxy.as_ref(), &probe_host(&host, tailscale), port, timeout)
+            },
             move |result| match result {
                 Ok(_) => {
                     start_telnet_connection_internal(
@@ -1175,11 +1241,11 @@ fn start_telnet_connection_internal(
     let conn_name = conn.name.clone();
     let port = conn.port;
 
-    // Get terminal settings from state
+    // Get terminal settings from state, with the connection's overrides
     let terminal_settings = state
         .try_borrow()
         .ok()
-        .map(|s| s.settings().terminal.clone())
+        .map(|s| s.settings().terminal.for_connection(conn))
         .unwrap_or_default();
 
     // Get global variables for substitution (secret values resolved from vault)
@@ -1272,6 +1338,8 @@ fn start_telnet_connection_internal(
 
     // Spawn telnet
     let extra_refs: Vec<&str> = extra_args.iter().map(String::as_str).collect();
+    let conn_env = resolve_connection_env(state, conn);
+    let env_refs: Vec<&str> = conn_env.iter().map(String::as_str).collect();
     notebook.spawn_telnet(
         session_id,
         &host,
@@ -1279,6 +1347,7 @@ fn start_telnet_connection_internal(
         &extra_refs,
         backspace_sends,
         delete_sends,
+        Some(&env_refs),
     );
 
     // --- Auto-recording for Telnet ---
@@ -1403,11 +1472,11 @@ pub fn start_zerotrust_connection(
 
     let automation_config = resolve_automation_for_connection(state, conn);
 
-    // Get terminal settings from state
+    // Get terminal settings from state, with the connection's overrides
     let terminal_settings = state
         .try_borrow()
         .ok()
-        .map(|s| s.settings().terminal.clone())
+        .map(|s| s.settings().terminal.for_connection(conn))
         .unwrap_or_default();
 
     // Get global variables for substitution in Expect responses
@@ -1479,6 +1548,8 @@ pub fn start_zerotrust_connection(
         rustconn_core::ProtocolConfig::ZeroTrust(zt)
             if matches!(zt.provider, rustconn_core::models::ZeroTrustProvider::Generic)
     );
+    let conn_env = resolve_connection_env(state, conn);
+    let env_refs: Vec<&str> = conn_env.iter().map(String::as_str).collect();
 
     if is_generic && rustconn_core::flatpak::is_flatpak() {
         // Generic command_template is already a shell command string.
@@ -1502,7 +1573,13 @@ pub fn start_zerotrust_connection(
         let spawn_cmd = format!(
             "flatpak-spawn --host --env=TERM=xterm-256color -- sh -lc '{host_runner}' rustconn '{escaped}'"
         );
-        notebook.spawn_command(session_id, &["/bin/sh", "-c", &spawn_cmd], None, None, None);
+        notebook.spawn_command(
+            session_id,
+            &["/bin/sh", "-c", &spawn_cmd],
+            Some(&env_refs),
+            None,
+            None,
+        );
     } else {
         // For Generic provider, build_command already returns ("sh", ["-c", "template"])
         // which is a complete shell invocation. Wrapping in yet another shell would
@@ -1512,14 +1589,14 @@ pub fn start_zerotrust_connection(
             let spawn_argv: Vec<&str> = std::iter::once(program.as_str())
                 .chain(args.iter().map(String::as_str))
                 .collect();
-            notebook.spawn_command(session_id, &spawn_argv, None, None, None);
+            notebook.spawn_command(session_id, &spawn_argv, Some(&env_refs), None, None);
         } else {
             let spawn_command = rustconn_core::flatpak::wrap_host_command(&full_command);
             let shell = std::env::var("SHELL").unwrap_or_else(|_| "/bin/bash".to_string());
             notebook.spawn_command(
                 session_id,
                 &[&shell, "-c", &spawn_command],
-                None,
+                Some(&env_refs),
                 None,
                 None,
             );
@@ -1567,7 +1644,7 @@ pub fn start_serial_connection(
 
     // Build picocom command via SerialProtocol
     let serial = SerialProtocol::new();
-    let Some(command) = serial.build_command(conn) else {
+    let Some(mut command) = serial.build_command(conn) else {
         tracing::error!(
             connection = %conn_name,
             "Failed to build picocom command for Serial connection"
@@ -1581,11 +1658,11 @@ pub fn start_serial_connection(
         "Starting Serial connection"
     );
 
-    // Get terminal settings from state
+    // Get terminal settings from state, with the connection's overrides
     let terminal_settings = state
         .try_borrow()
         .ok()
-        .map(|s| s.settings().terminal.clone())
+        .map(|s| s.settings().terminal.for_connection(conn))
         .unwrap_or_default();
 
     // Get global variables for substitution in Expect responses
@@ -1645,12 +1722,11 @@ pub fn start_serial_connection(
     // Wire up child exited callback
     MainWindow::setup_child_exited_handler(state, notebook, sidebar, session_id, connection_id);
 
-    // Get device name for display
-    let device = if let rustconn_core::ProtocolConfig::Serial(ref cfg) = conn.protocol_config {
-        cfg.device.clone()
-    } else {
-        String::new()
-    };
+    attach_serial_capture(notebook, session_id, conn, &mut command);
+
+    // Device name for display: the node picocom opens, which differs from
+    // the configured path when a USB adapter was looked up
+    let device = command.last().cloned().unwrap_or_default();
 
     // Build command string for display
     let serial_command = command.join(" ");
@@ -1660,7 +1736,9 @@ pub fn start_serial_connection(
     notebook.display_output(session_id, &feedback);
 
     // Spawn picocom
-    notebook.spawn_serial(session_id, &command);
+    let conn_env = resolve_connection_env(state, conn);
+    let env_refs: Vec<&str> = conn_env.iter().map(String::as_str).collect();
+    notebook.spawn_serial(session_id, &command, Some(&env_refs));
 
     // --- Auto-recording for Serial ---
     if conn.session_recording_enabled {
@@ -1679,6 +1757,41 @@ pub fn start_serial_connection(
     Some(session_id)
 }
 
+/// Starts the raw byte capture of a serial session if the connection asks
+/// for one
+///
+/// Adds picocom's `--logfile` arguments in front of the device (the last
+/// element of `command`) and keeps the capture alive with the tab. A capture
+/// that cannot be started is logged and the session runs without it.
+fn attach_serial_capture(
+    notebook: &SharedNotebook,
+    session_id: Uuid,
+    conn: &rustconn_core::Connection,
+    command: &mut Vec<String>,
+) {
+    use rustconn_core::session::{LogContext, SerialCapture, SerialCaptureTap};
+
+    let rustconn_core::ProtocolConfig::Serial(ref config) = conn.protocol_config else {
+        return;
+    };
+    if !config.capture_on_connect {
+        return;
+    }
+    let context = LogContext::new(&conn.name, "serial");
+    let tap = SerialCapture::new(config, &context, None).and_then(SerialCaptureTap::spawn);
+    match tap {
+        Ok(tap) => {
+            let device_idx = command.len().saturating_sub(1);
+            command.splice(device_idx..device_idx, tap.picocom_args());
+            notebook.store_serial_capture(session_id, tap);
+            tracing::info!(%session_id, "Serial raw capture started");
+        }
+        Err(e) => {
+            tracing::warn!(%session_id, %e, "Failed to start serial raw capture");
+        }
+    }
+}
+
 /// Starts a Kubernetes connection
 ///
 /// Creates a terminal tab and spawns `kubectl exec` or `kubectl run`
@@ -1748,11 +1861,11 @@ pub fn start_kubernetes_connection(
         "Starting Kubernetes connection"
     );
 
-    // Get terminal settings from state
+    // Get terminal settings from state, with the connection's overrides
     let terminal_settings = state
         .try_borrow()
         .ok()
-        .map(|s| s.settings().terminal.clone())
+        .map(|s| s.settings().terminal.for_connection(conn))
         .unwrap_or_default();
 
     // Get global variables for substitution in Expect responses
@@ -1835,7 +1948,9 @@ pub fn start_kubernetes_connection(
     // in (possibly imported, untrusted) kubectl configs. kubectl runs in-sandbox
     // under Flatpak (Flatpak Components), so no host wrapper is needed.
     let argv: Vec<&str> = command.iter().map(String::as_str).collect();
-    notebook.spawn_command(session_id, &argv, None, None, None);
+    let conn_env = resolve_connection_env(state, conn);
+    let env_refs: Vec<&str> = conn_env.iter().map(String::as_str).collect();
+    notebook.spawn_command(session_id, &argv, Some(&env_refs), None, None);
 
     // --- Auto-recording for Kubernetes ---
     if conn.session_recording_enabled {
@@ -1883,11 +1998,12 @@ pub fn start_mosh_connection(
 
     if should_check {
         let ssh_port = if let rustconn_core::ProtocolConfig::Mosh(ref cfg) = conn.protocol_config {
-            cfg.ssh_port.unwrap_or(22)
+            cfg.ssh_port.unwrap_or(conn.port)
         } else {
-            22
+            conn.port
         };
         let host = conn.host.clone();
+        let tailscale = conn.probes_via_tailscale();
         let timeout = settings.connection.port_check_timeout_secs;
         let state_clone = state.clone();
         let notebook_clone = notebook.clone();
@@ -1895,7 +2011,7 @@ pub fn start_mosh_connection(
         let conn_clone = conn.clone();
 
         spawn_blocking_with_callback(
-            move || check_port(&host, ssh_port, timeout),
+            move || check_port(&probe_host(&host, tailscale), ssh_port, timeout),
             move |result| match result {
                 Ok(_) => {
                     start_mosh_connection_internal(
@@ -1996,11 +2112,11 @@ fn start_mosh_connection_internal(
         "Starting MOSH connection"
     );
 
-    // Get terminal settings from state
+    // Get terminal settings from state, with the connection's overrides
     let terminal_settings = state
         .try_borrow()
         .ok()
-        .map(|s| s.settings().terminal.clone())
+        .map(|s| s.settings().terminal.for_connection(conn))
         .unwrap_or_default();
 
     // Get global variables for substitution in Expect responses
@@ -2067,9 +2183,21 @@ fn start_mosh_connection_internal(
     let feedback = format!("{conn_msg}\r\n{cmd_msg}\r\n\r\n");
     notebook.display_output(session_id, &feedback);
 
-    // Spawn mosh — uses exec (no shell wrapper needed)
-    let argv: Vec<&str> = command.iter().map(String::as_str).collect();
-    notebook.spawn_command(session_id, &argv, None, None, None);
+    // Spawn mosh — its SSH handshake needs the same agent as plain SSH
+    let groups: Vec<rustconn_core::ConnectionGroup> = state
+        .try_borrow()
+        .ok()
+        .map(|s| s.list_groups().into_iter().cloned().collect())
+        .unwrap_or_default();
+    let agent_socket = ssh_inheritance::resolve_ssh_agent_socket(conn, &groups);
+    let conn_env = resolve_connection_env(state, conn);
+    let env_refs: Vec<&str> = conn_env.iter().map(String::as_str).collect();
+    notebook.spawn_mosh(
+        session_id,
+        &command,
+        agent_socket.as_deref(),
+        Some(&env_refs),
+    );
 
     // --- Auto-recording for MOSH ---
     if conn.session_recording_enabled {
@@ -2078,9 +2206,9 @@ fn start_mosh_connection_internal(
         let recording_started = std::rc::Rc::new(std::cell::Cell::new(false));
         let recording_started_clone = recording_started.clone();
         let ssh_port = if let rustconn_core::ProtocolConfig::Mosh(ref cfg) = conn.protocol_config {
-            cfg.ssh_port.unwrap_or(22)
+            cfg.ssh_port.unwrap_or(conn.port)
         } else {
-            22
+            conn.port
         };
         let recording_ssh_params = Some(crate::terminal::SshRecordingParams {
             host: conn.host.clone(),
@@ -6,7 +6,9 @@ use std::rc::Rc;
 
 use gtk4::glib;
 use gtk4::prelude::*;
-use rustconn_core::connection::{check_port, ssh_inheritance};
+use rustconn_core::connection::{check_port_with_proxy, probe_host, ssh_inheritance};
+use rustconn_core::models::SshKeySource;
+use rustconn_core::secret::{KeePassXcAttachments, TempIdentityFile, export_attachment_key};
 use secrecy::SecretString;
 use uuid::Uuid;
 
@@ -15,6 +17,7 @@ use super::protocols::{
     SharedNotebook, SharedSidebar, append_proxy_command_destination, contains_ssh_failure,
     resolve_automation_for_connection, substitute_variables,
 };
+use crate::i18n::{i18n, i18n_f};
 use crate::state::SharedAppState;
 use crate::utils::spawn_blocking_with_callback;
 
@@ -192,7 +195,27 @@ fn build_ssh_command_args(
     // Use build_command_args() for all SSH-specific flags:
     // identity, IdentitiesOnly, proxy_jump, ControlMaster/Persist,
     // agent forwarding, X11, compression, custom options, port forwards
-    let mut args = ssh_config.build_command_args();
+    // (including those of the connection's port-forward profiles)
+    let mut args = if ssh_config.port_forward_profiles.is_empty() {
+        ssh_config.build_command_args()
+    } else {
+        let profiles = state
+            .try_borrow()
+            .ok()
+            .and_then(|state_ref| {
+                state_ref
+                    .config_manager()
+                    .load_port_forward_profiles()
+                    .inspect_err(|e| {
+                        tracing::warn!(%e, "Failed to load port forward profiles");
+                    })
+                    .ok()
+            })
+            .unwrap_or_default();
+        let mut expanded = ssh_config.clone();
+        expanded.expand_port_forward_profiles(&profiles);
+        expanded.build_command_args()
+    };
 
     // Remove -i <path> from args because the identity file is already
     // resolved separately via resolve_ssh_key_path() and passed as
@@ -223,8 +246,19 @@ fn build_ssh_command_args(
     // the nested ProxyCommand ssh, NOT via the VTE prompt.
     let mut first_hop_password: Option<SecretString> = None;
 
-    // Handle string-based proxy jump (legacy/manual or inherited from group)
-    if let Some(proxy) = ssh_inheritance::resolve_ssh_proxy_jump(conn, groups) {
+    // Explicit jump-host chain: stored client-first, but `jump_hosts` is
+    // target-first (see proxy_jump_arg), so push the hops in reverse.
+    if ssh_config.first_jump_host().is_some() {
+        jump_hosts.extend(
+            ssh_config
+                .jump_hosts
+                .iter()
+                .rev()
+                .filter(|hop| !hop.host.trim().is_empty())
+                .map(rustconn_core::JumpHost::to_spec),
+        );
+    } else if let Some(proxy) = ssh_inheritance::resolve_ssh_proxy_jump(conn, groups) {
+        // String-based proxy jump (legacy/manual or inherited from group)
         jump_hosts.push(proxy);
     }
 
@@ -494,8 +528,8 @@ fn build_ssh_command_args(
     let jump_host_str = if jump_hosts.is_empty() {
         None
     } else {
-        // Remove the -J added by build_command_args (if proxy_jump was set)
-        if ssh_config.proxy_jump.is_some()
+        // Remove the -J added by build_command_args (if a jump chain was set)
+        if ssh_config.proxy_jump_spec().is_some()
             && let Some(pos) = args.iter().position(|a| a == "-J")
         {
             args.remove(pos); // remove "-J"
@@ -677,6 +711,24 @@ fn build_ssh_command_args(
         Some(chain)
     };
 
+    // Zero-trust access tunnel. Connection::check_access_tunnel rejects it
+    // alongside any other route.
+    if let Some(ref tunnel) = conn.access_tunnel {
+        args.extend(tunnel.ssh_args());
+    }
+
+    // Per-connection SOCKS/HTTP proxy. Connection::check_proxy rejects it
+    // alongside a jump host or ProxyCommand, so this is the only route.
+    if jump_host_str.is_none()
+        && conn.access_tunnel.is_none()
+        && ssh_config.proxy_command.is_none()
+        && ssh_config.proxy_jump_spec().is_none()
+        && let Some(ref proxy) = conn.proxy
+    {
+        args.push("-o".to_string());
+        args.push(format!("ProxyCommand={}", proxy.ssh_proxy_command()));
+    }
+
     // Check waypipe: enabled in config + binary available on PATH
     let waypipe = ssh_config.waypipe && rustconn_core::protocol::detect_waypipe().installed;
     if ssh_config.waypipe && !waypipe {
@@ -736,6 +788,68 @@ fn bastion_may_prompt_for_password(
     }
 }
 
+/// Exports an SSH key stored as a `KeePass` entry attachment for one session.
+///
+/// Returns `None` when the connection does not use a `KeePass` attachment, or
+/// when the export fails; the error is then shown in the terminal and ssh
+/// falls back to its default keys. The returned file must outlive the ssh
+/// process, see [`keep_identity_until_exit`].
+fn export_keepass_identity(
+    state: &SharedAppState,
+    notebook: &SharedNotebook,
+    session_id: Uuid,
+    conn: &rustconn_core::Connection,
+) -> Option<TempIdentityFile> {
+    let rustconn_core::ProtocolConfig::Ssh(ssh) = &conn.protocol_config else {
+        return None;
+    };
+    if !matches!(ssh.key_source, SshKeySource::KeePassAttachment { .. }) {
+        return None;
+    }
+    let fetcher = state.try_borrow().ok().and_then(|s| {
+        let secrets = &s.settings().secrets;
+        secrets.kdbx_path.clone().map(|path| {
+            KeePassXcAttachments::new(
+                path,
+                secrets.kdbx_password.clone(),
+                secrets.kdbx_key_file.clone(),
+            )
+        })
+    });
+    let result = fetcher.map_or_else(
+        || Err(i18n("No KeePass database is configured")),
+        |fetcher| export_attachment_key(&ssh.key_source, &fetcher).map_err(|e| e.to_string()),
+    );
+    match result {
+        Ok(key) => key,
+        Err(e) => {
+            tracing::error!(connection = %conn.name, %e, "Failed to export SSH key from KeePass");
+            notebook.display_output(
+                session_id,
+                &format!("{}: {e}\r\n", i18n("Could not export SSH key from KeePass")),
+            );
+            None
+        }
+    }
+}
+
+/// Keeps an exported identity file until the session's ssh process exits.
+///
+/// The file is also removed if the terminal is destroyed first, since the
+/// handler (and the file it owns) is dropped with it.
+fn keep_identity_until_exit(
+    notebook: &SharedNotebook,
+    session_id: Uuid,
+    key: Option<TempIdentityFile>,
+) {
+    if let Some(key) = key {
+        let key = std::cell::RefCell::new(Some(key));
+        notebook.connect_child_exited(session_id, move |_| {
+            key.borrow_mut().take();
+        });
+    }
+}
+
 /// Creates a terminal tab and spawns the SSH process with the given configuration.
 pub fn start_ssh_connection(
     state: &SharedAppState,
@@ -746,6 +860,34 @@ pub fn start_ssh_connection(
     conn: &rustconn_core::Connection,
     logging_enabled: bool,
 ) -> Option<Uuid> {
+    // A zero-trust access tunnel runs the provider's client as ProxyCommand
+    if let Some(tunnel) = &conn.access_tunnel {
+        let available = if rustconn_core::is_sandboxed() {
+            rustconn_core::flatpak::is_host_command_available(tunnel.client())
+        } else {
+            tunnel.detect_client().installed
+        };
+        if !available {
+            tracing::warn!(
+                connection = %conn.name,
+                client = tunnel.client(),
+                "Access tunnel client not found for SSH connection"
+            );
+            if let Some(root) = notebook.widget().root()
+                && let Some(window) = root.downcast_ref::<gtk4::Window>()
+            {
+                crate::toast::show_missing_cli_toast(
+                    window,
+                    &i18n_f(
+                        "Install {} to connect through {}",
+                        &[tunnel.client(), tunnel.provider().display_name()],
+                    ),
+                );
+            }
+            return None;
+        }
+    }
+
     // Check if port check is needed
     let settings = state.borrow().settings().clone();
     // Collect groups for SSH inheritance resolution (proxy_jump can be inherited from group)
@@ -770,6 +912,8 @@ pub fn start_ssh_connection(
     if should_check {
         let host = conn.host.clone();
         let port = conn.port;
+        let proxy = conn.proxy.clone();
+        let tailscale = conn.probes_via_tailscale();
         let timeout = settings.connection.port_check_timeout_secs;
         let state_clone = state.clone();
         let notebook_clone = notebook.clone();
@@ -779,7 +923,9 @@ pub fn start_ssh_connection(
 
         // Run port check in background thread
         spawn_blocking_with_callback(
-            move || check_port(&host, port, timeout),
+            move || {
+                check_port_with_proxy(proxy.as_ref(), &probe_host(&host, tailscale), port, timeout)
+            },
             move |result| {
                 match result {
                     Ok(_) => {
@@ -859,11 +1005,11 @@ fn start_ssh_connection_internal(
 
     let conn_name = conn.name.clone();
 
-    // Get terminal settings from state
+    // Get terminal settings from state, with the connection's overrides
     let terminal_settings = state
         .try_borrow()
         .ok()
-        .map(|s| s.settings().terminal.clone())
+        .map(|s| s.settings().terminal.for_connection(conn))
         .unwrap_or_default();
 
     // Get global variables for substitution (secret values resolved from vault)
@@ -937,6 +1083,13 @@ fn start_ssh_connection_internal(
     let (identity_file, extra_args, use_waypipe, jump_host_chain, jump_host_passwords) =
         build_ssh_command_args(conn, connection_id, state, &groups);
 
+    // Keys stored as KeePass attachments are exported for this session only
+    let keepass_identity = export_keepass_identity(state, notebook, session_id, conn);
+    let identity_file = keepass_identity
+        .as_ref()
+        .map(|key| key.path().display().to_string())
+        .or(identity_file);
+
     // Extract MPTCP flag from SSH config
     let use_mptcp =
         matches!(&conn.protocol_config, rustconn_core::ProtocolConfig::Ssh(cfg) if cfg.mptcp);
@@ -1024,7 +1177,14 @@ fn start_ssh_connection_internal(
                 zeroize::Zeroizing::new(format!("{env_name}={}", pw.expose_secret()))
             })
             .collect();
-        let extra_env_refs: Vec<&str> = jump_host_env.iter().map(|e| e.as_str()).collect();
+        // Per-connection variables first, so jump host secrets cannot be
+        // shadowed by a same-named entry
+        let conn_env = conn.resolve_env(&global_variables);
+        let extra_env_refs: Vec<&str> = conn_env
+            .iter()
+            .map(String::as_str)
+            .chain(jump_host_env.iter().map(|e| e.as_str()))
+            .collect();
         notebook.spawn_ssh(
             session_id,
             &host,
@@ -1043,6 +1203,7 @@ fn start_ssh_connection_internal(
             use_mptcp,
         );
     }
+    keep_identity_until_exit(notebook, session_id, keepass_identity);
 
     // --- VTE password injection: detect "password:" prompt and feed cached password ---
     // This replaces the previous sshpass dependency. The terminal output is
@@ -1286,6 +1447,8 @@ fn start_ssh_connection_internal(
             let identity_file_mon = ssh_inheritance::resolve_ssh_key_path(conn, &groups)
                 .and_then(|p| rustconn_core::resolve_key_path(&p))
                 .map(|p| p.to_string_lossy().to_string());
+            // Same agent socket as spawn_ssh so agent-only auth works for monitoring
+            let agent_socket_mon = ssh_inheritance::resolve_ssh_agent_socket(conn, &groups);
             let cached_pw = state_ref
                 .get_cached_credentials(connection_id)
                 .and_then(|c| {
@@ -1329,6 +1492,7 @@ fn start_ssh_connection_internal(
                         identity_file_mon.as_deref(),
                         cached_pw.clone(),
                         mon_jump_host.as_deref(),
+                        agent_socket_mon.as_deref(),
                     );
                 }
             });
@@ -1419,6 +1583,13 @@ pub fn reconnect_ssh_in_place(
     let (identity_file, extra_args, use_waypipe, jump_host_chain, jump_host_passwords) =
         build_ssh_command_args(&conn, connection_id, state, &groups);
 
+    // Keys stored as KeePass attachments are exported for this session only
+    let keepass_identity = export_keepass_identity(state, notebook, session_id, &conn);
+    let identity_file = keepass_identity
+        .as_ref()
+        .map(|key| key.path().display().to_string())
+        .or(identity_file);
+
     // Extract MPTCP flag from SSH config
     let use_mptcp =
         matches!(&conn.protocol_config, rustconn_core::ProtocolConfig::Ssh(cfg) if cfg.mptcp);
@@ -1491,7 +1662,14 @@ pub fn reconnect_ssh_in_place(
                 zeroize::Zeroizing::new(format!("{env_name}={}", pw.expose_secret()))
             })
             .collect();
-        let extra_env_refs: Vec<&str> = jump_host_env.iter().map(|e| e.as_str()).collect();
+        // Per-connection variables first, so jump host secrets cannot be
+        // shadowed by a same-named entry
+        let conn_env = conn.resolve_env(&global_variables);
+        let extra_env_refs: Vec<&str> = conn_env
+            .iter()
+            .map(String::as_str)
+            .chain(jump_host_env.iter().map(|e| e.as_str()))
+            .collect();
         notebook.spawn_ssh(
             session_id,
             &host,
@@ -1510,6 +1688,7 @@ pub fn reconnect_ssh_in_place(
             use_mptcp,
         );
     }
+    keep_identity_until_exit(notebook, session_id, keepass_identity);
 
     // VTE password injection (issue #194: also subscribe to cursor-moved)
     // NOTE: Passphrase prompts ("Enter passphrase for key") are explicitly
@@ -1681,6 +1860,8 @@ pub fn reconnect_ssh_in_place(
             let identity_file_mon = ssh_inheritance::resolve_ssh_key_path(&conn, &groups)
                 .and_then(|p| rustconn_core::resolve_key_path(&p))
                 .map(|p| p.to_string_lossy().to_string());
+            // Same agent socket as spawn_ssh so agent-only auth works for monitoring
+            let agent_socket_mon = ssh_inheritance::resolve_ssh_agent_socket(&conn, &groups);
             let cached_pw = state_ref
                 .get_cached_credentials(connection_id)
                 .and_then(|c| {
@@ -1724,6 +1905,7 @@ pub fn reconnect_ssh_in_place(
                         identity_file_mon.as_deref(),
                         cached_pw.clone(),
                         mon_jump_host.as_deref(),
+                        agent_socket_mon.as_deref(),
                     );
                 }
             });
@@ -536,6 +536,19 @@ fn start_embedded_rdp_session(
         embedded_config.gateway_hostname = Some(gateway.hostname.clone());
         embedded_config.gateway_port = gateway.port;
         embedded_config.gateway_username = gateway.username.clone();
+        embedded_config.gateway_domain = gateway.domain.clone();
+        // A separate gateway account has its own password source, resolved
+        // apart from the session password
+        if gateway.has_own_credentials() {
+            embedded_config.gateway_password = state
+                .try_borrow()
+                .ok()
+                .and_then(|s| {
+                    s.get_connection(connection_id)
+                        .and_then(|conn| s.resolve_gateway_credentials_blocking(conn))
+                })
+                .and_then(|credentials| credentials.password);
+        }
     }
 
     // Pass mouse jiggler settings
@@ -1296,7 +1309,7 @@ fn start_vnc_session_internal(
     if let Some(ref conn_hist) = conn_for_history
         && conn_hist.uses_external_viewer()
     {
-        let Some(viewer) = crate::session::VncSessionWidget::detect_vnc_viewer() else {
+        let Some(viewer) = crate::session::VncSessionWidget::detect_vnc_viewer(&vnc_config) else {
             tracing::error!(connection = %conn_name, "No external VNC viewer installed");
             crate::toast::show_error_toast_on_active_window(&crate::i18n::i18n(
                 "No VNC viewer found. Install TigerVNC or Remmina.",
@@ -386,24 +386,7 @@ impl MainWindow {
                 }
                 // Add connection-scoped synthetic variables (host, port, username, name)
                 if let Some(ref conn) = post_disconnect_conn {
-                    var_manager.set_connection(
-                        connection_id,
-                        rustconn_core::Variable::new("host", &conn.host),
-                    );
-                    var_manager.set_connection(
-                        connection_id,
-                        rustconn_core::Variable::new("port", conn.port.to_string()),
-                    );
-                    if let Some(ref user) = conn.username {
-                        var_manager.set_connection(
-                            connection_id,
-                            rustconn_core::Variable::new("username", user),
-                        );
-                    }
-                    var_manager.set_connection(
-                        connection_id,
-                        rustconn_core::Variable::new("name", &conn.name),
-                    );
+                    var_manager.set_connection_builtins(conn);
                 }
 
                 let executor =
@@ -602,9 +585,11 @@ impl MainWindow {
                 && let Ok(state_ref) = state_clone.try_borrow()
                 && let Some(conn) = state_ref.get_connection(connection_id)
             {
-                // Use per-connection retry config or default
-                let retry_config = conn.retry_config.clone()
-                    .unwrap_or_default();
+                // Use per-connection retry config or the jittered default
+                let retry_config = conn
+                    .retry_config
+                    .clone()
+                    .unwrap_or_else(rustconn_core::RetryConfig::for_reconnect);
 
                 // If retry is explicitly disabled, skip auto-reconnect
                 if !retry_config.enabled {
@@ -613,6 +598,22 @@ impl MainWindow {
                     return;
                 }
 
+                // A replugged USB serial adapter has no host to poll; wait
+                // for its device node to come back instead
+                if let rustconn_core::ProtocolConfig::Serial(ref serial) = conn.protocol_config
+                    && let Some(device_match) = serial.device_match.clone()
+                {
+                    drop(state_ref);
+                    Self::watch_serial_adapter(
+                        &notebook_clone,
+                        session_id,
+                        connection_id,
+                        device_match,
+                    );
+                    sidebar_clone.decrement_session_count(&connection_id_str, is_failure);
+                    return;
+                }
+
                 let host = conn.host.clone();
                 let port = conn.port;
                 drop(state_ref);
@@ -629,6 +630,8 @@ impl MainWindow {
                     %port,
                     max_attempts = retry_config.max_attempts,
                     initial_delay_ms = retry_config.initial_delay_ms,
+                    jitter = retry_config.jitter_fraction(),
+                    max_elapsed_ms = ?retry_config.max_elapsed_ms,
                     "Starting auto-reconnect with exponential backoff"
                 );
 
@@ -719,6 +722,62 @@ impl MainWindow {
         });
     }
 
+    /// Reconnects a serial session once its USB adapter is plugged back in
+    ///
+    /// Checks once a second. An adapter that is still present when the
+    /// session ends must disappear first or come back under another device
+    /// node, so a session failing for other reasons does not reconnect in a
+    /// loop. Closing the tab stops the watch.
+    fn watch_serial_adapter(
+        notebook: &SharedNotebook,
+        session_id: Uuid,
+        connection_id: Uuid,
+        device_match: rustconn_core::models::SerialDeviceMatch,
+    ) {
+        let resolver = rustconn_core::SerialDeviceResolver::default();
+        let initial = resolver.find(&device_match);
+        let mut seen_absent = initial.is_none();
+
+        let cancel = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        notebook.register_poll_cancel(session_id, cancel.clone());
+        notebook.update_reconnect_banner_status(session_id, true);
+        tracing::info!(
+            %connection_id,
+            %device_match,
+            "Waiting for serial adapter to be plugged back in"
+        );
+
+        let notebook = notebook.clone();
+        let on_reconnect = notebook.reconnect_callback();
+        glib::timeout_add_seconds_local(1, move || {
+            if cancel.load(std::sync::atomic::Ordering::Relaxed) {
+                return glib::ControlFlow::Break;
+            }
+            let current = resolver.find(&device_match);
+            if current.is_none() {
+                seen_absent = true;
+                return glib::ControlFlow::Continue;
+            }
+            if !seen_absent && current == initial {
+                return glib::ControlFlow::Continue;
+            }
+
+            notebook.cancel_poll(session_id);
+            if !notebook.sessions_map().borrow().contains_key(&session_id) {
+                return glib::ControlFlow::Break;
+            }
+            tracing::info!(
+                %connection_id,
+                node = ?current,
+                "Serial adapter is back, triggering reconnect"
+            );
+            if let Some(ref cb) = *on_reconnect.borrow() {
+                cb(session_id, connection_id);
+            }
+            glib::ControlFlow::Break
+        });
+    }
+
     /// Sets up logging handlers for a terminal session based on settings
     ///
     /// Supports three logging modes:
@@ -671,11 +671,18 @@ pub fn show_variable_input_dialog(
             entry.set_text(default);
         }
 
-        // Set placeholder from snippet variable description
-        if let Some(var_def) = snippet.variables.iter().find(|v| &v.name == var_name)
-            && let Some(ref desc) = var_def.description
-        {
-            entry.set_placeholder_text(Some(desc));
+        // Set placeholder from snippet variable description, or list the
+        // options of a choice variable
+        if let Some(var_def) = snippet.variables.iter().find(|v| &v.name == var_name) {
+            if let Some(ref desc) = var_def.description {
+                entry.set_placeholder_text(Some(desc));
+            } else if let Some(options) = var_def.kind.options() {
+                entry.set_placeholder_text(Some(&options.join(" | ")));
+            }
+            if var_def.kind.is_secret() {
+                entry.set_visibility(false);
+                entry.set_input_purpose(gtk4::InputPurpose::Password);
+            }
         }
 
         #[expect(
@@ -357,6 +357,23 @@ pub fn refresh_templates_list(
     }
 }
 
+/// Builds a connection from a template, resolving its parent chain
+///
+/// If the chain cannot be resolved (missing parent, cycle), a warning toast
+/// is shown and only the template's own fields are used.
+pub fn connection_from_template(
+    state: &SharedAppState,
+    template: &rustconn_core::models::ConnectionTemplate,
+) -> rustconn_core::models::Connection {
+    let templates = state.borrow().get_all_templates();
+    rustconn_core::models::Connection::new_from_template(template, &templates, None)
+        .unwrap_or_else(|e| {
+            tracing::warn!(template = %template.name, error = %e, "Template inheritance not resolved");
+            crate::toast::show_warning_toast_on_active_window(&e.to_string());
+            template.apply(None)
+        })
+}
+
 /// Shows the new connection dialog pre-populated from a template
 pub fn show_new_connection_from_template(
     window: &gtk4::Window,
@@ -365,7 +382,7 @@ pub fn show_new_connection_from_template(
     template: &rustconn_core::models::ConnectionTemplate,
 ) {
     // Create connection from template
-    let connection = template.apply(None);
+    let connection = connection_from_template(&state, template);
 
     let dialog = ConnectionDialog::new(Some(window), state.clone());
     dialog.setup_key_file_chooser(Some(window));
@@ -259,6 +259,29 @@ impl MainWindow {
         });
         window.add_action(&close_tab_by_id_action);
 
+        // Switch the context/namespace of a Kubernetes session (tab menu)
+        let kubernetes_switch_action =
+            gio::SimpleAction::new("kubernetes-switch", Some(glib::VariantTy::STRING));
+        let window_clone = window.clone();
+        let notebook_clone = terminal_notebook.clone();
+        let sidebar_clone = sidebar.clone();
+        let state_clone = state.clone();
+        kubernetes_switch_action.connect_activate(move |_, param| {
+            if let Some(param) = param
+                && let Some(session_id_str) = param.get::<String>()
+                && let Ok(session_id) = uuid::Uuid::parse_str(&session_id_str)
+            {
+                kubernetes_switch::show_kubernetes_switch_dialog(
+                    &window_clone,
+                    &state_clone,
+                    &notebook_clone,
+                    &sidebar_clone,
+                    session_id,
+                );
+            }
+        });
+        window.add_action(&kubernetes_switch_action);
+
         // Local shell action
         let local_shell_action = gio::SimpleAction::new("local-shell", None);
         let notebook_clone = terminal_notebook.clone();